@@ -1,10 +1,14 @@
 pub mod address;
 pub mod binary;
+pub mod calculation;
+pub(crate) mod chart_render;
 pub mod color;
+pub mod conditional_formatting;
 pub mod const_str;
 pub mod coordinate;
 pub mod crypt;
 pub mod date;
+pub mod font_metrics;
 pub mod formula;
 pub mod html;
 pub mod number_format;
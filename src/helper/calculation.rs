@@ -0,0 +1,1184 @@
+//! Formula calculation engine.
+//!
+//! Formulas are tokenized with `helper::formula::parse_to_tokens`, converted to an
+//! expression tree and evaluated against the worksheets of a workbook.
+
+mod date_function;
+mod logical_function;
+mod lookup_function;
+mod math_function;
+mod text_function;
+
+use crate::helper::address::*;
+use crate::helper::coordinate::*;
+use crate::helper::formula::*;
+use crate::structs::CellErrorType;
+use crate::structs::CellRawValue;
+use crate::structs::DefinedName;
+use crate::structs::Worksheet;
+use std::cmp::Ordering;
+use std::collections::HashMap;
+use std::collections::HashSet;
+
+/// Maximum nesting of defined name lookups before the name is treated as invalid.
+const MAX_NAME_DEPTH: usize = 16;
+
+/// A value produced while evaluating a formula.
+#[derive(Clone, Debug, PartialEq)]
+pub(crate) enum CalcValue {
+    Empty,
+    Number(f64),
+    Text(String),
+    Bool(bool),
+    Error(CellErrorType),
+    /// Values of a range or an array constant, stored row by row.
+    Array(Vec<Vec<CalcValue>>),
+}
+impl CalcValue {
+    pub(crate) fn from_raw(value: &CellRawValue) -> Self {
+        match value {
+            CellRawValue::String(v) => CalcValue::Text(v.to_string()),
+            CellRawValue::RichText(v) => CalcValue::Text(v.get_text().to_string()),
+            CellRawValue::Lazy(v) => {
+                CalcValue::from_raw(&crate::structs::CellValue::guess_typed_data(v))
+            }
+            CellRawValue::Numeric(v) => CalcValue::Number(*v),
+            CellRawValue::Bool(v) => CalcValue::Bool(*v),
+            CellRawValue::Error(v) => CalcValue::Error(v.clone()),
+            CellRawValue::Empty => CalcValue::Empty,
+        }
+    }
+
+    /// Convert the result of a formula into a cell value.
+    /// Empty results are shown as `0` by Excel.
+    pub(crate) fn into_raw(self) -> CellRawValue {
+        match self.into_scalar() {
+            CalcValue::Empty => CellRawValue::Numeric(0f64),
+            CalcValue::Number(v) => CellRawValue::Numeric(v),
+            CalcValue::Text(v) => CellRawValue::String(v.into_boxed_str()),
+            CalcValue::Bool(v) => CellRawValue::Bool(v),
+            CalcValue::Error(v) => CellRawValue::Error(v),
+            CalcValue::Array(_) => CellRawValue::Error(CellErrorType::Value),
+        }
+    }
+
+    /// Top-left value of an array, or the value itself.
+    pub(crate) fn into_scalar(self) -> Self {
+        match self {
+            CalcValue::Array(rows) => rows
+                .into_iter()
+                .next()
+                .and_then(|row| row.into_iter().next())
+                .map(|v| v.into_scalar())
+                .unwrap_or(CalcValue::Error(CellErrorType::Value)),
+            _ => self,
+        }
+    }
+
+    #[inline]
+    pub(crate) fn is_error(&self) -> bool {
+        matches!(self, CalcValue::Error(_))
+    }
+
+    pub(crate) fn to_number(&self) -> Result<f64, CellErrorType> {
+        match self {
+            CalcValue::Empty => Ok(0f64),
+            CalcValue::Number(v) => Ok(*v),
+            CalcValue::Bool(v) => Ok(if *v { 1f64 } else { 0f64 }),
+            CalcValue::Text(v) => parse_number(v).ok_or(CellErrorType::Value),
+            CalcValue::Error(e) => Err(e.clone()),
+            CalcValue::Array(_) => self.clone().into_scalar().to_number(),
+        }
+    }
+
+    pub(crate) fn to_text(&self) -> Result<String, CellErrorType> {
+        match self {
+            CalcValue::Empty => Ok(String::new()),
+            CalcValue::Number(v) => Ok(number_to_string(*v)),
+            CalcValue::Bool(v) => Ok(if *v { "TRUE" } else { "FALSE" }.to_string()),
+            CalcValue::Text(v) => Ok(v.clone()),
+            CalcValue::Error(e) => Err(e.clone()),
+            CalcValue::Array(_) => self.clone().into_scalar().to_text(),
+        }
+    }
+
+    pub(crate) fn to_bool(&self) -> Result<bool, CellErrorType> {
+        match self {
+            CalcValue::Empty => Ok(false),
+            CalcValue::Number(v) => Ok(*v != 0f64),
+            CalcValue::Bool(v) => Ok(*v),
+            CalcValue::Text(v) => match v.to_uppercase().as_str() {
+                "TRUE" => Ok(true),
+                "FALSE" => Ok(false),
+                _ => Err(CellErrorType::Value),
+            },
+            CalcValue::Error(e) => Err(e.clone()),
+            CalcValue::Array(_) => self.clone().into_scalar().to_bool(),
+        }
+    }
+
+    /// All values of an array (row by row), or the value itself.
+    pub(crate) fn flatten(&self) -> Vec<&CalcValue> {
+        match self {
+            CalcValue::Array(rows) => rows.iter().flat_map(|row| row.iter()).collect(),
+            _ => vec![self],
+        }
+    }
+
+    /// Rows of an array; a scalar is treated as a 1x1 array.
+    pub(crate) fn rows(&self) -> Vec<Vec<CalcValue>> {
+        match self {
+            CalcValue::Array(rows) => rows.clone(),
+            _ => vec![vec![self.clone()]],
+        }
+    }
+}
+
+/// Parse text the way Excel coerces it to a number (`"1.5"`, `" 2 "`, `"50%"`).
+pub(crate) fn parse_number(value: &str) -> Option<f64> {
+    let value = value.trim();
+    if value.is_empty() {
+        return None;
+    }
+    if let Some(v) = value.strip_suffix('%') {
+        return v.trim().parse::<f64>().ok().map(|v| v / 100f64);
+    }
+    value.parse::<f64>().ok()
+}
+
+/// Convert a number to text with up to 15 significant digits, like Excel does.
+pub(crate) fn number_to_string(value: f64) -> String {
+    if value == 0f64 {
+        return "0".to_string();
+    }
+    if value.fract() == 0f64 && value.abs() < 1e15 {
+        return format!("{}", value as i64);
+    }
+    let exponent = value.abs().log10().floor() as i32;
+    if !(-10..=14).contains(&exponent) {
+        let result = format!("{:.14E}", value);
+        let (mantissa, exponent) = result.split_once('E').unwrap();
+        let mantissa = mantissa.trim_end_matches('0').trim_end_matches('.');
+        let exponent = exponent.parse::<i32>().unwrap();
+        let sign = if exponent < 0 { '-' } else { '+' };
+        return format!("{}E{}{:02}", mantissa, sign, exponent.abs());
+    }
+    let decimals = (14 - exponent).max(0) as usize;
+    let result = format!("{:.*}", decimals, value);
+    if result.contains('.') {
+        result
+            .trim_end_matches('0')
+            .trim_end_matches('.')
+            .to_string()
+    } else {
+        result
+    }
+}
+
+/// Compare two scalar values using Excel's ordering
+/// (numbers < text < logical values, text compared case-insensitively).
+pub(crate) fn compare_values(left: &CalcValue, right: &CalcValue) -> Ordering {
+    fn rank(value: &CalcValue) -> u8 {
+        match value {
+            CalcValue::Number(_) | CalcValue::Empty => 0,
+            CalcValue::Text(_) => 1,
+            CalcValue::Bool(_) => 2,
+            _ => 3,
+        }
+    }
+    match (left, right) {
+        (CalcValue::Empty, CalcValue::Text(v)) => "".cmp(&v.to_lowercase()[..]),
+        (CalcValue::Text(v), CalcValue::Empty) => v.to_lowercase()[..].cmp(""),
+        (CalcValue::Empty, CalcValue::Bool(v)) => false.cmp(v),
+        (CalcValue::Bool(v), CalcValue::Empty) => v.cmp(&false),
+        (CalcValue::Number(_) | CalcValue::Empty, CalcValue::Number(_) | CalcValue::Empty) => {
+            let l = left.to_number().unwrap_or_default();
+            let r = right.to_number().unwrap_or_default();
+            l.partial_cmp(&r).unwrap_or(Ordering::Equal)
+        }
+        (CalcValue::Text(l), CalcValue::Text(r)) => l.to_lowercase().cmp(&r.to_lowercase()),
+        (CalcValue::Bool(l), CalcValue::Bool(r)) => l.cmp(r),
+        _ => rank(left).cmp(&rank(right)),
+    }
+}
+
+/// Expression tree built from formula tokens.
+#[derive(Clone, Debug, PartialEq)]
+pub(crate) enum Expression {
+    Value(CalcValue),
+    Reference(String),
+    Array(Vec<Vec<Expression>>),
+    Negate(Box<Expression>),
+    Percent(Box<Expression>),
+    Binary(String, Box<Expression>, Box<Expression>),
+    Function(String, Vec<Expression>),
+}
+
+struct Parser {
+    tokens: Vec<FormulaToken>,
+    position: usize,
+}
+impl Parser {
+    fn new(tokens: Vec<FormulaToken>) -> Self {
+        Self {
+            tokens,
+            position: 0,
+        }
+    }
+
+    #[inline]
+    fn peek(&self) -> Option<&FormulaToken> {
+        self.tokens.get(self.position)
+    }
+
+    #[inline]
+    fn next(&mut self) -> Option<FormulaToken> {
+        let token = self.tokens.get(self.position).cloned();
+        self.position += 1;
+        token
+    }
+
+    fn is_separator(token: &FormulaToken) -> bool {
+        token.get_value() == ","
+            && (token.get_token_type() == &FormulaTokenTypes::Argument
+                || token.get_token_sub_type() == &FormulaTokenSubTypes::Union)
+    }
+
+    fn is_stop(token: &FormulaToken) -> bool {
+        token.get_token_sub_type() == &FormulaTokenSubTypes::Stop
+            && (token.get_token_type() == &FormulaTokenTypes::Function
+                || token.get_token_type() == &FormulaTokenTypes::Subexpression)
+    }
+
+    fn infix_binding_power(token: &FormulaToken) -> Option<(u8, u8)> {
+        if token.get_token_type() != &FormulaTokenTypes::OperatorInfix {
+            return None;
+        }
+        if token.get_token_sub_type() == &FormulaTokenSubTypes::Intersection {
+            return Some((13, 14));
+        }
+        match token.get_value() {
+            "=" | "<>" | "<" | ">" | "<=" | ">=" => Some((1, 2)),
+            "&" => Some((3, 4)),
+            "+" | "-" => Some((5, 6)),
+            "*" | "/" => Some((7, 8)),
+            "^" => Some((9, 10)),
+            _ => None,
+        }
+    }
+
+    fn parse(mut self) -> Result<Expression, CellErrorType> {
+        let expression = self.parse_expression(0)?;
+        if self.peek().is_some() {
+            return Err(CellErrorType::Name);
+        }
+        Ok(expression)
+    }
+
+    fn parse_expression(&mut self, min_bp: u8) -> Result<Expression, CellErrorType> {
+        let mut lhs = self.parse_primary()?;
+        loop {
+            let token = match self.peek() {
+                Some(v) => v.clone(),
+                None => break,
+            };
+            if token.get_token_type() == &FormulaTokenTypes::OperatorPostfix {
+                self.next();
+                lhs = Expression::Percent(Box::new(lhs));
+                continue;
+            }
+            let (l_bp, r_bp) = match Self::infix_binding_power(&token) {
+                Some(v) => v,
+                None => break,
+            };
+            if l_bp < min_bp {
+                break;
+            }
+            self.next();
+            let rhs = self.parse_expression(r_bp)?;
+            let operator = if token.get_token_sub_type() == &FormulaTokenSubTypes::Intersection {
+                " ".to_string()
+            } else {
+                token.get_value().to_string()
+            };
+            lhs = Expression::Binary(operator, Box::new(lhs), Box::new(rhs));
+        }
+        Ok(lhs)
+    }
+
+    fn parse_primary(&mut self) -> Result<Expression, CellErrorType> {
+        let token = self.next().ok_or(CellErrorType::Name)?;
+        match token.get_token_type() {
+            FormulaTokenTypes::Operand => match token.get_token_sub_type() {
+                FormulaTokenSubTypes::Number => token
+                    .get_value()
+                    .parse::<f64>()
+                    .map(|v| Expression::Value(CalcValue::Number(v)))
+                    .map_err(|_| CellErrorType::Name),
+                FormulaTokenSubTypes::Text => Ok(Expression::Value(CalcValue::Text(
+                    token.get_value().to_string(),
+                ))),
+                FormulaTokenSubTypes::Logical => Ok(Expression::Value(CalcValue::Bool(
+                    token.get_value().to_uppercase() == "TRUE",
+                ))),
+                FormulaTokenSubTypes::Error => token
+                    .get_value()
+                    .parse::<CellErrorType>()
+                    .map(|v| Expression::Value(CalcValue::Error(v)))
+                    .map_err(|_| CellErrorType::Name),
+                _ => Ok(Expression::Reference(token.get_value().to_string())),
+            },
+            FormulaTokenTypes::OperatorPrefix => {
+                let operand = self.parse_expression(11)?;
+                Ok(Expression::Negate(Box::new(operand)))
+            }
+            FormulaTokenTypes::Subexpression
+                if token.get_token_sub_type() == &FormulaTokenSubTypes::Start =>
+            {
+                let expression = self.parse_expression(0)?;
+                match self.next() {
+                    Some(v) if Self::is_stop(&v) => Ok(expression),
+                    _ => Err(CellErrorType::Name),
+                }
+            }
+            FormulaTokenTypes::Function
+                if token.get_token_sub_type() == &FormulaTokenSubTypes::Start =>
+            {
+                if token.get_value() == "ARRAY" {
+                    return self.parse_array();
+                }
+                let name = token
+                    .get_value()
+                    .to_uppercase()
+                    .trim_start_matches("_XLFN.")
+                    .trim_start_matches("_XLWS.")
+                    .to_string();
+                let args = self.parse_arguments()?;
+                Ok(Expression::Function(name, args))
+            }
+            _ => Err(CellErrorType::Name),
+        }
+    }
+
+    fn parse_arguments(&mut self) -> Result<Vec<Expression>, CellErrorType> {
+        let mut args = Vec::new();
+        if let Some(v) = self.peek() {
+            if Self::is_stop(v) {
+                self.next();
+                return Ok(args);
+            }
+        }
+        loop {
+            // omitted argument, e.g. `IF(A1,,1)`
+            let omitted = match self.peek() {
+                Some(v) => Self::is_separator(v) || Self::is_stop(v),
+                None => return Err(CellErrorType::Name),
+            };
+            if omitted {
+                args.push(Expression::Value(CalcValue::Empty));
+            } else {
+                args.push(self.parse_expression(0)?);
+            }
+            match self.next() {
+                Some(v) if Self::is_separator(&v) => continue,
+                Some(v) if Self::is_stop(&v) => break,
+                _ => return Err(CellErrorType::Name),
+            }
+        }
+        Ok(args)
+    }
+
+    fn parse_array(&mut self) -> Result<Expression, CellErrorType> {
+        let mut rows = Vec::new();
+        loop {
+            match self.next() {
+                Some(v)
+                    if v.get_token_type() == &FormulaTokenTypes::Function
+                        && v.get_value() == "ARRAYROW" =>
+                {
+                    rows.push(self.parse_arguments()?);
+                }
+                Some(v) if Self::is_separator(&v) => continue,
+                Some(v) if Self::is_stop(&v) => break,
+                _ => return Err(CellErrorType::Name),
+            }
+        }
+        Ok(Expression::Array(rows))
+    }
+}
+
+/// Parse a formula (with or without the leading `=`) into an expression tree.
+pub(crate) fn parse_formula(formula: &str) -> Result<Expression, CellErrorType> {
+    let formula = formula.trim();
+    let formula = if formula.starts_with('=') {
+        formula.to_string()
+    } else {
+        format!("={}", formula)
+    };
+    let tokens = parse_to_tokens(formula);
+    if tokens.is_empty() {
+        return Err(CellErrorType::Name);
+    }
+    Parser::new(tokens).parse()
+}
+
+/// A rectangular cell area on a worksheet.
+#[derive(Clone, Debug, PartialEq)]
+pub(crate) struct RangeReference {
+    pub(crate) sheet_index: usize,
+    pub(crate) col_start: u32,
+    pub(crate) row_start: u32,
+    pub(crate) col_end: u32,
+    pub(crate) row_end: u32,
+}
+
+/// Evaluates formulas against a set of worksheets and caches cell results.
+pub(crate) struct Calculator<'a> {
+    sheets: Vec<&'a Worksheet>,
+    defined_names: Vec<(Option<usize>, &'a DefinedName)>,
+    cache: HashMap<(usize, u32, u32), CalcValue>,
+    in_progress: HashSet<(usize, u32, u32)>,
+    used_areas: Vec<(u32, u32)>,
+    name_depth: usize,
+    position: (u32, u32),
+}
+impl<'a> Calculator<'a> {
+    pub(crate) fn new(sheets: Vec<&'a Worksheet>, defined_names: &'a [DefinedName]) -> Self {
+        let mut names: Vec<(Option<usize>, &'a DefinedName)> = Vec::new();
+        for defined_name in defined_names {
+            let local = if defined_name.has_local_sheet_id() {
+                Some(*defined_name.get_local_sheet_id() as usize)
+            } else {
+                None
+            };
+            names.push((local, defined_name));
+        }
+        for (index, sheet) in sheets.iter().enumerate() {
+            for defined_name in sheet.get_defined_names() {
+                names.push((Some(index), defined_name));
+            }
+        }
+        let used_areas = sheets
+            .iter()
+            .map(|v| v.get_highest_column_and_row())
+            .collect();
+        Self {
+            sheets,
+            defined_names: names,
+            cache: HashMap::new(),
+            in_progress: HashSet::new(),
+            used_areas,
+            name_depth: 0,
+            position: (1, 1),
+        }
+    }
+
+    /// Returns the value of a cell, evaluating its formula if it has one.
+    /// The formula cells it refers to are evaluated first from a work stack,
+    /// so a long chain of references does not overflow the call stack.
+    pub(crate) fn calculate_cell(&mut self, sheet_index: usize, col: u32, row: u32) -> CalcValue {
+        let key = (sheet_index, col, row);
+        if let Some(v) = self.cache.get(&key) {
+            return v.clone();
+        }
+        match self.sheets[sheet_index].get_cell((col, row)) {
+            Some(cell) if cell.is_formula() => {}
+            Some(cell) => return CalcValue::from_raw(cell.get_raw_value()),
+            None => return CalcValue::Empty,
+        }
+        // Circular references evaluate to 0, as Excel does after warning the user.
+        if self.in_progress.contains(&key) {
+            return CalcValue::Number(0f64);
+        }
+
+        // a cell is pushed back with its expression and evaluated once its dependencies are.
+        let mut stack: Vec<((usize, u32, u32), Option<Result<Expression, CellErrorType>>)> =
+            vec![(key, None)];
+        while let Some((cell_key, expression)) = stack.pop() {
+            match expression {
+                Some(expression) => {
+                    let result = self.evaluate_cell(cell_key, &expression);
+                    self.in_progress.remove(&cell_key);
+                    self.cache.insert(cell_key, result);
+                }
+                None => {
+                    if self.cache.contains_key(&cell_key) || self.in_progress.contains(&cell_key) {
+                        continue;
+                    }
+                    let (index, col, row) = cell_key;
+                    let formula = match self.sheets[index].get_cell((col, row)) {
+                        Some(cell) if cell.is_formula() => cell.get_formula().to_string(),
+                        _ => continue,
+                    };
+                    let expression = parse_formula(&formula);
+                    let mut dependencies = Vec::new();
+                    if let Ok(v) = &expression {
+                        self.collect_dependencies(v, index, &mut dependencies);
+                    }
+                    self.in_progress.insert(cell_key);
+                    stack.push((cell_key, Some(expression)));
+                    // reversed, so that they are evaluated from left to right.
+                    stack.extend(dependencies.into_iter().rev().map(|v| (v, None)));
+                }
+            }
+        }
+        self.cache[&key].clone()
+    }
+
+    fn evaluate_cell(
+        &mut self,
+        key: (usize, u32, u32),
+        expression: &Result<Expression, CellErrorType>,
+    ) -> CalcValue {
+        let (sheet_index, col, row) = key;
+        let position = std::mem::replace(&mut self.position, (col, row));
+        let result = match expression {
+            Ok(v) => self.evaluate(v, sheet_index).into_scalar(),
+            Err(e) => CalcValue::Error(e.clone()),
+        };
+        self.position = position;
+        match result {
+            CalcValue::Empty => CalcValue::Number(0f64),
+            v => v,
+        }
+    }
+
+    /// Formula cells referred to by an expression that are not evaluated yet.
+    /// References made at evaluation time (names, `INDIRECT`, `OFFSET`) are left out.
+    fn collect_dependencies(
+        &self,
+        expression: &Expression,
+        sheet_index: usize,
+        list: &mut Vec<(usize, u32, u32)>,
+    ) {
+        match expression {
+            Expression::Value(_) => (),
+            Expression::Reference(v) => {
+                let range = match self.resolve_reference(v, sheet_index) {
+                    Ok(v) => self.clamp_range(&v),
+                    Err(_) => return,
+                };
+                let sheet = self.sheets[range.sheet_index];
+                for row in range.row_start..=range.row_end {
+                    for col in range.col_start..=range.col_end {
+                        let key = (range.sheet_index, col, row);
+                        if sheet.get_cell((col, row)).is_some_and(|v| v.is_formula())
+                            && !self.cache.contains_key(&key)
+                            && !self.in_progress.contains(&key)
+                        {
+                            list.push(key);
+                        }
+                    }
+                }
+            }
+            Expression::Array(rows) => {
+                for v in rows.iter().flatten() {
+                    self.collect_dependencies(v, sheet_index, list);
+                }
+            }
+            Expression::Negate(v) | Expression::Percent(v) => {
+                self.collect_dependencies(v, sheet_index, list);
+            }
+            Expression::Binary(_, lhs, rhs) => {
+                self.collect_dependencies(lhs, sheet_index, list);
+                self.collect_dependencies(rhs, sheet_index, list);
+            }
+            Expression::Function(_, args) => {
+                for v in args {
+                    self.collect_dependencies(v, sheet_index, list);
+                }
+            }
+        }
+    }
+
+    pub(crate) fn evaluate_formula(&mut self, sheet_index: usize, formula: &str) -> CalcValue {
+        match parse_formula(formula) {
+            Ok(expression) => self.evaluate(&expression, sheet_index),
+            Err(e) => CalcValue::Error(e),
+        }
+    }
+
+    pub(crate) fn evaluate(&mut self, expression: &Expression, sheet_index: usize) -> CalcValue {
+        match expression {
+            Expression::Value(v) => v.clone(),
+            Expression::Reference(v) => match self.resolve_reference(v, sheet_index) {
+                Ok(range) => self.get_range_value(&range),
+                Err(CellErrorType::Name) => self.evaluate_name(v, sheet_index),
+                Err(e) => CalcValue::Error(e),
+            },
+            Expression::Array(rows) => CalcValue::Array(
+                rows.iter()
+                    .map(|row| {
+                        row.iter()
+                            .map(|v| self.evaluate(v, sheet_index).into_scalar())
+                            .collect()
+                    })
+                    .collect(),
+            ),
+            Expression::Negate(v) => {
+                let value = self.evaluate(v, sheet_index);
+                map_array(value, &|v| match v.to_number() {
+                    Ok(n) => CalcValue::Number(-n),
+                    Err(e) => CalcValue::Error(e),
+                })
+            }
+            Expression::Percent(v) => {
+                let value = self.evaluate(v, sheet_index);
+                map_array(value, &|v| match v.to_number() {
+                    Ok(n) => CalcValue::Number(n / 100f64),
+                    Err(e) => CalcValue::Error(e),
+                })
+            }
+            Expression::Binary(operator, lhs, rhs) => {
+                if operator == " " {
+                    return self.evaluate_intersection(lhs, rhs, sheet_index);
+                }
+                let left = self.evaluate(lhs, sheet_index);
+                let right = self.evaluate(rhs, sheet_index);
+                zip_array(left, right, &|l, r| binary_operation(operator, l, r))
+            }
+            Expression::Function(name, args) => self.evaluate_function(name, args, sheet_index),
+        }
+    }
+
+    fn evaluate_intersection(
+        &mut self,
+        lhs: &Expression,
+        rhs: &Expression,
+        sheet_index: usize,
+    ) -> CalcValue {
+        let left = match self.expression_to_range(lhs, sheet_index) {
+            Ok(v) => v,
+            Err(e) => return CalcValue::Error(e),
+        };
+        let right = match self.expression_to_range(rhs, sheet_index) {
+            Ok(v) => v,
+            Err(e) => return CalcValue::Error(e),
+        };
+        let range = RangeReference {
+            sheet_index: left.sheet_index,
+            col_start: left.col_start.max(right.col_start),
+            row_start: left.row_start.max(right.row_start),
+            col_end: left.col_end.min(right.col_end),
+            row_end: left.row_end.min(right.row_end),
+        };
+        if left.sheet_index != right.sheet_index
+            || range.col_start > range.col_end
+            || range.row_start > range.row_end
+        {
+            return CalcValue::Error(CellErrorType::Null);
+        }
+        self.get_range_value(&range)
+    }
+
+    fn evaluate_name(&mut self, name: &str, sheet_index: usize) -> CalcValue {
+        let upper_name = name.to_uppercase();
+        let defined_name = self
+            .defined_names
+            .iter()
+            .filter(|(local, v)| {
+                v.get_name().to_uppercase() == upper_name
+                    && (local.is_none() || local == &Some(sheet_index))
+            })
+            .max_by_key(|(local, _)| local.is_some())
+            .map(|(_, v)| *v);
+        let defined_name = match defined_name {
+            Some(v) => v,
+            None => return CalcValue::Error(CellErrorType::Name),
+        };
+        if self.name_depth >= MAX_NAME_DEPTH {
+            return CalcValue::Error(CellErrorType::Name);
+        }
+        self.name_depth += 1;
+        let result = self.evaluate_formula(sheet_index, &defined_name.get_address());
+        self.name_depth -= 1;
+        result
+    }
+
+    /// Column and row of the cell whose formula is being evaluated.
+    #[inline]
+    pub(crate) fn get_position(&self) -> (u32, u32) {
+        self.position
+    }
+
+    /// Resolve an expression that must be a reference (e.g. the argument of `ROW`).
+    pub(crate) fn expression_to_range(
+        &mut self,
+        expression: &Expression,
+        sheet_index: usize,
+    ) -> Result<RangeReference, CellErrorType> {
+        match expression {
+            Expression::Reference(v) => match self.resolve_reference(v, sheet_index) {
+                Err(CellErrorType::Name) => {
+                    let upper_name = v.to_uppercase();
+                    let address = self
+                        .defined_names
+                        .iter()
+                        .find(|(local, d)| {
+                            d.get_name().to_uppercase() == upper_name
+                                && (local.is_none() || local == &Some(sheet_index))
+                        })
+                        .map(|(_, d)| d.get_address())
+                        .ok_or(CellErrorType::Name)?;
+                    self.resolve_reference(&address, sheet_index)
+                }
+                v => v,
+            },
+            Expression::Function(name, args) if name == "INDIRECT" => {
+                let text = match args.first() {
+                    Some(v) => self.evaluate(v, sheet_index).to_text()?,
+                    None => return Err(CellErrorType::Value),
+                };
+                self.resolve_reference(&text, sheet_index)
+                    .map_err(|_| CellErrorType::Ref)
+            }
+            Expression::Function(name, args) if name == "OFFSET" => {
+                lookup_function::offset_range(self, args, sheet_index)
+            }
+            _ => Err(CellErrorType::Value),
+        }
+    }
+
+    /// Resolve reference text such as `A1`, `$A$1:B2`, `A:A`, `1:3` or `'Sheet 1'!A1`.
+    /// Returns `#NAME?` when the text is not a reference, so it can be tried as a defined name.
+    pub(crate) fn resolve_reference(
+        &self,
+        reference: &str,
+        sheet_index: usize,
+    ) -> Result<RangeReference, CellErrorType> {
+        let (sheet_name, range) = split_address(reference);
+        let sheet_name = sheet_name.replace("''", "'");
+        let target_index = if sheet_name.is_empty() {
+            sheet_index
+        } else {
+            match self
+                .sheets
+                .iter()
+                .position(|v| v.get_name().to_uppercase() == sheet_name.to_uppercase())
+            {
+                Some(v) => v,
+                None => return Err(CellErrorType::Ref),
+            }
+        };
+        let upper_range = range.to_uppercase();
+        let parts: Vec<&str> = upper_range.split(':').collect();
+        if parts.len() > 2 || parts.iter().any(|v| !is_reference_part(v)) {
+            return Err(CellErrorType::Name);
+        }
+        let start = index_from_coordinate(parts[0]);
+        let end = index_from_coordinate(parts.get(1).unwrap_or(&parts[0]));
+        let (max_col, max_row) = self.used_areas[target_index];
+        let result = match (start.0, start.1, end.0, end.1) {
+            (Some(c1), Some(r1), Some(c2), Some(r2)) => (c1, r1, c2, r2),
+            (Some(c1), None, Some(c2), None) if parts.len() == 2 => (c1, 1, c2, max_row.max(1)),
+            (None, Some(r1), None, Some(r2)) if parts.len() == 2 => (1, r1, max_col.max(1), r2),
+            _ => return Err(CellErrorType::Name),
+        };
+        if result.0 == 0 || result.1 == 0 {
+            return Err(CellErrorType::Ref);
+        }
+        Ok(RangeReference {
+            sheet_index: target_index,
+            col_start: result.0.min(result.2),
+            row_start: result.1.min(result.3),
+            col_end: result.0.max(result.2),
+            row_end: result.1.max(result.3),
+        })
+    }
+
+    /// The part of a range inside the used area of its sheet, keeping at least its first cell.
+    fn clamp_range(&self, range: &RangeReference) -> RangeReference {
+        let (max_col, max_row) = self.used_areas[range.sheet_index];
+        RangeReference {
+            col_end: range.col_end.min(max_col.max(range.col_start)),
+            row_end: range.row_end.min(max_row.max(range.row_start)),
+            ..range.clone()
+        }
+    }
+
+    /// Values of a range; a single cell yields a scalar.
+    /// Cells past the used area of the sheet are left out, as they are empty.
+    pub(crate) fn get_range_value(&mut self, range: &RangeReference) -> CalcValue {
+        if range.col_start == range.col_end && range.row_start == range.row_end {
+            return self.calculate_cell(range.sheet_index, range.col_start, range.row_start);
+        }
+        let range = self.clamp_range(range);
+        let mut rows = Vec::new();
+        for row in range.row_start..=range.row_end {
+            let mut values = Vec::new();
+            for col in range.col_start..=range.col_end {
+                values.push(self.calculate_cell(range.sheet_index, col, row));
+            }
+            rows.push(values);
+        }
+        CalcValue::Array(rows)
+    }
+
+    fn evaluate_function(
+        &mut self,
+        name: &str,
+        args: &[Expression],
+        sheet_index: usize,
+    ) -> CalcValue {
+        // Functions that need unevaluated arguments.
+        if let Some(v) = logical_function::evaluate_lazy(self, name, args, sheet_index) {
+            return v;
+        }
+        if let Some(v) = lookup_function::evaluate_reference(self, name, args, sheet_index) {
+            return v;
+        }
+
+        // References are passed as arrays, even for a single cell, so that functions
+        // can apply the rules Excel uses for values that come from cells.
+        let values: Vec<CalcValue> = args
+            .iter()
+            .map(|v| match (v, self.evaluate(v, sheet_index)) {
+                (Expression::Reference(_), CalcValue::Error(e)) => CalcValue::Error(e),
+                (Expression::Reference(_), CalcValue::Array(a)) => CalcValue::Array(a),
+                (Expression::Reference(_), value) => CalcValue::Array(vec![vec![value]]),
+                (_, value) => value,
+            })
+            .collect();
+        if let Some(v) = math_function::evaluate(name, &values) {
+            return v;
+        }
+        if let Some(v) = logical_function::evaluate(name, &values) {
+            return v;
+        }
+        if let Some(v) = lookup_function::evaluate(name, &values) {
+            return v;
+        }
+        let date1904 = self.sheets[sheet_index].get_date1904();
+        if let Some(v) = text_function::evaluate(name, &values, date1904) {
+            return v;
+        }
+        if let Some(v) = date_function::evaluate(name, &values, date1904) {
+            return v;
+        }
+        CalcValue::Error(CellErrorType::Name)
+    }
+}
+
+fn is_reference_part(value: &str) -> bool {
+    lazy_static! {
+        static ref RE: fancy_regex::Regex =
+            fancy_regex::Regex::new(r"^(\$?[A-Z]{1,3})?(\$?[0-9]+)?$").unwrap();
+    }
+    !value.is_empty() && RE.is_match(value).unwrap_or(false)
+}
+
+/// Apply `f` to every element of an array, or to a scalar.
+pub(crate) fn map_array(value: CalcValue, f: &dyn Fn(&CalcValue) -> CalcValue) -> CalcValue {
+    match value {
+        CalcValue::Array(rows) => {
+            CalcValue::Array(rows.iter().map(|row| row.iter().map(f).collect()).collect())
+        }
+        v => f(&v),
+    }
+}
+
+/// Apply `f` element-wise to two values, broadcasting scalars over arrays.
+pub(crate) fn zip_array(
+    left: CalcValue,
+    right: CalcValue,
+    f: &dyn Fn(&CalcValue, &CalcValue) -> CalcValue,
+) -> CalcValue {
+    match (&left, &right) {
+        (CalcValue::Array(_), _) | (_, CalcValue::Array(_)) => {
+            let l = left.rows();
+            let r = right.rows();
+            let height = l.len().max(r.len());
+            let width = l
+                .iter()
+                .chain(r.iter())
+                .map(|row| row.len())
+                .max()
+                .unwrap_or(0);
+            let pick = |rows: &Vec<Vec<CalcValue>>, row: usize, col: usize| -> CalcValue {
+                let row_index = if rows.len() == 1 { 0 } else { row };
+                match rows.get(row_index) {
+                    Some(v) => {
+                        let col_index = if v.len() == 1 { 0 } else { col };
+                        v.get(col_index)
+                            .cloned()
+                            .unwrap_or(CalcValue::Error(CellErrorType::NA))
+                    }
+                    None => CalcValue::Error(CellErrorType::NA),
+                }
+            };
+            CalcValue::Array(
+                (0..height)
+                    .map(|row| {
+                        (0..width)
+                            .map(|col| f(&pick(&l, row, col), &pick(&r, row, col)))
+                            .collect()
+                    })
+                    .collect(),
+            )
+        }
+        _ => f(&left, &right),
+    }
+}
+
+fn binary_operation(operator: &str, left: &CalcValue, right: &CalcValue) -> CalcValue {
+    if let CalcValue::Error(e) = left {
+        return CalcValue::Error(e.clone());
+    }
+    if let CalcValue::Error(e) = right {
+        return CalcValue::Error(e.clone());
+    }
+    match operator {
+        "&" => match (left.to_text(), right.to_text()) {
+            (Ok(l), Ok(r)) => CalcValue::Text(format!("{}{}", l, r)),
+            (Err(e), _) | (_, Err(e)) => CalcValue::Error(e),
+        },
+        "=" | "<>" | "<" | ">" | "<=" | ">=" => {
+            let ordering = compare_values(left, right);
+            CalcValue::Bool(match operator {
+                "=" => ordering == Ordering::Equal,
+                "<>" => ordering != Ordering::Equal,
+                "<" => ordering == Ordering::Less,
+                ">" => ordering == Ordering::Greater,
+                "<=" => ordering != Ordering::Greater,
+                _ => ordering != Ordering::Less,
+            })
+        }
+        _ => {
+            let (l, r) = match (left.to_number(), right.to_number()) {
+                (Ok(l), Ok(r)) => (l, r),
+                (Err(e), _) | (_, Err(e)) => return CalcValue::Error(e),
+            };
+            let result = match operator {
+                "+" => l + r,
+                "-" => l - r,
+                "*" => l * r,
+                "/" => {
+                    if r == 0f64 {
+                        return CalcValue::Error(CellErrorType::Div0);
+                    }
+                    l / r
+                }
+                "^" => {
+                    if l == 0f64 && r == 0f64 {
+                        return CalcValue::Error(CellErrorType::Num);
+                    }
+                    l.powf(r)
+                }
+                _ => return CalcValue::Error(CellErrorType::Value),
+            };
+            number_result(result)
+        }
+    }
+}
+
+/// Wrap a number, turning NaN and infinities into `#NUM!`.
+#[inline]
+pub(crate) fn number_result(value: f64) -> CalcValue {
+    if value.is_finite() {
+        CalcValue::Number(value)
+    } else {
+        CalcValue::Error(CellErrorType::Num)
+    }
+}
+
+/// Match text against an Excel wildcard pattern (`*`, `?`, `~` escape), case-insensitively.
+pub(crate) fn wildcard_match(pattern: &str, text: &str) -> bool {
+    let pattern: Vec<char> = pattern.to_lowercase().chars().collect();
+    let text: Vec<char> = text.to_lowercase().chars().collect();
+
+    fn matches(pattern: &[char], text: &[char]) -> bool {
+        match pattern.first() {
+            None => text.is_empty(),
+            Some('*') => (0..=text.len()).any(|i| matches(&pattern[1..], &text[i..])),
+            Some('?') => !text.is_empty() && matches(&pattern[1..], &text[1..]),
+            Some('~') if pattern.len() > 1 => {
+                !text.is_empty() && text[0] == pattern[1] && matches(&pattern[2..], &text[1..])
+            }
+            Some(c) => !text.is_empty() && text[0] == *c && matches(&pattern[1..], &text[1..]),
+        }
+    }
+    matches(&pattern, &text)
+}
+
+/// Test a value against a `COUNTIF`-style criteria such as `">=10"`, `"<>"`, `"a*"` or `5`.
+pub(crate) fn match_criteria(value: &CalcValue, criteria: &CalcValue) -> bool {
+    let criteria_text = match criteria {
+        CalcValue::Empty => return matches!(value, CalcValue::Empty),
+        v => match v.to_text() {
+            Ok(t) => t,
+            Err(_) => return false,
+        },
+    };
+    let (operator, operand) = ["<>", ">=", "<=", "=", ">", "<"]
+        .iter()
+        .find_map(|op| criteria_text.strip_prefix(op).map(|rest| (*op, rest)))
+        .unwrap_or(("", criteria_text.as_str()));
+
+    if operand.is_empty() {
+        let is_blank = matches!(value, CalcValue::Empty)
+            || matches!(value, CalcValue::Text(v) if v.is_empty());
+        return match operator {
+            "<>" => !is_blank,
+            "=" | "" => is_blank,
+            _ => false,
+        };
+    }
+
+    let target = if let Some(n) = parse_number(operand) {
+        CalcValue::Number(n)
+    } else {
+        match operand.to_uppercase().as_str() {
+            "TRUE" => CalcValue::Bool(true),
+            "FALSE" => CalcValue::Bool(false),
+            _ => CalcValue::Text(operand.to_string()),
+        }
+    };
+
+    let value = match (value, &target) {
+        (CalcValue::Text(v), CalcValue::Number(_)) => match parse_number(v) {
+            Some(n) => CalcValue::Number(n),
+            None => value.clone(),
+        },
+        _ => value.clone(),
+    };
+    let same_type = std::mem::discriminant(&value) == std::mem::discriminant(&target);
+
+    match operator {
+        "" | "=" => match (&value, &target) {
+            (CalcValue::Text(v), CalcValue::Text(t)) => wildcard_match(t, v),
+            _ => same_type && compare_values(&value, &target) == Ordering::Equal,
+        },
+        "<>" => match (&value, &target) {
+            (CalcValue::Text(v), CalcValue::Text(t)) => !wildcard_match(t, v),
+            _ => !(same_type && compare_values(&value, &target) == Ordering::Equal),
+        },
+        _ => {
+            if !same_type {
+                return false;
+            }
+            let ordering = compare_values(&value, &target);
+            match operator {
+                ">" => ordering == Ordering::Greater,
+                "<" => ordering == Ordering::Less,
+                ">=" => ordering != Ordering::Less,
+                _ => ordering != Ordering::Greater,
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::structs::Spreadsheet;
+
+    fn calc(book: &Spreadsheet, formula: &str) -> CalcValue {
+        let sheets: Vec<&Worksheet> = book.get_sheet_collection().iter().collect();
+        let mut calculator = Calculator::new(sheets, book.get_defined_names());
+        calculator.evaluate_formula(0, formula).into_scalar()
+    }
+
+    #[test]
+    fn operators() {
+        let book = crate::new_file();
+        assert_eq!(calc(&book, "=1+2*3"), CalcValue::Number(7f64));
+        assert_eq!(calc(&book, "=(1+2)*3"), CalcValue::Number(9f64));
+        assert_eq!(calc(&book, "=-2^2"), CalcValue::Number(4f64));
+        assert_eq!(calc(&book, "=2^3^2"), CalcValue::Number(64f64));
+        assert_eq!(calc(&book, "=50%"), CalcValue::Number(0.5f64));
+        assert_eq!(calc(&book, "=1/0"), CalcValue::Error(CellErrorType::Div0));
+        assert_eq!(
+            calc(&book, "=\"a\"&1.5&TRUE"),
+            CalcValue::Text("a1.5TRUE".into())
+        );
+        assert_eq!(calc(&book, "=\"abc\"=\"ABC\""), CalcValue::Bool(true));
+        assert_eq!(calc(&book, "=1<\"a\""), CalcValue::Bool(true));
+        assert_eq!(
+            calc(&book, "=\"x\"+1"),
+            CalcValue::Error(CellErrorType::Value)
+        );
+        assert_eq!(
+            calc(&book, "=NOSUCHFUNCTION(1)"),
+            CalcValue::Error(CellErrorType::Name)
+        );
+        assert_eq!(calc(&book, "=SUM({1,2;3,4})"), CalcValue::Number(10f64));
+    }
+
+    #[test]
+    fn number_text() {
+        assert_eq!(number_to_string(1f64), "1");
+        assert_eq!(number_to_string(-2.5f64), "-2.5");
+        assert_eq!(number_to_string(1f64 / 3f64), "0.333333333333333");
+        assert_eq!(number_to_string(0.1f64 + 0.2f64), "0.3");
+        assert_eq!(number_to_string(1e20f64), "1E+20");
+    }
+
+    #[test]
+    fn criteria() {
+        assert!(match_criteria(
+            &CalcValue::Number(5f64),
+            &CalcValue::Text(">=5".into())
+        ));
+        assert!(!match_criteria(
+            &CalcValue::Number(4f64),
+            &CalcValue::Text(">=5".into())
+        ));
+        assert!(match_criteria(
+            &CalcValue::Text("Apple".into()),
+            &CalcValue::Text("a*".into())
+        ));
+        assert!(match_criteria(
+            &CalcValue::Text("5".into()),
+            &CalcValue::Number(5f64)
+        ));
+        assert!(match_criteria(
+            &CalcValue::Empty,
+            &CalcValue::Text("".into())
+        ));
+        assert!(match_criteria(
+            &CalcValue::Text("x".into()),
+            &CalcValue::Text("<>".into())
+        ));
+    }
+
+    #[test]
+    fn reference_chain() {
+        let mut book = crate::new_file();
+        let sheet = book.get_sheet_mut(&0).unwrap();
+        sheet.get_cell_mut("A1").set_value_number(1);
+        for row in 2..=100000u32 {
+            sheet
+                .get_cell_mut((1, row))
+                .set_formula(format!("A{}+1", row - 1));
+        }
+        sheet.get_cell_mut("B1").set_formula("B2+1");
+        sheet.get_cell_mut("B2").set_formula("B1+1");
+
+        let sheets: Vec<&Worksheet> = book.get_sheet_collection().iter().collect();
+        let mut calculator = Calculator::new(sheets, book.get_defined_names());
+        assert_eq!(
+            calculator.calculate_cell(0, 1, 100000),
+            CalcValue::Number(100000f64)
+        );
+        // circular references still evaluate to 0 where the loop closes.
+        assert_eq!(calculator.calculate_cell(0, 2, 1), CalcValue::Number(2f64));
+        assert_eq!(calculator.calculate_cell(0, 2, 2), CalcValue::Number(1f64));
+    }
+
+    #[test]
+    fn range_past_used_area() {
+        let mut book = crate::new_file();
+        let sheet = book.get_sheet_mut(&0).unwrap();
+        sheet.get_cell_mut("A1").set_value_number(1);
+        sheet.get_cell_mut("B2").set_value_number(2);
+        assert_eq!(calc(&book, "=SUM(A1:XFD1048576)"), CalcValue::Number(3f64));
+        assert_eq!(
+            calc(&book, "=COUNTA(C3:XFD1048576)"),
+            CalcValue::Number(0f64)
+        );
+        assert_eq!(
+            calc(&book, "=ROWS(A1:XFD1048576)"),
+            CalcValue::Number(1048576f64)
+        );
+    }
+}
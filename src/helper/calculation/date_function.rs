@@ -0,0 +1,353 @@
+use super::*;
+use chrono::{Datelike, Duration, NaiveDate, NaiveDateTime, Timelike};
+
+const SECONDS_PER_DAY: f64 = 86400f64;
+
+/// Serial number of 1904-01-01, the first day of the 1904 date system, in the 1900 date system.
+const DATE1904_OFFSET: f64 = 1462f64;
+
+#[inline]
+fn base_date() -> NaiveDate {
+    NaiveDate::from_ymd_opt(1899, 12, 30).unwrap()
+}
+
+/// Serial number of a date in the 1900 date system, including Excel's fictitious 1900-02-29.
+pub(crate) fn date_to_serial(date: &NaiveDate) -> f64 {
+    let days = (*date - base_date()).num_days();
+    // dates before 1900-03-01 are shifted by the non-existent leap day
+    if days < 61 {
+        (days - 1) as f64
+    } else {
+        days as f64
+    }
+}
+
+/// `(year, month, day)` of a serial number in the 1900 date system.
+pub(crate) fn serial_to_ymd(serial: f64) -> Option<(i32, u32, u32)> {
+    let days = serial.floor() as i64;
+    match days {
+        d if d < 0 => None,
+        0 => Some((1900, 1, 0)),
+        60 => Some((1900, 2, 29)),
+        d => {
+            let date = if d < 60 {
+                base_date() + Duration::days(d + 1)
+            } else {
+                base_date() + Duration::days(d)
+            };
+            if date.year() > 9999 {
+                return None;
+            }
+            Some((date.year(), date.month(), date.day()))
+        }
+    }
+}
+
+fn serial_to_date(serial: f64) -> Option<NaiveDate> {
+    let (year, month, day) = serial_to_ymd(serial)?;
+    NaiveDate::from_ymd_opt(year, month, day.max(1))
+}
+
+/// Build a date from possibly out-of-range parts, the way `DATE` normalizes them.
+fn normalized_date(year: i64, month: i64, day: i64) -> Option<NaiveDate> {
+    let year = if (0..1900).contains(&year) {
+        year + 1900
+    } else {
+        year
+    };
+    let months = year * 12 + month - 1;
+    let first = NaiveDate::from_ymd_opt(
+        months.div_euclid(12) as i32,
+        (months.rem_euclid(12) + 1) as u32,
+        1,
+    )?;
+    first.checked_add_signed(Duration::days(day - 1))
+}
+
+// Dates are computed in the 1900 date system, `offset` converts the serials of the workbook.
+fn get_offset(date1904: bool) -> f64 {
+    if date1904 {
+        DATE1904_OFFSET
+    } else {
+        0f64
+    }
+}
+
+fn date_result(date: Option<NaiveDate>, offset: f64) -> CalcValue {
+    match date.map(|v| (v, date_to_serial(&v) - offset)) {
+        Some((v, serial)) if serial >= 0f64 && v.year() >= 1900 && v.year() <= 9999 => {
+            CalcValue::Number(serial)
+        }
+        _ => CalcValue::Error(CellErrorType::Num),
+    }
+}
+
+fn number_arg(values: &[CalcValue], index: usize) -> Result<f64, CellErrorType> {
+    match values.get(index) {
+        Some(CalcValue::Text(v)) => match parse_number(v) {
+            Some(n) => Ok(n),
+            None => parse_date_text(v).ok_or(CellErrorType::Value),
+        },
+        Some(v) => v.to_number(),
+        None => Err(CellErrorType::Value),
+    }
+}
+
+/// A date argument as a serial number in the 1900 date system.
+fn serial_arg(values: &[CalcValue], index: usize, offset: f64) -> Result<f64, CellErrorType> {
+    let serial = match values.get(index) {
+        Some(CalcValue::Text(v)) => match parse_number(v) {
+            Some(n) => n,
+            None => return parse_date_text(v).ok_or(CellErrorType::Value),
+        },
+        Some(v) => v.to_number()?,
+        None => return Err(CellErrorType::Value),
+    };
+    if serial < 0f64 {
+        return Err(CellErrorType::Num);
+    }
+    Ok(serial + offset)
+}
+
+/// Parse ISO-like date and date-time text (`2024-01-31`, `2024/01/31 12:30:00`).
+pub(crate) fn parse_date_text(value: &str) -> Option<f64> {
+    let value = value.trim();
+    for format in [
+        "%Y-%m-%d %H:%M:%S",
+        "%Y/%m/%d %H:%M:%S",
+        "%Y-%m-%dT%H:%M:%S",
+    ] {
+        if let Ok(v) = NaiveDateTime::parse_from_str(value, format) {
+            return Some(date_time_to_serial(&v));
+        }
+    }
+    for format in ["%Y-%m-%d", "%Y/%m/%d", "%m/%d/%Y"] {
+        if let Ok(v) = NaiveDate::parse_from_str(value, format) {
+            return Some(date_to_serial(&v));
+        }
+    }
+    None
+}
+
+fn date_time_to_serial(value: &NaiveDateTime) -> f64 {
+    let seconds = value.time().num_seconds_from_midnight() as f64;
+    date_to_serial(&value.date()) + seconds / SECONDS_PER_DAY
+}
+
+/// Seconds since midnight of the time part of a serial, rounded to the nearest second.
+fn serial_seconds(serial: f64) -> u32 {
+    let seconds = ((serial - serial.floor()) * SECONDS_PER_DAY).round() as u32;
+    seconds % 86400
+}
+
+fn date_part(values: &[CalcValue], offset: f64, f: &dyn Fn((i32, u32, u32)) -> u32) -> CalcValue {
+    match serial_arg(values, 0, offset) {
+        Ok(v) => match serial_to_ymd(v) {
+            Some(ymd) => CalcValue::Number(f(ymd) as f64),
+            None => CalcValue::Error(CellErrorType::Num),
+        },
+        Err(e) => CalcValue::Error(e),
+    }
+}
+
+fn time_part(values: &[CalcValue], f: &dyn Fn(u32) -> u32) -> CalcValue {
+    match number_arg(values, 0) {
+        Ok(v) if v < 0f64 => CalcValue::Error(CellErrorType::Num),
+        Ok(v) => CalcValue::Number(f(serial_seconds(v)) as f64),
+        Err(e) => CalcValue::Error(e),
+    }
+}
+
+fn add_months(
+    values: &[CalcValue],
+    end_of_month: bool,
+    offset: f64,
+) -> Result<CalcValue, CellErrorType> {
+    // EDATE(start_date, months) / EOMONTH(start_date, months)
+    let start = serial_arg(values, 0, offset)?;
+    let months = number_arg(values, 1)?.trunc() as i64;
+    let (year, month, day) = serial_to_ymd(start).ok_or(CellErrorType::Num)?;
+    let date = if end_of_month {
+        normalized_date(year as i64, month as i64 + months + 1, 0)
+    } else {
+        let first =
+            normalized_date(year as i64, month as i64 + months, 1).ok_or(CellErrorType::Num)?;
+        let last = normalized_date(first.year() as i64, first.month() as i64 + 1, 0)
+            .ok_or(CellErrorType::Num)?;
+        NaiveDate::from_ymd_opt(first.year(), first.month(), day.max(1).min(last.day()))
+    };
+    Ok(date_result(date, offset))
+}
+
+fn weekday(values: &[CalcValue], offset: f64) -> Result<CalcValue, CellErrorType> {
+    // WEEKDAY(serial_number, [return_type])
+    let serial = serial_arg(values, 0, offset)?;
+    let return_type = match values.get(1) {
+        Some(CalcValue::Empty) | None => 1,
+        Some(v) => v.to_number()?.trunc() as i64,
+    };
+    // serial 1 (1900-01-01) is a Sunday in Excel's calendar
+    let sunday_based = (serial.floor() as i64 + 6).rem_euclid(7);
+    let result = match return_type {
+        1 | 17 => sunday_based + 1,
+        2 | 11 => (sunday_based + 6).rem_euclid(7) + 1,
+        3 => (sunday_based + 6).rem_euclid(7),
+        12..=16 => (sunday_based - (return_type - 10)).rem_euclid(7) + 1,
+        _ => return Err(CellErrorType::Num),
+    };
+    Ok(CalcValue::Number(result as f64))
+}
+
+fn datedif(values: &[CalcValue], offset: f64) -> Result<CalcValue, CellErrorType> {
+    // DATEDIF(start_date, end_date, unit)
+    let start = serial_arg(values, 0, offset)?.floor();
+    let end = serial_arg(values, 1, offset)?.floor();
+    let unit = values
+        .get(2)
+        .ok_or(CellErrorType::Value)?
+        .to_text()?
+        .to_uppercase();
+    if start > end {
+        return Err(CellErrorType::Num);
+    }
+    let (sy, sm, sd) = serial_to_ymd(start).ok_or(CellErrorType::Num)?;
+    let (ey, em, ed) = serial_to_ymd(end).ok_or(CellErrorType::Num)?;
+    let mut months = (ey - sy) as i64 * 12 + em as i64 - sm as i64;
+    if ed < sd {
+        months -= 1;
+    }
+    let result = match unit.as_str() {
+        "D" => end - start,
+        "M" => months as f64,
+        "Y" => (months / 12) as f64,
+        "YM" => (months % 12) as f64,
+        "MD" => {
+            if ed >= sd {
+                (ed - sd) as f64
+            } else {
+                let previous =
+                    normalized_date(ey as i64, em as i64, 0).ok_or(CellErrorType::Num)?;
+                (previous.day() as i64 - sd as i64 + ed as i64).max(0) as f64
+            }
+        }
+        "YD" => {
+            let anniversary = normalized_date(ey as i64, sm as i64, sd as i64)
+                .map(|v| date_to_serial(&v))
+                .ok_or(CellErrorType::Num)?;
+            if anniversary <= end {
+                end - anniversary
+            } else {
+                let previous = normalized_date(ey as i64 - 1, sm as i64, sd as i64)
+                    .map(|v| date_to_serial(&v))
+                    .ok_or(CellErrorType::Num)?;
+                end - previous
+            }
+        }
+        _ => return Err(CellErrorType::Num),
+    };
+    Ok(CalcValue::Number(result))
+}
+
+fn evaluate_date(
+    name: &str,
+    values: &[CalcValue],
+    date1904: bool,
+) -> Result<CalcValue, CellErrorType> {
+    let offset = get_offset(date1904);
+    let result = match name {
+        "DATE" => {
+            let year = number_arg(values, 0)?.trunc() as i64;
+            let month = number_arg(values, 1)?.trunc() as i64;
+            let day = number_arg(values, 2)?.trunc() as i64;
+            if !(0..=9999).contains(&year) {
+                return Err(CellErrorType::Num);
+            }
+            date_result(normalized_date(year, month, day), offset)
+        }
+        "TIME" => {
+            let hour = number_arg(values, 0)?.trunc();
+            let minute = number_arg(values, 1)?.trunc();
+            let second = number_arg(values, 2)?.trunc();
+            let total = hour * 3600f64 + minute * 60f64 + second;
+            if total < 0f64 {
+                return Err(CellErrorType::Num);
+            }
+            CalcValue::Number((total % SECONDS_PER_DAY) / SECONDS_PER_DAY)
+        }
+        "TODAY" => CalcValue::Number(date_to_serial(&chrono::Local::now().date_naive()) - offset),
+        "NOW" => {
+            CalcValue::Number(date_time_to_serial(&chrono::Local::now().naive_local()) - offset)
+        }
+        "YEAR" => date_part(values, offset, &|(y, _, _)| y as u32),
+        "MONTH" => date_part(values, offset, &|(_, m, _)| m),
+        "DAY" => date_part(values, offset, &|(_, _, d)| d),
+        "HOUR" => time_part(values, &|s| s / 3600),
+        "MINUTE" => time_part(values, &|s| s % 3600 / 60),
+        "SECOND" => time_part(values, &|s| s % 60),
+        "WEEKDAY" => weekday(values, offset)?,
+        "EDATE" => add_months(values, false, offset)?,
+        "EOMONTH" => add_months(values, true, offset)?,
+        "DAYS" => CalcValue::Number(
+            serial_arg(values, 0, offset)?.floor() - serial_arg(values, 1, offset)?.floor(),
+        ),
+        "DATEDIF" => datedif(values, offset)?,
+        "DATEVALUE" => {
+            let text = values.first().ok_or(CellErrorType::Value)?.to_text()?;
+            let serial = parse_date_text(&text).ok_or(CellErrorType::Value)?.floor() - offset;
+            if serial < 0f64 {
+                return Err(CellErrorType::Value);
+            }
+            CalcValue::Number(serial)
+        }
+        _ => return Err(CellErrorType::Name),
+    };
+    Ok(result)
+}
+
+pub(crate) fn evaluate(name: &str, values: &[CalcValue], date1904: bool) -> Option<CalcValue> {
+    match evaluate_date(name, values, date1904) {
+        Ok(v) => Some(v),
+        Err(CellErrorType::Name) => None,
+        Err(e) => Some(CalcValue::Error(e)),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn serial() {
+        let date = |y, m, d| NaiveDate::from_ymd_opt(y, m, d).unwrap();
+        assert_eq!(date_to_serial(&date(1900, 1, 1)), 1f64);
+        assert_eq!(date_to_serial(&date(1900, 2, 28)), 59f64);
+        assert_eq!(date_to_serial(&date(1900, 3, 1)), 61f64);
+        assert_eq!(date_to_serial(&date(2024, 1, 31)), 45322f64);
+        assert_eq!(serial_to_ymd(60f64), Some((1900, 2, 29)));
+        assert_eq!(serial_to_ymd(45322.75f64), Some((2024, 1, 31)));
+        assert_eq!(serial_to_ymd(59f64), Some((1900, 2, 28)));
+    }
+
+    #[test]
+    fn date1904() {
+        let number = |v: f64| CalcValue::Number(v);
+        assert_eq!(
+            date_to_serial(&NaiveDate::from_ymd_opt(1904, 1, 1).unwrap()),
+            DATE1904_OFFSET
+        );
+        let values = [number(2024f64), number(1f64), number(31f64)];
+        assert_eq!(evaluate("DATE", &values, true), Some(number(43860f64)));
+        assert_eq!(
+            evaluate("YEAR", &[number(0f64)], true),
+            Some(number(1904f64))
+        );
+        assert_eq!(
+            evaluate("EOMONTH", &[number(43860f64), number(1f64)], true),
+            Some(number(43889f64))
+        );
+        assert_eq!(
+            evaluate("DATEVALUE", &[CalcValue::Text("2024-01-31".into())], true),
+            Some(number(43860f64))
+        );
+    }
+}
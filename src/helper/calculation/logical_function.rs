@@ -0,0 +1,165 @@
+use super::*;
+
+/// Functions that only evaluate the arguments they need.
+pub(crate) fn evaluate_lazy(
+    calculator: &mut Calculator,
+    name: &str,
+    args: &[Expression],
+    sheet_index: usize,
+) -> Option<CalcValue> {
+    let result = match name {
+        "IF" => {
+            if args.is_empty() || args.len() > 3 {
+                return Some(CalcValue::Error(CellErrorType::Value));
+            }
+            let condition = calculator.evaluate(&args[0], sheet_index);
+            match condition.to_bool() {
+                Ok(true) => match args.get(1) {
+                    Some(v) => calculator.evaluate(v, sheet_index),
+                    None => CalcValue::Bool(true),
+                },
+                Ok(false) => match args.get(2) {
+                    Some(v) => calculator.evaluate(v, sheet_index),
+                    None => CalcValue::Bool(false),
+                },
+                Err(e) => CalcValue::Error(e),
+            }
+        }
+        "IFERROR" | "IFNA" => {
+            if args.len() != 2 {
+                return Some(CalcValue::Error(CellErrorType::Value));
+            }
+            let value = calculator.evaluate(&args[0], sheet_index).into_scalar();
+            let replace = match &value {
+                CalcValue::Error(CellErrorType::NA) => true,
+                CalcValue::Error(_) => name == "IFERROR",
+                _ => false,
+            };
+            if replace {
+                calculator.evaluate(&args[1], sheet_index)
+            } else {
+                value
+            }
+        }
+        "IFS" => {
+            if args.is_empty() || args.len() % 2 != 0 {
+                return Some(CalcValue::Error(CellErrorType::Value));
+            }
+            for pair in args.chunks(2) {
+                match calculator.evaluate(&pair[0], sheet_index).to_bool() {
+                    Ok(true) => return Some(calculator.evaluate(&pair[1], sheet_index)),
+                    Ok(false) => {}
+                    Err(e) => return Some(CalcValue::Error(e)),
+                }
+            }
+            CalcValue::Error(CellErrorType::NA)
+        }
+        "SWITCH" => {
+            if args.len() < 3 {
+                return Some(CalcValue::Error(CellErrorType::Value));
+            }
+            let value = calculator.evaluate(&args[0], sheet_index).into_scalar();
+            if let CalcValue::Error(e) = value {
+                return Some(CalcValue::Error(e));
+            }
+            let rest = &args[1..];
+            for pair in rest.chunks(2) {
+                if pair.len() == 1 {
+                    return Some(calculator.evaluate(&pair[0], sheet_index));
+                }
+                let case = calculator.evaluate(&pair[0], sheet_index).into_scalar();
+                if compare_values(&value, &case) == Ordering::Equal {
+                    return Some(calculator.evaluate(&pair[1], sheet_index));
+                }
+            }
+            CalcValue::Error(CellErrorType::NA)
+        }
+        "CHOOSE" => {
+            if args.len() < 2 {
+                return Some(CalcValue::Error(CellErrorType::Value));
+            }
+            match calculator.evaluate(&args[0], sheet_index).to_number() {
+                Ok(v) if v >= 1f64 && (v as usize) < args.len() => {
+                    calculator.evaluate(&args[v as usize], sheet_index)
+                }
+                Ok(_) => CalcValue::Error(CellErrorType::Value),
+                Err(e) => CalcValue::Error(e),
+            }
+        }
+        _ => return None,
+    };
+    Some(result)
+}
+
+fn logical(values: &[CalcValue], f: &dyn Fn(Vec<bool>) -> bool) -> CalcValue {
+    let mut result = Vec::new();
+    for value in values {
+        match value {
+            CalcValue::Array(_) => {
+                for v in value.flatten() {
+                    match v {
+                        CalcValue::Number(n) => result.push(*n != 0f64),
+                        CalcValue::Bool(b) => result.push(*b),
+                        CalcValue::Error(e) => return CalcValue::Error(e.clone()),
+                        _ => {}
+                    }
+                }
+            }
+            CalcValue::Empty => {}
+            v => match v.to_bool() {
+                Ok(b) => result.push(b),
+                Err(e) => return CalcValue::Error(e),
+            },
+        }
+    }
+    if result.is_empty() {
+        return CalcValue::Error(CellErrorType::Value);
+    }
+    CalcValue::Bool(f(result))
+}
+
+fn is_function(values: &[CalcValue], f: &dyn Fn(&CalcValue) -> bool) -> CalcValue {
+    if values.len() != 1 {
+        return CalcValue::Error(CellErrorType::Value);
+    }
+    CalcValue::Bool(f(&values[0].clone().into_scalar()))
+}
+
+pub(crate) fn evaluate(name: &str, values: &[CalcValue]) -> Option<CalcValue> {
+    let result = match name {
+        "AND" => logical(values, &|v| v.iter().all(|b| *b)),
+        "OR" => logical(values, &|v| v.iter().any(|b| *b)),
+        "XOR" => logical(values, &|v| v.iter().filter(|b| **b).count() % 2 == 1),
+        "NOT" => match values {
+            [v] => match v.to_bool() {
+                Ok(b) => CalcValue::Bool(!b),
+                Err(e) => CalcValue::Error(e),
+            },
+            _ => CalcValue::Error(CellErrorType::Value),
+        },
+        "TRUE" => CalcValue::Bool(true),
+        "FALSE" => CalcValue::Bool(false),
+        "NA" => CalcValue::Error(CellErrorType::NA),
+        "ISBLANK" => is_function(values, &|v| matches!(v, CalcValue::Empty)),
+        "ISNUMBER" => is_function(values, &|v| matches!(v, CalcValue::Number(_))),
+        "ISTEXT" => is_function(values, &|v| matches!(v, CalcValue::Text(_))),
+        "ISNONTEXT" => is_function(values, &|v| !matches!(v, CalcValue::Text(_))),
+        "ISLOGICAL" => is_function(values, &|v| matches!(v, CalcValue::Bool(_))),
+        "ISERROR" => is_function(values, &|v| v.is_error()),
+        "ISNA" => is_function(values, &|v| {
+            matches!(v, CalcValue::Error(CellErrorType::NA))
+        }),
+        "ISERR" => is_function(values, &|v| {
+            v.is_error() && !matches!(v, CalcValue::Error(CellErrorType::NA))
+        }),
+        "ISEVEN" | "ISODD" => match values {
+            [v] => match v.to_number() {
+                Ok(n) => CalcValue::Bool((n.trunc() as i64 % 2 == 0) == (name == "ISEVEN")),
+                Err(e) => CalcValue::Error(e),
+            },
+            _ => CalcValue::Error(CellErrorType::Value),
+        },
+        _ => return None,
+    };
+    Some(result)
+}
@@ -0,0 +1,331 @@
+use super::*;
+
+/// `OFFSET(reference, rows, cols, [height], [width])` as a range.
+pub(crate) fn offset_range(
+    calculator: &mut Calculator,
+    args: &[Expression],
+    sheet_index: usize,
+) -> Result<RangeReference, CellErrorType> {
+    if args.len() < 3 || args.len() > 5 {
+        return Err(CellErrorType::Value);
+    }
+    let base = calculator.expression_to_range(&args[0], sheet_index)?;
+    let mut numbers = Vec::new();
+    for arg in &args[1..] {
+        let value = calculator.evaluate(arg, sheet_index);
+        numbers.push(match value {
+            CalcValue::Empty => None,
+            v => Some(v.to_number()?.trunc() as i64),
+        });
+    }
+    let rows = numbers[0].unwrap_or(0);
+    let cols = numbers[1].unwrap_or(0);
+    let height = numbers
+        .get(2)
+        .copied()
+        .flatten()
+        .unwrap_or((base.row_end - base.row_start + 1) as i64);
+    let width = numbers
+        .get(3)
+        .copied()
+        .flatten()
+        .unwrap_or((base.col_end - base.col_start + 1) as i64);
+    if height < 1 || width < 1 {
+        return Err(CellErrorType::Ref);
+    }
+    let row_start = base.row_start as i64 + rows;
+    let col_start = base.col_start as i64 + cols;
+    if row_start < 1 || col_start < 1 {
+        return Err(CellErrorType::Ref);
+    }
+    Ok(RangeReference {
+        sheet_index: base.sheet_index,
+        col_start: col_start as u32,
+        row_start: row_start as u32,
+        col_end: (col_start + width - 1) as u32,
+        row_end: (row_start + height - 1) as u32,
+    })
+}
+
+/// Functions that work on references rather than values.
+pub(crate) fn evaluate_reference(
+    calculator: &mut Calculator,
+    name: &str,
+    args: &[Expression],
+    sheet_index: usize,
+) -> Option<CalcValue> {
+    let result = match name {
+        "ROW" | "COLUMN" => {
+            if args.is_empty() {
+                let (col, row) = calculator.get_position();
+                return Some(CalcValue::Number(
+                    if name == "ROW" { row } else { col } as f64
+                ));
+            }
+            match calculator.expression_to_range(&args[0], sheet_index) {
+                Ok(range) => CalcValue::Number(if name == "ROW" {
+                    range.row_start
+                } else {
+                    range.col_start
+                } as f64),
+                Err(e) => CalcValue::Error(e),
+            }
+        }
+        "ROWS" | "COLUMNS" => {
+            if args.len() != 1 {
+                return Some(CalcValue::Error(CellErrorType::Value));
+            }
+            let size = match calculator.expression_to_range(&args[0], sheet_index) {
+                Ok(range) => (
+                    range.row_end - range.row_start + 1,
+                    range.col_end - range.col_start + 1,
+                ),
+                Err(_) => {
+                    let rows = calculator.evaluate(&args[0], sheet_index).rows();
+                    (
+                        rows.len() as u32,
+                        rows.first().map(|v| v.len()).unwrap_or(0) as u32,
+                    )
+                }
+            };
+            CalcValue::Number(if name == "ROWS" { size.0 } else { size.1 } as f64)
+        }
+        "ISREF" => {
+            if args.len() != 1 {
+                return Some(CalcValue::Error(CellErrorType::Value));
+            }
+            CalcValue::Bool(
+                calculator
+                    .expression_to_range(&args[0], sheet_index)
+                    .is_ok(),
+            )
+        }
+        "OFFSET" | "INDIRECT" => {
+            let expression = Expression::Function(name.to_string(), args.to_vec());
+            match calculator.expression_to_range(&expression, sheet_index) {
+                Ok(range) => calculator.get_range_value(&range),
+                Err(e) => CalcValue::Error(e),
+            }
+        }
+        _ => return None,
+    };
+    Some(result)
+}
+
+/// Whether a lookup candidate equals the lookup value (text may use wildcards).
+fn is_exact_match(candidate: &CalcValue, lookup: &CalcValue, wildcard: bool) -> bool {
+    match (candidate, lookup) {
+        (CalcValue::Text(c), CalcValue::Text(l)) => {
+            if wildcard {
+                wildcard_match(l, c)
+            } else {
+                c.to_lowercase() == l.to_lowercase()
+            }
+        }
+        (CalcValue::Number(_), CalcValue::Number(_)) | (CalcValue::Bool(_), CalcValue::Bool(_)) => {
+            compare_values(candidate, lookup) == Ordering::Equal
+        }
+        _ => false,
+    }
+}
+
+fn same_kind(candidate: &CalcValue, lookup: &CalcValue) -> bool {
+    matches!(
+        (candidate, lookup),
+        (CalcValue::Number(_), CalcValue::Number(_))
+            | (CalcValue::Text(_), CalcValue::Text(_))
+            | (CalcValue::Bool(_), CalcValue::Bool(_))
+    )
+}
+
+/// Position of `lookup` in `list`.
+/// `match_type` follows `MATCH`: 0 exact, 1 largest value <= lookup, -1 smallest value >= lookup.
+fn find_position(list: &[&CalcValue], lookup: &CalcValue, match_type: i32) -> Option<usize> {
+    if match_type == 0 {
+        return list.iter().position(|v| is_exact_match(v, lookup, true));
+    }
+    let mut result = None;
+    for (index, value) in list.iter().enumerate() {
+        if !same_kind(value, lookup) {
+            continue;
+        }
+        let ordering = compare_values(value, lookup);
+        if match_type > 0 {
+            if ordering == Ordering::Greater {
+                break;
+            }
+            result = Some(index);
+        } else {
+            if ordering == Ordering::Less {
+                break;
+            }
+            result = Some(index);
+        }
+    }
+    result
+}
+
+fn lookup_table(values: &[CalcValue], vertical: bool) -> CalcValue {
+    // VLOOKUP(lookup_value, table_array, col_index_num, [range_lookup])
+    if values.len() < 3 || values.len() > 4 {
+        return CalcValue::Error(CellErrorType::Value);
+    }
+    let lookup = values[0].clone().into_scalar();
+    if let CalcValue::Error(e) = lookup {
+        return CalcValue::Error(e);
+    }
+    let mut table = values[1].rows();
+    if !vertical {
+        table = transpose(table);
+    }
+    let index = match values[2].to_number() {
+        Ok(v) => v.trunc() as i64,
+        Err(e) => return CalcValue::Error(e),
+    };
+    let approximate = match values.get(3) {
+        Some(CalcValue::Empty) | None => true,
+        Some(v) => match v.to_bool() {
+            Ok(b) => b,
+            Err(e) => return CalcValue::Error(e),
+        },
+    };
+    if index < 1 {
+        return CalcValue::Error(CellErrorType::Value);
+    }
+    let width = table.first().map(|v| v.len()).unwrap_or(0);
+    if index as usize > width {
+        return CalcValue::Error(CellErrorType::Ref);
+    }
+    let keys: Vec<&CalcValue> = table.iter().map(|row| &row[0]).collect();
+    let match_type = if approximate { 1 } else { 0 };
+    match find_position(&keys, &lookup, match_type) {
+        Some(row) => table[row][index as usize - 1].clone(),
+        None => CalcValue::Error(CellErrorType::NA),
+    }
+}
+
+fn transpose(rows: Vec<Vec<CalcValue>>) -> Vec<Vec<CalcValue>> {
+    let width = rows.first().map(|v| v.len()).unwrap_or(0);
+    (0..width)
+        .map(|col| rows.iter().map(|row| row[col].clone()).collect())
+        .collect()
+}
+
+fn match_function(values: &[CalcValue]) -> CalcValue {
+    // MATCH(lookup_value, lookup_array, [match_type])
+    if values.len() < 2 || values.len() > 3 {
+        return CalcValue::Error(CellErrorType::Value);
+    }
+    let lookup = values[0].clone().into_scalar();
+    if let CalcValue::Error(e) = lookup {
+        return CalcValue::Error(e);
+    }
+    let rows = values[1].rows();
+    if rows.len() > 1 && rows.iter().any(|v| v.len() > 1) {
+        return CalcValue::Error(CellErrorType::NA);
+    }
+    let match_type = match values.get(2) {
+        Some(CalcValue::Empty) | None => 1,
+        Some(v) => match v.to_number() {
+            Ok(n) => n.signum() as i32 * (n != 0f64) as i32,
+            Err(e) => return CalcValue::Error(e),
+        },
+    };
+    let list = values[1].flatten();
+    match find_position(&list, &lookup, match_type) {
+        Some(v) => CalcValue::Number((v + 1) as f64),
+        None => CalcValue::Error(CellErrorType::NA),
+    }
+}
+
+fn index_function(values: &[CalcValue]) -> CalcValue {
+    // INDEX(array, row_num, [column_num])
+    if values.len() < 2 || values.len() > 3 {
+        return CalcValue::Error(CellErrorType::Value);
+    }
+    let rows = values[0].rows();
+    let mut row_num = match values[1].to_number() {
+        Ok(v) => v.trunc() as i64,
+        Err(e) => return CalcValue::Error(e),
+    };
+    let mut col_num = match values.get(2) {
+        Some(v) => match v.to_number() {
+            Ok(v) => v.trunc() as i64,
+            Err(e) => return CalcValue::Error(e),
+        },
+        None => 0,
+    };
+    let height = rows.len() as i64;
+    let width = rows.first().map(|v| v.len()).unwrap_or(0) as i64;
+    // a single row accepts the position as the only index
+    if values.len() == 2 && height == 1 && width > 1 {
+        col_num = row_num;
+        row_num = 1;
+    } else if values.len() == 2 && width == 1 {
+        col_num = 1;
+    }
+    if row_num < 0 || col_num < 0 || row_num > height || col_num > width {
+        return CalcValue::Error(CellErrorType::Ref);
+    }
+    match (row_num, col_num) {
+        (0, 0) => CalcValue::Array(rows),
+        (0, c) => CalcValue::Array(
+            rows.iter()
+                .map(|row| vec![row[c as usize - 1].clone()])
+                .collect(),
+        ),
+        (r, 0) => CalcValue::Array(vec![rows[r as usize - 1].clone()]),
+        (r, c) => rows[r as usize - 1][c as usize - 1].clone(),
+    }
+}
+
+fn xlookup(values: &[CalcValue]) -> CalcValue {
+    // XLOOKUP(lookup_value, lookup_array, return_array, [if_not_found])
+    if values.len() < 3 {
+        return CalcValue::Error(CellErrorType::Value);
+    }
+    let lookup = values[0].clone().into_scalar();
+    if let CalcValue::Error(e) = lookup {
+        return CalcValue::Error(e);
+    }
+    let keys = values[1].flatten();
+    let rows = values[2].rows();
+    let position = keys.iter().position(|v| is_exact_match(v, &lookup, false));
+    match position {
+        Some(index) => {
+            if rows.len() == keys.len() {
+                CalcValue::Array(vec![rows[index].clone()]).into_scalar_if_single()
+            } else {
+                match rows.first().and_then(|row| row.get(index)) {
+                    Some(v) => v.clone(),
+                    None => CalcValue::Error(CellErrorType::Value),
+                }
+            }
+        }
+        None => match values.get(3) {
+            Some(v) => v.clone(),
+            None => CalcValue::Error(CellErrorType::NA),
+        },
+    }
+}
+
+impl CalcValue {
+    fn into_scalar_if_single(self) -> Self {
+        match &self {
+            CalcValue::Array(rows) if rows.len() == 1 && rows[0].len() == 1 => self.into_scalar(),
+            _ => self,
+        }
+    }
+}
+
+pub(crate) fn evaluate(name: &str, values: &[CalcValue]) -> Option<CalcValue> {
+    let result = match name {
+        "VLOOKUP" => lookup_table(values, true),
+        "HLOOKUP" => lookup_table(values, false),
+        "MATCH" => match_function(values),
+        "INDEX" => index_function(values),
+        "XLOOKUP" => xlookup(values),
+        _ => return None,
+    };
+    Some(result)
+}
@@ -0,0 +1,389 @@
+use super::*;
+
+/// Numbers of the arguments as `SUM` sees them: values that come from ranges must be
+/// numbers (text, logical values and blanks are skipped), direct arguments are coerced.
+pub(crate) fn collect_numbers(values: &[CalcValue]) -> Result<Vec<f64>, CellErrorType> {
+    let mut result = Vec::new();
+    for value in values {
+        match value {
+            CalcValue::Array(_) => {
+                for v in value.flatten() {
+                    match v {
+                        CalcValue::Number(n) => result.push(*n),
+                        CalcValue::Error(e) => return Err(e.clone()),
+                        _ => {}
+                    }
+                }
+            }
+            CalcValue::Empty => {}
+            v => result.push(v.to_number()?),
+        }
+    }
+    Ok(result)
+}
+
+/// Round half away from zero, absorbing binary representation noise (e.g. `2.675`).
+pub(crate) fn round_half_away(value: f64, digits: i32) -> f64 {
+    let factor = 10f64.powi(digits);
+    let scaled = value * factor;
+    let scaled = (scaled * 1e9).round() / 1e9;
+    scaled.round() / factor
+}
+
+fn number_arg(values: &[CalcValue], index: usize) -> Result<f64, CellErrorType> {
+    match values.get(index) {
+        Some(v) => v.to_number(),
+        None => Err(CellErrorType::Value),
+    }
+}
+
+fn number_arg_or(values: &[CalcValue], index: usize, default: f64) -> Result<f64, CellErrorType> {
+    match values.get(index) {
+        Some(CalcValue::Empty) | None => Ok(default),
+        Some(v) => v.to_number(),
+    }
+}
+
+fn unary(values: &[CalcValue], f: &dyn Fn(f64) -> CalcValue) -> CalcValue {
+    if values.len() != 1 {
+        return CalcValue::Error(CellErrorType::Value);
+    }
+    match values[0].to_number() {
+        Ok(v) => f(v),
+        Err(e) => CalcValue::Error(e),
+    }
+}
+
+fn aggregate(values: &[CalcValue], f: &dyn Fn(Vec<f64>) -> CalcValue) -> CalcValue {
+    match collect_numbers(values) {
+        Ok(v) => f(v),
+        Err(e) => CalcValue::Error(e),
+    }
+}
+
+fn median(mut numbers: Vec<f64>) -> CalcValue {
+    if numbers.is_empty() {
+        return CalcValue::Error(CellErrorType::Num);
+    }
+    numbers.sort_by(|a, b| a.partial_cmp(b).unwrap_or(Ordering::Equal));
+    let mid = numbers.len() / 2;
+    if numbers.len() % 2 == 0 {
+        CalcValue::Number((numbers[mid - 1] + numbers[mid]) / 2f64)
+    } else {
+        CalcValue::Number(numbers[mid])
+    }
+}
+
+fn average(numbers: Vec<f64>) -> CalcValue {
+    if numbers.is_empty() {
+        return CalcValue::Error(CellErrorType::Div0);
+    }
+    CalcValue::Number(numbers.iter().sum::<f64>() / numbers.len() as f64)
+}
+
+fn count(values: &[CalcValue]) -> CalcValue {
+    let mut result = 0;
+    for value in values {
+        match value {
+            CalcValue::Array(_) => {
+                result += value
+                    .flatten()
+                    .iter()
+                    .filter(|v| matches!(v, CalcValue::Number(_)))
+                    .count();
+            }
+            CalcValue::Number(_) | CalcValue::Bool(_) => result += 1,
+            CalcValue::Text(v) if parse_number(v).is_some() => result += 1,
+            _ => {}
+        }
+    }
+    CalcValue::Number(result as f64)
+}
+
+fn count_a(values: &[CalcValue]) -> CalcValue {
+    let result = values
+        .iter()
+        .flat_map(|v| v.flatten())
+        .filter(|v| !matches!(v, CalcValue::Empty))
+        .count();
+    CalcValue::Number(result as f64)
+}
+
+fn count_blank(values: &[CalcValue]) -> CalcValue {
+    let result = values
+        .iter()
+        .flat_map(|v| v.flatten())
+        .filter(|v| {
+            matches!(v, CalcValue::Empty) || matches!(v, CalcValue::Text(t) if t.is_empty())
+        })
+        .count();
+    CalcValue::Number(result as f64)
+}
+
+fn sum_product(values: &[CalcValue]) -> CalcValue {
+    if values.is_empty() {
+        return CalcValue::Error(CellErrorType::Value);
+    }
+    let arrays: Vec<Vec<&CalcValue>> = values.iter().map(|v| v.flatten()).collect();
+    let len = arrays[0].len();
+    if arrays.iter().any(|v| v.len() != len) {
+        return CalcValue::Error(CellErrorType::Value);
+    }
+    let mut result = 0f64;
+    for i in 0..len {
+        let mut product = 1f64;
+        for array in &arrays {
+            match array[i] {
+                CalcValue::Number(n) => product *= n,
+                CalcValue::Error(e) => return CalcValue::Error(e.clone()),
+                _ => product = 0f64,
+            }
+        }
+        result += product;
+    }
+    CalcValue::Number(result)
+}
+
+/// Indexes of cells matching every `(range, criteria)` pair, as used by `COUNTIFS` and friends.
+fn match_criteria_list(pairs: &[(&CalcValue, &CalcValue)]) -> Result<Vec<usize>, CellErrorType> {
+    let ranges: Vec<Vec<&CalcValue>> = pairs.iter().map(|(range, _)| range.flatten()).collect();
+    let len = ranges.first().map(|v| v.len()).unwrap_or(0);
+    if ranges.iter().any(|v| v.len() != len) {
+        return Err(CellErrorType::Value);
+    }
+    let criterias: Vec<CalcValue> = pairs
+        .iter()
+        .map(|(_, criteria)| (*criteria).clone().into_scalar())
+        .collect();
+    Ok((0..len)
+        .filter(|i| {
+            ranges
+                .iter()
+                .zip(criterias.iter())
+                .all(|(range, criteria)| match_criteria(range[*i], criteria))
+        })
+        .collect())
+}
+
+fn conditional(values: &[CalcValue], kind: &str) -> CalcValue {
+    // SUMIF(range, criteria, [sum_range]) / COUNTIF(range, criteria) / AVERAGEIF(...)
+    if values.len() < 2 {
+        return CalcValue::Error(CellErrorType::Value);
+    }
+    let indexes = match match_criteria_list(&[(&values[0], &values[1])]) {
+        Ok(v) => v,
+        Err(e) => return CalcValue::Error(e),
+    };
+    if kind == "COUNT" {
+        return CalcValue::Number(indexes.len() as f64);
+    }
+    let target = values.get(2).unwrap_or(&values[0]).flatten();
+    let numbers: Vec<f64> = indexes
+        .iter()
+        .filter_map(|i| match target.get(*i) {
+            Some(CalcValue::Number(n)) => Some(*n),
+            _ => None,
+        })
+        .collect();
+    if kind == "SUM" {
+        CalcValue::Number(numbers.iter().sum())
+    } else {
+        average(numbers)
+    }
+}
+
+fn conditional_multi(values: &[CalcValue], kind: &str) -> CalcValue {
+    // SUMIFS(sum_range, range1, criteria1, ...) / COUNTIFS(range1, criteria1, ...)
+    let (target, rest) = if kind == "COUNT" {
+        (None, values)
+    } else {
+        match values.split_first() {
+            Some((first, rest)) => (Some(first), rest),
+            None => return CalcValue::Error(CellErrorType::Value),
+        }
+    };
+    if rest.is_empty() || rest.len() % 2 != 0 {
+        return CalcValue::Error(CellErrorType::Value);
+    }
+    let pairs: Vec<(&CalcValue, &CalcValue)> = rest.chunks(2).map(|v| (&v[0], &v[1])).collect();
+    let indexes = match match_criteria_list(&pairs) {
+        Ok(v) => v,
+        Err(e) => return CalcValue::Error(e),
+    };
+    let target = match target {
+        Some(v) => v.flatten(),
+        None => return CalcValue::Number(indexes.len() as f64),
+    };
+    if target.len() != pairs[0].0.flatten().len() {
+        return CalcValue::Error(CellErrorType::Value);
+    }
+    let numbers: Vec<f64> = indexes
+        .iter()
+        .filter_map(|i| match target[*i] {
+            CalcValue::Number(n) => Some(*n),
+            _ => None,
+        })
+        .collect();
+    if kind == "SUM" {
+        CalcValue::Number(numbers.iter().sum())
+    } else {
+        average(numbers)
+    }
+}
+
+fn round_function(values: &[CalcValue], f: &dyn Fn(f64, i32) -> f64) -> CalcValue {
+    if values.is_empty() || values.len() > 2 {
+        return CalcValue::Error(CellErrorType::Value);
+    }
+    let value = match number_arg(values, 0) {
+        Ok(v) => v,
+        Err(e) => return CalcValue::Error(e),
+    };
+    let digits = match number_arg_or(values, 1, 0f64) {
+        Ok(v) => v.trunc() as i32,
+        Err(e) => return CalcValue::Error(e),
+    };
+    number_result(f(value, digits))
+}
+
+fn multiple_function(values: &[CalcValue], up: bool) -> CalcValue {
+    // CEILING(number, significance) / FLOOR(number, significance)
+    let value = match number_arg(values, 0) {
+        Ok(v) => v,
+        Err(e) => return CalcValue::Error(e),
+    };
+    let significance = match number_arg_or(values, 1, if value < 0f64 { -1f64 } else { 1f64 }) {
+        Ok(v) => v,
+        Err(e) => return CalcValue::Error(e),
+    };
+    if significance == 0f64 {
+        return CalcValue::Number(0f64);
+    }
+    if value > 0f64 && significance < 0f64 {
+        return CalcValue::Error(CellErrorType::Num);
+    }
+    let quotient = round_half_away(value / significance, 9);
+    let result = if up {
+        quotient.ceil()
+    } else {
+        quotient.floor()
+    };
+    number_result(result * significance)
+}
+
+fn subtotal(values: &[CalcValue]) -> CalcValue {
+    let function_num = match number_arg(values, 0) {
+        Ok(v) => v.trunc() as i32 % 100,
+        Err(e) => return CalcValue::Error(e),
+    };
+    let name = match function_num {
+        1 => "AVERAGE",
+        2 => "COUNT",
+        3 => "COUNTA",
+        4 => "MAX",
+        5 => "MIN",
+        6 => "PRODUCT",
+        9 => "SUM",
+        _ => return CalcValue::Error(CellErrorType::Value),
+    };
+    evaluate(name, &values[1..]).unwrap_or(CalcValue::Error(CellErrorType::Value))
+}
+
+pub(crate) fn evaluate(name: &str, values: &[CalcValue]) -> Option<CalcValue> {
+    let result = match name {
+        "SUM" => aggregate(values, &|v| CalcValue::Number(v.iter().sum())),
+        "PRODUCT" => aggregate(values, &|v| CalcValue::Number(v.iter().product())),
+        "AVERAGE" => aggregate(values, &average),
+        "MIN" => aggregate(values, &|v| {
+            CalcValue::Number(v.into_iter().reduce(f64::min).unwrap_or(0f64))
+        }),
+        "MAX" => aggregate(values, &|v| {
+            CalcValue::Number(v.into_iter().reduce(f64::max).unwrap_or(0f64))
+        }),
+        "MEDIAN" => aggregate(values, &median),
+        "COUNT" => count(values),
+        "COUNTA" => count_a(values),
+        "COUNTBLANK" => count_blank(values),
+        "SUMPRODUCT" => sum_product(values),
+        "SUMIF" => conditional(values, "SUM"),
+        "COUNTIF" => conditional(values, "COUNT"),
+        "AVERAGEIF" => conditional(values, "AVERAGE"),
+        "SUMIFS" => conditional_multi(values, "SUM"),
+        "COUNTIFS" => conditional_multi(values, "COUNT"),
+        "AVERAGEIFS" => conditional_multi(values, "AVERAGE"),
+        "SUBTOTAL" => subtotal(values),
+        "ABS" => unary(values, &|v| CalcValue::Number(v.abs())),
+        "INT" => unary(values, &|v| CalcValue::Number(v.floor())),
+        "SIGN" => unary(values, &|v| {
+            CalcValue::Number(if v == 0f64 { 0f64 } else { v.signum() })
+        }),
+        "SQRT" => unary(values, &|v| {
+            if v < 0f64 {
+                CalcValue::Error(CellErrorType::Num)
+            } else {
+                CalcValue::Number(v.sqrt())
+            }
+        }),
+        "EXP" => unary(values, &|v| number_result(v.exp())),
+        "LN" => unary(values, &|v| {
+            if v <= 0f64 {
+                CalcValue::Error(CellErrorType::Num)
+            } else {
+                CalcValue::Number(v.ln())
+            }
+        }),
+        "LOG10" => unary(values, &|v| {
+            if v <= 0f64 {
+                CalcValue::Error(CellErrorType::Num)
+            } else {
+                CalcValue::Number(v.log10())
+            }
+        }),
+        "LOG" => {
+            let value = number_arg(values, 0);
+            let base = number_arg_or(values, 1, 10f64);
+            match (value, base) {
+                (Ok(v), Ok(b)) if v > 0f64 && b > 0f64 && b != 1f64 => {
+                    CalcValue::Number(v.ln() / b.ln())
+                }
+                (Err(e), _) | (_, Err(e)) => CalcValue::Error(e),
+                _ => CalcValue::Error(CellErrorType::Num),
+            }
+        }
+        "PI" => CalcValue::Number(std::f64::consts::PI),
+        "POWER" => match (number_arg(values, 0), number_arg(values, 1)) {
+            (Ok(v), Ok(p)) => {
+                if v == 0f64 && p <= 0f64 {
+                    CalcValue::Error(if p == 0f64 {
+                        CellErrorType::Num
+                    } else {
+                        CellErrorType::Div0
+                    })
+                } else {
+                    number_result(v.powf(p))
+                }
+            }
+            (Err(e), _) | (_, Err(e)) => CalcValue::Error(e),
+        },
+        "MOD" => match (number_arg(values, 0), number_arg(values, 1)) {
+            (Ok(_), Ok(d)) if d == 0f64 => CalcValue::Error(CellErrorType::Div0),
+            (Ok(n), Ok(d)) => CalcValue::Number(n - d * (n / d).floor()),
+            (Err(e), _) | (_, Err(e)) => CalcValue::Error(e),
+        },
+        "ROUND" => round_function(values, &round_half_away),
+        "ROUNDUP" => round_function(values, &|v, d| {
+            let factor = 10f64.powi(d);
+            let scaled = round_half_away(v * factor, 9);
+            scaled.abs().ceil().copysign(v) / factor
+        }),
+        "ROUNDDOWN" | "TRUNC" => round_function(values, &|v, d| {
+            let factor = 10f64.powi(d);
+            let scaled = round_half_away(v * factor, 9);
+            scaled.trunc() / factor
+        }),
+        "CEILING" => multiple_function(values, true),
+        "FLOOR" => multiple_function(values, false),
+        _ => return None,
+    };
+    Some(result)
+}
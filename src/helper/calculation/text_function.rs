@@ -0,0 +1,283 @@
+use super::*;
+use crate::helper::number_format::to_formatted_string_crate;
+
+fn text_arg(values: &[CalcValue], index: usize) -> Result<String, CellErrorType> {
+    match values.get(index) {
+        Some(v) => v.to_text(),
+        None => Err(CellErrorType::Value),
+    }
+}
+
+fn optional_count(values: &[CalcValue], index: usize) -> Result<usize, CellErrorType> {
+    match values.get(index) {
+        Some(CalcValue::Empty) | None => Ok(1),
+        Some(v) => {
+            let n = v.to_number()?;
+            if n < 0f64 {
+                return Err(CellErrorType::Value);
+            }
+            Ok(n.trunc() as usize)
+        }
+    }
+}
+
+fn left_right(values: &[CalcValue], left: bool) -> Result<CalcValue, CellErrorType> {
+    let text: Vec<char> = text_arg(values, 0)?.chars().collect();
+    let count = optional_count(values, 1)?.min(text.len());
+    let result: String = if left {
+        text[..count].iter().collect()
+    } else {
+        text[text.len() - count..].iter().collect()
+    };
+    Ok(CalcValue::Text(result))
+}
+
+fn mid(values: &[CalcValue]) -> Result<CalcValue, CellErrorType> {
+    let text: Vec<char> = text_arg(values, 0)?.chars().collect();
+    let start = values
+        .get(1)
+        .ok_or(CellErrorType::Value)?
+        .to_number()?
+        .trunc();
+    let count = values
+        .get(2)
+        .ok_or(CellErrorType::Value)?
+        .to_number()?
+        .trunc();
+    if start < 1f64 || count < 0f64 {
+        return Err(CellErrorType::Value);
+    }
+    let start = (start as usize - 1).min(text.len());
+    let end = (start + count as usize).min(text.len());
+    Ok(CalcValue::Text(text[start..end].iter().collect()))
+}
+
+fn proper(text: &str) -> String {
+    let mut result = String::with_capacity(text.len());
+    let mut capitalize = true;
+    for c in text.chars() {
+        if capitalize {
+            result.extend(c.to_uppercase());
+        } else {
+            result.extend(c.to_lowercase());
+        }
+        capitalize = !c.is_alphabetic();
+    }
+    result
+}
+
+fn substitute(values: &[CalcValue]) -> Result<CalcValue, CellErrorType> {
+    // SUBSTITUTE(text, old_text, new_text, [instance_num])
+    let text = text_arg(values, 0)?;
+    let old = text_arg(values, 1)?;
+    let new = text_arg(values, 2)?;
+    if old.is_empty() {
+        return Ok(CalcValue::Text(text));
+    }
+    match values.get(3) {
+        Some(v) => {
+            let instance = v.to_number()?.trunc();
+            if instance < 1f64 {
+                return Err(CellErrorType::Value);
+            }
+            match text.match_indices(&old).nth(instance as usize - 1) {
+                Some((index, _)) => Ok(CalcValue::Text(format!(
+                    "{}{}{}",
+                    &text[..index],
+                    new,
+                    &text[index + old.len()..]
+                ))),
+                None => Ok(CalcValue::Text(text)),
+            }
+        }
+        None => Ok(CalcValue::Text(text.replace(&old, &new))),
+    }
+}
+
+fn replace(values: &[CalcValue]) -> Result<CalcValue, CellErrorType> {
+    // REPLACE(old_text, start_num, num_chars, new_text)
+    let text: Vec<char> = text_arg(values, 0)?.chars().collect();
+    let start = values
+        .get(1)
+        .ok_or(CellErrorType::Value)?
+        .to_number()?
+        .trunc();
+    let count = values
+        .get(2)
+        .ok_or(CellErrorType::Value)?
+        .to_number()?
+        .trunc();
+    let new = text_arg(values, 3)?;
+    if start < 1f64 || count < 0f64 {
+        return Err(CellErrorType::Value);
+    }
+    let start = (start as usize - 1).min(text.len());
+    let end = (start + count as usize).min(text.len());
+    let result: String = text[..start]
+        .iter()
+        .chain(new.chars().collect::<Vec<char>>().iter())
+        .chain(text[end..].iter())
+        .collect();
+    Ok(CalcValue::Text(result))
+}
+
+fn find(values: &[CalcValue], case_sensitive: bool) -> Result<CalcValue, CellErrorType> {
+    // FIND(find_text, within_text, [start_num])
+    let find_text = text_arg(values, 0)?;
+    let within: Vec<char> = text_arg(values, 1)?.chars().collect();
+    let start = match values.get(2) {
+        Some(v) => v.to_number()?.trunc(),
+        None => 1f64,
+    };
+    if start < 1f64 || start as usize > within.len() + 1 {
+        return Err(CellErrorType::Value);
+    }
+    let start = start as usize - 1;
+    let needle: Vec<char> = find_text.chars().collect();
+    for index in start..=within.len() {
+        let rest: String = within[index..].iter().collect();
+        let found = if case_sensitive {
+            rest.starts_with(&find_text)
+        } else {
+            // SEARCH supports wildcards and ignores case
+            (needle.len()..=rest.chars().count()).any(|len| {
+                let candidate: String = rest.chars().take(len).collect();
+                wildcard_match(&find_text, &candidate)
+            }) || find_text.is_empty()
+        };
+        if found {
+            return Ok(CalcValue::Number((index + 1) as f64));
+        }
+    }
+    Err(CellErrorType::Value)
+}
+
+fn text_join(values: &[CalcValue]) -> Result<CalcValue, CellErrorType> {
+    // TEXTJOIN(delimiter, ignore_empty, text1, ...)
+    let delimiter = text_arg(values, 0)?;
+    let ignore_empty = values.get(1).ok_or(CellErrorType::Value)?.to_bool()?;
+    let mut list = Vec::new();
+    for value in &values[2..] {
+        for v in value.flatten() {
+            let text = v.to_text()?;
+            if ignore_empty && text.is_empty() {
+                continue;
+            }
+            list.push(text);
+        }
+    }
+    Ok(CalcValue::Text(list.join(&delimiter)))
+}
+
+fn concat(values: &[CalcValue], flatten: bool) -> Result<CalcValue, CellErrorType> {
+    let mut result = String::new();
+    for value in values {
+        if flatten {
+            for v in value.flatten() {
+                result.push_str(&v.to_text()?);
+            }
+        } else {
+            result.push_str(&value.to_text()?);
+        }
+    }
+    Ok(CalcValue::Text(result))
+}
+
+fn text(values: &[CalcValue], date1904: bool) -> Result<CalcValue, CellErrorType> {
+    // TEXT(value, format_text)
+    let value = values
+        .first()
+        .ok_or(CellErrorType::Value)?
+        .clone()
+        .into_scalar();
+    let format = text_arg(values, 1)?;
+    let value = match value {
+        CalcValue::Number(n) => n.to_string(),
+        CalcValue::Error(e) => return Err(e),
+        v => v.to_text()?,
+    };
+    Ok(CalcValue::Text(to_formatted_string_crate(
+        &value, &format, date1904,
+    )))
+}
+
+fn evaluate_text(
+    name: &str,
+    values: &[CalcValue],
+    date1904: bool,
+) -> Result<CalcValue, CellErrorType> {
+    let result = match name {
+        "CONCATENATE" => concat(values, false)?,
+        "CONCAT" => concat(values, true)?,
+        "TEXTJOIN" => text_join(values)?,
+        "LEFT" => left_right(values, true)?,
+        "RIGHT" => left_right(values, false)?,
+        "MID" => mid(values)?,
+        "LEN" => CalcValue::Number(text_arg(values, 0)?.chars().count() as f64),
+        "UPPER" => CalcValue::Text(text_arg(values, 0)?.to_uppercase()),
+        "LOWER" => CalcValue::Text(text_arg(values, 0)?.to_lowercase()),
+        "PROPER" => CalcValue::Text(proper(&text_arg(values, 0)?)),
+        "TRIM" => CalcValue::Text(
+            text_arg(values, 0)?
+                .split(' ')
+                .filter(|v| !v.is_empty())
+                .collect::<Vec<&str>>()
+                .join(" "),
+        ),
+        "SUBSTITUTE" => substitute(values)?,
+        "REPLACE" => replace(values)?,
+        "FIND" => find(values, true)?,
+        "SEARCH" => find(values, false)?,
+        "REPT" => {
+            let text = text_arg(values, 0)?;
+            let count = values.get(1).ok_or(CellErrorType::Value)?.to_number()?;
+            if count < 0f64 {
+                return Err(CellErrorType::Value);
+            }
+            CalcValue::Text(text.repeat(count.trunc() as usize))
+        }
+        "EXACT" => CalcValue::Bool(text_arg(values, 0)? == text_arg(values, 1)?),
+        "VALUE" => match values.first().map(|v| v.clone().into_scalar()) {
+            Some(CalcValue::Number(n)) => CalcValue::Number(n),
+            Some(CalcValue::Empty) => CalcValue::Number(0f64),
+            Some(CalcValue::Text(t)) => {
+                CalcValue::Number(parse_number(&t).ok_or(CellErrorType::Value)?)
+            }
+            Some(CalcValue::Error(e)) => return Err(e),
+            _ => return Err(CellErrorType::Value),
+        },
+        "TEXT" => text(values, date1904)?,
+        "T" => match values.first().map(|v| v.clone().into_scalar()) {
+            Some(CalcValue::Text(t)) => CalcValue::Text(t),
+            Some(CalcValue::Error(e)) => return Err(e),
+            _ => CalcValue::Text(String::new()),
+        },
+        "N" => match values.first().map(|v| v.clone().into_scalar()) {
+            Some(CalcValue::Number(n)) => CalcValue::Number(n),
+            Some(CalcValue::Bool(b)) => CalcValue::Number(if b { 1f64 } else { 0f64 }),
+            Some(CalcValue::Error(e)) => return Err(e),
+            _ => CalcValue::Number(0f64),
+        },
+        "CHAR" => {
+            let code = values.first().ok_or(CellErrorType::Value)?.to_number()?;
+            if !(1f64..=255f64).contains(&code) {
+                return Err(CellErrorType::Value);
+            }
+            CalcValue::Text(char::from(code as u8).to_string())
+        }
+        "CODE" => match text_arg(values, 0)?.chars().next() {
+            Some(c) => CalcValue::Number(c as u32 as f64),
+            None => return Err(CellErrorType::Value),
+        },
+        _ => return Err(CellErrorType::Name),
+    };
+    Ok(result)
+}
+
+pub(crate) fn evaluate(name: &str, values: &[CalcValue], date1904: bool) -> Option<CalcValue> {
+    match evaluate_text(name, values, date1904) {
+        Ok(v) => Some(v),
+        Err(CellErrorType::Name) => None,
+        Err(e) => Some(CalcValue::Error(e)),
+    }
+}
@@ -0,0 +1,752 @@
+//! Chart rendering.
+//!
+//! A chart is laid out into a list of simple shapes (`Scene`), which is then written
+//! as SVG or, with the `image` feature, rasterized into a PNG image.
+//! Series data is read from the cells of the workbook and falls back to the values
+//! cached in the chart when the referenced worksheet is not available.
+
+#[cfg(feature = "image")]
+mod font;
+mod plot;
+#[cfg(feature = "image")]
+mod raster;
+mod svg;
+
+use crate::helper::color::*;
+use crate::helper::range::get_coordinate_list;
+use crate::structs::drawing::charts::AreaChartSeries;
+use crate::structs::drawing::charts::CategoryAxisData;
+use crate::structs::drawing::charts::DisplayBlanksAsValues;
+use crate::structs::drawing::charts::Formula;
+use crate::structs::drawing::charts::LegendPositionValues;
+use crate::structs::drawing::charts::MarkerStyleValues;
+use crate::structs::drawing::charts::NumberReference;
+use crate::structs::drawing::charts::ShapeProperties;
+use crate::structs::drawing::charts::Title;
+use crate::structs::drawing::SchemeColor;
+use crate::structs::drawing::SchemeColorValues;
+use crate::structs::drawing::SolidFill;
+use crate::structs::Cell;
+use crate::structs::Chart;
+use crate::structs::Spreadsheet;
+#[cfg(feature = "image")]
+use crate::XlsxError;
+
+pub(crate) const TITLE_SIZE: f64 = 18.0;
+pub(crate) const LABEL_SIZE: f64 = 12.0;
+const MARGIN: f64 = 10.0;
+
+/// Office theme accents, used when the workbook theme has no color scheme.
+const DEFAULT_ACCENTS: [&str; 6] = ["4472C4", "ED7D31", "A5A5A5", "FFC000", "5B9BD5", "70AD47"];
+
+/// A color of the rendered chart.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub(crate) struct Rgb {
+    pub(crate) r: u8,
+    pub(crate) g: u8,
+    pub(crate) b: u8,
+}
+impl Rgb {
+    pub(crate) const WHITE: Rgb = Rgb::new(255, 255, 255);
+    pub(crate) const TEXT: Rgb = Rgb::new(0x59, 0x59, 0x59);
+    pub(crate) const LINE: Rgb = Rgb::new(0xD9, 0xD9, 0xD9);
+
+    pub(crate) const fn new(r: u8, g: u8, b: u8) -> Self {
+        Self { r, g, b }
+    }
+
+    /// Parse `RRGGBB` or `AARRGGBB`.
+    pub(crate) fn from_hex(value: &str) -> Option<Self> {
+        let value = match value.len() {
+            6 => value,
+            8 => &value[2..],
+            _ => return None,
+        };
+        let parse = |i: usize| u8::from_str_radix(value.get(i..i + 2)?, 16).ok();
+        Some(Self::new(parse(0)?, parse(2)?, parse(4)?))
+    }
+
+    pub(crate) fn to_hex(self) -> String {
+        format!("{:02X}{:02X}{:02X}", self.r, self.g, self.b)
+    }
+
+    /// Apply `lumMod` and `lumOff` given as fractions.
+    pub(crate) fn luminance(self, modulation: f64, offset: f64) -> Self {
+        let mut hls = convert_rgb_to_hls(&self.to_hex());
+        hls.l = (hls.l * modulation + offset).clamp(0.0, 1.0);
+        Self::from_hex(&convert_hls_to_rgb(&hls)).unwrap_or(self)
+    }
+
+    fn shade(self, value: f64) -> Self {
+        let f = |c: u8| (c as f64 * value).round().clamp(0.0, 255.0) as u8;
+        Self::new(f(self.r), f(self.g), f(self.b))
+    }
+
+    fn tint(self, value: f64) -> Self {
+        let f = |c: u8| {
+            (c as f64 * value + 255.0 * (1.0 - value))
+                .round()
+                .clamp(0.0, 255.0) as u8
+        };
+        Self::new(f(self.r), f(self.g), f(self.b))
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub(crate) struct Stroke {
+    pub(crate) color: Rgb,
+    pub(crate) width: f64,
+}
+impl Stroke {
+    pub(crate) fn new(color: Rgb, width: f64) -> Self {
+        Self { color, width }
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub(crate) enum TextAnchor {
+    Start,
+    Middle,
+    End,
+}
+
+/// A shape of the rendered chart. Coordinates are in pixels.
+#[derive(Clone, Debug, PartialEq)]
+pub(crate) enum Shape {
+    Rect {
+        x: f64,
+        y: f64,
+        width: f64,
+        height: f64,
+        fill: Option<Rgb>,
+        stroke: Option<Stroke>,
+    },
+    Line {
+        points: Vec<(f64, f64)>,
+        stroke: Stroke,
+    },
+    Polygon {
+        points: Vec<(f64, f64)>,
+        fill: Option<Rgb>,
+        stroke: Option<Stroke>,
+    },
+    Circle {
+        x: f64,
+        y: f64,
+        radius: f64,
+        fill: Option<Rgb>,
+        stroke: Option<Stroke>,
+    },
+    /// `y` is the vertical center of the text.
+    /// Vertical text is rotated 90 degrees counterclockwise around (`x`, `y`).
+    Text {
+        x: f64,
+        y: f64,
+        text: String,
+        size: f64,
+        color: Rgb,
+        anchor: TextAnchor,
+        vertical: bool,
+    },
+}
+
+#[derive(Clone, Debug, Default)]
+pub(crate) struct Scene {
+    pub(crate) width: f64,
+    pub(crate) height: f64,
+    pub(crate) shapes: Vec<Shape>,
+}
+impl Scene {
+    pub(crate) fn push(&mut self, shape: Shape) {
+        self.shapes.push(shape);
+    }
+
+    pub(crate) fn text<S: Into<String>>(
+        &mut self,
+        x: f64,
+        y: f64,
+        text: S,
+        size: f64,
+        anchor: TextAnchor,
+        vertical: bool,
+    ) {
+        self.push(Shape::Text {
+            x,
+            y,
+            text: text.into(),
+            size,
+            color: Rgb::TEXT,
+            anchor,
+            vertical,
+        });
+    }
+}
+
+/// Estimated width of a text. Wide characters count twice.
+pub(crate) fn text_width(text: &str, size: f64) -> f64 {
+    let count: usize = text
+        .chars()
+        .map(|c| if (c as u32) >= 0x2E80 { 2 } else { 1 })
+        .sum();
+    count as f64 * size * 0.55
+}
+
+/// A rectangle of the chart area.
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub(crate) struct Area {
+    pub(crate) x: f64,
+    pub(crate) y: f64,
+    pub(crate) width: f64,
+    pub(crate) height: f64,
+}
+impl Area {
+    pub(crate) fn new(x: f64, y: f64, width: f64, height: f64) -> Self {
+        Self {
+            x,
+            y,
+            width: width.max(1.0),
+            height: height.max(1.0),
+        }
+    }
+
+    pub(crate) fn right(&self) -> f64 {
+        self.x + self.width
+    }
+
+    pub(crate) fn bottom(&self) -> f64 {
+        self.y + self.height
+    }
+}
+
+/// How a legend entry shows its color.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub(crate) enum LegendKind {
+    Box,
+    Line,
+}
+
+#[derive(Clone, Debug)]
+pub(crate) struct LegendEntry {
+    pub(crate) text: String,
+    pub(crate) color: Rgb,
+    pub(crate) kind: LegendKind,
+}
+
+/// Values and formatting of a series, resolved against the workbook.
+#[derive(Clone, Debug)]
+pub(crate) struct SeriesData {
+    pub(crate) index: u32,
+    pub(crate) name: String,
+    pub(crate) fill: Rgb,
+    pub(crate) line: Rgb,
+    pub(crate) has_fill: bool,
+    pub(crate) has_line: bool,
+    /// Outline color set on the series itself.
+    pub(crate) outline: Option<Rgb>,
+    pub(crate) line_width: f64,
+    pub(crate) marker: Option<MarkerStyleValues>,
+    pub(crate) smooth: bool,
+    pub(crate) explosion: f64,
+    pub(crate) categories: Vec<String>,
+    pub(crate) values: Vec<Option<f64>>,
+    pub(crate) x_values: Vec<Option<f64>>,
+    pub(crate) sizes: Vec<Option<f64>>,
+}
+
+/// Workbook data and theme used while rendering.
+pub(crate) struct Context<'a> {
+    spreadsheet: &'a Spreadsheet,
+    accents: [Rgb; 6],
+    display_blanks_as: DisplayBlanksAsValues,
+}
+impl<'a> Context<'a> {
+    fn new(spreadsheet: &'a Spreadsheet, chart: &Chart) -> Self {
+        let scheme = spreadsheet
+            .get_theme()
+            .get_theme_elements()
+            .get_color_scheme();
+        let theme = [
+            scheme.get_accent1().get_val(),
+            scheme.get_accent2().get_val(),
+            scheme.get_accent3().get_val(),
+            scheme.get_accent4().get_val(),
+            scheme.get_accent5().get_val(),
+            scheme.get_accent6().get_val(),
+        ];
+        let mut accents = [Rgb::WHITE; 6];
+        for (i, accent) in accents.iter_mut().enumerate() {
+            *accent = Rgb::from_hex(&theme[i])
+                .or_else(|| Rgb::from_hex(DEFAULT_ACCENTS[i]))
+                .unwrap();
+        }
+        Self {
+            spreadsheet,
+            accents,
+            display_blanks_as: chart
+                .get_chart_space()
+                .get_chart()
+                .get_display_blanks_as()
+                .get_val()
+                .clone(),
+        }
+    }
+
+    /// Default color of the n-th series or point.
+    /// After the six accents the same colors repeat darker or lighter.
+    pub(crate) fn get_accent(&self, index: usize) -> Rgb {
+        let color = self.accents[index % 6];
+        match (index / 6) % 5 {
+            0 => color,
+            1 => color.luminance(0.6, 0.0),
+            2 => color.luminance(0.8, 0.2),
+            3 => color.luminance(0.8, 0.0),
+            _ => color.luminance(0.6, 0.4),
+        }
+    }
+
+    fn get_scheme_color(&self, value: &SchemeColor) -> Option<Rgb> {
+        let scheme = self
+            .spreadsheet
+            .get_theme()
+            .get_theme_elements()
+            .get_color_scheme();
+        let hex = match value.get_val() {
+            SchemeColorValues::Accent1 => {
+                return Some(self.apply_modifiers(self.accents[0], value))
+            }
+            SchemeColorValues::Accent2 => {
+                return Some(self.apply_modifiers(self.accents[1], value))
+            }
+            SchemeColorValues::Accent3 => {
+                return Some(self.apply_modifiers(self.accents[2], value))
+            }
+            SchemeColorValues::Accent4 => {
+                return Some(self.apply_modifiers(self.accents[3], value))
+            }
+            SchemeColorValues::Accent5 => {
+                return Some(self.apply_modifiers(self.accents[4], value))
+            }
+            SchemeColorValues::Accent6 => {
+                return Some(self.apply_modifiers(self.accents[5], value))
+            }
+            SchemeColorValues::Background1 | SchemeColorValues::Light1 => {
+                scheme.get_lt1().get_val()
+            }
+            SchemeColorValues::Background2 | SchemeColorValues::Light2 => {
+                scheme.get_lt2().get_val()
+            }
+            SchemeColorValues::Text1 | SchemeColorValues::Dark1 => scheme.get_dk1().get_val(),
+            SchemeColorValues::Text2 | SchemeColorValues::Dark2 => scheme.get_dk2().get_val(),
+            SchemeColorValues::Hyperlink => scheme.get_hlink().get_val(),
+            SchemeColorValues::FollowedHyperlink => scheme.get_fol_hlink().get_val(),
+            SchemeColorValues::PhColor => return None,
+        };
+        let color = Rgb::from_hex(&hex).unwrap_or(match value.get_val() {
+            SchemeColorValues::Background1 | SchemeColorValues::Light1 => Rgb::WHITE,
+            _ => Rgb::new(0, 0, 0),
+        });
+        Some(self.apply_modifiers(color, value))
+    }
+
+    fn apply_modifiers(&self, mut color: Rgb, value: &SchemeColor) -> Rgb {
+        if let Some(v) = value.get_shade() {
+            color = color.shade(*v.get_val() as f64 / 100000.0);
+        }
+        if let Some(v) = value.get_tint() {
+            color = color.tint(*v.get_val() as f64 / 100000.0);
+        }
+        let modulation = value
+            .get_luminance_modulation()
+            .map_or(1.0, |v| *v.get_val() as f64 / 100000.0);
+        let offset = value
+            .get_luminance_offset()
+            .map_or(0.0, |v| *v.get_val() as f64 / 100000.0);
+        if modulation != 1.0 || offset != 0.0 {
+            color = color.luminance(modulation, offset);
+        }
+        color
+    }
+
+    pub(crate) fn get_fill_color(&self, value: &SolidFill) -> Option<Rgb> {
+        if let Some(v) = value.get_scheme_color() {
+            return self.get_scheme_color(v);
+        }
+        if let Some(v) = value.get_rgb_color_model_hex() {
+            return Rgb::from_hex(v.get_val());
+        }
+        None
+    }
+
+    /// Fill color of shape properties, `Some(None)` when filling is turned off.
+    pub(crate) fn get_shape_fill(&self, value: Option<&ShapeProperties>) -> Option<Option<Rgb>> {
+        let value = value?;
+        if value.get_no_fill().is_some() {
+            return Some(None);
+        }
+        value
+            .get_solid_fill()
+            .and_then(|v| self.get_fill_color(v))
+            .map(Some)
+    }
+
+    /// Outline color of shape properties, `Some(None)` when the outline is turned off.
+    pub(crate) fn get_shape_line(&self, value: Option<&ShapeProperties>) -> Option<Option<Rgb>> {
+        let outline = value?.get_outline()?;
+        if outline.get_no_fill().is_some() {
+            return Some(None);
+        }
+        outline
+            .get_solid_fill()
+            .and_then(|v| self.get_fill_color(v))
+            .map(Some)
+    }
+
+    pub(crate) fn get_series_data(&self, series: &AreaChartSeries) -> SeriesData {
+        let index = *series.get_index().get_val();
+        let accent = self.get_accent(index as usize);
+        let shape_properties = series.get_shape_properties();
+        let fill = self.get_shape_fill(shape_properties);
+        let line = self.get_shape_line(shape_properties);
+        let fill_color = fill.flatten().or(line.flatten()).unwrap_or(accent);
+        let line_color = line.flatten().or(fill.flatten()).unwrap_or(accent);
+        let line_width = shape_properties
+            .and_then(|v| v.get_outline())
+            .map(|v| *v.get_width() as f64 / 12700.0)
+            .filter(|v| *v > 0.0)
+            .unwrap_or(2.25);
+
+        let mut values = series
+            .get_values()
+            .map(|v| self.get_number_list(v.get_number_reference()))
+            .unwrap_or_default();
+        if let Some(v) = series.get_y_values() {
+            values = self.get_number_list(v.get_number_reference());
+        }
+        let x_values = series
+            .get_x_values()
+            .map(|v| self.get_number_list(v.get_number_reference()))
+            .unwrap_or_default();
+        if matches!(self.display_blanks_as, DisplayBlanksAsValues::Zero) {
+            values.iter_mut().for_each(|v| *v = Some(v.unwrap_or(0.0)));
+        }
+
+        SeriesData {
+            index,
+            name: series
+                .get_series_text()
+                .map(|v| v.get_value().to_string())
+                .filter(|v| !v.is_empty())
+                .unwrap_or_else(|| format!("Series{}", index + 1)),
+            fill: fill_color,
+            line: line_color,
+            has_fill: !matches!(fill, Some(None)),
+            has_line: !matches!(line, Some(None)),
+            outline: line.flatten(),
+            line_width: line_width.clamp(0.5, 10.0),
+            marker: series.get_marker().map(|v| {
+                v.get_symbol()
+                    .map_or(MarkerStyleValues::Auto, |s| s.get_val().clone())
+            }),
+            smooth: series.get_smooth().is_some_and(|v| *v.get_val()),
+            explosion: series
+                .get_explosion()
+                .map_or(0.0, |v| *v.get_val() as f64 / 100.0),
+            categories: series
+                .get_category_axis_data()
+                .map(|v| self.get_string_list(v))
+                .unwrap_or_default(),
+            values,
+            x_values,
+            sizes: series
+                .get_bubble_size()
+                .map(|v| self.get_number_list(v.get_number_reference()))
+                .unwrap_or_default(),
+        }
+    }
+
+    pub(crate) fn is_span_blanks(&self) -> bool {
+        matches!(self.display_blanks_as, DisplayBlanksAsValues::Span)
+    }
+
+    /// Cells referenced by a formula, `None` when the worksheet is not available.
+    fn get_cell_list(&self, formula: &Formula) -> Option<Vec<Option<&'a Cell>>> {
+        let address = formula.get_address();
+        let range = address.get_range().get_range();
+        if address.get_sheet_name().is_empty() || range.is_empty() {
+            return None;
+        }
+        let worksheet = self
+            .spreadsheet
+            .get_sheet_collection_no_check()
+            .iter()
+            .find(|v| v.get_name() == address.get_sheet_name() && v.is_deserialized())?;
+        Some(
+            get_coordinate_list(&range)
+                .into_iter()
+                .map(|coordinate| worksheet.get_cell(coordinate))
+                .collect(),
+        )
+    }
+
+    fn get_number_list(&self, value: &NumberReference) -> Vec<Option<f64>> {
+        if let Some(list) = self.get_cell_list(value.get_formula()) {
+            return list
+                .into_iter()
+                .map(|v| v.and_then(|cell| cell.get_value_number()))
+                .collect();
+        }
+        value
+            .get_numbering_cache()
+            .get_point_list()
+            .iter()
+            .map(|v| v.get_numeric_value().get_text().parse::<f64>().ok())
+            .collect()
+    }
+
+    fn get_string_list(&self, value: &CategoryAxisData) -> Vec<String> {
+        if let Some(reference) = value.get_string_reference() {
+            if let Some(list) = self.get_cell_list(reference.get_formula()) {
+                let date1904 = self.spreadsheet.get_date1904();
+                return list
+                    .into_iter()
+                    .map(|v| {
+                        v.map(|cell| cell.get_formatted_value_crate(date1904))
+                            .unwrap_or_default()
+                    })
+                    .collect();
+            }
+            return reference
+                .get_string_cache()
+                .get_point_list()
+                .iter()
+                .map(|v| v.get_numeric_value().get_text().to_string())
+                .collect();
+        }
+        if let Some(literal) = value.get_string_literal() {
+            return literal
+                .get_string_point_list()
+                .iter()
+                .map(|v| v.get_numeric_value().get_text().to_string())
+                .collect();
+        }
+        Vec::new()
+    }
+}
+
+/// Text of a title, joined line by line.
+pub(crate) fn get_title_text(title: &Title) -> Option<String> {
+    let text = title.get_chart_text()?;
+    let lines: Vec<String> = text
+        .get_rich_text()
+        .get_paragraph()
+        .iter()
+        .map(|paragraph| {
+            paragraph
+                .get_run()
+                .iter()
+                .map(|run| run.get_text())
+                .collect::<String>()
+        })
+        .collect();
+    Some(lines.join(" "))
+}
+
+/// Lay out a chart into shapes.
+pub(crate) fn make_scene(
+    chart: &Chart,
+    spreadsheet: &Spreadsheet,
+    width: u32,
+    height: u32,
+) -> Scene {
+    let width = width.max(1) as f64;
+    let height = height.max(1) as f64;
+    let mut scene = Scene {
+        width,
+        height,
+        shapes: Vec::new(),
+    };
+    let context = Context::new(spreadsheet, chart);
+    let chart_data = chart.get_chart_space().get_chart();
+
+    // background
+    let background = context
+        .get_shape_fill(chart.get_chart_space().get_shape_properties())
+        .unwrap_or(Some(Rgb::WHITE));
+    scene.push(Shape::Rect {
+        x: 0.5,
+        y: 0.5,
+        width: width - 1.0,
+        height: height - 1.0,
+        fill: background,
+        stroke: Some(Stroke::new(Rgb::LINE, 1.0)),
+    });
+
+    let plot = plot::Plot::new(&context, chart_data.get_plot_area());
+    let mut area = Area::new(MARGIN, MARGIN, width - MARGIN * 2.0, height - MARGIN * 2.0);
+
+    // title
+    let title = match chart_data.get_title() {
+        Some(v) => get_title_text(v).or_else(|| plot.get_auto_title()),
+        None if !*chart_data.get_auto_title_deleted().get_val() => plot.get_auto_title(),
+        None => None,
+    };
+    if let Some(text) = title.filter(|v| !v.is_empty()) {
+        let line_height = TITLE_SIZE * 1.4;
+        scene.text(
+            width / 2.0,
+            area.y + line_height / 2.0,
+            text,
+            TITLE_SIZE,
+            TextAnchor::Middle,
+            false,
+        );
+        area = Area::new(
+            area.x,
+            area.y + line_height + 4.0,
+            area.width,
+            area.height - line_height - 4.0,
+        );
+    }
+
+    // legend
+    let entries = plot.get_legend_entries();
+    if !entries.is_empty() {
+        let position = chart_data.get_legend().get_legend_position().get_val();
+        area = draw_legend(&mut scene, &entries, position, area);
+    }
+
+    plot.draw(&mut scene, area);
+    scene
+}
+
+/// Draw the legend and return the area left for the plot.
+fn draw_legend(
+    scene: &mut Scene,
+    entries: &[LegendEntry],
+    position: &LegendPositionValues,
+    area: Area,
+) -> Area {
+    let size = LABEL_SIZE;
+    let line_height = size * 1.6;
+    let swatch = size * 0.8;
+    let entry_width = |entry: &LegendEntry| swatch + 6.0 + text_width(&entry.text, size);
+
+    let draw_entry = |scene: &mut Scene, entry: &LegendEntry, x: f64, y: f64| {
+        match entry.kind {
+            LegendKind::Box => scene.push(Shape::Rect {
+                x,
+                y: y - swatch / 2.0,
+                width: swatch,
+                height: swatch,
+                fill: Some(entry.color),
+                stroke: None,
+            }),
+            LegendKind::Line => scene.push(Shape::Line {
+                points: vec![(x, y), (x + swatch, y)],
+                stroke: Stroke::new(entry.color, 2.25),
+            }),
+        }
+        scene.text(
+            x + swatch + 6.0,
+            y,
+            entry.text.clone(),
+            size,
+            TextAnchor::Start,
+            false,
+        );
+    };
+
+    match position {
+        LegendPositionValues::Top | LegendPositionValues::Bottom => {
+            // entries in rows, centered
+            let mut rows: Vec<(f64, Vec<&LegendEntry>)> = vec![(0.0, Vec::new())];
+            for entry in entries {
+                let w = entry_width(entry) + 12.0;
+                let row = rows.last_mut().unwrap();
+                if !row.1.is_empty() && row.0 + w > area.width {
+                    rows.push((w, vec![entry]));
+                } else {
+                    row.0 += w;
+                    row.1.push(entry);
+                }
+            }
+            let legend_height = rows.len() as f64 * line_height;
+            let top = if matches!(position, LegendPositionValues::Top) {
+                area.y
+            } else {
+                area.bottom() - legend_height
+            };
+            for (i, (row_width, row)) in rows.iter().enumerate() {
+                let mut x = area.x + (area.width - row_width + 12.0) / 2.0;
+                let y = top + line_height * (i as f64 + 0.5);
+                for entry in row {
+                    draw_entry(scene, entry, x, y);
+                    x += entry_width(entry) + 12.0;
+                }
+            }
+            let y = if matches!(position, LegendPositionValues::Top) {
+                area.y + legend_height + 4.0
+            } else {
+                area.y
+            };
+            Area::new(area.x, y, area.width, area.height - legend_height - 4.0)
+        }
+        _ => {
+            // entries in a column
+            let legend_width = entries
+                .iter()
+                .map(entry_width)
+                .fold(0.0, f64::max)
+                .min(area.width / 2.0);
+            let legend_height = entries.len() as f64 * line_height;
+            let left = if matches!(position, LegendPositionValues::Left) {
+                area.x
+            } else {
+                area.right() - legend_width
+            };
+            let top = if matches!(position, LegendPositionValues::TopRight) {
+                area.y
+            } else {
+                area.y + (area.height - legend_height).max(0.0) / 2.0
+            };
+            for (i, entry) in entries.iter().enumerate() {
+                draw_entry(scene, entry, left, top + line_height * (i as f64 + 0.5));
+            }
+            let x = if matches!(position, LegendPositionValues::Left) {
+                area.x + legend_width + 8.0
+            } else {
+                area.x
+            };
+            Area::new(x, area.y, area.width - legend_width - 8.0, area.height)
+        }
+    }
+}
+
+/// Render a chart as an SVG document.
+pub(crate) fn render_svg(
+    chart: &Chart,
+    spreadsheet: &Spreadsheet,
+    width: u32,
+    height: u32,
+) -> String {
+    svg::write(&make_scene(chart, spreadsheet, width, height))
+}
+
+/// Render a chart as a PNG image.
+#[cfg(feature = "image")]
+pub(crate) fn render_png(
+    chart: &Chart,
+    spreadsheet: &Spreadsheet,
+    width: u32,
+    height: u32,
+) -> Result<Vec<u8>, XlsxError> {
+    let image = raster::rasterize(&make_scene(chart, spreadsheet, width, height));
+    let mut buffer: Vec<u8> = Vec::new();
+    image::DynamicImage::ImageRgba8(image)
+        .write_to(
+            &mut std::io::Cursor::new(&mut buffer),
+            image::ImageFormat::Png,
+        )
+        .map_err(std::io::Error::other)?;
+    Ok(buffer)
+}
@@ -0,0 +1,1975 @@
+use super::*;
+use crate::helper::number_format::to_formatted_string;
+use crate::structs::drawing::charts::AreaChartSeriesList;
+use crate::structs::drawing::charts::AxisId;
+use crate::structs::drawing::charts::AxisPositionValues;
+use crate::structs::drawing::charts::BarDirectionValues;
+use crate::structs::drawing::charts::CrossBetweenValues;
+use crate::structs::drawing::charts::GroupingValues;
+use crate::structs::drawing::charts::OrientationValues;
+use crate::structs::drawing::charts::PlotArea;
+use crate::structs::drawing::charts::RadarStyleValues;
+use crate::structs::drawing::charts::Scaling;
+use crate::structs::drawing::charts::ScatterStyleValues;
+use crate::structs::drawing::charts::ValueAxis;
+use std::f64::consts::PI;
+
+#[derive(Clone, Debug)]
+enum GroupKind {
+    Bar {
+        horizontal: bool,
+        gap_width: f64,
+        overlap: f64,
+    },
+    Line {
+        show_marker: bool,
+    },
+    Area,
+    Stock {
+        high_low_lines: bool,
+        up_down_bars: bool,
+    },
+    Scatter {
+        line: bool,
+        marker: bool,
+        smooth: bool,
+    },
+    Bubble {
+        scale: f64,
+    },
+    Pie {
+        first_slice_angle: f64,
+        hole_size: f64,
+    },
+    Radar {
+        filled: bool,
+        marker: bool,
+    },
+    Surface {
+        wireframe: bool,
+    },
+}
+
+/// A chart group with its resolved series.
+#[derive(Clone, Debug)]
+struct Group {
+    kind: GroupKind,
+    grouping: GroupingValues,
+    vary_colors: bool,
+    axis_ids: Vec<u32>,
+    series: Vec<SeriesData>,
+}
+impl Group {
+    fn is_category(&self) -> bool {
+        matches!(
+            self.kind,
+            GroupKind::Bar { .. }
+                | GroupKind::Line { .. }
+                | GroupKind::Area
+                | GroupKind::Stock { .. }
+        )
+    }
+
+    fn is_xy(&self) -> bool {
+        matches!(
+            self.kind,
+            GroupKind::Scatter { .. } | GroupKind::Bubble { .. }
+        )
+    }
+
+    fn is_bar(&self) -> bool {
+        matches!(self.kind, GroupKind::Bar { .. })
+    }
+
+    fn point_count(&self) -> usize {
+        self.series
+            .iter()
+            .map(|v| v.values.len())
+            .max()
+            .unwrap_or(0)
+    }
+
+    /// Bottom and top of each point after stacking, in axis units.
+    fn get_stacked_values(&self) -> Vec<Vec<Option<(f64, f64)>>> {
+        let count = self.point_count();
+        let mut totals = vec![0.0; count];
+        if matches!(self.grouping, GroupingValues::PercentStacked) {
+            for series in &self.series {
+                for (i, value) in series.values.iter().enumerate() {
+                    totals[i] += value.unwrap_or(0.0).abs();
+                }
+            }
+        }
+        let mut positive = vec![0.0; count];
+        let mut negative = vec![0.0; count];
+        let mut result = Vec::new();
+        for series in &self.series {
+            let mut list = Vec::new();
+            for (i, value) in series.values.iter().enumerate() {
+                let value = match value {
+                    Some(v) => *v,
+                    None => {
+                        list.push(None);
+                        continue;
+                    }
+                };
+                let range = match self.grouping {
+                    GroupingValues::Clustered | GroupingValues::Standard => (0.0, value),
+                    GroupingValues::Stacked | GroupingValues::PercentStacked => {
+                        let value = if matches!(self.grouping, GroupingValues::PercentStacked) {
+                            if totals[i] == 0.0 {
+                                0.0
+                            } else {
+                                value / totals[i]
+                            }
+                        } else {
+                            value
+                        };
+                        let base = if value < 0.0 {
+                            &mut negative[i]
+                        } else {
+                            &mut positive[i]
+                        };
+                        let bottom = *base;
+                        *base += value;
+                        (bottom, *base)
+                    }
+                };
+                list.push(Some(range));
+            }
+            result.push(list);
+        }
+        result
+    }
+}
+
+/// A value axis scale.
+#[derive(Clone, Debug)]
+pub(crate) struct Scale {
+    min: f64,
+    max: f64,
+    major: f64,
+    log_base: Option<f64>,
+    reversed: bool,
+    format: String,
+}
+impl Scale {
+    /// Scale showing the data between `data_min` and `data_max`.
+    /// `with_zero` always shows zero, as bars and areas start from it.
+    fn new(
+        data_min: f64,
+        data_max: f64,
+        with_zero: bool,
+        scaling: Option<&Scaling>,
+        major_unit: Option<f64>,
+        format: &str,
+    ) -> Self {
+        let (mut min, mut max) = if data_min <= data_max {
+            (data_min, data_max)
+        } else {
+            (0.0, 1.0)
+        };
+        let fixed_min = scaling
+            .and_then(|v| v.get_min_axis_value())
+            .map(|v| *v.get_val());
+        let fixed_max = scaling
+            .and_then(|v| v.get_max_axis_value())
+            .map(|v| *v.get_val());
+        let log_base = scaling
+            .and_then(|v| v.get_log_base())
+            .map(|v| *v.get_val())
+            .filter(|v| *v > 1.0);
+        let reversed = scaling
+            .is_some_and(|v| matches!(v.get_orientation().get_val(), OrientationValues::MaxMin));
+        let format = format.to_string();
+
+        if let Some(base) = log_base {
+            let min = fixed_min
+                .filter(|v| *v > 0.0)
+                .unwrap_or_else(|| base.powf(min.max(f64::MIN_POSITIVE).log(base).floor()));
+            let mut max = fixed_max
+                .filter(|v| *v > min)
+                .unwrap_or_else(|| base.powf(max.max(min).log(base).ceil()));
+            if max <= min {
+                max = min * base;
+            }
+            return Self {
+                min,
+                max,
+                major: base,
+                log_base,
+                reversed,
+                format,
+            };
+        }
+
+        // zero is shown unless the values are close together
+        if with_zero || (min >= 0.0 && min < max * 5.0 / 6.0) || min == max {
+            min = min.min(0.0);
+        }
+        if with_zero || (max <= 0.0 && max > min * 5.0 / 6.0) {
+            max = max.max(0.0);
+        }
+        if let Some(v) = fixed_min {
+            min = v;
+        }
+        if let Some(v) = fixed_max {
+            max = v;
+        }
+        if max <= min {
+            max = min + 1.0;
+        }
+        let major = major_unit
+            .filter(|v| *v > 0.0 && (max - min) / v <= 1000.0)
+            .unwrap_or_else(|| get_nice_step((max - min) / 6.0));
+        if fixed_min.is_none() {
+            min = (min / major).floor() * major;
+        }
+        if fixed_max.is_none() {
+            max = (max / major).ceil() * major;
+        }
+        if max <= min {
+            max = min + major;
+        }
+        Self {
+            min,
+            max,
+            major,
+            log_base,
+            reversed,
+            format,
+        }
+    }
+
+    fn from_axis(data_min: f64, data_max: f64, with_zero: bool, axis: Option<&ValueAxis>) -> Self {
+        Self::new(
+            data_min,
+            data_max,
+            with_zero,
+            axis.map(|v| v.get_scaling()),
+            axis.and_then(|v| v.get_major_unit()).map(|v| *v.get_val()),
+            axis.map_or("General", |v| v.get_numbering_format().get_format_code()),
+        )
+    }
+
+    /// Position of a value between 0 (start of the axis) and 1 (end of the axis).
+    pub(crate) fn ratio(&self, value: f64) -> f64 {
+        let ratio = match self.log_base {
+            Some(_) => {
+                if value <= 0.0 {
+                    0.0
+                } else {
+                    (value.ln() - self.min.ln()) / (self.max.ln() - self.min.ln())
+                }
+            }
+            None => (value - self.min) / (self.max - self.min),
+        };
+        if self.reversed {
+            1.0 - ratio
+        } else {
+            ratio
+        }
+    }
+
+    /// Position of the value where bars and areas start.
+    fn base_ratio(&self) -> f64 {
+        let base = match self.log_base {
+            Some(_) => self.min,
+            None => 0.0f64.clamp(self.min, self.max),
+        };
+        self.ratio(base)
+    }
+
+    pub(crate) fn get_ticks(&self) -> Vec<f64> {
+        let mut result = Vec::new();
+        match self.log_base {
+            Some(base) => {
+                let mut value = self.min;
+                while value <= self.max * (1.0 + 1e-9) && result.len() < 1000 {
+                    result.push(value);
+                    value *= base;
+                }
+            }
+            None => {
+                let count = ((self.max - self.min) / self.major + 1e-9).floor() as usize;
+                for i in 0..=count.min(1000) {
+                    result.push(self.min + self.major * i as f64);
+                }
+            }
+        }
+        result
+    }
+
+    pub(crate) fn get_label(&self, value: f64) -> String {
+        let format = if self.format.is_empty() {
+            "General"
+        } else {
+            &self.format
+        };
+        to_formatted_string(get_number_string(value), format)
+    }
+}
+
+/// Step of 1, 2 or 5 times a power of ten that is not smaller than `value`.
+fn get_nice_step(value: f64) -> f64 {
+    if value <= 0.0 || !value.is_finite() {
+        return 1.0;
+    }
+    let magnitude = 10f64.powf(value.log10().floor());
+    let normalized = value / magnitude;
+    let step = if normalized <= 1.0 {
+        1.0
+    } else if normalized <= 2.0 {
+        2.0
+    } else if normalized <= 5.0 {
+        5.0
+    } else {
+        10.0
+    };
+    step * magnitude
+}
+
+/// Number as text without floating point noise.
+fn get_number_string(value: f64) -> String {
+    let value = (value * 1e9).round() / 1e9;
+    if value == 0.0 {
+        return String::from("0");
+    }
+    value.to_string()
+}
+
+/// Maps categories and values to the plot area.
+struct Frame {
+    area: Area,
+    horizontal: bool,
+    count: usize,
+    between: bool,
+    reversed: bool,
+}
+impl Frame {
+    /// Center of a category between 0 and 1.
+    fn category(&self, index: usize) -> f64 {
+        if self.between {
+            (index as f64 + 0.5) / self.count.max(1) as f64
+        } else if self.count <= 1 {
+            0.5
+        } else {
+            index as f64 / (self.count - 1) as f64
+        }
+    }
+
+    /// Width of a category between 0 and 1.
+    fn slot(&self) -> f64 {
+        if self.between {
+            1.0 / self.count.max(1) as f64
+        } else {
+            1.0 / self.count.max(2).saturating_sub(1) as f64
+        }
+    }
+
+    /// Pixel position of a category position and a value position.
+    fn point(&self, category: f64, value: f64) -> (f64, f64) {
+        let category = if self.reversed {
+            1.0 - category
+        } else {
+            category
+        };
+        if self.horizontal {
+            (
+                self.area.x + value * self.area.width,
+                self.area.bottom() - category * self.area.height,
+            )
+        } else {
+            (
+                self.area.x + category * self.area.width,
+                self.area.bottom() - value * self.area.height,
+            )
+        }
+    }
+}
+
+/// Smooth a line with Catmull-Rom splines.
+fn get_smooth_points(points: &[(f64, f64)]) -> Vec<(f64, f64)> {
+    if points.len() < 3 {
+        return points.to_vec();
+    }
+    let mut result = vec![points[0]];
+    for i in 0..points.len() - 1 {
+        let p0 = points[i.saturating_sub(1)];
+        let p1 = points[i];
+        let p2 = points[i + 1];
+        let p3 = points[(i + 2).min(points.len() - 1)];
+        for step in 1..=8 {
+            let t = step as f64 / 8.0;
+            let t2 = t * t;
+            let t3 = t2 * t;
+            let f = |a: f64, b: f64, c: f64, d: f64| {
+                0.5 * (2.0 * b
+                    + (c - a) * t
+                    + (2.0 * a - 5.0 * b + 4.0 * c - d) * t2
+                    + (3.0 * b - a - 3.0 * c + d) * t3)
+            };
+            result.push((f(p0.0, p1.0, p2.0, p3.0), f(p0.1, p1.1, p2.1, p3.1)));
+        }
+    }
+    result
+}
+
+/// Point of a circle, the angle is in degrees clockwise from the top.
+fn get_arc_point(x: f64, y: f64, radius: f64, angle: f64) -> (f64, f64) {
+    let radian = angle * PI / 180.0;
+    (x + radius * radian.sin(), y - radius * radian.cos())
+}
+
+fn get_arc(x: f64, y: f64, radius: f64, start: f64, sweep: f64) -> Vec<(f64, f64)> {
+    let count = (sweep.abs() / 2.0).ceil().max(1.0) as usize;
+    (0..=count)
+        .map(|i| get_arc_point(x, y, radius, start + sweep * i as f64 / count as f64))
+        .collect()
+}
+
+fn draw_marker(scene: &mut Scene, symbol: &MarkerStyleValues, x: f64, y: f64, color: Rgb) {
+    let size = 3.5;
+    let fill = Some(color);
+    match symbol {
+        MarkerStyleValues::None => {}
+        MarkerStyleValues::Square => scene.push(Shape::Rect {
+            x: x - size,
+            y: y - size,
+            width: size * 2.0,
+            height: size * 2.0,
+            fill,
+            stroke: None,
+        }),
+        MarkerStyleValues::Diamond => scene.push(Shape::Polygon {
+            points: vec![
+                (x, y - size * 1.3),
+                (x + size * 1.3, y),
+                (x, y + size * 1.3),
+                (x - size * 1.3, y),
+            ],
+            fill,
+            stroke: None,
+        }),
+        MarkerStyleValues::Triangle => scene.push(Shape::Polygon {
+            points: vec![
+                (x, y - size * 1.3),
+                (x + size * 1.2, y + size),
+                (x - size * 1.2, y + size),
+            ],
+            fill,
+            stroke: None,
+        }),
+        MarkerStyleValues::Dash => scene.push(Shape::Line {
+            points: vec![(x - size, y), (x + size, y)],
+            stroke: Stroke::new(color, 2.0),
+        }),
+        MarkerStyleValues::X | MarkerStyleValues::Plus | MarkerStyleValues::Star => {
+            let lines = match symbol {
+                MarkerStyleValues::X => vec![(1.0, 1.0), (1.0, -1.0)],
+                MarkerStyleValues::Plus => vec![(1.0, 0.0), (0.0, 1.0)],
+                _ => vec![(1.0, 1.0), (1.0, -1.0), (0.0, 1.0)],
+            };
+            for (dx, dy) in lines {
+                scene.push(Shape::Line {
+                    points: vec![
+                        (x - dx * size, y - dy * size),
+                        (x + dx * size, y + dy * size),
+                    ],
+                    stroke: Stroke::new(color, 1.5),
+                });
+            }
+        }
+        MarkerStyleValues::Dot => scene.push(Shape::Circle {
+            x,
+            y,
+            radius: size / 2.0,
+            fill,
+            stroke: None,
+        }),
+        _ => scene.push(Shape::Circle {
+            x,
+            y,
+            radius: size,
+            fill,
+            stroke: None,
+        }),
+    }
+}
+
+/// Marker symbol of a series, automatic markers change with the series.
+fn get_marker_symbol(series: &SeriesData) -> MarkerStyleValues {
+    const AUTO: [MarkerStyleValues; 4] = [
+        MarkerStyleValues::Diamond,
+        MarkerStyleValues::Square,
+        MarkerStyleValues::Triangle,
+        MarkerStyleValues::Circle,
+    ];
+    match &series.marker {
+        None | Some(MarkerStyleValues::Auto) => AUTO[series.index as usize % AUTO.len()].clone(),
+        Some(v) => v.clone(),
+    }
+}
+
+/// Split a line at missing points.
+fn get_line_segments(points: &[Option<(f64, f64)>], span: bool) -> Vec<Vec<(f64, f64)>> {
+    let mut result: Vec<Vec<(f64, f64)>> = vec![Vec::new()];
+    for point in points {
+        match point {
+            Some(v) => result.last_mut().unwrap().push(*v),
+            None if !span && !result.last().unwrap().is_empty() => result.push(Vec::new()),
+            None => {}
+        }
+    }
+    result.retain(|v| !v.is_empty());
+    result
+}
+
+fn get_value_range<'b, I: IntoIterator<Item = &'b Option<f64>>>(values: I) -> Option<(f64, f64)> {
+    values
+        .into_iter()
+        .flatten()
+        .filter(|v| v.is_finite())
+        .fold(None, |range, v| match range {
+            None => Some((*v, *v)),
+            Some((min, max)) => Some((min.min(*v), max.max(*v))),
+        })
+}
+
+fn merge_range(a: Option<(f64, f64)>, b: Option<(f64, f64)>) -> Option<(f64, f64)> {
+    match (a, b) {
+        (Some(a), Some(b)) => Some((a.0.min(b.0), a.1.max(b.1))),
+        (a, None) => a,
+        (None, b) => b,
+    }
+}
+
+/// Axes and scales of a chart drawn on a category or value axis.
+struct Axes<'b> {
+    horizontal: bool,
+    xy: bool,
+    count: usize,
+    categories: Vec<String>,
+    between: bool,
+    category_reversed: bool,
+    x_scale: Option<Scale>,
+    x_axis: Option<&'b ValueAxis>,
+    primary: Option<(Scale, Option<&'b ValueAxis>)>,
+    secondary: Option<(Scale, Option<&'b ValueAxis>)>,
+}
+
+/// The plot area of a chart, ready to be drawn.
+pub(crate) struct Plot<'a> {
+    context: &'a Context<'a>,
+    plot_area: &'a PlotArea,
+    groups: Vec<Group>,
+}
+impl<'a> Plot<'a> {
+    pub(crate) fn new(context: &'a Context<'a>, plot_area: &'a PlotArea) -> Self {
+        let mut plot = Self {
+            context,
+            plot_area,
+            groups: Vec::new(),
+        };
+        let standard = GroupingValues::Standard;
+        for v in plot_area.get_bar_chart_collection() {
+            plot.add_group(
+                GroupKind::Bar {
+                    horizontal: matches!(v.get_bar_direction().get_val(), BarDirectionValues::Bar),
+                    gap_width: *v.get_gap_width().get_val() as f64,
+                    overlap: *v.get_overlap().get_val() as f64,
+                },
+                v.get_grouping().get_val(),
+                *v.get_vary_colors().get_val(),
+                v.get_axis_id(),
+                v.get_area_chart_series_list(),
+            );
+        }
+        for v in plot_area.get_bar_3d_chart_collection() {
+            plot.add_group(
+                GroupKind::Bar {
+                    horizontal: matches!(v.get_bar_direction().get_val(), BarDirectionValues::Bar),
+                    gap_width: *v.get_gap_width().get_val() as f64,
+                    overlap: 0.0,
+                },
+                v.get_grouping().get_val(),
+                *v.get_vary_colors().get_val(),
+                v.get_axis_id(),
+                v.get_area_chart_series_list(),
+            );
+        }
+        for v in plot_area.get_area_chart_collection() {
+            plot.add_group(
+                GroupKind::Area,
+                v.get_grouping().get_val(),
+                *v.get_vary_colors().get_val(),
+                v.get_axis_id(),
+                v.get_area_chart_series_list(),
+            );
+        }
+        for v in plot_area.get_area_3d_chart_collection() {
+            plot.add_group(
+                GroupKind::Area,
+                v.get_grouping().get_val(),
+                *v.get_vary_colors().get_val(),
+                v.get_axis_id(),
+                v.get_area_chart_series_list(),
+            );
+        }
+        for v in plot_area.get_line_chart_collection() {
+            plot.add_group(
+                GroupKind::Line {
+                    show_marker: *v.get_show_marker().get_val(),
+                },
+                v.get_grouping().get_val(),
+                *v.get_vary_colors().get_val(),
+                v.get_axis_id(),
+                v.get_area_chart_series_list(),
+            );
+        }
+        for v in plot_area.get_line_3d_chart_collection() {
+            plot.add_group(
+                GroupKind::Line { show_marker: false },
+                v.get_grouping().get_val(),
+                *v.get_vary_colors().get_val(),
+                v.get_axis_id(),
+                v.get_area_chart_series_list(),
+            );
+        }
+        for v in plot_area.get_stock_chart_collection() {
+            plot.add_group(
+                GroupKind::Stock {
+                    high_low_lines: v.get_high_low_lines().is_some(),
+                    up_down_bars: v.get_up_down_bars().is_some(),
+                },
+                &standard,
+                false,
+                v.get_axis_id(),
+                v.get_area_chart_series_list(),
+            );
+        }
+        for v in plot_area.get_scatter_chart_collection() {
+            let style = v.get_scatter_style().get_val();
+            plot.add_group(
+                GroupKind::Scatter {
+                    line: !matches!(style, ScatterStyleValues::Marker),
+                    marker: matches!(
+                        style,
+                        ScatterStyleValues::LineMarker
+                            | ScatterStyleValues::Marker
+                            | ScatterStyleValues::SmoothMarker
+                    ),
+                    smooth: matches!(
+                        style,
+                        ScatterStyleValues::Smooth | ScatterStyleValues::SmoothMarker
+                    ),
+                },
+                &standard,
+                *v.get_vary_colors().get_val(),
+                v.get_axis_id(),
+                v.get_area_chart_series_list(),
+            );
+        }
+        for v in plot_area.get_bubble_chart_collection() {
+            plot.add_group(
+                GroupKind::Bubble {
+                    scale: *v.get_bubble_scale().get_val() as f64,
+                },
+                &standard,
+                *v.get_vary_colors().get_val(),
+                v.get_axis_id(),
+                v.get_area_chart_series_list(),
+            );
+        }
+        for v in plot_area.get_radar_chart_collection() {
+            let style = v.get_radar_style().get_val();
+            plot.add_group(
+                GroupKind::Radar {
+                    filled: matches!(style, RadarStyleValues::Filled),
+                    marker: matches!(style, RadarStyleValues::Marker),
+                },
+                &standard,
+                *v.get_vary_colors().get_val(),
+                v.get_axis_id(),
+                v.get_area_chart_series_list(),
+            );
+        }
+        for v in plot_area.get_surface_chart_collection() {
+            plot.add_group(
+                GroupKind::Surface {
+                    wireframe: v.get_wireframe().is_some_and(|w| *w.get_val()),
+                },
+                &standard,
+                false,
+                v.get_axis_id(),
+                v.get_area_chart_series_list(),
+            );
+        }
+        for v in plot_area.get_surface_3d_chart_collection() {
+            plot.add_group(
+                GroupKind::Surface {
+                    wireframe: v.get_wireframe().is_some_and(|w| *w.get_val()),
+                },
+                &standard,
+                false,
+                v.get_axis_id(),
+                v.get_area_chart_series_list(),
+            );
+        }
+        for v in plot_area.get_pie_chart_collection() {
+            plot.add_pie_group(
+                *v.get_first_slice_angle().get_val() as f64,
+                0.0,
+                *v.get_vary_colors().get_val(),
+                v.get_area_chart_series_list(),
+            );
+        }
+        for v in plot_area.get_pie_3d_chart_collection() {
+            plot.add_pie_group(
+                0.0,
+                0.0,
+                *v.get_vary_colors().get_val(),
+                v.get_area_chart_series_list(),
+            );
+        }
+        for v in plot_area.get_of_pie_chart_collection() {
+            plot.add_pie_group(
+                0.0,
+                0.0,
+                *v.get_vary_colors().get_val(),
+                v.get_area_chart_series_list(),
+            );
+        }
+        for v in plot_area.get_doughnut_chart_collection() {
+            plot.add_pie_group(
+                *v.get_first_slice_angle().get_val() as f64,
+                *v.get_hole_size().get_val() as f64 / 100.0,
+                *v.get_vary_colors().get_val(),
+                v.get_area_chart_series_list(),
+            );
+        }
+        plot
+    }
+
+    fn add_group(
+        &mut self,
+        kind: GroupKind,
+        grouping: &GroupingValues,
+        vary_colors: bool,
+        axis_ids: &[AxisId],
+        series_list: &AreaChartSeriesList,
+    ) {
+        let mut series_list: Vec<_> = series_list.get_area_chart_series().iter().collect();
+        series_list.sort_by_key(|v| *v.get_order().get_val());
+        self.groups.push(Group {
+            kind,
+            grouping: grouping.clone(),
+            vary_colors,
+            axis_ids: axis_ids.iter().map(|v| *v.get_val()).collect(),
+            series: series_list
+                .into_iter()
+                .map(|v| self.context.get_series_data(v))
+                .collect(),
+        });
+    }
+
+    fn add_pie_group(
+        &mut self,
+        first_slice_angle: f64,
+        hole_size: f64,
+        vary_colors: bool,
+        series_list: &AreaChartSeriesList,
+    ) {
+        self.add_group(
+            GroupKind::Pie {
+                first_slice_angle,
+                hole_size,
+            },
+            &GroupingValues::Standard,
+            vary_colors,
+            &[],
+            series_list,
+        );
+    }
+
+    /// Groups drawn together: charts on axes, or else radar, surface or pie charts.
+    fn get_drawn_groups(&self) -> Vec<&Group> {
+        let filters: [fn(&Group) -> bool; 4] = [
+            |v| v.is_category() || v.is_xy(),
+            |v| matches!(v.kind, GroupKind::Radar { .. }),
+            |v| matches!(v.kind, GroupKind::Surface { .. }),
+            |v| matches!(v.kind, GroupKind::Pie { .. }),
+        ];
+        for filter in filters {
+            let list: Vec<&Group> = self.groups.iter().filter(|v| filter(v)).collect();
+            if !list.is_empty() {
+                return list;
+            }
+        }
+        Vec::new()
+    }
+
+    /// Title shown when the chart has an automatic title: the name of a single series.
+    pub(crate) fn get_auto_title(&self) -> Option<String> {
+        let mut series = self.groups.iter().flat_map(|v| v.series.iter());
+        match (series.next(), series.next()) {
+            (Some(v), None) => Some(v.name.clone()),
+            _ => None,
+        }
+    }
+
+    fn get_point_color(&self, group: &Group, series: &SeriesData, index: usize) -> Rgb {
+        if group.vary_colors {
+            return self.context.get_accent(index);
+        }
+        series.fill
+    }
+
+    pub(crate) fn get_legend_entries(&self) -> Vec<LegendEntry> {
+        let groups = self.get_drawn_groups();
+        let first = match groups.first() {
+            Some(v) => *v,
+            None => return Vec::new(),
+        };
+        match first.kind {
+            GroupKind::Pie { .. } if first.vary_colors => {
+                let series = match first.series.first() {
+                    Some(v) => v,
+                    None => return Vec::new(),
+                };
+                (0..series.values.len())
+                    .map(|i| LegendEntry {
+                        text: series
+                            .categories
+                            .get(i)
+                            .cloned()
+                            .unwrap_or_else(|| (i + 1).to_string()),
+                        color: self.get_point_color(first, series, i),
+                        kind: LegendKind::Box,
+                    })
+                    .collect()
+            }
+            GroupKind::Surface { .. } => {
+                let scale = self.get_surface_scale(first);
+                let ticks = scale.get_ticks();
+                ticks
+                    .windows(2)
+                    .enumerate()
+                    .map(|(i, v)| LegendEntry {
+                        text: format!("{}-{}", scale.get_label(v[0]), scale.get_label(v[1])),
+                        color: self.context.get_accent(i),
+                        kind: LegendKind::Box,
+                    })
+                    .collect()
+            }
+            _ => {
+                let mut result = Vec::new();
+                for group in groups {
+                    for series in &group.series {
+                        let line = match group.kind {
+                            GroupKind::Line { .. } | GroupKind::Stock { .. } => true,
+                            GroupKind::Scatter { line, .. } => line && series.has_line,
+                            GroupKind::Radar { filled, .. } => !filled,
+                            _ => false,
+                        };
+                        result.push(LegendEntry {
+                            text: series.name.clone(),
+                            color: if line { series.line } else { series.fill },
+                            kind: if line {
+                                LegendKind::Line
+                            } else {
+                                LegendKind::Box
+                            },
+                        });
+                    }
+                }
+                result
+            }
+        }
+    }
+
+    pub(crate) fn draw(&self, scene: &mut Scene, area: Area) {
+        let groups = self.get_drawn_groups();
+        let first = match groups.first() {
+            Some(v) => *v,
+            None => return,
+        };
+        match first.kind {
+            GroupKind::Pie { .. } => self.draw_pie(scene, first, area),
+            GroupKind::Radar { .. } => self.draw_radar(scene, &groups, area),
+            GroupKind::Surface { .. } => self.draw_surface(scene, first, area),
+            _ => self.draw_axes_chart(scene, &groups, area),
+        }
+    }
+
+    fn get_value_axis(&self, group: &Group, vertical: bool) -> Option<&'a ValueAxis> {
+        let axis_list: Vec<&ValueAxis> = self
+            .plot_area
+            .get_value_axis()
+            .iter()
+            .filter(|v| group.axis_ids.contains(v.get_axis_id().get_val()))
+            .collect();
+        if !group.is_xy() {
+            return axis_list.first().copied();
+        }
+        axis_list.into_iter().find(|v| {
+            let position = v.get_axis_position().get_val();
+            let is_vertical = matches!(
+                position,
+                AxisPositionValues::Left | AxisPositionValues::Right
+            );
+            is_vertical == vertical
+        })
+    }
+
+    fn is_secondary(axis: Option<&ValueAxis>) -> bool {
+        axis.is_some_and(|v| {
+            matches!(
+                v.get_axis_position().get_val(),
+                AxisPositionValues::Right | AxisPositionValues::Top
+            )
+        })
+    }
+
+    fn get_axes(&self, groups: &[&Group]) -> Axes<'a> {
+        let horizontal = groups.iter().any(|v| {
+            matches!(
+                v.kind,
+                GroupKind::Bar {
+                    horizontal: true,
+                    ..
+                }
+            )
+        });
+        let xy = !groups.iter().any(|v| v.is_category());
+
+        // value ranges of each side
+        let mut ranges: [Option<(f64, f64)>; 2] = [None, None];
+        let mut with_zero = [false, false];
+        let mut axes: [Option<&ValueAxis>; 2] = [None, None];
+        let mut x_range: Option<(f64, f64)> = None;
+        let mut x_axis: Option<&ValueAxis> = None;
+        let mut count = 0;
+        for group in groups {
+            let axis = self.get_value_axis(group, true);
+            let side = usize::from(Self::is_secondary(axis));
+            if axes[side].is_none() {
+                axes[side] = axis;
+            }
+            let range = if group.is_xy() {
+                get_value_range(group.series.iter().flat_map(|v| v.values.iter()))
+            } else {
+                get_value_range(
+                    group
+                        .get_stacked_values()
+                        .iter()
+                        .flatten()
+                        .flat_map(|v| v.map(|(a, b)| [Some(a), Some(b)]).unwrap_or_default())
+                        .collect::<Vec<Option<f64>>>()
+                        .iter(),
+                )
+            };
+            ranges[side] = merge_range(ranges[side], range);
+            if matches!(group.kind, GroupKind::Bar { .. } | GroupKind::Area)
+                || matches!(group.grouping, GroupingValues::PercentStacked)
+            {
+                with_zero[side] = true;
+            }
+            count = count.max(group.point_count());
+            if group.is_xy() {
+                if x_axis.is_none() {
+                    x_axis = self.get_value_axis(group, false);
+                }
+                for series in &group.series {
+                    x_range =
+                        merge_range(x_range, get_value_range(Self::get_x_values(series).iter()));
+                }
+            }
+        }
+        let percent = groups
+            .iter()
+            .any(|v| v.is_category() && matches!(v.grouping, GroupingValues::PercentStacked));
+        let mut make_scale = |side: usize| {
+            let (min, max) = ranges[side]?;
+            let mut scale = Scale::from_axis(min, max, with_zero[side], axes[side]);
+            if percent {
+                scale.format = String::from("0%");
+            }
+            Some((scale, axes[side]))
+        };
+        let mut primary = make_scale(0);
+        let secondary = make_scale(1);
+        if primary.is_none() && secondary.is_none() {
+            primary = Some((Scale::from_axis(0.0, 1.0, true, axes[0]), axes[0]));
+        }
+
+        let category_axis_scaling = self
+            .plot_area
+            .get_category_axis()
+            .first()
+            .map(|v| v.get_scaling())
+            .or_else(|| {
+                self.plot_area
+                    .get_date_axis()
+                    .first()
+                    .map(|v| v.get_scaling())
+            });
+        let between = groups.iter().any(|v| v.is_bar())
+            || axes[0].is_none_or(|v| {
+                matches!(v.get_cross_between().get_val(), CrossBetweenValues::Between)
+            });
+        Axes {
+            horizontal,
+            xy,
+            count,
+            categories: groups
+                .iter()
+                .filter(|v| v.is_category())
+                .flat_map(|v| v.series.iter())
+                .map(|v| v.categories.clone())
+                .find(|v| !v.is_empty())
+                .unwrap_or_default(),
+            between,
+            category_reversed: category_axis_scaling.is_some_and(|v| {
+                matches!(v.get_orientation().get_val(), OrientationValues::MaxMin)
+            }),
+            x_scale: if xy {
+                let (min, max) = x_range.unwrap_or((1.0, count.max(1) as f64));
+                Some(Scale::from_axis(min, max, false, x_axis))
+            } else {
+                None
+            },
+            x_axis,
+            primary,
+            secondary,
+        }
+    }
+
+    /// X values of a scatter series, numbered from 1 when the series has none.
+    fn get_x_values(series: &SeriesData) -> Vec<Option<f64>> {
+        if series.x_values.iter().any(|v| v.is_some()) {
+            return series.x_values.clone();
+        }
+        (1..=series.values.len()).map(|v| Some(v as f64)).collect()
+    }
+
+    fn draw_axes_chart(&self, scene: &mut Scene, groups: &[&Group], area: Area) {
+        let axes = self.get_axes(groups);
+        let size = LABEL_SIZE;
+        let category_axis = self.plot_area.get_category_axis().first();
+        let date_axis = self.plot_area.get_date_axis().first();
+        let category_deleted = if axes.xy {
+            axes.x_axis.is_some_and(|v| *v.get_delete().get_val())
+        } else {
+            category_axis
+                .map(|v| *v.get_delete().get_val())
+                .or_else(|| date_axis.map(|v| *v.get_delete().get_val()))
+                .unwrap_or(false)
+        };
+        let category_title = if axes.xy {
+            axes.x_axis.and_then(|v| v.get_title())
+        } else {
+            category_axis
+                .and_then(|v| v.get_title())
+                .or_else(|| date_axis.and_then(|v| v.get_title()))
+        }
+        .and_then(get_title_text);
+        let category_labels: Vec<String> = match &axes.x_scale {
+            Some(scale) => scale
+                .get_ticks()
+                .into_iter()
+                .map(|v| scale.get_label(v))
+                .collect(),
+            None => (0..axes.count)
+                .map(|i| {
+                    axes.categories
+                        .get(i)
+                        .cloned()
+                        .unwrap_or_else(|| (i + 1).to_string())
+                })
+                .collect(),
+        };
+        let value_labels = |side: &Option<(Scale, Option<&ValueAxis>)>| -> Option<(Vec<(f64, String)>, Option<String>)> {
+            let (scale, axis) = side.as_ref()?;
+            if axis.is_some_and(|v| *v.get_delete().get_val()) {
+                return Some((Vec::new(), None));
+            }
+            let labels = scale
+                .get_ticks()
+                .into_iter()
+                .map(|v| (v, scale.get_label(v)))
+                .collect();
+            Some((labels, axis.and_then(|v| v.get_title()).and_then(get_title_text)))
+        };
+        let primary_labels = value_labels(&axes.primary);
+        let secondary_labels = value_labels(&axes.secondary);
+        let max_width = |labels: &[String]| {
+            labels
+                .iter()
+                .map(|v| text_width(v, size))
+                .fold(0.0, f64::max)
+        };
+        let title_space = size * 1.6;
+        let line_height = size * 1.4;
+
+        // space around the plot for labels and titles
+        let (mut left, mut right, mut top, mut bottom) = (0.0, 6.0, 6.0, 0.0);
+        let category_space = if category_deleted {
+            0.0
+        } else if axes.horizontal {
+            max_width(&category_labels) + 6.0
+        } else {
+            line_height + 4.0
+        } + if category_title.is_some() {
+            title_space
+        } else {
+            0.0
+        };
+        let value_space = |labels: &Option<(Vec<(f64, String)>, Option<String>)>| match labels {
+            Some((list, title)) => {
+                let texts: Vec<String> = list.iter().map(|v| v.1.clone()).collect();
+                let label_space = if texts.is_empty() {
+                    0.0
+                } else if axes.horizontal {
+                    line_height + 4.0
+                } else {
+                    max_width(&texts) + 6.0
+                };
+                label_space + if title.is_some() { title_space } else { 0.0 }
+            }
+            None => 0.0,
+        };
+        if axes.horizontal {
+            left += category_space;
+            bottom += value_space(&primary_labels);
+            top += value_space(&secondary_labels);
+        } else {
+            bottom += category_space;
+            left += value_space(&primary_labels);
+            right += value_space(&secondary_labels);
+        }
+        let plot = Area::new(
+            area.x + left,
+            area.y + top,
+            area.width - left - right,
+            area.height - top - bottom,
+        );
+        if let Some(fill) = self
+            .context
+            .get_shape_fill(self.plot_area.get_shape_properties())
+            .flatten()
+        {
+            scene.push(Shape::Rect {
+                x: plot.x,
+                y: plot.y,
+                width: plot.width,
+                height: plot.height,
+                fill: Some(fill),
+                stroke: None,
+            });
+        }
+        let frame = Frame {
+            area: plot,
+            horizontal: axes.horizontal,
+            count: axes.count,
+            between: axes.between,
+            reversed: axes.category_reversed,
+        };
+
+        // gridlines
+        let grid = Stroke::new(Rgb::LINE, 1.0);
+        if let Some((scale, axis)) = &axes.primary {
+            if axis.is_none_or(|v| v.get_major_gridlines().is_some()) {
+                for tick in scale.get_ticks() {
+                    let r = scale.ratio(tick);
+                    scene.push(Shape::Line {
+                        points: vec![frame.point(0.0, r), frame.point(1.0, r)],
+                        stroke: grid,
+                    });
+                }
+            }
+        }
+        let category_gridlines = if axes.xy {
+            axes.x_axis
+                .is_some_and(|v| v.get_major_gridlines().is_some())
+        } else {
+            category_axis.is_some_and(|v| v.get_major_gridlines().is_some())
+        };
+        if category_gridlines {
+            let positions: Vec<f64> = match &axes.x_scale {
+                Some(scale) => scale
+                    .get_ticks()
+                    .into_iter()
+                    .map(|v| scale.ratio(v))
+                    .collect(),
+                None if axes.between => (0..=axes.count)
+                    .map(|i| i as f64 / axes.count.max(1) as f64)
+                    .collect(),
+                None => (0..axes.count).map(|i| frame.category(i)).collect(),
+            };
+            for c in positions {
+                scene.push(Shape::Line {
+                    points: vec![frame.point(c, 0.0), frame.point(c, 1.0)],
+                    stroke: grid,
+                });
+            }
+        }
+
+        // series: areas first, then bars, then lines and points
+        let order = |group: &&&Group| match group.kind {
+            GroupKind::Area => 0,
+            GroupKind::Bar { .. } => 1,
+            _ => 2,
+        };
+        let mut sorted: Vec<&&Group> = groups.iter().collect();
+        sorted.sort_by_key(order);
+        for group in sorted {
+            let axis = self.get_value_axis(group, true);
+            let scale = match (Self::is_secondary(axis), &axes.primary, &axes.secondary) {
+                (true, _, Some(v))
+                | (false, Some(v), _)
+                | (_, Some(v), None)
+                | (_, None, Some(v)) => &v.0,
+                _ => continue,
+            };
+            match &group.kind {
+                GroupKind::Bar {
+                    gap_width, overlap, ..
+                } => self.draw_bars(scene, group, &frame, scale, *gap_width, *overlap),
+                GroupKind::Area => self.draw_areas(scene, group, &frame, scale),
+                GroupKind::Line { show_marker } => {
+                    self.draw_lines(scene, group, &frame, scale, *show_marker)
+                }
+                GroupKind::Stock {
+                    high_low_lines,
+                    up_down_bars,
+                } => self.draw_stock(scene, group, &frame, scale, *high_low_lines, *up_down_bars),
+                GroupKind::Scatter {
+                    line,
+                    marker,
+                    smooth,
+                } => {
+                    if let Some(x_scale) = &axes.x_scale {
+                        self.draw_scatter(
+                            scene, group, &frame, x_scale, scale, *line, *marker, *smooth,
+                        );
+                    }
+                }
+                GroupKind::Bubble {
+                    scale: bubble_scale,
+                } => {
+                    if let Some(x_scale) = &axes.x_scale {
+                        self.draw_bubbles(
+                            scene,
+                            groups,
+                            group,
+                            &frame,
+                            x_scale,
+                            scale,
+                            *bubble_scale,
+                        );
+                    }
+                }
+                _ => {}
+            }
+        }
+
+        // category axis line at zero
+        if !category_deleted {
+            if let Some((scale, _)) = &axes.primary {
+                let r = scale.base_ratio();
+                scene.push(Shape::Line {
+                    points: vec![frame.point(0.0, r), frame.point(1.0, r)],
+                    stroke: Stroke::new(Rgb::new(0xBF, 0xBF, 0xBF), 1.0),
+                });
+            }
+        }
+
+        // category labels
+        if !category_deleted {
+            let positions: Vec<(f64, String)> = match &axes.x_scale {
+                Some(scale) => scale
+                    .get_ticks()
+                    .into_iter()
+                    .map(|v| (scale.ratio(v), scale.get_label(v)))
+                    .collect(),
+                None => category_labels
+                    .iter()
+                    .enumerate()
+                    .map(|(i, v)| (frame.category(i), v.clone()))
+                    .collect(),
+            };
+            let available = if axes.horizontal {
+                plot.height
+            } else {
+                plot.width
+            } * frame.slot();
+            let needed = if axes.horizontal {
+                line_height
+            } else {
+                max_width(&category_labels) + 6.0
+            };
+            let step = if axes.x_scale.is_some() || available <= 0.0 {
+                1
+            } else {
+                (needed / available).ceil().max(1.0) as usize
+            };
+            for (c, text) in positions.into_iter().step_by(step) {
+                let (x, y) = frame.point(c, 0.0);
+                if axes.horizontal {
+                    scene.text(plot.x - 6.0, y, text, size, TextAnchor::End, false);
+                } else {
+                    scene.text(
+                        x,
+                        plot.bottom() + 4.0 + line_height / 2.0,
+                        text,
+                        size,
+                        TextAnchor::Middle,
+                        false,
+                    );
+                }
+            }
+            if let Some(title) = category_title {
+                if axes.horizontal {
+                    scene.text(
+                        area.x + size * 0.8,
+                        plot.y + plot.height / 2.0,
+                        title,
+                        size,
+                        TextAnchor::Middle,
+                        true,
+                    );
+                } else {
+                    scene.text(
+                        plot.x + plot.width / 2.0,
+                        area.bottom() - size * 0.8,
+                        title,
+                        size,
+                        TextAnchor::Middle,
+                        false,
+                    );
+                }
+            }
+        }
+
+        // value labels
+        for (secondary, labels) in [(false, &primary_labels), (true, &secondary_labels)] {
+            let (list, title) = match labels {
+                Some(v) => v,
+                None => continue,
+            };
+            let scale = match if secondary {
+                &axes.secondary
+            } else {
+                &axes.primary
+            } {
+                Some(v) => &v.0,
+                None => continue,
+            };
+            for (value, text) in list {
+                let r = scale.ratio(*value);
+                if axes.horizontal {
+                    let x = plot.x + r * plot.width;
+                    let y = if secondary {
+                        plot.y - 4.0 - line_height / 2.0
+                    } else {
+                        plot.bottom() + 4.0 + line_height / 2.0
+                    };
+                    scene.text(x, y, text.clone(), size, TextAnchor::Middle, false);
+                } else {
+                    let y = plot.bottom() - r * plot.height;
+                    if secondary {
+                        scene.text(
+                            plot.right() + 6.0,
+                            y,
+                            text.clone(),
+                            size,
+                            TextAnchor::Start,
+                            false,
+                        );
+                    } else {
+                        scene.text(plot.x - 6.0, y, text.clone(), size, TextAnchor::End, false);
+                    }
+                }
+            }
+            if let Some(title) = title {
+                if axes.horizontal {
+                    let y = if secondary {
+                        area.y + size * 0.8
+                    } else {
+                        area.bottom() - size * 0.8
+                    };
+                    scene.text(
+                        plot.x + plot.width / 2.0,
+                        y,
+                        title.clone(),
+                        size,
+                        TextAnchor::Middle,
+                        false,
+                    );
+                } else {
+                    let x = if secondary {
+                        area.right() - size * 0.8
+                    } else {
+                        area.x + size * 0.8
+                    };
+                    scene.text(
+                        x,
+                        plot.y + plot.height / 2.0,
+                        title.clone(),
+                        size,
+                        TextAnchor::Middle,
+                        true,
+                    );
+                }
+            }
+        }
+    }
+
+    fn push_bar(
+        scene: &mut Scene,
+        frame: &Frame,
+        from: (f64, f64),
+        to: (f64, f64),
+        fill: Option<Rgb>,
+        stroke: Option<Stroke>,
+    ) {
+        let a = frame.point(from.0, from.1.clamp(0.0, 1.0));
+        let b = frame.point(to.0, to.1.clamp(0.0, 1.0));
+        scene.push(Shape::Rect {
+            x: a.0.min(b.0),
+            y: a.1.min(b.1),
+            width: (a.0 - b.0).abs(),
+            height: (a.1 - b.1).abs(),
+            fill,
+            stroke,
+        });
+    }
+
+    fn draw_bars(
+        &self,
+        scene: &mut Scene,
+        group: &Group,
+        frame: &Frame,
+        scale: &Scale,
+        gap_width: f64,
+        overlap: f64,
+    ) {
+        let count = group.series.len().max(1) as f64;
+        let stacked = !matches!(group.grouping, GroupingValues::Standard);
+        let slot = frame.slot();
+        let group_width = slot / (1.0 + gap_width / 100.0);
+        let overlap = if stacked {
+            1.0
+        } else {
+            (overlap / 100.0).clamp(-1.0, 1.0)
+        };
+        let bar_width = group_width / (count - (count - 1.0) * overlap);
+        for (k, (series, values)) in group
+            .series
+            .iter()
+            .zip(group.get_stacked_values())
+            .enumerate()
+        {
+            let offset = if stacked {
+                0.0
+            } else {
+                k as f64 * bar_width * (1.0 - overlap)
+            };
+            for (i, value) in values.into_iter().enumerate() {
+                let (bottom, top) = match value {
+                    Some(v) => v,
+                    None => continue,
+                };
+                let start = frame.category(i) - group_width / 2.0 + offset;
+                let bottom = if stacked {
+                    scale.ratio(bottom)
+                } else {
+                    scale.base_ratio()
+                };
+                Self::push_bar(
+                    scene,
+                    frame,
+                    (start, bottom),
+                    (start + bar_width, scale.ratio(top)),
+                    series
+                        .has_fill
+                        .then(|| self.get_point_color(group, series, i)),
+                    series.outline.map(|v| Stroke::new(v, 1.0)),
+                );
+            }
+        }
+    }
+
+    fn draw_areas(&self, scene: &mut Scene, group: &Group, frame: &Frame, scale: &Scale) {
+        for (series, values) in group.series.iter().zip(group.get_stacked_values()).rev() {
+            if values.is_empty() {
+                continue;
+            }
+            let mut points = Vec::new();
+            let mut bases = Vec::new();
+            for (i, value) in values.iter().enumerate() {
+                let (bottom, top) = value.unwrap_or((0.0, 0.0));
+                let bottom = if matches!(group.grouping, GroupingValues::Standard) {
+                    scale.base_ratio()
+                } else {
+                    scale.ratio(bottom)
+                };
+                points.push(frame.point(frame.category(i), scale.ratio(top).clamp(0.0, 1.0)));
+                bases.push(frame.point(frame.category(i), bottom.clamp(0.0, 1.0)));
+            }
+            points.extend(bases.into_iter().rev());
+            scene.push(Shape::Polygon {
+                points,
+                fill: series.has_fill.then_some(series.fill),
+                stroke: series.outline.map(|v| Stroke::new(v, series.line_width)),
+            });
+        }
+    }
+
+    fn draw_series_line(
+        &self,
+        scene: &mut Scene,
+        series: &SeriesData,
+        points: &[Option<(f64, f64)>],
+        smooth: bool,
+    ) {
+        if !series.has_line {
+            return;
+        }
+        for segment in get_line_segments(points, self.context.is_span_blanks()) {
+            let segment = if smooth {
+                get_smooth_points(&segment)
+            } else {
+                segment
+            };
+            if segment.len() < 2 {
+                continue;
+            }
+            scene.push(Shape::Line {
+                points: segment,
+                stroke: Stroke::new(series.line, series.line_width),
+            });
+        }
+    }
+
+    fn draw_series_markers(scene: &mut Scene, series: &SeriesData, points: &[Option<(f64, f64)>]) {
+        let symbol = get_marker_symbol(series);
+        for (x, y) in points.iter().flatten() {
+            draw_marker(scene, &symbol, *x, *y, series.fill);
+        }
+    }
+
+    fn draw_lines(
+        &self,
+        scene: &mut Scene,
+        group: &Group,
+        frame: &Frame,
+        scale: &Scale,
+        show_marker: bool,
+    ) {
+        for (series, values) in group.series.iter().zip(group.get_stacked_values()) {
+            let points: Vec<Option<(f64, f64)>> = values
+                .iter()
+                .enumerate()
+                .map(|(i, v)| v.map(|(_, top)| frame.point(frame.category(i), scale.ratio(top))))
+                .collect();
+            self.draw_series_line(scene, series, &points, series.smooth);
+            if show_marker && !matches!(series.marker, Some(MarkerStyleValues::None)) {
+                Self::draw_series_markers(scene, series, &points);
+            }
+        }
+    }
+
+    fn draw_stock(
+        &self,
+        scene: &mut Scene,
+        group: &Group,
+        frame: &Frame,
+        scale: &Scale,
+        high_low_lines: bool,
+        up_down_bars: bool,
+    ) {
+        let count = group.point_count();
+        let dark = Rgb::new(0x40, 0x40, 0x40);
+        if high_low_lines {
+            for i in 0..count {
+                let range = get_value_range(group.series.iter().filter_map(|v| v.values.get(i)));
+                if let Some((low, high)) = range {
+                    let c = frame.category(i);
+                    scene.push(Shape::Line {
+                        points: vec![
+                            frame.point(c, scale.ratio(low)),
+                            frame.point(c, scale.ratio(high)),
+                        ],
+                        stroke: Stroke::new(dark, 1.0),
+                    });
+                }
+            }
+        }
+        if up_down_bars && group.series.len() >= 2 {
+            let open = &group.series[0];
+            let close = &group.series[group.series.len() - 1];
+            let width = frame.slot() / 2.5;
+            for i in 0..count {
+                if let (Some(Some(a)), Some(Some(b))) = (open.values.get(i), close.values.get(i)) {
+                    let c = frame.category(i) - width / 2.0;
+                    Self::push_bar(
+                        scene,
+                        frame,
+                        (c, scale.ratio(*a)),
+                        (c + width, scale.ratio(*b)),
+                        Some(if b >= a { Rgb::WHITE } else { dark }),
+                        Some(Stroke::new(dark, 1.0)),
+                    );
+                }
+            }
+        }
+        for series in &group.series {
+            let points: Vec<Option<(f64, f64)>> = series
+                .values
+                .iter()
+                .enumerate()
+                .map(|(i, v)| v.map(|v| frame.point(frame.category(i), scale.ratio(v))))
+                .collect();
+            self.draw_series_line(scene, series, &points, series.smooth);
+            if series.marker.is_some() && !matches!(series.marker, Some(MarkerStyleValues::None)) {
+                Self::draw_series_markers(scene, series, &points);
+            }
+        }
+    }
+
+    #[allow(clippy::too_many_arguments)]
+    fn draw_scatter(
+        &self,
+        scene: &mut Scene,
+        group: &Group,
+        frame: &Frame,
+        x_scale: &Scale,
+        y_scale: &Scale,
+        line: bool,
+        marker: bool,
+        smooth: bool,
+    ) {
+        for series in &group.series {
+            let points: Vec<Option<(f64, f64)>> = Self::get_x_values(series)
+                .iter()
+                .zip(series.values.iter())
+                .map(|(x, y)| match (x, y) {
+                    (Some(x), Some(y)) => Some(frame.point(x_scale.ratio(*x), y_scale.ratio(*y))),
+                    _ => None,
+                })
+                .collect();
+            if line {
+                self.draw_series_line(scene, series, &points, smooth && series.smooth);
+            }
+            if marker && !matches!(series.marker, Some(MarkerStyleValues::None)) {
+                Self::draw_series_markers(scene, series, &points);
+            }
+        }
+    }
+
+    #[allow(clippy::too_many_arguments)]
+    fn draw_bubbles(
+        &self,
+        scene: &mut Scene,
+        groups: &[&Group],
+        group: &Group,
+        frame: &Frame,
+        x_scale: &Scale,
+        y_scale: &Scale,
+        bubble_scale: f64,
+    ) {
+        let max_size = groups
+            .iter()
+            .filter(|v| matches!(v.kind, GroupKind::Bubble { .. }))
+            .flat_map(|v| v.series.iter())
+            .flat_map(|v| v.sizes.iter().flatten())
+            .fold(0.0f64, |a, b| a.max(b.abs()));
+        let max_radius = frame.area.width.min(frame.area.height) * 0.125 * bubble_scale / 100.0;
+        for series in &group.series {
+            for (i, (x, y)) in Self::get_x_values(series)
+                .iter()
+                .zip(series.values.iter())
+                .enumerate()
+            {
+                let (x, y) = match (x, y) {
+                    (Some(x), Some(y)) => (*x, *y),
+                    _ => continue,
+                };
+                let size = series.sizes.get(i).copied().flatten().unwrap_or(1.0).abs();
+                let radius = if max_size > 0.0 {
+                    (size / max_size).sqrt() * max_radius
+                } else {
+                    max_radius
+                };
+                let (x, y) = frame.point(x_scale.ratio(x), y_scale.ratio(y));
+                scene.push(Shape::Circle {
+                    x,
+                    y,
+                    radius,
+                    fill: Some(self.get_point_color(group, series, i)),
+                    stroke: series.outline.map(|v| Stroke::new(v, 1.0)),
+                });
+            }
+        }
+    }
+
+    fn draw_pie(&self, scene: &mut Scene, group: &Group, area: Area) {
+        let (first_slice_angle, hole_size) = match group.kind {
+            GroupKind::Pie {
+                first_slice_angle,
+                hole_size,
+            } => (first_slice_angle, hole_size),
+            _ => return,
+        };
+        let x = area.x + area.width / 2.0;
+        let y = area.y + area.height / 2.0;
+        let radius = area.width.min(area.height) / 2.0 * 0.9;
+        // a pie shows its first series, a doughnut has a ring for each series
+        let series_list: Vec<&SeriesData> = if hole_size > 0.0 {
+            group.series.iter().collect()
+        } else {
+            group.series.iter().take(1).collect()
+        };
+        let ring_count = series_list.len().max(1) as f64;
+        let inner = radius * hole_size;
+        let ring_width = (radius - inner) / ring_count;
+        for (k, series) in series_list.into_iter().enumerate() {
+            let total: f64 = series.values.iter().flatten().map(|v| v.abs()).sum();
+            if total <= 0.0 {
+                continue;
+            }
+            let explosion = series.explosion;
+            let outer_radius = if hole_size > 0.0 {
+                inner + ring_width * (k as f64 + 1.0)
+            } else {
+                radius / (1.0 + explosion)
+            };
+            let inner_radius = if hole_size > 0.0 {
+                inner + ring_width * k as f64
+            } else {
+                0.0
+            };
+            let mut angle = first_slice_angle;
+            for (i, value) in series.values.iter().enumerate() {
+                let value = match value {
+                    Some(v) if *v != 0.0 => v.abs(),
+                    _ => continue,
+                };
+                let sweep = value / total * 360.0;
+                let (cx, cy) = if hole_size > 0.0 {
+                    (x, y)
+                } else {
+                    get_arc_point(x, y, outer_radius * explosion, angle + sweep / 2.0)
+                };
+                let mut points = get_arc(cx, cy, outer_radius, angle, sweep);
+                if inner_radius > 0.0 {
+                    points.extend(get_arc(cx, cy, inner_radius, angle + sweep, -sweep));
+                } else if sweep < 360.0 {
+                    points.push((cx, cy));
+                }
+                scene.push(Shape::Polygon {
+                    points,
+                    fill: series
+                        .has_fill
+                        .then(|| self.get_point_color(group, series, i)),
+                    stroke: Some(Stroke::new(series.outline.unwrap_or(Rgb::WHITE), 1.0)),
+                });
+                angle += sweep;
+            }
+        }
+    }
+
+    fn draw_radar(&self, scene: &mut Scene, groups: &[&Group], area: Area) {
+        let size = LABEL_SIZE;
+        let count = groups.iter().map(|v| v.point_count()).max().unwrap_or(0);
+        if count == 0 {
+            return;
+        }
+        let categories: Vec<String> = (0..count)
+            .map(|i| {
+                groups
+                    .iter()
+                    .flat_map(|v| v.series.iter())
+                    .find_map(|v| v.categories.get(i).cloned())
+                    .unwrap_or_else(|| (i + 1).to_string())
+            })
+            .collect();
+        let label_width = categories
+            .iter()
+            .map(|v| text_width(v, size))
+            .fold(0.0, f64::max);
+        let x = area.x + area.width / 2.0;
+        let y = area.y + area.height / 2.0;
+        let radius = (area.width / 2.0 - label_width - 8.0)
+            .min(area.height / 2.0 - size * 1.5)
+            .max(1.0);
+        let axis = groups.first().and_then(|v| self.get_value_axis(v, true));
+        let (min, max) = get_value_range(
+            groups
+                .iter()
+                .flat_map(|v| v.series.iter())
+                .flat_map(|v| v.values.iter()),
+        )
+        .unwrap_or((0.0, 1.0));
+        let scale = Scale::from_axis(min, max, false, axis);
+        let angle = |i: usize| i as f64 * 360.0 / count as f64;
+
+        // grid, spokes and labels
+        let grid = Stroke::new(Rgb::LINE, 1.0);
+        for tick in scale.get_ticks() {
+            let r = scale.ratio(tick) * radius;
+            let mut points: Vec<(f64, f64)> = (0..count)
+                .map(|i| get_arc_point(x, y, r, angle(i)))
+                .collect();
+            points.push(points[0]);
+            scene.push(Shape::Line {
+                points,
+                stroke: grid,
+            });
+            if !axis.is_some_and(|v| *v.get_delete().get_val()) {
+                scene.text(
+                    x - 4.0,
+                    y - r,
+                    scale.get_label(tick),
+                    size,
+                    TextAnchor::End,
+                    false,
+                );
+            }
+        }
+        for (i, text) in categories.into_iter().enumerate() {
+            scene.push(Shape::Line {
+                points: vec![(x, y), get_arc_point(x, y, radius, angle(i))],
+                stroke: grid,
+            });
+            let (lx, ly) = get_arc_point(x, y, radius + size, angle(i));
+            let sin = (angle(i) * PI / 180.0).sin();
+            let anchor = if sin > 0.1 {
+                TextAnchor::Start
+            } else if sin < -0.1 {
+                TextAnchor::End
+            } else {
+                TextAnchor::Middle
+            };
+            scene.text(lx, ly, text, size, anchor, false);
+        }
+
+        // series
+        for group in groups {
+            let (filled, marker) = match group.kind {
+                GroupKind::Radar { filled, marker } => (filled, marker),
+                _ => continue,
+            };
+            for series in &group.series {
+                let points: Vec<Option<(f64, f64)>> = series
+                    .values
+                    .iter()
+                    .enumerate()
+                    .map(|(i, v)| {
+                        v.map(|v| get_arc_point(x, y, scale.ratio(v).max(0.0) * radius, angle(i)))
+                    })
+                    .collect();
+                if filled {
+                    scene.push(Shape::Polygon {
+                        points: points.iter().flatten().copied().collect(),
+                        fill: series.has_fill.then_some(series.fill),
+                        stroke: series.outline.map(|v| Stroke::new(v, 1.0)),
+                    });
+                    continue;
+                }
+                let mut closed = points.clone();
+                if let Some(first) = points.first() {
+                    closed.push(*first);
+                }
+                self.draw_series_line(scene, series, &closed, false);
+                if marker && !matches!(series.marker, Some(MarkerStyleValues::None)) {
+                    Self::draw_series_markers(scene, series, &points);
+                }
+            }
+        }
+    }
+
+    fn get_surface_scale(&self, group: &Group) -> Scale {
+        let (min, max) = get_value_range(group.series.iter().flat_map(|v| v.values.iter()))
+            .unwrap_or((0.0, 1.0));
+        Scale::from_axis(min, max, false, self.get_value_axis(group, true))
+    }
+
+    /// Surface charts are drawn from the top, colored by the value bands shown in the legend.
+    fn draw_surface(&self, scene: &mut Scene, group: &Group, area: Area) {
+        let size = LABEL_SIZE;
+        let count = group.point_count();
+        let rows = group.series.len();
+        if count == 0 || rows == 0 {
+            return;
+        }
+        let scale = self.get_surface_scale(group);
+        let ticks = scale.get_ticks();
+        let wireframe = matches!(group.kind, GroupKind::Surface { wireframe: true });
+        let categories: Vec<String> = (0..count)
+            .map(|i| {
+                group
+                    .series
+                    .iter()
+                    .find_map(|v| v.categories.get(i).cloned())
+                    .unwrap_or_else(|| (i + 1).to_string())
+            })
+            .collect();
+        let left = group
+            .series
+            .iter()
+            .map(|v| text_width(&v.name, size))
+            .fold(0.0, f64::max)
+            + 6.0;
+        let plot = Area::new(
+            area.x + left,
+            area.y + 6.0,
+            area.width - left - 6.0,
+            area.height - size * 1.4 - 10.0,
+        );
+        let cell_width = plot.width / count as f64;
+        let cell_height = plot.height / rows as f64;
+        for (j, series) in group.series.iter().enumerate() {
+            let cy = plot.bottom() - cell_height * (j as f64 + 1.0);
+            for (i, value) in series.values.iter().enumerate() {
+                let value = match value {
+                    Some(v) => *v,
+                    None => continue,
+                };
+                let band = ticks
+                    .windows(2)
+                    .position(|v| value >= v[0].min(v[1]) && value <= v[0].max(v[1]))
+                    .unwrap_or(0);
+                let color = self.context.get_accent(band);
+                scene.push(Shape::Rect {
+                    x: plot.x + cell_width * i as f64,
+                    y: cy,
+                    width: cell_width,
+                    height: cell_height,
+                    fill: (!wireframe).then_some(color),
+                    stroke: Some(Stroke::new(if wireframe { color } else { Rgb::WHITE }, 1.0)),
+                });
+            }
+            scene.text(
+                plot.x - 6.0,
+                cy + cell_height / 2.0,
+                series.name.clone(),
+                size,
+                TextAnchor::End,
+                false,
+            );
+        }
+        for (i, text) in categories.into_iter().enumerate() {
+            scene.text(
+                plot.x + cell_width * (i as f64 + 0.5),
+                plot.bottom() + 4.0 + size * 0.7,
+                text,
+                size,
+                TextAnchor::Middle,
+                false,
+            );
+        }
+    }
+}
@@ -0,0 +1,232 @@
+use super::font::get_glyph;
+use super::*;
+use image::Rgba;
+use image::RgbaImage;
+
+struct Canvas {
+    image: RgbaImage,
+}
+impl Canvas {
+    fn set(&mut self, x: i64, y: i64, color: Rgb) {
+        if x < 0 || y < 0 || x >= self.image.width() as i64 || y >= self.image.height() as i64 {
+            return;
+        }
+        self.image
+            .put_pixel(x as u32, y as u32, Rgba([color.r, color.g, color.b, 255]));
+    }
+
+    /// Fill the pixels whose centers are inside the rectangle, at least one pixel.
+    fn fill_rect(&mut self, x: f64, y: f64, width: f64, height: f64, color: Rgb) {
+        let x0 = x.round() as i64;
+        let y0 = y.round() as i64;
+        let x1 = ((x + width).round() as i64).max(x0 + 1);
+        let y1 = ((y + height).round() as i64).max(y0 + 1);
+        let max_x = self.image.width() as i64;
+        let max_y = self.image.height() as i64;
+        for py in y0.max(0)..y1.min(max_y) {
+            for px in x0.max(0)..x1.min(max_x) {
+                self.set(px, py, color);
+            }
+        }
+    }
+
+    /// Scanline fill with the even-odd rule.
+    fn fill_polygon(&mut self, points: &[(f64, f64)], color: Rgb) {
+        if points.len() < 3 {
+            return;
+        }
+        let min_y = points
+            .iter()
+            .map(|v| v.1)
+            .fold(f64::MAX, f64::min)
+            .floor()
+            .max(0.0) as i64;
+        let max_y = points
+            .iter()
+            .map(|v| v.1)
+            .fold(f64::MIN, f64::max)
+            .ceil()
+            .min(self.image.height() as f64) as i64;
+        let mut crossings: Vec<f64> = Vec::new();
+        for py in min_y..max_y {
+            let y = py as f64 + 0.5;
+            crossings.clear();
+            for i in 0..points.len() {
+                let (x0, y0) = points[i];
+                let (x1, y1) = points[(i + 1) % points.len()];
+                if (y0 <= y && y < y1) || (y1 <= y && y < y0) {
+                    crossings.push(x0 + (y - y0) * (x1 - x0) / (y1 - y0));
+                }
+            }
+            crossings.sort_by(|a, b| a.total_cmp(b));
+            for pair in crossings.chunks(2) {
+                if let [a, b] = pair {
+                    let start = (a - 0.5).ceil() as i64;
+                    let end = (b - 0.5).floor() as i64;
+                    for px in start.max(0)..=end.min(self.image.width() as i64 - 1) {
+                        self.set(px, py, color);
+                    }
+                }
+            }
+        }
+    }
+
+    fn fill_circle(&mut self, x: f64, y: f64, radius: f64, color: Rgb) {
+        self.fill_ring(x, y, 0.0, radius, color);
+    }
+
+    fn fill_ring(&mut self, x: f64, y: f64, inner: f64, outer: f64, color: Rgb) {
+        let inner2 = inner * inner;
+        let outer2 = outer * outer;
+        for py in (y - outer).floor() as i64..=(y + outer).ceil() as i64 {
+            for px in (x - outer).floor() as i64..=(x + outer).ceil() as i64 {
+                let dx = px as f64 + 0.5 - x;
+                let dy = py as f64 + 0.5 - y;
+                let d = dx * dx + dy * dy;
+                if d <= outer2 && d >= inner2 {
+                    self.set(px, py, color);
+                }
+            }
+        }
+    }
+
+    /// Draw a line as a band for each segment with round joins.
+    fn stroke_line(&mut self, points: &[(f64, f64)], stroke: &Stroke) {
+        let half = stroke.width.max(1.0) / 2.0;
+        for pair in points.windows(2) {
+            let (x0, y0) = pair[0];
+            let (x1, y1) = pair[1];
+            let length = ((x1 - x0).powi(2) + (y1 - y0).powi(2)).sqrt();
+            if length == 0.0 {
+                continue;
+            }
+            let nx = -(y1 - y0) / length * half;
+            let ny = (x1 - x0) / length * half;
+            self.fill_polygon(
+                &[
+                    (x0 + nx, y0 + ny),
+                    (x1 + nx, y1 + ny),
+                    (x1 - nx, y1 - ny),
+                    (x0 - nx, y0 - ny),
+                ],
+                stroke.color,
+            );
+        }
+        if half > 0.75 {
+            for (x, y) in points {
+                self.fill_circle(*x, *y, half, stroke.color);
+            }
+        }
+    }
+
+    fn draw_text(&mut self, shape: &Shape) {
+        let (x, y, text, size, color, anchor, vertical) = match shape {
+            Shape::Text {
+                x,
+                y,
+                text,
+                size,
+                color,
+                anchor,
+                vertical,
+            } => (*x, *y, text, *size, *color, anchor, *vertical),
+            _ => return,
+        };
+        // a character cell is 6 by 8 units, as wide as `text_width` estimates
+        let unit = size * 0.55 / 6.0;
+        let width = text_width(text, size);
+        let mut u = match anchor {
+            TextAnchor::Start => 0.0,
+            TextAnchor::Middle => -width / 2.0,
+            TextAnchor::End => -width,
+        };
+        let top = -3.5 * unit;
+        for c in text.chars() {
+            let glyph = get_glyph(c);
+            for (column, bits) in glyph.iter().enumerate() {
+                for row in 0..7 {
+                    if bits & (1 << row) == 0 {
+                        continue;
+                    }
+                    let gu = u + column as f64 * unit;
+                    let gv = top + row as f64 * unit;
+                    if vertical {
+                        self.fill_rect(x + gv, y - gu - unit, unit, unit, color);
+                    } else {
+                        self.fill_rect(x + gu, y + gv, unit, unit, color);
+                    }
+                }
+            }
+            u += if (c as u32) >= 0x2E80 { 12.0 } else { 6.0 } * unit;
+        }
+    }
+}
+
+/// Rasterize a scene. Shapes are drawn without anti-aliasing.
+pub(crate) fn rasterize(scene: &Scene) -> RgbaImage {
+    let mut canvas = Canvas {
+        image: RgbaImage::from_pixel(
+            scene.width.round().max(1.0) as u32,
+            scene.height.round().max(1.0) as u32,
+            Rgba([255, 255, 255, 255]),
+        ),
+    };
+    for shape in &scene.shapes {
+        match shape {
+            Shape::Rect {
+                x,
+                y,
+                width,
+                height,
+                fill,
+                stroke,
+            } => {
+                if let Some(color) = fill {
+                    canvas.fill_rect(*x, *y, *width, *height, *color);
+                }
+                if let Some(stroke) = stroke {
+                    let (x1, y1) = (x + width, y + height);
+                    canvas.stroke_line(&[(*x, *y), (x1, *y), (x1, y1), (*x, y1), (*x, *y)], stroke);
+                }
+            }
+            Shape::Line { points, stroke } => canvas.stroke_line(points, stroke),
+            Shape::Polygon {
+                points,
+                fill,
+                stroke,
+            } => {
+                if let Some(color) = fill {
+                    canvas.fill_polygon(points, *color);
+                }
+                if let (Some(stroke), Some(first)) = (stroke, points.first()) {
+                    let mut closed = points.clone();
+                    closed.push(*first);
+                    canvas.stroke_line(&closed, stroke);
+                }
+            }
+            Shape::Circle {
+                x,
+                y,
+                radius,
+                fill,
+                stroke,
+            } => {
+                if let Some(color) = fill {
+                    canvas.fill_circle(*x, *y, *radius, *color);
+                }
+                if let Some(stroke) = stroke {
+                    let half = stroke.width.max(1.0) / 2.0;
+                    canvas.fill_ring(
+                        *x,
+                        *y,
+                        (radius - half).max(0.0),
+                        radius + half,
+                        stroke.color,
+                    );
+                }
+            }
+            Shape::Text { .. } => canvas.draw_text(shape),
+        }
+    }
+    canvas.image
+}
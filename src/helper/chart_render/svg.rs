@@ -0,0 +1,135 @@
+use super::*;
+use quick_xml::escape::escape;
+use std::fmt::Write;
+
+const FONT_FAMILY: &str = "Calibri, Arial, sans-serif";
+
+/// Number with at most two decimals.
+fn num(value: f64) -> String {
+    let value = (value * 100.0).round() / 100.0;
+    if value == 0.0 {
+        return String::from("0");
+    }
+    value.to_string()
+}
+
+fn paint(fill: &Option<Rgb>, stroke: &Option<Stroke>) -> String {
+    let mut result = match fill {
+        Some(v) => format!(" fill=\"#{}\"", v.to_hex()),
+        None => String::from(" fill=\"none\""),
+    };
+    if let Some(v) = stroke {
+        let _ = write!(
+            result,
+            " stroke=\"#{}\" stroke-width=\"{}\"",
+            v.color.to_hex(),
+            num(v.width)
+        );
+    }
+    result
+}
+
+fn points(list: &[(f64, f64)]) -> String {
+    list.iter()
+        .map(|(x, y)| format!("{},{}", num(*x), num(*y)))
+        .collect::<Vec<String>>()
+        .join(" ")
+}
+
+/// Write a scene as an SVG document.
+pub(crate) fn write(scene: &Scene) -> String {
+    let mut result = String::new();
+    let _ = writeln!(
+        result,
+        "<svg xmlns=\"http://www.w3.org/2000/svg\" width=\"{0}\" height=\"{1}\" viewBox=\"0 0 {0} {1}\">",
+        num(scene.width),
+        num(scene.height)
+    );
+    for shape in &scene.shapes {
+        let _ = match shape {
+            Shape::Rect {
+                x,
+                y,
+                width,
+                height,
+                fill,
+                stroke,
+            } => writeln!(
+                result,
+                "<rect x=\"{}\" y=\"{}\" width=\"{}\" height=\"{}\"{}/>",
+                num(*x),
+                num(*y),
+                num(*width),
+                num(*height),
+                paint(fill, stroke)
+            ),
+            Shape::Line {
+                points: list,
+                stroke,
+            } => writeln!(
+                result,
+                "<polyline points=\"{}\"{} stroke-linejoin=\"round\" stroke-linecap=\"round\"/>",
+                points(list),
+                paint(&None, &Some(*stroke))
+            ),
+            Shape::Polygon {
+                points: list,
+                fill,
+                stroke,
+            } => writeln!(
+                result,
+                "<polygon points=\"{}\"{}/>",
+                points(list),
+                paint(fill, stroke)
+            ),
+            Shape::Circle {
+                x,
+                y,
+                radius,
+                fill,
+                stroke,
+            } => writeln!(
+                result,
+                "<circle cx=\"{}\" cy=\"{}\" r=\"{}\"{}/>",
+                num(*x),
+                num(*y),
+                num(*radius),
+                paint(fill, stroke)
+            ),
+            Shape::Text {
+                x,
+                y,
+                text,
+                size,
+                color,
+                anchor,
+                vertical,
+            } => {
+                let anchor = match anchor {
+                    TextAnchor::Start => "start",
+                    TextAnchor::Middle => "middle",
+                    TextAnchor::End => "end",
+                };
+                let transform = if *vertical {
+                    format!(" transform=\"rotate(-90 {} {})\"", num(*x), num(*y))
+                } else {
+                    String::new()
+                };
+                writeln!(
+                    result,
+                    "<text x=\"{}\" y=\"{}\" font-family=\"{}\" font-size=\"{}\" fill=\"#{}\" text-anchor=\"{}\" dominant-baseline=\"central\"{}>{}</text>",
+                    num(*x),
+                    num(*y),
+                    FONT_FAMILY,
+                    num(*size),
+                    color.to_hex(),
+                    anchor,
+                    transform,
+                    escape(text.as_str())
+                )
+            }
+        };
+    }
+    result.push_str("</svg>\n");
+    result
+}
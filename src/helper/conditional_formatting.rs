@@ -0,0 +1,729 @@
+//! Evaluation of conditional formatting rules against the values of a worksheet.
+
+use crate::helper::calculation::*;
+use crate::helper::date::excel_to_date_time_object_crate;
+use crate::helper::formula::*;
+use crate::helper::range::get_start_and_end_point;
+use crate::structs::office2010::excel::ConditionalFormattingIcon;
+use crate::structs::Color;
+use crate::structs::ColorScale;
+use crate::structs::ConditionalFormatValueObject;
+use crate::structs::ConditionalFormatValueObjectValues;
+use crate::structs::ConditionalFormatValues;
+use crate::structs::ConditionalFormatting;
+use crate::structs::ConditionalFormattingOperatorValues;
+use crate::structs::ConditionalFormattingResult;
+use crate::structs::ConditionalFormattingRule;
+use crate::structs::DataBar;
+use crate::structs::IconSet;
+use crate::structs::PatternValues;
+use crate::structs::Style;
+use crate::structs::TimePeriodValues;
+use crate::structs::Worksheet;
+use chrono::{Datelike, Duration, Local, NaiveDate};
+use std::cmp::Ordering;
+use std::collections::HashMap;
+
+/// Evaluate the conditional formatting rules of the worksheet for one cell.
+#[inline]
+pub(crate) fn evaluate(worksheet: &Worksheet, col: u32, row: u32) -> ConditionalFormattingResult {
+    Evaluator::new(worksheet).evaluate(col, row)
+}
+
+/// Evaluates the conditional formatting of the cells of a worksheet.
+/// The values of a formatting range are read once and shared by all of its cells.
+pub(crate) struct Evaluator<'a> {
+    worksheet: &'a Worksheet,
+    calculator: Calculator<'a>,
+    today: NaiveDate,
+    ranges: Vec<Vec<(u32, u32, u32, u32)>>,
+    statistics: Vec<Option<RangeStatistics>>,
+}
+impl<'a> Evaluator<'a> {
+    pub(crate) fn new(worksheet: &'a Worksheet) -> Self {
+        let ranges: Vec<Vec<(u32, u32, u32, u32)>> = worksheet
+            .get_conditional_formatting_collection()
+            .iter()
+            .map(get_ranges)
+            .collect();
+        let statistics = ranges.iter().map(|_| None).collect();
+        Self {
+            worksheet,
+            calculator: Calculator::new(vec![worksheet], &[]),
+            today: Local::now().date_naive(),
+            ranges,
+            statistics,
+        }
+    }
+
+    pub(crate) fn evaluate(&mut self, col: u32, row: u32) -> ConditionalFormattingResult {
+        let worksheet = self.worksheet;
+        let mut result = ConditionalFormattingResult::default();
+        *result.get_style_mut() = worksheet.get_style((col, row)).clone();
+
+        // rules of the ranges containing the cell, highest priority first
+        let mut rules: Vec<(usize, &ConditionalFormattingRule)> = Vec::new();
+        for (index, formatting) in worksheet
+            .get_conditional_formatting_collection()
+            .iter()
+            .enumerate()
+        {
+            if self.ranges[index].iter().any(|v| is_in_range(v, col, row)) {
+                for rule in formatting.get_conditional_collection() {
+                    rules.push((index, rule));
+                }
+            }
+        }
+        if rules.is_empty() {
+            return result;
+        }
+        rules.sort_by_key(|(_, rule)| *rule.get_priority());
+
+        let value = self.calculator.calculate_cell(0, col, row);
+        let mut styles: Vec<&Style> = Vec::new();
+        let mut color_scale_color: Option<Color> = None;
+        for (index, rule) in rules {
+            let statistics = self.statistics[index].get_or_insert_with(|| {
+                RangeStatistics::new(&self.ranges[index], worksheet, &mut self.calculator)
+            });
+            let mut context = RangeContext {
+                calculator: &mut self.calculator,
+                statistics,
+                date1904: worksheet.get_date1904(),
+            };
+            let is_match = match rule.get_type() {
+                ConditionalFormatValues::ColorScale => match rule.get_color_scale() {
+                    Some(v) => match get_color_scale_color(v, &value, &mut context) {
+                        Some(color) => {
+                            color_scale_color.get_or_insert(color);
+                            true
+                        }
+                        None => false,
+                    },
+                    None => false,
+                },
+                ConditionalFormatValues::DataBar => match rule.get_data_bar() {
+                    Some(v) => match get_data_bar_length(v, &value, &mut context) {
+                        Some(length) => {
+                            if !result.has_data_bar() {
+                                result.set_data_bar(get_data_bar_color(v, &value), length);
+                            }
+                            true
+                        }
+                        None => false,
+                    },
+                    None => false,
+                },
+                ConditionalFormatValues::IconSet => match rule.get_icon_set() {
+                    Some(v) => match get_icon(v, &value, &mut context) {
+                        Some(icon) => {
+                            if result.get_icon().is_none() {
+                                result.set_icon(icon);
+                            }
+                            true
+                        }
+                        None => false,
+                    },
+                    None => false,
+                },
+                _ => is_match(rule, &value, &mut context, col, row, self.today),
+            };
+            if !is_match {
+                continue;
+            }
+            if let Some(v) = rule.get_style() {
+                styles.push(v);
+            }
+            if *rule.get_stop_if_true() {
+                break;
+            }
+        }
+
+        // rules of higher priority win over the ones of lower priority
+        for style in styles.into_iter().rev() {
+            merge_differential_style(result.get_style_mut(), style);
+        }
+        if let Some(color) = color_scale_color {
+            result
+                .get_style_mut()
+                .set_background_color_solid(color.get_argb());
+            result.set_color_scale_color(color);
+        }
+        result
+    }
+}
+
+/// Apply a differential format (dxf) over a cell style.
+pub(crate) fn merge_differential_style(style: &mut Style, differential: &Style) {
+    if let Some(font) = differential.get_font() {
+        let target = style.get_font_mut();
+        if font.get_font_name().val.has_value() {
+            target.set_font_name(font.get_font_name().clone());
+        }
+        if font.get_font_size().val.has_value() {
+            target.set_font_size(font.get_font_size().clone());
+        }
+        if font.get_font_bold().val.has_value() {
+            target.set_font_bold(font.get_font_bold().clone());
+        }
+        if font.get_font_italic().val.has_value() {
+            target.set_font_italic(font.get_font_italic().clone());
+        }
+        if font.get_font_underline().val.has_value() {
+            target.set_font_underline(font.get_font_underline().clone());
+        }
+        if font.get_font_strike().val.has_value() {
+            target.set_font_strike(font.get_font_strike().clone());
+        }
+        if font.get_color().has_value() {
+            target.set_color(font.get_color().clone());
+        }
+    }
+
+    if let Some(fill) = differential.get_fill() {
+        if fill.get_gradient_fill().is_some() {
+            style.set_fill(fill.clone());
+        } else if let Some(pattern_fill) = fill.get_pattern_fill() {
+            match pattern_fill.get_pattern_type() {
+                PatternValues::None | PatternValues::Solid => {
+                    // differential formats store the solid fill color in bgColor
+                    let color = pattern_fill
+                        .get_background_color()
+                        .filter(|v| v.has_value())
+                        .or(pattern_fill.get_foreground_color());
+                    if let Some(color) = color {
+                        style
+                            .get_fill_mut()
+                            .get_pattern_fill_mut()
+                            .set_pattern_type(PatternValues::Solid)
+                            .remove_background_color()
+                            .set_foreground_color(color.clone());
+                    }
+                }
+                _ => {
+                    style.set_fill(fill.clone());
+                }
+            }
+        }
+    }
+
+    if let Some(borders) = differential.get_borders() {
+        let target = style.get_borders_mut();
+        if !borders.get_left_border().is_visually_empty() {
+            target.set_left_border(borders.get_left_border().clone());
+        }
+        if !borders.get_right_border().is_visually_empty() {
+            target.set_right_border(borders.get_right_border().clone());
+        }
+        if !borders.get_top_border().is_visually_empty() {
+            target.set_top_border(borders.get_top_border().clone());
+        }
+        if !borders.get_bottom_border().is_visually_empty() {
+            target.set_bottom_border(borders.get_bottom_border().clone());
+        }
+    }
+
+    if let Some(v) = differential.get_numbering_format() {
+        style.set_numbering_format(v.clone());
+    }
+    if let Some(v) = differential.get_alignment() {
+        style.set_alignment(v.clone());
+    }
+}
+
+// Cell area of a range as (col_start, row_start, col_end, row_end). Whole rows or columns are unbounded.
+fn get_ranges(formatting: &ConditionalFormatting) -> Vec<(u32, u32, u32, u32)> {
+    formatting
+        .get_sequence_of_references()
+        .get_range_collection()
+        .iter()
+        .map(|v| {
+            let (row_start, row_end, col_start, col_end) = get_start_and_end_point(&v.get_range());
+            (
+                col_start.max(1),
+                row_start.max(1),
+                if col_end == 0 { u32::MAX } else { col_end },
+                if row_end == 0 { u32::MAX } else { row_end },
+            )
+        })
+        .collect()
+}
+
+#[inline]
+fn is_in_range(range: &(u32, u32, u32, u32), col: u32, row: u32) -> bool {
+    col >= range.0 && col <= range.2 && row >= range.1 && row <= range.3
+}
+
+// Statistics of the values of the cells of a conditional formatting range.
+struct RangeStatistics {
+    anchor: (u32, u32),
+    // sorted ascending
+    numbers: Vec<f64>,
+    average: Option<f64>,
+    // population standard deviation
+    std_deviation: f64,
+    occurrences: HashMap<String, usize>,
+}
+impl RangeStatistics {
+    fn new(
+        ranges: &[(u32, u32, u32, u32)],
+        worksheet: &Worksheet,
+        calculator: &mut Calculator,
+    ) -> Self {
+        let anchor = ranges.first().map(|v| (v.0, v.1)).unwrap_or((1, 1));
+        let mut numbers: Vec<f64> = Vec::new();
+        let mut occurrences: HashMap<String, usize> = HashMap::new();
+        for cell in worksheet.get_cell_collection() {
+            let col = *cell.get_coordinate().get_col_num();
+            let row = *cell.get_coordinate().get_row_num();
+            if !ranges.iter().any(|v| is_in_range(v, col, row)) {
+                continue;
+            }
+            let value = calculator.calculate_cell(0, col, row);
+            if let CalcValue::Number(v) = value {
+                numbers.push(v);
+            }
+            if let Some(key) = get_occurrence_key(&value) {
+                *occurrences.entry(key).or_insert(0) += 1;
+            }
+        }
+        numbers.sort_by(f64::total_cmp);
+
+        let average = if numbers.is_empty() {
+            None
+        } else {
+            Some(numbers.iter().sum::<f64>() / numbers.len() as f64)
+        };
+        let std_deviation = match average {
+            Some(average) => (numbers.iter().map(|v| (v - average).powi(2)).sum::<f64>()
+                / numbers.len() as f64)
+                .sqrt(),
+            None => 0f64,
+        };
+        Self {
+            anchor,
+            numbers,
+            average,
+            std_deviation,
+            occurrences,
+        }
+    }
+}
+
+// The statistics of a range and the calculator to evaluate the formulas of its rules.
+struct RangeContext<'a, 'b> {
+    calculator: &'b mut Calculator<'a>,
+    statistics: &'b RangeStatistics,
+    date1904: bool,
+}
+impl RangeContext<'_, '_> {
+    // Formulas are relative to the top-left cell of the range.
+    fn evaluate_formula(&mut self, formula: &str, col: u32, row: u32) -> CalcValue {
+        let mut tokens = parse_to_tokens(format!("={}", formula));
+        adjustment_formula_coordinate(
+            &mut tokens,
+            &(col as i32 - self.statistics.anchor.0 as i32),
+            &(row as i32 - self.statistics.anchor.1 as i32),
+        );
+        self.calculator
+            .evaluate_formula(0, &render(&tokens))
+            .into_scalar()
+    }
+
+    fn get_percentile(&self, percent: f64) -> Option<f64> {
+        let numbers = &self.statistics.numbers;
+        if numbers.is_empty() {
+            return None;
+        }
+        let rank = (percent / 100f64).clamp(0f64, 1f64) * (numbers.len() - 1) as f64;
+        let lower = rank.floor() as usize;
+        let upper = rank.ceil() as usize;
+        Some(numbers[lower] + (numbers[upper] - numbers[lower]) * (rank - lower as f64))
+    }
+
+    // Threshold of a cfvo of a color scale, data bar or icon set.
+    fn get_threshold(&mut self, cfvo: &ConditionalFormatValueObject) -> Option<f64> {
+        let min = *self.statistics.numbers.first()?;
+        let max = *self.statistics.numbers.last()?;
+        let val = || -> Option<f64> { parse_number(cfvo.get_val()) };
+        match cfvo.get_type() {
+            ConditionalFormatValueObjectValues::Min => Some(min),
+            ConditionalFormatValueObjectValues::Max => Some(max),
+            ConditionalFormatValueObjectValues::AutoMin => Some(min.min(0f64)),
+            ConditionalFormatValueObjectValues::AutoMax => Some(max.max(0f64)),
+            ConditionalFormatValueObjectValues::Percent => val()
+                .or_else(|| self.evaluate_threshold_formula(cfvo))
+                .map(|v| min + (max - min) * v / 100f64),
+            ConditionalFormatValueObjectValues::Percentile => val()
+                .or_else(|| self.evaluate_threshold_formula(cfvo))
+                .and_then(|v| self.get_percentile(v)),
+            ConditionalFormatValueObjectValues::Number
+            | ConditionalFormatValueObjectValues::Formula => {
+                val().or_else(|| self.evaluate_threshold_formula(cfvo))
+            }
+        }
+    }
+
+    fn evaluate_threshold_formula(&mut self, cfvo: &ConditionalFormatValueObject) -> Option<f64> {
+        if cfvo.get_val().is_empty() {
+            return None;
+        }
+        let (col, row) = self.statistics.anchor;
+        self.evaluate_formula(cfvo.get_val(), col, row)
+            .to_number()
+            .ok()
+    }
+}
+
+fn is_match(
+    rule: &ConditionalFormattingRule,
+    value: &CalcValue,
+    context: &mut RangeContext,
+    col: u32,
+    row: u32,
+    today: NaiveDate,
+) -> bool {
+    match rule.get_type() {
+        ConditionalFormatValues::CellIs => is_match_cell_is(rule, value, context, col, row),
+        ConditionalFormatValues::Expression => match rule.get_formula() {
+            Some(v) => context
+                .evaluate_formula(&v.get_address_str(), col, row)
+                .to_bool()
+                .unwrap_or(false),
+            None => false,
+        },
+        ConditionalFormatValues::ContainsText => {
+            is_match_text(value, rule.get_text(), |v, t| v.contains(t))
+        }
+        ConditionalFormatValues::NotContainsText => {
+            !is_match_text(value, rule.get_text(), |v, t| v.contains(t))
+        }
+        ConditionalFormatValues::BeginsWith => {
+            is_match_text(value, rule.get_text(), |v, t| v.starts_with(t))
+        }
+        ConditionalFormatValues::EndsWith => {
+            is_match_text(value, rule.get_text(), |v, t| v.ends_with(t))
+        }
+        ConditionalFormatValues::ContainsBlanks => is_blank(value),
+        ConditionalFormatValues::NotContainsBlanks => !is_blank(value),
+        ConditionalFormatValues::ContainsErrors => value.is_error(),
+        ConditionalFormatValues::NotContainsErrors => !value.is_error(),
+        ConditionalFormatValues::Top10 => is_match_top10(rule, value, context),
+        ConditionalFormatValues::AboveAverage => is_match_above_average(rule, value, context),
+        ConditionalFormatValues::DuplicateValues => get_occurrence(value, context) > 1,
+        ConditionalFormatValues::UniqueValues => get_occurrence(value, context) == 1,
+        ConditionalFormatValues::TimePeriod => {
+            is_match_time_period(rule, value, today, context.date1904)
+        }
+        _ => false,
+    }
+}
+
+fn is_match_cell_is(
+    rule: &ConditionalFormattingRule,
+    value: &CalcValue,
+    context: &mut RangeContext,
+    col: u32,
+    row: u32,
+) -> bool {
+    if value.is_error() {
+        return false;
+    }
+    let operand = match rule.get_formula() {
+        Some(v) => context.evaluate_formula(&v.get_address_str(), col, row),
+        None => return false,
+    };
+    if operand.is_error() {
+        return false;
+    }
+    let ordering = compare_values(value, &operand);
+    match rule.get_operator() {
+        ConditionalFormattingOperatorValues::Equal => ordering == Ordering::Equal,
+        ConditionalFormattingOperatorValues::NotEqual => ordering != Ordering::Equal,
+        ConditionalFormattingOperatorValues::GreaterThan => ordering == Ordering::Greater,
+        ConditionalFormattingOperatorValues::GreaterThanOrEqual => ordering != Ordering::Less,
+        ConditionalFormattingOperatorValues::LessThan => ordering == Ordering::Less,
+        ConditionalFormattingOperatorValues::LessThanOrEqual => ordering != Ordering::Greater,
+        ConditionalFormattingOperatorValues::Between
+        | ConditionalFormattingOperatorValues::NotBetween => {
+            let operand2 = match rule.get_formula2() {
+                Some(v) => context.evaluate_formula(&v.get_address_str(), col, row),
+                None => return false,
+            };
+            if operand2.is_error() {
+                return false;
+            }
+            // the bounds may be given in either order
+            let (low, high) = match compare_values(&operand, &operand2) {
+                Ordering::Greater => (&operand2, &operand),
+                _ => (&operand, &operand2),
+            };
+            let is_between = compare_values(value, low) != Ordering::Less
+                && compare_values(value, high) != Ordering::Greater;
+            is_between == (rule.get_operator() == &ConditionalFormattingOperatorValues::Between)
+        }
+        ConditionalFormattingOperatorValues::ContainsText => {
+            is_match_text_value(value, &operand, |v, t| v.contains(t))
+        }
+        ConditionalFormattingOperatorValues::NotContains => {
+            !is_match_text_value(value, &operand, |v, t| v.contains(t))
+        }
+        ConditionalFormattingOperatorValues::BeginsWith => {
+            is_match_text_value(value, &operand, |v, t| v.starts_with(t))
+        }
+        ConditionalFormattingOperatorValues::EndsWith => {
+            is_match_text_value(value, &operand, |v, t| v.ends_with(t))
+        }
+    }
+}
+
+// Text comparisons are case-insensitive.
+fn is_match_text(value: &CalcValue, text: &str, f: impl Fn(&str, &str) -> bool) -> bool {
+    match value.to_text() {
+        Ok(v) => f(&v.to_lowercase(), &text.to_lowercase()),
+        Err(_) => false,
+    }
+}
+
+fn is_match_text_value(
+    value: &CalcValue,
+    operand: &CalcValue,
+    f: impl Fn(&str, &str) -> bool,
+) -> bool {
+    match operand.to_text() {
+        Ok(text) => is_match_text(value, &text, f),
+        Err(_) => false,
+    }
+}
+
+fn is_blank(value: &CalcValue) -> bool {
+    match value {
+        CalcValue::Empty => true,
+        CalcValue::Text(v) => v.trim().is_empty(),
+        _ => false,
+    }
+}
+
+fn is_match_top10(
+    rule: &ConditionalFormattingRule,
+    value: &CalcValue,
+    context: &RangeContext,
+) -> bool {
+    let number = match value {
+        CalcValue::Number(v) => *v,
+        _ => return false,
+    };
+    let numbers = &context.statistics.numbers;
+    if numbers.is_empty() {
+        return false;
+    }
+    let count = if *rule.get_percent() {
+        (numbers.len() as f64 * *rule.get_rank() as f64 / 100f64).floor() as usize
+    } else {
+        *rule.get_rank() as usize
+    };
+    let count = count.clamp(1, numbers.len());
+    if *rule.get_bottom() {
+        number <= numbers[count - 1]
+    } else {
+        number >= numbers[numbers.len() - count]
+    }
+}
+
+fn is_match_above_average(
+    rule: &ConditionalFormattingRule,
+    value: &CalcValue,
+    context: &RangeContext,
+) -> bool {
+    let number = match value {
+        CalcValue::Number(v) => *v,
+        _ => return false,
+    };
+    let average = match context.statistics.average {
+        Some(v) => v,
+        None => return false,
+    };
+    let deviation = context.statistics.std_deviation * *rule.get_std_dev() as f64;
+    let equal = *rule.get_equal_average();
+    if rule.is_above_average() {
+        let threshold = average + deviation;
+        number > threshold || (equal && number == threshold)
+    } else {
+        let threshold = average - deviation;
+        number < threshold || (equal && number == threshold)
+    }
+}
+
+// Number of cells of the range with the same value, compared case-insensitively.
+fn get_occurrence(value: &CalcValue, context: &RangeContext) -> usize {
+    match get_occurrence_key(value) {
+        Some(key) => context
+            .statistics
+            .occurrences
+            .get(&key)
+            .copied()
+            .unwrap_or(0),
+        None => 0,
+    }
+}
+
+fn get_occurrence_key(value: &CalcValue) -> Option<String> {
+    match value {
+        CalcValue::Empty | CalcValue::Array(_) => None,
+        CalcValue::Number(v) => Some(format!("n{}", number_to_string(*v))),
+        CalcValue::Text(v) => Some(format!("t{}", v.to_lowercase())),
+        CalcValue::Bool(v) => Some(format!("b{}", v)),
+        CalcValue::Error(_) => value.to_text().ok().or(Some(String::from("e"))),
+    }
+}
+
+fn is_match_time_period(
+    rule: &ConditionalFormattingRule,
+    value: &CalcValue,
+    today: NaiveDate,
+    date1904: bool,
+) -> bool {
+    let number = match value {
+        CalcValue::Number(v) => *v,
+        _ => return false,
+    };
+    let date = excel_to_date_time_object_crate(&number, None, !date1904).date();
+    let month_index = |v: NaiveDate| v.year() * 12 + v.month0() as i32;
+    let week_start = |v: NaiveDate| v - Duration::days(v.weekday().num_days_from_sunday() as i64);
+    match rule.get_time_period() {
+        TimePeriodValues::Today => date == today,
+        TimePeriodValues::Yesterday => date == today - Duration::days(1),
+        TimePeriodValues::Tomorrow => date == today + Duration::days(1),
+        TimePeriodValues::Last7Days => date <= today && date > today - Duration::days(7),
+        TimePeriodValues::ThisWeek => week_start(date) == week_start(today),
+        TimePeriodValues::LastWeek => week_start(date) == week_start(today) - Duration::days(7),
+        TimePeriodValues::NextWeek => week_start(date) == week_start(today) + Duration::days(7),
+        TimePeriodValues::ThisMonth => month_index(date) == month_index(today),
+        TimePeriodValues::LastMonth => month_index(date) == month_index(today) - 1,
+        TimePeriodValues::NextMonth => month_index(date) == month_index(today) + 1,
+    }
+}
+
+fn get_color_scale_color(
+    color_scale: &ColorScale,
+    value: &CalcValue,
+    context: &mut RangeContext,
+) -> Option<Color> {
+    let number = match value {
+        CalcValue::Number(v) => *v,
+        _ => return None,
+    };
+    let mut stops: Vec<(f64, (u8, u8, u8))> = Vec::new();
+    for (cfvo, color) in color_scale
+        .get_cfvo_collection()
+        .iter()
+        .zip(color_scale.get_color_collection())
+    {
+        stops.push((context.get_threshold(cfvo)?, parse_rgb(color.get_argb())?));
+    }
+    let (first, last) = (stops.first()?, stops.last()?);
+    let rgb = if number <= first.0 {
+        first.1
+    } else if number >= last.0 {
+        last.1
+    } else {
+        let index = stops.iter().position(|v| number < v.0)?;
+        let (low, high) = (&stops[index - 1], &stops[index]);
+        let ratio = if high.0 > low.0 {
+            (number - low.0) / (high.0 - low.0)
+        } else {
+            1f64
+        };
+        let mix = |a: u8, b: u8| (a as f64 + (b as f64 - a as f64) * ratio).round() as u8;
+        (
+            mix(low.1 .0, high.1 .0),
+            mix(low.1 .1, high.1 .1),
+            mix(low.1 .2, high.1 .2),
+        )
+    };
+    let mut color = Color::default();
+    color.set_argb(format!("FF{:02X}{:02X}{:02X}", rgb.0, rgb.1, rgb.2));
+    Some(color)
+}
+
+fn parse_rgb(argb: &str) -> Option<(u8, u8, u8)> {
+    let rgb = match argb.len() {
+        8 => &argb[2..],
+        6 => argb,
+        _ => return None,
+    };
+    Some((
+        u8::from_str_radix(&rgb[0..2], 16).ok()?,
+        u8::from_str_radix(&rgb[2..4], 16).ok()?,
+        u8::from_str_radix(&rgb[4..6], 16).ok()?,
+    ))
+}
+
+fn get_data_bar_length(
+    data_bar: &DataBar,
+    value: &CalcValue,
+    context: &mut RangeContext,
+) -> Option<f64> {
+    let number = match value {
+        CalcValue::Number(v) => *v,
+        _ => return None,
+    };
+    let cfvo_collection = data_bar.get_cfvo_collection();
+    let low = context.get_threshold(cfvo_collection.first()?)?;
+    let high = context.get_threshold(cfvo_collection.get(1)?)?;
+    let ratio = if high > low {
+        ((number - low) / (high - low)).clamp(0f64, 1f64)
+    } else {
+        1f64
+    };
+    let (min_length, max_length) = data_bar.get_length_range();
+    Some(min_length + (max_length - min_length) * ratio)
+}
+
+fn get_data_bar_color(data_bar: &DataBar, value: &CalcValue) -> Option<Color> {
+    let is_negative = matches!(value, CalcValue::Number(v) if *v < 0f64);
+    if is_negative
+        && data_bar.get_negative_fill_color().has_value()
+        && !*data_bar.get_negative_bar_color_same_as_positive()
+    {
+        return Some(data_bar.get_negative_fill_color().clone());
+    }
+    data_bar.get_color_collection().first().cloned()
+}
+
+fn get_icon(
+    icon_set: &IconSet,
+    value: &CalcValue,
+    context: &mut RangeContext,
+) -> Option<ConditionalFormattingIcon> {
+    let number = match value {
+        CalcValue::Number(v) => *v,
+        _ => return None,
+    };
+    let cfvo_collection = icon_set.get_cfvo_collection();
+    if cfvo_collection.is_empty() {
+        return None;
+    }
+    let mut index = 0;
+    for (i, cfvo) in cfvo_collection.iter().enumerate().skip(1) {
+        let threshold = context.get_threshold(cfvo)?;
+        let is_over = if cfvo.is_greater_than_or_equal() {
+            number >= threshold
+        } else {
+            number > threshold
+        };
+        if is_over {
+            index = i;
+        }
+    }
+    if *icon_set.get_reverse() {
+        index = cfvo_collection.len() - 1 - index;
+    }
+    if let Some(v) = icon_set.get_icon_collection().get(index) {
+        return Some(v.clone());
+    }
+    let mut icon = ConditionalFormattingIcon::default();
+    icon.set_icon_set(icon_set.get_icon_set().clone())
+        .set_icon_id(index as u32);
+    Some(icon)
+}
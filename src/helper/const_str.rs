@@ -2,6 +2,14 @@ pub(crate) const CERTIFICATE_NS: &str =
     "http://schemas.microsoft.com/office/2006/keyEncryptor/certificate";
 pub(crate) const CHART_NS: &str =
     "http://schemas.openxmlformats.org/officeDocument/2006/relationships/chart";
+pub(crate) const CHART_EX_NS: &str =
+    "http://schemas.microsoft.com/office/2014/relationships/chartEx";
+pub(crate) const CHART_STYLE_NS: &str =
+    "http://schemas.microsoft.com/office/2011/relationships/chartStyle";
+pub(crate) const CHART_COLOR_STYLE_NS: &str =
+    "http://schemas.microsoft.com/office/2011/relationships/chartColorStyle";
+pub(crate) const CHARTEX_NS: &str = "http://schemas.microsoft.com/office/drawing/2014/chartex";
+pub(crate) const CX1_NS: &str = "http://schemas.microsoft.com/office/drawing/2015/9/8/chartex";
 pub(crate) const COMMENTS_NS: &str =
     "http://schemas.openxmlformats.org/officeDocument/2006/relationships/comments";
 pub(crate) const CONTYPES_NS: &str = "http://schemas.openxmlformats.org/package/2006/content-types";
@@ -44,6 +52,10 @@ pub(crate) const PRINTER_SETTINGS_NS: &str =
     "http://schemas.openxmlformats.org/officeDocument/2006/relationships/printerSettings";
 pub(crate) const PIVOT_CACHE_DEF_NS: &str =
     "http://schemas.openxmlformats.org/officeDocument/2006/relationships/pivotCacheDefinition";
+pub(crate) const PIVOT_CACHE_RECORDS_NS: &str =
+    "http://schemas.openxmlformats.org/officeDocument/2006/relationships/pivotCacheRecords";
+pub(crate) const PIVOT_TABLE_NS: &str =
+    "http://schemas.openxmlformats.org/officeDocument/2006/relationships/pivotTable";
 pub(crate) const STYLES_NS: &str =
     "http://schemas.openxmlformats.org/officeDocument/2006/relationships/styles";
 pub(crate) const SHARED_STRINGS_NS: &str =
@@ -85,12 +97,21 @@ pub(crate) const COREPROPS_REL: &str =
 
 pub(crate) const CHART_TYPE: &str =
     "application/vnd.openxmlformats-officedocument.drawingml.chart+xml";
+pub(crate) const CHART_EX_TYPE: &str = "application/vnd.ms-office.chartex+xml";
+pub(crate) const CHART_STYLE_TYPE: &str = "application/vnd.ms-office.chartstyle+xml";
+pub(crate) const CHART_COLOR_STYLE_TYPE: &str = "application/vnd.ms-office.chartcolorstyle+xml";
 pub(crate) const COMMENTS_TYPE: &str =
     "application/vnd.openxmlformats-officedocument.spreadsheetml.comments+xml";
 pub(crate) const CORE_PROPS_TYPE: &str =
     "application/vnd.openxmlformats-package.core-properties+xml";
 pub(crate) const DRAWING_TYPE: &str = "application/vnd.openxmlformats-officedocument.drawing+xml";
 pub(crate) const OLE_OBJECT_TYPE: &str = "application/vnd.openxmlformats-officedocument.oleObject";
+pub(crate) const PIVOT_CACHE_DEF_TYPE: &str =
+    "application/vnd.openxmlformats-officedocument.spreadsheetml.pivotCacheDefinition+xml";
+pub(crate) const PIVOT_CACHE_RECORDS_TYPE: &str =
+    "application/vnd.openxmlformats-officedocument.spreadsheetml.pivotCacheRecords+xml";
+pub(crate) const PIVOT_TABLE_TYPE: &str =
+    "application/vnd.openxmlformats-officedocument.spreadsheetml.pivotTable+xml";
 pub(crate) const PRNTR_SETTINGS_TYPE: &str =
     "application/vnd.openxmlformats-officedocument.spreadsheetml.printerSettings";
 pub(crate) const REL_TYPE: &str = "application/vnd.openxmlformats-package.relationships+xml";
@@ -117,10 +138,13 @@ pub(crate) const CUSTOM_PROPS_TYPE: &str =
     "application/vnd.openxmlformats-officedocument.custom-properties+xml";
 
 pub(crate) const PKG_CHARTS: &str = "xl/charts";
+pub(crate) const PKG_CHARTS_RELS: &str = "xl/charts/_rels";
 pub(crate) const PKG_DRAWINGS: &str = "xl/drawings";
 pub(crate) const PKG_DRAWINGS_RELS: &str = "xl/drawings/_rels/drawing";
 pub(crate) const PKG_EMBEDDINGS: &str = "xl/embeddings";
 pub(crate) const PKG_MEDIA: &str = "xl/media";
+pub(crate) const PKG_PIVOT_CACHE: &str = "xl/pivotCache";
+pub(crate) const PKG_PIVOT_TABLES: &str = "xl/pivotTables";
 pub(crate) const PKG_PRNTR_SETTINGS: &str = "xl/printerSettings";
 pub(crate) const PKG_SHARED_STRINGS: &str = "xl/sharedStrings.xml";
 pub(crate) const PKG_SHEET: &str = "xl/worksheets/sheet";
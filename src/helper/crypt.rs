@@ -1,24 +1,32 @@
 use super::const_str::*;
+use crate::reader::driver::get_attribute;
 use crate::structs::SheetProtection;
 use crate::structs::WorkbookProtection;
 use crate::writer::driver::*;
-use aes::cipher::{block_padding::NoPadding, BlockEncryptMut, KeyIvInit};
+use crate::XlsxError;
+use aes::cipher::{block_padding::NoPadding, BlockDecryptMut, BlockEncryptMut, KeyIvInit};
 use base64::{engine::general_purpose::STANDARD, Engine as _};
 use byteorder::{ByteOrder, LittleEndian};
 use cfb;
 use hmac::{Hmac, Mac};
 use quick_xml::events::{BytesDecl, Event};
+use quick_xml::Reader;
 use quick_xml::Writer;
 use sha2::{Digest, Sha512};
 use std::cmp::Ordering;
 use std::io;
+use std::io::Read;
 use std::io::Write;
 use std::path::Path;
 
 type Aes256CbcEnc = cbc::Encryptor<aes::Aes256>;
+type Aes128CbcDec = cbc::Decryptor<aes::Aes128>;
+type Aes192CbcDec = cbc::Decryptor<aes::Aes192>;
+type Aes256CbcDec = cbc::Decryptor<aes::Aes256>;
 
 const ENCRYPTION_INFO_PREFIX: &[u8] = &[0x04, 0x00, 0x04, 0x00, 0x40, 0x00, 0x00, 0x00]; // First 4 bytes are the version number, second 4 bytes are reserved.
 const PACKAGE_ENCRYPTION_CHUNK_SIZE: usize = 4096;
+const MAX_SPIN_COUNT: usize = 10_000_000; // upper bound of spinCount in [MS-OFFCRYPTO]
 const PACKAGE_OFFSET: usize = 8; // First 8 bytes are the size of the stream
 const BLOCK_KEYS_DATA_INTEGRITY_HMAC_KEY: &[u8] = &[0x5f, 0xb2, 0xad, 0x01, 0x0c, 0xb9, 0xe1, 0xf6];
 const BLOCK_KEYS_DATA_INTEGRITY_HMAC_VALUE: &[u8] =
@@ -256,6 +264,325 @@ pub fn encrypt<P: AsRef<Path>>(filepath: &P, data: &[u8], password: &str) {
     }
 }
 
+/// Decrypt an Agile-encrypted CFB container (as written by `encrypt`) into the raw xlsx package.
+pub fn decrypt<R: io::Read + io::Seek>(reader: R, password: &str) -> Result<Vec<u8>, XlsxError> {
+    let mut comp = cfb::CompoundFile::open(reader)?;
+    let mut encryption_info_buffer = Vec::new();
+    comp.open_stream("EncryptionInfo")
+        .map_err(|_| XlsxError::CorruptedPackage("missing EncryptionInfo stream".to_string()))?
+        .read_to_end(&mut encryption_info_buffer)?;
+    let mut encrypted_package = Vec::new();
+    comp.open_stream("EncryptedPackage")
+        .map_err(|_| XlsxError::CorruptedPackage("missing EncryptedPackage stream".to_string()))?
+        .read_to_end(&mut encrypted_package)?;
+
+    let info = EncryptionInfo::parse(&encryption_info_buffer)?;
+    let corrupted = |e: String| XlsxError::CorruptedPackage(e);
+
+    // verifier: the hash of the decrypted input must match the decrypted hash value
+    let verifier_hash_input_key = convert_password_to_key(
+        password,
+        &info.key_hash_algorithm,
+        &info.key_salt_value,
+        &info.key_spin_count,
+        &info.key_key_bits,
+        BLOCK_VERIFIER_HASH_INPUT,
+    );
+    let verifier_hash_input = crypt(
+        &false,
+        &info.key_cipher_algorithm,
+        &info.key_cipher_chaining,
+        &verifier_hash_input_key,
+        &info.key_salt_value,
+        &info.key_encrypted_verifier_hash_input,
+    )
+    .map_err(corrupted)?;
+    let verifier_hash_value_key = convert_password_to_key(
+        password,
+        &info.key_hash_algorithm,
+        &info.key_salt_value,
+        &info.key_spin_count,
+        &info.key_key_bits,
+        BLOCK_VERIFIER_HASH_VALUE,
+    );
+    let verifier_hash_value = crypt(
+        &false,
+        &info.key_cipher_algorithm,
+        &info.key_cipher_chaining,
+        &verifier_hash_value_key,
+        &info.key_salt_value,
+        &info.key_encrypted_verifier_hash_value,
+    )
+    .map_err(corrupted)?;
+    let verifier_hash = hash(
+        &info.key_hash_algorithm,
+        vec![&verifier_hash_input[..verifier_hash_input.len().min(info.key_salt_value.len())]],
+    )
+    .map_err(corrupted)?;
+    if verifier_hash_value.len() < info.key_hash_size
+        || verifier_hash.len() < info.key_hash_size
+        || verifier_hash_value[..info.key_hash_size] != verifier_hash[..info.key_hash_size]
+    {
+        return Err(XlsxError::WrongPassword);
+    }
+
+    // package key
+    let key = convert_password_to_key(
+        password,
+        &info.key_hash_algorithm,
+        &info.key_salt_value,
+        &info.key_spin_count,
+        &info.key_key_bits,
+        BLOCK_KEYS_KEY,
+    );
+    let package_key = crypt(
+        &false,
+        &info.key_cipher_algorithm,
+        &info.key_cipher_chaining,
+        &key,
+        &info.key_salt_value,
+        &info.key_encrypted_key_value,
+    )
+    .map_err(corrupted)?;
+    let package_key_len = info.package_key_bits / 8;
+    if package_key.len() < package_key_len {
+        return Err(corrupted("encrypted key value is too short".to_string()));
+    }
+    let package_key = buffer_slice(&package_key, 0, package_key_len);
+
+    // data integrity
+    let hmac_key_iv = create_iv(
+        &info.package_hash_algorithm,
+        &info.package_salt_value,
+        &info.package_block_size,
+        BLOCK_KEYS_DATA_INTEGRITY_HMAC_KEY,
+    );
+    let hmac_key = crypt(
+        &false,
+        &info.package_cipher_algorithm,
+        &info.package_cipher_chaining,
+        &package_key,
+        &hmac_key_iv,
+        &info.data_integrity_encrypted_hmac_key,
+    )
+    .map_err(corrupted)?;
+    let hmac_value_iv = create_iv(
+        &info.package_hash_algorithm,
+        &info.package_salt_value,
+        &info.package_block_size,
+        BLOCK_KEYS_DATA_INTEGRITY_HMAC_VALUE,
+    );
+    let hmac_value = crypt(
+        &false,
+        &info.package_cipher_algorithm,
+        &info.package_cipher_chaining,
+        &package_key,
+        &hmac_value_iv,
+        &info.data_integrity_encrypted_hmac_value,
+    )
+    .map_err(corrupted)?;
+    let hash_size = info.package_hash_size;
+    if hmac_key.len() < hash_size || hmac_value.len() < hash_size {
+        return Err(corrupted("data integrity block is too short".to_string()));
+    }
+    let actual_hmac_value = hmac(
+        &info.package_hash_algorithm,
+        &hmac_key[..hash_size],
+        vec![&encrypted_package],
+    )
+    .map_err(corrupted)?;
+    if actual_hmac_value[..hash_size] != hmac_value[..hash_size] {
+        return Err(corrupted("data integrity check failed".to_string()));
+    }
+
+    // package
+    if encrypted_package.len() < PACKAGE_OFFSET
+        || (encrypted_package.len() - PACKAGE_OFFSET) % info.package_block_size != 0
+        || buffer_read_u_int32_le(&encrypted_package, &0) as usize
+            > encrypted_package.len() - PACKAGE_OFFSET
+    {
+        return Err(corrupted("invalid encrypted package size".to_string()));
+    }
+    Ok(crypt_package(
+        &false,
+        &info.package_cipher_algorithm,
+        &info.package_cipher_chaining,
+        &info.package_hash_algorithm,
+        &info.package_block_size,
+        &info.package_salt_value,
+        &package_key,
+        &encrypted_package,
+    ))
+}
+
+// Parameters of the agile `EncryptionInfo` stream.
+#[derive(Default)]
+struct EncryptionInfo {
+    package_salt_value: Vec<u8>,
+    package_block_size: usize,
+    package_key_bits: usize,
+    package_hash_size: usize,
+    package_cipher_algorithm: String,
+    package_cipher_chaining: String,
+    package_hash_algorithm: String,
+    data_integrity_encrypted_hmac_key: Vec<u8>,
+    data_integrity_encrypted_hmac_value: Vec<u8>,
+    key_spin_count: usize,
+    key_salt_value: Vec<u8>,
+    key_block_size: usize,
+    key_key_bits: usize,
+    key_hash_size: usize,
+    key_cipher_algorithm: String,
+    key_cipher_chaining: String,
+    key_hash_algorithm: String,
+    key_encrypted_verifier_hash_input: Vec<u8>,
+    key_encrypted_verifier_hash_value: Vec<u8>,
+    key_encrypted_key_value: Vec<u8>,
+}
+
+impl EncryptionInfo {
+    fn parse(buffer: &[u8]) -> Result<Self, XlsxError> {
+        if buffer.len() < ENCRYPTION_INFO_PREFIX.len() {
+            return Err(XlsxError::CorruptedPackage(
+                "EncryptionInfo stream is too short".to_string(),
+            ));
+        }
+        // only the agile encryption (version 4.4) is supported
+        if buffer[..4] != ENCRYPTION_INFO_PREFIX[..4] {
+            return Err(XlsxError::UnsupportedEncryption(format!(
+                "encryption version {}.{}",
+                buffer[0], buffer[2]
+            )));
+        }
+
+        let mut info = EncryptionInfo::default();
+        let mut has_key_data = false;
+        let mut has_data_integrity = false;
+        let mut has_encrypted_key = false;
+        let mut reader = Reader::from_reader(&buffer[ENCRYPTION_INFO_PREFIX.len()..]);
+        let mut buf = Vec::new();
+        loop {
+            let ev = reader
+                .read_event_into(&mut buf)
+                .map_err(|e| XlsxError::CorruptedPackage(e.to_string()))?;
+            match ev {
+                Event::Start(ref e) | Event::Empty(ref e) => match e.local_name().into_inner() {
+                    b"keyData" => {
+                        info.package_salt_value = base64_attribute(e, "saltValue")?;
+                        info.package_block_size = number_attribute(e, "blockSize")?;
+                        info.package_key_bits = number_attribute(e, "keyBits")?;
+                        info.package_hash_size = number_attribute(e, "hashSize")?;
+                        info.package_cipher_algorithm = string_attribute(e, "cipherAlgorithm")?;
+                        info.package_cipher_chaining = string_attribute(e, "cipherChaining")?;
+                        info.package_hash_algorithm = string_attribute(e, "hashAlgorithm")?;
+                        has_key_data = true;
+                    }
+                    b"dataIntegrity" => {
+                        info.data_integrity_encrypted_hmac_key =
+                            base64_attribute(e, "encryptedHmacKey")?;
+                        info.data_integrity_encrypted_hmac_value =
+                            base64_attribute(e, "encryptedHmacValue")?;
+                        has_data_integrity = true;
+                    }
+                    b"encryptedKey" if !has_encrypted_key => {
+                        info.key_spin_count = number_attribute(e, "spinCount")?;
+                        info.key_salt_value = base64_attribute(e, "saltValue")?;
+                        info.key_block_size = number_attribute(e, "blockSize")?;
+                        info.key_key_bits = number_attribute(e, "keyBits")?;
+                        info.key_hash_size = number_attribute(e, "hashSize")?;
+                        info.key_cipher_algorithm = string_attribute(e, "cipherAlgorithm")?;
+                        info.key_cipher_chaining = string_attribute(e, "cipherChaining")?;
+                        info.key_hash_algorithm = string_attribute(e, "hashAlgorithm")?;
+                        info.key_encrypted_verifier_hash_input =
+                            base64_attribute(e, "encryptedVerifierHashInput")?;
+                        info.key_encrypted_verifier_hash_value =
+                            base64_attribute(e, "encryptedVerifierHashValue")?;
+                        info.key_encrypted_key_value = base64_attribute(e, "encryptedKeyValue")?;
+                        has_encrypted_key = true;
+                    }
+                    _ => {}
+                },
+                Event::Eof => break,
+                _ => {}
+            }
+            buf.clear();
+        }
+
+        if !has_key_data || !has_data_integrity {
+            return Err(XlsxError::CorruptedPackage(
+                "EncryptionInfo is incomplete".to_string(),
+            ));
+        }
+        if !has_encrypted_key {
+            return Err(XlsxError::UnsupportedEncryption(
+                "no password key encryptor".to_string(),
+            ));
+        }
+        for (cipher_algorithm, cipher_chaining, hash_algorithm) in [
+            (
+                &info.package_cipher_algorithm,
+                &info.package_cipher_chaining,
+                &info.package_hash_algorithm,
+            ),
+            (
+                &info.key_cipher_algorithm,
+                &info.key_cipher_chaining,
+                &info.key_hash_algorithm,
+            ),
+        ] {
+            if cipher_algorithm != "AES"
+                || cipher_chaining != "ChainingModeCBC"
+                || hash(hash_algorithm, vec![]).is_err()
+            {
+                return Err(XlsxError::UnsupportedEncryption(format!(
+                    "{} {} {}",
+                    cipher_algorithm, cipher_chaining, hash_algorithm
+                )));
+            }
+        }
+        if info.key_spin_count > MAX_SPIN_COUNT {
+            return Err(XlsxError::UnsupportedEncryption(format!(
+                "spin count {}",
+                info.key_spin_count
+            )));
+        }
+        for key_bits in [info.package_key_bits, info.key_key_bits] {
+            if ![128, 192, 256].contains(&key_bits) {
+                return Err(XlsxError::UnsupportedEncryption(format!(
+                    "key size {}",
+                    key_bits
+                )));
+            }
+        }
+        if info.package_block_size != 16 || info.key_block_size != 16 {
+            return Err(XlsxError::CorruptedPackage(
+                "invalid block size".to_string(),
+            ));
+        }
+        Ok(info)
+    }
+}
+
+fn string_attribute(e: &quick_xml::events::BytesStart<'_>, key: &str) -> Result<String, XlsxError> {
+    get_attribute(e, key.as_bytes())?
+        .ok_or_else(|| XlsxError::CorruptedPackage(format!("missing attribute {}", key)))
+}
+
+fn number_attribute(e: &quick_xml::events::BytesStart<'_>, key: &str) -> Result<usize, XlsxError> {
+    string_attribute(e, key)?
+        .parse()
+        .map_err(|_| XlsxError::CorruptedPackage(format!("invalid attribute {}", key)))
+}
+
+fn base64_attribute(
+    e: &quick_xml::events::BytesStart<'_>,
+    key: &str,
+) -> Result<Vec<u8>, XlsxError> {
+    STANDARD
+        .decode(string_attribute(e, key)?)
+        .map_err(|_| XlsxError::CorruptedPackage(format!("invalid attribute {}", key)))
+}
+
 // Encrypt/decrypt the package
 #[allow(clippy::too_many_arguments)]
 fn crypt_package(
@@ -271,15 +598,16 @@ fn crypt_package(
     // The first 8 bytes is supposed to be the length, but it seems like it is really the length - 4..
     let mut output_chunks: Vec<Vec<u8>> = Vec::new();
     let offset = if encrypt == &true { 0 } else { PACKAGE_OFFSET };
+    let data_len = input.len() - offset;
 
     // The package is encoded in chunks. Encrypt/decrypt each and concat.
     let mut i: usize = 0;
     let mut end = 0;
-    while end < input.len() {
+    while end < data_len {
         let start = end;
         end = start + PACKAGE_ENCRYPTION_CHUNK_SIZE;
-        if end > input.len() {
-            end = input.len();
+        if end > data_len {
+            end = data_len;
         };
 
         // Grab the next chunk
@@ -358,13 +686,31 @@ fn create_iv(
 
 // Encrypt/decrypt input
 fn crypt(
-    _encrypt: &bool,
+    encrypt: &bool,
     _cipher_algorithm: &str,
     _cipher_chaining: &str,
     key: &[u8],
     iv: &[u8],
     input: &[u8],
 ) -> Result<Vec<u8>, String> {
+    if !*encrypt {
+        let mut buf = input.to_vec();
+        let pt = match key.len() * 8 {
+            128 => Aes128CbcDec::new_from_slices(key, iv)
+                .map_err(|e| e.to_string())?
+                .decrypt_padded_mut::<NoPadding>(&mut buf),
+            192 => Aes192CbcDec::new_from_slices(key, iv)
+                .map_err(|e| e.to_string())?
+                .decrypt_padded_mut::<NoPadding>(&mut buf),
+            256 => Aes256CbcDec::new_from_slices(key, iv)
+                .map_err(|e| e.to_string())?
+                .decrypt_padded_mut::<NoPadding>(&mut buf),
+            _ => {
+                return Err("key size not supported!".to_string());
+            }
+        };
+        return pt.map(|v| v.to_vec()).map_err(|e| e.to_string());
+    }
     let mut buf = [0u8; 4096];
     let pt_len = input.len();
     buf[..pt_len].copy_from_slice(input);
@@ -382,7 +728,7 @@ fn crypt(
 
 fn hmac(algorithm: &str, key: &[u8], buffers: Vec<&[u8]>) -> Result<Vec<u8>, String> {
     let mut mac = match algorithm {
-        "SHA512" => {
+        "SHA512" | "SHA-512" => {
             type HmacSha512 = Hmac<Sha512>;
             HmacSha512::new_from_slice(key).unwrap()
         }
@@ -933,4 +1279,48 @@ mod tests {
             "8d5869311b1c1fdb59a1de6fe1e6f2ce7dccd4deb198a6dfb1f7fb55bc03487d"
         );
     }
+
+    #[test]
+    fn test_hmac() {
+        let key = decode_hex("4c251b321d85cecfcb6d952ba6d81846").unwrap();
+        let result = hmac("SHA512", &key, vec![b"data"]).unwrap();
+        assert_eq!(result.len(), 64);
+        assert_eq!(hmac("SHA-512", &key, vec![b"data"]).unwrap(), result);
+        assert!(hmac("SHA1", &key, vec![b"data"]).is_err());
+    }
+
+    #[test]
+    fn test_parse_spin_count() {
+        let build = |spin_count: usize| {
+            let salt = [0u8; 16];
+            build_encryption_info(
+                &salt,
+                &16,
+                &256,
+                &64,
+                "AES",
+                "ChainingModeCBC",
+                "SHA512",
+                &salt,
+                &salt,
+                &spin_count,
+                &salt,
+                &16,
+                &256,
+                &64,
+                "AES",
+                "ChainingModeCBC",
+                "SHA512",
+                &salt,
+                &salt,
+                &salt,
+            )
+        };
+        let info = EncryptionInfo::parse(&build(MAX_SPIN_COUNT)).unwrap();
+        assert_eq!(info.key_spin_count, MAX_SPIN_COUNT);
+        assert!(matches!(
+            EncryptionInfo::parse(&build(MAX_SPIN_COUNT + 1)),
+            Err(XlsxError::UnsupportedEncryption(_))
+        ));
+    }
 }
@@ -1,18 +1,38 @@
-use chrono::{Duration, NaiveDateTime};
+use chrono::{Duration, NaiveDate, NaiveDateTime, Timelike};
 
 pub const CALENDAR_WINDOWS_1900: &str = "1900";
 pub const CALENDAR_MAC_1904: &str = "1904";
 
+#[inline]
 pub fn excel_to_date_time_object(
     excel_timestamp: &f64,
     time_zone: Option<String>,
+) -> NaiveDateTime {
+    excel_to_date_time_object_crate(excel_timestamp, time_zone, true)
+}
+
+#[inline]
+pub fn excel_to_date_time_object_mac_1904(
+    excel_timestamp: &f64,
+    time_zone: Option<String>,
+) -> NaiveDateTime {
+    excel_to_date_time_object_crate(excel_timestamp, time_zone, false)
+}
+
+pub(crate) fn excel_to_date_time_object_crate(
+    excel_timestamp: &f64,
+    time_zone: Option<String>,
+    is_calendar_windows_1900: bool,
 ) -> NaiveDateTime {
     let _time_zone = match time_zone {
         Some(v) => v,
         None => get_default_timezone(),
     };
 
-    let mut base_date = if excel_timestamp < &1f64 {
+    let mut base_date = if !is_calendar_windows_1900 {
+        // the 1904 date system has no leap year adjustment
+        NaiveDateTime::parse_from_str("1904-01-01 00:00:00", "%Y-%m-%d %T").unwrap()
+    } else if excel_timestamp < &1f64 {
         // Unix timestamp base date
         NaiveDateTime::parse_from_str("1970-01-01 00:00:00", "%Y-%m-%d %T").unwrap()
     } else {
@@ -80,7 +100,7 @@ pub fn convert_date_mac_1904(
     convert_date_crate(year, month, day, hours, minutes, seconds, false)
 }
 
-fn convert_date_crate(
+pub(crate) fn convert_date_crate(
     year: i32,
     month: i32,
     day: i32,
@@ -127,3 +147,52 @@ fn convert_date_crate(
 
     return (excel_date as f64 + excel_time) as f64;
 }
+
+// The day serial 0 stands for, and the serial of the fictitious 1900-02-29.
+fn get_base_date(is_calendar_windows_1900: bool) -> (NaiveDate, Option<i64>) {
+    if is_calendar_windows_1900 {
+        (NaiveDate::from_ymd_opt(1899, 12, 31).unwrap(), Some(60))
+    } else {
+        (NaiveDate::from_ymd_opt(1904, 1, 1).unwrap(), None)
+    }
+}
+
+/// Serial number of a date and time, with sub-second precision.
+/// Dates from 1900-03-01 on count the non-existent 1900-02-29 in the 1900 date system.
+pub(crate) fn date_time_to_excel_crate(
+    value: &NaiveDateTime,
+    is_calendar_windows_1900: bool,
+) -> f64 {
+    let (base_date, leap_day) = get_base_date(is_calendar_windows_1900);
+    let mut days = (value.date() - base_date).num_days();
+    if matches!(leap_day, Some(v) if days >= v) {
+        days += 1;
+    }
+    let nanoseconds =
+        value.num_seconds_from_midnight() as f64 * 1e9 + value.nanosecond().min(999_999_999) as f64;
+    days as f64 + nanoseconds / 86_400e9
+}
+
+/// Date and time of a serial number, rounded to the millisecond.
+/// Returns `None` for negative serials and for the fictitious 1900-02-29.
+pub(crate) fn excel_to_date_time_crate(
+    excel_timestamp: f64,
+    is_calendar_windows_1900: bool,
+) -> Option<NaiveDateTime> {
+    if !excel_timestamp.is_finite() || excel_timestamp < 0f64 {
+        return None;
+    }
+    let milliseconds = (excel_timestamp * 86_400_000f64).round() as i64;
+    let mut days = milliseconds.div_euclid(86_400_000);
+    let milliseconds = milliseconds.rem_euclid(86_400_000);
+    let (base_date, leap_day) = get_base_date(is_calendar_windows_1900);
+    match leap_day {
+        Some(v) if days == v => return None,
+        Some(v) if days > v => days -= 1,
+        _ => {}
+    }
+    base_date
+        .checked_add_signed(Duration::days(days))?
+        .and_hms_opt(0, 0, 0)?
+        .checked_add_signed(Duration::milliseconds(milliseconds))
+}
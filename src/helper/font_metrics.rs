@@ -0,0 +1,420 @@
+//! Text measurement for column auto width and row auto height.
+//!
+//! The widths of the common Office fonts (Calibri, Arial, Times New Roman, Courier New,
+//! MS Gothic, Yu Gothic, Meiryo) are embedded. Other fonts can be measured by
+//! registering their metrics with [`register_true_type_font`] or [`register_font_metrics`].
+//! Text in an unknown font is measured with the metrics of Calibri.
+//!
+//! Sizes are calculated in pixels at 96 DPI, the way Excel lays out a sheet at 100% zoom.
+
+mod embedded;
+mod true_type;
+
+pub use self::true_type::TrueTypeMetrics;
+
+use self::embedded::*;
+use crate::structs::Font;
+use crate::XlsxError;
+use std::collections::HashMap;
+use std::sync::{Arc, RwLock};
+
+/// Metrics of a font face, relative to the font size.
+pub trait FontMetrics: Send + Sync {
+    /// Advance width of a character in em.
+    fn get_char_width(&self, c: char) -> f64;
+
+    /// Height of a line in em.
+    fn get_line_height(&self) -> f64;
+}
+
+lazy_static! {
+    static ref REGISTERED_METRICS: RwLock<HashMap<String, Arc<dyn FontMetrics>>> =
+        RwLock::new(HashMap::new());
+}
+
+// Widening of a regular face used for bold text when no bold metrics are known.
+const BOLD_FACTOR: f64 = 1.07;
+
+// Space Excel leaves around the text of a cell, in pixels.
+const CELL_PADDING: f64 = 5f64;
+
+/// Register the metrics of a font.
+/// Register the bold face as `"<name> Bold"`, otherwise bold text is widened from the regular face.
+/// # Arguments
+/// * `name` - Font name as used in the styles. ex) "Noto Sans"
+/// * `metrics` - Metrics of the font.
+pub fn register_font_metrics<S: Into<String>, M: FontMetrics + 'static>(name: S, metrics: M) {
+    REGISTERED_METRICS
+        .write()
+        .unwrap()
+        .insert(name.into().to_lowercase(), Arc::new(metrics));
+}
+
+/// Register the metrics of a TrueType or OpenType font file.
+/// # Arguments
+/// * `name` - Font name as used in the styles. ex) "Noto Sans", "Noto Sans Bold"
+/// * `data` - Content of the font file.
+/// # Examples
+/// ```
+/// let result = umya_spreadsheet::helper::font_metrics::register_true_type_font("Broken", b"");
+/// assert!(result.is_err());
+/// ```
+pub fn register_true_type_font<S: Into<String>>(name: S, data: &[u8]) -> Result<(), XlsxError> {
+    let metrics = TrueTypeMetrics::parse(data)?;
+    register_font_metrics(name, metrics);
+    Ok(())
+}
+
+/// Whether a character is East Asian wide, drawn one em wide by the CJK fonts.
+pub(crate) fn is_wide_char(c: char) -> bool {
+    matches!(c as u32,
+        0x1100..=0x115F
+        | 0x2E80..=0x303E
+        | 0x3041..=0xA4CF
+        | 0xAC00..=0xD7A3
+        | 0xF900..=0xFAFF
+        | 0xFE30..=0xFE4F
+        | 0xFF00..=0xFF60
+        | 0xFFE0..=0xFFE6
+        | 0x20000..=0x3FFFD)
+}
+
+// Returns the metrics of a face and the widening applied to them.
+fn get_font_metrics(name: &str, bold: bool) -> (Arc<dyn FontMetrics>, f64) {
+    let key = name.to_lowercase();
+    {
+        let registered = REGISTERED_METRICS.read().unwrap();
+        if bold {
+            if let Some(v) = registered.get(&format!("{} bold", key)) {
+                return (v.clone(), 1f64);
+            }
+        }
+        if let Some(v) = registered.get(&key) {
+            return (v.clone(), if bold { BOLD_FACTOR } else { 1f64 });
+        }
+    }
+    let (metrics, is_bold) = get_embedded_metrics(&key, bold)
+        .unwrap_or_else(|| get_embedded_metrics("calibri", bold).unwrap());
+    let factor = if bold && !is_bold { BOLD_FACTOR } else { 1f64 };
+    (Arc::new(StaticMetrics(metrics)), factor)
+}
+
+struct StaticMetrics(&'static EmbeddedMetrics);
+
+impl FontMetrics for StaticMetrics {
+    fn get_char_width(&self, c: char) -> f64 {
+        self.0.get_char_width(c)
+    }
+
+    fn get_line_height(&self) -> f64 {
+        self.0.get_line_height()
+    }
+}
+
+#[inline]
+fn point_to_pixel(value: f64) -> f64 {
+    value * 96f64 / 72f64
+}
+
+/// Width of a single line of text in pixels. Each character is rounded to whole pixels.
+/// # Arguments
+/// * `text` - Text to measure.
+/// * `font_name` - Font name. ex) "Calibri"
+/// * `font_size` - Font size in points.
+/// * `bold` - Whether the text is bold.
+/// # Examples
+/// ```
+/// use umya_spreadsheet::helper::font_metrics::get_text_width;
+/// assert!(get_text_width("WWW", "Calibri", 11.0, false) > get_text_width("iii", "Calibri", 11.0, false));
+/// ```
+pub fn get_text_width(text: &str, font_name: &str, font_size: f64, bold: bool) -> f64 {
+    let (metrics, factor) = get_font_metrics(font_name, bold);
+    let size = point_to_pixel(font_size);
+    text.chars()
+        .map(|c| get_advance(metrics.as_ref(), c, factor, size))
+        .sum()
+}
+
+// Advance of a character in whole pixels, as hinted text is drawn.
+#[inline]
+fn get_advance(metrics: &dyn FontMetrics, c: char, factor: f64, size: f64) -> f64 {
+    (metrics.get_char_width(c) * factor * size).round()
+}
+
+/// Height of a line of text in a cell in pixels, including the spacing between rows.
+/// # Arguments
+/// * `font_name` - Font name. ex) "Calibri"
+/// * `font_size` - Font size in points.
+/// # Examples
+/// ```
+/// use umya_spreadsheet::helper::font_metrics::get_line_height;
+/// // 15 points
+/// assert_eq!(get_line_height("Calibri", 11.0), 20.0);
+/// ```
+pub fn get_line_height(font_name: &str, font_size: f64) -> f64 {
+    let (metrics, _) = get_font_metrics(font_name, false);
+    (metrics.get_line_height() * point_to_pixel(font_size)).ceil() + 2f64
+}
+
+/// Width of the widest digit in pixels, the unit of column widths.
+pub(crate) fn get_max_digit_width(font: &TextFont) -> f64 {
+    let (metrics, _) = get_font_metrics(&font.name, false);
+    let em = ('0'..='9')
+        .map(|c| metrics.get_char_width(c))
+        .fold(0f64, f64::max);
+    (em * point_to_pixel(font.size)).round().max(1f64)
+}
+
+/// Column width in characters that fits a text width in pixels.
+pub(crate) fn get_column_width(text_width: f64, digit_width: f64) -> f64 {
+    ((text_width + CELL_PADDING) / digit_width * 256f64).trunc() / 256f64
+}
+
+/// Width in pixels available to the text in a column width in characters.
+pub(crate) fn get_text_area_width(column_width: f64, digit_width: f64) -> f64 {
+    column_width * digit_width - CELL_PADDING
+}
+
+/// Width in characters of the columns without a width.
+pub(crate) fn get_default_column_width(digit_width: f64) -> f64 {
+    // 8 digits with padding, rounded up to a multiple of 8 pixels
+    let pixels = ((8f64 * digit_width + CELL_PADDING) / 8f64).ceil() * 8f64;
+    ((pixels - CELL_PADDING) / digit_width * 256f64).trunc() / 256f64
+}
+
+/// Convert pixels to points.
+#[inline]
+pub(crate) fn pixel_to_point(value: f64) -> f64 {
+    value * 72f64 / 96f64
+}
+
+/// Font face and size of a run of text.
+#[derive(Clone, Debug, PartialEq)]
+pub(crate) struct TextFont {
+    pub(crate) name: String,
+    pub(crate) size: f64,
+    pub(crate) bold: bool,
+}
+
+impl TextFont {
+    /// The font of a style. An unset name or size falls back to Calibri 11.
+    pub(crate) fn new(font: &Font) -> Self {
+        let name = match font.get_name() {
+            "" => "Calibri",
+            v => v,
+        };
+        let size = match *font.get_size() {
+            v if v > 0f64 => v,
+            _ => 11f64,
+        };
+        Self {
+            name: name.to_string(),
+            size,
+            bold: *font.get_bold(),
+        }
+    }
+
+    /// This font overridden by the set properties of a font.
+    pub(crate) fn with_font(&self, font: Option<&Font>) -> Self {
+        let font = match font {
+            Some(v) => v,
+            None => return self.clone(),
+        };
+        let mut result = self.clone();
+        if !font.get_name().is_empty() {
+            result.name = font.get_name().to_string();
+        }
+        if *font.get_size() > 0f64 {
+            result.size = *font.get_size();
+        }
+        result.bold = *font.get_bold();
+        result
+    }
+}
+
+/// Width and height of laid out text in pixels.
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub(crate) struct TextExtent {
+    pub(crate) width: f64,
+    pub(crate) height: f64,
+}
+
+struct Glyph {
+    c: char,
+    width: f64,
+    line_height: f64,
+}
+
+/// Lay out runs of text the way a cell shows them.
+/// # Arguments
+/// * `runs` - Text and font of each run.
+/// * `wrap_width` - Width to wrap the text at, `None` for a single line.
+///   Wrapped text always breaks at line feeds.
+/// * `rotation` - Text rotation of the alignment, 255 for stacked text.
+pub(crate) fn layout_text(
+    runs: &[(&str, &TextFont)],
+    wrap_width: Option<f64>,
+    rotation: u32,
+) -> TextExtent {
+    let mut glyphs = Vec::new();
+    let mut empty_line_height = 0f64;
+    for (text, font) in runs {
+        let (metrics, factor) = get_font_metrics(&font.name, font.bold);
+        let size = point_to_pixel(font.size);
+        let line_height = (metrics.get_line_height() * size).ceil() + 2f64;
+        empty_line_height = f64::max(empty_line_height, line_height);
+        for c in text.chars() {
+            if c == '\r' || (c == '\n' && wrap_width.is_none()) {
+                continue;
+            }
+            glyphs.push(Glyph {
+                c,
+                width: get_advance(metrics.as_ref(), c, factor, size),
+                line_height,
+            });
+        }
+    }
+
+    if rotation == 255 {
+        // one character on each line
+        let width = glyphs.iter().map(|v| v.width).fold(0f64, f64::max);
+        let height = glyphs.iter().map(|v| v.line_height).sum::<f64>();
+        return TextExtent {
+            width,
+            height: height.max(empty_line_height),
+        };
+    }
+
+    let lines = break_lines(&glyphs, wrap_width.unwrap_or(f64::INFINITY));
+    let mut extent = TextExtent::default();
+    for line in lines {
+        let width: f64 = line.iter().map(|v| v.width).sum();
+        let height = line.iter().map(|v| v.line_height).fold(0f64, f64::max);
+        extent.width = extent.width.max(width);
+        extent.height += if height > 0f64 {
+            height
+        } else {
+            empty_line_height
+        };
+    }
+
+    let angle = match rotation {
+        1..=90 => rotation as f64,
+        91..=180 => rotation as f64 - 90f64,
+        _ => return extent,
+    }
+    .to_radians();
+    let (sin, cos) = (angle.sin(), angle.cos());
+    TextExtent {
+        width: extent.width * cos + extent.height * sin,
+        height: extent.width * sin + extent.height * cos,
+    }
+}
+
+// Breaks at line feeds, then before the glyph that overflows the width,
+// after the last space or between wide characters when possible.
+fn break_lines(glyphs: &[Glyph], wrap_width: f64) -> Vec<&[Glyph]> {
+    // half a pixel of rounding in the column width
+    let limit = wrap_width + 0.5;
+    let mut lines = Vec::new();
+    for paragraph in glyphs.split(|v| v.c == '\n') {
+        let mut start = 0;
+        let mut width = 0f64;
+        let mut break_at: Option<usize> = None;
+        let mut index = 0;
+        while index < paragraph.len() {
+            let glyph = &paragraph[index];
+            if glyph.c != ' ' && width + glyph.width > limit && index > start {
+                let end = if is_wide_char(glyph.c) {
+                    index
+                } else {
+                    break_at.filter(|v| *v > start).unwrap_or(index)
+                };
+                lines.push(trim_end(&paragraph[start..end]));
+                start = end;
+                while start < paragraph.len() && paragraph[start].c == ' ' {
+                    start += 1;
+                }
+                width = paragraph[start..index].iter().map(|v| v.width).sum();
+                break_at = None;
+                continue;
+            }
+            width += glyph.width;
+            if glyph.c == ' ' || is_wide_char(glyph.c) {
+                break_at = Some(index + 1);
+            }
+            index += 1;
+        }
+        lines.push(trim_end(&paragraph[start..]));
+    }
+    lines
+}
+
+fn trim_end(line: &[Glyph]) -> &[Glyph] {
+    let end = line.iter().rposition(|v| v.c != ' ').map_or(0, |v| v + 1);
+    &line[..end]
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn calibri() -> TextFont {
+        TextFont {
+            name: "Calibri".to_string(),
+            size: 11f64,
+            bold: false,
+        }
+    }
+
+    #[test]
+    fn measure() {
+        assert_eq!(get_max_digit_width(&calibri()), 7f64);
+        assert_eq!(get_column_width(59f64, 7f64), 9.140625);
+        assert_eq!(get_default_column_width(7f64), 8.42578125);
+        assert_eq!(pixel_to_point(get_line_height("Arial", 10f64)), 12.75);
+        assert!(
+            get_text_width("Bold text", "Arial", 11f64, true)
+                > get_text_width("Bold text", "Arial", 11f64, false)
+        );
+        assert_eq!(
+            get_text_width("ab", "Unknown Font", 11f64, false),
+            get_text_width("ab", "Calibri", 11f64, false)
+        );
+        assert_eq!(
+            get_text_width("案", "Arial", 12f64, false),
+            get_text_width("案", "MS Gothic", 12f64, false)
+        );
+    }
+
+    #[test]
+    fn layout() {
+        let font = calibri();
+        let one_line = layout_text(&[("aaa bbb", &font)], None, 0);
+        assert_eq!(one_line.height, 20f64);
+
+        let wrapped = layout_text(&[("aaa bbb", &font)], Some(one_line.width - 1f64), 0);
+        assert_eq!(wrapped.height, 40f64);
+        assert_eq!(
+            wrapped.width,
+            get_text_width("bbb", "Calibri", 11f64, false)
+        );
+
+        // line feeds only break wrapped text
+        let text = "aaa\nbbb";
+        assert_eq!(layout_text(&[(text, &font)], None, 0).height, 20f64);
+        assert_eq!(
+            layout_text(&[(text, &font)], Some(f64::INFINITY), 0).height,
+            40f64
+        );
+
+        // wide characters break anywhere
+        let wide = layout_text(&[("案案案案", &font)], Some(30f64), 0);
+        assert_eq!(wide.height, 40f64);
+
+        let rotated = layout_text(&[("aaa", &font)], None, 90);
+        assert!((rotated.width - 20f64).abs() < 1e-9);
+        let stacked = layout_text(&[("aaa", &font)], None, 255);
+        assert_eq!(stacked.height, 60f64);
+    }
+}
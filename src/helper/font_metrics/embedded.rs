@@ -0,0 +1,189 @@
+// Metrics of the common Office fonts, in 1/1000 em.
+use super::{is_wide_char, FontMetrics};
+
+pub(crate) struct EmbeddedMetrics {
+    // advance widths of U+0020 to U+007E
+    ascii: &'static [u16; 95],
+    // width of the other narrow characters
+    other: u16,
+    // width of the East Asian wide characters
+    wide: u16,
+    // height of a line in em
+    line_height: f64,
+}
+
+impl FontMetrics for EmbeddedMetrics {
+    fn get_char_width(&self, c: char) -> f64 {
+        let code = c as u32;
+        let width = match code {
+            0x20..=0x7E => self.ascii[(code - 0x20) as usize],
+            _ if is_wide_char(c) => self.wide,
+            _ => self.other,
+        };
+        width as f64 / 1000f64
+    }
+
+    fn get_line_height(&self) -> f64 {
+        self.line_height
+    }
+}
+
+#[rustfmt::skip]
+const CALIBRI_WIDTHS: [u16; 95] = [
+    226, 326, 401, 498, 507, 715, 682, 221, 303, 303, 498, 498, 250, 306, 252, 386,
+    507, 507, 507, 507, 507, 507, 507, 507, 507, 507, 268, 268, 498, 498, 498, 463,
+    894, 579, 544, 533, 615, 488, 459, 631, 623, 252, 319, 520, 420, 855, 646, 662,
+    517, 673, 543, 459, 487, 642, 567, 890, 519, 487, 468, 307, 386, 307, 498, 498,
+    291, 479, 525, 423, 525, 498, 305, 471, 525, 230, 239, 455, 230, 799, 525, 527,
+    525, 525, 349, 391, 335, 525, 452, 715, 433, 453, 395, 314, 460, 314, 498,
+];
+
+#[rustfmt::skip]
+const CALIBRI_BOLD_WIDTHS: [u16; 95] = [
+    226, 326, 438, 498, 507, 729, 705, 233, 312, 312, 498, 498, 258, 306, 267, 430,
+    507, 507, 507, 507, 507, 507, 507, 507, 507, 507, 276, 276, 498, 498, 498, 463,
+    898, 606, 561, 529, 630, 488, 459, 637, 631, 267, 331, 547, 423, 874, 659, 676,
+    532, 686, 563, 473, 495, 653, 591, 906, 551, 520, 478, 325, 430, 325, 498, 498,
+    300, 494, 537, 418, 537, 503, 316, 474, 537, 246, 255, 480, 246, 813, 537, 538,
+    537, 537, 355, 399, 347, 537, 473, 745, 459, 474, 397, 344, 475, 344, 498,
+];
+
+#[rustfmt::skip]
+const ARIAL_WIDTHS: [u16; 95] = [
+    278, 278, 355, 556, 556, 889, 667, 191, 333, 333, 389, 584, 278, 333, 278, 278,
+    556, 556, 556, 556, 556, 556, 556, 556, 556, 556, 278, 278, 584, 584, 584, 556,
+    1015, 667, 667, 722, 722, 667, 611, 778, 722, 278, 500, 667, 556, 833, 722, 778,
+    667, 778, 722, 667, 611, 722, 667, 944, 667, 667, 611, 278, 278, 278, 469, 556,
+    333, 556, 556, 500, 556, 556, 278, 556, 556, 222, 222, 500, 222, 833, 556, 556,
+    556, 556, 333, 500, 278, 556, 500, 722, 500, 500, 500, 334, 260, 334, 584,
+];
+
+#[rustfmt::skip]
+const ARIAL_BOLD_WIDTHS: [u16; 95] = [
+    278, 333, 474, 556, 556, 889, 722, 238, 333, 333, 389, 584, 278, 333, 278, 278,
+    556, 556, 556, 556, 556, 556, 556, 556, 556, 556, 333, 333, 584, 584, 584, 611,
+    975, 722, 722, 722, 722, 667, 611, 778, 722, 278, 556, 722, 611, 833, 722, 778,
+    667, 778, 722, 667, 611, 722, 667, 944, 667, 667, 611, 333, 278, 333, 584, 556,
+    333, 556, 611, 556, 611, 556, 333, 611, 611, 278, 278, 556, 278, 889, 611, 611,
+    611, 611, 389, 556, 333, 611, 556, 778, 556, 556, 500, 389, 280, 389, 584,
+];
+
+#[rustfmt::skip]
+const TIMES_WIDTHS: [u16; 95] = [
+    250, 333, 408, 500, 500, 833, 778, 180, 333, 333, 500, 564, 250, 333, 250, 278,
+    500, 500, 500, 500, 500, 500, 500, 500, 500, 500, 278, 278, 564, 564, 564, 444,
+    921, 722, 667, 667, 722, 611, 556, 722, 722, 333, 389, 722, 611, 889, 722, 722,
+    556, 722, 667, 556, 611, 722, 722, 944, 722, 722, 611, 333, 278, 333, 469, 500,
+    333, 444, 500, 444, 500, 444, 333, 500, 500, 278, 278, 500, 278, 778, 500, 500,
+    500, 500, 333, 389, 278, 500, 500, 722, 500, 500, 444, 480, 200, 480, 541,
+];
+
+#[rustfmt::skip]
+const TIMES_BOLD_WIDTHS: [u16; 95] = [
+    250, 333, 555, 500, 500, 1000, 833, 278, 333, 333, 500, 570, 250, 333, 250, 278,
+    500, 500, 500, 500, 500, 500, 500, 500, 500, 500, 333, 333, 570, 570, 570, 500,
+    930, 722, 667, 722, 722, 667, 611, 778, 778, 389, 500, 778, 667, 944, 722, 778,
+    611, 778, 722, 556, 667, 722, 722, 1000, 722, 722, 667, 333, 278, 333, 581, 500,
+    333, 500, 556, 444, 556, 444, 333, 500, 556, 278, 333, 556, 278, 833, 556, 500,
+    556, 556, 444, 389, 333, 556, 500, 722, 500, 500, 444, 394, 220, 394, 520,
+];
+
+const MONOSPACE_WIDTHS: [u16; 95] = [600; 95];
+
+// the Japanese fonts have half-width Latin characters
+const HALF_WIDTHS: [u16; 95] = [500; 95];
+
+pub(crate) const CALIBRI: EmbeddedMetrics = EmbeddedMetrics {
+    ascii: &CALIBRI_WIDTHS,
+    other: 525,
+    wide: 1000,
+    line_height: 1.2207,
+};
+
+const CALIBRI_BOLD: EmbeddedMetrics = EmbeddedMetrics {
+    ascii: &CALIBRI_BOLD_WIDTHS,
+    other: 537,
+    wide: 1000,
+    line_height: 1.2207,
+};
+
+const ARIAL: EmbeddedMetrics = EmbeddedMetrics {
+    ascii: &ARIAL_WIDTHS,
+    other: 556,
+    wide: 1000,
+    line_height: 1.1172,
+};
+
+const ARIAL_BOLD: EmbeddedMetrics = EmbeddedMetrics {
+    ascii: &ARIAL_BOLD_WIDTHS,
+    other: 611,
+    wide: 1000,
+    line_height: 1.1172,
+};
+
+const TIMES: EmbeddedMetrics = EmbeddedMetrics {
+    ascii: &TIMES_WIDTHS,
+    other: 500,
+    wide: 1000,
+    line_height: 1.1074,
+};
+
+const TIMES_BOLD: EmbeddedMetrics = EmbeddedMetrics {
+    ascii: &TIMES_BOLD_WIDTHS,
+    other: 556,
+    wide: 1000,
+    line_height: 1.1074,
+};
+
+const COURIER: EmbeddedMetrics = EmbeddedMetrics {
+    ascii: &MONOSPACE_WIDTHS,
+    other: 600,
+    wide: 1000,
+    line_height: 1.1328,
+};
+
+const MS_GOTHIC: EmbeddedMetrics = EmbeddedMetrics {
+    ascii: &HALF_WIDTHS,
+    other: 500,
+    wide: 1000,
+    line_height: 1.07,
+};
+
+const YU_GOTHIC: EmbeddedMetrics = EmbeddedMetrics {
+    ascii: &HALF_WIDTHS,
+    other: 500,
+    wide: 1000,
+    line_height: 1.56,
+};
+
+const MEIRYO: EmbeddedMetrics = EmbeddedMetrics {
+    ascii: &HALF_WIDTHS,
+    other: 500,
+    wide: 1000,
+    line_height: 1.5,
+};
+
+/// Embedded metrics by lower case font name.
+/// The second value tells whether the metrics are of the bold face.
+pub(crate) fn get_embedded_metrics(
+    name: &str,
+    bold: bool,
+) -> Option<(&'static EmbeddedMetrics, bool)> {
+    let result = match (name, bold) {
+        ("calibri", true) => (&CALIBRI_BOLD, true),
+        ("calibri", false) => (&CALIBRI, false),
+        ("arial", true) => (&ARIAL_BOLD, true),
+        ("arial", false) => (&ARIAL, false),
+        ("times new roman", true) => (&TIMES_BOLD, true),
+        ("times new roman", false) => (&TIMES, false),
+        ("courier new", _) => (&COURIER, true),
+        ("ms gothic" | "ms pgothic" | "ms mincho" | "ms pmincho", _) => (&MS_GOTHIC, false),
+        ("ｍｓ ゴシック" | "ｍｓ ｐゴシック" | "ｍｓ 明朝" | "ｍｓ ｐ明朝", _) => {
+            (&MS_GOTHIC, false)
+        }
+        ("yu gothic" | "游ゴシック" | "yu mincho" | "游明朝", _) => (&YU_GOTHIC, false),
+        ("meiryo" | "メイリオ", _) => (&MEIRYO, false),
+        _ => return None,
+    };
+    Some(result)
+}
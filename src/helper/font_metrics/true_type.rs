@@ -0,0 +1,284 @@
+// Metrics read from TrueType and OpenType font files.
+use super::{is_wide_char, FontMetrics};
+use crate::XlsxError;
+use std::collections::HashMap;
+
+/// Character widths and line height read from the `cmap`, `hmtx`, `head`, `hhea`
+/// and `OS/2` tables of a TrueType or OpenType font.
+/// The first font of a collection (`.ttc`) is used.
+#[derive(Clone, Debug)]
+pub struct TrueTypeMetrics {
+    units_per_em: f64,
+    line_height: f64,
+    // glyph index by code point
+    glyphs: HashMap<u32, u16>,
+    advances: Vec<u16>,
+}
+
+impl TrueTypeMetrics {
+    /// Read the metrics of a font file.
+    /// # Arguments
+    /// * `data` - Content of a `.ttf`, `.otf` or `.ttc` file.
+    pub fn parse(data: &[u8]) -> Result<Self, XlsxError> {
+        let mut offset = 0;
+        if data.get(0..4) == Some(b"ttcf") {
+            offset = read_u32(data, 12)? as usize;
+        }
+        let tables = read_table_directory(data, offset)?;
+        let table = |tag: &[u8; 4]| -> Result<&[u8], XlsxError> {
+            tables
+                .get(tag)
+                .map(|(start, length)| &data[*start..*start + *length])
+                .ok_or_else(|| invalid(&format!("missing table {}", String::from_utf8_lossy(tag))))
+        };
+
+        let head = table(b"head")?;
+        let units_per_em = read_u16(head, 18)? as f64;
+        if units_per_em == 0f64 {
+            return Err(invalid("unitsPerEm is zero"));
+        }
+
+        let hhea = table(b"hhea")?;
+        let number_of_metrics = read_u16(hhea, 34)? as usize;
+        let line_units = match table(b"OS/2") {
+            Ok(os2) if os2.len() >= 78 => read_u16(os2, 74)? as f64 + read_u16(os2, 76)? as f64,
+            _ => {
+                read_u16(hhea, 4)? as i16 as f64 - read_u16(hhea, 6)? as i16 as f64
+                    + read_u16(hhea, 8)? as i16 as f64
+            }
+        };
+
+        let hmtx = table(b"hmtx")?;
+        let advances = (0..number_of_metrics)
+            .map(|i| read_u16(hmtx, i * 4))
+            .collect::<Result<Vec<u16>, XlsxError>>()?;
+        if advances.is_empty() {
+            return Err(invalid("no horizontal metrics"));
+        }
+
+        let glyphs = read_cmap(table(b"cmap")?)?;
+
+        Ok(TrueTypeMetrics {
+            units_per_em,
+            line_height: line_units / units_per_em,
+            glyphs,
+            advances,
+        })
+    }
+
+    fn get_advance(&self, glyph: u16) -> u16 {
+        // glyphs after the last metric share its advance width
+        let index = (glyph as usize).min(self.advances.len() - 1);
+        self.advances[index]
+    }
+}
+
+impl FontMetrics for TrueTypeMetrics {
+    fn get_char_width(&self, c: char) -> f64 {
+        match self.glyphs.get(&(c as u32)) {
+            Some(glyph) => self.get_advance(*glyph) as f64 / self.units_per_em,
+            // Excel draws the character with a fallback font
+            None if is_wide_char(c) => 1f64,
+            None => self.get_advance(0) as f64 / self.units_per_em,
+        }
+    }
+
+    fn get_line_height(&self) -> f64 {
+        self.line_height
+    }
+}
+
+fn invalid(message: &str) -> XlsxError {
+    XlsxError::InvalidFont(message.to_string())
+}
+
+fn read_u16(data: &[u8], offset: usize) -> Result<u16, XlsxError> {
+    data.get(offset..offset + 2)
+        .map(|v| u16::from_be_bytes([v[0], v[1]]))
+        .ok_or_else(|| invalid("unexpected end of data"))
+}
+
+fn read_u32(data: &[u8], offset: usize) -> Result<u32, XlsxError> {
+    data.get(offset..offset + 4)
+        .map(|v| u32::from_be_bytes([v[0], v[1], v[2], v[3]]))
+        .ok_or_else(|| invalid("unexpected end of data"))
+}
+
+fn read_table_directory(
+    data: &[u8],
+    offset: usize,
+) -> Result<HashMap<[u8; 4], (usize, usize)>, XlsxError> {
+    let version = read_u32(data, offset)?;
+    if !matches!(version, 0x0001_0000 | 0x4F54_544F | 0x7472_7565) {
+        return Err(invalid("not a TrueType or OpenType font"));
+    }
+    let count = read_u16(data, offset + 4)? as usize;
+    let mut result = HashMap::new();
+    for i in 0..count {
+        let record = offset + 12 + i * 16;
+        let tag = data
+            .get(record..record + 4)
+            .ok_or_else(|| invalid("unexpected end of data"))?;
+        let start = read_u32(data, record + 8)? as usize;
+        let length = read_u32(data, record + 12)? as usize;
+        if start
+            .checked_add(length)
+            .map_or(true, |end| end > data.len())
+        {
+            return Err(invalid("table out of range"));
+        }
+        result.insert([tag[0], tag[1], tag[2], tag[3]], (start, length));
+    }
+    Ok(result)
+}
+
+// Reads the Unicode subtable with the widest coverage.
+fn read_cmap(cmap: &[u8]) -> Result<HashMap<u32, u16>, XlsxError> {
+    let count = read_u16(cmap, 2)? as usize;
+    let mut best: Option<(u8, usize)> = None;
+    for i in 0..count {
+        let record = 4 + i * 8;
+        let platform = read_u16(cmap, record)?;
+        let encoding = read_u16(cmap, record + 2)?;
+        let offset = read_u32(cmap, record + 4)? as usize;
+        let rank = match (platform, encoding) {
+            (3, 10) | (0, 4) | (0, 6) => 3,
+            (3, 1) | (0, _) => 2,
+            _ => continue,
+        };
+        if best.map_or(true, |(v, _)| rank > v) {
+            best = Some((rank, offset));
+        }
+    }
+    let (_, offset) = best.ok_or_else(|| invalid("no Unicode cmap subtable"))?;
+
+    let mut glyphs = HashMap::new();
+    match read_u16(cmap, offset)? {
+        4 => {
+            let segments = read_u16(cmap, offset + 6)? as usize / 2;
+            let ends = offset + 14;
+            let starts = ends + segments * 2 + 2;
+            let deltas = starts + segments * 2;
+            let range_offsets = deltas + segments * 2;
+            for i in 0..segments {
+                let end = read_u16(cmap, ends + i * 2)?;
+                let start = read_u16(cmap, starts + i * 2)?;
+                let delta = read_u16(cmap, deltas + i * 2)?;
+                let range_offset = read_u16(cmap, range_offsets + i * 2)? as usize;
+                if start > end || start == 0xFFFF {
+                    continue;
+                }
+                for code in start..=end {
+                    let glyph = if range_offset == 0 {
+                        code.wrapping_add(delta)
+                    } else {
+                        let position =
+                            range_offsets + i * 2 + range_offset + (code - start) as usize * 2;
+                        match read_u16(cmap, position)? {
+                            0 => 0,
+                            v => v.wrapping_add(delta),
+                        }
+                    };
+                    if glyph != 0 {
+                        glyphs.insert(code as u32, glyph);
+                    }
+                }
+            }
+        }
+        12 => {
+            let groups = read_u32(cmap, offset + 12)? as usize;
+            for i in 0..groups {
+                let group = offset + 16 + i * 12;
+                let start = read_u32(cmap, group)?;
+                let end = read_u32(cmap, group + 4)?.min(0x10FFFF);
+                let first_glyph = read_u32(cmap, group + 8)?;
+                for code in start..=end {
+                    let glyph = first_glyph + (code - start);
+                    if glyph != 0 && glyph <= u16::MAX as u32 {
+                        glyphs.insert(code, glyph as u16);
+                    }
+                }
+            }
+        }
+        format => {
+            return Err(invalid(&format!("unsupported cmap format {}", format)));
+        }
+    }
+    Ok(glyphs)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // A font with the glyphs .notdef, "A" and "i", 1000 units per em.
+    fn build_font() -> Vec<u8> {
+        let mut head = vec![0u8; 54];
+        head[18..20].copy_from_slice(&1000u16.to_be_bytes());
+
+        let mut hhea = vec![0u8; 36];
+        hhea[4..6].copy_from_slice(&800i16.to_be_bytes());
+        hhea[6..8].copy_from_slice(&(-200i16).to_be_bytes());
+        hhea[34..36].copy_from_slice(&3u16.to_be_bytes());
+
+        let mut hmtx = Vec::new();
+        for advance in [500u16, 600, 250] {
+            hmtx.extend_from_slice(&advance.to_be_bytes());
+            hmtx.extend_from_slice(&0u16.to_be_bytes());
+        }
+
+        // format 4 with the segments "A", "i" and the final 0xFFFF
+        let mut subtable = Vec::new();
+        for v in [4u16, 40, 0, 6, 4, 1, 2] {
+            subtable.extend_from_slice(&v.to_be_bytes());
+        }
+        for v in [0x41u16, 0x69, 0xFFFF, 0, 0x41, 0x69, 0xFFFF] {
+            subtable.extend_from_slice(&v.to_be_bytes());
+        }
+        for v in [(1i16 - 0x41) as u16, (2i16 - 0x69) as u16, 1, 0, 0, 0] {
+            subtable.extend_from_slice(&v.to_be_bytes());
+        }
+        let mut cmap = Vec::new();
+        for v in [0u16, 1, 3, 1] {
+            cmap.extend_from_slice(&v.to_be_bytes());
+        }
+        cmap.extend_from_slice(&12u32.to_be_bytes());
+        cmap.extend_from_slice(&subtable);
+
+        let tables: [(&[u8; 4], Vec<u8>); 4] = [
+            (b"cmap", cmap),
+            (b"head", head),
+            (b"hhea", hhea),
+            (b"hmtx", hmtx),
+        ];
+        let mut data = Vec::new();
+        data.extend_from_slice(&0x0001_0000u32.to_be_bytes());
+        data.extend_from_slice(&(tables.len() as u16).to_be_bytes());
+        data.extend_from_slice(&[0u8; 6]);
+        let mut offset = 12 + tables.len() * 16;
+        let mut body = Vec::new();
+        for (tag, table) in &tables {
+            data.extend_from_slice(*tag);
+            data.extend_from_slice(&0u32.to_be_bytes());
+            data.extend_from_slice(&(offset as u32).to_be_bytes());
+            data.extend_from_slice(&(table.len() as u32).to_be_bytes());
+            offset += table.len();
+            body.extend_from_slice(table);
+        }
+        data.extend_from_slice(&body);
+        data
+    }
+
+    #[test]
+    fn parse() {
+        let metrics = TrueTypeMetrics::parse(&build_font()).unwrap();
+        assert_eq!(metrics.get_char_width('A'), 0.6);
+        assert_eq!(metrics.get_char_width('i'), 0.25);
+        assert_eq!(metrics.get_char_width('z'), 0.5);
+        assert_eq!(metrics.get_char_width('あ'), 1.0);
+        assert_eq!(metrics.get_line_height(), 1.0);
+
+        assert!(TrueTypeMetrics::parse(b"not a font").is_err());
+        assert!(TrueTypeMetrics::parse(&build_font()[..40]).is_err());
+    }
+}
@@ -114,7 +114,7 @@ pub const ERRORS: &'static [&'static str] = &[
 const COMPARATORS_MULTI: &'static [&'static str] = &[">=", "<=", "<>"];
 
 lazy_static! {
-    pub static ref SCIENTIFIC_REGEX: Regex = Regex::new(r#"/^[1-9]{1}(\\.\\d+)?E{1}$/"#).unwrap();
+    pub static ref SCIENTIFIC_REGEX: Regex = Regex::new(r#"^[1-9]{1}(\.\d+)?E{1}$"#).unwrap();
 }
 
 pub(crate) fn parse_to_tokens<S: Into<String>>(formula: S) -> Vec<FormulaToken> {
@@ -176,10 +176,11 @@ pub(crate) fn parse_to_tokens<S: Into<String>>(formula: S) -> Vec<FormulaToken>
                 if ((index + 2) <= formula_length)
                     && (formula.chars().nth(index + 1).unwrap() == self::QUOTE_SINGLE)
                 {
-                    value = format!("{}{}", value, self::QUOTE_SINGLE);
+                    value = format!("{}{}{}", value, self::QUOTE_SINGLE, self::QUOTE_SINGLE);
                     index += 1;
                 } else {
                     in_path = false;
+                    value = format!("{}{}", value, self::QUOTE_SINGLE);
                 }
             } else {
                 value = format!("{}{}", value, formula.chars().nth(index).unwrap());
@@ -197,7 +198,7 @@ pub(crate) fn parse_to_tokens<S: Into<String>>(formula: S) -> Vec<FormulaToken>
                 in_range = false;
             }
             value = format!("{}{}", value, formula.chars().nth(index).unwrap());
-            index;
+            index += 1;
 
             continue;
         }
@@ -223,10 +224,7 @@ pub(crate) fn parse_to_tokens<S: Into<String>>(formula: S) -> Vec<FormulaToken>
         // scientific notation check
         if self::OPERATORS_SN.contains(formula.chars().nth(index).unwrap()) {
             if value.len() > 1 {
-                if SCIENTIFIC_REGEX
-                    .is_match(&formula.chars().nth(index).unwrap().to_string())
-                    .unwrap_or(false)
-                {
+                if SCIENTIFIC_REGEX.is_match(&value).unwrap_or(false) {
                     value = format!("{}{}", value, formula.chars().nth(index).unwrap());
                     index += 1;
 
@@ -262,7 +260,8 @@ pub(crate) fn parse_to_tokens<S: Into<String>>(formula: S) -> Vec<FormulaToken>
                 tokens1.push(obj);
                 value = String::new();
             }
-            in_string = true;
+            in_path = true;
+            value = format!("{}{}", value, self::QUOTE_SINGLE);
             index += 1;
 
             continue;
@@ -1,17 +1,12 @@
 mod date_formater;
-mod fraction_formater;
+mod format_code;
+mod locale;
 mod number_formater;
-mod percentage_formater;
 
-use std::borrow::Cow;
-
-use crate::helper::date::*;
-use crate::structs::Color;
+use self::format_code::{FormatCode, Section, SectionKind, Token};
 use crate::structs::NumberingFormat;
-use fancy_regex::Captures;
 use fancy_regex::Matches;
 use fancy_regex::Regex;
-use thousands::Separable;
 
 pub struct Split<'r, 't> {
     finder: Matches<'r, 't>,
@@ -59,163 +54,101 @@ lazy_static! {
     pub static ref PERCENT_DOLLAR_REGEX: Regex = Regex::new("%$").unwrap();
 }
 
+/// Formats a cell value with a number format code, the way Excel displays it.
+/// Leading and trailing spaces (from `_` paddings, for example) are trimmed.
+/// # Arguments
+/// * `value` - the value, a number or a text.
+/// * `format` - the format code, e.g. `#,##0.00;[Red]-#,##0.00`.
+/// # Examples
+/// ```
+/// use umya_spreadsheet::helper::number_format::to_formatted_string;
+/// assert_eq!(to_formatted_string("1234.5", "#,##0.00"), "1,234.50");
+/// assert_eq!(to_formatted_string("0.75", "# ?/?"), "3/4");
+/// assert_eq!(to_formatted_string("45435.5", "yyyy-mm-dd h:mm AM/PM"), "2024-05-23 12:00 PM");
+/// assert_eq!(to_formatted_string("abc", r#"0;-0;0;"text: "@"#), "text: abc");
+/// ```
+#[inline]
 pub fn to_formatted_string<S: AsRef<str>, P: AsRef<str>>(value: S, format: P) -> String {
-    let mut value: Cow<str> = Cow::Borrowed(value.as_ref());
-    let format = Cow::Borrowed(format.as_ref());
+    to_formatted_string_crate(value, format, false)
+}
+
+/// Same as `to_formatted_string`, with the date system of the workbook.
+/// Date serials are counted from 1904-01-01 when `date1904` is true.
+pub(crate) fn to_formatted_string_crate<S: AsRef<str>, P: AsRef<str>>(
+    value: S,
+    format: P,
+    date1904: bool,
+) -> String {
+    let value = value.as_ref();
+    let format = format.as_ref();
 
     // is empty
     if value.is_empty() {
-        return value.to_string();
+        return String::new();
     }
-    // is numeric
-    match &value.parse::<f64>() {
-        // convert value
-        Ok(val) if format == NumberingFormat::FORMAT_GENERAL => {
-            return val.to_string();
-        }
-        Ok(_) if format == NumberingFormat::FORMAT_TEXT => return value.to_string(),
-        Err(_) => return value.to_string(),
-        _ => {}
+    // text cells keep their digits
+    if format == NumberingFormat::FORMAT_TEXT {
+        return value.to_string();
     }
 
-    // Convert any other escaped characters to quoted strings, e.g. (\T to "T")
-
-    let mut format = ESCAPE_REGEX.replace_all(&format, r#""$0""#);
-
-    // Get the sections, there can be up to four sections, separated with a semi-colon (but only if not a quoted literal)
-
-    let sections: Vec<&str> = split(&SECTION_REGEX, &format).collect();
-
-    let (_, split_format, split_value) = split_format(sections, &value.parse::<f64>().unwrap());
-    format = Cow::Owned(split_format);
-    value = Cow::Owned(split_value);
-
-    // In Excel formats, "_" is used to add spacing,
-    //    The following character indicates the size of the spacing, which we can't do in HTML, so we just use a standard space
-    let re = Regex::new("_.").unwrap();
-    let format = re.replace_all(&format, " ");
-
-    // Let's begin inspecting the format and converting the value to a formatted string
-
-    //  Check for date/time characters (not inside quotes)
-
-    if DATE_TIME_REGEX.is_match(&format).unwrap_or(false) {
-        // datetime format
-        value = date_formater::format_as_date(&value.parse::<f64>().unwrap(), &format);
-    } else if format.starts_with('"') && format.ends_with('"') {
-        let conv_format = format.trim_matches('"').parse::<f64>().unwrap();
-        value = Cow::Owned(conv_format.to_string());
-    } else if PERCENT_DOLLAR_REGEX.is_match(&format).unwrap_or(false) {
-        // % number format
-        value = percentage_formater::format_as_percentage(&value.parse::<f64>().unwrap(), &format);
-    } else {
-        value = number_formater::format_as_number(&value.parse::<f64>().unwrap(), &format);
-    }
-    value.trim().to_string()
+    let format_code = FormatCode::get(format);
+    let result = match value.parse::<f64>() {
+        Ok(v) if v.is_finite() => format_number(&format_code, v, date1904),
+        _ => match format_code.get_text_section() {
+            Some(section) => format_text(section, value),
+            None => value.to_string(),
+        },
+    };
+    result.trim().to_string()
 }
 
-fn split_format(sections: Vec<&str>, value: &f64) -> (String, String, String) {
-    let mut converted_sections: Vec<String> = Vec::new();
+/// Formats a text value with the text section of a format code.
+/// Digits in the text are not read as a number.
+pub(crate) fn to_formatted_text<S: AsRef<str>, P: AsRef<str>>(value: S, format: P) -> String {
+    let value = value.as_ref();
+    let result = match FormatCode::get(format.as_ref()).get_text_section() {
+        Some(section) => format_text(section, value),
+        None => value.to_string(),
+    };
+    result.trim().to_string()
+}
 
-    // Extract the relevant section depending on whether number is positive, negative, or zero?
-    // Text not supported yet.
+fn format_number(format_code: &FormatCode, value: f64, date1904: bool) -> String {
     // Here is how the sections apply to various values in Excel:
-    //   1 section:   [POSITIVE/NEGATIVE/ZERO/TEXT]
-    //   2 sections:  [POSITIVE/ZERO/TEXT] [NEGATIVE]
-    //   3 sections:  [POSITIVE/TEXT] [NEGATIVE] [ZERO]
-    //   4 sections:  [POSITIVE] [NEGATIVE] [ZERO] [TEXT]
-    let cnt: usize = sections.len();
-    let color_regex = format!("{}{}{}", "\\[(", Color::NAMED_COLORS.join("|"), ")\\]");
-    let cond_regex = r"\[(>|>=|<|<=|=|<>)([+-]?\d+([.]\d+)?)\]";
-    let color_re = Regex::new(&color_regex).unwrap();
-    let cond_re = Regex::new(cond_regex).unwrap();
-
-    let mut colors = [""; 5];
-    let mut condops = [""; 5];
-
-    let mut condvals = ["0"; 5];
-    sections.into_iter().enumerate().for_each(|(idx, section)| {
-        let mut converted_section = section.to_string();
-        if color_re.find(section).ok().flatten().is_some() {
-            let mut item: Vec<&str> = Vec::new();
-            for ite in color_re.captures(section).ok().flatten().unwrap().iter() {
-                item.push(ite.unwrap().as_str());
-            }
-            std::mem::replace(&mut colors[idx], item[0]);
-            converted_section = color_re.replace_all(section, "").to_string();
-        }
-        if cond_re.find(section).ok().flatten().is_some() {
-            let mut item: Vec<&str> = Vec::new();
-            for ite in cond_re.captures(section).ok().flatten().unwrap().iter() {
-                match ite {
-                    Some(v) => item.push(v.as_str()),
-                    None => {}
-                }
-            }
-            match item.get(1) {
-                Some(v) => {
-                    std::mem::replace(&mut condops[idx], v);
-                }
-                None => {}
-            }
-            match item.get(2) {
-                Some(v) => {
-                    std::mem::replace(&mut condvals[idx], v);
-                }
-                None => {}
-            }
-            converted_section = cond_re.replace_all(section, "").to_string();
-        }
-        converted_sections.insert(idx, converted_section);
-    });
-
-    let mut color = colors[0];
-    let mut format = &converted_sections[0];
-    let mut absval = *value;
-    match cnt {
-        2 => {
-            absval = absval.abs();
-            let condval_one = &condvals[0].parse::<f64>().unwrap();
-            if !split_format_compare(value, &condops[0], condval_one, ">=", &0f64) {
-                color = &colors[1];
-                format = &converted_sections[1];
-            }
-        }
-        3 | 4 => {
-            absval = absval.abs();
-            let condval_one = &condvals[0].parse::<f64>().unwrap();
-            let condval_two = &condvals[1].parse::<f64>().unwrap();
-            if !split_format_compare(value, &condops[0], condval_one, ">", &0f64) {
-                if split_format_compare(value, &condops[1], condval_two, "<", &0f64) {
-                    color = &colors[1];
-                    format = &converted_sections[1];
-                } else {
-                    color = &colors[2];
-                    format = &converted_sections[2];
-                }
-            }
-        }
-        _ => {}
+    //   1 section:   [POSITIVE/NEGATIVE/ZERO]
+    //   2 sections:  [POSITIVE/ZERO] [NEGATIVE]
+    //   3 sections:  [POSITIVE] [NEGATIVE] [ZERO]
+    // A last section with an @ is for text.
+    let (section, explicit) = match format_code.get_number_section(value) {
+        Some(v) => v,
+        None => return number_formater::format_as_general(value),
+    };
+    let value = if explicit { value.abs() } else { value };
+    let result = match section.kind {
+        SectionKind::Date => match date_formater::format_as_date(section, value, date1904) {
+            Some(v) => v,
+            None => return number_formater::format_as_general(value),
+        },
+        SectionKind::Text => format_text(section, &number_formater::format_as_general(value)),
+        SectionKind::Number => number_formater::format_as_number(section, value),
+    };
+    match section.numeral {
+        Some(v) => locale::convert_numerals(&result, v, section.get_language()),
+        None => result,
     }
-    (color.to_string(), format.into(), absval.to_string())
 }
 
-fn split_format_compare(value: &f64, cond: &str, val: &f64, dfcond: &str, dfval: &f64) -> bool {
-    let mut check_cond = cond;
-    let mut check_val = val;
-    if cond.is_empty() {
-        check_cond = dfcond;
-        check_val = dfval;
-    }
-    match check_cond {
-        ">" => return value > check_val,
-        "<" => return value < check_val,
-        "<=" => return value <= check_val,
-        "<>" => return value != check_val,
-        "=" => return value == check_val,
-        _ => {}
+fn format_text(section: &Section, value: &str) -> String {
+    let mut result = String::new();
+    for token in &section.tokens {
+        match token {
+            Token::Text => result.push_str(value),
+            Token::Literal(v) => result.push_str(v),
+            Token::Fill(v) => result.push(*v),
+            _ => {}
+        }
     }
-    value >= check_val
+    result
 }
 
 #[test]
@@ -278,11 +211,11 @@ fn test_to_formatted_string_date() {
         to_formatted_string(&value, NumberingFormat::FORMAT_DATE_DATETIME)
     );
     assert_eq!(
-        r#"12:00 am"#,
+        r#"12:00 AM"#,
         to_formatted_string(&value, NumberingFormat::FORMAT_DATE_TIME1)
     );
     assert_eq!(
-        r#"12:00:00 am"#,
+        r#"12:00:00 AM"#,
         to_formatted_string(&value, NumberingFormat::FORMAT_DATE_TIME2)
     );
     assert_eq!(
@@ -368,11 +301,11 @@ fn test_to_formatted_string_date() {
         to_formatted_string(&value, NumberingFormat::FORMAT_DATE_DATETIME)
     );
     assert_eq!(
-        r#"5:04 am"#,
+        r#"5:04 AM"#,
         to_formatted_string(&value, NumberingFormat::FORMAT_DATE_TIME1)
     );
     assert_eq!(
-        r#"5:04:02 am"#,
+        r#"5:04:02 AM"#,
         to_formatted_string(&value, NumberingFormat::FORMAT_DATE_TIME2)
     );
     assert_eq!(
@@ -401,3 +334,136 @@ fn test_to_formatted_string_date() {
     );
     assert_eq!(r#"2"#, to_formatted_string(&value, "d"))
 }
+
+#[test]
+fn test_to_formatted_string_conformance() {
+    let corpus = [
+        // General
+        ("1234.5", "General", "1234.5"),
+        ("-1234.5", "General", "-1234.5"),
+        ("0.30000000000000004", "General", "0.3"),
+        ("0.333333333333333", "General", "0.333333333"),
+        ("12345678901", "General", "12345678901"),
+        ("123456789012", "General", "1.23457E+11"),
+        ("0.000000000123", "General", "1.23E-10"),
+        // digit placeholders
+        ("1234.5678", "0", "1235"),
+        ("1234.5678", "0.00", "1234.57"),
+        ("1.2345", "0.000", "1.235"),
+        ("-2.5", "0", "-3"),
+        ("5", "000", "005"),
+        ("0.5", "#.00", ".50"),
+        ("12.5", ".00", "12.50"),
+        ("1.5", "0.##", "1.5"),
+        ("1", "0.##", "1."),
+        ("0.5", "0.0??", "0.5"),
+        ("12", r"#\ #", "1 2"),
+        ("5551234", "000-0000", "555-1234"),
+        // thousands separators and scaling
+        ("1234567.891", "#,##0", "1,234,568"),
+        ("5", "0,000", "0,005"),
+        ("1234567", "#,##0,", "1,235"),
+        ("1234567", r#"0.0,,"M""#, "1.2M"),
+        // percentages
+        ("0.25", "0%", "25%"),
+        ("0.1234", "0.0%", "12.3%"),
+        // sections and conditions
+        ("-5", "0", "-5"),
+        ("-5", "0;(0)", "(5)"),
+        ("-5", "0.00;[Red]-0.00", "-5.00"),
+        ("0", r#"0;-0;"zero""#, "zero"),
+        ("5", r#"[>=10]"big";[<0]"neg";"small""#, "small"),
+        ("15", r#"[>=10]"big";[<0]"neg";"small""#, "big"),
+        ("-3", r#"[>=10]"big";[<0]"neg";"small""#, "neg"),
+        ("1.3", "[<1]0;0", "1"),
+        // scientific
+        ("12345", "0.00E+00", "1.23E+04"),
+        ("0.000123", "0.00E+00", "1.23E-04"),
+        ("12345", "0.00E-00", "1.23E04"),
+        ("0", "0.00E+00", "0.00E+00"),
+        ("99999", "0.0E+0", "1.0E+5"),
+        ("12345", "##0.0E+0", "12.3E+3"),
+        // fractions
+        ("0.5", "# ?/?", "1/2"),
+        ("1.25", "# ?/?", "1 1/4"),
+        ("1.25", "?/?", "5/4"),
+        ("2", "# ?/?", "2"),
+        ("0.333", "# ??/??", "1/3"),
+        ("0.75", "# ?/8", "6/8"),
+        ("0.5", "?/10", "5/10"),
+        // text
+        ("abc", "@", "abc"),
+        ("abc", "0.00", "abc"),
+        ("abc", r#"0;-0;0;"["@"]""#, "[abc]"),
+        ("abc", r#""Name: "@"#, "Name: abc"),
+        ("12", r#""Name: "@"#, "12"),
+        ("12", "0.0;@", "12.0"),
+        // spacing and fill
+        ("333", r"_-\$* #,##0.00_ ", "$ 333.00"),
+        ("1234.5", r#"_("$"* #,##0.00_)"#, "$ 1,234.50"),
+        (
+            "-1234.5",
+            r#"_("$"* #,##0.00_);_("$"* \(#,##0.00\)"#,
+            "$ (1,234.50)",
+        ),
+        (
+            "0",
+            r#"_("$"* #,##0.00_);_("$"* \(#,##0.00\);_("$"* "-"??_)"#,
+            "$ -",
+        ),
+        // currency and locale tags
+        ("1234.5", "[$€-407]#,##0.00", "€1,234.50"),
+        ("1234.5", "#,##0.00 [$USD]", "1,234.50 USD"),
+        (
+            "45435",
+            "[$-407]dddd, d. mmmm yyyy",
+            "Donnerstag, 23. Mai 2024",
+        ),
+        ("45435", "[$-40C]d mmmm yyyy", "23 mai 2024"),
+        ("45435", "[$-F800]", "Thursday, May 23, 2024"),
+        ("45435.75", "[$-F400]", "6:00:00 PM"),
+        // dates and times
+        ("45435", "dddd, mmmm d, yyyy", "Thursday, May 23, 2024"),
+        ("45435", "ddd mmm", "Thu May"),
+        ("45435", "mmmmm", "M"),
+        ("45435.75", "h:mm AM/PM", "6:00 PM"),
+        ("45435.75", "h:mm a/p", "6:00 p"),
+        ("45435.75", "hh:mm:ss", "18:00:00"),
+        ("0.99999999", "h:mm", "0:00"),
+        ("0.500005787", "h:mm:ss.000", "12:00:00.500"),
+        ("0", "yyyy-mm-dd", "1900-01-00"),
+        ("60", "yyyy-mm-dd ddd", "1900-02-29 Wed"),
+        ("45435", "bbbb", "2567"),
+        // elapsed time
+        ("1.5", "[h]:mm:ss", "36:00:00"),
+        ("0.0625", "[mm]:ss", "90:00"),
+        ("0.5", "[ss]", "43200"),
+        // Japanese era
+        ("43831", r#"[$-411]ggge"年"m"月"d"日""#, "令和2年1月1日"),
+        ("32874", "[$-411]ge.m.d", "H2.1.1"),
+        ("43466", "[$-411]gge", "平31"),
+        (
+            "45435",
+            r#"[$-411]yyyy"年"m"月"d"日"(aaa)"#,
+            "2024年5月23日(木)",
+        ),
+        ("45435.75", "[$-411]AM/PM h:mm", "午後 6:00"),
+        // DBNum
+        ("1234", "[DBNum1][$-411]General", "千二百三十四"),
+        ("1234", "[DBNum3][$-411]0", "１２３４"),
+        ("45435", r#"[DBNum1][$-411]m"月"d"日""#, "五月二十三日"),
+    ];
+    for (value, format, expected) in corpus {
+        assert_eq!(
+            to_formatted_string(value, format),
+            expected,
+            "{} formatted with {}",
+            value,
+            format
+        );
+    }
+    assert_eq!(
+        to_formatted_string_crate("0", "yyyy-mm-dd dddd", true),
+        "1904-01-01 Friday"
+    );
+}
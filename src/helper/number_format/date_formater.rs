@@ -1,120 +1,176 @@
-use std::borrow::Cow;
+use super::format_code::{DatePart, Section, Token};
+use super::locale::*;
+use chrono::{Datelike, Duration, NaiveDate};
 
-use crate::helper::date::*;
-use crate::structs::Color;
-use crate::structs::NumberingFormat;
-use fancy_regex::Captures;
-use fancy_regex::Matches;
-use fancy_regex::Regex;
-use thousands::Separable;
+// 9999-12-31 in the 1900 date system
+const MAX_SERIAL: f64 = 2958466f64;
 
-const DATE_FORMAT_REPLACEMENTS: &[(&str, &str)] = &[
-    // first remove escapes related to non-format characters
-    ("\\", ""),
-    // 12-hour suffix
-    ("am/pm", "%P"),
-    // Era - no rust equivalent
-    ("ggge", "%Y"),
-    // 4-digit year
-    ("e", "%Y"),
-    ("yyyy", "%Y"),
-    // 2-digit year
-    ("yy", "%y"),
-    // first letter of month - no rust equivalent
-    ("mmmmm", "%b"),
-    // full month name
-    ("mmmm", "%B"),
-    // short month name
-    ("mmm", "%b"),
-    // mm is minutes if time, but can also be month w/leading zero
-    // so we try to identify times be the inclusion of a : separator in the mask
-    // It isn't perfect, but the best way I know how
-    (":mm", ":%M"),
-    ("mm:", "%M:"),
-    // month leading zero (first)
-    ("mm", "MM"),
-    // month no leading zero
-    ("m", "%-m"),
-    // month leading zero (second)
-    ("MM", "%m"),
-    // full day of week name
-    ("dddd", "%A"),
-    // short day of week name
-    ("ddd", "%a"),
-    // days leading zero (first)
-    ("dd", "D"),
-    // days no leading zero
-    ("d", "%-d"),
-    // days leading zero (second)
-    ("D", "%d"),
-    // seconds
-    ("ss", "%S"),
-    // fractional seconds - no rust equivalent
-    (".s", ""),
-];
-
-const DATE_FORMAT_REPLACEMENTS_24: &[(&str, &str)] = &[("hh", "%H"), ("h", "%-H")];
+struct DateParts {
+    date: Option<NaiveDate>,
+    year: i32,
+    month: u32,
+    day: u32,
+    // 0 is Sunday
+    weekday: usize,
+    hour: i64,
+    minute: i64,
+    second: i64,
+    // in units of the displayed sub-second digits
+    sub_second: i64,
+    // whole elapsed seconds
+    elapsed: i64,
+}
 
-const DATE_FORMAT_REPLACEMENTS_12: &[(&str, &str)] = &[("hh", "%I"), ("h", "%-I")];
+impl DateParts {
+    fn new(serial: f64, date1904: bool, sub_second_digits: u32) -> Option<Self> {
+        // round to the smallest unit shown, a second by default
+        let unit = 10i64.pow(sub_second_digits);
+        let total = (serial * 86_400f64 * unit as f64).round() as i64;
+        let days = total.div_euclid(86_400 * unit);
+        let elapsed = total.div_euclid(unit);
+        let seconds = elapsed.rem_euclid(86_400);
 
-pub(crate) fn format_as_date<'input>(value: &f64, format: &'input str) -> Cow<'input, str> {
-    let format = Cow::Borrowed(format);
+        let (date, year, month, day, weekday) = if date1904 {
+            let date =
+                NaiveDate::from_ymd_opt(1904, 1, 1)?.checked_add_signed(Duration::days(days))?;
+            let weekday = date.weekday().num_days_from_sunday() as usize;
+            (Some(date), date.year(), date.month(), date.day(), weekday)
+        } else {
+            // the 1900 date system has 1900-01-00 and 1900-02-29
+            let weekday = (days + 6).rem_euclid(7) as usize;
+            match days {
+                0 => (None, 1900, 1, 0, weekday),
+                60 => (None, 1900, 2, 29, weekday),
+                _ => {
+                    let base = if days < 60 { 31 } else { 30 };
+                    let date = NaiveDate::from_ymd_opt(1899, 12, base)?
+                        .checked_add_signed(Duration::days(days))?;
+                    (Some(date), date.year(), date.month(), date.day(), weekday)
+                }
+            }
+        };
+        Some(DateParts {
+            date,
+            year,
+            month,
+            day,
+            weekday,
+            hour: seconds / 3600,
+            minute: seconds / 60 % 60,
+            second: seconds % 60,
+            sub_second: total.rem_euclid(unit),
+            elapsed,
+        })
+    }
+}
 
-    // strip off first part containing e.g. [$-F800] or [$USD-409]
-    // general syntax: [$<Currency string>-<language info>]
-    // language info is in hexadecimal
-    // strip off chinese part like [DBNum1][$-804]
-    let re = Regex::new(r"^(\[[0-9A-Za-z]*\])*(\[\$[A-Z]*-[0-9A-F]*\])").unwrap();
-    let format = re.replace_all(&format, r#""#);
+/// Formats a date serial with a date section.
+/// Returns `None` for serials out of the range of dates.
+pub(crate) fn format_as_date(section: &Section, value: f64, date1904: bool) -> Option<String> {
+    if value < 0f64 || value >= MAX_SERIAL {
+        return None;
+    }
+    let sub_second_digits = section
+        .tokens
+        .iter()
+        .filter_map(|v| match v {
+            Token::Date(DatePart::SubSecond(n)) => Some(*n as u32),
+            _ => None,
+        })
+        .max()
+        .unwrap_or(0)
+        .min(3);
+    let parts = DateParts::new(value, date1904, sub_second_digits)?;
 
-    // OpenOffice.org uses upper-case number formats, e.g. 'YYYY', convert to lower-case;
-    //    but we don't want to change any quoted strings
-    let re = Regex::new(r#"(?:^|")([^"]*)(?:$|")"#).unwrap();
-    let mut format = re.replace_all(&format, |caps: &Captures| {
-        let caps_string = caps.get(0).unwrap().as_str();
-        caps_string.to_lowercase()
+    let language = section.get_language();
+    let names = get_locale_names(language);
+    // the Japanese emperor era calendar
+    let calendar = section.locale.map(|v| (v >> 16) & 0xFF).unwrap_or(0);
+    let japanese_era = match parts.date {
+        Some(date) if language == LANGUAGE_JAPANESE || calendar == 3 => get_japanese_era(&date),
+        _ => None,
+    };
+    let twelve_hour = section.has_token(|v| {
+        matches!(
+            v,
+            Token::Date(DatePart::AmPm) | Token::Date(DatePart::ShortAmPm(_, _))
+        )
     });
+    let is_pm = parts.hour >= 12;
+    let hour = match (twelve_hour, parts.hour % 12) {
+        (true, 0) => 12,
+        (true, v) => v,
+        (false, _) => parts.hour,
+    };
 
-    // Only process the non-quoted blocks for date format characters
-    let blocks: Vec<&str> = format.split('"').collect();
-    let mut converted_blocks: Vec<String> = Vec::new();
-    let mut i = 0;
-    for block in blocks {
-        let mut block = block.to_string();
-        if i % 2 == 0 {
-            for (before, after) in DATE_FORMAT_REPLACEMENTS {
-                block = block.replace(before, after);
+    let mut result = String::new();
+    for token in &section.tokens {
+        let part = match token {
+            Token::Date(v) => v,
+            Token::Literal(v) => {
+                result.push_str(v);
+                continue;
             }
-            if !block.contains("%P") {
-                // 24-hour time format
-                // when [h]:mm format, the [h] should replace to the hours of the value * 24
-                if block.contains("[h]") {
-                    let hours = value * 24f64;
-                    block = block.replace("[h]", &hours.to_string());
-                    converted_blocks.push(block);
-                    continue;
-                }
-                for (before, after) in DATE_FORMAT_REPLACEMENTS_24 {
-                    block = block.replace(before, after);
-                }
-            } else {
-                for (before, after) in DATE_FORMAT_REPLACEMENTS_12 {
-                    block = block.replace(before, after);
-                }
+            Token::Fill(v) => {
+                result.push(*v);
+                continue;
+            }
+            _ => continue,
+        };
+        let text = match *part {
+            DatePart::Year(n) if n <= 2 => format!("{:02}", parts.year % 100),
+            DatePart::Year(_) => format!("{:04}", parts.year),
+            DatePart::Month(1) => parts.month.to_string(),
+            DatePart::Month(2) => format!("{:02}", parts.month),
+            DatePart::Month(3) => names.short_months[parts.month as usize - 1].to_string(),
+            DatePart::Month(4) => names.months[parts.month as usize - 1].to_string(),
+            DatePart::Month(_) => names.months[parts.month as usize - 1]
+                .chars()
+                .next()
+                .unwrap()
+                .to_string(),
+            DatePart::Day(1) => parts.day.to_string(),
+            DatePart::Day(2) => format!("{:02}", parts.day),
+            DatePart::Day(3) => names.short_days[parts.weekday].to_string(),
+            DatePart::Day(_) => names.days[parts.weekday].to_string(),
+            DatePart::Weekday(3) => {
+                get_locale_names(LANGUAGE_JAPANESE).short_days[parts.weekday].to_string()
+            }
+            DatePart::Weekday(_) => {
+                get_locale_names(LANGUAGE_JAPANESE).days[parts.weekday].to_string()
             }
-        }
-        converted_blocks.push(block);
-        i += 1;
+            DatePart::Hour(1) => hour.to_string(),
+            DatePart::Hour(_) => format!("{:02}", hour),
+            DatePart::Minute(1) => parts.minute.to_string(),
+            DatePart::Minute(_) => format!("{:02}", parts.minute),
+            DatePart::Second(1) => parts.second.to_string(),
+            DatePart::Second(_) => format!("{:02}", parts.second),
+            DatePart::SubSecond(n) => {
+                let digits = format!("{:01$}", parts.sub_second, sub_second_digits as usize);
+                format!(".{:0<1$.1$}", digits, n)
+            }
+            DatePart::ElapsedHours(n) => format!("{:0width$}", parts.elapsed / 3600, width = n),
+            DatePart::ElapsedMinutes(n) => format!("{:0width$}", parts.elapsed / 60, width = n),
+            DatePart::ElapsedSeconds(n) => format!("{:0width$}", parts.elapsed, width = n),
+            DatePart::AmPm => if is_pm { names.pm } else { names.am }.to_string(),
+            DatePart::ShortAmPm(am, pm) => if is_pm { pm } else { am }.to_string(),
+            DatePart::EraName(n) => match japanese_era {
+                Some((name, initial, _)) => match n {
+                    1 => initial.to_string(),
+                    2 => name.chars().next().unwrap().to_string(),
+                    _ => name.to_string(),
+                },
+                None => String::new(),
+            },
+            DatePart::EraYear(n) => match japanese_era {
+                Some((_, _, year)) if n >= 2 => format!("{:02}", year),
+                Some((_, _, year)) => year.to_string(),
+                None => parts.year.to_string(),
+            },
+            DatePart::BuddhistYear(n) if n <= 2 => format!("{:02}", (parts.year + 543) % 100),
+            DatePart::BuddhistYear(_) => (parts.year + 543).to_string(),
+        };
+        result.push_str(&text);
     }
-    format = Cow::Owned(converted_blocks.join(r#""#));
-
-    // escape any quoted characters so that DateTime format() will render them correctly
-    let re = Regex::new(r#""(.*)""#).unwrap();
-    let format = re.replace_all(&format, |caps: &Captures| {
-        let caps_string = caps.get(0).unwrap().as_str();
-        caps_string.to_lowercase()
-    });
-
-    let date_obj = excel_to_date_time_object(value, None);
-    Cow::Owned(date_obj.format(&format).to_string())
+    Some(result)
 }
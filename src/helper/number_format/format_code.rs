@@ -0,0 +1,668 @@
+// Parsed number format codes (ECMA-376 Part 1, 18.8.31 numFmts).
+use std::collections::HashMap;
+use std::sync::{Arc, RwLock};
+
+const CACHE_CAPACITY: usize = 1024;
+
+lazy_static! {
+    static ref FORMAT_CODE_CACHE: RwLock<HashMap<String, Arc<FormatCode>>> =
+        RwLock::new(HashMap::new());
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub(crate) enum Comparison {
+    Equal,
+    NotEqual,
+    Less,
+    LessOrEqual,
+    Greater,
+    GreaterOrEqual,
+}
+
+impl Comparison {
+    #[inline]
+    pub(crate) fn is_match(&self, value: f64, operand: f64) -> bool {
+        match self {
+            Comparison::Equal => value == operand,
+            Comparison::NotEqual => value != operand,
+            Comparison::Less => value < operand,
+            Comparison::LessOrEqual => value <= operand,
+            Comparison::Greater => value > operand,
+            Comparison::GreaterOrEqual => value >= operand,
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub(crate) enum Placeholder {
+    // 0
+    Zero,
+    // #
+    Hash,
+    // ?
+    Question,
+}
+
+impl Placeholder {
+    #[inline]
+    fn get_char(&self) -> char {
+        match self {
+            Placeholder::Zero => '0',
+            Placeholder::Hash => '#',
+            Placeholder::Question => '?',
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub(crate) enum DatePart {
+    Year(usize),
+    Month(usize),
+    Day(usize),
+    Hour(usize),
+    Minute(usize),
+    Second(usize),
+    SubSecond(usize),
+    ElapsedHours(usize),
+    ElapsedMinutes(usize),
+    ElapsedSeconds(usize),
+    // AM/PM
+    AmPm,
+    // A/P, written with the letters to display
+    ShortAmPm(char, char),
+    // g
+    EraName(usize),
+    // e
+    EraYear(usize),
+    // b
+    BuddhistYear(usize),
+    // aaa
+    Weekday(usize),
+}
+
+impl DatePart {
+    #[inline]
+    fn is_hour(&self) -> bool {
+        matches!(self, DatePart::Hour(_) | DatePart::ElapsedHours(_))
+    }
+
+    #[inline]
+    fn is_second(&self) -> bool {
+        matches!(self, DatePart::Second(_) | DatePart::ElapsedSeconds(_))
+    }
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub(crate) enum Token {
+    Literal(String),
+    // *x: repeat x to fill the cell, rendered once
+    Fill(char),
+    // @
+    Text,
+    General,
+    Digit(Placeholder),
+    Point,
+    Comma,
+    Percent,
+    // E+ (true) or E-
+    Exponent(bool),
+    // the slash of a fraction
+    Slash,
+    Date(DatePart),
+    // letters whose meaning depends on the section: g, e, b and aaa
+    Letters(String),
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub(crate) enum SectionKind {
+    Number,
+    Date,
+    Text,
+}
+
+#[derive(Clone, Debug)]
+pub(crate) struct Section {
+    pub(crate) tokens: Vec<Token>,
+    pub(crate) condition: Option<(Comparison, f64)>,
+    // LCID of a [$-409] tag
+    pub(crate) locale: Option<u32>,
+    // n of a [DBNumn] tag
+    pub(crate) numeral: Option<u8>,
+    pub(crate) kind: SectionKind,
+}
+
+impl Section {
+    #[inline]
+    pub(crate) fn get_language(&self) -> u32 {
+        self.locale.map(|v| v & 0x3FF).unwrap_or(0x09)
+    }
+
+    #[inline]
+    pub(crate) fn has_token(&self, predicate: impl Fn(&Token) -> bool) -> bool {
+        self.tokens.iter().any(predicate)
+    }
+
+    fn parse(code: &str) -> Self {
+        let mut section = Section {
+            tokens: Vec::new(),
+            condition: None,
+            locale: None,
+            numeral: None,
+            kind: SectionKind::Number,
+        };
+        section.tokenize(code);
+        section.resolve();
+        section
+    }
+
+    fn push_literal(&mut self, value: &str) {
+        if let Some(Token::Literal(v)) = self.tokens.last_mut() {
+            v.push_str(value);
+            return;
+        }
+        self.tokens.push(Token::Literal(value.to_string()));
+    }
+
+    fn tokenize(&mut self, code: &str) {
+        let chars: Vec<char> = code.chars().collect();
+        let mut i = 0;
+        while i < chars.len() {
+            let c = chars[i];
+            let rest = &chars[i..];
+            match c {
+                '"' => {
+                    let end = chars[i + 1..]
+                        .iter()
+                        .position(|v| v == &'"')
+                        .map(|v| i + 1 + v)
+                        .unwrap_or(chars.len());
+                    let literal: String = chars[i + 1..end].iter().collect();
+                    self.push_literal(&literal);
+                    i = end + 1;
+                    continue;
+                }
+                '\\' => {
+                    if let Some(v) = chars.get(i + 1) {
+                        self.push_literal(&v.to_string());
+                    }
+                    i += 2;
+                    continue;
+                }
+                '_' => {
+                    self.push_literal(" ");
+                    i += 2;
+                    continue;
+                }
+                '*' => {
+                    if let Some(v) = chars.get(i + 1) {
+                        self.tokens.push(Token::Fill(*v));
+                    }
+                    i += 2;
+                    continue;
+                }
+                '[' => {
+                    let end = chars[i + 1..]
+                        .iter()
+                        .position(|v| v == &']')
+                        .map(|v| i + 1 + v)
+                        .unwrap_or(chars.len());
+                    let content: String = chars[i + 1..end].iter().collect();
+                    self.parse_bracket(&content);
+                    i = end + 1;
+                    continue;
+                }
+                '@' => self.tokens.push(Token::Text),
+                '0' => self.tokens.push(Token::Digit(Placeholder::Zero)),
+                '#' => self.tokens.push(Token::Digit(Placeholder::Hash)),
+                '?' => self.tokens.push(Token::Digit(Placeholder::Question)),
+                '.' => self.tokens.push(Token::Point),
+                ',' => self.tokens.push(Token::Comma),
+                '%' => self.tokens.push(Token::Percent),
+                '/' => self.tokens.push(Token::Slash),
+                'E' | 'e' if matches!(chars.get(i + 1), Some('+') | Some('-')) => {
+                    self.tokens.push(Token::Exponent(chars[i + 1] == '+'));
+                    i += 2;
+                    continue;
+                }
+                _ if starts_with_ignore_case(rest, "general") => {
+                    self.tokens.push(Token::General);
+                    i += 7;
+                    continue;
+                }
+                _ if starts_with_ignore_case(rest, "G/標準") => {
+                    self.tokens.push(Token::General);
+                    i += 4;
+                    continue;
+                }
+                _ if starts_with_ignore_case(rest, "am/pm") => {
+                    self.tokens.push(Token::Date(DatePart::AmPm));
+                    i += 5;
+                    continue;
+                }
+                _ if starts_with_ignore_case(rest, "上午/下午") => {
+                    self.tokens.push(Token::Date(DatePart::AmPm));
+                    i += 5;
+                    continue;
+                }
+                _ if starts_with_ignore_case(rest, "a/p") => {
+                    self.tokens
+                        .push(Token::Date(DatePart::ShortAmPm(chars[i], chars[i + 2])));
+                    i += 3;
+                    continue;
+                }
+                // B1 and B2 pick the Gregorian and Hijri calendars
+                'B' | 'b' if matches!(chars.get(i + 1), Some('1') | Some('2')) => {
+                    i += 2;
+                    continue;
+                }
+                _ => {
+                    let lower = c.to_ascii_lowercase();
+                    let count = rest
+                        .iter()
+                        .take_while(|v| v.to_ascii_lowercase() == lower)
+                        .count();
+                    let part = match lower {
+                        'y' => Some(DatePart::Year(count)),
+                        'm' => Some(DatePart::Month(count)),
+                        'd' => Some(DatePart::Day(count)),
+                        'h' => Some(DatePart::Hour(count)),
+                        's' => Some(DatePart::Second(count)),
+                        _ => None,
+                    };
+                    if let Some(v) = part {
+                        self.tokens.push(Token::Date(v));
+                        i += count;
+                        continue;
+                    }
+                    if matches!(lower, 'g' | 'e' | 'b') || (lower == 'a' && count >= 3) {
+                        self.tokens
+                            .push(Token::Letters(rest[..count].iter().collect()));
+                        i += count;
+                        continue;
+                    }
+                    self.push_literal(&c.to_string());
+                }
+            }
+            i += 1;
+        }
+    }
+
+    fn parse_bracket(&mut self, content: &str) {
+        let lower = content.to_ascii_lowercase();
+        if let Some(v) = content.strip_prefix('$') {
+            let (currency, lcid) = match v.rfind('-') {
+                Some(pos) => (&v[..pos], &v[pos + 1..]),
+                None => (v, ""),
+            };
+            if !currency.is_empty() {
+                self.push_literal(currency);
+            }
+            if let Ok(lcid) = u32::from_str_radix(lcid, 16) {
+                self.locale = Some(lcid);
+                // system long date and system time
+                match lcid & 0xFFFF {
+                    0xF800 => self.tokenize("dddd, mmmm d, yyyy"),
+                    0xF400 => self.tokenize("h:mm:ss AM/PM"),
+                    _ => {}
+                }
+            }
+            return;
+        }
+        if let Some(v) = lower.strip_prefix("dbnum") {
+            self.numeral = v.parse::<u8>().ok();
+            return;
+        }
+        if let Some(v) = parse_condition(content) {
+            self.condition = Some(v);
+            return;
+        }
+        let count = lower.chars().count();
+        if count > 0 {
+            let first = lower.chars().next().unwrap();
+            if lower.chars().all(|v| v == first) {
+                let part = match first {
+                    'h' => Some(DatePart::ElapsedHours(count)),
+                    'm' => Some(DatePart::ElapsedMinutes(count)),
+                    's' => Some(DatePart::ElapsedSeconds(count)),
+                    _ => None,
+                };
+                if let Some(v) = part {
+                    self.tokens.push(Token::Date(v));
+                }
+            }
+        }
+        // colors ([Red], [Color10]) do not change the text
+    }
+
+    fn resolve(&mut self) {
+        // era and Buddhist years make a date on their own, g and aaa only with other date codes
+        let is_date = self.has_token(|v| match v {
+            Token::Date(_) => true,
+            Token::Letters(v) => v.starts_with(['e', 'E', 'b', 'B']),
+            _ => false,
+        });
+        if self.has_token(|v| v == &Token::Text) {
+            self.kind = SectionKind::Text;
+        } else if is_date {
+            self.kind = SectionKind::Date;
+        }
+
+        let tokens = std::mem::take(&mut self.tokens);
+        for token in tokens {
+            match token {
+                Token::Letters(v) if self.kind == SectionKind::Date => {
+                    let count = v.chars().count();
+                    let part = match v.chars().next().unwrap().to_ascii_lowercase() {
+                        'g' => DatePart::EraName(count),
+                        'e' => DatePart::EraYear(count),
+                        'b' => DatePart::BuddhistYear(count),
+                        _ => DatePart::Weekday(count),
+                    };
+                    self.tokens.push(Token::Date(part));
+                }
+                Token::Letters(v) => self.push_literal(&v),
+                _ => self.tokens.push(token),
+            }
+        }
+
+        match self.kind {
+            SectionKind::Date => self.resolve_date(),
+            SectionKind::Text => self.demote_number_tokens(),
+            SectionKind::Number => self.resolve_number(),
+        }
+    }
+
+    fn resolve_date(&mut self) {
+        // m is a minute right after an hour or right before a second
+        let dates: Vec<(usize, DatePart)> = self
+            .tokens
+            .iter()
+            .enumerate()
+            .filter_map(|(i, v)| match v {
+                Token::Date(part) => Some((i, *part)),
+                _ => None,
+            })
+            .collect();
+        for (n, (i, part)) in dates.iter().enumerate() {
+            if let DatePart::Month(count) = part {
+                if *count > 2 {
+                    continue;
+                }
+                let after_hour = n > 0 && dates[n - 1].1.is_hour();
+                let before_second = matches!(dates.get(n + 1), Some((_, v)) if v.is_second());
+                if after_hour || before_second {
+                    self.tokens[*i] = Token::Date(DatePart::Minute(*count));
+                }
+            }
+        }
+
+        // fractional seconds: ss.000
+        let mut i = 0;
+        while i < self.tokens.len() {
+            if self.tokens[i] == Token::Point {
+                let count = self.tokens[i + 1..]
+                    .iter()
+                    .take_while(|v| v == &&Token::Digit(Placeholder::Zero))
+                    .count();
+                if count > 0 {
+                    self.tokens
+                        .splice(i..=i + count, [Token::Date(DatePart::SubSecond(count))]);
+                }
+            }
+            i += 1;
+        }
+        self.demote_number_tokens();
+    }
+
+    fn resolve_number(&mut self) {
+        // only the first slash between digits is a fraction
+        let mut found = false;
+        let mut i = 0;
+        while i < self.tokens.len() {
+            i += 1;
+            if self.tokens[i - 1] != Token::Slash {
+                continue;
+            }
+            let i = i - 1;
+            let before = matches!(self.tokens[..i].last(), Some(Token::Digit(_)));
+            let after = match self.tokens.get(i + 1) {
+                Some(Token::Digit(_)) => true,
+                Some(Token::Literal(v)) => v.starts_with(|c: char| c.is_ascii_digit()),
+                _ => false,
+            };
+            if found || !before || !after {
+                self.tokens[i] = Token::Literal("/".to_string());
+                continue;
+            }
+            found = true;
+            // a fixed denominator such as ?/10
+            if let Some(Token::Literal(denominator)) = self.tokens.get(i + 1).cloned() {
+                if denominator.chars().all(|c| c.is_ascii_digit()) {
+                    let count = self.tokens[i + 2..]
+                        .iter()
+                        .take_while(|v| v == &&Token::Digit(Placeholder::Zero))
+                        .count();
+                    let denominator = format!("{}{}", denominator, "0".repeat(count));
+                    self.tokens
+                        .splice(i + 1..i + 2 + count, [Token::Literal(denominator)]);
+                }
+            }
+        }
+        self.merge_literals();
+    }
+
+    // date and text sections show number placeholders as they are written
+    fn demote_number_tokens(&mut self) {
+        for token in self.tokens.iter_mut() {
+            let literal = match token {
+                Token::Digit(v) => v.get_char().to_string(),
+                Token::Point => ".".to_string(),
+                Token::Comma => ",".to_string(),
+                Token::Percent => "%".to_string(),
+                Token::Slash => "/".to_string(),
+                Token::Exponent(true) => "E+".to_string(),
+                Token::Exponent(false) => "E-".to_string(),
+                _ => continue,
+            };
+            *token = Token::Literal(literal);
+        }
+        self.merge_literals();
+    }
+
+    fn merge_literals(&mut self) {
+        let tokens = std::mem::take(&mut self.tokens);
+        for token in tokens {
+            match token {
+                Token::Literal(v) => self.push_literal(&v),
+                _ => self.tokens.push(token),
+            }
+        }
+    }
+}
+
+#[derive(Clone, Debug)]
+pub(crate) struct FormatCode {
+    pub(crate) sections: Vec<Section>,
+}
+
+impl FormatCode {
+    /// Parsed format code, shared through a cache.
+    pub(crate) fn get(code: &str) -> Arc<FormatCode> {
+        if let Some(v) = FORMAT_CODE_CACHE
+            .read()
+            .ok()
+            .and_then(|cache| cache.get(code).cloned())
+        {
+            return v;
+        }
+        let format_code = Arc::new(FormatCode::parse(code));
+        if let Ok(mut cache) = FORMAT_CODE_CACHE.write() {
+            if cache.len() >= CACHE_CAPACITY {
+                cache.clear();
+            }
+            cache.insert(code.to_string(), format_code.clone());
+        }
+        format_code
+    }
+
+    pub(crate) fn parse(code: &str) -> Self {
+        FormatCode {
+            sections: split_sections(code)
+                .into_iter()
+                .map(|v| Section::parse(&v))
+                .collect(),
+        }
+    }
+
+    // The last of fewer than four sections is for text only when it has an @.
+    #[inline]
+    fn get_number_section_count(&self) -> usize {
+        let count = self.sections.len().min(4);
+        match self.sections.last() {
+            Some(v) if count == 4 || v.kind == SectionKind::Text => count - 1,
+            _ => count,
+        }
+    }
+
+    /// The section for a number, and whether the section shows the minus sign itself.
+    /// `None` means the number is shown as General.
+    pub(crate) fn get_number_section(&self, value: f64) -> Option<(&Section, bool)> {
+        let count = self.get_number_section_count();
+        if count == 0 {
+            return None;
+        }
+        let sections = &self.sections[..count];
+        let first = &sections[0];
+        let second = sections.get(1);
+        let explicit = count > 1;
+
+        let first_condition = first.condition;
+        let second_condition = second.and_then(|v| v.condition);
+        if first_condition.is_some() || second_condition.is_some() {
+            if matches!(first_condition, Some((op, operand)) if op.is_match(value, operand)) {
+                return Some((first, explicit));
+            }
+            if matches!(second_condition, Some((op, operand)) if op.is_match(value, operand)) {
+                return Some((second.unwrap(), explicit));
+            }
+            let section = match (first_condition, second_condition) {
+                (Some(_), Some(_)) => sections.get(2).unwrap_or(first),
+                _ => second.unwrap_or(first),
+            };
+            return Some((section, explicit));
+        }
+
+        let section = if value < 0f64 {
+            second.unwrap_or(first)
+        } else if value == 0f64 {
+            sections.get(2).unwrap_or(first)
+        } else {
+            first
+        };
+        Some((section, explicit))
+    }
+
+    /// The section for a text value, if any.
+    pub(crate) fn get_text_section(&self) -> Option<&Section> {
+        if self.sections.len() >= 4 {
+            return self.sections.get(3);
+        }
+        self.sections.last().filter(|v| v.kind == SectionKind::Text)
+    }
+}
+
+fn split_sections(code: &str) -> Vec<String> {
+    let mut sections = Vec::new();
+    let mut current = String::new();
+    let mut chars = code.chars();
+    let mut in_quote = false;
+    let mut in_bracket = false;
+    while let Some(c) = chars.next() {
+        match c {
+            '"' if !in_bracket => in_quote = !in_quote,
+            '[' if !in_quote => in_bracket = true,
+            ']' if !in_quote => in_bracket = false,
+            '\\' | '_' | '*' if !in_quote && !in_bracket => {
+                current.push(c);
+                if let Some(v) = chars.next() {
+                    current.push(v);
+                }
+                continue;
+            }
+            ';' if !in_quote && !in_bracket => {
+                sections.push(std::mem::take(&mut current));
+                continue;
+            }
+            _ => {}
+        }
+        current.push(c);
+    }
+    sections.push(current);
+    sections
+}
+
+fn parse_condition(content: &str) -> Option<(Comparison, f64)> {
+    let (op, operand) = [
+        ("<>", Comparison::NotEqual),
+        ("<=", Comparison::LessOrEqual),
+        (">=", Comparison::GreaterOrEqual),
+        ("<", Comparison::Less),
+        (">", Comparison::Greater),
+        ("=", Comparison::Equal),
+    ]
+    .iter()
+    .find_map(|(prefix, op)| content.strip_prefix(prefix).map(|v| (*op, v)))?;
+    operand.trim().parse::<f64>().ok().map(|v| (op, v))
+}
+
+#[inline]
+fn starts_with_ignore_case(chars: &[char], value: &str) -> bool {
+    let mut iter = chars.iter();
+    value.chars().all(|v| match iter.next() {
+        Some(c) => c.to_lowercase().eq(v.to_lowercase()),
+        None => false,
+    })
+}
+
+#[test]
+fn parse() {
+    let code = FormatCode::parse(r#"[Red][<=100]#,##0.00_);[Blue]"neg "0;"zero";@" text""#);
+    assert_eq!(code.sections.len(), 4);
+    assert_eq!(
+        code.sections[0].condition,
+        Some((Comparison::LessOrEqual, 100f64))
+    );
+    assert_eq!(code.sections[0].tokens[1], Token::Comma);
+    assert_eq!(
+        code.sections[1].tokens[0],
+        Token::Literal("neg ".to_string())
+    );
+    assert_eq!(code.sections[3].kind, SectionKind::Text);
+    assert_eq!(code.get_number_section_count(), 3);
+
+    let code = FormatCode::parse(r#"[$-411]ggge"年"m"月"d"日";@"#);
+    assert_eq!(code.sections[0].locale, Some(0x411));
+    assert_eq!(code.sections[0].kind, SectionKind::Date);
+    assert_eq!(
+        code.sections[0].tokens[0],
+        Token::Date(DatePart::EraName(3))
+    );
+    assert_eq!(code.get_number_section_count(), 1);
+
+    let code = FormatCode::parse("[h]:mm:ss.00");
+    assert_eq!(
+        code.sections[0].tokens,
+        vec![
+            Token::Date(DatePart::ElapsedHours(1)),
+            Token::Literal(":".to_string()),
+            Token::Date(DatePart::Minute(2)),
+            Token::Literal(":".to_string()),
+            Token::Date(DatePart::Second(2)),
+            Token::Date(DatePart::SubSecond(2)),
+        ]
+    );
+
+    let code = FormatCode::parse(r#"0 "kg";\;"#);
+    assert_eq!(code.sections.len(), 2);
+    assert_eq!(code.sections[0].kind, SectionKind::Number);
+}
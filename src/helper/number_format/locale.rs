@@ -0,0 +1,408 @@
+// Names and numerals for the locales of [$-xxx] tags.
+use chrono::NaiveDate;
+
+pub(crate) struct LocaleNames {
+    pub(crate) months: [&'static str; 12],
+    pub(crate) short_months: [&'static str; 12],
+    // starting on Sunday
+    pub(crate) days: [&'static str; 7],
+    pub(crate) short_days: [&'static str; 7],
+    pub(crate) am: &'static str,
+    pub(crate) pm: &'static str,
+}
+
+const ENGLISH: LocaleNames = LocaleNames {
+    months: [
+        "January",
+        "February",
+        "March",
+        "April",
+        "May",
+        "June",
+        "July",
+        "August",
+        "September",
+        "October",
+        "November",
+        "December",
+    ],
+    short_months: [
+        "Jan", "Feb", "Mar", "Apr", "May", "Jun", "Jul", "Aug", "Sep", "Oct", "Nov", "Dec",
+    ],
+    days: [
+        "Sunday",
+        "Monday",
+        "Tuesday",
+        "Wednesday",
+        "Thursday",
+        "Friday",
+        "Saturday",
+    ],
+    short_days: ["Sun", "Mon", "Tue", "Wed", "Thu", "Fri", "Sat"],
+    am: "AM",
+    pm: "PM",
+};
+
+const JAPANESE: LocaleNames = LocaleNames {
+    months: [
+        "1月", "2月", "3月", "4月", "5月", "6月", "7月", "8月", "9月", "10月", "11月", "12月",
+    ],
+    short_months: [
+        "1月", "2月", "3月", "4月", "5月", "6月", "7月", "8月", "9月", "10月", "11月", "12月",
+    ],
+    days: [
+        "日曜日",
+        "月曜日",
+        "火曜日",
+        "水曜日",
+        "木曜日",
+        "金曜日",
+        "土曜日",
+    ],
+    short_days: ["日", "月", "火", "水", "木", "金", "土"],
+    am: "午前",
+    pm: "午後",
+};
+
+const CHINESE: LocaleNames = LocaleNames {
+    months: [
+        "一月",
+        "二月",
+        "三月",
+        "四月",
+        "五月",
+        "六月",
+        "七月",
+        "八月",
+        "九月",
+        "十月",
+        "十一月",
+        "十二月",
+    ],
+    short_months: [
+        "1月", "2月", "3月", "4月", "5月", "6月", "7月", "8月", "9月", "10月", "11月", "12月",
+    ],
+    days: [
+        "星期日",
+        "星期一",
+        "星期二",
+        "星期三",
+        "星期四",
+        "星期五",
+        "星期六",
+    ],
+    short_days: ["周日", "周一", "周二", "周三", "周四", "周五", "周六"],
+    am: "上午",
+    pm: "下午",
+};
+
+const KOREAN: LocaleNames = LocaleNames {
+    months: [
+        "1월", "2월", "3월", "4월", "5월", "6월", "7월", "8월", "9월", "10월", "11월", "12월",
+    ],
+    short_months: [
+        "1월", "2월", "3월", "4월", "5월", "6월", "7월", "8월", "9월", "10월", "11월", "12월",
+    ],
+    days: [
+        "일요일",
+        "월요일",
+        "화요일",
+        "수요일",
+        "목요일",
+        "금요일",
+        "토요일",
+    ],
+    short_days: ["일", "월", "화", "수", "목", "금", "토"],
+    am: "오전",
+    pm: "오후",
+};
+
+const GERMAN: LocaleNames = LocaleNames {
+    months: [
+        "Januar",
+        "Februar",
+        "März",
+        "April",
+        "Mai",
+        "Juni",
+        "Juli",
+        "August",
+        "September",
+        "Oktober",
+        "November",
+        "Dezember",
+    ],
+    short_months: [
+        "Jan", "Feb", "Mrz", "Apr", "Mai", "Jun", "Jul", "Aug", "Sep", "Okt", "Nov", "Dez",
+    ],
+    days: [
+        "Sonntag",
+        "Montag",
+        "Dienstag",
+        "Mittwoch",
+        "Donnerstag",
+        "Freitag",
+        "Samstag",
+    ],
+    short_days: ["So", "Mo", "Di", "Mi", "Do", "Fr", "Sa"],
+    am: "AM",
+    pm: "PM",
+};
+
+const FRENCH: LocaleNames = LocaleNames {
+    months: [
+        "janvier",
+        "février",
+        "mars",
+        "avril",
+        "mai",
+        "juin",
+        "juillet",
+        "août",
+        "septembre",
+        "octobre",
+        "novembre",
+        "décembre",
+    ],
+    short_months: [
+        "janv.", "févr.", "mars", "avr.", "mai", "juin", "juil.", "août", "sept.", "oct.", "nov.",
+        "déc.",
+    ],
+    days: [
+        "dimanche", "lundi", "mardi", "mercredi", "jeudi", "vendredi", "samedi",
+    ],
+    short_days: ["dim.", "lun.", "mar.", "mer.", "jeu.", "ven.", "sam."],
+    am: "AM",
+    pm: "PM",
+};
+
+const SPANISH: LocaleNames = LocaleNames {
+    months: [
+        "enero",
+        "febrero",
+        "marzo",
+        "abril",
+        "mayo",
+        "junio",
+        "julio",
+        "agosto",
+        "septiembre",
+        "octubre",
+        "noviembre",
+        "diciembre",
+    ],
+    short_months: [
+        "ene", "feb", "mar", "abr", "may", "jun", "jul", "ago", "sept", "oct", "nov", "dic",
+    ],
+    days: [
+        "domingo",
+        "lunes",
+        "martes",
+        "miércoles",
+        "jueves",
+        "viernes",
+        "sábado",
+    ],
+    short_days: ["dom", "lun", "mar", "mié", "jue", "vie", "sáb"],
+    am: "a. m.",
+    pm: "p. m.",
+};
+
+const ITALIAN: LocaleNames = LocaleNames {
+    months: [
+        "gennaio",
+        "febbraio",
+        "marzo",
+        "aprile",
+        "maggio",
+        "giugno",
+        "luglio",
+        "agosto",
+        "settembre",
+        "ottobre",
+        "novembre",
+        "dicembre",
+    ],
+    short_months: [
+        "gen", "feb", "mar", "apr", "mag", "giu", "lug", "ago", "set", "ott", "nov", "dic",
+    ],
+    days: [
+        "domenica",
+        "lunedì",
+        "martedì",
+        "mercoledì",
+        "giovedì",
+        "venerdì",
+        "sabato",
+    ],
+    short_days: ["dom", "lun", "mar", "mer", "gio", "ven", "sab"],
+    am: "AM",
+    pm: "PM",
+};
+
+pub(crate) const LANGUAGE_JAPANESE: u32 = 0x11;
+pub(crate) const LANGUAGE_CHINESE: u32 = 0x04;
+pub(crate) const LANGUAGE_KOREAN: u32 = 0x12;
+
+/// Names for the primary language of an LCID.
+pub(crate) fn get_locale_names(language: u32) -> &'static LocaleNames {
+    match language {
+        LANGUAGE_JAPANESE => &JAPANESE,
+        LANGUAGE_CHINESE => &CHINESE,
+        LANGUAGE_KOREAN => &KOREAN,
+        0x07 => &GERMAN,
+        0x0C => &FRENCH,
+        0x0A => &SPANISH,
+        0x10 => &ITALIAN,
+        _ => &ENGLISH,
+    }
+}
+
+// start date, name, initial
+const JAPANESE_ERAS: &[((i32, u32, u32), &str, &str)] = &[
+    ((2019, 5, 1), "令和", "R"),
+    ((1989, 1, 8), "平成", "H"),
+    ((1926, 12, 25), "昭和", "S"),
+    ((1912, 7, 30), "大正", "T"),
+    ((1868, 9, 8), "明治", "M"),
+];
+
+/// Japanese era name and year of a date, if the date is in an era.
+pub(crate) fn get_japanese_era(date: &NaiveDate) -> Option<(&'static str, &'static str, i32)> {
+    use chrono::Datelike;
+    JAPANESE_ERAS
+        .iter()
+        .find(|((y, m, d), _, _)| date >= &NaiveDate::from_ymd_opt(*y, *m, *d).unwrap())
+        .map(|((y, _, _), name, initial)| (*name, *initial, date.year() - y + 1))
+}
+
+/// Rewrites the ASCII digits of a formatted value for a [DBNumn] tag.
+/// [DBNum1] and [DBNum2] spell integers with place values (千二百三十四),
+/// [DBNum3] uses full-width digits. Digits after a decimal point are converted one by one.
+pub(crate) fn convert_numerals(value: &str, numeral: u8, language: u32) -> String {
+    let chinese = language == LANGUAGE_CHINESE;
+    let (digits, units): ([&str; 10], [&str; 3]) = match (numeral, chinese) {
+        (1, _) => (
+            ["〇", "一", "二", "三", "四", "五", "六", "七", "八", "九"],
+            ["十", "百", "千"],
+        ),
+        (2, false) => (
+            ["〇", "壱", "弐", "参", "四", "伍", "六", "七", "八", "九"],
+            ["拾", "百", "阡"],
+        ),
+        (2, true) => (
+            ["零", "壹", "贰", "叁", "肆", "伍", "陆", "柒", "捌", "玖"],
+            ["拾", "佰", "仟"],
+        ),
+        (3, _) => (
+            ["０", "１", "２", "３", "４", "５", "６", "７", "８", "９"],
+            ["", "", ""],
+        ),
+        _ => return value.to_string(),
+    };
+    let large_units: [&str; 3] = match (numeral, chinese) {
+        (2, false) => ["萬", "億", "兆"],
+        (_, true) => ["万", "亿", "兆"],
+        _ => ["万", "億", "兆"],
+    };
+
+    let mut result = String::new();
+    let mut run = String::new();
+    let mut after_point = false;
+    let mut flush = |run: &mut String, after_point: bool, result: &mut String| {
+        if run.is_empty() {
+            return;
+        }
+        if numeral == 3 || after_point || run.len() > 16 {
+            run.bytes()
+                .for_each(|v| result.push_str(digits[(v - b'0') as usize]));
+        } else {
+            result.push_str(&spell_number(
+                run.parse::<u64>().unwrap(),
+                &digits,
+                &units,
+                &large_units,
+                numeral == 1 && !chinese,
+                if chinese { Some("零") } else { None },
+            ));
+        }
+        run.clear();
+    };
+    for c in value.chars() {
+        if c.is_ascii_digit() {
+            run.push(c);
+            continue;
+        }
+        flush(&mut run, after_point, &mut result);
+        after_point = c == '.';
+        result.push(c);
+    }
+    flush(&mut run, after_point, &mut result);
+    result
+}
+
+fn spell_number(
+    value: u64,
+    digits: &[&str; 10],
+    units: &[&str; 3],
+    large_units: &[&str; 3],
+    omit_one: bool,
+    zero_gap: Option<&str>,
+) -> String {
+    if value == 0 {
+        return digits[0].to_string();
+    }
+    let mut groups = Vec::new();
+    let mut rest = value;
+    while rest > 0 {
+        groups.push(rest % 10000);
+        rest /= 10000;
+    }
+    let mut result = String::new();
+    let mut pending_zero = false;
+    for (index, group) in groups.iter().enumerate().rev() {
+        if *group == 0 {
+            pending_zero = !result.is_empty();
+            continue;
+        }
+        if !result.is_empty() && *group < 1000 {
+            pending_zero = true;
+        }
+        for place in (0..4).rev() {
+            let digit = (group / 10u64.pow(place)) % 10;
+            if digit == 0 {
+                if !result.is_empty() && place > 0 && group % 10u64.pow(place) != 0 {
+                    pending_zero = true;
+                }
+                continue;
+            }
+            if let Some(v) = zero_gap.filter(|_| pending_zero) {
+                result.push_str(v);
+            }
+            pending_zero = false;
+            if !(omit_one && digit == 1 && place > 0) {
+                result.push_str(digits[digit as usize]);
+            }
+            if place > 0 {
+                result.push_str(units[place as usize - 1]);
+            }
+        }
+        if index > 0 {
+            result.push_str(large_units[index - 1]);
+        }
+    }
+    result
+}
+
+#[test]
+fn numerals() {
+    assert_eq!(
+        convert_numerals("1234", 1, LANGUAGE_JAPANESE),
+        "千二百三十四"
+    );
+    assert_eq!(convert_numerals("10004", 1, LANGUAGE_JAPANESE), "一万四");
+    assert_eq!(convert_numerals("1004", 1, LANGUAGE_CHINESE), "一千零四");
+    assert_eq!(convert_numerals("20", 2, LANGUAGE_JAPANESE), "弐拾");
+    assert_eq!(convert_numerals("12.5", 3, LANGUAGE_JAPANESE), "１２.５");
+    assert_eq!(convert_numerals("3.25", 1, LANGUAGE_JAPANESE), "三.二五");
+}
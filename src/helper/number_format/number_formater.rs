@@ -1,272 +1,474 @@
-use super::fraction_formater::*;
-use fancy_regex::Regex;
-use std::borrow::Cow;
-use thousands::Separable;
+use super::format_code::{Placeholder, Section, Token};
 
-pub(crate) fn format_as_number<'input>(value: &f64, format: &'input str) -> Cow<'input, str> {
-    lazy_static! {
-        static ref THOUSANDS_SEP_REGEX: Regex = Regex::new(r#"(#,#|0,0)"#).unwrap();
-        static ref SCALE_REGEX: Regex = Regex::new(r#"(#|0)(,+)"#).unwrap();
-        static ref TRAILING_COMMA_REGEX: Regex = Regex::new("(#|0),+").unwrap();
-        static ref FRACTION_REGEX: Regex = Regex::new(r"#?.*\?{1,2}\/\?{1,2}").unwrap();
-        static ref SQUARE_BRACKET_REGEX: Regex = Regex::new(r"\[[^\]]+\]").unwrap();
-        static ref NUMBER_REGEX: Regex = Regex::new(r"(0+)(\.?)(0*)").unwrap();
-    }
-
-    let mut value = value.to_string();
-
-    // The "_" in this string has already been stripped out,
-    // so this test is never true. Furthermore, testing
-    // on Excel shows this format uses Euro symbol, not "EUR".
-    //if ($format === self::FORMAT_CURRENCY_EUR_SIMPLE) {
-    //    return 'EUR ' . sprintf('%1.2f', $value);
-    //}
+/// Decimal digits of a number, limited to the 15 significant digits Excel keeps.
+#[derive(Clone, Debug)]
+struct Digits {
+    digits: Vec<u8>,
+    // count of digits before the decimal point
+    point: i32,
+}
 
-    // Some non-number strings are quoted, so we'll get rid of the quotes, likewise any positional * symbols
-    let mut format = format.replace(['"', '*'], "");
+impl Digits {
+    fn new(value: f64) -> Self {
+        let value = value.abs();
+        if value == 0f64 || !value.is_finite() {
+            return Digits {
+                digits: vec![0],
+                point: 1,
+            };
+        }
+        let text = format!("{:.14e}", value);
+        let (mantissa, exponent) = text.split_once('e').unwrap();
+        Digits {
+            digits: mantissa
+                .bytes()
+                .filter(u8::is_ascii_digit)
+                .map(|v| v - b'0')
+                .collect(),
+            point: exponent.parse::<i32>().unwrap() + 1,
+        }
+    }
 
-    // Find out if we need thousands separator
-    // This is indicated by a comma enclosed by a digit placeholder:
-    //        #,#   or   0,0
+    #[inline]
+    fn is_zero(&self) -> bool {
+        self.digits.iter().all(|v| v == &0)
+    }
 
-    let use_thousands = THOUSANDS_SEP_REGEX.is_match(&format).unwrap_or(false);
-    if use_thousands {
-        format = format.replace("0,0", "00");
-        format = format.replace("#,#", "##");
+    #[inline]
+    fn get_exponent(&self) -> i32 {
+        self.point - 1
     }
 
-    // Scale thousands, millions,...
-    // This is indicated by a number of commas after a digit placeholder:
-    //        #,   or    0.0,,
-    let mut scale: f64 = 1f64; // same as no scale
+    // the digits divided by 10^exponent
+    #[inline]
+    fn shift(&self, exponent: i32) -> Self {
+        Digits {
+            digits: self.digits.clone(),
+            point: self.point - exponent,
+        }
+    }
 
-    if SCALE_REGEX.is_match(&format).unwrap_or(false) {
-        let mut matches: Vec<&str> = Vec::new();
-        for ite in SCALE_REGEX.captures(&format).ok().flatten().unwrap().iter() {
-            matches.push(ite.unwrap().as_str());
+    /// Integer digits without leading zeros and `decimals` fraction digits,
+    /// rounded half away from zero.
+    fn round(&self, decimals: usize) -> (String, String) {
+        let mut digits = self.digits.clone();
+        let mut point = self.point;
+        if point < 0 {
+            let mut padded = vec![0; (-point) as usize];
+            padded.append(&mut digits);
+            digits = padded;
+            point = 0;
         }
-        scale = 1000i32.pow(matches[2].len() as u32) as f64;
+        let cut = point as usize + decimals;
+        let round_up = matches!(digits.get(cut), Some(v) if v >= &5);
+        digits.resize(cut, 0);
+        if round_up {
+            let mut i = cut;
+            loop {
+                if i == 0 {
+                    digits.insert(0, 1);
+                    point += 1;
+                    break;
+                }
+                i -= 1;
+                if digits[i] == 9 {
+                    digits[i] = 0;
+                } else {
+                    digits[i] += 1;
+                    break;
+                }
+            }
+        }
+        let to_string = |v: &[u8]| v.iter().map(|d| (d + b'0') as char).collect::<String>();
+        let integer = to_string(&digits[..point as usize]);
+        let fraction = to_string(&digits[point as usize..]);
+        (integer.trim_start_matches('0').to_string(), fraction)
+    }
+}
 
-        // strip the commas
-        format = TRAILING_COMMA_REGEX.replace_all(&format, "$1").into()
+/// The General format: as many digits as fit in 11 characters,
+/// switching to scientific notation for very large and very small numbers.
+pub(crate) fn format_as_general(value: f64) -> String {
+    let digits = Digits::new(value);
+    if digits.is_zero() {
+        return "0".to_string();
     }
-    if FRACTION_REGEX.is_match(&format).unwrap_or(false) {
-        if value.parse::<usize>().is_err() {
-            //println!("format as fraction {} {}", value, format);
-            value = format_as_fraction(&value.parse::<f64>().unwrap(), &format);
+    let fixed = |decimals: usize| {
+        let (integer, fraction) = digits.round(decimals);
+        let integer = if integer.is_empty() { "0" } else { &integer };
+        match fraction.trim_end_matches('0') {
+            "" => integer.to_string(),
+            v => format!("{}.{}", integer, v),
         }
+    };
+    let exponent = digits.get_exponent();
+    let result = match exponent {
+        -4..=-1 => Some(fixed(9)),
+        -9..=9 => Some(fixed(12))
+            .filter(|v| v.len() <= 11)
+            .or_else(|| (exponent >= 0).then(|| fixed((9 - exponent) as usize))),
+        10 => Some(fixed(0)),
+        _ => None,
+    };
+    let result = result
+        .filter(|v| v.len() <= 11)
+        .unwrap_or_else(|| format_general_scientific(&digits));
+    if value < 0f64 {
+        format!("-{}", result)
     } else {
-        // Handle the number itself
-
-        // scale number
-        value = (value.parse::<f64>().unwrap() / scale).to_string();
-        // Strip #
-        format = format.replace('#', "0");
-        // Remove \
-        format = format.replace('\\', "");
-        // Remove locale code [$-###]
-        format = format.replace("[$-.*]", "");
-        // Trim
-        format = format.trim().to_string();
-
-        let m = SQUARE_BRACKET_REGEX.replace_all(&format, "");
-
-        if NUMBER_REGEX.is_match(&m).unwrap_or(false) {
-            let mut item: Vec<String> = Vec::new();
-            for ite in NUMBER_REGEX.captures(&m).ok().flatten().unwrap().iter() {
-                item.push(ite.unwrap().as_str().to_string());
-            }
-            value = format_straight_numeric_value(
-                &value,
-                &format,
-                &item,
-                &use_thousands,
-                r"(0+)(\.?)(0*)",
-            );
-        }
+        result
     }
+}
 
-    let re = Regex::new(r"\$[^0-9]*").unwrap();
-    if re.find(&format).ok().flatten().is_some() {
-        let item: Vec<&str> = re
-            .captures(&format)
-            .ok()
-            .flatten()
-            .unwrap()
-            .iter()
-            .map(|ite| ite.unwrap().as_str())
-            .collect();
-        value = format!("{}{}", item[0], value);
-        //    //  Currency or Accounting
-        //    let currency_code = item.get(1).unwrap().to_string();
-        //    value = Regex::new(r#"\[\$([^\]]*)\]"#).unwrap().replace_all(&value, currency_code.as_str()).to_string();
+fn format_general_scientific(digits: &Digits) -> String {
+    let mut exponent = digits.get_exponent();
+    let (mut integer, mut fraction) = digits.shift(exponent).round(5);
+    if integer.len() > 1 {
+        exponent += 1;
+        (integer, fraction) = digits.shift(exponent).round(5);
     }
+    let fraction = fraction.trim_end_matches('0');
+    format!(
+        "{}{}{}E{}{:02}",
+        integer,
+        if fraction.is_empty() { "" } else { "." },
+        fraction,
+        if exponent < 0 { "-" } else { "+" },
+        exponent.abs()
+    )
+}
 
-    Cow::Owned(value)
+/// Formats a number with a number section.
+/// A negative value gets a leading minus sign.
+pub(crate) fn format_as_number(section: &Section, value: f64) -> String {
+    let tokens = &section.tokens;
+    let body = if let Some(index) = tokens.iter().position(|v| v == &Token::Slash) {
+        format_fraction(tokens, index, value.abs())
+    } else if let Some(index) = tokens.iter().position(|v| matches!(v, Token::Exponent(_))) {
+        format_scientific(tokens, index, value.abs())
+    } else {
+        format_decimal(tokens, value.abs())
+    };
+    if value < 0f64 {
+        format!("-{}", body)
+    } else {
+        body
+    }
 }
 
-fn format_straight_numeric_value(
-    value: &str,
-    _format: &str,
-    matches: &[String],
-    use_thousands: &bool,
-    _number_regex: &str,
-) -> String {
-    let mut value = value.to_string();
+fn format_decimal(tokens: &[Token], value: f64) -> String {
+    let point = get_point(tokens);
+    let (_, scale, _) = analyze_commas(tokens);
+    let digits = Digits::new(value).shift(3 * scale as i32 - 2 * count_percent(tokens));
+    let (integer, fraction) = digits.round(get_placeholders(&tokens[point..]).len());
+    render_decimal(tokens, &integer, &fraction, value)
+}
 
-    let right = matches.get(3).unwrap();
+fn format_scientific(tokens: &[Token], index: usize, value: f64) -> String {
+    let mantissa = &tokens[..index];
+    let point = get_point(mantissa);
+    let integer_placeholders = get_placeholders(&mantissa[..point]);
+    let decimals = get_placeholders(&mantissa[point..]).len();
+    let digits = Digits::new(value).shift(-2 * count_percent(mantissa));
 
-    // minimun width of formatted number (including dot)
-    if *use_thousands {
-        value = value.parse::<f64>().unwrap().separate_with_commas();
-    }
-    let blocks: Vec<&str> = value.split('.').collect();
-    let left_value = blocks[0].to_string();
-    let mut right_value = match blocks.get(1) {
-        Some(v) => v.to_string(),
-        None => String::from("0"),
+    // ##0.0E+0 keeps the exponent a multiple of the integer digits
+    let width = integer_placeholders.len().max(1) as i32;
+    let engineering = width > 1 && integer_placeholders.contains(&Placeholder::Hash);
+    let step = if engineering { width } else { 1 };
+    let mut exponent = match digits.is_zero() {
+        true => 0,
+        false if engineering => digits.get_exponent().div_euclid(width) * width,
+        false => digits.get_exponent() - (width - 1),
     };
-    if right.is_empty() {
-        return left_value;
+    let (mut integer, mut fraction) = digits.shift(exponent).round(decimals);
+    if integer.len() as i32 > width {
+        exponent += step;
+        (integer, fraction) = digits.shift(exponent).round(decimals);
     }
-    if right.len() != right_value.len() {
-        if right_value == "0" {
-            right_value = right.to_string();
-        } else if right.len() > right_value.len() {
-            let pow = 10i32.pow(right.len() as u32);
-            right_value = format!("{}", right_value.parse::<i32>().unwrap() * pow);
-        } else {
-            let mut right_value_conv: String = right_value.chars().take(right.len()).collect();
-            let ajst_str: String = right_value.chars().skip(right.len()).take(1).collect();
-            let ajst_int = ajst_str.parse::<i32>().unwrap();
-            if ajst_int > 4 {
-                right_value_conv = (right_value_conv.parse::<i32>().unwrap() + 1).to_string();
-            }
-            right_value = right_value_conv;
+
+    let mut result = render_decimal(mantissa, &integer, &fraction, value);
+    result.push('E');
+    if exponent < 0 {
+        result.push('-');
+    } else if tokens[index] == Token::Exponent(true) {
+        result.push('+');
+    }
+    let exponent_tokens = &tokens[index + 1..];
+    let mut places = fill_right(
+        &get_placeholders(exponent_tokens),
+        &exponent.abs().to_string(),
+    )
+    .into_iter();
+    for token in exponent_tokens {
+        match token {
+            Token::Digit(_) => result.push_str(&places.next().unwrap()),
+            _ => push_literal(&mut result, token),
         }
     }
-    value = format!("{}.{}", left_value, right_value);
-    value
-
-    //    if use_thousands == &true {
-    //        value = value.parse::<f64>().unwrap().separate_with_commas();
-    //        dbg!(&value);
-    //        value = Regex::new(&number_regex).unwrap().replace_all(&format, value.as_str());
-    //        dbg!(&value);
-    //    } else {
-    //        if Regex::new(r#"[0#]E[+-]0"#).unwrap().find(&format).is_some() {
-    //            // Scientific format
-    //            value = value.parse::<f64>().unwrap().to_string();
-    //        } else if Regex::new(r#"0([^\d\.]+)0"#).unwrap().find(&format).is_some() || format.find(".").is_some() {
-    //            if value.parse::<f64>().unwrap() as usize as f64 == value.parse::<f64>().unwrap() && format.find(".").is_some() {
-    //                let format_collect:Vec<&str> = format.split('.').collect();
-    //                let pow = 10i32.pow(format_collect.get(1).unwrap().len() as u32);
-    //                value = format!("{}", value.parse::<i32>().unwrap() * pow);
-    //            }
-    //            value = complex_number_format_mask(&value.parse::<f64>().unwrap(), &format, &true);
-    //        } else {
-    //            value = format!("{:0width$.len$}", value, width = min_width, len = right.len());
-    //            value = Regex::new(&number_regex).unwrap().replace_all(&format, value.as_str());
-    //        }
-    //    }
-    //    value
+    result
 }
 
-fn merge_complex_number_format_masks(numbers: &[String], masks: &[String]) -> Vec<String> {
-    let mut decimal_count = numbers[1].len();
-    let mut post_decimal_masks: Vec<&str> = Vec::new();
+fn format_fraction(tokens: &[Token], slash: usize, value: f64) -> String {
+    let numerator_start = slash
+        - tokens[..slash]
+            .iter()
+            .rev()
+            .take_while(|v| matches!(v, Token::Digit(_)))
+            .count();
+    let numerator_placeholders = get_placeholders(&tokens[numerator_start..slash]);
+    let mixed = !get_placeholders(&tokens[..numerator_start]).is_empty();
 
-    for mask in masks.iter().rev() {
-        post_decimal_masks.push(mask);
-        decimal_count -= mask.to_string().len();
-        if decimal_count == 0 {
-            break;
+    // ?/8 has a fixed denominator, ?/?? up to two digits
+    let (fixed, denominator_placeholders, rest) = match tokens.get(slash + 1) {
+        Some(Token::Literal(v)) => {
+            let digits: String = v.chars().take_while(char::is_ascii_digit).collect();
+            let literal = Token::Literal(v[digits.len()..].to_string());
+            let rest = std::iter::once(literal)
+                .chain(tokens[slash + 2..].iter().cloned())
+                .collect();
+            (digits.parse::<u64>().ok(), Vec::new(), rest)
         }
-    }
-
-    post_decimal_masks.reverse();
-    vec![masks.join("."), post_decimal_masks.join(".")]
-}
+        _ => {
+            let count = tokens[slash + 1..]
+                .iter()
+                .take_while(|v| matches!(v, Token::Digit(_)))
+                .count();
+            let placeholders = get_placeholders(&tokens[slash + 1..slash + 1 + count]);
+            (None, placeholders, tokens[slash + 1 + count..].to_vec())
+        }
+    };
 
-fn process_complex_number_format_mask(number: &f64, mask: &str) -> String {
-    let mut result = number.to_string();
-    let re = Regex::new(r#"0+"#).unwrap();
-    let mut masking_blocks: Vec<(&str, usize)> = Vec::new();
-    let mut masking_str: Vec<&str> = Vec::new();
-    let mut masking_beg: Vec<usize> = Vec::new();
-    for ite in re.captures(mask).ok().flatten().unwrap().iter() {
-        masking_str.push(ite.unwrap().as_str());
+    let mut whole = if mixed { value.trunc() } else { 0f64 };
+    let part = value - whole;
+    let (mut numerator, denominator) = match fixed {
+        Some(v) if v > 0 => ((part * v as f64).round() as u64, v),
+        _ => {
+            let limit = 10u64.pow(denominator_placeholders.len().max(1) as u32) - 1;
+            approximate(part, limit)
+        }
+    };
+    if mixed && numerator == denominator {
+        whole += 1f64;
+        numerator = 0;
     }
-    for pos in re.captures(mask).ok().flatten().unwrap().iter() {
-        let beg = pos.unwrap().start();
-        masking_beg.push(beg);
+
+    let (mut integer, _) = Digits::new(whole).round(0);
+    if integer.is_empty() && numerator == 0 {
+        integer = "0".to_string();
     }
-    for i in 0..masking_str.len() {
-        masking_blocks.push((masking_str.get(i).unwrap(), *masking_beg.get(i).unwrap()));
+    let mut result = render_decimal(&tokens[..numerator_start], &integer, "", value);
+
+    let numerator_text: String =
+        fill_right(&numerator_placeholders, &numerator.to_string()).concat();
+    let denominator_text = match fixed {
+        Some(v) => v.to_string(),
+        None => {
+            let digits: Vec<char> = denominator.to_string().chars().collect();
+            denominator_placeholders
+                .iter()
+                .enumerate()
+                .map(|(i, v)| match digits.get(i) {
+                    Some(_) if i + 1 == denominator_placeholders.len() => {
+                        digits[i..].iter().collect()
+                    }
+                    Some(c) => c.to_string(),
+                    None => get_filler(v).to_string(),
+                })
+                .collect()
+        }
+    };
+    if mixed && numerator == 0 {
+        let width = numerator_text.chars().count() + 1 + denominator_text.chars().count();
+        result.push_str(&" ".repeat(width));
+    } else {
+        result.push_str(&numerator_text);
+        result.push('/');
+        result.push_str(&denominator_text);
     }
-    let mut mask = mask.to_string();
+    rest.iter().for_each(|v| push_literal(&mut result, v));
+    result
+}
 
-    if masking_blocks.len() > 1 {
-        let mut number = *number;
-        let mut offset: usize = 0;
-        for (block, pos) in masking_blocks.iter().rev() {
-            let divisor = format!("{}{}", 1, block).parse::<f64>().unwrap();
-            let size = block.len();
-            offset = *pos;
+// the closest fraction with a denominator up to `limit`, the smallest denominator on ties
+fn approximate(value: f64, limit: u64) -> (u64, u64) {
+    let mut best = (value.round() as u64, 1);
+    let mut best_error = (value - value.round()).abs();
+    for denominator in 2..=limit {
+        if best_error == 0f64 {
+            break;
+        }
+        let numerator = (value * denominator as f64).round();
+        let error = (value - numerator / denominator as f64).abs();
+        if error < best_error {
+            best = (numerator as u64, denominator);
+            best_error = error;
+        }
+    }
+    best
+}
 
-            let block_value = format!("{:0width$}", (number % divisor), width = size);
+// Renders digit placeholders, separators and literals.
+// `integer` has no leading zeros and `fraction` one digit per placeholder after the point.
+fn render_decimal(tokens: &[Token], integer: &str, fraction: &str, value: f64) -> String {
+    let point = get_point(tokens);
+    let (grouping, _, hidden) = analyze_commas(tokens);
+    let integer_placeholders = get_placeholders(&tokens[..point]);
+    let has_integer_places = !integer_placeholders.is_empty();
+    let integer_places = fill_right(&integer_placeholders, integer);
+    let fraction_places = fill_left(&get_placeholders(&tokens[point..]), fraction);
 
-            number /= divisor;
-            let from: String = mask.chars().skip(offset).take(size).collect();
-            mask = mask.replace(&from, &block_value);
-        }
-        if number > 0f64 {
-            let from: String = mask.chars().skip(offset).collect();
-            mask = mask.replace(&from, &number.to_string());
+    // digits left to place, to put the thousands separators
+    let mut remaining: usize = integer_places.iter().map(|v| v.chars().count()).sum();
+    let mut integer_places = integer_places.into_iter();
+    let mut fraction_places = fraction_places.into_iter();
+    let mut result = String::new();
+    for (i, token) in tokens.iter().enumerate() {
+        match token {
+            Token::Digit(_) if i < point => {
+                for c in integer_places.next().unwrap().chars() {
+                    result.push(c);
+                    remaining -= 1;
+                    if grouping && c.is_ascii_digit() && remaining > 0 && remaining % 3 == 0 {
+                        result.push(',');
+                    }
+                }
+            }
+            Token::Digit(_) => result.push_str(&fraction_places.next().unwrap()),
+            Token::Point if i == point => {
+                // .00 still shows the integer part
+                if !has_integer_places {
+                    result.push_str(integer);
+                }
+                result.push('.');
+            }
+            Token::Comma if hidden[i] => {}
+            Token::General => result.push_str(&format_as_general(value)),
+            _ => push_literal(&mut result, token),
         }
-        result = mask;
     }
     result
 }
 
-fn complex_number_format_mask(number: &f64, mask: &str, split_on_point: &bool) -> String {
-    let sign = number < &0.0;
-    let number = number.abs();
+fn push_literal(result: &mut String, token: &Token) {
+    match token {
+        Token::Literal(v) => result.push_str(v),
+        Token::Fill(v) => result.push(*v),
+        Token::Point => result.push('.'),
+        Token::Comma => result.push(','),
+        Token::Percent => result.push('%'),
+        Token::Slash => result.push('/'),
+        _ => {}
+    }
+}
 
-    if *split_on_point && mask.contains('.') && number.to_string().contains('.') {
-        let number_str = number.to_string();
-        let numbers_as: Vec<&str> = number_str.split('.').collect();
-        let mut numbers: Vec<String> = Vec::new();
-        for n in numbers_as {
-            numbers.push(n.to_string());
+// Commas between digit placeholders group thousands,
+// commas right after the last placeholder divide by 1000 each.
+fn analyze_commas(tokens: &[Token]) -> (bool, usize, Vec<bool>) {
+    let mut grouping = false;
+    let mut scale = 0;
+    let mut hidden = vec![false; tokens.len()];
+    for (i, token) in tokens.iter().enumerate() {
+        if token != &Token::Comma {
+            continue;
         }
-        let masks_as: Vec<&str> = mask.split('.').collect();
-        let mut masks: Vec<String> = Vec::new();
-        for mask in masks_as {
-            masks.push(mask.to_string());
+        let after_digit = matches!(
+            tokens[..i].iter().rev().find(|v| v != &&Token::Comma),
+            Some(Token::Digit(_))
+        );
+        if !after_digit {
+            continue;
         }
-        if masks.len() > 2 {
-            masks = merge_complex_number_format_masks(&numbers, &masks);
+        match tokens.get(i + 1) {
+            Some(Token::Digit(_)) => grouping = true,
+            Some(Token::Comma) => {}
+            _ => {
+                let count = tokens[..=i]
+                    .iter()
+                    .rev()
+                    .take_while(|v| v == &&Token::Comma)
+                    .count();
+                let followed = tokens[i + 1..]
+                    .iter()
+                    .any(|v| matches!(v, Token::Digit(_) | Token::Point));
+                if followed {
+                    continue;
+                }
+                scale += count;
+                (i + 1 - count..=i).for_each(|v| hidden[v] = true);
+                continue;
+            }
         }
-        let result1 =
-            complex_number_format_mask(&numbers[0].parse::<f64>().unwrap(), &masks[0], &false);
-        let result2 = complex_number_format_mask(
-            &numbers[1]
-                .chars()
-                .rev()
-                .collect::<String>()
-                .parse::<f64>()
-                .unwrap(),
-            &masks[1].chars().rev().collect::<String>(),
-            &false,
-        )
-        .chars()
-        .rev()
-        .collect::<String>();
+        hidden[i] = true;
+    }
+    (grouping, scale, hidden)
+}
 
-        return format!("{}{}.{}", if sign { "-" } else { "" }, result1, result2);
+// placeholders right-aligned on the digits, the first one takes any extra digits
+fn fill_right(placeholders: &[Placeholder], digits: &str) -> Vec<String> {
+    let digits: Vec<char> = digits.chars().collect();
+    let count = placeholders.len();
+    placeholders
+        .iter()
+        .enumerate()
+        .map(|(i, v)| {
+            let position = count - 1 - i;
+            if i == 0 && digits.len() > count {
+                digits[..digits.len() - count + 1].iter().collect()
+            } else if position < digits.len() {
+                digits[digits.len() - 1 - position].to_string()
+            } else {
+                get_filler(v).to_string()
+            }
+        })
+        .collect()
+}
+
+// placeholders left-aligned on the digits, trailing zeros are insignificant
+fn fill_left(placeholders: &[Placeholder], digits: &str) -> Vec<String> {
+    let significant = digits.trim_end_matches('0').len();
+    placeholders
+        .iter()
+        .zip(digits.chars())
+        .enumerate()
+        .map(|(i, (v, c))| {
+            if i < significant {
+                c.to_string()
+            } else {
+                get_filler(v).to_string()
+            }
+        })
+        .collect()
+}
+
+#[inline]
+fn get_filler(placeholder: &Placeholder) -> &'static str {
+    match placeholder {
+        Placeholder::Zero => "0",
+        Placeholder::Hash => "",
+        Placeholder::Question => " ",
     }
+}
+
+#[inline]
+fn get_placeholders(tokens: &[Token]) -> Vec<Placeholder> {
+    tokens
+        .iter()
+        .filter_map(|v| match v {
+            Token::Digit(p) => Some(*p),
+            _ => None,
+        })
+        .collect()
+}
+
+#[inline]
+fn get_point(tokens: &[Token]) -> usize {
+    tokens
+        .iter()
+        .position(|v| v == &Token::Point)
+        .unwrap_or(tokens.len())
+}
 
-    let result = process_complex_number_format_mask(&number, mask);
-    format!("{}{}", if sign { "-" } else { "" }, result)
+#[inline]
+fn count_percent(tokens: &[Token]) -> i32 {
+    tokens.iter().filter(|v| v == &&Token::Percent).count() as i32
 }
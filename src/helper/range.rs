@@ -1,4 +1,5 @@
 use crate::helper::coordinate::*;
+use fancy_regex::Regex;
 
 use super::coordinate;
 
@@ -50,7 +51,7 @@ pub fn get_start_and_end_point(range_str: &str) -> (u32, u32, u32, u32) {
                 col_end = v;
             }
             None => {
-                assert!(is_col_select, "Non-standard range.");
+                assert!(!is_col_select, "Non-standard range.");
             }
         };
 
@@ -59,7 +60,7 @@ pub fn get_start_and_end_point(range_str: &str) -> (u32, u32, u32, u32) {
                 row_end = v;
             }
             None => {
-                assert!(is_row_select, "Non-standard range.");
+                assert!(!is_row_select, "Non-standard range.");
             }
         }
     }
@@ -67,6 +68,41 @@ pub fn get_start_and_end_point(range_str: &str) -> (u32, u32, u32, u32) {
     (row_start, row_end, col_start, col_end)
 }
 
+/// Whether the string is a cell (`A1`), a cell range (`A1:C5`),
+/// a column range (`B:D`) or a row range (`3:7`) whose start is not after its end.
+pub(crate) fn is_valid_range(range_str: &str) -> bool {
+    lazy_static! {
+        static ref RE: Regex = Regex::new(r"^(\$?[A-Z]{1,3})?(\$?[0-9]+)?$").unwrap();
+    }
+
+    let upper_rng_str = range_str.to_uppercase();
+    let coordinate_collection: Vec<&str> = upper_rng_str.split(':').collect();
+    if !matches!(coordinate_collection.len(), 1 | 2) {
+        return false;
+    }
+    let mut points = Vec::new();
+    for coordinate in coordinate_collection {
+        if coordinate.is_empty() || !RE.is_match(coordinate).unwrap_or(false) {
+            return false;
+        }
+        let (col, row, ..) = index_from_coordinate(coordinate);
+        if col.is_some_and(|v| v > 16384) || row.is_some_and(|v| v == 0 || v > 1048576) {
+            return false;
+        }
+        points.push((col, row));
+    }
+    match points[..] {
+        [_] => true,
+        [(col_start, row_start), (col_end, row_end)] => {
+            col_start.is_some() == col_end.is_some()
+                && row_start.is_some() == row_end.is_some()
+                && col_start <= col_end
+                && row_start <= row_end
+        }
+        _ => false,
+    }
+}
+
 #[inline]
 pub fn get_split_range(range: &str) -> Vec<&str> {
     range.split(':').collect()
@@ -76,3 +112,51 @@ pub fn get_split_range(range: &str) -> Vec<&str> {
 pub fn get_join_range(coordinate_list: &[String]) -> String {
     coordinate_list.join(":")
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn get_start_and_end_point_1() {
+        // (row_start, row_end, col_start, col_end)
+        assert_eq!(get_start_and_end_point("A1"), (1, 1, 1, 1));
+        assert_eq!(get_start_and_end_point("B2:D5"), (2, 5, 2, 4));
+        assert_eq!(get_start_and_end_point("$B$2:$D$5"), (2, 5, 2, 4));
+        assert_eq!(get_start_and_end_point("B:D"), (0, 0, 2, 4));
+        assert_eq!(get_start_and_end_point("3:7"), (3, 7, 0, 0));
+    }
+
+    #[test]
+    fn is_valid_range_1() {
+        for range in ["A1", "a1:c5", "$A$1:$C$5", "B:D", "3:7", "XFD1048576"] {
+            assert!(is_valid_range(range), "{}", range);
+        }
+        for range in [
+            "",
+            "A1:B2:C3",
+            "B:7",
+            "3:D",
+            "C5:A1",
+            "A0",
+            "XFE1",
+            "A1:",
+            "#",
+            "Sheet1!A1",
+        ] {
+            assert!(!is_valid_range(range), "{}", range);
+        }
+    }
+
+    #[test]
+    #[should_panic(expected = "Non-standard range.")]
+    fn get_start_and_end_point_column_to_row() {
+        get_start_and_end_point("B:7");
+    }
+
+    #[test]
+    #[should_panic(expected = "Non-standard range.")]
+    fn get_start_and_end_point_row_to_column() {
+        get_start_and_end_point("3:D");
+    }
+}
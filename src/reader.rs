@@ -1,4 +1,5 @@
 //! file reader library.
 
+pub mod csv;
 pub(crate) mod driver;
 pub mod xlsx;
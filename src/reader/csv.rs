@@ -0,0 +1,270 @@
+use std::fs::File;
+use std::io;
+use std::path::Path;
+
+use chrono::{Datelike, NaiveDate, NaiveDateTime, Timelike};
+
+use crate::helper::date::convert_date_crate;
+use crate::structs::CsvEncodeValues;
+use crate::structs::CsvReaderOption;
+use crate::structs::NumberingFormat;
+use crate::structs::Spreadsheet;
+use crate::structs::Worksheet;
+use crate::structs::XlsxError;
+
+const FORMAT_DATE_TIME_ISO: &str = "yyyy-mm-dd h:mm:ss";
+
+/// read csv from arbitrary reader into a new spreadsheet.
+/// # Arguments
+/// * `reader` - reader to read from.
+/// * `option` - options.
+/// # Return value
+/// * `Result` - OK is Spreadsheet. Err is error message.
+pub fn read_reader<R: io::Read>(
+    reader: R,
+    option: Option<&CsvReaderOption>,
+) -> Result<Spreadsheet, XlsxError> {
+    let def_option = CsvReaderOption::default();
+    let option = option.unwrap_or(&def_option);
+    let mut book = crate::new_file();
+    if !option.get_sheet_name().is_empty() {
+        book.get_sheet_mut(&0)
+            .unwrap()
+            .set_name(option.get_sheet_name());
+    }
+    read_reader_into(&mut book, reader, Some(option))?;
+    Ok(book)
+}
+
+/// read csv file into a new spreadsheet.
+/// # Arguments
+/// * `path` - file path to read.
+/// * `option` - options.
+/// # Return value
+/// * `Result` - OK is Spreadsheet. Err is error message.
+/// # Examples
+/// ```
+/// use umya_spreadsheet::*;
+/// let path = std::path::Path::new("./tests/result_files/zzz_read.csv");
+/// std::fs::write(path, "name,price\r\napple,1.5\r\n").unwrap();
+/// let mut option = structs::CsvReaderOption::default();
+/// option.set_has_header(true);
+/// let book = reader::csv::read(path, Some(&option)).unwrap();
+/// ```
+#[inline]
+pub fn read<P: AsRef<Path>>(
+    path: P,
+    option: Option<&CsvReaderOption>,
+) -> Result<Spreadsheet, XlsxError> {
+    let file = File::open(path)?;
+    read_reader(io::BufReader::new(file), option)
+}
+
+/// import csv from arbitrary reader into an existing spreadsheet.
+/// The target sheet is created when it does not exist yet.
+/// # Arguments
+/// * `spreadsheet` - Spreadsheet structs object.
+/// * `reader` - reader to read from.
+/// * `option` - options.
+/// # Return value
+/// * `Result` - OK is void. Err is error message.
+pub fn read_reader_into<R: io::Read>(
+    spreadsheet: &mut Spreadsheet,
+    mut reader: R,
+    option: Option<&CsvReaderOption>,
+) -> Result<(), XlsxError> {
+    let def_option = CsvReaderOption::default();
+    let option = option.unwrap_or(&def_option);
+
+    let mut bytes = Vec::new();
+    reader.read_to_end(&mut bytes)?;
+    let data = decode(&bytes, option.get_csv_encode_value());
+    let records = parse(&data, *option.get_delimiter(), *option.get_quote_char());
+
+    let sheet_name = option.get_sheet_name();
+    let worksheet = if sheet_name.is_empty() {
+        spreadsheet.get_active_sheet_mut()
+    } else {
+        match spreadsheet.get_sheet_by_name_mut(sheet_name) {
+            Some(v) => v,
+            None => spreadsheet
+                .new_sheet(sheet_name)
+                .map_err(|_| XlsxError::DuplicateSheetName(sheet_name.to_string()))?,
+        }
+    };
+
+    let (start_column, start_row) = option.get_start_cell();
+    for (row_index, record) in records.iter().enumerate() {
+        let is_header = row_index == 0 && *option.get_has_header();
+        for (col_index, field) in record.iter().enumerate() {
+            let value = if *option.get_do_trim() {
+                field.trim()
+            } else {
+                field.as_str()
+            };
+            if value.is_empty() {
+                continue;
+            }
+            let coordinate = (
+                start_column + col_index as u32,
+                start_row + row_index as u32,
+            );
+            if is_header || !*option.get_infer_types() {
+                worksheet.get_cell_mut(coordinate).set_value_string(value);
+            } else {
+                set_typed_value(worksheet, coordinate, value);
+            }
+        }
+    }
+    Ok(())
+}
+
+/// import csv file into an existing spreadsheet.
+/// # Arguments
+/// * `spreadsheet` - Spreadsheet structs object.
+/// * `path` - file path to read.
+/// * `option` - options.
+/// # Return value
+/// * `Result` - OK is void. Err is error message.
+#[inline]
+pub fn read_into<P: AsRef<Path>>(
+    spreadsheet: &mut Spreadsheet,
+    path: P,
+    option: Option<&CsvReaderOption>,
+) -> Result<(), XlsxError> {
+    let file = File::open(path)?;
+    read_reader_into(spreadsheet, io::BufReader::new(file), option)
+}
+
+fn decode(bytes: &[u8], encode: &CsvEncodeValues) -> String {
+    let encoding = match encode {
+        CsvEncodeValues::ShiftJis => encoding_rs::SHIFT_JIS,
+        CsvEncodeValues::Koi8u => encoding_rs::KOI8_U,
+        CsvEncodeValues::Koi8r => encoding_rs::KOI8_R,
+        CsvEncodeValues::Iso88598i => encoding_rs::ISO_8859_8_I,
+        CsvEncodeValues::Gbk => encoding_rs::GBK,
+        CsvEncodeValues::EucKr => encoding_rs::EUC_KR,
+        CsvEncodeValues::Big5 => encoding_rs::BIG5,
+        CsvEncodeValues::Utf16Le => encoding_rs::UTF_16LE,
+        CsvEncodeValues::Utf16Be => encoding_rs::UTF_16BE,
+        CsvEncodeValues::Utf8 => encoding_rs::UTF_8,
+    };
+    encoding.decode_with_bom_removal(bytes).0.into_owned()
+}
+
+/// Split RFC 4180 text into records.
+/// Quoted fields may contain the delimiter, line breaks and doubled quote chars.
+fn parse(data: &str, delimiter: char, quote_char: char) -> Vec<Vec<String>> {
+    let mut records = Vec::new();
+    let mut record = Vec::new();
+    let mut field = String::new();
+    let mut in_quotes = false;
+    let mut chars = data.chars().peekable();
+    while let Some(c) = chars.next() {
+        if in_quotes {
+            if c == quote_char {
+                if chars.peek() == Some(&quote_char) {
+                    field.push(quote_char);
+                    chars.next();
+                } else {
+                    in_quotes = false;
+                }
+            } else {
+                field.push(c);
+            }
+            continue;
+        }
+        match c {
+            c if c == quote_char => in_quotes = true,
+            c if c == delimiter => record.push(std::mem::take(&mut field)),
+            '\r' | '\n' => {
+                if c == '\r' && chars.peek() == Some(&'\n') {
+                    chars.next();
+                }
+                record.push(std::mem::take(&mut field));
+                records.push(std::mem::take(&mut record));
+            }
+            c => field.push(c),
+        }
+    }
+    if !field.is_empty() || !record.is_empty() {
+        record.push(field);
+        records.push(record);
+    }
+    records
+}
+
+fn set_typed_value(worksheet: &mut Worksheet, coordinate: (u32, u32), value: &str) {
+    let date1904 = worksheet.get_date1904();
+    let cell = worksheet.get_cell_mut(coordinate);
+    match value.to_uppercase().as_str() {
+        "TRUE" => {
+            cell.set_value_bool(true);
+            return;
+        }
+        "FALSE" => {
+            cell.set_value_bool(false);
+            return;
+        }
+        _ => {}
+    }
+    if let Some(v) = parse_number(value) {
+        cell.set_value_number(v);
+        return;
+    }
+    if let Some((serial, format)) = parse_iso_date(value, date1904) {
+        cell.set_value_number(serial);
+        cell.get_style_mut()
+            .get_number_format_mut()
+            .set_format_code(format);
+        return;
+    }
+    cell.set_value_string(value);
+}
+
+fn parse_number(value: &str) -> Option<f64> {
+    let value = value.trim();
+    // "inf", "NaN" and the like are text; leading zeros (zip codes, ids) are kept as text too
+    if !value
+        .chars()
+        .all(|c| c.is_ascii_digit() || matches!(c, '.' | '-' | '+' | 'e' | 'E'))
+    {
+        return None;
+    }
+    let digits = value.trim_start_matches(['-', '+']);
+    if digits.len() > 1 && digits.starts_with('0') && !digits.starts_with("0.") {
+        return None;
+    }
+    value.parse::<f64>().ok().filter(|v| v.is_finite())
+}
+
+fn parse_iso_date(value: &str, date1904: bool) -> Option<(f64, &'static str)> {
+    let value = value.trim();
+    for format in ["%Y-%m-%d %H:%M:%S", "%Y-%m-%dT%H:%M:%S", "%Y-%m-%d %H:%M"] {
+        if let Ok(v) = NaiveDateTime::parse_from_str(value, format) {
+            let serial = convert_date_crate(
+                v.year(),
+                v.month() as i32,
+                v.day() as i32,
+                v.hour() as i32,
+                v.minute() as i32,
+                v.second() as i32,
+                !date1904,
+            );
+            return Some((serial, FORMAT_DATE_TIME_ISO));
+        }
+    }
+    if let Ok(v) = NaiveDate::parse_from_str(value, "%Y-%m-%d") {
+        let serial = convert_date_crate(
+            v.year(),
+            v.month() as i32,
+            v.day() as i32,
+            0,
+            0,
+            0,
+            !date1904,
+        );
+        return Some((serial, NumberingFormat::FORMAT_DATE_YYYYMMDD));
+    }
+    None
+}
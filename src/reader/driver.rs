@@ -1,4 +1,6 @@
+use crate::XlsxError;
 use quick_xml::events::attributes::Attribute;
+use quick_xml::Reader;
 use std::path::{Component, Path, PathBuf};
 use std::string::FromUtf8Error;
 
@@ -9,7 +11,7 @@ macro_rules! xml_read_loop {
         loop {
             let ev = match $reader.read_event_into(&mut buf) {
                 Ok(v) => v,
-                Err(e) => panic!("Error at position {}: {e:?}", $reader.buffer_position()),
+                Err(e) => return Err($crate::reader::driver::xml_error(&$reader, e)),
             };
 
             match ev {
@@ -27,8 +29,8 @@ pub(crate) use crate::xml_read_loop;
 #[macro_export]
 macro_rules! set_string_from_xml {
     ($self:ident, $e:ident, $attr:ident, $xml_attr:expr) => {{
-        if let Some(v) = get_attribute($e, $xml_attr.as_bytes()) {
-            $self.$attr.set_value_string(v);
+        if let Some(v) = get_attribute($e, $xml_attr.as_bytes())? {
+            $self.$attr.try_set_value_string(v, $xml_attr)?;
         }
     }};
 }
@@ -77,19 +79,69 @@ pub(crate) fn normalize_path_to_str(path: &str) -> String {
     ret.to_str().unwrap_or("").replace('\\', "/")
 }
 
-#[inline]
-pub(crate) fn get_attribute(e: &quick_xml::events::BytesStart<'_>, key: &[u8]) -> Option<String> {
-    e.attributes()
-        .with_checks(false)
-        .find_map(|attr| match attr {
+pub(crate) fn get_attribute(
+    e: &quick_xml::events::BytesStart<'_>,
+    key: &[u8],
+) -> Result<Option<String>, XlsxError> {
+    for attr in e.attributes().with_checks(false) {
+        match attr {
             Ok(ref attr) if attr.key.into_inner() == key => {
-                Some(get_attribute_value(attr).unwrap())
+                return get_attribute_value(attr).map(Some).map_err(|_| {
+                    XlsxError::InvalidAttribute {
+                        name: String::from_utf8_lossy(key).into_owned(),
+                        value: String::from_utf8_lossy(&attr.value).into_owned(),
+                    }
+                });
             }
-            _ => None,
-        })
+            _ => (),
+        }
+    }
+    Ok(None)
+}
+
+/// Get an attribute that the schema requires.
+#[inline]
+pub(crate) fn get_required_attribute(
+    e: &quick_xml::events::BytesStart<'_>,
+    key: &[u8],
+) -> Result<String, XlsxError> {
+    get_attribute(e, key)?
+        .ok_or_else(|| XlsxError::MissingAttribute(String::from_utf8_lossy(key).into_owned()))
 }
 
 #[inline]
 pub(crate) fn get_attribute_value(attr: &Attribute) -> Result<String, FromUtf8Error> {
     String::from_utf8(attr.value.to_vec())
 }
+
+#[inline]
+pub(crate) fn xml_error<R>(reader: &Reader<R>, e: quick_xml::Error) -> XlsxError {
+    XlsxError::XmlAt {
+        position: reader.buffer_position(),
+        source: e,
+    }
+}
+
+#[inline]
+pub(crate) fn end_element_error<R>(reader: &Reader<R>, element: &str) -> XlsxError {
+    XlsxError::MissingEndElement {
+        element: element.to_string(),
+        position: reader.buffer_position(),
+    }
+}
+
+#[inline]
+pub(crate) fn invalid_attribute_error<S: Into<String>>(name: &str, value: S) -> XlsxError {
+    XlsxError::InvalidAttribute {
+        name: name.to_string(),
+        value: value.into(),
+    }
+}
+
+/// Parse the value of an attribute or element.
+#[inline]
+pub(crate) fn parse_value<T: std::str::FromStr>(name: &str, value: &str) -> Result<T, XlsxError> {
+    value
+        .parse::<T>()
+        .map_err(|_| invalid_attribute_error(name, value))
+}
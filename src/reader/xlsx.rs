@@ -8,6 +8,7 @@ use std::sync::RwLock;
 
 use super::driver;
 use crate::helper::const_str::*;
+use crate::helper::crypt::decrypt;
 use crate::structs::drawing::Theme;
 use crate::structs::raw::RawWorksheet;
 use crate::structs::SharedStringTable;
@@ -17,6 +18,7 @@ use crate::structs::Worksheet;
 use crate::XlsxError;
 
 pub(crate) mod chart;
+pub(crate) mod chart_ex;
 pub(crate) mod comment;
 mod content_types;
 mod doc_props_app;
@@ -25,6 +27,7 @@ mod doc_props_custom;
 pub(crate) mod drawing;
 mod pivot_table;
 mod rels;
+mod row_iterator;
 mod shared_strings;
 mod styles;
 pub(crate) mod table;
@@ -35,6 +38,8 @@ mod workbook;
 mod workbook_rels;
 pub(crate) mod worksheet;
 
+pub use self::row_iterator::*;
+
 /// read spreadsheet from arbitrary reader.
 /// # Arguments
 /// * `reader` - reader to read from.
@@ -46,24 +51,26 @@ pub fn read_reader<R: io::Read + io::Seek>(
 ) -> Result<Spreadsheet, XlsxError> {
     let mut arv = zip::read::ZipArchive::new(reader)?;
 
-    let mut book = workbook::read(&mut arv)?;
-    doc_props_app::read(&mut arv, &mut book)?;
-    doc_props_core::read(&mut arv, &mut book)?;
-    doc_props_custom::read(&mut arv, &mut book)?;
-    vba_project_bin::read(&mut arv, &mut book)?;
-    content_types::read(&mut arv, &mut book)?;
-    let workbook_rel = workbook_rels::read(&mut arv, &mut book)?;
+    let mut book = workbook::read(&mut arv).map_err(|e| e.in_part(PKG_WORKBOOK))?;
+    doc_props_app::read(&mut arv, &mut book).map_err(|e| e.in_part(ARC_APP))?;
+    doc_props_core::read(&mut arv, &mut book).map_err(|e| e.in_part(ARC_CORE))?;
+    doc_props_custom::read(&mut arv, &mut book).map_err(|e| e.in_part(ARC_CUSTOM))?;
+    vba_project_bin::read(&mut arv, &mut book).map_err(|e| e.in_part(PKG_VBA_PROJECT))?;
+    content_types::read(&mut arv, &mut book).map_err(|e| e.in_part(CONTENT_TYPES))?;
+    let workbook_rel =
+        workbook_rels::read(&mut arv, &mut book).map_err(|e| e.in_part(PKG_WORKBOOK_RELS))?;
 
     book.set_theme(Theme::get_default_value());
     for (_, type_value, rel_target) in &workbook_rel {
         if type_value == THEME_NS {
-            let theme = theme::read(&mut arv, rel_target)?;
+            let theme = theme::read(&mut arv, rel_target)
+                .map_err(|e| e.in_part(format!("xl/{}", rel_target)))?;
             book.set_theme(theme);
         }
     }
 
-    shared_strings::read(&mut arv, &mut book)?;
-    styles::read(&mut arv, &mut book)?;
+    shared_strings::read(&mut arv, &mut book).map_err(|e| e.in_part(PKG_SHARED_STRINGS))?;
+    styles::read(&mut arv, &mut book).map_err(|e| e.in_part(PKG_STYLES))?;
 
     for sheet in book.get_sheet_collection_mut() {
         for (rel_id, _, rel_target) in &workbook_rel {
@@ -71,13 +78,15 @@ pub fn read_reader<R: io::Read + io::Seek>(
                 continue;
             }
             let mut raw_worksheet = RawWorksheet::default();
-            raw_worksheet.read(&mut arv, rel_target);
+            raw_worksheet
+                .read(&mut arv, rel_target)
+                .map_err(|e| e.in_part(driver::join_paths("xl", rel_target)))?;
             sheet.set_raw_data_of_worksheet(raw_worksheet);
         }
     }
 
     if with_sheet_read {
-        book.read_sheet_collection();
+        book.read_sheet_collection()?;
     }
 
     Ok(book)
@@ -117,13 +126,53 @@ pub fn lazy_read(path: &Path) -> Result<Spreadsheet, XlsxError> {
     read_reader(file, false)
 }
 
+/// read password-protected spreadsheet from arbitrary reader.
+/// # Arguments
+/// * `reader` - reader to read from.
+/// * `password` - password.
+/// * `with_sheet_read` - if false, the worksheets are loaded lazily.
+/// # Return value
+/// * `Result` - OK is Spreadsheet. Err is error message.
+/// `XlsxError::WrongPassword` is returned when the password does not match,
+/// `XlsxError::CorruptedPackage` when the encrypted data is damaged.
+pub fn read_reader_with_password<R: io::Read + io::Seek>(
+    reader: R,
+    password: &str,
+    with_sheet_read: bool,
+) -> Result<Spreadsheet, XlsxError> {
+    let data = decrypt(reader, password)?;
+    read_reader(io::Cursor::new(data), with_sheet_read)
+}
+
+/// read password-protected spreadsheet file.
+/// # Arguments
+/// * `path` - file path to read.
+/// * `password` - password.
+/// # Return value
+/// * `Result` - OK is Spreadsheet. Err is error message.
+/// # Examples
+/// ```
+/// let mut book = umya_spreadsheet::new_file();
+/// let path = std::path::Path::new("./tests/result_files/zzz_read_password.xlsx");
+/// let _ = umya_spreadsheet::writer::xlsx::write_with_password(&book, path, "password");
+/// let mut book = umya_spreadsheet::reader::xlsx::read_with_password(path, "password").unwrap();
+/// ```
+#[inline]
+pub fn read_with_password<P: AsRef<Path>>(
+    path: P,
+    password: &str,
+) -> Result<Spreadsheet, XlsxError> {
+    let file = File::open(path)?;
+    read_reader_with_password(file, password, true)
+}
+
 pub(crate) fn raw_to_deserialize_by_worksheet(
     worksheet: &mut Worksheet,
     shared_string_table: &RwLock<SharedStringTable>,
     stylesheet: &Stylesheet,
-) {
+) -> Result<(), XlsxError> {
     if worksheet.is_deserialized() {
-        return;
+        return Ok(());
     }
 
     let raw_data_of_worksheet = worksheet.get_raw_data_of_worksheet().clone();
@@ -134,27 +183,36 @@ pub(crate) fn raw_to_deserialize_by_worksheet(
         shared_string_table,
         stylesheet,
     )
-    .unwrap();
+    .map_err(|e| e.in_part(raw_data_of_worksheet.get_worksheet_file().get_file_target()))?;
 
     if let Some(v) = raw_data_of_worksheet.get_worksheet_relationships() {
         for relationship in v.get_relationship_list() {
+            let raw_file = relationship.get_raw_file();
             match relationship.get_type() {
                 // drawing, chart
                 DRAWINGS_NS => {
                     drawing::read(
                         worksheet,
-                        relationship.get_raw_file(),
+                        raw_file,
                         raw_data_of_worksheet.get_drawing_relationships(),
                     )
-                    .unwrap();
+                    .map_err(|e| e.in_part(raw_file.get_file_target()))?;
+                    chart_ex::read_related_parts(worksheet, &raw_data_of_worksheet);
                 }
                 // comment
                 COMMENTS_NS => {
-                    comment::read(worksheet, relationship.get_raw_file()).unwrap();
+                    comment::read(worksheet, raw_file)
+                        .map_err(|e| e.in_part(raw_file.get_file_target()))?;
                 }
                 // table
                 TABLE_NS => {
-                    table::read(worksheet, relationship.get_raw_file()).unwrap();
+                    table::read(worksheet, raw_file)
+                        .map_err(|e| e.in_part(raw_file.get_file_target()))?;
+                }
+                // pivot table
+                PIVOT_TABLE_NS => {
+                    pivot_table::read(worksheet, raw_file, &raw_data_of_worksheet)
+                        .map_err(|e| e.in_part(raw_file.get_file_target()))?;
                 }
                 _ => {}
             }
@@ -162,15 +220,17 @@ pub(crate) fn raw_to_deserialize_by_worksheet(
         for relationship in v.get_relationship_list() {
             // vmlDrawing
             if relationship.get_type() == VML_DRAWING_NS {
+                let raw_file = relationship.get_raw_file();
                 vml_drawing::read(
                     worksheet,
-                    relationship.get_raw_file(),
-                    raw_data_of_worksheet.get_vml_drawing_relationships(),
+                    raw_file,
+                    raw_data_of_worksheet.get_relationships_of(raw_file),
                 )
-                .unwrap();
+                .map_err(|e| e.in_part(raw_file.get_file_target()))?;
             }
         }
     }
 
     worksheet.remove_raw_data_of_worksheet();
+    Ok(())
 }
@@ -0,0 +1,58 @@
+use super::XlsxError;
+use crate::helper::const_str::*;
+use crate::structs::drawing::chart_ex::ChartSpace;
+use crate::structs::raw::RawFile;
+use crate::structs::raw::RawWorksheet;
+use crate::structs::Worksheet;
+use crate::xml_read_loop;
+use quick_xml::events::Event;
+use quick_xml::Reader;
+use std::result;
+
+pub(crate) fn read(
+    raw_file: &RawFile,
+    chart_space: &mut ChartSpace,
+) -> result::Result<(), XlsxError> {
+    let data = std::io::Cursor::new(raw_file.get_file_data());
+    let mut reader = Reader::from_reader(data);
+
+    reader.config_mut().trim_text(true);
+
+    xml_read_loop!(
+        reader,
+        Event::Start(ref e) => {
+            if e.name().into_inner() == b"cx:chartSpace" {
+                chart_space.set_attributes(&mut reader, e)?;
+            }
+        },
+        Event::Eof => break,
+    );
+    chart_space.set_file_target(raw_file.get_file_target());
+
+    Ok(())
+}
+
+/// Keep the style and colors parts of the ChartEx charts of the worksheet.
+pub(crate) fn read_related_parts(worksheet: &mut Worksheet, raw_data_of_worksheet: &RawWorksheet) {
+    for chart in worksheet
+        .get_worksheet_drawing_mut()
+        .get_chart_ex_collection_mut()
+    {
+        let chart_space = chart.get_chart_space_mut();
+        let mut chart_file = RawFile::default();
+        chart_file.set_file_target(chart_space.get_file_target());
+        let relationships = match raw_data_of_worksheet.get_relationships_of(&chart_file) {
+            Some(v) => v,
+            None => continue,
+        };
+        for relationship in relationships.get_relationship_list() {
+            if matches!(
+                relationship.get_type(),
+                CHART_STYLE_NS | CHART_COLOR_STYLE_NS
+            ) {
+                chart_space
+                    .add_related_part(relationship.get_type(), relationship.get_raw_file().clone());
+            }
+        }
+    }
+}
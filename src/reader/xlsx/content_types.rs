@@ -19,8 +19,8 @@ pub(crate) fn read<R: io::Read + io::Seek>(
         reader,
         Event::Empty(ref e) => {
             if e.name().into_inner() == b"Override" {
-                let part_name = get_attribute(e, b"PartName").unwrap();
-                let content_type = get_attribute(e, b"ContentType").unwrap();
+                let part_name = get_required_attribute(e, b"PartName")?;
+                let content_type = get_required_attribute(e, b"ContentType")?;
                 list.push((part_name, content_type));
             }
         },
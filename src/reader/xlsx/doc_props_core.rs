@@ -1,3 +1,4 @@
+use super::driver::*;
 use super::XlsxError;
 use quick_xml::events::Event;
 use quick_xml::Reader;
@@ -28,11 +29,11 @@ pub(crate) fn read<R: io::Read + io::Seek>(
                 if e.name().into_inner() == b"cp:coreProperties" {
                     spreadsheet
                         .get_properties_mut()
-                        .set_attributes_core(&mut reader, e);
+                        .set_attributes_core(&mut reader, e)?;
                 }
             }
             Ok(Event::Eof) => break,
-            Err(e) => panic!("Error at position {}: {:?}", reader.buffer_position(), e),
+            Err(e) => return Err(xml_error(&reader, e)),
             _ => (),
         }
         buf.clear();
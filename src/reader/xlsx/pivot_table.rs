@@ -1,6 +1,10 @@
 use super::driver::*;
 use super::XlsxError;
+use crate::helper::const_str::*;
 use crate::structs::raw::RawFile;
+use crate::structs::raw::RawWorksheet;
+use crate::structs::PivotCacheDefinition;
+use crate::structs::PivotCacheRecords;
 use crate::structs::PivotTable;
 use crate::structs::PivotTableDefinition;
 use crate::structs::Worksheet;
@@ -11,34 +15,89 @@ use std::result;
 pub(crate) fn read(
     worksheet: &mut Worksheet,
     pivot_table_file: &RawFile,
+    raw_data_of_worksheet: &RawWorksheet,
 ) -> result::Result<(), XlsxError> {
-    let data = std::io::Cursor::new(pivot_table_file.get_file_data());
-    let mut reader = Reader::from_reader(data);
-    reader.config_mut().trim_text(false);
-    let mut buf = Vec::new();
     let mut pivot_table = PivotTable::default();
-    loop {
-        match reader.read_event_into(&mut buf) {
-            Ok(Event::Start(ref e)) => match e.name().into_inner() {
-                b"pivotTableDefinition" => {
-                    let mut obj = PivotTableDefinition::default();
-                    obj.set_attributes(&mut reader, e);
-                    pivot_table.set_pivot_table_definition(obj);
-                }
-                _ => (),
-            },
-            Ok(Event::End(ref e)) => match e.name().into_inner() {
-                b"pivotTableDefinition" => {
+    let mut reader = make_reader(pivot_table_file);
+    xml_read_loop!(
+        reader,
+        Event::Start(ref e) => {
+            if e.name().into_inner() == b"pivotTableDefinition" {
+                let mut obj = PivotTableDefinition::default();
+                obj.set_attributes(&mut reader, e)?;
+                pivot_table.set_pivot_table_definition(obj);
+                break;
+            }
+        },
+        Event::Eof => break,
+    );
+
+    // pivotCacheDefinition
+    let cache_definition_file =
+        match find_related_file(raw_data_of_worksheet, pivot_table_file, PIVOT_CACHE_DEF_NS) {
+            Some(v) => v,
+            None => {
+                worksheet.get_pivot_tables_mut().push(pivot_table);
+                return Ok(());
+            }
+        };
+    let mut reader = make_reader(cache_definition_file);
+    xml_read_loop!(
+        reader,
+        Event::Start(ref e) => {
+            if e.name().into_inner() == b"pivotCacheDefinition" {
+                let mut obj = PivotCacheDefinition::default();
+                obj.set_attributes(&mut reader, e)
+                    .map_err(|e| e.in_part(cache_definition_file.get_file_target()))?;
+                pivot_table.set_pivot_cache_definition(obj);
+                break;
+            }
+        },
+        Event::Eof => break,
+    );
+
+    // pivotCacheRecords
+    if let Some(records_file) = find_related_file(
+        raw_data_of_worksheet,
+        cache_definition_file,
+        PIVOT_CACHE_RECORDS_NS,
+    ) {
+        let mut reader = make_reader(records_file);
+        xml_read_loop!(
+            reader,
+            Event::Start(ref e) => {
+                if e.name().into_inner() == b"pivotCacheRecords" {
+                    let mut obj = PivotCacheRecords::default();
+                    obj.set_attributes(&mut reader, e)
+                        .map_err(|e| e.in_part(records_file.get_file_target()))?;
+                    pivot_table.set_pivot_cache_records(obj);
                     break;
                 }
-                _ => (),
             },
-            Ok(Event::Eof) => break,
-            Err(e) => panic!("Error at position {}: {:?}", reader.buffer_position(), e),
-            _ => (),
-        }
-        buf.clear();
+            Event::Eof => break,
+        );
     }
-    worksheet.add_pivot_table(pivot_table);
+
+    // the report is already in the cells.
+    worksheet.get_pivot_tables_mut().push(pivot_table);
     Ok(())
 }
+
+fn make_reader(file: &RawFile) -> Reader<std::io::Cursor<&[u8]>> {
+    let mut reader = Reader::from_reader(std::io::Cursor::new(file.get_file_data()));
+    reader.config_mut().trim_text(false);
+    reader
+}
+
+fn find_related_file<'a>(
+    raw_data_of_worksheet: &'a RawWorksheet,
+    file: &RawFile,
+    relationship_type: &str,
+) -> Option<&'a RawFile> {
+    raw_data_of_worksheet
+        .get_relationships_of(file)?
+        .get_relationship_list()
+        .iter()
+        .find(|v| v.get_type() == relationship_type)
+        .map(|v| v.get_raw_file())
+}
@@ -0,0 +1,380 @@
+use super::driver::*;
+use super::XlsxError;
+use super::{shared_strings, styles, workbook, workbook_rels};
+use crate::helper::const_str::*;
+use crate::helper::coordinate::index_from_coordinate;
+use crate::structs::CellValue;
+use crate::structs::NumberingFormat;
+use crate::structs::SharedStringTable;
+use quick_xml::events::{BytesStart, Event};
+use quick_xml::Reader;
+use std::fs::File;
+use std::io;
+use std::path::Path;
+use std::sync::{Arc, RwLock};
+use zip::read::ZipFile;
+use zip::ZipArchive;
+
+/// A cell yielded by `RowIter`.
+#[derive(Clone, Default, Debug, PartialEq)]
+pub struct SheetCell {
+    col_num: u32,
+    value: CellValue,
+    format_code: Box<str>,
+    date1904: bool,
+}
+impl SheetCell {
+    #[inline]
+    pub fn get_col_num(&self) -> &u32 {
+        &self.col_num
+    }
+
+    #[inline]
+    pub fn get_value(&self) -> &CellValue {
+        &self.value
+    }
+
+    #[inline]
+    pub fn into_value(self) -> CellValue {
+        self.value
+    }
+
+    /// Number format code of the cell style.
+    #[inline]
+    pub fn get_format_code(&self) -> &str {
+        &self.format_code
+    }
+
+    /// Value converted with the number format of the cell style,
+    /// in the date system of the workbook.
+    #[inline]
+    pub fn get_formatted_value(&self) -> String {
+        self.value
+            .get_formatted_value_crate(&self.format_code, self.date1904)
+    }
+}
+
+/// A row yielded by `RowIter`. Only the cells present in the file are included.
+#[derive(Clone, Default, Debug, PartialEq)]
+pub struct SheetRow {
+    row_num: u32,
+    cells: Vec<SheetCell>,
+}
+impl SheetRow {
+    #[inline]
+    pub fn get_row_num(&self) -> &u32 {
+        &self.row_num
+    }
+
+    #[inline]
+    pub fn get_cells(&self) -> &[SheetCell] {
+        &self.cells
+    }
+
+    #[inline]
+    pub fn get_cell(&self, col_num: u32) -> Option<&SheetCell> {
+        self.cells.iter().find(|v| v.col_num == col_num)
+    }
+
+    /// Values from column A to the last cell of the row. Missing cells are empty values.
+    pub fn to_values(&self) -> Vec<CellValue> {
+        let len = self.cells.last().map(|v| v.col_num as usize).unwrap_or(0);
+        let mut result = vec![CellValue::default(); len];
+        for cell in &self.cells {
+            result[cell.col_num as usize - 1] = cell.value.clone();
+        }
+        result
+    }
+}
+
+/// A worksheet opened to be read row by row.
+/// Created with `reader::xlsx::rows`.
+pub struct Rows<R: io::Read + io::Seek> {
+    arv: ZipArchive<R>,
+    index: usize,
+    shared_string_table: Arc<RwLock<SharedStringTable>>,
+    format_codes: Vec<Box<str>>,
+    date1904: bool,
+}
+impl<R: io::Read + io::Seek> Rows<R> {
+    /// Iterator over the rows, read directly from the xml stream of the worksheet part.
+    pub fn iter(&mut self) -> Result<RowIter<'_, R>, XlsxError> {
+        let file = self.arv.by_index(self.index)?;
+        let mut reader = Reader::from_reader(io::BufReader::new(file));
+        reader.config_mut().trim_text(false);
+        Ok(RowIter {
+            reader,
+            shared_string_table: &self.shared_string_table,
+            format_codes: &self.format_codes,
+            date1904: self.date1904,
+            last_row_num: 0,
+            finished: false,
+        })
+    }
+}
+
+/// Iterator over the rows of a worksheet. Created with `Rows::iter`.
+pub struct RowIter<'a, R: io::Read> {
+    reader: Reader<io::BufReader<ZipFile<'a, R>>>,
+    shared_string_table: &'a Arc<RwLock<SharedStringTable>>,
+    format_codes: &'a [Box<str>],
+    date1904: bool,
+    last_row_num: u32,
+    finished: bool,
+}
+impl<R: io::Read> RowIter<'_, R> {
+    fn read_row(&mut self) -> Result<Option<SheetRow>, XlsxError> {
+        let mut buf = Vec::new();
+        let mut row: Option<SheetRow> = None;
+        let mut cell: Option<RawCell> = None;
+        let mut last_col_num = 0;
+        let mut target = TextTarget::None;
+        let mut in_inline_string = false;
+        let mut in_phonetic = false;
+        loop {
+            buf.clear();
+            match self.reader.read_event_into(&mut buf)? {
+                Event::Start(ref e) => match e.name().into_inner() {
+                    b"row" => {
+                        let row_num = self.get_row_num(e)?;
+                        row = Some(SheetRow {
+                            row_num,
+                            cells: Vec::new(),
+                        });
+                        last_col_num = 0;
+                    }
+                    b"c" => {
+                        let raw_cell = RawCell::new(e, last_col_num)?;
+                        last_col_num = raw_cell.col_num;
+                        cell = Some(raw_cell);
+                    }
+                    b"v" => target = TextTarget::Value,
+                    b"f" => target = TextTarget::Formula,
+                    b"is" => in_inline_string = true,
+                    b"rPh" => in_phonetic = true,
+                    b"t" if in_inline_string && !in_phonetic => target = TextTarget::Value,
+                    _ => (),
+                },
+                Event::Empty(ref e) => match e.name().into_inner() {
+                    // rows and cells without content carry no values
+                    b"row" => {
+                        self.get_row_num(e)?;
+                    }
+                    b"c" => {
+                        last_col_num = RawCell::new(e, last_col_num)?.col_num;
+                    }
+                    _ => (),
+                },
+                Event::Text(e) => {
+                    if let Some(v) = cell.as_mut() {
+                        match target {
+                            TextTarget::Value => v.value.push_str(&e.unescape()?),
+                            TextTarget::Formula => v.formula.push_str(&e.unescape()?),
+                            TextTarget::None => (),
+                        }
+                    }
+                }
+                Event::End(ref e) => match e.name().into_inner() {
+                    b"v" | b"f" | b"t" => target = TextTarget::None,
+                    b"is" => in_inline_string = false,
+                    b"rPh" => in_phonetic = false,
+                    b"c" => {
+                        if let (Some(row), Some(cell)) = (row.as_mut(), cell.take()) {
+                            let cell = self.make_cell(cell)?;
+                            row.cells.push(cell);
+                        }
+                    }
+                    b"row" => return Ok(row),
+                    b"sheetData" => return Ok(None),
+                    _ => (),
+                },
+                Event::Eof => return Ok(None),
+                _ => (),
+            }
+        }
+    }
+
+    fn get_row_num(&mut self, e: &BytesStart) -> Result<u32, XlsxError> {
+        self.last_row_num = get_attribute(e, b"r")?
+            .and_then(|v| v.parse::<u32>().ok())
+            .unwrap_or(self.last_row_num + 1);
+        Ok(self.last_row_num)
+    }
+
+    fn make_cell(&self, cell: RawCell) -> Result<SheetCell, XlsxError> {
+        let mut value = CellValue::default();
+        match cell.data_type.as_str() {
+            "s" => {
+                let shared_string_table = self.shared_string_table.read().unwrap();
+                let item = cell
+                    .value
+                    .trim()
+                    .parse::<usize>()
+                    .ok()
+                    .and_then(|v| shared_string_table.get_shared_string_item().get(v))
+                    .ok_or_else(|| {
+                        io::Error::new(
+                            io::ErrorKind::InvalidData,
+                            format!("invalid shared string index: {}", cell.value),
+                        )
+                    })?;
+                value.set_shared_string_item(item.clone());
+            }
+            "str" | "inlineStr" => {
+                value.set_value_string_crate(cell.value);
+            }
+            "b" => {
+                value.set_value_bool_crate(cell.value.trim() == "1");
+            }
+            "e" => {
+                value.set_error(cell.value);
+            }
+            _ => {
+                value.set_value_crate(cell.value);
+            }
+        }
+        if !cell.formula.is_empty() {
+            value.set_formula(cell.formula);
+        }
+        let format_code = self
+            .format_codes
+            .get(cell.style_index)
+            .cloned()
+            .unwrap_or_else(|| NumberingFormat::FORMAT_GENERAL.into());
+        Ok(SheetCell {
+            col_num: cell.col_num,
+            value,
+            format_code,
+            date1904: self.date1904,
+        })
+    }
+}
+impl<R: io::Read> Iterator for RowIter<'_, R> {
+    type Item = Result<SheetRow, XlsxError>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.finished {
+            return None;
+        }
+        match self.read_row() {
+            Ok(Some(v)) => Some(Ok(v)),
+            Ok(None) => {
+                self.finished = true;
+                None
+            }
+            Err(e) => {
+                self.finished = true;
+                Some(Err(e))
+            }
+        }
+    }
+}
+
+enum TextTarget {
+    None,
+    Value,
+    Formula,
+}
+
+struct RawCell {
+    col_num: u32,
+    style_index: usize,
+    data_type: String,
+    value: String,
+    formula: String,
+}
+impl RawCell {
+    fn new(e: &BytesStart, last_col_num: u32) -> Result<Self, XlsxError> {
+        let col_num = get_attribute(e, b"r")?
+            .and_then(|v| index_from_coordinate(v).0)
+            .unwrap_or(last_col_num + 1);
+        let style_index = get_attribute(e, b"s")?
+            .and_then(|v| v.parse::<usize>().ok())
+            .unwrap_or(0);
+        Ok(Self {
+            col_num,
+            style_index,
+            data_type: get_attribute(e, b"t")?.unwrap_or_default(),
+            value: String::new(),
+            formula: String::new(),
+        })
+    }
+}
+
+/// iterate over the rows of a worksheet read from arbitrary reader.
+/// # Arguments
+/// * `reader` - reader to read from.
+/// * `sheet_name` - name of the worksheet.
+/// # Return value
+/// * `Result` - OK is Rows. Err is error message.
+pub fn rows_reader<R: io::Read + io::Seek>(
+    reader: R,
+    sheet_name: &str,
+) -> Result<Rows<R>, XlsxError> {
+    let mut arv = zip::read::ZipArchive::new(reader)?;
+
+    let mut book = workbook::read(&mut arv).map_err(|e| e.in_part(PKG_WORKBOOK))?;
+    let workbook_rel =
+        workbook_rels::read(&mut arv, &mut book).map_err(|e| e.in_part(PKG_WORKBOOK_RELS))?;
+    shared_strings::read(&mut arv, &mut book).map_err(|e| e.in_part(PKG_SHARED_STRINGS))?;
+    styles::read(&mut arv, &mut book).map_err(|e| e.in_part(PKG_STYLES))?;
+
+    let r_id = match book.get_sheet_by_name(sheet_name) {
+        Some(v) => v.get_r_id().to_string(),
+        None => return Err(XlsxError::SheetNotFound(sheet_name.to_string())),
+    };
+    let target = match workbook_rel.iter().find(|(id, _, _)| id == &r_id) {
+        Some((_, _, v)) => join_paths("xl", v),
+        None => return Err(XlsxError::SheetNotFound(sheet_name.to_string())),
+    };
+    let format_codes: Vec<Box<str>> = book
+        .get_stylesheet()
+        .get_maked_style_list()
+        .iter()
+        .map(|v| match v.get_number_format() {
+            Some(number_format) => number_format.get_format_code().into(),
+            None => NumberingFormat::FORMAT_GENERAL.into(),
+        })
+        .collect();
+    let shared_string_table = book.get_shared_string_table();
+
+    // the worksheet part is read from the archive while iterating, it is never held in memory
+    let index = arv
+        .index_for_name(&target)
+        .ok_or_else(|| XlsxError::MissingPart(target.clone()))?;
+
+    Ok(Rows {
+        arv,
+        index,
+        shared_string_table,
+        format_codes,
+        date1904: book.get_date1904(),
+    })
+}
+
+/// iterate over the rows of a worksheet.
+/// Cells are read from the xml stream one row at a time,
+/// so memory use does not grow with the size of the worksheet.
+/// Only the shared strings and styles of the workbook are loaded up front.
+/// # Arguments
+/// * `path` - file path to read.
+/// * `sheet_name` - name of the worksheet.
+/// # Return value
+/// * `Result` - OK is Rows. Err is error message.
+/// # Examples
+/// ```
+/// let path = std::path::Path::new("./tests/test_files/aaa.xlsx");
+/// let mut rows = umya_spreadsheet::reader::xlsx::rows(path, "Sheet1").unwrap();
+/// for row in rows.iter().unwrap() {
+///     let row = row.unwrap();
+///     for cell in row.get_cells() {
+///         let _ = cell.get_value().get_value();
+///         let _ = cell.get_formatted_value();
+///     }
+/// }
+/// ```
+#[inline]
+pub fn rows<P: AsRef<Path>>(path: P, sheet_name: &str) -> Result<Rows<File>, XlsxError> {
+    let file = File::open(path)?;
+    rows_reader(file, sheet_name)
+}
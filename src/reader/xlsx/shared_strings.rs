@@ -29,7 +29,7 @@ pub(crate) fn read<R: io::Read + io::Seek>(
         Event::Start(ref e) => {
             if e.name().into_inner() == b"sst" {
                 let mut obj = SharedStringTable::default();
-                obj.set_attributes(&mut reader, e);
+                obj.set_attributes(&mut reader, e)?;
                 spreadsheet.set_shared_string_table(obj);
             }
         },
@@ -37,7 +37,7 @@ pub(crate) fn read(
                                 }
                                 b"totalsRowFunction" => {
                                     let attr_val = get_attribute_value(attr)?;
-                                    table_column.set_totals_row_function_str(&attr_val);
+                                    table_column.set_totals_row_function_str(&attr_val)?;
                                 }
                                 _ => {}
                             },
@@ -94,7 +94,7 @@ pub(crate) fn read(
                 }
                 _ => (),
             },
-            Ok(Event::Text(e)) => string_value = e.unescape().unwrap().to_string(),
+            Ok(Event::Text(e)) => string_value = e.unescape()?.to_string(),
             Ok(Event::Start(ref e)) => match e.name().into_inner() {
                 b"table" => {
                     for a in e.attributes().with_checks(false) {
@@ -115,10 +115,10 @@ pub(crate) fn read(
                                         }
                                     }
                                     b"totalsRowShown" => {
-                                        table.set_totals_row_shown_str(&attr_val);
+                                        table.set_totals_row_shown_str(&attr_val)?;
                                     }
                                     b"totalsRowCount" => {
-                                        table.set_totals_row_count_str(&attr_val);
+                                        table.set_totals_row_count_str(&attr_val)?;
                                     }
                                     _ => {}
                                 }
@@ -142,7 +142,7 @@ pub(crate) fn read(
                                 }
                                 b"totalsRowFunction" => {
                                     let attr_val = get_attribute_value(attr)?;
-                                    table_column.set_totals_row_function_str(&attr_val);
+                                    table_column.set_totals_row_function_str(&attr_val)?;
                                 }
                                 _ => {}
                             },
@@ -167,7 +167,7 @@ pub(crate) fn read(
                 _ => (),
             },
             Ok(Event::Eof) => break,
-            Err(e) => panic!("Error at position {}: {:?}", reader.buffer_position(), e),
+            Err(e) => return Err(xml_error(&reader, e)),
             _ => (),
         }
         buf.clear();
@@ -1,7 +1,9 @@
 use super::XlsxError;
+use crate::reader::driver::get_attribute;
 use crate::structs::raw::RawFile;
 use crate::structs::raw::RawRelationships;
 use crate::structs::vml::Shape;
+use crate::structs::HeaderFooterImage;
 use crate::structs::Worksheet;
 use crate::xml_read_loop;
 use quick_xml::events::Event;
@@ -24,23 +26,30 @@ pub(crate) fn read(
         reader,
             Event::Start(ref e) => {
                 if e.name().into_inner() == b"v:shape" {
-                    let mut obj = Shape::default();
-                    obj.set_attributes(&mut reader, e, drawing_relationships);
-                    match obj.get_client_data().get_comment_column_target() {
-                        Some(_) => {
-                            worksheet
-                                .get_comments_mut()
-                                .get_mut(comment_index)
-                                .map(|comment| comment.set_shape(obj));
-                            comment_index += 1;
-                        }
-                        None => {
-                            worksheet
-                                .get_ole_objects_mut()
-                                .get_ole_object_mut()
-                                .get_mut(ole_index)
-                                .map(|ole_obj| ole_obj.set_shape(obj));
-                            ole_index += 1;
+                    if get_attribute(e, b"id")?.is_some_and(|v| HeaderFooterImage::is_position(&v)) {
+                        // legacyDrawingHF
+                        let mut obj = HeaderFooterImage::default();
+                        obj.set_attributes(&mut reader, e, drawing_relationships)?;
+                        worksheet.get_header_footer_mut().add_image(obj);
+                    } else {
+                        let mut obj = Shape::default();
+                        obj.set_attributes(&mut reader, e, drawing_relationships)?;
+                        match obj.get_client_data().get_comment_column_target() {
+                            Some(_) => {
+                                worksheet
+                                    .get_comments_mut()
+                                    .get_mut(comment_index)
+                                    .map(|comment| comment.set_shape(obj));
+                                comment_index += 1;
+                            }
+                            None => {
+                                worksheet
+                                    .get_ole_objects_mut()
+                                    .get_ole_object_mut()
+                                    .get_mut(ole_index)
+                                    .map(|ole_obj| ole_obj.set_shape(obj));
+                                ole_index += 1;
+                            }
                         }
                     }
                 }
@@ -30,21 +30,26 @@ pub(crate) fn read<R: io::Read + io::Seek>(
             match e.name().into_inner() {
                 b"workbookView" => {
                     let mut obj = WorkbookView::default();
-                    obj.set_attributes(&mut reader, e);
+                    obj.set_attributes(&mut reader, e)?;
                     spreadsheet.set_workbook_view(obj);
                 }
+                b"workbookPr" => {
+                    if let Some(v) = get_attribute(e, b"date1904")? {
+                        spreadsheet.set_date1904(v == "1" || v == "true");
+                    }
+                }
                 b"workbookProtection" => {
                     let mut obj = WorkbookProtection::default();
-                    obj.set_attributes(&mut reader, e);
+                    obj.set_attributes(&mut reader, e)?;
                     spreadsheet.set_workbook_protection(obj);
                 }
                 b"sheet" => {
-                    let name_value = get_attribute(e, b"name").unwrap();
-                    let sheet_id_value = get_attribute(e, b"sheetId").unwrap();
-                    let r_id_value = get_attribute(e, b"r:id").unwrap();
-                    let state = get_attribute(e, b"state");
+                    let name_value = get_required_attribute(e, b"name")?;
+                    let sheet_id_value = get_required_attribute(e, b"sheetId")?;
+                    let r_id_value = get_required_attribute(e, b"r:id")?;
+                    let state = get_attribute(e, b"state")?;
                     let mut worksheet = Worksheet::default();
-                    worksheet.set_name(escape::unescape(&name_value).unwrap());
+                    worksheet.set_name(escape::unescape(&name_value).map_err(quick_xml::Error::from)?);
                     worksheet.set_sheet_id(sheet_id_value);
                     worksheet.set_r_id(r_id_value);
                     if let Some(v) = state {
@@ -53,8 +58,8 @@ pub(crate) fn read<R: io::Read + io::Seek>(
                     spreadsheet.add_sheet(worksheet);
                 }
                 b"pivotCache" => {
-                    let cache_id = get_attribute(e, b"cacheId").unwrap();
-                    let r_id = get_attribute(e, b"r:id").unwrap();
+                    let cache_id = get_required_attribute(e, b"cacheId")?;
+                    let r_id = get_required_attribute(e, b"r:id")?;
                     spreadsheet.add_pivot_caches((r_id, cache_id, String::new()));
                 }
                 _ => (),
@@ -63,7 +68,7 @@ pub(crate) fn read<R: io::Read + io::Seek>(
         Event::Start(ref e) => {
             if e.name().into_inner() == b"definedName" {
                 let mut obj = DefinedName::default();
-                obj.set_attributes(&mut reader, e);
+                obj.set_attributes(&mut reader, e)?;
                 defined_names.push(obj);
             }
         },
@@ -75,7 +80,7 @@ pub(crate) fn read<R: io::Read + io::Seek>(
             let local_sheet_id = defined_name.get_local_sheet_id().clone() as usize;
             spreadsheet
                 .get_sheet_mut(&local_sheet_id)
-                .unwrap()
+                .ok_or_else(|| invalid_attribute_error("localSheetId", local_sheet_id.to_string()))?
                 .add_defined_names(defined_name.clone());
         } else {
             match defined_name.get_address_obj().get(0) {
@@ -20,9 +20,9 @@ pub(crate) fn read<R: io::Read + io::Seek>(
         reader,
         Event::Empty(ref e) => {
             if e.name().into_inner() == b"Relationship" {
-                let id_value = get_attribute(e, b"Id").unwrap();
-                let type_value = get_attribute(e, b"Type").unwrap();
-                let target_value = get_attribute(e, b"Target").unwrap();
+                let id_value = get_required_attribute(e, b"Id")?;
+                let type_value = get_required_attribute(e, b"Type")?;
+                let target_value = get_required_attribute(e, b"Target")?;
                 let target_value = target_value
                     .strip_prefix("/xl/")
                     .map(|t| t.to_owned())
@@ -6,8 +6,10 @@ use std::collections::HashMap;
 
 use crate::helper::formula::*;
 use crate::structs::office2010::excel::DataValidations as DataValidations2010;
+use crate::structs::office2010::excel::SparklineGroups;
 use crate::structs::raw::RawRelationships;
 use crate::structs::raw::RawWorksheet;
+use crate::structs::AutoFilter;
 use crate::structs::Cells;
 use crate::structs::Columns;
 use crate::structs::ConditionalFormatting;
@@ -17,6 +19,7 @@ use crate::structs::OleObjects;
 use crate::structs::Row;
 use crate::structs::SharedStringTable;
 use crate::structs::SheetProtection;
+use crate::structs::SortState;
 use crate::structs::Stylesheet;
 use crate::structs::Worksheet;
 
@@ -47,12 +50,12 @@ pub(crate) fn read(
             b"sheetViews" => {
                 worksheet
                     .get_sheet_views_mut()
-                    .set_attributes(&mut reader, e);
+                    .set_attributes(&mut reader, e)?;
             }
             b"sheetFormatPr" => {
                 worksheet
                     .get_sheet_format_properties_mut()
-                    .set_attributes(&mut reader, e);
+                    .set_attributes(&mut reader, e)?;
             }
             b"selection" => {
                 for a in e.attributes().with_checks(false) {
@@ -75,60 +78,90 @@ pub(crate) fn read(
                     stylesheet,
                     &mut formula_shared_list,
                     false,
-                );
+                )?;
                 worksheet.set_row_dimension(obj);
             }
             b"autoFilter" => {
-                worksheet.set_auto_filter(get_attribute(e, b"ref").unwrap());
+                let mut obj = AutoFilter::default();
+                obj.set_attributes(&mut reader, e, stylesheet.get_differential_formats(), false)?;
+                worksheet.set_auto_filter_obj(obj);
+            }
+            b"sortState" => {
+                let mut obj = SortState::default();
+                obj.set_attributes(&mut reader, e, stylesheet.get_differential_formats(), false)?;
+                worksheet.set_sort_state(obj);
             }
             b"cols" => {
                 let mut obj = Columns::default();
-                obj.set_attributes(&mut reader, e, stylesheet);
+                obj.set_attributes(&mut reader, e, stylesheet)?;
                 worksheet.set_column_dimensions_crate(obj);
             }
             b"mergeCells" => {
                 worksheet
                     .get_merge_cells_crate_mut()
-                    .set_attributes(&mut reader, e);
+                    .set_attributes(&mut reader, e)?;
             }
             b"conditionalFormatting" => {
                 let mut obj = ConditionalFormatting::default();
-                obj.set_attributes(&mut reader, e, stylesheet.get_differential_formats());
+                obj.set_attributes(&mut reader, e, stylesheet.get_differential_formats())?;
                 worksheet.add_conditional_formatting_collection(obj);
             }
+            b"x14:conditionalFormatting" => {
+                let mut obj = ConditionalFormatting::default();
+                obj.set_attributes_x14(
+                    &mut reader,
+                    e,
+                    worksheet.get_conditional_formatting_collection_mut(),
+                )?;
+                if !obj.get_conditional_collection().is_empty() {
+                    worksheet.add_conditional_formatting_collection(obj);
+                }
+            }
             b"dataValidations" => {
                 let mut obj = DataValidations::default();
-                obj.set_attributes(&mut reader, e);
+                obj.set_attributes(&mut reader, e)?;
                 worksheet.set_data_validations(obj);
             }
             b"x14:dataValidations" => {
                 let mut obj = DataValidations2010::default();
-                obj.set_attributes(&mut reader, e);
+                obj.set_attributes(&mut reader, e)?;
                 worksheet.set_data_validations_2010(obj);
             }
+            b"x14:sparklineGroups" => {
+                let mut obj = SparklineGroups::default();
+                obj.set_attributes(&mut reader, e)?;
+                worksheet.set_sparkline_groups(obj);
+            }
             b"oleObjects" => {
                 let mut obj = OleObjects::default();
                 obj.set_attributes(
                     &mut reader,
                     e,
-                    raw_data_of_worksheet.get_worksheet_relationships().unwrap(),
-                );
+                    raw_data_of_worksheet
+                        .get_worksheet_relationships()
+                        .ok_or_else(|| {
+                            XlsxError::MissingPart(format!(
+                                "relationships of {}",
+                                raw_data_of_worksheet.get_worksheet_file().get_file_target()
+                            ))
+                        })?,
+                )?;
                 worksheet.set_ole_objects(obj);
             }
             b"headerFooter" => {
                 worksheet
                     .get_header_footer_mut()
-                    .set_attributes(&mut reader, e);
+                    .set_attributes(&mut reader, e, false)?;
             }
             b"rowBreaks" => {
                 worksheet
                     .get_row_breaks_mut()
-                    .set_attributes(&mut reader, e);
+                    .set_attributes(&mut reader, e)?;
             }
             b"colBreaks" => {
                 worksheet
                     .get_column_breaks_mut()
-                    .set_attributes(&mut reader, e);
+                    .set_attributes(&mut reader, e)?;
             }
             _ => (),
         },
@@ -144,15 +177,25 @@ pub(crate) fn read(
                     }
                 }
             }
+            b"outlinePr" => {
+                worksheet
+                    .get_outline_properties_mut()
+                    .set_attributes(&mut reader, e)?;
+            }
             b"tabColor" => {
                 worksheet
                     .get_tab_color_mut()
-                    .set_attributes(&mut reader, e, true);
+                    .set_attributes(&mut reader, e, true)?;
             }
             b"sheetFormatPr" => {
                 worksheet
                     .get_sheet_format_properties_mut()
-                    .set_attributes(&mut reader, e);
+                    .set_attributes(&mut reader, e)?;
+            }
+            b"headerFooter" => {
+                worksheet
+                    .get_header_footer_mut()
+                    .set_attributes(&mut reader, e, true)?;
             }
             b"selection" => {
                 for a in e.attributes().with_checks(false) {
@@ -175,39 +218,46 @@ pub(crate) fn read(
                     stylesheet,
                     &mut formula_shared_list,
                     true,
-                );
+                )?;
                 worksheet.set_row_dimension(obj);
             }
             b"autoFilter" => {
-                worksheet.set_auto_filter(get_attribute(e, b"ref").unwrap());
+                let mut obj = AutoFilter::default();
+                obj.set_attributes(&mut reader, e, stylesheet.get_differential_formats(), true)?;
+                worksheet.set_auto_filter_obj(obj);
+            }
+            b"sortState" => {
+                let mut obj = SortState::default();
+                obj.set_attributes(&mut reader, e, stylesheet.get_differential_formats(), true)?;
+                worksheet.set_sort_state(obj);
             }
             b"pageMargins" => {
                 worksheet
                     .get_page_margins_mut()
-                    .set_attributes(&mut reader, e);
+                    .set_attributes(&mut reader, e)?;
             }
             b"hyperlink" => {
-                let (coor, hyperlink) = get_hyperlink(e, raw_data_of_worksheet.get_worksheet_relationships());
+                let (coor, hyperlink) = get_hyperlink(e, raw_data_of_worksheet.get_worksheet_relationships())?;
                 worksheet.get_cell_mut(coor).set_hyperlink(hyperlink);
             }
             b"printOptions" => {
                 worksheet
                     .get_print_options_mut()
-                    .set_attributes(&mut reader, e);
+                    .set_attributes(&mut reader, e)?;
             }
             b"pageSetup" => {
                 worksheet.get_page_setup_mut().set_attributes(
                     &mut reader,
                     e,
                     raw_data_of_worksheet.get_worksheet_relationships(),
-                );
+                )?;
             }
             b"sheetProtection" => {
                 let mut obj = SheetProtection::default();
                 obj.set_attributes(
                     &mut reader,
                     e,
-                );
+                )?;
                 worksheet.set_sheet_protection(obj);
             }
             _ => (),
@@ -242,7 +292,7 @@ pub(crate) fn read_lite(
                     stylesheet,
                     &mut formula_shared_list,
                     false,
-                );
+                )?;
             }
         },
         Event::Empty(ref e) => {
@@ -256,7 +306,7 @@ pub(crate) fn read_lite(
                     stylesheet,
                     &mut formula_shared_list,
                     true,
-                );
+                )?;
             }
         },
         Event::Eof => break,
@@ -268,18 +318,20 @@ pub(crate) fn read_lite(
 fn get_hyperlink(
     e: &quick_xml::events::BytesStart<'_>,
     raw_relationships: Option<&RawRelationships>,
-) -> (String, Hyperlink) {
+) -> Result<(String, Hyperlink), XlsxError> {
     let mut hyperlink = Hyperlink::default();
     let mut rid = String::new();
 
-    let coordition = get_attribute(e, b"ref").unwrap_or_default();
-    if let Some(v) = get_attribute(e, b"location") {
+    let coordition = get_attribute(e, b"ref")?.unwrap_or_default();
+    if let Some(v) = get_attribute(e, b"location")? {
         hyperlink.set_url(v);
         hyperlink.set_location(true);
     }
-    if let Some(v) = get_attribute(e, b"r:id") {
-        let relationship = raw_relationships.unwrap().get_relationship_by_rid(&v);
+    if let Some(v) = get_attribute(e, b"r:id")? {
+        let relationship = raw_relationships
+            .ok_or_else(|| XlsxError::MissingPart(format!("relationship {}", v)))?
+            .get_relationship_by_rid(&v)?;
         hyperlink.set_url(relationship.get_target());
     }
-    (coordition, hyperlink)
+    Ok((coordition, hyperlink))
 }
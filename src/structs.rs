@@ -46,6 +46,60 @@ pub use self::sheet_view::*;
 mod auto_filter;
 pub use self::auto_filter::*;
 
+mod filter_column;
+pub use self::filter_column::*;
+
+mod filters;
+pub use self::filters::*;
+
+mod filter;
+pub use self::filter::*;
+
+mod date_group_item;
+pub use self::date_group_item::*;
+
+mod date_time_grouping_values;
+pub use self::date_time_grouping_values::*;
+
+mod custom_filters;
+pub use self::custom_filters::*;
+
+mod custom_filter;
+pub use self::custom_filter::*;
+
+mod filter_operator_values;
+pub use self::filter_operator_values::*;
+
+mod top10;
+pub use self::top10::*;
+
+mod dynamic_filter;
+pub use self::dynamic_filter::*;
+
+mod dynamic_filter_values;
+pub use self::dynamic_filter_values::*;
+
+mod color_filter;
+pub use self::color_filter::*;
+
+mod icon_filter;
+pub use self::icon_filter::*;
+
+mod icon_set_values;
+pub use self::icon_set_values::*;
+
+mod sort_state;
+pub use self::sort_state::*;
+
+mod sort_condition;
+pub use self::sort_condition::*;
+
+mod sort_by_values;
+pub use self::sort_by_values::*;
+
+mod sort_method_values;
+pub use self::sort_method_values::*;
+
 mod column;
 pub use self::column::*;
 
@@ -70,6 +124,9 @@ pub use self::alignment::*;
 mod conditional_formatting_rule;
 pub use self::conditional_formatting_rule::*;
 
+mod conditional_formatting_result;
+pub use self::conditional_formatting_result::*;
+
 mod protection;
 pub use self::protection::*;
 
@@ -322,6 +379,15 @@ pub use self::chart::*;
 mod chart_type;
 pub use self::chart_type::*;
 
+mod chart_ex;
+pub use self::chart_ex::*;
+
+mod chart_ex_type;
+pub use self::chart_ex_type::*;
+
+mod axis_side;
+pub use self::axis_side::*;
+
 mod merge_cells;
 pub(crate) use self::merge_cells::*;
 
@@ -337,6 +403,27 @@ pub use self::odd_header::*;
 mod odd_footer;
 pub use self::odd_footer::*;
 
+mod even_header;
+pub use self::even_header::*;
+
+mod even_footer;
+pub use self::even_footer::*;
+
+mod first_header;
+pub use self::first_header::*;
+
+mod first_footer;
+pub use self::first_footer::*;
+
+mod header_footer_image;
+pub use self::header_footer_image::*;
+
+mod header_footer_builder;
+pub use self::header_footer_builder::*;
+
+mod header_footer_section;
+pub use self::header_footer_section::*;
+
 mod r#break;
 pub use self::r#break::*;
 
@@ -364,9 +451,15 @@ pub(crate) use self::media_object::*;
 mod csv_writer_option;
 pub use self::csv_writer_option::*;
 
+mod csv_reader_option;
+pub use self::csv_reader_option::*;
+
 mod csv_encode_values;
 pub use self::csv_encode_values::*;
 
+mod csv_quoting_values;
+pub use self::csv_quoting_values::*;
+
 mod cell_raw_value;
 pub use self::cell_raw_value::*;
 
@@ -415,6 +508,9 @@ pub use self::data_validations::*;
 mod sheet_format_properties;
 pub use self::sheet_format_properties::*;
 
+mod outline_properties;
+pub use self::outline_properties::*;
+
 mod sheet_protection;
 pub use self::sheet_protection::*;
 
@@ -498,3 +594,33 @@ pub use self::cache_field::*;
 
 mod shared_items;
 pub use self::shared_items::*;
+
+mod cache_item_value;
+pub use self::cache_item_value::*;
+
+mod pivot_cache_records;
+pub use self::pivot_cache_records::*;
+
+mod pivot_cache_record;
+pub use self::pivot_cache_record::*;
+
+mod pivot_items;
+pub use self::pivot_items::*;
+
+mod pivot_item;
+pub use self::pivot_item::*;
+
+mod row_fields;
+pub use self::row_fields::*;
+
+mod page_fields;
+pub use self::page_fields::*;
+
+mod page_field;
+pub use self::page_field::*;
+
+mod data_consolidate_function_values;
+pub use self::data_consolidate_function_values::*;
+
+mod pivot_table_axis_values;
+pub use self::pivot_table_axis_values::*;
@@ -6,6 +6,7 @@ use super::UInt32Value;
 use super::VerticalAlignmentValues;
 use crate::reader::driver::*;
 use crate::writer::driver::*;
+use crate::XlsxError;
 use md5::Digest;
 use quick_xml::events::BytesStart;
 use quick_xml::Reader;
@@ -79,11 +80,12 @@ impl Alignment {
         &mut self,
         _reader: &mut Reader<R>,
         e: &BytesStart,
-    ) {
+    ) -> Result<(), XlsxError> {
         set_string_from_xml!(self, e, horizontal, "horizontal");
         set_string_from_xml!(self, e, vertical, "vertical");
         set_string_from_xml!(self, e, wrap_text, "wrapText");
         set_string_from_xml!(self, e, text_rotation, "textRotation");
+        Ok(())
     }
 
     pub(crate) fn write_to(&self, writer: &mut Writer<Cursor<Vec<u8>>>) {
@@ -1,9 +1,35 @@
+use super::Cell;
+use super::ColorFilter;
+use super::CustomFilter;
+use super::CustomFilters;
+use super::DateTimeGroupingValues;
+use super::DifferentialFormats;
+use super::DynamicFilter;
+use super::DynamicFilterValues;
+use super::FilterColumn;
+use super::FilterOperatorValues;
+use super::Filters;
 use super::Range;
+use super::SortState;
+use super::Top10;
+use super::Worksheet;
+use crate::helper::date::excel_to_date_time_object_crate;
+use crate::reader::driver::*;
 use crate::traits::AdjustmentCoordinate;
+use crate::writer::driver::*;
+use crate::XlsxError;
+use chrono::{Datelike, Duration, Local, NaiveDate, Timelike};
+use quick_xml::events::{BytesStart, Event};
+use quick_xml::Reader;
+use quick_xml::Writer;
+use std::io::Cursor;
+use thin_vec::ThinVec;
 
 #[derive(Clone, Default, Debug)]
 pub struct AutoFilter {
     range: Range,
+    filter_column: ThinVec<FilterColumn>,
+    sort_state: Option<Box<SortState>>,
 }
 
 impl AutoFilter {
@@ -23,6 +49,223 @@ impl AutoFilter {
         range.set_range(value.into());
         self.range = range;
     }
+
+    #[inline]
+    pub fn get_filter_column_list(&self) -> &[FilterColumn] {
+        &self.filter_column
+    }
+
+    #[inline]
+    pub fn get_filter_column_list_mut(&mut self) -> &mut ThinVec<FilterColumn> {
+        &mut self.filter_column
+    }
+
+    /// Get the filter of a column.
+    /// # Arguments
+    /// * `column_id` - zero-based column offset from the first column of the range.
+    #[inline]
+    pub fn get_filter_column(&self, column_id: u32) -> Option<&FilterColumn> {
+        self.filter_column
+            .iter()
+            .find(|v| v.get_column_id() == &column_id)
+    }
+
+    /// Get the filter of a column in mutable, adding it when it does not exist.
+    /// # Arguments
+    /// * `column_id` - zero-based column offset from the first column of the range.
+    /// # Examples
+    /// ```
+    /// use umya_spreadsheet::*;
+    /// let mut book = new_file();
+    /// let worksheet = book.get_sheet_mut(&0).unwrap();
+    /// worksheet.set_auto_filter("A1:C10");
+    /// let mut filters = Filters::default();
+    /// filters.add_value("East").add_value("West");
+    /// worksheet
+    ///     .get_auto_filter_mut()
+    ///     .unwrap()
+    ///     .get_filter_column_mut(1)
+    ///     .set_filters(filters);
+    /// worksheet.apply_auto_filter();
+    /// ```
+    pub fn get_filter_column_mut(&mut self, column_id: u32) -> &mut FilterColumn {
+        match self
+            .filter_column
+            .iter()
+            .position(|v| v.get_column_id() == &column_id)
+        {
+            Some(i) => &mut self.filter_column[i],
+            None => {
+                let mut obj = FilterColumn::default();
+                obj.set_column_id(column_id);
+                self.filter_column.push(obj);
+                self.filter_column.last_mut().unwrap()
+            }
+        }
+    }
+
+    #[inline]
+    pub fn add_filter_column(&mut self, value: FilterColumn) -> &mut Self {
+        self.filter_column.push(value);
+        self
+    }
+
+    #[inline]
+    pub fn remove_filter_column(&mut self, column_id: u32) -> &mut Self {
+        self.filter_column
+            .retain(|v| v.get_column_id() != &column_id);
+        self
+    }
+
+    #[inline]
+    pub fn get_sort_state(&self) -> Option<&SortState> {
+        self.sort_state.as_deref()
+    }
+
+    #[inline]
+    pub fn get_sort_state_mut(&mut self) -> Option<&mut SortState> {
+        self.sort_state.as_deref_mut()
+    }
+
+    #[inline]
+    pub fn set_sort_state(&mut self, value: SortState) -> &mut Self {
+        self.sort_state = Some(Box::new(value));
+        self
+    }
+
+    #[inline]
+    pub fn remove_sort_state(&mut self) -> &mut Self {
+        self.sort_state = None;
+        self
+    }
+
+    /// Evaluate the filter columns against the data rows of the range.
+    /// Returns the row number and whether the row is shown.
+    /// Icon filters depend on conditional formatting and always match.
+    pub(crate) fn get_row_visibility(&self, worksheet: &Worksheet) -> Vec<(u32, bool)> {
+        let start_col = match self.range.get_coordinate_start_col() {
+            Some(v) => *v.get_num(),
+            None => return Vec::new(),
+        };
+        let start_row = match self.range.get_coordinate_start_row() {
+            Some(v) => *v.get_num(),
+            None => return Vec::new(),
+        };
+        let mut end_row = self
+            .range
+            .get_coordinate_end_row()
+            .map(|v| *v.get_num())
+            .unwrap_or(start_row);
+        if end_row <= start_row {
+            // only the header row is given: filter everything below it
+            end_row = worksheet.get_highest_row();
+        }
+        if end_row <= start_row {
+            return Vec::new();
+        }
+
+        let today = Local::now().date_naive();
+        let mut result: Vec<(u32, bool)> =
+            ((start_row + 1)..=end_row).map(|row| (row, true)).collect();
+        for filter_column in &self.filter_column {
+            let col = start_col + filter_column.get_column_id();
+            let cells: Vec<Option<&Cell>> = result
+                .iter()
+                .map(|(row, _)| worksheet.get_cell((col, *row)))
+                .collect();
+            let numbers: Vec<f64> = cells
+                .iter()
+                .filter_map(|v| v.and_then(|cell| cell.get_cell_value().get_value_number()))
+                .filter(|v| v.is_finite())
+                .collect();
+            let context =
+                ColumnContext::new(filter_column, &numbers, today, worksheet.get_date1904());
+            for (visible, cell) in result.iter_mut().zip(cells) {
+                if visible.1 && !context.is_match(cell) {
+                    visible.1 = false;
+                }
+            }
+        }
+        result
+    }
+
+    pub(crate) fn set_attributes<R: std::io::BufRead>(
+        &mut self,
+        reader: &mut Reader<R>,
+        e: &BytesStart,
+        differential_formats: &DifferentialFormats,
+        empty_flag: bool,
+    ) -> Result<(), XlsxError> {
+        if let Some(v) = get_attribute(e, b"ref")? {
+            self.set_range(v);
+        }
+
+        if empty_flag {
+            return Ok(());
+        }
+
+        xml_read_loop!(
+            reader,
+            Event::Start(ref e) => match e.name().into_inner() {
+                b"filterColumn" => {
+                    let mut obj = FilterColumn::default();
+                    obj.set_attributes(reader, e, differential_formats, false)?;
+                    self.add_filter_column(obj);
+                }
+                b"sortState" => {
+                    let mut obj = SortState::default();
+                    obj.set_attributes(reader, e, differential_formats, false)?;
+                    self.set_sort_state(obj);
+                }
+                _ => (),
+            },
+            Event::Empty(ref e) => match e.name().into_inner() {
+                b"filterColumn" => {
+                    let mut obj = FilterColumn::default();
+                    obj.set_attributes(reader, e, differential_formats, true)?;
+                    self.add_filter_column(obj);
+                }
+                b"sortState" => {
+                    let mut obj = SortState::default();
+                    obj.set_attributes(reader, e, differential_formats, true)?;
+                    self.set_sort_state(obj);
+                }
+                _ => (),
+            },
+            Event::End(ref e) => {
+                if e.name().into_inner() == b"autoFilter" {
+                    return Ok(())
+                }
+            },
+            Event::Eof => return Err(end_element_error(reader, "autoFilter"))
+        );
+    }
+
+    pub(crate) fn write_to(
+        &self,
+        writer: &mut Writer<Cursor<Vec<u8>>>,
+        differential_formats: &mut DifferentialFormats,
+    ) {
+        // autoFilter
+        let range = self.range.get_range();
+        let empty_flag = self.filter_column.is_empty() && self.sort_state.is_none();
+        write_start_tag(writer, "autoFilter", vec![("ref", &range)], empty_flag);
+        if empty_flag {
+            return;
+        }
+
+        // filterColumn
+        for obj in &self.filter_column {
+            obj.write_to(writer, differential_formats);
+        }
+
+        // sortState
+        if let Some(v) = &self.sort_state {
+            v.write_to(writer, differential_formats);
+        }
+
+        write_end_tag(writer, "autoFilter");
+    }
 }
 impl AdjustmentCoordinate for AutoFilter {
     #[inline]
@@ -39,6 +282,14 @@ impl AdjustmentCoordinate for AutoFilter {
             root_row_num,
             offset_row_num,
         );
+        if let Some(v) = &mut self.sort_state {
+            v.adjustment_insert_coordinate(
+                root_col_num,
+                offset_col_num,
+                root_row_num,
+                offset_row_num,
+            );
+        }
     }
 
     #[inline]
@@ -55,5 +306,292 @@ impl AdjustmentCoordinate for AutoFilter {
             root_row_num,
             offset_row_num,
         );
+        if let Some(v) = &mut self.sort_state {
+            v.adjustment_remove_coordinate(
+                root_col_num,
+                offset_col_num,
+                root_row_num,
+                offset_row_num,
+            );
+        }
+    }
+}
+
+// The criteria of one filter column together with the values that depend on the whole column.
+struct ColumnContext<'a> {
+    filter_column: &'a FilterColumn,
+    threshold: Option<f64>,
+    average: f64,
+    today: NaiveDate,
+    date1904: bool,
+}
+impl<'a> ColumnContext<'a> {
+    fn new(
+        filter_column: &'a FilterColumn,
+        numbers: &[f64],
+        today: NaiveDate,
+        date1904: bool,
+    ) -> Self {
+        let threshold = filter_column
+            .get_top10()
+            .and_then(|v| get_top10_threshold(v, numbers));
+        let average = if numbers.is_empty() {
+            0f64
+        } else {
+            numbers.iter().sum::<f64>() / numbers.len() as f64
+        };
+        Self {
+            filter_column,
+            threshold,
+            average,
+            today,
+            date1904,
+        }
+    }
+
+    fn is_match(&self, cell: Option<&Cell>) -> bool {
+        let number = cell.and_then(|v| v.get_cell_value().get_value_number());
+        let text = cell
+            .map(|v| v.get_formatted_value_crate(self.date1904))
+            .unwrap_or_default();
+        if let Some(v) = self.filter_column.get_filters() {
+            return is_match_filters(v, &text, number, self.date1904);
+        }
+        if let Some(v) = self.filter_column.get_custom_filters() {
+            return is_match_custom_filters(v, &text, number);
+        }
+        if let Some(v) = self.filter_column.get_top10() {
+            return match (number, self.threshold) {
+                (Some(n), Some(t)) if *v.get_top() => n >= t,
+                (Some(n), Some(t)) => n <= t,
+                _ => false,
+            };
+        }
+        if let Some(v) = self.filter_column.get_dynamic_filter() {
+            return is_match_dynamic_filter(v, number, self.average, self.today, self.date1904);
+        }
+        if let Some(v) = self.filter_column.get_color_filter() {
+            return is_match_color_filter(v, cell);
+        }
+        true
+    }
+}
+
+fn is_match_filters(filters: &Filters, text: &str, number: Option<f64>, date1904: bool) -> bool {
+    if text.is_empty() {
+        return *filters.get_blank();
+    }
+    let text = text.to_lowercase();
+    if filters
+        .get_filter_list()
+        .iter()
+        .any(|v| v.get_val().to_lowercase() == text)
+    {
+        return true;
+    }
+    let date_time = match number {
+        Some(v) if !filters.get_date_group_item_list().is_empty() => {
+            excel_to_date_time_object_crate(&v, None, !date1904)
+        }
+        _ => return false,
+    };
+    filters.get_date_group_item_list().iter().any(|item| {
+        let grouping = item.get_date_time_grouping();
+        let depth = match grouping {
+            DateTimeGroupingValues::Year => 1,
+            DateTimeGroupingValues::Month => 2,
+            DateTimeGroupingValues::Day => 3,
+            DateTimeGroupingValues::Hour => 4,
+            DateTimeGroupingValues::Minute => 5,
+            DateTimeGroupingValues::Second => 6,
+        };
+        let parts = [
+            (date_time.year() as u32, *item.get_year() as u32),
+            (date_time.month(), *item.get_month() as u32),
+            (date_time.day(), *item.get_day() as u32),
+            (date_time.hour(), *item.get_hour() as u32),
+            (date_time.minute(), *item.get_minute() as u32),
+            (date_time.second(), *item.get_second() as u32),
+        ];
+        parts.iter().take(depth).all(|(a, b)| a == b)
+    })
+}
+
+fn is_match_custom_filters(
+    custom_filters: &CustomFilters,
+    text: &str,
+    number: Option<f64>,
+) -> bool {
+    let list = custom_filters.get_custom_filter_list();
+    if list.is_empty() {
+        return true;
     }
+    let mut results = list.iter().map(|v| is_match_custom_filter(v, text, number));
+    if *custom_filters.get_and() {
+        results.all(|v| v)
+    } else {
+        results.any(|v| v)
+    }
+}
+
+fn is_match_custom_filter(custom_filter: &CustomFilter, text: &str, number: Option<f64>) -> bool {
+    let operator = custom_filter.get_operator();
+    let val = custom_filter.get_val();
+    if let (Some(n), Ok(v)) = (number, val.trim().parse::<f64>()) {
+        return match operator {
+            FilterOperatorValues::Equal => n == v,
+            FilterOperatorValues::NotEqual => n != v,
+            FilterOperatorValues::GreaterThan => n > v,
+            FilterOperatorValues::GreaterThanOrEqual => n >= v,
+            FilterOperatorValues::LessThan => n < v,
+            FilterOperatorValues::LessThanOrEqual => n <= v,
+        };
+    }
+    let text = text.to_lowercase();
+    let val = val.to_lowercase();
+    match operator {
+        FilterOperatorValues::Equal => is_match_wildcard(&text, &val),
+        FilterOperatorValues::NotEqual => !is_match_wildcard(&text, &val),
+        // numbers and text are not comparable
+        _ if number.is_some() || val.parse::<f64>().is_ok() => false,
+        FilterOperatorValues::GreaterThan => text > val,
+        FilterOperatorValues::GreaterThanOrEqual => text >= val,
+        FilterOperatorValues::LessThan => text < val,
+        FilterOperatorValues::LessThanOrEqual => text <= val,
+    }
+}
+
+// `*` matches any sequence, `?` any single char, `~` escapes the next char.
+fn is_match_wildcard(text: &str, pattern: &str) -> bool {
+    let text: Vec<char> = text.chars().collect();
+    let mut tokens: Vec<(char, bool)> = Vec::new();
+    let mut chars = pattern.chars();
+    while let Some(c) = chars.next() {
+        match c {
+            '~' => {
+                if let Some(v) = chars.next() {
+                    tokens.push((v, false));
+                }
+            }
+            '*' | '?' => tokens.push((c, true)),
+            _ => tokens.push((c, false)),
+        }
+    }
+
+    let (mut t, mut p) = (0, 0);
+    let mut backtrack: Option<(usize, usize)> = None;
+    while t < text.len() {
+        match tokens.get(p) {
+            Some(('*', true)) => {
+                backtrack = Some((p, t));
+                p += 1;
+            }
+            Some(('?', true)) => {
+                t += 1;
+                p += 1;
+            }
+            Some((c, false)) if *c == text[t] => {
+                t += 1;
+                p += 1;
+            }
+            _ => match backtrack {
+                Some((bp, bt)) => {
+                    p = bp + 1;
+                    t = bt + 1;
+                    backtrack = Some((bp, bt + 1));
+                }
+                None => return false,
+            },
+        }
+    }
+    tokens[p..].iter().all(|v| *v == ('*', true))
+}
+
+fn get_top10_threshold(top10: &Top10, numbers: &[f64]) -> Option<f64> {
+    if numbers.is_empty() {
+        return None;
+    }
+    let mut sorted = numbers.to_vec();
+    sorted.sort_by(f64::total_cmp);
+    if *top10.get_top() {
+        sorted.reverse();
+    }
+    let count = if *top10.get_percent() {
+        (sorted.len() as f64 * top10.get_val() / 100f64).floor() as usize
+    } else {
+        *top10.get_val() as usize
+    };
+    let count = count.clamp(1, sorted.len());
+    Some(sorted[count - 1])
+}
+
+fn is_match_dynamic_filter(
+    dynamic_filter: &DynamicFilter,
+    number: Option<f64>,
+    average: f64,
+    today: NaiveDate,
+    date1904: bool,
+) -> bool {
+    let number = match number {
+        Some(v) => v,
+        None => return dynamic_filter.get_type() == &DynamicFilterValues::Null,
+    };
+    let date = excel_to_date_time_object_crate(&number, None, !date1904).date();
+    let month_index = |v: NaiveDate| v.year() * 12 + v.month0() as i32;
+    let quarter_index = |v: NaiveDate| v.year() * 4 + (v.month0() / 3) as i32;
+    let week_start = |v: NaiveDate| v - Duration::days(v.weekday().num_days_from_sunday() as i64);
+    match dynamic_filter.get_type() {
+        DynamicFilterValues::Null => true,
+        DynamicFilterValues::AboveAverage => number > average,
+        DynamicFilterValues::BelowAverage => number < average,
+        DynamicFilterValues::Today => date == today,
+        DynamicFilterValues::Yesterday => date == today - Duration::days(1),
+        DynamicFilterValues::Tomorrow => date == today + Duration::days(1),
+        DynamicFilterValues::ThisWeek => week_start(date) == week_start(today),
+        DynamicFilterValues::LastWeek => week_start(date) == week_start(today) - Duration::days(7),
+        DynamicFilterValues::NextWeek => week_start(date) == week_start(today) + Duration::days(7),
+        DynamicFilterValues::ThisMonth => month_index(date) == month_index(today),
+        DynamicFilterValues::LastMonth => month_index(date) == month_index(today) - 1,
+        DynamicFilterValues::NextMonth => month_index(date) == month_index(today) + 1,
+        DynamicFilterValues::ThisQuarter => quarter_index(date) == quarter_index(today),
+        DynamicFilterValues::LastQuarter => quarter_index(date) == quarter_index(today) - 1,
+        DynamicFilterValues::NextQuarter => quarter_index(date) == quarter_index(today) + 1,
+        DynamicFilterValues::ThisYear => date.year() == today.year(),
+        DynamicFilterValues::LastYear => date.year() == today.year() - 1,
+        DynamicFilterValues::NextYear => date.year() == today.year() + 1,
+        DynamicFilterValues::YearToDate => date.year() == today.year() && date <= today,
+        DynamicFilterValues::Quarter1 => date.month0() / 3 == 0,
+        DynamicFilterValues::Quarter2 => date.month0() / 3 == 1,
+        DynamicFilterValues::Quarter3 => date.month0() / 3 == 2,
+        DynamicFilterValues::Quarter4 => date.month0() / 3 == 3,
+        DynamicFilterValues::January => date.month() == 1,
+        DynamicFilterValues::February => date.month() == 2,
+        DynamicFilterValues::March => date.month() == 3,
+        DynamicFilterValues::April => date.month() == 4,
+        DynamicFilterValues::May => date.month() == 5,
+        DynamicFilterValues::June => date.month() == 6,
+        DynamicFilterValues::July => date.month() == 7,
+        DynamicFilterValues::August => date.month() == 8,
+        DynamicFilterValues::September => date.month() == 9,
+        DynamicFilterValues::October => date.month() == 10,
+        DynamicFilterValues::November => date.month() == 11,
+        DynamicFilterValues::December => date.month() == 12,
+    }
+}
+
+fn is_match_color_filter(color_filter: &ColorFilter, cell: Option<&Cell>) -> bool {
+    let cell_color = *color_filter.get_cell_color();
+    let get_argb = |style: &super::Style| -> String {
+        let color = if cell_color {
+            style.get_background_color()
+        } else {
+            style.get_font().map(|v| v.get_color())
+        };
+        color
+            .map(|v| v.get_argb().to_uppercase())
+            .unwrap_or_default()
+    };
+    let expected = color_filter.get_style().map(get_argb).unwrap_or_default();
+    let actual = cell.map(|v| get_argb(v.get_style())).unwrap_or_default();
+    expected == actual
 }
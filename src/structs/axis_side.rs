@@ -0,0 +1,12 @@
+/// Value axis a series group of a combo chart is plotted on.
+#[derive(Clone, Debug)]
+pub enum AxisSide {
+    Primary,
+    Secondary,
+}
+impl Default for AxisSide {
+    #[inline]
+    fn default() -> Self {
+        Self::Primary
+    }
+}
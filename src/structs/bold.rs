@@ -2,6 +2,7 @@
 use super::BooleanValue;
 use crate::reader::driver::*;
 use crate::writer::driver::*;
+use crate::XlsxError;
 use quick_xml::events::BytesStart;
 use quick_xml::Reader;
 use quick_xml::Writer;
@@ -29,9 +30,10 @@ impl Bold {
         &mut self,
         _reader: &mut Reader<R>,
         e: &BytesStart,
-    ) {
+    ) -> Result<(), XlsxError> {
         self.val.set_value(true);
         set_string_from_xml!(self, e, val, "val");
+        Ok(())
     }
 
     #[inline]
@@ -1,3 +1,5 @@
+use crate::reader::driver::invalid_attribute_error;
+use crate::XlsxError;
 #[derive(Clone, Default, Debug, Eq, Ord, PartialEq, PartialOrd)]
 pub struct BooleanValue {
     value: Option<bool>,
@@ -28,6 +30,20 @@ impl BooleanValue {
         self.set_value(matches!(value.into().as_str(), "true" | "1"))
     }
 
+    #[inline]
+    pub(crate) fn try_set_value_string<S: Into<String>>(
+        &mut self,
+        value: S,
+        name: &str,
+    ) -> Result<&mut Self, XlsxError> {
+        let value = value.into();
+        match value.as_str() {
+            "true" | "1" => Ok(self.set_value(true)),
+            "false" | "0" => Ok(self.set_value(false)),
+            _ => Err(invalid_attribute_error(name, value)),
+        }
+    }
+
     #[inline]
     pub(crate) fn has_value(&self) -> bool {
         self.value.is_some()
@@ -4,6 +4,7 @@ use super::Color;
 use super::EnumValue;
 use crate::reader::driver::*;
 use crate::writer::driver::*;
+use crate::XlsxError;
 use md5::Digest;
 use quick_xml::events::{BytesStart, Event};
 use quick_xml::Reader;
@@ -91,36 +92,33 @@ impl Border {
         reader: &mut Reader<R>,
         e: &BytesStart,
         empty_flg: bool,
-    ) {
+    ) -> Result<(), XlsxError> {
         set_string_from_xml!(self, e, style, "style");
 
         if empty_flg {
-            return;
+            return Ok(());
         }
 
         xml_read_loop!(
             reader,
             Event::Empty(ref e) => {
                 if e.name().into_inner() == b"color" {
-                    self.color.set_attributes(reader, e, true);
+                    self.color.set_attributes(reader, e, true)?;
                 }
             },
             Event::End(ref e) => {
                 match e.name().into_inner() {
-                    b"left" => return,
-                    b"right" => return,
-                    b"top" => return,
-                    b"bottom" => return,
-                    b"diagonal" => return,
-                    b"vertical" => return,
-                    b"horizontal" => return,
+                    b"left" => return Ok(()),
+                    b"right" => return Ok(()),
+                    b"top" => return Ok(()),
+                    b"bottom" => return Ok(()),
+                    b"diagonal" => return Ok(()),
+                    b"vertical" => return Ok(()),
+                    b"horizontal" => return Ok(()),
                     _ => (),
                 }
             },
-            Event::Eof => panic!(
-                "Error: Could not find {} end element",
-                "left,right,top,bottom,diagonal,vertical,horizontal"
-            )
+            Event::Eof => return Err(end_element_error(reader, "left,right,top,bottom,diagonal,vertical,horizontal"))
         );
     }
 
@@ -3,6 +3,7 @@ use super::BooleanValue;
 use super::Border;
 use crate::reader::driver::*;
 use crate::writer::driver::*;
+use crate::XlsxError;
 use md5::Digest;
 use quick_xml::events::{BytesStart, Event};
 use quick_xml::Reader;
@@ -291,7 +292,7 @@ impl Borders {
         &mut self,
         reader: &mut Reader<R>,
         e: &BytesStart,
-    ) {
+    ) -> Result<(), XlsxError> {
         set_string_from_xml!(self, e, diagonal_up, "diagonalUp");
         set_string_from_xml!(self, e, diagonal_down, "diagonalDown");
 
@@ -300,25 +301,25 @@ impl Borders {
             Event::Empty(ref e) => {
                 match e.name().into_inner() {
                     b"left" => {
-                        self.left_border.set_attributes(reader, e, true);
+                        self.left_border.set_attributes(reader, e, true)?;
                     }
                     b"right" => {
-                        self.right_border.set_attributes(reader, e, true);
+                        self.right_border.set_attributes(reader, e, true)?;
                     }
                     b"top" => {
-                        self.top_border.set_attributes(reader, e, true);
+                        self.top_border.set_attributes(reader, e, true)?;
                     }
                     b"bottom" => {
-                        self.bottom_border.set_attributes(reader, e, true);
+                        self.bottom_border.set_attributes(reader, e, true)?;
                     }
                     b"diagonal" => {
-                        self.diagonal_border.set_attributes(reader, e, true);
+                        self.diagonal_border.set_attributes(reader, e, true)?;
                     }
                     b"vertical" => {
-                        self.vertical_border.set_attributes(reader, e, true);
+                        self.vertical_border.set_attributes(reader, e, true)?;
                     }
                     b"horizontal" => {
-                        self.horizontal_border.set_attributes(reader, e, true);
+                        self.horizontal_border.set_attributes(reader, e, true)?;
                     }
                     _ => (),
                 }
@@ -326,35 +327,35 @@ impl Borders {
             Event::Start(ref e) => {
                 match e.name().into_inner() {
                     b"left" => {
-                        self.left_border.set_attributes(reader, e, false);
+                        self.left_border.set_attributes(reader, e, false)?;
                     }
                     b"right" => {
-                        self.right_border.set_attributes(reader, e, false);
+                        self.right_border.set_attributes(reader, e, false)?;
                     }
                     b"top" => {
-                        self.top_border.set_attributes(reader, e, false);
+                        self.top_border.set_attributes(reader, e, false)?;
                     }
                     b"bottom" => {
-                        self.bottom_border.set_attributes(reader, e, false);
+                        self.bottom_border.set_attributes(reader, e, false)?;
                     }
                     b"diagonal" => {
-                        self.diagonal_border.set_attributes(reader, e, false);
+                        self.diagonal_border.set_attributes(reader, e, false)?;
                     }
                     b"vertical" => {
-                        self.vertical_border.set_attributes(reader, e, false);
+                        self.vertical_border.set_attributes(reader, e, false)?;
                     }
                     b"horizontal" => {
-                        self.horizontal_border.set_attributes(reader, e, false);
+                        self.horizontal_border.set_attributes(reader, e, false)?;
                     }
                     _ => (),
                 }
             },
             Event::End(ref e) => {
                 if e.name().into_inner() == b"border" {
-                    return
+                    return Ok(())
                 }
             },
-            Event::Eof => panic!("Error: Could not find {} end element", "border")
+            Event::Eof => return Err(end_element_error(reader, "border"))
         );
     }
 
@@ -3,6 +3,7 @@ use super::Borders;
 use super::Style;
 use crate::reader::driver::*;
 use crate::writer::driver::*;
+use crate::XlsxError;
 use quick_xml::events::{BytesStart, Event};
 use quick_xml::Reader;
 use quick_xml::Writer;
@@ -53,7 +54,7 @@ impl BordersCrate {
         &mut self,
         reader: &mut Reader<R>,
         _e: &BytesStart,
-    ) {
+    ) -> Result<(), XlsxError> {
         xml_read_loop!(
             reader,
             Event::Empty(ref e) => {
@@ -65,16 +66,16 @@ impl BordersCrate {
             Event::Start(ref e) => {
                 if e.name().into_inner() == b"border" {
                     let mut obj = Borders::default();
-                    obj.set_attributes(reader, e);
+                    obj.set_attributes(reader, e)?;
                     self.set_borders(obj);
                 }
             },
             Event::End(ref e) => {
                 if e.name().into_inner() == b"borders" {
-                    return
+                    return Ok(())
                 }
             },
-            Event::Eof => panic!("Error: Could not find {} end element", "borders")
+            Event::Eof => return Err(end_element_error(reader, "borders"))
         );
     }
 
@@ -3,6 +3,7 @@ use crate::reader::driver::*;
 use crate::structs::BooleanValue;
 use crate::structs::UInt32Value;
 use crate::writer::driver::*;
+use crate::XlsxError;
 use quick_xml::events::BytesStart;
 use quick_xml::Reader;
 use quick_xml::Writer;
@@ -55,11 +56,12 @@ impl Break {
         &mut self,
         _reader: &mut Reader<R>,
         e: &BytesStart,
-    ) {
+    ) -> Result<(), XlsxError> {
         set_string_from_xml!(self, e, id, "id");
         set_string_from_xml!(self, e, max, "max");
         set_string_from_xml!(self, e, min, "min");
         set_string_from_xml!(self, e, manual_page_break, "man");
+        Ok(())
     }
 
     pub(crate) fn write_to(&self, writer: &mut Writer<Cursor<Vec<u8>>>) {
@@ -1,4 +1,6 @@
-#[derive(Clone, Default, Debug)]
+use crate::reader::driver::invalid_attribute_error;
+use crate::XlsxError;
+#[derive(Clone, Default, Debug, Eq, Ord, PartialEq, PartialOrd)]
 pub struct ByteValue {
     value: Option<u8>,
 }
@@ -23,8 +25,16 @@ impl ByteValue {
     }
 
     #[inline]
-    pub(crate) fn set_value_string<S: Into<String>>(&mut self, value: S) -> &mut ByteValue {
-        self.set_value(value.into().parse::<u8>().unwrap())
+    pub(crate) fn try_set_value_string<S: Into<String>>(
+        &mut self,
+        value: S,
+        name: &str,
+    ) -> Result<&mut ByteValue, XlsxError> {
+        let value = value.into();
+        match value.parse::<u8>() {
+            Ok(v) => Ok(self.set_value(v)),
+            Err(_) => Err(invalid_attribute_error(name, value)),
+        }
     }
 
     #[inline]
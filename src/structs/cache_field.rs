@@ -4,6 +4,7 @@ use crate::structs::SharedItems;
 use crate::structs::StringValue;
 use crate::structs::UInt32Value;
 use crate::writer::driver::*;
+use crate::XlsxError;
 use quick_xml::events::{BytesStart, Event};
 use quick_xml::Reader;
 use quick_xml::Writer;
@@ -51,7 +52,7 @@ impl CacheField {
         &mut self,
         reader: &mut Reader<R>,
         e: &BytesStart,
-    ) {
+    ) -> Result<(), XlsxError> {
         set_string_from_xml!(self, e, name, "name");
         set_string_from_xml!(self, e, number_format_id, "numFmtId");
 
@@ -60,24 +61,31 @@ impl CacheField {
             Event::Empty(ref e) => {
                 if e.name().into_inner() == b"sharedItems" {
                     let mut obj = SharedItems::default();
-                    obj.set_attributes(reader, e);
+                    obj.set_attributes(reader, e, true)?;
+                    self.set_shared_items(obj);
+                }
+            },
+            Event::Start(ref e) => {
+                if e.name().into_inner() == b"sharedItems" {
+                    let mut obj = SharedItems::default();
+                    obj.set_attributes(reader, e, false)?;
                     self.set_shared_items(obj);
                 }
             },
             Event::End(ref e) => {
                 if e.name().into_inner() == b"cacheField" {
-                    return
+                    return Ok(())
                 }
             },
-            Event::Eof => panic!("Error: Could not find {} end element", "cacheField")
+            Event::Eof => return Err(end_element_error(reader, "cacheField"))
         );
     }
 
     pub(crate) fn write_to(&self, writer: &mut Writer<Cursor<Vec<u8>>>) {
-        // pivotField
+        // cacheField
         write_start_tag(
             writer,
-            "pivotField",
+            "cacheField",
             vec![
                 ("name", self.name.get_value_str()),
                 ("numFmtId", &self.number_format_id.get_value_string()),
@@ -88,6 +96,6 @@ impl CacheField {
         // sharedItems
         self.shared_items.write_to(writer);
 
-        write_end_tag(writer, "pivotField");
+        write_end_tag(writer, "cacheField");
     }
 }
@@ -6,6 +6,7 @@ use crate::structs::CacheField;
 use crate::structs::StringValue;
 use crate::structs::UInt32Value;
 use crate::writer::driver::*;
+use crate::XlsxError;
 use quick_xml::events::{BytesStart, Event};
 use quick_xml::Reader;
 use quick_xml::Writer;
@@ -33,22 +34,22 @@ impl CacheFields {
         &mut self,
         reader: &mut Reader<R>,
         e: &BytesStart,
-    ) {
+    ) -> Result<(), XlsxError> {
         xml_read_loop!(
             reader,
             Event::Start(ref e) => {
                 if e.name().into_inner() == b"cacheField" {
                     let mut obj = CacheField::default();
-                    obj.set_attributes(reader, e);
+                    obj.set_attributes(reader, e)?;
                     self.add_list_mut(obj);
                 }
             },
             Event::End(ref e) => {
                 if e.name().into_inner() == b"cacheFields" {
-                    return
+                    return Ok(())
                 }
             },
-            Event::Eof => panic!("Error: Could not find {} end element", "cacheFields")
+            Event::Eof => return Err(end_element_error(reader, "cacheFields"))
         );
     }
 
@@ -0,0 +1,127 @@
+// s, n, b, e, d, m, x
+use crate::reader::driver::*;
+use crate::writer::driver::*;
+use crate::XlsxError;
+use quick_xml::events::BytesStart;
+use quick_xml::Writer;
+use std::io::Cursor;
+
+/// A value of a pivot cache field.
+/// `Index` (`x`) only appears in cache records and points into the shared items of the field.
+#[derive(Clone, Debug, PartialEq, PartialOrd)]
+pub enum CacheItemValue {
+    String(Box<str>),
+    Number(f64),
+    Boolean(bool),
+    Error(Box<str>),
+    DateTime(Box<str>),
+    Missing,
+    Index(u32),
+}
+impl Default for CacheItemValue {
+    #[inline]
+    fn default() -> Self {
+        Self::Missing
+    }
+}
+impl CacheItemValue {
+    #[inline]
+    pub fn get_number(&self) -> Option<f64> {
+        match self {
+            Self::Number(v) => Some(*v),
+            _ => None,
+        }
+    }
+
+    #[inline]
+    pub fn is_missing(&self) -> bool {
+        matches!(self, Self::Missing)
+    }
+
+    /// Text shown for the item in the rendered pivot table.
+    pub fn get_caption(&self) -> String {
+        match self {
+            Self::String(v) | Self::Error(v) | Self::DateTime(v) => v.to_string(),
+            Self::Number(v) => v.to_string(),
+            Self::Boolean(v) => String::from(if *v { "TRUE" } else { "FALSE" }),
+            Self::Missing => String::from("(blank)"),
+            Self::Index(v) => v.to_string(),
+        }
+    }
+
+    /// Key used to find equal items while building shared items.
+    pub(crate) fn get_hash_string(&self) -> String {
+        match self {
+            Self::String(v) => format!("s:{}", v.to_lowercase()),
+            Self::Number(v) => format!("n:{}", v),
+            Self::Boolean(v) => format!("b:{}", v),
+            Self::Error(v) => format!("e:{}", v),
+            Self::DateTime(v) => format!("d:{}", v),
+            Self::Missing => String::from("m"),
+            Self::Index(v) => format!("x:{}", v),
+        }
+    }
+
+    /// Ordering used for the items of an axis field: numbers, text, booleans, errors, then blanks.
+    pub(crate) fn cmp_item(&self, other: &Self) -> std::cmp::Ordering {
+        fn rank(value: &CacheItemValue) -> u8 {
+            match value {
+                CacheItemValue::Number(_) | CacheItemValue::Index(_) => 0,
+                CacheItemValue::DateTime(_) => 1,
+                CacheItemValue::String(_) => 2,
+                CacheItemValue::Boolean(_) => 3,
+                CacheItemValue::Error(_) => 4,
+                CacheItemValue::Missing => 5,
+            }
+        }
+        match (self, other) {
+            (Self::Number(a), Self::Number(b)) => a.total_cmp(b),
+            (Self::String(a), Self::String(b)) => a.to_lowercase().cmp(&b.to_lowercase()),
+            (Self::DateTime(a), Self::DateTime(b)) | (Self::Error(a), Self::Error(b)) => a.cmp(b),
+            (Self::Boolean(a), Self::Boolean(b)) => a.cmp(b),
+            (Self::Index(a), Self::Index(b)) => a.cmp(b),
+            _ => rank(self).cmp(&rank(other)),
+        }
+    }
+
+    pub(crate) fn set_attributes(e: &BytesStart) -> Result<Option<Self>, XlsxError> {
+        let value = get_attribute(e, b"v")?;
+        let result = match e.name().into_inner() {
+            b"s" => Self::String(value.unwrap_or_default().into_boxed_str()),
+            b"n" => {
+                let value = value.unwrap_or_default();
+                match value.parse::<f64>() {
+                    Ok(v) => Self::Number(v),
+                    Err(_) => return Err(invalid_attribute_error("v", value)),
+                }
+            }
+            b"b" => Self::Boolean(matches!(value.as_deref(), Some("1") | Some("true"))),
+            b"e" => Self::Error(value.unwrap_or_default().into_boxed_str()),
+            b"d" => Self::DateTime(value.unwrap_or_default().into_boxed_str()),
+            b"m" => Self::Missing,
+            b"x" => {
+                let value = value.unwrap_or_else(|| String::from("0"));
+                match value.parse::<u32>() {
+                    Ok(v) => Self::Index(v),
+                    Err(_) => return Err(invalid_attribute_error("v", value)),
+                }
+            }
+            _ => return Ok(None),
+        };
+        Ok(Some(result))
+    }
+
+    pub(crate) fn write_to(&self, writer: &mut Writer<Cursor<Vec<u8>>>) {
+        match self {
+            Self::String(v) => write_start_tag(writer, "s", vec![("v", v.as_ref())], true),
+            Self::Number(v) => write_start_tag(writer, "n", vec![("v", &v.to_string())], true),
+            Self::Boolean(v) => {
+                write_start_tag(writer, "b", vec![("v", if *v { "1" } else { "0" })], true)
+            }
+            Self::Error(v) => write_start_tag(writer, "e", vec![("v", v.as_ref())], true),
+            Self::DateTime(v) => write_start_tag(writer, "d", vec![("v", v.as_ref())], true),
+            Self::Missing => write_start_tag(writer, "m", vec![], true),
+            Self::Index(v) => write_start_tag(writer, "x", vec![("v", &v.to_string())], true),
+        }
+    }
+}
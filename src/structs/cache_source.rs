@@ -2,6 +2,7 @@
 use crate::structs::EnumValue;
 use crate::structs::SourceValues;
 use crate::structs::WorksheetSource;
+use crate::XlsxError;
 
 use crate::helper::const_str::*;
 use crate::reader::driver::*;
@@ -45,11 +46,11 @@ impl CacheSource {
         reader: &mut Reader<R>,
         e: &BytesStart,
         empty_flg: bool,
-    ) {
+    ) -> Result<(), XlsxError> {
         set_string_from_xml!(self, e, r#type, "type");
 
         if empty_flg {
-            return;
+            return Ok(());
         }
 
         xml_read_loop!(
@@ -57,16 +58,16 @@ impl CacheSource {
             Event::Empty(ref e) => {
                 if e.name().into_inner() == b"worksheetSource" {
                     let mut obj = WorksheetSource::default();
-                    obj.set_attributes(reader, e);
+                    obj.set_attributes(reader, e)?;
                     self.set_worksheet_source_mut(obj);
                 }
             },
             Event::End(ref e) => {
                 if e.name().into_inner() == b"cacheSource" {
-                    return
+                    return Ok(())
                 }
             },
-            Event::Eof => panic!("Error: Could not find {} end element", "cacheSource")
+            Event::Eof => return Err(end_element_error(reader, "cacheSource"))
         );
     }
 
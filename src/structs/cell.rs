@@ -1,7 +1,10 @@
 use crate::helper::coordinate::*;
+use crate::helper::date::*;
+use crate::helper::font_metrics::{layout_text, TextExtent, TextFont};
 use crate::helper::formula::*;
 use crate::helper::number_format::*;
 use crate::reader::driver::*;
+use crate::structs::CellErrorType;
 use crate::structs::CellFormula;
 use crate::structs::CellFormulaValues;
 use crate::structs::CellRawValue;
@@ -18,6 +21,8 @@ use crate::structs::UInt32Value;
 use crate::traits::AdjustmentCoordinate;
 use crate::traits::AdjustmentCoordinateWith2Sheet;
 use crate::writer::driver::*;
+use crate::XlsxError;
+use chrono::{Duration, NaiveDate, NaiveDateTime, NaiveTime, Timelike};
 use quick_xml::events::{BytesStart, Event};
 use quick_xml::Reader;
 use quick_xml::Writer;
@@ -142,6 +147,24 @@ impl Cell {
         self.cell_value.get_value_number()
     }
 
+    /// Get the date and time of a number shown with a date or time format.
+    /// Serial numbers are read in the 1900 date system, to the millisecond.
+    /// Use `Worksheet::get_value_datetime` for workbooks that use the 1904 date system.
+    /// # Return value
+    /// * `Option<NaiveDateTime>` - None if the cell is not a number with a date format,
+    ///   or if it is the non-existent 1900-02-29.
+    #[inline]
+    pub fn get_value_datetime(&self) -> Option<NaiveDateTime> {
+        self.get_value_datetime_crate(false)
+    }
+
+    pub(crate) fn get_value_datetime_crate(&self, date1904: bool) -> Option<NaiveDateTime> {
+        if !self.style.get_number_format()?.is_date_format() {
+            return None;
+        }
+        excel_to_date_time_crate(self.get_value_number()?, !date1904)
+    }
+
     #[inline]
     pub fn get_value_lazy(&mut self) -> Cow<'static, str> {
         self.cell_value.get_value_lazy()
@@ -206,6 +229,87 @@ impl Cell {
         self
     }
 
+    /// Set a date and time as a serial number in the 1900 date system.
+    /// A date format is applied unless the cell already has one.
+    /// Use `Worksheet::set_value_datetime` for workbooks that use the 1904 date system.
+    /// # Examples
+    /// ```
+    /// use chrono::NaiveDate;
+    /// let mut book = umya_spreadsheet::new_file();
+    /// let worksheet = book.get_sheet_mut(&0).unwrap();
+    /// let value = NaiveDate::from_ymd_opt(2024, 1, 31)
+    ///     .unwrap()
+    ///     .and_hms_milli_opt(12, 30, 15, 250)
+    ///     .unwrap();
+    /// let cell = worksheet.get_cell_mut("A1");
+    /// cell.set_value_datetime(value);
+    /// assert_eq!(cell.get_value_datetime(), Some(value));
+    /// ```
+    #[inline]
+    pub fn set_value_datetime(&mut self, value: NaiveDateTime) -> &mut Self {
+        self.set_value_datetime_crate(value, false)
+    }
+
+    pub(crate) fn set_value_datetime_crate(
+        &mut self,
+        value: NaiveDateTime,
+        date1904: bool,
+    ) -> &mut Self {
+        self.set_value_number(date_time_to_excel_crate(&value, !date1904));
+        // m/d/yyyy h:mm
+        self.set_default_date_format(22)
+    }
+
+    /// Set a date as a serial number in the 1900 date system.
+    /// A date format is applied unless the cell already has one.
+    /// Use `Worksheet::set_value_date` for workbooks that use the 1904 date system.
+    #[inline]
+    pub fn set_value_date(&mut self, value: NaiveDate) -> &mut Self {
+        self.set_value_date_crate(value, false)
+    }
+
+    pub(crate) fn set_value_date_crate(&mut self, value: NaiveDate, date1904: bool) -> &mut Self {
+        self.set_value_number(date_time_to_excel_crate(
+            &value.and_time(NaiveTime::MIN),
+            !date1904,
+        ));
+        // m/d/yyyy
+        self.set_default_date_format(14)
+    }
+
+    /// Set a time of day as the fraction of a day.
+    /// A time format is applied unless the cell already has a date format.
+    pub fn set_value_time(&mut self, value: NaiveTime) -> &mut Self {
+        let seconds = value.num_seconds_from_midnight() as f64
+            + value.nanosecond().min(999_999_999) as f64 / 1e9;
+        self.set_value_number(seconds / 86400f64);
+        // h:mm:ss
+        self.set_default_date_format(21)
+    }
+
+    /// Set a duration as a number of days.
+    /// An elapsed time format is applied unless the cell already has a date format.
+    pub fn set_value_duration(&mut self, value: Duration) -> &mut Self {
+        let seconds = value.num_milliseconds() as f64 / 1000f64;
+        self.set_value_number(seconds / 86400f64);
+        // [h]:mm:ss
+        self.set_default_date_format(46)
+    }
+
+    fn set_default_date_format(&mut self, number_format_id: u32) -> &mut Self {
+        let is_date_format = self
+            .style
+            .get_number_format()
+            .map(|v| v.is_date_format())
+            .unwrap_or(false);
+        if !is_date_format {
+            self.style
+                .get_number_format_mut()
+                .set_number_format_id(number_format_id);
+        }
+        self
+    }
+
     #[inline]
     pub fn set_rich_text(&mut self, value: RichText) -> &mut Self {
         self.cell_value.set_rich_text(value);
@@ -282,48 +386,56 @@ impl Cell {
         None
     }
 
-    pub(crate) fn get_width_point(&self, column_font_size: &f64) -> f64 {
-        // get cell value len.
-        let char_cnt = self.get_width_point_cell();
-
-        // get font size.
-        let font_size = match self.get_style().get_font() {
-            Some(font) => font.get_font_size().get_val(),
-            None => column_font_size,
+    /// Size of the formatted value in pixels, laid out with the font and alignment of the cell.
+    /// # Arguments
+    /// * `default_font` - Font of the text when the style has none.
+    /// * `wrap_width` - Width of the text area for wrapped text.
+    pub(crate) fn get_text_extent(&self, default_font: &TextFont, wrap_width: f64) -> TextExtent {
+        let font = default_font.with_font(self.get_style().get_font());
+        let (wrap_width, rotation) = match self.get_style().get_alignment() {
+            Some(v) => (
+                v.get_wrap_text().then_some(wrap_width),
+                *v.get_text_rotation(),
+            ),
+            None => (None, 0),
         };
-
-        let mut column_width = 1.4 * char_cnt;
-        column_width = column_width * font_size / 11f64;
-
-        column_width
-    }
-
-    pub(crate) fn get_width_point_cell(&self) -> f64 {
-        let value = self.get_formatted_value();
-
-        value.split('\n').fold(0f64, |mut acc, value| {
-            let mut point = 0f64;
-            for chr in value.chars() {
-                let clen = if chr.len_utf8() > 1 { 1.5 } else { 1.0 };
-
-                point += clen;
-            }
-            if point > acc {
-                acc = point;
+        match self.get_raw_value() {
+            CellRawValue::RichText(v) => {
+                let elements = v.get_rich_text_elements();
+                let fonts: Vec<TextFont> = elements
+                    .iter()
+                    .map(|element| font.with_font(element.get_run_properties()))
+                    .collect();
+                let runs: Vec<(&str, &TextFont)> = elements
+                    .iter()
+                    .map(|element| element.get_text())
+                    .zip(fonts.iter())
+                    .collect();
+                layout_text(&runs, wrap_width, rotation)
             }
-            acc
-        })
+            _ => layout_text(
+                &[(&self.get_formatted_value(), &font)],
+                wrap_width,
+                rotation,
+            ),
+        }
     }
 
+    /// Get the value converted with the number format of the cell style.
+    /// Dates are read in the 1900 date system.
+    /// `Worksheet::get_formatted_value` follows the date system of the workbook.
+    #[inline]
     pub fn get_formatted_value(&self) -> String {
-        let value = self.get_value();
+        self.get_formatted_value_crate(false)
+    }
 
-        // convert value
-        let result = match self.get_style().get_number_format() {
-            Some(nmuber_format) => to_formatted_string(&value, nmuber_format.get_format_code()),
-            None => to_formatted_string(&value, NumberingFormat::FORMAT_GENERAL),
+    pub(crate) fn get_formatted_value_crate(&self, date1904: bool) -> String {
+        let format_code = match self.get_style().get_number_format() {
+            Some(v) => v.get_format_code(),
+            None => NumberingFormat::FORMAT_GENERAL,
         };
-        result
+        self.cell_value
+            .get_formatted_value_crate(format_code, date1904)
     }
 
     // When opened in software such as Excel, it is visually blank.
@@ -350,39 +462,41 @@ impl Cell {
         stylesheet: &Stylesheet,
         empty_flag: bool,
         formula_shared_list: &mut HashMap<u32, (String, Vec<FormulaToken>)>,
-    ) {
+    ) -> Result<(), XlsxError> {
         let mut type_value: String = String::new();
         let mut cell_reference: String = String::new();
 
-        if let Some(v) = get_attribute(e, b"r") {
+        if let Some(v) = get_attribute(e, b"r")? {
             cell_reference = v;
             self.coordinate.set_coordinate(&cell_reference);
         }
 
-        if let Some(v) = get_attribute(e, b"s") {
-            let style = stylesheet.get_style(v.parse::<usize>().unwrap());
+        if let Some(v) = get_attribute(e, b"s")? {
+            let style = stylesheet
+                .get_style(parse_value("s", &v)?)
+                .ok_or_else(|| invalid_attribute_error("s", v))?;
             self.set_style(style);
         }
 
-        if let Some(v) = get_attribute(e, b"t") {
+        if let Some(v) = get_attribute(e, b"t")? {
             type_value = v;
         }
 
         set_string_from_xml!(self, e, cell_meta_index, "cm");
 
         if empty_flag {
-            return;
+            return Ok(());
         }
 
         let mut string_value: String = String::new();
         let mut buf = Vec::new();
         loop {
             match reader.read_event_into(&mut buf) {
-                Ok(Event::Text(e)) => string_value = e.unescape().unwrap().to_string(),
+                Ok(Event::Text(e)) => string_value = e.unescape()?.to_string(),
                 Ok(Event::Start(ref e)) => match e.name().into_inner() {
                     b"f" => {
                         let mut obj = CellFormula::default();
-                        obj.set_attributes(reader, e, false, &cell_reference, formula_shared_list);
+                        obj.set_attributes(reader, e, false, &cell_reference, formula_shared_list)?;
                         self.cell_value.set_formula_obj(obj);
                     }
                     b"t" => {
@@ -399,7 +513,7 @@ impl Cell {
                 Ok(Event::Empty(ref e)) => {
                     if e.name().into_inner() == b"f" {
                         let mut obj = CellFormula::default();
-                        obj.set_attributes(reader, e, true, &cell_reference, formula_shared_list);
+                        obj.set_attributes(reader, e, true, &cell_reference, formula_shared_list)?;
                         self.cell_value.set_formula_obj(obj);
                     }
                 }
@@ -409,11 +523,10 @@ impl Cell {
                             self.set_value_string_crate(&string_value);
                         }
                         "s" => {
-                            let index = string_value.parse::<usize>().unwrap();
                             let shared_string_item = shared_string_table
                                 .get_shared_string_item()
-                                .get(index)
-                                .unwrap();
+                                .get(parse_value::<usize>("v", &string_value)?)
+                                .ok_or_else(|| invalid_attribute_error("v", &string_value))?;
                             self.set_shared_string_item(shared_string_item.clone());
                         }
                         "b" => {
@@ -430,17 +543,17 @@ impl Cell {
                     },
                     b"is" => {
                         if type_value == "inlineStr" {
-                            self.set_value_crate(&string_value);
+                            self.set_value_string_crate(&string_value);
                         }
                     }
-                    b"c" => return,
+                    b"c" => return Ok(()),
                     b"t" => {
                         reader.config_mut().trim_text(true);
                     }
                     _ => (),
                 },
-                Ok(Event::Eof) => panic!("Error: Could not find {} end element", "c"),
-                Err(e) => panic!("Error at position {}: {:?}", reader.buffer_position(), e),
+                Ok(Event::Eof) => return Err(end_element_error(reader, "c")),
+                Err(e) => return Err(xml_error(reader, e)),
                 _ => (),
             }
             buf.clear();
@@ -522,7 +635,11 @@ impl Cell {
                     write_text_node(writer, prm);
                 }
                 "e" => {
-                    let prm = "#VALUE!";
+                    let prm = match self.get_raw_value() {
+                        CellRawValue::Error(CellErrorType::Data) => "#VALUE!".into(),
+                        CellRawValue::Error(e) => e.to_string(),
+                        _ => "#VALUE!".into(),
+                    };
                     write_text_node(writer, prm);
                 }
                 _ => write_text_node_conversion(writer, self.get_value()),
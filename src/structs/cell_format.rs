@@ -5,6 +5,7 @@ use super::Protection;
 use super::UInt32Value;
 use crate::reader::driver::*;
 use crate::writer::driver::*;
+use crate::XlsxError;
 use md5::Digest;
 use quick_xml::events::{BytesStart, Event};
 use quick_xml::Reader;
@@ -217,7 +218,7 @@ impl CellFormat {
         reader: &mut Reader<R>,
         e: &BytesStart,
         empty_flag: bool,
-    ) {
+    ) -> Result<(), XlsxError> {
         set_string_from_xml!(self, e, number_format_id, "numFmtId");
         set_string_from_xml!(self, e, font_id, "fontId");
         set_string_from_xml!(self, e, fill_id, "fillId");
@@ -230,7 +231,7 @@ impl CellFormat {
         set_string_from_xml!(self, e, apply_protection, "applyProtection");
 
         if empty_flag {
-            return;
+            return Ok(());
         }
 
         xml_read_loop!(
@@ -239,12 +240,12 @@ impl CellFormat {
                 match e.name().into_inner(){
                     b"alignment" =>{
                         let mut obj = Alignment::default();
-                        obj.set_attributes(reader, e);
+                        obj.set_attributes(reader, e)?;
                         self.set_alignment(obj);
                     },
                     b"protection" =>{
                         let mut obj = Protection::default();
-                        obj.set_attributes(reader, e);
+                        obj.set_attributes(reader, e)?;
                         self.set_protection(obj);
                     },
                     _ =>{}
@@ -252,10 +253,10 @@ impl CellFormat {
             },
             Event::End(ref e) => {
                 if e.name().into_inner() == b"xf" {
-                    return
+                    return Ok(())
                 }
             },
-            Event::Eof => panic!("Error: Could not find {} end element", "xf")
+            Event::Eof => return Err(end_element_error(reader, "xf"))
         );
     }
 
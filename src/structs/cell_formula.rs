@@ -8,6 +8,7 @@ use crate::structs::StringValue;
 use crate::structs::UInt32Value;
 use crate::traits::AdjustmentCoordinateWith2Sheet;
 use crate::writer::driver::*;
+use crate::XlsxError;
 use quick_xml::de;
 use quick_xml::events::{BytesStart, Event};
 use quick_xml::Reader;
@@ -167,7 +168,7 @@ impl CellFormula {
         is_empty: bool,
         cell_reference_str: &str,
         formula_shared_list: &mut HashMap<u32, (String, Vec<FormulaToken>)>,
-    ) {
+    ) -> Result<(), XlsxError> {
         set_string_from_xml!(self, e, bx, "bx");
         set_string_from_xml!(self, e, data_table_2d, "dt2D");
         set_string_from_xml!(self, e, data_table_row, "dtr");
@@ -183,14 +184,14 @@ impl CellFormula {
             xml_read_loop!(
                 reader,
                 Event::Text(e) => {
-                    self.text.set_value(e.unescape().unwrap().to_string());
+                    self.text.set_value(e.unescape()?.to_string());
                 },
                 Event::End(ref e) => {
                     if e.name().into_inner() == b"f" {
                         break;
                     }
                 },
-                Event::Eof => panic!("Error: Could not find {} end element", "f")
+                Event::Eof => return Err(end_element_error(reader, "f"))
             );
         }
 
@@ -234,6 +235,7 @@ impl CellFormula {
                 }
             }
         }
+        Ok(())
     }
 
     pub(crate) fn write_to(
@@ -3,6 +3,7 @@ use crate::reader::driver::*;
 use crate::structs::StringValue;
 use crate::structs::UInt32Value;
 use crate::writer::driver::*;
+use crate::XlsxError;
 use quick_xml::events::BytesStart;
 use quick_xml::Reader;
 use quick_xml::Writer;
@@ -54,10 +55,11 @@ impl CellStyle {
         &mut self,
         _reader: &mut Reader<R>,
         e: &BytesStart,
-    ) {
+    ) -> Result<(), XlsxError> {
         set_string_from_xml!(self, e, name, "name");
         set_string_from_xml!(self, e, builtin_id, "builtinId");
         set_string_from_xml!(self, e, format_id, "xfId");
+        Ok(())
     }
 
     #[inline]
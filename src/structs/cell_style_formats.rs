@@ -2,6 +2,7 @@
 use super::CellFormat;
 use crate::reader::driver::*;
 use crate::writer::driver::*;
+use crate::XlsxError;
 use quick_xml::events::{BytesStart, Event};
 use quick_xml::Reader;
 use quick_xml::Writer;
@@ -34,29 +35,29 @@ impl CellStyleFormats {
         &mut self,
         reader: &mut Reader<R>,
         _e: &BytesStart,
-    ) {
+    ) -> Result<(), XlsxError> {
         xml_read_loop!(
             reader,
             Event::Empty(ref e) => {
                 if e.name().into_inner() == b"xf" {
                     let mut obj = CellFormat::default();
-                    obj.set_attributes(reader, e, true);
+                    obj.set_attributes(reader, e, true)?;
                     self.set_cell_format(obj);
                 }
             },
             Event::Start(ref e) => {
                 if e.name().into_inner() == b"xf" {
                     let mut obj = CellFormat::default();
-                    obj.set_attributes(reader, e, false);
+                    obj.set_attributes(reader, e, false)?;
                     self.set_cell_format(obj);
                 }
             },
             Event::End(ref e) => {
                 if e.name().into_inner() == b"cellStyleXfs" {
-                    return
+                    return Ok(())
                 }
             },
-            Event::Eof => panic!("Error: Could not find {} end element", "cellStyleXfs")
+            Event::Eof => return Err(end_element_error(reader, "cellStyleXfs"))
         );
     }
 
@@ -2,6 +2,7 @@ use super::RichText;
 use super::SharedStringItem;
 use super::Text;
 use crate::helper::formula::*;
+use crate::helper::number_format::{to_formatted_string_crate, to_formatted_text};
 use crate::structs::CellFormula;
 use crate::structs::CellRawValue;
 use crate::traits::AdjustmentCoordinateWith2Sheet;
@@ -28,7 +29,12 @@ impl CellValue {
     #[inline]
     pub(crate) fn get_data_type_crate(&self) -> &str {
         match &self.formula {
-            Some(_) => "str",
+            Some(_) => match &self.raw_value {
+                CellRawValue::Numeric(_) => "n",
+                CellRawValue::Bool(_) => "b",
+                CellRawValue::Error(_) => "e",
+                _ => "str",
+            },
             None => self.raw_value.get_data_type(),
         }
     }
@@ -38,6 +44,18 @@ impl CellValue {
         self.raw_value.to_string().into()
     }
 
+    /// Value converted with a number format code.
+    /// Texts only use the text section of the format, booleans and errors are shown as they are.
+    pub(crate) fn get_formatted_value_crate(&self, format_code: &str, date1904: bool) -> String {
+        match &self.raw_value {
+            CellRawValue::String(_) | CellRawValue::RichText(_) => {
+                to_formatted_text(self.get_value(), format_code)
+            }
+            CellRawValue::Bool(_) | CellRawValue::Error(_) => self.get_value().to_string(),
+            _ => to_formatted_string_crate(self.get_value(), format_code, date1904),
+        }
+    }
+
     #[inline]
     pub fn get_value_number(&self) -> Option<f64> {
         self.raw_value.get_number()
@@ -183,6 +201,13 @@ impl CellValue {
         self
     }
 
+    /// Store the calculated result of the formula, keeping the formula itself.
+    #[inline]
+    pub(crate) fn set_formula_result(&mut self, value: CellRawValue) -> &mut Self {
+        self.raw_value = value;
+        self
+    }
+
     #[inline]
     pub fn set_error<S: Into<String>>(&mut self, value: S) -> &mut Self {
         self.set_value_crate(value);
@@ -1,32 +1,57 @@
+#[cfg(feature = "image")]
+use crate::helper::chart_render::render_png;
+use crate::helper::chart_render::render_svg;
 use crate::structs::drawing::charts::Area3DChart;
 use crate::structs::drawing::charts::AreaChart;
 use crate::structs::drawing::charts::AreaChartSeries;
 use crate::structs::drawing::charts::AreaChartSeriesList;
 use crate::structs::drawing::charts::AxisId;
 use crate::structs::drawing::charts::AxisPositionValues;
+use crate::structs::drawing::charts::BandFormats;
 use crate::structs::drawing::charts::Bar3DChart;
 use crate::structs::drawing::charts::BarChart;
 use crate::structs::drawing::charts::BarDirectionValues;
+use crate::structs::drawing::charts::BaseTimeUnit;
 use crate::structs::drawing::charts::Bubble3D;
 use crate::structs::drawing::charts::BubbleChart;
 use crate::structs::drawing::charts::BubbleSize;
+use crate::structs::drawing::charts::BuiltInUnit;
+use crate::structs::drawing::charts::BuiltInUnitValues;
 use crate::structs::drawing::charts::CategoryAxis;
 use crate::structs::drawing::charts::CategoryAxisData;
 use crate::structs::drawing::charts::ChartSpace;
 use crate::structs::drawing::charts::ChartText;
 use crate::structs::drawing::charts::CrossBetweenValues;
+use crate::structs::drawing::charts::CrossesValues;
+use crate::structs::drawing::charts::DateAxis;
 use crate::structs::drawing::charts::DisplayBlanksAsValues;
+use crate::structs::drawing::charts::DisplayUnits;
+use crate::structs::drawing::charts::DisplayUnitsLabel;
 use crate::structs::drawing::charts::DoughnutChart;
+use crate::structs::drawing::charts::DownBars;
+use crate::structs::drawing::charts::GapWidth;
 use crate::structs::drawing::charts::GroupingValues;
+use crate::structs::drawing::charts::HighLowLines;
 use crate::structs::drawing::charts::InvertIfNegative;
 use crate::structs::drawing::charts::Layout;
 use crate::structs::drawing::charts::LegendPositionValues;
 use crate::structs::drawing::charts::Line3DChart;
 use crate::structs::drawing::charts::LineChart;
+use crate::structs::drawing::charts::LogBase;
 use crate::structs::drawing::charts::MajorGridlines;
+use crate::structs::drawing::charts::MajorTimeUnit;
+use crate::structs::drawing::charts::MajorUnit;
+use crate::structs::drawing::charts::Marker;
+use crate::structs::drawing::charts::MarkerStyleValues;
+use crate::structs::drawing::charts::MaxAxisValue;
+use crate::structs::drawing::charts::MinAxisValue;
+use crate::structs::drawing::charts::MinorTimeUnit;
+use crate::structs::drawing::charts::MinorUnit;
+use crate::structs::drawing::charts::NumberingFormat;
 use crate::structs::drawing::charts::OfPieChart;
 use crate::structs::drawing::charts::OfPieValues;
 use crate::structs::drawing::charts::OrientationValues;
+use crate::structs::drawing::charts::Perspective;
 use crate::structs::drawing::charts::Pie3DChart;
 use crate::structs::drawing::charts::PieChart;
 use crate::structs::drawing::charts::PlotArea;
@@ -35,32 +60,49 @@ use crate::structs::drawing::charts::RadarChart;
 use crate::structs::drawing::charts::RightAngleAxes;
 use crate::structs::drawing::charts::RotateX;
 use crate::structs::drawing::charts::RotateY;
+use crate::structs::drawing::charts::Scaling;
 use crate::structs::drawing::charts::ScatterChart;
+use crate::structs::drawing::charts::SeriesAxis;
 use crate::structs::drawing::charts::SeriesText;
+use crate::structs::drawing::charts::ShapeProperties;
 use crate::structs::drawing::charts::ShapeValues;
 use crate::structs::drawing::charts::ShowLeaderLines;
 use crate::structs::drawing::charts::Smooth;
+use crate::structs::drawing::charts::StockChart;
 use crate::structs::drawing::charts::StringLiteral;
 use crate::structs::drawing::charts::StringPoint;
 use crate::structs::drawing::charts::StringReference;
+use crate::structs::drawing::charts::Surface3DChart;
+use crate::structs::drawing::charts::SurfaceChart;
+use crate::structs::drawing::charts::Symbol;
 use crate::structs::drawing::charts::TextProperties;
 use crate::structs::drawing::charts::TickLabelPositionValues;
 use crate::structs::drawing::charts::TickMarkValues;
+use crate::structs::drawing::charts::TimeUnitValues;
 use crate::structs::drawing::charts::Title;
+use crate::structs::drawing::charts::UpBars;
+use crate::structs::drawing::charts::UpDownBars;
 use crate::structs::drawing::charts::ValueAxis;
 use crate::structs::drawing::charts::Values;
 use crate::structs::drawing::charts::View3D;
+use crate::structs::drawing::charts::Wireframe;
 use crate::structs::drawing::charts::XValues;
 use crate::structs::drawing::charts::YValues;
 use crate::structs::drawing::spreadsheet::GraphicFrame;
 use crate::structs::drawing::spreadsheet::MarkerType;
 use crate::structs::drawing::spreadsheet::TwoCellAnchor;
+use crate::structs::drawing::NoFill;
+use crate::structs::drawing::Outline;
 use crate::structs::drawing::Paragraph;
 use crate::structs::drawing::Run;
 use crate::structs::drawing::RunProperties;
+use crate::structs::AxisSide;
 use crate::structs::ChartType;
+use crate::structs::Spreadsheet;
 use crate::traits::AdjustmentCoordinate;
 use crate::traits::AdjustmentCoordinateWithSheet;
+#[cfg(feature = "image")]
+use crate::XlsxError;
 
 /// ## Supported chart types
 /// * AreaChart
@@ -75,6 +117,9 @@ use crate::traits::AdjustmentCoordinateWithSheet;
 /// * PieChart
 /// * RadarChart
 /// * ScatterChart
+/// * StockChart
+/// * SurfaceChart
+/// * Surface3DChart
 ///
 /// Other types will be supported sequentially.
 ///
@@ -161,19 +206,22 @@ impl Chart {
 
     pub fn set_vertical_title<S: Into<String>>(&mut self, value: S) -> &mut Self {
         let title = self.make_title(value);
-        let plot_area = self.get_plot_area_mut();
-        match plot_area.get_value_axis_mut().len() {
-            1 => {
-                if let Some(v) = plot_area.get_value_axis_mut().get_mut(0) {
-                    v.set_title(title);
-                }
-            }
-            2 => {
-                if let Some(v) = plot_area.get_value_axis_mut().get_mut(1) {
-                    v.set_title(title);
-                }
-            }
-            _ => {}
+        if let Some(v) = self.get_vertical_axis_mut() {
+            v.set_title(title);
+        }
+        self
+    }
+
+    /// Set the title of the secondary vertical axis of a combo chart.
+    pub fn set_secondary_vertical_title<S: Into<String>>(&mut self, value: S) -> &mut Self {
+        let title = self.make_title(value);
+        if let Some(v) = self
+            .get_plot_area_mut()
+            .get_value_axis_mut()
+            .iter_mut()
+            .find(|v| matches!(v.get_axis_position().get_val(), AxisPositionValues::Right))
+        {
+            v.set_title(title);
         }
         self
     }
@@ -181,28 +229,275 @@ impl Chart {
     pub fn set_horizontal_title<S: Into<String>>(&mut self, value: S) -> &mut Self {
         let title = self.make_title(value);
         let plot_area = self.get_plot_area_mut();
-        match plot_area.get_value_axis_mut().len() {
-            1 => {
-                if let Some(v) = plot_area.get_category_axis_mut().get_mut(0) {
-                    v.set_title(title);
-                }
+        if Self::is_xy_plot_area(plot_area) {
+            if let Some(v) = plot_area.get_value_axis_mut().get_mut(0) {
+                v.set_title(title);
             }
-            2 => {
-                if let Some(v) = plot_area.get_value_axis_mut().get_mut(0) {
-                    v.set_title(title);
-                }
+        } else if let Some(v) = plot_area.get_category_axis_mut().get_mut(0) {
+            v.set_title(title);
+        } else if let Some(v) = plot_area.get_date_axis_mut().get_mut(0) {
+            v.set_title(title);
+        }
+        self
+    }
+
+    /// Set the minimum of the vertical axis.
+    pub fn set_vertical_axis_min(&mut self, value: f64) -> &mut Self {
+        if let Some(v) = self.get_vertical_axis_mut() {
+            let mut obj = MinAxisValue::default();
+            obj.set_val(value);
+            v.get_scaling_mut().set_min_axis_value(obj);
+        }
+        self
+    }
+
+    /// Set the maximum of the vertical axis.
+    pub fn set_vertical_axis_max(&mut self, value: f64) -> &mut Self {
+        if let Some(v) = self.get_vertical_axis_mut() {
+            let mut obj = MaxAxisValue::default();
+            obj.set_val(value);
+            v.get_scaling_mut().set_max_axis_value(obj);
+        }
+        self
+    }
+
+    /// Set the interval of the major tick marks and gridlines of the vertical axis.
+    pub fn set_vertical_axis_major_unit(&mut self, value: f64) -> &mut Self {
+        if let Some(v) = self.get_vertical_axis_mut() {
+            let mut obj = MajorUnit::default();
+            obj.set_val(value);
+            v.set_major_unit(obj);
+        }
+        self
+    }
+
+    /// Set the interval of the minor tick marks of the vertical axis.
+    pub fn set_vertical_axis_minor_unit(&mut self, value: f64) -> &mut Self {
+        if let Some(v) = self.get_vertical_axis_mut() {
+            let mut obj = MinorUnit::default();
+            obj.set_val(value);
+            v.set_minor_unit(obj);
+        }
+        self
+    }
+
+    /// Use a logarithmic scale on the vertical axis.
+    /// # Arguments
+    /// * `value` - Base of the logarithm, between 2 and 1000.
+    pub fn set_vertical_axis_log_base(&mut self, value: f64) -> &mut Self {
+        if let Some(v) = self.get_vertical_axis_mut() {
+            let mut obj = LogBase::default();
+            obj.set_val(value);
+            v.get_scaling_mut().set_log_base(obj);
+        }
+        self
+    }
+
+    /// Show the values of the vertical axis in a unit, with a unit label.
+    pub fn set_vertical_axis_display_units(&mut self, value: BuiltInUnitValues) -> &mut Self {
+        if let Some(v) = self.get_vertical_axis_mut() {
+            let mut built_in_unit = BuiltInUnit::default();
+            built_in_unit.set_val(value);
+            let mut obj = DisplayUnits::default();
+            obj.set_built_in_unit(built_in_unit);
+            obj.set_display_units_label(DisplayUnitsLabel::default());
+            v.set_display_units(obj);
+        }
+        self
+    }
+
+    /// Set the number format of the vertical axis labels.
+    pub fn set_vertical_axis_number_format<S: Into<String>>(&mut self, value: S) -> &mut Self {
+        if let Some(v) = self.get_vertical_axis_mut() {
+            v.get_numbering_format_mut()
+                .set_format_code(value)
+                .set_source_linked(false);
+        }
+        self
+    }
+
+    /// Set the minimum of the horizontal axis.
+    /// Dates are given as serial numbers.
+    pub fn set_horizontal_axis_min(&mut self, value: f64) -> &mut Self {
+        if let Some(v) = self.get_horizontal_scaling_mut() {
+            let mut obj = MinAxisValue::default();
+            obj.set_val(value);
+            v.set_min_axis_value(obj);
+        }
+        self
+    }
+
+    /// Set the maximum of the horizontal axis.
+    /// Dates are given as serial numbers.
+    pub fn set_horizontal_axis_max(&mut self, value: f64) -> &mut Self {
+        if let Some(v) = self.get_horizontal_scaling_mut() {
+            let mut obj = MaxAxisValue::default();
+            obj.set_val(value);
+            v.set_max_axis_value(obj);
+        }
+        self
+    }
+
+    /// Replace the category axis by a date axis.
+    /// # Arguments
+    /// * `base_time_unit` - Unit the dates of the series are grouped by.
+    /// # Examples
+    /// ```
+    /// use umya_spreadsheet::*;
+    /// use umya_spreadsheet::drawing::charts::TimeUnitValues;
+    ///
+    /// let mut from_marker = structs::drawing::spreadsheet::MarkerType::default();
+    /// let mut to_marker = structs::drawing::spreadsheet::MarkerType::default();
+    /// from_marker.set_coordinate("C1");
+    /// to_marker.set_coordinate("D11");
+    /// let mut chart = Chart::default();
+    /// chart.new_chart(
+    ///     ChartType::LineChart,
+    ///     from_marker,
+    ///     to_marker,
+    ///     vec!["Sheet1!$A$1:$A$10"],
+    /// );
+    /// chart
+    ///     .set_horizontal_axis_date(TimeUnitValues::Days)
+    ///     .set_horizontal_axis_date_major_unit(1f64, TimeUnitValues::Months)
+    ///     .set_horizontal_axis_number_format("yyyy/mm");
+    /// ```
+    pub fn set_horizontal_axis_date(&mut self, base_time_unit: TimeUnitValues) -> &mut Self {
+        let mut obj = BaseTimeUnit::default();
+        obj.set_val(base_time_unit);
+        let plot_area = self.get_plot_area_mut();
+        if !plot_area.get_category_axis().is_empty() {
+            let category_axis = plot_area.get_category_axis_mut().remove(0);
+            let mut date_axis = DateAxis::default();
+            date_axis
+                .set_axis_id(category_axis.get_axis_id().clone())
+                .set_scaling(category_axis.get_scaling().clone())
+                .set_delete(category_axis.get_delete().clone())
+                .set_axis_position(category_axis.get_axis_position().clone())
+                .set_major_tick_mark(category_axis.get_major_tick_mark().clone())
+                .set_minor_tick_mark(category_axis.get_minor_tick_mark().clone())
+                .set_tick_label_position(category_axis.get_tick_label_position().clone())
+                .set_tick_crossing_axis(category_axis.get_tick_crossing_axis().clone())
+                .set_crosses(category_axis.get_crosses().clone())
+                .set_label_offset(category_axis.get_label_offset().clone());
+            date_axis.get_auto_labeled_mut().set_val(false);
+            if let Some(v) = category_axis.get_title() {
+                date_axis.set_title(v.clone());
+            }
+            if let Some(v) = category_axis.get_major_gridlines() {
+                date_axis.set_major_gridlines(v.clone());
+            }
+            if let Some(v) = category_axis.get_shape_properties() {
+                date_axis.set_shape_properties(v.clone());
+            }
+            if let Some(v) = category_axis.get_text_properties() {
+                date_axis.set_text_properties(v.clone());
             }
-            _ => {}
+            let mut numbering_format = NumberingFormat::default();
+            numbering_format
+                .set_format_code("m/d/yyyy")
+                .set_source_linked(true);
+            date_axis.set_numbering_format(numbering_format);
+            plot_area.get_date_axis_mut().insert(0, date_axis);
+        }
+        if let Some(v) = plot_area.get_date_axis_mut().get_mut(0) {
+            v.set_base_time_unit(obj);
         }
         self
     }
 
+    /// Set the interval of the major tick marks of the date axis.
+    pub fn set_horizontal_axis_date_major_unit(
+        &mut self,
+        value: f64,
+        time_unit: TimeUnitValues,
+    ) -> &mut Self {
+        if let Some(v) = self.get_plot_area_mut().get_date_axis_mut().get_mut(0) {
+            let mut major_unit = MajorUnit::default();
+            major_unit.set_val(value);
+            let mut major_time_unit = MajorTimeUnit::default();
+            major_time_unit.set_val(time_unit);
+            v.set_major_unit(major_unit)
+                .set_major_time_unit(major_time_unit);
+        }
+        self
+    }
+
+    /// Set the interval of the minor tick marks of the date axis.
+    pub fn set_horizontal_axis_date_minor_unit(
+        &mut self,
+        value: f64,
+        time_unit: TimeUnitValues,
+    ) -> &mut Self {
+        if let Some(v) = self.get_plot_area_mut().get_date_axis_mut().get_mut(0) {
+            let mut minor_unit = MinorUnit::default();
+            minor_unit.set_val(value);
+            let mut minor_time_unit = MinorTimeUnit::default();
+            minor_time_unit.set_val(time_unit);
+            v.set_minor_unit(minor_unit)
+                .set_minor_time_unit(minor_time_unit);
+        }
+        self
+    }
+
+    /// Set the number format of the horizontal axis labels.
+    /// Only a date axis or the value axis of a scatter or bubble chart has a number format.
+    pub fn set_horizontal_axis_number_format<S: Into<String>>(&mut self, value: S) -> &mut Self {
+        let plot_area = self.get_plot_area_mut();
+        if Self::is_xy_plot_area(plot_area) {
+            if let Some(v) = plot_area.get_value_axis_mut().get_mut(0) {
+                v.get_numbering_format_mut()
+                    .set_format_code(value)
+                    .set_source_linked(false);
+            }
+        } else if let Some(v) = plot_area.get_date_axis_mut().get_mut(0) {
+            let mut obj = NumberingFormat::default();
+            obj.set_format_code(value).set_source_linked(false);
+            v.set_numbering_format(obj);
+        }
+        self
+    }
+
+    // scatter and bubble charts have a value axis for the x values
+    fn is_xy_plot_area(plot_area: &PlotArea) -> bool {
+        plot_area.get_category_axis().is_empty()
+            && plot_area.get_date_axis().is_empty()
+            && plot_area.get_value_axis().len() >= 2
+    }
+
+    fn get_vertical_axis_mut(&mut self) -> Option<&mut ValueAxis> {
+        let plot_area = self.get_plot_area_mut();
+        let index = if Self::is_xy_plot_area(plot_area) {
+            1
+        } else {
+            0
+        };
+        plot_area.get_value_axis_mut().get_mut(index)
+    }
+
+    fn get_horizontal_scaling_mut(&mut self) -> Option<&mut Scaling> {
+        let plot_area = self.get_plot_area_mut();
+        if Self::is_xy_plot_area(plot_area) {
+            return plot_area
+                .get_value_axis_mut()
+                .get_mut(0)
+                .map(|v| v.get_scaling_mut());
+        }
+        if !plot_area.get_date_axis().is_empty() {
+            return plot_area
+                .get_date_axis_mut()
+                .get_mut(0)
+                .map(|v| v.get_scaling_mut());
+        }
+        plot_area
+            .get_category_axis_mut()
+            .get_mut(0)
+            .map(|v| v.get_scaling_mut())
+    }
+
     pub fn set_series_title<S: Into<String>>(&mut self, value: Vec<S>) -> &mut Self {
         let mut value_iter = value.into_iter().map(Into::into);
-        for series in self
-            .get_area_chart_series_list_mut()
-            .get_area_chart_series_mut()
-        {
+        for series in self.get_plot_area_mut().get_area_chart_series_all_mut() {
             let value_raw = value_iter.next();
             if let Some(v) = value_raw {
                 let mut series_text = SeriesText::default();
@@ -220,10 +515,7 @@ impl Chart {
             string_point.get_numeric_value_mut().set_text(v);
             string_literal.add_string_point_list(string_point);
         }
-        for series in self
-            .get_area_chart_series_list_mut()
-            .get_area_chart_series_mut()
-        {
+        for series in self.get_plot_area_mut().get_area_chart_series_all_mut() {
             match series.get_category_axis_data_mut() {
                 Some(v) => {
                     v.remove_string_reference();
@@ -344,11 +636,191 @@ impl Chart {
             ChartType::ScatterChart => {
                 self.new_chart_scatter_chart(area_chart_series_list);
             }
+            ChartType::StockChart => {
+                self.new_chart_stock_chart(area_chart_series_list);
+            }
+            ChartType::SurfaceChart => {
+                self.new_chart_surface_chart(area_chart_series_list);
+            }
+            ChartType::Surface3DChart => {
+                self.new_chart_surface_3d_chart(area_chart_series_list);
+            }
         }
 
         self
     }
 
+    /// Add a series group to the chart to make a combo chart.
+    /// # Arguments
+    /// * `chart_type` - Chart type of the group. Only 2D chart types with axes can be put on the secondary axis.
+    /// * `area_chart_series_list` - Series of the group.
+    /// * `axis_side` - Value axis the group is plotted on.
+    /// # Examples
+    /// ```
+    /// use umya_spreadsheet::*;
+    ///
+    /// let mut from_marker = structs::drawing::spreadsheet::MarkerType::default();
+    /// let mut to_marker = structs::drawing::spreadsheet::MarkerType::default();
+    /// from_marker.set_coordinate("C1");
+    /// to_marker.set_coordinate("D11");
+    /// let mut chart = Chart::default();
+    /// chart
+    ///     .new_chart(
+    ///         ChartType::BarChart,
+    ///         from_marker,
+    ///         to_marker,
+    ///         vec!["Sheet1!$A$1:$A$10"],
+    ///     )
+    ///     .add_series_group(
+    ///         ChartType::LineChart,
+    ///         vec!["Sheet1!$B$1:$B$10"],
+    ///         AxisSide::Secondary,
+    ///     );
+    /// ```
+    pub fn add_series_group(
+        &mut self,
+        chart_type: ChartType,
+        area_chart_series_list: Vec<&str>,
+        axis_side: AxisSide,
+    ) -> &mut Self {
+        let mut chart = Chart::default();
+        chart.set_default_language(&self.default_language);
+        chart.new_chart(
+            chart_type,
+            MarkerType::default(),
+            MarkerType::default(),
+            area_chart_series_list,
+        );
+        let mut group = std::mem::take(chart.get_plot_area_mut());
+
+        let plot_area = self.get_plot_area_mut();
+
+        // series index and order are unique in the chart
+        let offset = plot_area.get_area_chart_series_all_mut().len() as u32;
+        for series in group.get_area_chart_series_all_mut() {
+            let index = series.get_index().get_val() + offset;
+            series.get_index_mut().set_val(index);
+            let order = series.get_order().get_val() + offset;
+            series.get_order_mut().set_val(order);
+        }
+
+        let axis_id_list = plot_area.get_axis_id_list();
+        match axis_side {
+            AxisSide::Primary if !axis_id_list.is_empty() => {
+                // share the axes of the first chart group
+                let primary = plot_area.get_primary_axis_id_list();
+                for (i, axis_id) in group.get_chart_group_axis_id_mut().into_iter().enumerate() {
+                    if let Some(v) = primary.get(i) {
+                        axis_id.set_val(*v);
+                    }
+                }
+                group.get_category_axis_mut().clear();
+                group.get_date_axis_mut().clear();
+                group.get_value_axis_mut().clear();
+                group.get_series_axis_mut().clear();
+            }
+            _ => {
+                // new axes of the group, the secondary ones cross at the maximum
+                let secondary = matches!(axis_side, AxisSide::Secondary);
+                let mut next_id = axis_id_list.iter().max().copied().unwrap_or(0);
+                let mut id_map: Vec<(u32, u32)> = Vec::new();
+                for old_id in group.get_axis_id_list() {
+                    next_id += 1;
+                    id_map.push((old_id, next_id));
+                }
+                let new_id = |id: &u32| {
+                    id_map
+                        .iter()
+                        .find(|(old, _)| old == id)
+                        .map(|(_, new)| *new)
+                        .unwrap_or(*id)
+                };
+                for axis_id in group.get_chart_group_axis_id_mut() {
+                    let id = new_id(axis_id.get_val());
+                    axis_id.set_val(id);
+                }
+                for axis in group.get_category_axis_mut() {
+                    let id = new_id(axis.get_axis_id().get_val());
+                    axis.get_axis_id_mut().set_val(id);
+                    let id = new_id(axis.get_tick_crossing_axis().get_val());
+                    axis.get_tick_crossing_axis_mut().set_val(id);
+                    if secondary {
+                        axis.get_delete_mut().set_val(true);
+                    }
+                }
+                for axis in group.get_series_axis_mut() {
+                    let id = new_id(axis.get_axis_id().get_val());
+                    axis.get_axis_id_mut().set_val(id);
+                    let id = new_id(axis.get_tick_crossing_axis().get_val());
+                    axis.get_tick_crossing_axis_mut().set_val(id);
+                }
+                for axis in group.get_value_axis_mut() {
+                    let id = new_id(axis.get_axis_id().get_val());
+                    axis.get_axis_id_mut().set_val(id);
+                    let id = new_id(axis.get_tick_crossing_axis().get_val());
+                    axis.get_tick_crossing_axis_mut().set_val(id);
+                    if !secondary {
+                        continue;
+                    }
+                    match axis.get_axis_position().get_val() {
+                        AxisPositionValues::Left => {
+                            axis.get_axis_position_mut()
+                                .set_val(AxisPositionValues::Right);
+                            axis.get_crosses_mut().set_val(CrossesValues::Maximum);
+                            axis.remove_major_gridlines();
+                        }
+                        AxisPositionValues::Bottom => {
+                            axis.get_delete_mut().set_val(true);
+                        }
+                        _ => {}
+                    }
+                }
+            }
+        }
+
+        plot_area.append(group);
+        self
+    }
+
+    /// Render the chart as an SVG document of the given size in pixels.
+    /// Series values are read from the cells of `spreadsheet`,
+    /// or from the values cached in the chart when the worksheet is not available.
+    /// 3D charts are drawn flat and surface charts are drawn from the top.
+    /// # Examples
+    /// ```
+    /// use umya_spreadsheet::structs::drawing::spreadsheet::MarkerType;
+    /// use umya_spreadsheet::structs::{Chart, ChartType};
+    ///
+    /// let mut book = umya_spreadsheet::new_file();
+    /// let worksheet = book.get_sheet_mut(&0).unwrap();
+    /// worksheet.get_cell_mut("A1").set_value_number(1);
+    /// worksheet.get_cell_mut("A2").set_value_number(3);
+    /// let mut from_marker = MarkerType::default();
+    /// let mut to_marker = MarkerType::default();
+    /// from_marker.set_coordinate("C1");
+    /// to_marker.set_coordinate("H11");
+    /// let mut chart = Chart::default();
+    /// chart.new_chart(ChartType::BarChart, from_marker, to_marker, vec!["Sheet1!$A$1:$A$2"]);
+    ///
+    /// let svg = chart.to_svg(&book, 480, 320);
+    /// assert!(svg.starts_with("<svg"));
+    /// ```
+    pub fn to_svg(&self, spreadsheet: &Spreadsheet, width: u32, height: u32) -> String {
+        render_svg(self, spreadsheet, width, height)
+    }
+
+    /// Render the chart as a PNG image of the given size in pixels.
+    /// See [`Chart::to_svg`]. Text is drawn with a small built-in bitmap font.
+    #[cfg(feature = "image")]
+    pub fn to_png(
+        &self,
+        spreadsheet: &Spreadsheet,
+        width: u32,
+        height: u32,
+    ) -> Result<Vec<u8>, XlsxError> {
+        render_png(self, spreadsheet, width, height)
+    }
+
     #[inline]
     pub fn get_coordinate(&self) -> String {
         self.two_cell_anchor.get_from_marker().get_coordinate()
@@ -2159,6 +2631,363 @@ impl Chart {
         self.two_cell_anchor.set_graphic_frame(graphic_frame);
     }
 
+    pub(crate) fn new_chart_stock_chart(&mut self, area_chart_series_list: Vec<&str>) {
+        // high-low-close: the series are only drawn by the high-low lines and markers
+        let mut acsl_obj = self.convert_series(area_chart_series_list, true);
+        let series_count = acsl_obj.get_area_chart_series().len();
+        for (idx, series) in acsl_obj.get_area_chart_series_mut().iter_mut().enumerate() {
+            let mut outline = Outline::default();
+            outline.set_width(19050);
+            outline.set_no_fill(NoFill::default());
+            let mut shape_properties = ShapeProperties::default();
+            shape_properties.set_outline(outline);
+            series.set_shape_properties(shape_properties);
+
+            let mut symbol = Symbol::default();
+            if idx + 1 == series_count && series_count > 2 {
+                symbol.set_val(MarkerStyleValues::Dot);
+            } else {
+                symbol.set_val(MarkerStyleValues::None);
+            }
+            let mut marker = Marker::default();
+            marker.set_symbol(symbol);
+            series.set_marker(marker);
+        }
+
+        let mut axis_id1 = AxisId::default();
+        axis_id1.set_val(213468160);
+
+        let mut axis_id2 = AxisId::default();
+        axis_id2.set_val(169590080);
+
+        let mut gap_width = GapWidth::default();
+        gap_width.set_val(150);
+        let mut up_down_bars = UpDownBars::default();
+        up_down_bars
+            .set_gap_width(gap_width)
+            .set_up_bars(UpBars::default())
+            .set_down_bars(DownBars::default());
+
+        let mut stock_chart = StockChart::default();
+        stock_chart.set_area_chart_series_list(acsl_obj);
+        stock_chart.set_high_low_lines(HighLowLines::default());
+        if series_count == 4 {
+            // open-high-low-close
+            stock_chart.set_up_down_bars(up_down_bars);
+        }
+        stock_chart.add_axis_id(axis_id1);
+        stock_chart.add_axis_id(axis_id2);
+
+        let mut category_axis = CategoryAxis::default();
+        category_axis.get_axis_id_mut().set_val(213468160);
+        category_axis
+            .get_scaling_mut()
+            .get_orientation_mut()
+            .set_val(OrientationValues::MinMax);
+        category_axis
+            .get_major_tick_mark_mut()
+            .set_val(TickMarkValues::Outside);
+        category_axis
+            .get_minor_tick_mark_mut()
+            .set_val(TickMarkValues::None);
+        category_axis
+            .get_tick_label_position_mut()
+            .set_val(TickLabelPositionValues::NextTo);
+        category_axis
+            .get_tick_crossing_axis_mut()
+            .set_val(169590080);
+        category_axis.get_auto_labeled_mut().set_val(true);
+        category_axis.get_label_offset_mut().set_val(100);
+
+        let major_gridlines = MajorGridlines::default();
+
+        let mut value_axis = ValueAxis::default();
+        value_axis.get_axis_id_mut().set_val(169590080);
+        value_axis
+            .get_scaling_mut()
+            .get_orientation_mut()
+            .set_val(OrientationValues::MinMax);
+        value_axis
+            .get_axis_position_mut()
+            .set_val(AxisPositionValues::Left);
+        value_axis.set_major_gridlines(major_gridlines);
+        value_axis
+            .get_numbering_format_mut()
+            .set_format_code("General");
+        value_axis
+            .get_numbering_format_mut()
+            .set_source_linked(true);
+        value_axis
+            .get_major_tick_mark_mut()
+            .set_val(TickMarkValues::Outside);
+        value_axis
+            .get_minor_tick_mark_mut()
+            .set_val(TickMarkValues::None);
+        value_axis
+            .get_tick_label_position_mut()
+            .set_val(TickLabelPositionValues::NextTo);
+        value_axis.get_tick_crossing_axis_mut().set_val(213468160);
+        value_axis
+            .get_cross_between_mut()
+            .set_val(CrossBetweenValues::Between);
+
+        let mut graphic_frame = GraphicFrame::default();
+        graphic_frame
+            .get_graphic_mut()
+            .get_graphic_data_mut()
+            .get_chart_space_mut()
+            .get_editing_language_mut()
+            .set_val(&self.default_language);
+        graphic_frame
+            .get_graphic_mut()
+            .get_graphic_data_mut()
+            .get_chart_space_mut()
+            .get_chart_mut()
+            .get_plot_area_mut()
+            .set_stock_chart(stock_chart);
+        graphic_frame
+            .get_graphic_mut()
+            .get_graphic_data_mut()
+            .get_chart_space_mut()
+            .get_chart_mut()
+            .get_plot_area_mut()
+            .add_category_axis(category_axis);
+        graphic_frame
+            .get_graphic_mut()
+            .get_graphic_data_mut()
+            .get_chart_space_mut()
+            .get_chart_mut()
+            .get_plot_area_mut()
+            .add_value_axis(value_axis);
+        graphic_frame
+            .get_graphic_mut()
+            .get_graphic_data_mut()
+            .get_chart_space_mut()
+            .get_chart_mut()
+            .get_legend_mut()
+            .get_legend_position_mut()
+            .set_val(LegendPositionValues::Right);
+        graphic_frame
+            .get_graphic_mut()
+            .get_graphic_data_mut()
+            .get_chart_space_mut()
+            .get_chart_mut()
+            .get_plot_visible_only_mut()
+            .set_val(true);
+        graphic_frame
+            .get_graphic_mut()
+            .get_graphic_data_mut()
+            .get_chart_space_mut()
+            .get_chart_mut()
+            .get_display_blanks_as_mut()
+            .set_val(DisplayBlanksAsValues::Gap);
+        graphic_frame
+            .get_graphic_mut()
+            .get_graphic_data_mut()
+            .get_chart_space_mut()
+            .set_print_settings(self.make_print_settings());
+        self.two_cell_anchor.set_graphic_frame(graphic_frame);
+    }
+
+    pub(crate) fn new_chart_surface_chart(&mut self, area_chart_series_list: Vec<&str>) {
+        // a 2D surface chart is a surface seen from the top
+        let mut rotate_x = RotateX::default();
+        let mut rotate_y = RotateY::default();
+        let mut right_angle_axes = RightAngleAxes::default();
+        let mut perspective = Perspective::default();
+        rotate_x.set_val(90);
+        rotate_y.set_val(0);
+        right_angle_axes.set_val(false);
+        perspective.set_val(0);
+        let mut view_3d = View3D::default();
+        view_3d.set_rotate_x(rotate_x);
+        view_3d.set_rotate_y(rotate_y);
+        view_3d.set_right_angle_axes(right_angle_axes);
+        view_3d.set_perspective(perspective);
+
+        let mut wireframe = Wireframe::default();
+        wireframe.set_val(false);
+
+        let mut surface_chart = SurfaceChart::default();
+        surface_chart.set_wireframe(wireframe);
+        surface_chart
+            .set_area_chart_series_list(self.convert_surface_series(area_chart_series_list));
+        surface_chart.set_band_formats(BandFormats::default());
+        for id in [213468160, 169590080, 174567232] {
+            let mut axis_id = AxisId::default();
+            axis_id.set_val(id);
+            surface_chart.add_axis_id(axis_id);
+        }
+
+        let mut graphic_frame = GraphicFrame::default();
+        let chart = graphic_frame
+            .get_graphic_mut()
+            .get_graphic_data_mut()
+            .get_chart_space_mut()
+            .get_chart_mut();
+        chart.set_view_3d(view_3d);
+        chart.get_plot_area_mut().set_surface_chart(surface_chart);
+        self.make_surface_axes(chart.get_plot_area_mut());
+        self.finish_surface_chart(graphic_frame);
+    }
+
+    pub(crate) fn new_chart_surface_3d_chart(&mut self, area_chart_series_list: Vec<&str>) {
+        let mut rotate_x = RotateX::default();
+        let mut rotate_y = RotateY::default();
+        let mut right_angle_axes = RightAngleAxes::default();
+        let mut perspective = Perspective::default();
+        rotate_x.set_val(15);
+        rotate_y.set_val(20);
+        right_angle_axes.set_val(false);
+        perspective.set_val(30);
+        let mut view_3d = View3D::default();
+        view_3d.set_rotate_x(rotate_x);
+        view_3d.set_rotate_y(rotate_y);
+        view_3d.set_right_angle_axes(right_angle_axes);
+        view_3d.set_perspective(perspective);
+
+        let mut wireframe = Wireframe::default();
+        wireframe.set_val(false);
+
+        let mut surface_3d_chart = Surface3DChart::default();
+        surface_3d_chart.set_wireframe(wireframe);
+        surface_3d_chart
+            .set_area_chart_series_list(self.convert_surface_series(area_chart_series_list));
+        surface_3d_chart.set_band_formats(BandFormats::default());
+        for id in [213468160, 169590080, 174567232] {
+            let mut axis_id = AxisId::default();
+            axis_id.set_val(id);
+            surface_3d_chart.add_axis_id(axis_id);
+        }
+
+        let mut graphic_frame = GraphicFrame::default();
+        let chart = graphic_frame
+            .get_graphic_mut()
+            .get_graphic_data_mut()
+            .get_chart_space_mut()
+            .get_chart_mut();
+        chart.set_view_3d(view_3d);
+        chart
+            .get_plot_area_mut()
+            .set_surface_3d_chart(surface_3d_chart);
+        self.make_surface_axes(chart.get_plot_area_mut());
+        self.finish_surface_chart(graphic_frame);
+    }
+
+    fn convert_surface_series(&self, area_chart_series_list: Vec<&str>) -> AreaChartSeriesList {
+        // surface series have neither smoothing nor negative inversion
+        let mut acsl_obj = self.convert_series(area_chart_series_list, true);
+        for series in acsl_obj.get_area_chart_series_mut() {
+            series.remove_smooth();
+        }
+        acsl_obj
+    }
+
+    fn make_surface_axes(&self, plot_area: &mut PlotArea) {
+        let mut category_axis = CategoryAxis::default();
+        category_axis.get_axis_id_mut().set_val(213468160);
+        category_axis
+            .get_scaling_mut()
+            .get_orientation_mut()
+            .set_val(OrientationValues::MinMax);
+        category_axis
+            .get_axis_position_mut()
+            .set_val(AxisPositionValues::Bottom);
+        category_axis
+            .get_major_tick_mark_mut()
+            .set_val(TickMarkValues::Outside);
+        category_axis
+            .get_minor_tick_mark_mut()
+            .set_val(TickMarkValues::None);
+        category_axis
+            .get_tick_label_position_mut()
+            .set_val(TickLabelPositionValues::NextTo);
+        category_axis
+            .get_tick_crossing_axis_mut()
+            .set_val(169590080);
+        category_axis.get_auto_labeled_mut().set_val(true);
+        category_axis.get_label_offset_mut().set_val(100);
+
+        let mut value_axis = ValueAxis::default();
+        value_axis.get_axis_id_mut().set_val(169590080);
+        value_axis
+            .get_scaling_mut()
+            .get_orientation_mut()
+            .set_val(OrientationValues::MinMax);
+        value_axis
+            .get_axis_position_mut()
+            .set_val(AxisPositionValues::Left);
+        value_axis.set_major_gridlines(MajorGridlines::default());
+        value_axis
+            .get_numbering_format_mut()
+            .set_format_code("General");
+        value_axis
+            .get_numbering_format_mut()
+            .set_source_linked(true);
+        value_axis
+            .get_major_tick_mark_mut()
+            .set_val(TickMarkValues::Outside);
+        value_axis
+            .get_minor_tick_mark_mut()
+            .set_val(TickMarkValues::None);
+        value_axis
+            .get_tick_label_position_mut()
+            .set_val(TickLabelPositionValues::NextTo);
+        value_axis.get_tick_crossing_axis_mut().set_val(213468160);
+        value_axis
+            .get_cross_between_mut()
+            .set_val(CrossBetweenValues::MidpointCategory);
+
+        let mut series_axis = SeriesAxis::default();
+        series_axis.get_axis_id_mut().set_val(174567232);
+        series_axis
+            .get_scaling_mut()
+            .get_orientation_mut()
+            .set_val(OrientationValues::MinMax);
+        series_axis
+            .get_axis_position_mut()
+            .set_val(AxisPositionValues::Bottom);
+        series_axis
+            .get_major_tick_mark_mut()
+            .set_val(TickMarkValues::Outside);
+        series_axis
+            .get_minor_tick_mark_mut()
+            .set_val(TickMarkValues::None);
+        series_axis
+            .get_tick_label_position_mut()
+            .set_val(TickLabelPositionValues::NextTo);
+        series_axis.get_tick_crossing_axis_mut().set_val(169590080);
+
+        plot_area.add_category_axis(category_axis);
+        plot_area.add_value_axis(value_axis);
+        plot_area.add_series_axis(series_axis);
+    }
+
+    fn finish_surface_chart(&mut self, mut graphic_frame: GraphicFrame) {
+        let chart_space = graphic_frame
+            .get_graphic_mut()
+            .get_graphic_data_mut()
+            .get_chart_space_mut();
+        chart_space
+            .get_editing_language_mut()
+            .set_val(&self.default_language);
+        chart_space
+            .get_chart_mut()
+            .get_legend_mut()
+            .get_legend_position_mut()
+            .set_val(LegendPositionValues::Right);
+        chart_space
+            .get_chart_mut()
+            .get_plot_visible_only_mut()
+            .set_val(true);
+        chart_space
+            .get_chart_mut()
+            .get_display_blanks_as_mut()
+            .set_val(DisplayBlanksAsValues::Zero);
+        chart_space.set_print_settings(self.make_print_settings());
+        self.two_cell_anchor.set_graphic_frame(graphic_frame);
+    }
+
     #[inline]
     pub(crate) fn make_print_settings(&self) -> PrintSettings {
         let mut obj = PrintSettings::default();
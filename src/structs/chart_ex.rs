@@ -0,0 +1,644 @@
+use crate::structs::drawing::chart_ex::Axis;
+use crate::structs::drawing::chart_ex::Binning;
+use crate::structs::drawing::chart_ex::ChartSpace;
+use crate::structs::drawing::chart_ex::Data;
+use crate::structs::drawing::chart_ex::DataLabelPositionValues;
+use crate::structs::drawing::chart_ex::DataLabelVisibilities;
+use crate::structs::drawing::chart_ex::DataLabels;
+use crate::structs::drawing::chart_ex::Formula;
+use crate::structs::drawing::chart_ex::LayoutProperties;
+use crate::structs::drawing::chart_ex::Legend;
+use crate::structs::drawing::chart_ex::NumericDimension;
+use crate::structs::drawing::chart_ex::NumericDimensionValues;
+use crate::structs::drawing::chart_ex::ParentLabelLayoutValues;
+use crate::structs::drawing::chart_ex::QuartileMethodValues;
+use crate::structs::drawing::chart_ex::Series;
+use crate::structs::drawing::chart_ex::SeriesElementVisibilities;
+use crate::structs::drawing::chart_ex::SeriesLayoutValues;
+use crate::structs::drawing::chart_ex::StringDimension;
+use crate::structs::drawing::chart_ex::StringDimensionValues;
+use crate::structs::drawing::chart_ex::Text;
+use crate::structs::drawing::chart_ex::TextData;
+use crate::structs::drawing::chart_ex::Title;
+use crate::structs::drawing::spreadsheet::GraphicFrame;
+use crate::structs::drawing::spreadsheet::MarkerType;
+use crate::structs::drawing::spreadsheet::TwoCellAnchor;
+use crate::structs::ChartExType;
+use crate::traits::AdjustmentCoordinate;
+use crate::traits::AdjustmentCoordinateWithSheet;
+
+/// Chart types added in Excel 2016, stored as ChartEx parts (`xl/charts/chartEx*.xml`).
+/// Older versions of Excel show a placeholder shape instead.
+///
+/// ## Supported chart types
+/// * Waterfall
+/// * Histogram
+/// * Pareto
+/// * Treemap
+/// * Sunburst
+/// * Funnel
+/// * BoxWhisker
+///
+/// ## Example
+/// ```rust
+/// extern crate umya_spreadsheet;
+/// let mut book = umya_spreadsheet::new_file();
+///
+/// // Add ChartEx
+/// let mut from_marker = umya_spreadsheet::structs::drawing::spreadsheet::MarkerType::default();
+/// let mut to_marker = umya_spreadsheet::structs::drawing::spreadsheet::MarkerType::default();
+/// from_marker.set_coordinate("C1");
+/// to_marker.set_coordinate("J15");
+/// let mut chart = umya_spreadsheet::structs::ChartEx::default();
+/// chart
+///     .new_chart(
+///         umya_spreadsheet::structs::ChartExType::Waterfall,
+///         from_marker,
+///         to_marker,
+///         Some("Sheet1!$A$1:$A$5"),
+///         vec!["Sheet1!$B$1:$B$5"],
+///     )
+///     .set_title("Cash flow")
+///     .set_subtotals(vec![4]);
+/// book.get_sheet_by_name_mut("Sheet1").unwrap()
+///     .add_chart_ex(chart);
+///
+/// // Get ChartEx by Worksheet.
+/// let worksheet = book.get_sheet_by_name("Sheet1").unwrap();
+/// let chart = &worksheet.get_chart_ex_collection()[0];
+/// ```
+#[derive(Clone, Default, Debug)]
+pub struct ChartEx {
+    two_cell_anchor: Box<TwoCellAnchor>,
+}
+
+impl ChartEx {
+    #[inline]
+    pub fn get_two_cell_anchor(&self) -> &TwoCellAnchor {
+        &self.two_cell_anchor
+    }
+
+    #[inline]
+    pub fn get_two_cell_anchor_mut(&mut self) -> &mut TwoCellAnchor {
+        &mut self.two_cell_anchor
+    }
+
+    #[inline]
+    pub fn set_two_cell_anchor(&mut self, value: TwoCellAnchor) -> &mut Self {
+        self.two_cell_anchor = Box::new(value);
+        self
+    }
+
+    pub fn get_chart_space(&self) -> &ChartSpace {
+        self.two_cell_anchor
+            .get_graphic_frame()
+            .expect("Non-ChartSpace.")
+            .get_graphic()
+            .get_graphic_data()
+            .get_chart_ex_space()
+            .expect("Non-ChartSpace.")
+    }
+
+    pub fn get_chart_space_mut(&mut self) -> &mut ChartSpace {
+        self.two_cell_anchor
+            .get_graphic_frame_mut()
+            .expect("Non-ChartSpace.")
+            .get_graphic_mut()
+            .get_graphic_data_mut()
+            .get_chart_ex_space_mut()
+            .expect("Non-ChartSpace.")
+    }
+
+    #[inline]
+    pub fn set_title<S: Into<String>>(&mut self, value: S) -> &mut Self {
+        let mut text_data = TextData::default();
+        text_data.set_value(value);
+        let mut text = Text::default();
+        text.set_text_data(text_data);
+        let mut title = Title::default();
+        title.set_overlay(false).set_text(text);
+        self.get_chart_space_mut().get_chart_mut().set_title(title);
+        self
+    }
+
+    /// Set the name of each series, in order.
+    pub fn set_series_title(&mut self, value: Vec<&str>) -> &mut Self {
+        let series_list = self
+            .get_chart_space_mut()
+            .get_chart_mut()
+            .get_plot_area_mut()
+            .get_plot_area_region_mut()
+            .get_series_mut();
+        for (series, title) in series_list.iter_mut().zip(value) {
+            let mut text_data = TextData::default();
+            text_data.set_value(title);
+            let mut text = Text::default();
+            text.set_text_data(text_data);
+            series.set_text(text);
+        }
+        self
+    }
+
+    /// Set the points of a waterfall chart that are drawn as subtotals.
+    /// # Arguments
+    /// * `value` - Zero-based indexes of the points.
+    pub fn set_subtotals(&mut self, value: Vec<u32>) -> &mut Self {
+        for series in self.get_series_list_mut() {
+            if !matches!(series.get_layout_id(), SeriesLayoutValues::Waterfall) {
+                continue;
+            }
+            if let Some(layout_properties) = series.get_layout_properties_mut() {
+                layout_properties.set_subtotals(value.clone());
+            }
+        }
+        self
+    }
+
+    /// Set the number of bins of a histogram or pareto chart.
+    #[inline]
+    pub fn set_bin_count(&mut self, value: u32) -> &mut Self {
+        for binning in self.get_binning_list_mut() {
+            binning.set_bin_count(value);
+        }
+        self
+    }
+
+    /// Set the width of the bins of a histogram or pareto chart.
+    #[inline]
+    pub fn set_bin_size(&mut self, value: f64) -> &mut Self {
+        for binning in self.get_binning_list_mut() {
+            binning.set_bin_size(value);
+        }
+        self
+    }
+
+    #[inline]
+    pub fn get_coordinate(&self) -> String {
+        self.two_cell_anchor.get_from_marker().get_coordinate()
+    }
+
+    #[inline]
+    pub(crate) fn get_col(&self) -> &u32 {
+        self.two_cell_anchor.get_from_marker().get_col()
+    }
+
+    #[inline]
+    pub(crate) fn get_row(&self) -> &u32 {
+        self.two_cell_anchor.get_from_marker().get_row()
+    }
+
+    /// Build a new chart.
+    /// # Arguments
+    /// * `chart_type` - Chart type.
+    /// * `from_marker` - Top left cell of the chart.
+    /// * `to_marker` - Bottom right cell of the chart.
+    /// * `category` - Range of the categories, e.g. `Sheet1!$A$1:$A$10`. Treemap and sunburst charts take several columns for the levels of the hierarchy. Histograms ignore it.
+    /// * `values` - Range of each series.
+    pub fn new_chart(
+        &mut self,
+        chart_type: ChartExType,
+        from_marker: MarkerType,
+        to_marker: MarkerType,
+        category: Option<&str>,
+        values: Vec<&str>,
+    ) -> &mut Self {
+        self.two_cell_anchor.set_from_marker(from_marker);
+        self.two_cell_anchor.set_to_marker(to_marker);
+
+        let mut chart_space = ChartSpace::default();
+        match chart_type {
+            ChartExType::Waterfall => {
+                self.new_chart_waterfall(&mut chart_space, category, values);
+            }
+            ChartExType::Histogram => {
+                self.new_chart_histogram(&mut chart_space, values, false);
+            }
+            ChartExType::Pareto => {
+                self.new_chart_pareto(&mut chart_space, category, values);
+            }
+            ChartExType::Treemap => {
+                self.new_chart_treemap(&mut chart_space, category, values);
+            }
+            ChartExType::Sunburst => {
+                self.new_chart_sunburst(&mut chart_space, category, values);
+            }
+            ChartExType::Funnel => {
+                self.new_chart_funnel(&mut chart_space, category, values);
+            }
+            ChartExType::BoxWhisker => {
+                self.new_chart_box_whisker(&mut chart_space, category, values);
+            }
+        }
+
+        let mut graphic_frame = GraphicFrame::default();
+        graphic_frame
+            .get_graphic_mut()
+            .get_graphic_data_mut()
+            .set_chart_ex_space(chart_space);
+        self.two_cell_anchor.set_graphic_frame(graphic_frame);
+        self
+    }
+
+    fn new_chart_waterfall(
+        &self,
+        chart_space: &mut ChartSpace,
+        category: Option<&str>,
+        values: Vec<&str>,
+    ) {
+        let mut layout_properties = LayoutProperties::default();
+        layout_properties.set_subtotals(Vec::new());
+        self.add_series(
+            chart_space,
+            category,
+            values,
+            SeriesLayoutValues::Waterfall,
+            NumericDimensionValues::Value,
+            Some(self.make_data_labels(DataLabelPositionValues::OutsideEnd, false, true)),
+            Some(layout_properties),
+        );
+        self.add_category_axis(chart_space, Some(0.5));
+        self.add_value_axis(chart_space);
+        self.add_legend(chart_space);
+    }
+
+    fn new_chart_histogram(&self, chart_space: &mut ChartSpace, values: Vec<&str>, pareto: bool) {
+        let mut layout_properties = LayoutProperties::default();
+        layout_properties.set_binning(Binning::default());
+        let data_id_start = chart_space.get_chart_data().get_data().len() as u32;
+        self.add_series(
+            chart_space,
+            None,
+            values,
+            SeriesLayoutValues::ClusteredColumn,
+            NumericDimensionValues::Value,
+            None,
+            Some(layout_properties),
+        );
+        self.add_category_axis(chart_space, Some(0.0));
+        self.add_value_axis(chart_space);
+        if pareto {
+            self.add_pareto_lines(chart_space, data_id_start);
+        }
+    }
+
+    fn new_chart_pareto(
+        &self,
+        chart_space: &mut ChartSpace,
+        category: Option<&str>,
+        values: Vec<&str>,
+    ) {
+        if category.is_none() {
+            self.new_chart_histogram(chart_space, values, true);
+            return;
+        }
+        // with categories the bars are the aggregated values of each category.
+        let mut layout_properties = LayoutProperties::default();
+        layout_properties.set_aggregation(true);
+        self.add_series(
+            chart_space,
+            category,
+            values,
+            SeriesLayoutValues::ClusteredColumn,
+            NumericDimensionValues::Value,
+            None,
+            Some(layout_properties),
+        );
+        self.add_category_axis(chart_space, Some(0.0));
+        self.add_value_axis(chart_space);
+        self.add_pareto_lines(chart_space, 0);
+    }
+
+    fn new_chart_treemap(
+        &self,
+        chart_space: &mut ChartSpace,
+        category: Option<&str>,
+        values: Vec<&str>,
+    ) {
+        let mut layout_properties = LayoutProperties::default();
+        layout_properties.set_parent_label_layout(ParentLabelLayoutValues::Overlapping);
+        self.add_series(
+            chart_space,
+            category,
+            values,
+            SeriesLayoutValues::Treemap,
+            NumericDimensionValues::Size,
+            Some(self.make_data_labels(DataLabelPositionValues::InsideEnd, true, false)),
+            Some(layout_properties),
+        );
+        self.add_legend(chart_space);
+    }
+
+    fn new_chart_sunburst(
+        &self,
+        chart_space: &mut ChartSpace,
+        category: Option<&str>,
+        values: Vec<&str>,
+    ) {
+        self.add_series(
+            chart_space,
+            category,
+            values,
+            SeriesLayoutValues::Sunburst,
+            NumericDimensionValues::Size,
+            Some(self.make_data_labels(DataLabelPositionValues::Center, true, false)),
+            None,
+        );
+    }
+
+    fn new_chart_funnel(
+        &self,
+        chart_space: &mut ChartSpace,
+        category: Option<&str>,
+        values: Vec<&str>,
+    ) {
+        self.add_series(
+            chart_space,
+            category,
+            values,
+            SeriesLayoutValues::Funnel,
+            NumericDimensionValues::Value,
+            Some(self.make_data_labels(DataLabelPositionValues::Center, false, true)),
+            None,
+        );
+        self.add_category_axis(chart_space, Some(0.06));
+        let mut axis = Axis::default();
+        axis.set_id(1).set_hidden(true);
+        chart_space
+            .get_chart_mut()
+            .get_plot_area_mut()
+            .add_axis(axis);
+    }
+
+    fn new_chart_box_whisker(
+        &self,
+        chart_space: &mut ChartSpace,
+        category: Option<&str>,
+        values: Vec<&str>,
+    ) {
+        let mut visibility = SeriesElementVisibilities::default();
+        visibility
+            .set_mean_line(false)
+            .set_mean_marker(true)
+            .set_non_outliers(false)
+            .set_outliers(true);
+        let mut layout_properties = LayoutProperties::default();
+        layout_properties
+            .set_visibility(visibility)
+            .set_quartile_method(QuartileMethodValues::Exclusive);
+        self.add_series(
+            chart_space,
+            category,
+            values,
+            SeriesLayoutValues::BoxWhisker,
+            NumericDimensionValues::Value,
+            None,
+            Some(layout_properties),
+        );
+        self.add_category_axis(chart_space, None);
+        self.add_value_axis(chart_space);
+        self.add_legend(chart_space);
+    }
+
+    #[allow(clippy::too_many_arguments)]
+    fn add_series(
+        &self,
+        chart_space: &mut ChartSpace,
+        category: Option<&str>,
+        values: Vec<&str>,
+        layout_id: SeriesLayoutValues,
+        numeric_type: NumericDimensionValues,
+        data_labels: Option<DataLabels>,
+        layout_properties: Option<LayoutProperties>,
+    ) {
+        for value in values {
+            let data_id = chart_space.get_chart_data().get_data().len() as u32;
+
+            let mut data = Data::default();
+            data.set_id(data_id);
+            if let Some(category) = category {
+                let mut formula = Formula::default();
+                formula.set_address_str(category);
+                let mut string_dimension = StringDimension::default();
+                string_dimension
+                    .set_type(StringDimensionValues::Category)
+                    .set_formula(formula);
+                data.add_string_dimension(string_dimension);
+            }
+            let mut formula = Formula::default();
+            formula.set_address_str(value);
+            let mut numeric_dimension = NumericDimension::default();
+            numeric_dimension
+                .set_type(numeric_type.clone())
+                .set_formula(formula);
+            data.add_numeric_dimension(numeric_dimension);
+            chart_space.get_chart_data_mut().add_data(data);
+
+            let mut series = Series::default();
+            series
+                .set_layout_id(layout_id.clone())
+                .set_unique_id(format!("{{{:08X}-0000-0000-0000-000000000000}}", data_id))
+                .set_data_id(data_id);
+            if let Some(v) = &data_labels {
+                series.set_data_labels(v.clone());
+            }
+            if let Some(v) = &layout_properties {
+                series.set_layout_properties(v.clone());
+            }
+            chart_space
+                .get_chart_mut()
+                .get_plot_area_mut()
+                .get_plot_area_region_mut()
+                .add_series(series);
+        }
+    }
+
+    fn add_pareto_lines(&self, chart_space: &mut ChartSpace, data_id_start: u32) {
+        let plot_area = chart_space.get_chart_mut().get_plot_area_mut();
+        let mut lines: Vec<Series> = Vec::new();
+        for (idx, series) in plot_area
+            .get_plot_area_region_mut()
+            .get_series_mut()
+            .iter_mut()
+            .enumerate()
+        {
+            if series.get_data_id() < &data_id_start {
+                continue;
+            }
+            series.add_axis_id(0).add_axis_id(1);
+
+            let mut line = Series::default();
+            line.set_layout_id(SeriesLayoutValues::ParetoLine)
+                .set_owner_index(idx as u32)
+                .set_unique_id(format!(
+                    "{{{:08X}-0000-0000-0000-000000000001}}",
+                    series.get_data_id()
+                ))
+                .set_data_id(*series.get_data_id())
+                .add_axis_id(2);
+            lines.push(line);
+        }
+        for line in lines {
+            plot_area.get_plot_area_region_mut().add_series(line);
+        }
+
+        let mut axis = Axis::default();
+        axis.set_id(2)
+            .set_max(1.0)
+            .set_min(0.0)
+            .set_tick_labels(true);
+        plot_area.add_axis(axis);
+    }
+
+    fn add_category_axis(&self, chart_space: &mut ChartSpace, gap_width: Option<f64>) {
+        let mut axis = Axis::default();
+        axis.set_id(0).set_is_category(true).set_tick_labels(true);
+        if let Some(v) = gap_width {
+            axis.set_gap_width(v);
+        }
+        chart_space
+            .get_chart_mut()
+            .get_plot_area_mut()
+            .add_axis(axis);
+    }
+
+    fn add_value_axis(&self, chart_space: &mut ChartSpace) {
+        let mut axis = Axis::default();
+        axis.set_id(1)
+            .set_major_gridlines(true)
+            .set_tick_labels(true);
+        chart_space
+            .get_chart_mut()
+            .get_plot_area_mut()
+            .add_axis(axis);
+    }
+
+    fn add_legend(&self, chart_space: &mut ChartSpace) {
+        let mut legend = Legend::default();
+        legend.set_overlay(false);
+        chart_space.get_chart_mut().set_legend(legend);
+    }
+
+    fn make_data_labels(
+        &self,
+        position: DataLabelPositionValues,
+        category_name: bool,
+        value: bool,
+    ) -> DataLabels {
+        let mut visibility = DataLabelVisibilities::default();
+        visibility
+            .set_series_name(false)
+            .set_category_name(category_name)
+            .set_value(value);
+        let mut data_labels = DataLabels::default();
+        data_labels
+            .set_position(position)
+            .set_visibility(visibility);
+        data_labels
+    }
+
+    fn get_series_list_mut(&mut self) -> &mut [Series] {
+        self.get_chart_space_mut()
+            .get_chart_mut()
+            .get_plot_area_mut()
+            .get_plot_area_region_mut()
+            .get_series_mut()
+    }
+
+    fn get_binning_list_mut(&mut self) -> Vec<&mut Binning> {
+        self.get_series_list_mut()
+            .iter_mut()
+            .filter_map(|series| series.get_layout_properties_mut())
+            .filter_map(|layout_properties| layout_properties.get_binning_mut())
+            .collect()
+    }
+}
+impl AdjustmentCoordinate for ChartEx {
+    #[inline]
+    fn adjustment_insert_coordinate(
+        &mut self,
+        root_col_num: &u32,
+        offset_col_num: &u32,
+        root_row_num: &u32,
+        offset_row_num: &u32,
+    ) {
+        self.two_cell_anchor.as_mut().adjustment_insert_coordinate(
+            root_col_num,
+            offset_col_num,
+            root_row_num,
+            offset_row_num,
+        );
+    }
+
+    #[inline]
+    fn adjustment_remove_coordinate(
+        &mut self,
+        root_col_num: &u32,
+        offset_col_num: &u32,
+        root_row_num: &u32,
+        offset_row_num: &u32,
+    ) {
+        self.two_cell_anchor.as_mut().adjustment_remove_coordinate(
+            root_col_num,
+            offset_col_num,
+            root_row_num,
+            offset_row_num,
+        );
+    }
+
+    #[inline]
+    fn is_remove_coordinate(
+        &self,
+        root_col_num: &u32,
+        offset_col_num: &u32,
+        root_row_num: &u32,
+        offset_row_num: &u32,
+    ) -> bool {
+        self.two_cell_anchor.as_ref().is_remove_coordinate(
+            root_col_num,
+            offset_col_num,
+            root_row_num,
+            offset_row_num,
+        )
+    }
+}
+impl AdjustmentCoordinateWithSheet for ChartEx {
+    #[inline]
+    fn adjustment_insert_coordinate_with_sheet(
+        &mut self,
+        sheet_name: &str,
+        root_col_num: &u32,
+        offset_col_num: &u32,
+        root_row_num: &u32,
+        offset_row_num: &u32,
+    ) {
+        self.two_cell_anchor
+            .as_mut()
+            .adjustment_insert_coordinate_with_sheet(
+                sheet_name,
+                root_col_num,
+                offset_col_num,
+                root_row_num,
+                offset_row_num,
+            );
+    }
+
+    #[inline]
+    fn adjustment_remove_coordinate_with_sheet(
+        &mut self,
+        sheet_name: &str,
+        root_col_num: &u32,
+        offset_col_num: &u32,
+        root_row_num: &u32,
+        offset_row_num: &u32,
+    ) {
+        self.two_cell_anchor
+            .as_mut()
+            .adjustment_remove_coordinate_with_sheet(
+                sheet_name,
+                root_col_num,
+                offset_col_num,
+                root_row_num,
+                offset_row_num,
+            );
+    }
+}
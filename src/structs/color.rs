@@ -6,6 +6,7 @@ use crate::helper::color::*;
 use crate::reader::driver::*;
 use crate::structs::drawing::Theme;
 use crate::writer::driver::*;
+use crate::XlsxError;
 use md5::Digest;
 use quick_xml::events::{BytesStart, Event};
 use quick_xml::Reader;
@@ -235,51 +236,38 @@ impl Color {
         reader: &mut Reader<R>,
         e: &BytesStart,
         empty_flg: bool,
-    ) {
-        for a in e.attributes().with_checks(false) {
-            match a {
-                Ok(ref attr) => match attr.key.0 {
-                    b"indexed" => {
-                        self.indexed
-                            .set_value_string(get_attribute_value(attr).unwrap());
-                    }
-                    b"theme" => {
-                        self.theme_index
-                            .set_value_string(get_attribute_value(attr).unwrap());
-                    }
-                    b"rgb" => {
-                        self.argb
-                            .set_value_string(get_attribute_value(attr).unwrap());
-                    }
-                    b"tint" => {
-                        self.tint
-                            .set_value_string(get_attribute_value(attr).unwrap());
-                    }
-                    _ => {}
-                },
-                Err(_) => {}
-            }
-        }
+    ) -> Result<(), XlsxError> {
+        set_string_from_xml!(self, e, indexed, "indexed");
+        set_string_from_xml!(self, e, theme_index, "theme");
+        set_string_from_xml!(self, e, argb, "rgb");
+        set_string_from_xml!(self, e, tint, "tint");
 
         if empty_flg {
-            return;
+            return Ok(());
         }
 
         let mut buf = Vec::new();
         loop {
             match reader.read_event_into(&mut buf) {
                 Ok(Event::End(ref e)) => match e.name().into_inner() {
-                    b"color" => return,
-                    b"fgColor" => return,
-                    b"bgColor" => return,
-                    b"tabColor" => return,
+                    b"color" => return Ok(()),
+                    b"fgColor" => return Ok(()),
+                    b"bgColor" => return Ok(()),
+                    b"tabColor" => return Ok(()),
+                    b"x14:colorSeries" | b"x14:colorNegative" | b"x14:colorAxis"
+                    | b"x14:colorMarkers" | b"x14:colorFirst" | b"x14:colorLast"
+                    | b"x14:colorHigh" | b"x14:colorLow" => return Ok(()),
+                    b"x14:fillColor"
+                    | b"x14:borderColor"
+                    | b"x14:negativeFillColor"
+                    | b"x14:negativeBorderColor"
+                    | b"x14:axisColor" => return Ok(()),
                     _ => (),
                 },
-                Ok(Event::Eof) => panic!(
-                    "Error: Could not find {} end element",
-                    "color,fgColor,bgColor,tabColor"
-                ),
-                Err(e) => panic!("Error at position {}: {:?}", reader.buffer_position(), e),
+                Ok(Event::Eof) => {
+                    return Err(end_element_error(reader, "color,fgColor,bgColor,tabColor"))
+                }
+                Err(e) => return Err(xml_error(reader, e)),
                 _ => (),
             }
             buf.clear();
@@ -310,7 +298,7 @@ impl Color {
         self.write_to(writer, "tabColor");
     }
 
-    fn write_to(&self, writer: &mut Writer<Cursor<Vec<u8>>>, tag_name: &str) {
+    pub(crate) fn write_to(&self, writer: &mut Writer<Cursor<Vec<u8>>>, tag_name: &str) {
         let mut attributes: Vec<(&str, &str)> = Vec::new();
         let theme_index = self.theme_index.get_value_string();
         let indexed = self.indexed.get_value_string();
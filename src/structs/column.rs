@@ -1,11 +1,15 @@
 use super::BooleanValue;
+use super::ByteValue;
 use super::DoubleValue;
 use super::Style;
 use super::Stylesheet;
 use super::UInt32Value;
+use crate::helper::font_metrics::*;
 use crate::reader::driver::*;
 use crate::structs::Cells;
+use crate::structs::MergeCells;
 use crate::traits::AdjustmentValue;
+use crate::XlsxError;
 use md5::Digest;
 use quick_xml::events::BytesStart;
 use quick_xml::Reader;
@@ -31,6 +35,8 @@ pub struct Column {
     pub(crate) width: DoubleValue,
     pub(crate) hidden: BooleanValue,
     pub(crate) best_fit: BooleanValue,
+    pub(crate) outline_level: ByteValue,
+    pub(crate) collapsed: BooleanValue,
     style: Box<Style>,
     auto_width: BooleanValue,
 }
@@ -45,6 +51,8 @@ impl Default for Column {
             width,
             hidden: BooleanValue::default(),
             best_fit: BooleanValue::default(),
+            outline_level: ByteValue::default(),
+            collapsed: BooleanValue::default(),
             style: Box::new(Style::default()),
             auto_width: BooleanValue::default(),
         }
@@ -96,6 +104,30 @@ impl Column {
         self
     }
 
+    /// Outline (group) level of the column, 0 to 7.
+    #[inline]
+    pub fn get_outline_level(&self) -> &u8 {
+        self.outline_level.get_value()
+    }
+
+    #[inline]
+    pub fn set_outline_level(&mut self, value: u8) -> &mut Self {
+        self.outline_level.set_value(value.min(7));
+        self
+    }
+
+    /// The group next to this summary column is collapsed.
+    #[inline]
+    pub fn get_collapsed(&self) -> &bool {
+        self.collapsed.get_value()
+    }
+
+    #[inline]
+    pub fn set_collapsed(&mut self, value: bool) -> &mut Self {
+        self.collapsed.set_value(value);
+        self
+    }
+
     #[inline]
     pub fn get_style(&self) -> &Style {
         &self.style
@@ -123,39 +155,43 @@ impl Column {
         self
     }
 
-    pub(crate) fn calculation_auto_width(&mut self, cells: &Cells) -> &mut Self {
+    pub(crate) fn calculation_auto_width(
+        &mut self,
+        cells: &Cells,
+        merge_cells: &MergeCells,
+        default_font: &TextFont,
+    ) -> &mut Self {
         if !*self.get_auto_width() {
             return self;
         }
 
-        let mut column_width_max = 0f64;
+        let digit_width = get_max_digit_width(default_font);
+        // font of the cells without a font of their own.
+        let column_font = default_font.with_font(self.get_style().get_font());
 
-        // default font size len.
-        let column_font_size = match self.get_style().get_font() {
-            Some(font) => *font.get_font_size().get_val(),
-            None => 11f64,
-        };
-
-        let mut cell_list = cells.get_collection_by_column(self.get_col_num());
-        cell_list.sort_by(|a, b| {
-            a.get_coordinate()
-                .get_row_num()
-                .cmp(b.get_coordinate().get_row_num())
-        });
-        for cell in cell_list {
-            let column_width = cell.get_width_point(&column_font_size);
-
-            if column_width > column_width_max {
-                column_width_max = column_width;
+        let mut text_width_max = 0f64;
+        for cell in cells.get_collection_by_column(self.get_col_num()) {
+            let coordinate = cell.get_coordinate();
+            // the text of a range merged across columns is not fitted into one column.
+            if let Some((start_col, _, end_col, _)) =
+                merge_cells.get_merged_area(coordinate.get_col_num(), coordinate.get_row_num())
+            {
+                if start_col != end_col {
+                    continue;
+                }
             }
+            let extent = cell.get_text_extent(&column_font, f64::INFINITY);
+            text_width_max = text_width_max.max(extent.width);
         }
 
         // set default width if empty column.
-        if column_width_max == 0f64 {
-            column_width_max = 8.38f64;
-        }
+        let column_width = if text_width_max == 0f64 {
+            8.38f64
+        } else {
+            get_column_width(text_width_max, digit_width)
+        };
 
-        self.set_width(column_width_max);
+        self.set_width(column_width);
         self
     }
 
@@ -169,10 +205,12 @@ impl Column {
         format!(
             "{:x}",
             md5::Md5::digest(format!(
-                "{}{}{}",
+                "{}{}{}{}{}",
                 &self.width.get_value_string(),
                 &self.hidden.get_value_string(),
                 &self.best_fit.get_value_string(),
+                &self.outline_level.get_value_string(),
+                &self.collapsed.get_value_string(),
             ))
         )
     }
@@ -182,15 +220,20 @@ impl Column {
         _reader: &mut Reader<R>,
         e: &BytesStart,
         stylesheet: &Stylesheet,
-    ) {
+    ) -> Result<(), XlsxError> {
         set_string_from_xml!(self, e, width, "width");
         set_string_from_xml!(self, e, hidden, "hidden");
         set_string_from_xml!(self, e, best_fit, "bestFit");
+        set_string_from_xml!(self, e, outline_level, "outlineLevel");
+        set_string_from_xml!(self, e, collapsed, "collapsed");
 
-        if let Some(v) = get_attribute(e, b"style") {
-            let style = stylesheet.get_style(v.parse::<usize>().unwrap());
+        if let Some(v) = get_attribute(e, b"style")? {
+            let style = stylesheet
+                .get_style(parse_value("style", &v)?)
+                .ok_or_else(|| invalid_attribute_error("style", v))?;
             self.set_style(style);
         }
+        Ok(())
     }
 }
 impl AdjustmentValue for Column {
@@ -2,6 +2,7 @@
 use crate::reader::driver::*;
 use crate::structs::Break;
 use crate::writer::driver::*;
+use crate::XlsxError;
 use quick_xml::events::{BytesStart, Event};
 use quick_xml::Reader;
 use quick_xml::Writer;
@@ -39,22 +40,22 @@ impl ColumnBreaks {
         &mut self,
         reader: &mut Reader<R>,
         _e: &BytesStart,
-    ) {
+    ) -> Result<(), XlsxError> {
         xml_read_loop!(
             reader,
             Event::Empty(ref e) => {
                 if e.name().into_inner() == b"brk" {
                     let mut obj = Break::default();
-                    obj.set_attributes(reader, e);
+                    obj.set_attributes(reader, e)?;
                     self.add_break_list(obj);
                 }
             },
             Event::End(ref e) => {
                 if e.name().into_inner() == b"colBreaks" {
-                    return
+                    return Ok(())
                 }
             },
-            Event::Eof => panic!("Error: Could not find {} end element", "colBreaks")
+            Event::Eof => return Err(end_element_error(reader, "colBreaks"))
         );
     }
 
@@ -2,6 +2,7 @@
 use crate::reader::driver::*;
 use crate::structs::RowItem;
 use crate::writer::driver::*;
+use crate::XlsxError;
 use quick_xml::events::{BytesStart, Event};
 use quick_xml::Reader;
 use quick_xml::Writer;
@@ -34,29 +35,29 @@ impl ColumnItems {
         &mut self,
         reader: &mut Reader<R>,
         e: &BytesStart,
-    ) {
+    ) -> Result<(), XlsxError> {
         xml_read_loop!(
             reader,
             Event::Empty(ref e) => {
                 if e.name().into_inner() == b"i" {
                     let mut obj = RowItem::default();
-                    obj.set_attributes(reader, e, true);
+                    obj.set_attributes(reader, e, true)?;
                     self.add_list_mut(obj);
                 }
             },
             Event::Start(ref e) => {
                 if e.name().into_inner() == b"i" {
                     let mut obj = RowItem::default();
-                    obj.set_attributes(reader, e, false);
+                    obj.set_attributes(reader, e, false)?;
                     self.add_list_mut(obj);
                 }
             },
             Event::End(ref e) => {
                 if e.name().into_inner() == b"colItems" {
-                    return
+                    return Ok(())
                 }
             },
-            Event::Eof => panic!("Error: Could not find {} end element", "colItems")
+            Event::Eof => return Err(end_element_error(reader, "colItems"))
         );
     }
 
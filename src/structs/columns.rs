@@ -1,4 +1,5 @@
 // fills
+use crate::helper::font_metrics::TextFont;
 use crate::reader::driver::*;
 use crate::structs::Cells;
 use crate::structs::Column;
@@ -6,6 +7,7 @@ use crate::structs::MergeCells;
 use crate::structs::Stylesheet;
 use crate::traits::AdjustmentValue;
 use crate::writer::driver::*;
+use crate::XlsxError;
 use quick_xml::events::{BytesStart, Event};
 use quick_xml::Reader;
 use quick_xml::Writer;
@@ -59,13 +61,10 @@ impl Columns {
         &mut self,
         cells: &Cells,
         merge_cells: &MergeCells,
+        default_font: &TextFont,
     ) -> &mut Self {
         for column in self.get_column_collection_mut() {
-            let has_horizontal = merge_cells.has_horizontal(column.get_col_num());
-            if has_horizontal {
-                continue;
-            }
-            column.calculation_auto_width(cells);
+            column.calculation_auto_width(cells, merge_cells, default_font);
         }
         self
     }
@@ -75,15 +74,15 @@ impl Columns {
         reader: &mut Reader<R>,
         _e: &BytesStart,
         stylesheet: &Stylesheet,
-    ) {
+    ) -> Result<(), XlsxError> {
         xml_read_loop!(
             reader,
             Event::Empty(ref e) => {
                 if e.name().into_inner() == b"col" {
                     let mut obj = Column::default();
-                    obj.set_attributes(reader, e, stylesheet);
-                    let min = get_attribute(e, b"min").unwrap().parse::<u32>().unwrap();
-                    let max = get_attribute(e, b"max").unwrap().parse::<u32>().unwrap();
+                    obj.set_attributes(reader, e, stylesheet)?;
+                    let min: u32 = parse_value("min", &get_required_attribute(e, b"min")?)?;
+                    let max: u32 = parse_value("max", &get_required_attribute(e, b"max")?)?;
                     for i in min..=max {
                         obj.set_col_num(i);
                         self.set_column(obj.clone());
@@ -92,10 +91,10 @@ impl Columns {
             },
             Event::End(ref e) => {
                 if e.name().into_inner() == b"cols" {
-                    return
+                    return Ok(())
                 }
             },
-            Event::Eof => panic!("Error: Could not find {} end element", "cols")
+            Event::Eof => return Err(end_element_error(reader, "cols"))
         );
     }
 
@@ -170,6 +169,13 @@ impl Columns {
             attributes.push(("bestFit", column.best_fit.get_value_string()));
         }
         attributes.push(("customWidth", "1"));
+        let outline_level = column.outline_level.get_value_string();
+        if column.outline_level.get_value() > &0 {
+            attributes.push(("outlineLevel", &outline_level));
+        }
+        if *column.collapsed.get_value() {
+            attributes.push(("collapsed", column.collapsed.get_value_string()));
+        }
         let xf_index_str: String;
         let xf_index = stylesheet.set_style(column.get_style());
         if xf_index > 0 {
@@ -1,4 +1,5 @@
 use crate::xml_read_loop;
+use crate::XlsxError;
 
 use super::vml::office::InsetMarginValues;
 use super::vml::spreadsheet::Anchor;
@@ -176,30 +177,29 @@ impl Comment {
         reader: &mut Reader<R>,
         e: &BytesStart,
         authors: &[String],
-    ) {
-        let coordinate = get_attribute(e, b"ref").unwrap();
+    ) -> Result<(), XlsxError> {
+        let coordinate = get_required_attribute(e, b"ref")?;
         self.get_coordinate_mut().set_coordinate(coordinate);
 
-        let author_id = get_attribute(e, b"authorId")
-            .unwrap()
-            .parse::<usize>()
-            .unwrap();
-        let author = authors.get(author_id).unwrap();
+        let author_id = get_required_attribute(e, b"authorId")?;
+        let author = authors
+            .get(parse_value::<usize>("authorId", &author_id)?)
+            .ok_or_else(|| invalid_attribute_error("authorId", author_id))?;
         self.set_author(author);
 
         xml_read_loop!(
             reader,
             Event::Start(ref e) => {
                 if e.name().into_inner() == b"text" {
-                    self.get_text_mut().set_attributes_text(reader, e);
+                    self.get_text_mut().set_attributes_text(reader, e)?;
                 }
             },
             Event::End(ref e) => {
                 if e.name().into_inner() == b"comment" {
-                    return
+                    return Ok(())
                 }
             },
-            Event::Eof => panic!("Error: Could not find {} end element", "comment")
+            Event::Eof => return Err(end_element_error(reader, "comment"))
         );
     }
 }
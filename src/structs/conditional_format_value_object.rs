@@ -1,8 +1,10 @@
+use super::BooleanValue;
 use super::ConditionalFormatValueObjectValues;
 use super::EnumValue;
 use super::StringValue;
 use crate::reader::driver::*;
 use crate::writer::driver::*;
+use crate::XlsxError;
 use quick_xml::events::{BytesStart, Event};
 use quick_xml::Reader;
 use quick_xml::Writer;
@@ -12,6 +14,7 @@ use std::io::Cursor;
 pub struct ConditionalFormatValueObject {
     r#type: EnumValue<ConditionalFormatValueObjectValues>,
     val: StringValue,
+    greater_than_or_equal: BooleanValue,
 }
 
 impl ConditionalFormatValueObject {
@@ -37,34 +40,62 @@ impl ConditionalFormatValueObject {
         self
     }
 
+    /// Whether the threshold is inclusive (`>=`) or exclusive (`>`). Used by icon sets.
+    #[inline]
+    pub fn get_greater_than_or_equal(&self) -> &bool {
+        self.greater_than_or_equal.get_value()
+    }
+
+    #[inline]
+    pub fn set_greater_than_or_equal(&mut self, value: bool) -> &mut Self {
+        self.greater_than_or_equal.set_value(value);
+        self
+    }
+
+    /// `gte` is true when the attribute is omitted.
+    #[inline]
+    pub(crate) fn is_greater_than_or_equal(&self) -> bool {
+        !self.greater_than_or_equal.has_value() || *self.greater_than_or_equal.get_value()
+    }
+
     pub(crate) fn set_attributes<R: std::io::BufRead>(
         &mut self,
         reader: &mut Reader<R>,
         e: &BytesStart,
         empty_flg: bool,
-    ) {
+    ) -> Result<(), XlsxError> {
         set_string_from_xml!(self, e, r#type, "type");
         set_string_from_xml!(self, e, val, "val");
+        set_string_from_xml!(self, e, greater_than_or_equal, "gte");
 
         if empty_flg {
-            return;
+            return Ok(());
         }
 
+        // the x14 extension stores the value in a xm:f child element
         xml_read_loop!(
             reader,
+            Event::Text(e) => {
+                self.val.set_value(e.unescape()?);
+            },
             Event::End(ref e) => {
-                if e.name().into_inner() == b"cfvo" {
-                    return
+                if matches!(e.name().into_inner(), b"cfvo" | b"x14:cfvo") {
+                    return Ok(())
                 }
             },
-            Event::Eof => panic!("Error: Could not find {} end element", "cfvo")
+            Event::Eof => return Err(end_element_error(reader, "cfvo"))
         );
     }
 
     pub(crate) fn write_to(&self, writer: &mut Writer<Cursor<Vec<u8>>>) {
         // cfvo
         let mut attributes: Vec<(&str, &str)> = Vec::new();
-        let ctype = self.r#type.get_value_string();
+        // autoMin and autoMax are only known to the x14 extension
+        let ctype = match self.r#type.get_value() {
+            ConditionalFormatValueObjectValues::AutoMin => "min",
+            ConditionalFormatValueObjectValues::AutoMax => "max",
+            _ => self.r#type.get_value_string(),
+        };
         if self.r#type.has_value() {
             attributes.push(("type", ctype));
         }
@@ -72,7 +103,34 @@ impl ConditionalFormatValueObject {
         if self.val.has_value() {
             attributes.push(("val", val));
         }
+        if self.greater_than_or_equal.has_value() {
+            attributes.push(("gte", self.greater_than_or_equal.get_value_string()));
+        }
 
         write_start_tag(writer, "cfvo", attributes, true);
     }
+
+    pub(crate) fn write_to_x14(&self, writer: &mut Writer<Cursor<Vec<u8>>>) {
+        // x14:cfvo
+        let mut attributes: Vec<(&str, &str)> = Vec::new();
+        if self.r#type.has_value() {
+            attributes.push(("type", self.r#type.get_value_string()));
+        }
+        if self.greater_than_or_equal.has_value() {
+            attributes.push(("gte", self.greater_than_or_equal.get_value_string()));
+        }
+
+        if !self.val.has_value() {
+            write_start_tag(writer, "x14:cfvo", attributes, true);
+            return;
+        }
+        write_start_tag(writer, "x14:cfvo", attributes, false);
+
+        // xm:f
+        write_start_tag(writer, "xm:f", vec![], false);
+        write_text_node(writer, self.val.get_value_str());
+        write_end_tag(writer, "xm:f");
+
+        write_end_tag(writer, "x14:cfvo");
+    }
 }
@@ -2,6 +2,10 @@ use super::EnumTrait;
 use std::str::FromStr;
 #[derive(Clone, Debug, Eq, Ord, PartialEq, PartialOrd)]
 pub enum ConditionalFormatValueObjectValues {
+    /// Automatic maximum of a data bar (x14 extension only).
+    AutoMax,
+    /// Automatic minimum of a data bar (x14 extension only).
+    AutoMin,
     Formula,
     Max,
     Min,
@@ -19,6 +23,8 @@ impl EnumTrait for ConditionalFormatValueObjectValues {
     #[inline]
     fn get_value_string(&self) -> &str {
         match &self {
+            Self::AutoMax => "autoMax",
+            Self::AutoMin => "autoMin",
             Self::Formula => "formula",
             Self::Max => "max",
             Self::Min => "min",
@@ -34,6 +40,8 @@ impl FromStr for ConditionalFormatValueObjectValues {
     #[inline]
     fn from_str(input: &str) -> Result<Self, Self::Err> {
         match input {
+            "autoMax" => Ok(Self::AutoMax),
+            "autoMin" => Ok(Self::AutoMin),
             "formula" => Ok(Self::Formula),
             "max" => Ok(Self::Max),
             "min" => Ok(Self::Min),
@@ -1,9 +1,12 @@
+use super::office::excel::ReferenceSequence;
 use super::ConditionalFormattingRule;
 use super::DifferentialFormats;
 use super::SequenceOfReferences;
+use crate::helper::const_str::*;
 use crate::reader::driver::*;
 use crate::traits::AdjustmentCoordinate;
 use crate::writer::driver::*;
+use crate::XlsxError;
 use quick_xml::events::BytesStart;
 use quick_xml::events::Event;
 use quick_xml::Reader;
@@ -64,8 +67,8 @@ impl ConditionalFormatting {
         reader: &mut Reader<R>,
         e: &BytesStart,
         differential_formats: &DifferentialFormats,
-    ) {
-        if let Some(v) = get_attribute(e, b"sqref") {
+    ) -> Result<(), XlsxError> {
+        if let Some(v) = get_attribute(e, b"sqref")? {
             self.sequence_of_references.set_sqref(v);
         }
 
@@ -74,31 +77,95 @@ impl ConditionalFormatting {
             Event::Empty(ref e) => {
                 if e.name().into_inner() == b"cfRule" {
                     let mut obj = ConditionalFormattingRule::default();
-                    obj.set_attributes(reader, e, differential_formats, true);
+                    obj.set_attributes(reader, e, differential_formats, true)?;
                     self.conditional_collection.push(obj);
                 }
             },
             Event::Start(ref e) => {
                 if e.name().into_inner() == b"cfRule" {
                     let mut obj = ConditionalFormattingRule::default();
-                    obj.set_attributes(reader, e, differential_formats, false);
+                    obj.set_attributes(reader, e, differential_formats, false)?;
                     self.conditional_collection.push(obj);
                 }
             },
             Event::End(ref e) => {
                 if e.name().into_inner() == b"conditionalFormatting" {
-                    return
+                    return Ok(())
                 }
             },
-            Event::Eof => panic!("Error: Could not find {} end element", "conditionalFormatting")
+            Event::Eof => return Err(end_element_error(reader, "conditionalFormatting"))
         );
     }
 
+    #[inline]
+    pub(crate) fn has_x14_extension(&self) -> bool {
+        self.conditional_collection
+            .iter()
+            .any(|v| v.has_x14_extension())
+    }
+
+    /// Reads `x14:conditionalFormatting`.
+    /// Rules linked to a rule of `conditional_formatting_collection` are merged into it,
+    /// the other ones are added to `self`.
+    pub(crate) fn set_attributes_x14<R: std::io::BufRead>(
+        &mut self,
+        reader: &mut Reader<R>,
+        _e: &BytesStart,
+        conditional_formatting_collection: &mut [ConditionalFormatting],
+    ) -> Result<(), XlsxError> {
+        xml_read_loop!(
+            reader,
+            Event::Start(ref e) => {
+                match e.name().into_inner() {
+                    b"x14:cfRule" => {
+                        let id = get_attribute(e, b"id")?.unwrap_or_default();
+                        let linked = conditional_formatting_collection
+                            .iter_mut()
+                            .flat_map(|v| v.conditional_collection.iter_mut())
+                            .find(|v| !id.is_empty() && v.get_x14_id() == id);
+                        match linked {
+                            Some(obj) => obj.set_attributes_x14(reader, e)?,
+                            None => {
+                                let mut obj = ConditionalFormattingRule::default();
+                                obj.set_attributes_x14(reader, e)?;
+                                if obj.get_data_bar().is_some() || obj.get_icon_set().is_some() {
+                                    self.conditional_collection.push(obj);
+                                }
+                            }
+                        }
+                    }
+                    b"xm:sqref" => {
+                        let mut obj = ReferenceSequence::default();
+                        obj.set_attributes(reader, e)?;
+                        self.sequence_of_references.set_sqref(obj.get_sqref());
+                    }
+                    _ => (),
+                }
+            },
+            Event::End(ref e) => {
+                if e.name().into_inner() == b"x14:conditionalFormatting" {
+                    return Ok(())
+                }
+            },
+            Event::Eof => return Err(end_element_error(reader, "x14:conditionalFormatting"))
+        );
+    }
+
+    /// `x14_index` counts the rules having an x14 extension, see `write_to_x14`.
     pub(crate) fn write_to(
         &self,
         writer: &mut Writer<Cursor<Vec<u8>>>,
         differential_formats: &mut DifferentialFormats,
+        x14_index: &mut usize,
     ) {
+        // rules only known to the x14 extension are written by write_to_x14
+        if !self.conditional_collection.is_empty()
+            && self.conditional_collection.iter().all(|v| v.is_x14_only())
+        {
+            *x14_index += self.conditional_collection.len();
+            return;
+        }
+
         let is_inner = !self.conditional_collection.is_empty();
 
         // conditionalFormatting
@@ -112,12 +179,51 @@ impl ConditionalFormatting {
         if is_inner {
             // cfRule
             for v in &self.conditional_collection {
-                v.write_to(writer, differential_formats);
+                if !v.has_x14_extension() {
+                    v.write_to(writer, differential_formats, None);
+                    continue;
+                }
+                let x14_id = ConditionalFormattingRule::make_x14_id(*x14_index);
+                *x14_index += 1;
+                if !v.is_x14_only() {
+                    v.write_to(writer, differential_formats, Some(&x14_id));
+                }
             }
 
             write_end_tag(writer, "conditionalFormatting");
         }
     }
+
+    /// Writes the x14 part of the rules. `x14_index` must follow the same order as `write_to`.
+    pub(crate) fn write_to_x14(&self, writer: &mut Writer<Cursor<Vec<u8>>>, x14_index: &mut usize) {
+        if !self.has_x14_extension() {
+            return;
+        }
+
+        // x14:conditionalFormatting
+        write_start_tag(
+            writer,
+            "x14:conditionalFormatting",
+            vec![("xmlns:xm", EXCEL_MAIN_NS)],
+            false,
+        );
+
+        // x14:cfRule
+        for v in &self.conditional_collection {
+            if v.has_x14_extension() {
+                let x14_id = ConditionalFormattingRule::make_x14_id(*x14_index);
+                *x14_index += 1;
+                v.write_to_x14(writer, &x14_id);
+            }
+        }
+
+        // xm:sqref
+        write_start_tag(writer, "xm:sqref", vec![], false);
+        write_text_node(writer, self.sequence_of_references.get_sqref());
+        write_end_tag(writer, "xm:sqref");
+
+        write_end_tag(writer, "x14:conditionalFormatting");
+    }
 }
 impl AdjustmentCoordinate for ConditionalFormatting {
     #[inline]
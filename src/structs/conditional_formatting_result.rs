@@ -0,0 +1,81 @@
+use super::office2010::excel::ConditionalFormattingIcon;
+use super::Color;
+use super::Style;
+
+/// Conditional formatting applied to one cell.
+/// Created with `Worksheet::get_conditional_formatting_result`.
+#[derive(Clone, Default, Debug)]
+pub struct ConditionalFormattingResult {
+    style: Style,
+    color_scale_color: Option<Color>,
+    data_bar_color: Option<Color>,
+    data_bar_length: Option<f64>,
+    icon: Option<ConditionalFormattingIcon>,
+}
+
+impl ConditionalFormattingResult {
+    /// Style of the cell with the differential formats of the matching rules
+    /// and the color scale fill applied.
+    #[inline]
+    pub fn get_style(&self) -> &Style {
+        &self.style
+    }
+
+    #[inline]
+    pub fn into_style(self) -> Style {
+        self.style
+    }
+
+    #[inline]
+    pub(crate) fn get_style_mut(&mut self) -> &mut Style {
+        &mut self.style
+    }
+
+    /// Color computed by a color scale.
+    #[inline]
+    pub fn get_color_scale_color(&self) -> Option<&Color> {
+        self.color_scale_color.as_ref()
+    }
+
+    #[inline]
+    pub(crate) fn set_color_scale_color(&mut self, value: Color) -> &mut Self {
+        self.color_scale_color = Some(value);
+        self
+    }
+
+    /// Fill color of a data bar.
+    #[inline]
+    pub fn get_data_bar_color(&self) -> Option<&Color> {
+        self.data_bar_color.as_ref()
+    }
+
+    /// Length of a data bar in percent of the cell width.
+    #[inline]
+    pub fn get_data_bar_length(&self) -> Option<f64> {
+        self.data_bar_length
+    }
+
+    #[inline]
+    pub(crate) fn set_data_bar(&mut self, color: Option<Color>, length: f64) -> &mut Self {
+        self.data_bar_color = color;
+        self.data_bar_length = Some(length);
+        self
+    }
+
+    /// Icon shown by an icon set.
+    #[inline]
+    pub fn get_icon(&self) -> Option<&ConditionalFormattingIcon> {
+        self.icon.as_ref()
+    }
+
+    #[inline]
+    pub(crate) fn set_icon(&mut self, value: ConditionalFormattingIcon) -> &mut Self {
+        self.icon = Some(value);
+        self
+    }
+
+    #[inline]
+    pub(crate) fn has_data_bar(&self) -> bool {
+        self.data_bar_length.is_some()
+    }
+}
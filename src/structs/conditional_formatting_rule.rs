@@ -12,13 +12,16 @@ use super::StringValue;
 use super::Style;
 use super::TimePeriodValues;
 use super::UInt32Value;
+use crate::helper::const_str::*;
 use crate::reader::driver::*;
 use crate::writer::driver::*;
+use crate::XlsxError;
 use quick_xml::events::BytesStart;
 use quick_xml::events::Event;
 use quick_xml::Reader;
 use quick_xml::Writer;
 use std::io::Cursor;
+use thin_vec::ThinVec;
 
 #[derive(Clone, Default, Debug)]
 pub struct ConditionalFormattingRule {
@@ -39,6 +42,8 @@ pub struct ConditionalFormattingRule {
     data_bar: Option<DataBar>,
     icon_set: Option<IconSet>,
     formula: Option<Box<Formula>>,
+    formula2: Option<Box<Formula>>,
+    x14_id: StringValue,
 }
 
 impl ConditionalFormattingRule {
@@ -259,19 +264,76 @@ impl ConditionalFormattingRule {
         self
     }
 
+    /// Second formula of the `between` and `notBetween` operators.
+    #[inline]
+    pub fn get_formula2(&self) -> Option<&Formula> {
+        self.formula2.as_deref()
+    }
+
+    #[inline]
+    pub fn set_formula2(&mut self, value: Formula) -> &mut Self {
+        self.formula2 = Some(Box::new(value));
+        self
+    }
+
+    #[inline]
+    pub fn remove_formula2(&mut self) -> &mut Self {
+        self.formula2 = None;
+        self
+    }
+
+    /// `aboveAverage` is true when the attribute is omitted.
+    #[inline]
+    pub(crate) fn is_above_average(&self) -> bool {
+        !self.above_average.has_value() || *self.above_average.get_value()
+    }
+
+    /// Whether the rule has options stored in the x14 extension of the worksheet.
+    #[inline]
+    pub(crate) fn has_x14_extension(&self) -> bool {
+        self.is_x14_only()
+            || self
+                .data_bar
+                .as_ref()
+                .map(|v| v.has_x14_extension())
+                .unwrap_or(false)
+    }
+
+    /// Whether the rule can only be stored in the x14 extension of the worksheet.
+    #[inline]
+    pub(crate) fn is_x14_only(&self) -> bool {
+        self.icon_set
+            .as_ref()
+            .map(|v| v.is_x14_only())
+            .unwrap_or(false)
+    }
+
+    /// Id linking the rule to its `x14:cfRule` when read from a file.
+    #[inline]
+    pub(crate) fn get_x14_id(&self) -> &str {
+        self.x14_id.get_value_str()
+    }
+
+    /// Id written to link the rule to its `x14:cfRule`.
+    #[inline]
+    pub(crate) fn make_x14_id(index: usize) -> String {
+        format!("{{00000000-0000-4000-8000-{:012X}}}", index + 1)
+    }
+
     pub(crate) fn set_attributes<R: std::io::BufRead>(
         &mut self,
         reader: &mut Reader<R>,
         e: &BytesStart,
         differential_formats: &DifferentialFormats,
         empty_flag: bool,
-    ) {
+    ) -> Result<(), XlsxError> {
         set_string_from_xml!(self, e, r#type, "type");
         set_string_from_xml!(self, e, operator, "operator");
 
-        if let Some(v) = get_attribute(e, b"dxfId") {
-            let dxf_id = v.parse::<usize>().unwrap();
-            let style = differential_formats.get_style(dxf_id);
+        if let Some(v) = get_attribute(e, b"dxfId")? {
+            let style = differential_formats
+                .get_style(parse_value("dxfId", &v)?)
+                .ok_or_else(|| invalid_attribute_error("dxfId", v))?;
             self.set_style(style);
         }
 
@@ -286,42 +348,96 @@ impl ConditionalFormattingRule {
         set_string_from_xml!(self, e, equal_average, "equalAverage");
 
         if empty_flag {
-            return;
+            return Ok(());
         }
 
+        let mut is_x14_id = false;
         xml_read_loop!(
             reader,
             Event::Start(ref e) => {
                 match e.name().into_inner() {
+                    b"x14:id" => is_x14_id = true,
                     b"colorScale" => {
                         let mut obj = ColorScale::default();
-                        obj.set_attributes(reader, e);
+                        obj.set_attributes(reader, e)?;
                         self.color_scale = Some(obj);
                     }
                     b"dataBar" => {
                         let mut obj = DataBar::default();
-                        obj.set_attributes(reader, e);
+                        obj.set_attributes(reader, e)?;
                         self.data_bar = Some(obj);
                     }
                     b"iconSet" => {
                         let mut obj = IconSet::default();
-                        obj.set_attributes(reader, e);
+                        obj.set_attributes(reader, e)?;
                         self.icon_set = Some(obj);
                     }
                     b"formula" => {
                         let mut obj = Formula::default();
-                        obj.set_attributes(reader, e);
-                        self.formula = Some(Box::new(obj));
+                        obj.set_attributes(reader, e)?;
+                        if self.formula.is_none() {
+                            self.formula = Some(Box::new(obj));
+                        } else {
+                            self.formula2 = Some(Box::new(obj));
+                        }
+                    }
+                    _ => (),
+                }
+            },
+            Event::Text(e) => {
+                if is_x14_id {
+                    self.x14_id.set_value(e.unescape()?);
+                }
+            },
+            Event::End(ref e) => {
+                match e.name().into_inner() {
+                    b"x14:id" => is_x14_id = false,
+                    b"cfRule" => return Ok(()),
+                    _ => (),
+                }
+            },
+            Event::Eof => return Err(end_element_error(reader, "cfRule"))
+        );
+    }
+
+    /// Reads `x14:cfRule`. Only data bars and icon sets are taken over.
+    pub(crate) fn set_attributes_x14<R: std::io::BufRead>(
+        &mut self,
+        reader: &mut Reader<R>,
+        e: &BytesStart,
+    ) -> Result<(), XlsxError> {
+        if !self.r#type.has_value() {
+            set_string_from_xml!(self, e, r#type, "type");
+        }
+        if !self.priority.has_value() {
+            set_string_from_xml!(self, e, priority, "priority");
+        }
+
+        xml_read_loop!(
+            reader,
+            Event::Start(ref e) => {
+                match e.name().into_inner() {
+                    b"x14:dataBar" => {
+                        // the x14 thresholds replace the ones of the main part (e.g. autoMin)
+                        let mut obj = self.data_bar.take().unwrap_or_default();
+                        obj.set_cfvo_collection(ThinVec::new());
+                        obj.set_attributes(reader, e)?;
+                        self.data_bar = Some(obj);
+                    }
+                    b"x14:iconSet" => {
+                        let mut obj = IconSet::default();
+                        obj.set_attributes(reader, e)?;
+                        self.icon_set = Some(obj);
                     }
                     _ => (),
                 }
             },
             Event::End(ref e) => {
-                if e.name().into_inner() == b"cfRule" {
-                    return
+                if e.name().into_inner() == b"x14:cfRule" {
+                    return Ok(())
                 }
             },
-            Event::Eof => panic!("Error: Could not find {} end element", "cfRule")
+            Event::Eof => return Err(end_element_error(reader, "x14:cfRule"))
         );
     }
 
@@ -329,11 +445,13 @@ impl ConditionalFormattingRule {
         &self,
         writer: &mut Writer<Cursor<Vec<u8>>>,
         differential_formats: &mut DifferentialFormats,
+        x14_id: Option<&str>,
     ) {
         let is_inner = self.color_scale.is_some()
             || self.data_bar.is_some()
             || self.icon_set.is_some()
-            || self.formula.is_some();
+            || self.formula.is_some()
+            || x14_id.is_some();
 
         // cfRule
         let mut attributes: Vec<(&str, &str)> = Vec::new();
@@ -422,8 +540,57 @@ impl ConditionalFormattingRule {
             if let Some(v) = &self.formula {
                 v.write_to(writer)
             }
+            if let Some(v) = &self.formula2 {
+                v.write_to(writer)
+            }
+
+            // extLst
+            if let Some(v) = x14_id {
+                write_start_tag(writer, "extLst", vec![], false);
+                write_start_tag(
+                    writer,
+                    "ext",
+                    vec![
+                        ("uri", "{B025F937-C7B1-47D3-B67F-A62EFF666E3E}"),
+                        ("xmlns:x14", SHEET_MS_MAIN_NS),
+                    ],
+                    false,
+                );
+                write_start_tag(writer, "x14:id", vec![], false);
+                write_text_node(writer, v);
+                write_end_tag(writer, "x14:id");
+                write_end_tag(writer, "ext");
+                write_end_tag(writer, "extLst");
+            }
 
             write_end_tag(writer, "cfRule");
         }
     }
+
+    pub(crate) fn write_to_x14(&self, writer: &mut Writer<Cursor<Vec<u8>>>, x14_id: &str) {
+        // x14:cfRule
+        let mut attributes: Vec<(&str, &str)> = Vec::new();
+        if self.r#type.has_value() {
+            attributes.push(("type", self.r#type.get_value_string()));
+        }
+        // the priority is kept by the main cfRule unless the rule only lives here
+        let priority = self.priority.get_value_string();
+        if self.is_x14_only() && self.priority.has_value() {
+            attributes.push(("priority", &priority));
+        }
+        attributes.push(("id", x14_id));
+        write_start_tag(writer, "x14:cfRule", attributes, false);
+
+        if self.is_x14_only() {
+            // x14:iconSet
+            if let Some(v) = &self.icon_set {
+                v.write_to_x14(writer);
+            }
+        } else if let Some(v) = &self.data_bar {
+            // x14:dataBar
+            v.write_to_x14(writer);
+        }
+
+        write_end_tag(writer, "x14:cfRule");
+    }
 }
@@ -0,0 +1,40 @@
+use super::EnumTrait;
+use std::str::FromStr;
+#[derive(Clone, Debug, Eq, Ord, PartialEq, PartialOrd)]
+pub enum CsvQuotingValues {
+    /// Quote only fields containing the delimiter, the quote char or a line break.
+    Minimal,
+    /// Quote every field.
+    All,
+    /// Quote every field that is not a number.
+    NonNumeric,
+}
+impl Default for CsvQuotingValues {
+    #[inline]
+    fn default() -> Self {
+        Self::Minimal
+    }
+}
+impl EnumTrait for CsvQuotingValues {
+    #[inline]
+    fn get_value_string(&self) -> &str {
+        match &self {
+            Self::Minimal => "minimal",
+            Self::All => "all",
+            Self::NonNumeric => "non_numeric",
+        }
+    }
+}
+impl FromStr for CsvQuotingValues {
+    type Err = ();
+
+    #[inline]
+    fn from_str(input: &str) -> Result<Self, Self::Err> {
+        match input {
+            "minimal" => Ok(Self::Minimal),
+            "all" => Ok(Self::All),
+            "non_numeric" => Ok(Self::NonNumeric),
+            _ => Err(()),
+        }
+    }
+}
@@ -0,0 +1,131 @@
+use super::EnumValue;
+use crate::helper::coordinate::CellCoordinates;
+use crate::structs::CsvEncodeValues;
+
+#[derive(Clone, Debug, Eq, Ord, PartialEq, PartialOrd)]
+pub struct CsvReaderOption {
+    pub(crate) csv_encode_values: EnumValue<CsvEncodeValues>,
+    pub(crate) delimiter: char,
+    pub(crate) quote_char: char,
+    pub(crate) has_header: bool,
+    pub(crate) do_trim: bool,
+    pub(crate) infer_types: bool,
+    pub(crate) sheet_name: Box<str>,
+    pub(crate) start_column: u32,
+    pub(crate) start_row: u32,
+}
+impl Default for CsvReaderOption {
+    #[inline]
+    fn default() -> Self {
+        Self {
+            csv_encode_values: EnumValue::default(),
+            delimiter: ',',
+            quote_char: '"',
+            has_header: false,
+            do_trim: false,
+            infer_types: true,
+            sheet_name: "".into(),
+            start_column: 1,
+            start_row: 1,
+        }
+    }
+}
+impl CsvReaderOption {
+    #[inline]
+    pub fn get_csv_encode_value(&self) -> &CsvEncodeValues {
+        self.csv_encode_values.get_value()
+    }
+
+    #[inline]
+    pub fn set_csv_encode_value(&mut self, value: CsvEncodeValues) -> &mut Self {
+        self.csv_encode_values.set_value(value);
+        self
+    }
+
+    /// Field separator (`,` by default, `\t` for TSV).
+    #[inline]
+    pub fn get_delimiter(&self) -> &char {
+        &self.delimiter
+    }
+
+    #[inline]
+    pub fn set_delimiter(&mut self, value: char) -> &mut Self {
+        self.delimiter = value;
+        self
+    }
+
+    #[inline]
+    pub fn get_quote_char(&self) -> &char {
+        &self.quote_char
+    }
+
+    #[inline]
+    pub fn set_quote_char(&mut self, value: char) -> &mut Self {
+        self.quote_char = value;
+        self
+    }
+
+    /// When true, the first record is imported as text without type inference.
+    #[inline]
+    pub fn get_has_header(&self) -> &bool {
+        &self.has_header
+    }
+
+    #[inline]
+    pub fn set_has_header(&mut self, value: bool) -> &mut Self {
+        self.has_header = value;
+        self
+    }
+
+    #[inline]
+    pub fn get_do_trim(&self) -> &bool {
+        &self.do_trim
+    }
+
+    #[inline]
+    pub fn set_do_trim(&mut self, value: bool) -> &mut Self {
+        self.do_trim = value;
+        self
+    }
+
+    /// When true, numbers, booleans and ISO dates are stored as typed values.
+    #[inline]
+    pub fn get_infer_types(&self) -> &bool {
+        &self.infer_types
+    }
+
+    #[inline]
+    pub fn set_infer_types(&mut self, value: bool) -> &mut Self {
+        self.infer_types = value;
+        self
+    }
+
+    /// Target sheet. When empty, the active sheet is used.
+    #[inline]
+    pub fn get_sheet_name(&self) -> &str {
+        &self.sheet_name
+    }
+
+    #[inline]
+    pub fn set_sheet_name<S: Into<String>>(&mut self, value: S) -> &mut Self {
+        self.sheet_name = value.into().into_boxed_str();
+        self
+    }
+
+    /// Cell that receives the first field of the first record.
+    #[inline]
+    pub fn get_start_cell(&self) -> (u32, u32) {
+        (self.start_column, self.start_row)
+    }
+
+    #[inline]
+    pub fn set_start_cell<T>(&mut self, coordinate: T) -> &mut Self
+    where
+        T: Into<CellCoordinates>,
+    {
+        let CellCoordinates { col, row } = coordinate.into();
+        self.start_column = col;
+        self.start_row = row;
+        self
+    }
+}
@@ -1,11 +1,38 @@
 use super::EnumValue;
 use crate::structs::CsvEncodeValues;
+use crate::structs::CsvQuotingValues;
 
-#[derive(Clone, Default, Debug, Eq, Ord, PartialEq, PartialOrd)]
+#[derive(Clone, Debug, Eq, Ord, PartialEq, PartialOrd)]
 pub struct CsvWriterOption {
     pub(crate) csv_encode_values: EnumValue<CsvEncodeValues>,
     pub(crate) wrap_with_char: Box<str>,
     pub(crate) do_trim: bool,
+    pub(crate) sheet_name: Box<str>,
+    pub(crate) sheet_index: Option<usize>,
+    pub(crate) range: Box<str>,
+    pub(crate) delimiter: char,
+    pub(crate) quoting: EnumValue<CsvQuotingValues>,
+    pub(crate) line_terminator: Box<str>,
+    pub(crate) write_bom: bool,
+    pub(crate) use_formatted_value: bool,
+}
+impl Default for CsvWriterOption {
+    #[inline]
+    fn default() -> Self {
+        Self {
+            csv_encode_values: EnumValue::default(),
+            wrap_with_char: "".into(),
+            do_trim: false,
+            sheet_name: "".into(),
+            sheet_index: None,
+            range: "".into(),
+            delimiter: ',',
+            quoting: EnumValue::default(),
+            line_terminator: "\r\n".into(),
+            write_bom: false,
+            use_formatted_value: false,
+        }
+    }
 }
 impl CsvWriterOption {
     #[inline]
@@ -19,6 +46,7 @@ impl CsvWriterOption {
         self
     }
 
+    /// When set, every field is wrapped with this char (embedded occurrences are doubled).
     #[inline]
     pub fn get_wrap_with_char(&self) -> &str {
         &self.wrap_with_char
@@ -40,4 +68,98 @@ impl CsvWriterOption {
         self.do_trim = value;
         self
     }
+
+    /// Sheet to export. Takes precedence over the sheet index.
+    #[inline]
+    pub fn get_sheet_name(&self) -> &str {
+        &self.sheet_name
+    }
+
+    #[inline]
+    pub fn set_sheet_name<S: Into<String>>(&mut self, value: S) -> &mut Self {
+        self.sheet_name = value.into().into_boxed_str();
+        self
+    }
+
+    /// Sheet to export. The active sheet is used when neither name nor index is set.
+    #[inline]
+    pub fn get_sheet_index(&self) -> Option<usize> {
+        self.sheet_index
+    }
+
+    #[inline]
+    pub fn set_sheet_index(&mut self, value: usize) -> &mut Self {
+        self.sheet_index = Some(value);
+        self
+    }
+
+    /// Range to export (e.g. `A1:C10`, `B:D` or `2:5`). The used range is exported when empty.
+    /// The writer returns `XlsxError::InvalidRange` for any other value.
+    #[inline]
+    pub fn get_range(&self) -> &str {
+        &self.range
+    }
+
+    #[inline]
+    pub fn set_range<S: Into<String>>(&mut self, value: S) -> &mut Self {
+        self.range = value.into().into_boxed_str();
+        self
+    }
+
+    #[inline]
+    pub fn get_delimiter(&self) -> &char {
+        &self.delimiter
+    }
+
+    #[inline]
+    pub fn set_delimiter(&mut self, value: char) -> &mut Self {
+        self.delimiter = value;
+        self
+    }
+
+    #[inline]
+    pub fn get_quoting(&self) -> &CsvQuotingValues {
+        self.quoting.get_value()
+    }
+
+    #[inline]
+    pub fn set_quoting(&mut self, value: CsvQuotingValues) -> &mut Self {
+        self.quoting.set_value(value);
+        self
+    }
+
+    #[inline]
+    pub fn get_line_terminator(&self) -> &str {
+        &self.line_terminator
+    }
+
+    #[inline]
+    pub fn set_line_terminator<S: Into<String>>(&mut self, value: S) -> &mut Self {
+        self.line_terminator = value.into().into_boxed_str();
+        self
+    }
+
+    /// Write a byte order mark for the UTF-8 and UTF-16 encodings.
+    #[inline]
+    pub fn get_write_bom(&self) -> &bool {
+        &self.write_bom
+    }
+
+    #[inline]
+    pub fn set_write_bom(&mut self, value: bool) -> &mut Self {
+        self.write_bom = value;
+        self
+    }
+
+    /// Export the value as displayed by its number format instead of the raw value.
+    #[inline]
+    pub fn get_use_formatted_value(&self) -> &bool {
+        &self.use_formatted_value
+    }
+
+    #[inline]
+    pub fn set_use_formatted_value(&mut self, value: bool) -> &mut Self {
+        self.use_formatted_value = value;
+        self
+    }
 }
@@ -0,0 +1,64 @@
+// customFilter
+use super::EnumValue;
+use super::FilterOperatorValues;
+use super::StringValue;
+use crate::reader::driver::*;
+use crate::writer::driver::*;
+use crate::XlsxError;
+use quick_xml::events::BytesStart;
+use quick_xml::Reader;
+use quick_xml::Writer;
+use std::io::Cursor;
+
+#[derive(Clone, Default, Debug)]
+pub struct CustomFilter {
+    operator: EnumValue<FilterOperatorValues>,
+    val: StringValue,
+}
+
+impl CustomFilter {
+    #[inline]
+    pub fn get_operator(&self) -> &FilterOperatorValues {
+        self.operator.get_value()
+    }
+
+    #[inline]
+    pub fn set_operator(&mut self, value: FilterOperatorValues) -> &mut Self {
+        self.operator.set_value(value);
+        self
+    }
+
+    /// Value to compare with. `*` and `?` are wildcards for text comparisons.
+    #[inline]
+    pub fn get_val(&self) -> &str {
+        self.val.get_value_str()
+    }
+
+    #[inline]
+    pub fn set_val<S: Into<String>>(&mut self, value: S) -> &mut Self {
+        self.val.set_value(value);
+        self
+    }
+
+    #[inline]
+    pub(crate) fn set_attributes<R: std::io::BufRead>(
+        &mut self,
+        _reader: &mut Reader<R>,
+        e: &BytesStart,
+    ) -> Result<(), XlsxError> {
+        set_string_from_xml!(self, e, operator, "operator");
+        set_string_from_xml!(self, e, val, "val");
+        Ok(())
+    }
+
+    #[inline]
+    pub(crate) fn write_to(&self, writer: &mut Writer<Cursor<Vec<u8>>>) {
+        // customFilter
+        let mut attributes: Vec<(&str, &str)> = Vec::new();
+        if self.operator.has_value() {
+            attributes.push(("operator", self.operator.get_value_string()));
+        }
+        attributes.push(("val", self.val.get_value_str()));
+        write_start_tag(writer, "customFilter", attributes, true);
+    }
+}
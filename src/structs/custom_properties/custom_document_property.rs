@@ -2,6 +2,7 @@ use crate::reader::driver::*;
 use crate::structs::custom_properties::CustomDocumentPropertyValue;
 use crate::structs::StringValue;
 use crate::writer::driver::*;
+use crate::XlsxError;
 use quick_xml::events::BytesStart;
 use quick_xml::events::Event;
 use quick_xml::Reader;
@@ -96,31 +97,31 @@ impl CustomDocumentProperty {
         reader: &mut Reader<R>,
         e: &BytesStart,
         empty_flag: bool,
-    ) {
+    ) -> Result<(), XlsxError> {
         set_string_from_xml!(self, e, name, "name");
         set_string_from_xml!(self, e, link_target, "linkTarget");
 
         if empty_flag {
-            return;
+            return Ok(());
         }
 
         let mut value: String = String::new();
         xml_read_loop!(
             reader,
             Event::Text(e) => {
-                value = e.unescape().unwrap().to_string();
+                value = e.unescape()?.to_string();
             },
             Event::End(ref e) => {
                 match e.name().into_inner(){
                     b"vt:lpwstr" =>{self.set_value_string(&value);}
                     b"vt:filetime" =>{self.set_value_date_manual(&value);}
-                    b"vt:i4"=> {self.set_value_number(value.parse::<i32>().unwrap());}
+                    b"vt:i4"=> {self.set_value_number(parse_value::<i32>("vt:i4", &value)?);}
                     b"vt:bool"=> {self.set_value_bool(matches!(value.as_str(), "true" | "1"));}
-                    b"property"=> {return}
+                    b"property"=> {return Ok(())}
                     _=>{}
                 }
             },
-            Event::Eof => panic!("Error: Could not find {} end element", "property")
+            Event::Eof => return Err(end_element_error(reader, "property"))
         );
     }
 
@@ -2,6 +2,7 @@ use crate::helper::const_str::*;
 use crate::reader::driver::*;
 use crate::structs::custom_properties::CustomDocumentProperty;
 use crate::writer::driver::*;
+use crate::XlsxError;
 use quick_xml::events::BytesStart;
 use quick_xml::events::Event;
 use quick_xml::Reader;
@@ -59,30 +60,30 @@ impl Properties {
         &mut self,
         reader: &mut Reader<R>,
         _e: &BytesStart,
-    ) {
+    ) -> Result<(), XlsxError> {
         let mut value: String = String::new();
         xml_read_loop!(
             reader,
             Event::Empty(ref e) => {
                 if e.name().into_inner() == b"property" {
                     let mut obj = CustomDocumentProperty::default();
-                    obj.set_attributes(reader, e, true);
+                    obj.set_attributes(reader, e, true)?;
                     self.add_custom_document_property_list(obj);
                 }
             },
             Event::Start(ref e) => {
                 if e.name().into_inner() == b"property" {
                     let mut obj = CustomDocumentProperty::default();
-                    obj.set_attributes(reader, e, false);
+                    obj.set_attributes(reader, e, false)?;
                     self.add_custom_document_property_list(obj);
                 }
             },
             Event::End(ref e) => {
                 if e.name().into_inner() == b"Properties" {
-                    return
+                    return Ok(())
                 }
             },
-            Event::Eof => panic!("Error: Could not find {} end element", "Properties")
+            Event::Eof => return Err(end_element_error(reader, "Properties"))
         );
     }
 
@@ -1,7 +1,14 @@
+use super::office2010::excel::DataBarAxisPositionValues;
+use super::office2010::excel::DataBarDirectionValues;
+use super::BooleanValue;
 use super::Color;
 use super::ConditionalFormatValueObject;
+use super::ConditionalFormatValueObjectValues;
+use super::EnumValue;
+use super::UInt32Value;
 use crate::reader::driver::*;
 use crate::writer::driver::*;
+use crate::XlsxError;
 use quick_xml::events::BytesStart;
 use quick_xml::events::Event;
 use quick_xml::Reader;
@@ -13,6 +20,19 @@ use thin_vec::ThinVec;
 pub struct DataBar {
     cfvo_collection: ThinVec<ConditionalFormatValueObject>,
     color_collection: ThinVec<Color>,
+    min_length: UInt32Value,
+    max_length: UInt32Value,
+    show_value: BooleanValue,
+    gradient: BooleanValue,
+    border: BooleanValue,
+    negative_bar_color_same_as_positive: BooleanValue,
+    negative_bar_border_color_same_as_positive: BooleanValue,
+    axis_position: EnumValue<DataBarAxisPositionValues>,
+    direction: EnumValue<DataBarDirectionValues>,
+    border_color: Color,
+    negative_fill_color: Color,
+    negative_border_color: Color,
+    axis_color: Color,
 }
 
 impl DataBar {
@@ -53,40 +73,300 @@ impl DataBar {
         self
     }
 
+    /// Minimum length of the bar in percent of the cell width. Excel uses 10 when not set.
+    #[inline]
+    pub fn get_min_length(&self) -> &u32 {
+        self.min_length.get_value()
+    }
+
+    #[inline]
+    pub fn set_min_length(&mut self, value: u32) -> &mut Self {
+        self.min_length.set_value(value);
+        self
+    }
+
+    /// Maximum length of the bar in percent of the cell width. Excel uses 90 when not set.
+    #[inline]
+    pub fn get_max_length(&self) -> &u32 {
+        self.max_length.get_value()
+    }
+
+    #[inline]
+    pub fn set_max_length(&mut self, value: u32) -> &mut Self {
+        self.max_length.set_value(value);
+        self
+    }
+
+    /// Show the cell value next to the bar. Excel shows it when not set.
+    #[inline]
+    pub fn get_show_value(&self) -> &bool {
+        self.show_value.get_value()
+    }
+
+    #[inline]
+    pub fn set_show_value(&mut self, value: bool) -> &mut Self {
+        self.show_value.set_value(value);
+        self
+    }
+
+    /// Gradient fill instead of solid fill. Excel uses a gradient when not set.
+    #[inline]
+    pub fn get_gradient(&self) -> &bool {
+        self.gradient.get_value()
+    }
+
+    #[inline]
+    pub fn set_gradient(&mut self, value: bool) -> &mut Self {
+        self.gradient.set_value(value);
+        self
+    }
+
+    /// Draw a border around the bar.
+    #[inline]
+    pub fn get_border(&self) -> &bool {
+        self.border.get_value()
+    }
+
+    #[inline]
+    pub fn set_border(&mut self, value: bool) -> &mut Self {
+        self.border.set_value(value);
+        self
+    }
+
+    #[inline]
+    pub fn get_negative_bar_color_same_as_positive(&self) -> &bool {
+        self.negative_bar_color_same_as_positive.get_value()
+    }
+
+    #[inline]
+    pub fn set_negative_bar_color_same_as_positive(&mut self, value: bool) -> &mut Self {
+        self.negative_bar_color_same_as_positive.set_value(value);
+        self
+    }
+
+    #[inline]
+    pub fn get_negative_bar_border_color_same_as_positive(&self) -> &bool {
+        self.negative_bar_border_color_same_as_positive.get_value()
+    }
+
+    #[inline]
+    pub fn set_negative_bar_border_color_same_as_positive(&mut self, value: bool) -> &mut Self {
+        self.negative_bar_border_color_same_as_positive
+            .set_value(value);
+        self
+    }
+
+    #[inline]
+    pub fn get_axis_position(&self) -> &DataBarAxisPositionValues {
+        self.axis_position.get_value()
+    }
+
+    #[inline]
+    pub fn set_axis_position(&mut self, value: DataBarAxisPositionValues) -> &mut Self {
+        self.axis_position.set_value(value);
+        self
+    }
+
+    #[inline]
+    pub fn get_direction(&self) -> &DataBarDirectionValues {
+        self.direction.get_value()
+    }
+
+    #[inline]
+    pub fn set_direction(&mut self, value: DataBarDirectionValues) -> &mut Self {
+        self.direction.set_value(value);
+        self
+    }
+
+    #[inline]
+    pub fn get_border_color(&self) -> &Color {
+        &self.border_color
+    }
+
+    #[inline]
+    pub fn get_border_color_mut(&mut self) -> &mut Color {
+        &mut self.border_color
+    }
+
+    #[inline]
+    pub fn set_border_color(&mut self, value: Color) -> &mut Self {
+        self.border_color = value;
+        self
+    }
+
+    #[inline]
+    pub fn get_negative_fill_color(&self) -> &Color {
+        &self.negative_fill_color
+    }
+
+    #[inline]
+    pub fn get_negative_fill_color_mut(&mut self) -> &mut Color {
+        &mut self.negative_fill_color
+    }
+
+    #[inline]
+    pub fn set_negative_fill_color(&mut self, value: Color) -> &mut Self {
+        self.negative_fill_color = value;
+        self
+    }
+
+    #[inline]
+    pub fn get_negative_border_color(&self) -> &Color {
+        &self.negative_border_color
+    }
+
+    #[inline]
+    pub fn get_negative_border_color_mut(&mut self) -> &mut Color {
+        &mut self.negative_border_color
+    }
+
+    #[inline]
+    pub fn set_negative_border_color(&mut self, value: Color) -> &mut Self {
+        self.negative_border_color = value;
+        self
+    }
+
+    #[inline]
+    pub fn get_axis_color(&self) -> &Color {
+        &self.axis_color
+    }
+
+    #[inline]
+    pub fn get_axis_color_mut(&mut self) -> &mut Color {
+        &mut self.axis_color
+    }
+
+    #[inline]
+    pub fn set_axis_color(&mut self, value: Color) -> &mut Self {
+        self.axis_color = value;
+        self
+    }
+
+    /// Minimum and maximum length of the bar, with the defaults of Excel when not set.
+    pub(crate) fn get_length_range(&self) -> (f64, f64) {
+        let min_length = if self.min_length.has_value() {
+            *self.min_length.get_value()
+        } else {
+            10
+        };
+        let max_length = if self.max_length.has_value() {
+            *self.max_length.get_value()
+        } else {
+            90
+        };
+        (min_length as f64, max_length as f64)
+    }
+
+    /// Whether an option only stored in the x14 extension is used.
+    pub(crate) fn has_x14_extension(&self) -> bool {
+        self.gradient.has_value()
+            || self.border.has_value()
+            || self.negative_bar_color_same_as_positive.has_value()
+            || self.negative_bar_border_color_same_as_positive.has_value()
+            || self.axis_position.has_value()
+            || self.direction.has_value()
+            || self.border_color.has_value()
+            || self.negative_fill_color.has_value()
+            || self.negative_border_color.has_value()
+            || self.axis_color.has_value()
+            || self.cfvo_collection.iter().any(|v| {
+                matches!(
+                    v.get_type(),
+                    ConditionalFormatValueObjectValues::AutoMin
+                        | ConditionalFormatValueObjectValues::AutoMax
+                )
+            })
+    }
+
+    /// Reads `dataBar` as well as `x14:dataBar`.
     pub(crate) fn set_attributes<R: std::io::BufRead>(
         &mut self,
         reader: &mut Reader<R>,
-        _e: &BytesStart,
-    ) {
+        e: &BytesStart,
+    ) -> Result<(), XlsxError> {
+        set_string_from_xml!(self, e, min_length, "minLength");
+        set_string_from_xml!(self, e, max_length, "maxLength");
+        set_string_from_xml!(self, e, show_value, "showValue");
+        set_string_from_xml!(self, e, gradient, "gradient");
+        set_string_from_xml!(self, e, border, "border");
+        set_string_from_xml!(
+            self,
+            e,
+            negative_bar_color_same_as_positive,
+            "negativeBarColorSameAsPositive"
+        );
+        set_string_from_xml!(
+            self,
+            e,
+            negative_bar_border_color_same_as_positive,
+            "negativeBarBorderColorSameAsPositive"
+        );
+        set_string_from_xml!(self, e, axis_position, "axisPosition");
+        set_string_from_xml!(self, e, direction, "direction");
+
         xml_read_loop!(
             reader,
             Event::Empty(ref e) => {
                 match e.name().into_inner() {
-                    b"cfvo" => {
+                    b"cfvo" | b"x14:cfvo" => {
                         let mut obj = ConditionalFormatValueObject::default();
-                        obj.set_attributes(reader, e, true);
+                        obj.set_attributes(reader, e, true)?;
                         self.cfvo_collection.push(obj);
                     }
                     b"color" => {
                         let mut obj = Color::default();
-                        obj.set_attributes(reader, e, true);
+                        obj.set_attributes(reader, e, true)?;
                         self.color_collection.push(obj);
                     }
+                    b"x14:fillColor" => {
+                        if self.color_collection.is_empty() {
+                            let mut obj = Color::default();
+                            obj.set_attributes(reader, e, true)?;
+                            self.color_collection.push(obj);
+                        }
+                    }
+                    b"x14:borderColor" => self.border_color.set_attributes(reader, e, true)?,
+                    b"x14:negativeFillColor" => {
+                        self.negative_fill_color.set_attributes(reader, e, true)?
+                    }
+                    b"x14:negativeBorderColor" => {
+                        self.negative_border_color.set_attributes(reader, e, true)?
+                    }
+                    b"x14:axisColor" => self.axis_color.set_attributes(reader, e, true)?,
                     _ => (),
                 }
             },
+            Event::Start(ref e) => {
+                if e.name().into_inner() == b"x14:cfvo" {
+                    let mut obj = ConditionalFormatValueObject::default();
+                    obj.set_attributes(reader, e, false)?;
+                    self.cfvo_collection.push(obj);
+                }
+            },
             Event::End(ref e) => {
-                if e.name().into_inner() == b"dataBar" {
-                    return
+                if matches!(e.name().into_inner(), b"dataBar" | b"x14:dataBar") {
+                    return Ok(())
                 }
             },
-            Event::Eof => panic!("Error: Could not find {} end element", "dataBar")
+            Event::Eof => return Err(end_element_error(reader, "dataBar"))
         );
     }
 
     pub(crate) fn write_to(&self, writer: &mut Writer<Cursor<Vec<u8>>>) {
         // dataBar
-        write_start_tag(writer, "dataBar", vec![], false);
+        let mut attributes: Vec<(&str, &str)> = Vec::new();
+        let min_length = self.min_length.get_value_string();
+        if self.min_length.has_value() {
+            attributes.push(("minLength", &min_length));
+        }
+        let max_length = self.max_length.get_value_string();
+        if self.max_length.has_value() {
+            attributes.push(("maxLength", &max_length));
+        }
+        if self.show_value.has_value() {
+            attributes.push(("showValue", self.show_value.get_value_string()));
+        }
+        write_start_tag(writer, "dataBar", attributes, false);
 
         // cfvo
         for v in &self.cfvo_collection {
@@ -100,4 +380,61 @@ impl DataBar {
 
         write_end_tag(writer, "dataBar");
     }
+
+    pub(crate) fn write_to_x14(&self, writer: &mut Writer<Cursor<Vec<u8>>>) {
+        // x14:dataBar
+        let mut attributes: Vec<(&str, &str)> = Vec::new();
+        let min_length = self.min_length.get_value_string();
+        if self.min_length.has_value() {
+            attributes.push(("minLength", &min_length));
+        }
+        let max_length = self.max_length.get_value_string();
+        if self.max_length.has_value() {
+            attributes.push(("maxLength", &max_length));
+        }
+        if self.gradient.has_value() {
+            attributes.push(("gradient", self.gradient.get_value_string()));
+        }
+        if self.border.has_value() {
+            attributes.push(("border", self.border.get_value_string()));
+        }
+        if self.negative_bar_color_same_as_positive.has_value() {
+            attributes.push((
+                "negativeBarColorSameAsPositive",
+                self.negative_bar_color_same_as_positive.get_value_string(),
+            ));
+        }
+        if self.negative_bar_border_color_same_as_positive.has_value() {
+            attributes.push((
+                "negativeBarBorderColorSameAsPositive",
+                self.negative_bar_border_color_same_as_positive
+                    .get_value_string(),
+            ));
+        }
+        if self.axis_position.has_value() {
+            attributes.push(("axisPosition", self.axis_position.get_value_string()));
+        }
+        if self.direction.has_value() {
+            attributes.push(("direction", self.direction.get_value_string()));
+        }
+        write_start_tag(writer, "x14:dataBar", attributes, false);
+
+        // x14:cfvo
+        for v in &self.cfvo_collection {
+            v.write_to_x14(writer);
+        }
+
+        // colors
+        if let Some(v) = self.color_collection.first() {
+            v.write_to(writer, "x14:fillColor");
+        }
+        self.border_color.write_to(writer, "x14:borderColor");
+        self.negative_fill_color
+            .write_to(writer, "x14:negativeFillColor");
+        self.negative_border_color
+            .write_to(writer, "x14:negativeBorderColor");
+        self.axis_color.write_to(writer, "x14:axisColor");
+
+        write_end_tag(writer, "x14:dataBar");
+    }
 }
@@ -1,11 +1,14 @@
 // dataField
 use crate::reader::driver::*;
 use crate::structs::BooleanValue;
+use crate::structs::DataConsolidateFunctionValues;
+use crate::structs::EnumValue;
 use crate::structs::Int32Value;
 use crate::structs::Location;
 use crate::structs::StringValue;
 use crate::structs::UInt32Value;
 use crate::writer::driver::*;
+use crate::XlsxError;
 use quick_xml::events::{BytesStart, Event};
 use quick_xml::Reader;
 use quick_xml::Writer;
@@ -17,6 +20,7 @@ pub struct DataField {
     fie_id: UInt32Value,
     base_fie_id: Int32Value,
     base_item: UInt32Value,
+    subtotal: EnumValue<DataConsolidateFunctionValues>,
 }
 impl DataField {
     #[inline]
@@ -63,31 +67,45 @@ impl DataField {
         self
     }
 
+    #[inline]
+    pub fn get_subtotal(&self) -> &DataConsolidateFunctionValues {
+        self.subtotal.get_value()
+    }
+
+    #[inline]
+    pub fn set_subtotal(&mut self, value: DataConsolidateFunctionValues) -> &mut Self {
+        self.subtotal.set_value(value);
+        self
+    }
+
     #[inline]
     pub(crate) fn set_attributes<R: std::io::BufRead>(
         &mut self,
         _reader: &mut Reader<R>,
         e: &BytesStart,
-    ) {
+    ) -> Result<(), XlsxError> {
         set_string_from_xml!(self, e, name, "name");
         set_string_from_xml!(self, e, fie_id, "fld");
         set_string_from_xml!(self, e, base_fie_id, "baseField");
         set_string_from_xml!(self, e, base_item, "baseItem");
+        set_string_from_xml!(self, e, subtotal, "subtotal");
+        Ok(())
     }
 
     #[inline]
     pub(crate) fn write_to(&self, writer: &mut Writer<Cursor<Vec<u8>>>) {
         // dataField
-        write_start_tag(
-            writer,
-            "dataField",
-            vec![
-                ("name", self.name.get_value_str()),
-                ("fld", &self.fie_id.get_value_string()),
-                ("baseField", &self.base_fie_id.get_value_string()),
-                ("baseItem", &self.base_item.get_value_string()),
-            ],
-            true,
-        );
+        let mut attributes: Vec<(&str, &str)> = Vec::new();
+        attributes.push(("name", self.name.get_value_str()));
+        let fie_id_str = self.fie_id.get_value_string();
+        attributes.push(("fld", &fie_id_str));
+        if self.subtotal.has_value() {
+            attributes.push(("subtotal", self.subtotal.get_value_string()));
+        }
+        let base_fie_id_str = self.base_fie_id.get_value_string();
+        attributes.push(("baseField", &base_fie_id_str));
+        let base_item_str = self.base_item.get_value_string();
+        attributes.push(("baseItem", &base_item_str));
+        write_start_tag(writer, "dataField", attributes, true);
     }
 }
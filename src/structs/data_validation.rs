@@ -7,6 +7,7 @@ use super::SequenceOfReferences;
 use super::StringValue;
 use crate::reader::driver::*;
 use crate::writer::driver::*;
+use crate::XlsxError;
 use quick_xml::events::{BytesStart, Event};
 use quick_xml::Reader;
 use quick_xml::Writer;
@@ -171,49 +172,33 @@ impl DataValidation {
         reader: &mut Reader<R>,
         e: &BytesStart,
         empty_flg: bool,
-    ) {
-        if let Some(v) = get_attribute(e, b"type") {
-            self.r#type.set_value_string(v);
+    ) -> Result<(), XlsxError> {
+        if let Some(v) = get_attribute(e, b"type")? {
+            self.r#type.try_set_value_string(v, "type")?;
         }
 
-        if let Some(v) = get_attribute(e, b"operator") {
-            self.operator.set_value_string(v);
-        }
+        set_string_from_xml!(self, e, operator, "operator");
 
-        if let Some(v) = get_attribute(e, b"allowBlank") {
-            self.allow_blank.set_value_string(v);
-        }
+        set_string_from_xml!(self, e, allow_blank, "allowBlank");
 
-        if let Some(v) = get_attribute(e, b"showInputMessage") {
-            self.show_input_message.set_value_string(v);
-        }
+        set_string_from_xml!(self, e, show_input_message, "showInputMessage");
 
-        if let Some(v) = get_attribute(e, b"showErrorMessage") {
-            self.show_error_message.set_value_string(v);
-        }
+        set_string_from_xml!(self, e, show_error_message, "showErrorMessage");
 
-        if let Some(v) = get_attribute(e, b"errorTitle") {
-            self.error_title.set_value_string(v);
-        }
+        set_string_from_xml!(self, e, error_title, "errorTitle");
 
-        if let Some(v) = get_attribute(e, b"error") {
-            self.error_messsage.set_value_string(v);
-        }
+        set_string_from_xml!(self, e, error_messsage, "error");
 
-        if let Some(v) = get_attribute(e, b"promptTitle") {
-            self.prompt_title.set_value_string(v);
-        }
+        set_string_from_xml!(self, e, prompt_title, "promptTitle");
 
-        if let Some(v) = get_attribute(e, b"prompt") {
-            self.prompt.set_value_string(v);
-        }
+        set_string_from_xml!(self, e, prompt, "prompt");
 
-        if let Some(v) = get_attribute(e, b"sqref") {
+        if let Some(v) = get_attribute(e, b"sqref")? {
             self.sequence_of_references.set_sqref(v);
         }
 
         if empty_flg {
-            return;
+            return Ok(());
         }
 
         let mut value: String = String::new();
@@ -221,7 +206,7 @@ impl DataValidation {
         loop {
             match reader.read_event_into(&mut buf) {
                 Ok(Event::Text(e)) => {
-                    value = e.unescape().unwrap().to_string();
+                    value = e.unescape()?.to_string();
                 }
                 Ok(Event::End(ref e)) => match e.name().into_inner() {
                     b"formula1" => {
@@ -230,11 +215,11 @@ impl DataValidation {
                     b"formula2" => {
                         self.formula2.set_value_string(std::mem::take(&mut value));
                     }
-                    b"dataValidation" => return,
+                    b"dataValidation" => return Ok(()),
                     _ => {}
                 },
-                Ok(Event::Eof) => panic!("Error: Could not find {} end element", "dataValidation"),
-                Err(e) => panic!("Error at position {}: {:?}", reader.buffer_position(), e),
+                Ok(Event::Eof) => return Err(end_element_error(reader, "dataValidation")),
+                Err(e) => return Err(xml_error(reader, e)),
                 _ => {}
             }
             buf.clear();
@@ -2,6 +2,7 @@
 use super::DataValidation;
 use crate::reader::driver::*;
 use crate::writer::driver::*;
+use crate::XlsxError;
 use quick_xml::events::{BytesStart, Event};
 use quick_xml::Reader;
 use quick_xml::Writer;
@@ -43,29 +44,29 @@ impl DataValidations {
         &mut self,
         reader: &mut Reader<R>,
         _e: &BytesStart,
-    ) {
+    ) -> Result<(), XlsxError> {
         xml_read_loop!(
             reader,
             Event::Empty(ref e) => {
                 if e.name().into_inner() == b"dataValidation" {
                     let mut obj = DataValidation::default();
-                    obj.set_attributes(reader, e, true);
+                    obj.set_attributes(reader, e, true)?;
                     self.add_data_validation_list(obj);
                 }
             },
             Event::Start(ref e) => {
                 if e.name().into_inner() == b"dataValidation" {
                     let mut obj = DataValidation::default();
-                    obj.set_attributes(reader, e, false);
+                    obj.set_attributes(reader, e, false)?;
                     self.add_data_validation_list(obj);
                 }
             },
             Event::End(ref e) => {
                 if e.name().into_inner() == b"dataValidations" {
-                    return
+                    return Ok(())
                 }
             },
-            Event::Eof => panic!("Error: Could not find {} end element", "dataValidations")
+            Event::Eof => return Err(end_element_error(reader, "dataValidations"))
         );
     }
 
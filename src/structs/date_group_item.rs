@@ -0,0 +1,164 @@
+// dateGroupItem
+use super::DateTimeGroupingValues;
+use super::EnumValue;
+use super::UInt16Value;
+use crate::reader::driver::*;
+use crate::writer::driver::*;
+use crate::XlsxError;
+use quick_xml::events::BytesStart;
+use quick_xml::Reader;
+use quick_xml::Writer;
+use std::io::Cursor;
+
+#[derive(Clone, Default, Debug)]
+pub struct DateGroupItem {
+    year: UInt16Value,
+    month: UInt16Value,
+    day: UInt16Value,
+    hour: UInt16Value,
+    minute: UInt16Value,
+    second: UInt16Value,
+    date_time_grouping: EnumValue<DateTimeGroupingValues>,
+}
+
+impl DateGroupItem {
+    #[inline]
+    pub fn get_year(&self) -> &u16 {
+        self.year.get_value()
+    }
+
+    #[inline]
+    pub fn set_year(&mut self, value: u16) -> &mut Self {
+        self.year.set_value(value);
+        self
+    }
+
+    #[inline]
+    pub fn get_month(&self) -> &u16 {
+        self.month.get_value()
+    }
+
+    #[inline]
+    pub fn set_month(&mut self, value: u16) -> &mut Self {
+        self.month.set_value(value);
+        self
+    }
+
+    #[inline]
+    pub fn get_day(&self) -> &u16 {
+        self.day.get_value()
+    }
+
+    #[inline]
+    pub fn set_day(&mut self, value: u16) -> &mut Self {
+        self.day.set_value(value);
+        self
+    }
+
+    #[inline]
+    pub fn get_hour(&self) -> &u16 {
+        self.hour.get_value()
+    }
+
+    #[inline]
+    pub fn set_hour(&mut self, value: u16) -> &mut Self {
+        self.hour.set_value(value);
+        self
+    }
+
+    #[inline]
+    pub fn get_minute(&self) -> &u16 {
+        self.minute.get_value()
+    }
+
+    #[inline]
+    pub fn set_minute(&mut self, value: u16) -> &mut Self {
+        self.minute.set_value(value);
+        self
+    }
+
+    #[inline]
+    pub fn get_second(&self) -> &u16 {
+        self.second.get_value()
+    }
+
+    #[inline]
+    pub fn set_second(&mut self, value: u16) -> &mut Self {
+        self.second.set_value(value);
+        self
+    }
+
+    /// The smallest unit that has to match, e.g. `Month` matches every day of `year`/`month`.
+    #[inline]
+    pub fn get_date_time_grouping(&self) -> &DateTimeGroupingValues {
+        self.date_time_grouping.get_value()
+    }
+
+    #[inline]
+    pub fn set_date_time_grouping(&mut self, value: DateTimeGroupingValues) -> &mut Self {
+        self.date_time_grouping.set_value(value);
+        self
+    }
+
+    pub(crate) fn set_attributes<R: std::io::BufRead>(
+        &mut self,
+        _reader: &mut Reader<R>,
+        e: &BytesStart,
+    ) -> Result<(), XlsxError> {
+        set_string_from_xml!(self, e, year, "year");
+        set_string_from_xml!(self, e, month, "month");
+        set_string_from_xml!(self, e, day, "day");
+        set_string_from_xml!(self, e, hour, "hour");
+        set_string_from_xml!(self, e, minute, "minute");
+        set_string_from_xml!(self, e, second, "second");
+        set_string_from_xml!(self, e, date_time_grouping, "dateTimeGrouping");
+        Ok(())
+    }
+
+    pub(crate) fn write_to(&self, writer: &mut Writer<Cursor<Vec<u8>>>) {
+        // dateGroupItem
+        let mut attributes: Vec<(&str, &str)> = Vec::new();
+        let year = self.year.get_value_string();
+        attributes.push(("year", &year));
+        let month = self.month.get_value_string();
+        let day = self.day.get_value_string();
+        let hour = self.hour.get_value_string();
+        let minute = self.minute.get_value_string();
+        let second = self.second.get_value_string();
+        let grouping = self.get_date_time_grouping();
+        if grouping != &DateTimeGroupingValues::Year {
+            attributes.push(("month", &month));
+        }
+        if matches!(
+            grouping,
+            DateTimeGroupingValues::Day
+                | DateTimeGroupingValues::Hour
+                | DateTimeGroupingValues::Minute
+                | DateTimeGroupingValues::Second
+        ) {
+            attributes.push(("day", &day));
+        }
+        if matches!(
+            grouping,
+            DateTimeGroupingValues::Hour
+                | DateTimeGroupingValues::Minute
+                | DateTimeGroupingValues::Second
+        ) {
+            attributes.push(("hour", &hour));
+        }
+        if matches!(
+            grouping,
+            DateTimeGroupingValues::Minute | DateTimeGroupingValues::Second
+        ) {
+            attributes.push(("minute", &minute));
+        }
+        if grouping == &DateTimeGroupingValues::Second {
+            attributes.push(("second", &second));
+        }
+        attributes.push((
+            "dateTimeGrouping",
+            self.date_time_grouping.get_value_string(),
+        ));
+        write_start_tag(writer, "dateGroupItem", attributes, true);
+    }
+}
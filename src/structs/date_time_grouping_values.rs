@@ -0,0 +1,46 @@
+use super::EnumTrait;
+use std::str::FromStr;
+#[derive(Clone, Debug, Eq, Ord, PartialEq, PartialOrd)]
+pub enum DateTimeGroupingValues {
+    Day,
+    Hour,
+    Minute,
+    Month,
+    Second,
+    Year,
+}
+impl Default for DateTimeGroupingValues {
+    #[inline]
+    fn default() -> Self {
+        Self::Year
+    }
+}
+impl EnumTrait for DateTimeGroupingValues {
+    #[inline]
+    fn get_value_string(&self) -> &str {
+        match &self {
+            Self::Day => "day",
+            Self::Hour => "hour",
+            Self::Minute => "minute",
+            Self::Month => "month",
+            Self::Second => "second",
+            Self::Year => "year",
+        }
+    }
+}
+impl FromStr for DateTimeGroupingValues {
+    type Err = ();
+
+    #[inline]
+    fn from_str(input: &str) -> Result<Self, Self::Err> {
+        match input {
+            "day" => Ok(Self::Day),
+            "hour" => Ok(Self::Hour),
+            "minute" => Ok(Self::Minute),
+            "month" => Ok(Self::Month),
+            "second" => Ok(Self::Second),
+            "year" => Ok(Self::Year),
+            _ => Err(()),
+        }
+    }
+}
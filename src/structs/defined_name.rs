@@ -6,6 +6,7 @@ use crate::helper::address::*;
 use crate::reader::driver::*;
 use crate::traits::AdjustmentCoordinateWithSheet;
 use crate::writer::driver::*;
+use crate::XlsxError;
 use quick_xml::events::{BytesStart, Event};
 use quick_xml::Reader;
 use quick_xml::Writer;
@@ -173,7 +174,7 @@ impl DefinedName {
         &mut self,
         reader: &mut Reader<R>,
         e: &BytesStart,
-    ) {
+    ) -> Result<(), XlsxError> {
         set_string_from_xml!(self, e, name, "name");
         set_string_from_xml!(self, e, local_sheet_id, "localSheetId");
         set_string_from_xml!(self, e, hidden, "hidden");
@@ -182,15 +183,15 @@ impl DefinedName {
         xml_read_loop!(
             reader,
                 Event::Text(e) => {
-                    value = e.unescape().unwrap().to_string();
+                    value = e.unescape()?.to_string();
                 },
                 Event::End(ref e) => {
                     if e.name().into_inner() == b"definedName" {
                         self.set_address(value);
-                        return
+                        return Ok(())
                     }
                 },
-                Event::Eof => panic!("Error: Could not find {} end element", "definedName")
+                Event::Eof => return Err(end_element_error(reader, "definedName"))
         );
     }
 
@@ -1,3 +1,5 @@
+use crate::reader::driver::*;
+use crate::XlsxError;
 use super::BorderStyleValues;
 use super::Color;
 use super::EnumValue;
@@ -82,26 +84,24 @@ impl DiagonalBorder {
         &mut self,
         reader: &mut Reader<R>,
         e: &BytesStart,
-    ) {
-        if let Some(v) = get_attribute(e, b"style") {
-            self.style.set_value_string(v);
-        }
+    ) -> Result<(), XlsxError> {
+        set_string_from_xml!(self, e, style, "style");
 
         let mut buf = Vec::new();
         loop {
             match reader.read_event_into(&mut buf) {
                 Ok(Event::Empty(ref e)) => match e.name().into_inner() {
                     b"color" => {
-                        &mut self.color.set_attributes(reader, e);
+                        &mut self.color.set_attributes(reader, e)?;
                     }
                     _ => (),
                 },
                 Ok(Event::End(ref e)) => match e.name().into_inner() {
-                    b"diagonal" => return,
+                    b"diagonal" => return Ok(()),
                     _ => (),
                 },
-                Ok(Event::Eof) => panic!("Error: Could not find {} end element", "diagonal"),
-                Err(e) => panic!("Error at position {}: {:?}", reader.buffer_position(), e),
+                Ok(Event::Eof) => return Err(end_element_error(reader, "diagonal")),
+                Err(e) => return Err(xml_error(reader, e)),
                 _ => (),
             }
             buf.clear();
@@ -6,6 +6,7 @@ use super::Font;
 use super::Style;
 use crate::reader::driver::*;
 use crate::writer::driver::*;
+use crate::XlsxError;
 use md5::Digest;
 use quick_xml::events::{BytesStart, Event};
 use quick_xml::Reader;
@@ -148,29 +149,29 @@ impl DifferentialFormat {
         &mut self,
         reader: &mut Reader<R>,
         _e: &BytesStart,
-    ) {
+    ) -> Result<(), XlsxError> {
         xml_read_loop!(
             reader,
             Event::Start(ref e) => {
                 match e.name().into_inner() {
                     b"font" => {
                         let mut obj = Font::default();
-                        obj.set_attributes(reader, e);
+                        obj.set_attributes(reader, e)?;
                         self.set_font(obj);
                     }
                     b"fill" => {
                         let mut obj = Fill::default();
-                        obj.set_attributes(reader, e);
+                        obj.set_attributes(reader, e)?;
                         self.set_fill(obj);
                     }
                     b"border" => {
                         let mut obj = Borders::default();
-                        obj.set_attributes(reader, e);
+                        obj.set_attributes(reader, e)?;
                         self.set_borders(obj);
                     }
                     b"alignment" => {
                         let mut obj = Alignment::default();
-                        obj.set_attributes(reader, e);
+                        obj.set_attributes(reader, e)?;
                         self.set_alignment(obj);
                     }
                     _ => (),
@@ -178,10 +179,10 @@ impl DifferentialFormat {
             },
             Event::End(ref e) => {
                 if e.name().into_inner() == b"dxf" {
-                    return
+                    return Ok(())
                 }
             },
-            Event::Eof => panic!("Error: Could not find {} end element", "dxf")
+            Event::Eof => return Err(end_element_error(reader, "dxf"))
         );
     }
 
@@ -3,6 +3,7 @@ use super::DifferentialFormat;
 use super::Style;
 use crate::reader::driver::*;
 use crate::writer::driver::*;
+use crate::XlsxError;
 use quick_xml::events::{BytesStart, Event};
 use quick_xml::Reader;
 use quick_xml::Writer;
@@ -32,9 +33,8 @@ impl DifferentialFormats {
     }
 
     #[inline]
-    pub(crate) fn get_style(&self, id: usize) -> Style {
-        let differential_format = self.differential_format.get(id).unwrap().clone();
-        differential_format.get_style()
+    pub(crate) fn get_style(&self, id: usize) -> Option<Style> {
+        self.differential_format.get(id).map(|v| v.get_style())
     }
 
     pub(crate) fn set_style(&mut self, style: &Style) -> u32 {
@@ -58,22 +58,22 @@ impl DifferentialFormats {
         &mut self,
         reader: &mut Reader<R>,
         _e: &BytesStart,
-    ) {
+    ) -> Result<(), XlsxError> {
         xml_read_loop!(
             reader,
             Event::Start(ref e) => {
                 if e.name().into_inner() == b"dxf" {
                     let mut obj = DifferentialFormat::default();
-                    obj.set_attributes(reader, e);
+                    obj.set_attributes(reader, e)?;
                     self.set_differential_format(obj);
                 }
             },
             Event::End(ref e) => {
                 if e.name().into_inner() == b"dxfs" {
-                    return
+                    return Ok(())
                 }
             },
-            Event::Eof => panic!("Error: Could not find {} end element", "dxfs")
+            Event::Eof => return Err(end_element_error(reader, "dxfs"))
         );
     }
 
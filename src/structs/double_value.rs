@@ -1,3 +1,5 @@
+use crate::reader::driver::invalid_attribute_error;
+use crate::XlsxError;
 #[derive(Clone, Default, Debug, PartialEq, PartialOrd)]
 pub struct DoubleValue {
     value: Option<f64>,
@@ -27,6 +29,19 @@ impl DoubleValue {
         self.set_value(value.into().parse::<f64>().unwrap_or_default())
     }
 
+    #[inline]
+    pub(crate) fn try_set_value_string<S: Into<String>>(
+        &mut self,
+        value: S,
+        name: &str,
+    ) -> Result<&mut Self, XlsxError> {
+        let value = value.into();
+        match value.parse::<f64>() {
+            Ok(v) => Ok(self.set_value(v)),
+            Err(_) => Err(invalid_attribute_error(name, value)),
+        }
+    }
+
     #[inline]
     pub(crate) fn has_value(&self) -> bool {
         self.value.is_some()
@@ -1,5 +1,6 @@
 //! structs of drawing.
 
+pub mod chart_ex;
 pub mod charts;
 pub mod spreadsheet;
 
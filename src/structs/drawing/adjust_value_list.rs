@@ -2,6 +2,7 @@
 use super::shape_guide::ShapeGuide;
 use crate::reader::driver::*;
 use crate::writer::driver::*;
+use crate::XlsxError;
 use quick_xml::events::{BytesStart, Event};
 use quick_xml::Reader;
 use quick_xml::Writer;
@@ -38,23 +39,23 @@ impl AdjustValueList {
         &mut self,
         reader: &mut Reader<R>,
         _e: &BytesStart,
-    ) {
+    ) -> Result<(), XlsxError> {
         xml_read_loop!(
             reader,
             Event::Empty(ref e) => {
                 if e.name().into_inner() == b"a:gd" {
                     let mut shape_guide = ShapeGuide::default();
-                    shape_guide.set_name(get_attribute(e, b"name").unwrap());
-                    shape_guide.set_fmla(get_attribute(e, b"fmla").unwrap());
+                    shape_guide.set_name(get_required_attribute(e, b"name")?);
+                    shape_guide.set_fmla(get_required_attribute(e, b"fmla")?);
                     self.add_shape_guide_collection(shape_guide);
                 }
             },
             Event::End(ref e) => {
                 if e.name().into_inner() == b"a:avLst" {
-                    return;
+                    return Ok(());
                 }
             },
-            Event::Eof => panic!("Error: Could not find {} end element", "a:avLst")
+            Event::Eof => return Err(end_element_error(reader, "a:avLst"))
         );
     }
 
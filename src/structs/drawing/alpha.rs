@@ -1,6 +1,7 @@
 // a:alpha
 use crate::reader::driver::*;
 use crate::writer::driver::*;
+use crate::XlsxError;
 use quick_xml::events::BytesStart;
 use quick_xml::Reader;
 use quick_xml::Writer;
@@ -26,8 +27,9 @@ impl Alpha {
         &mut self,
         _reader: &mut Reader<R>,
         e: &BytesStart,
-    ) {
-        self.set_val(get_attribute(e, b"val").unwrap());
+    ) -> Result<(), XlsxError> {
+        self.set_val(get_required_attribute(e, b"val")?);
+        Ok(())
     }
 
     #[inline]
@@ -2,6 +2,7 @@ use super::GradientFill;
 use super::SolidFill;
 use crate::reader::driver::*;
 use crate::writer::driver::*;
+use crate::XlsxError;
 use quick_xml::events::{BytesStart, Event};
 use quick_xml::Reader;
 use quick_xml::Writer;
@@ -66,19 +67,19 @@ impl BackgroundFillStyleList {
         &mut self,
         reader: &mut Reader<R>,
         _e: &BytesStart,
-    ) {
+    ) -> Result<(), XlsxError> {
         xml_read_loop!(
             reader,
             Event::Start(ref e) => {
                 match e.name().into_inner() {
                 b"a:solidFill" => {
                     let mut obj = SolidFill::default();
-                    obj.set_attributes(reader, e);
+                    obj.set_attributes(reader, e)?;
                     self.solid_fill.push(obj);
                 }
                 b"a:gradFill" => {
                     let mut obj = GradientFill::default();
-                    obj.set_attributes(reader, e);
+                    obj.set_attributes(reader, e)?;
                     self.gradient_fill_collection.push(obj);
                 }
                 _ => (),
@@ -86,10 +87,10 @@ impl BackgroundFillStyleList {
             },
             Event::End(ref e) => {
                 if  e.name().into_inner() == b"a:bgFillStyleLst" {
-                    return
+                    return Ok(())
                 }
             },
-            Event::Eof => panic!("Error: Could not find {} end element", "bgFillStyleLst")
+            Event::Eof => return Err(end_element_error(reader, "bgFillStyleLst"))
         );
     }
 
@@ -1,5 +1,6 @@
 // a:bevel
 use crate::writer::driver::*;
+use crate::XlsxError;
 use quick_xml::events::BytesStart;
 use quick_xml::Reader;
 use quick_xml::Writer;
@@ -13,7 +14,8 @@ impl Bevel {
         &mut self,
         _reader: &mut Reader<R>,
         _e: &BytesStart,
-    ) {
+    ) -> Result<(), XlsxError> {
+        Ok(())
     }
 
     #[inline]
@@ -4,6 +4,7 @@ use super::super::Int64Value;
 use super::BevelPresetValues;
 use crate::reader::driver::*;
 use crate::writer::driver::*;
+use crate::XlsxError;
 use quick_xml::events::BytesStart;
 use quick_xml::Reader;
 use quick_xml::Writer;
@@ -55,10 +56,11 @@ impl BevelTop {
         &mut self,
         _reader: &mut Reader<R>,
         e: &BytesStart,
-    ) {
+    ) -> Result<(), XlsxError> {
         set_string_from_xml!(self, e, width, "w");
         set_string_from_xml!(self, e, height, "h");
         set_string_from_xml!(self, e, preset, "prst");
+        Ok(())
     }
 
     pub(crate) fn write_to(&self, writer: &mut Writer<Cursor<Vec<u8>>>) {
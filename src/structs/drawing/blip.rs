@@ -4,6 +4,7 @@ use crate::reader::driver::*;
 use crate::structs::raw::RawRelationships;
 use crate::structs::MediaObject;
 use crate::writer::driver::*;
+use crate::XlsxError;
 use quick_xml::events::{BytesStart, Event};
 use quick_xml::Reader;
 use quick_xml::Writer;
@@ -49,30 +50,30 @@ impl Blip {
         e: &BytesStart,
         drawing_relationships: &RawRelationships,
         empty_flag: bool,
-    ) {
-        if let Some(v) = get_attribute(e, b"cstate") {
+    ) -> Result<(), XlsxError> {
+        if let Some(v) = get_attribute(e, b"cstate")? {
             self.set_cstate(v);
         }
 
-        let picture_id = get_attribute(e, b"r:embed").unwrap();
-        let relationship = drawing_relationships.get_relationship_by_rid(&picture_id);
+        let picture_id = get_required_attribute(e, b"r:embed")?;
+        let relationship = drawing_relationships.get_relationship_by_rid(&picture_id)?;
         self.get_image_mut()
             .set_image_name(relationship.get_raw_file().get_file_name());
         self.get_image_mut()
             .set_image_data(relationship.get_raw_file().get_file_data());
 
         if empty_flag {
-            return;
+            return Ok(());
         }
 
         xml_read_loop!(
             reader,
             Event::End(ref e) => {
                 if e.name().into_inner() == b"a:blip" {
-                    return
+                    return Ok(())
                 }
             },
-            Event::Eof => panic!("Error: Could not find {} end element", "a:blip")
+            Event::Eof => return Err(end_element_error(reader, "a:blip"))
         );
     }
 
@@ -6,6 +6,7 @@ use super::TextWrappingValues;
 use crate::reader::driver::*;
 use crate::writer::driver::*;
 use crate::StringValue;
+use crate::XlsxError;
 use quick_xml::events::{BytesStart, Event};
 use quick_xml::Reader;
 use quick_xml::Writer;
@@ -137,11 +138,11 @@ impl BodyProperties {
         reader: &mut Reader<R>,
         e: &BytesStart,
         empty_flag: bool,
-    ) {
+    ) -> Result<(), XlsxError> {
         for attr in e.attributes().with_checks(false) {
             if let Ok(attr) = attr {
                 let key = attr.key.into_inner();
-                let value = get_attribute_value(&attr).unwrap();
+                let value = get_attribute_value(&attr)?;
                 match key {
                     b"vertOverflow" => {
                         self.set_vert_overflow(value);
@@ -156,19 +157,19 @@ impl BodyProperties {
                         self.set_anchor(value);
                     }
                     b"wrap" => {
-                        self.wrap.set_value_string(value);
+                        self.wrap.try_set_value_string(value, "wrap")?;
                     }
                     b"lIns" => {
-                        self.left_inset.set_value_string(value);
+                        self.left_inset.try_set_value_string(value, "lIns")?;
                     }
                     b"tIns" => {
-                        self.top_inset.set_value_string(value);
+                        self.top_inset.try_set_value_string(value, "tIns")?;
                     }
                     b"rIns" => {
-                        self.right_inset.set_value_string(value);
+                        self.right_inset.try_set_value_string(value, "rIns")?;
                     }
                     b"bIns" => {
-                        self.bottom_inset.set_value_string(value);
+                        self.bottom_inset.try_set_value_string(value, "bIns")?;
                     }
                     _ => {}
                 }
@@ -176,7 +177,7 @@ impl BodyProperties {
         }
 
         if empty_flag {
-            return;
+            return Ok(());
         }
 
         xml_read_loop!(
@@ -184,16 +185,16 @@ impl BodyProperties {
             Event::Empty(ref e) => {
                 if e.name().into_inner() == b"a:spAutoFit" {
                     let mut obj = ShapeAutoFit::default();
-                    obj.set_attributes(reader, e);
+                    obj.set_attributes(reader, e)?;
                     self.set_shape_auto_fit(obj);
                 }
             },
             Event::End(ref e) => {
                 if e.name().into_inner() == b"a:bodyPr" {
-                     return
+                     return Ok(())
                 }
             },
-            Event::Eof => panic!("Error: Could not find {} end element", "a:bodyPr")
+            Event::Eof => return Err(end_element_error(reader, "a:bodyPr"))
         );
     }
 
@@ -4,6 +4,7 @@ use super::PresetCameraValues;
 use super::Rotation;
 use crate::reader::driver::*;
 use crate::writer::driver::*;
+use crate::XlsxError;
 use quick_xml::events::{BytesStart, Event};
 use quick_xml::Reader;
 use quick_xml::Writer;
@@ -48,11 +49,11 @@ impl Camera {
         reader: &mut Reader<R>,
         e: &BytesStart,
         empty_flag: bool,
-    ) {
+    ) -> Result<(), XlsxError> {
         set_string_from_xml!(self, e, preset, "prst");
 
         if empty_flag {
-            return;
+            return Ok(());
         }
 
         xml_read_loop!(
@@ -60,16 +61,16 @@ impl Camera {
             Event::Empty(ref e) => {
                 if e.name().into_inner() == b"a:rot" {
                     let mut obj = Rotation::default();
-                    obj.set_attributes(reader, e);
+                    obj.set_attributes(reader, e)?;
                     self.rotation = Some(Box::new(obj));
                 }
             },
             Event::End(ref e) => {
                 if e.name().into_inner() == b"a:camera" {
-                    return
+                    return Ok(())
                 }
             },
-            Event::Eof => panic!("Error: Could not find {} end element", "a:camera")
+            Event::Eof => return Err(end_element_error(reader, "a:camera"))
         );
     }
 
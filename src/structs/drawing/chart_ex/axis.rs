@@ -0,0 +1,301 @@
+// cx:axis
+use super::super::super::BooleanValue;
+use super::super::super::DoubleValue;
+use super::super::super::UInt32Value;
+use super::RawElement;
+use crate::reader::driver::*;
+use crate::writer::driver::*;
+use crate::xml_read_loop;
+use crate::XlsxError;
+use quick_xml::events::{BytesStart, Event};
+use quick_xml::Reader;
+use quick_xml::Writer;
+use std::io::Cursor;
+use thin_vec::ThinVec;
+
+const RAW_CHILDREN: &[&str] = &[
+    "cx:title",
+    "cx:units",
+    "cx:majorGridlines",
+    "cx:minorGridlines",
+    "cx:majorTickMarks",
+    "cx:minorTickMarks",
+    "cx:tickLabels",
+    "cx:numFmt",
+    "cx:spPr",
+    "cx:txPr",
+    "cx:extLst",
+];
+
+/// A category or value axis of a ChartEx plot area.
+/// Scaling values that are not set are left to Excel (`auto`).
+#[derive(Clone, Default, Debug)]
+pub struct Axis {
+    id: UInt32Value,
+    hidden: BooleanValue,
+    is_category: bool,
+    gap_width: DoubleValue,
+    max: DoubleValue,
+    min: DoubleValue,
+    major_unit: DoubleValue,
+    minor_unit: DoubleValue,
+    major_gridlines: bool,
+    minor_gridlines: bool,
+    tick_labels: bool,
+    raw_children: ThinVec<RawElement>,
+}
+
+impl Axis {
+    pub fn get_id(&self) -> &u32 {
+        self.id.get_value()
+    }
+
+    pub fn set_id(&mut self, value: u32) -> &mut Self {
+        self.id.set_value(value);
+        self
+    }
+
+    pub fn get_hidden(&self) -> &bool {
+        self.hidden.get_value()
+    }
+
+    pub fn set_hidden(&mut self, value: bool) -> &mut Self {
+        self.hidden.set_value(value);
+        self
+    }
+
+    /// Whether the axis uses `cx:catScaling` rather than `cx:valScaling`.
+    pub fn get_is_category(&self) -> &bool {
+        &self.is_category
+    }
+
+    pub fn set_is_category(&mut self, value: bool) -> &mut Self {
+        self.is_category = value;
+        self
+    }
+
+    pub fn get_gap_width(&self) -> Option<&f64> {
+        if self.gap_width.has_value() {
+            return Some(self.gap_width.get_value());
+        }
+        None
+    }
+
+    pub fn set_gap_width(&mut self, value: f64) -> &mut Self {
+        self.gap_width.set_value(value);
+        self
+    }
+
+    pub fn get_max(&self) -> Option<&f64> {
+        if self.max.has_value() {
+            return Some(self.max.get_value());
+        }
+        None
+    }
+
+    pub fn set_max(&mut self, value: f64) -> &mut Self {
+        self.max.set_value(value);
+        self
+    }
+
+    pub fn get_min(&self) -> Option<&f64> {
+        if self.min.has_value() {
+            return Some(self.min.get_value());
+        }
+        None
+    }
+
+    pub fn set_min(&mut self, value: f64) -> &mut Self {
+        self.min.set_value(value);
+        self
+    }
+
+    pub fn get_major_unit(&self) -> Option<&f64> {
+        if self.major_unit.has_value() {
+            return Some(self.major_unit.get_value());
+        }
+        None
+    }
+
+    pub fn set_major_unit(&mut self, value: f64) -> &mut Self {
+        self.major_unit.set_value(value);
+        self
+    }
+
+    pub fn get_minor_unit(&self) -> Option<&f64> {
+        if self.minor_unit.has_value() {
+            return Some(self.minor_unit.get_value());
+        }
+        None
+    }
+
+    pub fn set_minor_unit(&mut self, value: f64) -> &mut Self {
+        self.minor_unit.set_value(value);
+        self
+    }
+
+    pub fn get_major_gridlines(&self) -> &bool {
+        &self.major_gridlines
+    }
+
+    pub fn set_major_gridlines(&mut self, value: bool) -> &mut Self {
+        self.major_gridlines = value;
+        self
+    }
+
+    pub fn get_minor_gridlines(&self) -> &bool {
+        &self.minor_gridlines
+    }
+
+    pub fn set_minor_gridlines(&mut self, value: bool) -> &mut Self {
+        self.minor_gridlines = value;
+        self
+    }
+
+    pub fn get_tick_labels(&self) -> &bool {
+        &self.tick_labels
+    }
+
+    pub fn set_tick_labels(&mut self, value: bool) -> &mut Self {
+        self.tick_labels = value;
+        self
+    }
+
+    pub(crate) fn set_attributes<R: std::io::BufRead>(
+        &mut self,
+        reader: &mut Reader<R>,
+        e: &BytesStart,
+    ) -> Result<(), XlsxError> {
+        set_string_from_xml!(self, e, id, "id");
+        set_string_from_xml!(self, e, hidden, "hidden");
+
+        xml_read_loop!(
+            reader,
+            Event::Start(ref e) => {
+                match e.name().0 {
+                    b"cx:majorGridlines" => self.major_gridlines = true,
+                    b"cx:minorGridlines" => self.minor_gridlines = true,
+                    b"cx:tickLabels" => self.tick_labels = true,
+                    _ => (),
+                }
+                RawElement::read_into(&mut self.raw_children, RAW_CHILDREN, reader, e, false)?;
+            },
+            Event::Empty(ref e) => match e.name().0 {
+                b"cx:catScaling" => {
+                    self.is_category = true;
+                    Self::set_scaling_attribute(&mut self.gap_width, e, "gapWidth")?;
+                }
+                b"cx:valScaling" => {
+                    self.is_category = false;
+                    Self::set_scaling_attribute(&mut self.max, e, "max")?;
+                    Self::set_scaling_attribute(&mut self.min, e, "min")?;
+                    Self::set_scaling_attribute(&mut self.major_unit, e, "majorUnit")?;
+                    Self::set_scaling_attribute(&mut self.minor_unit, e, "minorUnit")?;
+                }
+                b"cx:majorGridlines" => self.major_gridlines = true,
+                b"cx:minorGridlines" => self.minor_gridlines = true,
+                b"cx:tickLabels" => self.tick_labels = true,
+                _ => RawElement::read_into(&mut self.raw_children, RAW_CHILDREN, reader, e, true)?,
+            },
+            Event::End(ref e) => {
+                if e.name().0 == b"cx:axis" {
+                    return Ok(());
+                }
+            },
+            Event::Eof => return Err(end_element_error(reader, "cx:axis")),
+        );
+    }
+
+    fn set_scaling_attribute(
+        value: &mut DoubleValue,
+        e: &BytesStart,
+        name: &str,
+    ) -> Result<(), XlsxError> {
+        if let Some(v) = get_attribute(e, name.as_bytes())? {
+            if v != "auto" {
+                value.try_set_value_string(v, name)?;
+            }
+        }
+        Ok(())
+    }
+
+    pub(crate) fn write_to(&self, writer: &mut Writer<Cursor<Vec<u8>>>) {
+        // cx:axis
+        let id = self.id.get_value_string();
+        let mut attributes: Vec<(&str, &str)> = vec![("id", &id)];
+        if self.hidden.has_value() {
+            attributes.push(("hidden", self.hidden.get_value_string()));
+        }
+        write_start_tag(writer, "cx:axis", attributes, false);
+
+        if self.is_category {
+            // cx:catScaling
+            let gap_width = self.gap_width.get_value_string();
+            let mut attributes: Vec<(&str, &str)> = Vec::new();
+            if self.gap_width.has_value() {
+                attributes.push(("gapWidth", &gap_width));
+            }
+            write_start_tag(writer, "cx:catScaling", attributes, true);
+        } else {
+            // cx:valScaling
+            let max = self.max.get_value_string();
+            let min = self.min.get_value_string();
+            let major_unit = self.major_unit.get_value_string();
+            let minor_unit = self.minor_unit.get_value_string();
+            let mut attributes: Vec<(&str, &str)> = Vec::new();
+            if self.max.has_value() {
+                attributes.push(("max", &max));
+            }
+            if self.min.has_value() {
+                attributes.push(("min", &min));
+            }
+            if self.major_unit.has_value() {
+                attributes.push(("majorUnit", &major_unit));
+            }
+            if self.minor_unit.has_value() {
+                attributes.push(("minorUnit", &minor_unit));
+            }
+            write_start_tag(writer, "cx:valScaling", attributes, true);
+        }
+
+        // cx:title, cx:units
+        RawElement::write_list(writer, &self.raw_children, &["cx:title", "cx:units"]);
+
+        // cx:majorGridlines
+        if self.major_gridlines
+            && !RawElement::write_list(writer, &self.raw_children, &["cx:majorGridlines"])
+        {
+            write_start_tag(writer, "cx:majorGridlines", vec![], true);
+        }
+
+        // cx:minorGridlines
+        if self.minor_gridlines
+            && !RawElement::write_list(writer, &self.raw_children, &["cx:minorGridlines"])
+        {
+            write_start_tag(writer, "cx:minorGridlines", vec![], true);
+        }
+
+        // cx:majorTickMarks, cx:minorTickMarks
+        RawElement::write_list(
+            writer,
+            &self.raw_children,
+            &["cx:majorTickMarks", "cx:minorTickMarks"],
+        );
+
+        // cx:tickLabels
+        if self.tick_labels
+            && !RawElement::write_list(writer, &self.raw_children, &["cx:tickLabels"])
+        {
+            write_start_tag(writer, "cx:tickLabels", vec![], true);
+        }
+
+        // cx:numFmt, cx:spPr, cx:txPr, cx:extLst
+        RawElement::write_list(
+            writer,
+            &self.raw_children,
+            &["cx:numFmt", "cx:spPr", "cx:txPr", "cx:extLst"],
+        );
+
+        write_end_tag(writer, "cx:axis");
+    }
+}
@@ -0,0 +1,112 @@
+// cx:chartData
+use super::Data;
+use crate::reader::driver::*;
+use crate::structs::Spreadsheet;
+use crate::traits::AdjustmentCoordinateWithSheet;
+use crate::writer::driver::*;
+use crate::xml_read_loop;
+use crate::XlsxError;
+use quick_xml::events::{BytesStart, Event};
+use quick_xml::Reader;
+use quick_xml::Writer;
+use std::io::Cursor;
+use thin_vec::ThinVec;
+
+#[derive(Clone, Default, Debug)]
+pub struct ChartData {
+    data: ThinVec<Data>,
+}
+
+impl ChartData {
+    pub fn get_data(&self) -> &[Data] {
+        &self.data
+    }
+
+    pub fn get_data_mut(&mut self) -> &mut ThinVec<Data> {
+        &mut self.data
+    }
+
+    pub fn set_data(&mut self, value: impl Into<ThinVec<Data>>) -> &mut Self {
+        self.data = value.into();
+        self
+    }
+
+    pub fn add_data(&mut self, value: Data) -> &mut Self {
+        self.data.push(value);
+        self
+    }
+
+    pub(crate) fn set_attributes<R: std::io::BufRead>(
+        &mut self,
+        reader: &mut Reader<R>,
+        _e: &BytesStart,
+    ) -> Result<(), XlsxError> {
+        xml_read_loop!(
+            reader,
+            Event::Start(ref e) => {
+                if e.name().0 == b"cx:data" {
+                    let mut obj = Data::default();
+                    obj.set_attributes(reader, e)?;
+                    self.add_data(obj);
+                }
+            },
+            Event::End(ref e) => {
+                if e.name().0 == b"cx:chartData" {
+                    return Ok(());
+                }
+            },
+            Event::Eof => return Err(end_element_error(reader, "cx:chartData")),
+        );
+    }
+
+    pub(crate) fn write_to(&self, writer: &mut Writer<Cursor<Vec<u8>>>, spreadsheet: &Spreadsheet) {
+        // cx:chartData
+        write_start_tag(writer, "cx:chartData", vec![], false);
+
+        // cx:data
+        for v in &self.data {
+            v.write_to(writer, spreadsheet);
+        }
+
+        write_end_tag(writer, "cx:chartData");
+    }
+}
+impl AdjustmentCoordinateWithSheet for ChartData {
+    fn adjustment_insert_coordinate_with_sheet(
+        &mut self,
+        sheet_name: &str,
+        root_col_num: &u32,
+        offset_col_num: &u32,
+        root_row_num: &u32,
+        offset_row_num: &u32,
+    ) {
+        for v in &mut self.data {
+            v.adjustment_insert_coordinate_with_sheet(
+                sheet_name,
+                root_col_num,
+                offset_col_num,
+                root_row_num,
+                offset_row_num,
+            );
+        }
+    }
+
+    fn adjustment_remove_coordinate_with_sheet(
+        &mut self,
+        sheet_name: &str,
+        root_col_num: &u32,
+        offset_col_num: &u32,
+        root_row_num: &u32,
+        offset_row_num: &u32,
+    ) {
+        for v in &mut self.data {
+            v.adjustment_remove_coordinate_with_sheet(
+                sheet_name,
+                root_col_num,
+                offset_col_num,
+                root_row_num,
+                offset_row_num,
+            );
+        }
+    }
+}
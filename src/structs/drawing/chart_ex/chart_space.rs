@@ -0,0 +1,201 @@
+// cx:chartSpace
+use super::Chart;
+use super::ChartData;
+use super::RawElement;
+use crate::helper::const_str::*;
+use crate::reader::driver::*;
+use crate::structs::raw::RawFile;
+use crate::structs::Spreadsheet;
+use crate::traits::AdjustmentCoordinateWithSheet;
+use crate::writer::driver::*;
+use crate::xml_read_loop;
+use crate::XlsxError;
+use quick_xml::events::{BytesStart, Event};
+use quick_xml::Reader;
+use quick_xml::Writer;
+use std::io::Cursor;
+use thin_vec::ThinVec;
+
+const RAW_CHILDREN: &[&str] = &[
+    "cx:spPr",
+    "cx:txPr",
+    "cx:clrMapOvr",
+    "cx:fmtOvrs",
+    "cx:printSettings",
+    "cx:extLst",
+];
+
+#[derive(Clone, Default, Debug)]
+pub struct ChartSpace {
+    chart_data: ChartData,
+    chart: Chart,
+    namespaces: ThinVec<(String, String)>,
+    raw_children: ThinVec<RawElement>,
+    file_target: String,
+    related_parts: ThinVec<(String, RawFile)>,
+}
+
+impl ChartSpace {
+    pub fn get_chart_data(&self) -> &ChartData {
+        &self.chart_data
+    }
+
+    pub fn get_chart_data_mut(&mut self) -> &mut ChartData {
+        &mut self.chart_data
+    }
+
+    pub fn set_chart_data(&mut self, value: ChartData) -> &mut Self {
+        self.chart_data = value;
+        self
+    }
+
+    pub fn get_chart(&self) -> &Chart {
+        &self.chart
+    }
+
+    pub fn get_chart_mut(&mut self) -> &mut Chart {
+        &mut self.chart
+    }
+
+    pub fn set_chart(&mut self, value: Chart) -> &mut Self {
+        self.chart = value;
+        self
+    }
+
+    /// Part the chart was read from.
+    #[inline]
+    pub(crate) fn get_file_target(&self) -> &str {
+        &self.file_target
+    }
+
+    #[inline]
+    pub(crate) fn set_file_target<S: Into<String>>(&mut self, value: S) -> &mut Self {
+        self.file_target = value.into();
+        self
+    }
+
+    /// Style and colors parts of the chart, with their relationship type.
+    #[inline]
+    pub(crate) fn get_related_parts(&self) -> &[(String, RawFile)] {
+        &self.related_parts
+    }
+
+    #[inline]
+    pub(crate) fn add_related_part<S: Into<String>>(
+        &mut self,
+        relationship_type: S,
+        value: RawFile,
+    ) -> &mut Self {
+        self.related_parts.push((relationship_type.into(), value));
+        self
+    }
+
+    pub(crate) fn set_attributes<R: std::io::BufRead>(
+        &mut self,
+        reader: &mut Reader<R>,
+        e: &BytesStart,
+    ) -> Result<(), XlsxError> {
+        for attr in e.attributes().with_checks(false).flatten() {
+            let key = String::from_utf8_lossy(attr.key.into_inner()).into_owned();
+            if key.starts_with("xmlns:") && !matches!(&*key, "xmlns:a" | "xmlns:r" | "xmlns:cx") {
+                let value = get_attribute_value(&attr)?;
+                self.namespaces.push((key, value));
+            }
+        }
+
+        xml_read_loop!(
+            reader,
+            Event::Start(ref e) => match e.name().0 {
+                b"cx:chartData" => {
+                    self.chart_data.set_attributes(reader, e)?;
+                }
+                b"cx:chart" => {
+                    self.chart.set_attributes(reader, e)?;
+                }
+                _ => RawElement::read_into(&mut self.raw_children, RAW_CHILDREN, reader, e, false)?,
+            },
+            Event::Empty(ref e) => {
+                RawElement::read_into(&mut self.raw_children, RAW_CHILDREN, reader, e, true)?;
+            },
+            Event::End(ref e) => {
+                if e.name().0 == b"cx:chartSpace" {
+                    return Ok(());
+                }
+            },
+            Event::Eof => return Err(end_element_error(reader, "cx:chartSpace")),
+        );
+    }
+
+    pub(crate) fn write_to(&self, writer: &mut Writer<Cursor<Vec<u8>>>, spreadsheet: &Spreadsheet) {
+        // cx:chartSpace
+        let mut attributes: Vec<(&str, &str)> = vec![
+            ("xmlns:a", DRAWINGML_MAIN_NS),
+            ("xmlns:r", REL_OFC_NS),
+            ("xmlns:cx", CHARTEX_NS),
+        ];
+        for (key, value) in &self.namespaces {
+            attributes.push((key, value));
+        }
+        write_start_tag(writer, "cx:chartSpace", attributes, false);
+
+        // cx:chartData
+        self.chart_data.write_to(writer, spreadsheet);
+
+        // cx:chart
+        self.chart.write_to(writer, spreadsheet);
+
+        // cx:spPr, cx:txPr, cx:clrMapOvr, cx:fmtOvrs, cx:printSettings, cx:extLst
+        RawElement::write_list(writer, &self.raw_children, RAW_CHILDREN);
+
+        write_end_tag(writer, "cx:chartSpace");
+    }
+}
+impl AdjustmentCoordinateWithSheet for ChartSpace {
+    fn adjustment_insert_coordinate_with_sheet(
+        &mut self,
+        sheet_name: &str,
+        root_col_num: &u32,
+        offset_col_num: &u32,
+        root_row_num: &u32,
+        offset_row_num: &u32,
+    ) {
+        self.chart_data.adjustment_insert_coordinate_with_sheet(
+            sheet_name,
+            root_col_num,
+            offset_col_num,
+            root_row_num,
+            offset_row_num,
+        );
+        self.chart.adjustment_insert_coordinate_with_sheet(
+            sheet_name,
+            root_col_num,
+            offset_col_num,
+            root_row_num,
+            offset_row_num,
+        );
+    }
+
+    fn adjustment_remove_coordinate_with_sheet(
+        &mut self,
+        sheet_name: &str,
+        root_col_num: &u32,
+        offset_col_num: &u32,
+        root_row_num: &u32,
+        offset_row_num: &u32,
+    ) {
+        self.chart_data.adjustment_remove_coordinate_with_sheet(
+            sheet_name,
+            root_col_num,
+            offset_col_num,
+            root_row_num,
+            offset_row_num,
+        );
+        self.chart.adjustment_remove_coordinate_with_sheet(
+            sheet_name,
+            root_col_num,
+            offset_col_num,
+            root_row_num,
+            offset_row_num,
+        );
+    }
+}
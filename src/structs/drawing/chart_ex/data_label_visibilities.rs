@@ -0,0 +1,71 @@
+// cx:visibility
+use super::super::super::BooleanValue;
+use crate::reader::driver::*;
+use crate::writer::driver::*;
+use crate::XlsxError;
+use quick_xml::events::BytesStart;
+use quick_xml::Reader;
+use quick_xml::Writer;
+use std::io::Cursor;
+
+#[derive(Clone, Default, Debug)]
+pub struct DataLabelVisibilities {
+    series_name: BooleanValue,
+    category_name: BooleanValue,
+    value: BooleanValue,
+}
+
+impl DataLabelVisibilities {
+    pub fn get_series_name(&self) -> &bool {
+        self.series_name.get_value()
+    }
+
+    pub fn set_series_name(&mut self, value: bool) -> &mut Self {
+        self.series_name.set_value(value);
+        self
+    }
+
+    pub fn get_category_name(&self) -> &bool {
+        self.category_name.get_value()
+    }
+
+    pub fn set_category_name(&mut self, value: bool) -> &mut Self {
+        self.category_name.set_value(value);
+        self
+    }
+
+    pub fn get_value(&self) -> &bool {
+        self.value.get_value()
+    }
+
+    pub fn set_value(&mut self, value: bool) -> &mut Self {
+        self.value.set_value(value);
+        self
+    }
+
+    pub(crate) fn set_attributes<R: std::io::BufRead>(
+        &mut self,
+        _reader: &mut Reader<R>,
+        e: &BytesStart,
+    ) -> Result<(), XlsxError> {
+        set_string_from_xml!(self, e, series_name, "seriesName");
+        set_string_from_xml!(self, e, category_name, "categoryName");
+        set_string_from_xml!(self, e, value, "value");
+        Ok(())
+    }
+
+    pub(crate) fn write_to(&self, writer: &mut Writer<Cursor<Vec<u8>>>) {
+        // cx:visibility
+        let mut attributes: Vec<(&str, &str)> = Vec::new();
+        if self.series_name.has_value() {
+            attributes.push(("seriesName", self.series_name.get_value_string()));
+        }
+        if self.category_name.has_value() {
+            attributes.push(("categoryName", self.category_name.get_value_string()));
+        }
+        if self.value.has_value() {
+            attributes.push(("value", self.value.get_value_string()));
+        }
+        write_start_tag(writer, "cx:visibility", attributes, true);
+    }
+}
@@ -0,0 +1,129 @@
+// cx:dataLabels
+use super::super::super::EnumValue;
+use super::DataLabelPositionValues;
+use super::DataLabelVisibilities;
+use super::RawElement;
+use crate::reader::driver::*;
+use crate::writer::driver::*;
+use crate::xml_read_loop;
+use crate::XlsxError;
+use quick_xml::events::{BytesStart, Event};
+use quick_xml::Reader;
+use quick_xml::Writer;
+use std::io::Cursor;
+use thin_vec::ThinVec;
+
+const RAW_CHILDREN: &[&str] = &[
+    "cx:numFmt",
+    "cx:spPr",
+    "cx:txPr",
+    "cx:separator",
+    "cx:dataLabel",
+    "cx:dataLabelHidden",
+    "cx:extLst",
+];
+
+#[derive(Clone, Default, Debug)]
+pub struct DataLabels {
+    position: EnumValue<DataLabelPositionValues>,
+    visibility: Option<DataLabelVisibilities>,
+    raw_children: ThinVec<RawElement>,
+}
+
+impl DataLabels {
+    pub fn get_position(&self) -> &DataLabelPositionValues {
+        self.position.get_value()
+    }
+
+    pub fn set_position(&mut self, value: DataLabelPositionValues) -> &mut Self {
+        self.position.set_value(value);
+        self
+    }
+
+    pub fn get_visibility(&self) -> Option<&DataLabelVisibilities> {
+        self.visibility.as_ref()
+    }
+
+    pub fn get_visibility_mut(&mut self) -> Option<&mut DataLabelVisibilities> {
+        self.visibility.as_mut()
+    }
+
+    pub fn set_visibility(&mut self, value: DataLabelVisibilities) -> &mut Self {
+        self.visibility = Some(value);
+        self
+    }
+
+    pub(crate) fn set_attributes<R: std::io::BufRead>(
+        &mut self,
+        reader: &mut Reader<R>,
+        e: &BytesStart,
+        empty_flg: bool,
+    ) -> Result<(), XlsxError> {
+        set_string_from_xml!(self, e, position, "pos");
+
+        if empty_flg {
+            return Ok(());
+        }
+
+        xml_read_loop!(
+            reader,
+            Event::Start(ref e) => {
+                RawElement::read_into(&mut self.raw_children, RAW_CHILDREN, reader, e, false)?;
+            },
+            Event::Empty(ref e) => {
+                if e.name().0 == b"cx:visibility" {
+                    let mut obj = DataLabelVisibilities::default();
+                    obj.set_attributes(reader, e)?;
+                    self.set_visibility(obj);
+                } else {
+                    RawElement::read_into(&mut self.raw_children, RAW_CHILDREN, reader, e, true)?;
+                }
+            },
+            Event::End(ref e) => {
+                if e.name().0 == b"cx:dataLabels" {
+                    return Ok(());
+                }
+            },
+            Event::Eof => return Err(end_element_error(reader, "cx:dataLabels")),
+        );
+    }
+
+    pub(crate) fn write_to(&self, writer: &mut Writer<Cursor<Vec<u8>>>) {
+        // cx:dataLabels
+        let mut attributes: Vec<(&str, &str)> = Vec::new();
+        if self.position.has_value() {
+            attributes.push(("pos", self.position.get_value_string()));
+        }
+        let empty_flg = self.visibility.is_none() && self.raw_children.is_empty();
+        write_start_tag(writer, "cx:dataLabels", attributes, empty_flg);
+        if empty_flg {
+            return;
+        }
+
+        // cx:numFmt, cx:spPr, cx:txPr
+        RawElement::write_list(
+            writer,
+            &self.raw_children,
+            &["cx:numFmt", "cx:spPr", "cx:txPr"],
+        );
+
+        // cx:visibility
+        if let Some(v) = &self.visibility {
+            v.write_to(writer);
+        }
+
+        // cx:separator, cx:dataLabel, cx:dataLabelHidden, cx:extLst
+        RawElement::write_list(
+            writer,
+            &self.raw_children,
+            &[
+                "cx:separator",
+                "cx:dataLabel",
+                "cx:dataLabelHidden",
+                "cx:extLst",
+            ],
+        );
+
+        write_end_tag(writer, "cx:dataLabels");
+    }
+}
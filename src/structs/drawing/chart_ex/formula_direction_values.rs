@@ -0,0 +1,27 @@
+use super::super::super::EnumTrait;
+use std::str::FromStr;
+#[derive(Clone, Debug, Default)]
+pub enum FormulaDirectionValues {
+    #[default]
+    Column,
+    Row,
+}
+
+impl EnumTrait for FormulaDirectionValues {
+    fn get_value_string(&self) -> &str {
+        match &self {
+            Self::Column => "col",
+            Self::Row => "row",
+        }
+    }
+}
+impl FromStr for FormulaDirectionValues {
+    type Err = ();
+    fn from_str(input: &str) -> Result<FormulaDirectionValues, Self::Err> {
+        match input {
+            "col" => Ok(Self::Column),
+            "row" => Ok(Self::Row),
+            _ => Err(()),
+        }
+    }
+}
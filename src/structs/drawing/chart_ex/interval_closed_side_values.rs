@@ -0,0 +1,27 @@
+use super::super::super::EnumTrait;
+use std::str::FromStr;
+#[derive(Clone, Debug, Default)]
+pub enum IntervalClosedSideValues {
+    Left,
+    #[default]
+    Right,
+}
+
+impl EnumTrait for IntervalClosedSideValues {
+    fn get_value_string(&self) -> &str {
+        match &self {
+            Self::Left => "l",
+            Self::Right => "r",
+        }
+    }
+}
+impl FromStr for IntervalClosedSideValues {
+    type Err = ();
+    fn from_str(input: &str) -> Result<IntervalClosedSideValues, Self::Err> {
+        match input {
+            "l" => Ok(Self::Left),
+            "r" => Ok(Self::Right),
+            _ => Err(()),
+        }
+    }
+}
@@ -0,0 +1,225 @@
+// cx:layoutPr
+use super::super::super::BooleanValue;
+use super::super::super::EnumValue;
+use super::Binning;
+use super::ParentLabelLayoutValues;
+use super::QuartileMethodValues;
+use super::RawElement;
+use super::SeriesElementVisibilities;
+use crate::reader::driver::*;
+use crate::writer::driver::*;
+use crate::xml_read_loop;
+use crate::XlsxError;
+use quick_xml::events::{BytesStart, Event};
+use quick_xml::Reader;
+use quick_xml::Writer;
+use std::io::Cursor;
+use thin_vec::ThinVec;
+
+const RAW_CHILDREN: &[&str] = &["cx:regionLabelLayout", "cx:geography", "cx:extLst"];
+
+/// Layout options of a series, which depend on the chart type.
+#[derive(Clone, Default, Debug)]
+pub struct LayoutProperties {
+    parent_label_layout: EnumValue<ParentLabelLayoutValues>,
+    visibility: Option<SeriesElementVisibilities>,
+    aggregation: bool,
+    binning: Option<Binning>,
+    quartile_method: EnumValue<QuartileMethodValues>,
+    subtotals: ThinVec<u32>,
+    raw_children: ThinVec<RawElement>,
+}
+
+impl LayoutProperties {
+    /// Label layout of treemap parents.
+    pub fn get_parent_label_layout(&self) -> &ParentLabelLayoutValues {
+        self.parent_label_layout.get_value()
+    }
+
+    pub fn set_parent_label_layout(&mut self, value: ParentLabelLayoutValues) -> &mut Self {
+        self.parent_label_layout.set_value(value);
+        self
+    }
+
+    pub fn get_visibility(&self) -> Option<&SeriesElementVisibilities> {
+        self.visibility.as_ref()
+    }
+
+    pub fn get_visibility_mut(&mut self) -> Option<&mut SeriesElementVisibilities> {
+        self.visibility.as_mut()
+    }
+
+    pub fn set_visibility(&mut self, value: SeriesElementVisibilities) -> &mut Self {
+        self.visibility = Some(value);
+        self
+    }
+
+    /// Whether a pareto or histogram series aggregates duplicate categories instead of binning.
+    pub fn get_aggregation(&self) -> &bool {
+        &self.aggregation
+    }
+
+    pub fn set_aggregation(&mut self, value: bool) -> &mut Self {
+        self.aggregation = value;
+        if value {
+            self.binning = None;
+        }
+        self
+    }
+
+    pub fn get_binning(&self) -> Option<&Binning> {
+        self.binning.as_ref()
+    }
+
+    pub fn get_binning_mut(&mut self) -> Option<&mut Binning> {
+        self.binning.as_mut()
+    }
+
+    pub fn set_binning(&mut self, value: Binning) -> &mut Self {
+        self.binning = Some(value);
+        self.aggregation = false;
+        self
+    }
+
+    pub fn get_quartile_method(&self) -> &QuartileMethodValues {
+        self.quartile_method.get_value()
+    }
+
+    pub fn set_quartile_method(&mut self, value: QuartileMethodValues) -> &mut Self {
+        self.quartile_method.set_value(value);
+        self
+    }
+
+    /// Zero-based indexes of the waterfall points drawn as subtotals.
+    pub fn get_subtotals(&self) -> &[u32] {
+        &self.subtotals
+    }
+
+    pub fn get_subtotals_mut(&mut self) -> &mut ThinVec<u32> {
+        &mut self.subtotals
+    }
+
+    pub fn set_subtotals(&mut self, value: impl Into<ThinVec<u32>>) -> &mut Self {
+        self.subtotals = value.into();
+        self
+    }
+
+    pub fn add_subtotal(&mut self, value: u32) -> &mut Self {
+        self.subtotals.push(value);
+        self
+    }
+
+    pub(crate) fn set_attributes<R: std::io::BufRead>(
+        &mut self,
+        reader: &mut Reader<R>,
+        _e: &BytesStart,
+    ) -> Result<(), XlsxError> {
+        xml_read_loop!(
+            reader,
+            Event::Start(ref e) => {
+                if e.name().0 == b"cx:binning" {
+                    let mut obj = Binning::default();
+                    obj.set_attributes(reader, e, false)?;
+                    self.set_binning(obj);
+                } else {
+                    RawElement::read_into(&mut self.raw_children, RAW_CHILDREN, reader, e, false)?;
+                }
+            },
+            Event::Empty(ref e) => match e.name().0 {
+                b"cx:parentLabelLayout" => {
+                    set_string_from_xml!(self, e, parent_label_layout, "val");
+                }
+                b"cx:visibility" => {
+                    let mut obj = SeriesElementVisibilities::default();
+                    obj.set_attributes(reader, e)?;
+                    self.set_visibility(obj);
+                }
+                b"cx:aggregation" => {
+                    self.set_aggregation(true);
+                }
+                b"cx:binning" => {
+                    let mut obj = Binning::default();
+                    obj.set_attributes(reader, e, true)?;
+                    self.set_binning(obj);
+                }
+                b"cx:statistics" => {
+                    set_string_from_xml!(self, e, quartile_method, "quartileMethod");
+                }
+                b"cx:idx" => {
+                    if let Some(v) = get_attribute(e, b"val")? {
+                        if let Ok(v) = v.parse::<u32>() {
+                            self.add_subtotal(v);
+                        }
+                    }
+                }
+                _ => RawElement::read_into(&mut self.raw_children, RAW_CHILDREN, reader, e, true)?,
+            },
+            Event::End(ref e) => {
+                if e.name().0 == b"cx:layoutPr" {
+                    return Ok(());
+                }
+            },
+            Event::Eof => return Err(end_element_error(reader, "cx:layoutPr")),
+        );
+    }
+
+    pub(crate) fn write_to(&self, writer: &mut Writer<Cursor<Vec<u8>>>) {
+        // cx:layoutPr
+        write_start_tag(writer, "cx:layoutPr", vec![], false);
+
+        // cx:parentLabelLayout
+        if self.parent_label_layout.has_value() {
+            write_start_tag(
+                writer,
+                "cx:parentLabelLayout",
+                vec![("val", self.parent_label_layout.get_value_string())],
+                true,
+            );
+        }
+
+        // cx:regionLabelLayout
+        RawElement::write_list(writer, &self.raw_children, &["cx:regionLabelLayout"]);
+
+        // cx:visibility
+        if let Some(v) = &self.visibility {
+            v.write_to(writer);
+        }
+
+        // cx:aggregation
+        if self.aggregation {
+            write_start_tag(writer, "cx:aggregation", vec![], true);
+        }
+
+        // cx:binning
+        if let Some(v) = &self.binning {
+            v.write_to(writer);
+        }
+
+        // cx:geography
+        RawElement::write_list(writer, &self.raw_children, &["cx:geography"]);
+
+        // cx:statistics
+        if self.quartile_method.has_value() {
+            write_start_tag(
+                writer,
+                "cx:statistics",
+                vec![("quartileMethod", self.quartile_method.get_value_string())],
+                true,
+            );
+        }
+
+        // cx:subtotals
+        if !self.subtotals.is_empty() {
+            write_start_tag(writer, "cx:subtotals", vec![], false);
+            for idx in &self.subtotals {
+                write_start_tag(writer, "cx:idx", vec![("val", &idx.to_string())], true);
+            }
+            write_end_tag(writer, "cx:subtotals");
+        }
+
+        // cx:extLst
+        RawElement::write_list(writer, &self.raw_children, &["cx:extLst"]);
+
+        write_end_tag(writer, "cx:layoutPr");
+    }
+}
@@ -0,0 +1,104 @@
+// cx:legend
+use super::super::super::BooleanValue;
+use super::super::super::EnumValue;
+use super::PositionAlignmentValues;
+use super::RawElement;
+use super::SidePositionValues;
+use crate::reader::driver::*;
+use crate::writer::driver::*;
+use crate::xml_read_loop;
+use crate::XlsxError;
+use quick_xml::events::{BytesStart, Event};
+use quick_xml::Reader;
+use quick_xml::Writer;
+use std::io::Cursor;
+use thin_vec::ThinVec;
+
+const RAW_CHILDREN: &[&str] = &["cx:spPr", "cx:txPr", "cx:extLst"];
+
+#[derive(Clone, Default, Debug)]
+pub struct Legend {
+    position: EnumValue<SidePositionValues>,
+    alignment: EnumValue<PositionAlignmentValues>,
+    overlay: BooleanValue,
+    raw_children: ThinVec<RawElement>,
+}
+
+impl Legend {
+    pub fn get_position(&self) -> &SidePositionValues {
+        self.position.get_value()
+    }
+
+    pub fn set_position(&mut self, value: SidePositionValues) -> &mut Self {
+        self.position.set_value(value);
+        self
+    }
+
+    pub fn get_alignment(&self) -> &PositionAlignmentValues {
+        self.alignment.get_value()
+    }
+
+    pub fn set_alignment(&mut self, value: PositionAlignmentValues) -> &mut Self {
+        self.alignment.set_value(value);
+        self
+    }
+
+    pub fn get_overlay(&self) -> &bool {
+        self.overlay.get_value()
+    }
+
+    pub fn set_overlay(&mut self, value: bool) -> &mut Self {
+        self.overlay.set_value(value);
+        self
+    }
+
+    pub(crate) fn set_attributes<R: std::io::BufRead>(
+        &mut self,
+        reader: &mut Reader<R>,
+        e: &BytesStart,
+        empty_flg: bool,
+    ) -> Result<(), XlsxError> {
+        set_string_from_xml!(self, e, position, "pos");
+        set_string_from_xml!(self, e, alignment, "align");
+        set_string_from_xml!(self, e, overlay, "overlay");
+
+        if empty_flg {
+            return Ok(());
+        }
+
+        xml_read_loop!(
+            reader,
+            Event::Start(ref e) => {
+                RawElement::read_into(&mut self.raw_children, RAW_CHILDREN, reader, e, false)?;
+            },
+            Event::Empty(ref e) => {
+                RawElement::read_into(&mut self.raw_children, RAW_CHILDREN, reader, e, true)?;
+            },
+            Event::End(ref e) => {
+                if e.name().0 == b"cx:legend" {
+                    return Ok(());
+                }
+            },
+            Event::Eof => return Err(end_element_error(reader, "cx:legend")),
+        );
+    }
+
+    pub(crate) fn write_to(&self, writer: &mut Writer<Cursor<Vec<u8>>>) {
+        // cx:legend
+        write_start_tag(
+            writer,
+            "cx:legend",
+            vec![
+                ("pos", self.position.get_value_string()),
+                ("align", self.alignment.get_value_string()),
+                ("overlay", self.overlay.get_value_string()),
+            ],
+            self.raw_children.is_empty(),
+        );
+
+        // cx:spPr, cx:txPr, cx:extLst
+        if RawElement::write_list(writer, &self.raw_children, RAW_CHILDREN) {
+            write_end_tag(writer, "cx:legend");
+        }
+    }
+}
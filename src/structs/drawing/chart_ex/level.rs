@@ -0,0 +1,143 @@
+// cx:lvl
+use super::super::super::StringValue;
+use super::super::super::UInt32Value;
+use super::Point;
+use crate::reader::driver::*;
+use crate::structs::Address;
+use crate::structs::Spreadsheet;
+use crate::writer::driver::*;
+use crate::xml_read_loop;
+use crate::XlsxError;
+use quick_xml::events::{BytesStart, Event};
+use quick_xml::Reader;
+use quick_xml::Writer;
+use std::io::Cursor;
+use thin_vec::ThinVec;
+
+/// Cached values of one level of a data dimension.
+#[derive(Clone, Default, Debug)]
+pub struct Level {
+    point_count: UInt32Value,
+    format_code: StringValue,
+    name: StringValue,
+    point: ThinVec<Point>,
+}
+
+impl Level {
+    pub fn get_point_count(&self) -> &u32 {
+        self.point_count.get_value()
+    }
+
+    pub fn set_point_count(&mut self, value: u32) -> &mut Self {
+        self.point_count.set_value(value);
+        self
+    }
+
+    pub fn get_format_code(&self) -> &str {
+        self.format_code.get_value_str()
+    }
+
+    pub fn set_format_code<S: Into<String>>(&mut self, value: S) -> &mut Self {
+        self.format_code.set_value(value);
+        self
+    }
+
+    pub fn get_name(&self) -> &str {
+        self.name.get_value_str()
+    }
+
+    pub fn set_name<S: Into<String>>(&mut self, value: S) -> &mut Self {
+        self.name.set_value(value);
+        self
+    }
+
+    pub fn get_point(&self) -> &[Point] {
+        &self.point
+    }
+
+    pub fn get_point_mut(&mut self) -> &mut ThinVec<Point> {
+        &mut self.point
+    }
+
+    pub fn set_point(&mut self, value: impl Into<ThinVec<Point>>) -> &mut Self {
+        self.point = value.into();
+        self
+    }
+
+    pub fn add_point(&mut self, value: Point) -> &mut Self {
+        self.point.push(value);
+        self
+    }
+
+    /// (This method is crate only.)
+    /// Make the cached values of a cell range.
+    pub(crate) fn from_address(address: &Address, spreadsheet: &Spreadsheet) -> Self {
+        let mut level = Level::default();
+        let cell_value_list = spreadsheet.get_cell_value_by_address_crate(address);
+        level.set_point_count(cell_value_list.len() as u32);
+        for (idx, cell_value) in cell_value_list.into_iter().enumerate() {
+            let mut point = Point::default();
+            point
+                .set_index(idx as u32)
+                .set_value(cell_value.get_value());
+            level.add_point(point);
+        }
+        level
+    }
+
+    pub(crate) fn set_attributes<R: std::io::BufRead>(
+        &mut self,
+        reader: &mut Reader<R>,
+        e: &BytesStart,
+        empty_flg: bool,
+    ) -> Result<(), XlsxError> {
+        set_string_from_xml!(self, e, point_count, "ptCount");
+        set_string_from_xml!(self, e, format_code, "formatCode");
+        set_string_from_xml!(self, e, name, "name");
+
+        if empty_flg {
+            return Ok(());
+        }
+
+        xml_read_loop!(
+            reader,
+            Event::Start(ref e) => {
+                if e.name().0 == b"cx:pt" {
+                    let mut obj = Point::default();
+                    obj.set_attributes(reader, e)?;
+                    self.add_point(obj);
+                }
+            },
+            Event::End(ref e) => {
+                if e.name().0 == b"cx:lvl" {
+                    return Ok(());
+                }
+            },
+            Event::Eof => return Err(end_element_error(reader, "cx:lvl")),
+        );
+    }
+
+    pub(crate) fn write_to(&self, writer: &mut Writer<Cursor<Vec<u8>>>) {
+        // cx:lvl
+        let point_count = self.point_count.get_value_string();
+        let mut attributes: Vec<(&str, &str)> = vec![("ptCount", &point_count)];
+        if self.format_code.has_value() {
+            attributes.push(("formatCode", self.format_code.get_value_str()));
+        }
+        if self.name.has_value() {
+            attributes.push(("name", self.name.get_value_str()));
+        }
+        let empty = self.point.is_empty();
+        write_start_tag(writer, "cx:lvl", attributes, empty);
+        if empty {
+            return;
+        }
+
+        // cx:pt
+        for v in &self.point {
+            v.write_to(writer);
+        }
+
+        write_end_tag(writer, "cx:lvl");
+    }
+}
@@ -0,0 +1,36 @@
+use super::super::super::EnumTrait;
+use std::str::FromStr;
+#[derive(Clone, Debug, Default)]
+pub enum NumericDimensionValues {
+    ColorValue,
+    Size,
+    #[default]
+    Value,
+    X,
+    Y,
+}
+
+impl EnumTrait for NumericDimensionValues {
+    fn get_value_string(&self) -> &str {
+        match &self {
+            Self::ColorValue => "colorVal",
+            Self::Size => "size",
+            Self::Value => "val",
+            Self::X => "x",
+            Self::Y => "y",
+        }
+    }
+}
+impl FromStr for NumericDimensionValues {
+    type Err = ();
+    fn from_str(input: &str) -> Result<NumericDimensionValues, Self::Err> {
+        match input {
+            "colorVal" => Ok(Self::ColorValue),
+            "size" => Ok(Self::Size),
+            "val" => Ok(Self::Value),
+            "x" => Ok(Self::X),
+            "y" => Ok(Self::Y),
+            _ => Err(()),
+        }
+    }
+}
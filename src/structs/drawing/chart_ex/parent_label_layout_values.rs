@@ -0,0 +1,30 @@
+use super::super::super::EnumTrait;
+use std::str::FromStr;
+#[derive(Clone, Debug, Default)]
+pub enum ParentLabelLayoutValues {
+    Banner,
+    None,
+    #[default]
+    Overlapping,
+}
+
+impl EnumTrait for ParentLabelLayoutValues {
+    fn get_value_string(&self) -> &str {
+        match &self {
+            Self::Banner => "banner",
+            Self::None => "none",
+            Self::Overlapping => "overlapping",
+        }
+    }
+}
+impl FromStr for ParentLabelLayoutValues {
+    type Err = ();
+    fn from_str(input: &str) -> Result<ParentLabelLayoutValues, Self::Err> {
+        match input {
+            "banner" => Ok(Self::Banner),
+            "none" => Ok(Self::None),
+            "overlapping" => Ok(Self::Overlapping),
+            _ => Err(()),
+        }
+    }
+}
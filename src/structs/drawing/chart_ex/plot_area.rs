@@ -0,0 +1,142 @@
+// cx:plotArea
+use super::Axis;
+use super::PlotAreaRegion;
+use super::RawElement;
+use crate::reader::driver::*;
+use crate::structs::Spreadsheet;
+use crate::traits::AdjustmentCoordinateWithSheet;
+use crate::writer::driver::*;
+use crate::xml_read_loop;
+use crate::XlsxError;
+use quick_xml::events::{BytesStart, Event};
+use quick_xml::Reader;
+use quick_xml::Writer;
+use std::io::Cursor;
+use thin_vec::ThinVec;
+
+const RAW_CHILDREN: &[&str] = &["cx:spPr", "cx:extLst"];
+
+#[derive(Clone, Default, Debug)]
+pub struct PlotArea {
+    plot_area_region: PlotAreaRegion,
+    axis: ThinVec<Axis>,
+    raw_children: ThinVec<RawElement>,
+}
+
+impl PlotArea {
+    pub fn get_plot_area_region(&self) -> &PlotAreaRegion {
+        &self.plot_area_region
+    }
+
+    pub fn get_plot_area_region_mut(&mut self) -> &mut PlotAreaRegion {
+        &mut self.plot_area_region
+    }
+
+    pub fn set_plot_area_region(&mut self, value: PlotAreaRegion) -> &mut Self {
+        self.plot_area_region = value;
+        self
+    }
+
+    pub fn get_axis(&self) -> &[Axis] {
+        &self.axis
+    }
+
+    pub fn get_axis_mut(&mut self) -> &mut ThinVec<Axis> {
+        &mut self.axis
+    }
+
+    pub fn set_axis(&mut self, value: impl Into<ThinVec<Axis>>) -> &mut Self {
+        self.axis = value.into();
+        self
+    }
+
+    pub fn add_axis(&mut self, value: Axis) -> &mut Self {
+        self.axis.push(value);
+        self
+    }
+
+    pub(crate) fn set_attributes<R: std::io::BufRead>(
+        &mut self,
+        reader: &mut Reader<R>,
+        _e: &BytesStart,
+    ) -> Result<(), XlsxError> {
+        xml_read_loop!(
+            reader,
+            Event::Start(ref e) => match e.name().0 {
+                b"cx:plotAreaRegion" => {
+                    self.plot_area_region.set_attributes(reader, e)?;
+                }
+                b"cx:axis" => {
+                    let mut obj = Axis::default();
+                    obj.set_attributes(reader, e)?;
+                    self.add_axis(obj);
+                }
+                _ => RawElement::read_into(&mut self.raw_children, RAW_CHILDREN, reader, e, false)?,
+            },
+            Event::Empty(ref e) => {
+                RawElement::read_into(&mut self.raw_children, RAW_CHILDREN, reader, e, true)?;
+            },
+            Event::End(ref e) => {
+                if e.name().0 == b"cx:plotArea" {
+                    return Ok(());
+                }
+            },
+            Event::Eof => return Err(end_element_error(reader, "cx:plotArea")),
+        );
+    }
+
+    pub(crate) fn write_to(&self, writer: &mut Writer<Cursor<Vec<u8>>>, spreadsheet: &Spreadsheet) {
+        // cx:plotArea
+        write_start_tag(writer, "cx:plotArea", vec![], false);
+
+        // cx:plotAreaRegion
+        self.plot_area_region.write_to(writer, spreadsheet);
+
+        // cx:axis
+        for v in &self.axis {
+            v.write_to(writer);
+        }
+
+        // cx:spPr, cx:extLst
+        RawElement::write_list(writer, &self.raw_children, RAW_CHILDREN);
+
+        write_end_tag(writer, "cx:plotArea");
+    }
+}
+impl AdjustmentCoordinateWithSheet for PlotArea {
+    fn adjustment_insert_coordinate_with_sheet(
+        &mut self,
+        sheet_name: &str,
+        root_col_num: &u32,
+        offset_col_num: &u32,
+        root_row_num: &u32,
+        offset_row_num: &u32,
+    ) {
+        self.plot_area_region
+            .adjustment_insert_coordinate_with_sheet(
+                sheet_name,
+                root_col_num,
+                offset_col_num,
+                root_row_num,
+                offset_row_num,
+            );
+    }
+
+    fn adjustment_remove_coordinate_with_sheet(
+        &mut self,
+        sheet_name: &str,
+        root_col_num: &u32,
+        offset_col_num: &u32,
+        root_row_num: &u32,
+        offset_row_num: &u32,
+    ) {
+        self.plot_area_region
+            .adjustment_remove_coordinate_with_sheet(
+                sheet_name,
+                root_col_num,
+                offset_col_num,
+                root_row_num,
+                offset_row_num,
+            );
+    }
+}
@@ -0,0 +1,127 @@
+// cx:plotAreaRegion
+use super::RawElement;
+use super::Series;
+use crate::reader::driver::*;
+use crate::structs::Spreadsheet;
+use crate::traits::AdjustmentCoordinateWithSheet;
+use crate::writer::driver::*;
+use crate::xml_read_loop;
+use crate::XlsxError;
+use quick_xml::events::{BytesStart, Event};
+use quick_xml::Reader;
+use quick_xml::Writer;
+use std::io::Cursor;
+use thin_vec::ThinVec;
+
+const RAW_CHILDREN: &[&str] = &["cx:plotSurface", "cx:extLst"];
+
+#[derive(Clone, Default, Debug)]
+pub struct PlotAreaRegion {
+    series: ThinVec<Series>,
+    raw_children: ThinVec<RawElement>,
+}
+
+impl PlotAreaRegion {
+    pub fn get_series(&self) -> &[Series] {
+        &self.series
+    }
+
+    pub fn get_series_mut(&mut self) -> &mut ThinVec<Series> {
+        &mut self.series
+    }
+
+    pub fn set_series(&mut self, value: impl Into<ThinVec<Series>>) -> &mut Self {
+        self.series = value.into();
+        self
+    }
+
+    pub fn add_series(&mut self, value: Series) -> &mut Self {
+        self.series.push(value);
+        self
+    }
+
+    pub(crate) fn set_attributes<R: std::io::BufRead>(
+        &mut self,
+        reader: &mut Reader<R>,
+        _e: &BytesStart,
+    ) -> Result<(), XlsxError> {
+        xml_read_loop!(
+            reader,
+            Event::Start(ref e) => {
+                if e.name().0 == b"cx:series" {
+                    let mut obj = Series::default();
+                    obj.set_attributes(reader, e)?;
+                    self.add_series(obj);
+                } else {
+                    RawElement::read_into(&mut self.raw_children, RAW_CHILDREN, reader, e, false)?;
+                }
+            },
+            Event::Empty(ref e) => {
+                RawElement::read_into(&mut self.raw_children, RAW_CHILDREN, reader, e, true)?;
+            },
+            Event::End(ref e) => {
+                if e.name().0 == b"cx:plotAreaRegion" {
+                    return Ok(());
+                }
+            },
+            Event::Eof => return Err(end_element_error(reader, "cx:plotAreaRegion")),
+        );
+    }
+
+    pub(crate) fn write_to(&self, writer: &mut Writer<Cursor<Vec<u8>>>, spreadsheet: &Spreadsheet) {
+        // cx:plotAreaRegion
+        write_start_tag(writer, "cx:plotAreaRegion", vec![], false);
+
+        // cx:plotSurface
+        RawElement::write_list(writer, &self.raw_children, &["cx:plotSurface"]);
+
+        // cx:series
+        for v in &self.series {
+            v.write_to(writer, spreadsheet);
+        }
+
+        // cx:extLst
+        RawElement::write_list(writer, &self.raw_children, &["cx:extLst"]);
+
+        write_end_tag(writer, "cx:plotAreaRegion");
+    }
+}
+impl AdjustmentCoordinateWithSheet for PlotAreaRegion {
+    fn adjustment_insert_coordinate_with_sheet(
+        &mut self,
+        sheet_name: &str,
+        root_col_num: &u32,
+        offset_col_num: &u32,
+        root_row_num: &u32,
+        offset_row_num: &u32,
+    ) {
+        for v in &mut self.series {
+            v.adjustment_insert_coordinate_with_sheet(
+                sheet_name,
+                root_col_num,
+                offset_col_num,
+                root_row_num,
+                offset_row_num,
+            );
+        }
+    }
+
+    fn adjustment_remove_coordinate_with_sheet(
+        &mut self,
+        sheet_name: &str,
+        root_col_num: &u32,
+        offset_col_num: &u32,
+        root_row_num: &u32,
+        offset_row_num: &u32,
+    ) {
+        for v in &mut self.series {
+            v.adjustment_remove_coordinate_with_sheet(
+                sheet_name,
+                root_col_num,
+                offset_col_num,
+                root_row_num,
+                offset_row_num,
+            );
+        }
+    }
+}
@@ -0,0 +1,70 @@
+// cx:pt
+use super::super::super::StringValue;
+use super::super::super::UInt32Value;
+use crate::reader::driver::*;
+use crate::writer::driver::*;
+use crate::xml_read_loop;
+use crate::XlsxError;
+use quick_xml::events::{BytesStart, Event};
+use quick_xml::Reader;
+use quick_xml::Writer;
+use std::io::Cursor;
+
+#[derive(Clone, Default, Debug)]
+pub struct Point {
+    index: UInt32Value,
+    value: StringValue,
+}
+
+impl Point {
+    pub fn get_index(&self) -> &u32 {
+        self.index.get_value()
+    }
+
+    pub fn set_index(&mut self, value: u32) -> &mut Self {
+        self.index.set_value(value);
+        self
+    }
+
+    pub fn get_value(&self) -> &str {
+        self.value.get_value_str()
+    }
+
+    pub fn set_value<S: Into<String>>(&mut self, value: S) -> &mut Self {
+        self.value.set_value(value);
+        self
+    }
+
+    pub(crate) fn set_attributes<R: std::io::BufRead>(
+        &mut self,
+        reader: &mut Reader<R>,
+        e: &BytesStart,
+    ) -> Result<(), XlsxError> {
+        set_string_from_xml!(self, e, index, "idx");
+
+        xml_read_loop!(
+            reader,
+            Event::Text(e) => {
+                self.value.set_value(e.unescape()?);
+            },
+            Event::End(ref e) => {
+                if e.name().0 == b"cx:pt" {
+                    return Ok(());
+                }
+            },
+            Event::Eof => return Err(end_element_error(reader, "cx:pt")),
+        );
+    }
+
+    pub(crate) fn write_to(&self, writer: &mut Writer<Cursor<Vec<u8>>>) {
+        // cx:pt
+        write_start_tag(
+            writer,
+            "cx:pt",
+            vec![("idx", &self.index.get_value_string())],
+            false,
+        );
+        write_text_node(writer, self.value.get_value_str());
+        write_end_tag(writer, "cx:pt");
+    }
+}
@@ -0,0 +1,30 @@
+use super::super::super::EnumTrait;
+use std::str::FromStr;
+#[derive(Clone, Debug, Default)]
+pub enum PositionAlignmentValues {
+    #[default]
+    Center,
+    Maximum,
+    Minimum,
+}
+
+impl EnumTrait for PositionAlignmentValues {
+    fn get_value_string(&self) -> &str {
+        match &self {
+            Self::Center => "ctr",
+            Self::Maximum => "max",
+            Self::Minimum => "min",
+        }
+    }
+}
+impl FromStr for PositionAlignmentValues {
+    type Err = ();
+    fn from_str(input: &str) -> Result<PositionAlignmentValues, Self::Err> {
+        match input {
+            "ctr" => Ok(Self::Center),
+            "max" => Ok(Self::Maximum),
+            "min" => Ok(Self::Minimum),
+            _ => Err(()),
+        }
+    }
+}
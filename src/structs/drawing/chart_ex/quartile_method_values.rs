@@ -0,0 +1,27 @@
+use super::super::super::EnumTrait;
+use std::str::FromStr;
+#[derive(Clone, Debug, Default)]
+pub enum QuartileMethodValues {
+    #[default]
+    Exclusive,
+    Inclusive,
+}
+
+impl EnumTrait for QuartileMethodValues {
+    fn get_value_string(&self) -> &str {
+        match &self {
+            Self::Exclusive => "exclusive",
+            Self::Inclusive => "inclusive",
+        }
+    }
+}
+impl FromStr for QuartileMethodValues {
+    type Err = ();
+    fn from_str(input: &str) -> Result<QuartileMethodValues, Self::Err> {
+        match input {
+            "exclusive" => Ok(Self::Exclusive),
+            "inclusive" => Ok(Self::Inclusive),
+            _ => Err(()),
+        }
+    }
+}
@@ -0,0 +1,99 @@
+// any cx: element that is not modeled (cx:spPr, cx:txPr, cx:extLst, ...)
+use crate::reader::driver::*;
+use crate::XlsxError;
+use quick_xml::events::{BytesStart, Event};
+use quick_xml::Reader;
+use quick_xml::Writer;
+use std::io::Cursor;
+use thin_vec::ThinVec;
+
+/// An element of a ChartEx part that has no model of its own.
+/// It is kept as read, so that formatting and extensions written by Excel round-trip.
+#[derive(Clone, Default, Debug)]
+pub(crate) struct RawElement {
+    name: String,
+    events: Vec<Event<'static>>,
+}
+
+impl RawElement {
+    #[inline]
+    pub(crate) fn get_name(&self) -> &str {
+        &self.name
+    }
+
+    pub(crate) fn set_attributes<R: std::io::BufRead>(
+        &mut self,
+        reader: &mut Reader<R>,
+        e: &BytesStart,
+        empty_flg: bool,
+    ) -> Result<(), XlsxError> {
+        self.name = String::from_utf8_lossy(e.name().into_inner()).into_owned();
+        if empty_flg {
+            self.events.push(Event::Empty(e.to_owned().into_owned()));
+            return Ok(());
+        }
+        self.events.push(Event::Start(e.to_owned().into_owned()));
+
+        // text such as `<a:t>` and `<cx:separator>` is kept with its spaces.
+        let config = reader.config().clone();
+        reader.config_mut().trim_text(false);
+
+        let mut depth = 0;
+        let mut buf = Vec::new();
+        loop {
+            let ev = reader
+                .read_event_into(&mut buf)
+                .map_err(|err| xml_error(reader, err))?;
+            match ev {
+                Event::Start(_) => depth += 1,
+                Event::End(_) if depth == 0 => {
+                    self.events.push(ev.into_owned());
+                    *reader.config_mut() = config;
+                    return Ok(());
+                }
+                Event::End(_) => depth -= 1,
+                Event::Eof => return Err(end_element_error(reader, &self.name)),
+                _ => (),
+            }
+            self.events.push(ev.into_owned());
+            buf.clear();
+        }
+    }
+
+    /// Read the element into `list` if it is one of `names`.
+    pub(crate) fn read_into<R: std::io::BufRead>(
+        list: &mut ThinVec<RawElement>,
+        names: &[&str],
+        reader: &mut Reader<R>,
+        e: &BytesStart,
+        empty_flg: bool,
+    ) -> Result<(), XlsxError> {
+        if names.iter().any(|v| v.as_bytes() == e.name().into_inner()) {
+            let mut obj = RawElement::default();
+            obj.set_attributes(reader, e, empty_flg)?;
+            list.push(obj);
+        }
+        Ok(())
+    }
+
+    pub(crate) fn write_to(&self, writer: &mut Writer<Cursor<Vec<u8>>>) {
+        for ev in &self.events {
+            writer.write_event(ev.clone());
+        }
+    }
+
+    /// Write the elements of `list` named `names`, in the order they were read.
+    /// Returns whether any element was written.
+    pub(crate) fn write_list(
+        writer: &mut Writer<Cursor<Vec<u8>>>,
+        list: &[RawElement],
+        names: &[&str],
+    ) -> bool {
+        let mut result = false;
+        for v in list.iter().filter(|v| names.contains(&v.get_name())) {
+            v.write_to(writer);
+            result = true;
+        }
+        result
+    }
+}
@@ -0,0 +1,326 @@
+// cx:series
+use super::super::super::BooleanValue;
+use super::super::super::EnumValue;
+use super::super::super::StringValue;
+use super::super::super::UInt32Value;
+use super::DataLabels;
+use super::LayoutProperties;
+use super::RawElement;
+use super::SeriesLayoutValues;
+use super::Text;
+use crate::reader::driver::*;
+use crate::structs::Spreadsheet;
+use crate::traits::AdjustmentCoordinateWithSheet;
+use crate::writer::driver::*;
+use crate::xml_read_loop;
+use crate::XlsxError;
+use quick_xml::events::{BytesStart, Event};
+use quick_xml::Reader;
+use quick_xml::Writer;
+use std::io::Cursor;
+use thin_vec::ThinVec;
+
+const RAW_CHILDREN: &[&str] = &[
+    "cx:spPr",
+    "cx:valueColors",
+    "cx:valueColorPositions",
+    "cx:dataPt",
+    "cx:extLst",
+];
+
+#[derive(Clone, Default, Debug)]
+pub struct Series {
+    layout_id: EnumValue<SeriesLayoutValues>,
+    unique_id: StringValue,
+    hidden: BooleanValue,
+    owner_index: UInt32Value,
+    format_index: UInt32Value,
+    text: Option<Text>,
+    data_labels: Option<DataLabels>,
+    data_id: UInt32Value,
+    layout_properties: Option<LayoutProperties>,
+    axis_id: ThinVec<u32>,
+    raw_children: ThinVec<RawElement>,
+}
+
+impl Series {
+    pub fn get_layout_id(&self) -> &SeriesLayoutValues {
+        self.layout_id.get_value()
+    }
+
+    pub fn set_layout_id(&mut self, value: SeriesLayoutValues) -> &mut Self {
+        self.layout_id.set_value(value);
+        self
+    }
+
+    pub fn get_unique_id(&self) -> &str {
+        self.unique_id.get_value_str()
+    }
+
+    pub fn set_unique_id<S: Into<String>>(&mut self, value: S) -> &mut Self {
+        self.unique_id.set_value(value);
+        self
+    }
+
+    pub fn get_hidden(&self) -> &bool {
+        self.hidden.get_value()
+    }
+
+    pub fn set_hidden(&mut self, value: bool) -> &mut Self {
+        self.hidden.set_value(value);
+        self
+    }
+
+    /// Index of the series this one is derived from, e.g. the pareto line of a histogram.
+    pub fn get_owner_index(&self) -> Option<&u32> {
+        if self.owner_index.has_value() {
+            return Some(self.owner_index.get_value());
+        }
+        None
+    }
+
+    pub fn set_owner_index(&mut self, value: u32) -> &mut Self {
+        self.owner_index.set_value(value);
+        self
+    }
+
+    pub fn get_format_index(&self) -> Option<&u32> {
+        if self.format_index.has_value() {
+            return Some(self.format_index.get_value());
+        }
+        None
+    }
+
+    pub fn set_format_index(&mut self, value: u32) -> &mut Self {
+        self.format_index.set_value(value);
+        self
+    }
+
+    pub fn get_text(&self) -> Option<&Text> {
+        self.text.as_ref()
+    }
+
+    pub fn get_text_mut(&mut self) -> Option<&mut Text> {
+        self.text.as_mut()
+    }
+
+    pub fn set_text(&mut self, value: Text) -> &mut Self {
+        self.text = Some(value);
+        self
+    }
+
+    pub fn get_data_labels(&self) -> Option<&DataLabels> {
+        self.data_labels.as_ref()
+    }
+
+    pub fn get_data_labels_mut(&mut self) -> Option<&mut DataLabels> {
+        self.data_labels.as_mut()
+    }
+
+    pub fn set_data_labels(&mut self, value: DataLabels) -> &mut Self {
+        self.data_labels = Some(value);
+        self
+    }
+
+    /// Id of the `cx:data` element holding the values of this series.
+    pub fn get_data_id(&self) -> &u32 {
+        self.data_id.get_value()
+    }
+
+    pub fn set_data_id(&mut self, value: u32) -> &mut Self {
+        self.data_id.set_value(value);
+        self
+    }
+
+    pub fn get_layout_properties(&self) -> Option<&LayoutProperties> {
+        self.layout_properties.as_ref()
+    }
+
+    pub fn get_layout_properties_mut(&mut self) -> Option<&mut LayoutProperties> {
+        self.layout_properties.as_mut()
+    }
+
+    pub fn set_layout_properties(&mut self, value: LayoutProperties) -> &mut Self {
+        self.layout_properties = Some(value);
+        self
+    }
+
+    pub fn get_axis_id(&self) -> &[u32] {
+        &self.axis_id
+    }
+
+    pub fn get_axis_id_mut(&mut self) -> &mut ThinVec<u32> {
+        &mut self.axis_id
+    }
+
+    pub fn add_axis_id(&mut self, value: u32) -> &mut Self {
+        self.axis_id.push(value);
+        self
+    }
+
+    pub(crate) fn set_attributes<R: std::io::BufRead>(
+        &mut self,
+        reader: &mut Reader<R>,
+        e: &BytesStart,
+    ) -> Result<(), XlsxError> {
+        set_string_from_xml!(self, e, layout_id, "layoutId");
+        set_string_from_xml!(self, e, unique_id, "uniqueId");
+        set_string_from_xml!(self, e, hidden, "hidden");
+        set_string_from_xml!(self, e, owner_index, "ownerIdx");
+        set_string_from_xml!(self, e, format_index, "formatIdx");
+
+        xml_read_loop!(
+            reader,
+            Event::Start(ref e) => match e.name().0 {
+                b"cx:tx" => {
+                    let mut obj = Text::default();
+                    obj.set_attributes(reader, e)?;
+                    self.set_text(obj);
+                }
+                b"cx:dataLabels" => {
+                    let mut obj = DataLabels::default();
+                    obj.set_attributes(reader, e, false)?;
+                    self.set_data_labels(obj);
+                }
+                b"cx:layoutPr" => {
+                    let mut obj = LayoutProperties::default();
+                    obj.set_attributes(reader, e)?;
+                    self.set_layout_properties(obj);
+                }
+                _ => RawElement::read_into(&mut self.raw_children, RAW_CHILDREN, reader, e, false)?,
+            },
+            Event::Empty(ref e) => match e.name().0 {
+                b"cx:dataLabels" => {
+                    let mut obj = DataLabels::default();
+                    obj.set_attributes(reader, e, true)?;
+                    self.set_data_labels(obj);
+                }
+                b"cx:dataId" => {
+                    set_string_from_xml!(self, e, data_id, "val");
+                }
+                b"cx:layoutPr" => {
+                    self.set_layout_properties(LayoutProperties::default());
+                }
+                b"cx:axisId" => {
+                    if let Some(v) = get_attribute(e, b"val")? {
+                        if let Ok(v) = v.parse::<u32>() {
+                            self.add_axis_id(v);
+                        }
+                    }
+                }
+                _ => RawElement::read_into(&mut self.raw_children, RAW_CHILDREN, reader, e, true)?,
+            },
+            Event::End(ref e) => {
+                if e.name().0 == b"cx:series" {
+                    return Ok(());
+                }
+            },
+            Event::Eof => return Err(end_element_error(reader, "cx:series")),
+        );
+    }
+
+    pub(crate) fn write_to(&self, writer: &mut Writer<Cursor<Vec<u8>>>, spreadsheet: &Spreadsheet) {
+        // cx:series
+        let owner_index = self.owner_index.get_value_string();
+        let format_index = self.format_index.get_value_string();
+        let mut attributes: Vec<(&str, &str)> = Vec::new();
+        attributes.push(("layoutId", self.layout_id.get_value_string()));
+        if self.hidden.has_value() {
+            attributes.push(("hidden", self.hidden.get_value_string()));
+        }
+        if self.owner_index.has_value() {
+            attributes.push(("ownerIdx", &owner_index));
+        }
+        if self.unique_id.has_value() {
+            attributes.push(("uniqueId", self.unique_id.get_value_str()));
+        }
+        if self.format_index.has_value() {
+            attributes.push(("formatIdx", &format_index));
+        }
+        write_start_tag(writer, "cx:series", attributes, false);
+
+        // cx:tx
+        if let Some(v) = &self.text {
+            v.write_to(writer, spreadsheet);
+        }
+
+        // cx:spPr, cx:valueColors, cx:valueColorPositions, cx:dataPt
+        RawElement::write_list(
+            writer,
+            &self.raw_children,
+            &[
+                "cx:spPr",
+                "cx:valueColors",
+                "cx:valueColorPositions",
+                "cx:dataPt",
+            ],
+        );
+
+        // cx:dataLabels
+        if let Some(v) = &self.data_labels {
+            v.write_to(writer);
+        }
+
+        // cx:dataId
+        write_start_tag(
+            writer,
+            "cx:dataId",
+            vec![("val", &self.data_id.get_value_string())],
+            true,
+        );
+
+        // cx:layoutPr
+        if let Some(v) = &self.layout_properties {
+            v.write_to(writer);
+        }
+
+        // cx:axisId
+        for id in &self.axis_id {
+            write_start_tag(writer, "cx:axisId", vec![("val", &id.to_string())], true);
+        }
+
+        // cx:extLst
+        RawElement::write_list(writer, &self.raw_children, &["cx:extLst"]);
+
+        write_end_tag(writer, "cx:series");
+    }
+}
+impl AdjustmentCoordinateWithSheet for Series {
+    fn adjustment_insert_coordinate_with_sheet(
+        &mut self,
+        sheet_name: &str,
+        root_col_num: &u32,
+        offset_col_num: &u32,
+        root_row_num: &u32,
+        offset_row_num: &u32,
+    ) {
+        if let Some(v) = &mut self.text {
+            v.adjustment_insert_coordinate_with_sheet(
+                sheet_name,
+                root_col_num,
+                offset_col_num,
+                root_row_num,
+                offset_row_num,
+            );
+        }
+    }
+
+    fn adjustment_remove_coordinate_with_sheet(
+        &mut self,
+        sheet_name: &str,
+        root_col_num: &u32,
+        offset_col_num: &u32,
+        root_row_num: &u32,
+        offset_row_num: &u32,
+    ) {
+        if let Some(v) = &mut self.text {
+            v.adjustment_remove_coordinate_with_sheet(
+                sheet_name,
+                root_col_num,
+                offset_col_num,
+                root_row_num,
+                offset_row_num,
+            );
+        }
+    }
+}
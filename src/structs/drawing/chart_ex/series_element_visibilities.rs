@@ -0,0 +1,86 @@
+// cx:visibility
+use super::super::super::BooleanValue;
+use crate::reader::driver::*;
+use crate::writer::driver::*;
+use crate::XlsxError;
+use quick_xml::events::BytesStart;
+use quick_xml::Reader;
+use quick_xml::Writer;
+use std::io::Cursor;
+
+/// Which parts of a box & whisker series are drawn.
+#[derive(Clone, Default, Debug)]
+pub struct SeriesElementVisibilities {
+    mean_line: BooleanValue,
+    mean_marker: BooleanValue,
+    non_outliers: BooleanValue,
+    outliers: BooleanValue,
+}
+
+impl SeriesElementVisibilities {
+    pub fn get_mean_line(&self) -> &bool {
+        self.mean_line.get_value()
+    }
+
+    pub fn set_mean_line(&mut self, value: bool) -> &mut Self {
+        self.mean_line.set_value(value);
+        self
+    }
+
+    pub fn get_mean_marker(&self) -> &bool {
+        self.mean_marker.get_value()
+    }
+
+    pub fn set_mean_marker(&mut self, value: bool) -> &mut Self {
+        self.mean_marker.set_value(value);
+        self
+    }
+
+    pub fn get_non_outliers(&self) -> &bool {
+        self.non_outliers.get_value()
+    }
+
+    pub fn set_non_outliers(&mut self, value: bool) -> &mut Self {
+        self.non_outliers.set_value(value);
+        self
+    }
+
+    pub fn get_outliers(&self) -> &bool {
+        self.outliers.get_value()
+    }
+
+    pub fn set_outliers(&mut self, value: bool) -> &mut Self {
+        self.outliers.set_value(value);
+        self
+    }
+
+    pub(crate) fn set_attributes<R: std::io::BufRead>(
+        &mut self,
+        _reader: &mut Reader<R>,
+        e: &BytesStart,
+    ) -> Result<(), XlsxError> {
+        set_string_from_xml!(self, e, mean_line, "meanLine");
+        set_string_from_xml!(self, e, mean_marker, "meanMarker");
+        set_string_from_xml!(self, e, non_outliers, "nonoutliers");
+        set_string_from_xml!(self, e, outliers, "outliers");
+        Ok(())
+    }
+
+    pub(crate) fn write_to(&self, writer: &mut Writer<Cursor<Vec<u8>>>) {
+        // cx:visibility
+        let mut attributes: Vec<(&str, &str)> = Vec::new();
+        if self.mean_line.has_value() {
+            attributes.push(("meanLine", self.mean_line.get_value_string()));
+        }
+        if self.mean_marker.has_value() {
+            attributes.push(("meanMarker", self.mean_marker.get_value_string()));
+        }
+        if self.non_outliers.has_value() {
+            attributes.push(("nonoutliers", self.non_outliers.get_value_string()));
+        }
+        if self.outliers.has_value() {
+            attributes.push(("outliers", self.outliers.get_value_string()));
+        }
+        write_start_tag(writer, "cx:visibility", attributes, true);
+    }
+}
@@ -0,0 +1,45 @@
+use super::super::super::EnumTrait;
+use std::str::FromStr;
+#[derive(Clone, Debug, Default)]
+pub enum SeriesLayoutValues {
+    BoxWhisker,
+    #[default]
+    ClusteredColumn,
+    Funnel,
+    ParetoLine,
+    RegionMap,
+    Sunburst,
+    Treemap,
+    Waterfall,
+}
+
+impl EnumTrait for SeriesLayoutValues {
+    fn get_value_string(&self) -> &str {
+        match &self {
+            Self::BoxWhisker => "boxWhisker",
+            Self::ClusteredColumn => "clusteredColumn",
+            Self::Funnel => "funnel",
+            Self::ParetoLine => "paretoLine",
+            Self::RegionMap => "regionMap",
+            Self::Sunburst => "sunburst",
+            Self::Treemap => "treemap",
+            Self::Waterfall => "waterfall",
+        }
+    }
+}
+impl FromStr for SeriesLayoutValues {
+    type Err = ();
+    fn from_str(input: &str) -> Result<SeriesLayoutValues, Self::Err> {
+        match input {
+            "boxWhisker" => Ok(Self::BoxWhisker),
+            "clusteredColumn" => Ok(Self::ClusteredColumn),
+            "funnel" => Ok(Self::Funnel),
+            "paretoLine" => Ok(Self::ParetoLine),
+            "regionMap" => Ok(Self::RegionMap),
+            "sunburst" => Ok(Self::Sunburst),
+            "treemap" => Ok(Self::Treemap),
+            "waterfall" => Ok(Self::Waterfall),
+            _ => Err(()),
+        }
+    }
+}
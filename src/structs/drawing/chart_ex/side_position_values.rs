@@ -0,0 +1,33 @@
+use super::super::super::EnumTrait;
+use std::str::FromStr;
+#[derive(Clone, Debug, Default)]
+pub enum SidePositionValues {
+    Bottom,
+    Left,
+    Right,
+    #[default]
+    Top,
+}
+
+impl EnumTrait for SidePositionValues {
+    fn get_value_string(&self) -> &str {
+        match &self {
+            Self::Bottom => "b",
+            Self::Left => "l",
+            Self::Right => "r",
+            Self::Top => "t",
+        }
+    }
+}
+impl FromStr for SidePositionValues {
+    type Err = ();
+    fn from_str(input: &str) -> Result<SidePositionValues, Self::Err> {
+        match input {
+            "b" => Ok(Self::Bottom),
+            "l" => Ok(Self::Left),
+            "r" => Ok(Self::Right),
+            "t" => Ok(Self::Top),
+            _ => Err(()),
+        }
+    }
+}
@@ -0,0 +1,209 @@
+// cx:strDim
+use super::super::super::Address;
+use super::super::super::EnumValue;
+use super::Formula;
+use super::Level;
+use super::StringDimensionValues;
+use crate::helper::coordinate::*;
+use crate::reader::driver::*;
+use crate::structs::Spreadsheet;
+use crate::traits::AdjustmentCoordinateWithSheet;
+use crate::writer::driver::*;
+use crate::xml_read_loop;
+use crate::XlsxError;
+use quick_xml::events::{BytesStart, Event};
+use quick_xml::Reader;
+use quick_xml::Writer;
+use std::io::Cursor;
+use thin_vec::ThinVec;
+
+/// Text data of a chart, such as the categories.
+/// A range of several columns makes a hierarchy, as used by treemap and sunburst charts.
+#[derive(Clone, Default, Debug)]
+pub struct StringDimension {
+    r#type: EnumValue<StringDimensionValues>,
+    formula: Option<Formula>,
+    level: ThinVec<Level>,
+}
+
+impl StringDimension {
+    pub fn get_type(&self) -> &StringDimensionValues {
+        self.r#type.get_value()
+    }
+
+    pub fn set_type(&mut self, value: StringDimensionValues) -> &mut Self {
+        self.r#type.set_value(value);
+        self
+    }
+
+    pub fn get_formula(&self) -> Option<&Formula> {
+        self.formula.as_ref()
+    }
+
+    pub fn get_formula_mut(&mut self) -> Option<&mut Formula> {
+        self.formula.as_mut()
+    }
+
+    pub fn set_formula(&mut self, value: Formula) -> &mut Self {
+        self.formula = Some(value);
+        self
+    }
+
+    pub fn get_level(&self) -> &[Level] {
+        &self.level
+    }
+
+    pub fn get_level_mut(&mut self) -> &mut ThinVec<Level> {
+        &mut self.level
+    }
+
+    pub fn set_level(&mut self, value: impl Into<ThinVec<Level>>) -> &mut Self {
+        self.level = value.into();
+        self
+    }
+
+    pub fn add_level(&mut self, value: Level) -> &mut Self {
+        self.level.push(value);
+        self
+    }
+
+    // one level per column, the innermost level first
+    fn make_level_list(address: &Address, spreadsheet: &Spreadsheet) -> Vec<Level> {
+        let range = address.get_range();
+        let (start_col, end_col, start_row, end_row) = match (
+            range.get_coordinate_start_col(),
+            range.get_coordinate_end_col(),
+            range.get_coordinate_start_row(),
+            range.get_coordinate_end_row(),
+        ) {
+            (Some(start_col), Some(end_col), Some(start_row), Some(end_row)) => (
+                *start_col.get_num(),
+                *end_col.get_num(),
+                *start_row.get_num(),
+                *end_row.get_num(),
+            ),
+            _ => return vec![Level::from_address(address, spreadsheet)],
+        };
+        (start_col..=end_col)
+            .rev()
+            .map(|col| {
+                let mut column = Address::default();
+                column.set_sheet_name(address.get_sheet_name());
+                column.get_range_mut().set_range(format!(
+                    "{}:{}",
+                    coordinate_from_index(&col, &start_row),
+                    coordinate_from_index(&col, &end_row)
+                ));
+                Level::from_address(&column, spreadsheet)
+            })
+            .collect()
+    }
+
+    pub(crate) fn set_attributes<R: std::io::BufRead>(
+        &mut self,
+        reader: &mut Reader<R>,
+        e: &BytesStart,
+    ) -> Result<(), XlsxError> {
+        set_string_from_xml!(self, e, r#type, "type");
+
+        xml_read_loop!(
+            reader,
+            Event::Start(ref e) => match e.name().0 {
+                b"cx:f" => {
+                    let mut obj = Formula::default();
+                    obj.set_attributes(reader, e)?;
+                    self.set_formula(obj);
+                }
+                b"cx:lvl" => {
+                    let mut obj = Level::default();
+                    obj.set_attributes(reader, e, false)?;
+                    self.add_level(obj);
+                }
+                _ => (),
+            },
+            Event::Empty(ref e) => {
+                if e.name().0 == b"cx:lvl" {
+                    let mut obj = Level::default();
+                    obj.set_attributes(reader, e, true)?;
+                    self.add_level(obj);
+                }
+            },
+            Event::End(ref e) => {
+                if e.name().0 == b"cx:strDim" {
+                    return Ok(());
+                }
+            },
+            Event::Eof => return Err(end_element_error(reader, "cx:strDim")),
+        );
+    }
+
+    pub(crate) fn write_to(&self, writer: &mut Writer<Cursor<Vec<u8>>>, spreadsheet: &Spreadsheet) {
+        // cx:strDim
+        write_start_tag(
+            writer,
+            "cx:strDim",
+            vec![("type", self.r#type.get_value_string())],
+            false,
+        );
+
+        // cx:f
+        if let Some(v) = &self.formula {
+            v.write_to(writer);
+        }
+
+        // cx:lvl
+        match &self.formula {
+            Some(v) if v.has_address() => {
+                for level in Self::make_level_list(v.get_address(), spreadsheet) {
+                    level.write_to(writer);
+                }
+            }
+            _ => {
+                for level in &self.level {
+                    level.write_to(writer);
+                }
+            }
+        }
+
+        write_end_tag(writer, "cx:strDim");
+    }
+}
+impl AdjustmentCoordinateWithSheet for StringDimension {
+    fn adjustment_insert_coordinate_with_sheet(
+        &mut self,
+        sheet_name: &str,
+        root_col_num: &u32,
+        offset_col_num: &u32,
+        root_row_num: &u32,
+        offset_row_num: &u32,
+    ) {
+        if let Some(v) = &mut self.formula {
+            v.adjustment_insert_coordinate_with_sheet(
+                sheet_name,
+                root_col_num,
+                offset_col_num,
+                root_row_num,
+                offset_row_num,
+            );
+        }
+    }
+
+    fn adjustment_remove_coordinate_with_sheet(
+        &mut self,
+        sheet_name: &str,
+        root_col_num: &u32,
+        offset_col_num: &u32,
+        root_row_num: &u32,
+        offset_row_num: &u32,
+    ) {
+        if let Some(v) = &mut self.formula {
+            v.adjustment_remove_coordinate_with_sheet(
+                sheet_name,
+                root_col_num,
+                offset_col_num,
+                root_row_num,
+                offset_row_num,
+            );
+        }
+    }
+}
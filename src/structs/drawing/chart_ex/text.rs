@@ -0,0 +1,112 @@
+// cx:tx
+use super::RawElement;
+use super::TextData;
+use crate::reader::driver::*;
+use crate::structs::Spreadsheet;
+use crate::traits::AdjustmentCoordinateWithSheet;
+use crate::writer::driver::*;
+use crate::xml_read_loop;
+use crate::XlsxError;
+use quick_xml::events::{BytesStart, Event};
+use quick_xml::Reader;
+use quick_xml::Writer;
+use std::io::Cursor;
+
+#[derive(Clone, Default, Debug)]
+pub struct Text {
+    text_data: TextData,
+    rich: Option<RawElement>,
+}
+
+impl Text {
+    pub fn get_text_data(&self) -> &TextData {
+        &self.text_data
+    }
+
+    pub fn get_text_data_mut(&mut self) -> &mut TextData {
+        &mut self.text_data
+    }
+
+    pub fn set_text_data(&mut self, value: TextData) -> &mut Self {
+        self.text_data = value;
+        self
+    }
+
+    pub(crate) fn set_attributes<R: std::io::BufRead>(
+        &mut self,
+        reader: &mut Reader<R>,
+        _e: &BytesStart,
+    ) -> Result<(), XlsxError> {
+        xml_read_loop!(
+            reader,
+            Event::Start(ref e) => {
+                match e.name().0 {
+                    b"cx:txData" => {
+                        self.text_data.set_attributes(reader, e)?;
+                    }
+                    b"cx:rich" => {
+                        let mut obj = RawElement::default();
+                        obj.set_attributes(reader, e, false)?;
+                        self.rich = Some(obj);
+                    }
+                    _ => (),
+                }
+            },
+            Event::End(ref e) => {
+                if e.name().0 == b"cx:tx" {
+                    return Ok(());
+                }
+            },
+            Event::Eof => return Err(end_element_error(reader, "cx:tx")),
+        );
+    }
+
+    pub(crate) fn write_to(&self, writer: &mut Writer<Cursor<Vec<u8>>>, spreadsheet: &Spreadsheet) {
+        // cx:tx
+        write_start_tag(writer, "cx:tx", vec![], false);
+
+        match &self.rich {
+            // cx:rich
+            Some(v) => v.write_to(writer),
+            // cx:txData
+            None => self.text_data.write_to(writer, spreadsheet),
+        }
+
+        write_end_tag(writer, "cx:tx");
+    }
+}
+impl AdjustmentCoordinateWithSheet for Text {
+    fn adjustment_insert_coordinate_with_sheet(
+        &mut self,
+        sheet_name: &str,
+        root_col_num: &u32,
+        offset_col_num: &u32,
+        root_row_num: &u32,
+        offset_row_num: &u32,
+    ) {
+        self.text_data.adjustment_insert_coordinate_with_sheet(
+            sheet_name,
+            root_col_num,
+            offset_col_num,
+            root_row_num,
+            offset_row_num,
+        );
+    }
+
+    fn adjustment_remove_coordinate_with_sheet(
+        &mut self,
+        sheet_name: &str,
+        root_col_num: &u32,
+        offset_col_num: &u32,
+        root_row_num: &u32,
+        offset_row_num: &u32,
+    ) {
+        self.text_data.adjustment_remove_coordinate_with_sheet(
+            sheet_name,
+            root_col_num,
+            offset_col_num,
+            root_row_num,
+            offset_row_num,
+        );
+    }
+}
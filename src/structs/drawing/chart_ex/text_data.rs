@@ -0,0 +1,135 @@
+// cx:txData
+use super::super::super::StringValue;
+use super::Formula;
+use crate::reader::driver::*;
+use crate::structs::Spreadsheet;
+use crate::traits::AdjustmentCoordinateWithSheet;
+use crate::writer::driver::*;
+use crate::xml_read_loop;
+use crate::XlsxError;
+use quick_xml::events::{BytesStart, Event};
+use quick_xml::Reader;
+use quick_xml::Writer;
+use std::io::Cursor;
+
+#[derive(Clone, Default, Debug)]
+pub struct TextData {
+    formula: Option<Formula>,
+    value: StringValue,
+}
+
+impl TextData {
+    pub fn get_formula(&self) -> Option<&Formula> {
+        self.formula.as_ref()
+    }
+
+    pub fn get_formula_mut(&mut self) -> Option<&mut Formula> {
+        self.formula.as_mut()
+    }
+
+    pub fn set_formula(&mut self, value: Formula) -> &mut Self {
+        self.formula = Some(value);
+        self
+    }
+
+    pub fn get_value(&self) -> &str {
+        self.value.get_value_str()
+    }
+
+    pub fn set_value<S: Into<String>>(&mut self, value: S) -> &mut Self {
+        self.value.set_value(value);
+        self
+    }
+
+    pub(crate) fn set_attributes<R: std::io::BufRead>(
+        &mut self,
+        reader: &mut Reader<R>,
+        _e: &BytesStart,
+    ) -> Result<(), XlsxError> {
+        let mut value = String::new();
+        xml_read_loop!(
+            reader,
+            Event::Start(ref e) => {
+                if e.name().0 == b"cx:f" {
+                    let mut obj = Formula::default();
+                    obj.set_attributes(reader, e)?;
+                    self.set_formula(obj);
+                }
+            },
+            Event::Text(e) => {
+                value = e.unescape()?.to_string();
+            },
+            Event::End(ref e) => match e.name().0 {
+                b"cx:v" => {
+                    self.set_value(std::mem::take(&mut value));
+                }
+                b"cx:txData" => return Ok(()),
+                _ => (),
+            },
+            Event::Eof => return Err(end_element_error(reader, "cx:txData")),
+        );
+    }
+
+    pub(crate) fn write_to(&self, writer: &mut Writer<Cursor<Vec<u8>>>, spreadsheet: &Spreadsheet) {
+        // cx:txData
+        write_start_tag(writer, "cx:txData", vec![], false);
+
+        // cx:f
+        let mut value = self.value.get_value_str().to_string();
+        if let Some(v) = &self.formula {
+            v.write_to(writer);
+            if v.has_address() {
+                let cell_value_list = spreadsheet.get_cell_value_by_address_crate(v.get_address());
+                if let Some(cell_value) = cell_value_list.first() {
+                    value = cell_value.get_value().to_string();
+                }
+            }
+        }
+
+        // cx:v
+        write_start_tag(writer, "cx:v", vec![], false);
+        write_text_node(writer, value);
+        write_end_tag(writer, "cx:v");
+
+        write_end_tag(writer, "cx:txData");
+    }
+}
+impl AdjustmentCoordinateWithSheet for TextData {
+    fn adjustment_insert_coordinate_with_sheet(
+        &mut self,
+        sheet_name: &str,
+        root_col_num: &u32,
+        offset_col_num: &u32,
+        root_row_num: &u32,
+        offset_row_num: &u32,
+    ) {
+        if let Some(v) = &mut self.formula {
+            v.adjustment_insert_coordinate_with_sheet(
+                sheet_name,
+                root_col_num,
+                offset_col_num,
+                root_row_num,
+                offset_row_num,
+            );
+        }
+    }
+
+    fn adjustment_remove_coordinate_with_sheet(
+        &mut self,
+        sheet_name: &str,
+        root_col_num: &u32,
+        offset_col_num: &u32,
+        root_row_num: &u32,
+        offset_row_num: &u32,
+    ) {
+        if let Some(v) = &mut self.formula {
+            v.adjustment_remove_coordinate_with_sheet(
+                sheet_name,
+                root_col_num,
+                offset_col_num,
+                root_row_num,
+                offset_row_num,
+            );
+        }
+    }
+}
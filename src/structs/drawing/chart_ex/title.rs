@@ -0,0 +1,170 @@
+// cx:title
+use super::super::super::BooleanValue;
+use super::super::super::EnumValue;
+use super::PositionAlignmentValues;
+use super::RawElement;
+use super::SidePositionValues;
+use super::Text;
+use crate::reader::driver::*;
+use crate::structs::Spreadsheet;
+use crate::traits::AdjustmentCoordinateWithSheet;
+use crate::writer::driver::*;
+use crate::xml_read_loop;
+use crate::XlsxError;
+use quick_xml::events::{BytesStart, Event};
+use quick_xml::Reader;
+use quick_xml::Writer;
+use std::io::Cursor;
+use thin_vec::ThinVec;
+
+const RAW_CHILDREN: &[&str] = &["cx:spPr", "cx:txPr", "cx:extLst"];
+
+#[derive(Clone, Default, Debug)]
+pub struct Title {
+    position: EnumValue<SidePositionValues>,
+    alignment: EnumValue<PositionAlignmentValues>,
+    overlay: BooleanValue,
+    text: Option<Text>,
+    raw_children: ThinVec<RawElement>,
+}
+
+impl Title {
+    pub fn get_position(&self) -> &SidePositionValues {
+        self.position.get_value()
+    }
+
+    pub fn set_position(&mut self, value: SidePositionValues) -> &mut Self {
+        self.position.set_value(value);
+        self
+    }
+
+    pub fn get_alignment(&self) -> &PositionAlignmentValues {
+        self.alignment.get_value()
+    }
+
+    pub fn set_alignment(&mut self, value: PositionAlignmentValues) -> &mut Self {
+        self.alignment.set_value(value);
+        self
+    }
+
+    pub fn get_overlay(&self) -> &bool {
+        self.overlay.get_value()
+    }
+
+    pub fn set_overlay(&mut self, value: bool) -> &mut Self {
+        self.overlay.set_value(value);
+        self
+    }
+
+    pub fn get_text(&self) -> Option<&Text> {
+        self.text.as_ref()
+    }
+
+    pub fn get_text_mut(&mut self) -> Option<&mut Text> {
+        self.text.as_mut()
+    }
+
+    pub fn set_text(&mut self, value: Text) -> &mut Self {
+        self.text = Some(value);
+        self
+    }
+
+    pub(crate) fn set_attributes<R: std::io::BufRead>(
+        &mut self,
+        reader: &mut Reader<R>,
+        e: &BytesStart,
+        empty_flg: bool,
+    ) -> Result<(), XlsxError> {
+        set_string_from_xml!(self, e, position, "pos");
+        set_string_from_xml!(self, e, alignment, "align");
+        set_string_from_xml!(self, e, overlay, "overlay");
+
+        if empty_flg {
+            return Ok(());
+        }
+
+        xml_read_loop!(
+            reader,
+            Event::Start(ref e) => {
+                if e.name().0 == b"cx:tx" {
+                    let mut obj = Text::default();
+                    obj.set_attributes(reader, e)?;
+                    self.set_text(obj);
+                } else {
+                    RawElement::read_into(&mut self.raw_children, RAW_CHILDREN, reader, e, false)?;
+                }
+            },
+            Event::Empty(ref e) => {
+                RawElement::read_into(&mut self.raw_children, RAW_CHILDREN, reader, e, true)?;
+            },
+            Event::End(ref e) => {
+                if e.name().0 == b"cx:title" {
+                    return Ok(());
+                }
+            },
+            Event::Eof => return Err(end_element_error(reader, "cx:title")),
+        );
+    }
+
+    pub(crate) fn write_to(&self, writer: &mut Writer<Cursor<Vec<u8>>>, spreadsheet: &Spreadsheet) {
+        // cx:title
+        let mut attributes: Vec<(&str, &str)> = Vec::new();
+        attributes.push(("pos", self.position.get_value_string()));
+        attributes.push(("align", self.alignment.get_value_string()));
+        attributes.push(("overlay", self.overlay.get_value_string()));
+        let empty_flg = self.text.is_none() && self.raw_children.is_empty();
+        write_start_tag(writer, "cx:title", attributes, empty_flg);
+        if empty_flg {
+            return;
+        }
+
+        // cx:tx
+        if let Some(v) = &self.text {
+            v.write_to(writer, spreadsheet);
+        }
+
+        // cx:spPr, cx:txPr, cx:extLst
+        RawElement::write_list(writer, &self.raw_children, RAW_CHILDREN);
+
+        write_end_tag(writer, "cx:title");
+    }
+}
+impl AdjustmentCoordinateWithSheet for Title {
+    fn adjustment_insert_coordinate_with_sheet(
+        &mut self,
+        sheet_name: &str,
+        root_col_num: &u32,
+        offset_col_num: &u32,
+        root_row_num: &u32,
+        offset_row_num: &u32,
+    ) {
+        if let Some(v) = &mut self.text {
+            v.adjustment_insert_coordinate_with_sheet(
+                sheet_name,
+                root_col_num,
+                offset_col_num,
+                root_row_num,
+                offset_row_num,
+            );
+        }
+    }
+
+    fn adjustment_remove_coordinate_with_sheet(
+        &mut self,
+        sheet_name: &str,
+        root_col_num: &u32,
+        offset_col_num: &u32,
+        root_row_num: &u32,
+        offset_row_num: &u32,
+    ) {
+        if let Some(v) = &mut self.text {
+            v.adjustment_remove_coordinate_with_sheet(
+                sheet_name,
+                root_col_num,
+                offset_col_num,
+                root_row_num,
+                offset_row_num,
+            );
+        }
+    }
+}
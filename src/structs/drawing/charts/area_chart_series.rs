@@ -3,6 +3,7 @@ use super::Bubble3D;
 use super::BubbleSize;
 use super::CategoryAxisData;
 use super::DataLabels;
+use super::ErrorBars;
 use super::Explosion;
 use super::Formula;
 use super::Index;
@@ -12,16 +13,20 @@ use super::Order;
 use super::SeriesText;
 use super::ShapeProperties;
 use super::Smooth;
+use super::Trendline;
 use super::Values;
 use super::XValues;
 use super::YValues;
+use crate::reader::driver::*;
 use crate::structs::Spreadsheet;
 use crate::writer::driver::*;
 use crate::xml_read_loop;
+use crate::XlsxError;
 use quick_xml::events::{BytesStart, Event};
 use quick_xml::Reader;
 use quick_xml::Writer;
 use std::io::Cursor;
+use thin_vec::ThinVec;
 
 #[derive(Clone, Default, Debug)]
 pub struct AreaChartSeries {
@@ -40,6 +45,8 @@ pub struct AreaChartSeries {
     bubble_3d: Option<Bubble3D>,
     smooth: Option<Smooth>,
     data_labels: Option<DataLabels>,
+    trendline: ThinVec<Trendline>,
+    error_bars: ThinVec<ErrorBars>,
 }
 
 impl AreaChartSeries {
@@ -225,6 +232,11 @@ impl AreaChartSeries {
         self
     }
 
+    pub fn remove_smooth(&mut self) -> &mut Self {
+        self.smooth = None;
+        self
+    }
+
     pub fn get_data_labels(&self) -> Option<&DataLabels> {
         self.data_labels.as_ref()
     }
@@ -238,6 +250,44 @@ impl AreaChartSeries {
         self
     }
 
+    pub fn get_trendline(&self) -> &[Trendline] {
+        &self.trendline
+    }
+
+    pub fn get_trendline_mut(&mut self) -> &mut ThinVec<Trendline> {
+        &mut self.trendline
+    }
+
+    pub fn set_trendline(&mut self, value: impl Into<ThinVec<Trendline>>) -> &mut Self {
+        self.trendline = value.into();
+        self
+    }
+
+    pub fn add_trendline(&mut self, value: Trendline) -> &mut Self {
+        self.trendline.push(value);
+        self
+    }
+
+    /// Error bars of the series.
+    /// Scatter and bubble series may have one for each direction.
+    pub fn get_error_bars(&self) -> &[ErrorBars] {
+        &self.error_bars
+    }
+
+    pub fn get_error_bars_mut(&mut self) -> &mut ThinVec<ErrorBars> {
+        &mut self.error_bars
+    }
+
+    pub fn set_error_bars(&mut self, value: impl Into<ThinVec<ErrorBars>>) -> &mut Self {
+        self.error_bars = value.into();
+        self
+    }
+
+    pub fn add_error_bars(&mut self, value: ErrorBars) -> &mut Self {
+        self.error_bars.push(value);
+        self
+    }
+
     pub fn get_formula_mut(&mut self) -> Vec<&mut Formula> {
         let mut result: Vec<&mut Formula> = Vec::default();
 
@@ -258,6 +308,9 @@ impl AreaChartSeries {
         if let Some(v) = &mut self.bubble_size {
             result.push(v.get_number_reference_mut().get_formula_mut());
         }
+        for v in &mut self.error_bars {
+            result.extend(v.get_formula_mut());
+        }
         result
     }
 
@@ -265,92 +318,102 @@ impl AreaChartSeries {
         &mut self,
         reader: &mut Reader<R>,
         _e: &BytesStart,
-    ) {
+    ) -> Result<(), XlsxError> {
         xml_read_loop!(
             reader,
             Event::Start(ref e) => match e.name().into_inner() {
                 b"c:v" => {
                     let mut obj = SeriesText::default();
-                    obj.set_attributes(reader, e);
+                    obj.set_attributes(reader, e)?;
                     self.set_series_text(obj);
                 }
                 b"c:marker" => {
                     let mut obj = Marker::default();
-                    obj.set_attributes(reader, e, false);
+                    obj.set_attributes(reader, e, false)?;
                     self.set_marker(obj);
                 }
                 b"c:spPr" => {
                     let mut obj = ShapeProperties::default();
-                    obj.set_attributes(reader, e);
+                    obj.set_attributes(reader, e)?;
                     self.set_shape_properties(obj);
                 }
                 b"c:cat" => {
                     let mut obj = CategoryAxisData::default();
-                    obj.set_attributes(reader, e);
+                    obj.set_attributes(reader, e)?;
                     self.set_category_axis_data(obj);
                 }
                 b"c:val" => {
                     let mut obj = Values::default();
-                    obj.set_attributes(reader, e);
+                    obj.set_attributes(reader, e)?;
                     self.set_values(obj);
                 }
                 b"c:xVal" => {
                     let mut obj = XValues::default();
-                    obj.set_attributes(reader, e);
+                    obj.set_attributes(reader, e)?;
                     self.set_x_values(obj);
                 }
                 b"c:yVal" => {
                     let mut obj = YValues::default();
-                    obj.set_attributes(reader, e);
+                    obj.set_attributes(reader, e)?;
                     self.set_y_values(obj);
                 }
                 b"c:bubbleSize" => {
                     let mut obj = BubbleSize::default();
-                    obj.set_attributes(reader, e);
+                    obj.set_attributes(reader, e)?;
                     self.set_bubble_size(obj);
                 }
                 b"c:dLbls" => {
                     let mut obj = DataLabels::default();
-                    obj.set_attributes(reader, e);
+                    obj.set_attributes(reader, e)?;
                     self.set_data_labels(obj);
                 }
+                b"c:trendline" => {
+                    let mut obj = Trendline::default();
+                    obj.set_attributes(reader, e)?;
+                    self.add_trendline(obj);
+                }
+                b"c:errBars" => {
+                    let mut obj = ErrorBars::default();
+                    obj.set_attributes(reader, e)?;
+                    self.add_error_bars(obj);
+                }
                 _ => (),
             },
             Event::Empty(ref e) => match e.name().into_inner() {
                 b"c:idx" => {
-                    self.index.set_attributes(reader, e);
+                    self.index.set_attributes(reader, e)?;
                 }
                 b"c:order" => {
-                    self.order.set_attributes(reader, e);
+                    self.order.set_attributes(reader, e)?;
                 }
                 b"c:explosion" => {
                     let mut obj = Explosion::default();
-                    obj.set_attributes(reader, e);
+                    obj.set_attributes(reader, e)?;
                     self.set_explosion(obj);
                 }
                 b"c:invertIfNegative" => {
                     let mut obj = InvertIfNegative::default();
-                    obj.set_attributes(reader, e);
+                    obj.set_attributes(reader, e)?;
                     self.set_invert_if_negative(obj);
                 }
                 b"c:bubble3D" => {
                     let mut obj = Bubble3D::default();
-                    obj.set_attributes(reader, e);
+                    obj.set_attributes(reader, e)?;
                     self.set_bubble_3d(obj);
                 }
                 b"c:smooth" => {
                     let mut obj = Smooth::default();
-                    obj.set_attributes(reader, e);
+                    obj.set_attributes(reader, e)?;
                     self.set_smooth(obj);
                 }
                 _ => (),
             },
             Event::End(ref e) => {
                 if e.name().into_inner() == b"c:ser" {
-                    return;
+                    return Ok(());
                 }
             },
-            Event::Eof => panic!("Error: Could not find {} end element", "c:ser"),
+            Event::Eof => return Err(end_element_error(reader, "c:ser")),
         );
     }
 
@@ -369,6 +432,11 @@ impl AreaChartSeries {
             v.write_to(writer);
         }
 
+        // c:spPr
+        if let Some(v) = &self.shape_properties {
+            v.write_to(writer);
+        }
+
         // c:explosion
         if let Some(v) = &self.explosion {
             v.write_to(writer);
@@ -384,16 +452,21 @@ impl AreaChartSeries {
             v.write_to(writer);
         }
 
-        // c:spPr
-        if let Some(v) = &self.shape_properties {
+        // c:dLbls
+        if let Some(v) = &self.data_labels {
             v.write_to(writer);
         }
 
-        // c:dLbls
-        if let Some(v) = &self.data_labels {
+        // c:trendline
+        for v in &self.trendline {
             v.write_to(writer);
         }
 
+        // c:errBars
+        for v in &self.error_bars {
+            v.write_to(writer, spreadsheet);
+        }
+
         // c:cat
         if let Some(v) = &self.category_axis_data {
             v.write_to(writer, spreadsheet);
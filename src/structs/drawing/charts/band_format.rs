@@ -0,0 +1,90 @@
+// c:bandFmt
+use super::Index;
+use super::ShapeProperties;
+use crate::reader::driver::*;
+use crate::writer::driver::*;
+use crate::xml_read_loop;
+use crate::XlsxError;
+use quick_xml::events::{BytesStart, Event};
+use quick_xml::Reader;
+use quick_xml::Writer;
+use std::io::Cursor;
+
+#[derive(Clone, Default, Debug)]
+pub struct BandFormat {
+    index: Index,
+    shape_properties: Option<ShapeProperties>,
+}
+
+impl BandFormat {
+    pub fn get_index(&self) -> &Index {
+        &self.index
+    }
+
+    pub fn get_index_mut(&mut self) -> &mut Index {
+        &mut self.index
+    }
+
+    pub fn set_index(&mut self, value: Index) -> &mut Self {
+        self.index = value;
+        self
+    }
+
+    pub fn get_shape_properties(&self) -> Option<&ShapeProperties> {
+        self.shape_properties.as_ref()
+    }
+
+    pub fn get_shape_properties_mut(&mut self) -> Option<&mut ShapeProperties> {
+        self.shape_properties.as_mut()
+    }
+
+    pub fn set_shape_properties(&mut self, value: ShapeProperties) -> &mut Self {
+        self.shape_properties = Some(value);
+        self
+    }
+
+    pub(crate) fn set_attributes<R: std::io::BufRead>(
+        &mut self,
+        reader: &mut Reader<R>,
+        _e: &BytesStart,
+    ) -> Result<(), XlsxError> {
+        xml_read_loop!(
+            reader,
+            Event::Start(ref e) => match e.name().0 {
+                b"c:spPr" => {
+                    let mut obj = ShapeProperties::default();
+                    obj.set_attributes(reader, e)?;
+                    self.set_shape_properties(obj);
+                }
+                _ => (),
+            },
+            Event::Empty(ref e) => match e.name().0 {
+                b"c:idx" => {
+                    self.index.set_attributes(reader, e)?;
+                }
+                _ => (),
+            },
+            Event::End(ref e) => {
+                if e.name().0 == b"c:bandFmt" {
+                    return Ok(());
+                }
+            },
+            Event::Eof => return Err(end_element_error(reader, "c:bandFmt")),
+        );
+    }
+
+    pub(crate) fn write_to(&self, writer: &mut Writer<Cursor<Vec<u8>>>) {
+        // c:bandFmt
+        write_start_tag(writer, "c:bandFmt", vec![], false);
+
+        // c:idx
+        self.index.write_to(writer);
+
+        // c:spPr
+        if let Some(v) = &self.shape_properties {
+            v.write_to(writer);
+        }
+
+        write_end_tag(writer, "c:bandFmt");
+    }
+}
@@ -0,0 +1,86 @@
+// c:bandFmts
+use super::BandFormat;
+use crate::reader::driver::*;
+use crate::writer::driver::*;
+use crate::xml_read_loop;
+use crate::XlsxError;
+use quick_xml::events::{BytesStart, Event};
+use quick_xml::Reader;
+use quick_xml::Writer;
+use std::io::Cursor;
+use thin_vec::ThinVec;
+
+#[derive(Clone, Default, Debug)]
+pub struct BandFormats {
+    band_format: ThinVec<BandFormat>,
+}
+
+impl BandFormats {
+    pub fn get_band_format(&self) -> &[BandFormat] {
+        &self.band_format
+    }
+
+    pub fn get_band_format_mut(&mut self) -> &mut ThinVec<BandFormat> {
+        &mut self.band_format
+    }
+
+    pub fn set_band_format(&mut self, value: impl Into<ThinVec<BandFormat>>) -> &mut Self {
+        self.band_format = value.into();
+        self
+    }
+
+    pub fn add_band_format(&mut self, value: BandFormat) -> &mut Self {
+        self.band_format.push(value);
+        self
+    }
+
+    pub(crate) fn set_attributes<R: std::io::BufRead>(
+        &mut self,
+        reader: &mut Reader<R>,
+        _e: &BytesStart,
+        empty_flg: bool,
+    ) -> Result<(), XlsxError> {
+        if empty_flg {
+            return Ok(());
+        }
+
+        xml_read_loop!(
+            reader,
+            Event::Start(ref e) => match e.name().0 {
+                b"c:bandFmt" => {
+                    let mut obj = BandFormat::default();
+                    obj.set_attributes(reader, e)?;
+                    self.add_band_format(obj);
+                }
+                _ => (),
+            },
+            Event::End(ref e) => {
+                if e.name().0 == b"c:bandFmts" {
+                    return Ok(());
+                }
+            },
+            Event::Eof => return Err(end_element_error(reader, "c:bandFmts")),
+        );
+    }
+
+    fn with_include(&self) -> bool {
+        !self.band_format.is_empty()
+    }
+
+    pub(crate) fn write_to(&self, writer: &mut Writer<Cursor<Vec<u8>>>) {
+        if !self.with_include() {
+            // c:bandFmts
+            write_start_tag(writer, "c:bandFmts", vec![], true);
+            return;
+        }
+        // c:bandFmts
+        write_start_tag(writer, "c:bandFmts", vec![], false);
+
+        // c:bandFmt
+        for v in &self.band_format {
+            v.write_to(writer);
+        }
+
+        write_end_tag(writer, "c:bandFmts");
+    }
+}
@@ -11,6 +11,7 @@ use super::VaryColors;
 use crate::reader::driver::*;
 use crate::structs::Spreadsheet;
 use crate::writer::driver::*;
+use crate::XlsxError;
 use quick_xml::events::{BytesStart, Event};
 use quick_xml::Reader;
 use quick_xml::Writer;
@@ -143,19 +144,19 @@ impl Bar3DChart {
         &mut self,
         reader: &mut Reader<R>,
         _e: &BytesStart,
-    ) {
+    ) -> Result<(), XlsxError> {
         xml_read_loop!(
             reader,
             Event::Start(ref e) => {
                 match e.name().into_inner() {
                     b"c:ser" => {
                         let mut obj = AreaChartSeries::default();
-                        obj.set_attributes(reader, e);
+                        obj.set_attributes(reader, e)?;
                         self.get_area_chart_series_list_mut()
                             .add_area_chart_series(obj);
                         }
                     b"c:dLbls" => {
-                        self.data_labels.set_attributes(reader, e);
+                        self.data_labels.set_attributes(reader, e)?;
                     }
                     _ => (),
                 }
@@ -163,23 +164,23 @@ impl Bar3DChart {
             Event::Empty(ref e) => {
                 match e.name().into_inner() {
                     b"c:barDir" => {
-                        self.bar_direction.set_attributes(reader, e);
+                        self.bar_direction.set_attributes(reader, e)?;
                     }
                     b"c:grouping" => {
-                        self.grouping.set_attributes(reader, e);
+                        self.grouping.set_attributes(reader, e)?;
                     }
                     b"c:varyColors" => {
-                        self.vary_colors.set_attributes(reader, e);
+                        self.vary_colors.set_attributes(reader, e)?;
                     }
                     b"c:gapWidth" => {
-                        self.gap_width.set_attributes(reader, e);
+                        self.gap_width.set_attributes(reader, e)?;
                     }
                     b"c:shape" => {
-                        self.shape.set_attributes(reader, e);
+                        self.shape.set_attributes(reader, e)?;
                     }
                     b"c:axId" => {
                         let mut obj = AxisId::default();
-                        obj.set_attributes(reader, e);
+                        obj.set_attributes(reader, e)?;
                         self.add_axis_id(obj);
                     }
                     _ => (),
@@ -187,10 +188,10 @@ impl Bar3DChart {
             },
             Event::End(ref e) => {
                 if e.name().into_inner() == b"c:bar3DChart" {
-                    return;
+                    return Ok(());
                 }
             },
-            Event::Eof => panic!("Error: Could not find {} end element", "c:bar3DChart")
+            Event::Eof => return Err(end_element_error(reader, "c:bar3DChart"))
         );
     }
 
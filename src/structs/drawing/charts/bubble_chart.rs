@@ -9,6 +9,7 @@ use super::VaryColors;
 use crate::reader::driver::*;
 use crate::structs::Spreadsheet;
 use crate::writer::driver::*;
+use crate::XlsxError;
 use quick_xml::events::{BytesStart, Event};
 use quick_xml::Reader;
 use quick_xml::Writer;
@@ -113,19 +114,19 @@ impl BubbleChart {
         &mut self,
         reader: &mut Reader<R>,
         _e: &BytesStart,
-    ) {
+    ) -> Result<(), XlsxError> {
         xml_read_loop!(
             reader,
             Event::Start(ref e) => {
                 match e.name().into_inner() {
                     b"c:ser" => {
                         let mut obj = AreaChartSeries::default();
-                        obj.set_attributes(reader, e);
+                        obj.set_attributes(reader, e)?;
                         self.get_area_chart_series_list_mut()
                             .add_area_chart_series(obj);
                         }
                     b"c:dLbls" => {
-                        self.data_labels.set_attributes(reader, e);
+                        self.data_labels.set_attributes(reader, e)?;
                     }
                     _ => (),
                 }
@@ -133,17 +134,17 @@ impl BubbleChart {
             Event::Empty(ref e) => {
                 match e.name().into_inner() {
                     b"c:varyColors" => {
-                        self.vary_colors.set_attributes(reader, e);
+                        self.vary_colors.set_attributes(reader, e)?;
                     }
                     b"c:bubbleScale" => {
-                        self.bubble_scale.set_attributes(reader, e);
+                        self.bubble_scale.set_attributes(reader, e)?;
                     }
                     b"c:showNegBubbles" => {
-                        self.show_negative_bubbles.set_attributes(reader, e);
+                        self.show_negative_bubbles.set_attributes(reader, e)?;
                     }
                     b"c:axId" => {
                         let mut obj = AxisId::default();
-                        obj.set_attributes(reader, e);
+                        obj.set_attributes(reader, e)?;
                         self.add_axis_id(obj);
                     }
                     _ => (),
@@ -151,10 +152,10 @@ impl BubbleChart {
             },
             Event::End(ref e) => {
                 if e.name().into_inner() == b"c:bubbleChart" {
-                    return;
+                    return Ok(());
                 }
             },
-            Event::Eof => panic!("Error: Could not find {} end element", "c:bubbleChart")
+            Event::Eof => return Err(end_element_error(reader, "c:bubbleChart"))
         );
     }
 
@@ -0,0 +1,51 @@
+use super::super::super::EnumTrait;
+use std::str::FromStr;
+#[derive(Clone, Debug)]
+pub enum BuiltInUnitValues {
+    Billions,
+    HundredMillions,
+    Hundreds,
+    HundredThousands,
+    Millions,
+    TenMillions,
+    TenThousands,
+    Thousands,
+    Trillions,
+}
+impl Default for BuiltInUnitValues {
+    fn default() -> Self {
+        Self::Thousands
+    }
+}
+impl EnumTrait for BuiltInUnitValues {
+    fn get_value_string(&self) -> &str {
+        match &self {
+            Self::Billions => "billions",
+            Self::HundredMillions => "hundredMillions",
+            Self::Hundreds => "hundreds",
+            Self::HundredThousands => "hundredThousands",
+            Self::Millions => "millions",
+            Self::TenMillions => "tenMillions",
+            Self::TenThousands => "tenThousands",
+            Self::Thousands => "thousands",
+            Self::Trillions => "trillions",
+        }
+    }
+}
+impl FromStr for BuiltInUnitValues {
+    type Err = ();
+    fn from_str(input: &str) -> Result<Self, Self::Err> {
+        match input {
+            "billions" => Ok(Self::Billions),
+            "hundredMillions" => Ok(Self::HundredMillions),
+            "hundreds" => Ok(Self::Hundreds),
+            "hundredThousands" => Ok(Self::HundredThousands),
+            "millions" => Ok(Self::Millions),
+            "tenMillions" => Ok(Self::TenMillions),
+            "tenThousands" => Ok(Self::TenThousands),
+            "thousands" => Ok(Self::Thousands),
+            "trillions" => Ok(Self::Trillions),
+            _ => Err(()),
+        }
+    }
+}
@@ -4,6 +4,7 @@ use super::StringReference;
 use crate::reader::driver::*;
 use crate::structs::Spreadsheet;
 use crate::writer::driver::*;
+use crate::XlsxError;
 use quick_xml::events::{BytesStart, Event};
 use quick_xml::Reader;
 use quick_xml::Writer;
@@ -56,19 +57,19 @@ impl CategoryAxisData {
         &mut self,
         reader: &mut Reader<R>,
         _e: &BytesStart,
-    ) {
+    ) -> Result<(), XlsxError> {
         xml_read_loop!(
             reader,
             Event::Start(ref e) => {
                 match e.name().into_inner() {
                     b"c:strRef" => {
                         let mut obj = StringReference::default();
-                        obj.set_attributes(reader, e);
+                        obj.set_attributes(reader, e)?;
                         self.set_string_reference(obj);
                     }
                     b"c:strLit" => {
                         let mut obj = StringLiteral::default();
-                        obj.set_attributes(reader, e);
+                        obj.set_attributes(reader, e)?;
                         self.set_string_literal(obj);
                     }
                     _ => (),
@@ -76,10 +77,10 @@ impl CategoryAxisData {
             },
             Event::End(ref e) => {
                 if e.name().into_inner() == b"c:cat" {
-                    return;
+                    return Ok(());
                 }
             },
-            Event::Eof => panic!("Error: Could not find {} end element", "c:cat")
+            Event::Eof => return Err(end_element_error(reader, "c:cat"))
         );
     }
 
@@ -1,4 +1,6 @@
+use crate::reader::driver::*;
 use crate::xml_read_loop;
+use crate::XlsxError;
 
 // c:chartSpace
 use super::Chart;
@@ -124,48 +126,48 @@ impl ChartSpace {
         &mut self,
         reader: &mut Reader<R>,
         _e: &BytesStart,
-    ) {
+    ) -> Result<(), XlsxError> {
         xml_read_loop!(
             reader,
             Event::Start(ref e) => match e.name().into_inner() {
                 b"mc:AlternateContent" => {
                     let mut obj = Style::default();
-                    obj.set_attributes(reader, e);
+                    obj.set_attributes(reader, e)?;
                     self.set_style(obj);
                 }
                 b"c:chart" => {
-                    self.chart.set_attributes(reader, e);
+                    self.chart.set_attributes(reader, e)?;
                 }
                 b"c:printSettings" => {
                     let mut obj = PrintSettings::default();
-                    obj.set_attributes(reader, e);
+                    obj.set_attributes(reader, e)?;
                     self.set_print_settings(obj);
                 }
                 b"c:spPr" => {
                     let mut obj = ShapeProperties::default();
-                    obj.set_attributes(reader, e);
+                    obj.set_attributes(reader, e)?;
                     self.set_shape_properties(obj);
                 }
                 _ => (),
             },
             Event::Empty(ref e) => match e.name().into_inner() {
                 b"c:date1904" => {
-                    self.date1904.set_attributes(reader, e);
+                    self.date1904.set_attributes(reader, e)?;
                 }
                 b"c:lang" => {
-                    self.editing_language.set_attributes(reader, e);
+                    self.editing_language.set_attributes(reader, e)?;
                 }
                 b"c:roundedCorners" => {
-                    self.rounded_corners.set_attributes(reader, e);
+                    self.rounded_corners.set_attributes(reader, e)?;
                 }
                 _ => (),
             },
             Event::End(ref e) => {
                 if e.name().into_inner() == b"c:chartSpace" {
-                    return;
+                    return Ok(());
                 }
             },
-            Event::Eof => panic!("Error: Could not find {} end element", "c:chartSpace"),
+            Event::Eof => return Err(end_element_error(reader, "c:chartSpace")),
         );
     }
 
@@ -1,7 +1,9 @@
 // c:tx
 use super::RichText;
+use crate::reader::driver::*;
 use crate::writer::driver::*;
 use crate::xml_read_loop;
+use crate::XlsxError;
 use quick_xml::events::{BytesStart, Event};
 use quick_xml::Reader;
 use quick_xml::Writer;
@@ -30,20 +32,20 @@ impl ChartText {
         &mut self,
         reader: &mut Reader<R>,
         _e: &BytesStart,
-    ) {
+    ) -> Result<(), XlsxError> {
         xml_read_loop!(
             reader,
             Event::Start(ref e) => {
                 if e.name().into_inner() == b"c:rich" {
-                    self.rich_text.set_attributes(reader, e);
+                    self.rich_text.set_attributes(reader, e)?;
                 }
             },
             Event::End(ref e) => {
                 if e.name().into_inner() == b"c:tx" {
-                    return;
+                    return Ok(());
                 }
             },
-            Event::Eof => panic!("Error: Could not find {} end element", "c:tx"),
+            Event::Eof => return Err(end_element_error(reader, "c:tx")),
         );
     }
 
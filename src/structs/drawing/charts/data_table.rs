@@ -0,0 +1,213 @@
+// c:dTable
+use super::ShapeProperties;
+use super::ShowHorizontalBorder;
+use super::ShowKeys;
+use super::ShowOutlineBorder;
+use super::ShowVerticalBorder;
+use super::TextProperties;
+use crate::reader::driver::*;
+use crate::writer::driver::*;
+use crate::xml_read_loop;
+use crate::XlsxError;
+use quick_xml::events::{BytesStart, Event};
+use quick_xml::Reader;
+use quick_xml::Writer;
+use std::io::Cursor;
+
+#[derive(Clone, Default, Debug)]
+pub struct DataTable {
+    show_horizontal_border: Option<ShowHorizontalBorder>,
+    show_vertical_border: Option<ShowVerticalBorder>,
+    show_outline_border: Option<ShowOutlineBorder>,
+    show_keys: Option<ShowKeys>,
+    shape_properties: Option<ShapeProperties>,
+    text_properties: Option<TextProperties>,
+}
+
+impl DataTable {
+    pub fn get_show_horizontal_border(&self) -> Option<&ShowHorizontalBorder> {
+        self.show_horizontal_border.as_ref()
+    }
+
+    pub fn get_show_horizontal_border_mut(&mut self) -> Option<&mut ShowHorizontalBorder> {
+        self.show_horizontal_border.as_mut()
+    }
+
+    pub fn set_show_horizontal_border(&mut self, value: ShowHorizontalBorder) -> &mut Self {
+        self.show_horizontal_border = Some(value);
+        self
+    }
+
+    pub fn get_show_vertical_border(&self) -> Option<&ShowVerticalBorder> {
+        self.show_vertical_border.as_ref()
+    }
+
+    pub fn get_show_vertical_border_mut(&mut self) -> Option<&mut ShowVerticalBorder> {
+        self.show_vertical_border.as_mut()
+    }
+
+    pub fn set_show_vertical_border(&mut self, value: ShowVerticalBorder) -> &mut Self {
+        self.show_vertical_border = Some(value);
+        self
+    }
+
+    pub fn get_show_outline_border(&self) -> Option<&ShowOutlineBorder> {
+        self.show_outline_border.as_ref()
+    }
+
+    pub fn get_show_outline_border_mut(&mut self) -> Option<&mut ShowOutlineBorder> {
+        self.show_outline_border.as_mut()
+    }
+
+    pub fn set_show_outline_border(&mut self, value: ShowOutlineBorder) -> &mut Self {
+        self.show_outline_border = Some(value);
+        self
+    }
+
+    pub fn get_show_keys(&self) -> Option<&ShowKeys> {
+        self.show_keys.as_ref()
+    }
+
+    pub fn get_show_keys_mut(&mut self) -> Option<&mut ShowKeys> {
+        self.show_keys.as_mut()
+    }
+
+    pub fn set_show_keys(&mut self, value: ShowKeys) -> &mut Self {
+        self.show_keys = Some(value);
+        self
+    }
+
+    pub fn get_shape_properties(&self) -> Option<&ShapeProperties> {
+        self.shape_properties.as_ref()
+    }
+
+    pub fn get_shape_properties_mut(&mut self) -> Option<&mut ShapeProperties> {
+        self.shape_properties.as_mut()
+    }
+
+    pub fn set_shape_properties(&mut self, value: ShapeProperties) -> &mut Self {
+        self.shape_properties = Some(value);
+        self
+    }
+
+    pub fn get_text_properties(&self) -> Option<&TextProperties> {
+        self.text_properties.as_ref()
+    }
+
+    pub fn get_text_properties_mut(&mut self) -> Option<&mut TextProperties> {
+        self.text_properties.as_mut()
+    }
+
+    pub fn set_text_properties(&mut self, value: TextProperties) -> &mut Self {
+        self.text_properties = Some(value);
+        self
+    }
+
+    pub(crate) fn set_attributes<R: std::io::BufRead>(
+        &mut self,
+        reader: &mut Reader<R>,
+        _e: &BytesStart,
+        empty_flg: bool,
+    ) -> Result<(), XlsxError> {
+        if empty_flg {
+            return Ok(());
+        }
+
+        xml_read_loop!(
+            reader,
+            Event::Start(ref e) => match e.name().0 {
+                b"c:spPr" => {
+                    let mut obj = ShapeProperties::default();
+                    obj.set_attributes(reader, e)?;
+                    self.set_shape_properties(obj);
+                }
+                b"c:txPr" => {
+                    let mut obj = TextProperties::default();
+                    obj.set_attributes(reader, e)?;
+                    self.set_text_properties(obj);
+                }
+                _ => (),
+            },
+            Event::Empty(ref e) => match e.name().0 {
+                b"c:showHorzBorder" => {
+                    let mut obj = ShowHorizontalBorder::default();
+                    obj.set_attributes(reader, e)?;
+                    self.set_show_horizontal_border(obj);
+                }
+                b"c:showVertBorder" => {
+                    let mut obj = ShowVerticalBorder::default();
+                    obj.set_attributes(reader, e)?;
+                    self.set_show_vertical_border(obj);
+                }
+                b"c:showOutline" => {
+                    let mut obj = ShowOutlineBorder::default();
+                    obj.set_attributes(reader, e)?;
+                    self.set_show_outline_border(obj);
+                }
+                b"c:showKeys" => {
+                    let mut obj = ShowKeys::default();
+                    obj.set_attributes(reader, e)?;
+                    self.set_show_keys(obj);
+                }
+                _ => (),
+            },
+            Event::End(ref e) => {
+                if e.name().0 == b"c:dTable" {
+                    return Ok(());
+                }
+            },
+            Event::Eof => return Err(end_element_error(reader, "c:dTable")),
+        );
+    }
+
+    fn with_include(&self) -> bool {
+        self.show_horizontal_border.is_some()
+            || self.show_vertical_border.is_some()
+            || self.show_outline_border.is_some()
+            || self.show_keys.is_some()
+            || self.shape_properties.is_some()
+            || self.text_properties.is_some()
+    }
+
+    pub(crate) fn write_to(&self, writer: &mut Writer<Cursor<Vec<u8>>>) {
+        if !self.with_include() {
+            // c:dTable
+            write_start_tag(writer, "c:dTable", vec![], true);
+            return;
+        }
+        // c:dTable
+        write_start_tag(writer, "c:dTable", vec![], false);
+
+        // c:showHorzBorder
+        if let Some(v) = &self.show_horizontal_border {
+            v.write_to(writer);
+        }
+
+        // c:showVertBorder
+        if let Some(v) = &self.show_vertical_border {
+            v.write_to(writer);
+        }
+
+        // c:showOutline
+        if let Some(v) = &self.show_outline_border {
+            v.write_to(writer);
+        }
+
+        // c:showKeys
+        if let Some(v) = &self.show_keys {
+            v.write_to(writer);
+        }
+
+        // c:spPr
+        if let Some(v) = &self.shape_properties {
+            v.write_to(writer);
+        }
+
+        // c:txPr
+        if let Some(v) = &self.text_properties {
+            v.write_to(writer);
+        }
+
+        write_end_tag(writer, "c:dTable");
+    }
+}
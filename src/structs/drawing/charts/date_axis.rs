@@ -0,0 +1,530 @@
+// c:dateAx
+use super::AutoLabeled;
+use super::AxisId;
+use super::AxisPosition;
+use super::BaseTimeUnit;
+use super::Crosses;
+use super::CrossingAxis;
+use super::Delete;
+use super::LabelOffset;
+use super::MajorGridlines;
+use super::MajorTickMark;
+use super::MajorTimeUnit;
+use super::MajorUnit;
+use super::MinorTickMark;
+use super::MinorTimeUnit;
+use super::MinorUnit;
+use super::NumberingFormat;
+use super::Scaling;
+use super::ShapeProperties;
+use super::TextProperties;
+use super::TickLabelPosition;
+use super::Title;
+use crate::reader::driver::*;
+use crate::writer::driver::*;
+use crate::xml_read_loop;
+use crate::XlsxError;
+use quick_xml::events::{BytesStart, Event};
+use quick_xml::Reader;
+use quick_xml::Writer;
+use std::io::Cursor;
+
+#[derive(Clone, Default, Debug)]
+pub struct DateAxis {
+    axis_id: AxisId,
+    scaling: Scaling,
+    delete: Delete,
+    axis_position: AxisPosition,
+    major_gridlines: Option<MajorGridlines>,
+    title: Option<Title>,
+    numbering_format: Option<NumberingFormat>,
+    major_tick_mark: MajorTickMark,
+    minor_tick_mark: MinorTickMark,
+    tick_label_position: TickLabelPosition,
+    shape_properties: Option<ShapeProperties>,
+    text_properties: Option<TextProperties>,
+    crossing_axis: CrossingAxis,
+    crosses: Crosses,
+    auto_labeled: AutoLabeled,
+    label_offset: LabelOffset,
+    base_time_unit: Option<BaseTimeUnit>,
+    major_unit: Option<MajorUnit>,
+    major_time_unit: Option<MajorTimeUnit>,
+    minor_unit: Option<MinorUnit>,
+    minor_time_unit: Option<MinorTimeUnit>,
+}
+
+impl DateAxis {
+    pub fn get_axis_id(&self) -> &AxisId {
+        &self.axis_id
+    }
+
+    pub fn get_axis_id_mut(&mut self) -> &mut AxisId {
+        &mut self.axis_id
+    }
+
+    pub fn set_axis_id(&mut self, value: AxisId) -> &mut Self {
+        self.axis_id = value;
+        self
+    }
+
+    pub fn get_scaling(&self) -> &Scaling {
+        &self.scaling
+    }
+
+    pub fn get_scaling_mut(&mut self) -> &mut Scaling {
+        &mut self.scaling
+    }
+
+    pub fn set_scaling(&mut self, value: Scaling) -> &mut Self {
+        self.scaling = value;
+        self
+    }
+
+    pub fn get_delete(&self) -> &Delete {
+        &self.delete
+    }
+
+    pub fn get_delete_mut(&mut self) -> &mut Delete {
+        &mut self.delete
+    }
+
+    pub fn set_delete(&mut self, value: Delete) -> &mut Self {
+        self.delete = value;
+        self
+    }
+
+    pub fn get_axis_position(&self) -> &AxisPosition {
+        &self.axis_position
+    }
+
+    pub fn get_axis_position_mut(&mut self) -> &mut AxisPosition {
+        &mut self.axis_position
+    }
+
+    pub fn set_axis_position(&mut self, value: AxisPosition) -> &mut Self {
+        self.axis_position = value;
+        self
+    }
+
+    pub fn get_major_gridlines(&self) -> Option<&MajorGridlines> {
+        self.major_gridlines.as_ref()
+    }
+
+    pub fn get_major_gridlines_mut(&mut self) -> Option<&mut MajorGridlines> {
+        self.major_gridlines.as_mut()
+    }
+
+    pub fn set_major_gridlines(&mut self, value: MajorGridlines) -> &mut Self {
+        self.major_gridlines = Some(value);
+        self
+    }
+
+    pub fn get_title(&self) -> Option<&Title> {
+        self.title.as_ref()
+    }
+
+    pub fn get_title_mut(&mut self) -> Option<&mut Title> {
+        self.title.as_mut()
+    }
+
+    pub fn set_title(&mut self, value: Title) -> &mut Self {
+        self.title = Some(value);
+        self
+    }
+
+    pub fn get_numbering_format(&self) -> Option<&NumberingFormat> {
+        self.numbering_format.as_ref()
+    }
+
+    pub fn get_numbering_format_mut(&mut self) -> Option<&mut NumberingFormat> {
+        self.numbering_format.as_mut()
+    }
+
+    pub fn set_numbering_format(&mut self, value: NumberingFormat) -> &mut Self {
+        self.numbering_format = Some(value);
+        self
+    }
+
+    pub fn get_major_tick_mark(&self) -> &MajorTickMark {
+        &self.major_tick_mark
+    }
+
+    pub fn get_major_tick_mark_mut(&mut self) -> &mut MajorTickMark {
+        &mut self.major_tick_mark
+    }
+
+    pub fn set_major_tick_mark(&mut self, value: MajorTickMark) -> &mut Self {
+        self.major_tick_mark = value;
+        self
+    }
+
+    pub fn get_minor_tick_mark(&self) -> &MinorTickMark {
+        &self.minor_tick_mark
+    }
+
+    pub fn get_minor_tick_mark_mut(&mut self) -> &mut MinorTickMark {
+        &mut self.minor_tick_mark
+    }
+
+    pub fn set_minor_tick_mark(&mut self, value: MinorTickMark) -> &mut Self {
+        self.minor_tick_mark = value;
+        self
+    }
+
+    pub fn get_tick_label_position(&self) -> &TickLabelPosition {
+        &self.tick_label_position
+    }
+
+    pub fn get_tick_label_position_mut(&mut self) -> &mut TickLabelPosition {
+        &mut self.tick_label_position
+    }
+
+    pub fn set_tick_label_position(&mut self, value: TickLabelPosition) -> &mut Self {
+        self.tick_label_position = value;
+        self
+    }
+
+    pub fn get_shape_properties(&self) -> Option<&ShapeProperties> {
+        self.shape_properties.as_ref()
+    }
+
+    pub fn get_shape_properties_mut(&mut self) -> Option<&mut ShapeProperties> {
+        self.shape_properties.as_mut()
+    }
+
+    pub fn set_shape_properties(&mut self, value: ShapeProperties) -> &mut Self {
+        self.shape_properties = Some(value);
+        self
+    }
+
+    pub fn get_text_properties(&self) -> Option<&TextProperties> {
+        self.text_properties.as_ref()
+    }
+
+    pub fn get_text_properties_mut(&mut self) -> Option<&mut TextProperties> {
+        self.text_properties.as_mut()
+    }
+
+    pub fn set_text_properties(&mut self, value: TextProperties) -> &mut Self {
+        self.text_properties = Some(value);
+        self
+    }
+
+    pub fn get_tick_crossing_axis(&self) -> &CrossingAxis {
+        &self.crossing_axis
+    }
+
+    pub fn get_tick_crossing_axis_mut(&mut self) -> &mut CrossingAxis {
+        &mut self.crossing_axis
+    }
+
+    pub fn set_tick_crossing_axis(&mut self, value: CrossingAxis) -> &mut Self {
+        self.crossing_axis = value;
+        self
+    }
+
+    pub fn get_crosses(&self) -> &Crosses {
+        &self.crosses
+    }
+
+    pub fn get_crosses_mut(&mut self) -> &mut Crosses {
+        &mut self.crosses
+    }
+
+    pub fn set_crosses(&mut self, value: Crosses) -> &mut Self {
+        self.crosses = value;
+        self
+    }
+
+    pub fn get_auto_labeled(&self) -> &AutoLabeled {
+        &self.auto_labeled
+    }
+
+    pub fn get_auto_labeled_mut(&mut self) -> &mut AutoLabeled {
+        &mut self.auto_labeled
+    }
+
+    pub fn set_auto_labeled(&mut self, value: AutoLabeled) -> &mut Self {
+        self.auto_labeled = value;
+        self
+    }
+
+    pub fn get_label_offset(&self) -> &LabelOffset {
+        &self.label_offset
+    }
+
+    pub fn get_label_offset_mut(&mut self) -> &mut LabelOffset {
+        &mut self.label_offset
+    }
+
+    pub fn set_label_offset(&mut self, value: LabelOffset) -> &mut Self {
+        self.label_offset = value;
+        self
+    }
+
+    pub fn get_base_time_unit(&self) -> Option<&BaseTimeUnit> {
+        self.base_time_unit.as_ref()
+    }
+
+    pub fn get_base_time_unit_mut(&mut self) -> Option<&mut BaseTimeUnit> {
+        self.base_time_unit.as_mut()
+    }
+
+    pub fn set_base_time_unit(&mut self, value: BaseTimeUnit) -> &mut Self {
+        self.base_time_unit = Some(value);
+        self
+    }
+
+    pub fn get_major_unit(&self) -> Option<&MajorUnit> {
+        self.major_unit.as_ref()
+    }
+
+    pub fn get_major_unit_mut(&mut self) -> Option<&mut MajorUnit> {
+        self.major_unit.as_mut()
+    }
+
+    pub fn set_major_unit(&mut self, value: MajorUnit) -> &mut Self {
+        self.major_unit = Some(value);
+        self
+    }
+
+    pub fn get_major_time_unit(&self) -> Option<&MajorTimeUnit> {
+        self.major_time_unit.as_ref()
+    }
+
+    pub fn get_major_time_unit_mut(&mut self) -> Option<&mut MajorTimeUnit> {
+        self.major_time_unit.as_mut()
+    }
+
+    pub fn set_major_time_unit(&mut self, value: MajorTimeUnit) -> &mut Self {
+        self.major_time_unit = Some(value);
+        self
+    }
+
+    pub fn get_minor_unit(&self) -> Option<&MinorUnit> {
+        self.minor_unit.as_ref()
+    }
+
+    pub fn get_minor_unit_mut(&mut self) -> Option<&mut MinorUnit> {
+        self.minor_unit.as_mut()
+    }
+
+    pub fn set_minor_unit(&mut self, value: MinorUnit) -> &mut Self {
+        self.minor_unit = Some(value);
+        self
+    }
+
+    pub fn get_minor_time_unit(&self) -> Option<&MinorTimeUnit> {
+        self.minor_time_unit.as_ref()
+    }
+
+    pub fn get_minor_time_unit_mut(&mut self) -> Option<&mut MinorTimeUnit> {
+        self.minor_time_unit.as_mut()
+    }
+
+    pub fn set_minor_time_unit(&mut self, value: MinorTimeUnit) -> &mut Self {
+        self.minor_time_unit = Some(value);
+        self
+    }
+
+    pub(crate) fn set_attributes<R: std::io::BufRead>(
+        &mut self,
+        reader: &mut Reader<R>,
+        _e: &BytesStart,
+    ) -> Result<(), XlsxError> {
+        xml_read_loop!(
+            reader,
+            Event::Start(ref e) => match e.name().0 {
+                b"c:scaling" => {
+                    self.scaling.set_attributes(reader, e)?;
+                }
+                b"c:title" => {
+                    let mut obj = Title::default();
+                    obj.set_attributes(reader, e)?;
+                    self.set_title(obj);
+                }
+                b"c:spPr" => {
+                    let mut obj = ShapeProperties::default();
+                    obj.set_attributes(reader, e)?;
+                    self.set_shape_properties(obj);
+                }
+                b"c:txPr" => {
+                    let mut obj = TextProperties::default();
+                    obj.set_attributes(reader, e)?;
+                    self.set_text_properties(obj);
+                }
+                b"c:majorGridlines" => {
+                    let mut obj = MajorGridlines::default();
+                    obj.set_attributes(reader, e, false)?;
+                    self.set_major_gridlines(obj);
+                }
+                _ => (),
+            },
+            Event::Empty(ref e) => match e.name().0 {
+                b"c:axId" => {
+                    self.axis_id.set_attributes(reader, e)?;
+                }
+                b"c:delete" => {
+                    self.delete.set_attributes(reader, e)?;
+                }
+                b"c:axPos" => {
+                    self.axis_position.set_attributes(reader, e)?;
+                }
+                b"c:majorGridlines" => {
+                    let mut obj = MajorGridlines::default();
+                    obj.set_attributes(reader, e, true)?;
+                    self.set_major_gridlines(obj);
+                }
+                b"c:numFmt" => {
+                    let mut obj = NumberingFormat::default();
+                    obj.set_attributes(reader, e)?;
+                    self.set_numbering_format(obj);
+                }
+                b"c:majorTickMark" => {
+                    self.major_tick_mark.set_attributes(reader, e)?;
+                }
+                b"c:minorTickMark" => {
+                    self.minor_tick_mark.set_attributes(reader, e)?;
+                }
+                b"c:tickLblPos" => {
+                    self.tick_label_position.set_attributes(reader, e)?;
+                }
+                b"c:crossAx" => {
+                    self.crossing_axis.set_attributes(reader, e)?;
+                }
+                b"c:crosses" => {
+                    self.crosses.set_attributes(reader, e)?;
+                }
+                b"c:auto" => {
+                    self.auto_labeled.set_attributes(reader, e)?;
+                }
+                b"c:lblOffset" => {
+                    self.label_offset.set_attributes(reader, e)?;
+                }
+                b"c:baseTimeUnit" => {
+                    let mut obj = BaseTimeUnit::default();
+                    obj.set_attributes(reader, e)?;
+                    self.set_base_time_unit(obj);
+                }
+                b"c:majorUnit" => {
+                    let mut obj = MajorUnit::default();
+                    obj.set_attributes(reader, e)?;
+                    self.set_major_unit(obj);
+                }
+                b"c:majorTimeUnit" => {
+                    let mut obj = MajorTimeUnit::default();
+                    obj.set_attributes(reader, e)?;
+                    self.set_major_time_unit(obj);
+                }
+                b"c:minorUnit" => {
+                    let mut obj = MinorUnit::default();
+                    obj.set_attributes(reader, e)?;
+                    self.set_minor_unit(obj);
+                }
+                b"c:minorTimeUnit" => {
+                    let mut obj = MinorTimeUnit::default();
+                    obj.set_attributes(reader, e)?;
+                    self.set_minor_time_unit(obj);
+                }
+                _ => (),
+            },
+            Event::End(ref e) => {
+                if e.name().0 == b"c:dateAx" {
+                    return Ok(());
+                }
+            },
+            Event::Eof => return Err(end_element_error(reader, "c:dateAx"))
+        );
+    }
+
+    pub(crate) fn write_to(&self, writer: &mut Writer<Cursor<Vec<u8>>>) {
+        // c:dateAx
+        write_start_tag(writer, "c:dateAx", vec![], false);
+
+        // c:axId
+        self.axis_id.write_to(writer);
+
+        // c:scaling
+        self.scaling.write_to(writer);
+
+        // c:delete
+        self.delete.write_to(writer);
+
+        // c:axPos
+        self.axis_position.write_to(writer);
+
+        // c:majorGridlines
+        if let Some(v) = &self.major_gridlines {
+            v.write_to(writer);
+        }
+
+        // c:title
+        if let Some(v) = &self.title {
+            v.write_to(writer);
+        }
+
+        // c:numFmt
+        if let Some(v) = &self.numbering_format {
+            v.write_to(writer);
+        }
+
+        // c:majorTickMark
+        self.major_tick_mark.write_to(writer);
+
+        // c:minorTickMark
+        self.minor_tick_mark.write_to(writer);
+
+        // c:tickLblPos
+        self.tick_label_position.write_to(writer);
+
+        // c:spPr
+        if let Some(v) = &self.shape_properties {
+            v.write_to(writer);
+        }
+
+        // c:txPr
+        if let Some(v) = &self.text_properties {
+            v.write_to(writer);
+        }
+
+        // c:crossAx
+        self.crossing_axis.write_to(writer);
+
+        // c:crosses
+        self.crosses.write_to(writer);
+
+        // c:auto
+        self.auto_labeled.write_to(writer);
+
+        // c:lblOffset
+        self.label_offset.write_to(writer);
+
+        // c:baseTimeUnit
+        if let Some(v) = &self.base_time_unit {
+            v.write_to(writer);
+        }
+
+        // c:majorUnit
+        if let Some(v) = &self.major_unit {
+            v.write_to(writer);
+        }
+
+        // c:majorTimeUnit
+        if let Some(v) = &self.major_time_unit {
+            v.write_to(writer);
+        }
+
+        // c:minorUnit
+        if let Some(v) = &self.minor_unit {
+            v.write_to(writer);
+        }
+
+        // c:minorTimeUnit
+        if let Some(v) = &self.minor_time_unit {
+            v.write_to(writer);
+        }
+
+        write_end_tag(writer, "c:dateAx");
+    }
+}
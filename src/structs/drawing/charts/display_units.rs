@@ -0,0 +1,135 @@
+// c:dispUnits
+use super::BuiltInUnit;
+use super::BuiltInUnitValues;
+use super::CustomDisplayUnit;
+use super::DisplayUnitsLabel;
+use crate::reader::driver::*;
+use crate::writer::driver::*;
+use crate::xml_read_loop;
+use crate::XlsxError;
+use quick_xml::events::{BytesStart, Event};
+use quick_xml::Reader;
+use quick_xml::Writer;
+use std::io::Cursor;
+
+#[derive(Clone, Default, Debug)]
+pub struct DisplayUnits {
+    custom_display_unit: Option<CustomDisplayUnit>,
+    built_in_unit: Option<BuiltInUnit>,
+    display_units_label: Option<DisplayUnitsLabel>,
+}
+
+impl DisplayUnits {
+    pub fn get_custom_display_unit(&self) -> Option<&CustomDisplayUnit> {
+        self.custom_display_unit.as_ref()
+    }
+
+    pub fn get_custom_display_unit_mut(&mut self) -> Option<&mut CustomDisplayUnit> {
+        self.custom_display_unit.as_mut()
+    }
+
+    /// Set a custom unit. This removes the built-in unit.
+    pub fn set_custom_display_unit(&mut self, value: CustomDisplayUnit) -> &mut Self {
+        self.custom_display_unit = Some(value);
+        self.built_in_unit = None;
+        self
+    }
+
+    pub fn get_built_in_unit(&self) -> Option<&BuiltInUnit> {
+        self.built_in_unit.as_ref()
+    }
+
+    pub fn get_built_in_unit_mut(&mut self) -> Option<&mut BuiltInUnit> {
+        self.built_in_unit.as_mut()
+    }
+
+    /// Set a built-in unit. This removes the custom unit.
+    pub fn set_built_in_unit(&mut self, value: BuiltInUnit) -> &mut Self {
+        self.built_in_unit = Some(value);
+        self.custom_display_unit = None;
+        self
+    }
+
+    pub fn get_display_units_label(&self) -> Option<&DisplayUnitsLabel> {
+        self.display_units_label.as_ref()
+    }
+
+    pub fn get_display_units_label_mut(&mut self) -> Option<&mut DisplayUnitsLabel> {
+        self.display_units_label.as_mut()
+    }
+
+    pub fn set_display_units_label(&mut self, value: DisplayUnitsLabel) -> &mut Self {
+        self.display_units_label = Some(value);
+        self
+    }
+
+    pub(crate) fn set_attributes<R: std::io::BufRead>(
+        &mut self,
+        reader: &mut Reader<R>,
+        _e: &BytesStart,
+    ) -> Result<(), XlsxError> {
+        xml_read_loop!(
+            reader,
+            Event::Start(ref e) => {
+                if e.name().0 == b"c:dispUnitsLbl" {
+                    let mut obj = DisplayUnitsLabel::default();
+                    obj.set_attributes(reader, e, false)?;
+                    self.set_display_units_label(obj);
+                }
+            },
+            Event::Empty(ref e) => match e.name().0 {
+                b"c:custUnit" => {
+                    let mut obj = CustomDisplayUnit::default();
+                    obj.set_attributes(reader, e)?;
+                    self.set_custom_display_unit(obj);
+                }
+                b"c:builtInUnit" => {
+                    let mut obj = BuiltInUnit::default();
+                    obj.set_attributes(reader, e)?;
+                    self.set_built_in_unit(obj);
+                }
+                b"c:dispUnitsLbl" => {
+                    let mut obj = DisplayUnitsLabel::default();
+                    obj.set_attributes(reader, e, true)?;
+                    self.set_display_units_label(obj);
+                }
+                _ => (),
+            },
+            Event::End(ref e) => {
+                if e.name().0 == b"c:dispUnits" {
+                    return Ok(());
+                }
+            },
+            Event::Eof => return Err(end_element_error(reader, "c:dispUnits")),
+        );
+    }
+
+    pub(crate) fn write_to(&self, writer: &mut Writer<Cursor<Vec<u8>>>) {
+        // c:dispUnits
+        write_start_tag(writer, "c:dispUnits", vec![], false);
+
+        // c:custUnit
+        if let Some(v) = &self.custom_display_unit {
+            v.write_to(writer);
+        }
+
+        // c:builtInUnit
+        if let Some(v) = &self.built_in_unit {
+            v.write_to(writer);
+        }
+
+        // c:builtInUnit is required when no unit is set
+        if self.custom_display_unit.is_none() && self.built_in_unit.is_none() {
+            let mut obj = BuiltInUnit::default();
+            obj.set_val(BuiltInUnitValues::Thousands);
+            obj.write_to(writer);
+        }
+
+        // c:dispUnitsLbl
+        if let Some(v) = &self.display_units_label {
+            v.write_to(writer);
+        }
+
+        write_end_tag(writer, "c:dispUnits");
+    }
+}
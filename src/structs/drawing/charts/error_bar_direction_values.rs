@@ -0,0 +1,30 @@
+use super::super::super::EnumTrait;
+use std::str::FromStr;
+#[derive(Clone, Debug)]
+pub enum ErrorBarDirectionValues {
+    X,
+    Y,
+}
+impl Default for ErrorBarDirectionValues {
+    fn default() -> Self {
+        Self::Y
+    }
+}
+impl EnumTrait for ErrorBarDirectionValues {
+    fn get_value_string(&self) -> &str {
+        match &self {
+            Self::X => "x",
+            Self::Y => "y",
+        }
+    }
+}
+impl FromStr for ErrorBarDirectionValues {
+    type Err = ();
+    fn from_str(input: &str) -> Result<Self, Self::Err> {
+        match input {
+            "x" => Ok(Self::X),
+            "y" => Ok(Self::Y),
+            _ => Err(()),
+        }
+    }
+}
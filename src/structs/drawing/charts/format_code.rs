@@ -1,5 +1,7 @@
 // c:formatCode
+use crate::reader::driver::*;
 use crate::writer::driver::*;
+use crate::XlsxError;
 use quick_xml::events::{BytesStart, Event};
 use quick_xml::Reader;
 use quick_xml::Writer;
@@ -26,18 +28,18 @@ impl FormatCode {
         &mut self,
         reader: &mut Reader<R>,
         _e: &BytesStart,
-    ) {
+    ) -> Result<(), XlsxError> {
         xml_read_loop!(
             reader,
             Event::Text(e) => {
-                self.set_text(e.unescape().unwrap());
+                self.set_text(e.unescape()?);
             },
             Event::End(ref e) => {
                 if e.name().0 == b"c:formatCode" {
-                    return;
+                    return Ok(());
                 }
             },
-            Event::Eof => panic!("Error: Could not find {} end element", "c:formatCode"),
+            Event::Eof => return Err(end_element_error(reader, "c:formatCode")),
         );
     }
 
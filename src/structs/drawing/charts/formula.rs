@@ -1,4 +1,6 @@
+use crate::reader::driver::*;
 use crate::xml_read_loop;
+use crate::XlsxError;
 
 // c:f
 use super::super::super::Address;
@@ -59,18 +61,18 @@ impl Formula {
         &mut self,
         reader: &mut Reader<R>,
         _e: &BytesStart,
-    ) {
+    ) -> Result<(), XlsxError> {
         xml_read_loop!(
             reader,
             Event::Text(e) => {
-                self.set_address_str(e.unescape().unwrap());
+                self.set_address_str(e.unescape()?);
             },
             Event::End(ref e) => {
                if  e.name().0 == b"c:f" {
-                   return;
+                   return Ok(());
                }
             },
-            Event::Eof => panic!("Error: Could not find {} end element", "c:f"),
+            Event::Eof => return Err(end_element_error(reader, "c:f")),
         );
     }
 
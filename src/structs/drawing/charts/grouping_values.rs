@@ -2,6 +2,7 @@ use super::super::super::EnumTrait;
 use std::str::FromStr;
 #[derive(Clone, Debug)]
 pub enum GroupingValues {
+    Clustered,
     PercentStacked,
     Stacked,
     Standard,
@@ -14,6 +15,7 @@ impl Default for GroupingValues {
 impl EnumTrait for GroupingValues {
     fn get_value_string(&self) -> &str {
         match &self {
+            Self::Clustered => "clustered",
             Self::PercentStacked => "percentStacked",
             Self::Stacked => "stacked",
             Self::Standard => "standard",
@@ -24,6 +26,7 @@ impl FromStr for GroupingValues {
     type Err = ();
     fn from_str(input: &str) -> Result<Self, Self::Err> {
         match input {
+            "clustered" => Ok(Self::Clustered),
             "percentStacked" => Ok(Self::PercentStacked),
             "stacked" => Ok(Self::Stacked),
             "standard" => Ok(Self::Standard),
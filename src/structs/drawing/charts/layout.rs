@@ -1,7 +1,9 @@
 // c:layout
 use super::ManualLayout;
+use crate::reader::driver::*;
 use crate::writer::driver::*;
 use crate::xml_read_loop;
+use crate::XlsxError;
 use quick_xml::events::{BytesStart, Event};
 use quick_xml::Reader;
 use quick_xml::Writer;
@@ -35,9 +37,9 @@ impl Layout {
         reader: &mut Reader<R>,
         _e: &BytesStart,
         empty_flag: bool,
-    ) {
+    ) -> Result<(), XlsxError> {
         if empty_flag {
-            return;
+            return Ok(());
         }
 
         xml_read_loop!(
@@ -45,16 +47,16 @@ impl Layout {
             Event::Start(ref e) => {
                 if e.name().into_inner() == b"c:manualLayout" {
                     let mut obj = ManualLayout::default();
-                    obj.set_attributes(reader, e);
+                    obj.set_attributes(reader, e)?;
                     self.set_manual_layout(obj);
                 }
             },
             Event::End(ref e) => {
                 if e.name().into_inner() == b"c:layout" {
-                    return;
+                    return Ok(());
                 }
             },
-            Event::Eof => panic!("Error: Could not find {} end element", "c:layout"),
+            Event::Eof => return Err(end_element_error(reader, "c:layout")),
         );
     }
 
@@ -3,11 +3,13 @@ use super::AreaChartSeries;
 use super::AreaChartSeriesList;
 use super::AxisId;
 use super::DataLabels;
+use super::DropLines;
 use super::Grouping;
 use super::VaryColors;
 use crate::reader::driver::*;
 use crate::structs::Spreadsheet;
 use crate::writer::driver::*;
+use crate::XlsxError;
 use quick_xml::events::{BytesStart, Event};
 use quick_xml::Reader;
 use quick_xml::Writer;
@@ -20,6 +22,7 @@ pub struct Line3DChart {
     vary_colors: VaryColors,
     area_chart_series_list: AreaChartSeriesList,
     data_labels: DataLabels,
+    drop_lines: Option<DropLines>,
     axis_id: ThinVec<AxisId>,
 }
 
@@ -76,6 +79,19 @@ impl Line3DChart {
         self
     }
 
+    pub fn get_drop_lines(&self) -> Option<&DropLines> {
+        self.drop_lines.as_ref()
+    }
+
+    pub fn get_drop_lines_mut(&mut self) -> Option<&mut DropLines> {
+        self.drop_lines.as_mut()
+    }
+
+    pub fn set_drop_lines(&mut self, value: DropLines) -> &mut Self {
+        self.drop_lines = Some(value);
+        self
+    }
+
     pub fn get_axis_id(&self) -> &[AxisId] {
         &self.axis_id
     }
@@ -98,19 +114,24 @@ impl Line3DChart {
         &mut self,
         reader: &mut Reader<R>,
         _e: &BytesStart,
-    ) {
+    ) -> Result<(), XlsxError> {
         xml_read_loop!(
             reader,
             Event::Start(ref e) => {
                 match e.name().into_inner() {
                     b"c:ser" => {
                         let mut obj = AreaChartSeries::default();
-                        obj.set_attributes(reader, e);
+                        obj.set_attributes(reader, e)?;
                         self.get_area_chart_series_list_mut()
                             .add_area_chart_series(obj);
                         }
                     b"c:dLbls" => {
-                        self.data_labels.set_attributes(reader, e);
+                        self.data_labels.set_attributes(reader, e)?;
+                    }
+                    b"c:dropLines" => {
+                        let mut obj = DropLines::default();
+                        obj.set_attributes(reader, e, false)?;
+                        self.set_drop_lines(obj);
                     }
                     _ => (),
                 }
@@ -118,14 +139,19 @@ impl Line3DChart {
             Event::Empty(ref e) => {
                 match e.name().into_inner() {
                     b"c:grouping" => {
-                        self.grouping.set_attributes(reader, e);
+                        self.grouping.set_attributes(reader, e)?;
                     }
                     b"c:varyColors" => {
-                        self.vary_colors.set_attributes(reader, e);
+                        self.vary_colors.set_attributes(reader, e)?;
+                    }
+                    b"c:dropLines" => {
+                        let mut obj = DropLines::default();
+                        obj.set_attributes(reader, e, true)?;
+                        self.set_drop_lines(obj);
                     }
                     b"c:axId" => {
                         let mut obj = AxisId::default();
-                        obj.set_attributes(reader, e);
+                        obj.set_attributes(reader, e)?;
                         self.add_axis_id(obj);
                     }
                     _ => (),
@@ -133,10 +159,10 @@ impl Line3DChart {
             },
             Event::End(ref e) => {
                 if e.name().into_inner() == b"c:line3DChart" {
-                    return;
+                    return Ok(());
                 }
             },
-            Event::Eof => panic!("Error: Could not find {} end element", "c:line3DChart")
+            Event::Eof => return Err(end_element_error(reader, "c:line3DChart"))
         );
     }
 
@@ -158,6 +184,11 @@ impl Line3DChart {
         // c:dLbls
         self.data_labels.write_to(writer);
 
+        // c:dropLines
+        if let Some(v) = &self.drop_lines {
+            v.write_to(writer);
+        }
+
         // c:axId
         for v in &self.axis_id {
             v.write_to(writer);
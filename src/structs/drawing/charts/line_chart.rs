@@ -1,13 +1,18 @@
+use crate::reader::driver::*;
 use crate::xml_read_loop;
+use crate::XlsxError;
 
 // lineChart
 use super::AreaChartSeries;
 use super::AreaChartSeriesList;
 use super::AxisId;
 use super::DataLabels;
+use super::DropLines;
 use super::Grouping;
+use super::HighLowLines;
 use super::ShowMarker;
 use super::Smooth;
+use super::UpDownBars;
 use super::VaryColors;
 use crate::structs::Spreadsheet;
 use crate::writer::driver::*;
@@ -23,6 +28,9 @@ pub struct LineChart {
     vary_colors: VaryColors,
     area_chart_series_list: AreaChartSeriesList,
     data_labels: DataLabels,
+    drop_lines: Option<DropLines>,
+    high_low_lines: Option<HighLowLines>,
+    up_down_bars: Option<UpDownBars>,
     show_marker: ShowMarker,
     smooth: Smooth,
     axis_id: ThinVec<AxisId>,
@@ -81,6 +89,45 @@ impl LineChart {
         self
     }
 
+    pub fn get_drop_lines(&self) -> Option<&DropLines> {
+        self.drop_lines.as_ref()
+    }
+
+    pub fn get_drop_lines_mut(&mut self) -> Option<&mut DropLines> {
+        self.drop_lines.as_mut()
+    }
+
+    pub fn set_drop_lines(&mut self, value: DropLines) -> &mut Self {
+        self.drop_lines = Some(value);
+        self
+    }
+
+    pub fn get_high_low_lines(&self) -> Option<&HighLowLines> {
+        self.high_low_lines.as_ref()
+    }
+
+    pub fn get_high_low_lines_mut(&mut self) -> Option<&mut HighLowLines> {
+        self.high_low_lines.as_mut()
+    }
+
+    pub fn set_high_low_lines(&mut self, value: HighLowLines) -> &mut Self {
+        self.high_low_lines = Some(value);
+        self
+    }
+
+    pub fn get_up_down_bars(&self) -> Option<&UpDownBars> {
+        self.up_down_bars.as_ref()
+    }
+
+    pub fn get_up_down_bars_mut(&mut self) -> Option<&mut UpDownBars> {
+        self.up_down_bars.as_mut()
+    }
+
+    pub fn set_up_down_bars(&mut self, value: UpDownBars) -> &mut Self {
+        self.up_down_bars = Some(value);
+        self
+    }
+
     pub fn get_show_marker(&self) -> &ShowMarker {
         &self.show_marker
     }
@@ -129,47 +176,77 @@ impl LineChart {
         &mut self,
         reader: &mut Reader<R>,
         _e: &BytesStart,
-    ) {
+    ) -> Result<(), XlsxError> {
         xml_read_loop!(
             reader,
             Event::Start(ref e) => match e.name().into_inner() {
                 b"c:ser" => {
                     let mut obj = AreaChartSeries::default();
-                    obj.set_attributes(reader, e);
+                    obj.set_attributes(reader, e)?;
                     self.get_area_chart_series_list_mut()
                         .add_area_chart_series(obj);
                 }
                 b"c:dLbls" => {
-                    self.data_labels.set_attributes(reader, e);
+                    self.data_labels.set_attributes(reader, e)?;
+                }
+                b"c:dropLines" => {
+                    let mut obj = DropLines::default();
+                    obj.set_attributes(reader, e, false)?;
+                    self.set_drop_lines(obj);
+                }
+                b"c:hiLowLines" => {
+                    let mut obj = HighLowLines::default();
+                    obj.set_attributes(reader, e, false)?;
+                    self.set_high_low_lines(obj);
+                }
+                b"c:upDownBars" => {
+                    let mut obj = UpDownBars::default();
+                    obj.set_attributes(reader, e, false)?;
+                    self.set_up_down_bars(obj);
                 }
                 _ => (),
             },
             Event::Empty(ref e) => match e.name().into_inner() {
                 b"c:grouping" => {
-                    self.grouping.set_attributes(reader, e);
+                    self.grouping.set_attributes(reader, e)?;
                 }
                 b"c:varyColors" => {
-                    self.vary_colors.set_attributes(reader, e);
+                    self.vary_colors.set_attributes(reader, e)?;
                 }
                 b"c:marker" => {
-                    self.show_marker.set_attributes(reader, e);
+                    self.show_marker.set_attributes(reader, e)?;
                 }
                 b"c:smooth" => {
-                    self.smooth.set_attributes(reader, e);
+                    self.smooth.set_attributes(reader, e)?;
+                }
+                b"c:dropLines" => {
+                    let mut obj = DropLines::default();
+                    obj.set_attributes(reader, e, true)?;
+                    self.set_drop_lines(obj);
+                }
+                b"c:hiLowLines" => {
+                    let mut obj = HighLowLines::default();
+                    obj.set_attributes(reader, e, true)?;
+                    self.set_high_low_lines(obj);
+                }
+                b"c:upDownBars" => {
+                    let mut obj = UpDownBars::default();
+                    obj.set_attributes(reader, e, true)?;
+                    self.set_up_down_bars(obj);
                 }
                 b"c:axId" => {
                     let mut obj = AxisId::default();
-                    obj.set_attributes(reader, e);
+                    obj.set_attributes(reader, e)?;
                     self.add_axis_id(obj);
                 }
                 _ => (),
             },
             Event::End(ref e) => {
                 if e.name().into_inner() == b"c:lineChart" {
-                    return;
+                    return Ok(());
                 }
             },
-            Event::Eof => panic!("Error: Could not find {} end element", "c:lineChart"),
+            Event::Eof => return Err(end_element_error(reader, "c:lineChart")),
         );
     }
 
@@ -191,6 +268,21 @@ impl LineChart {
         // c:dLbls
         self.data_labels.write_to(writer);
 
+        // c:dropLines
+        if let Some(v) = &self.drop_lines {
+            v.write_to(writer);
+        }
+
+        // c:hiLowLines
+        if let Some(v) = &self.high_low_lines {
+            v.write_to(writer);
+        }
+
+        // c:upDownBars
+        if let Some(v) = &self.up_down_bars {
+            v.write_to(writer);
+        }
+
         // c:marker
         self.show_marker.write_to(writer);
 
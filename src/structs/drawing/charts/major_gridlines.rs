@@ -2,6 +2,7 @@
 use super::ShapeProperties;
 use crate::reader::driver::*;
 use crate::writer::driver::*;
+use crate::XlsxError;
 use quick_xml::events::{BytesStart, Event};
 use quick_xml::Reader;
 use quick_xml::Writer;
@@ -31,9 +32,9 @@ impl MajorGridlines {
         reader: &mut Reader<R>,
         _e: &BytesStart,
         empty_flg: bool,
-    ) {
+    ) -> Result<(), XlsxError> {
         if empty_flg {
-            return;
+            return Ok(());
         }
 
         xml_read_loop!(
@@ -41,16 +42,16 @@ impl MajorGridlines {
             Event::Start(ref e) => {
                 if e.name().into_inner() == b"c:spPr" {
                     let mut obj = ShapeProperties::default();
-                    obj.set_attributes(reader, e);
+                    obj.set_attributes(reader, e)?;
                     self.set_shape_properties(obj);
                 }
             },
             Event::End(ref e) => {
                 if e.name().into_inner() == b"c:majorGridlines" {
-                    return
+                    return Ok(())
                 }
             },
-            Event::Eof => panic!("Error: Could not find {} end element", "c:majorGridlines")
+            Event::Eof => return Err(end_element_error(reader, "c:majorGridlines"))
         );
     }
 
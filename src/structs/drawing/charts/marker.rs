@@ -2,6 +2,7 @@
 use super::Symbol;
 use crate::reader::driver::*;
 use crate::writer::driver::*;
+use crate::XlsxError;
 use quick_xml::events::{BytesStart, Event};
 use quick_xml::Reader;
 use quick_xml::Writer;
@@ -31,9 +32,9 @@ impl Marker {
         reader: &mut Reader<R>,
         _: &BytesStart,
         empty_flag: bool,
-    ) {
+    ) -> Result<(), XlsxError> {
         if empty_flag {
-            return;
+            return Ok(());
         }
 
         xml_read_loop!(
@@ -41,16 +42,16 @@ impl Marker {
             Event::Empty(ref e) => {
                 if e.name().0 == b"c:symbol" {
                     let mut obj = Symbol::default();
-                    obj.set_attributes(reader, e);
+                    obj.set_attributes(reader, e)?;
                     self.set_symbol(obj);
                 }
             },
             Event::End(ref e) => {
                 if e.name().0 == b"c:marker" {
-                    return;
+                    return Ok(());
                 }
             },
-            Event::Eof => panic!("Error: Could not find {} end element", "c:marker")
+            Event::Eof => return Err(end_element_error(reader, "c:marker"))
         );
     }
 
@@ -1,4 +1,6 @@
+use crate::reader::driver::*;
 use crate::xml_read_loop;
+use crate::XlsxError;
 
 // c:numRef
 use super::Formula;
@@ -47,24 +49,24 @@ impl NumberReference {
         &mut self,
         reader: &mut Reader<R>,
         _e: &BytesStart,
-    ) {
+    ) -> Result<(), XlsxError> {
         xml_read_loop!(
             reader,
             Event::Start(ref e) => match e.name().0 {
                 b"c:f" => {
-                    self.formula.set_attributes(reader, e);
+                    self.formula.set_attributes(reader, e)?;
                 }
                 b"c:numCache" => {
-                    self.numbering_cache.set_attributes(reader, e);
+                    self.numbering_cache.set_attributes(reader, e)?;
                 }
                 _ => (),
             },
             Event::End(ref e) => {
                 if e.name().0 == b"c:numRef" {
-                    return;
+                    return Ok(());
                 }
             },
-            Event::Eof => panic!("Error: Could not find {} end element", "c:numRef"),
+            Event::Eof => return Err(end_element_error(reader, "c:numRef")),
         );
     }
 
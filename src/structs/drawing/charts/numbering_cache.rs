@@ -1,17 +1,22 @@
 // c:numCache
+use super::string_point::*;
 use super::FormatCode;
+use crate::reader::driver::*;
 use crate::structs::Address;
 use crate::structs::Spreadsheet;
 use crate::writer::driver::*;
 use crate::xml_read_loop;
+use crate::XlsxError;
 use quick_xml::events::{BytesStart, Event};
 use quick_xml::Reader;
 use quick_xml::Writer;
 use std::io::Cursor;
+use thin_vec::ThinVec;
 
 #[derive(Clone, Default, Debug)]
 pub struct NumberingCache {
     format_code: FormatCode,
+    point_list: ThinVec<StringPoint>,
 }
 
 impl NumberingCache {
@@ -28,24 +33,48 @@ impl NumberingCache {
         self
     }
 
+    /// Cached values as read from the file, indexed by point.
+    /// Missing points are kept as empty values.
+    pub fn get_point_list(&self) -> &[StringPoint] {
+        &self.point_list
+    }
+
+    pub fn get_point_list_mut(&mut self) -> &mut ThinVec<StringPoint> {
+        &mut self.point_list
+    }
+
     pub(crate) fn set_attributes<R: std::io::BufRead>(
         &mut self,
         reader: &mut Reader<R>,
         _e: &BytesStart,
-    ) {
+    ) -> Result<(), XlsxError> {
         xml_read_loop!(
             reader,
             Event::Start(ref e) => {
-                if e.name().0 == b"c:formatCode" {
-                    self.format_code.set_attributes(reader, e);
+                match e.name().0 {
+                    b"c:formatCode" => {
+                        self.format_code.set_attributes(reader, e)?;
+                    }
+                    b"c:pt" => {
+                        let index = get_point_index(e)?;
+                        let mut obj = StringPoint::default();
+                        obj.set_attributes(reader, e)?;
+                        set_point(&mut self.point_list, index, obj);
+                    }
+                    _ => (),
+                }
+            },
+            Event::Empty(ref e) => {
+                if e.name().0 == b"c:ptCount" {
+                    resize_point_list(&mut self.point_list, e)?;
                 }
             },
             Event::End(ref e) => {
                if e.name().0 == b"c:numCache" {
-                   return;
+                   return Ok(());
                }
             },
-            Event::Eof => panic!("Error: Could not find {} end element", "c:numCache"),
+            Event::Eof => return Err(end_element_error(reader, "c:numCache")),
         );
     }
 
@@ -3,6 +3,7 @@ use super::super::super::BooleanValue;
 use super::super::super::StringValue;
 use crate::reader::driver::*;
 use crate::writer::driver::*;
+use crate::XlsxError;
 use quick_xml::events::BytesStart;
 use quick_xml::Reader;
 use quick_xml::Writer;
@@ -36,11 +37,12 @@ impl NumberingFormat {
         &mut self,
         _reader: &mut Reader<R>,
         e: &BytesStart,
-    ) {
+    ) -> Result<(), XlsxError> {
         self.format_code
-            .set_value_string(get_attribute(e, b"formatCode").unwrap());
+            .try_set_value_string(get_required_attribute(e, b"formatCode")?, "formatCode")?;
         self.source_linked
-            .set_value_string(get_attribute(e, b"sourceLinked").unwrap());
+            .try_set_value_string(get_required_attribute(e, b"sourceLinked")?, "sourceLinked")?;
+        Ok(())
     }
 
     pub(crate) fn write_to(&self, writer: &mut Writer<Cursor<Vec<u8>>>) {
@@ -1,6 +1,7 @@
 // c:v
+use crate::reader::driver::*;
 use crate::writer::driver::*;
-use crate::xml_read_loop;
+use crate::XlsxError;
 use quick_xml::events::{BytesStart, Event};
 use quick_xml::Reader;
 use quick_xml::Writer;
@@ -25,18 +26,18 @@ impl NumericValue {
         &mut self,
         reader: &mut Reader<R>,
         _e: &BytesStart,
-    ) {
+    ) -> Result<(), XlsxError> {
         xml_read_loop!(
             reader,
             Event::Text(e) => {
-                self.set_text(e.unescape().unwrap());
+                self.set_text(e.unescape()?);
             },
             Event::End(ref e) => {
                 if e.name().0 == b"c:v" {
-                    return;
+                    return Ok(());
                 }
             },
-            Event::Eof => panic!("Error: Could not find {} end element", "c:v"),
+            Event::Eof => return Err(end_element_error(reader, "c:v")),
         );
     }
 
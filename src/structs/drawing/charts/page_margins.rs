@@ -2,6 +2,7 @@
 use super::super::super::DoubleValue;
 use crate::reader::driver::*;
 use crate::writer::driver::*;
+use crate::XlsxError;
 use quick_xml::events::BytesStart;
 use quick_xml::Reader;
 use quick_xml::Writer;
@@ -75,16 +76,20 @@ impl PageMargins {
         &mut self,
         _reader: &mut Reader<R>,
         e: &BytesStart,
-    ) {
+    ) -> Result<(), XlsxError> {
         self.bottom
-            .set_value_string(get_attribute(e, b"b").unwrap());
-        self.left.set_value_string(get_attribute(e, b"l").unwrap());
-        self.right.set_value_string(get_attribute(e, b"r").unwrap());
-        self.top.set_value_string(get_attribute(e, b"t").unwrap());
+            .try_set_value_string(get_required_attribute(e, b"b")?, "b")?;
+        self.left
+            .try_set_value_string(get_required_attribute(e, b"l")?, "l")?;
+        self.right
+            .try_set_value_string(get_required_attribute(e, b"r")?, "r")?;
+        self.top
+            .try_set_value_string(get_required_attribute(e, b"t")?, "t")?;
         self.header
-            .set_value_string(get_attribute(e, b"header").unwrap());
+            .try_set_value_string(get_required_attribute(e, b"header")?, "header")?;
         self.footer
-            .set_value_string(get_attribute(e, b"footer").unwrap());
+            .try_set_value_string(get_required_attribute(e, b"footer")?, "footer")?;
+        Ok(())
     }
 
     pub(crate) fn write_to(&self, writer: &mut Writer<Cursor<Vec<u8>>>) {
@@ -1,11 +1,15 @@
 // c:plotArea
 use super::Area3DChart;
 use super::AreaChart;
+use super::AreaChartSeries;
 use super::AreaChartSeriesList;
+use super::AxisId;
 use super::Bar3DChart;
 use super::BarChart;
 use super::BubbleChart;
 use super::CategoryAxis;
+use super::DataTable;
+use super::DateAxis;
 use super::DoughnutChart;
 use super::Formula;
 use super::GroupingValues;
@@ -19,37 +23,68 @@ use super::RadarChart;
 use super::ScatterChart;
 use super::SeriesAxis;
 use super::ShapeProperties;
+use super::StockChart;
+use super::Surface3DChart;
+use super::SurfaceChart;
 use super::ValueAxis;
+use crate::reader::driver::*;
 use crate::structs::Spreadsheet;
 use crate::traits::AdjustmentCoordinateWithSheet;
 use crate::writer::driver::*;
 use crate::xml_read_loop;
+use crate::XlsxError;
 use quick_xml::events::{BytesStart, Event};
 use quick_xml::Reader;
 use quick_xml::Writer;
+use std::collections::HashMap;
 use std::io::Cursor;
-use thin_vec::ThinVec;
+use thin_vec::{thin_vec, ThinVec};
+
+const CHART_GROUP_NAMES: [&str; 16] = [
+    "c:areaChart",
+    "c:area3DChart",
+    "c:barChart",
+    "c:bar3DChart",
+    "c:lineChart",
+    "c:line3DChart",
+    "c:pieChart",
+    "c:pie3DChart",
+    "c:doughnutChart",
+    "c:ofPieChart",
+    "c:radarChart",
+    "c:stockChart",
+    "c:surfaceChart",
+    "c:surface3DChart",
+    "c:scatterChart",
+    "c:bubbleChart",
+];
 
 #[derive(Clone, Default, Debug)]
 pub struct PlotArea {
     layout: Layout,
-    line_chart: Option<LineChart>,
-    line_3d_chart: Option<Line3DChart>,
-    pie_chart: Option<PieChart>,
-    pie_3d_chart: Option<Pie3DChart>,
-    doughnut_chart: Option<DoughnutChart>,
-    scatter_chart: Option<ScatterChart>,
-    bar_chart: Option<BarChart>,
-    bar_3d_chart: Option<Bar3DChart>,
-    radar_chart: Option<RadarChart>,
-    bubble_chart: Option<BubbleChart>,
-    area_chart: Option<AreaChart>,
-    area_3d_chart: Option<Area3DChart>,
-    of_pie_chart: Option<OfPieChart>,
+    line_chart: ThinVec<LineChart>,
+    line_3d_chart: ThinVec<Line3DChart>,
+    pie_chart: ThinVec<PieChart>,
+    pie_3d_chart: ThinVec<Pie3DChart>,
+    doughnut_chart: ThinVec<DoughnutChart>,
+    scatter_chart: ThinVec<ScatterChart>,
+    bar_chart: ThinVec<BarChart>,
+    bar_3d_chart: ThinVec<Bar3DChart>,
+    radar_chart: ThinVec<RadarChart>,
+    stock_chart: ThinVec<StockChart>,
+    surface_chart: ThinVec<SurfaceChart>,
+    surface_3d_chart: ThinVec<Surface3DChart>,
+    bubble_chart: ThinVec<BubbleChart>,
+    area_chart: ThinVec<AreaChart>,
+    area_3d_chart: ThinVec<Area3DChart>,
+    of_pie_chart: ThinVec<OfPieChart>,
     category_axis: ThinVec<CategoryAxis>,
+    date_axis: ThinVec<DateAxis>,
     value_axis: ThinVec<ValueAxis>,
     series_axis: ThinVec<SeriesAxis>,
+    data_table: Option<DataTable>,
     shape_properties: Option<ShapeProperties>,
+    chart_order: ThinVec<&'static str>,
 }
 
 impl PlotArea {
@@ -67,171 +102,466 @@ impl PlotArea {
     }
 
     pub fn get_line_chart(&self) -> Option<&LineChart> {
-        self.line_chart.as_ref()
+        self.line_chart.first()
     }
 
     pub fn get_line_chart_mut(&mut self) -> Option<&mut LineChart> {
-        self.line_chart.as_mut()
+        self.line_chart.first_mut()
     }
 
     pub fn set_line_chart(&mut self, value: LineChart) -> &mut Self {
-        self.line_chart = Some(value);
+        self.line_chart = thin_vec![value];
+        self.chart_order.retain(|v| *v != "c:lineChart");
+        self.chart_order.push("c:lineChart");
+        self
+    }
+
+    pub fn get_line_chart_collection(&self) -> &[LineChart] {
+        &self.line_chart
+    }
+
+    pub fn get_line_chart_collection_mut(&mut self) -> &mut ThinVec<LineChart> {
+        &mut self.line_chart
+    }
+
+    pub fn add_line_chart(&mut self, value: LineChart) -> &mut Self {
+        self.line_chart.push(value);
+        self.chart_order.push("c:lineChart");
         self
     }
 
     pub fn get_line_3d_chart(&self) -> Option<&Line3DChart> {
-        self.line_3d_chart.as_ref()
+        self.line_3d_chart.first()
     }
 
     pub fn get_line_3d_chart_mut(&mut self) -> Option<&mut Line3DChart> {
-        self.line_3d_chart.as_mut()
+        self.line_3d_chart.first_mut()
     }
 
     pub fn set_line_3d_chart(&mut self, value: Line3DChart) -> &mut Self {
-        self.line_3d_chart = Some(value);
+        self.line_3d_chart = thin_vec![value];
+        self.chart_order.retain(|v| *v != "c:line3DChart");
+        self.chart_order.push("c:line3DChart");
+        self
+    }
+
+    pub fn get_line_3d_chart_collection(&self) -> &[Line3DChart] {
+        &self.line_3d_chart
+    }
+
+    pub fn get_line_3d_chart_collection_mut(&mut self) -> &mut ThinVec<Line3DChart> {
+        &mut self.line_3d_chart
+    }
+
+    pub fn add_line_3d_chart(&mut self, value: Line3DChart) -> &mut Self {
+        self.line_3d_chart.push(value);
+        self.chart_order.push("c:line3DChart");
         self
     }
 
     pub fn get_pie_chart(&self) -> Option<&PieChart> {
-        self.pie_chart.as_ref()
+        self.pie_chart.first()
     }
 
     pub fn get_pie_chart_mut(&mut self) -> Option<&mut PieChart> {
-        self.pie_chart.as_mut()
+        self.pie_chart.first_mut()
     }
 
     pub fn set_pie_chart(&mut self, value: PieChart) -> &mut Self {
-        self.pie_chart = Some(value);
+        self.pie_chart = thin_vec![value];
+        self.chart_order.retain(|v| *v != "c:pieChart");
+        self.chart_order.push("c:pieChart");
+        self
+    }
+
+    pub fn get_pie_chart_collection(&self) -> &[PieChart] {
+        &self.pie_chart
+    }
+
+    pub fn get_pie_chart_collection_mut(&mut self) -> &mut ThinVec<PieChart> {
+        &mut self.pie_chart
+    }
+
+    pub fn add_pie_chart(&mut self, value: PieChart) -> &mut Self {
+        self.pie_chart.push(value);
+        self.chart_order.push("c:pieChart");
         self
     }
 
     pub fn get_pie_3d_chart(&self) -> Option<&Pie3DChart> {
-        self.pie_3d_chart.as_ref()
+        self.pie_3d_chart.first()
     }
 
     pub fn get_pie_3d_chart_mut(&mut self) -> Option<&mut Pie3DChart> {
-        self.pie_3d_chart.as_mut()
+        self.pie_3d_chart.first_mut()
     }
 
     pub fn set_pie_3d_chart(&mut self, value: Pie3DChart) -> &mut Self {
-        self.pie_3d_chart = Some(value);
+        self.pie_3d_chart = thin_vec![value];
+        self.chart_order.retain(|v| *v != "c:pie3DChart");
+        self.chart_order.push("c:pie3DChart");
+        self
+    }
+
+    pub fn get_pie_3d_chart_collection(&self) -> &[Pie3DChart] {
+        &self.pie_3d_chart
+    }
+
+    pub fn get_pie_3d_chart_collection_mut(&mut self) -> &mut ThinVec<Pie3DChart> {
+        &mut self.pie_3d_chart
+    }
+
+    pub fn add_pie_3d_chart(&mut self, value: Pie3DChart) -> &mut Self {
+        self.pie_3d_chart.push(value);
+        self.chart_order.push("c:pie3DChart");
         self
     }
 
     pub fn get_doughnut_chart(&self) -> Option<&DoughnutChart> {
-        self.doughnut_chart.as_ref()
+        self.doughnut_chart.first()
     }
 
     pub fn get_doughnut_chart_mut(&mut self) -> Option<&mut DoughnutChart> {
-        self.doughnut_chart.as_mut()
+        self.doughnut_chart.first_mut()
     }
 
     pub fn set_doughnut_chart(&mut self, value: DoughnutChart) -> &mut Self {
-        self.doughnut_chart = Some(value);
+        self.doughnut_chart = thin_vec![value];
+        self.chart_order.retain(|v| *v != "c:doughnutChart");
+        self.chart_order.push("c:doughnutChart");
+        self
+    }
+
+    pub fn get_doughnut_chart_collection(&self) -> &[DoughnutChart] {
+        &self.doughnut_chart
+    }
+
+    pub fn get_doughnut_chart_collection_mut(&mut self) -> &mut ThinVec<DoughnutChart> {
+        &mut self.doughnut_chart
+    }
+
+    pub fn add_doughnut_chart(&mut self, value: DoughnutChart) -> &mut Self {
+        self.doughnut_chart.push(value);
+        self.chart_order.push("c:doughnutChart");
         self
     }
 
     pub fn get_scatter_chart(&self) -> Option<&ScatterChart> {
-        self.scatter_chart.as_ref()
+        self.scatter_chart.first()
     }
 
     pub fn get_scatter_chart_mut(&mut self) -> Option<&mut ScatterChart> {
-        self.scatter_chart.as_mut()
+        self.scatter_chart.first_mut()
     }
 
     pub fn set_scatter_chart(&mut self, value: ScatterChart) -> &mut Self {
-        self.scatter_chart = Some(value);
+        self.scatter_chart = thin_vec![value];
+        self.chart_order.retain(|v| *v != "c:scatterChart");
+        self.chart_order.push("c:scatterChart");
+        self
+    }
+
+    pub fn get_scatter_chart_collection(&self) -> &[ScatterChart] {
+        &self.scatter_chart
+    }
+
+    pub fn get_scatter_chart_collection_mut(&mut self) -> &mut ThinVec<ScatterChart> {
+        &mut self.scatter_chart
+    }
+
+    pub fn add_scatter_chart(&mut self, value: ScatterChart) -> &mut Self {
+        self.scatter_chart.push(value);
+        self.chart_order.push("c:scatterChart");
         self
     }
 
     pub fn get_bar_chart(&self) -> Option<&BarChart> {
-        self.bar_chart.as_ref()
+        self.bar_chart.first()
     }
 
     pub fn get_bar_chart_mut(&mut self) -> Option<&mut BarChart> {
-        self.bar_chart.as_mut()
+        self.bar_chart.first_mut()
     }
 
     pub fn set_bar_chart(&mut self, value: BarChart) -> &mut Self {
-        self.bar_chart = Some(value);
+        self.bar_chart = thin_vec![value];
+        self.chart_order.retain(|v| *v != "c:barChart");
+        self.chart_order.push("c:barChart");
+        self
+    }
+
+    pub fn get_bar_chart_collection(&self) -> &[BarChart] {
+        &self.bar_chart
+    }
+
+    pub fn get_bar_chart_collection_mut(&mut self) -> &mut ThinVec<BarChart> {
+        &mut self.bar_chart
+    }
+
+    pub fn add_bar_chart(&mut self, value: BarChart) -> &mut Self {
+        self.bar_chart.push(value);
+        self.chart_order.push("c:barChart");
         self
     }
 
     pub fn get_bar_3d_chart(&self) -> Option<&Bar3DChart> {
-        self.bar_3d_chart.as_ref()
+        self.bar_3d_chart.first()
     }
 
     pub fn get_bar_3d_chart_mut(&mut self) -> Option<&mut Bar3DChart> {
-        self.bar_3d_chart.as_mut()
+        self.bar_3d_chart.first_mut()
     }
 
     pub fn set_bar_3d_chart(&mut self, value: Bar3DChart) -> &mut Self {
-        self.bar_3d_chart = Some(value);
+        self.bar_3d_chart = thin_vec![value];
+        self.chart_order.retain(|v| *v != "c:bar3DChart");
+        self.chart_order.push("c:bar3DChart");
+        self
+    }
+
+    pub fn get_bar_3d_chart_collection(&self) -> &[Bar3DChart] {
+        &self.bar_3d_chart
+    }
+
+    pub fn get_bar_3d_chart_collection_mut(&mut self) -> &mut ThinVec<Bar3DChart> {
+        &mut self.bar_3d_chart
+    }
+
+    pub fn add_bar_3d_chart(&mut self, value: Bar3DChart) -> &mut Self {
+        self.bar_3d_chart.push(value);
+        self.chart_order.push("c:bar3DChart");
         self
     }
 
     pub fn get_radar_chart(&self) -> Option<&RadarChart> {
-        self.radar_chart.as_ref()
+        self.radar_chart.first()
     }
 
     pub fn get_radar_chart_mut(&mut self) -> Option<&mut RadarChart> {
-        self.radar_chart.as_mut()
+        self.radar_chart.first_mut()
     }
 
     pub fn set_radar_chart(&mut self, value: RadarChart) -> &mut Self {
-        self.radar_chart = Some(value);
+        self.radar_chart = thin_vec![value];
+        self.chart_order.retain(|v| *v != "c:radarChart");
+        self.chart_order.push("c:radarChart");
+        self
+    }
+
+    pub fn get_radar_chart_collection(&self) -> &[RadarChart] {
+        &self.radar_chart
+    }
+
+    pub fn get_radar_chart_collection_mut(&mut self) -> &mut ThinVec<RadarChart> {
+        &mut self.radar_chart
+    }
+
+    pub fn add_radar_chart(&mut self, value: RadarChart) -> &mut Self {
+        self.radar_chart.push(value);
+        self.chart_order.push("c:radarChart");
+        self
+    }
+
+    pub fn get_stock_chart(&self) -> Option<&StockChart> {
+        self.stock_chart.first()
+    }
+
+    pub fn get_stock_chart_mut(&mut self) -> Option<&mut StockChart> {
+        self.stock_chart.first_mut()
+    }
+
+    pub fn set_stock_chart(&mut self, value: StockChart) -> &mut Self {
+        self.stock_chart = thin_vec![value];
+        self.chart_order.retain(|v| *v != "c:stockChart");
+        self.chart_order.push("c:stockChart");
+        self
+    }
+
+    pub fn get_stock_chart_collection(&self) -> &[StockChart] {
+        &self.stock_chart
+    }
+
+    pub fn get_stock_chart_collection_mut(&mut self) -> &mut ThinVec<StockChart> {
+        &mut self.stock_chart
+    }
+
+    pub fn add_stock_chart(&mut self, value: StockChart) -> &mut Self {
+        self.stock_chart.push(value);
+        self.chart_order.push("c:stockChart");
+        self
+    }
+
+    pub fn get_surface_chart(&self) -> Option<&SurfaceChart> {
+        self.surface_chart.first()
+    }
+
+    pub fn get_surface_chart_mut(&mut self) -> Option<&mut SurfaceChart> {
+        self.surface_chart.first_mut()
+    }
+
+    pub fn set_surface_chart(&mut self, value: SurfaceChart) -> &mut Self {
+        self.surface_chart = thin_vec![value];
+        self.chart_order.retain(|v| *v != "c:surfaceChart");
+        self.chart_order.push("c:surfaceChart");
+        self
+    }
+
+    pub fn get_surface_chart_collection(&self) -> &[SurfaceChart] {
+        &self.surface_chart
+    }
+
+    pub fn get_surface_chart_collection_mut(&mut self) -> &mut ThinVec<SurfaceChart> {
+        &mut self.surface_chart
+    }
+
+    pub fn add_surface_chart(&mut self, value: SurfaceChart) -> &mut Self {
+        self.surface_chart.push(value);
+        self.chart_order.push("c:surfaceChart");
+        self
+    }
+
+    pub fn get_surface_3d_chart(&self) -> Option<&Surface3DChart> {
+        self.surface_3d_chart.first()
+    }
+
+    pub fn get_surface_3d_chart_mut(&mut self) -> Option<&mut Surface3DChart> {
+        self.surface_3d_chart.first_mut()
+    }
+
+    pub fn set_surface_3d_chart(&mut self, value: Surface3DChart) -> &mut Self {
+        self.surface_3d_chart = thin_vec![value];
+        self.chart_order.retain(|v| *v != "c:surface3DChart");
+        self.chart_order.push("c:surface3DChart");
+        self
+    }
+
+    pub fn get_surface_3d_chart_collection(&self) -> &[Surface3DChart] {
+        &self.surface_3d_chart
+    }
+
+    pub fn get_surface_3d_chart_collection_mut(&mut self) -> &mut ThinVec<Surface3DChart> {
+        &mut self.surface_3d_chart
+    }
+
+    pub fn add_surface_3d_chart(&mut self, value: Surface3DChart) -> &mut Self {
+        self.surface_3d_chart.push(value);
+        self.chart_order.push("c:surface3DChart");
         self
     }
 
     pub fn get_bubble_chart(&self) -> Option<&BubbleChart> {
-        self.bubble_chart.as_ref()
+        self.bubble_chart.first()
     }
 
     pub fn get_bubble_chart_mut(&mut self) -> Option<&mut BubbleChart> {
-        self.bubble_chart.as_mut()
+        self.bubble_chart.first_mut()
     }
 
     pub fn set_bubble_chart(&mut self, value: BubbleChart) -> &mut Self {
-        self.bubble_chart = Some(value);
+        self.bubble_chart = thin_vec![value];
+        self.chart_order.retain(|v| *v != "c:bubbleChart");
+        self.chart_order.push("c:bubbleChart");
+        self
+    }
+
+    pub fn get_bubble_chart_collection(&self) -> &[BubbleChart] {
+        &self.bubble_chart
+    }
+
+    pub fn get_bubble_chart_collection_mut(&mut self) -> &mut ThinVec<BubbleChart> {
+        &mut self.bubble_chart
+    }
+
+    pub fn add_bubble_chart(&mut self, value: BubbleChart) -> &mut Self {
+        self.bubble_chart.push(value);
+        self.chart_order.push("c:bubbleChart");
         self
     }
 
     pub fn get_area_chart(&self) -> Option<&AreaChart> {
-        self.area_chart.as_ref()
+        self.area_chart.first()
     }
 
     pub fn get_area_chart_mut(&mut self) -> Option<&mut AreaChart> {
-        self.area_chart.as_mut()
+        self.area_chart.first_mut()
     }
 
     pub fn set_area_chart(&mut self, value: AreaChart) -> &mut Self {
-        self.area_chart = Some(value);
+        self.area_chart = thin_vec![value];
+        self.chart_order.retain(|v| *v != "c:areaChart");
+        self.chart_order.push("c:areaChart");
+        self
+    }
+
+    pub fn get_area_chart_collection(&self) -> &[AreaChart] {
+        &self.area_chart
+    }
+
+    pub fn get_area_chart_collection_mut(&mut self) -> &mut ThinVec<AreaChart> {
+        &mut self.area_chart
+    }
+
+    pub fn add_area_chart(&mut self, value: AreaChart) -> &mut Self {
+        self.area_chart.push(value);
+        self.chart_order.push("c:areaChart");
         self
     }
 
     pub fn get_area_3d_chart(&self) -> Option<&Area3DChart> {
-        self.area_3d_chart.as_ref()
+        self.area_3d_chart.first()
     }
 
     pub fn get_area_3d_chart_mut(&mut self) -> Option<&mut Area3DChart> {
-        self.area_3d_chart.as_mut()
+        self.area_3d_chart.first_mut()
     }
 
     pub fn set_area_3d_chart(&mut self, value: Area3DChart) -> &mut Self {
-        self.area_3d_chart = Some(value);
+        self.area_3d_chart = thin_vec![value];
+        self.chart_order.retain(|v| *v != "c:area3DChart");
+        self.chart_order.push("c:area3DChart");
+        self
+    }
+
+    pub fn get_area_3d_chart_collection(&self) -> &[Area3DChart] {
+        &self.area_3d_chart
+    }
+
+    pub fn get_area_3d_chart_collection_mut(&mut self) -> &mut ThinVec<Area3DChart> {
+        &mut self.area_3d_chart
+    }
+
+    pub fn add_area_3d_chart(&mut self, value: Area3DChart) -> &mut Self {
+        self.area_3d_chart.push(value);
+        self.chart_order.push("c:area3DChart");
         self
     }
 
     pub fn get_of_pie_chart(&self) -> Option<&OfPieChart> {
-        self.of_pie_chart.as_ref()
+        self.of_pie_chart.first()
     }
 
     pub fn get_of_pie_chart_mut(&mut self) -> Option<&mut OfPieChart> {
-        self.of_pie_chart.as_mut()
+        self.of_pie_chart.first_mut()
     }
 
     pub fn set_of_pie_chart(&mut self, value: OfPieChart) -> &mut Self {
-        self.of_pie_chart = Some(value);
+        self.of_pie_chart = thin_vec![value];
+        self.chart_order.retain(|v| *v != "c:ofPieChart");
+        self.chart_order.push("c:ofPieChart");
+        self
+    }
+
+    pub fn get_of_pie_chart_collection(&self) -> &[OfPieChart] {
+        &self.of_pie_chart
+    }
+
+    pub fn get_of_pie_chart_collection_mut(&mut self) -> &mut ThinVec<OfPieChart> {
+        &mut self.of_pie_chart
+    }
+
+    pub fn add_of_pie_chart(&mut self, value: OfPieChart) -> &mut Self {
+        self.of_pie_chart.push(value);
+        self.chart_order.push("c:ofPieChart");
         self
     }
 
@@ -253,6 +583,24 @@ impl PlotArea {
         self
     }
 
+    pub fn get_date_axis(&self) -> &[DateAxis] {
+        &self.date_axis
+    }
+
+    pub fn get_date_axis_mut(&mut self) -> &mut ThinVec<DateAxis> {
+        &mut self.date_axis
+    }
+
+    pub fn set_date_axis(&mut self, value: impl Into<ThinVec<DateAxis>>) -> &mut Self {
+        self.date_axis = value.into();
+        self
+    }
+
+    pub fn add_date_axis(&mut self, value: DateAxis) -> &mut Self {
+        self.date_axis.push(value);
+        self
+    }
+
     pub fn get_value_axis(&self) -> &[ValueAxis] {
         &self.value_axis
     }
@@ -289,6 +637,19 @@ impl PlotArea {
         self
     }
 
+    pub fn get_data_table(&self) -> Option<&DataTable> {
+        self.data_table.as_ref()
+    }
+
+    pub fn get_data_table_mut(&mut self) -> Option<&mut DataTable> {
+        self.data_table.as_mut()
+    }
+
+    pub fn set_data_table(&mut self, value: DataTable) -> &mut Self {
+        self.data_table = Some(value);
+        self
+    }
+
     pub fn get_shape_properties(&self) -> Option<&ShapeProperties> {
         self.shape_properties.as_ref()
     }
@@ -303,27 +664,27 @@ impl PlotArea {
     }
 
     pub fn set_grouping(&mut self, value: GroupingValues) -> &mut Self {
-        if let Some(chart) = &mut self.line_chart {
+        if let Some(chart) = self.line_chart.first_mut() {
             chart.get_grouping_mut().set_val(value);
             return self;
         }
-        if let Some(chart) = &mut self.line_3d_chart {
+        if let Some(chart) = self.line_3d_chart.first_mut() {
             chart.get_grouping_mut().set_val(value);
             return self;
         }
-        if let Some(chart) = &mut self.bar_chart {
+        if let Some(chart) = self.bar_chart.first_mut() {
             chart.get_grouping_mut().set_val(value);
             return self;
         }
-        if let Some(chart) = &mut self.bar_3d_chart {
+        if let Some(chart) = self.bar_3d_chart.first_mut() {
             chart.get_grouping_mut().set_val(value);
             return self;
         }
-        if let Some(chart) = &mut self.area_chart {
+        if let Some(chart) = self.area_chart.first_mut() {
             chart.get_grouping_mut().set_val(value);
             return self;
         }
-        if let Some(chart) = &mut self.area_3d_chart {
+        if let Some(chart) = self.area_3d_chart.first_mut() {
             chart.get_grouping_mut().set_val(value);
             return self;
         }
@@ -331,43 +692,52 @@ impl PlotArea {
     }
 
     pub fn get_area_chart_series_list_mut(&mut self) -> &mut AreaChartSeriesList {
-        if let Some(chart) = &mut self.line_chart {
+        if let Some(chart) = self.line_chart.first_mut() {
+            return chart.get_area_chart_series_list_mut();
+        }
+        if let Some(chart) = self.line_3d_chart.first_mut() {
+            return chart.get_area_chart_series_list_mut();
+        }
+        if let Some(chart) = self.pie_chart.first_mut() {
             return chart.get_area_chart_series_list_mut();
         }
-        if let Some(chart) = &mut self.line_3d_chart {
+        if let Some(chart) = self.pie_3d_chart.first_mut() {
             return chart.get_area_chart_series_list_mut();
         }
-        if let Some(chart) = &mut self.pie_chart {
+        if let Some(chart) = self.doughnut_chart.first_mut() {
             return chart.get_area_chart_series_list_mut();
         }
-        if let Some(chart) = &mut self.pie_3d_chart {
+        if let Some(chart) = self.scatter_chart.first_mut() {
             return chart.get_area_chart_series_list_mut();
         }
-        if let Some(chart) = &mut self.doughnut_chart {
+        if let Some(chart) = self.bar_chart.first_mut() {
             return chart.get_area_chart_series_list_mut();
         }
-        if let Some(chart) = &mut self.scatter_chart {
+        if let Some(chart) = self.bar_3d_chart.first_mut() {
             return chart.get_area_chart_series_list_mut();
         }
-        if let Some(chart) = &mut self.bar_chart {
+        if let Some(chart) = self.radar_chart.first_mut() {
             return chart.get_area_chart_series_list_mut();
         }
-        if let Some(chart) = &mut self.bar_3d_chart {
+        if let Some(chart) = self.stock_chart.first_mut() {
             return chart.get_area_chart_series_list_mut();
         }
-        if let Some(chart) = &mut self.radar_chart {
+        if let Some(chart) = self.surface_chart.first_mut() {
             return chart.get_area_chart_series_list_mut();
         }
-        if let Some(chart) = &mut self.bubble_chart {
+        if let Some(chart) = self.surface_3d_chart.first_mut() {
             return chart.get_area_chart_series_list_mut();
         }
-        if let Some(chart) = &mut self.area_chart {
+        if let Some(chart) = self.bubble_chart.first_mut() {
             return chart.get_area_chart_series_list_mut();
         }
-        if let Some(chart) = &mut self.area_3d_chart {
+        if let Some(chart) = self.area_chart.first_mut() {
             return chart.get_area_chart_series_list_mut();
         }
-        if let Some(chart) = &mut self.of_pie_chart {
+        if let Some(chart) = self.area_3d_chart.first_mut() {
+            return chart.get_area_chart_series_list_mut();
+        }
+        if let Some(chart) = self.of_pie_chart.first_mut() {
             return chart.get_area_chart_series_list_mut();
         }
         panic! {"Non-ChartSeriesList."};
@@ -375,7 +745,37 @@ impl PlotArea {
 
     pub fn get_formula_mut(&mut self) -> Vec<&mut Formula> {
         let mut result: Vec<&mut Formula> = Vec::default();
-        if let Some(v) = &mut self.line_chart {
+        for v in &mut self.line_chart {
+            for ser in v
+                .get_area_chart_series_list_mut()
+                .get_area_chart_series_mut()
+            {
+                for formula in ser.get_formula_mut() {
+                    result.push(formula);
+                }
+            }
+        }
+        for v in &mut self.line_3d_chart {
+            for ser in v
+                .get_area_chart_series_list_mut()
+                .get_area_chart_series_mut()
+            {
+                for formula in ser.get_formula_mut() {
+                    result.push(formula);
+                }
+            }
+        }
+        for v in &mut self.pie_chart {
+            for ser in v
+                .get_area_chart_series_list_mut()
+                .get_area_chart_series_mut()
+            {
+                for formula in ser.get_formula_mut() {
+                    result.push(formula);
+                }
+            }
+        }
+        for v in &mut self.pie_3d_chart {
             for ser in v
                 .get_area_chart_series_list_mut()
                 .get_area_chart_series_mut()
@@ -385,7 +785,7 @@ impl PlotArea {
                 }
             }
         }
-        if let Some(v) = &mut self.line_3d_chart {
+        for v in &mut self.doughnut_chart {
             for ser in v
                 .get_area_chart_series_list_mut()
                 .get_area_chart_series_mut()
@@ -395,7 +795,7 @@ impl PlotArea {
                 }
             }
         }
-        if let Some(v) = &mut self.pie_chart {
+        for v in &mut self.scatter_chart {
             for ser in v
                 .get_area_chart_series_list_mut()
                 .get_area_chart_series_mut()
@@ -405,7 +805,7 @@ impl PlotArea {
                 }
             }
         }
-        if let Some(v) = &mut self.pie_3d_chart {
+        for v in &mut self.bar_chart {
             for ser in v
                 .get_area_chart_series_list_mut()
                 .get_area_chart_series_mut()
@@ -415,7 +815,7 @@ impl PlotArea {
                 }
             }
         }
-        if let Some(v) = &mut self.doughnut_chart {
+        for v in &mut self.bar_3d_chart {
             for ser in v
                 .get_area_chart_series_list_mut()
                 .get_area_chart_series_mut()
@@ -425,7 +825,7 @@ impl PlotArea {
                 }
             }
         }
-        if let Some(v) = &mut self.scatter_chart {
+        for v in &mut self.radar_chart {
             for ser in v
                 .get_area_chart_series_list_mut()
                 .get_area_chart_series_mut()
@@ -435,7 +835,7 @@ impl PlotArea {
                 }
             }
         }
-        if let Some(v) = &mut self.bar_chart {
+        for v in &mut self.stock_chart {
             for ser in v
                 .get_area_chart_series_list_mut()
                 .get_area_chart_series_mut()
@@ -445,7 +845,7 @@ impl PlotArea {
                 }
             }
         }
-        if let Some(v) = &mut self.bar_3d_chart {
+        for v in &mut self.surface_chart {
             for ser in v
                 .get_area_chart_series_list_mut()
                 .get_area_chart_series_mut()
@@ -455,7 +855,7 @@ impl PlotArea {
                 }
             }
         }
-        if let Some(v) = &mut self.radar_chart {
+        for v in &mut self.surface_3d_chart {
             for ser in v
                 .get_area_chart_series_list_mut()
                 .get_area_chart_series_mut()
@@ -465,7 +865,7 @@ impl PlotArea {
                 }
             }
         }
-        if let Some(v) = &mut self.bubble_chart {
+        for v in &mut self.bubble_chart {
             for ser in v
                 .get_area_chart_series_list_mut()
                 .get_area_chart_series_mut()
@@ -475,7 +875,7 @@ impl PlotArea {
                 }
             }
         }
-        if let Some(v) = &mut self.area_chart {
+        for v in &mut self.area_chart {
             for ser in v
                 .get_area_chart_series_list_mut()
                 .get_area_chart_series_mut()
@@ -485,7 +885,7 @@ impl PlotArea {
                 }
             }
         }
-        if let Some(v) = &mut self.area_3d_chart {
+        for v in &mut self.area_3d_chart {
             for ser in v
                 .get_area_chart_series_list_mut()
                 .get_area_chart_series_mut()
@@ -495,7 +895,7 @@ impl PlotArea {
                 }
             }
         }
-        if let Some(v) = &mut self.of_pie_chart {
+        for v in &mut self.of_pie_chart {
             for ser in v
                 .get_area_chart_series_list_mut()
                 .get_area_chart_series_mut()
@@ -508,126 +908,391 @@ impl PlotArea {
         result
     }
 
+    /// (This method is crate only.)
+    /// Series of all chart groups.
+    pub(crate) fn get_area_chart_series_all_mut(&mut self) -> Vec<&mut AreaChartSeries> {
+        let mut result: Vec<&mut AreaChartSeries> = Vec::default();
+        for v in &mut self.line_chart {
+            result.extend(
+                v.get_area_chart_series_list_mut()
+                    .get_area_chart_series_mut()
+                    .iter_mut(),
+            );
+        }
+        for v in &mut self.line_3d_chart {
+            result.extend(
+                v.get_area_chart_series_list_mut()
+                    .get_area_chart_series_mut()
+                    .iter_mut(),
+            );
+        }
+        for v in &mut self.pie_chart {
+            result.extend(
+                v.get_area_chart_series_list_mut()
+                    .get_area_chart_series_mut()
+                    .iter_mut(),
+            );
+        }
+        for v in &mut self.pie_3d_chart {
+            result.extend(
+                v.get_area_chart_series_list_mut()
+                    .get_area_chart_series_mut()
+                    .iter_mut(),
+            );
+        }
+        for v in &mut self.doughnut_chart {
+            result.extend(
+                v.get_area_chart_series_list_mut()
+                    .get_area_chart_series_mut()
+                    .iter_mut(),
+            );
+        }
+        for v in &mut self.scatter_chart {
+            result.extend(
+                v.get_area_chart_series_list_mut()
+                    .get_area_chart_series_mut()
+                    .iter_mut(),
+            );
+        }
+        for v in &mut self.bar_chart {
+            result.extend(
+                v.get_area_chart_series_list_mut()
+                    .get_area_chart_series_mut()
+                    .iter_mut(),
+            );
+        }
+        for v in &mut self.bar_3d_chart {
+            result.extend(
+                v.get_area_chart_series_list_mut()
+                    .get_area_chart_series_mut()
+                    .iter_mut(),
+            );
+        }
+        for v in &mut self.radar_chart {
+            result.extend(
+                v.get_area_chart_series_list_mut()
+                    .get_area_chart_series_mut()
+                    .iter_mut(),
+            );
+        }
+        for v in &mut self.stock_chart {
+            result.extend(
+                v.get_area_chart_series_list_mut()
+                    .get_area_chart_series_mut()
+                    .iter_mut(),
+            );
+        }
+        for v in &mut self.surface_chart {
+            result.extend(
+                v.get_area_chart_series_list_mut()
+                    .get_area_chart_series_mut()
+                    .iter_mut(),
+            );
+        }
+        for v in &mut self.surface_3d_chart {
+            result.extend(
+                v.get_area_chart_series_list_mut()
+                    .get_area_chart_series_mut()
+                    .iter_mut(),
+            );
+        }
+        for v in &mut self.bubble_chart {
+            result.extend(
+                v.get_area_chart_series_list_mut()
+                    .get_area_chart_series_mut()
+                    .iter_mut(),
+            );
+        }
+        for v in &mut self.area_chart {
+            result.extend(
+                v.get_area_chart_series_list_mut()
+                    .get_area_chart_series_mut()
+                    .iter_mut(),
+            );
+        }
+        for v in &mut self.area_3d_chart {
+            result.extend(
+                v.get_area_chart_series_list_mut()
+                    .get_area_chart_series_mut()
+                    .iter_mut(),
+            );
+        }
+        for v in &mut self.of_pie_chart {
+            result.extend(
+                v.get_area_chart_series_list_mut()
+                    .get_area_chart_series_mut()
+                    .iter_mut(),
+            );
+        }
+        result
+    }
+
+    /// (This method is crate only.)
+    /// Axis ids referenced by all chart groups.
+    pub(crate) fn get_chart_group_axis_id_mut(&mut self) -> Vec<&mut AxisId> {
+        let mut result: Vec<&mut AxisId> = Vec::default();
+        for v in &mut self.line_chart {
+            result.extend(v.get_axis_id_mut().iter_mut());
+        }
+        for v in &mut self.line_3d_chart {
+            result.extend(v.get_axis_id_mut().iter_mut());
+        }
+        for v in &mut self.scatter_chart {
+            result.extend(v.get_axis_id_mut().iter_mut());
+        }
+        for v in &mut self.bar_chart {
+            result.extend(v.get_axis_id_mut().iter_mut());
+        }
+        for v in &mut self.bar_3d_chart {
+            result.extend(v.get_axis_id_mut().iter_mut());
+        }
+        for v in &mut self.radar_chart {
+            result.extend(v.get_axis_id_mut().iter_mut());
+        }
+        for v in &mut self.stock_chart {
+            result.extend(v.get_axis_id_mut().iter_mut());
+        }
+        for v in &mut self.surface_chart {
+            result.extend(v.get_axis_id_mut().iter_mut());
+        }
+        for v in &mut self.surface_3d_chart {
+            result.extend(v.get_axis_id_mut().iter_mut());
+        }
+        for v in &mut self.bubble_chart {
+            result.extend(v.get_axis_id_mut().iter_mut());
+        }
+        for v in &mut self.area_chart {
+            result.extend(v.get_axis_id_mut().iter_mut());
+        }
+        for v in &mut self.area_3d_chart {
+            result.extend(v.get_axis_id_mut().iter_mut());
+        }
+        result
+    }
+
+    /// (This method is crate only.)
+    /// Axis ids of the chart group plotted on the first value axis.
+    pub(crate) fn get_primary_axis_id_list(&self) -> Vec<u32> {
+        let value_axis_id = match self.value_axis.first() {
+            Some(v) => *v.get_axis_id().get_val(),
+            None => return Vec::new(),
+        };
+        let mut group_list: Vec<&[AxisId]> = Vec::new();
+        group_list.extend(self.area_chart.iter().map(|v| v.get_axis_id()));
+        group_list.extend(self.area_3d_chart.iter().map(|v| v.get_axis_id()));
+        group_list.extend(self.bar_chart.iter().map(|v| v.get_axis_id()));
+        group_list.extend(self.bar_3d_chart.iter().map(|v| v.get_axis_id()));
+        group_list.extend(self.line_chart.iter().map(|v| v.get_axis_id()));
+        group_list.extend(self.line_3d_chart.iter().map(|v| v.get_axis_id()));
+        group_list.extend(self.radar_chart.iter().map(|v| v.get_axis_id()));
+        group_list.extend(self.stock_chart.iter().map(|v| v.get_axis_id()));
+        group_list.extend(self.surface_chart.iter().map(|v| v.get_axis_id()));
+        group_list.extend(self.surface_3d_chart.iter().map(|v| v.get_axis_id()));
+        group_list.extend(self.scatter_chart.iter().map(|v| v.get_axis_id()));
+        group_list.extend(self.bubble_chart.iter().map(|v| v.get_axis_id()));
+        group_list
+            .into_iter()
+            .find(|ids| ids.iter().any(|v| v.get_val() == &value_axis_id))
+            .map(|ids| ids.iter().map(|v| *v.get_val()).collect())
+            .unwrap_or_default()
+    }
+
+    /// (This method is crate only.)
+    /// Ids of all axes.
+    pub(crate) fn get_axis_id_list(&self) -> Vec<u32> {
+        let mut result: Vec<u32> = Vec::default();
+        result.extend(
+            self.category_axis
+                .iter()
+                .map(|v| *v.get_axis_id().get_val()),
+        );
+        result.extend(self.date_axis.iter().map(|v| *v.get_axis_id().get_val()));
+        result.extend(self.value_axis.iter().map(|v| *v.get_axis_id().get_val()));
+        result.extend(self.series_axis.iter().map(|v| *v.get_axis_id().get_val()));
+        result
+    }
+
+    /// (This method is crate only.)
+    /// Move the chart groups and axes of another plot area into this one.
+    pub(crate) fn append(&mut self, other: PlotArea) {
+        self.line_chart.extend(other.line_chart);
+        self.line_3d_chart.extend(other.line_3d_chart);
+        self.pie_chart.extend(other.pie_chart);
+        self.pie_3d_chart.extend(other.pie_3d_chart);
+        self.doughnut_chart.extend(other.doughnut_chart);
+        self.scatter_chart.extend(other.scatter_chart);
+        self.bar_chart.extend(other.bar_chart);
+        self.bar_3d_chart.extend(other.bar_3d_chart);
+        self.radar_chart.extend(other.radar_chart);
+        self.stock_chart.extend(other.stock_chart);
+        self.surface_chart.extend(other.surface_chart);
+        self.surface_3d_chart.extend(other.surface_3d_chart);
+        self.bubble_chart.extend(other.bubble_chart);
+        self.area_chart.extend(other.area_chart);
+        self.area_3d_chart.extend(other.area_3d_chart);
+        self.of_pie_chart.extend(other.of_pie_chart);
+        self.chart_order.extend(other.chart_order);
+        self.category_axis.extend(other.category_axis);
+        self.date_axis.extend(other.date_axis);
+        self.value_axis.extend(other.value_axis);
+        self.series_axis.extend(other.series_axis);
+    }
+
     pub(crate) fn is_support(&self) -> bool {
-        self.line_chart.is_some()
-            || self.line_3d_chart.is_some()
-            || self.pie_chart.is_some()
-            || self.pie_3d_chart.is_some()
-            || self.doughnut_chart.is_some()
-            || self.scatter_chart.is_some()
-            || self.bar_chart.is_some()
-            || self.bar_3d_chart.is_some()
-            || self.radar_chart.is_some()
-            || self.bubble_chart.is_some()
-            || self.area_chart.is_some()
-            || self.area_3d_chart.is_some()
-            || self.of_pie_chart.is_some()
+        !self.line_chart.is_empty()
+            || !self.line_3d_chart.is_empty()
+            || !self.pie_chart.is_empty()
+            || !self.pie_3d_chart.is_empty()
+            || !self.doughnut_chart.is_empty()
+            || !self.scatter_chart.is_empty()
+            || !self.bar_chart.is_empty()
+            || !self.bar_3d_chart.is_empty()
+            || !self.radar_chart.is_empty()
+            || !self.stock_chart.is_empty()
+            || !self.surface_chart.is_empty()
+            || !self.surface_3d_chart.is_empty()
+            || !self.bubble_chart.is_empty()
+            || !self.area_chart.is_empty()
+            || !self.area_3d_chart.is_empty()
+            || !self.of_pie_chart.is_empty()
     }
 
     pub(crate) fn set_attributes<R: std::io::BufRead>(
         &mut self,
         reader: &mut Reader<R>,
         _e: &BytesStart,
-    ) {
+    ) -> Result<(), XlsxError> {
         xml_read_loop!(
             reader,
             Event::Start(ref e) => match e.name().0 {
                 b"c:layout" => {
-                    self.layout.set_attributes(reader, e, false);
+                    self.layout.set_attributes(reader, e, false)?;
                 }
                 b"c:lineChart" => {
                     let mut obj = LineChart::default();
-                    obj.set_attributes(reader, e);
-                    self.set_line_chart(obj);
+                    obj.set_attributes(reader, e)?;
+                    self.add_line_chart(obj);
                 }
                 b"c:line3DChart" => {
                     let mut obj = Line3DChart::default();
-                    obj.set_attributes(reader, e);
-                    self.set_line_3d_chart(obj);
+                    obj.set_attributes(reader, e)?;
+                    self.add_line_3d_chart(obj);
                 }
                 b"c:pieChart" => {
                     let mut obj = PieChart::default();
-                    obj.set_attributes(reader, e);
-                    self.set_pie_chart(obj);
+                    obj.set_attributes(reader, e)?;
+                    self.add_pie_chart(obj);
                 }
                 b"c:pie3DChart" => {
                     let mut obj = Pie3DChart::default();
-                    obj.set_attributes(reader, e);
-                    self.set_pie_3d_chart(obj);
+                    obj.set_attributes(reader, e)?;
+                    self.add_pie_3d_chart(obj);
                 }
                 b"c:doughnutChart" => {
                     let mut obj = DoughnutChart::default();
-                    obj.set_attributes(reader, e);
-                    self.set_doughnut_chart(obj);
+                    obj.set_attributes(reader, e)?;
+                    self.add_doughnut_chart(obj);
                 }
                 b"c:scatterChart" => {
                     let mut obj = ScatterChart::default();
-                    obj.set_attributes(reader, e);
-                    self.set_scatter_chart(obj);
+                    obj.set_attributes(reader, e)?;
+                    self.add_scatter_chart(obj);
                 }
                 b"c:barChart" => {
                     let mut obj = BarChart::default();
-                    obj.set_attributes(reader, e);
-                    self.set_bar_chart(obj);
+                    obj.set_attributes(reader, e)?;
+                    self.add_bar_chart(obj);
                 }
                 b"c:bar3DChart" => {
                     let mut obj = Bar3DChart::default();
-                    obj.set_attributes(reader, e);
-                    self.set_bar_3d_chart(obj);
+                    obj.set_attributes(reader, e)?;
+                    self.add_bar_3d_chart(obj);
                 }
                 b"c:radarChart" => {
                     let mut obj = RadarChart::default();
-                    obj.set_attributes(reader, e);
-                    self.set_radar_chart(obj);
+                    obj.set_attributes(reader, e)?;
+                    self.add_radar_chart(obj);
+                }
+                b"c:stockChart" => {
+                    let mut obj = StockChart::default();
+                    obj.set_attributes(reader, e)?;
+                    self.add_stock_chart(obj);
+                }
+                b"c:surfaceChart" => {
+                    let mut obj = SurfaceChart::default();
+                    obj.set_attributes(reader, e)?;
+                    self.add_surface_chart(obj);
+                }
+                b"c:surface3DChart" => {
+                    let mut obj = Surface3DChart::default();
+                    obj.set_attributes(reader, e)?;
+                    self.add_surface_3d_chart(obj);
                 }
                 b"c:bubbleChart" => {
                     let mut obj = BubbleChart::default();
-                    obj.set_attributes(reader, e);
-                    self.set_bubble_chart(obj);
+                    obj.set_attributes(reader, e)?;
+                    self.add_bubble_chart(obj);
                 }
                 b"c:areaChart" => {
                     let mut obj = AreaChart::default();
-                    obj.set_attributes(reader, e);
-                    self.set_area_chart(obj);
+                    obj.set_attributes(reader, e)?;
+                    self.add_area_chart(obj);
                 }
                 b"c:area3DChart" => {
                     let mut obj = Area3DChart::default();
-                    obj.set_attributes(reader, e);
-                    self.set_area_3d_chart(obj);
+                    obj.set_attributes(reader, e)?;
+                    self.add_area_3d_chart(obj);
                 }
                 b"c:ofPieChart" => {
                     let mut obj = OfPieChart::default();
-                    obj.set_attributes(reader, e);
-                    self.set_of_pie_chart(obj);
+                    obj.set_attributes(reader, e)?;
+                    self.add_of_pie_chart(obj);
                 }
                 b"c:catAx" => {
                     let mut obj = CategoryAxis::default();
-                    obj.set_attributes(reader, e);
+                    obj.set_attributes(reader, e)?;
                     self.add_category_axis(obj);
                 }
+                b"c:dateAx" => {
+                    let mut obj = DateAxis::default();
+                    obj.set_attributes(reader, e)?;
+                    self.add_date_axis(obj);
+                }
                 b"c:valAx" => {
                     let mut obj = ValueAxis::default();
-                    obj.set_attributes(reader, e);
+                    obj.set_attributes(reader, e)?;
                     self.add_value_axis(obj);
                 }
                 b"c:serAx" => {
                     let mut obj = SeriesAxis::default();
-                    obj.set_attributes(reader, e);
+                    obj.set_attributes(reader, e)?;
                     self.add_series_axis(obj);
                 }
+                b"c:dTable" => {
+                    let mut obj = DataTable::default();
+                    obj.set_attributes(reader, e, false)?;
+                    self.set_data_table(obj);
+                }
                 b"c:spPr" => {
                     let mut obj = ShapeProperties::default();
-                    obj.set_attributes(reader, e);
+                    obj.set_attributes(reader, e)?;
                     self.set_shape_properties(obj);
                 }
                 _ => (),
             },
+            Event::Empty(ref e) => {
+                if e.name().0 == b"c:dTable" {
+                    let mut obj = DataTable::default();
+                    obj.set_attributes(reader, e, true)?;
+                    self.set_data_table(obj);
+                }
+            },
             Event::End(ref e) => {
                 if e.name().0 == b"c:plotArea" {
-                    return;
+                    return Ok(());
                 }
             },
-            Event::Eof => panic!("Error: Could not find {} end element", "c:plotArea"),
+            Event::Eof => return Err(end_element_error(reader, "c:plotArea")),
         );
     }
 
@@ -638,69 +1303,22 @@ impl PlotArea {
         // c:layout
         self.layout.write_to(writer);
 
-        // c:lineChart
-        if let Some(v) = &self.line_chart {
-            v.write_to(writer, spreadsheet);
-        }
-
-        // c:line3DChart
-        if let Some(v) = &self.line_3d_chart {
-            v.write_to(writer, spreadsheet);
-        }
-
-        // c:pieChart
-        if let Some(v) = &self.pie_chart {
-            v.write_to(writer, spreadsheet);
-        }
-
-        // c:pie3DChart
-        if let Some(v) = &self.pie_3d_chart {
-            v.write_to(writer, spreadsheet);
-        }
-
-        // c:doughnutChart
-        if let Some(v) = &self.doughnut_chart {
-            v.write_to(writer, spreadsheet);
-        }
-
-        // c:scatterChart
-        if let Some(v) = &self.scatter_chart {
-            v.write_to(writer, spreadsheet);
-        }
-
-        // c:barChart
-        if let Some(v) = &self.bar_chart {
-            v.write_to(writer, spreadsheet);
-        }
-
-        // c:bar3DChart
-        if let Some(v) = &self.bar_3d_chart {
-            v.write_to(writer, spreadsheet);
-        }
-
-        // c:radarChart
-        if let Some(v) = &self.radar_chart {
-            v.write_to(writer, spreadsheet);
-        }
-
-        // c:bubbleChart
-        if let Some(v) = &self.bubble_chart {
-            v.write_to(writer, spreadsheet);
-        }
-
-        // c:areaChart
-        if let Some(v) = &self.area_chart {
-            v.write_to(writer, spreadsheet);
-        }
-
-        // c:area3DChart
-        if let Some(v) = &self.area_3d_chart {
-            v.write_to(writer, spreadsheet);
+        // chart groups are written in the order they were read or added,
+        // so that the later groups are drawn over the earlier ones.
+        let mut index_list: HashMap<&str, usize> = HashMap::new();
+        for name in &self.chart_order {
+            let index = index_list.entry(name).or_insert(0);
+            if self.write_chart_group(writer, spreadsheet, name, *index) {
+                *index += 1;
+            }
         }
 
-        // c:ofPieChart
-        if let Some(v) = &self.of_pie_chart {
-            v.write_to(writer, spreadsheet);
+        // chart groups pushed to the collections directly
+        for name in CHART_GROUP_NAMES {
+            let mut index = index_list.get(name).copied().unwrap_or(0);
+            while self.write_chart_group(writer, spreadsheet, name, index) {
+                index += 1;
+            }
         }
 
         // c:catAx
@@ -708,6 +1326,11 @@ impl PlotArea {
             v.write_to(writer);
         }
 
+        // c:dateAx
+        for v in &self.date_axis {
+            v.write_to(writer);
+        }
+
         // c:valAx
         for v in &self.value_axis {
             v.write_to(writer);
@@ -718,6 +1341,11 @@ impl PlotArea {
             v.write_to(writer);
         }
 
+        // c:dTable
+        if let Some(v) = &self.data_table {
+            v.write_to(writer);
+        }
+
         // c:spPr
         if let Some(v) = &self.shape_properties {
             v.write_to(writer);
@@ -725,6 +1353,82 @@ impl PlotArea {
 
         write_end_tag(writer, "c:plotArea");
     }
+    fn write_chart_group(
+        &self,
+        writer: &mut Writer<Cursor<Vec<u8>>>,
+        spreadsheet: &Spreadsheet,
+        name: &str,
+        index: usize,
+    ) -> bool {
+        match name {
+            "c:areaChart" => self
+                .area_chart
+                .get(index)
+                .map(|v| v.write_to(writer, spreadsheet)),
+            "c:area3DChart" => self
+                .area_3d_chart
+                .get(index)
+                .map(|v| v.write_to(writer, spreadsheet)),
+            "c:barChart" => self
+                .bar_chart
+                .get(index)
+                .map(|v| v.write_to(writer, spreadsheet)),
+            "c:bar3DChart" => self
+                .bar_3d_chart
+                .get(index)
+                .map(|v| v.write_to(writer, spreadsheet)),
+            "c:lineChart" => self
+                .line_chart
+                .get(index)
+                .map(|v| v.write_to(writer, spreadsheet)),
+            "c:line3DChart" => self
+                .line_3d_chart
+                .get(index)
+                .map(|v| v.write_to(writer, spreadsheet)),
+            "c:pieChart" => self
+                .pie_chart
+                .get(index)
+                .map(|v| v.write_to(writer, spreadsheet)),
+            "c:pie3DChart" => self
+                .pie_3d_chart
+                .get(index)
+                .map(|v| v.write_to(writer, spreadsheet)),
+            "c:doughnutChart" => self
+                .doughnut_chart
+                .get(index)
+                .map(|v| v.write_to(writer, spreadsheet)),
+            "c:ofPieChart" => self
+                .of_pie_chart
+                .get(index)
+                .map(|v| v.write_to(writer, spreadsheet)),
+            "c:radarChart" => self
+                .radar_chart
+                .get(index)
+                .map(|v| v.write_to(writer, spreadsheet)),
+            "c:stockChart" => self
+                .stock_chart
+                .get(index)
+                .map(|v| v.write_to(writer, spreadsheet)),
+            "c:surfaceChart" => self
+                .surface_chart
+                .get(index)
+                .map(|v| v.write_to(writer, spreadsheet)),
+            "c:surface3DChart" => self
+                .surface_3d_chart
+                .get(index)
+                .map(|v| v.write_to(writer, spreadsheet)),
+            "c:scatterChart" => self
+                .scatter_chart
+                .get(index)
+                .map(|v| v.write_to(writer, spreadsheet)),
+            "c:bubbleChart" => self
+                .bubble_chart
+                .get(index)
+                .map(|v| v.write_to(writer, spreadsheet)),
+            _ => None,
+        }
+        .is_some()
+    }
 }
 impl AdjustmentCoordinateWithSheet for PlotArea {
     fn adjustment_insert_coordinate_with_sheet(
@@ -4,6 +4,7 @@ use super::PageMargins;
 use super::PageSetup;
 use crate::reader::driver::*;
 use crate::writer::driver::*;
+use crate::XlsxError;
 use quick_xml::events::{BytesStart, Event};
 use quick_xml::Reader;
 use quick_xml::Writer;
@@ -60,31 +61,31 @@ impl PrintSettings {
         &mut self,
         reader: &mut Reader<R>,
         _e: &BytesStart,
-    ) {
+    ) -> Result<(), XlsxError> {
         xml_read_loop!(
             reader,
             Event::Start(ref e) => {
                 match e.name().0 {
                 b"c:headerFooter" => {
-                    self.header_footer.set_attributes(reader, e);
+                    self.header_footer.set_attributes(reader, e)?;
                 }
                 b"c:pageSetup" => {
-                    self.page_setup.set_attributes(reader, e);
+                    self.page_setup.set_attributes(reader, e)?;
                 }
                 _ => (),
                 }
             },
             Event::Empty(ref e) => {
                 if e.name().0 == b"c:pageMargins" {
-                    self.page_margins.set_attributes(reader, e);
+                    self.page_margins.set_attributes(reader, e)?;
                 }
             },
             Event::End(ref e) => {
                 if e.name().0 == b"c:printSettings" {
-                    return;
+                    return Ok(());
                 }
             },
-            Event::Eof => panic!("Error: Could not find {} end element", "c:printSettings")
+            Event::Eof => return Err(end_element_error(reader, "c:printSettings"))
         );
     }
 
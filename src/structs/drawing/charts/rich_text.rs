@@ -2,8 +2,10 @@
 use super::super::BodyProperties;
 use super::super::ListStyle;
 use super::super::Paragraph;
+use crate::reader::driver::*;
 use crate::writer::driver::*;
 use crate::xml_read_loop;
+use crate::XlsxError;
 use quick_xml::events::{BytesStart, Event};
 use quick_xml::Reader;
 use quick_xml::Writer;
@@ -58,18 +60,18 @@ impl RichText {
         &mut self,
         reader: &mut Reader<R>,
         _e: &BytesStart,
-    ) {
+    ) -> Result<(), XlsxError> {
         xml_read_loop!(
             reader,
             Event::Start(ref e) => match e.name().0 {
                 b"a:p" => {
                     let mut paragraph = Paragraph::default();
-                    paragraph.set_attributes(reader, e);
+                    paragraph.set_attributes(reader, e)?;
                     self.add_paragraph(paragraph);
                 }
                 b"a:bodyPr" => {
                     let mut body_properties = BodyProperties::default();
-                    body_properties.set_attributes(reader, e, false);
+                    body_properties.set_attributes(reader, e, false)?;
                     self.set_body_properties(body_properties);
                 }
                 _ => (),
@@ -77,16 +79,16 @@ impl RichText {
             Event::Empty(ref e) => {
                 if e.name().0 == b"a:bodyPr" {
                     let mut body_properties = BodyProperties::default();
-                    body_properties.set_attributes(reader, e, true);
+                    body_properties.set_attributes(reader, e, true)?;
                     self.set_body_properties(body_properties);
                 }
             },
             Event::End(ref e) => {
                 if e.name().0 == b"c:rich" {
-                    return;
+                    return Ok(());
                 }
             },
-            Event::Eof => panic!("Error: Could not find {} end element", "c:rich"),
+            Event::Eof => return Err(end_element_error(reader, "c:rich")),
         );
     }
 
@@ -1,7 +1,11 @@
 // c:scaling
+use super::LogBase;
+use super::MaxAxisValue;
+use super::MinAxisValue;
 use super::Orientation;
 use crate::reader::driver::*;
 use crate::writer::driver::*;
+use crate::XlsxError;
 use quick_xml::events::{BytesStart, Event};
 use quick_xml::Reader;
 use quick_xml::Writer;
@@ -9,10 +13,31 @@ use std::io::Cursor;
 
 #[derive(Clone, Default, Debug)]
 pub struct Scaling {
+    log_base: Option<LogBase>,
     orientation: Orientation,
+    max_axis_value: Option<MaxAxisValue>,
+    min_axis_value: Option<MinAxisValue>,
 }
 
 impl Scaling {
+    pub fn get_log_base(&self) -> Option<&LogBase> {
+        self.log_base.as_ref()
+    }
+
+    pub fn get_log_base_mut(&mut self) -> Option<&mut LogBase> {
+        self.log_base.as_mut()
+    }
+
+    pub fn set_log_base(&mut self, value: LogBase) -> &mut Self {
+        self.log_base = Some(value);
+        self
+    }
+
+    pub fn remove_log_base(&mut self) -> &mut Self {
+        self.log_base = None;
+        self
+    }
+
     pub fn get_orientation(&self) -> &Orientation {
         &self.orientation
     }
@@ -26,24 +51,76 @@ impl Scaling {
         self
     }
 
+    pub fn get_max_axis_value(&self) -> Option<&MaxAxisValue> {
+        self.max_axis_value.as_ref()
+    }
+
+    pub fn get_max_axis_value_mut(&mut self) -> Option<&mut MaxAxisValue> {
+        self.max_axis_value.as_mut()
+    }
+
+    pub fn set_max_axis_value(&mut self, value: MaxAxisValue) -> &mut Self {
+        self.max_axis_value = Some(value);
+        self
+    }
+
+    pub fn remove_max_axis_value(&mut self) -> &mut Self {
+        self.max_axis_value = None;
+        self
+    }
+
+    pub fn get_min_axis_value(&self) -> Option<&MinAxisValue> {
+        self.min_axis_value.as_ref()
+    }
+
+    pub fn get_min_axis_value_mut(&mut self) -> Option<&mut MinAxisValue> {
+        self.min_axis_value.as_mut()
+    }
+
+    pub fn set_min_axis_value(&mut self, value: MinAxisValue) -> &mut Self {
+        self.min_axis_value = Some(value);
+        self
+    }
+
+    pub fn remove_min_axis_value(&mut self) -> &mut Self {
+        self.min_axis_value = None;
+        self
+    }
+
     pub(crate) fn set_attributes<R: std::io::BufRead>(
         &mut self,
         reader: &mut Reader<R>,
         _e: &BytesStart,
-    ) {
+    ) -> Result<(), XlsxError> {
         xml_read_loop!(
             reader,
-            Event::Empty(ref e) => {
-                if e.name().0 == b"c:orientation" {
-                    self.orientation.set_attributes(reader, e);
+            Event::Empty(ref e) => match e.name().0 {
+                b"c:logBase" => {
+                    let mut obj = LogBase::default();
+                    obj.set_attributes(reader, e)?;
+                    self.set_log_base(obj);
+                }
+                b"c:orientation" => {
+                    self.orientation.set_attributes(reader, e)?;
+                }
+                b"c:max" => {
+                    let mut obj = MaxAxisValue::default();
+                    obj.set_attributes(reader, e)?;
+                    self.set_max_axis_value(obj);
                 }
+                b"c:min" => {
+                    let mut obj = MinAxisValue::default();
+                    obj.set_attributes(reader, e)?;
+                    self.set_min_axis_value(obj);
+                }
+                _ => (),
             },
             Event::End(ref e) => {
                 if e.name().0 == b"c:scaling" {
-                    return;
+                    return Ok(());
                 }
             },
-            Event::Eof => panic!("Error: Could not find {} end element", "c:scaling")
+            Event::Eof => return Err(end_element_error(reader, "c:scaling"))
         );
     }
 
@@ -51,9 +128,24 @@ impl Scaling {
         // c:scaling
         write_start_tag(writer, "c:scaling", vec![], false);
 
+        // c:logBase
+        if let Some(v) = &self.log_base {
+            v.write_to(writer);
+        }
+
         // c:orientation
         self.orientation.write_to(writer);
 
+        // c:max
+        if let Some(v) = &self.max_axis_value {
+            v.write_to(writer);
+        }
+
+        // c:min
+        if let Some(v) = &self.min_axis_value {
+            v.write_to(writer);
+        }
+
         write_end_tag(writer, "c:scaling");
     }
 }
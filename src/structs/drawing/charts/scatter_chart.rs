@@ -8,6 +8,7 @@ use super::VaryColors;
 use crate::reader::driver::*;
 use crate::structs::Spreadsheet;
 use crate::writer::driver::*;
+use crate::XlsxError;
 use quick_xml::events::{BytesStart, Event};
 use quick_xml::Reader;
 use quick_xml::Writer;
@@ -98,19 +99,19 @@ impl ScatterChart {
         &mut self,
         reader: &mut Reader<R>,
         _e: &BytesStart,
-    ) {
+    ) -> Result<(), XlsxError> {
         xml_read_loop!(
             reader,
             Event::Start(ref e) => {
                 match e.name().0 {
                 b"c:ser" => {
                     let mut obj = AreaChartSeries::default();
-                    obj.set_attributes(reader, e);
+                    obj.set_attributes(reader, e)?;
                     self.get_area_chart_series_list_mut()
                         .add_area_chart_series(obj);
                 }
                 b"c:dLbls" => {
-                    self.data_labels.set_attributes(reader, e);
+                    self.data_labels.set_attributes(reader, e)?;
                 }
                 _ => (),
                 }
@@ -118,14 +119,14 @@ impl ScatterChart {
             Event::Empty(ref e) => {
                 match e.name().0 {
                 b"c:scatterStyle" => {
-                    self.scatter_style.set_attributes(reader, e);
+                    self.scatter_style.set_attributes(reader, e)?;
                 }
                 b"c:varyColors" => {
-                    self.vary_colors.set_attributes(reader, e);
+                    self.vary_colors.set_attributes(reader, e)?;
                 }
                 b"c:axId" => {
                     let mut obj = AxisId::default();
-                    obj.set_attributes(reader, e);
+                    obj.set_attributes(reader, e)?;
                     self.add_axis_id(obj);
                 }
                 _ => (),
@@ -133,10 +134,10 @@ impl ScatterChart {
             },
             Event::End(ref e) => {
                 if e.name().0 == b"c:scatterChart" {
-                    return;
+                    return Ok(());
                 }
             },
-            Event::Eof => panic!("Error: Could not find {} end element", "c:scatterChart")
+            Event::Eof => return Err(end_element_error(reader, "c:scatterChart"))
         );
     }
 
@@ -2,6 +2,7 @@
 use crate::reader::driver::*;
 use crate::structs::StringValue;
 use crate::writer::driver::*;
+use crate::XlsxError;
 use quick_xml::events::{BytesStart, Event};
 use quick_xml::Reader;
 use quick_xml::Writer;
@@ -26,18 +27,18 @@ impl SeriesText {
         &mut self,
         reader: &mut Reader<R>,
         _e: &BytesStart,
-    ) {
+    ) -> Result<(), XlsxError> {
         xml_read_loop!(
             reader,
             Event::Text(e) => {
-                self.set_value(e.unescape().unwrap());
+                self.set_value(e.unescape()?);
             },
             Event::End(ref e) => {
                 if e.name().0 == b"c:tx" {
-                    return
+                    return Ok(())
                 }
             },
-            Event::Eof => panic!("Error: Could not find {} end element", "c:tx")
+            Event::Eof => return Err(end_element_error(reader, "c:tx"))
         );
     }
 
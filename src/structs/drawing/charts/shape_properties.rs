@@ -10,6 +10,7 @@ use super::super::SolidFill;
 use super::super::Transform2D;
 use crate::reader::driver::*;
 use crate::writer::driver::*;
+use crate::XlsxError;
 use quick_xml::events::{BytesStart, Event};
 use quick_xml::Reader;
 use quick_xml::Writer;
@@ -150,49 +151,49 @@ impl ShapeProperties {
         &mut self,
         reader: &mut Reader<R>,
         _e: &BytesStart,
-    ) {
+    ) -> Result<(), XlsxError> {
         xml_read_loop!(
             reader,
             Event::Start(ref e) => {
                 match e.name().0 {
                     b"a:pattFill" => {
                         let mut obj = PatternFill::default();
-                        obj.set_attributes(reader, e);
+                        obj.set_attributes(reader, e)?;
                         self.set_pattern_fill(obj);
                     }
                     b"a:xfrm" => {
                         let mut obj = Transform2D::default();
-                        obj.set_attributes(reader, e);
+                        obj.set_attributes(reader, e)?;
                         self.set_transform2d(obj);
                     }
                     b"a:prstGeom" => {
                         let mut obj = PresetGeometry::default();
-                        obj.set_attributes(reader, e);
+                        obj.set_attributes(reader, e)?;
                         self.set_geometry(obj);
                     }
                     b"a:ln" => {
                         let mut obj = Outline::default();
-                        obj.set_attributes(reader, e);
+                        obj.set_attributes(reader, e)?;
                         self.set_outline(obj);
                     }
                     b"a:solidFill" => {
                         let mut obj = SolidFill::default();
-                        obj.set_attributes(reader, e);
+                        obj.set_attributes(reader, e)?;
                         self.set_solid_fill(obj);
                     }
                     b"a:effectLst" => {
                         let mut obj = EffectList::default();
-                        obj.set_attributes(reader, e, false);
+                        obj.set_attributes(reader, e, false)?;
                         self.set_effect_list(obj);
                     }
                     b"a:scene3d" => {
                         let mut obj = Scene3DType::default();
-                        obj.set_attributes(reader, e);
+                        obj.set_attributes(reader, e)?;
                         self.set_scene_3d_type(obj);
                     }
                     b"a:sp3d" => {
                         let mut obj = Shape3DType::default();
-                        obj.set_attributes(reader, e);
+                        obj.set_attributes(reader, e)?;
                         self.set_shape_3d_type(obj);
                     }
                     _ => (),
@@ -201,16 +202,16 @@ impl ShapeProperties {
             Event::Empty(ref e) => {
                 if e.name().0 == b"a:noFill" {
                     let mut obj = NoFill::default();
-                    obj.set_attributes(reader, e);
+                    obj.set_attributes(reader, e)?;
                     self.set_no_fill(obj);
                 }
             },
             Event::End(ref e) => {
                 if e.name().0 == b"c:spPr" {
-                    return;
+                    return Ok(());
                 }
             },
-            Event::Eof => panic!("Error: Could not find {} end element", "c:spPr")
+            Event::Eof => return Err(end_element_error(reader, "c:spPr"))
         );
     }
 
@@ -1,30 +1,58 @@
 // c:strCache
+use super::string_point::*;
 use crate::reader::driver::*;
 use crate::structs::Address;
 use crate::structs::Spreadsheet;
 use crate::writer::driver::*;
+use crate::XlsxError;
 use quick_xml::events::{BytesStart, Event};
 use quick_xml::Reader;
 use quick_xml::Writer;
 use std::io::Cursor;
+use thin_vec::ThinVec;
 
 #[derive(Clone, Default, Debug)]
-pub struct StringCache {}
+pub struct StringCache {
+    point_list: ThinVec<StringPoint>,
+}
 
 impl StringCache {
+    /// Cached values as read from the file, indexed by point.
+    /// Missing points are kept as empty values.
+    pub fn get_point_list(&self) -> &[StringPoint] {
+        &self.point_list
+    }
+
+    pub fn get_point_list_mut(&mut self) -> &mut ThinVec<StringPoint> {
+        &mut self.point_list
+    }
+
     pub(crate) fn set_attributes<R: std::io::BufRead>(
         &mut self,
         reader: &mut Reader<R>,
         _e: &BytesStart,
-    ) {
+    ) -> Result<(), XlsxError> {
         xml_read_loop!(
             reader,
+            Event::Start(ref e) => {
+                if e.name().0 == b"c:pt" {
+                    let index = get_point_index(e)?;
+                    let mut obj = StringPoint::default();
+                    obj.set_attributes(reader, e)?;
+                    set_point(&mut self.point_list, index, obj);
+                }
+            },
+            Event::Empty(ref e) => {
+                if e.name().0 == b"c:ptCount" {
+                    resize_point_list(&mut self.point_list, e)?;
+                }
+            },
             Event::End(ref e) => {
                 if e.name().0 == b"c:strCache" {
-                    return;
+                    return Ok(());
                 }
             },
-            Event::Eof => panic!("Error: Could not find {} end element", "c:strCache")
+            Event::Eof => return Err(end_element_error(reader, "c:strCache"))
         );
     }
 
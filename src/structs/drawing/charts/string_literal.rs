@@ -2,6 +2,7 @@
 use super::StringPoint;
 use crate::reader::driver::*;
 use crate::writer::driver::*;
+use crate::XlsxError;
 use quick_xml::events::{BytesStart, Event};
 use quick_xml::Reader;
 use quick_xml::Writer;
@@ -31,22 +32,22 @@ impl StringLiteral {
         &mut self,
         reader: &mut Reader<R>,
         _e: &BytesStart,
-    ) {
+    ) -> Result<(), XlsxError> {
         xml_read_loop!(
             reader,
             Event::Start(ref e) => {
                 if e.name().0 == b"c:pt" {
                     let mut obj = StringPoint::default();
-                    obj.set_attributes(reader, e);
+                    obj.set_attributes(reader, e)?;
                     self.add_string_point_list(obj);
                 }
             },
             Event::End(ref e) => {
                 if e.name().0 == b"c:strLit" {
-                    return;
+                    return Ok(());
                 }
             },
-            Event::Eof => panic!("Error: Could not find {} end element", "c:strLit")
+            Event::Eof => return Err(end_element_error(reader, "c:strLit"))
         );
     }
 
@@ -1,4 +1,6 @@
+use crate::reader::driver::*;
 use crate::xml_read_loop;
+use crate::XlsxError;
 
 // c:pt
 use super::NumericValue;
@@ -7,6 +9,7 @@ use quick_xml::events::{BytesStart, Event};
 use quick_xml::Reader;
 use quick_xml::Writer;
 use std::io::Cursor;
+use thin_vec::ThinVec;
 
 #[derive(Clone, Default, Debug)]
 pub struct StringPoint {
@@ -31,20 +34,20 @@ impl StringPoint {
         &mut self,
         reader: &mut Reader<R>,
         _e: &BytesStart,
-    ) {
+    ) -> Result<(), XlsxError> {
         xml_read_loop!(
             reader,
             Event::Start(ref e) => {
                 if e.name().0 == b"c:v" {
-                    self.numeric_value._set_attributes(reader, e);
+                    self.numeric_value._set_attributes(reader, e)?;
                 }
             },
             Event::End(ref e) => {
                 if e.name().0 == b"c:pt" {
-                    return;
+                    return Ok(());
                 }
             },
-            Event::Eof => panic!("Error: Could not find {} end element", "c:pt"),
+            Event::Eof => return Err(end_element_error(reader, "c:pt")),
         );
     }
 
@@ -59,3 +62,30 @@ impl StringPoint {
         write_end_tag(writer, "c:pt");
     }
 }
+
+/// Index of a `c:pt` element, or `None` when the attribute is missing.
+pub(crate) fn get_point_index(e: &BytesStart) -> Result<Option<usize>, XlsxError> {
+    Ok(get_attribute(e, b"idx")?.and_then(|v| v.parse::<usize>().ok()))
+}
+
+/// Store a cached point at its index, padding skipped points with empty values.
+pub(crate) fn set_point(list: &mut ThinVec<StringPoint>, index: Option<usize>, value: StringPoint) {
+    let index = index.unwrap_or(list.len());
+    if list.len() <= index {
+        list.resize(index + 1, StringPoint::default());
+    }
+    list[index] = value;
+}
+
+/// Size the cached point list from `c:ptCount`.
+pub(crate) fn resize_point_list(
+    list: &mut ThinVec<StringPoint>,
+    e: &BytesStart,
+) -> Result<(), XlsxError> {
+    if let Some(count) = get_attribute(e, b"val")?.and_then(|v| v.parse::<usize>().ok()) {
+        if list.len() < count {
+            list.resize(count, StringPoint::default());
+        }
+    }
+    Ok(())
+}
@@ -1,4 +1,6 @@
+use crate::reader::driver::*;
 use crate::xml_read_loop;
+use crate::XlsxError;
 
 // c:strRef
 use super::Formula;
@@ -47,24 +49,24 @@ impl StringReference {
         &mut self,
         reader: &mut Reader<R>,
         _e: &BytesStart,
-    ) {
+    ) -> Result<(), XlsxError> {
         xml_read_loop!(
             reader,
             Event::Start(ref e) => match e.name().0 {
                 b"c:f" => {
-                    self.formula.set_attributes(reader, e);
+                    self.formula.set_attributes(reader, e)?;
                 }
                 b"c:strCache" => {
-                    self.string_cache.set_attributes(reader, e);
+                    self.string_cache.set_attributes(reader, e)?;
                 }
                 _ => (),
             },
             Event::End(ref e) => {
                 if e.name().0 == b"c:strRef" {
-                    return;
+                    return Ok(());
                 }
             },
-            Event::Eof => panic!("Error: Could not find {} end element", "c:strRef"),
+            Event::Eof => return Err(end_element_error(reader, "c:strRef")),
         );
     }
 
@@ -2,8 +2,10 @@
 use super::ChartText;
 use super::Layout;
 use super::Overlay;
+use crate::reader::driver::*;
 use crate::writer::driver::*;
 use crate::xml_read_loop;
+use crate::XlsxError;
 use quick_xml::events::{BytesStart, Event};
 use quick_xml::Reader;
 use quick_xml::Writer;
@@ -60,39 +62,39 @@ impl Title {
         &mut self,
         reader: &mut Reader<R>,
         _e: &BytesStart,
-    ) {
+    ) -> Result<(), XlsxError> {
         xml_read_loop!(
             reader,
             Event::Start(ref e) => match e.name().0 {
                 b"c:tx" => {
                     let mut obj = ChartText::default();
-                    obj.set_attributes(reader, e);
+                    obj.set_attributes(reader, e)?;
                     self.set_chart_text(obj);
                 }
                 b"c:layout" => {
                     let mut obj = Layout::default();
-                    obj.set_attributes(reader, e, false);
+                    obj.set_attributes(reader, e, false)?;
                     self.set_layout(obj);
                 }
                 _ => (),
             },
             Event::Empty(ref e) => match e.name().0 {
                 b"c:overlay" => {
-                    self.overlay.set_attributes(reader, e);
+                    self.overlay.set_attributes(reader, e)?;
                 }
                 b"c:layout" => {
                     let mut obj = Layout::default();
-                    obj.set_attributes(reader, e, true);
+                    obj.set_attributes(reader, e, true)?;
                     self.set_layout(obj);
                 }
                 _ => (),
             },
             Event::End(ref e) => {
                 if e.name().0 == b"c:title" {
-                    return;
+                    return Ok(());
                 }
             },
-            Event::Eof => panic!("Error: Could not find {} end element", "c:title"),
+            Event::Eof => return Err(end_element_error(reader, "c:title")),
         );
     }
 
@@ -0,0 +1,320 @@
+// c:trendline
+use super::Backward;
+use super::DisplayEquation;
+use super::DisplayRSquaredValue;
+use super::Forward;
+use super::Intercept;
+use super::Period;
+use super::PolynomialOrder;
+use super::ShapeProperties;
+use super::TrendlineLabel;
+use super::TrendlineName;
+use super::TrendlineType;
+use crate::reader::driver::*;
+use crate::writer::driver::*;
+use crate::xml_read_loop;
+use crate::XlsxError;
+use quick_xml::events::{BytesStart, Event};
+use quick_xml::Reader;
+use quick_xml::Writer;
+use std::io::Cursor;
+
+#[derive(Clone, Default, Debug)]
+pub struct Trendline {
+    trendline_name: Option<TrendlineName>,
+    shape_properties: Option<ShapeProperties>,
+    trendline_type: TrendlineType,
+    polynomial_order: Option<PolynomialOrder>,
+    period: Option<Period>,
+    forward: Option<Forward>,
+    backward: Option<Backward>,
+    intercept: Option<Intercept>,
+    display_r_squared_value: Option<DisplayRSquaredValue>,
+    display_equation: Option<DisplayEquation>,
+    trendline_label: Option<TrendlineLabel>,
+}
+
+impl Trendline {
+    pub fn get_trendline_name(&self) -> Option<&TrendlineName> {
+        self.trendline_name.as_ref()
+    }
+
+    pub fn get_trendline_name_mut(&mut self) -> Option<&mut TrendlineName> {
+        self.trendline_name.as_mut()
+    }
+
+    pub fn set_trendline_name(&mut self, value: TrendlineName) -> &mut Self {
+        self.trendline_name = Some(value);
+        self
+    }
+
+    pub fn get_shape_properties(&self) -> Option<&ShapeProperties> {
+        self.shape_properties.as_ref()
+    }
+
+    pub fn get_shape_properties_mut(&mut self) -> Option<&mut ShapeProperties> {
+        self.shape_properties.as_mut()
+    }
+
+    pub fn set_shape_properties(&mut self, value: ShapeProperties) -> &mut Self {
+        self.shape_properties = Some(value);
+        self
+    }
+
+    pub fn get_trendline_type(&self) -> &TrendlineType {
+        &self.trendline_type
+    }
+
+    pub fn get_trendline_type_mut(&mut self) -> &mut TrendlineType {
+        &mut self.trendline_type
+    }
+
+    pub fn set_trendline_type(&mut self, value: TrendlineType) -> &mut Self {
+        self.trendline_type = value;
+        self
+    }
+
+    pub fn get_polynomial_order(&self) -> Option<&PolynomialOrder> {
+        self.polynomial_order.as_ref()
+    }
+
+    pub fn get_polynomial_order_mut(&mut self) -> Option<&mut PolynomialOrder> {
+        self.polynomial_order.as_mut()
+    }
+
+    pub fn set_polynomial_order(&mut self, value: PolynomialOrder) -> &mut Self {
+        self.polynomial_order = Some(value);
+        self
+    }
+
+    pub fn get_period(&self) -> Option<&Period> {
+        self.period.as_ref()
+    }
+
+    pub fn get_period_mut(&mut self) -> Option<&mut Period> {
+        self.period.as_mut()
+    }
+
+    pub fn set_period(&mut self, value: Period) -> &mut Self {
+        self.period = Some(value);
+        self
+    }
+
+    pub fn get_forward(&self) -> Option<&Forward> {
+        self.forward.as_ref()
+    }
+
+    pub fn get_forward_mut(&mut self) -> Option<&mut Forward> {
+        self.forward.as_mut()
+    }
+
+    pub fn set_forward(&mut self, value: Forward) -> &mut Self {
+        self.forward = Some(value);
+        self
+    }
+
+    pub fn get_backward(&self) -> Option<&Backward> {
+        self.backward.as_ref()
+    }
+
+    pub fn get_backward_mut(&mut self) -> Option<&mut Backward> {
+        self.backward.as_mut()
+    }
+
+    pub fn set_backward(&mut self, value: Backward) -> &mut Self {
+        self.backward = Some(value);
+        self
+    }
+
+    pub fn get_intercept(&self) -> Option<&Intercept> {
+        self.intercept.as_ref()
+    }
+
+    pub fn get_intercept_mut(&mut self) -> Option<&mut Intercept> {
+        self.intercept.as_mut()
+    }
+
+    pub fn set_intercept(&mut self, value: Intercept) -> &mut Self {
+        self.intercept = Some(value);
+        self
+    }
+
+    pub fn get_display_r_squared_value(&self) -> Option<&DisplayRSquaredValue> {
+        self.display_r_squared_value.as_ref()
+    }
+
+    pub fn get_display_r_squared_value_mut(&mut self) -> Option<&mut DisplayRSquaredValue> {
+        self.display_r_squared_value.as_mut()
+    }
+
+    pub fn set_display_r_squared_value(&mut self, value: DisplayRSquaredValue) -> &mut Self {
+        self.display_r_squared_value = Some(value);
+        self
+    }
+
+    pub fn get_display_equation(&self) -> Option<&DisplayEquation> {
+        self.display_equation.as_ref()
+    }
+
+    pub fn get_display_equation_mut(&mut self) -> Option<&mut DisplayEquation> {
+        self.display_equation.as_mut()
+    }
+
+    pub fn set_display_equation(&mut self, value: DisplayEquation) -> &mut Self {
+        self.display_equation = Some(value);
+        self
+    }
+
+    pub fn get_trendline_label(&self) -> Option<&TrendlineLabel> {
+        self.trendline_label.as_ref()
+    }
+
+    pub fn get_trendline_label_mut(&mut self) -> Option<&mut TrendlineLabel> {
+        self.trendline_label.as_mut()
+    }
+
+    pub fn set_trendline_label(&mut self, value: TrendlineLabel) -> &mut Self {
+        self.trendline_label = Some(value);
+        self
+    }
+
+    pub(crate) fn set_attributes<R: std::io::BufRead>(
+        &mut self,
+        reader: &mut Reader<R>,
+        _e: &BytesStart,
+    ) -> Result<(), XlsxError> {
+        xml_read_loop!(
+            reader,
+            Event::Start(ref e) => match e.name().0 {
+                b"c:name" => {
+                    let mut obj = TrendlineName::default();
+                    obj.set_attributes(reader, e)?;
+                    self.set_trendline_name(obj);
+                }
+                b"c:spPr" => {
+                    let mut obj = ShapeProperties::default();
+                    obj.set_attributes(reader, e)?;
+                    self.set_shape_properties(obj);
+                }
+                b"c:trendlineLbl" => {
+                    let mut obj = TrendlineLabel::default();
+                    obj.set_attributes(reader, e, false)?;
+                    self.set_trendline_label(obj);
+                }
+                _ => (),
+            },
+            Event::Empty(ref e) => match e.name().0 {
+                b"c:trendlineType" => {
+                    self.trendline_type.set_attributes(reader, e)?;
+                }
+                b"c:order" => {
+                    let mut obj = PolynomialOrder::default();
+                    obj.set_attributes(reader, e)?;
+                    self.set_polynomial_order(obj);
+                }
+                b"c:period" => {
+                    let mut obj = Period::default();
+                    obj.set_attributes(reader, e)?;
+                    self.set_period(obj);
+                }
+                b"c:forward" => {
+                    let mut obj = Forward::default();
+                    obj.set_attributes(reader, e)?;
+                    self.set_forward(obj);
+                }
+                b"c:backward" => {
+                    let mut obj = Backward::default();
+                    obj.set_attributes(reader, e)?;
+                    self.set_backward(obj);
+                }
+                b"c:intercept" => {
+                    let mut obj = Intercept::default();
+                    obj.set_attributes(reader, e)?;
+                    self.set_intercept(obj);
+                }
+                b"c:dispRSqr" => {
+                    let mut obj = DisplayRSquaredValue::default();
+                    obj.set_attributes(reader, e)?;
+                    self.set_display_r_squared_value(obj);
+                }
+                b"c:dispEq" => {
+                    let mut obj = DisplayEquation::default();
+                    obj.set_attributes(reader, e)?;
+                    self.set_display_equation(obj);
+                }
+                b"c:trendlineLbl" => {
+                    let mut obj = TrendlineLabel::default();
+                    obj.set_attributes(reader, e, true)?;
+                    self.set_trendline_label(obj);
+                }
+                _ => (),
+            },
+            Event::End(ref e) => {
+                if e.name().0 == b"c:trendline" {
+                    return Ok(());
+                }
+            },
+            Event::Eof => return Err(end_element_error(reader, "c:trendline")),
+        );
+    }
+
+    pub(crate) fn write_to(&self, writer: &mut Writer<Cursor<Vec<u8>>>) {
+        // c:trendline
+        write_start_tag(writer, "c:trendline", vec![], false);
+
+        // c:name
+        if let Some(v) = &self.trendline_name {
+            v.write_to(writer);
+        }
+
+        // c:spPr
+        if let Some(v) = &self.shape_properties {
+            v.write_to(writer);
+        }
+
+        // c:trendlineType
+        self.trendline_type.write_to(writer);
+
+        // c:order
+        if let Some(v) = &self.polynomial_order {
+            v.write_to(writer);
+        }
+
+        // c:period
+        if let Some(v) = &self.period {
+            v.write_to(writer);
+        }
+
+        // c:forward
+        if let Some(v) = &self.forward {
+            v.write_to(writer);
+        }
+
+        // c:backward
+        if let Some(v) = &self.backward {
+            v.write_to(writer);
+        }
+
+        // c:intercept
+        if let Some(v) = &self.intercept {
+            v.write_to(writer);
+        }
+
+        // c:dispRSqr
+        if let Some(v) = &self.display_r_squared_value {
+            v.write_to(writer);
+        }
+
+        // c:dispEq
+        if let Some(v) = &self.display_equation {
+            v.write_to(writer);
+        }
+
+        // c:trendlineLbl
+        if let Some(v) = &self.trendline_label {
+            v.write_to(writer);
+        }
+
+        write_end_tag(writer, "c:trendline");
+    }
+}
@@ -0,0 +1,192 @@
+// c:trendlineLbl
+use super::ChartText;
+use super::Layout;
+use super::NumberingFormat;
+use super::ShapeProperties;
+use super::TextProperties;
+use crate::reader::driver::*;
+use crate::writer::driver::*;
+use crate::xml_read_loop;
+use crate::XlsxError;
+use quick_xml::events::{BytesStart, Event};
+use quick_xml::Reader;
+use quick_xml::Writer;
+use std::io::Cursor;
+
+#[derive(Clone, Default, Debug)]
+pub struct TrendlineLabel {
+    layout: Option<Layout>,
+    chart_text: Option<ChartText>,
+    numbering_format: Option<NumberingFormat>,
+    shape_properties: Option<ShapeProperties>,
+    text_properties: Option<TextProperties>,
+}
+
+impl TrendlineLabel {
+    pub fn get_layout(&self) -> Option<&Layout> {
+        self.layout.as_ref()
+    }
+
+    pub fn get_layout_mut(&mut self) -> Option<&mut Layout> {
+        self.layout.as_mut()
+    }
+
+    pub fn set_layout(&mut self, value: Layout) -> &mut Self {
+        self.layout = Some(value);
+        self
+    }
+
+    pub fn get_chart_text(&self) -> Option<&ChartText> {
+        self.chart_text.as_ref()
+    }
+
+    pub fn get_chart_text_mut(&mut self) -> Option<&mut ChartText> {
+        self.chart_text.as_mut()
+    }
+
+    pub fn set_chart_text(&mut self, value: ChartText) -> &mut Self {
+        self.chart_text = Some(value);
+        self
+    }
+
+    pub fn get_numbering_format(&self) -> Option<&NumberingFormat> {
+        self.numbering_format.as_ref()
+    }
+
+    pub fn get_numbering_format_mut(&mut self) -> Option<&mut NumberingFormat> {
+        self.numbering_format.as_mut()
+    }
+
+    pub fn set_numbering_format(&mut self, value: NumberingFormat) -> &mut Self {
+        self.numbering_format = Some(value);
+        self
+    }
+
+    pub fn get_shape_properties(&self) -> Option<&ShapeProperties> {
+        self.shape_properties.as_ref()
+    }
+
+    pub fn get_shape_properties_mut(&mut self) -> Option<&mut ShapeProperties> {
+        self.shape_properties.as_mut()
+    }
+
+    pub fn set_shape_properties(&mut self, value: ShapeProperties) -> &mut Self {
+        self.shape_properties = Some(value);
+        self
+    }
+
+    pub fn get_text_properties(&self) -> Option<&TextProperties> {
+        self.text_properties.as_ref()
+    }
+
+    pub fn get_text_properties_mut(&mut self) -> Option<&mut TextProperties> {
+        self.text_properties.as_mut()
+    }
+
+    pub fn set_text_properties(&mut self, value: TextProperties) -> &mut Self {
+        self.text_properties = Some(value);
+        self
+    }
+
+    pub(crate) fn set_attributes<R: std::io::BufRead>(
+        &mut self,
+        reader: &mut Reader<R>,
+        _e: &BytesStart,
+        empty_flg: bool,
+    ) -> Result<(), XlsxError> {
+        if empty_flg {
+            return Ok(());
+        }
+
+        xml_read_loop!(
+            reader,
+            Event::Start(ref e) => match e.name().0 {
+                b"c:layout" => {
+                    let mut obj = Layout::default();
+                    obj.set_attributes(reader, e, false)?;
+                    self.set_layout(obj);
+                }
+                b"c:tx" => {
+                    let mut obj = ChartText::default();
+                    obj.set_attributes(reader, e)?;
+                    self.set_chart_text(obj);
+                }
+                b"c:spPr" => {
+                    let mut obj = ShapeProperties::default();
+                    obj.set_attributes(reader, e)?;
+                    self.set_shape_properties(obj);
+                }
+                b"c:txPr" => {
+                    let mut obj = TextProperties::default();
+                    obj.set_attributes(reader, e)?;
+                    self.set_text_properties(obj);
+                }
+                _ => (),
+            },
+            Event::Empty(ref e) => match e.name().0 {
+                b"c:layout" => {
+                    let mut obj = Layout::default();
+                    obj.set_attributes(reader, e, true)?;
+                    self.set_layout(obj);
+                }
+                b"c:numFmt" => {
+                    let mut obj = NumberingFormat::default();
+                    obj.set_attributes(reader, e)?;
+                    self.set_numbering_format(obj);
+                }
+                _ => (),
+            },
+            Event::End(ref e) => {
+                if e.name().0 == b"c:trendlineLbl" {
+                    return Ok(());
+                }
+            },
+            Event::Eof => return Err(end_element_error(reader, "c:trendlineLbl")),
+        );
+    }
+
+    fn with_include(&self) -> bool {
+        self.layout.is_some()
+            || self.chart_text.is_some()
+            || self.numbering_format.is_some()
+            || self.shape_properties.is_some()
+            || self.text_properties.is_some()
+    }
+
+    pub(crate) fn write_to(&self, writer: &mut Writer<Cursor<Vec<u8>>>) {
+        if !self.with_include() {
+            // c:trendlineLbl
+            write_start_tag(writer, "c:trendlineLbl", vec![], true);
+            return;
+        }
+        // c:trendlineLbl
+        write_start_tag(writer, "c:trendlineLbl", vec![], false);
+
+        // c:layout
+        if let Some(v) = &self.layout {
+            v.write_to(writer);
+        }
+
+        // c:tx
+        if let Some(v) = &self.chart_text {
+            v.write_to(writer);
+        }
+
+        // c:numFmt
+        if let Some(v) = &self.numbering_format {
+            v.write_to(writer);
+        }
+
+        // c:spPr
+        if let Some(v) = &self.shape_properties {
+            v.write_to(writer);
+        }
+
+        // c:txPr
+        if let Some(v) = &self.text_properties {
+            v.write_to(writer);
+        }
+
+        write_end_tag(writer, "c:trendlineLbl");
+    }
+}
@@ -0,0 +1,51 @@
+// c:name
+use super::super::super::StringValue;
+use crate::reader::driver::*;
+use crate::writer::driver::*;
+use crate::xml_read_loop;
+use crate::XlsxError;
+use quick_xml::events::{BytesStart, Event};
+use quick_xml::Reader;
+use quick_xml::Writer;
+use std::io::Cursor;
+
+#[derive(Clone, Default, Debug)]
+pub struct TrendlineName {
+    text: StringValue,
+}
+impl TrendlineName {
+    pub fn get_text(&self) -> &str {
+        self.text.get_value_str()
+    }
+
+    pub fn set_text<S: Into<String>>(&mut self, value: S) -> &mut TrendlineName {
+        self.text.set_value(value);
+        self
+    }
+
+    pub(crate) fn set_attributes<R: std::io::BufRead>(
+        &mut self,
+        reader: &mut Reader<R>,
+        _e: &BytesStart,
+    ) -> Result<(), XlsxError> {
+        xml_read_loop!(
+            reader,
+            Event::Text(e) => {
+                self.set_text(e.unescape()?);
+            },
+            Event::End(ref e) => {
+                if e.name().0 == b"c:name" {
+                    return Ok(());
+                }
+            },
+            Event::Eof => return Err(end_element_error(reader, "c:name")),
+        );
+    }
+
+    pub(crate) fn write_to(&self, writer: &mut Writer<Cursor<Vec<u8>>>) {
+        // c:name
+        write_start_tag(writer, "c:name", vec![], false);
+        write_text_node(writer, self.text.get_value_str());
+        write_end_tag(writer, "c:name");
+    }
+}
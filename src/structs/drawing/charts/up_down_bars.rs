@@ -0,0 +1,143 @@
+// c:upDownBars
+use super::DownBars;
+use super::GapWidth;
+use super::UpBars;
+use crate::reader::driver::*;
+use crate::writer::driver::*;
+use crate::xml_read_loop;
+use crate::XlsxError;
+use quick_xml::events::{BytesStart, Event};
+use quick_xml::Reader;
+use quick_xml::Writer;
+use std::io::Cursor;
+
+#[derive(Clone, Default, Debug)]
+pub struct UpDownBars {
+    gap_width: Option<GapWidth>,
+    up_bars: Option<UpBars>,
+    down_bars: Option<DownBars>,
+}
+
+impl UpDownBars {
+    pub fn get_gap_width(&self) -> Option<&GapWidth> {
+        self.gap_width.as_ref()
+    }
+
+    pub fn get_gap_width_mut(&mut self) -> Option<&mut GapWidth> {
+        self.gap_width.as_mut()
+    }
+
+    pub fn set_gap_width(&mut self, value: GapWidth) -> &mut Self {
+        self.gap_width = Some(value);
+        self
+    }
+
+    pub fn get_up_bars(&self) -> Option<&UpBars> {
+        self.up_bars.as_ref()
+    }
+
+    pub fn get_up_bars_mut(&mut self) -> Option<&mut UpBars> {
+        self.up_bars.as_mut()
+    }
+
+    pub fn set_up_bars(&mut self, value: UpBars) -> &mut Self {
+        self.up_bars = Some(value);
+        self
+    }
+
+    pub fn get_down_bars(&self) -> Option<&DownBars> {
+        self.down_bars.as_ref()
+    }
+
+    pub fn get_down_bars_mut(&mut self) -> Option<&mut DownBars> {
+        self.down_bars.as_mut()
+    }
+
+    pub fn set_down_bars(&mut self, value: DownBars) -> &mut Self {
+        self.down_bars = Some(value);
+        self
+    }
+
+    pub(crate) fn set_attributes<R: std::io::BufRead>(
+        &mut self,
+        reader: &mut Reader<R>,
+        _e: &BytesStart,
+        empty_flg: bool,
+    ) -> Result<(), XlsxError> {
+        if empty_flg {
+            return Ok(());
+        }
+
+        xml_read_loop!(
+            reader,
+            Event::Start(ref e) => match e.name().0 {
+                b"c:upBars" => {
+                    let mut obj = UpBars::default();
+                    obj.set_attributes(reader, e, false)?;
+                    self.set_up_bars(obj);
+                }
+                b"c:downBars" => {
+                    let mut obj = DownBars::default();
+                    obj.set_attributes(reader, e, false)?;
+                    self.set_down_bars(obj);
+                }
+                _ => (),
+            },
+            Event::Empty(ref e) => match e.name().0 {
+                b"c:gapWidth" => {
+                    let mut obj = GapWidth::default();
+                    obj.set_attributes(reader, e)?;
+                    self.set_gap_width(obj);
+                }
+                b"c:upBars" => {
+                    let mut obj = UpBars::default();
+                    obj.set_attributes(reader, e, true)?;
+                    self.set_up_bars(obj);
+                }
+                b"c:downBars" => {
+                    let mut obj = DownBars::default();
+                    obj.set_attributes(reader, e, true)?;
+                    self.set_down_bars(obj);
+                }
+                _ => (),
+            },
+            Event::End(ref e) => {
+                if e.name().0 == b"c:upDownBars" {
+                    return Ok(());
+                }
+            },
+            Event::Eof => return Err(end_element_error(reader, "c:upDownBars")),
+        );
+    }
+
+    fn with_include(&self) -> bool {
+        self.gap_width.is_some() || self.up_bars.is_some() || self.down_bars.is_some()
+    }
+
+    pub(crate) fn write_to(&self, writer: &mut Writer<Cursor<Vec<u8>>>) {
+        if !self.with_include() {
+            // c:upDownBars
+            write_start_tag(writer, "c:upDownBars", vec![], true);
+            return;
+        }
+        // c:upDownBars
+        write_start_tag(writer, "c:upDownBars", vec![], false);
+
+        // c:gapWidth
+        if let Some(v) = &self.gap_width {
+            v.write_to(writer);
+        }
+
+        // c:upBars
+        if let Some(v) = &self.up_bars {
+            v.write_to(writer);
+        }
+
+        // c:downBars
+        if let Some(v) = &self.down_bars {
+            v.write_to(writer);
+        }
+
+        write_end_tag(writer, "c:upDownBars");
+    }
+}
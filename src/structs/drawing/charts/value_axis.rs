@@ -5,9 +5,12 @@ use super::CrossBetween;
 use super::Crosses;
 use super::CrossingAxis;
 use super::Delete;
+use super::DisplayUnits;
 use super::MajorGridlines;
 use super::MajorTickMark;
+use super::MajorUnit;
 use super::MinorTickMark;
+use super::MinorUnit;
 use super::NumberingFormat;
 use super::Scaling;
 use super::ShapeProperties;
@@ -16,6 +19,7 @@ use super::TickLabelPosition;
 use super::Title;
 use crate::reader::driver::*;
 use crate::writer::driver::*;
+use crate::XlsxError;
 use quick_xml::events::{BytesStart, Event};
 use quick_xml::Reader;
 use quick_xml::Writer;
@@ -36,6 +40,9 @@ pub struct ValueAxis {
     crossing_axis: CrossingAxis,
     crosses: Crosses,
     cross_between: CrossBetween,
+    major_unit: Option<MajorUnit>,
+    minor_unit: Option<MinorUnit>,
+    display_units: Option<DisplayUnits>,
     shape_properties: Option<ShapeProperties>,
     text_properties: Option<TextProperties>,
 }
@@ -106,6 +113,11 @@ impl ValueAxis {
         self
     }
 
+    pub fn remove_major_gridlines(&mut self) -> &mut Self {
+        self.major_gridlines = None;
+        self
+    }
+
     pub fn get_title(&self) -> Option<&Title> {
         self.title.as_ref()
     }
@@ -210,6 +222,45 @@ impl ValueAxis {
         self
     }
 
+    pub fn get_major_unit(&self) -> Option<&MajorUnit> {
+        self.major_unit.as_ref()
+    }
+
+    pub fn get_major_unit_mut(&mut self) -> Option<&mut MajorUnit> {
+        self.major_unit.as_mut()
+    }
+
+    pub fn set_major_unit(&mut self, value: MajorUnit) -> &mut Self {
+        self.major_unit = Some(value);
+        self
+    }
+
+    pub fn get_minor_unit(&self) -> Option<&MinorUnit> {
+        self.minor_unit.as_ref()
+    }
+
+    pub fn get_minor_unit_mut(&mut self) -> Option<&mut MinorUnit> {
+        self.minor_unit.as_mut()
+    }
+
+    pub fn set_minor_unit(&mut self, value: MinorUnit) -> &mut Self {
+        self.minor_unit = Some(value);
+        self
+    }
+
+    pub fn get_display_units(&self) -> Option<&DisplayUnits> {
+        self.display_units.as_ref()
+    }
+
+    pub fn get_display_units_mut(&mut self) -> Option<&mut DisplayUnits> {
+        self.display_units.as_mut()
+    }
+
+    pub fn set_display_units(&mut self, value: DisplayUnits) -> &mut Self {
+        self.display_units = Some(value);
+        self
+    }
+
     pub fn get_shape_properties(&self) -> Option<&ShapeProperties> {
         self.shape_properties.as_ref()
     }
@@ -240,79 +291,94 @@ impl ValueAxis {
         &mut self,
         reader: &mut Reader<R>,
         _e: &BytesStart,
-    ) {
+    ) -> Result<(), XlsxError> {
         xml_read_loop!(
             reader,
             Event::Start(ref e) => match e.name().0 {
                 b"c:scaling" => {
-                    self.scaling.set_attributes(reader, e);
+                    self.scaling.set_attributes(reader, e)?;
                 }
                 b"c:title" => {
                     let mut obj = Title::default();
-                    obj.set_attributes(reader, e);
+                    obj.set_attributes(reader, e)?;
                     self.set_title(obj);
                 }
                 b"c:spPr" => {
                     let mut obj = ShapeProperties::default();
-                    obj.set_attributes(reader, e);
+                    obj.set_attributes(reader, e)?;
                     self.set_shape_properties(obj);
                 }
                 b"c:txPr" => {
                     let mut obj = TextProperties::default();
-                    obj.set_attributes(reader, e);
+                    obj.set_attributes(reader, e)?;
                     self.set_text_properties(obj);
                 }
                 b"c:majorGridlines" => {
                     let mut obj = MajorGridlines::default();
-                    obj.set_attributes(reader, e, false);
+                    obj.set_attributes(reader, e, false)?;
                     self.set_major_gridlines(obj);
                 }
+                b"c:dispUnits" => {
+                    let mut obj = DisplayUnits::default();
+                    obj.set_attributes(reader, e)?;
+                    self.set_display_units(obj);
+                }
                 _ => (),
             },
             Event::Empty(ref e) => match e.name().0 {
                 b"c:axId" => {
-                    self.axis_id.set_attributes(reader, e);
+                    self.axis_id.set_attributes(reader, e)?;
                 }
                 b"c:delete" => {
-                    self.delete.set_attributes(reader, e);
+                    self.delete.set_attributes(reader, e)?;
                 }
                 b"c:axPos" => {
-                    self.axis_position.set_attributes(reader, e);
+                    self.axis_position.set_attributes(reader, e)?;
                 }
                 b"c:majorGridlines" => {
                     let mut obj = MajorGridlines::default();
-                    obj.set_attributes(reader, e, true);
+                    obj.set_attributes(reader, e, true)?;
                     self.set_major_gridlines(obj);
                 }
                 b"c:numFmt" => {
-                    self.numbering_format.set_attributes(reader, e);
+                    self.numbering_format.set_attributes(reader, e)?;
                 }
                 b"c:majorTickMark" => {
-                    self.major_tick_mark.set_attributes(reader, e);
+                    self.major_tick_mark.set_attributes(reader, e)?;
                 }
                 b"c:minorTickMark" => {
-                    self.minor_tick_mark.set_attributes(reader, e);
+                    self.minor_tick_mark.set_attributes(reader, e)?;
                 }
                 b"c:tickLblPos" => {
-                    self.tick_label_position.set_attributes(reader, e);
+                    self.tick_label_position.set_attributes(reader, e)?;
                 }
                 b"c:crossAx" => {
-                    self.crossing_axis.set_attributes(reader, e);
+                    self.crossing_axis.set_attributes(reader, e)?;
                 }
                 b"c:crosses" => {
-                    self.crosses.set_attributes(reader, e);
+                    self.crosses.set_attributes(reader, e)?;
                 }
                 b"c:crossBetween" => {
-                    self.cross_between.set_attributes(reader, e);
+                    self.cross_between.set_attributes(reader, e)?;
+                }
+                b"c:majorUnit" => {
+                    let mut obj = MajorUnit::default();
+                    obj.set_attributes(reader, e)?;
+                    self.set_major_unit(obj);
+                }
+                b"c:minorUnit" => {
+                    let mut obj = MinorUnit::default();
+                    obj.set_attributes(reader, e)?;
+                    self.set_minor_unit(obj);
                 }
                 _ => (),
             },
             Event::End(ref e) => {
                 if e.name().0 == b"c:valAx" {
-                    return;
+                    return Ok(());
                 }
             },
-            Event::Eof => panic!("Error: Could not find {} end element", "c:valAx")
+            Event::Eof => return Err(end_element_error(reader, "c:valAx"))
         );
     }
 
@@ -373,6 +439,21 @@ impl ValueAxis {
         // c:crossBetween
         self.cross_between.write_to(writer);
 
+        // c:majorUnit
+        if let Some(v) = &self.major_unit {
+            v.write_to(writer);
+        }
+
+        // c:minorUnit
+        if let Some(v) = &self.minor_unit {
+            v.write_to(writer);
+        }
+
+        // c:dispUnits
+        if let Some(v) = &self.display_units {
+            v.write_to(writer);
+        }
+
         write_end_tag(writer, "c:valAx");
     }
 }
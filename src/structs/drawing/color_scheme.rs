@@ -3,6 +3,7 @@ use super::super::StringValue;
 use super::Color2Type;
 use crate::reader::driver::*;
 use crate::writer::driver::*;
+use crate::XlsxError;
 use quick_xml::events::{BytesStart, Event};
 use quick_xml::Reader;
 use quick_xml::Writer;
@@ -239,7 +240,7 @@ impl ColorScheme {
         &mut self,
         reader: &mut Reader<R>,
         e: &BytesStart,
-    ) {
+    ) -> Result<(), XlsxError> {
         set_string_from_xml!(self, e, name, "name");
 
         xml_read_loop!(
@@ -247,50 +248,50 @@ impl ColorScheme {
             Event::Start(ref e) => {
                 match e.name().into_inner() {
                     b"a:accent1" => {
-                        self.accent1.set_attributes(reader, e);
+                        self.accent1.set_attributes(reader, e)?;
                     }
                     b"a:accent2" => {
-                        self.accent2.set_attributes(reader, e);
+                        self.accent2.set_attributes(reader, e)?;
                     }
                     b"a:accent3" => {
-                        self.accent3.set_attributes(reader, e);
+                        self.accent3.set_attributes(reader, e)?;
                     }
                     b"a:accent4" => {
-                        self.accent4.set_attributes(reader, e);
+                        self.accent4.set_attributes(reader, e)?;
                     }
                     b"a:accent5" => {
-                        self.accent5.set_attributes(reader, e);
+                        self.accent5.set_attributes(reader, e)?;
                     }
                     b"a:accent6" => {
-                        self.accent6.set_attributes(reader, e);
+                        self.accent6.set_attributes(reader, e)?;
                     }
                     b"a:dk1" => {
-                        self.dk1.set_attributes(reader, e);
+                        self.dk1.set_attributes(reader, e)?;
                     }
                     b"a:dk2" => {
-                        self.dk2.set_attributes(reader, e);
+                        self.dk2.set_attributes(reader, e)?;
                     }
                     b"a:folHlink" => {
-                        self.fol_hlink.set_attributes(reader, e);
+                        self.fol_hlink.set_attributes(reader, e)?;
                     }
                     b"a:hlink" => {
-                        self.hlink.set_attributes(reader, e);
+                        self.hlink.set_attributes(reader, e)?;
                     }
                     b"a:lt1" => {
-                        self.lt1.set_attributes(reader, e);
+                        self.lt1.set_attributes(reader, e)?;
                     }
                     b"a:lt2" => {
-                        self.lt2.set_attributes(reader, e);
+                        self.lt2.set_attributes(reader, e)?;
                     }
                     _ => (),
                 }
             },
             Event::End(ref e) => {
                 if e.name().into_inner() == b"a:clrScheme" {
-                    return
+                    return Ok(())
                 }
             },
-            Event::Eof => panic!("Error: Could not find {} end element", "a:clrScheme")
+            Event::Eof => return Err(end_element_error(reader, "a:clrScheme"))
         );
     }
 
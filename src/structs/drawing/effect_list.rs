@@ -4,6 +4,7 @@ use super::OuterShadow;
 use super::SoftEdge;
 use crate::reader::driver::*;
 use crate::writer::driver::*;
+use crate::XlsxError;
 use quick_xml::events::{BytesStart, Event};
 use quick_xml::Reader;
 use quick_xml::Writer;
@@ -67,9 +68,9 @@ impl EffectList {
         reader: &mut Reader<R>,
         _e: &BytesStart,
         empty_flag: bool,
-    ) {
+    ) -> Result<(), XlsxError> {
         if empty_flag {
-            return;
+            return Ok(());
         }
 
         xml_read_loop!(
@@ -77,7 +78,7 @@ impl EffectList {
             Event::Empty(ref e) => {
                 if e.name().into_inner() == b"a:softEdge" {
                     let mut obj = SoftEdge::default();
-                    obj.set_attributes(reader, e);
+                    obj.set_attributes(reader, e)?;
                     self.set_soft_edge(obj);
                 }
             },
@@ -85,12 +86,12 @@ impl EffectList {
                 match e.name().into_inner() {
                     b"a:glow" => {
                         let mut obj = Glow::default();
-                        obj.set_attributes(reader, e);
+                        obj.set_attributes(reader, e)?;
                         self.set_glow(obj);
                     }
                     b"a:outerShdw" => {
                         let mut obj = OuterShadow::default();
-                        obj.set_attributes(reader, e);
+                        obj.set_attributes(reader, e)?;
                         self.set_outer_shadow(obj);
                     }
                     _ => (),
@@ -98,10 +99,10 @@ impl EffectList {
             },
             Event::End(ref e) => {
                 if e.name().into_inner() == b"a:effectLst" {
-                    return;
+                    return Ok(());
                 }
             },
-            Event::Eof => panic!("Error: Could not find {} end element", "a:effectLst")
+            Event::Eof => return Err(end_element_error(reader, "a:effectLst"))
         );
     }
 
@@ -3,6 +3,7 @@ use super::Scene3DType;
 use super::Shape3DType;
 use crate::reader::driver::*;
 use crate::writer::driver::*;
+use crate::XlsxError;
 use quick_xml::events::{BytesStart, Event};
 use quick_xml::Reader;
 use quick_xml::Writer;
@@ -69,24 +70,24 @@ impl EffectStyle {
         &mut self,
         reader: &mut Reader<R>,
         _e: &BytesStart,
-    ) {
+    ) -> Result<(), XlsxError> {
         xml_read_loop!(
             reader,
             Event::Start(ref e) => {
                 match e.name().into_inner() {
                 b"a:effectLst" => {
                     let mut obj = EffectList::default();
-                    obj.set_attributes(reader, e, false);
+                    obj.set_attributes(reader, e, false)?;
                     self.effect_list = Some(Box::new(obj));
                 }
                 b"a:scene3d" => {
                     let mut obj = Scene3DType::default();
-                    obj.set_attributes(reader, e);
+                    obj.set_attributes(reader, e)?;
                     self.scene_3d_type = Some(Box::new(obj));
                 }
                 b"a:sp3d" => {
                     let mut obj = Shape3DType::default();
-                    obj.set_attributes(reader, e);
+                    obj.set_attributes(reader, e)?;
                     self.shape_3d_type = Some(Box::new(obj));
                 }
                 _ => (),
@@ -95,16 +96,16 @@ impl EffectStyle {
             Event::Empty(ref e) => {
                 if e.name().into_inner() == b"a:effectLst" {
                     let mut obj = EffectList::default();
-                    obj.set_attributes(reader, e, true);
+                    obj.set_attributes(reader, e, true)?;
                     self.set_effect_list(obj);
                 }
             },
             Event::End(ref e) => {
                 if e.name().into_inner() == b"a:effectStyle" {
-                    return
+                    return Ok(())
                 }
             },
-            Event::Eof => panic!("Error: Could not find {} end element", "a:effectStyle")
+            Event::Eof => return Err(end_element_error(reader, "a:effectStyle"))
         );
     }
 
@@ -1,6 +1,7 @@
 use super::EffectStyle;
 use crate::reader::driver::*;
 use crate::writer::driver::*;
+use crate::XlsxError;
 use quick_xml::events::{BytesStart, Event};
 use quick_xml::Reader;
 use quick_xml::Writer;
@@ -42,22 +43,22 @@ impl EffectStyleList {
         &mut self,
         reader: &mut Reader<R>,
         _e: &BytesStart,
-    ) {
+    ) -> Result<(), XlsxError> {
         xml_read_loop!(
             reader,
             Event::Start(ref e) => {
                 if e.name().into_inner() == b"a:effectStyle" {
                     let mut obj = EffectStyle::default();
-                    obj.set_attributes(reader, e);
+                    obj.set_attributes(reader, e)?;
                     self.effect_style_collection.push(obj);
                 }
             },
             Event::End(ref e) => {
                 if e.name().into_inner() == b"a:effectStyleLst" {
-                    return
+                    return Ok(())
                 }
             },
-            Event::Eof => panic!("Error: Could not find {} end element", "a:effectStyleLst")
+            Event::Eof => return Err(end_element_error(reader, "a:effectStyleLst"))
         );
     }
 
@@ -2,6 +2,7 @@
 use super::super::super::UInt32Value;
 use crate::reader::driver::*;
 use crate::writer::driver::*;
+use crate::XlsxError;
 use quick_xml::events::BytesStart;
 use quick_xml::Reader;
 use quick_xml::Writer;
@@ -38,10 +39,12 @@ impl EndConnection {
         &mut self,
         _reader: &mut Reader<R>,
         e: &BytesStart,
-    ) {
-        self.id.set_value_string(get_attribute(e, b"id").unwrap());
+    ) -> Result<(), XlsxError> {
+        self.id
+            .try_set_value_string(get_required_attribute(e, b"id")?, "id")?;
         self.index
-            .set_value_string(get_attribute(e, b"idx").unwrap());
+            .try_set_value_string(get_required_attribute(e, b"idx")?, "idx")?;
+        Ok(())
     }
 
     #[inline]
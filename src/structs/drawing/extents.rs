@@ -2,6 +2,7 @@
 use super::super::super::Int64Value;
 use crate::reader::driver::*;
 use crate::writer::driver::*;
+use crate::XlsxError;
 use quick_xml::events::BytesStart;
 use quick_xml::Reader;
 use quick_xml::Writer;
@@ -40,9 +41,12 @@ impl Extents {
         &mut self,
         _reader: &mut Reader<R>,
         e: &BytesStart,
-    ) {
-        self.cx.set_value_string(get_attribute(e, b"cx").unwrap());
-        self.cy.set_value_string(get_attribute(e, b"cy").unwrap());
+    ) -> Result<(), XlsxError> {
+        self.cx
+            .try_set_value_string(get_required_attribute(e, b"cx")?, "cx")?;
+        self.cy
+            .try_set_value_string(get_required_attribute(e, b"cy")?, "cy")?;
+        Ok(())
     }
 
     pub(crate) fn write_to(&self, writer: &mut Writer<Cursor<Vec<u8>>>) {
@@ -1,5 +1,6 @@
 // a:fillRect
 use crate::writer::driver::*;
+use crate::XlsxError;
 use quick_xml::events::BytesStart;
 use quick_xml::Reader;
 use quick_xml::Writer;
@@ -58,7 +59,8 @@ impl FillRectangle {
         &mut self,
         _reader: &mut Reader<R>,
         _e: &BytesStart,
-    ) {
+    ) -> Result<(), XlsxError> {
+        Ok(())
     }
 
     #[inline]
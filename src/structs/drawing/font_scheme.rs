@@ -3,6 +3,7 @@ use super::super::StringValue;
 use super::FontCollectionType;
 use crate::reader::driver::*;
 use crate::writer::driver::*;
+use crate::XlsxError;
 use quick_xml::events::{BytesStart, Event};
 use quick_xml::Reader;
 use quick_xml::Writer;
@@ -63,8 +64,8 @@ impl FontScheme {
         &mut self,
         reader: &mut Reader<R>,
         e: &BytesStart,
-    ) {
-        if let Some(v) = get_attribute(e, b"name") {
+    ) -> Result<(), XlsxError> {
+        if let Some(v) = get_attribute(e, b"name")? {
             self.name.set_value(v);
         }
 
@@ -73,20 +74,20 @@ impl FontScheme {
             Event::Start(ref e) => {
                 match e.name().into_inner() {
                 b"a:majorFont" => {
-                    self.major_font.set_attributes(reader, e);
+                    self.major_font.set_attributes(reader, e)?;
                 }
                 b"a:minorFont" => {
-                    self.minor_font.set_attributes(reader, e);
+                    self.minor_font.set_attributes(reader, e)?;
                 }
                 _ => (),
                 }
             },
             Event::End(ref e) => {
                 if e.name().into_inner() == b"a:fontScheme" {
-                    return
+                    return Ok(())
                 }
             },
-            Event::Eof => panic!("Error: Could not find {} end element", "a:fontScheme")
+            Event::Eof => return Err(end_element_error(reader, "a:fontScheme"))
         );
     }
 
@@ -2,6 +2,7 @@
 use super::SchemeColor;
 use crate::reader::driver::*;
 use crate::writer::driver::*;
+use crate::XlsxError;
 use quick_xml::events::{BytesStart, Event};
 use quick_xml::Reader;
 use quick_xml::Writer;
@@ -33,25 +34,25 @@ impl ForegroundColor {
         &mut self,
         reader: &mut Reader<R>,
         _e: &BytesStart,
-    ) {
+    ) -> Result<(), XlsxError> {
         xml_read_loop!(
             reader,
             Event::Start(ref e) => {
                 if e.name().into_inner() == b"a:schemeClr" {
-                    self.scheme_color.set_attributes(reader, e, false);
+                    self.scheme_color.set_attributes(reader, e, false)?;
                 }
             },
             Event::Empty(ref e) => {
                 if e.name().into_inner() == b"a:schemeClr" {
-                    self.scheme_color.set_attributes(reader, e, true);
+                    self.scheme_color.set_attributes(reader, e, true)?;
                 }
             },
             Event::End(ref e) => {
                 if e.name().into_inner() == b"a:fgClr" {
-                    return
+                    return Ok(())
                 }
             },
-            Event::Eof => panic!("Error: Could not find {} end element", "a:fgClr")
+            Event::Eof => return Err(end_element_error(reader, "a:fgClr"))
         );
     }
 
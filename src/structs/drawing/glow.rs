@@ -3,6 +3,7 @@ use super::super::super::Int64Value;
 use super::SchemeColor;
 use crate::reader::driver::*;
 use crate::writer::driver::*;
+use crate::XlsxError;
 use quick_xml::events::{BytesStart, Event};
 use quick_xml::Reader;
 use quick_xml::Writer;
@@ -40,25 +41,25 @@ impl Glow {
         &mut self,
         reader: &mut Reader<R>,
         e: &BytesStart,
-    ) {
+    ) -> Result<(), XlsxError> {
         self.radius
-            .set_value_string(get_attribute(e, b"rad").unwrap());
+            .try_set_value_string(get_required_attribute(e, b"rad")?, "rad")?;
 
         xml_read_loop!(
             reader,
             Event::Start(ref e) => {
                 if e.name().into_inner() == b"a:schemeClr" {
                     let mut obj = SchemeColor::default();
-                    obj.set_attributes(reader, e, false);
+                    obj.set_attributes(reader, e, false)?;
                     self.set_scheme_color(obj);
                 }
             },
             Event::End(ref e) => {
                 if e.name().into_inner() == b"a:glow" {
-                    return;
+                    return Ok(());
                 }
             },
-            Event::Eof => panic!("Error: Could not find {} end element", "a:glow")
+            Event::Eof => return Err(end_element_error(reader, "a:glow"))
         );
     }
 
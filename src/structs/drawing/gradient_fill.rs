@@ -7,6 +7,7 @@ use super::TileFlipValues;
 use super::TileRectangle;
 use crate::reader::driver::*;
 use crate::writer::driver::*;
+use crate::XlsxError;
 use quick_xml::events::{BytesStart, Event};
 use quick_xml::Reader;
 use quick_xml::Writer;
@@ -96,7 +97,7 @@ impl GradientFill {
         &mut self,
         reader: &mut Reader<R>,
         e: &BytesStart,
-    ) {
+    ) -> Result<(), XlsxError> {
         set_string_from_xml!(self, e, flip, "flip");
         set_string_from_xml!(self, e, rotate_with_shape, "rotWithShape");
 
@@ -106,12 +107,12 @@ impl GradientFill {
                 match e.name().into_inner() {
                 b"a:lin" => {
                     let mut obj = LinearGradientFill::default();
-                    obj.set_attributes(reader, e);
+                    obj.set_attributes(reader, e)?;
                     self.set_linear_gradient_fill(obj);
                 }
                 b"a:tileRect" => {
                     let mut obj = TileRectangle::default();
-                    obj.set_attributes(reader, e);
+                    obj.set_attributes(reader, e)?;
                     self.set_tile_rectangle(obj);
                 }
                 _ => (),
@@ -119,15 +120,15 @@ impl GradientFill {
             },
             Event::Start(ref e) => {
                 if e.name().into_inner() == b"a:gsLst" {
-                    self.gradient_stop_list.set_attributes(reader, e);
+                    self.gradient_stop_list.set_attributes(reader, e)?;
                 }
             },
             Event::End(ref e) => {
                 if e.name().into_inner() == b"a:gradFill" {
-                    return
+                    return Ok(())
                 }
             },
-            Event::Eof => panic!("Error: Could not find {} end element", "a:gradFill")
+            Event::Eof => return Err(end_element_error(reader, "a:gradFill"))
         );
     }
 
@@ -3,6 +3,7 @@ use super::RgbColorModelHex;
 use super::SchemeColor;
 use crate::reader::driver::*;
 use crate::writer::driver::*;
+use crate::XlsxError;
 use quick_xml::events::{BytesStart, Event};
 use quick_xml::Reader;
 use quick_xml::Writer;
@@ -63,9 +64,9 @@ impl GradientStop {
         &mut self,
         reader: &mut Reader<R>,
         e: &BytesStart,
-    ) {
-        if let Some(v) = get_attribute(e, b"pos") {
-            self.set_position(v.parse::<i32>().unwrap());
+    ) -> Result<(), XlsxError> {
+        if let Some(v) = get_attribute(e, b"pos")? {
+            self.set_position(parse_value("pos", &v)?);
         }
 
         xml_read_loop!(
@@ -74,12 +75,12 @@ impl GradientStop {
                 match e.name().into_inner() {
                     b"a:schemeClr" => {
                         let mut obj = SchemeColor::default();
-                        obj.set_attributes(reader, e, false);
+                        obj.set_attributes(reader, e, false)?;
                         self.set_scheme_color(obj);
                     }
                     b"a:srgbClr" => {
                         let mut obj = RgbColorModelHex::default();
-                        obj.set_attributes(reader, e, false);
+                        obj.set_attributes(reader, e, false)?;
                         self.set_rgb_color_model_hex(obj);
                     }
                     _ => (),
@@ -89,12 +90,12 @@ impl GradientStop {
                 match e.name().into_inner() {
                     b"a:schemeClr" => {
                         let mut obj = SchemeColor::default();
-                        obj.set_attributes(reader, e, true);
+                        obj.set_attributes(reader, e, true)?;
                         self.set_scheme_color(obj);
                     }
                     b"a:srgbClr" => {
                         let mut obj = RgbColorModelHex::default();
-                        obj.set_attributes(reader, e, true);
+                        obj.set_attributes(reader, e, true)?;
                         self.set_rgb_color_model_hex(obj);
                     }
                     _ => (),
@@ -102,10 +103,10 @@ impl GradientStop {
             },
             Event::End(ref e) => {
                 if e.name().into_inner() == b"a:gs" {
-                    return
+                    return Ok(())
                 }
             },
-            Event::Eof => panic!("Error: Could not find {} end element", "a:gs")
+            Event::Eof => return Err(end_element_error(reader, "a:gs"))
         );
     }
 
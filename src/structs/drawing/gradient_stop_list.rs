@@ -2,6 +2,7 @@
 use super::GradientStop;
 use crate::reader::driver::*;
 use crate::writer::driver::*;
+use crate::XlsxError;
 use quick_xml::events::{BytesStart, Event};
 use quick_xml::Reader;
 use quick_xml::Writer;
@@ -43,22 +44,22 @@ impl GradientStopList {
         &mut self,
         reader: &mut Reader<R>,
         _e: &BytesStart,
-    ) {
+    ) -> Result<(), XlsxError> {
         xml_read_loop!(
             reader,
             Event::Start(ref e) => {
                 if e.name().into_inner() == b"a:gs" {
                     let mut obj = GradientStop::default();
-                    obj.set_attributes(reader, e);
+                    obj.set_attributes(reader, e)?;
                     self.add_gradient_stop(obj);
                 }
             },
             Event::End(ref e) => {
                 if e.name().into_inner() == b"a:gsLst" {
-                    return
+                    return Ok(())
                 }
             },
-            Event::Eof => panic!("Error: Could not find {} end element", "a:gsLst")
+            Event::Eof => return Err(end_element_error(reader, "a:gsLst"))
         );
     }
 
@@ -4,6 +4,7 @@ use crate::reader::driver::*;
 use crate::structs::raw::RawRelationships;
 use crate::traits::AdjustmentCoordinateWithSheet;
 use crate::writer::driver::*;
+use crate::XlsxError;
 use quick_xml::events::{BytesStart, Event};
 use quick_xml::Reader;
 use quick_xml::Writer;
@@ -36,21 +37,21 @@ impl Graphic {
         reader: &mut Reader<R>,
         _e: &BytesStart,
         drawing_relationships: Option<&RawRelationships>,
-    ) {
+    ) -> Result<(), XlsxError> {
         xml_read_loop!(
             reader,
             Event::Start(ref e) => {
                 if e.name().into_inner() == b"a:graphicData" {
                     self.graphic_data
-                        .set_attributes(reader, e, drawing_relationships);
+                        .set_attributes(reader, e, drawing_relationships)?;
                 }
             },
             Event::End(ref e) => {
                 if e.name().into_inner() == b"a:graphic" {
-                    return
+                    return Ok(())
                 }
             },
-            Event::Eof => panic!("Error: Could not find {} end element", "a:graphic")
+            Event::Eof => return Err(end_element_error(reader, "a:graphic"))
         );
     }
 
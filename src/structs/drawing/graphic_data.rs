@@ -1,11 +1,14 @@
 // *:graphicData
+use super::chart_ex;
 use super::charts::ChartSpace;
 use crate::helper::const_str::*;
 use crate::reader::driver::*;
 use crate::reader::xlsx::chart;
+use crate::reader::xlsx::chart_ex as chart_ex_reader;
 use crate::structs::raw::RawRelationships;
 use crate::traits::AdjustmentCoordinateWithSheet;
 use crate::writer::driver::*;
+use crate::XlsxError;
 use quick_xml::events::{BytesStart, Event};
 use quick_xml::Reader;
 use quick_xml::Writer;
@@ -14,6 +17,7 @@ use std::io::Cursor;
 #[derive(Clone, Default, Debug)]
 pub struct GraphicData {
     chart_space: ChartSpace,
+    chart_ex_space: Option<chart_ex::ChartSpace>,
 }
 
 impl GraphicData {
@@ -33,29 +37,60 @@ impl GraphicData {
         self
     }
 
+    /// ChartEx (`cx:`) chart of the frame, for Excel 2016 chart types.
+    #[inline]
+    pub fn get_chart_ex_space(&self) -> Option<&chart_ex::ChartSpace> {
+        self.chart_ex_space.as_ref()
+    }
+
+    #[inline]
+    pub fn get_chart_ex_space_mut(&mut self) -> Option<&mut chart_ex::ChartSpace> {
+        self.chart_ex_space.as_mut()
+    }
+
+    #[inline]
+    pub fn set_chart_ex_space(&mut self, value: chart_ex::ChartSpace) -> &GraphicData {
+        self.chart_ex_space = Some(value);
+        self
+    }
+
     pub(crate) fn set_attributes<R: std::io::BufRead>(
         &mut self,
         reader: &mut Reader<R>,
         _e: &BytesStart,
         drawing_relationships: Option<&RawRelationships>,
-    ) {
+    ) -> Result<(), XlsxError> {
         xml_read_loop!(
             reader,
-            Event::Empty(ref e) => {
-                if e.name().into_inner() == b"c:chart" {
-                    let chart_id = get_attribute(e, b"r:id").unwrap();
+            Event::Empty(ref e) => match e.name().into_inner() {
+                b"c:chart" => {
+                    let chart_id = get_required_attribute(e, b"r:id")?;
+                    let relationship = drawing_relationships
+                        .ok_or_else(|| XlsxError::MissingPart(format!("relationship {}", chart_id)))?
+                        .get_relationship_by_rid(&chart_id)?;
+                    let raw_file = relationship.get_raw_file();
+                    chart::read(raw_file, &mut self.chart_space)
+                        .map_err(|e| e.in_part(raw_file.get_file_target()))?;
+                }
+                b"cx:chart" => {
+                    let chart_id = get_required_attribute(e, b"r:id")?;
                     let relationship = drawing_relationships
-                        .unwrap()
-                        .get_relationship_by_rid(&chart_id);
-                    chart::read(relationship.get_raw_file(), &mut self.chart_space);
+                        .ok_or_else(|| XlsxError::MissingPart(format!("relationship {}", chart_id)))?
+                        .get_relationship_by_rid(&chart_id)?;
+                    let raw_file = relationship.get_raw_file();
+                    let mut obj = chart_ex::ChartSpace::default();
+                    chart_ex_reader::read(raw_file, &mut obj)
+                        .map_err(|e| e.in_part(raw_file.get_file_target()))?;
+                    self.set_chart_ex_space(obj);
                 }
+                _ => (),
             },
             Event::End(ref e) => {
                 if e.name().into_inner() == b"a:graphicData" {
-                    return;
+                    return Ok(());
                 }
             },
-            Event::Eof => panic!("Error: Could not find {} end element", "a:graphicData")
+            Event::Eof => return Err(end_element_error(reader, "a:graphicData"))
         );
     }
 
@@ -64,6 +99,27 @@ impl GraphicData {
         writer: &mut Writer<Cursor<Vec<u8>>>,
         rel_list: &mut Vec<(String, String)>,
     ) {
+        if self.chart_ex_space.is_some() {
+            // a:graphicData
+            write_start_tag(writer, "a:graphicData", vec![("uri", CHARTEX_NS)], false);
+
+            // cx:chart
+            rel_list.push((String::from("CHART_EX"), String::new()));
+            write_start_tag(
+                writer,
+                "cx:chart",
+                vec![
+                    ("xmlns:cx", CHARTEX_NS),
+                    ("xmlns:r", REL_OFC_NS),
+                    ("r:id", format!("rId{}", rel_list.len()).as_str()),
+                ],
+                true,
+            );
+
+            write_end_tag(writer, "a:graphicData");
+            return;
+        }
+
         // a:graphicData
         write_start_tag(
             writer,
@@ -105,6 +161,15 @@ impl AdjustmentCoordinateWithSheet for GraphicData {
             root_row_num,
             offset_row_num,
         );
+        if let Some(v) = &mut self.chart_ex_space {
+            v.adjustment_insert_coordinate_with_sheet(
+                sheet_name,
+                root_col_num,
+                offset_col_num,
+                root_row_num,
+                offset_row_num,
+            );
+        }
     }
 
     #[inline]
@@ -123,5 +188,14 @@ impl AdjustmentCoordinateWithSheet for GraphicData {
             root_row_num,
             offset_row_num,
         );
+        if let Some(v) = &mut self.chart_ex_space {
+            v.adjustment_remove_coordinate_with_sheet(
+                sheet_name,
+                root_col_num,
+                offset_col_num,
+                root_row_num,
+                offset_row_num,
+            );
+        }
     }
 }
@@ -2,6 +2,7 @@
 use crate::reader::driver::*;
 use crate::structs::BooleanValue;
 use crate::writer::driver::*;
+use crate::XlsxError;
 use quick_xml::events::BytesStart;
 use quick_xml::Reader;
 use quick_xml::Writer;
@@ -33,7 +34,7 @@ impl GroupShapeLocks {
         &mut self,
         _reader: &mut Reader<R>,
         e: &BytesStart,
-    ) {
+    ) -> Result<(), XlsxError> {
         set_string_from_xml!(self, e, no_change_aspect, "noChangeAspect");
         set_string_from_xml!(self, e, no_grouping, "noGrp");
         set_string_from_xml!(self, e, no_move, "noMove");
@@ -41,6 +42,7 @@ impl GroupShapeLocks {
         set_string_from_xml!(self, e, no_rotation, "noRot");
         set_string_from_xml!(self, e, no_selection, "noSelect");
         set_string_from_xml!(self, e, no_ungrouping, "noUngrp");
+        Ok(())
     }
 
     pub(crate) fn write_to(&self, writer: &mut Writer<Cursor<Vec<u8>>>) {
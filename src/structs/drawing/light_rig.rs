@@ -5,6 +5,7 @@ use super::LightRigValues;
 use super::Rotation;
 use crate::reader::driver::*;
 use crate::writer::driver::*;
+use crate::XlsxError;
 use quick_xml::events::{BytesStart, Event};
 use quick_xml::Reader;
 use quick_xml::Writer;
@@ -61,12 +62,12 @@ impl LightRig {
         reader: &mut Reader<R>,
         e: &BytesStart,
         empty_flag: bool,
-    ) {
+    ) -> Result<(), XlsxError> {
         set_string_from_xml!(self, e, rig, "rig");
         set_string_from_xml!(self, e, definition, "dir");
 
         if empty_flag {
-            return;
+            return Ok(());
         }
 
         xml_read_loop!(
@@ -74,16 +75,16 @@ impl LightRig {
             Event::Empty(ref e) => {
                 if e.name().into_inner() == b"a:rot" {
                     let mut obj = Rotation::default();
-                    obj.set_attributes(reader, e);
+                    obj.set_attributes(reader, e)?;
                     self.rotation = Some(Box::new(obj));
                 }
             },
             Event::End(ref e) => {
                 if e.name().into_inner() == b"a:lightRig" {
-                    return
+                    return Ok(())
                 }
             },
-            Event::Eof => panic!("Error: Could not find {} end element", "a:lightRig")
+            Event::Eof => return Err(end_element_error(reader, "a:lightRig"))
         );
     }
 
@@ -2,6 +2,7 @@
 use super::SpacingPercent;
 use crate::reader::driver::*;
 use crate::writer::driver::*;
+use crate::XlsxError;
 use quick_xml::events::{BytesStart, Event};
 use quick_xml::Reader;
 use quick_xml::Writer;
@@ -38,22 +39,22 @@ impl LineSpacing {
         &mut self,
         reader: &mut Reader<R>,
         _e: &BytesStart,
-    ) {
+    ) -> Result<(), XlsxError> {
         xml_read_loop!(
             reader,
             Event::Empty(ref e) => {
                 if e.name().into_inner() == b"a:spcPct" {
                     let mut obj = SpacingPercent::default();
-                    obj.set_attributes(reader, e);
+                    obj.set_attributes(reader, e)?;
                     self.set_spacing_percent(obj);
                 }
             },
             Event::End(ref e) => {
                 if e.name().into_inner() == b"a:lnSpc" {
-                    return;
+                    return Ok(());
                 }
             },
-            Event::Eof => panic!("Error: Could not find {} end element", "a:lnSpc")
+            Event::Eof => return Err(end_element_error(reader, "a:lnSpc"))
         );
     }
 
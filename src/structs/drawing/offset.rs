@@ -2,6 +2,7 @@
 use super::super::super::Int64Value;
 use crate::reader::driver::*;
 use crate::writer::driver::*;
+use crate::XlsxError;
 use quick_xml::events::BytesStart;
 use quick_xml::Reader;
 use quick_xml::Writer;
@@ -38,9 +39,12 @@ impl Offset {
         &mut self,
         _reader: &mut Reader<R>,
         e: &BytesStart,
-    ) {
-        self.x.set_value_string(get_attribute(e, b"x").unwrap());
-        self.y.set_value_string(get_attribute(e, b"y").unwrap());
+    ) -> Result<(), XlsxError> {
+        self.x
+            .try_set_value_string(get_required_attribute(e, b"x")?, "x")?;
+        self.y
+            .try_set_value_string(get_required_attribute(e, b"y")?, "y")?;
+        Ok(())
     }
 
     pub(crate) fn write_to(&self, writer: &mut Writer<Cursor<Vec<u8>>>) {
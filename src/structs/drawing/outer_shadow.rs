@@ -5,6 +5,7 @@ use super::SchemeColor;
 use crate::reader::driver::*;
 use crate::writer::driver::*;
 use crate::StringValue;
+use crate::XlsxError;
 use quick_xml::events::{BytesStart, Event};
 use quick_xml::Reader;
 use quick_xml::Writer;
@@ -153,26 +154,26 @@ impl OuterShadow {
         &mut self,
         reader: &mut Reader<R>,
         e: &BytesStart,
-    ) {
-        if let Some(v) = get_attribute(e, b"blurRad") {
+    ) -> Result<(), XlsxError> {
+        if let Some(v) = get_attribute(e, b"blurRad")? {
             self.set_blur_radius(v);
         }
-        if let Some(v) = get_attribute(e, b"dist") {
+        if let Some(v) = get_attribute(e, b"dist")? {
             self.set_distance(v);
         }
-        if let Some(v) = get_attribute(e, b"dir") {
+        if let Some(v) = get_attribute(e, b"dir")? {
             self.set_direction(v);
         }
-        if let Some(v) = get_attribute(e, b"sx") {
+        if let Some(v) = get_attribute(e, b"sx")? {
             self.set_horizontal_ratio(v);
         }
-        if let Some(v) = get_attribute(e, b"sy") {
+        if let Some(v) = get_attribute(e, b"sy")? {
             self.set_vertical_ratio(v);
         }
-        if let Some(v) = get_attribute(e, b"algn") {
+        if let Some(v) = get_attribute(e, b"algn")? {
             self.set_alignment(v);
         }
-        if let Some(v) = get_attribute(e, b"rotWithShape") {
+        if let Some(v) = get_attribute(e, b"rotWithShape")? {
             self.set_rotate_with_shape(v);
         }
 
@@ -182,12 +183,12 @@ impl OuterShadow {
                 match e.name().into_inner() {
                     b"a:schemeClr" => {
                         let mut obj = SchemeColor::default();
-                        obj.set_attributes(reader, e, true);
+                        obj.set_attributes(reader, e, true)?;
                         self.set_scheme_color(obj);
                     }
                     b"a:srgbClr" => {
                         let mut obj = RgbColorModelHex::default();
-                        obj.set_attributes(reader, e, true);
+                        obj.set_attributes(reader, e, true)?;
                         self.set_rgb_color_model_hex(obj);
                     }
                     _ => (),
@@ -197,17 +198,17 @@ impl OuterShadow {
                 match e.name().into_inner() {
                     b"a:prstClr" => {
                         let mut obj = PresetColor::default();
-                        obj.set_attributes(reader, e);
+                        obj.set_attributes(reader, e)?;
                         self.set_preset_color(obj);
                     }
                     b"a:schemeClr" => {
                         let mut obj = SchemeColor::default();
-                        obj.set_attributes(reader, e, false);
+                        obj.set_attributes(reader, e, false)?;
                         self.set_scheme_color(obj);
                     }
                     b"a:srgbClr" => {
                         let mut obj = RgbColorModelHex::default();
-                        obj.set_attributes(reader, e, false);
+                        obj.set_attributes(reader, e, false)?;
                         self.set_rgb_color_model_hex(obj);
                     }
                     _ => (),
@@ -215,10 +216,10 @@ impl OuterShadow {
             },
             Event::End(ref e) => {
                 if e.name().into_inner() == b"a:outerShdw" {
-                    return
+                    return Ok(())
                 }
             },
-            Event::Eof => panic!("Error: Could not find {} end element", "a:outerShdw")
+            Event::Eof => return Err(end_element_error(reader, "a:outerShdw"))
         );
     }
 
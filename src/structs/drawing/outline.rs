@@ -13,6 +13,7 @@ use crate::structs::EnumValue;
 use crate::structs::UInt32Value;
 use crate::writer::driver::*;
 use crate::StringValue;
+use crate::XlsxError;
 use quick_xml::events::{BytesStart, Event};
 use quick_xml::Reader;
 use quick_xml::Writer;
@@ -210,16 +211,16 @@ impl Outline {
         &mut self,
         reader: &mut Reader<R>,
         e: &BytesStart,
-    ) {
-        if let Some(v) = get_attribute(e, b"w") {
-            self.set_width(v.parse::<u32>().unwrap());
+    ) -> Result<(), XlsxError> {
+        if let Some(v) = get_attribute(e, b"w")? {
+            self.set_width(parse_value("w", &v)?);
         }
 
-        if let Some(v) = get_attribute(e, b"cap") {
+        if let Some(v) = get_attribute(e, b"cap")? {
             self.set_cap_type(v);
         }
 
-        if let Some(v) = get_attribute(e, b"cmpd") {
+        if let Some(v) = get_attribute(e, b"cmpd")? {
             self.set_compound_line_type(v);
         }
 
@@ -231,12 +232,12 @@ impl Outline {
                 match e.name().into_inner() {
                     b"a:solidFill" => {
                         let mut solid_fill = SolidFill::default();
-                        solid_fill.set_attributes(reader, e);
+                        solid_fill.set_attributes(reader, e)?;
                         self.set_solid_fill(solid_fill);
                     }
                     b"a:gradFill" => {
                         let mut obj = GradientFill::default();
-                        obj.set_attributes(reader, e);
+                        obj.set_attributes(reader, e)?;
                         self.set_gradient_fill(obj);
                     }
                     _ => (),
@@ -246,32 +247,32 @@ impl Outline {
                 match e.name().into_inner() {
                     b"a:tailEnd" => {
                         let mut obj = TailEnd::default();
-                        obj.set_attributes(reader, e);
+                        obj.set_attributes(reader, e)?;
                         self.set_tail_end(obj);
                     }
                     b"a:noFill" => {
                         let mut obj = NoFill::default();
-                        obj.set_attributes(reader, e);
+                        obj.set_attributes(reader, e)?;
                         self.set_no_fill(obj);
                     }
                     b"a:bevel" => {
                         let mut obj = Bevel::default();
-                        obj.set_attributes(reader, e);
+                        obj.set_attributes(reader, e)?;
                         self.set_bevel(obj);
                     }
                     b"a:miter" => {
                         let mut obj = Miter::default();
-                        obj.set_attributes(reader, e);
+                        obj.set_attributes(reader, e)?;
                         self.set_miter(obj);
                     }
                     b"a:prstDash" => {
                         let mut obj = PresetDash::default();
-                        obj.set_attributes(reader, e);
+                        obj.set_attributes(reader, e)?;
                         self.set_preset_dash(obj);
                     }
                     b"a:round" => {
                         let mut obj = Round::default();
-                        obj.set_attributes(reader, e);
+                        obj.set_attributes(reader, e)?;
                         self.set_round(obj);
                     }
                     _ => (),
@@ -279,10 +280,10 @@ impl Outline {
             },
             Event::End(ref e) => {
                 if  e.name().into_inner() == b"a:ln" {
-                    return;
+                    return Ok(());
                 }
             },
-            Event::Eof => panic!("Error: Could not find {} end element", "a:ln")
+            Event::Eof => return Err(end_element_error(reader, "a:ln"))
         );
     }
 
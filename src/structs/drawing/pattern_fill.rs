@@ -3,6 +3,7 @@ use super::BackgroundColor;
 use super::ForegroundColor;
 use crate::reader::driver::*;
 use crate::writer::driver::*;
+use crate::XlsxError;
 use quick_xml::events::{BytesStart, Event};
 use quick_xml::Reader;
 use quick_xml::Writer;
@@ -74,8 +75,8 @@ impl PatternFill {
         &mut self,
         reader: &mut Reader<R>,
         e: &BytesStart,
-    ) {
-        if let Some(v) = get_attribute(e, b"prst") {
+    ) -> Result<(), XlsxError> {
+        if let Some(v) = get_attribute(e, b"prst")? {
             self.set_preset(v);
         }
 
@@ -84,20 +85,20 @@ impl PatternFill {
             Event::Start(ref e) => {
                 match e.name().into_inner() {
                     b"a:fgClr" => {
-                        self.foreground_color.set_attributes(reader, e);
+                        self.foreground_color.set_attributes(reader, e)?;
                     },
                     b"a:bgClr" => {
-                        self.background_color.set_attributes(reader, e);
+                        self.background_color.set_attributes(reader, e)?;
                     },
                     _ => (),
                 }
             },
             Event::End(ref e) => {
                 if e.name().into_inner() == b"a:pattFill" {
-                    return
+                    return Ok(())
                 }
             },
-            Event::Eof => panic!("Error: Could not find {} end element", "a:pattFill")
+            Event::Eof => return Err(end_element_error(reader, "a:pattFill"))
         );
     }
 
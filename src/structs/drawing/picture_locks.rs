@@ -1,6 +1,7 @@
 // a:picLocks
 use crate::reader::driver::*;
 use crate::writer::driver::*;
+use crate::XlsxError;
 use quick_xml::events::BytesStart;
 use quick_xml::Reader;
 use quick_xml::Writer;
@@ -27,12 +28,13 @@ impl PictureLocks {
         &mut self,
         _reader: &mut Reader<R>,
         e: &BytesStart,
-    ) {
-        if let Some(v) = get_attribute(e, b"noChangeAspect") {
+    ) -> Result<(), XlsxError> {
+        if let Some(v) = get_attribute(e, b"noChangeAspect")? {
             if v == "1" {
                 self.set_no_change_aspect(true);
             }
         }
+        Ok(())
     }
 
     #[inline]
@@ -3,6 +3,7 @@
 use crate::reader::driver::*;
 use crate::structs::Int64Value;
 use crate::writer::driver::*;
+use crate::XlsxError;
 use quick_xml::events::{BytesStart, Event};
 use quick_xml::Reader;
 use quick_xml::Writer;
@@ -40,9 +41,10 @@ impl Point2DType {
         &mut self,
         _reader: &mut Reader<R>,
         e: &BytesStart,
-    ) {
+    ) -> Result<(), XlsxError> {
         set_string_from_xml!(self, e, x, "x");
         set_string_from_xml!(self, e, y, "y");
+        Ok(())
     }
 
     #[inline]
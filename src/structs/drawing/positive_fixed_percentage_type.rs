@@ -1,6 +1,7 @@
 use super::super::Int32Value;
 use crate::reader::driver::*;
 use crate::writer::driver::*;
+use crate::XlsxError;
 use quick_xml::events::BytesStart;
 use quick_xml::Reader;
 use quick_xml::Writer;
@@ -28,8 +29,9 @@ impl PositiveFixedPercentageType {
         &mut self,
         _reader: &mut Reader<R>,
         e: &BytesStart,
-    ) {
+    ) -> Result<(), XlsxError> {
         set_string_from_xml!(self, e, val, "val");
+        Ok(())
     }
 
     #[inline]
@@ -2,6 +2,7 @@
 use super::alpha::Alpha;
 use crate::reader::driver::*;
 use crate::writer::driver::*;
+use crate::XlsxError;
 use quick_xml::events::{BytesStart, Event};
 use quick_xml::Reader;
 use quick_xml::Writer;
@@ -43,24 +44,24 @@ impl PresetColor {
         &mut self,
         reader: &mut Reader<R>,
         e: &BytesStart,
-    ) {
-        self.set_val(get_attribute(e, b"val").unwrap());
+    ) -> Result<(), XlsxError> {
+        self.set_val(get_required_attribute(e, b"val")?);
 
         xml_read_loop!(
             reader,
             Event::Empty(ref e) => {
                 if e.name().into_inner() == b"a:alpha" {
                     let mut alpha = Alpha::default();
-                    alpha.set_attributes(reader, e);
+                    alpha.set_attributes(reader, e)?;
                     self.set_alpha(alpha);
                 }
             },
             Event::End(ref e) => {
                 if e.name().into_inner() == b"a:prstClr" {
-                    return
+                    return Ok(())
                 }
             },
-            Event::Eof => panic!("Error: Could not find {} end element", "a:prstClr")
+            Event::Eof => return Err(end_element_error(reader, "a:prstClr"))
         );
     }
 
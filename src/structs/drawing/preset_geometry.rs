@@ -2,6 +2,7 @@
 use super::adjust_value_list::AdjustValueList;
 use crate::reader::driver::*;
 use crate::writer::driver::*;
+use crate::XlsxError;
 use quick_xml::events::{BytesStart, Event};
 use quick_xml::Reader;
 use quick_xml::Writer;
@@ -232,22 +233,22 @@ impl PresetGeometry {
         &mut self,
         reader: &mut Reader<R>,
         e: &BytesStart,
-    ) {
-        self.set_geometry(get_attribute(e, b"prst").unwrap());
+    ) -> Result<(), XlsxError> {
+        self.set_geometry(get_required_attribute(e, b"prst")?);
 
         xml_read_loop!(
             reader,
             Event::Start(ref e) => {
                 if e.name().into_inner() == b"a:avLst" {
-                    self.get_adjust_value_list_mut().set_attributes(reader, e);
+                    self.get_adjust_value_list_mut().set_attributes(reader, e)?;
                 }
             },
             Event::End(ref e) => {
                 if e.name().into_inner() == b"a:prstGeom" {
-                    return;
+                    return Ok(());
                 }
             },
-            Event::Eof => panic!("Error: Could not find {} end element", "a:prstGeom")
+            Event::Eof => return Err(end_element_error(reader, "a:prstGeom"))
         );
     }
 
@@ -2,6 +2,7 @@
 use super::super::Int32Value;
 use crate::reader::driver::*;
 use crate::writer::driver::*;
+use crate::XlsxError;
 use quick_xml::events::BytesStart;
 use quick_xml::Reader;
 use quick_xml::Writer;
@@ -53,10 +54,11 @@ impl Rotation {
         &mut self,
         _reader: &mut Reader<R>,
         e: &BytesStart,
-    ) {
+    ) -> Result<(), XlsxError> {
         set_string_from_xml!(self, e, latitude, "lat");
         set_string_from_xml!(self, e, longitude, "lon");
         set_string_from_xml!(self, e, revolution, "rev");
+        Ok(())
     }
 
     pub(crate) fn write_to(&self, writer: &mut Writer<Cursor<Vec<u8>>>) {
@@ -1,6 +1,7 @@
 use super::run_properties::RunProperties;
 use crate::reader::driver::*;
 use crate::writer::driver::*;
+use crate::XlsxError;
 use quick_xml::events::{BytesStart, Event};
 use quick_xml::Reader;
 use quick_xml::Writer;
@@ -42,28 +43,28 @@ impl Run {
         &mut self,
         reader: &mut Reader<R>,
         _e: &BytesStart,
-    ) {
+    ) -> Result<(), XlsxError> {
         xml_read_loop!(
             reader,
             Event::Start(ref e) => {
                 if e.name().0 == b"a:rPr" {
-                    self.run_properties.set_attributes(reader, e, false);
+                    self.run_properties.set_attributes(reader, e, false)?;
                 }
             },
             Event::Empty(ref e) => {
                 if e.name().0 == b"a:rPr" {
-                    self.run_properties.set_attributes(reader, e, true);
+                    self.run_properties.set_attributes(reader, e, true)?;
                 }
             },
             Event::Text(e) => {
-                self.set_text(e.unescape().unwrap());
+                self.set_text(e.unescape()?);
             },
             Event::End(ref e) => {
                 if e.name().0 == b"a:r" {
-                    return;
+                    return Ok(());
                 }
             },
-            Event::Eof => panic!("Error: Could not find {} end element", "a:r")
+            Event::Eof => return Err(end_element_error(reader, "a:r"))
         );
     }
 
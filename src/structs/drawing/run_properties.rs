@@ -10,6 +10,7 @@ use super::TextFontType;
 use crate::reader::driver::*;
 use crate::structs::StringValue;
 use crate::writer::driver::*;
+use crate::XlsxError;
 use quick_xml::events::{BytesStart, Event};
 use quick_xml::Reader;
 use quick_xml::Writer;
@@ -264,37 +265,33 @@ impl RunProperties {
         reader: &mut Reader<R>,
         e: &BytesStart,
         empty_flag: bool,
-    ) {
-        if let Some(v) = get_attribute(e, b"kumimoji") {
+    ) -> Result<(), XlsxError> {
+        if let Some(v) = get_attribute(e, b"kumimoji")? {
             self.set_kumimoji(v);
         }
-        if let Some(v) = get_attribute(e, b"lang") {
+        if let Some(v) = get_attribute(e, b"lang")? {
             self.set_language(v);
         }
-        if let Some(v) = get_attribute(e, b"altLang") {
+        if let Some(v) = get_attribute(e, b"altLang")? {
             self.set_alternative_language(v);
         }
-        if let Some(v) = get_attribute(e, b"b") {
+        if let Some(v) = get_attribute(e, b"b")? {
             self.set_bold(v);
         }
-        if let Some(v) = get_attribute(e, b"sz") {
+        if let Some(v) = get_attribute(e, b"sz")? {
             self.set_sz(v);
         }
-        if let Some(v) = get_attribute(e, b"strike") {
+        if let Some(v) = get_attribute(e, b"strike")? {
             self.set_strike(v);
         }
-        if let Some(v) = get_attribute(e, b"i") {
+        if let Some(v) = get_attribute(e, b"i")? {
             self.set_italic(v);
         }
-        if let Some(v) = get_attribute(e, b"cap") {
-            self.capital.set_value_string(v);
-        }
-        if let Some(v) = get_attribute(e, b"spc") {
-            self.spacing.set_value_string(v);
-        }
+        set_string_from_xml!(self, e, capital, "cap");
+        set_string_from_xml!(self, e, spacing, "spc");
 
         if empty_flag {
-            return;
+            return Ok(());
         }
 
         xml_read_loop!(
@@ -303,22 +300,22 @@ impl RunProperties {
                 match e.name().into_inner() {
                 b"a:solidFill" => {
                     let mut obj = SolidFill::default();
-                    obj.set_attributes(reader, e);
+                    obj.set_attributes(reader, e)?;
                     self.set_solid_fill(obj);
                 }
                 b"a:ln" => {
                     let mut obj = Outline::default();
-                    obj.set_attributes(reader, e);
+                    obj.set_attributes(reader, e)?;
                     self.set_outline(obj);
                 }
                 b"a:gradFill" => {
                     let mut obj = GradientFill::default();
-                    obj.set_attributes(reader, e);
+                    obj.set_attributes(reader, e)?;
                     self.set_gradient_fill(obj);
                 }
                 b"a:effectLst" => {
                     let mut effect_list = EffectList::default();
-                    effect_list.set_attributes(reader, e, false);
+                    effect_list.set_attributes(reader, e, false)?;
                     self.set_effect_list(effect_list);
                 }
                 _ => (),
@@ -328,22 +325,22 @@ impl RunProperties {
                 match e.name().into_inner() {
                 b"a:latin" => {
                     let mut obj = TextFontType::default();
-                    obj.set_attributes(reader, e);
+                    obj.set_attributes(reader, e)?;
                     self.set_latin_font(obj);
                 }
                 b"a:ea" => {
                     let mut obj = TextFontType::default();
-                    obj.set_attributes(reader, e);
+                    obj.set_attributes(reader, e)?;
                     self.set_east_asian_font(obj);
                 }
                 b"a:noFill" => {
                     let mut obj = NoFill::default();
-                    obj.set_attributes(reader, e);
+                    obj.set_attributes(reader, e)?;
                     self.set_no_fill(obj);
                 }
                 b"a:effectLst" => {
                     let mut obj = EffectList::default();
-                    obj.set_attributes(reader, e, true);
+                    obj.set_attributes(reader, e, true)?;
                     self.set_effect_list(obj);
                 }
                 _ => (),
@@ -351,16 +348,13 @@ impl RunProperties {
             },
             Event::End(ref e) => {
                 match e.name().into_inner() {
-                b"a:rPr" => return,
-                b"a:endParaRPr" => return,
-                b"a:defRPr" => return,
+                b"a:rPr" => return Ok(()),
+                b"a:endParaRPr" => return Ok(()),
+                b"a:defRPr" => return Ok(()),
                 _ => (),
                 }
             },
-            Event::Eof => panic!(
-                "Error: Could not find {} end element",
-                "a:rPr, a:endParaRPr, a:defRPr"
-            )
+            Event::Eof => return Err(end_element_error(reader, "a:rPr, a:endParaRPr, a:defRPr"))
         );
     }
 
@@ -3,6 +3,7 @@ use super::Camera;
 use super::LightRig;
 use crate::reader::driver::*;
 use crate::writer::driver::*;
+use crate::XlsxError;
 use quick_xml::events::{BytesStart, Event};
 use quick_xml::Reader;
 use quick_xml::Writer;
@@ -41,19 +42,19 @@ impl Scene3DType {
         &mut self,
         reader: &mut Reader<R>,
         _e: &BytesStart,
-    ) {
+    ) -> Result<(), XlsxError> {
         xml_read_loop!(
             reader,
             Event::Empty(ref e) => {
                 match e.name().into_inner() {
                     b"a:camera" => {
                         let mut obj = Camera::default();
-                        obj.set_attributes(reader, e, true);
+                        obj.set_attributes(reader, e, true)?;
                         self.set_camera(obj);
                     }
                     b"a:lightRig" => {
                         let mut obj = LightRig::default();
-                        obj.set_attributes(reader, e, true);
+                        obj.set_attributes(reader, e, true)?;
                         self.set_light_rig(obj);
                     }
                     _ => (),
@@ -63,12 +64,12 @@ impl Scene3DType {
                 match e.name().into_inner() {
                     b"a:camera" => {
                         let mut obj = Camera::default();
-                        obj.set_attributes(reader, e, false);
+                        obj.set_attributes(reader, e, false)?;
                         self.set_camera(obj);
                     }
                     b"a:lightRig" => {
                         let mut obj = LightRig::default();
-                        obj.set_attributes(reader, e, false);
+                        obj.set_attributes(reader, e, false)?;
                         self.set_light_rig(obj);
                     }
                     _ => (),
@@ -76,10 +77,10 @@ impl Scene3DType {
             },
             Event::End(ref e) => {
                 if e.name().into_inner() == b"a:scene3d" {
-                    return;
+                    return Ok(());
                 }
             },
-            Event::Eof => panic!("Error: Could not find {} end element", "a:scene3d")
+            Event::Eof => return Err(end_element_error(reader, "a:scene3d"))
         );
     }
 
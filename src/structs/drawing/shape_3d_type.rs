@@ -5,6 +5,7 @@ use super::BevelTop;
 use super::PresetMaterialTypeValues;
 use crate::reader::driver::*;
 use crate::writer::driver::*;
+use crate::XlsxError;
 use quick_xml::events::{BytesStart, Event};
 use quick_xml::Reader;
 use quick_xml::Writer;
@@ -63,7 +64,7 @@ impl Shape3DType {
         &mut self,
         reader: &mut Reader<R>,
         e: &BytesStart,
-    ) {
+    ) -> Result<(), XlsxError> {
         set_string_from_xml!(self, e, preset_material, "prstMaterial");
 
         xml_read_loop!(
@@ -72,12 +73,12 @@ impl Shape3DType {
                 match e.name().into_inner() {
                     b"a:bevelT" => {
                         let mut obj = BevelTop::default();
-                        obj.set_attributes(reader, e);
+                        obj.set_attributes(reader, e)?;
                         self.set_bevel_top(obj);
                     }
                     b"a:bevelB" => {
                         let mut obj = BevelBottom::default();
-                        obj.set_attributes(reader, e);
+                        obj.set_attributes(reader, e)?;
                         self.set_bevel_bottom(obj);
                     }
                     _ => (),
@@ -85,10 +86,10 @@ impl Shape3DType {
             },
             Event::End(ref e) => {
                 if e.name().into_inner() == b"a:sp3d" {
-                    return
+                    return Ok(())
                 }
             },
-            Event::Eof => panic!("Error: Could not find {} end element", "a:sp3d")
+            Event::Eof => return Err(end_element_error(reader, "a:sp3d"))
         );
     }
 
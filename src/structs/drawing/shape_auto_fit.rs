@@ -1,5 +1,6 @@
 // a:spAutoFit
 use crate::writer::driver::*;
+use crate::XlsxError;
 use quick_xml::events::BytesStart;
 use quick_xml::Reader;
 use quick_xml::Writer;
@@ -13,7 +14,8 @@ impl ShapeAutoFit {
         &mut self,
         _reader: &mut Reader<R>,
         _: &BytesStart,
-    ) {
+    ) -> Result<(), XlsxError> {
+        Ok(())
     }
 
     #[inline]
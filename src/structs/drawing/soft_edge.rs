@@ -2,6 +2,7 @@
 use super::super::super::Int64Value;
 use crate::reader::driver::*;
 use crate::writer::driver::*;
+use crate::XlsxError;
 use quick_xml::events::BytesStart;
 use quick_xml::Reader;
 use quick_xml::Writer;
@@ -28,9 +29,10 @@ impl SoftEdge {
         &mut self,
         _reader: &mut Reader<R>,
         e: &BytesStart,
-    ) {
+    ) -> Result<(), XlsxError> {
         self.radius
-            .set_value_string(get_attribute(e, b"rad").unwrap());
+            .try_set_value_string(get_required_attribute(e, b"rad")?, "rad")?;
+        Ok(())
     }
 
     pub(crate) fn write_to(&self, writer: &mut Writer<Cursor<Vec<u8>>>) {
@@ -3,6 +3,7 @@ use super::rgb_color_model_hex::RgbColorModelHex;
 use super::scheme_color::SchemeColor;
 use crate::reader::driver::*;
 use crate::writer::driver::*;
+use crate::XlsxError;
 use quick_xml::events::{BytesStart, Event};
 use quick_xml::Reader;
 use quick_xml::Writer;
@@ -49,19 +50,19 @@ impl SolidFill {
         &mut self,
         reader: &mut Reader<R>,
         _e: &BytesStart,
-    ) {
+    ) -> Result<(), XlsxError> {
         xml_read_loop!(
             reader,
             Event::Start(ref e) => {
                 match e.name().into_inner() {
                 b"a:schemeClr" => {
                     let mut scheme_color = SchemeColor::default();
-                    scheme_color.set_attributes(reader, e, false);
+                    scheme_color.set_attributes(reader, e, false)?;
                     self.set_scheme_color(scheme_color);
                 }
                 b"a:srgbClr" => {
                     let mut rgb_color_model_hex = RgbColorModelHex::default();
-                    rgb_color_model_hex.set_attributes(reader, e, false);
+                    rgb_color_model_hex.set_attributes(reader, e, false)?;
                     self.set_rgb_color_model_hex(rgb_color_model_hex);
                 }
                 _ => (),
@@ -71,12 +72,12 @@ impl SolidFill {
                 match e.name().into_inner() {
                 b"a:schemeClr" => {
                     let mut scheme_color = SchemeColor::default();
-                    scheme_color.set_attributes(reader, e, true);
+                    scheme_color.set_attributes(reader, e, true)?;
                     self.set_scheme_color(scheme_color);
                 }
                 b"a:srgbClr" => {
                     let mut rgb_color_model_hex = RgbColorModelHex::default();
-                    rgb_color_model_hex.set_attributes(reader, e, true);
+                    rgb_color_model_hex.set_attributes(reader, e, true)?;
                     self.set_rgb_color_model_hex(rgb_color_model_hex);
                 }
                 _ => (),
@@ -84,10 +85,10 @@ impl SolidFill {
             },
             Event::End(ref e) => {
                 if e.name().into_inner() == b"a:solidFill" {
-                    return;
+                    return Ok(());
                 }
             },
-            Event::Eof => panic!("Error: Could not find {} end element", "a:solidFill")
+            Event::Eof => return Err(end_element_error(reader, "a:solidFill"))
         );
     }
 
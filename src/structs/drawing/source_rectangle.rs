@@ -2,6 +2,7 @@
 use crate::reader::driver::*;
 use crate::writer::driver::*;
 use crate::StringValue;
+use crate::XlsxError;
 use quick_xml::events::BytesStart;
 use quick_xml::Reader;
 use quick_xml::Writer;
@@ -59,18 +60,19 @@ impl SourceRectangle {
         &mut self,
         _reader: &mut Reader<R>,
         e: &BytesStart,
-    ) {
+    ) -> Result<(), XlsxError> {
         for a in e.attributes().with_checks(false) {
             if let Ok(attr) = a {
                 match attr.key.0 {
-                    b"t" => self.set_t(get_attribute_value(&attr).unwrap()),
-                    b"l" => self.set_l(get_attribute_value(&attr).unwrap()),
-                    b"r" => self.set_r(get_attribute_value(&attr).unwrap()),
-                    b"b" => self.set_b(get_attribute_value(&attr).unwrap()),
+                    b"t" => self.set_t(get_attribute_value(&attr)?),
+                    b"l" => self.set_l(get_attribute_value(&attr)?),
+                    b"r" => self.set_r(get_attribute_value(&attr)?),
+                    b"b" => self.set_b(get_attribute_value(&attr)?),
                     _ => {}
                 }
             }
         }
+        Ok(())
     }
 
     pub(crate) fn write_to(&self, writer: &mut Writer<Cursor<Vec<u8>>>) {
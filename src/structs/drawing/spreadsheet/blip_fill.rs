@@ -6,6 +6,7 @@ use super::super::Stretch;
 use crate::reader::driver::*;
 use crate::structs::raw::RawRelationships;
 use crate::writer::driver::*;
+use crate::XlsxError;
 use quick_xml::events::{BytesStart, Event};
 use quick_xml::Reader;
 use quick_xml::Writer;
@@ -84,7 +85,7 @@ impl BlipFill {
         reader: &mut Reader<R>,
         e: &BytesStart,
         drawing_relationships: Option<&RawRelationships>,
-    ) {
+    ) -> Result<(), XlsxError> {
         set_string_from_xml!(self, e, rotate_with_shape, "rotWithShape");
 
         xml_read_loop!(
@@ -93,10 +94,10 @@ impl BlipFill {
                 match e.name().into_inner() {
                     b"a:blip" => {
                         self.blip
-                            .set_attributes(reader, e, drawing_relationships.unwrap(), false);
+                            .set_attributes(reader, e, drawing_relationships.ok_or_else(|| XlsxError::MissingPart("drawing relationships".into()))?, false)?;
                         }
                     b"a:stretch" => {
-                        self.stretch.set_attributes(reader, e);
+                        self.stretch.set_attributes(reader, e)?;
                     }
                     _ => (),
                 }
@@ -105,11 +106,11 @@ impl BlipFill {
                 match e.name().into_inner() {
                     b"a:blip" => {
                         self.blip
-                            .set_attributes(reader, e, drawing_relationships.unwrap(), true);
+                            .set_attributes(reader, e, drawing_relationships.ok_or_else(|| XlsxError::MissingPart("drawing relationships".into()))?, true)?;
                         }
                     b"a:srcRect" => {
                         let mut source_rectangle = SourceRectangle::default();
-                        source_rectangle.set_attributes(reader, e);
+                        source_rectangle.set_attributes(reader, e)?;
                         self.set_source_rectangle(source_rectangle);
                     }
                     _ => (),
@@ -117,10 +118,10 @@ impl BlipFill {
             },
             Event::End(ref e) => {
                 if e.name().into_inner() == b"xdr:blipFill" {
-                    return;
+                    return Ok(());
                 }
             },
-            Event::Eof => panic!("Error: Could not find {} end element", "xdr:blipFill")
+            Event::Eof => return Err(end_element_error(reader, "xdr:blipFill"))
         );
     }
 
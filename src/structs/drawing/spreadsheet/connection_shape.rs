@@ -6,6 +6,7 @@ use super::ShapeStyle;
 use crate::reader::driver::*;
 use crate::structs::raw::RawRelationships;
 use crate::writer::driver::*;
+use crate::XlsxError;
 use quick_xml::events::{BytesStart, Event};
 use quick_xml::Reader;
 use quick_xml::Writer;
@@ -92,30 +93,30 @@ impl ConnectionShape {
         reader: &mut Reader<R>,
         _e: &BytesStart,
         drawing_relationships: Option<&RawRelationships>,
-    ) {
+    ) -> Result<(), XlsxError> {
         xml_read_loop!(
             reader,
             Event::Start(ref e) => {
                 match e.name().into_inner() {
                     b"xdr:nvCxnSpPr" => {
                         self.non_visual_connection_shape_properties
-                            .set_attributes(reader, e);
+                            .set_attributes(reader, e)?;
                         }
                     b"xdr:spPr" => {
-                        self.shape_properties.set_attributes(reader, e, drawing_relationships);
+                        self.shape_properties.set_attributes(reader, e, drawing_relationships)?;
                     }
                     b"xdr:style" => {
-                        self.shape_style.set_attributes(reader, e);
+                        self.shape_style.set_attributes(reader, e)?;
                     }
                     _ => (),
                 }
             },
             Event::End(ref e) => {
                 if e.name().into_inner() == b"xdr:cxnSp" {
-                    return;
+                    return Ok(());
                 }
             },
-            Event::Eof => panic!("Error: Could not find {} end element", "xdr:cxnSp")
+            Event::Eof => return Err(end_element_error(reader, "xdr:cxnSp"))
         );
     }
 
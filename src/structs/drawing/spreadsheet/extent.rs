@@ -2,6 +2,7 @@
 use super::super::super::Int64Value;
 use crate::reader::driver::*;
 use crate::writer::driver::*;
+use crate::XlsxError;
 use quick_xml::events::BytesStart;
 use quick_xml::Reader;
 use quick_xml::Writer;
@@ -41,9 +42,10 @@ impl Extent {
         &mut self,
         _reader: &mut Reader<R>,
         e: &BytesStart,
-    ) {
+    ) -> Result<(), XlsxError> {
         set_string_from_xml!(self, e, cx, "cx");
         set_string_from_xml!(self, e, cy, "cy");
+        Ok(())
     }
 
     #[inline]
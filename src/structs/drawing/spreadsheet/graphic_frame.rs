@@ -7,6 +7,7 @@ use crate::reader::driver::*;
 use crate::structs::raw::RawRelationships;
 use crate::traits::AdjustmentCoordinateWithSheet;
 use crate::writer::driver::*;
+use crate::XlsxError;
 use quick_xml::events::{BytesStart, Event};
 use quick_xml::Reader;
 use quick_xml::Writer;
@@ -90,7 +91,7 @@ impl GraphicFrame {
         reader: &mut Reader<R>,
         e: &BytesStart,
         drawing_relationships: Option<&RawRelationships>,
-    ) {
+    ) -> Result<(), XlsxError> {
         set_string_from_xml!(self, e, r#macro, "macro");
 
         xml_read_loop!(
@@ -99,24 +100,24 @@ impl GraphicFrame {
                 match e.name().into_inner() {
                     b"xdr:nvGraphicFramePr" => {
                         self.non_visual_graphic_frame_properties
-                            .set_attributes(reader, e);
+                            .set_attributes(reader, e)?;
                         }
                     b"xdr:xfrm" => {
-                        self.transform.set_attributes(reader, e);
+                        self.transform.set_attributes(reader, e)?;
                     }
                     b"a:graphic" => {
                         self.graphic
-                            .set_attributes(reader, e, drawing_relationships);
+                            .set_attributes(reader, e, drawing_relationships)?;
                         }
                     _ => (),
                 }
             },
             Event::End(ref e) => {
                 if  e.name().into_inner() == b"xdr:graphicFrame" {
-                    return
+                    return Ok(())
                 }
             },
-            Event::Eof => panic!("Error: Could not find {} end element", "xdr:graphicFrame")
+            Event::Eof => return Err(end_element_error(reader, "xdr:graphicFrame"))
         );
     }
 
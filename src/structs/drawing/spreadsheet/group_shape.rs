@@ -6,6 +6,7 @@ use super::Shape;
 use crate::reader::driver::*;
 use crate::structs::raw::RawRelationships;
 use crate::writer::driver::*;
+use crate::XlsxError;
 use quick_xml::events::{BytesStart, Event};
 use quick_xml::Reader;
 use quick_xml::Writer;
@@ -88,25 +89,25 @@ impl GroupShape {
         reader: &mut Reader<R>,
         _e: &BytesStart,
         drawing_relationships: Option<&RawRelationships>,
-    ) {
+    ) -> Result<(), XlsxError> {
         xml_read_loop!(
             reader,
             Event::Start(ref e) => {
                 match e.name().into_inner() {
                     b"xdr:nvGrpSpPr" => {
-                        self.non_visual_group_shape_properties.set_attributes(reader, e);
+                        self.non_visual_group_shape_properties.set_attributes(reader, e)?;
                     }
                     b"xdr:grpSpPr" => {
-                        self.group_shape_properties.set_attributes(reader, e);
+                        self.group_shape_properties.set_attributes(reader, e)?;
                     }
                     b"xdr:pic" => {
                         let mut obj = Picture::default();
-                        obj.set_attributes(reader, e, drawing_relationships);
+                        obj.set_attributes(reader, e, drawing_relationships)?;
                         self.add_picture_collection(obj);
                     }
                     b"xdr:sp" => {
                         let mut obj = Shape::default();
-                        obj.set_attributes(reader, e, drawing_relationships);
+                        obj.set_attributes(reader, e, drawing_relationships)?;
                         self.add_shape_collection(obj);
                     }
                     _ => (),
@@ -114,10 +115,10 @@ impl GroupShape {
             },
             Event::End(ref e) => {
                 if e.name().into_inner() == b"xdr:grpSp" {
-                    return;
+                    return Ok(());
                 }
             },
-            Event::Eof => panic!("Error: Could not find {} end element", "xdr:grpSp")
+            Event::Eof => return Err(end_element_error(reader, "xdr:grpSp"))
         );
     }
 
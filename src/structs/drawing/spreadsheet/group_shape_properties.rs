@@ -2,6 +2,7 @@
 use super::super::Transform2D;
 use crate::reader::driver::*;
 use crate::writer::driver::*;
+use crate::XlsxError;
 use quick_xml::events::{BytesStart, Event};
 use quick_xml::Reader;
 use quick_xml::Writer;
@@ -33,14 +34,14 @@ impl GroupShapeProperties {
         &mut self,
         reader: &mut Reader<R>,
         _e: &BytesStart,
-    ) {
+    ) -> Result<(), XlsxError> {
         xml_read_loop!(
             reader,
             Event::Start(ref e) => {
                 match e.name().0 {
                     b"a:xfrm" => {
                         let mut obj = Transform2D::default();
-                        obj.set_attributes(reader, e);
+                        obj.set_attributes(reader, e)?;
                         self.set_transform2d(obj);
                     }
                     _ => (),
@@ -48,10 +49,10 @@ impl GroupShapeProperties {
             },
             Event::End(ref e) => {
                 if e.name().0 == b"xdr:grpSpPr" {
-                    return;
+                    return Ok(());
                 }
             },
-            Event::Eof => panic!("Error: Could not find {} end element", "xdr:grpSpPr")
+            Event::Eof => return Err(end_element_error(reader, "xdr:grpSpPr"))
         );
     }
 
@@ -1,7 +1,9 @@
 // xdr:from,xdr:to
 use crate::helper::coordinate::*;
+use crate::reader::driver::*;
 use crate::traits::AdjustmentCoordinate;
 use crate::writer::driver::*;
+use crate::XlsxError;
 use quick_xml::events::{BytesStart, Event};
 use quick_xml::Reader;
 use quick_xml::Writer;
@@ -87,31 +89,31 @@ impl MarkerType {
         &mut self,
         reader: &mut Reader<R>,
         _e: &BytesStart,
-    ) {
+    ) -> Result<(), XlsxError> {
         let mut string_value: String = String::new();
         let mut buf = Vec::new();
         loop {
             match reader.read_event_into(&mut buf) {
-                Ok(Event::Text(e)) => string_value = e.unescape().unwrap().to_string(),
+                Ok(Event::Text(e)) => string_value = e.unescape()?.to_string(),
                 Ok(Event::End(ref e)) => match e.name().into_inner() {
                     b"xdr:col" => {
-                        self.col = string_value.parse::<u32>().unwrap();
+                        self.col = parse_value("xdr:col", &string_value)?;
                     }
                     b"xdr:colOff" => {
-                        self.col_off = string_value.parse::<i32>().unwrap();
+                        self.col_off = parse_value("xdr:colOff", &string_value)?;
                     }
                     b"xdr:row" => {
-                        self.row = string_value.parse::<u32>().unwrap();
+                        self.row = parse_value("xdr:row", &string_value)?;
                     }
                     b"xdr:rowOff" => {
-                        self.row_off = string_value.parse::<i32>().unwrap();
+                        self.row_off = parse_value("xdr:rowOff", &string_value)?;
                     }
-                    b"xdr:from" => return,
-                    b"xdr:to" => return,
+                    b"xdr:from" => return Ok(()),
+                    b"xdr:to" => return Ok(()),
                     _ => (),
                 },
-                Ok(Event::Eof) => panic!("Error: Could not find {} end element", "xdr:from,xdr:to"),
-                Err(e) => panic!("Error at position {}: {:?}", reader.buffer_position(), e),
+                Ok(Event::Eof) => return Err(end_element_error(reader, "xdr:from,xdr:to")),
+                Err(e) => return Err(xml_error(reader, e)),
                 _ => (),
             }
             buf.clear();
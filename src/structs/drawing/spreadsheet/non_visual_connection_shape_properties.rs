@@ -3,6 +3,7 @@ use super::NonVisualConnectorShapeDrawingProperties;
 use super::NonVisualDrawingProperties;
 use crate::reader::driver::*;
 use crate::writer::driver::*;
+use crate::XlsxError;
 use quick_xml::events::{BytesStart, Event};
 use quick_xml::Reader;
 use quick_xml::Writer;
@@ -61,27 +62,27 @@ impl NonVisualConnectionShapeProperties {
         &mut self,
         reader: &mut Reader<R>,
         _e: &BytesStart,
-    ) {
+    ) -> Result<(), XlsxError> {
         xml_read_loop!(
             reader,
             Event::Start(ref e) => {
                 if e.name().into_inner() == b"xdr:cNvCxnSpPr" {
                     self.non_visual_connector_shape_drawing_properties
-                        .set_attributes(reader, e);
+                        .set_attributes(reader, e)?;
                 }
             },
             Event::Empty(ref e) => {
                 if e.name().into_inner() == b"xdr:cNvPr" {
                     self.non_visual_drawing_properties
-                        .set_attributes(reader, e, true);
+                        .set_attributes(reader, e, true)?;
                 }
             },
             Event::End(ref e) => {
                 if e.name().into_inner() == b"xdr:nvCxnSpPr" {
-                    return
+                    return Ok(())
                 }
             },
-            Event::Eof => panic!("Error: Could not find {} end element", "xdr:nvCxnSpPr")
+            Event::Eof => return Err(end_element_error(reader, "xdr:nvCxnSpPr"))
         );
     }
 
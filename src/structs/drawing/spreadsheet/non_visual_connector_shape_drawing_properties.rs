@@ -3,6 +3,7 @@ use super::super::EndConnection;
 use super::super::StartConnection;
 use crate::reader::driver::*;
 use crate::writer::driver::*;
+use crate::XlsxError;
 use quick_xml::events::{BytesStart, Event};
 use quick_xml::Reader;
 use quick_xml::Writer;
@@ -49,19 +50,19 @@ impl NonVisualConnectorShapeDrawingProperties {
         &mut self,
         reader: &mut Reader<R>,
         _e: &BytesStart,
-    ) {
+    ) -> Result<(), XlsxError> {
         xml_read_loop!(
             reader,
             Event::Empty(ref e) => {
                 match e.name().into_inner() {
                     b"a:stCxn" => {
                         let mut connection_type = StartConnection::default();
-                        connection_type.set_attributes(reader, e);
+                        connection_type.set_attributes(reader, e)?;
                         self.set_start_connection(connection_type);
                     }
                     b"a:endCxn" => {
                         let mut connection_type = EndConnection::default();
-                        connection_type.set_attributes(reader, e);
+                        connection_type.set_attributes(reader, e)?;
                         self.set_end_connection(connection_type);
                     }
                     _ => (),
@@ -69,10 +70,10 @@ impl NonVisualConnectorShapeDrawingProperties {
             },
             Event::End(ref e) => {
                 if e.name().into_inner() == b"xdr:cNvCxnSpPr" {
-                    return;
+                    return Ok(());
                 }
             },
-            Event::Eof => panic!("Error: Could not find {} end element", "xdr:cNvCxnSpPr")
+            Event::Eof => return Err(end_element_error(reader, "xdr:cNvCxnSpPr"))
         );
     }
 
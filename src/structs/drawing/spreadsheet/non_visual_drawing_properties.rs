@@ -4,6 +4,7 @@ use super::super::super::StringValue;
 use super::super::super::UInt32Value;
 use crate::reader::driver::*;
 use crate::writer::driver::*;
+use crate::XlsxError;
 use quick_xml::events::{BytesStart, Event};
 use quick_xml::Reader;
 use quick_xml::Writer;
@@ -55,24 +56,25 @@ impl NonVisualDrawingProperties {
         reader: &mut Reader<R>,
         e: &BytesStart,
         empty_flg: bool,
-    ) {
-        self.id.set_value_string(get_attribute(e, b"id").unwrap());
+    ) -> Result<(), XlsxError> {
+        self.id
+            .try_set_value_string(get_required_attribute(e, b"id")?, "id")?;
         self.name
-            .set_value_string(get_attribute(e, b"name").unwrap());
+            .try_set_value_string(get_required_attribute(e, b"name")?, "name")?;
         set_string_from_xml!(self, e, hidden, "hidden");
 
         if empty_flg {
-            return;
+            return Ok(());
         }
 
         xml_read_loop!(
             reader,
             Event::End(ref e) => {
                 if e.name().into_inner() == b"xdr:cNvPr" {
-                    return;
+                    return Ok(());
                 }
             },
-            Event::Eof => panic!("Error: Could not find {} end element", "xdr:cNvPr")
+            Event::Eof => return Err(end_element_error(reader, "xdr:cNvPr"))
         );
     }
 
@@ -3,6 +3,7 @@ use super::NonVisualDrawingProperties;
 use super::NonVisualGraphicFrameDrawingProperties;
 use crate::reader::driver::*;
 use crate::writer::driver::*;
+use crate::XlsxError;
 use quick_xml::events::{BytesStart, Event};
 use quick_xml::Reader;
 use quick_xml::Writer;
@@ -61,18 +62,18 @@ impl NonVisualGraphicFrameProperties {
         &mut self,
         reader: &mut Reader<R>,
         _e: &BytesStart,
-    ) {
+    ) -> Result<(), XlsxError> {
         xml_read_loop!(
             reader,
             Event::Empty(ref e) => {
                 match e.name().into_inner() {
                     b"xdr:cNvPr" => {
                         self.non_visual_drawing_properties
-                            .set_attributes(reader, e, true);
+                            .set_attributes(reader, e, true)?;
                     },
                     b"xdr:cNvGraphicFramePr" => {
                         self.non_visual_graphic_frame_drawing_properties
-                            .set_attributes(reader, e);
+                            .set_attributes(reader, e)?;
                     },
                     _ => (),
                 }
@@ -80,15 +81,15 @@ impl NonVisualGraphicFrameProperties {
             Event::Start(ref e) => {
                 if e.name().into_inner() == b"xdr:cNvPr" {
                     self.non_visual_drawing_properties
-                        .set_attributes(reader, e, false);
+                        .set_attributes(reader, e, false)?;
                 }
             },
             Event::End(ref e) => {
                 if e.name().into_inner() == b"xdr:nvGraphicFramePr" {
-                    return
+                    return Ok(())
                 }
             },
-            Event::Eof => panic!("Error: Could not find {} end element", "xdr:nvGraphicFramePr")
+            Event::Eof => return Err(end_element_error(reader, "xdr:nvGraphicFramePr"))
         );
     }
 
@@ -3,6 +3,7 @@ use super::super::GroupShapeLocks;
 use super::NonVisualDrawingProperties;
 use crate::reader::driver::*;
 use crate::writer::driver::*;
+use crate::XlsxError;
 use quick_xml::events::{BytesStart, Event};
 use quick_xml::Reader;
 use quick_xml::Writer;
@@ -35,9 +36,9 @@ impl NonVisualGroupShapeDrawingProperties {
         reader: &mut Reader<R>,
         _e: &BytesStart,
         empty_flg: bool,
-    ) {
+    ) -> Result<(), XlsxError> {
         if empty_flg {
-            return;
+            return Ok(());
         }
 
         xml_read_loop!(
@@ -45,16 +46,16 @@ impl NonVisualGroupShapeDrawingProperties {
             Event::Empty(ref e) => {
                 if e.name().into_inner() == b"a:grpSpLocks" {
                     let mut obj = GroupShapeLocks::default();
-                    obj.set_attributes(reader, e);
+                    obj.set_attributes(reader, e)?;
                     self.set_group_shape_locks(obj);
                 }
             },
             Event::End(ref e) => {
                 if e.name().into_inner() == b"xdr:cNvGrpSpPr" {
-                    return;
+                    return Ok(());
                 }
             },
-            Event::Eof => panic!("Error: Could not find {} end element", "xdr:cNvGrpSpPr")
+            Event::Eof => return Err(end_element_error(reader, "xdr:cNvGrpSpPr"))
         );
     }
 
@@ -3,6 +3,7 @@ use super::NonVisualDrawingProperties;
 use super::NonVisualGroupShapeDrawingProperties;
 use crate::reader::driver::*;
 use crate::writer::driver::*;
+use crate::XlsxError;
 use quick_xml::events::{BytesStart, Event};
 use quick_xml::Reader;
 use quick_xml::Writer;
@@ -61,18 +62,18 @@ impl NonVisualGroupShapeProperties {
         &mut self,
         reader: &mut Reader<R>,
         _e: &BytesStart,
-    ) {
+    ) -> Result<(), XlsxError> {
         xml_read_loop!(
             reader,
             Event::Start(ref e) => {
                 match e.name().into_inner(){
                     b"xdr:cNvPr" =>{
                         self.non_visual_drawing_properties
-                            .set_attributes(reader, e, false);
+                            .set_attributes(reader, e, false)?;
                     }
                     b"a:cNvGrpSpPr"=> {
                         self.non_visual_group_shape_drawing_properties
-                            .set_attributes(reader, e, false);
+                            .set_attributes(reader, e, false)?;
                     }
                     _=>()
                 }
@@ -81,21 +82,21 @@ impl NonVisualGroupShapeProperties {
                 match e.name().into_inner() {
                     b"xdr:cNvPr" =>{
                         self.non_visual_drawing_properties
-                        .set_attributes(reader, e, true);
+                        .set_attributes(reader, e, true)?;
                     }
                     b"a:cNvGrpSpPr" =>{
                         self.non_visual_group_shape_drawing_properties
-                        .set_attributes(reader, e, true);
+                        .set_attributes(reader, e, true)?;
                     }
                     _ => ()
                 }
             },
             Event::End(ref e) => {
                 if e.name().into_inner() == b"xdr:nvGrpSpPr" {
-                    return;
+                    return Ok(());
                 }
             },
-            Event::Eof => panic!("Error: Could not find {} end element", "xdr:nvGrpSpPr")
+            Event::Eof => return Err(end_element_error(reader, "xdr:nvGrpSpPr"))
         );
     }
 
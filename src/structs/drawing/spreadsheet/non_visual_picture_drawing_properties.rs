@@ -3,6 +3,7 @@ use super::super::PictureLocks;
 use crate::reader::driver::*;
 use crate::structs::BooleanValue;
 use crate::writer::driver::*;
+use crate::XlsxError;
 use quick_xml::events::{BytesStart, Event};
 use quick_xml::Reader;
 use quick_xml::Writer;
@@ -45,11 +46,11 @@ impl NonVisualPictureDrawingProperties {
         reader: &mut Reader<R>,
         e: &BytesStart,
         empty_flag: bool,
-    ) {
+    ) -> Result<(), XlsxError> {
         set_string_from_xml!(self, e, prefer_relative_resize, "preferRelativeResize");
 
         if empty_flag {
-            return;
+            return Ok(());
         }
 
         xml_read_loop!(
@@ -57,16 +58,16 @@ impl NonVisualPictureDrawingProperties {
             Event::Empty(ref e) => {
                 if e.name().into_inner() == b"a:picLocks" {
                     let mut obj = PictureLocks::default();
-                    obj.set_attributes(reader, e);
+                    obj.set_attributes(reader, e)?;
                     self.set_picture_locks(obj);
                 }
             },
             Event::End(ref e) => {
                 if e.name().into_inner() == b"xdr:cNvPicPr" {
-                    return
+                    return Ok(())
                 }
             },
-            Event::Eof => panic!("Error: Could not find {} end element", "xdr:cNvPicPr")
+            Event::Eof => return Err(end_element_error(reader, "xdr:cNvPicPr"))
         );
     }
 
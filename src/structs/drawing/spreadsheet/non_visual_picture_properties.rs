@@ -3,6 +3,7 @@ use super::NonVisualDrawingProperties;
 use super::NonVisualPictureDrawingProperties;
 use crate::reader::driver::*;
 use crate::writer::driver::*;
+use crate::XlsxError;
 use quick_xml::events::{BytesStart, Event};
 use quick_xml::Reader;
 use quick_xml::Writer;
@@ -54,18 +55,18 @@ impl NonVisualPictureProperties {
         &mut self,
         reader: &mut Reader<R>,
         _e: &BytesStart,
-    ) {
+    ) -> Result<(), XlsxError> {
         xml_read_loop!(
             reader,
             Event::Start(ref e) => {
                 match e.name().into_inner() {
                     b"xdr:cNvPicPr" => {
                         self.non_visual_picture_drawing_properties
-                            .set_attributes(reader, e, false);
+                            .set_attributes(reader, e, false)?;
                         }
                     b"xdr:cNvPr" => {
                         self.non_visual_drawing_properties
-                            .set_attributes(reader, e, false);
+                            .set_attributes(reader, e, false)?;
                         }
                     _ => (),
                 }
@@ -74,21 +75,21 @@ impl NonVisualPictureProperties {
                 match e.name().into_inner() {
                     b"xdr:cNvPicPr" => {
                         self.non_visual_picture_drawing_properties
-                            .set_attributes(reader, e, true);
+                            .set_attributes(reader, e, true)?;
                         }
                     b"xdr:cNvPr" => {
                         self.non_visual_drawing_properties
-                            .set_attributes(reader, e, true);
+                            .set_attributes(reader, e, true)?;
                         }
                     _ => (),
                 }
             },
             Event::End(ref e) => {
                 if e.name().into_inner() == b"xdr:nvPicPr" {
-                    return;
+                    return Ok(());
                 }
             },
-            Event::Eof => panic!("Error: Could not find {} end element", "xdr:nvPicPr")
+            Event::Eof => return Err(end_element_error(reader, "xdr:nvPicPr"))
         );
     }
 
@@ -8,6 +8,7 @@ use crate::reader::driver::*;
 use crate::structs::raw::RawRelationships;
 use crate::traits::AdjustmentCoordinate;
 use crate::writer::driver::*;
+use crate::XlsxError;
 use quick_xml::events::{BytesStart, Event};
 use quick_xml::Reader;
 use quick_xml::Writer;
@@ -113,27 +114,27 @@ impl OneCellAnchor {
         reader: &mut Reader<R>,
         _e: &BytesStart,
         drawing_relationships: Option<&RawRelationships>,
-    ) {
+    ) -> Result<(), XlsxError> {
         xml_read_loop!(
             reader,
             Event::Start(ref e) => {
                 match e.name().into_inner() {
                     b"xdr:from" => {
-                        self.from_marker.set_attributes(reader, e);
+                        self.from_marker.set_attributes(reader, e)?;
                     }
                     b"xdr:grpSp" => {
                         let mut obj = GroupShape::default();
-                        obj.set_attributes(reader, e, drawing_relationships);
+                        obj.set_attributes(reader, e, drawing_relationships)?;
                         self.set_group_shape(obj);
                     }
                     b"xdr:sp" => {
                         let mut obj = Shape::default();
-                        obj.set_attributes(reader, e, drawing_relationships);
+                        obj.set_attributes(reader, e, drawing_relationships)?;
                         self.set_shape(obj);
                     }
                     b"xdr:pic" => {
                         let mut obj = Picture::default();
-                        obj.set_attributes(reader, e, drawing_relationships);
+                        obj.set_attributes(reader, e, drawing_relationships)?;
                         self.set_picture(obj);
                     }
                     _ => (),
@@ -141,15 +142,15 @@ impl OneCellAnchor {
             },
             Event::Empty(ref e) => {
                 if e.name().into_inner() == b"xdr:ext" {
-                    self.extent.set_attributes(reader, e);
+                    self.extent.set_attributes(reader, e)?;
                 }
             },
             Event::End(ref e) => {
                 if e.name().into_inner() == b"xdr:oneCellAnchor" {
-                    return
+                    return Ok(())
                 }
             },
-            Event::Eof => panic!("Error: Could not find {} end element", "xdr:oneCellAnchor")
+            Event::Eof => return Err(end_element_error(reader, "xdr:oneCellAnchor"))
         );
     }
 
@@ -5,6 +5,7 @@ use super::ShapeProperties;
 use crate::reader::driver::*;
 use crate::structs::raw::RawRelationships;
 use crate::writer::driver::*;
+use crate::XlsxError;
 use quick_xml::events::{BytesStart, Event};
 use quick_xml::Reader;
 use quick_xml::Writer;
@@ -68,30 +69,30 @@ impl Picture {
         reader: &mut Reader<R>,
         _e: &BytesStart,
         drawing_relationships: Option<&RawRelationships>,
-    ) {
+    ) -> Result<(), XlsxError> {
         xml_read_loop!(
             reader,
             Event::Start(ref e) => {
                 match e.name().into_inner() {
                     b"xdr:nvPicPr" => {
-                        self.non_visual_picture_properties.set_attributes(reader, e);
+                        self.non_visual_picture_properties.set_attributes(reader, e)?;
                     }
                     b"xdr:blipFill" => {
                         self.blip_fill
-                            .set_attributes(reader, e, drawing_relationships);
+                            .set_attributes(reader, e, drawing_relationships)?;
                         }
                     b"xdr:spPr" => {
-                        self.shape_properties.set_attributes(reader, e, drawing_relationships);
+                        self.shape_properties.set_attributes(reader, e, drawing_relationships)?;
                     }
                     _ => (),
                 }
             },
             Event::End(ref e) => {
                 if e.name().into_inner() == b"xdr:pic" {
-                    return;
+                    return Ok(());
                 }
             },
-            Event::Eof => panic!("Error: Could not find {} end element", "xdr:pic")
+            Event::Eof => return Err(end_element_error(reader, "xdr:pic"))
         );
     }
 
@@ -7,6 +7,7 @@ use super::TextBody;
 use crate::reader::driver::*;
 use crate::structs::raw::RawRelationships;
 use crate::writer::driver::*;
+use crate::XlsxError;
 use quick_xml::events::{BytesStart, Event};
 use quick_xml::Reader;
 use quick_xml::Writer;
@@ -100,25 +101,25 @@ impl Shape {
         reader: &mut Reader<R>,
         _e: &BytesStart,
         drawing_relationships: Option<&RawRelationships>,
-    ) {
+    ) -> Result<(), XlsxError> {
         xml_read_loop!(
             reader,
                 Event::Start(ref e) => {
                     match e.name().into_inner() {
                         b"xdr:nvSpPr" => {
-                            self.non_visual_shape_properties.set_attributes(reader, e);
+                            self.non_visual_shape_properties.set_attributes(reader, e)?;
                         }
                         b"xdr:spPr" => {
-                            self.shape_properties.set_attributes(reader, e, drawing_relationships);
+                            self.shape_properties.set_attributes(reader, e, drawing_relationships)?;
                         }
                         b"xdr:style" => {
                             let mut obj = ShapeStyle::default();
-                            obj.set_attributes(reader, e);
+                            obj.set_attributes(reader, e)?;
                             self.set_shape_style(obj);
                         }
                         b"xdr:txBody" => {
                             let mut obj = TextBody::default();
-                            obj.set_attributes(reader, e);
+                            obj.set_attributes(reader, e)?;
                             self.set_text_body(obj);
                         }
                         _ => (),
@@ -126,10 +127,10 @@ impl Shape {
                 },
                 Event::End(ref e) => {
                     if e.name().into_inner() == b"xdr:sp" {
-                        return;
+                        return Ok(());
                     }
                 },
-                Event::Eof => panic!("Error: Could not find {} end element", "xdr:sp")
+                Event::Eof => return Err(end_element_error(reader, "xdr:sp"))
         );
     }
 
@@ -10,6 +10,7 @@ use super::super::Transform2D;
 use crate::reader::driver::*;
 use crate::structs::raw::RawRelationships;
 use crate::writer::driver::*;
+use crate::XlsxError;
 use quick_xml::events::{BytesStart, Event};
 use quick_xml::Reader;
 use quick_xml::Writer;
@@ -160,42 +161,42 @@ impl ShapeProperties {
         reader: &mut Reader<R>,
         _e: &BytesStart,
         drawing_relationships: Option<&RawRelationships>,
-    ) {
+    ) -> Result<(), XlsxError> {
         xml_read_loop!(
             reader,
             Event::Start(ref e) => {
                 match e.name().into_inner() {
                     b"a:xfrm" => {
                         let mut obj = Transform2D::default();
-                        obj.set_attributes(reader, e);
+                        obj.set_attributes(reader, e)?;
                         self.set_transform2d(obj);
                     }
                     b"a:prstGeom" => {
-                        self.preset_geometry.set_attributes(reader, e);
+                        self.preset_geometry.set_attributes(reader, e)?;
                     }
                     b"a:blipFill" => {
                         let mut obj = BlipFill::default();
-                        obj.set_attributes(reader, e, drawing_relationships);
+                        obj.set_attributes(reader, e, drawing_relationships)?;
                         self.set_blip_fill(obj);
                     }
                     b"a:ln" => {
                         let mut outline = Outline::default();
-                        outline.set_attributes(reader, e);
+                        outline.set_attributes(reader, e)?;
                         self.set_outline(outline);
                     }
                     b"a:solidFill" => {
                         let mut solid_fill = SolidFill::default();
-                        solid_fill.set_attributes(reader, e);
+                        solid_fill.set_attributes(reader, e)?;
                         self.set_solid_fill(solid_fill);
                     }
                     b"a:effectLst" => {
                         let mut effect_list = EffectList::default();
-                        effect_list.set_attributes(reader, e, false);
+                        effect_list.set_attributes(reader, e, false)?;
                         self.set_effect_list(effect_list);
                     }
                     b"a:extLst" => {
                         let mut obj = ExtensionList::default();
-                        obj.set_attributes(reader, e);
+                        obj.set_attributes(reader, e)?;
                         self.set_extension_list(obj);
                     }
                     _ => (),
@@ -204,16 +205,16 @@ impl ShapeProperties {
             Event::Empty(ref e) => {
                 if e.name().into_inner() == b"a:noFill" {
                     let mut obj = NoFill::default();
-                    obj.set_attributes(reader, e);
+                    obj.set_attributes(reader, e)?;
                     self.set_no_fill(obj);
                 }
             },
             Event::End(ref e) => {
                 if e.name().into_inner() == b"xdr:spPr" {
-                    return;
+                    return Ok(());
                 }
             },
-            Event::Eof => panic!("Error: Could not find {} end element", "xdr:spPr")
+            Event::Eof => return Err(end_element_error(reader, "xdr:spPr"))
         );
     }
 
@@ -2,6 +2,7 @@
 use super::super::StyleMatrixReferenceType;
 use crate::reader::driver::*;
 use crate::writer::driver::*;
+use crate::XlsxError;
 use quick_xml::events::{BytesStart, Event};
 use quick_xml::Reader;
 use quick_xml::Writer;
@@ -60,29 +61,29 @@ impl ShapeStyle {
         &mut self,
         reader: &mut Reader<R>,
         _e: &BytesStart,
-    ) {
+    ) -> Result<(), XlsxError> {
         xml_read_loop!(
             reader,
             Event::Start(ref e) => {
                 match e.name().into_inner() {
                     b"a:lnRef" => {
                         let mut style_matrix_reference_type = StyleMatrixReferenceType::default();
-                        style_matrix_reference_type.set_attributes(reader, e, false);
+                        style_matrix_reference_type.set_attributes(reader, e, false)?;
                         self.set_line_reference(style_matrix_reference_type);
                     }
                     b"a:fillRef" => {
                         let mut style_matrix_reference_type = StyleMatrixReferenceType::default();
-                        style_matrix_reference_type.set_attributes(reader, e, false);
+                        style_matrix_reference_type.set_attributes(reader, e, false)?;
                         self.set_fill_reference(style_matrix_reference_type);
                     }
                     b"a:effectRef" => {
                         let mut style_matrix_reference_type = StyleMatrixReferenceType::default();
-                        style_matrix_reference_type.set_attributes(reader, e, false);
+                        style_matrix_reference_type.set_attributes(reader, e, false)?;
                         self.set_effect_reference(style_matrix_reference_type);
                     }
                     b"a:fontRef" => {
                         let mut style_matrix_reference_type = StyleMatrixReferenceType::default();
-                        style_matrix_reference_type.set_attributes(reader, e, false);
+                        style_matrix_reference_type.set_attributes(reader, e, false)?;
                         self.set_font_reference(style_matrix_reference_type);
                     }
                     _ => (),
@@ -92,22 +93,22 @@ impl ShapeStyle {
                 match e.name().into_inner() {
                     b"a:lnRef" => {
                         let mut style_matrix_reference_type = StyleMatrixReferenceType::default();
-                        style_matrix_reference_type.set_attributes(reader, e, true);
+                        style_matrix_reference_type.set_attributes(reader, e, true)?;
                         self.set_line_reference(style_matrix_reference_type);
                     }
                     b"a:fillRef" => {
                         let mut style_matrix_reference_type = StyleMatrixReferenceType::default();
-                        style_matrix_reference_type.set_attributes(reader, e, true);
+                        style_matrix_reference_type.set_attributes(reader, e, true)?;
                         self.set_fill_reference(style_matrix_reference_type);
                     }
                     b"a:effectRef" => {
                         let mut style_matrix_reference_type = StyleMatrixReferenceType::default();
-                        style_matrix_reference_type.set_attributes(reader, e, true);
+                        style_matrix_reference_type.set_attributes(reader, e, true)?;
                         self.set_effect_reference(style_matrix_reference_type);
                     }
                     b"a:fontRef" => {
                         let mut style_matrix_reference_type = StyleMatrixReferenceType::default();
-                        style_matrix_reference_type.set_attributes(reader, e, true);
+                        style_matrix_reference_type.set_attributes(reader, e, true)?;
                         self.set_font_reference(style_matrix_reference_type);
                     }
                     _ => (),
@@ -115,10 +116,10 @@ impl ShapeStyle {
             },
             Event::End(ref e) => {
                 if e.name().into_inner() == b"xdr:style" {
-                    return;
+                    return Ok(());
                 }
             },
-            Event::Eof => panic!("Error: Could not find {} end element", "xdr:style")
+            Event::Eof => return Err(end_element_error(reader, "xdr:style"))
         );
     }
 
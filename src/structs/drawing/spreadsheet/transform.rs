@@ -2,6 +2,7 @@
 use super::super::{Extents, Offset};
 use crate::reader::driver::*;
 use crate::writer::driver::*;
+use crate::XlsxError;
 use crate::{BooleanValue, Int32Value};
 use quick_xml::events::{BytesStart, Event};
 use quick_xml::Reader;
@@ -84,7 +85,7 @@ impl Transform {
         &mut self,
         reader: &mut Reader<R>,
         e: &BytesStart,
-    ) {
+    ) -> Result<(), XlsxError> {
         set_string_from_xml!(self, e, rotation, "rot");
         set_string_from_xml!(self, e, horizontal_flip, "flipH");
         set_string_from_xml!(self, e, vertical_flip, "flipV");
@@ -94,20 +95,20 @@ impl Transform {
             Event::Empty(ref e) => {
                 match e.name().into_inner() {
                     b"a:off" => {
-                        self.offset.set_attributes(reader, e);
+                        self.offset.set_attributes(reader, e)?;
                     }
                     b"a:ext" => {
-                        self.extents.set_attributes(reader, e);
+                        self.extents.set_attributes(reader, e)?;
                     }
                     _ => (),
                 }
             },
             Event::End(ref e) => {
                 if  e.name().into_inner() == b"xdr:xfrm" {
-                    return;
+                    return Ok(());
                 }
             },
-            Event::Eof => panic!("Error: Could not find {} end element", "xdr:xfrm")
+            Event::Eof => return Err(end_element_error(reader, "xdr:xfrm"))
         );
     }
 
@@ -15,6 +15,7 @@ use crate::structs::BooleanValue;
 use crate::traits::AdjustmentCoordinate;
 use crate::traits::AdjustmentCoordinateWithSheet;
 use crate::writer::driver::*;
+use crate::XlsxError;
 use quick_xml::events::{BytesStart, Event};
 use quick_xml::Reader;
 use quick_xml::Writer;
@@ -170,6 +171,9 @@ impl TwoCellAnchor {
 
     #[inline]
     pub(crate) fn is_support(&self) -> bool {
+        if self.is_chart_ex() {
+            return true;
+        }
         self.graphic_frame.as_ref().map_or(true, |v| {
             v.get_graphic()
                 .get_graphic_data()
@@ -182,7 +186,17 @@ impl TwoCellAnchor {
 
     #[inline]
     pub(crate) fn is_chart(&self) -> bool {
-        self.graphic_frame.is_some()
+        self.graphic_frame.is_some() && !self.is_chart_ex()
+    }
+
+    #[inline]
+    pub(crate) fn is_chart_ex(&self) -> bool {
+        self.graphic_frame.as_ref().map_or(false, |v| {
+            v.get_graphic()
+                .get_graphic_data()
+                .get_chart_ex_space()
+                .is_some()
+        })
     }
 
     #[inline]
@@ -195,7 +209,7 @@ impl TwoCellAnchor {
         reader: &mut Reader<R>,
         e: &BytesStart,
         drawing_relationships: Option<&RawRelationships>,
-    ) {
+    ) -> Result<(), XlsxError> {
         set_string_from_xml!(self, e, edit_as, "editAs");
 
         xml_read_loop!(
@@ -203,45 +217,51 @@ impl TwoCellAnchor {
             Event::Start(ref e) => {
                 match e.name().into_inner() {
                 b"xdr:from" => {
-                    self.from_marker.set_attributes(reader, e);
+                    self.from_marker.set_attributes(reader, e)?;
                 }
                 b"xdr:to" => {
-                    self.to_marker.set_attributes(reader, e);
+                    self.to_marker.set_attributes(reader, e)?;
                 }
                 b"xdr:grpSp" => {
                     let mut obj = GroupShape::default();
-                    obj.set_attributes(reader, e, drawing_relationships);
+                    obj.set_attributes(reader, e, drawing_relationships)?;
                     self.set_group_shape(obj);
                 }
                 b"xdr:graphicFrame" => {
                     let mut obj = GraphicFrame::default();
-                    obj.set_attributes(reader, e, drawing_relationships);
+                    obj.set_attributes(reader, e, drawing_relationships)?;
                     self.set_graphic_frame(obj);
                 }
                 b"xdr:sp" => {
                     let mut obj = Shape::default();
-                    obj.set_attributes(reader, e, drawing_relationships);
+                    obj.set_attributes(reader, e, drawing_relationships)?;
                     self.set_shape(obj);
                 }
                 b"xdr:cxnSp" => {
                     let mut obj = ConnectionShape::default();
-                    obj.set_attributes(reader, e, drawing_relationships);
+                    obj.set_attributes(reader, e, drawing_relationships)?;
                     self.set_connection_shape(obj);
                 }
                 b"xdr:pic" => {
                     let mut obj = Picture::default();
-                    obj.set_attributes(reader, e, drawing_relationships);
+                    obj.set_attributes(reader, e, drawing_relationships)?;
                     self.set_picture(obj);
                 }
+                b"mc:Fallback" => {
+                    // the fallback of a chartEx frame is a placeholder shape, rebuilt on write.
+                    reader
+                        .read_to_end_into(e.name(), &mut Vec::new())
+                        .map_err(|err| xml_error(reader, err))?;
+                }
                 _ => (),
                 }
             },
             Event::End(ref e) => {
                 if e.name().into_inner() == b"xdr:twoCellAnchor" {
-                    return
+                    return Ok(())
                 }
             },
-            Event::Eof => panic!("Error: Could not find {} end element", "xdr:twoCellAnchor")
+            Event::Eof => return Err(end_element_error(reader, "xdr:twoCellAnchor"))
         );
     }
 
@@ -289,7 +309,11 @@ impl TwoCellAnchor {
 
         // xdr:graphicFrame
         if let Some(v) = &self.graphic_frame {
-            v.write_to(writer, rel_list);
+            if self.is_chart_ex() {
+                self.write_chart_ex_frame(writer, v, rel_list);
+            } else {
+                v.write_to(writer, rel_list);
+            }
         }
 
         // xdr:sp
@@ -322,6 +346,90 @@ impl TwoCellAnchor {
         }
     }
 }
+impl TwoCellAnchor {
+    fn write_chart_ex_frame(
+        &self,
+        writer: &mut Writer<Cursor<Vec<u8>>>,
+        graphic_frame: &GraphicFrame,
+        rel_list: &mut Vec<(String, String)>,
+    ) {
+        // mc:AlternateContent
+        write_start_tag(
+            writer,
+            "mc:AlternateContent",
+            vec![("xmlns:mc", MC_NS)],
+            false,
+        );
+
+        // mc:Choice
+        write_start_tag(
+            writer,
+            "mc:Choice",
+            vec![("xmlns:cx1", CX1_NS), ("Requires", "cx1")],
+            false,
+        );
+        graphic_frame.write_to(writer, rel_list);
+        write_end_tag(writer, "mc:Choice");
+
+        // mc:Fallback
+        write_start_tag(writer, "mc:Fallback", vec![], false);
+
+        // xdr:sp
+        write_start_tag(
+            writer,
+            "xdr:sp",
+            vec![("macro", ""), ("textlink", "")],
+            false,
+        );
+
+        // xdr:nvSpPr
+        write_start_tag(writer, "xdr:nvSpPr", vec![], false);
+        write_start_tag(writer, "xdr:cNvPr", vec![("id", "0"), ("name", "")], true);
+        write_start_tag(writer, "xdr:cNvSpPr", vec![], false);
+        write_start_tag(writer, "a:spLocks", vec![("noTextEdit", "1")], true);
+        write_end_tag(writer, "xdr:cNvSpPr");
+        write_end_tag(writer, "xdr:nvSpPr");
+
+        // xdr:spPr
+        write_start_tag(writer, "xdr:spPr", vec![], false);
+        write_start_tag(writer, "a:xfrm", vec![], false);
+        write_start_tag(writer, "a:off", vec![("x", "0"), ("y", "0")], true);
+        write_start_tag(writer, "a:ext", vec![("cx", "0"), ("cy", "0")], true);
+        write_end_tag(writer, "a:xfrm");
+        write_start_tag(writer, "a:prstGeom", vec![("prst", "rect")], false);
+        write_start_tag(writer, "a:avLst", vec![], true);
+        write_end_tag(writer, "a:prstGeom");
+        write_end_tag(writer, "xdr:spPr");
+
+        // xdr:txBody
+        write_start_tag(writer, "xdr:txBody", vec![], false);
+        write_start_tag(writer, "a:bodyPr", vec![], true);
+        write_start_tag(writer, "a:lstStyle", vec![], true);
+        write_start_tag(writer, "a:p", vec![], false);
+        write_start_tag(writer, "a:r", vec![], false);
+        write_start_tag(
+            writer,
+            "a:rPr",
+            vec![("lang", "en-US"), ("sz", "1100")],
+            true,
+        );
+        write_start_tag(writer, "a:t", vec![], false);
+        write_text_node(
+            writer,
+            "This chart isn't available in your version of Excel.",
+        );
+        write_end_tag(writer, "a:t");
+        write_end_tag(writer, "a:r");
+        write_end_tag(writer, "a:p");
+        write_end_tag(writer, "xdr:txBody");
+
+        write_end_tag(writer, "xdr:sp");
+
+        write_end_tag(writer, "mc:Fallback");
+
+        write_end_tag(writer, "mc:AlternateContent");
+    }
+}
 impl AdjustmentCoordinate for TwoCellAnchor {
     #[inline]
     fn adjustment_insert_coordinate(
@@ -9,11 +9,13 @@ use crate::helper::const_str::*;
 use crate::reader::driver::*;
 use crate::structs::raw::RawRelationships;
 use crate::structs::Chart;
+use crate::structs::ChartEx;
 use crate::structs::Image;
 use crate::structs::OleObjects;
 use crate::traits::AdjustmentCoordinate;
 use crate::traits::AdjustmentCoordinateWithSheet;
 use crate::writer::driver::*;
+use crate::XlsxError;
 use quick_xml::events::{BytesStart, Event};
 use quick_xml::Reader;
 use quick_xml::Writer;
@@ -24,6 +26,7 @@ use thin_vec::ThinVec;
 pub struct WorksheetDrawing {
     image_collection: ThinVec<Image>,
     chart_collection: ThinVec<Chart>,
+    chart_ex_collection: ThinVec<ChartEx>,
     one_cell_anchor_collection: ThinVec<OneCellAnchor>,
     two_cell_anchor_collection: ThinVec<TwoCellAnchor>,
 }
@@ -117,6 +120,36 @@ impl WorksheetDrawing {
             .collect()
     }
 
+    #[inline]
+    pub fn get_chart_ex_collection(&self) -> &[ChartEx] {
+        &self.chart_ex_collection
+    }
+
+    #[inline]
+    pub fn get_chart_ex_collection_mut(&mut self) -> &mut ThinVec<ChartEx> {
+        &mut self.chart_ex_collection
+    }
+
+    #[inline]
+    pub fn add_chart_ex_collection(&mut self, value: ChartEx) -> &mut Self {
+        self.chart_ex_collection.push(value);
+        self
+    }
+
+    pub fn get_charts_ex(&self, col: &u32, row: &u32) -> Vec<&ChartEx> {
+        self.chart_ex_collection
+            .iter()
+            .filter(|chart| chart.get_col() == &(col - 1) && chart.get_row() == &(row - 1))
+            .collect()
+    }
+
+    pub fn get_charts_ex_mut(&mut self, col: &u32, row: &u32) -> Vec<&mut ChartEx> {
+        self.chart_ex_collection
+            .iter_mut()
+            .filter(|chart| chart.get_col() == &(col - 1) && chart.get_row() == &(row - 1))
+            .collect()
+    }
+
     #[inline]
     pub fn get_one_cell_anchor_collection(&self) -> &[OneCellAnchor] {
         &self.one_cell_anchor_collection
@@ -152,6 +185,7 @@ impl WorksheetDrawing {
     #[inline]
     pub fn has_drawing_object(&self) -> bool {
         !self.chart_collection.is_empty()
+            || !self.chart_ex_collection.is_empty()
             || !self.image_collection.is_empty()
             || !self.one_cell_anchor_collection.is_empty()
             || !self.two_cell_anchor_collection.is_empty()
@@ -225,6 +259,11 @@ impl WorksheetDrawing {
                     .iter_mut()
                     .map(|chart| chart.get_two_cell_anchor_mut()),
             )
+            .chain(
+                self.chart_ex_collection
+                    .iter_mut()
+                    .map(|chart| chart.get_two_cell_anchor_mut()),
+            )
             .chain(
                 self.image_collection
                     .iter_mut()
@@ -246,7 +285,7 @@ impl WorksheetDrawing {
         _e: &BytesStart,
         drawing_relationships: Option<&RawRelationships>,
         ole_objects: &mut OleObjects,
-    ) {
+    ) -> Result<(), XlsxError> {
         let mut ole_index = 0;
         let mut is_alternate_content = false;
 
@@ -262,7 +301,7 @@ impl WorksheetDrawing {
                             continue;
                         }
                         let mut obj = OneCellAnchor::default();
-                        obj.set_attributes(reader, e, drawing_relationships);
+                        obj.set_attributes(reader, e, drawing_relationships)?;
                         if obj.is_image() {
                             let mut image = Image::default();
                             image.set_one_cell_anchor(obj);
@@ -281,14 +320,18 @@ impl WorksheetDrawing {
                                 reader,
                                 e,
                                 drawing_relationships,
-                            );
+                            )?;
                             ole_index += 1;
                             continue;
                         }
                         let mut obj = TwoCellAnchor::default();
-                        obj.set_attributes(reader, e, drawing_relationships);
+                        obj.set_attributes(reader, e, drawing_relationships)?;
                         if obj.is_support() {
-                            if obj.is_chart() {
+                            if obj.is_chart_ex() {
+                                let mut chart = ChartEx::default();
+                                chart.set_two_cell_anchor(obj);
+                                self.add_chart_ex_collection(chart);
+                            } else if obj.is_chart() {
                                 let mut chart = Chart::default();
                                 chart.set_two_cell_anchor(obj);
                                 self.add_chart_collection(chart);
@@ -310,12 +353,12 @@ impl WorksheetDrawing {
                     b"mc:AlternateContent" => {
                         is_alternate_content = false;
                     }
-                    b"xdr:wsDr" => return,
+                    b"xdr:wsDr" => return Ok(()),
                     _ => (),
                 }
             },
 
-            Event::Eof => panic!("Error: Could not find {} end element", "xdr:wsDr")
+            Event::Eof => return Err(end_element_error(reader, "xdr:wsDr"))
         );
     }
 
@@ -340,6 +383,9 @@ impl WorksheetDrawing {
         for chart in &self.chart_collection {
             chart.get_two_cell_anchor().write_to(writer, rel_list, &0);
         }
+        for chart in &self.chart_ex_collection {
+            chart.get_two_cell_anchor().write_to(writer, rel_list, &0);
+        }
         for image in &self.image_collection {
             image.write_to(writer, rel_list);
         }
@@ -396,6 +442,14 @@ impl AdjustmentCoordinate for WorksheetDrawing {
                 offset_row_num,
             );
         }
+        for chart in &mut self.chart_ex_collection {
+            chart.adjustment_insert_coordinate(
+                root_col_num,
+                offset_col_num,
+                root_row_num,
+                offset_row_num,
+            );
+        }
         for image in &mut self.image_collection {
             image.adjustment_insert_coordinate(
                 root_col_num,
@@ -446,6 +500,17 @@ impl AdjustmentCoordinate for WorksheetDrawing {
                 offset_row_num,
             );
         }
+        &mut self.chart_ex_collection.retain(|k| {
+            !(k.is_remove_coordinate(root_col_num, offset_col_num, root_row_num, offset_row_num))
+        });
+        for chart in &mut self.chart_ex_collection {
+            chart.adjustment_remove_coordinate(
+                root_col_num,
+                offset_col_num,
+                root_row_num,
+                offset_row_num,
+            );
+        }
         &mut self.image_collection.retain(|k| {
             !(k.is_remove_coordinate(root_col_num, offset_col_num, root_row_num, offset_row_num))
         });
@@ -478,6 +543,15 @@ impl AdjustmentCoordinateWithSheet for WorksheetDrawing {
                 offset_row_num,
             );
         }
+        for chart in &mut self.chart_ex_collection {
+            chart.adjustment_insert_coordinate_with_sheet(
+                sheet_name,
+                root_col_num,
+                offset_col_num,
+                root_row_num,
+                offset_row_num,
+            );
+        }
     }
 
     fn adjustment_remove_coordinate_with_sheet(
@@ -498,5 +572,14 @@ impl AdjustmentCoordinateWithSheet for WorksheetDrawing {
                 offset_row_num,
             );
         }
+        for chart in &mut self.chart_ex_collection {
+            chart.adjustment_remove_coordinate_with_sheet(
+                sheet_name,
+                root_col_num,
+                offset_col_num,
+                root_row_num,
+                offset_row_num,
+            );
+        }
     }
 }
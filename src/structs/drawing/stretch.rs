@@ -2,6 +2,7 @@
 use super::fill_rectangle::FillRectangle;
 use crate::reader::driver::*;
 use crate::writer::driver::*;
+use crate::XlsxError;
 use quick_xml::events::{BytesStart, Event};
 use quick_xml::Reader;
 use quick_xml::Writer;
@@ -32,22 +33,22 @@ impl Stretch {
         &mut self,
         reader: &mut Reader<R>,
         _e: &BytesStart,
-    ) {
+    ) -> Result<(), XlsxError> {
         xml_read_loop!(
             reader,
             Event::Empty(ref e) => {
                 if e.name().into_inner() == b"a:fillRect" {
                     let mut fill_rectangle = FillRectangle::default();
-                    fill_rectangle.set_attributes(reader, e);
+                    fill_rectangle.set_attributes(reader, e)?;
                     self.set_fill_rectangle(fill_rectangle);
                 }
             },
             Event::End(ref e) => {
                 if e.name().into_inner() == b"a:stretch" {
-                    return;
+                    return Ok(());
                 }
             },
-            Event::Eof => panic!("Error: Could not find {} end element", "a:stretch")
+            Event::Eof => return Err(end_element_error(reader, "a:stretch"))
         );
     }
 
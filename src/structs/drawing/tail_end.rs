@@ -2,6 +2,7 @@
 use crate::reader::driver::*;
 use crate::structs::StringValue;
 use crate::writer::driver::*;
+use crate::XlsxError;
 use quick_xml::events::BytesStart;
 use quick_xml::Reader;
 use quick_xml::Writer;
@@ -49,18 +50,19 @@ impl TailEnd {
         &mut self,
         _reader: &mut Reader<R>,
         e: &BytesStart,
-    ) {
-        if let Some(v) = get_attribute(e, b"type") {
+    ) -> Result<(), XlsxError> {
+        if let Some(v) = get_attribute(e, b"type")? {
             self.set_type(v);
         }
 
-        if let Some(v) = get_attribute(e, b"w") {
+        if let Some(v) = get_attribute(e, b"w")? {
             self.set_width(v);
         }
 
-        if let Some(v) = get_attribute(e, b"len") {
+        if let Some(v) = get_attribute(e, b"len")? {
             self.set_length(v);
         }
+        Ok(())
     }
 
     pub(crate) fn write_to(&self, writer: &mut Writer<Cursor<Vec<u8>>>) {
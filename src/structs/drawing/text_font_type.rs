@@ -1,6 +1,7 @@
 use super::super::StringValue;
 use crate::reader::driver::*;
 use crate::writer::driver::*;
+use crate::XlsxError;
 use quick_xml::events::BytesStart;
 use quick_xml::Reader;
 use quick_xml::Writer;
@@ -63,19 +64,20 @@ impl TextFontType {
         &mut self,
         _reader: &mut Reader<R>,
         e: &BytesStart,
-    ) {
-        if let Some(v) = get_attribute(e, b"typeface") {
+    ) -> Result<(), XlsxError> {
+        if let Some(v) = get_attribute(e, b"typeface")? {
             self.set_typeface(v);
         }
-        if let Some(v) = get_attribute(e, b"pitchFamily") {
+        if let Some(v) = get_attribute(e, b"pitchFamily")? {
             self.set_pitch_family(v);
         }
-        if let Some(v) = get_attribute(e, b"charset") {
+        if let Some(v) = get_attribute(e, b"charset")? {
             self.set_charset(v);
         }
-        if let Some(v) = get_attribute(e, b"panose") {
+        if let Some(v) = get_attribute(e, b"panose")? {
             self.set_panose(v);
         }
+        Ok(())
     }
 
     #[inline]
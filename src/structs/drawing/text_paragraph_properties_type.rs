@@ -8,6 +8,7 @@ use super::TextAlignmentTypeValues;
 use super::TextFontAlignmentValues;
 use crate::reader::driver::*;
 use crate::writer::driver::*;
+use crate::XlsxError;
 use quick_xml::events::{BytesStart, Event};
 use quick_xml::Reader;
 use quick_xml::Writer;
@@ -108,7 +109,7 @@ impl TextParagraphPropertiesType {
         &mut self,
         reader: &mut Reader<R>,
         e: &BytesStart,
-    ) {
+    ) -> Result<(), XlsxError> {
         set_string_from_xml!(self, e, right_to_left, "rtl");
         set_string_from_xml!(self, e, alignment, "algn");
         set_string_from_xml!(self, e, font_alignment, "fontAlgn");
@@ -119,7 +120,7 @@ impl TextParagraphPropertiesType {
                 match e.name().into_inner() {
                     b"a:defRPr" => {
                         let mut obj = RunProperties::default();
-                        obj.set_attributes(reader, e, true);
+                        obj.set_attributes(reader, e, true)?;
                         self.set_default_run_properties(obj);
                     }
                     _ => (),
@@ -129,17 +130,17 @@ impl TextParagraphPropertiesType {
                 match e.name().into_inner() {
                 b"a:spcBef" => {
                     let mut obj = SpaceBefore::default();
-                    obj.set_attributes(reader, e);
+                    obj.set_attributes(reader, e)?;
                     self.set_space_before(obj);
                 }
                 b"a:spcAft" => {
                     let mut obj = SpaceAfter::default();
-                    obj.set_attributes(reader, e);
+                    obj.set_attributes(reader, e)?;
                     self.set_space_after(obj);
                 }
                 b"a:defRPr" => {
                     let mut obj = RunProperties::default();
-                    obj.set_attributes(reader, e, false);
+                    obj.set_attributes(reader, e, false)?;
                     self.set_default_run_properties(obj);
                 }
                 _ => (),
@@ -147,20 +148,20 @@ impl TextParagraphPropertiesType {
             },
             Event::End(ref e) => {
                 match e.name().into_inner() {
-                    b"a:defPPr"  => return,
-                    b"a:lvl1pPr" => return,
-                    b"a:lvl2pPr" => return,
-                    b"a:lvl3pPr" => return,
-                    b"a:lvl4pPr" => return,
-                    b"a:lvl5pPr" => return,
-                    b"a:lvl6pPr" => return,
-                    b"a:lvl7pPr" => return,
-                    b"a:lvl8pPr" => return,
-                    b"a:lvl9pPr" => return,
+                    b"a:defPPr"  => return Ok(()),
+                    b"a:lvl1pPr" => return Ok(()),
+                    b"a:lvl2pPr" => return Ok(()),
+                    b"a:lvl3pPr" => return Ok(()),
+                    b"a:lvl4pPr" => return Ok(()),
+                    b"a:lvl5pPr" => return Ok(()),
+                    b"a:lvl6pPr" => return Ok(()),
+                    b"a:lvl7pPr" => return Ok(()),
+                    b"a:lvl8pPr" => return Ok(()),
+                    b"a:lvl9pPr" => return Ok(()),
                     _ =>()
                 }
             },
-            Event::Eof => panic!("Error: Could not find {} end element", "a:lvl1pPr-lvl9pPr")
+            Event::Eof => return Err(end_element_error(reader, "a:lvl1pPr-lvl9pPr"))
         );
     }
 
@@ -22,6 +22,7 @@ use super::ThemeElements;
 use crate::helper::const_str::*;
 use crate::reader::driver::*;
 use crate::writer::driver::*;
+use crate::XlsxError;
 use quick_xml::events::{BytesStart, Event};
 use quick_xml::Reader;
 use quick_xml::Writer;
@@ -532,8 +533,8 @@ impl Theme {
         &mut self,
         reader: &mut Reader<R>,
         e: &BytesStart,
-    ) {
-        if let Some(v) = get_attribute(e, b"name") {
+    ) -> Result<(), XlsxError> {
+        if let Some(v) = get_attribute(e, b"name")? {
             self.name.set_value(v);
         }
 
@@ -541,15 +542,15 @@ impl Theme {
             reader,
             Event::Start(ref e) => {
                 if e.name().into_inner() == b"a:themeElements" {
-                    self.theme_elements.set_attributes(reader, e);
+                    self.theme_elements.set_attributes(reader, e)?;
                 }
             },
             Event::End(ref e) => {
                 if e.name().into_inner() == b"a:theme" {
-                    return
+                    return Ok(())
                 }
             },
-            Event::Eof => panic!("Error: Could not find {} end element", "a:theme")
+            Event::Eof => return Err(end_element_error(reader, "a:theme"))
         );
     }
 
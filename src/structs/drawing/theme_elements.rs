@@ -4,6 +4,7 @@ use super::FontScheme;
 use super::FormatScheme;
 use crate::reader::driver::*;
 use crate::writer::driver::*;
+use crate::XlsxError;
 use quick_xml::events::{BytesStart, Event};
 use quick_xml::Reader;
 use quick_xml::Writer;
@@ -66,29 +67,29 @@ impl ThemeElements {
         &mut self,
         reader: &mut Reader<R>,
         _e: &BytesStart,
-    ) {
+    ) -> Result<(), XlsxError> {
         xml_read_loop!(
             reader,
             Event::Start(ref e) => {
                 match e.name().into_inner() {
                     b"a:clrScheme" => {
-                        self.color_scheme.set_attributes(reader, e);
+                        self.color_scheme.set_attributes(reader, e)?;
                     }
                     b"a:fontScheme" => {
-                        self.font_scheme.set_attributes(reader, e);
+                        self.font_scheme.set_attributes(reader, e)?;
                     }
                     b"a:fmtScheme" => {
-                        self.format_scheme.set_attributes(reader, e);
+                        self.format_scheme.set_attributes(reader, e)?;
                     }
                     _ => (),
                 }
             },
             Event::End(ref e) => {
                 if e.name().into_inner() == b"a:themeElements" {
-                    return
+                    return Ok(())
                 }
             },
-            Event::Eof => panic!("Error: Could not find {} end element", "a:themeElements")
+            Event::Eof => return Err(end_element_error(reader, "a:themeElements"))
         );
     }
 
@@ -4,6 +4,7 @@ use crate::structs::drawing::Point2DType;
 use crate::structs::drawing::PositiveSize2DType;
 use crate::writer::driver::*;
 use crate::StringValue;
+use crate::XlsxError;
 use quick_xml::events::{BytesStart, Event};
 use quick_xml::Reader;
 use quick_xml::Writer;
@@ -115,16 +116,16 @@ impl Transform2D {
         &mut self,
         reader: &mut Reader<R>,
         e: &BytesStart,
-    ) {
-        if let Some(v) = get_attribute(e, b"rot") {
+    ) -> Result<(), XlsxError> {
+        if let Some(v) = get_attribute(e, b"rot")? {
             self.set_rot(v);
         }
 
-        if let Some(v) = get_attribute(e, b"flipH") {
+        if let Some(v) = get_attribute(e, b"flipH")? {
             self.set_flip_h(v);
         }
 
-        if let Some(v) = get_attribute(e, b"flipV") {
+        if let Some(v) = get_attribute(e, b"flipV")? {
             self.set_flip_v(v);
         }
 
@@ -133,19 +134,19 @@ impl Transform2D {
             Event::Empty(ref e) => {
                 match e.name().into_inner() {
                     b"a:off" => {
-                        self.offset.set_attributes(reader, e);
+                        self.offset.set_attributes(reader, e)?;
                     }
                     b"a:ext" => {
-                        self.extents.set_attributes(reader, e);
+                        self.extents.set_attributes(reader, e)?;
                     }
                     b"a:chOff" => {
                         let mut obj = Point2DType::default();
-                        obj.set_attributes(reader, e);
+                        obj.set_attributes(reader, e)?;
                         self.set_child_offset(obj);
                     }
                     b"a:chExt" => {
                         let mut obj = PositiveSize2DType::default();
-                        obj.set_attributes(reader, e);
+                        obj.set_attributes(reader, e)?;
                         self.set_child_extents(obj);
                     }
                     _ => (),
@@ -153,10 +154,10 @@ impl Transform2D {
             },
             Event::End(ref e) => {
                 if e.name().into_inner() == b"a:xfrm" {
-                    return;
+                    return Ok(());
                 }
             },
-            Event::Eof => panic!("Error: Could not find {} end element", "a:xfrm")
+            Event::Eof => return Err(end_element_error(reader, "a:xfrm"))
         );
     }
 
@@ -0,0 +1,89 @@
+// dynamicFilter
+use super::DoubleValue;
+use super::DynamicFilterValues;
+use super::EnumValue;
+use crate::reader::driver::*;
+use crate::writer::driver::*;
+use crate::XlsxError;
+use quick_xml::events::BytesStart;
+use quick_xml::Reader;
+use quick_xml::Writer;
+use std::io::Cursor;
+
+#[derive(Clone, Default, Debug)]
+pub struct DynamicFilter {
+    r#type: EnumValue<DynamicFilterValues>,
+    val: DoubleValue,
+    max_val: DoubleValue,
+}
+
+impl DynamicFilter {
+    #[inline]
+    pub fn get_type(&self) -> &DynamicFilterValues {
+        self.r#type.get_value()
+    }
+
+    #[inline]
+    pub fn set_type(&mut self, value: DynamicFilterValues) -> &mut Self {
+        self.r#type.set_value(value);
+        self
+    }
+
+    /// The average or the start date calculated by the application that last applied the filter.
+    #[inline]
+    pub fn get_val(&self) -> Option<&f64> {
+        if self.val.has_value() {
+            return Some(self.val.get_value());
+        }
+        None
+    }
+
+    #[inline]
+    pub fn set_val(&mut self, value: f64) -> &mut Self {
+        self.val.set_value(value);
+        self
+    }
+
+    /// The end date calculated by the application that last applied the filter.
+    #[inline]
+    pub fn get_max_val(&self) -> Option<&f64> {
+        if self.max_val.has_value() {
+            return Some(self.max_val.get_value());
+        }
+        None
+    }
+
+    #[inline]
+    pub fn set_max_val(&mut self, value: f64) -> &mut Self {
+        self.max_val.set_value(value);
+        self
+    }
+
+    #[inline]
+    pub(crate) fn set_attributes<R: std::io::BufRead>(
+        &mut self,
+        _reader: &mut Reader<R>,
+        e: &BytesStart,
+    ) -> Result<(), XlsxError> {
+        set_string_from_xml!(self, e, r#type, "type");
+        set_string_from_xml!(self, e, val, "val");
+        set_string_from_xml!(self, e, max_val, "maxVal");
+        Ok(())
+    }
+
+    #[inline]
+    pub(crate) fn write_to(&self, writer: &mut Writer<Cursor<Vec<u8>>>) {
+        // dynamicFilter
+        let mut attributes: Vec<(&str, &str)> = Vec::new();
+        attributes.push(("type", self.r#type.get_value_string()));
+        let val = self.val.get_value_string();
+        if self.val.has_value() {
+            attributes.push(("val", &val));
+        }
+        let max_val = self.max_val.get_value_string();
+        if self.max_val.has_value() {
+            attributes.push(("maxVal", &max_val));
+        }
+        write_start_tag(writer, "dynamicFilter", attributes, true);
+    }
+}
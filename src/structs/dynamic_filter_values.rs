@@ -0,0 +1,133 @@
+use super::EnumTrait;
+use std::str::FromStr;
+#[derive(Clone, Debug, Eq, Ord, PartialEq, PartialOrd)]
+pub enum DynamicFilterValues {
+    AboveAverage,
+    BelowAverage,
+    LastMonth,
+    LastQuarter,
+    LastWeek,
+    LastYear,
+    January,
+    October,
+    November,
+    December,
+    February,
+    March,
+    April,
+    May,
+    June,
+    July,
+    August,
+    September,
+    NextMonth,
+    NextQuarter,
+    NextWeek,
+    NextYear,
+    Null,
+    Quarter1,
+    Quarter2,
+    Quarter3,
+    Quarter4,
+    ThisMonth,
+    ThisQuarter,
+    ThisWeek,
+    ThisYear,
+    Today,
+    Tomorrow,
+    YearToDate,
+    Yesterday,
+}
+impl Default for DynamicFilterValues {
+    #[inline]
+    fn default() -> Self {
+        Self::Null
+    }
+}
+impl EnumTrait for DynamicFilterValues {
+    #[inline]
+    fn get_value_string(&self) -> &str {
+        match &self {
+            Self::AboveAverage => "aboveAverage",
+            Self::BelowAverage => "belowAverage",
+            Self::LastMonth => "lastMonth",
+            Self::LastQuarter => "lastQuarter",
+            Self::LastWeek => "lastWeek",
+            Self::LastYear => "lastYear",
+            Self::January => "M1",
+            Self::October => "M10",
+            Self::November => "M11",
+            Self::December => "M12",
+            Self::February => "M2",
+            Self::March => "M3",
+            Self::April => "M4",
+            Self::May => "M5",
+            Self::June => "M6",
+            Self::July => "M7",
+            Self::August => "M8",
+            Self::September => "M9",
+            Self::NextMonth => "nextMonth",
+            Self::NextQuarter => "nextQuarter",
+            Self::NextWeek => "nextWeek",
+            Self::NextYear => "nextYear",
+            Self::Null => "null",
+            Self::Quarter1 => "Q1",
+            Self::Quarter2 => "Q2",
+            Self::Quarter3 => "Q3",
+            Self::Quarter4 => "Q4",
+            Self::ThisMonth => "thisMonth",
+            Self::ThisQuarter => "thisQuarter",
+            Self::ThisWeek => "thisWeek",
+            Self::ThisYear => "thisYear",
+            Self::Today => "today",
+            Self::Tomorrow => "tomorrow",
+            Self::YearToDate => "yearToDate",
+            Self::Yesterday => "yesterday",
+        }
+    }
+}
+impl FromStr for DynamicFilterValues {
+    type Err = ();
+
+    #[inline]
+    fn from_str(input: &str) -> Result<Self, Self::Err> {
+        match input {
+            "aboveAverage" => Ok(Self::AboveAverage),
+            "belowAverage" => Ok(Self::BelowAverage),
+            "lastMonth" => Ok(Self::LastMonth),
+            "lastQuarter" => Ok(Self::LastQuarter),
+            "lastWeek" => Ok(Self::LastWeek),
+            "lastYear" => Ok(Self::LastYear),
+            "M1" => Ok(Self::January),
+            "M10" => Ok(Self::October),
+            "M11" => Ok(Self::November),
+            "M12" => Ok(Self::December),
+            "M2" => Ok(Self::February),
+            "M3" => Ok(Self::March),
+            "M4" => Ok(Self::April),
+            "M5" => Ok(Self::May),
+            "M6" => Ok(Self::June),
+            "M7" => Ok(Self::July),
+            "M8" => Ok(Self::August),
+            "M9" => Ok(Self::September),
+            "nextMonth" => Ok(Self::NextMonth),
+            "nextQuarter" => Ok(Self::NextQuarter),
+            "nextWeek" => Ok(Self::NextWeek),
+            "nextYear" => Ok(Self::NextYear),
+            "null" => Ok(Self::Null),
+            "Q1" => Ok(Self::Quarter1),
+            "Q2" => Ok(Self::Quarter2),
+            "Q3" => Ok(Self::Quarter3),
+            "Q4" => Ok(Self::Quarter4),
+            "thisMonth" => Ok(Self::ThisMonth),
+            "thisQuarter" => Ok(Self::ThisQuarter),
+            "thisWeek" => Ok(Self::ThisWeek),
+            "thisYear" => Ok(Self::ThisYear),
+            "today" => Ok(Self::Today),
+            "tomorrow" => Ok(Self::Tomorrow),
+            "yearToDate" => Ok(Self::YearToDate),
+            "yesterday" => Ok(Self::Yesterday),
+            _ => Err(()),
+        }
+    }
+}
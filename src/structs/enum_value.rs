@@ -1,4 +1,6 @@
 use super::EnumTrait;
+use crate::reader::driver::invalid_attribute_error;
+use crate::XlsxError;
 use std::str::FromStr;
 
 #[derive(Clone, Default, Debug, Eq, Ord, PartialEq, PartialOrd)]
@@ -35,6 +37,19 @@ impl<T: EnumTrait + FromStr> EnumValue<T> {
         self
     }
 
+    #[inline]
+    pub(crate) fn try_set_value_string<S: Into<String>>(
+        &mut self,
+        value: S,
+        name: &str,
+    ) -> Result<&mut EnumValue<T>, XlsxError> {
+        let value = value.into();
+        match T::from_str(&value) {
+            Ok(v) => Ok(self.set_value(v)),
+            Err(_) => Err(invalid_attribute_error(name, value)),
+        }
+    }
+
     #[inline]
     pub(crate) fn has_value(&self) -> bool {
         self.value.is_some()
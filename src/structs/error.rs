@@ -70,6 +70,67 @@ pub enum XlsxError {
     Uft8(std::string::FromUtf8Error),
     /// Cell error
     CellError(String),
+    /// The password does not match the one the file was encrypted with
+    WrongPassword,
+    /// The encrypted package is damaged or fails the data integrity check
+    CorruptedPackage(String),
+    /// The file uses an encryption scheme that is not supported
+    UnsupportedEncryption(String),
+    /// No worksheet with the given name or index
+    SheetNotFound(String),
+    /// Error while reading a part of the package
+    Part {
+        path: String,
+        source: Box<XlsxError>,
+    },
+    /// Malformed xml at the given byte position of the part
+    XmlAt {
+        position: u64,
+        source: quick_xml::Error,
+    },
+    /// The part ended before the element was closed
+    MissingEndElement {
+        element: String,
+        position: u64,
+    },
+    /// A part referenced by the package does not exist
+    MissingPart(String),
+    /// An attribute value that can not be parsed
+    InvalidAttribute {
+        name: String,
+        value: String,
+    },
+    /// A required attribute is missing
+    MissingAttribute(String),
+    /// No field with the given name in the source of the pivot table
+    PivotFieldNotFound(String),
+    /// Font data that can not be read
+    InvalidFont(String),
+    /// The pivot table has no worksheet source or cache to refresh from
+    UnsupportedPivotSource(String),
+    /// A string that is not a cell, column or row range
+    InvalidRange(String),
+    /// A worksheet with the given name already exists
+    DuplicateSheetName(String),
+    /// Rows were written before a sheet was started
+    NoActiveSheet,
+}
+impl XlsxError {
+    /// Attach the name of the part being read to the error.
+    /// A part that is not in the archive becomes `MissingPart`.
+    #[inline]
+    pub(crate) fn in_part<S: Into<String>>(self, path: S) -> Self {
+        match self {
+            XlsxError::Part { .. } | XlsxError::MissingPart(_) => self,
+            XlsxError::Zip(zip::result::ZipError::FileNotFound) => {
+                XlsxError::MissingPart(path.into())
+            }
+            _ => XlsxError::Part {
+                path: path.into(),
+                source: Box::new(self),
+            },
+        }
+    }
 }
 
 from_err!(std::io::Error, XlsxError, Io);
@@ -87,7 +148,41 @@ impl fmt::Display for XlsxError {
             Zip(s) => write!(f, "ZipError: {}", s),
             Uft8(s) => write!(f, "Uft8Error: {}", s),
             CellError(e) => write!(f, "Unsupported cell error value '{e}'"),
+            WrongPassword => write!(f, "The password is incorrect"),
+            CorruptedPackage(e) => write!(f, "The encrypted package is corrupted: {e}"),
+            UnsupportedEncryption(e) => write!(f, "Unsupported encryption: {e}"),
+            SheetNotFound(e) => write!(f, "Worksheet not found: {e}"),
+            Part { path, source } => write!(f, "Error in part '{path}': {source}"),
+            XmlAt { position, source } => write!(f, "XmlError at position {position}: {source}"),
+            MissingEndElement { element, position } => write!(
+                f,
+                "Could not find {element} end element (position {position})"
+            ),
+            MissingPart(e) => write!(f, "Part not found: {e}"),
+            InvalidAttribute { name, value } => {
+                write!(f, "Invalid value '{value}' for attribute '{name}'")
+            }
+            MissingAttribute(e) => write!(f, "Required attribute not found: {e}"),
+            PivotFieldNotFound(e) => write!(f, "Pivot table field not found: {e}"),
+            InvalidFont(e) => write!(f, "Invalid font data: {e}"),
+            UnsupportedPivotSource(e) => write!(f, "The pivot table can not be refreshed: {e}"),
+            InvalidRange(e) => write!(f, "Invalid range: {e}"),
+            DuplicateSheetName(e) => write!(f, "A worksheet named '{e}' already exists"),
+            NoActiveSheet => write!(f, "No sheet is open; call new_sheet first"),
+        }
+    }
+}
+impl std::error::Error for XlsxError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        use self::XlsxError::*;
+        match self {
+            Io(e) => Some(e),
+            Xml(e) => Some(e),
+            Zip(e) => Some(e),
+            Uft8(e) => Some(e),
+            Part { source, .. } => Some(source.as_ref()),
+            XmlAt { source, .. } => Some(source),
+            _ => None,
         }
     }
 }
-impl std::error::Error for XlsxError {}
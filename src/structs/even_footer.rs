@@ -0,0 +1,67 @@
+// evenFooter
+use crate::reader::driver::*;
+use crate::structs::StringValue;
+use crate::writer::driver::*;
+use crate::XlsxError;
+use md5::Digest;
+use quick_xml::events::{BytesStart, Event};
+use quick_xml::Reader;
+use quick_xml::Writer;
+use std::io::Cursor;
+
+#[derive(Clone, Default, Debug)]
+pub struct EvenFooter {
+    value: StringValue,
+}
+
+impl EvenFooter {
+    #[inline]
+    pub fn get_value(&self) -> &str {
+        self.value.get_value_str()
+    }
+
+    #[inline]
+    pub fn set_value<S: Into<String>>(&mut self, value: S) -> &mut Self {
+        self.value.set_value(value);
+        self
+    }
+
+    #[inline]
+    pub(crate) fn _get_hash_code(&self) -> String {
+        format!("{:x}", md5::Md5::digest(self.get_value()))
+    }
+
+    #[inline]
+    pub(crate) fn has_param(&self) -> bool {
+        self.value.has_value()
+    }
+
+    pub(crate) fn set_attributes<R: std::io::BufRead>(
+        &mut self,
+        reader: &mut Reader<R>,
+        _e: &BytesStart,
+    ) -> Result<(), XlsxError> {
+        xml_read_loop!(
+            reader,
+            Event::Text(e) => {
+                self.set_value(e.unescape()?);
+            },
+            Event::End(ref e) => {
+                if e.name().0 == b"evenFooter" {
+                    return Ok(())
+                }
+            },
+            Event::Eof => return Err(end_element_error(reader, "evenFooter"))
+        );
+    }
+
+    #[inline]
+    pub(crate) fn write_to(&self, writer: &mut Writer<Cursor<Vec<u8>>>) {
+        if self.has_param() {
+            // evenFooter
+            write_start_tag(writer, "evenFooter", vec![], false);
+            write_text_node(writer, self.value.get_value_str());
+            write_end_tag(writer, "evenFooter");
+        }
+    }
+}
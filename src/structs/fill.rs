@@ -3,6 +3,7 @@ use super::PatternFill;
 use super::PatternValues;
 use crate::reader::driver::*;
 use crate::writer::driver::*;
+use crate::XlsxError;
 use md5::Digest;
 use quick_xml::events::{BytesStart, Event};
 use quick_xml::Reader;
@@ -115,13 +116,13 @@ impl Fill {
         &mut self,
         reader: &mut Reader<R>,
         _e: &BytesStart,
-    ) {
+    ) -> Result<(), XlsxError> {
         xml_read_loop!(
             reader,
             Event::Empty(ref e) => {
                 if e.name().into_inner() == b"patternFill" {
                     let mut obj = PatternFill::default();
-                    obj.set_attributes(reader, e, true);
+                    obj.set_attributes(reader, e, true)?;
                     self.set_pattern_fill(obj);
                 }
             },
@@ -129,12 +130,12 @@ impl Fill {
                 match e.name().into_inner() {
                     b"patternFill" => {
                         let mut obj = PatternFill::default();
-                        obj.set_attributes(reader, e, false);
+                        obj.set_attributes(reader, e, false)?;
                         self.set_pattern_fill(obj);
                     }
                     b"gradientFill" => {
                         let mut obj = GradientFill::default();
-                        obj.set_attributes(reader, e);
+                        obj.set_attributes(reader, e)?;
                         self.set_gradient_fill(obj);
                     }
                     _ => (),
@@ -142,10 +143,10 @@ impl Fill {
             },
             Event::End(ref e) => {
                 if e.name().into_inner() == b"fill" {
-                    return
+                    return Ok(())
                 }
             },
-            Event::Eof => panic!("Error: Could not find {} end element", "fill")
+            Event::Eof => return Err(end_element_error(reader, "fill"))
         );
     }
 
@@ -3,6 +3,7 @@ use super::Fill;
 use super::Style;
 use crate::reader::driver::*;
 use crate::writer::driver::*;
+use crate::XlsxError;
 use quick_xml::events::{BytesStart, Event};
 use quick_xml::Reader;
 use quick_xml::Writer;
@@ -53,22 +54,22 @@ impl Fills {
         &mut self,
         reader: &mut Reader<R>,
         _e: &BytesStart,
-    ) {
+    ) -> Result<(), XlsxError> {
         xml_read_loop!(
             reader,
             Event::Start(ref e) => {
                 if e.name().into_inner() == b"fill" {
                     let mut obj = Fill::default();
-                    obj.set_attributes(reader, e);
+                    obj.set_attributes(reader, e)?;
                     self.set_fill(obj);
                 }
             },
             Event::End(ref e) => {
                 if e.name().into_inner() == b"fills" {
-                    return
+                    return Ok(())
                 }
             },
-            Event::Eof => panic!("Error: Could not find {} end element", "fills")
+            Event::Eof => return Err(end_element_error(reader, "fills"))
         );
     }
 
@@ -0,0 +1,48 @@
+// filter
+use super::StringValue;
+use crate::reader::driver::*;
+use crate::writer::driver::*;
+use crate::XlsxError;
+use quick_xml::events::BytesStart;
+use quick_xml::Reader;
+use quick_xml::Writer;
+use std::io::Cursor;
+
+#[derive(Clone, Default, Debug)]
+pub struct Filter {
+    val: StringValue,
+}
+
+impl Filter {
+    #[inline]
+    pub fn get_val(&self) -> &str {
+        self.val.get_value_str()
+    }
+
+    #[inline]
+    pub fn set_val<S: Into<String>>(&mut self, value: S) -> &mut Self {
+        self.val.set_value(value);
+        self
+    }
+
+    #[inline]
+    pub(crate) fn set_attributes<R: std::io::BufRead>(
+        &mut self,
+        _reader: &mut Reader<R>,
+        e: &BytesStart,
+    ) -> Result<(), XlsxError> {
+        set_string_from_xml!(self, e, val, "val");
+        Ok(())
+    }
+
+    #[inline]
+    pub(crate) fn write_to(&self, writer: &mut Writer<Cursor<Vec<u8>>>) {
+        // filter
+        write_start_tag(
+            writer,
+            "filter",
+            vec![("val", self.val.get_value_str())],
+            true,
+        );
+    }
+}
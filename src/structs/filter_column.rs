@@ -0,0 +1,299 @@
+// filterColumn
+use super::BooleanValue;
+use super::ColorFilter;
+use super::CustomFilters;
+use super::DifferentialFormats;
+use super::DynamicFilter;
+use super::Filters;
+use super::IconFilter;
+use super::Top10;
+use super::UInt32Value;
+use crate::reader::driver::*;
+use crate::writer::driver::*;
+use crate::XlsxError;
+use quick_xml::events::{BytesStart, Event};
+use quick_xml::Reader;
+use quick_xml::Writer;
+use std::io::Cursor;
+
+#[derive(Clone, Default, Debug)]
+pub struct FilterColumn {
+    column_id: UInt32Value,
+    hidden_button: BooleanValue,
+    show_button: BooleanValue,
+    filters: Option<Box<Filters>>,
+    custom_filters: Option<Box<CustomFilters>>,
+    top10: Option<Box<Top10>>,
+    dynamic_filter: Option<Box<DynamicFilter>>,
+    color_filter: Option<Box<ColorFilter>>,
+    icon_filter: Option<Box<IconFilter>>,
+}
+
+impl FilterColumn {
+    /// Zero-based column offset from the first column of the auto filter range.
+    #[inline]
+    pub fn get_column_id(&self) -> &u32 {
+        self.column_id.get_value()
+    }
+
+    #[inline]
+    pub fn set_column_id(&mut self, value: u32) -> &mut Self {
+        self.column_id.set_value(value);
+        self
+    }
+
+    #[inline]
+    pub fn get_hidden_button(&self) -> &bool {
+        self.hidden_button.get_value()
+    }
+
+    #[inline]
+    pub fn set_hidden_button(&mut self, value: bool) -> &mut Self {
+        self.hidden_button.set_value(value);
+        self
+    }
+
+    #[inline]
+    pub fn get_show_button(&self) -> &bool {
+        if self.show_button.has_value() {
+            return self.show_button.get_value();
+        }
+        &true
+    }
+
+    #[inline]
+    pub fn set_show_button(&mut self, value: bool) -> &mut Self {
+        self.show_button.set_value(value);
+        self
+    }
+
+    #[inline]
+    pub fn get_filters(&self) -> Option<&Filters> {
+        self.filters.as_deref()
+    }
+
+    #[inline]
+    pub fn get_filters_mut(&mut self) -> Option<&mut Filters> {
+        self.filters.as_deref_mut()
+    }
+
+    /// Set a value list filter. Replaces any other criteria of the column.
+    #[inline]
+    pub fn set_filters(&mut self, value: Filters) -> &mut Self {
+        self.remove_criteria();
+        self.filters = Some(Box::new(value));
+        self
+    }
+
+    #[inline]
+    pub fn get_custom_filters(&self) -> Option<&CustomFilters> {
+        self.custom_filters.as_deref()
+    }
+
+    #[inline]
+    pub fn get_custom_filters_mut(&mut self) -> Option<&mut CustomFilters> {
+        self.custom_filters.as_deref_mut()
+    }
+
+    /// Set custom conditions. Replaces any other criteria of the column.
+    #[inline]
+    pub fn set_custom_filters(&mut self, value: CustomFilters) -> &mut Self {
+        self.remove_criteria();
+        self.custom_filters = Some(Box::new(value));
+        self
+    }
+
+    #[inline]
+    pub fn get_top10(&self) -> Option<&Top10> {
+        self.top10.as_deref()
+    }
+
+    #[inline]
+    pub fn get_top10_mut(&mut self) -> Option<&mut Top10> {
+        self.top10.as_deref_mut()
+    }
+
+    /// Set a top/bottom filter. Replaces any other criteria of the column.
+    #[inline]
+    pub fn set_top10(&mut self, value: Top10) -> &mut Self {
+        self.remove_criteria();
+        self.top10 = Some(Box::new(value));
+        self
+    }
+
+    #[inline]
+    pub fn get_dynamic_filter(&self) -> Option<&DynamicFilter> {
+        self.dynamic_filter.as_deref()
+    }
+
+    #[inline]
+    pub fn get_dynamic_filter_mut(&mut self) -> Option<&mut DynamicFilter> {
+        self.dynamic_filter.as_deref_mut()
+    }
+
+    /// Set a dynamic filter. Replaces any other criteria of the column.
+    #[inline]
+    pub fn set_dynamic_filter(&mut self, value: DynamicFilter) -> &mut Self {
+        self.remove_criteria();
+        self.dynamic_filter = Some(Box::new(value));
+        self
+    }
+
+    #[inline]
+    pub fn get_color_filter(&self) -> Option<&ColorFilter> {
+        self.color_filter.as_deref()
+    }
+
+    #[inline]
+    pub fn get_color_filter_mut(&mut self) -> Option<&mut ColorFilter> {
+        self.color_filter.as_deref_mut()
+    }
+
+    /// Set a color filter. Replaces any other criteria of the column.
+    #[inline]
+    pub fn set_color_filter(&mut self, value: ColorFilter) -> &mut Self {
+        self.remove_criteria();
+        self.color_filter = Some(Box::new(value));
+        self
+    }
+
+    #[inline]
+    pub fn get_icon_filter(&self) -> Option<&IconFilter> {
+        self.icon_filter.as_deref()
+    }
+
+    #[inline]
+    pub fn get_icon_filter_mut(&mut self) -> Option<&mut IconFilter> {
+        self.icon_filter.as_deref_mut()
+    }
+
+    /// Set an icon filter. Replaces any other criteria of the column.
+    #[inline]
+    pub fn set_icon_filter(&mut self, value: IconFilter) -> &mut Self {
+        self.remove_criteria();
+        self.icon_filter = Some(Box::new(value));
+        self
+    }
+
+    #[inline]
+    pub fn remove_criteria(&mut self) -> &mut Self {
+        self.filters = None;
+        self.custom_filters = None;
+        self.top10 = None;
+        self.dynamic_filter = None;
+        self.color_filter = None;
+        self.icon_filter = None;
+        self
+    }
+
+    pub(crate) fn set_attributes<R: std::io::BufRead>(
+        &mut self,
+        reader: &mut Reader<R>,
+        e: &BytesStart,
+        differential_formats: &DifferentialFormats,
+        empty_flag: bool,
+    ) -> Result<(), XlsxError> {
+        set_string_from_xml!(self, e, column_id, "colId");
+        set_string_from_xml!(self, e, hidden_button, "hiddenButton");
+        set_string_from_xml!(self, e, show_button, "showButton");
+
+        if empty_flag {
+            return Ok(());
+        }
+
+        xml_read_loop!(
+            reader,
+            Event::Start(ref e) => match e.name().into_inner() {
+                b"filters" => {
+                    let mut obj = Filters::default();
+                    obj.set_attributes(reader, e, false)?;
+                    self.set_filters(obj);
+                }
+                b"customFilters" => {
+                    let mut obj = CustomFilters::default();
+                    obj.set_attributes(reader, e, false)?;
+                    self.set_custom_filters(obj);
+                }
+                _ => (),
+            },
+            Event::Empty(ref e) => match e.name().into_inner() {
+                b"filters" => {
+                    let mut obj = Filters::default();
+                    obj.set_attributes(reader, e, true)?;
+                    self.set_filters(obj);
+                }
+                b"customFilters" => {
+                    let mut obj = CustomFilters::default();
+                    obj.set_attributes(reader, e, true)?;
+                    self.set_custom_filters(obj);
+                }
+                b"top10" => {
+                    let mut obj = Top10::default();
+                    obj.set_attributes(reader, e)?;
+                    self.set_top10(obj);
+                }
+                b"dynamicFilter" => {
+                    let mut obj = DynamicFilter::default();
+                    obj.set_attributes(reader, e)?;
+                    self.set_dynamic_filter(obj);
+                }
+                b"colorFilter" => {
+                    let mut obj = ColorFilter::default();
+                    obj.set_attributes(reader, e, differential_formats)?;
+                    self.set_color_filter(obj);
+                }
+                b"iconFilter" => {
+                    let mut obj = IconFilter::default();
+                    obj.set_attributes(reader, e)?;
+                    self.set_icon_filter(obj);
+                }
+                _ => (),
+            },
+            Event::End(ref e) => {
+                if e.name().into_inner() == b"filterColumn" {
+                    return Ok(())
+                }
+            },
+            Event::Eof => return Err(end_element_error(reader, "filterColumn"))
+        );
+    }
+
+    pub(crate) fn write_to(
+        &self,
+        writer: &mut Writer<Cursor<Vec<u8>>>,
+        differential_formats: &mut DifferentialFormats,
+    ) {
+        // filterColumn
+        let mut attributes: Vec<(&str, &str)> = Vec::new();
+        let column_id = self.column_id.get_value_string();
+        attributes.push(("colId", &column_id));
+        if *self.hidden_button.get_value() {
+            attributes.push(("hiddenButton", self.hidden_button.get_value_string()));
+        }
+        if !*self.get_show_button() {
+            attributes.push(("showButton", self.show_button.get_value_string()));
+        }
+        write_start_tag(writer, "filterColumn", attributes, false);
+
+        if let Some(v) = &self.filters {
+            v.write_to(writer);
+        }
+        if let Some(v) = &self.top10 {
+            v.write_to(writer);
+        }
+        if let Some(v) = &self.custom_filters {
+            v.write_to(writer);
+        }
+        if let Some(v) = &self.dynamic_filter {
+            v.write_to(writer);
+        }
+        if let Some(v) = &self.color_filter {
+            v.write_to(writer, differential_formats);
+        }
+        if let Some(v) = &self.icon_filter {
+            v.write_to(writer);
+        }
+
+        write_end_tag(writer, "filterColumn");
+    }
+}
@@ -3,6 +3,7 @@ use crate::reader::driver::*;
 use crate::structs::Font;
 use crate::structs::Style;
 use crate::writer::driver::*;
+use crate::XlsxError;
 use quick_xml::events::{BytesStart, Event};
 use quick_xml::Reader;
 use quick_xml::Writer;
@@ -53,7 +54,7 @@ impl Fonts {
         &mut self,
         reader: &mut Reader<R>,
         _e: &BytesStart,
-    ) {
+    ) -> Result<(), XlsxError> {
         xml_read_loop!(
             reader,
             Event::Empty(ref e) => {
@@ -65,16 +66,16 @@ impl Fonts {
             Event::Start(ref e) => {
                 if e.name().into_inner() == b"font" {
                     let mut obj = Font::default();
-                    obj.set_attributes(reader, e);
+                    obj.set_attributes(reader, e)?;
                     self.set_font(obj);
                 }
             },
             Event::End(ref e) => {
                 if e.name().into_inner() == b"fonts" {
-                    return
+                    return Ok(())
                 }
             },
-            Event::Eof => panic!("Error: Could not find {} end element", "fonts")
+            Event::Eof => return Err(end_element_error(reader, "fonts"))
         );
     }
 
@@ -4,6 +4,7 @@ use super::StringValue;
 use crate::helper::address::*;
 use crate::reader::driver::*;
 use crate::writer::driver::*;
+use crate::XlsxError;
 use quick_xml::events::{BytesStart, Event};
 use quick_xml::Reader;
 use quick_xml::Writer;
@@ -63,18 +64,18 @@ impl Formula {
         &mut self,
         reader: &mut Reader<R>,
         _e: &BytesStart,
-    ) {
+    ) -> Result<(), XlsxError> {
         xml_read_loop!(
             reader,
             Event::Text(e) => {
-                self.set_address_str(e.unescape().unwrap());
+                self.set_address_str(e.unescape()?);
             },
             Event::End(ref e) => {
                 if e.name().into_inner() == b"formula" {
-                    return
+                    return Ok(())
                 }
             },
-            Event::Eof => panic!("Error: Could not find {} end element", "formula")
+            Event::Eof => return Err(end_element_error(reader, "formula"))
         );
     }
 
@@ -1,6 +1,7 @@
 // from
 use crate::reader::driver::*;
 use crate::writer::driver::*;
+use crate::XlsxError;
 use quick_xml::events::{BytesStart, Event};
 use quick_xml::Reader;
 use quick_xml::Writer;
@@ -91,28 +92,28 @@ impl FromMarker {
         &mut self,
         reader: &mut Reader<R>,
         _e: &BytesStart,
-    ) {
+    ) -> Result<(), XlsxError> {
         let mut string_value: String = String::new();
         xml_read_loop!(
             reader,
-            Event::Text(e) => string_value = e.unescape().unwrap().to_string(),
+            Event::Text(e) => string_value = e.unescape()?.to_string(),
             Event::End(ref e) => match e.name().into_inner() {
                 b"xdr:col" => {
-                    self.col = string_value.parse::<usize>().unwrap();
+                    self.col = parse_value("xdr:col", &string_value)?;
                 }
                 b"xdr:colOff" => {
-                    self.col_off = string_value.parse::<usize>().unwrap();
+                    self.col_off = parse_value("xdr:colOff", &string_value)?;
                 }
                 b"xdr:row" => {
-                    self.row = string_value.parse::<usize>().unwrap();
+                    self.row = parse_value("xdr:row", &string_value)?;
                 }
                 b"xdr:rowOff" => {
-                    self.row_off = string_value.parse::<usize>().unwrap();
+                    self.row_off = parse_value("xdr:rowOff", &string_value)?;
                 }
-                b"from" => return,
+                b"from" => return Ok(()),
                 _ => (),
             },
-            Event::Eof => panic!("Error: Could not find {} end element", "from")
+            Event::Eof => return Err(end_element_error(reader, "from"))
         );
     }
 
@@ -3,6 +3,7 @@ use super::DoubleValue;
 use super::GradientStop;
 use crate::reader::driver::*;
 use crate::writer::driver::*;
+use crate::XlsxError;
 use md5::Digest;
 use quick_xml::events::{BytesStart, Event};
 use quick_xml::Reader;
@@ -60,7 +61,7 @@ impl GradientFill {
         &mut self,
         reader: &mut Reader<R>,
         e: &BytesStart,
-    ) {
+    ) -> Result<(), XlsxError> {
         set_string_from_xml!(self, e, degree, "degree");
 
         xml_read_loop!(
@@ -68,16 +69,16 @@ impl GradientFill {
             Event::Start(ref e) => {
                 if e.name().into_inner() == b"stop" {
                     let mut obj = GradientStop::default();
-                    obj.set_attributes(reader, e);
+                    obj.set_attributes(reader, e)?;
                     self.set_gradient_stop(obj);
                 }
             },
             Event::End(ref e) => {
                 if e.name().into_inner() == b"gradientFill" {
-                    return
+                    return Ok(())
                 }
             },
-            Event::Eof => panic!("Error: Could not find {} end element", "gradientFill")
+            Event::Eof => return Err(end_element_error(reader, "gradientFill"))
         );
     }
 
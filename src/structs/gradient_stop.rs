@@ -3,6 +3,7 @@ use super::Color;
 use super::DoubleValue;
 use crate::reader::driver::*;
 use crate::writer::driver::*;
+use crate::XlsxError;
 use md5::Digest;
 use quick_xml::events::{BytesStart, Event};
 use quick_xml::Reader;
@@ -59,7 +60,7 @@ impl GradientStop {
         &mut self,
         reader: &mut Reader<R>,
         e: &BytesStart,
-    ) {
+    ) -> Result<(), XlsxError> {
         set_string_from_xml!(self, e, position, "position");
 
         xml_read_loop!(
@@ -67,16 +68,16 @@ impl GradientStop {
             Event::Empty(ref e) => {
                 if e.name().into_inner() == b"color" {
                     let mut obj = Color::default();
-                    obj.set_attributes(reader, e, true);
+                    obj.set_attributes(reader, e, true)?;
                     self.set_color(obj);
                 }
             },
             Event::End(ref e) => {
                 if e.name().into_inner() == b"stop" {
-                    return
+                    return Ok(())
                 }
             },
-            Event::Eof => panic!("Error: Could not find {} end element", "stop")
+            Event::Eof => return Err(end_element_error(reader, "stop"))
         );
     }
 
@@ -1,20 +1,91 @@
 // headerFooter
 use crate::reader::driver::*;
+use crate::structs::BooleanValue;
+use crate::structs::EvenFooter;
+use crate::structs::EvenHeader;
+use crate::structs::FirstFooter;
+use crate::structs::FirstHeader;
+use crate::structs::HeaderFooterImage;
 use crate::structs::OddFooter;
 use crate::structs::OddHeader;
 use crate::writer::driver::*;
+use crate::XlsxError;
 use quick_xml::events::{BytesStart, Event};
 use quick_xml::Reader;
 use quick_xml::Writer;
 use std::io::Cursor;
+use thin_vec::ThinVec;
 
 #[derive(Clone, Default, Debug)]
 pub struct HeaderFooter {
+    different_odd_even: BooleanValue,
+    different_first: BooleanValue,
+    scale_with_doc: BooleanValue,
+    align_with_margins: BooleanValue,
     odd_header: OddHeader,
     odd_footer: OddFooter,
+    even_header: EvenHeader,
+    even_footer: EvenFooter,
+    first_header: FirstHeader,
+    first_footer: FirstFooter,
+    images: ThinVec<HeaderFooterImage>,
 }
 
 impl HeaderFooter {
+    /// Use the even page header and footer on even pages.
+    #[inline]
+    pub fn get_different_odd_even(&self) -> &bool {
+        self.different_odd_even.get_value()
+    }
+
+    #[inline]
+    pub fn set_different_odd_even(&mut self, value: bool) -> &mut Self {
+        self.different_odd_even.set_value(value);
+        self
+    }
+
+    /// Use the first page header and footer on the first page.
+    #[inline]
+    pub fn get_different_first(&self) -> &bool {
+        self.different_first.get_value()
+    }
+
+    #[inline]
+    pub fn set_different_first(&mut self, value: bool) -> &mut Self {
+        self.different_first.set_value(value);
+        self
+    }
+
+    /// Scale the header and footer with the document. Defaults to `true`.
+    #[inline]
+    pub fn get_scale_with_doc(&self) -> &bool {
+        match self.scale_with_doc.has_value() {
+            true => self.scale_with_doc.get_value(),
+            false => &true,
+        }
+    }
+
+    #[inline]
+    pub fn set_scale_with_doc(&mut self, value: bool) -> &mut Self {
+        self.scale_with_doc.set_value(value);
+        self
+    }
+
+    /// Align the header and footer with the page margins. Defaults to `true`.
+    #[inline]
+    pub fn get_align_with_margins(&self) -> &bool {
+        match self.align_with_margins.has_value() {
+            true => self.align_with_margins.get_value(),
+            false => &true,
+        }
+    }
+
+    #[inline]
+    pub fn set_align_with_margins(&mut self, value: bool) -> &mut Self {
+        self.align_with_margins.set_value(value);
+        self
+    }
+
     #[inline]
     pub fn get_odd_header(&self) -> &OddHeader {
         &self.odd_header
@@ -47,42 +118,180 @@ impl HeaderFooter {
         self
     }
 
+    #[inline]
+    pub fn get_even_header(&self) -> &EvenHeader {
+        &self.even_header
+    }
+
+    #[inline]
+    pub fn get_even_header_mut(&mut self) -> &mut EvenHeader {
+        &mut self.even_header
+    }
+
+    #[inline]
+    pub fn set_even_header(&mut self, value: EvenHeader) -> &mut Self {
+        self.even_header = value;
+        self
+    }
+
+    #[inline]
+    pub fn get_even_footer(&self) -> &EvenFooter {
+        &self.even_footer
+    }
+
+    #[inline]
+    pub fn get_even_footer_mut(&mut self) -> &mut EvenFooter {
+        &mut self.even_footer
+    }
+
+    #[inline]
+    pub fn set_even_footer(&mut self, value: EvenFooter) -> &mut Self {
+        self.even_footer = value;
+        self
+    }
+
+    #[inline]
+    pub fn get_first_header(&self) -> &FirstHeader {
+        &self.first_header
+    }
+
+    #[inline]
+    pub fn get_first_header_mut(&mut self) -> &mut FirstHeader {
+        &mut self.first_header
+    }
+
+    #[inline]
+    pub fn set_first_header(&mut self, value: FirstHeader) -> &mut Self {
+        self.first_header = value;
+        self
+    }
+
+    #[inline]
+    pub fn get_first_footer(&self) -> &FirstFooter {
+        &self.first_footer
+    }
+
+    #[inline]
+    pub fn get_first_footer_mut(&mut self) -> &mut FirstFooter {
+        &mut self.first_footer
+    }
+
+    #[inline]
+    pub fn set_first_footer(&mut self, value: FirstFooter) -> &mut Self {
+        self.first_footer = value;
+        self
+    }
+
+    /// Pictures drawn by the `&G` codes.
+    #[inline]
+    pub fn get_images(&self) -> &[HeaderFooterImage] {
+        &self.images
+    }
+
+    #[inline]
+    pub fn get_images_mut(&mut self) -> &mut ThinVec<HeaderFooterImage> {
+        &mut self.images
+    }
+
+    /// Add a picture. A picture already at the same position is replaced.
+    pub fn add_image(&mut self, value: HeaderFooterImage) -> &mut Self {
+        self.images
+            .retain(|v| v.get_position() != value.get_position());
+        self.images.push(value);
+        self
+    }
+
+    #[inline]
+    pub(crate) fn has_images(&self) -> bool {
+        !self.images.is_empty()
+    }
+
     #[inline]
     pub(crate) fn has_param(&self) -> bool {
-        self.odd_header.has_param() || self.odd_footer.has_param()
+        self.different_odd_even.has_value()
+            || self.different_first.has_value()
+            || self.scale_with_doc.has_value()
+            || self.align_with_margins.has_value()
+            || self.odd_header.has_param()
+            || self.odd_footer.has_param()
+            || self.even_header.has_param()
+            || self.even_footer.has_param()
+            || self.first_header.has_param()
+            || self.first_footer.has_param()
     }
 
     pub(crate) fn set_attributes<R: std::io::BufRead>(
         &mut self,
         reader: &mut Reader<R>,
-        _e: &BytesStart,
-    ) {
+        e: &BytesStart,
+        empty_flag: bool,
+    ) -> Result<(), XlsxError> {
+        set_string_from_xml!(self, e, different_odd_even, "differentOddEven");
+        set_string_from_xml!(self, e, different_first, "differentFirst");
+        set_string_from_xml!(self, e, scale_with_doc, "scaleWithDoc");
+        set_string_from_xml!(self, e, align_with_margins, "alignWithMargins");
+
+        if empty_flag {
+            return Ok(());
+        }
+
         xml_read_loop!(
             reader,
             Event::Start(ref e) => {
                 match e.name().into_inner() {
                 b"oddHeader" => {
-                    self.odd_header.set_attributes(reader, e);
+                    self.odd_header.set_attributes(reader, e)?;
                 }
                 b"oddFooter" => {
-                    self.odd_footer.set_attributes(reader, e);
+                    self.odd_footer.set_attributes(reader, e)?;
+                }
+                b"evenHeader" => {
+                    self.even_header.set_attributes(reader, e)?;
+                }
+                b"evenFooter" => {
+                    self.even_footer.set_attributes(reader, e)?;
+                }
+                b"firstHeader" => {
+                    self.first_header.set_attributes(reader, e)?;
+                }
+                b"firstFooter" => {
+                    self.first_footer.set_attributes(reader, e)?;
                 }
                 _ => (),
                 }
             },
             Event::End(ref e) => {
                 if e.name().into_inner() == b"headerFooter" {
-                    return
+                    return Ok(())
                 }
             },
-            Event::Eof => panic!("Error: Could not find {} end element", "headerFooter")
+            Event::Eof => return Err(end_element_error(reader, "headerFooter"))
         );
     }
 
     pub(crate) fn write_to(&self, writer: &mut Writer<Cursor<Vec<u8>>>) {
         if self.has_param() {
             // headerFooter
-            write_start_tag(writer, "headerFooter", vec![], false);
+            let mut attributes: Vec<(&str, &str)> = Vec::new();
+            if self.different_odd_even.has_value() {
+                attributes.push((
+                    "differentOddEven",
+                    self.different_odd_even.get_value_string(),
+                ));
+            }
+            if self.different_first.has_value() {
+                attributes.push(("differentFirst", self.different_first.get_value_string()));
+            }
+            if self.scale_with_doc.has_value() {
+                attributes.push(("scaleWithDoc", self.scale_with_doc.get_value_string()));
+            }
+            if self.align_with_margins.has_value() {
+                attributes.push((
+                    "alignWithMargins",
+                    self.align_with_margins.get_value_string(),
+                ));
+            }
+            write_start_tag(writer, "headerFooter", attributes, false);
 
             // oddHeader
             self.get_odd_header().write_to(writer);
@@ -90,6 +299,18 @@ impl HeaderFooter {
             // oddFooter
             self.get_odd_footer().write_to(writer);
 
+            // evenHeader
+            self.get_even_header().write_to(writer);
+
+            // evenFooter
+            self.get_even_footer().write_to(writer);
+
+            // firstHeader
+            self.get_first_header().write_to(writer);
+
+            // firstFooter
+            self.get_first_footer().write_to(writer);
+
             write_end_tag(writer, "headerFooter");
         }
     }
@@ -0,0 +1,127 @@
+use crate::structs::HeaderFooterSection;
+
+/// Composes the value of a header or footer from its left, center and right parts.
+/// # Examples
+/// ```
+/// use umya_spreadsheet::*;
+/// let mut builder = HeaderFooterBuilder::default();
+/// builder.get_left_mut().add_sheet_name();
+/// builder
+///     .get_center_mut()
+///     .set_font("Arial", "Bold")
+///     .set_font_size(12)
+///     .add_text("Sales & Costs");
+/// builder
+///     .get_right_mut()
+///     .add_text("Page ")
+///     .add_page_number()
+///     .add_text(" of ")
+///     .add_page_count();
+/// assert_eq!(
+///     builder.build(),
+///     "&L&A&C&\"Arial,Bold\"&12Sales && Costs&RPage &P of &N"
+/// );
+///
+/// let mut book = new_file();
+/// let header_footer = book.get_sheet_mut(&0).unwrap().get_header_footer_mut();
+/// header_footer.get_odd_header_mut().set_value(builder.build());
+/// ```
+#[derive(Clone, Default, Debug)]
+pub struct HeaderFooterBuilder {
+    left: HeaderFooterSection,
+    center: HeaderFooterSection,
+    right: HeaderFooterSection,
+}
+
+impl HeaderFooterBuilder {
+    #[inline]
+    pub fn get_left(&self) -> &HeaderFooterSection {
+        &self.left
+    }
+
+    #[inline]
+    pub fn get_left_mut(&mut self) -> &mut HeaderFooterSection {
+        &mut self.left
+    }
+
+    #[inline]
+    pub fn set_left(&mut self, value: HeaderFooterSection) -> &mut Self {
+        self.left = value;
+        self
+    }
+
+    #[inline]
+    pub fn get_center(&self) -> &HeaderFooterSection {
+        &self.center
+    }
+
+    #[inline]
+    pub fn get_center_mut(&mut self) -> &mut HeaderFooterSection {
+        &mut self.center
+    }
+
+    #[inline]
+    pub fn set_center(&mut self, value: HeaderFooterSection) -> &mut Self {
+        self.center = value;
+        self
+    }
+
+    #[inline]
+    pub fn get_right(&self) -> &HeaderFooterSection {
+        &self.right
+    }
+
+    #[inline]
+    pub fn get_right_mut(&mut self) -> &mut HeaderFooterSection {
+        &mut self.right
+    }
+
+    #[inline]
+    pub fn set_right(&mut self, value: HeaderFooterSection) -> &mut Self {
+        self.right = value;
+        self
+    }
+
+    /// Value with the `&L`, `&C` and `&R` codes of the parts that are not empty.
+    pub fn build(&self) -> String {
+        let mut result = String::new();
+        for (code, section) in [
+            ("&L", &self.left),
+            ("&C", &self.center),
+            ("&R", &self.right),
+        ] {
+            if !section.is_empty() {
+                result.push_str(code);
+                result.push_str(section.get_value());
+            }
+        }
+        result
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn build() {
+        let mut builder = HeaderFooterBuilder::default();
+        assert_eq!(builder.build(), "");
+
+        builder
+            .get_center_mut()
+            .set_font_size(14)
+            .add_text("2024 report")
+            .toggle_bold()
+            .set_color("FFFF0000")
+            .add_date()
+            .add_time();
+        builder.get_right_mut().add_file_name().add_picture();
+        assert_eq!(builder.build(), "&C&14 2024 report&B&KFF0000&D&T&R&F&G");
+
+        let mut section = HeaderFooterSection::default();
+        section.set_font("-", "Italic").add_text("A&B");
+        builder.set_left(section);
+        assert!(builder.build().starts_with("&L&\"-,Italic\"A&&B&C"));
+    }
+}
@@ -0,0 +1,218 @@
+// v:shape (legacyDrawingHF)
+use crate::reader::driver::*;
+use crate::structs::raw::RawRelationships;
+use crate::structs::DoubleValue;
+use crate::structs::MediaObject;
+use crate::structs::StringValue;
+use crate::writer::driver::*;
+use crate::XlsxError;
+use quick_xml::events::{BytesStart, Event};
+use quick_xml::Reader;
+use quick_xml::Writer;
+use std::io::Cursor;
+
+/// Picture drawn by the `&G` code of a header or footer.
+#[derive(Clone, Default, Debug)]
+pub struct HeaderFooterImage {
+    position: StringValue,
+    image: MediaObject,
+    width: DoubleValue,
+    height: DoubleValue,
+}
+
+impl HeaderFooterImage {
+    /// Section the picture belongs to.
+    /// `L`, `C` or `R`, then `H` (header) or `F` (footer),
+    /// then `EVEN` or `FIRST` for the even and first page sections (e.g. `CH`, `RFFIRST`).
+    #[inline]
+    pub fn get_position(&self) -> &str {
+        self.position.get_value_str()
+    }
+
+    #[inline]
+    pub fn set_position<S: Into<String>>(&mut self, value: S) -> &mut Self {
+        self.position.set_value(value);
+        self
+    }
+
+    #[inline]
+    pub fn get_image(&self) -> &MediaObject {
+        &self.image
+    }
+
+    #[inline]
+    pub fn get_image_mut(&mut self) -> &mut MediaObject {
+        &mut self.image
+    }
+
+    #[inline]
+    pub fn set_image(&mut self, value: MediaObject) -> &mut Self {
+        self.image = value;
+        self
+    }
+
+    /// Width in points.
+    #[inline]
+    pub fn get_width(&self) -> &f64 {
+        self.width.get_value()
+    }
+
+    #[inline]
+    pub fn set_width(&mut self, value: f64) -> &mut Self {
+        self.width.set_value(value);
+        self
+    }
+
+    /// Height in points.
+    #[inline]
+    pub fn get_height(&self) -> &f64 {
+        self.height.get_value()
+    }
+
+    #[inline]
+    pub fn set_height(&mut self, value: f64) -> &mut Self {
+        self.height.set_value(value);
+        self
+    }
+
+    /// Create a picture from an image file.
+    /// # Arguments
+    /// * `position` - Section of the picture, see [`Self::get_position`].
+    /// * `path` - Path of the image file.
+    /// * `width` - Width in points.
+    /// * `height` - Height in points.
+    /// # Examples
+    /// ```
+    /// use umya_spreadsheet::*;
+    /// let mut book = new_file();
+    /// let image = HeaderFooterImage::new_file("CH", "./images/sample1.png", 72f64, 36f64).unwrap();
+    /// let header_footer = book.get_sheet_mut(&0).unwrap().get_header_footer_mut();
+    /// header_footer.add_image(image);
+    /// header_footer.get_odd_header_mut().set_value("&C&G");
+    /// ```
+    pub fn new_file<S: Into<String>>(
+        position: S,
+        path: &str,
+        width: f64,
+        height: f64,
+    ) -> Result<Self, XlsxError> {
+        let data = std::fs::read(path)?;
+        let name = std::path::Path::new(path)
+            .file_name()
+            .and_then(|v| v.to_str())
+            .unwrap_or("image.png")
+            .to_string();
+        let title = name.rsplit_once('.').map_or(name.as_str(), |(v, _)| v);
+
+        let mut obj = Self::default();
+        obj.set_position(position);
+        obj.get_image_mut()
+            .set_image_title(title)
+            .set_image_name(&name)
+            .set_image_data(data);
+        obj.set_width(width).set_height(height);
+        Ok(obj)
+    }
+
+    /// Whether a VML shape id names a header or footer section.
+    pub(crate) fn is_position(value: &str) -> bool {
+        let value = value
+            .strip_suffix("EVEN")
+            .or_else(|| value.strip_suffix("FIRST"))
+            .unwrap_or(value);
+        matches!(value, "LH" | "CH" | "RH" | "LF" | "CF" | "RF")
+    }
+
+    pub(crate) fn set_attributes<R: std::io::BufRead>(
+        &mut self,
+        reader: &mut Reader<R>,
+        e: &BytesStart,
+        drawing_relationships: Option<&RawRelationships>,
+    ) -> Result<(), XlsxError> {
+        set_string_from_xml!(self, e, position, "id");
+        if let Some(style) = get_attribute(e, b"style")? {
+            for (key, value) in style.split(';').filter_map(|v| v.split_once(':')) {
+                let value = value.trim().trim_end_matches("pt");
+                match key.trim() {
+                    "width" => {
+                        self.width.set_value_string(value);
+                    }
+                    "height" => {
+                        self.height.set_value_string(value);
+                    }
+                    _ => {}
+                }
+            }
+        }
+
+        xml_read_loop!(
+            reader,
+            Event::Empty(ref e) => {
+                if e.name().into_inner() == b"v:imagedata" {
+                    if let (Some(relid), Some(rel)) =
+                        (get_attribute(e, b"o:relid")?, drawing_relationships)
+                    {
+                        let relationship = rel.get_relationship_by_rid(&relid)?;
+                        self.image
+                            .set_image_name(relationship.get_raw_file().get_file_name())
+                            .set_image_data(relationship.get_raw_file().get_file_data());
+                    }
+                    if let Some(title) = get_attribute(e, b"o:title")? {
+                        self.image.set_image_title(title);
+                    }
+                }
+            },
+            Event::End(ref e) => {
+                if e.name().into_inner() == b"v:shape" {
+                    return Ok(())
+                }
+            },
+            Event::Eof => return Err(end_element_error(reader, "v:shape"))
+        );
+    }
+
+    pub(crate) fn write_to(
+        &self,
+        writer: &mut Writer<Cursor<Vec<u8>>>,
+        id: &usize,
+        rel_list: &mut Vec<(String, String)>,
+    ) {
+        // v:shape
+        let spid_str = format!("_x0000_s{}", id);
+        let style_str = format!(
+            "position:absolute;margin-left:0;margin-top:0;width:{}pt;height:{}pt;z-index:{}",
+            self.width.get_value_string(),
+            self.height.get_value_string(),
+            id % 1024
+        );
+        write_start_tag(
+            writer,
+            "v:shape",
+            vec![
+                ("id", self.position.get_value_str()),
+                ("o:spid", &spid_str),
+                ("type", "#_x0000_t75"),
+                ("style", &style_str),
+            ],
+            false,
+        );
+
+        // v:imagedata
+        let r_id_str = format!("rId{}", self.image.get_rid(rel_list));
+        let mut attributes: Vec<(&str, &str)> = vec![("o:relid", &r_id_str)];
+        if !self.image.get_image_title().is_empty() {
+            attributes.push(("o:title", self.image.get_image_title()));
+        }
+        write_start_tag(writer, "v:imagedata", attributes, true);
+
+        // o:lock
+        write_start_tag(
+            writer,
+            "o:lock",
+            vec![("v:ext", "edit"), ("rotation", "t")],
+            true,
+        );
+
+        write_end_tag(writer, "v:shape");
+    }
+}
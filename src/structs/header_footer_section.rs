@@ -0,0 +1,149 @@
+/// Text of the left, center or right part of a header or footer.
+/// Literal text is escaped, the other functions append the control codes.
+#[derive(Clone, Default, Debug)]
+pub struct HeaderFooterSection {
+    value: String,
+    // a font size code must not be followed by a digit
+    after_size: bool,
+}
+
+impl HeaderFooterSection {
+    #[inline]
+    pub fn get_value(&self) -> &str {
+        &self.value
+    }
+
+    #[inline]
+    pub(crate) fn is_empty(&self) -> bool {
+        self.value.is_empty()
+    }
+
+    fn push_code(&mut self, code: &str) -> &mut Self {
+        self.value.push_str(code);
+        self.after_size = false;
+        self
+    }
+
+    /// Add literal text.
+    pub fn add_text<S: AsRef<str>>(&mut self, value: S) -> &mut Self {
+        let value = value.as_ref();
+        if self.after_size && value.starts_with(|c: char| c.is_ascii_digit()) {
+            self.value.push(' ');
+        }
+        self.push_code(&value.replace('&', "&&"))
+    }
+
+    /// Add the page number (`&P`).
+    #[inline]
+    pub fn add_page_number(&mut self) -> &mut Self {
+        self.push_code("&P")
+    }
+
+    /// Add the number of pages (`&N`).
+    #[inline]
+    pub fn add_page_count(&mut self) -> &mut Self {
+        self.push_code("&N")
+    }
+
+    /// Add the print date (`&D`).
+    #[inline]
+    pub fn add_date(&mut self) -> &mut Self {
+        self.push_code("&D")
+    }
+
+    /// Add the print time (`&T`).
+    #[inline]
+    pub fn add_time(&mut self) -> &mut Self {
+        self.push_code("&T")
+    }
+
+    /// Add the workbook file name (`&F`).
+    #[inline]
+    pub fn add_file_name(&mut self) -> &mut Self {
+        self.push_code("&F")
+    }
+
+    /// Add the workbook file path (`&Z`).
+    #[inline]
+    pub fn add_file_path(&mut self) -> &mut Self {
+        self.push_code("&Z")
+    }
+
+    /// Add the sheet name (`&A`).
+    #[inline]
+    pub fn add_sheet_name(&mut self) -> &mut Self {
+        self.push_code("&A")
+    }
+
+    /// Add the picture of the section (`&G`).
+    /// The picture itself is set with [`crate::HeaderFooter::add_image`].
+    #[inline]
+    pub fn add_picture(&mut self) -> &mut Self {
+        self.push_code("&G")
+    }
+
+    /// Change the font of the following text (`&"Name,Style"`).
+    /// # Arguments
+    /// * `name` - Font name, `-` keeps the current font.
+    /// * `style` - Font style such as `Regular`, `Bold` or `Bold Italic`.
+    pub fn set_font(&mut self, name: &str, style: &str) -> &mut Self {
+        self.push_code(&format!("&\"{},{}\"", name, style))
+    }
+
+    /// Change the font size of the following text, in points (`&nn`).
+    pub fn set_font_size(&mut self, value: u32) -> &mut Self {
+        self.push_code(&format!("&{}", value));
+        self.after_size = true;
+        self
+    }
+
+    /// Change the font color of the following text (`&Krrggbb`).
+    /// # Arguments
+    /// * `argb` - Color such as `FF0000` or `FFFF0000`.
+    pub fn set_color(&mut self, argb: &str) -> &mut Self {
+        let rgb = if argb.len() == 8 { &argb[2..] } else { argb };
+        self.push_code(&format!("&K{}", rgb.to_uppercase()))
+    }
+
+    /// Toggle bold (`&B`).
+    #[inline]
+    pub fn toggle_bold(&mut self) -> &mut Self {
+        self.push_code("&B")
+    }
+
+    /// Toggle italic (`&I`).
+    #[inline]
+    pub fn toggle_italic(&mut self) -> &mut Self {
+        self.push_code("&I")
+    }
+
+    /// Toggle single underline (`&U`).
+    #[inline]
+    pub fn toggle_underline(&mut self) -> &mut Self {
+        self.push_code("&U")
+    }
+
+    /// Toggle double underline (`&E`).
+    #[inline]
+    pub fn toggle_double_underline(&mut self) -> &mut Self {
+        self.push_code("&E")
+    }
+
+    /// Toggle strikethrough (`&S`).
+    #[inline]
+    pub fn toggle_strikethrough(&mut self) -> &mut Self {
+        self.push_code("&S")
+    }
+
+    /// Toggle superscript (`&X`).
+    #[inline]
+    pub fn toggle_superscript(&mut self) -> &mut Self {
+        self.push_code("&X")
+    }
+
+    /// Toggle subscript (`&Y`).
+    #[inline]
+    pub fn toggle_subscript(&mut self) -> &mut Self {
+        self.push_code("&Y")
+    }
+}
@@ -0,0 +1,69 @@
+// iconFilter
+use super::EnumValue;
+use super::IconSetValues;
+use super::UInt32Value;
+use crate::reader::driver::*;
+use crate::writer::driver::*;
+use crate::XlsxError;
+use quick_xml::events::BytesStart;
+use quick_xml::Reader;
+use quick_xml::Writer;
+use std::io::Cursor;
+
+#[derive(Clone, Default, Debug)]
+pub struct IconFilter {
+    icon_set: EnumValue<IconSetValues>,
+    icon_id: UInt32Value,
+}
+
+impl IconFilter {
+    #[inline]
+    pub fn get_icon_set(&self) -> &IconSetValues {
+        self.icon_set.get_value()
+    }
+
+    #[inline]
+    pub fn set_icon_set(&mut self, value: IconSetValues) -> &mut Self {
+        self.icon_set.set_value(value);
+        self
+    }
+
+    /// Zero-based index of the icon within the icon set.
+    /// Cells without an icon are matched when not set.
+    #[inline]
+    pub fn get_icon_id(&self) -> Option<&u32> {
+        if self.icon_id.has_value() {
+            return Some(self.icon_id.get_value());
+        }
+        None
+    }
+
+    #[inline]
+    pub fn set_icon_id(&mut self, value: u32) -> &mut Self {
+        self.icon_id.set_value(value);
+        self
+    }
+
+    #[inline]
+    pub(crate) fn set_attributes<R: std::io::BufRead>(
+        &mut self,
+        _reader: &mut Reader<R>,
+        e: &BytesStart,
+    ) -> Result<(), XlsxError> {
+        set_string_from_xml!(self, e, icon_set, "iconSet");
+        set_string_from_xml!(self, e, icon_id, "iconId");
+        Ok(())
+    }
+
+    #[inline]
+    pub(crate) fn write_to(&self, writer: &mut Writer<Cursor<Vec<u8>>>) {
+        // iconFilter
+        let mut attributes: Vec<(&str, &str)> = Vec::new();
+        attributes.push(("iconSet", self.icon_set.get_value_string()));
+        let icon_id = self.icon_id.get_value_string();
+        if self.icon_id.has_value() {
+            attributes.push(("iconId", &icon_id));
+        }
+        write_start_tag(writer, "iconFilter", attributes, true);
+    }
+}
@@ -1,7 +1,12 @@
+use super::office2010::excel::ConditionalFormattingIcon;
+use super::BooleanValue;
 use super::Color;
 use super::ConditionalFormatValueObject;
+use super::EnumValue;
+use super::IconSetValues;
 use crate::reader::driver::*;
 use crate::writer::driver::*;
+use crate::XlsxError;
 use quick_xml::events::BytesStart;
 use quick_xml::events::Event;
 use quick_xml::Reader;
@@ -13,6 +18,11 @@ use thin_vec::ThinVec;
 pub struct IconSet {
     cfvo_collection: ThinVec<ConditionalFormatValueObject>,
     color_collection: ThinVec<Color>,
+    icon_set: EnumValue<IconSetValues>,
+    show_value: BooleanValue,
+    percent: BooleanValue,
+    reverse: BooleanValue,
+    icon_collection: ThinVec<ConditionalFormattingIcon>,
 }
 
 impl IconSet {
@@ -53,40 +63,150 @@ impl IconSet {
         self
     }
 
+    #[inline]
+    pub fn get_icon_set(&self) -> &IconSetValues {
+        self.icon_set.get_value()
+    }
+
+    #[inline]
+    pub fn set_icon_set(&mut self, value: IconSetValues) -> &mut Self {
+        self.icon_set.set_value(value);
+        self
+    }
+
+    /// Show the cell value next to the icon. Excel shows it when not set.
+    #[inline]
+    pub fn get_show_value(&self) -> &bool {
+        self.show_value.get_value()
+    }
+
+    #[inline]
+    pub fn set_show_value(&mut self, value: bool) -> &mut Self {
+        self.show_value.set_value(value);
+        self
+    }
+
+    /// Thresholds are percentages. Excel uses percentages when not set.
+    #[inline]
+    pub fn get_percent(&self) -> &bool {
+        self.percent.get_value()
+    }
+
+    #[inline]
+    pub fn set_percent(&mut self, value: bool) -> &mut Self {
+        self.percent.set_value(value);
+        self
+    }
+
+    /// Reverse the order of the icons.
+    #[inline]
+    pub fn get_reverse(&self) -> &bool {
+        self.reverse.get_value()
+    }
+
+    #[inline]
+    pub fn set_reverse(&mut self, value: bool) -> &mut Self {
+        self.reverse.set_value(value);
+        self
+    }
+
+    /// Custom icons, one per threshold. Written to the x14 extension.
+    #[inline]
+    pub fn get_icon_collection(&self) -> &[ConditionalFormattingIcon] {
+        &self.icon_collection
+    }
+
+    #[inline]
+    pub fn set_icon_collection(
+        &mut self,
+        value: impl Into<ThinVec<ConditionalFormattingIcon>>,
+    ) -> &mut Self {
+        self.icon_collection = value.into();
+        self
+    }
+
+    #[inline]
+    pub fn add_icon_collection(&mut self, value: ConditionalFormattingIcon) -> &mut Self {
+        self.icon_collection.push(value);
+        self
+    }
+
+    /// Whether the icon set can only be stored in the x14 extension.
+    #[inline]
+    pub(crate) fn is_x14_only(&self) -> bool {
+        !self.icon_collection.is_empty()
+            || (self.icon_set.has_value() && self.icon_set.get_value().is_x14_only())
+    }
+
+    /// Reads `iconSet` as well as `x14:iconSet`.
     pub(crate) fn set_attributes<R: std::io::BufRead>(
         &mut self,
         reader: &mut Reader<R>,
-        _e: &BytesStart,
-    ) {
+        e: &BytesStart,
+    ) -> Result<(), XlsxError> {
+        set_string_from_xml!(self, e, icon_set, "iconSet");
+        set_string_from_xml!(self, e, show_value, "showValue");
+        set_string_from_xml!(self, e, percent, "percent");
+        set_string_from_xml!(self, e, reverse, "reverse");
+
         xml_read_loop!(
             reader,
                 Event::Empty(ref e) => {
                     match e.name().into_inner() {
-                        b"cfvo" => {
+                        b"cfvo" | b"x14:cfvo" => {
                             let mut obj = ConditionalFormatValueObject::default();
-                            obj.set_attributes(reader, e, true);
+                            obj.set_attributes(reader, e, true)?;
                             self.cfvo_collection.push(obj);
                         }
+                        b"x14:cfIcon" => {
+                            let mut obj = ConditionalFormattingIcon::default();
+                            obj.set_attributes(reader, e)?;
+                            self.icon_collection.push(obj);
+                        }
                         b"color" => {
                             let mut obj = Color::default();
-                            obj.set_attributes(reader, e, true);
+                            obj.set_attributes(reader, e, true)?;
                             self.color_collection.push(obj);
                         }
                         _ => (),
                     }
                 },
+                Event::Start(ref e) => {
+                    if e.name().into_inner() == b"x14:cfvo" {
+                        let mut obj = ConditionalFormatValueObject::default();
+                        obj.set_attributes(reader, e, false)?;
+                        self.cfvo_collection.push(obj);
+                    }
+                },
                 Event::End(ref e) => {
-                    if e.name().into_inner() == b"dataBar" {
-                        return
+                    if matches!(e.name().into_inner(), b"iconSet" | b"x14:iconSet") {
+                        return Ok(())
                     }
                 },
-                Event::Eof => panic!("Error: Could not find {} end element", "dataBar")
+                Event::Eof => return Err(end_element_error(reader, "iconSet"))
         );
     }
 
+    fn get_attributes(&self) -> Vec<(&str, &str)> {
+        let mut attributes: Vec<(&str, &str)> = Vec::new();
+        if self.icon_set.has_value() {
+            attributes.push(("iconSet", self.icon_set.get_value_string()));
+        }
+        if self.show_value.has_value() {
+            attributes.push(("showValue", self.show_value.get_value_string()));
+        }
+        if self.percent.has_value() {
+            attributes.push(("percent", self.percent.get_value_string()));
+        }
+        if self.reverse.has_value() {
+            attributes.push(("reverse", self.reverse.get_value_string()));
+        }
+        attributes
+    }
+
     pub(crate) fn write_to(&self, writer: &mut Writer<Cursor<Vec<u8>>>) {
-        // dataBar
-        write_start_tag(writer, "dataBar", vec![], false);
+        // iconSet
+        write_start_tag(writer, "iconSet", self.get_attributes(), false);
 
         // cfvo
         for v in &self.cfvo_collection {
@@ -98,6 +218,27 @@ impl IconSet {
             v.write_to_color(writer);
         }
 
-        write_end_tag(writer, "dataBar");
+        write_end_tag(writer, "iconSet");
+    }
+
+    pub(crate) fn write_to_x14(&self, writer: &mut Writer<Cursor<Vec<u8>>>) {
+        // x14:iconSet
+        let mut attributes = self.get_attributes();
+        if !self.icon_collection.is_empty() {
+            attributes.push(("custom", "1"));
+        }
+        write_start_tag(writer, "x14:iconSet", attributes, false);
+
+        // x14:cfvo
+        for v in &self.cfvo_collection {
+            v.write_to_x14(writer);
+        }
+
+        // x14:cfIcon
+        for v in &self.icon_collection {
+            v.write_to(writer);
+        }
+
+        write_end_tag(writer, "x14:iconSet");
     }
 }
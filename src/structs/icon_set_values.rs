@@ -0,0 +1,105 @@
+use super::EnumTrait;
+use std::str::FromStr;
+#[derive(Clone, Debug, Eq, Ord, PartialEq, PartialOrd)]
+pub enum IconSetValues {
+    FiveArrows,
+    FiveArrowsGray,
+    /// x14 extension only.
+    FiveBoxes,
+    FiveQuarters,
+    FiveRating,
+    FourArrows,
+    FourArrowsGray,
+    FourRating,
+    FourRedToBlack,
+    FourTrafficLights,
+    /// x14 extension only.
+    NoIcons,
+    ThreeArrows,
+    ThreeArrowsGray,
+    ThreeFlags,
+    ThreeSigns,
+    /// x14 extension only.
+    ThreeStars,
+    ThreeSymbols,
+    ThreeSymbols2,
+    ThreeTrafficLights1,
+    ThreeTrafficLights2,
+    /// x14 extension only.
+    ThreeTriangles,
+}
+impl Default for IconSetValues {
+    #[inline]
+    fn default() -> Self {
+        Self::ThreeTrafficLights1
+    }
+}
+impl IconSetValues {
+    /// Icon sets that only exist in the x14 extension.
+    #[inline]
+    pub(crate) fn is_x14_only(&self) -> bool {
+        matches!(
+            self,
+            Self::FiveBoxes | Self::NoIcons | Self::ThreeStars | Self::ThreeTriangles
+        )
+    }
+}
+impl EnumTrait for IconSetValues {
+    #[inline]
+    fn get_value_string(&self) -> &str {
+        match &self {
+            Self::FiveArrows => "5Arrows",
+            Self::FiveArrowsGray => "5ArrowsGray",
+            Self::FiveBoxes => "5Boxes",
+            Self::FiveQuarters => "5Quarters",
+            Self::FiveRating => "5Rating",
+            Self::FourArrows => "4Arrows",
+            Self::FourArrowsGray => "4ArrowsGray",
+            Self::FourRating => "4Rating",
+            Self::FourRedToBlack => "4RedToBlack",
+            Self::FourTrafficLights => "4TrafficLights",
+            Self::NoIcons => "NoIcons",
+            Self::ThreeArrows => "3Arrows",
+            Self::ThreeArrowsGray => "3ArrowsGray",
+            Self::ThreeFlags => "3Flags",
+            Self::ThreeSigns => "3Signs",
+            Self::ThreeStars => "3Stars",
+            Self::ThreeSymbols => "3Symbols",
+            Self::ThreeSymbols2 => "3Symbols2",
+            Self::ThreeTrafficLights1 => "3TrafficLights1",
+            Self::ThreeTrafficLights2 => "3TrafficLights2",
+            Self::ThreeTriangles => "3Triangles",
+        }
+    }
+}
+impl FromStr for IconSetValues {
+    type Err = ();
+
+    #[inline]
+    fn from_str(input: &str) -> Result<Self, Self::Err> {
+        match input {
+            "5Arrows" => Ok(Self::FiveArrows),
+            "5ArrowsGray" => Ok(Self::FiveArrowsGray),
+            "5Boxes" => Ok(Self::FiveBoxes),
+            "5Quarters" => Ok(Self::FiveQuarters),
+            "5Rating" => Ok(Self::FiveRating),
+            "4Arrows" => Ok(Self::FourArrows),
+            "4ArrowsGray" => Ok(Self::FourArrowsGray),
+            "4Rating" => Ok(Self::FourRating),
+            "4RedToBlack" => Ok(Self::FourRedToBlack),
+            "4TrafficLights" => Ok(Self::FourTrafficLights),
+            "NoIcons" => Ok(Self::NoIcons),
+            "3Arrows" => Ok(Self::ThreeArrows),
+            "3ArrowsGray" => Ok(Self::ThreeArrowsGray),
+            "3Flags" => Ok(Self::ThreeFlags),
+            "3Signs" => Ok(Self::ThreeSigns),
+            "3Stars" => Ok(Self::ThreeStars),
+            "3Symbols" => Ok(Self::ThreeSymbols),
+            "3Symbols2" => Ok(Self::ThreeSymbols2),
+            "3TrafficLights1" => Ok(Self::ThreeTrafficLights1),
+            "3TrafficLights2" => Ok(Self::ThreeTrafficLights2),
+            "3Triangles" => Ok(Self::ThreeTriangles),
+            _ => Err(()),
+        }
+    }
+}
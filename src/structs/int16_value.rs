@@ -1,3 +1,5 @@
+use crate::reader::driver::invalid_attribute_error;
+use crate::XlsxError;
 #[derive(Clone, Default, Debug)]
 pub struct Int16Value {
     #[allow(dead_code)]
@@ -24,8 +26,16 @@ impl Int16Value {
     }
 
     #[inline]
-    pub(crate) fn _set_value_string<S: Into<String>>(&mut self, value: S) -> &mut Int16Value {
-        self._set_value(value.into().parse::<i16>().unwrap())
+    pub(crate) fn _try_set_value_string<S: Into<String>>(
+        &mut self,
+        value: S,
+        name: &str,
+    ) -> Result<&mut Int16Value, XlsxError> {
+        let value = value.into();
+        match value.parse::<i16>() {
+            Ok(v) => Ok(self._set_value(v)),
+            Err(_) => Err(invalid_attribute_error(name, value)),
+        }
     }
 
     #[inline]
@@ -1,3 +1,5 @@
+use crate::reader::driver::invalid_attribute_error;
+use crate::XlsxError;
 #[derive(Clone, Default, Debug, Eq, Ord, PartialEq, PartialOrd)]
 pub struct Int32Value {
     value: Option<i32>,
@@ -23,8 +25,16 @@ impl Int32Value {
     }
 
     #[inline]
-    pub(crate) fn set_value_string<S: Into<String>>(&mut self, value: S) -> &mut Self {
-        self.set_value(value.into().parse::<i32>().unwrap())
+    pub(crate) fn try_set_value_string<S: Into<String>>(
+        &mut self,
+        value: S,
+        name: &str,
+    ) -> Result<&mut Self, XlsxError> {
+        let value = value.into();
+        match value.parse::<i32>() {
+            Ok(v) => Ok(self.set_value(v)),
+            Err(_) => Err(invalid_attribute_error(name, value)),
+        }
     }
 
     #[inline]
@@ -1,3 +1,5 @@
+use crate::reader::driver::invalid_attribute_error;
+use crate::XlsxError;
 #[derive(Clone, Default, Debug)]
 pub struct Int64Value {
     value: Option<i64>,
@@ -23,8 +25,16 @@ impl Int64Value {
     }
 
     #[inline]
-    pub(crate) fn set_value_string<S: Into<String>>(&mut self, value: S) -> &mut Int64Value {
-        self.set_value(value.into().parse::<i64>().unwrap())
+    pub(crate) fn try_set_value_string<S: Into<String>>(
+        &mut self,
+        value: S,
+        name: &str,
+    ) -> Result<&mut Int64Value, XlsxError> {
+        let value = value.into();
+        match value.parse::<i64>() {
+            Ok(v) => Ok(self.set_value(v)),
+            Err(_) => Err(invalid_attribute_error(name, value)),
+        }
     }
 
     #[inline]
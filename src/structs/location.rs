@@ -2,6 +2,7 @@ use super::StringValue;
 use super::UInt32Value;
 use crate::reader::driver::*;
 use crate::writer::driver::*;
+use crate::XlsxError;
 use quick_xml::events::BytesStart;
 use quick_xml::Reader;
 use quick_xml::Writer;
@@ -13,6 +14,8 @@ pub struct Location {
     first_header_row: UInt32Value,
     first_data_row: UInt32Value,
     first_data_col: UInt32Value,
+    row_page_count: UInt32Value,
+    column_page_count: UInt32Value,
 }
 impl Location {
     pub fn get_reference(&self) -> &str {
@@ -51,29 +54,56 @@ impl Location {
         self
     }
 
+    pub fn get_row_page_count(&self) -> &u32 {
+        self.row_page_count.get_value()
+    }
+
+    pub fn set_row_page_count(&mut self, value: u32) -> &mut Self {
+        self.row_page_count.set_value(value);
+        self
+    }
+
+    pub fn get_column_page_count(&self) -> &u32 {
+        self.column_page_count.get_value()
+    }
+
+    pub fn set_column_page_count(&mut self, value: u32) -> &mut Self {
+        self.column_page_count.set_value(value);
+        self
+    }
+
     pub(crate) fn set_attributes<R: std::io::BufRead>(
         &mut self,
         _reader: &mut Reader<R>,
         e: &BytesStart,
-    ) {
+    ) -> Result<(), XlsxError> {
         set_string_from_xml!(self, e, reference, "ref");
         set_string_from_xml!(self, e, first_header_row, "firstHeaderRow");
         set_string_from_xml!(self, e, first_data_row, "firstDataRow");
         set_string_from_xml!(self, e, first_data_col, "firstDataCol");
+        set_string_from_xml!(self, e, row_page_count, "rowPageCount");
+        set_string_from_xml!(self, e, column_page_count, "colPageCount");
+        Ok(())
     }
 
     pub(crate) fn write_to(&self, writer: &mut Writer<Cursor<Vec<u8>>>) {
         // location
-        write_start_tag(
-            writer,
-            "location",
-            vec![
-                ("ref", self.reference.get_value_str()),
-                ("firstHeaderRow", &self.first_header_row.get_value_string()),
-                ("firstDataRow", &self.first_data_row.get_value_string()),
-                ("firstDataCol", &self.first_data_col.get_value_string()),
-            ],
-            true,
-        );
+        let mut attributes: Vec<(&str, &str)> = Vec::new();
+        attributes.push(("ref", self.reference.get_value_str()));
+        let first_header_row_str = self.first_header_row.get_value_string();
+        attributes.push(("firstHeaderRow", &first_header_row_str));
+        let first_data_row_str = self.first_data_row.get_value_string();
+        attributes.push(("firstDataRow", &first_data_row_str));
+        let first_data_col_str = self.first_data_col.get_value_string();
+        attributes.push(("firstDataCol", &first_data_col_str));
+        let row_page_count_str = self.row_page_count.get_value_string();
+        if self.row_page_count.has_value() {
+            attributes.push(("rowPageCount", &row_page_count_str));
+        }
+        let column_page_count_str = self.column_page_count.get_value_string();
+        if self.column_page_count.has_value() {
+            attributes.push(("colPageCount", &column_page_count_str));
+        }
+        write_start_tag(writer, "location", attributes, true);
     }
 }
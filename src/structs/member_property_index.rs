@@ -2,6 +2,7 @@
 use super::Int32Value;
 use crate::reader::driver::*;
 use crate::writer::driver::*;
+use crate::XlsxError;
 use quick_xml::events::BytesStart;
 use quick_xml::Reader;
 use quick_xml::Writer;
@@ -26,17 +27,18 @@ impl MemberPropertyIndex {
         &mut self,
         _reader: &mut Reader<R>,
         e: &BytesStart,
-    ) {
-        set_string_from_xml!(self, e, val, "val");
+    ) -> Result<(), XlsxError> {
+        set_string_from_xml!(self, e, val, "v");
+        Ok(())
     }
 
     pub(crate) fn write_to(&self, writer: &mut Writer<Cursor<Vec<u8>>>) {
         // x
-        if self.val.has_value() {
-            let mut attributes: Vec<(&str, &str)> = Vec::new();
-            let val = self.val.get_value_string();
-            attributes.push(("val", &val));
-            write_start_tag(writer, "x", attributes, true);
+        let mut attributes: Vec<(&str, &str)> = Vec::new();
+        let val = self.val.get_value_string();
+        if self.val.has_value() && self.val.get_value() != &0 {
+            attributes.push(("v", &val));
         }
+        write_start_tag(writer, "x", attributes, true);
     }
 }
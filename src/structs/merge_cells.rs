@@ -1,5 +1,6 @@
 // mergeCell
 use super::Range;
+use crate::XlsxError;
 
 use crate::reader::driver::*;
 use crate::writer::driver::*;
@@ -45,15 +46,29 @@ impl MergeCells {
         })
     }
 
-    pub(crate) fn has_horizontal(&self, col_num: &u32) -> bool {
-        self.get_range_collection().iter().any(|range| {
-            let start_num = range
-                .get_coordinate_start_col()
-                .map_or(true, |v| v.get_num() <= col_num);
-            let end_num = range
+    /// Bounds of the merged range containing a cell,
+    /// as (start column, start row, end column, end row).
+    pub(crate) fn get_merged_area(
+        &self,
+        col_num: &u32,
+        row_num: &u32,
+    ) -> Option<(u32, u32, u32, u32)> {
+        self.get_range_collection().iter().find_map(|range| {
+            if range.get_coordinate_end_col().is_none() && range.get_coordinate_end_row().is_none()
+            {
+                return None;
+            }
+            let start_col = range.get_coordinate_start_col().map_or(1, |v| *v.get_num());
+            let start_row = range.get_coordinate_start_row().map_or(1, |v| *v.get_num());
+            let end_col = range
                 .get_coordinate_end_col()
-                .map_or(true, |v| v.get_num() >= col_num);
-            start_num && end_num && start_num != end_num
+                .map_or(u32::MAX, |v| *v.get_num());
+            let end_row = range
+                .get_coordinate_end_row()
+                .map_or(u32::MAX, |v| *v.get_num());
+            let contains =
+                (start_col..=end_col).contains(col_num) && (start_row..=end_row).contains(row_num);
+            contains.then_some((start_col, start_row, end_col, end_row))
         })
     }
 
@@ -61,20 +76,20 @@ impl MergeCells {
         &mut self,
         reader: &mut Reader<R>,
         _e: &BytesStart,
-    ) {
+    ) -> Result<(), XlsxError> {
         xml_read_loop!(
             reader,
             Event::Empty(ref e) => {
                 if e.name().into_inner() == b"mergeCell" {
-                    self.add_range(get_attribute(e, b"ref").unwrap());
+                    self.add_range(get_required_attribute(e, b"ref")?);
                 }
             },
             Event::End(ref e) => {
                 if e.name().into_inner() == b"mergeCells" {
-                    return
+                    return Ok(())
                 }
             },
-            Event::Eof => panic!("Error: Could not find {} end element", "mergeCells")
+            Event::Eof => return Err(end_element_error(reader, "mergeCells"))
         );
     }
 
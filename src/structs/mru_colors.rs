@@ -2,6 +2,7 @@
 use super::Color;
 use crate::reader::driver::*;
 use crate::writer::driver::*;
+use crate::XlsxError;
 use quick_xml::events::{BytesStart, Event};
 use quick_xml::Reader;
 use quick_xml::Writer;
@@ -34,22 +35,22 @@ impl MruColors {
         &mut self,
         reader: &mut Reader<R>,
         _e: &BytesStart,
-    ) {
+    ) -> Result<(), XlsxError> {
         xml_read_loop!(
             reader,
             Event::Start(ref e) => {
                 if e.name().into_inner() == b"color" {
                     let mut obj = Color::default();
-                    obj.set_attributes(reader, e, true);
+                    obj.set_attributes(reader, e, true)?;
                     self.set_color(obj);
                 }
             },
             Event::End(ref e) => {
                 if e.name().into_inner() == b"mruColors" {
-                    return
+                    return Ok(())
                 }
             },
-            Event::Eof => panic!("Error: Could not find {} end element", "mruColors")
+            Event::Eof => return Err(end_element_error(reader, "mruColors"))
         );
     }
 
@@ -1,5 +1,6 @@
 use crate::reader::driver::*;
 use crate::writer::driver::*;
+use crate::XlsxError;
 use md5::Digest;
 use quick_xml::escape;
 use quick_xml::events::BytesStart;
@@ -136,6 +137,26 @@ impl NumberingFormat {
         &self.format_code
     }
 
+    /// Whether the format shows numbers as dates, times or elapsed times.
+    /// Built-in date formats are recognized by their id, other formats by their format code.
+    /// # Examples
+    /// ```
+    /// let mut number_format = umya_spreadsheet::NumberingFormat::default();
+    /// number_format.set_format_code("yyyy/mm/dd;@");
+    /// assert!(number_format.is_date_format());
+    /// number_format.set_format_code("[Red]#,##0");
+    /// assert!(!number_format.is_date_format());
+    /// ```
+    pub fn is_date_format(&self) -> bool {
+        if matches!(
+            self.number_format_id,
+            14..=22 | 27..=36 | 45..=47 | 50..=58 | 71..=81
+        ) {
+            return true;
+        }
+        is_date_format_code(&self.format_code)
+    }
+
     #[inline]
     pub(crate) fn get_is_build_in(&self) -> &bool {
         &self.is_build_in
@@ -150,16 +171,14 @@ impl NumberingFormat {
         &mut self,
         _reader: &mut Reader<R>,
         e: &BytesStart,
-    ) {
-        self.number_format_id = get_attribute(e, b"numFmtId")
-            .unwrap()
-            .parse::<u32>()
-            .unwrap();
-        self.format_code = escape::unescape(get_attribute(e, b"formatCode").unwrap().as_str())
-            .unwrap()
+    ) -> Result<(), XlsxError> {
+        self.number_format_id = parse_value("numFmtId", &get_required_attribute(e, b"numFmtId")?)?;
+        self.format_code = escape::unescape(get_required_attribute(e, b"formatCode")?.as_str())
+            .map_err(quick_xml::Error::from)?
             .to_string()
             .into_boxed_str();
         self.is_build_in = false;
+        Ok(())
     }
 
     pub(crate) fn write_to(&self, writer: &mut Writer<Cursor<Vec<u8>>>, number_format_id: &u32) {
@@ -176,6 +195,46 @@ impl NumberingFormat {
     }
 }
 
+// Looks for date and time tokens in the first section of a format code,
+// skipping literal text, escaped characters, colors, conditions and locale tags.
+fn is_date_format_code(format_code: &str) -> bool {
+    let mut chars = format_code.chars();
+    while let Some(c) = chars.next() {
+        match c {
+            ';' => break,
+            '"' => {
+                for v in chars.by_ref() {
+                    if v == '"' {
+                        break;
+                    }
+                }
+            }
+            '\\' | '_' | '*' => {
+                chars.next();
+            }
+            '[' => {
+                let mut tag = String::new();
+                for v in chars.by_ref() {
+                    if v == ']' {
+                        break;
+                    }
+                    tag.push(v.to_ascii_lowercase());
+                }
+                // elapsed time such as [h], [mm] or [ss]
+                let mut tag_chars = tag.chars();
+                if let Some(first) = tag_chars.next() {
+                    if matches!(first, 'h' | 'm' | 's') && tag_chars.all(|v| v == first) {
+                        return true;
+                    }
+                }
+            }
+            _ if matches!(c.to_ascii_lowercase(), 'y' | 'm' | 'd' | 'h' | 's') => return true,
+            _ => {}
+        }
+    }
+    false
+}
+
 lazy_static! {
     pub(crate) static ref FILL_BUILT_IN_FORMAT_CODES: HashMap<u32, String> = {
         let mut map:HashMap<u32, String> = HashMap::new();
@@ -264,4 +323,38 @@ mod tests {
         obj.set_number_format_id(1);
         assert_eq!(obj.get_format_code(), "0");
     }
+
+    #[test]
+    fn is_date_format() {
+        let mut obj = NumberingFormat::default();
+        assert!(!obj.is_date_format());
+
+        obj.set_number_format_id(14);
+        assert!(obj.is_date_format());
+        obj.set_number_format_id(46);
+        assert!(obj.is_date_format());
+        obj.set_number_format_id(11);
+        assert!(!obj.is_date_format());
+
+        for code in [
+            "yyyy-mm-dd",
+            "[$-409]h:mm AM/PM",
+            "[h]:mm",
+            "d\\-mmm",
+            "0;yyyy",
+        ] {
+            obj.set_format_code(code);
+            assert_eq!(obj.is_date_format(), code != "0;yyyy", "{}", code);
+        }
+        for code in [
+            "General",
+            "[Red]0.00",
+            "\"day\" 0",
+            "0.00E+00",
+            "#,##0_);(#,##0)",
+        ] {
+            obj.set_format_code(code);
+            assert!(!obj.is_date_format(), "{}", code);
+        }
+    }
 }
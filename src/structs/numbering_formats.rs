@@ -3,6 +3,7 @@ use super::NumberingFormat;
 use super::Style;
 use crate::reader::driver::*;
 use crate::writer::driver::*;
+use crate::XlsxError;
 use quick_xml::events::{BytesStart, Event};
 use quick_xml::Reader;
 use quick_xml::Writer;
@@ -79,22 +80,22 @@ impl NumberingFormats {
         &mut self,
         reader: &mut Reader<R>,
         _e: &BytesStart,
-    ) {
+    ) -> Result<(), XlsxError> {
         xml_read_loop!(
             reader,
             Event::Empty(ref e) => {
                 if e.name().into_inner() == b"numFmt" {
                     let mut obj = NumberingFormat::default();
-                    obj.set_attributes(reader, e);
+                    obj.set_attributes(reader, e)?;
                     self.set_numbering_format(obj);
                 }
             },
             Event::End(ref e) => {
                 if e.name().into_inner() == b"numFmts" {
-                    return
+                    return Ok(())
                 }
             },
-            Event::Eof => panic!("Error: Could not find {} end element", "numFmts")
+            Event::Eof => return Err(end_element_error(reader, "numFmts"))
         );
     }
 
@@ -3,6 +3,7 @@ use super::FromMarker;
 use super::ToMarker;
 use crate::reader::driver::*;
 use crate::writer::driver::*;
+use crate::XlsxError;
 use quick_xml::events::{BytesStart, Event};
 use quick_xml::Reader;
 use quick_xml::Writer;
@@ -87,7 +88,7 @@ impl ObjectAnchor {
         &mut self,
         reader: &mut Reader<R>,
         e: &BytesStart,
-    ) {
+    ) -> Result<(), XlsxError> {
         set_string_from_xml!(self, e, move_with_cells, "moveWithCells");
 
         xml_read_loop!(
@@ -95,26 +96,26 @@ impl ObjectAnchor {
             Event::Start(ref e) => {
                 match e.name().into_inner() {
                     b"from" => {
-                        self.from_marker.set_attributes(reader, e);
+                        self.from_marker.set_attributes(reader, e)?;
                     }
                     b"xdr:from" => {
-                        self.from_marker.set_attributes(reader, e);
+                        self.from_marker.set_attributes(reader, e)?;
                     }
                     b"to" => {
-                        self.to_marker.set_attributes(reader, e);
+                        self.to_marker.set_attributes(reader, e)?;
                     }
                     b"xdr:to" => {
-                        self.to_marker.set_attributes(reader, e);
+                        self.to_marker.set_attributes(reader, e)?;
                     }
                     _ => (),
                 }
             },
             Event::End(ref e) => {
                 if e.name().into_inner() == b"anchor" {
-                    return
+                    return Ok(())
                 }
             },
-            Event::Eof => panic!("Error: Could not find {} end element", "anchor")
+            Event::Eof => return Err(end_element_error(reader, "anchor"))
         );
     }
 
@@ -2,6 +2,7 @@
 use crate::reader::driver::*;
 use crate::structs::StringValue;
 use crate::writer::driver::*;
+use crate::XlsxError;
 use md5::Digest;
 use quick_xml::events::{BytesStart, Event};
 use quick_xml::Reader;
@@ -39,18 +40,18 @@ impl OddFooter {
         &mut self,
         reader: &mut Reader<R>,
         _e: &BytesStart,
-    ) {
+    ) -> Result<(), XlsxError> {
         xml_read_loop!(
             reader,
             Event::Text(e) => {
-                self.set_value(e.unescape().unwrap());
+                self.set_value(e.unescape()?);
             },
             Event::End(ref e) => {
                 if e.name().0 == b"oddFooter" {
-                    return
+                    return Ok(())
                 }
             },
-            Event::Eof => panic!("Error: Could not find {} end element", "oddFooter")
+            Event::Eof => return Err(end_element_error(reader, "oddFooter"))
         );
     }
 
@@ -3,6 +3,7 @@ use crate::reader::driver::*;
 use crate::structs::Coordinate;
 use crate::structs::Range;
 use crate::writer::driver::*;
+use crate::XlsxError;
 use quick_xml::events::{BytesStart, Event};
 use quick_xml::Reader;
 use quick_xml::Writer;
@@ -65,24 +66,24 @@ impl ReferenceSequence {
         &mut self,
         reader: &mut Reader<R>,
         _e: &BytesStart,
-    ) {
+    ) -> Result<(), XlsxError> {
         let mut value: String = String::new();
         let mut buf = Vec::new();
         loop {
             match reader.read_event_into(&mut buf) {
                 Ok(Event::Text(e)) => {
-                    value = e.unescape().unwrap().to_string();
+                    value = e.unescape()?.to_string();
                 }
                 Ok(Event::End(ref e)) => match e.name().into_inner() {
                     b"xm:sqref" => {
                         self.set_sqref(value);
                         value = String::new();
-                        return;
+                        return Ok(());
                     }
                     _ => (),
                 },
-                Ok(Event::Eof) => panic!("Error: Could not find {} end element", "xm:sqref"),
-                Err(e) => panic!("Error at position {}: {:?}", reader.buffer_position(), e),
+                Ok(Event::Eof) => return Err(end_element_error(reader, "xm:sqref")),
+                Err(e) => return Err(xml_error(reader, e)),
                 _ => (),
             }
             buf.clear();
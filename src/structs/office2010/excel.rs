@@ -9,3 +9,27 @@ pub use self::data_validation_forumla1::*;
 
 mod data_validation_forumla2;
 pub use self::data_validation_forumla2::*;
+
+mod sparkline;
+pub use self::sparkline::*;
+
+mod sparkline_group;
+pub use self::sparkline_group::*;
+
+mod sparkline_groups;
+pub use self::sparkline_groups::*;
+
+mod sparkline_type_values;
+pub use self::sparkline_type_values::*;
+
+mod sparkline_axis_min_max_values;
+pub use self::sparkline_axis_min_max_values::*;
+
+mod conditional_formatting_icon;
+pub use self::conditional_formatting_icon::*;
+
+mod data_bar_axis_position_values;
+pub use self::data_bar_axis_position_values::*;
+
+mod data_bar_direction_values;
+pub use self::data_bar_direction_values::*;
@@ -0,0 +1,70 @@
+// x14:cfIcon
+use crate::reader::driver::*;
+use crate::structs::EnumValue;
+use crate::structs::IconSetValues;
+use crate::structs::UInt32Value;
+use crate::writer::driver::*;
+use crate::XlsxError;
+use quick_xml::events::BytesStart;
+use quick_xml::Reader;
+use quick_xml::Writer;
+use std::io::Cursor;
+
+/// Custom icon of one threshold of an icon set.
+#[derive(Clone, Default, Debug)]
+pub struct ConditionalFormattingIcon {
+    icon_set: EnumValue<IconSetValues>,
+    icon_id: UInt32Value,
+}
+
+impl ConditionalFormattingIcon {
+    /// Icon set the icon is taken from.
+    #[inline]
+    pub fn get_icon_set(&self) -> &IconSetValues {
+        self.icon_set.get_value()
+    }
+
+    #[inline]
+    pub fn set_icon_set(&mut self, value: IconSetValues) -> &mut Self {
+        self.icon_set.set_value(value);
+        self
+    }
+
+    /// Zero based index of the icon in the icon set.
+    #[inline]
+    pub fn get_icon_id(&self) -> &u32 {
+        self.icon_id.get_value()
+    }
+
+    #[inline]
+    pub fn set_icon_id(&mut self, value: u32) -> &mut Self {
+        self.icon_id.set_value(value);
+        self
+    }
+
+    #[inline]
+    pub(crate) fn set_attributes<R: std::io::BufRead>(
+        &mut self,
+        _reader: &mut Reader<R>,
+        e: &BytesStart,
+    ) -> Result<(), XlsxError> {
+        set_string_from_xml!(self, e, icon_set, "iconSet");
+        set_string_from_xml!(self, e, icon_id, "iconId");
+        Ok(())
+    }
+
+    #[inline]
+    pub(crate) fn write_to(&self, writer: &mut Writer<Cursor<Vec<u8>>>) {
+        // x14:cfIcon
+        let icon_id = self.icon_id.get_value_string();
+        write_start_tag(
+            writer,
+            "x14:cfIcon",
+            vec![
+                ("iconSet", self.icon_set.get_value_string()),
+                ("iconId", &icon_id),
+            ],
+            true,
+        );
+    }
+}
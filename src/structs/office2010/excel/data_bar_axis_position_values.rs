@@ -0,0 +1,37 @@
+use crate::structs::EnumTrait;
+use std::str::FromStr;
+#[derive(Clone, Debug, Eq, Ord, PartialEq, PartialOrd)]
+pub enum DataBarAxisPositionValues {
+    Automatic,
+    Middle,
+    None,
+}
+impl Default for DataBarAxisPositionValues {
+    #[inline]
+    fn default() -> Self {
+        Self::Automatic
+    }
+}
+impl EnumTrait for DataBarAxisPositionValues {
+    #[inline]
+    fn get_value_string(&self) -> &str {
+        match &self {
+            Self::Automatic => "automatic",
+            Self::Middle => "middle",
+            Self::None => "none",
+        }
+    }
+}
+impl FromStr for DataBarAxisPositionValues {
+    type Err = ();
+
+    #[inline]
+    fn from_str(input: &str) -> Result<Self, Self::Err> {
+        match input {
+            "automatic" => Ok(Self::Automatic),
+            "middle" => Ok(Self::Middle),
+            "none" => Ok(Self::None),
+            _ => Err(()),
+        }
+    }
+}
@@ -0,0 +1,37 @@
+use crate::structs::EnumTrait;
+use std::str::FromStr;
+#[derive(Clone, Debug, Eq, Ord, PartialEq, PartialOrd)]
+pub enum DataBarDirectionValues {
+    Context,
+    LeftToRight,
+    RightToLeft,
+}
+impl Default for DataBarDirectionValues {
+    #[inline]
+    fn default() -> Self {
+        Self::Context
+    }
+}
+impl EnumTrait for DataBarDirectionValues {
+    #[inline]
+    fn get_value_string(&self) -> &str {
+        match &self {
+            Self::Context => "context",
+            Self::LeftToRight => "leftToRight",
+            Self::RightToLeft => "rightToLeft",
+        }
+    }
+}
+impl FromStr for DataBarDirectionValues {
+    type Err = ();
+
+    #[inline]
+    fn from_str(input: &str) -> Result<Self, Self::Err> {
+        match input {
+            "context" => Ok(Self::Context),
+            "leftToRight" => Ok(Self::LeftToRight),
+            "rightToLeft" => Ok(Self::RightToLeft),
+            _ => Err(()),
+        }
+    }
+}
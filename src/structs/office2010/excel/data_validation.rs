@@ -9,6 +9,7 @@ use crate::structs::DataValidationValues;
 use crate::structs::EnumValue;
 use crate::structs::StringValue;
 use crate::writer::driver::*;
+use crate::XlsxError;
 use quick_xml::events::{BytesStart, Event};
 use quick_xml::Reader;
 use quick_xml::Writer;
@@ -171,37 +172,25 @@ impl DataValidation {
         reader: &mut Reader<R>,
         e: &BytesStart,
         empty_flg: bool,
-    ) {
-        if let Some(v) = get_attribute(e, b"type") {
-            self.r#type.set_value_string(v);
+    ) -> Result<(), XlsxError> {
+        if let Some(v) = get_attribute(e, b"type")? {
+            self.r#type.try_set_value_string(v, "type")?;
         }
 
-        if let Some(v) = get_attribute(e, b"operator") {
-            self.operator.set_value_string(v);
-        }
+        set_string_from_xml!(self, e, operator, "operator");
 
-        if let Some(v) = get_attribute(e, b"allowBlank") {
-            self.allow_blank.set_value_string(v);
-        }
+        set_string_from_xml!(self, e, allow_blank, "allowBlank");
 
-        if let Some(v) = get_attribute(e, b"showInputMessage") {
-            self.show_input_message.set_value_string(v);
-        }
+        set_string_from_xml!(self, e, show_input_message, "showInputMessage");
 
-        if let Some(v) = get_attribute(e, b"showErrorMessage") {
-            self.show_error_message.set_value_string(v);
-        }
+        set_string_from_xml!(self, e, show_error_message, "showErrorMessage");
 
-        if let Some(v) = get_attribute(e, b"promptTitle") {
-            self.prompt_title.set_value_string(v);
-        }
+        set_string_from_xml!(self, e, prompt_title, "promptTitle");
 
-        if let Some(v) = get_attribute(e, b"prompt") {
-            self.prompt.set_value_string(v);
-        }
+        set_string_from_xml!(self, e, prompt, "prompt");
 
         if empty_flg {
-            return;
+            return Ok(());
         }
 
         let mut buf = Vec::new();
@@ -210,29 +199,27 @@ impl DataValidation {
                 Ok(Event::Start(ref e)) => match e.name().into_inner() {
                     b"x14:formula1" => {
                         let mut obj = DataValidationForumla1::default();
-                        obj.set_attributes(reader, e);
+                        obj.set_attributes(reader, e)?;
                         self.formula1 = Some(Box::new(obj));
                     }
                     b"x14:formula2" => {
                         let mut obj = DataValidationForumla2::default();
-                        obj.set_attributes(reader, e);
+                        obj.set_attributes(reader, e)?;
                         self.formula2 = Some(Box::new(obj));
                     }
                     b"xm:sqref" => {
                         let mut obj = ReferenceSequence::default();
-                        obj.set_attributes(reader, e);
+                        obj.set_attributes(reader, e)?;
                         self.reference_sequence = obj;
                     }
                     _ => (),
                 },
                 Ok(Event::End(ref e)) => match e.name().into_inner() {
-                    b"x14:dataValidation" => return,
+                    b"x14:dataValidation" => return Ok(()),
                     _ => (),
                 },
-                Ok(Event::Eof) => {
-                    panic!("Error: Could not find {} end element", "x14:dataValidation")
-                }
-                Err(e) => panic!("Error at position {}: {:?}", reader.buffer_position(), e),
+                Ok(Event::Eof) => return Err(end_element_error(reader, "x14:dataValidation")),
+                Err(e) => return Err(xml_error(reader, e)),
                 _ => (),
             }
             buf.clear();
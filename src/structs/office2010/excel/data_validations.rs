@@ -3,6 +3,7 @@ use crate::helper::const_str::*;
 use crate::reader::driver::*;
 use crate::structs::office2010::excel::DataValidation;
 use crate::writer::driver::*;
+use crate::XlsxError;
 use quick_xml::events::{BytesStart, Event};
 use quick_xml::Reader;
 use quick_xml::Writer;
@@ -44,29 +45,29 @@ impl DataValidations {
         &mut self,
         reader: &mut Reader<R>,
         _e: &BytesStart,
-    ) {
+    ) -> Result<(), XlsxError> {
         xml_read_loop!(
             reader,
             Event::Empty(ref e) => {
                 if e.name().into_inner() == b"x14:dataValidation" {
                     let mut obj = DataValidation::default();
-                    obj.set_attributes(reader, e, true);
+                    obj.set_attributes(reader, e, true)?;
                     self.add_data_validation_list(obj);
                 }
             },
             Event::Start(ref e) => {
                 if e.name().into_inner() == b"x14:dataValidation" {
                     let mut obj = DataValidation::default();
-                    obj.set_attributes(reader, e, false);
+                    obj.set_attributes(reader, e, false)?;
                     self.add_data_validation_list(obj);
                 }
             },
             Event::End(ref e) => {
                 if e.name().into_inner() == b"x14:dataValidations" {
-                    return
+                    return Ok(())
                 }
             },
-            Event::Eof => panic!("Error: Could not find {} end element", "x14:dataValidations")
+            Event::Eof => return Err(end_element_error(reader, "x14:dataValidations"))
         );
     }
 
@@ -0,0 +1,227 @@
+// x14:sparkline
+use crate::reader::driver::*;
+use crate::structs::office::excel::Formula;
+use crate::structs::office::excel::ReferenceSequence;
+use crate::structs::Coordinate;
+use crate::traits::AdjustmentCoordinate;
+use crate::traits::AdjustmentCoordinateWithSheet;
+use crate::writer::driver::*;
+use crate::XlsxError;
+use quick_xml::events::{BytesStart, Event};
+use quick_xml::Reader;
+use quick_xml::Writer;
+use std::io::Cursor;
+
+#[derive(Default, Debug, Clone)]
+pub struct Sparkline {
+    formula: Option<Formula>,
+    reference_sequence: ReferenceSequence,
+}
+impl Sparkline {
+    /// Data range of the sparkline (e.g. `Sheet1!A1:E1`).
+    #[inline]
+    pub fn get_formula(&self) -> Option<&Formula> {
+        self.formula.as_ref()
+    }
+
+    #[inline]
+    pub fn get_formula_mut(&mut self) -> Option<&mut Formula> {
+        self.formula.as_mut()
+    }
+
+    #[inline]
+    pub fn set_formula(&mut self, value: Formula) -> &mut Self {
+        self.formula = Some(value);
+        self
+    }
+
+    #[inline]
+    pub fn remove_formula(&mut self) -> &mut Self {
+        self.formula = None;
+        self
+    }
+
+    /// Cell the sparkline is drawn in.
+    #[inline]
+    pub fn get_reference_sequence(&self) -> &ReferenceSequence {
+        &self.reference_sequence
+    }
+
+    #[inline]
+    pub fn get_reference_sequence_mut(&mut self) -> &mut ReferenceSequence {
+        &mut self.reference_sequence
+    }
+
+    #[inline]
+    pub fn set_reference_sequence(&mut self, value: ReferenceSequence) -> &mut Self {
+        self.reference_sequence = value;
+        self
+    }
+
+    #[inline]
+    pub fn get_data_range(&self) -> String {
+        self.formula
+            .as_ref()
+            .map(|v| v.get_value().get_address())
+            .unwrap_or_default()
+    }
+
+    /// Set the data range. Must include the sheet name (e.g. `Sheet1!A1:E1`).
+    #[inline]
+    pub fn set_data_range<S: Into<String>>(&mut self, value: S) -> &mut Self {
+        let mut obj = Formula::default();
+        obj.get_value_mut().set_address(value);
+        self.formula = Some(obj);
+        self
+    }
+
+    #[inline]
+    pub fn get_location(&self) -> String {
+        self.reference_sequence.get_sqref()
+    }
+
+    #[inline]
+    pub fn set_location<S: Into<String>>(&mut self, value: S) -> &mut Self {
+        self.reference_sequence.remove_value();
+        self.reference_sequence.set_sqref(value);
+        self
+    }
+
+    pub(crate) fn set_attributes<R: std::io::BufRead>(
+        &mut self,
+        reader: &mut Reader<R>,
+        _e: &BytesStart,
+    ) -> Result<(), XlsxError> {
+        xml_read_loop!(
+            reader,
+            Event::Start(ref e) => match e.name().into_inner() {
+                b"xm:f" => {
+                    let mut obj = Formula::default();
+                    obj.set_attributes(reader, e)?;
+                    self.formula = Some(obj);
+                }
+                b"xm:sqref" => {
+                    let mut obj = ReferenceSequence::default();
+                    obj.set_attributes(reader, e)?;
+                    self.reference_sequence = obj;
+                }
+                _ => (),
+            },
+            Event::End(ref e) => {
+                if e.name().into_inner() == b"x14:sparkline" {
+                    return Ok(())
+                }
+            },
+            Event::Eof => return Err(end_element_error(reader, "x14:sparkline"))
+        );
+    }
+
+    pub(crate) fn write_to(&self, writer: &mut Writer<Cursor<Vec<u8>>>) {
+        // x14:sparkline
+        write_start_tag(writer, "x14:sparkline", vec![], false);
+        if let Some(v) = &self.formula {
+            v.write_to(writer);
+        }
+        self.reference_sequence.write_to(writer);
+        write_end_tag(writer, "x14:sparkline");
+    }
+}
+impl AdjustmentCoordinate for Sparkline {
+    #[inline]
+    fn adjustment_insert_coordinate(
+        &mut self,
+        root_col_num: &u32,
+        offset_col_num: &u32,
+        root_row_num: &u32,
+        offset_row_num: &u32,
+    ) {
+        for range in self.reference_sequence.get_value_mut() {
+            range.adjustment_insert_coordinate(
+                root_col_num,
+                offset_col_num,
+                root_row_num,
+                offset_row_num,
+            );
+        }
+    }
+
+    #[inline]
+    fn adjustment_remove_coordinate(
+        &mut self,
+        root_col_num: &u32,
+        offset_col_num: &u32,
+        root_row_num: &u32,
+        offset_row_num: &u32,
+    ) {
+        for range in self.reference_sequence.get_value_mut() {
+            range.adjustment_remove_coordinate(
+                root_col_num,
+                offset_col_num,
+                root_row_num,
+                offset_row_num,
+            );
+        }
+    }
+
+    // the location is a single cell.
+    #[inline]
+    fn is_remove_coordinate(
+        &self,
+        root_col_num: &u32,
+        offset_col_num: &u32,
+        root_row_num: &u32,
+        offset_row_num: &u32,
+    ) -> bool {
+        self.reference_sequence.get_value().iter().all(|range| {
+            let mut coordinate = Coordinate::default();
+            coordinate.set_coordinate(range.get_coordinate_start());
+            coordinate.is_remove_coordinate(
+                root_col_num,
+                offset_col_num,
+                root_row_num,
+                offset_row_num,
+            )
+        })
+    }
+}
+impl AdjustmentCoordinateWithSheet for Sparkline {
+    #[inline]
+    fn adjustment_insert_coordinate_with_sheet(
+        &mut self,
+        sheet_name: &str,
+        root_col_num: &u32,
+        offset_col_num: &u32,
+        root_row_num: &u32,
+        offset_row_num: &u32,
+    ) {
+        if let Some(v) = &mut self.formula {
+            v.get_value_mut().adjustment_insert_coordinate_with_sheet(
+                sheet_name,
+                root_col_num,
+                offset_col_num,
+                root_row_num,
+                offset_row_num,
+            );
+        }
+    }
+
+    #[inline]
+    fn adjustment_remove_coordinate_with_sheet(
+        &mut self,
+        sheet_name: &str,
+        root_col_num: &u32,
+        offset_col_num: &u32,
+        root_row_num: &u32,
+        offset_row_num: &u32,
+    ) {
+        if let Some(v) = &mut self.formula {
+            v.get_value_mut().adjustment_remove_coordinate_with_sheet(
+                sheet_name,
+                root_col_num,
+                offset_col_num,
+                root_row_num,
+                offset_row_num,
+            );
+        }
+    }
+}
@@ -0,0 +1,728 @@
+// x14:sparklineGroup
+use crate::reader::driver::*;
+use crate::structs::drawing::charts::DisplayBlanksAsValues;
+use crate::structs::office::excel::Formula;
+use crate::structs::office2010::excel::Sparkline;
+use crate::structs::office2010::excel::SparklineAxisMinMaxValues;
+use crate::structs::office2010::excel::SparklineTypeValues;
+use crate::structs::BooleanValue;
+use crate::structs::Color;
+use crate::structs::DoubleValue;
+use crate::structs::EnumValue;
+use crate::traits::AdjustmentCoordinate;
+use crate::traits::AdjustmentCoordinateWithSheet;
+use crate::writer::driver::*;
+use crate::XlsxError;
+use quick_xml::events::{BytesStart, Event};
+use quick_xml::Reader;
+use quick_xml::Writer;
+use std::io::Cursor;
+use thin_vec::ThinVec;
+
+#[derive(Debug, Clone)]
+pub struct SparklineGroup {
+    manual_max: DoubleValue,
+    manual_min: DoubleValue,
+    line_weight: DoubleValue,
+    r#type: EnumValue<SparklineTypeValues>,
+    date_axis: BooleanValue,
+    display_empty_cells_as: EnumValue<DisplayBlanksAsValues>,
+    markers: BooleanValue,
+    high: BooleanValue,
+    low: BooleanValue,
+    first: BooleanValue,
+    last: BooleanValue,
+    negative: BooleanValue,
+    display_x_axis: BooleanValue,
+    display_hidden: BooleanValue,
+    min_axis_type: EnumValue<SparklineAxisMinMaxValues>,
+    max_axis_type: EnumValue<SparklineAxisMinMaxValues>,
+    right_to_left: BooleanValue,
+    color_series: Color,
+    color_negative: Color,
+    color_axis: Color,
+    color_markers: Color,
+    color_first: Color,
+    color_last: Color,
+    color_high: Color,
+    color_low: Color,
+    formula: Option<Formula>,
+    sparkline_list: ThinVec<Sparkline>,
+}
+impl Default for SparklineGroup {
+    #[inline]
+    fn default() -> Self {
+        let mut line_weight = DoubleValue::default();
+        line_weight.set_value(0.75);
+        let mut display_empty_cells_as = EnumValue::default();
+        display_empty_cells_as.set_value(DisplayBlanksAsValues::Gap);
+        Self {
+            manual_max: DoubleValue::default(),
+            manual_min: DoubleValue::default(),
+            line_weight,
+            r#type: EnumValue::default(),
+            date_axis: BooleanValue::default(),
+            display_empty_cells_as,
+            markers: BooleanValue::default(),
+            high: BooleanValue::default(),
+            low: BooleanValue::default(),
+            first: BooleanValue::default(),
+            last: BooleanValue::default(),
+            negative: BooleanValue::default(),
+            display_x_axis: BooleanValue::default(),
+            display_hidden: BooleanValue::default(),
+            min_axis_type: EnumValue::default(),
+            max_axis_type: EnumValue::default(),
+            right_to_left: BooleanValue::default(),
+            color_series: Color::default(),
+            color_negative: Color::default(),
+            color_axis: Color::default(),
+            color_markers: Color::default(),
+            color_first: Color::default(),
+            color_last: Color::default(),
+            color_high: Color::default(),
+            color_low: Color::default(),
+            formula: None,
+            sparkline_list: ThinVec::new(),
+        }
+    }
+}
+impl SparklineGroup {
+    /// Maximum of the vertical axis when the max axis type is `Custom`.
+    #[inline]
+    pub fn get_manual_max(&self) -> &f64 {
+        self.manual_max.get_value()
+    }
+
+    #[inline]
+    pub fn set_manual_max(&mut self, value: f64) -> &mut Self {
+        self.manual_max.set_value(value);
+        self
+    }
+
+    /// Minimum of the vertical axis when the min axis type is `Custom`.
+    #[inline]
+    pub fn get_manual_min(&self) -> &f64 {
+        self.manual_min.get_value()
+    }
+
+    #[inline]
+    pub fn set_manual_min(&mut self, value: f64) -> &mut Self {
+        self.manual_min.set_value(value);
+        self
+    }
+
+    /// Line weight in points.
+    #[inline]
+    pub fn get_line_weight(&self) -> &f64 {
+        self.line_weight.get_value()
+    }
+
+    #[inline]
+    pub fn set_line_weight(&mut self, value: f64) -> &mut Self {
+        self.line_weight.set_value(value);
+        self
+    }
+
+    #[inline]
+    pub fn get_type(&self) -> &SparklineTypeValues {
+        self.r#type.get_value()
+    }
+
+    #[inline]
+    pub fn set_type(&mut self, value: SparklineTypeValues) -> &mut Self {
+        self.r#type.set_value(value);
+        self
+    }
+
+    /// Use the date range (`xm:f`) of the group as the horizontal axis.
+    #[inline]
+    pub fn get_date_axis(&self) -> &bool {
+        self.date_axis.get_value()
+    }
+
+    #[inline]
+    pub fn set_date_axis(&mut self, value: bool) -> &mut Self {
+        self.date_axis.set_value(value);
+        self
+    }
+
+    #[inline]
+    pub fn get_display_empty_cells_as(&self) -> &DisplayBlanksAsValues {
+        self.display_empty_cells_as.get_value()
+    }
+
+    #[inline]
+    pub fn set_display_empty_cells_as(&mut self, value: DisplayBlanksAsValues) -> &mut Self {
+        self.display_empty_cells_as.set_value(value);
+        self
+    }
+
+    #[inline]
+    pub fn get_markers(&self) -> &bool {
+        self.markers.get_value()
+    }
+
+    #[inline]
+    pub fn set_markers(&mut self, value: bool) -> &mut Self {
+        self.markers.set_value(value);
+        self
+    }
+
+    #[inline]
+    pub fn get_high(&self) -> &bool {
+        self.high.get_value()
+    }
+
+    #[inline]
+    pub fn set_high(&mut self, value: bool) -> &mut Self {
+        self.high.set_value(value);
+        self
+    }
+
+    #[inline]
+    pub fn get_low(&self) -> &bool {
+        self.low.get_value()
+    }
+
+    #[inline]
+    pub fn set_low(&mut self, value: bool) -> &mut Self {
+        self.low.set_value(value);
+        self
+    }
+
+    #[inline]
+    pub fn get_first(&self) -> &bool {
+        self.first.get_value()
+    }
+
+    #[inline]
+    pub fn set_first(&mut self, value: bool) -> &mut Self {
+        self.first.set_value(value);
+        self
+    }
+
+    #[inline]
+    pub fn get_last(&self) -> &bool {
+        self.last.get_value()
+    }
+
+    #[inline]
+    pub fn set_last(&mut self, value: bool) -> &mut Self {
+        self.last.set_value(value);
+        self
+    }
+
+    #[inline]
+    pub fn get_negative(&self) -> &bool {
+        self.negative.get_value()
+    }
+
+    #[inline]
+    pub fn set_negative(&mut self, value: bool) -> &mut Self {
+        self.negative.set_value(value);
+        self
+    }
+
+    #[inline]
+    pub fn get_display_x_axis(&self) -> &bool {
+        self.display_x_axis.get_value()
+    }
+
+    #[inline]
+    pub fn set_display_x_axis(&mut self, value: bool) -> &mut Self {
+        self.display_x_axis.set_value(value);
+        self
+    }
+
+    /// Plot data in hidden rows and columns.
+    #[inline]
+    pub fn get_display_hidden(&self) -> &bool {
+        self.display_hidden.get_value()
+    }
+
+    #[inline]
+    pub fn set_display_hidden(&mut self, value: bool) -> &mut Self {
+        self.display_hidden.set_value(value);
+        self
+    }
+
+    #[inline]
+    pub fn get_min_axis_type(&self) -> &SparklineAxisMinMaxValues {
+        self.min_axis_type.get_value()
+    }
+
+    #[inline]
+    pub fn set_min_axis_type(&mut self, value: SparklineAxisMinMaxValues) -> &mut Self {
+        self.min_axis_type.set_value(value);
+        self
+    }
+
+    #[inline]
+    pub fn get_max_axis_type(&self) -> &SparklineAxisMinMaxValues {
+        self.max_axis_type.get_value()
+    }
+
+    #[inline]
+    pub fn set_max_axis_type(&mut self, value: SparklineAxisMinMaxValues) -> &mut Self {
+        self.max_axis_type.set_value(value);
+        self
+    }
+
+    #[inline]
+    pub fn get_right_to_left(&self) -> &bool {
+        self.right_to_left.get_value()
+    }
+
+    #[inline]
+    pub fn set_right_to_left(&mut self, value: bool) -> &mut Self {
+        self.right_to_left.set_value(value);
+        self
+    }
+
+    #[inline]
+    pub fn get_color_series(&self) -> &Color {
+        &self.color_series
+    }
+
+    #[inline]
+    pub fn get_color_series_mut(&mut self) -> &mut Color {
+        &mut self.color_series
+    }
+
+    #[inline]
+    pub fn set_color_series(&mut self, value: Color) -> &mut Self {
+        self.color_series = value;
+        self
+    }
+
+    #[inline]
+    pub fn get_color_negative(&self) -> &Color {
+        &self.color_negative
+    }
+
+    #[inline]
+    pub fn get_color_negative_mut(&mut self) -> &mut Color {
+        &mut self.color_negative
+    }
+
+    #[inline]
+    pub fn set_color_negative(&mut self, value: Color) -> &mut Self {
+        self.color_negative = value;
+        self
+    }
+
+    #[inline]
+    pub fn get_color_axis(&self) -> &Color {
+        &self.color_axis
+    }
+
+    #[inline]
+    pub fn get_color_axis_mut(&mut self) -> &mut Color {
+        &mut self.color_axis
+    }
+
+    #[inline]
+    pub fn set_color_axis(&mut self, value: Color) -> &mut Self {
+        self.color_axis = value;
+        self
+    }
+
+    #[inline]
+    pub fn get_color_markers(&self) -> &Color {
+        &self.color_markers
+    }
+
+    #[inline]
+    pub fn get_color_markers_mut(&mut self) -> &mut Color {
+        &mut self.color_markers
+    }
+
+    #[inline]
+    pub fn set_color_markers(&mut self, value: Color) -> &mut Self {
+        self.color_markers = value;
+        self
+    }
+
+    #[inline]
+    pub fn get_color_first(&self) -> &Color {
+        &self.color_first
+    }
+
+    #[inline]
+    pub fn get_color_first_mut(&mut self) -> &mut Color {
+        &mut self.color_first
+    }
+
+    #[inline]
+    pub fn set_color_first(&mut self, value: Color) -> &mut Self {
+        self.color_first = value;
+        self
+    }
+
+    #[inline]
+    pub fn get_color_last(&self) -> &Color {
+        &self.color_last
+    }
+
+    #[inline]
+    pub fn get_color_last_mut(&mut self) -> &mut Color {
+        &mut self.color_last
+    }
+
+    #[inline]
+    pub fn set_color_last(&mut self, value: Color) -> &mut Self {
+        self.color_last = value;
+        self
+    }
+
+    #[inline]
+    pub fn get_color_high(&self) -> &Color {
+        &self.color_high
+    }
+
+    #[inline]
+    pub fn get_color_high_mut(&mut self) -> &mut Color {
+        &mut self.color_high
+    }
+
+    #[inline]
+    pub fn set_color_high(&mut self, value: Color) -> &mut Self {
+        self.color_high = value;
+        self
+    }
+
+    #[inline]
+    pub fn get_color_low(&self) -> &Color {
+        &self.color_low
+    }
+
+    #[inline]
+    pub fn get_color_low_mut(&mut self) -> &mut Color {
+        &mut self.color_low
+    }
+
+    #[inline]
+    pub fn set_color_low(&mut self, value: Color) -> &mut Self {
+        self.color_low = value;
+        self
+    }
+
+    /// Date range used when the date axis is enabled.
+    #[inline]
+    pub fn get_formula(&self) -> Option<&Formula> {
+        self.formula.as_ref()
+    }
+
+    #[inline]
+    pub fn get_formula_mut(&mut self) -> Option<&mut Formula> {
+        self.formula.as_mut()
+    }
+
+    #[inline]
+    pub fn set_formula(&mut self, value: Formula) -> &mut Self {
+        self.formula = Some(value);
+        self
+    }
+
+    #[inline]
+    pub fn remove_formula(&mut self) -> &mut Self {
+        self.formula = None;
+        self
+    }
+
+    #[inline]
+    pub fn get_sparkline_list(&self) -> &[Sparkline] {
+        &self.sparkline_list
+    }
+
+    #[inline]
+    pub fn get_sparkline_list_mut(&mut self) -> &mut ThinVec<Sparkline> {
+        &mut self.sparkline_list
+    }
+
+    #[inline]
+    pub fn add_sparkline(&mut self, value: Sparkline) -> &mut Self {
+        self.sparkline_list.push(value);
+        self
+    }
+
+    /// Add a sparkline drawing `data_range` (e.g. `Sheet1!A1:E1`) in the `location` cell.
+    #[inline]
+    pub fn add_sparkline_range<S: Into<String>>(
+        &mut self,
+        data_range: S,
+        location: S,
+    ) -> &mut Self {
+        let mut obj = Sparkline::default();
+        obj.set_data_range(data_range).set_location(location);
+        self.add_sparkline(obj)
+    }
+
+    pub(crate) fn set_attributes<R: std::io::BufRead>(
+        &mut self,
+        reader: &mut Reader<R>,
+        e: &BytesStart,
+    ) -> Result<(), XlsxError> {
+        set_string_from_xml!(self, e, manual_max, "manualMax");
+        set_string_from_xml!(self, e, manual_min, "manualMin");
+        set_string_from_xml!(self, e, line_weight, "lineWeight");
+        set_string_from_xml!(self, e, r#type, "type");
+        set_string_from_xml!(self, e, date_axis, "dateAxis");
+        set_string_from_xml!(self, e, display_empty_cells_as, "displayEmptyCellsAs");
+        set_string_from_xml!(self, e, markers, "markers");
+        set_string_from_xml!(self, e, high, "high");
+        set_string_from_xml!(self, e, low, "low");
+        set_string_from_xml!(self, e, first, "first");
+        set_string_from_xml!(self, e, last, "last");
+        set_string_from_xml!(self, e, negative, "negative");
+        set_string_from_xml!(self, e, display_x_axis, "displayXAxis");
+        set_string_from_xml!(self, e, display_hidden, "displayHidden");
+        set_string_from_xml!(self, e, min_axis_type, "minAxisType");
+        set_string_from_xml!(self, e, max_axis_type, "maxAxisType");
+        set_string_from_xml!(self, e, right_to_left, "rightToLeft");
+
+        xml_read_loop!(
+            reader,
+            Event::Empty(ref e) => match e.name().into_inner() {
+                b"x14:colorSeries" => self.color_series.set_attributes(reader, e, true)?,
+                b"x14:colorNegative" => self.color_negative.set_attributes(reader, e, true)?,
+                b"x14:colorAxis" => self.color_axis.set_attributes(reader, e, true)?,
+                b"x14:colorMarkers" => self.color_markers.set_attributes(reader, e, true)?,
+                b"x14:colorFirst" => self.color_first.set_attributes(reader, e, true)?,
+                b"x14:colorLast" => self.color_last.set_attributes(reader, e, true)?,
+                b"x14:colorHigh" => self.color_high.set_attributes(reader, e, true)?,
+                b"x14:colorLow" => self.color_low.set_attributes(reader, e, true)?,
+                _ => (),
+            },
+            Event::Start(ref e) => match e.name().into_inner() {
+                b"x14:colorSeries" => self.color_series.set_attributes(reader, e, false)?,
+                b"x14:colorNegative" => self.color_negative.set_attributes(reader, e, false)?,
+                b"x14:colorAxis" => self.color_axis.set_attributes(reader, e, false)?,
+                b"x14:colorMarkers" => self.color_markers.set_attributes(reader, e, false)?,
+                b"x14:colorFirst" => self.color_first.set_attributes(reader, e, false)?,
+                b"x14:colorLast" => self.color_last.set_attributes(reader, e, false)?,
+                b"x14:colorHigh" => self.color_high.set_attributes(reader, e, false)?,
+                b"x14:colorLow" => self.color_low.set_attributes(reader, e, false)?,
+                b"xm:f" => {
+                    let mut obj = Formula::default();
+                    obj.set_attributes(reader, e)?;
+                    self.formula = Some(obj);
+                }
+                b"x14:sparkline" => {
+                    let mut obj = Sparkline::default();
+                    obj.set_attributes(reader, e)?;
+                    self.add_sparkline(obj);
+                }
+                _ => (),
+            },
+            Event::End(ref e) => {
+                if e.name().into_inner() == b"x14:sparklineGroup" {
+                    return Ok(())
+                }
+            },
+            Event::Eof => return Err(end_element_error(reader, "x14:sparklineGroup"))
+        );
+    }
+
+    pub(crate) fn write_to(&self, writer: &mut Writer<Cursor<Vec<u8>>>) {
+        // x14:sparklineGroup
+        let mut attributes: Vec<(&str, &str)> = Vec::new();
+        let manual_max = self.manual_max.get_value_string();
+        if self.manual_max.has_value() {
+            attributes.push(("manualMax", &manual_max));
+        }
+        let manual_min = self.manual_min.get_value_string();
+        if self.manual_min.has_value() {
+            attributes.push(("manualMin", &manual_min));
+        }
+        let line_weight = self.line_weight.get_value_string();
+        if self.line_weight.has_value() {
+            attributes.push(("lineWeight", &line_weight));
+        }
+        if self.r#type.has_value() {
+            attributes.push(("type", self.r#type.get_value_string()));
+        }
+        if self.date_axis.has_value() {
+            attributes.push(("dateAxis", self.date_axis.get_value_string()));
+        }
+        if self.display_empty_cells_as.has_value() {
+            attributes.push((
+                "displayEmptyCellsAs",
+                self.display_empty_cells_as.get_value_string(),
+            ));
+        }
+        if self.markers.has_value() {
+            attributes.push(("markers", self.markers.get_value_string()));
+        }
+        if self.high.has_value() {
+            attributes.push(("high", self.high.get_value_string()));
+        }
+        if self.low.has_value() {
+            attributes.push(("low", self.low.get_value_string()));
+        }
+        if self.first.has_value() {
+            attributes.push(("first", self.first.get_value_string()));
+        }
+        if self.last.has_value() {
+            attributes.push(("last", self.last.get_value_string()));
+        }
+        if self.negative.has_value() {
+            attributes.push(("negative", self.negative.get_value_string()));
+        }
+        if self.display_x_axis.has_value() {
+            attributes.push(("displayXAxis", self.display_x_axis.get_value_string()));
+        }
+        if self.display_hidden.has_value() {
+            attributes.push(("displayHidden", self.display_hidden.get_value_string()));
+        }
+        if self.min_axis_type.has_value() {
+            attributes.push(("minAxisType", self.min_axis_type.get_value_string()));
+        }
+        if self.max_axis_type.has_value() {
+            attributes.push(("maxAxisType", self.max_axis_type.get_value_string()));
+        }
+        if self.right_to_left.has_value() {
+            attributes.push(("rightToLeft", self.right_to_left.get_value_string()));
+        }
+        write_start_tag(writer, "x14:sparklineGroup", attributes, false);
+
+        // colors
+        self.color_series.write_to(writer, "x14:colorSeries");
+        self.color_negative.write_to(writer, "x14:colorNegative");
+        self.color_axis.write_to(writer, "x14:colorAxis");
+        self.color_markers.write_to(writer, "x14:colorMarkers");
+        self.color_first.write_to(writer, "x14:colorFirst");
+        self.color_last.write_to(writer, "x14:colorLast");
+        self.color_high.write_to(writer, "x14:colorHigh");
+        self.color_low.write_to(writer, "x14:colorLow");
+
+        // xm:f
+        if let Some(v) = &self.formula {
+            v.write_to(writer);
+        }
+
+        // x14:sparklines
+        write_start_tag(writer, "x14:sparklines", vec![], false);
+        for obj in &self.sparkline_list {
+            obj.write_to(writer);
+        }
+        write_end_tag(writer, "x14:sparklines");
+
+        write_end_tag(writer, "x14:sparklineGroup");
+    }
+}
+impl AdjustmentCoordinate for SparklineGroup {
+    #[inline]
+    fn adjustment_insert_coordinate(
+        &mut self,
+        root_col_num: &u32,
+        offset_col_num: &u32,
+        root_row_num: &u32,
+        offset_row_num: &u32,
+    ) {
+        for sparkline in &mut self.sparkline_list {
+            sparkline.adjustment_insert_coordinate(
+                root_col_num,
+                offset_col_num,
+                root_row_num,
+                offset_row_num,
+            );
+        }
+    }
+
+    #[inline]
+    fn adjustment_remove_coordinate(
+        &mut self,
+        root_col_num: &u32,
+        offset_col_num: &u32,
+        root_row_num: &u32,
+        offset_row_num: &u32,
+    ) {
+        self.sparkline_list.retain(|x| {
+            !x.is_remove_coordinate(root_col_num, offset_col_num, root_row_num, offset_row_num)
+        });
+        for sparkline in &mut self.sparkline_list {
+            sparkline.adjustment_remove_coordinate(
+                root_col_num,
+                offset_col_num,
+                root_row_num,
+                offset_row_num,
+            );
+        }
+    }
+
+    #[inline]
+    fn is_remove_coordinate(
+        &self,
+        root_col_num: &u32,
+        offset_col_num: &u32,
+        root_row_num: &u32,
+        offset_row_num: &u32,
+    ) -> bool {
+        self.sparkline_list.iter().all(|x| {
+            x.is_remove_coordinate(root_col_num, offset_col_num, root_row_num, offset_row_num)
+        })
+    }
+}
+impl AdjustmentCoordinateWithSheet for SparklineGroup {
+    #[inline]
+    fn adjustment_insert_coordinate_with_sheet(
+        &mut self,
+        sheet_name: &str,
+        root_col_num: &u32,
+        offset_col_num: &u32,
+        root_row_num: &u32,
+        offset_row_num: &u32,
+    ) {
+        // date axis range
+        if let Some(v) = &mut self.formula {
+            v.get_value_mut().adjustment_insert_coordinate_with_sheet(
+                sheet_name,
+                root_col_num,
+                offset_col_num,
+                root_row_num,
+                offset_row_num,
+            );
+        }
+        for sparkline in &mut self.sparkline_list {
+            sparkline.adjustment_insert_coordinate_with_sheet(
+                sheet_name,
+                root_col_num,
+                offset_col_num,
+                root_row_num,
+                offset_row_num,
+            );
+        }
+    }
+
+    #[inline]
+    fn adjustment_remove_coordinate_with_sheet(
+        &mut self,
+        sheet_name: &str,
+        root_col_num: &u32,
+        offset_col_num: &u32,
+        root_row_num: &u32,
+        offset_row_num: &u32,
+    ) {
+        // date axis range
+        if let Some(v) = &mut self.formula {
+            v.get_value_mut().adjustment_remove_coordinate_with_sheet(
+                sheet_name,
+                root_col_num,
+                offset_col_num,
+                root_row_num,
+                offset_row_num,
+            );
+        }
+        for sparkline in &mut self.sparkline_list {
+            sparkline.adjustment_remove_coordinate_with_sheet(
+                sheet_name,
+                root_col_num,
+                offset_col_num,
+                root_row_num,
+                offset_row_num,
+            );
+        }
+    }
+}
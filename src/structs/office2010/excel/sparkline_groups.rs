@@ -0,0 +1,175 @@
+// x14:sparklineGroups
+use crate::helper::const_str::*;
+use crate::reader::driver::*;
+use crate::structs::office2010::excel::SparklineGroup;
+use crate::traits::AdjustmentCoordinate;
+use crate::traits::AdjustmentCoordinateWithSheet;
+use crate::writer::driver::*;
+use crate::XlsxError;
+use quick_xml::events::{BytesStart, Event};
+use quick_xml::Reader;
+use quick_xml::Writer;
+use std::io::Cursor;
+use thin_vec::ThinVec;
+
+#[derive(Default, Debug, Clone)]
+pub struct SparklineGroups {
+    sparkline_group_list: ThinVec<SparklineGroup>,
+}
+
+impl SparklineGroups {
+    #[inline]
+    pub fn get_sparkline_group_list(&self) -> &[SparklineGroup] {
+        &self.sparkline_group_list
+    }
+
+    #[inline]
+    pub fn get_sparkline_group_list_mut(&mut self) -> &mut ThinVec<SparklineGroup> {
+        &mut self.sparkline_group_list
+    }
+
+    #[inline]
+    pub fn set_sparkline_group_list(
+        &mut self,
+        value: impl Into<ThinVec<SparklineGroup>>,
+    ) -> &mut Self {
+        self.sparkline_group_list = value.into();
+        self
+    }
+
+    #[inline]
+    pub fn add_sparkline_group(&mut self, value: SparklineGroup) -> &mut Self {
+        self.sparkline_group_list.push(value);
+        self
+    }
+
+    #[inline]
+    pub(crate) fn is_empty(&self) -> bool {
+        self.sparkline_group_list.is_empty()
+    }
+
+    pub(crate) fn set_attributes<R: std::io::BufRead>(
+        &mut self,
+        reader: &mut Reader<R>,
+        _e: &BytesStart,
+    ) -> Result<(), XlsxError> {
+        xml_read_loop!(
+            reader,
+            Event::Start(ref e) => {
+                if e.name().into_inner() == b"x14:sparklineGroup" {
+                    let mut obj = SparklineGroup::default();
+                    obj.set_attributes(reader, e)?;
+                    self.add_sparkline_group(obj);
+                }
+            },
+            Event::End(ref e) => {
+                if e.name().into_inner() == b"x14:sparklineGroups" {
+                    return Ok(())
+                }
+            },
+            Event::Eof => return Err(end_element_error(reader, "x14:sparklineGroups"))
+        );
+    }
+
+    pub(crate) fn write_to(&self, writer: &mut Writer<Cursor<Vec<u8>>>) {
+        // ext
+        let mut attributes: Vec<(&str, &str)> = Vec::new();
+        attributes.push(("uri", "{05C60535-1F16-4fd2-B633-F4F36F0B64E0}"));
+        attributes.push(("xmlns:x14", SHEET_MS_MAIN_NS));
+        write_start_tag(writer, "ext", attributes, false);
+
+        // sparklineGroups
+        write_start_tag(
+            writer,
+            "x14:sparklineGroups",
+            vec![("xmlns:xm", EXCEL_MAIN_NS)],
+            false,
+        );
+        for obj in &self.sparkline_group_list {
+            obj.write_to(writer);
+        }
+        write_end_tag(writer, "x14:sparklineGroups");
+        write_end_tag(writer, "ext");
+    }
+}
+impl AdjustmentCoordinate for SparklineGroups {
+    #[inline]
+    fn adjustment_insert_coordinate(
+        &mut self,
+        root_col_num: &u32,
+        offset_col_num: &u32,
+        root_row_num: &u32,
+        offset_row_num: &u32,
+    ) {
+        for group in &mut self.sparkline_group_list {
+            group.adjustment_insert_coordinate(
+                root_col_num,
+                offset_col_num,
+                root_row_num,
+                offset_row_num,
+            );
+        }
+    }
+
+    #[inline]
+    fn adjustment_remove_coordinate(
+        &mut self,
+        root_col_num: &u32,
+        offset_col_num: &u32,
+        root_row_num: &u32,
+        offset_row_num: &u32,
+    ) {
+        self.sparkline_group_list.retain(|x| {
+            !x.is_remove_coordinate(root_col_num, offset_col_num, root_row_num, offset_row_num)
+        });
+        for group in &mut self.sparkline_group_list {
+            group.adjustment_remove_coordinate(
+                root_col_num,
+                offset_col_num,
+                root_row_num,
+                offset_row_num,
+            );
+        }
+    }
+}
+impl AdjustmentCoordinateWithSheet for SparklineGroups {
+    #[inline]
+    fn adjustment_insert_coordinate_with_sheet(
+        &mut self,
+        sheet_name: &str,
+        root_col_num: &u32,
+        offset_col_num: &u32,
+        root_row_num: &u32,
+        offset_row_num: &u32,
+    ) {
+        for group in &mut self.sparkline_group_list {
+            group.adjustment_insert_coordinate_with_sheet(
+                sheet_name,
+                root_col_num,
+                offset_col_num,
+                root_row_num,
+                offset_row_num,
+            );
+        }
+    }
+
+    #[inline]
+    fn adjustment_remove_coordinate_with_sheet(
+        &mut self,
+        sheet_name: &str,
+        root_col_num: &u32,
+        offset_col_num: &u32,
+        root_row_num: &u32,
+        offset_row_num: &u32,
+    ) {
+        for group in &mut self.sparkline_group_list {
+            group.adjustment_remove_coordinate_with_sheet(
+                sheet_name,
+                root_col_num,
+                offset_col_num,
+                root_row_num,
+                offset_row_num,
+            );
+        }
+    }
+}
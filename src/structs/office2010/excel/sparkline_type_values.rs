@@ -0,0 +1,38 @@
+use crate::structs::EnumTrait;
+use std::str::FromStr;
+#[derive(Clone, Debug, Eq, Ord, PartialEq, PartialOrd)]
+pub enum SparklineTypeValues {
+    Column,
+    Line,
+    /// Win/Loss
+    Stacked,
+}
+impl Default for SparklineTypeValues {
+    #[inline]
+    fn default() -> Self {
+        Self::Line
+    }
+}
+impl EnumTrait for SparklineTypeValues {
+    #[inline]
+    fn get_value_string(&self) -> &str {
+        match &self {
+            Self::Column => "column",
+            Self::Line => "line",
+            Self::Stacked => "stacked",
+        }
+    }
+}
+impl FromStr for SparklineTypeValues {
+    type Err = ();
+
+    #[inline]
+    fn from_str(input: &str) -> Result<Self, Self::Err> {
+        match input {
+            "column" => Ok(Self::Column),
+            "line" => Ok(Self::Line),
+            "stacked" => Ok(Self::Stacked),
+            _ => Err(()),
+        }
+    }
+}
@@ -6,6 +6,7 @@ use crate::structs::drawing::spreadsheet::TwoCellAnchor;
 use crate::structs::raw::RawRelationships;
 use crate::structs::vml::Shape;
 use crate::writer::driver::*;
+use crate::XlsxError;
 use quick_xml::events::{BytesStart, Event};
 use quick_xml::Reader;
 use quick_xml::Writer;
@@ -135,7 +136,7 @@ impl OleObject {
         reader: &mut Reader<R>,
         _e: &BytesStart,
         relationships: &RawRelationships,
-    ) {
+    ) -> Result<(), XlsxError> {
         let mut alternate_content: &str = "";
 
         xml_read_loop!(
@@ -151,19 +152,18 @@ impl OleObject {
                     }
                     b"oleObject" => {
                         if alternate_content == "Choice" {
-                            self.prog_id
-                                .set_value_string(get_attribute(e, b"progId").unwrap());
+                            self.prog_id.try_set_value_string(get_required_attribute(e, b"progId")?, "progId")?;
 
-                            let r_id = get_attribute(e, b"r:id").unwrap();
+                            let r_id = get_required_attribute(e, b"r:id")?;
                             let attached_file =
-                                relationships.get_relationship_by_rid(&r_id).get_raw_file();
+                                relationships.get_relationship_by_rid(&r_id)?.get_raw_file();
                             self.set_object_extension(attached_file.get_extension());
                             self.set_object_data(attached_file.get_file_data().clone());
                         }
                     }
                     b"objectPr" => {
                         let mut obj = EmbeddedObjectProperties::default();
-                        obj.set_attributes(reader, e, relationships);
+                        obj.set_attributes(reader, e, relationships)?;
                         self.set_embedded_object_properties(obj);
                     }
                     _ => (),
@@ -171,10 +171,10 @@ impl OleObject {
             },
             Event::End(ref e) => {
                 if e.name().into_inner() == b"mc:AlternateContent" {
-                    return
+                    return Ok(())
                 }
             },
-            Event::Eof => panic!("Error: Could not find {} end element", "mc:AlternateContent")
+            Event::Eof => return Err(end_element_error(reader, "mc:AlternateContent"))
         );
     }
 
@@ -3,6 +3,7 @@ use super::OleObject;
 use crate::reader::driver::*;
 use crate::structs::raw::RawRelationships;
 use crate::writer::driver::*;
+use crate::XlsxError;
 use quick_xml::events::{BytesStart, Event};
 use quick_xml::Reader;
 use quick_xml::Writer;
@@ -36,22 +37,22 @@ impl OleObjects {
         reader: &mut Reader<R>,
         _e: &BytesStart,
         relationships: &RawRelationships,
-    ) {
+    ) -> Result<(), XlsxError> {
         xml_read_loop!(
             reader,
             Event::Start(ref e) => {
                 if e.name().into_inner() == b"mc:AlternateContent" {
                     let mut obj = OleObject::default();
-                    obj.set_attributes(reader, e, relationships);
+                    obj.set_attributes(reader, e, relationships)?;
                     self.set_ole_object(obj);
                 }
             },
             Event::End(ref e) => {
                 if e.name().into_inner() == b"oleObjects" {
-                    return
+                    return Ok(())
                 }
             },
-            Event::Eof => panic!("Error: Could not find {} end element", "oleObjects")
+            Event::Eof => return Err(end_element_error(reader, "oleObjects"))
         );
     }
 
@@ -0,0 +1,84 @@
+// pageField
+use crate::reader::driver::*;
+use crate::structs::Int32Value;
+use crate::structs::UInt32Value;
+use crate::writer::driver::*;
+use crate::XlsxError;
+use quick_xml::events::BytesStart;
+use quick_xml::Reader;
+use quick_xml::Writer;
+use std::io::Cursor;
+
+#[derive(Clone, Default, Debug)]
+pub struct PageField {
+    field: Int32Value,
+    item: UInt32Value,
+    hierarchy: Int32Value,
+}
+impl PageField {
+    #[inline]
+    pub fn get_field(&self) -> &i32 {
+        self.field.get_value()
+    }
+
+    #[inline]
+    pub fn set_field(&mut self, value: i32) -> &mut Self {
+        self.field.set_value(value);
+        self
+    }
+
+    /// Selected item of the filter, `None` shows all items.
+    #[inline]
+    pub fn get_item(&self) -> Option<&u32> {
+        if self.item.has_value() {
+            return Some(self.item.get_value());
+        }
+        None
+    }
+
+    #[inline]
+    pub fn set_item(&mut self, value: u32) -> &mut Self {
+        self.item.set_value(value);
+        self
+    }
+
+    #[inline]
+    pub fn get_hierarchy(&self) -> &i32 {
+        self.hierarchy.get_value()
+    }
+
+    #[inline]
+    pub fn set_hierarchy(&mut self, value: i32) -> &mut Self {
+        self.hierarchy.set_value(value);
+        self
+    }
+
+    #[inline]
+    pub(crate) fn set_attributes<R: std::io::BufRead>(
+        &mut self,
+        _reader: &mut Reader<R>,
+        e: &BytesStart,
+    ) -> Result<(), XlsxError> {
+        set_string_from_xml!(self, e, field, "fld");
+        set_string_from_xml!(self, e, item, "item");
+        set_string_from_xml!(self, e, hierarchy, "hier");
+        Ok(())
+    }
+
+    #[inline]
+    pub(crate) fn write_to(&self, writer: &mut Writer<Cursor<Vec<u8>>>) {
+        // pageField
+        let mut attributes: Vec<(&str, &str)> = Vec::new();
+        let field_str = self.field.get_value_string();
+        attributes.push(("fld", &field_str));
+        let item_str = self.item.get_value_string();
+        if self.item.has_value() {
+            attributes.push(("item", &item_str));
+        }
+        let hierarchy_str = self.hierarchy.get_value_string();
+        if self.hierarchy.has_value() {
+            attributes.push(("hier", &hierarchy_str));
+        }
+        write_start_tag(writer, "pageField", attributes, true);
+    }
+}
@@ -0,0 +1,74 @@
+// pageFields
+use crate::reader::driver::*;
+use crate::structs::PageField;
+use crate::writer::driver::*;
+use crate::XlsxError;
+use quick_xml::events::{BytesStart, Event};
+use quick_xml::Reader;
+use quick_xml::Writer;
+use std::io::Cursor;
+use thin_vec::ThinVec;
+
+#[derive(Clone, Default, Debug)]
+pub struct PageFields {
+    list: ThinVec<PageField>,
+}
+impl PageFields {
+    #[inline]
+    pub fn get_list(&self) -> &[PageField] {
+        &self.list
+    }
+
+    #[inline]
+    pub fn get_list_mut(&mut self) -> &mut ThinVec<PageField> {
+        &mut self.list
+    }
+
+    #[inline]
+    pub fn add_list_mut(&mut self, value: PageField) -> &mut Self {
+        self.list.push(value);
+        self
+    }
+
+    #[inline]
+    pub(crate) fn set_attributes<R: std::io::BufRead>(
+        &mut self,
+        reader: &mut Reader<R>,
+        e: &BytesStart,
+    ) -> Result<(), XlsxError> {
+        xml_read_loop!(
+            reader,
+            Event::Empty(ref e) => {
+                if e.name().into_inner() == b"pageField" {
+                    let mut obj = PageField::default();
+                    obj.set_attributes(reader, e)?;
+                    self.add_list_mut(obj);
+                }
+            },
+            Event::End(ref e) => {
+                if e.name().into_inner() == b"pageFields" {
+                    return Ok(())
+                }
+            },
+            Event::Eof => return Err(end_element_error(reader, "pageFields"))
+        );
+    }
+
+    #[inline]
+    pub(crate) fn write_to(&self, writer: &mut Writer<Cursor<Vec<u8>>>) {
+        // pageFields
+        write_start_tag(
+            writer,
+            "pageFields",
+            vec![("count", &self.list.len().to_string())],
+            false,
+        );
+
+        // pageField
+        for i in &self.list {
+            i.write_to(writer);
+        }
+
+        write_end_tag(writer, "pageFields");
+    }
+}
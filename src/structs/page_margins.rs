@@ -1,4 +1,5 @@
 use crate::structs::DoubleValue;
+use crate::XlsxError;
 
 use crate::reader::driver::*;
 use crate::writer::driver::*;
@@ -87,18 +88,20 @@ impl PageMargins {
         &mut self,
         _reader: &mut Reader<R>,
         e: &BytesStart,
-    ) {
+    ) -> Result<(), XlsxError> {
         self.left
-            .set_value_string(get_attribute(e, b"left").unwrap());
+            .try_set_value_string(get_required_attribute(e, b"left")?, "left")?;
         self.right
-            .set_value_string(get_attribute(e, b"right").unwrap());
-        self.top.set_value_string(get_attribute(e, b"top").unwrap());
+            .try_set_value_string(get_required_attribute(e, b"right")?, "right")?;
+        self.top
+            .try_set_value_string(get_required_attribute(e, b"top")?, "top")?;
         self.bottom
-            .set_value_string(get_attribute(e, b"bottom").unwrap());
+            .try_set_value_string(get_required_attribute(e, b"bottom")?, "bottom")?;
         self.header
-            .set_value_string(get_attribute(e, b"header").unwrap());
+            .try_set_value_string(get_required_attribute(e, b"header")?, "header")?;
         self.footer
-            .set_value_string(get_attribute(e, b"footer").unwrap());
+            .try_set_value_string(get_required_attribute(e, b"footer")?, "footer")?;
+        Ok(())
     }
 
     pub(crate) fn write_to(&self, writer: &mut Writer<Cursor<Vec<u8>>>) {
@@ -4,6 +4,7 @@ use crate::structs::EnumValue;
 use crate::structs::OrientationValues;
 use crate::structs::UInt32Value;
 use crate::writer::driver::*;
+use crate::XlsxError;
 use quick_xml::events::BytesStart;
 use quick_xml::Reader;
 use quick_xml::Writer;
@@ -133,7 +134,7 @@ impl PageSetup {
         _reader: &mut Reader<R>,
         e: &BytesStart,
         relationships: Option<&RawRelationships>,
-    ) {
+    ) -> Result<(), XlsxError> {
         set_string_from_xml!(self, e, paper_size, "paperSize");
         set_string_from_xml!(self, e, orientation, "orientation");
         set_string_from_xml!(self, e, scale, "scale");
@@ -142,13 +143,14 @@ impl PageSetup {
         set_string_from_xml!(self, e, horizontal_dpi, "horizontalDpi");
         set_string_from_xml!(self, e, vertical_dpi, "verticalDpi");
 
-        if let Some(r_id) = get_attribute(e, b"r:id") {
+        if let Some(r_id) = get_attribute(e, b"r:id")? {
             let attached_file = relationships
-                .unwrap()
-                .get_relationship_by_rid(&r_id)
+                .ok_or_else(|| XlsxError::MissingPart(format!("relationship {}", r_id)))?
+                .get_relationship_by_rid(&r_id)?
                 .get_raw_file();
             self.set_object_data(attached_file.get_file_data().clone());
         }
+        Ok(())
     }
 
     pub(crate) fn write_to(&self, writer: &mut Writer<Cursor<Vec<u8>>>, r_id: &mut usize) {
@@ -5,6 +5,7 @@ use super::PaneStateValues;
 use super::PaneValues;
 use crate::reader::driver::*;
 use crate::writer::driver::*;
+use crate::XlsxError;
 use quick_xml::events::BytesStart;
 use quick_xml::Reader;
 use quick_xml::Writer;
@@ -84,15 +85,16 @@ impl Pane {
         &mut self,
         _reader: &mut Reader<R>,
         e: &BytesStart,
-    ) {
+    ) -> Result<(), XlsxError> {
         set_string_from_xml!(self, e, horizontal_split, "xSplit");
         set_string_from_xml!(self, e, vertical_split, "ySplit");
         set_string_from_xml!(self, e, active_pane, "activePane");
         set_string_from_xml!(self, e, state, "state");
 
-        if let Some(v) = get_attribute(e, b"topLeftCell") {
+        if let Some(v) = get_attribute(e, b"topLeftCell")? {
             self.top_left_cell.set_coordinate(v);
         }
+        Ok(())
     }
 
     pub(crate) fn write_to(&self, writer: &mut Writer<Cursor<Vec<u8>>>) {
@@ -20,7 +20,7 @@ impl EnumTrait for PaneValues {
             Self::BottomLeft => "bottomLeft",
             Self::BottomRight => "bottomRight",
             Self::TopLeft => "topLeft",
-            Self::TopRight => "TopRight",
+            Self::TopRight => "topRight",
         }
     }
 }
@@ -33,7 +33,7 @@ impl FromStr for PaneValues {
             "bottomLeft" => Ok(Self::BottomLeft),
             "bottomRight" => Ok(Self::BottomRight),
             "topLeft" => Ok(Self::TopLeft),
-            "TopRight" => Ok(Self::TopRight),
+            "topRight" => Ok(Self::TopRight),
             _ => Err(()),
         }
     }
@@ -4,6 +4,7 @@ use super::EnumValue;
 use super::PatternValues;
 use crate::reader::driver::*;
 use crate::writer::driver::*;
+use crate::XlsxError;
 use md5::Digest;
 use quick_xml::events::{BytesStart, Event};
 use quick_xml::Reader;
@@ -124,11 +125,11 @@ impl PatternFill {
         reader: &mut Reader<R>,
         e: &BytesStart,
         empty_flag: bool,
-    ) {
+    ) -> Result<(), XlsxError> {
         set_string_from_xml!(self, e, pattern_type, "patternType");
 
         if empty_flag {
-            return;
+            return Ok(());
         }
 
         xml_read_loop!(
@@ -137,12 +138,12 @@ impl PatternFill {
                 match e.name().into_inner() {
                     b"fgColor" => {
                         let mut obj = Color::default();
-                        obj.set_attributes(reader, e, true);
+                        obj.set_attributes(reader, e, true)?;
                         self.set_foreground_color(obj);
                     }
                     b"bgColor" => {
                         let mut obj = Color::default();
-                        obj.set_attributes(reader, e, true);
+                        obj.set_attributes(reader, e, true)?;
                         self.set_background_color(obj);
                     }
                     _ => (),
@@ -150,10 +151,10 @@ impl PatternFill {
             },
             Event::End(ref e) => {
                 if e.name().into_inner() == b"patternFill" {
-                    return
+                    return Ok(())
                 }
             },
-            Event::Eof => panic!("Error: Could not find {} end element", "patternFill")
+            Event::Eof => return Err(end_element_error(reader, "patternFill"))
         );
     }
 
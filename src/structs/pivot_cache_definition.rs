@@ -1,6 +1,8 @@
 // pivotCacheDefinition
 use crate::helper::const_str::*;
 use crate::reader::driver::*;
+use crate::structs::drawing::chart_ex::RawElement;
+use crate::structs::BooleanValue;
 use crate::structs::ByteValue;
 use crate::structs::CacheFields;
 use crate::structs::CacheSource;
@@ -8,14 +10,20 @@ use crate::structs::DoubleValue;
 use crate::structs::StringValue;
 use crate::structs::UInt32Value;
 use crate::writer::driver::*;
+use crate::XlsxError;
 use quick_xml::events::{BytesStart, Event};
 use quick_xml::Reader;
 use quick_xml::Writer;
 use std::io::Cursor;
+use thin_vec::ThinVec;
+
+// children that are not modeled, kept as read.
+const RAW_CHILDREN: &[&str] = &["extLst"];
 
 #[derive(Clone, Default, Debug)]
 pub struct PivotCacheDefinition {
     id: StringValue,
+    refresh_on_load: BooleanValue,
     refreshed_by: StringValue,
     refreshed_date: DoubleValue,
     created_version: ByteValue,
@@ -24,6 +32,8 @@ pub struct PivotCacheDefinition {
     record_count: UInt32Value,
     cache_source: CacheSource,
     cache_fields: CacheFields,
+    uid: StringValue,
+    raw_children: ThinVec<RawElement>,
 }
 
 impl PivotCacheDefinition {
@@ -38,6 +48,17 @@ impl PivotCacheDefinition {
         self
     }
 
+    #[inline]
+    pub fn get_refresh_on_load(&self) -> &bool {
+        self.refresh_on_load.get_value()
+    }
+
+    #[inline]
+    pub fn set_refresh_on_load(&mut self, value: bool) -> &mut Self {
+        self.refresh_on_load.set_value(value);
+        self
+    }
+
     #[inline]
     pub fn get_refreshed_by(&self) -> &str {
         self.refreshed_by.get_value_str()
@@ -136,53 +157,68 @@ impl PivotCacheDefinition {
         self
     }
 
+    #[inline]
+    pub fn get_uid(&self) -> &str {
+        self.uid.get_value_str()
+    }
+
+    #[inline]
+    pub fn set_uid<S: Into<String>>(&mut self, value: S) -> &mut Self {
+        self.uid.set_value(value);
+        self
+    }
+
     #[inline]
     pub(crate) fn set_attributes<R: std::io::BufRead>(
         &mut self,
         reader: &mut Reader<R>,
         e: &BytesStart,
-    ) {
+    ) -> Result<(), XlsxError> {
         set_string_from_xml!(self, e, id, "r:id");
+        set_string_from_xml!(self, e, refresh_on_load, "refreshOnLoad");
         set_string_from_xml!(self, e, refreshed_by, "refreshedBy");
         set_string_from_xml!(self, e, refreshed_date, "refreshedDate");
         set_string_from_xml!(self, e, created_version, "createdVersion");
         set_string_from_xml!(self, e, refreshed_version, "refreshedVersion");
         set_string_from_xml!(self, e, min_refreshable_version, "minRefreshableVersion");
         set_string_from_xml!(self, e, record_count, "recordCount");
+        set_string_from_xml!(self, e, uid, "xr:uid");
 
         xml_read_loop!(
             reader,
             Event::Empty(ref e) => {
                 if e.name().into_inner() == b"cacheSource" {
                     let mut obj = CacheSource::default();
-                    obj.set_attributes(reader, e, true);
+                    obj.set_attributes(reader, e, true)?;
                     self.set_cache_source(obj);
                 }
+                RawElement::read_into(&mut self.raw_children, RAW_CHILDREN, reader, e, true)?;
             },
             Event::Start(ref e) => {
                 if e.name().into_inner() == b"cacheSource" {
                     let mut obj = CacheSource::default();
-                    obj.set_attributes(reader, e, false);
+                    obj.set_attributes(reader, e, false)?;
                     self.set_cache_source(obj);
                 }
                 if e.name().into_inner() == b"cacheFields" {
                     let mut obj = CacheFields::default();
-                    obj.set_attributes(reader, e);
+                    obj.set_attributes(reader, e)?;
                     self.set_cache_fields(obj);
                 }
+                RawElement::read_into(&mut self.raw_children, RAW_CHILDREN, reader, e, false)?;
             },
             Event::End(ref e) => {
-                if e.name().into_inner() == b"pivotTableDefinition" {
-                    return
+                if e.name().into_inner() == b"pivotCacheDefinition" {
+                    return Ok(())
                 }
             },
-            Event::Eof => panic!("Error: Could not find {} end element", "pivotTableDefinition")
+            Event::Eof => return Err(end_element_error(reader, "pivotCacheDefinition"))
         );
     }
 
     #[inline]
     pub(crate) fn write_to(&self, writer: &mut Writer<Cursor<Vec<u8>>>) {
-        // pivotTableDefinition
+        // pivotCacheDefinition
         let mut attributes: Vec<(&str, &str)> = Vec::new();
         attributes.push(("xmlns", SHEET_MAIN_NS));
         attributes.push(("xmlns:r", REL_OFC_NS));
@@ -193,6 +229,9 @@ impl PivotCacheDefinition {
         if self.id.has_value() {
             attributes.push(("r:id", self.id.get_value_str()));
         }
+        if self.refresh_on_load.has_value() {
+            attributes.push(("refreshOnLoad", self.refresh_on_load.get_value_string()));
+        }
         if self.refreshed_by.has_value() {
             attributes.push(("refreshedBy", self.refreshed_by.get_value_str()));
         }
@@ -216,8 +255,11 @@ impl PivotCacheDefinition {
         if self.record_count.has_value() {
             attributes.push(("recordCount", &record_count_str));
         }
+        if self.uid.has_value() {
+            attributes.push(("xr:uid", self.uid.get_value_str()));
+        }
 
-        write_start_tag(writer, "pivotTableDefinition", attributes, false);
+        write_start_tag(writer, "pivotCacheDefinition", attributes, false);
 
         // cacheSource
         self.cache_source.write_to(writer);
@@ -225,6 +267,9 @@ impl PivotCacheDefinition {
         // cacheFields
         self.cache_fields.write_to(writer);
 
-        write_end_tag(writer, "pivotTableDefinition");
+        // extLst
+        RawElement::write_list(writer, &self.raw_children, RAW_CHILDREN);
+
+        write_end_tag(writer, "pivotCacheDefinition");
     }
 }
@@ -0,0 +1,63 @@
+// r
+use crate::reader::driver::*;
+use crate::structs::CacheItemValue;
+use crate::writer::driver::*;
+use crate::XlsxError;
+use quick_xml::events::{BytesStart, Event};
+use quick_xml::Reader;
+use quick_xml::Writer;
+use std::io::Cursor;
+use thin_vec::ThinVec;
+
+#[derive(Clone, Default, Debug)]
+pub struct PivotCacheRecord {
+    list: ThinVec<CacheItemValue>,
+}
+impl PivotCacheRecord {
+    /// Values of the record, one per cache field.
+    #[inline]
+    pub fn get_list(&self) -> &[CacheItemValue] {
+        &self.list
+    }
+
+    #[inline]
+    pub fn get_list_mut(&mut self) -> &mut ThinVec<CacheItemValue> {
+        &mut self.list
+    }
+
+    #[inline]
+    pub fn add_list_mut(&mut self, value: CacheItemValue) -> &mut Self {
+        self.list.push(value);
+        self
+    }
+
+    pub(crate) fn set_attributes<R: std::io::BufRead>(
+        &mut self,
+        reader: &mut Reader<R>,
+        _e: &BytesStart,
+    ) -> Result<(), XlsxError> {
+        xml_read_loop!(
+            reader,
+            Event::Empty(ref e) => {
+                if let Some(v) = CacheItemValue::set_attributes(e)? {
+                    self.add_list_mut(v);
+                }
+            },
+            Event::End(ref e) => {
+                if e.name().into_inner() == b"r" {
+                    return Ok(())
+                }
+            },
+            Event::Eof => return Err(end_element_error(reader, "r"))
+        );
+    }
+
+    pub(crate) fn write_to(&self, writer: &mut Writer<Cursor<Vec<u8>>>) {
+        // r
+        write_start_tag(writer, "r", vec![], false);
+        for value in &self.list {
+            value.write_to(writer);
+        }
+        write_end_tag(writer, "r");
+    }
+}
@@ -0,0 +1,85 @@
+// pivotCacheRecords
+use crate::helper::const_str::*;
+use crate::reader::driver::*;
+use crate::structs::PivotCacheRecord;
+use crate::writer::driver::*;
+use crate::XlsxError;
+use quick_xml::events::{BytesStart, Event};
+use quick_xml::Reader;
+use quick_xml::Writer;
+use std::io::Cursor;
+use thin_vec::ThinVec;
+
+#[derive(Clone, Default, Debug)]
+pub struct PivotCacheRecords {
+    list: ThinVec<PivotCacheRecord>,
+}
+impl PivotCacheRecords {
+    #[inline]
+    pub fn get_list(&self) -> &[PivotCacheRecord] {
+        &self.list
+    }
+
+    #[inline]
+    pub fn get_list_mut(&mut self) -> &mut ThinVec<PivotCacheRecord> {
+        &mut self.list
+    }
+
+    #[inline]
+    pub fn add_list_mut(&mut self, value: PivotCacheRecord) -> &mut Self {
+        self.list.push(value);
+        self
+    }
+
+    pub(crate) fn set_attributes<R: std::io::BufRead>(
+        &mut self,
+        reader: &mut Reader<R>,
+        _e: &BytesStart,
+    ) -> Result<(), XlsxError> {
+        xml_read_loop!(
+            reader,
+            Event::Start(ref e) => {
+                if e.name().into_inner() == b"r" {
+                    let mut obj = PivotCacheRecord::default();
+                    obj.set_attributes(reader, e)?;
+                    self.add_list_mut(obj);
+                }
+            },
+            Event::Empty(ref e) => {
+                if e.name().into_inner() == b"r" {
+                    self.add_list_mut(PivotCacheRecord::default());
+                }
+            },
+            Event::End(ref e) => {
+                if e.name().into_inner() == b"pivotCacheRecords" {
+                    return Ok(())
+                }
+            },
+            Event::Eof => return Err(end_element_error(reader, "pivotCacheRecords"))
+        );
+    }
+
+    pub(crate) fn write_to(&self, writer: &mut Writer<Cursor<Vec<u8>>>) {
+        // pivotCacheRecords
+        write_start_tag(
+            writer,
+            "pivotCacheRecords",
+            vec![
+                ("xmlns", SHEET_MAIN_NS),
+                ("xmlns:r", REL_OFC_NS),
+                ("xmlns:mc", MC_NS),
+                ("mc:Ignorable", "xr"),
+                ("xmlns:xr", SHEET_MS_REVISION_NS),
+                ("count", &self.list.len().to_string()),
+            ],
+            false,
+        );
+
+        // r
+        for record in &self.list {
+            record.write_to(writer);
+        }
+
+        write_end_tag(writer, "pivotCacheRecords");
+    }
+}
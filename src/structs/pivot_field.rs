@@ -1,11 +1,11 @@
 // pivotField
 use crate::reader::driver::*;
 use crate::structs::BooleanValue;
-use crate::structs::ByteValue;
-use crate::structs::Location;
-use crate::structs::StringValue;
-use crate::structs::UInt32Value;
+use crate::structs::EnumValue;
+use crate::structs::PivotItems;
+use crate::structs::PivotTableAxisValues;
 use crate::writer::driver::*;
+use crate::XlsxError;
 use quick_xml::events::{BytesStart, Event};
 use quick_xml::Reader;
 use quick_xml::Writer;
@@ -13,10 +13,26 @@ use std::io::Cursor;
 
 #[derive(Clone, Default, Debug)]
 pub struct PivotField {
+    axis: EnumValue<PivotTableAxisValues>,
     data_field: BooleanValue,
     show_all: BooleanValue,
+    items: PivotItems,
 }
 impl PivotField {
+    #[inline]
+    pub fn get_axis(&self) -> Option<&PivotTableAxisValues> {
+        if self.axis.has_value() {
+            return Some(self.axis.get_value());
+        }
+        None
+    }
+
+    #[inline]
+    pub fn set_axis(&mut self, value: PivotTableAxisValues) -> &mut Self {
+        self.axis.set_value(value);
+        self
+    }
+
     #[inline]
     pub fn get_data_field(&self) -> &bool {
         self.data_field.get_value()
@@ -40,26 +56,73 @@ impl PivotField {
     }
 
     #[inline]
+    pub fn get_items(&self) -> &PivotItems {
+        &self.items
+    }
+
+    #[inline]
+    pub fn get_items_mut(&mut self) -> &mut PivotItems {
+        &mut self.items
+    }
+
+    #[inline]
+    pub fn set_items(&mut self, value: PivotItems) -> &mut Self {
+        self.items = value;
+        self
+    }
+
     pub(crate) fn set_attributes<R: std::io::BufRead>(
         &mut self,
-        _reader: &mut Reader<R>,
+        reader: &mut Reader<R>,
         e: &BytesStart,
-    ) {
+        empty_flg: bool,
+    ) -> Result<(), XlsxError> {
+        set_string_from_xml!(self, e, axis, "axis");
         set_string_from_xml!(self, e, data_field, "dataField");
         set_string_from_xml!(self, e, show_all, "showAll");
+
+        if empty_flg {
+            return Ok(());
+        }
+
+        xml_read_loop!(
+            reader,
+            Event::Start(ref e) => {
+                if e.name().into_inner() == b"items" {
+                    let mut obj = PivotItems::default();
+                    obj.set_attributes(reader, e)?;
+                    self.set_items(obj);
+                }
+            },
+            Event::End(ref e) => {
+                if e.name().into_inner() == b"pivotField" {
+                    return Ok(())
+                }
+            },
+            Event::Eof => return Err(end_element_error(reader, "pivotField"))
+        );
     }
 
-    #[inline]
     pub(crate) fn write_to(&self, writer: &mut Writer<Cursor<Vec<u8>>>) {
         // pivotField
-        write_start_tag(
-            writer,
-            "pivotField",
-            vec![
-                ("dataField", self.data_field.get_value_string()),
-                ("showAll", self.show_all.get_value_string()),
-            ],
-            true,
-        );
+        let empty_flg = self.items.get_list().is_empty();
+        let mut attributes: Vec<(&str, &str)> = Vec::new();
+        if self.axis.has_value() {
+            attributes.push(("axis", self.axis.get_value_string()));
+        }
+        if self.data_field.has_value() {
+            attributes.push(("dataField", self.data_field.get_value_string()));
+        }
+        if self.show_all.has_value() {
+            attributes.push(("showAll", self.show_all.get_value_string()));
+        }
+        write_start_tag(writer, "pivotField", attributes, empty_flg);
+
+        if !empty_flg {
+            // items
+            self.items.write_to(writer);
+
+            write_end_tag(writer, "pivotField");
+        }
     }
 }
@@ -6,6 +6,7 @@ use crate::structs::PivotField;
 use crate::structs::StringValue;
 use crate::structs::UInt32Value;
 use crate::writer::driver::*;
+use crate::XlsxError;
 use quick_xml::events::{BytesStart, Event};
 use quick_xml::Reader;
 use quick_xml::Writer;
@@ -38,22 +39,29 @@ impl PivotFields {
         &mut self,
         reader: &mut Reader<R>,
         e: &BytesStart,
-    ) {
+    ) -> Result<(), XlsxError> {
         xml_read_loop!(
             reader,
             Event::Empty(ref e) => {
                 if e.name().into_inner() == b"pivotField" {
                     let mut obj = PivotField::default();
-                    obj.set_attributes(reader, e);
+                    obj.set_attributes(reader, e, true)?;
+                    self.add_list_mut(obj);
+                }
+            },
+            Event::Start(ref e) => {
+                if e.name().into_inner() == b"pivotField" {
+                    let mut obj = PivotField::default();
+                    obj.set_attributes(reader, e, false)?;
                     self.add_list_mut(obj);
                 }
             },
             Event::End(ref e) => {
                 if e.name().into_inner() == b"pivotFields" {
-                    return
+                    return Ok(())
                 }
             },
-            Event::Eof => panic!("Error: Could not find {} end element", "pivotFields")
+            Event::Eof => return Err(end_element_error(reader, "pivotFields"))
         );
     }
 
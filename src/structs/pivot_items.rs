@@ -0,0 +1,72 @@
+// items
+use crate::reader::driver::*;
+use crate::structs::PivotItem;
+use crate::writer::driver::*;
+use crate::XlsxError;
+use quick_xml::events::{BytesStart, Event};
+use quick_xml::Reader;
+use quick_xml::Writer;
+use std::io::Cursor;
+use thin_vec::ThinVec;
+
+#[derive(Clone, Default, Debug)]
+pub struct PivotItems {
+    list: ThinVec<PivotItem>,
+}
+impl PivotItems {
+    #[inline]
+    pub fn get_list(&self) -> &[PivotItem] {
+        &self.list
+    }
+
+    #[inline]
+    pub fn get_list_mut(&mut self) -> &mut ThinVec<PivotItem> {
+        &mut self.list
+    }
+
+    #[inline]
+    pub fn add_list_mut(&mut self, value: PivotItem) -> &mut Self {
+        self.list.push(value);
+        self
+    }
+
+    pub(crate) fn set_attributes<R: std::io::BufRead>(
+        &mut self,
+        reader: &mut Reader<R>,
+        _e: &BytesStart,
+    ) -> Result<(), XlsxError> {
+        xml_read_loop!(
+            reader,
+            Event::Empty(ref e) => {
+                if e.name().into_inner() == b"item" {
+                    let mut obj = PivotItem::default();
+                    obj.set_attributes(reader, e)?;
+                    self.add_list_mut(obj);
+                }
+            },
+            Event::End(ref e) => {
+                if e.name().into_inner() == b"items" {
+                    return Ok(())
+                }
+            },
+            Event::Eof => return Err(end_element_error(reader, "items"))
+        );
+    }
+
+    pub(crate) fn write_to(&self, writer: &mut Writer<Cursor<Vec<u8>>>) {
+        // items
+        write_start_tag(
+            writer,
+            "items",
+            vec![("count", &self.list.len().to_string())],
+            false,
+        );
+
+        // item
+        for item in &self.list {
+            item.write_to(writer);
+        }
+
+        write_end_tag(writer, "items");
+    }
+}
@@ -1,20 +1,110 @@
-use super::SheetView;
-use crate::reader::driver::*;
+use crate::helper::coordinate::*;
+use crate::helper::range::*;
+use crate::structs::Address;
+use crate::structs::CacheField;
+use crate::structs::CacheItemValue;
+use crate::structs::CellRawValue;
+use crate::structs::DataConsolidateFunctionValues;
+use crate::structs::DataField;
+use crate::structs::Field;
+use crate::structs::ItemValues;
+use crate::structs::MemberPropertyIndex;
+use crate::structs::PageField;
 use crate::structs::PivotCacheDefinition;
+use crate::structs::PivotCacheRecord;
+use crate::structs::PivotCacheRecords;
+use crate::structs::PivotField;
+use crate::structs::PivotItem;
+use crate::structs::PivotItems;
+use crate::structs::PivotTableAxisValues;
 use crate::structs::PivotTableDefinition;
-use crate::writer::driver::*;
-use quick_xml::events::{BytesStart, Event};
-use quick_xml::Reader;
-use quick_xml::Writer;
-use std::io::Cursor;
+use crate::structs::RowItem;
+use crate::structs::SharedItems;
+use crate::structs::SourceValues;
+use crate::structs::Spreadsheet;
+use crate::structs::Table;
+use crate::structs::Worksheet;
+use crate::structs::WorksheetSource;
+use crate::XlsxError;
+use std::collections::BTreeSet;
+use std::collections::HashMap;
+use thin_vec::ThinVec;
 
 #[derive(Clone, Default, Debug)]
 pub struct PivotTable {
     pivot_table_definition: PivotTableDefinition,
     pivot_cache_definition: PivotCacheDefinition,
+    pivot_cache_records: PivotCacheRecords,
+}
+
+/// One row or column of the rendered pivot table.
+#[derive(Clone, Default, Debug)]
+struct PivotLine {
+    item_type: Option<ItemValues>,
+    path: Vec<u32>,
+    data_index: usize,
 }
 
 impl PivotTable {
+    /// Create a pivot table over a range of a worksheet.
+    /// # Arguments
+    /// * `name` - Name of the pivot table.
+    /// * `worksheet` - Worksheet holding the source data.
+    /// * `range` - Source range including the header row. ex) `"A1:D20"`
+    /// * `location` - Top left cell of the report. ex) `"F3"`
+    /// # Examples
+    /// ```
+    /// use umya_spreadsheet::*;
+    /// let mut book = new_file();
+    /// let sheet = book.get_sheet_by_name_mut("Sheet1").unwrap();
+    /// sheet.get_cell_mut("A1").set_value("Region");
+    /// sheet.get_cell_mut("B1").set_value("Sales");
+    /// sheet.get_cell_mut("A2").set_value("East");
+    /// sheet.get_cell_mut("B2").set_value_number(10);
+    /// sheet.get_cell_mut("A3").set_value("West");
+    /// sheet.get_cell_mut("B3").set_value_number(20);
+    ///
+    /// let mut pivot_table = PivotTable::new("PivotTable1", sheet, "A1:B3", "D3");
+    /// pivot_table.add_row_field("Region").unwrap();
+    /// pivot_table
+    ///     .add_data_field("Sales", DataConsolidateFunctionValues::Sum)
+    ///     .unwrap();
+    /// sheet.add_pivot_table(pivot_table);
+    /// assert_eq!(sheet.get_value("E6"), "30");
+    /// ```
+    pub fn new(name: &str, worksheet: &Worksheet, range: &str, location: &str) -> Self {
+        let mut address = Address::default();
+        address.set_address(range);
+        address.set_sheet_name(worksheet.get_name());
+
+        let mut worksheet_source = WorksheetSource::default();
+        worksheet_source.set_address(address);
+
+        let mut pivot_table = PivotTable::default();
+        pivot_table.init(name, worksheet, worksheet_source, location);
+        pivot_table
+    }
+
+    /// Create a pivot table over a table of a worksheet.
+    /// # Arguments
+    /// * `name` - Name of the pivot table.
+    /// * `worksheet` - Worksheet holding the table.
+    /// * `table` - Source table.
+    /// * `location` - Top left cell of the report. ex) `"F3"`
+    pub fn new_with_table(
+        name: &str,
+        worksheet: &Worksheet,
+        table: &Table,
+        location: &str,
+    ) -> Self {
+        let (from, to) = table.get_area();
+        let span = (*from.get_col_num(), *to.get_col_num());
+        let worksheet_source = Self::make_table_source(worksheet, table, span);
+        let mut pivot_table = PivotTable::default();
+        pivot_table.init(name, worksheet, worksheet_source, location);
+        pivot_table
+    }
+
     #[inline]
     pub fn get_pivot_table_definition(&self) -> &PivotTableDefinition {
         &self.pivot_table_definition
@@ -46,4 +136,1089 @@ impl PivotTable {
         self.pivot_cache_definition = value;
         self
     }
+
+    #[inline]
+    pub fn get_pivot_cache_records(&self) -> &PivotCacheRecords {
+        &self.pivot_cache_records
+    }
+
+    #[inline]
+    pub fn get_pivot_cache_records_mut(&mut self) -> &mut PivotCacheRecords {
+        &mut self.pivot_cache_records
+    }
+
+    #[inline]
+    pub fn set_pivot_cache_records(&mut self, value: PivotCacheRecords) -> &mut Self {
+        self.pivot_cache_records = value;
+        self
+    }
+
+    /// Add a field of the source to the rows of the pivot table.
+    /// A field that is already on rows, columns or filters is left as is.
+    pub fn add_row_field(&mut self, field_name: &str) -> Result<&mut Self, XlsxError> {
+        let index = self.find_cache_field(field_name)?;
+        if self.set_axis_field(index, PivotTableAxisValues::AxisRow) {
+            let mut field = Field::default();
+            field.set_data_field(index as i32);
+            self.pivot_table_definition
+                .get_row_fields_mut()
+                .add_list_mut(field);
+            self.update_layout();
+        }
+        Ok(self)
+    }
+
+    /// Add a field of the source to the columns of the pivot table.
+    /// A field that is already on rows, columns or filters is left as is.
+    pub fn add_column_field(&mut self, field_name: &str) -> Result<&mut Self, XlsxError> {
+        let index = self.find_cache_field(field_name)?;
+        if self.set_axis_field(index, PivotTableAxisValues::AxisColumn) {
+            let mut field = Field::default();
+            field.set_data_field(index as i32);
+            self.pivot_table_definition
+                .get_column_fields_mut()
+                .add_list_mut(field);
+            self.update_layout();
+        }
+        Ok(self)
+    }
+
+    /// Add a field of the source to the filters (page fields) of the pivot table.
+    /// The filters are written above the report and show all items.
+    /// A field that is already on rows, columns or filters is left as is.
+    pub fn add_page_field(&mut self, field_name: &str) -> Result<&mut Self, XlsxError> {
+        let index = self.find_cache_field(field_name)?;
+        if self.set_axis_field(index, PivotTableAxisValues::AxisPage) {
+            let mut page_field = PageField::default();
+            page_field.set_field(index as i32);
+            page_field.set_hierarchy(-1);
+            self.pivot_table_definition
+                .get_page_fields_mut()
+                .add_list_mut(page_field);
+            self.update_layout();
+        }
+        Ok(self)
+    }
+
+    /// Add a field of the source to the values of the pivot table.
+    /// # Arguments
+    /// * `field_name` - Name of the source field.
+    /// * `function` - Aggregation of the values, ex) `DataConsolidateFunctionValues::Sum`
+    pub fn add_data_field(
+        &mut self,
+        field_name: &str,
+        function: DataConsolidateFunctionValues,
+    ) -> Result<&mut Self, XlsxError> {
+        let index = self.find_cache_field(field_name)?;
+
+        let base_name = format!("{} of {}", function.get_caption(), field_name);
+        let mut name = base_name.clone();
+        let mut no = 1;
+        while self
+            .pivot_table_definition
+            .get_data_fields()
+            .get_list()
+            .iter()
+            .any(|v| v.get_name() == name)
+        {
+            no += 1;
+            name = format!("{}{}", base_name, no);
+        }
+
+        let mut data_field = DataField::default();
+        data_field.set_name(name);
+        data_field.set_fie_id(index as u32);
+        if function != DataConsolidateFunctionValues::Sum {
+            data_field.set_subtotal(function);
+        }
+        data_field.set_base_fie_id(0);
+        data_field.set_base_item(0);
+        self.pivot_table_definition
+            .get_data_fields_mut()
+            .add_list_mut(data_field);
+
+        self.pivot_table_definition
+            .get_pivot_fields_mut()
+            .get_list_mut()[index]
+            .set_data_field(true);
+        self.update_layout();
+        Ok(self)
+    }
+
+    /// Rebuild the cache and the layout from the current data of the source.
+    /// Fields are matched to the source by name, so columns may be moved or added.
+    /// The report in the worksheet is rewritten by `Spreadsheet::refresh_pivot_tables`,
+    /// or by adding the refreshed pivot table to a worksheet.
+    /// # Arguments
+    /// * `spreadsheet` - Spreadsheet holding the source data.
+    /// # Return value
+    /// * `Result` - Err if the source sheet or a field in use no longer exists. The pivot table is left as is then.
+    pub fn refresh(&mut self, spreadsheet: &Spreadsheet) -> Result<&mut Self, XlsxError> {
+        let worksheet_source = match self
+            .pivot_cache_definition
+            .get_cache_source()
+            .get_worksheet_source()
+        {
+            Some(v) => v,
+            None => {
+                return Err(XlsxError::UnsupportedPivotSource(format!(
+                    "'{}' has no worksheet source",
+                    self.pivot_table_definition.get_name()
+                )))
+            }
+        };
+
+        // a table source follows the current area of the table.
+        let mut table_source = None;
+        if !worksheet_source.get_name().is_empty() {
+            let (table_name, columns) = split_table_reference(worksheet_source.get_name());
+            for worksheet in spreadsheet.get_sheet_collection_no_check() {
+                if !worksheet.is_deserialized() {
+                    continue;
+                }
+                if let Some(table) = worksheet
+                    .get_tables()
+                    .iter()
+                    .find(|v| v.get_name() == table_name)
+                {
+                    let span = Self::get_table_column_span(table, &columns)?;
+                    let mut source = Self::make_table_source(worksheet, table, span);
+                    source.set_name(worksheet_source.get_name());
+                    table_source = Some((worksheet, source));
+                    break;
+                }
+            }
+        }
+        let (worksheet, worksheet_source) = match table_source {
+            Some(v) => v,
+            None => {
+                let sheet_name = worksheet_source.get_address().get_sheet_name();
+                let worksheet = spreadsheet
+                    .get_sheet_collection_no_check()
+                    .iter()
+                    .find(|v| v.get_name() == sheet_name && v.is_deserialized())
+                    .ok_or_else(|| XlsxError::SheetNotFound(sheet_name.to_string()))?;
+                (worksheet, worksheet_source.clone())
+            }
+        };
+
+        let definition = &self.pivot_table_definition;
+        let location = definition
+            .get_location()
+            .get_reference()
+            .split(':')
+            .next()
+            .unwrap_or_default()
+            .to_string();
+        let mut pivot_table = PivotTable::default();
+        pivot_table.init(
+            definition.get_name(),
+            worksheet,
+            worksheet_source,
+            &location,
+        );
+        pivot_table
+            .pivot_table_definition
+            .set_pivot_table_style(definition.get_pivot_table_style().clone());
+
+        for index in self.get_row_field_list() {
+            pivot_table.add_row_field(self.get_cache_field_name(index))?;
+        }
+        for index in self.get_column_field_list() {
+            pivot_table.add_column_field(self.get_cache_field_name(index))?;
+        }
+        for page_field in definition.get_page_fields().get_list() {
+            pivot_table
+                .add_page_field(self.get_cache_field_name(*page_field.get_field() as usize))?;
+        }
+        for data_field in definition.get_data_fields().get_list() {
+            pivot_table.add_data_field(
+                self.get_cache_field_name(*data_field.get_fie_id() as usize),
+                data_field.get_subtotal().clone(),
+            )?;
+            if let Some(v) = pivot_table
+                .pivot_table_definition
+                .get_data_fields_mut()
+                .get_list_mut()
+                .last_mut()
+            {
+                v.set_name(data_field.get_name());
+            }
+        }
+
+        *self = pivot_table;
+        Ok(self)
+    }
+
+    fn make_table_source(
+        worksheet: &Worksheet,
+        table: &Table,
+        (col_start, col_end): (u32, u32),
+    ) -> WorksheetSource {
+        let (from, to) = table.get_area();
+        let mut address = Address::default();
+        address.get_range_mut().set_range(format!(
+            "{}:{}",
+            coordinate_from_index(&col_start, from.get_row_num()),
+            coordinate_from_index(&col_end, to.get_row_num())
+        ));
+        address.set_sheet_name(worksheet.get_name());
+
+        let mut worksheet_source = WorksheetSource::default();
+        worksheet_source.set_address(address);
+        worksheet_source.set_name(table.get_name());
+        worksheet_source
+    }
+
+    /// First and last column of the table columns named in a structured reference.
+    fn get_table_column_span(table: &Table, columns: &[&str]) -> Result<(u32, u32), XlsxError> {
+        let (from, to) = table.get_area();
+        let (first, last) = match (columns.first(), columns.last()) {
+            (Some(first), Some(last)) => (first, last),
+            _ => return Ok((*from.get_col_num(), *to.get_col_num())),
+        };
+        let position = |name: &str| {
+            table
+                .get_columns()
+                .iter()
+                .position(|v| v.get_name() == name)
+                .map(|v| from.get_col_num() + v as u32)
+                .ok_or_else(|| XlsxError::PivotFieldNotFound(name.to_string()))
+        };
+        Ok((position(first)?, position(last)?))
+    }
+
+    #[inline]
+    fn get_cache_field_name(&self, index: usize) -> &str {
+        self.pivot_cache_definition
+            .get_cache_fields()
+            .get_list()
+            .get(index)
+            .map_or("", |v| v.get_name())
+    }
+
+    fn init(
+        &mut self,
+        name: &str,
+        worksheet: &Worksheet,
+        worksheet_source: WorksheetSource,
+        location: &str,
+    ) {
+        let range = worksheet_source.get_address().get_range().get_range();
+        let (row_start, row_end, col_start, col_end) = get_start_and_end_point(&range);
+
+        // cache fields
+        let mut names: Vec<String> = Vec::new();
+        for col_num in col_start..=col_end {
+            let mut field_name = match worksheet.get_cell((col_num, row_start)) {
+                Some(cell) => cell.get_value().to_string(),
+                None => String::new(),
+            };
+            if field_name.is_empty() {
+                field_name = format!("Column{}", col_num - col_start + 1);
+            }
+            let base_name = field_name.clone();
+            let mut no = 1;
+            while names.contains(&field_name) {
+                no += 1;
+                field_name = format!("{}{}", base_name, no);
+            }
+            names.push(field_name);
+        }
+
+        // records
+        let mut records = PivotCacheRecords::default();
+        for row_num in (row_start + 1)..=row_end {
+            let mut record = PivotCacheRecord::default();
+            for col_num in col_start..=col_end {
+                let value = match worksheet.get_cell((col_num, row_num)) {
+                    Some(cell) => Self::get_cache_item_value(cell.get_raw_value()),
+                    None => CacheItemValue::Missing,
+                };
+                record.add_list_mut(value);
+            }
+            records.add_list_mut(record);
+        }
+        self.pivot_cache_records = records;
+
+        let cache_definition = &mut self.pivot_cache_definition;
+        cache_definition.set_refresh_on_load(true);
+        cache_definition.set_created_version(6);
+        cache_definition.set_refreshed_version(6);
+        cache_definition.set_min_refreshable_version(3);
+        cache_definition.set_record_count(self.pivot_cache_records.get_list().len() as u32);
+        let cache_source = cache_definition.get_cache_source_mut();
+        cache_source.set_type(SourceValues::Worksheet);
+        cache_source.set_worksheet_source_mut(worksheet_source);
+        for field_name in &names {
+            let mut cache_field = CacheField::default();
+            cache_field.set_name(field_name);
+            cache_field.set_number_format_id(0);
+            cache_definition
+                .get_cache_fields_mut()
+                .add_list_mut(cache_field);
+        }
+
+        // only text fields are shared up front, numeric fields are shared once they are put on an axis.
+        for index in 0..names.len() {
+            let is_shared = self.pivot_cache_records.get_list().iter().any(|record| {
+                !matches!(
+                    record.get_list()[index],
+                    CacheItemValue::Number(_) | CacheItemValue::Missing
+                )
+            });
+            self.update_shared_items(index, is_shared);
+        }
+
+        let definition = &mut self.pivot_table_definition;
+        definition.set_name(name);
+        definition.set_cache_id(0);
+        definition.set_apply_number_formats(false);
+        definition.set_apply_border_formats(false);
+        definition.set_apply_font_formats(false);
+        definition.set_apply_pattern_formats(false);
+        definition.set_apply_alignment_formats(false);
+        definition.set_apply_width_height_formats(true);
+        definition.set_data_caption("Values");
+        definition.set_updated_version(6);
+        definition.set_min_refreshable_version(3);
+        definition.set_use_auto_formatting(true);
+        definition.set_item_print_titles(true);
+        definition.set_created_version(6);
+        definition.set_indent(0);
+        definition.set_outline(true);
+        definition.set_outline_data(true);
+        definition.set_multiple_field_filters(false);
+        definition.get_location_mut().set_reference(location);
+        for _ in &names {
+            let mut pivot_field = PivotField::default();
+            pivot_field.set_show_all(false);
+            definition.get_pivot_fields_mut().add_list_mut(pivot_field);
+        }
+        let style = definition.get_pivot_table_style_mut();
+        style.set_name("PivotStyleLight16");
+        style.set_show_row_headers(true);
+        style.set_show_column_headers(true);
+        style.set_show_row_stripes(false);
+        style.set_show_column_stripes(false);
+        style.set_show_last_column(true);
+
+        self.update_layout();
+    }
+
+    fn get_cache_item_value(value: &CellRawValue) -> CacheItemValue {
+        match value {
+            CellRawValue::String(v) => CacheItemValue::String(v.clone()),
+            CellRawValue::RichText(v) => CacheItemValue::String(v.get_text().into()),
+            CellRawValue::Lazy(v) => match v.parse::<f64>() {
+                Ok(n) => CacheItemValue::Number(n),
+                Err(_) => CacheItemValue::String(v.clone()),
+            },
+            CellRawValue::Numeric(v) => CacheItemValue::Number(*v),
+            CellRawValue::Bool(v) => CacheItemValue::Boolean(*v),
+            CellRawValue::Error(v) => CacheItemValue::Error(v.to_string().into()),
+            CellRawValue::Empty => CacheItemValue::Missing,
+        }
+    }
+
+    fn find_cache_field(&self, field_name: &str) -> Result<usize, XlsxError> {
+        self.pivot_cache_definition
+            .get_cache_fields()
+            .get_list()
+            .iter()
+            .position(|v| v.get_name() == field_name)
+            .ok_or_else(|| XlsxError::PivotFieldNotFound(field_name.to_string()))
+    }
+
+    /// Value of a cache field in a record, with shared item indexes resolved.
+    fn get_record_value<'a>(
+        &'a self,
+        record: &'a PivotCacheRecord,
+        index: usize,
+    ) -> &'a CacheItemValue {
+        match &record.get_list()[index] {
+            CacheItemValue::Index(x) => {
+                let shared_items = self.pivot_cache_definition.get_cache_fields().get_list()[index]
+                    .get_shared_items()
+                    .get_list();
+                shared_items
+                    .get(*x as usize)
+                    .unwrap_or(&CacheItemValue::Missing)
+            }
+            value => value,
+        }
+    }
+
+    /// Rebuild the shared items of a cache field from the records.
+    /// With `is_shared` the distinct values are listed and the records refer to them by index.
+    fn update_shared_items(&mut self, index: usize, is_shared: bool) {
+        let values: Vec<CacheItemValue> = self
+            .pivot_cache_records
+            .get_list()
+            .iter()
+            .map(|record| self.get_record_value(record, index).clone())
+            .collect();
+
+        let mut shared_items = SharedItems::default();
+        if is_shared {
+            let mut item_map: HashMap<String, u32> = HashMap::new();
+            for (record, value) in self
+                .pivot_cache_records
+                .get_list_mut()
+                .iter_mut()
+                .zip(values.iter())
+            {
+                let key = value.get_hash_string();
+                let x = match item_map.get(&key) {
+                    Some(v) => *v,
+                    None => {
+                        let x = shared_items.get_list().len() as u32;
+                        shared_items.add_list_mut(value.clone());
+                        item_map.insert(key, x);
+                        x
+                    }
+                };
+                record.get_list_mut()[index] = CacheItemValue::Index(x);
+            }
+        }
+        shared_items.set_flags_from_values(values.iter());
+        self.pivot_cache_definition
+            .get_cache_fields_mut()
+            .get_list_mut()[index]
+            .set_shared_items(shared_items);
+    }
+
+    /// Put a field on an axis. Returns `false` if the field is already on an axis.
+    fn set_axis_field(&mut self, index: usize, axis: PivotTableAxisValues) -> bool {
+        if self.pivot_table_definition.get_pivot_fields().get_list()[index]
+            .get_axis()
+            .is_some()
+        {
+            return false;
+        }
+
+        let is_shared = !self.pivot_cache_definition.get_cache_fields().get_list()[index]
+            .get_shared_items()
+            .get_list()
+            .is_empty();
+        if !is_shared {
+            self.update_shared_items(index, true);
+        }
+
+        let shared_items = self.pivot_cache_definition.get_cache_fields().get_list()[index]
+            .get_shared_items()
+            .get_list();
+        let mut order: Vec<u32> = (0..shared_items.len() as u32).collect();
+        order.sort_by(|a, b| shared_items[*a as usize].cmp_item(&shared_items[*b as usize]));
+
+        let mut items = PivotItems::default();
+        for x in order {
+            let mut item = PivotItem::default();
+            item.set_index(x);
+            items.add_list_mut(item);
+        }
+        let mut item = PivotItem::default();
+        item.set_item_type(ItemValues::Default);
+        items.add_list_mut(item);
+
+        let pivot_field = &mut self
+            .pivot_table_definition
+            .get_pivot_fields_mut()
+            .get_list_mut()[index];
+        pivot_field.set_axis(axis);
+        pivot_field.set_items(items);
+        true
+    }
+
+    fn get_row_field_list(&self) -> Vec<usize> {
+        self.pivot_table_definition
+            .get_row_fields()
+            .get_list()
+            .iter()
+            .filter(|v| *v.get_data_field() >= 0)
+            .map(|v| *v.get_data_field() as usize)
+            .collect()
+    }
+
+    fn get_column_field_list(&self) -> Vec<usize> {
+        self.pivot_table_definition
+            .get_column_fields()
+            .get_list()
+            .iter()
+            .filter(|v| *v.get_data_field() >= 0)
+            .map(|v| *v.get_data_field() as usize)
+            .collect()
+    }
+
+    /// Shared item indexes of an axis field in the order of its pivot items.
+    fn get_item_order(&self, index: usize) -> Vec<u32> {
+        self.pivot_table_definition.get_pivot_fields().get_list()[index]
+            .get_items()
+            .get_list()
+            .iter()
+            .filter(|v| v.get_item_type().is_none())
+            .map(|v| *v.get_index())
+            .collect()
+    }
+
+    /// Position of every record on the given axis fields.
+    fn get_positions(&self, fields: &[usize]) -> Vec<Vec<u32>> {
+        let position_maps: Vec<HashMap<u32, u32>> = fields
+            .iter()
+            .map(|index| {
+                self.get_item_order(*index)
+                    .into_iter()
+                    .enumerate()
+                    .map(|(position, x)| (x, position as u32))
+                    .collect()
+            })
+            .collect();
+        self.pivot_cache_records
+            .get_list()
+            .iter()
+            .map(|record| {
+                fields
+                    .iter()
+                    .zip(position_maps.iter())
+                    .map(|(index, map)| match &record.get_list()[*index] {
+                        CacheItemValue::Index(x) => *map.get(x).unwrap_or(&0),
+                        _ => 0,
+                    })
+                    .collect()
+            })
+            .collect()
+    }
+
+    fn get_row_lines(&self, positions: &[Vec<u32>]) -> Vec<PivotLine> {
+        if self.get_row_field_list().is_empty() {
+            return vec![PivotLine::default()];
+        }
+        let leaves: BTreeSet<&Vec<u32>> = positions.iter().collect();
+        let mut lines: Vec<PivotLine> = Vec::new();
+        let mut prev: &[u32] = &[];
+        for leaf in leaves {
+            for depth in 0..leaf.len() {
+                if prev.get(..=depth) != Some(&leaf[..=depth]) {
+                    lines.push(PivotLine {
+                        item_type: None,
+                        path: leaf[..=depth].to_vec(),
+                        data_index: 0,
+                    });
+                }
+            }
+            prev = leaf;
+        }
+        lines.push(PivotLine {
+            item_type: Some(ItemValues::Grand),
+            path: Vec::new(),
+            data_index: 0,
+        });
+        lines
+    }
+
+    fn get_column_lines(&self, positions: &[Vec<u32>]) -> Vec<PivotLine> {
+        let data_count = self.get_values_count();
+        if self.get_column_field_list().is_empty() {
+            return (0..data_count)
+                .map(|data_index| PivotLine {
+                    item_type: None,
+                    path: Vec::new(),
+                    data_index,
+                })
+                .collect();
+        }
+        let leaves: Vec<&Vec<u32>> = positions
+            .iter()
+            .collect::<BTreeSet<_>>()
+            .into_iter()
+            .collect();
+        let mut lines: Vec<PivotLine> = Vec::new();
+        for (i, leaf) in leaves.iter().enumerate() {
+            for data_index in 0..data_count {
+                lines.push(PivotLine {
+                    item_type: None,
+                    path: leaf.to_vec(),
+                    data_index,
+                });
+            }
+            let next = leaves.get(i + 1);
+            for depth in (0..leaf.len() - 1).rev() {
+                if next.map_or(true, |v| v[..=depth] != leaf[..=depth]) {
+                    for data_index in 0..data_count {
+                        lines.push(PivotLine {
+                            item_type: Some(ItemValues::Default),
+                            path: leaf[..=depth].to_vec(),
+                            data_index,
+                        });
+                    }
+                }
+            }
+        }
+        for data_index in 0..data_count {
+            lines.push(PivotLine {
+                item_type: Some(ItemValues::Grand),
+                path: Vec::new(),
+                data_index,
+            });
+        }
+        lines
+    }
+
+    /// Number of value columns under each column item: one per data field when there are several.
+    fn get_values_count(&self) -> usize {
+        self.pivot_table_definition
+            .get_data_fields()
+            .get_list()
+            .len()
+            .max(1)
+    }
+
+    #[inline]
+    fn has_values_field(&self) -> bool {
+        self.pivot_table_definition
+            .get_data_fields()
+            .get_list()
+            .len()
+            > 1
+    }
+
+    fn make_row_items(lines: &[PivotLine], has_values_field: bool) -> ThinVec<RowItem> {
+        let mut result: ThinVec<RowItem> = ThinVec::new();
+        let mut prev: Vec<u32> = Vec::new();
+        for line in lines {
+            let mut item = RowItem::default();
+            let x_list = match &line.item_type {
+                None => {
+                    let mut full_path = line.path.clone();
+                    if has_values_field {
+                        full_path.push(line.data_index as u32);
+                    }
+                    let repeated = prev
+                        .iter()
+                        .zip(full_path.iter())
+                        .take_while(|(a, b)| a == b)
+                        .count();
+                    if repeated > 0 {
+                        item.set_repeated_item_count(repeated as u32);
+                    }
+                    let x_list = full_path[repeated..].to_vec();
+                    prev = full_path;
+                    x_list
+                }
+                Some(item_type) => {
+                    item.set_item_type(item_type.clone());
+                    if line.path.is_empty() {
+                        vec![0]
+                    } else {
+                        line.path.clone()
+                    }
+                }
+            };
+            if has_values_field && line.data_index > 0 {
+                item.set_index(line.data_index as u32);
+            }
+            for x in x_list {
+                let mut member_property_index = MemberPropertyIndex::default();
+                member_property_index.set_val(x as i32);
+                item.add_member_property_index(member_property_index);
+            }
+            result.push(item);
+        }
+        result
+    }
+
+    /// Top left cell of the report, moved down when the filters above it do not fit.
+    fn get_report_origin(&self) -> (u32, u32) {
+        let reference = self
+            .pivot_table_definition
+            .get_location()
+            .get_reference()
+            .to_string();
+        let (row_num, _, col_num, _) = get_start_and_end_point(&reference);
+        let page_count = self
+            .pivot_table_definition
+            .get_page_fields()
+            .get_list()
+            .len() as u32;
+        let mut row_num = row_num.max(1);
+        if page_count > 0 {
+            row_num = row_num.max(page_count + 2);
+        }
+        (col_num.max(1), row_num)
+    }
+
+    /// Rebuild the row / column items and the location from the fields.
+    fn update_layout(&mut self) {
+        let row_fields = self.get_row_field_list();
+        let column_fields = self.get_column_field_list();
+        let has_values_field = self.has_values_field();
+        let data_count = self
+            .pivot_table_definition
+            .get_data_fields()
+            .get_list()
+            .len();
+
+        // the values pseudo field (-2) goes last on the columns.
+        let column_field_list = self
+            .pivot_table_definition
+            .get_column_fields_mut()
+            .get_list_mut();
+        column_field_list.retain(|v| *v.get_data_field() >= 0);
+        if has_values_field {
+            let mut field = Field::default();
+            field.set_data_field(-2);
+            column_field_list.push(field);
+        }
+
+        let row_lines = self.get_row_lines(&self.get_positions(&row_fields));
+        let column_lines = self.get_column_lines(&self.get_positions(&column_fields));
+        let row_items = Self::make_row_items(&row_lines, false);
+        let column_items = Self::make_row_items(&column_lines, has_values_field);
+        *self
+            .pivot_table_definition
+            .get_row_items_mut()
+            .get_list_mut() = row_items;
+        *self
+            .pivot_table_definition
+            .get_column_items_mut()
+            .get_list_mut() = column_items;
+
+        let (col_num, row_num) = self.get_report_origin();
+        let label_cols: u32 = if row_fields.is_empty() && column_fields.is_empty() && data_count > 0
+        {
+            0
+        } else {
+            1
+        };
+        let data_cols = if data_count == 0 {
+            0
+        } else {
+            column_lines.len() as u32
+        };
+        let header_rows = self.get_header_row_count();
+        let width = (label_cols + data_cols).max(1);
+        let height = header_rows + row_lines.len() as u32;
+
+        let page_count = self
+            .pivot_table_definition
+            .get_page_fields()
+            .get_list()
+            .len() as u32;
+        let location = self.pivot_table_definition.get_location_mut();
+        location.set_reference(format!(
+            "{}:{}",
+            coordinate_from_index(&col_num, &row_num),
+            coordinate_from_index(&(col_num + width - 1), &(row_num + height - 1))
+        ));
+        location.set_first_header_row(if column_fields.is_empty() && data_count > 1 {
+            0
+        } else {
+            1
+        });
+        location.set_first_data_row(header_rows);
+        location.set_first_data_col(label_cols);
+        if page_count > 0 {
+            location.set_row_page_count(page_count);
+            location.set_column_page_count(1);
+        }
+    }
+
+    fn get_header_row_count(&self) -> u32 {
+        let column_fields = self.get_column_field_list();
+        if column_fields.is_empty() {
+            return 1;
+        }
+        1 + column_fields.len() as u32 + self.has_values_field() as u32
+    }
+
+    /// Caption of the item at `position` of an axis field.
+    fn get_item_caption(&self, index: usize, position: u32) -> CacheItemValue {
+        let order = self.get_item_order(index);
+        let shared_items = self.pivot_cache_definition.get_cache_fields().get_list()[index]
+            .get_shared_items()
+            .get_list();
+        order
+            .get(position as usize)
+            .and_then(|x| shared_items.get(*x as usize))
+            .cloned()
+            .unwrap_or_default()
+    }
+
+    fn get_data_field_name(&self, data_index: usize) -> String {
+        self.pivot_table_definition
+            .get_data_fields()
+            .get_list()
+            .get(data_index)
+            .map(|v| v.get_name().to_string())
+            .unwrap_or_default()
+    }
+
+    fn is_line_match(line: &PivotLine, position: &[u32]) -> bool {
+        match &line.item_type {
+            Some(ItemValues::Grand) => true,
+            _ => position.starts_with(&line.path),
+        }
+    }
+
+    fn aggregate(
+        function: &DataConsolidateFunctionValues,
+        values: &[&CacheItemValue],
+    ) -> Option<f64> {
+        let numbers: Vec<f64> = values.iter().filter_map(|v| v.get_number()).collect();
+        let count = numbers.len() as f64;
+        let mean = numbers.iter().sum::<f64>() / count;
+        let deviation = numbers.iter().map(|v| (v - mean).powi(2)).sum::<f64>();
+        match function {
+            DataConsolidateFunctionValues::Count => {
+                let count = values.iter().filter(|v| !v.is_missing()).count();
+                (count > 0).then_some(count as f64)
+            }
+            DataConsolidateFunctionValues::CountNumbers => (count > 0.0).then_some(count),
+            _ if numbers.is_empty() => None,
+            DataConsolidateFunctionValues::Sum => Some(numbers.iter().sum()),
+            DataConsolidateFunctionValues::Average => Some(mean),
+            DataConsolidateFunctionValues::Maximum => numbers.iter().cloned().reduce(f64::max),
+            DataConsolidateFunctionValues::Minimum => numbers.iter().cloned().reduce(f64::min),
+            DataConsolidateFunctionValues::Product => Some(numbers.iter().product()),
+            DataConsolidateFunctionValues::StandardDeviation => {
+                (count > 1.0).then(|| (deviation / (count - 1.0)).sqrt())
+            }
+            DataConsolidateFunctionValues::StandardDeviationP => Some((deviation / count).sqrt()),
+            DataConsolidateFunctionValues::Variance => {
+                (count > 1.0).then(|| deviation / (count - 1.0))
+            }
+            DataConsolidateFunctionValues::VarianceP => Some(deviation / count),
+        }
+    }
+
+    fn set_caption_value(
+        worksheet: &mut Worksheet,
+        col_num: u32,
+        row_num: u32,
+        value: &CacheItemValue,
+    ) {
+        let cell = worksheet.get_cell_mut((col_num, row_num));
+        match value {
+            CacheItemValue::Number(v) => {
+                cell.set_value_number(*v);
+            }
+            _ => {
+                cell.set_value_string(value.get_caption());
+            }
+        }
+    }
+
+    /// Blank the cells of the report and of the filters above it.
+    pub(crate) fn clear_output(&self, worksheet: &mut Worksheet) {
+        let definition = &self.pivot_table_definition;
+        let (row_start, row_end, col_start, col_end) =
+            get_start_and_end_point(definition.get_location().get_reference());
+        let page_count = definition.get_page_fields().get_list().len() as u32;
+        let row_start = match page_count {
+            0 => row_start,
+            _ => row_start.saturating_sub(page_count + 1).max(1),
+        };
+        for row_num in row_start..=row_end {
+            for col_num in col_start..=col_end {
+                if worksheet.get_cell((col_num, row_num)).is_some() {
+                    worksheet.get_cell_mut((col_num, row_num)).set_blank();
+                }
+            }
+        }
+    }
+
+    /// Write the report of the pivot table into the cells of its location.
+    pub(crate) fn write_output(&self, worksheet: &mut Worksheet) {
+        if self
+            .pivot_cache_definition
+            .get_cache_fields()
+            .get_list()
+            .is_empty()
+        {
+            return;
+        }
+
+        let row_fields = self.get_row_field_list();
+        let column_fields = self.get_column_field_list();
+        let has_values_field = self.has_values_field();
+        let data_fields = self.pivot_table_definition.get_data_fields().get_list();
+        let row_positions = self.get_positions(&row_fields);
+        let column_positions = self.get_positions(&column_fields);
+        let row_lines = self.get_row_lines(&row_positions);
+        let column_lines = self.get_column_lines(&column_positions);
+
+        let location = self.pivot_table_definition.get_location();
+        let (row_num, _, col_num, _) = get_start_and_end_point(location.get_reference());
+        let label_cols = *location.get_first_data_col();
+        let header_rows = *location.get_first_data_row();
+
+        // filters
+        let page_fields = self.pivot_table_definition.get_page_fields().get_list();
+        let page_count = page_fields.len() as u32;
+        for (i, page_field) in page_fields.iter().enumerate() {
+            let page_row = row_num - page_count - 1 + i as u32;
+            let field_name = self.pivot_cache_definition.get_cache_fields().get_list()
+                [*page_field.get_field() as usize]
+                .get_name()
+                .to_string();
+            worksheet
+                .get_cell_mut((col_num, page_row))
+                .set_value_string(field_name);
+            worksheet
+                .get_cell_mut((col_num + 1, page_row))
+                .set_value_string("(All)");
+        }
+
+        // headers
+        if column_fields.is_empty() {
+            if !row_fields.is_empty() {
+                worksheet
+                    .get_cell_mut((col_num, row_num))
+                    .set_value_string("Row Labels");
+            }
+            if !data_fields.is_empty() {
+                for (i, line) in column_lines.iter().enumerate() {
+                    worksheet
+                        .get_cell_mut((col_num + label_cols + i as u32, row_num))
+                        .set_value_string(self.get_data_field_name(line.data_index));
+                }
+            }
+        } else {
+            if data_fields.len() == 1 {
+                worksheet
+                    .get_cell_mut((col_num, row_num))
+                    .set_value_string(self.get_data_field_name(0));
+            }
+            worksheet
+                .get_cell_mut((col_num + label_cols, row_num))
+                .set_value_string("Column Labels");
+            if !row_fields.is_empty() {
+                worksheet
+                    .get_cell_mut((col_num, row_num + header_rows - 1))
+                    .set_value_string("Row Labels");
+            }
+            let mut prev: Vec<u32> = Vec::new();
+            for (i, line) in column_lines.iter().enumerate() {
+                let line_col = col_num + label_cols + i as u32;
+                match &line.item_type {
+                    None => {
+                        let mut full_path = line.path.clone();
+                        if has_values_field {
+                            full_path.push(line.data_index as u32);
+                        }
+                        let repeated = prev
+                            .iter()
+                            .zip(full_path.iter())
+                            .take_while(|(a, b)| a == b)
+                            .count();
+                        for (depth, position) in full_path.iter().enumerate().skip(repeated) {
+                            let line_row = row_num + 1 + depth as u32;
+                            match column_fields.get(depth) {
+                                Some(index) => {
+                                    let caption = self.get_item_caption(*index, *position);
+                                    Self::set_caption_value(
+                                        worksheet, line_col, line_row, &caption,
+                                    );
+                                }
+                                None => {
+                                    worksheet
+                                        .get_cell_mut((line_col, line_row))
+                                        .set_value_string(
+                                            self.get_data_field_name(line.data_index),
+                                        );
+                                }
+                            }
+                        }
+                        prev = full_path;
+                    }
+                    Some(ItemValues::Grand) => {
+                        let caption = match has_values_field {
+                            true => format!("Total {}", self.get_data_field_name(line.data_index)),
+                            false => String::from("Grand Total"),
+                        };
+                        worksheet
+                            .get_cell_mut((line_col, row_num + 1))
+                            .set_value_string(caption);
+                    }
+                    Some(_) => {
+                        let depth = line.path.len() - 1;
+                        let caption = self
+                            .get_item_caption(column_fields[depth], line.path[depth])
+                            .get_caption();
+                        let caption = match has_values_field {
+                            true => {
+                                format!("{} {}", caption, self.get_data_field_name(line.data_index))
+                            }
+                            false => format!("{} Total", caption),
+                        };
+                        worksheet
+                            .get_cell_mut((line_col, row_num + 1 + depth as u32))
+                            .set_value_string(caption);
+                    }
+                }
+            }
+        }
+
+        // rows
+        let records = self.pivot_cache_records.get_list();
+        for (i, row_line) in row_lines.iter().enumerate() {
+            let line_row = row_num + header_rows + i as u32;
+            match &row_line.item_type {
+                Some(_) => {
+                    worksheet
+                        .get_cell_mut((col_num, line_row))
+                        .set_value_string("Grand Total");
+                }
+                None => {
+                    if let Some(position) = row_line.path.last() {
+                        let depth = row_line.path.len() - 1;
+                        let caption = self.get_item_caption(row_fields[depth], *position);
+                        Self::set_caption_value(worksheet, col_num, line_row, &caption);
+                    }
+                }
+            }
+            if data_fields.is_empty() {
+                continue;
+            }
+
+            let record_list: Vec<usize> = (0..records.len())
+                .filter(|v| Self::is_line_match(row_line, &row_positions[*v]))
+                .collect();
+            for (j, column_line) in column_lines.iter().enumerate() {
+                let data_field = &data_fields[column_line.data_index];
+                let index = *data_field.get_fie_id() as usize;
+                let values: Vec<&CacheItemValue> = record_list
+                    .iter()
+                    .filter(|v| Self::is_line_match(column_line, &column_positions[**v]))
+                    .map(|v| self.get_record_value(&records[*v], index))
+                    .collect();
+                if let Some(value) = Self::aggregate(data_field.get_subtotal(), &values) {
+                    worksheet
+                        .get_cell_mut((col_num + label_cols + j as u32, line_row))
+                        .set_value_number(value);
+                }
+            }
+        }
+    }
+}
+
+/// Split a structured reference such as `Table1[[#All],[Sales]:[Cost]]`
+/// into the table name and the column names, leaving out the `#` specifiers.
+fn split_table_reference(value: &str) -> (&str, Vec<&str>) {
+    let (name, spec) = match value.split_once('[') {
+        Some((name, spec)) => (name, spec.strip_suffix(']').unwrap_or(spec)),
+        None => return (value, Vec::new()),
+    };
+    let items: Vec<&str> = if spec.contains('[') {
+        spec.split('[')
+            .filter_map(|v| v.split_once(']').map(|(item, _)| item))
+            .collect()
+    } else {
+        vec![spec]
+    };
+    let columns = items
+        .into_iter()
+        .filter(|v| !v.is_empty() && !v.starts_with('#'))
+        .collect();
+    (name, columns)
 }
@@ -1,22 +1,30 @@
 // pivotTableDefinition
 use crate::helper::const_str::*;
 use crate::reader::driver::*;
+use crate::structs::drawing::chart_ex::RawElement;
 use crate::structs::BooleanValue;
 use crate::structs::ByteValue;
 use crate::structs::ColumnFields;
 use crate::structs::ColumnItems;
 use crate::structs::DataFields;
 use crate::structs::Location;
+use crate::structs::PageFields;
 use crate::structs::PivotFields;
 use crate::structs::PivotTableStyle;
+use crate::structs::RowFields;
 use crate::structs::RowItems;
 use crate::structs::StringValue;
 use crate::structs::UInt32Value;
 use crate::writer::driver::*;
+use crate::XlsxError;
 use quick_xml::events::{BytesStart, Event};
 use quick_xml::Reader;
 use quick_xml::Writer;
 use std::io::Cursor;
+use thin_vec::ThinVec;
+
+// children that are not modeled, kept as read.
+const RAW_CHILDREN: &[&str] = &["extLst"];
 
 #[derive(Clone, Default, Debug)]
 pub struct PivotTableDefinition {
@@ -41,11 +49,15 @@ pub struct PivotTableDefinition {
     created_version: ByteValue,
     location: Location,
     pivot_fields: PivotFields,
+    row_fields: RowFields,
     row_items: RowItems,
     column_fields: ColumnFields,
     column_items: ColumnItems,
+    page_fields: PageFields,
     data_fields: DataFields,
     pivot_table_style: PivotTableStyle,
+    uid: StringValue,
+    raw_children: ThinVec<RawElement>,
 }
 impl PivotTableDefinition {
     #[inline]
@@ -289,6 +301,22 @@ impl PivotTableDefinition {
         self
     }
 
+    #[inline]
+    pub fn get_row_fields(&self) -> &RowFields {
+        &self.row_fields
+    }
+
+    #[inline]
+    pub fn get_row_fields_mut(&mut self) -> &mut RowFields {
+        &mut self.row_fields
+    }
+
+    #[inline]
+    pub fn set_row_fields(&mut self, value: RowFields) -> &mut Self {
+        self.row_fields = value;
+        self
+    }
+
     #[inline]
     pub fn get_row_items(&self) -> &RowItems {
         &self.row_items
@@ -337,6 +365,22 @@ impl PivotTableDefinition {
         self
     }
 
+    #[inline]
+    pub fn get_page_fields(&self) -> &PageFields {
+        &self.page_fields
+    }
+
+    #[inline]
+    pub fn get_page_fields_mut(&mut self) -> &mut PageFields {
+        &mut self.page_fields
+    }
+
+    #[inline]
+    pub fn set_page_fields(&mut self, value: PageFields) -> &mut Self {
+        self.page_fields = value;
+        self
+    }
+
     #[inline]
     pub fn get_data_fields(&self) -> &DataFields {
         &self.data_fields
@@ -369,12 +413,24 @@ impl PivotTableDefinition {
         self
     }
 
+    #[inline]
+    pub fn get_uid(&self) -> &str {
+        self.uid.get_value_str()
+    }
+
+    #[inline]
+    pub fn set_uid<S: Into<String>>(&mut self, value: S) -> &mut Self {
+        self.uid.set_value(value);
+        self
+    }
+
     #[inline]
     pub(crate) fn set_attributes<R: std::io::BufRead>(
         &mut self,
         reader: &mut Reader<R>,
         e: &BytesStart,
-    ) {
+    ) -> Result<(), XlsxError> {
+        set_string_from_xml!(self, e, uid, "xr:uid");
         set_string_from_xml!(self, e, name, "name");
         set_string_from_xml!(self, e, cache_id, "cacheId");
         set_string_from_xml!(self, e, apply_number_formats, "applyNumberFormats");
@@ -404,48 +460,60 @@ impl PivotTableDefinition {
             Event::Empty(ref e) => {
                 if e.name().into_inner() == b"location" {
                     let mut obj = Location::default();
-                    obj.set_attributes(reader, e);
+                    obj.set_attributes(reader, e)?;
                     self.set_location(obj);
                 }
                 if e.name().into_inner() == b"pivotTableStyleInfo" {
                     let mut obj = PivotTableStyle::default();
-                    obj.set_attributes(reader, e);
+                    obj.set_attributes(reader, e)?;
                     self.set_pivot_table_style(obj);
                 }
+                RawElement::read_into(&mut self.raw_children, RAW_CHILDREN, reader, e, true)?;
             },
             Event::Start(ref e) => {
                 if e.name().into_inner() == b"pivotFields" {
                     let mut obj = PivotFields::default();
-                    obj.set_attributes(reader, e);
+                    obj.set_attributes(reader, e)?;
                     self.set_pivot_fields(obj);
                 }
+                if e.name().into_inner() == b"rowFields" {
+                    let mut obj = RowFields::default();
+                    obj.set_attributes(reader, e)?;
+                    self.set_row_fields(obj);
+                }
                 if e.name().into_inner() == b"rowItems" {
                     let mut obj = RowItems::default();
-                    obj.set_attributes(reader, e);
+                    obj.set_attributes(reader, e)?;
                     self.set_row_items(obj);
                 }
                 if e.name().into_inner() == b"colFields" {
                     let mut obj = ColumnFields::default();
-                    obj.set_attributes(reader, e);
+                    obj.set_attributes(reader, e)?;
                     self.set_column_fields(obj);
                 }
                 if e.name().into_inner() == b"colItems" {
                     let mut obj = ColumnItems::default();
-                    obj.set_attributes(reader, e);
+                    obj.set_attributes(reader, e)?;
                     self.set_column_items(obj);
                 }
+                if e.name().into_inner() == b"pageFields" {
+                    let mut obj = PageFields::default();
+                    obj.set_attributes(reader, e)?;
+                    self.set_page_fields(obj);
+                }
                 if e.name().into_inner() == b"dataFields" {
                     let mut obj = DataFields::default();
-                    obj.set_attributes(reader, e);
+                    obj.set_attributes(reader, e)?;
                     self.set_data_fields(obj);
                 }
+                RawElement::read_into(&mut self.raw_children, RAW_CHILDREN, reader, e, false)?;
             },
             Event::End(ref e) => {
                 if e.name().into_inner() == b"pivotTableDefinition" {
-                    return
+                    return Ok(())
                 }
             },
-            Event::Eof => panic!("Error: Could not find {} end element", "pivotTableDefinition")
+            Event::Eof => return Err(end_element_error(reader, "pivotTableDefinition"))
         );
     }
 
@@ -457,6 +525,9 @@ impl PivotTableDefinition {
         attributes.push(("xmlns:mc", MC_NS));
         attributes.push(("mc:Ignorable", "xr"));
         attributes.push(("xmlns:xr", SHEET_MS_REVISION_NS));
+        if self.uid.has_value() {
+            attributes.push(("xr:uid", self.uid.get_value_str()));
+        }
         if self.name.has_value() {
             attributes.push(("name", self.name.get_value_str()));
         }
@@ -548,21 +619,42 @@ impl PivotTableDefinition {
         // pivotFields
         self.pivot_fields.write_to(writer);
 
+        // rowFields
+        if !self.row_fields.get_list().is_empty() {
+            self.row_fields.write_to(writer);
+        }
+
         // rowItems
-        self.row_items.write_to(writer);
+        if !self.row_items.get_list().is_empty() {
+            self.row_items.write_to(writer);
+        }
 
         // colFields
-        self.column_fields.write_to(writer);
+        if !self.column_fields.get_list().is_empty() {
+            self.column_fields.write_to(writer);
+        }
 
         // colItems
-        self.column_items.write_to(writer);
+        if !self.column_items.get_list().is_empty() {
+            self.column_items.write_to(writer);
+        }
+
+        // pageFields
+        if !self.page_fields.get_list().is_empty() {
+            self.page_fields.write_to(writer);
+        }
 
         // dataFields
-        self.data_fields.write_to(writer);
+        if !self.data_fields.get_list().is_empty() {
+            self.data_fields.write_to(writer);
+        }
 
         // pivotTableStyleInfo
         self.pivot_table_style.write_to(writer);
 
+        // extLst
+        RawElement::write_list(writer, &self.raw_children, RAW_CHILDREN);
+
         write_end_tag(writer, "pivotTableDefinition");
     }
 }
@@ -6,6 +6,7 @@ use crate::structs::Location;
 use crate::structs::StringValue;
 use crate::structs::UInt32Value;
 use crate::writer::driver::*;
+use crate::XlsxError;
 use quick_xml::events::{BytesStart, Event};
 use quick_xml::Reader;
 use quick_xml::Writer;
@@ -27,7 +28,7 @@ impl PivotTableStyle {
     }
 
     #[inline]
-    pub(crate) fn set_name<S: Into<String>>(&mut self, value: S) -> &mut Self {
+    pub fn set_name<S: Into<String>>(&mut self, value: S) -> &mut Self {
         self.name.set_value(value);
         self
     }
@@ -92,30 +93,44 @@ impl PivotTableStyle {
         &mut self,
         _reader: &mut Reader<R>,
         e: &BytesStart,
-    ) {
+    ) -> Result<(), XlsxError> {
         set_string_from_xml!(self, e, name, "name");
         set_string_from_xml!(self, e, show_row_headers, "showRowHeaders");
         set_string_from_xml!(self, e, show_column_headers, "showColHeaders");
         set_string_from_xml!(self, e, show_row_stripes, "showRowStripes");
         set_string_from_xml!(self, e, show_column_stripes, "showColStripes");
         set_string_from_xml!(self, e, show_last_column, "showLastColumn");
+        Ok(())
     }
 
     #[inline]
     pub(crate) fn write_to(&self, writer: &mut Writer<Cursor<Vec<u8>>>) {
         // pivotTableStyleInfo
-        write_start_tag(
-            writer,
-            "pivotTableStyleInfo",
-            vec![
-                ("dataField", self.name.get_value_str()),
-                ("showAll", self.show_row_headers.get_value_string()),
-                ("showAll", self.show_column_headers.get_value_string()),
-                ("showAll", self.show_row_stripes.get_value_string()),
-                ("showAll", self.show_column_stripes.get_value_string()),
-                ("showAll", self.show_last_column.get_value_string()),
-            ],
-            true,
-        );
+        let mut attributes: Vec<(&str, &str)> = Vec::new();
+        if self.name.has_value() {
+            attributes.push(("name", self.name.get_value_str()));
+        }
+        if self.show_row_headers.has_value() {
+            attributes.push(("showRowHeaders", self.show_row_headers.get_value_string()));
+        }
+        if self.show_column_headers.has_value() {
+            attributes.push((
+                "showColHeaders",
+                self.show_column_headers.get_value_string(),
+            ));
+        }
+        if self.show_row_stripes.has_value() {
+            attributes.push(("showRowStripes", self.show_row_stripes.get_value_string()));
+        }
+        if self.show_column_stripes.has_value() {
+            attributes.push((
+                "showColStripes",
+                self.show_column_stripes.get_value_string(),
+            ));
+        }
+        if self.show_last_column.has_value() {
+            attributes.push(("showLastColumn", self.show_last_column.get_value_string()));
+        }
+        write_start_tag(writer, "pivotTableStyleInfo", attributes, true);
     }
 }
@@ -4,6 +4,7 @@ use crate::structs::custom_properties::Properties as CustomProperties;
 use crate::structs::StringValue;
 use crate::structs::Worksheet;
 use crate::writer::driver::*;
+use crate::XlsxError;
 use quick_xml::events::BytesStart;
 use quick_xml::events::Event;
 use quick_xml::Reader;
@@ -217,12 +218,12 @@ impl Properties {
         &mut self,
         reader: &mut Reader<R>,
         _e: &BytesStart,
-    ) {
+    ) -> Result<(), XlsxError> {
         let mut value: String = String::new();
         xml_read_loop!(
             reader,
             Event::Text(e) => {
-                value = e.unescape().unwrap().to_string();
+                value = e.unescape()?.to_string();
             },
             Event::End(ref e) => match e.name().into_inner() {
                 b"dc:title" => {self.set_title(std::mem::take(&mut value));},
@@ -240,7 +241,7 @@ impl Properties {
                 b"Company" => {self.set_company(std::mem::take(&mut value));},
                 _ => {}
             },
-            Event::Eof => return,
+            Event::Eof => return Ok(()),
         );
     }
 
@@ -248,7 +249,7 @@ impl Properties {
         &mut self,
         reader: &mut Reader<R>,
         _e: &BytesStart,
-    ) {
+    ) -> Result<(), XlsxError> {
         let mut value: String = String::new();
         xml_read_loop!(
             reader,
@@ -260,14 +261,14 @@ impl Properties {
                 }
             },
             Event::Text(e) => {
-                value = e.unescape().unwrap().to_string();
+                value = e.unescape()?.to_string();
             },
             Event::End(ref e) => match e.name().into_inner() {
                 b"Manager" => {self.set_manager(std::mem::take(&mut value));}
                 b"Company" => {self.set_company(std::mem::take(&mut value));}
                 _ =>{}
             },
-            Event::Eof => return,
+            Event::Eof => return Ok(()),
         );
     }
 
@@ -276,10 +277,11 @@ impl Properties {
         &mut self,
         reader: &mut Reader<R>,
         e: &BytesStart,
-    ) {
+    ) -> Result<(), XlsxError> {
         let mut obj = CustomProperties::default();
-        obj.set_attributes(reader, e);
+        obj.set_attributes(reader, e)?;
         self.set_custom_properties(obj);
+        Ok(())
     }
 
     pub(crate) fn write_to_core(&self, writer: &mut Writer<Cursor<Vec<u8>>>) {
@@ -2,6 +2,7 @@
 use super::BooleanValue;
 use crate::reader::driver::*;
 use crate::writer::driver::*;
+use crate::XlsxError;
 use md5::Digest;
 use quick_xml::events::BytesStart;
 use quick_xml::Reader;
@@ -52,9 +53,10 @@ impl Protection {
         &mut self,
         _reader: &mut Reader<R>,
         e: &BytesStart,
-    ) {
+    ) -> Result<(), XlsxError> {
         set_string_from_xml!(self, e, locked, "locked");
         set_string_from_xml!(self, e, hidden, "hidden");
+        Ok(())
     }
 
     pub(crate) fn write_to(&self, writer: &mut Writer<Cursor<Vec<u8>>>) {
@@ -71,14 +71,22 @@ impl RawFile {
         arv: &mut zip::read::ZipArchive<R>,
         base_path: &str,
         target: &str,
-    ) {
+    ) -> Result<(), XlsxError> {
         let path_str = join_paths(base_path, target);
-        let mut r = io::BufReader::new(arv.by_name(&path_str).unwrap());
+        let file = match arv.by_name(&path_str) {
+            Ok(v) => v,
+            Err(zip::result::ZipError::FileNotFound) => {
+                return Err(XlsxError::MissingPart(path_str))
+            }
+            Err(e) => return Err(e.into()),
+        };
+        let mut r = io::BufReader::new(file);
         let mut buf = Vec::new();
-        r.read_to_end(&mut buf).unwrap();
+        r.read_to_end(&mut buf)?;
 
         self.set_file_target(path_str);
         self.set_file_data(&buf);
+        Ok(())
     }
 
     pub(crate) fn write_to<W: io::Seek + io::Write>(
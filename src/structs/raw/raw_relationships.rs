@@ -47,11 +47,14 @@ impl RawRelationships {
         &mut self.relationship_list
     }
 
-    pub(crate) fn get_relationship_by_rid(&self, r_id: &str) -> &RawRelationship {
+    pub(crate) fn get_relationship_by_rid(
+        &self,
+        r_id: &str,
+    ) -> Result<&RawRelationship, XlsxError> {
         self.get_relationship_list()
             .iter()
             .find(|relationship| relationship.get_id() == r_id)
-            .expect(&format!("Not found relationship with ID: {}.", r_id))
+            .ok_or_else(|| XlsxError::MissingPart(format!("{}#{}", self.get_file_target(), r_id)))
     }
 
     #[inline]
@@ -65,19 +68,19 @@ impl RawRelationships {
         arv: &mut zip::read::ZipArchive<R>,
         base_path: &str,
         target: &str,
-    ) -> bool {
+    ) -> Result<bool, XlsxError> {
         let data = {
             let path_str = join_paths(base_path, target);
             let file_path = match arv.by_name(&path_str) {
                 Ok(v) => v,
                 Err(_) => {
-                    return false;
+                    return Ok(false);
                 }
             };
             self.set_file_target(path_str);
             let mut r = io::BufReader::new(file_path);
             let mut buf = Vec::new();
-            r.read_to_end(&mut buf).unwrap();
+            r.read_to_end(&mut buf)?;
             std::io::Cursor::new(buf)
         };
         let mut reader = Reader::from_reader(data);
@@ -88,14 +91,14 @@ impl RawRelationships {
             Event::Empty(ref e) => {
                 if e.name().into_inner() == b"Relationship" {
                     let mut obj = RawRelationship::default();
-                    obj.set_attributes(&mut reader, e, arv, base_path);
+                    obj.set_attributes(&mut reader, e, arv, base_path)?;
                     self.add_relationship_list(obj);
                 }
             },
             Event::Eof => break
         );
 
-        true
+        Ok(true)
     }
 
     pub(crate) fn write_to<W: io::Seek + io::Write>(
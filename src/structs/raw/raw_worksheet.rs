@@ -1,4 +1,5 @@
 use crate::helper::const_str::*;
+use crate::reader::driver::join_paths;
 use crate::structs::raw::RawFile;
 use crate::structs::raw::RawRelationships;
 use crate::structs::WriterManager;
@@ -53,25 +54,24 @@ impl RawWorksheet {
         })
     }
 
-    pub(crate) fn get_vml_drawing_relationships(&self) -> Option<&RawRelationships> {
-        self.get_relationships_list().iter().find(|&relationships| {
-            relationships
-                .get_file_target()
-                .starts_with(PKG_VML_DRAWING_RELS)
-        })
+    pub(crate) fn get_relationships_of(&self, file: &RawFile) -> Option<&RawRelationships> {
+        let target = join_paths(&file.get_path(), &file.make_rel_name());
+        self.get_relationships_list()
+            .iter()
+            .find(|&relationships| relationships.get_file_target() == target)
     }
 
     pub(crate) fn read<R: io::Read + io::Seek>(
         &mut self,
         arv: &mut zip::read::ZipArchive<R>,
         target: &str,
-    ) {
+    ) -> Result<(), XlsxError> {
         self.get_worksheet_file_mut()
-            .set_attributes(arv, "xl", target);
+            .set_attributes(arv, "xl", target)?;
 
         let base_path = self.get_worksheet_file().get_path();
         let target = self.get_worksheet_file().make_rel_name();
-        self.read_rawrelationships(arv, &base_path, &target);
+        self.read_rawrelationships(arv, &base_path, &target)
     }
 
     pub(crate) fn read_rawrelationships<R: io::Read + io::Seek>(
@@ -79,16 +79,17 @@ impl RawWorksheet {
         arv: &mut zip::read::ZipArchive<R>,
         base_path: &str,
         target: &str,
-    ) {
+    ) -> Result<(), XlsxError> {
         let mut obj = RawRelationships::default();
-        if obj.set_attributes(arv, base_path, target) {
+        if obj.set_attributes(arv, base_path, target)? {
             for relationship in obj.get_relationship_list() {
                 let rels_base_path = relationship.get_raw_file().get_path();
                 let rels_target = relationship.get_raw_file().make_rel_name();
-                self.read_rawrelationships(arv, &rels_base_path, &rels_target);
+                self.read_rawrelationships(arv, &rels_base_path, &rels_target)?;
             }
             self.set_relationships(obj);
         }
+        Ok(())
     }
 
     pub(crate) fn write<W: io::Seek + io::Write>(
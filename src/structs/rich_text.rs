@@ -1,6 +1,7 @@
 use super::TextElement;
 use crate::reader::driver::*;
 use crate::writer::driver::*;
+use crate::XlsxError;
 use md5::Digest;
 use quick_xml::events::{BytesStart, Event};
 use quick_xml::Reader;
@@ -68,22 +69,22 @@ impl RichText {
         &mut self,
         reader: &mut Reader<R>,
         _e: &BytesStart,
-    ) {
+    ) -> Result<(), XlsxError> {
         xml_read_loop!(
             reader,
             Event::Start(ref e) => {
                 if e.name().into_inner() == b"r" {
                     let mut obj = TextElement::default();
-                    obj.set_attributes(reader, e);
+                    obj.set_attributes(reader, e)?;
                     self.add_rich_text_elements(obj);
                 }
             },
             Event::End(ref e) => {
                 if e.name().into_inner() == b"text" {
-                    return
+                    return Ok(())
                 }
             },
-            Event::Eof => panic!("Error: Could not find {} end element", "text")
+            Event::Eof => return Err(end_element_error(reader, "text"))
         );
     }
 
@@ -1,15 +1,20 @@
 use super::BooleanValue;
+use super::ByteValue;
 use super::Cell;
 use super::Cells;
+use super::Columns;
 use super::DoubleValue;
+use super::MergeCells;
 use super::SharedStringTable;
 use super::Style;
 use super::Stylesheet;
 use super::UInt32Value;
+use crate::helper::font_metrics::*;
 use crate::helper::formula::*;
 use crate::reader::driver::*;
 use crate::traits::AdjustmentValue;
 use crate::writer::driver::*;
+use crate::XlsxError;
 use quick_xml::events::{BytesStart, Event};
 use quick_xml::Reader;
 use quick_xml::Writer;
@@ -24,7 +29,10 @@ pub struct Row {
     thick_bot: BooleanValue,
     custom_height: BooleanValue,
     hidden: BooleanValue,
+    outline_level: ByteValue,
+    collapsed: BooleanValue,
     style: Box<Style>,
+    auto_height: BooleanValue,
 }
 impl Default for Row {
     #[inline]
@@ -36,7 +44,10 @@ impl Default for Row {
             thick_bot: BooleanValue::default(),
             custom_height: BooleanValue::default(),
             hidden: BooleanValue::default(),
+            outline_level: ByteValue::default(),
+            collapsed: BooleanValue::default(),
             style: Box::new(Style::default()),
+            auto_height: BooleanValue::default(),
         }
     }
 }
@@ -108,6 +119,30 @@ impl Row {
         self
     }
 
+    /// Outline (group) level of the row, 0 to 7.
+    #[inline]
+    pub fn get_outline_level(&self) -> &u8 {
+        self.outline_level.get_value()
+    }
+
+    #[inline]
+    pub fn set_outline_level(&mut self, value: u8) -> &mut Self {
+        self.outline_level.set_value(value.min(7));
+        self
+    }
+
+    /// The group following this summary row is collapsed.
+    #[inline]
+    pub fn get_collapsed(&self) -> &bool {
+        self.collapsed.get_value()
+    }
+
+    #[inline]
+    pub fn set_collapsed(&mut self, value: bool) -> &mut Self {
+        self.collapsed.set_value(value);
+        self
+    }
+
     #[inline]
     pub fn get_style(&self) -> &Style {
         &self.style
@@ -124,6 +159,69 @@ impl Row {
         self
     }
 
+    /// The height is fitted to the cells of the row when the file is written.
+    #[inline]
+    pub fn get_auto_height(&self) -> &bool {
+        self.auto_height.get_value()
+    }
+
+    #[inline]
+    pub fn set_auto_height(&mut self, value: bool) -> &mut Self {
+        self.auto_height.set_value(value);
+        self
+    }
+
+    pub(crate) fn calculation_auto_height(
+        &mut self,
+        cells: &Cells,
+        columns: &Columns,
+        merge_cells: &MergeCells,
+        default_font: &TextFont,
+        default_column_width: f64,
+    ) -> &mut Self {
+        if !*self.get_auto_height() {
+            return self;
+        }
+
+        let digit_width = get_max_digit_width(default_font);
+        // font of the cells without a font of their own.
+        let row_font = default_font.with_font(self.get_style().get_font());
+        let column_width_of = |col_num: u32| {
+            columns
+                .get_column(&col_num)
+                .map_or(default_column_width, |v| *v.get_width())
+        };
+
+        let mut height_max = 0f64;
+        for cell in cells.get_collection_by_row(self.get_row_num()) {
+            let coordinate = cell.get_coordinate();
+            let col_num = *coordinate.get_col_num();
+            // wrapped text of a range merged across columns spans their widths,
+            // a range merged across rows is not fitted into one row.
+            let column_width = match merge_cells.get_merged_area(&col_num, coordinate.get_row_num())
+            {
+                Some((_, start_row, _, end_row)) if start_row != end_row => continue,
+                Some((start_col, _, _, _)) if start_col != col_num => continue,
+                Some((start_col, _, end_col, _)) => {
+                    (start_col..=end_col.min(16384)).map(column_width_of).sum()
+                }
+                None => column_width_of(col_num),
+            };
+            let wrap_width = get_text_area_width(column_width, digit_width);
+            let extent = cell.get_text_extent(&row_font, wrap_width);
+            height_max = height_max.max(extent.height);
+        }
+
+        // a single line of the row font if no cell is fitted.
+        if height_max == 0f64 {
+            height_max = get_line_height(&row_font.name, row_font.size);
+        }
+
+        self.height.set_value(pixel_to_point(height_max));
+        self.custom_height.set_value(false);
+        self
+    }
+
     #[inline]
     pub(crate) fn has_style(&self) -> bool {
         &*self.style != &Style::default()
@@ -138,26 +236,30 @@ impl Row {
         stylesheet: &Stylesheet,
         formula_shared_list: &mut HashMap<u32, (String, Vec<FormulaToken>)>,
         empty_flag: bool,
-    ) {
+    ) -> Result<(), XlsxError> {
         set_string_from_xml!(self, e, row_num, "r");
         set_string_from_xml!(self, e, height, "ht");
         set_string_from_xml!(self, e, thick_bot, "thickBot");
         set_string_from_xml!(self, e, custom_height, "customHeight");
         set_string_from_xml!(self, e, hidden, "hidden");
+        set_string_from_xml!(self, e, outline_level, "outlineLevel");
+        set_string_from_xml!(self, e, collapsed, "collapsed");
 
-        if let Some(v) = get_attribute(e, b"x14ac:dyDescent") {
+        if let Some(v) = get_attribute(e, b"x14ac:dyDescent")? {
             if !v.is_empty() {
                 self.descent.set_value_string(v);
             }
         }
 
-        if let Some(v) = get_attribute(e, b"s") {
-            let style = stylesheet.get_style(v.parse::<usize>().unwrap());
+        if let Some(v) = get_attribute(e, b"s")? {
+            let style = stylesheet
+                .get_style(parse_value("s", &v)?)
+                .ok_or_else(|| invalid_attribute_error("s", v))?;
             self.set_style(style);
         }
 
         if empty_flag {
-            return;
+            return Ok(());
         }
 
         xml_read_loop!(
@@ -165,23 +267,23 @@ impl Row {
             Event::Empty(ref e) => {
                 if e.name().into_inner() == b"c" {
                     let mut obj = Cell::default();
-                    obj.set_attributes(reader, e, shared_string_table, stylesheet, true, formula_shared_list);
+                    obj.set_attributes(reader, e, shared_string_table, stylesheet, true, formula_shared_list)?;
                     cells.set_fast(obj);
                 }
             },
             Event::Start(ref e) => {
                 if e.name().into_inner() == b"c" {
                     let mut obj = Cell::default();
-                    obj.set_attributes(reader, e, shared_string_table, stylesheet, false, formula_shared_list);
+                    obj.set_attributes(reader, e, shared_string_table, stylesheet, false, formula_shared_list)?;
                     cells.set_fast(obj);
                 }
             },
             Event::End(ref e) => {
                 if e.name().into_inner() == b"row" {
-                    return
+                    return Ok(())
                 }
             },
-            Event::Eof => panic!("Error: Could not find {} end element", "row")
+            Event::Eof => return Err(end_element_error(reader, "row"))
         );
     }
 
@@ -218,6 +320,13 @@ impl Row {
         if *self.hidden.get_value() {
             attributes.push(("hidden", self.hidden.get_value_string()));
         }
+        let outline_level = self.outline_level.get_value_string();
+        if self.outline_level.get_value() > &0 {
+            attributes.push(("outlineLevel", &outline_level));
+        }
+        if *self.collapsed.get_value() {
+            attributes.push(("collapsed", self.collapsed.get_value_string()));
+        }
         let descent = self.descent.get_value_string();
         if self.descent.has_value() {
             attributes.push(("x14ac:dyDescent", &descent));
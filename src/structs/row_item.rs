@@ -5,17 +5,19 @@ use crate::structs::ItemValues;
 use crate::structs::MemberPropertyIndex;
 use crate::structs::UInt32Value;
 use crate::writer::driver::*;
+use crate::XlsxError;
 use quick_xml::events::{BytesStart, Event};
 use quick_xml::Reader;
 use quick_xml::Writer;
 use std::io::Cursor;
+use thin_vec::ThinVec;
 
 #[derive(Clone, Default, Debug)]
 pub struct RowItem {
     index: UInt32Value,
     item_type: EnumValue<ItemValues>,
     repeated_item_count: UInt32Value,
-    member_property_index: Option<MemberPropertyIndex>,
+    member_property_index: ThinVec<MemberPropertyIndex>,
 }
 impl RowItem {
     #[inline]
@@ -53,17 +55,29 @@ impl RowItem {
 
     #[inline]
     pub fn get_member_property_index(&self) -> Option<&MemberPropertyIndex> {
-        self.member_property_index.as_ref()
+        self.member_property_index.first()
     }
 
     #[inline]
     pub fn get_member_property_index_mut(&mut self) -> Option<&mut MemberPropertyIndex> {
-        self.member_property_index.as_mut()
+        self.member_property_index.first_mut()
     }
 
     #[inline]
     pub fn set_member_property_index_color(&mut self, value: MemberPropertyIndex) -> &mut Self {
-        self.member_property_index = Some(value);
+        self.member_property_index = vec![value].into();
+        self
+    }
+
+    /// Item indexes (`x`) of the fields on the axis, one per field level written by this line.
+    #[inline]
+    pub fn get_member_property_index_list(&self) -> &[MemberPropertyIndex] {
+        &self.member_property_index
+    }
+
+    #[inline]
+    pub fn add_member_property_index(&mut self, value: MemberPropertyIndex) -> &mut Self {
+        self.member_property_index.push(value);
         self
     }
 
@@ -73,13 +87,13 @@ impl RowItem {
         reader: &mut Reader<R>,
         e: &BytesStart,
         empty_flg: bool,
-    ) {
+    ) -> Result<(), XlsxError> {
         set_string_from_xml!(self, e, index, "i");
         set_string_from_xml!(self, e, item_type, "t");
         set_string_from_xml!(self, e, repeated_item_count, "r");
 
         if empty_flg {
-            return;
+            return Ok(());
         }
 
         xml_read_loop!(
@@ -87,22 +101,22 @@ impl RowItem {
             Event::Empty(ref e) => {
                 if e.name().into_inner() == b"x" {
                     let mut obj = MemberPropertyIndex::default();
-                    obj.set_attributes(reader, e);
-                    self.set_member_property_index_color(obj);
+                    obj.set_attributes(reader, e)?;
+                    self.add_member_property_index(obj);
                 }
             },
             Event::End(ref e) => {
                 if e.name().into_inner() == b"i" {
-                    return
+                    return Ok(())
                 }
             },
-            Event::Eof => panic!("Error: Could not find {} end element", "i")
+            Event::Eof => return Err(end_element_error(reader, "i"))
         );
     }
 
     #[inline]
     pub(crate) fn write_to(&self, writer: &mut Writer<Cursor<Vec<u8>>>) {
-        let empty_flg = self.member_property_index.is_some();
+        let empty_flg = self.member_property_index.is_empty();
         // i
         let mut attributes: Vec<(&str, &str)> = Vec::new();
         let index_str = self.index.get_value_string();
@@ -117,8 +131,8 @@ impl RowItem {
             attributes.push(("r", &repeated_item_count_str));
         }
         write_start_tag(writer, "i", attributes, empty_flg);
-        if empty_flg == false {
-            if let Some(v) = &self.member_property_index {
+        if !empty_flg {
+            for v in &self.member_property_index {
                 v.write_to(writer);
             }
             write_end_tag(writer, "i");
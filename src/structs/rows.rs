@@ -1,3 +1,7 @@
+use crate::helper::font_metrics::TextFont;
+use crate::structs::Cells;
+use crate::structs::Columns;
+use crate::structs::MergeCells;
 use crate::structs::Row;
 use crate::traits::AdjustmentValue;
 use std::collections::HashMap;
@@ -60,6 +64,26 @@ impl Rows {
         self
     }
 
+    pub(crate) fn calculation_auto_height(
+        &mut self,
+        cells: &Cells,
+        columns: &Columns,
+        merge_cells: &MergeCells,
+        default_font: &TextFont,
+        default_column_width: f64,
+    ) -> &mut Self {
+        for row in self.rows.values_mut() {
+            row.calculation_auto_height(
+                cells,
+                columns,
+                merge_cells,
+                default_font,
+                default_column_width,
+            );
+        }
+        self
+    }
+
     #[inline]
     pub(crate) fn rebuild_map(&mut self) {
         self.rows = self
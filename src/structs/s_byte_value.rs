@@ -1,3 +1,5 @@
+use crate::reader::driver::invalid_attribute_error;
+use crate::XlsxError;
 #[derive(Clone, Default, Debug)]
 pub struct SByteValue {
     value: Option<i8>,
@@ -20,8 +22,16 @@ impl SByteValue {
     }
 
     #[inline]
-    pub(crate) fn set_value_string<S: Into<String>>(&mut self, value: S) -> &mut SByteValue {
-        self.set_value(value.into().parse::<i8>().unwrap())
+    pub(crate) fn try_set_value_string<S: Into<String>>(
+        &mut self,
+        value: S,
+        name: &str,
+    ) -> Result<&mut SByteValue, XlsxError> {
+        let value = value.into();
+        match value.parse::<i8>() {
+            Ok(v) => Ok(self.set_value(v)),
+            Err(_) => Err(invalid_attribute_error(name, value)),
+        }
     }
 
     #[inline]
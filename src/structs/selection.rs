@@ -4,6 +4,7 @@ use super::PaneValues;
 use super::SequenceOfReferences;
 use crate::reader::driver::*;
 use crate::writer::driver::*;
+use crate::XlsxError;
 use quick_xml::events::BytesStart;
 use quick_xml::Reader;
 use quick_xml::Writer;
@@ -64,18 +65,19 @@ impl Selection {
         &mut self,
         _reader: &mut Reader<R>,
         e: &BytesStart,
-    ) {
+    ) -> Result<(), XlsxError> {
         set_string_from_xml!(self, e, pane, "pane");
 
-        if let Some(v) = get_attribute(e, b"activeCell") {
+        if let Some(v) = get_attribute(e, b"activeCell")? {
             let mut obj = Coordinate::default();
             obj.set_coordinate(v);
             self.set_active_cell(obj);
         }
 
-        if let Some(v) = get_attribute(e, b"sqref") {
+        if let Some(v) = get_attribute(e, b"sqref")? {
             self.sequence_of_references.set_sqref(v);
         }
+        Ok(())
     }
 
     pub(crate) fn write_to(&self, writer: &mut Writer<Cursor<Vec<u8>>>) {
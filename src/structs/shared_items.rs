@@ -1,21 +1,27 @@
 // sharedItems
 use crate::reader::driver::*;
 use crate::structs::BooleanValue;
+use crate::structs::CacheItemValue;
 use crate::structs::DoubleValue;
 use crate::writer::driver::*;
+use crate::XlsxError;
 use quick_xml::events::{BytesStart, Event};
 use quick_xml::Reader;
 use quick_xml::Writer;
 use std::io::Cursor;
+use thin_vec::ThinVec;
 
 #[derive(Clone, Default, Debug)]
 pub struct SharedItems {
     contains_semi_mixed_types: BooleanValue,
     contains_string: BooleanValue,
+    contains_blank: BooleanValue,
+    contains_mixed_types: BooleanValue,
     contains_number: BooleanValue,
     contains_integer: BooleanValue,
     min_value: DoubleValue,
     max_value: DoubleValue,
+    list: ThinVec<CacheItemValue>,
 }
 impl SharedItems {
     pub fn get_contains_semi_mixed_types(&self) -> &bool {
@@ -36,6 +42,24 @@ impl SharedItems {
         self
     }
 
+    pub fn get_contains_blank(&self) -> &bool {
+        self.contains_blank.get_value()
+    }
+
+    pub fn set_contains_blank(&mut self, value: bool) -> &mut Self {
+        self.contains_blank.set_value(value);
+        self
+    }
+
+    pub fn get_contains_mixed_types(&self) -> &bool {
+        self.contains_mixed_types.get_value()
+    }
+
+    pub fn set_contains_mixed_types(&mut self, value: bool) -> &mut Self {
+        self.contains_mixed_types.set_value(value);
+        self
+    }
+
     pub fn get_contains_number(&self) -> &bool {
         self.contains_number.get_value()
     }
@@ -72,36 +96,164 @@ impl SharedItems {
         self
     }
 
+    pub fn get_list(&self) -> &[CacheItemValue] {
+        &self.list
+    }
+
+    pub fn get_list_mut(&mut self) -> &mut ThinVec<CacheItemValue> {
+        &mut self.list
+    }
+
+    pub fn add_list_mut(&mut self, value: CacheItemValue) -> &mut Self {
+        self.list.push(value);
+        self
+    }
+
+    /// Rebuild the type flags and the min / max values from `values`,
+    /// the way Excel describes the content of a cache field.
+    pub(crate) fn set_flags_from_values<'a, I>(&mut self, values: I) -> &mut Self
+    where
+        I: IntoIterator<Item = &'a CacheItemValue>,
+    {
+        let mut has_string = false;
+        let mut has_blank = false;
+        let mut has_number = false;
+        let mut has_other = false;
+        let mut is_integer = true;
+        let mut min_value = f64::MAX;
+        let mut max_value = f64::MIN;
+        for value in values {
+            match value {
+                CacheItemValue::String(_) => has_string = true,
+                CacheItemValue::Missing => has_blank = true,
+                CacheItemValue::Number(v) => {
+                    has_number = true;
+                    is_integer &= v.fract() == 0.0;
+                    min_value = min_value.min(*v);
+                    max_value = max_value.max(*v);
+                }
+                _ => has_other = true,
+            }
+        }
+        *self = SharedItems {
+            list: std::mem::take(&mut self.list),
+            ..Default::default()
+        };
+        if !has_string && !has_blank && !has_other {
+            self.set_contains_semi_mixed_types(false);
+        }
+        if !has_string {
+            self.set_contains_string(false);
+        }
+        if has_blank {
+            self.set_contains_blank(true);
+        }
+        if [has_string, has_number, has_other]
+            .iter()
+            .filter(|v| **v)
+            .count()
+            > 1
+        {
+            self.set_contains_mixed_types(true);
+        }
+        if has_number {
+            self.set_contains_number(true);
+            if is_integer {
+                self.set_contains_integer(true);
+            }
+            self.set_min_value(min_value);
+            self.set_max_value(max_value);
+        }
+        self
+    }
+
     pub(crate) fn set_attributes<R: std::io::BufRead>(
         &mut self,
-        _reader: &mut Reader<R>,
+        reader: &mut Reader<R>,
         e: &BytesStart,
-    ) {
+        empty_flg: bool,
+    ) -> Result<(), XlsxError> {
         set_string_from_xml!(self, e, contains_semi_mixed_types, "containsSemiMixedTypes");
         set_string_from_xml!(self, e, contains_string, "containsString");
+        set_string_from_xml!(self, e, contains_blank, "containsBlank");
+        set_string_from_xml!(self, e, contains_mixed_types, "containsMixedTypes");
         set_string_from_xml!(self, e, contains_number, "containsNumber");
         set_string_from_xml!(self, e, contains_integer, "containsInteger");
         set_string_from_xml!(self, e, min_value, "minValue");
         set_string_from_xml!(self, e, max_value, "maxValue");
+
+        if empty_flg {
+            return Ok(());
+        }
+
+        xml_read_loop!(
+            reader,
+            Event::Empty(ref e) => {
+                if let Some(v) = CacheItemValue::set_attributes(e)? {
+                    self.add_list_mut(v);
+                }
+            },
+            Event::Start(ref e) => {
+                if let Some(v) = CacheItemValue::set_attributes(e)? {
+                    self.add_list_mut(v);
+                }
+            },
+            Event::End(ref e) => {
+                if e.name().into_inner() == b"sharedItems" {
+                    return Ok(())
+                }
+            },
+            Event::Eof => return Err(end_element_error(reader, "sharedItems"))
+        );
     }
 
     pub(crate) fn write_to(&self, writer: &mut Writer<Cursor<Vec<u8>>>) {
         // sharedItems
-        write_start_tag(
-            writer,
-            "sharedItems",
-            vec![
-                (
-                    "containsSemiMixedTypes",
-                    self.contains_semi_mixed_types.get_value_string(),
-                ),
-                ("containsString", self.contains_string.get_value_string()),
-                ("containsNumber", self.contains_number.get_value_string()),
-                ("containsInteger", self.contains_integer.get_value_string()),
-                ("minValue", &self.min_value.get_value_string()),
-                ("maxValue", &self.max_value.get_value_string()),
-            ],
-            true,
-        );
+        let empty_flg = self.list.is_empty();
+        let mut attributes: Vec<(&str, &str)> = Vec::new();
+        if self.contains_semi_mixed_types.has_value() {
+            attributes.push((
+                "containsSemiMixedTypes",
+                self.contains_semi_mixed_types.get_value_string(),
+            ));
+        }
+        if self.contains_string.has_value() {
+            attributes.push(("containsString", self.contains_string.get_value_string()));
+        }
+        if self.contains_blank.has_value() {
+            attributes.push(("containsBlank", self.contains_blank.get_value_string()));
+        }
+        if self.contains_mixed_types.has_value() {
+            attributes.push((
+                "containsMixedTypes",
+                self.contains_mixed_types.get_value_string(),
+            ));
+        }
+        if self.contains_number.has_value() {
+            attributes.push(("containsNumber", self.contains_number.get_value_string()));
+        }
+        if self.contains_integer.has_value() {
+            attributes.push(("containsInteger", self.contains_integer.get_value_string()));
+        }
+        let min_value_str = self.min_value.get_value_string();
+        if self.min_value.has_value() {
+            attributes.push(("minValue", &min_value_str));
+        }
+        let max_value_str = self.max_value.get_value_string();
+        if self.max_value.has_value() {
+            attributes.push(("maxValue", &max_value_str));
+        }
+        let count_str = self.list.len().to_string();
+        if !empty_flg {
+            attributes.push(("count", &count_str));
+        }
+        write_start_tag(writer, "sharedItems", attributes, empty_flg);
+
+        if !empty_flg {
+            for item in &self.list {
+                item.write_to(writer);
+            }
+            write_end_tag(writer, "sharedItems");
+        }
     }
 }
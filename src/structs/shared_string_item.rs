@@ -5,6 +5,7 @@ use super::Text;
 use super::TextElement;
 use crate::reader::driver::*;
 use crate::writer::driver::*;
+use crate::XlsxError;
 use md5::Digest;
 use quick_xml::events::{BytesStart, Event};
 use quick_xml::Reader;
@@ -84,7 +85,7 @@ impl SharedStringItem {
         &mut self,
         reader: &mut Reader<R>,
         _e: &BytesStart,
-    ) {
+    ) -> Result<(), XlsxError> {
         let mut vec_text_element: Vec<TextElement> = Vec::new();
 
         xml_read_loop!(
@@ -93,17 +94,17 @@ impl SharedStringItem {
                 match e.name().into_inner() {
                     b"t" => {
                         let mut obj = Text::default();
-                        obj.set_attributes(reader, e);
+                        obj.set_attributes(reader, e)?;
                         self.set_text(obj);
                     }
                     b"r" => {
                         let mut obj = TextElement::default();
-                        obj.set_attributes(reader, e);
+                        obj.set_attributes(reader, e)?;
                         vec_text_element.push(obj);
                     }
                     b"rPh" => {
                         let mut obj = PhoneticRun::default();
-                        obj.set_attributes(reader, e);
+                        obj.set_attributes(reader, e)?;
                     }
                     _ => (),
                 }
@@ -115,10 +116,10 @@ impl SharedStringItem {
                         obj.set_rich_text_elements(vec_text_element);
                         self.set_rich_text(obj);
                     }
-                    return;
+                    return Ok(());
                 }
             },
-            Event::Eof => panic!("Error: Could not find {} end element", "si")
+            Event::Eof => return Err(end_element_error(reader, "si"))
         );
     }
 
@@ -6,6 +6,7 @@ use crate::drawing::charts::View3D;
 use crate::helper::const_str::*;
 use crate::reader::driver::*;
 use crate::writer::driver::*;
+use crate::XlsxError;
 use quick_xml::events::{BytesStart, Event};
 use quick_xml::Reader;
 use quick_xml::Writer;
@@ -71,14 +72,14 @@ impl SharedStringTable {
         &mut self,
         reader: &mut Reader<R>,
         _e: &BytesStart,
-    ) {
+    ) -> Result<(), XlsxError> {
         let mut n: usize = 0;
         xml_read_loop!(
             reader,
             Event::Start(ref e) => {
                 if e.name().into_inner() == b"si" {
                     let mut shared_string_item = SharedStringItem::default();
-                    shared_string_item.set_attributes(reader, e);
+                    shared_string_item.set_attributes(reader, e)?;
 
                     let hash_code = shared_string_item.get_hash_u64();
                     self.map.insert(hash_code, n);
@@ -89,10 +90,10 @@ impl SharedStringTable {
             },
             Event::End(ref e) => {
                 if e.name().into_inner() == b"sst" {
-                    return
+                    return Ok(())
                 }
             },
-            Event::Eof => panic!("Error: Could not find {} end element", "sst")
+            Event::Eof => return Err(end_element_error(reader, "sst"))
         );
     }
 
@@ -5,6 +5,7 @@ use super::DoubleValue;
 use super::UInt32Value;
 use crate::reader::driver::*;
 use crate::writer::driver::*;
+use crate::XlsxError;
 use quick_xml::events::BytesStart;
 use quick_xml::Reader;
 use quick_xml::Writer;
@@ -134,7 +135,7 @@ impl SheetFormatProperties {
         &mut self,
         _reader: &mut Reader<R>,
         e: &BytesStart,
-    ) {
+    ) -> Result<(), XlsxError> {
         set_string_from_xml!(self, e, base_column_width, "baseColWidth");
         set_string_from_xml!(self, e, custom_height, "customHeight");
         set_string_from_xml!(self, e, default_column_width, "defaultColWidth");
@@ -144,6 +145,7 @@ impl SheetFormatProperties {
         set_string_from_xml!(self, e, outline_level_row, "outlineLevelRow");
         set_string_from_xml!(self, e, thick_bottom, "thickBottom");
         set_string_from_xml!(self, e, thick_top, "thickTop");
+        Ok(())
     }
 
     pub(crate) fn write_to(&self, writer: &mut Writer<Cursor<Vec<u8>>>) {
@@ -5,6 +5,7 @@ use super::UInt32Value;
 use crate::helper::crypt::*;
 use crate::reader::driver::*;
 use crate::writer::driver::*;
+use crate::XlsxError;
 use md5::Digest;
 use quick_xml::events::{BytesStart, Event};
 use quick_xml::Reader;
@@ -283,7 +284,7 @@ impl SheetProtection {
         &mut self,
         _reader: &mut Reader<R>,
         e: &BytesStart,
-    ) {
+    ) -> Result<(), XlsxError> {
         set_string_from_xml!(self, e, algorithm_name, "algorithmName");
         set_string_from_xml!(self, e, hash_value, "hashValue");
         set_string_from_xml!(self, e, salt_value, "saltValue");
@@ -305,6 +306,7 @@ impl SheetProtection {
         set_string_from_xml!(self, e, select_locked_cells, "selectLockedCells");
         set_string_from_xml!(self, e, select_unlocked_cells, "selectUnlockedCells");
         set_string_from_xml!(self, e, sort, "sort");
+        Ok(())
     }
 
     pub(crate) fn write_to(&self, writer: &mut Writer<Cursor<Vec<u8>>>) {
@@ -8,6 +8,7 @@ use super::StringValue;
 use super::UInt32Value;
 use crate::reader::driver::*;
 use crate::writer::driver::*;
+use crate::XlsxError;
 use quick_xml::events::{BytesStart, Event};
 use quick_xml::Reader;
 use quick_xml::Writer;
@@ -166,7 +167,7 @@ impl SheetView {
         reader: &mut Reader<R>,
         e: &BytesStart,
         empty_flag: bool,
-    ) {
+    ) -> Result<(), XlsxError> {
         set_string_from_xml!(self, e, show_grid_lines, "showGridLines");
         set_string_from_xml!(self, e, tab_selected, "tabSelected");
         set_string_from_xml!(self, e, workbook_view_id, "workbookViewId");
@@ -188,7 +189,7 @@ impl SheetView {
         set_string_from_xml!(self, e, top_left_cell, "topLeftCell");
 
         if empty_flag {
-            return;
+            return Ok(());
         }
 
         xml_read_loop!(
@@ -197,12 +198,12 @@ impl SheetView {
                 match e.name().into_inner() {
                     b"pane" => {
                         let mut obj = Pane::default();
-                        obj.set_attributes(reader, e);
+                        obj.set_attributes(reader, e)?;
                         self.set_pane(obj);
                     }
                     b"selection" => {
                         let mut obj = Selection::default();
-                        obj.set_attributes(reader, e);
+                        obj.set_attributes(reader, e)?;
                         self.set_selection(obj);
                     }
                     _ => (),
@@ -210,10 +211,10 @@ impl SheetView {
             },
             Event::End(ref e) => {
                 if e.name().into_inner() == b"sheetView" {
-                    return
+                    return Ok(())
                 }
             },
-            Event::Eof => panic!("Error: Could not find {} end element", "sheetView")
+            Event::Eof => return Err(end_element_error(reader, "sheetView"))
         );
     }
 
@@ -0,0 +1,40 @@
+use super::EnumTrait;
+use std::str::FromStr;
+#[derive(Clone, Debug, Eq, Ord, PartialEq, PartialOrd)]
+pub enum SortByValues {
+    CellColor,
+    FontColor,
+    Icon,
+    Value,
+}
+impl Default for SortByValues {
+    #[inline]
+    fn default() -> Self {
+        Self::Value
+    }
+}
+impl EnumTrait for SortByValues {
+    #[inline]
+    fn get_value_string(&self) -> &str {
+        match &self {
+            Self::CellColor => "cellColor",
+            Self::FontColor => "fontColor",
+            Self::Icon => "icon",
+            Self::Value => "value",
+        }
+    }
+}
+impl FromStr for SortByValues {
+    type Err = ();
+
+    #[inline]
+    fn from_str(input: &str) -> Result<Self, Self::Err> {
+        match input {
+            "cellColor" => Ok(Self::CellColor),
+            "fontColor" => Ok(Self::FontColor),
+            "icon" => Ok(Self::Icon),
+            "value" => Ok(Self::Value),
+            _ => Err(()),
+        }
+    }
+}
@@ -0,0 +1,209 @@
+// sortCondition
+use super::BooleanValue;
+use super::DifferentialFormats;
+use super::EnumValue;
+use super::IconSetValues;
+use super::Range;
+use super::SortByValues;
+use super::StringValue;
+use super::Style;
+use super::UInt32Value;
+use crate::reader::driver::*;
+use crate::traits::AdjustmentCoordinate;
+use crate::writer::driver::*;
+use crate::XlsxError;
+use quick_xml::events::BytesStart;
+use quick_xml::Reader;
+use quick_xml::Writer;
+use std::io::Cursor;
+
+#[derive(Clone, Default, Debug)]
+pub struct SortCondition {
+    descending: BooleanValue,
+    sort_by: EnumValue<SortByValues>,
+    range: Range,
+    custom_list: StringValue,
+    style: Option<Box<Style>>,
+    icon_set: EnumValue<IconSetValues>,
+    icon_id: UInt32Value,
+}
+
+impl SortCondition {
+    #[inline]
+    pub fn get_descending(&self) -> &bool {
+        self.descending.get_value()
+    }
+
+    #[inline]
+    pub fn set_descending(&mut self, value: bool) -> &mut Self {
+        self.descending.set_value(value);
+        self
+    }
+
+    #[inline]
+    pub fn get_sort_by(&self) -> &SortByValues {
+        self.sort_by.get_value()
+    }
+
+    #[inline]
+    pub fn set_sort_by(&mut self, value: SortByValues) -> &mut Self {
+        self.sort_by.set_value(value);
+        self
+    }
+
+    /// The column that is sorted, e.g. `B2:B10`.
+    #[inline]
+    pub fn get_range(&self) -> &Range {
+        &self.range
+    }
+
+    #[inline]
+    pub fn get_range_mut(&mut self) -> &mut Range {
+        &mut self.range
+    }
+
+    #[inline]
+    pub fn set_range<S: Into<String>>(&mut self, value: S) -> &mut Self {
+        self.range = Range::default();
+        self.range.set_range(value);
+        self
+    }
+
+    /// Comma separated custom sort order, e.g. `Low,Medium,High`.
+    #[inline]
+    pub fn get_custom_list(&self) -> &str {
+        self.custom_list.get_value_str()
+    }
+
+    #[inline]
+    pub fn set_custom_list<S: Into<String>>(&mut self, value: S) -> &mut Self {
+        self.custom_list.set_value(value);
+        self
+    }
+
+    /// The fill or font color used by the `CellColor` and `FontColor` sorts.
+    #[inline]
+    pub fn get_style(&self) -> Option<&Style> {
+        self.style.as_deref()
+    }
+
+    #[inline]
+    pub fn set_style(&mut self, value: Style) -> &mut Self {
+        self.style = Some(Box::new(value));
+        self
+    }
+
+    #[inline]
+    pub fn get_icon_set(&self) -> &IconSetValues {
+        self.icon_set.get_value()
+    }
+
+    #[inline]
+    pub fn set_icon_set(&mut self, value: IconSetValues) -> &mut Self {
+        self.icon_set.set_value(value);
+        self
+    }
+
+    #[inline]
+    pub fn get_icon_id(&self) -> &u32 {
+        self.icon_id.get_value()
+    }
+
+    #[inline]
+    pub fn set_icon_id(&mut self, value: u32) -> &mut Self {
+        self.icon_id.set_value(value);
+        self
+    }
+
+    pub(crate) fn set_attributes<R: std::io::BufRead>(
+        &mut self,
+        _reader: &mut Reader<R>,
+        e: &BytesStart,
+        differential_formats: &DifferentialFormats,
+    ) -> Result<(), XlsxError> {
+        set_string_from_xml!(self, e, descending, "descending");
+        set_string_from_xml!(self, e, sort_by, "sortBy");
+        set_string_from_xml!(self, e, custom_list, "customList");
+        set_string_from_xml!(self, e, icon_set, "iconSet");
+        set_string_from_xml!(self, e, icon_id, "iconId");
+
+        if let Some(v) = get_attribute(e, b"ref")? {
+            self.set_range(v);
+        }
+
+        if let Some(v) = get_attribute(e, b"dxfId")? {
+            let style = differential_formats
+                .get_style(parse_value("dxfId", &v)?)
+                .ok_or_else(|| invalid_attribute_error("dxfId", v))?;
+            self.set_style(style);
+        }
+        Ok(())
+    }
+
+    pub(crate) fn write_to(
+        &self,
+        writer: &mut Writer<Cursor<Vec<u8>>>,
+        differential_formats: &mut DifferentialFormats,
+    ) {
+        // sortCondition
+        let mut attributes: Vec<(&str, &str)> = Vec::new();
+        if *self.descending.get_value() {
+            attributes.push(("descending", self.descending.get_value_string()));
+        }
+        if self.sort_by.has_value() {
+            attributes.push(("sortBy", self.sort_by.get_value_string()));
+        }
+        let range = self.range.get_range();
+        attributes.push(("ref", &range));
+        if self.custom_list.has_value() {
+            attributes.push(("customList", self.custom_list.get_value_str()));
+        }
+        let dxf_id_str: String;
+        if let Some(v) = &self.style {
+            let dxf_id = differential_formats.set_style(v);
+            dxf_id_str = dxf_id.to_string();
+            attributes.push(("dxfId", &dxf_id_str));
+        }
+        if self.icon_set.has_value() {
+            attributes.push(("iconSet", self.icon_set.get_value_string()));
+        }
+        let icon_id = self.icon_id.get_value_string();
+        if self.icon_id.has_value() {
+            attributes.push(("iconId", &icon_id));
+        }
+        write_start_tag(writer, "sortCondition", attributes, true);
+    }
+}
+impl AdjustmentCoordinate for SortCondition {
+    #[inline]
+    fn adjustment_insert_coordinate(
+        &mut self,
+        root_col_num: &u32,
+        offset_col_num: &u32,
+        root_row_num: &u32,
+        offset_row_num: &u32,
+    ) {
+        self.range.adjustment_insert_coordinate(
+            root_col_num,
+            offset_col_num,
+            root_row_num,
+            offset_row_num,
+        );
+    }
+
+    #[inline]
+    fn adjustment_remove_coordinate(
+        &mut self,
+        root_col_num: &u32,
+        offset_col_num: &u32,
+        root_row_num: &u32,
+        offset_row_num: &u32,
+    ) {
+        self.range.adjustment_remove_coordinate(
+            root_col_num,
+            offset_col_num,
+            root_row_num,
+            offset_row_num,
+        );
+    }
+}
@@ -0,0 +1,37 @@
+use super::EnumTrait;
+use std::str::FromStr;
+#[derive(Clone, Debug, Eq, Ord, PartialEq, PartialOrd)]
+pub enum SortMethodValues {
+    None,
+    PinYin,
+    Stroke,
+}
+impl Default for SortMethodValues {
+    #[inline]
+    fn default() -> Self {
+        Self::None
+    }
+}
+impl EnumTrait for SortMethodValues {
+    #[inline]
+    fn get_value_string(&self) -> &str {
+        match &self {
+            Self::None => "none",
+            Self::PinYin => "pinYin",
+            Self::Stroke => "stroke",
+        }
+    }
+}
+impl FromStr for SortMethodValues {
+    type Err = ();
+
+    #[inline]
+    fn from_str(input: &str) -> Result<Self, Self::Err> {
+        match input {
+            "none" => Ok(Self::None),
+            "pinYin" => Ok(Self::PinYin),
+            "stroke" => Ok(Self::Stroke),
+            _ => Err(()),
+        }
+    }
+}
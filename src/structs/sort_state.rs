@@ -0,0 +1,213 @@
+// sortState
+use super::BooleanValue;
+use super::DifferentialFormats;
+use super::EnumValue;
+use super::Range;
+use super::SortCondition;
+use super::SortMethodValues;
+use crate::reader::driver::*;
+use crate::traits::AdjustmentCoordinate;
+use crate::writer::driver::*;
+use crate::XlsxError;
+use quick_xml::events::{BytesStart, Event};
+use quick_xml::Reader;
+use quick_xml::Writer;
+use std::io::Cursor;
+use thin_vec::ThinVec;
+
+#[derive(Clone, Default, Debug)]
+pub struct SortState {
+    column_sort: BooleanValue,
+    case_sensitive: BooleanValue,
+    sort_method: EnumValue<SortMethodValues>,
+    range: Range,
+    sort_condition_list: ThinVec<SortCondition>,
+}
+
+impl SortState {
+    /// Sort columns (left to right) instead of rows.
+    #[inline]
+    pub fn get_column_sort(&self) -> &bool {
+        self.column_sort.get_value()
+    }
+
+    #[inline]
+    pub fn set_column_sort(&mut self, value: bool) -> &mut Self {
+        self.column_sort.set_value(value);
+        self
+    }
+
+    #[inline]
+    pub fn get_case_sensitive(&self) -> &bool {
+        self.case_sensitive.get_value()
+    }
+
+    #[inline]
+    pub fn set_case_sensitive(&mut self, value: bool) -> &mut Self {
+        self.case_sensitive.set_value(value);
+        self
+    }
+
+    #[inline]
+    pub fn get_sort_method(&self) -> &SortMethodValues {
+        self.sort_method.get_value()
+    }
+
+    #[inline]
+    pub fn set_sort_method(&mut self, value: SortMethodValues) -> &mut Self {
+        self.sort_method.set_value(value);
+        self
+    }
+
+    /// The sorted cells, without the header row.
+    #[inline]
+    pub fn get_range(&self) -> &Range {
+        &self.range
+    }
+
+    #[inline]
+    pub fn get_range_mut(&mut self) -> &mut Range {
+        &mut self.range
+    }
+
+    #[inline]
+    pub fn set_range<S: Into<String>>(&mut self, value: S) -> &mut Self {
+        self.range = Range::default();
+        self.range.set_range(value);
+        self
+    }
+
+    #[inline]
+    pub fn get_sort_condition_list(&self) -> &[SortCondition] {
+        &self.sort_condition_list
+    }
+
+    #[inline]
+    pub fn get_sort_condition_list_mut(&mut self) -> &mut ThinVec<SortCondition> {
+        &mut self.sort_condition_list
+    }
+
+    #[inline]
+    pub fn add_sort_condition(&mut self, value: SortCondition) -> &mut Self {
+        self.sort_condition_list.push(value);
+        self
+    }
+
+    pub(crate) fn set_attributes<R: std::io::BufRead>(
+        &mut self,
+        reader: &mut Reader<R>,
+        e: &BytesStart,
+        differential_formats: &DifferentialFormats,
+        empty_flag: bool,
+    ) -> Result<(), XlsxError> {
+        set_string_from_xml!(self, e, column_sort, "columnSort");
+        set_string_from_xml!(self, e, case_sensitive, "caseSensitive");
+        set_string_from_xml!(self, e, sort_method, "sortMethod");
+
+        if let Some(v) = get_attribute(e, b"ref")? {
+            self.set_range(v);
+        }
+
+        if empty_flag {
+            return Ok(());
+        }
+
+        xml_read_loop!(
+            reader,
+            Event::Empty(ref e) => {
+                if e.name().into_inner() == b"sortCondition" {
+                    let mut obj = SortCondition::default();
+                    obj.set_attributes(reader, e, differential_formats)?;
+                    self.add_sort_condition(obj);
+                }
+            },
+            Event::End(ref e) => {
+                if e.name().into_inner() == b"sortState" {
+                    return Ok(())
+                }
+            },
+            Event::Eof => return Err(end_element_error(reader, "sortState"))
+        );
+    }
+
+    pub(crate) fn write_to(
+        &self,
+        writer: &mut Writer<Cursor<Vec<u8>>>,
+        differential_formats: &mut DifferentialFormats,
+    ) {
+        // sortState
+        let mut attributes: Vec<(&str, &str)> = Vec::new();
+        if *self.column_sort.get_value() {
+            attributes.push(("columnSort", self.column_sort.get_value_string()));
+        }
+        if *self.case_sensitive.get_value() {
+            attributes.push(("caseSensitive", self.case_sensitive.get_value_string()));
+        }
+        if self.sort_method.has_value() {
+            attributes.push(("sortMethod", self.sort_method.get_value_string()));
+        }
+        let range = self.range.get_range();
+        attributes.push(("ref", &range));
+        let empty_flag = self.sort_condition_list.is_empty();
+        write_start_tag(writer, "sortState", attributes, empty_flag);
+        if empty_flag {
+            return;
+        }
+
+        // sortCondition
+        for obj in &self.sort_condition_list {
+            obj.write_to(writer, differential_formats);
+        }
+
+        write_end_tag(writer, "sortState");
+    }
+}
+impl AdjustmentCoordinate for SortState {
+    #[inline]
+    fn adjustment_insert_coordinate(
+        &mut self,
+        root_col_num: &u32,
+        offset_col_num: &u32,
+        root_row_num: &u32,
+        offset_row_num: &u32,
+    ) {
+        self.range.adjustment_insert_coordinate(
+            root_col_num,
+            offset_col_num,
+            root_row_num,
+            offset_row_num,
+        );
+        for obj in &mut self.sort_condition_list {
+            obj.adjustment_insert_coordinate(
+                root_col_num,
+                offset_col_num,
+                root_row_num,
+                offset_row_num,
+            );
+        }
+    }
+
+    #[inline]
+    fn adjustment_remove_coordinate(
+        &mut self,
+        root_col_num: &u32,
+        offset_col_num: &u32,
+        root_row_num: &u32,
+        offset_row_num: &u32,
+    ) {
+        self.range.adjustment_remove_coordinate(
+            root_col_num,
+            offset_col_num,
+            root_row_num,
+            offset_row_num,
+        );
+        for obj in &mut self.sort_condition_list {
+            obj.adjustment_remove_coordinate(
+                root_col_num,
+                offset_col_num,
+                root_row_num,
+                offset_row_num,
+            );
+        }
+    }
+}
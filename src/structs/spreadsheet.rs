@@ -1,8 +1,10 @@
 use crate::helper::address::*;
+use crate::helper::calculation::*;
 use crate::helper::coordinate::*;
 use crate::reader::xlsx::*;
 use crate::structs::drawing::Theme;
 use crate::structs::Address;
+use crate::structs::CellRawValue;
 use crate::structs::CellValue;
 use crate::structs::Cells;
 use crate::structs::DefinedName;
@@ -15,12 +17,17 @@ use crate::structs::Worksheet;
 use crate::traits::AdjustmentCoordinate;
 use crate::traits::AdjustmentCoordinateWithSheet;
 use crate::StringValue;
+use crate::XlsxError;
 use std::sync::Arc;
 use std::sync::RwLock;
 use thin_vec::ThinVec;
 
 /// A Spreadsheet Object.
 /// The starting point of all struct.
+///
+/// The worksheets of a file read with `lazy_read` are read when they are first used,
+/// and the methods that use them panic when a worksheet is malformed.
+/// The `try_` methods and [`Self::read_sheet_collection`] return the error instead.
 #[derive(Clone, Default, Debug)]
 pub struct Spreadsheet {
     properties: Properties,
@@ -36,6 +43,7 @@ pub struct Spreadsheet {
     pivot_caches: ThinVec<(Box<str>, Box<str>, Box<str>)>,
     workbook_protection: Option<Box<WorkbookProtection>>,
     defined_names: ThinVec<DefinedName>,
+    date1904: bool,
 }
 
 impl Spreadsheet {
@@ -57,6 +65,19 @@ impl Spreadsheet {
         self.adjustment_insert_coordinate_with_sheet(sheet_name, &0, &0, row_index, num_rows);
     }
 
+    /// Fallible version of [`Self::insert_new_row`].
+    #[inline]
+    pub fn try_insert_new_row(
+        &mut self,
+        sheet_name: &str,
+        row_index: &u32,
+        num_rows: &u32,
+    ) -> Result<(), XlsxError> {
+        self.read_sheet_collection()?;
+        self.insert_new_row(sheet_name, row_index, num_rows);
+        Ok(())
+    }
+
     /// Insert new columns.
     /// # Arguments
     /// * `sheet_name` - Specify the sheet name. ex) "Sheet1"
@@ -72,6 +93,19 @@ impl Spreadsheet {
         self.insert_new_column_by_index(sheet_name, &column_index_from_string(column), num_columns);
     }
 
+    /// Fallible version of [`Self::insert_new_column`].
+    #[inline]
+    pub fn try_insert_new_column(
+        &mut self,
+        sheet_name: &str,
+        column: &str,
+        num_columns: &u32,
+    ) -> Result<(), XlsxError> {
+        self.read_sheet_collection()?;
+        self.insert_new_column(sheet_name, column, num_columns);
+        Ok(())
+    }
+
     /// Insert new columns.
     /// # Arguments
     /// * `sheet_name` - Specify the sheet name. ex) "Sheet1"
@@ -92,6 +126,19 @@ impl Spreadsheet {
         self.adjustment_insert_coordinate_with_sheet(sheet_name, column_index, num_columns, &0, &0);
     }
 
+    /// Fallible version of [`Self::insert_new_column_by_index`].
+    #[inline]
+    pub fn try_insert_new_column_by_index(
+        &mut self,
+        sheet_name: &str,
+        column_index: &u32,
+        num_columns: &u32,
+    ) -> Result<(), XlsxError> {
+        self.read_sheet_collection()?;
+        self.insert_new_column_by_index(sheet_name, column_index, num_columns);
+        Ok(())
+    }
+
     /// Remove rows.
     /// # Arguments
     /// * `sheet_name` - Specify the sheet name. ex) "Sheet1"
@@ -107,6 +154,19 @@ impl Spreadsheet {
         self.adjustment_remove_coordinate_with_sheet(sheet_name, &0, &0, row_index, num_rows);
     }
 
+    /// Fallible version of [`Self::remove_row`].
+    #[inline]
+    pub fn try_remove_row(
+        &mut self,
+        sheet_name: &str,
+        row_index: &u32,
+        num_rows: &u32,
+    ) -> Result<(), XlsxError> {
+        self.read_sheet_collection()?;
+        self.remove_row(sheet_name, row_index, num_rows);
+        Ok(())
+    }
+
     /// Remove columns.
     /// # Arguments
     /// * `sheet_name` - Specify the sheet name. ex) "Sheet1"
@@ -122,6 +182,19 @@ impl Spreadsheet {
         self.remove_column_by_index(sheet_name, &column_index_from_string(column), num_columns);
     }
 
+    /// Fallible version of [`Self::remove_column`].
+    #[inline]
+    pub fn try_remove_column(
+        &mut self,
+        sheet_name: &str,
+        column: &str,
+        num_columns: &u32,
+    ) -> Result<(), XlsxError> {
+        self.read_sheet_collection()?;
+        self.remove_column(sheet_name, column, num_columns);
+        Ok(())
+    }
+
     /// Remove columns.
     /// # Arguments
     /// * `sheet_name` - Specify the sheet name. ex) "Sheet1"
@@ -142,6 +215,19 @@ impl Spreadsheet {
         self.adjustment_remove_coordinate_with_sheet(sheet_name, column_index, num_columns, &0, &0);
     }
 
+    /// Fallible version of [`Self::remove_column_by_index`].
+    #[inline]
+    pub fn try_remove_column_by_index(
+        &mut self,
+        sheet_name: &str,
+        column_index: &u32,
+        num_columns: &u32,
+    ) -> Result<(), XlsxError> {
+        self.read_sheet_collection()?;
+        self.remove_column_by_index(sheet_name, column_index, num_columns);
+        Ok(())
+    }
+
     /// Gets the cell value by specifying an address.
     /// # Arguments
     /// * `address` - address. ex) "Sheet1!A1:C5"
@@ -173,6 +259,106 @@ impl Spreadsheet {
             .get_cell_value_by_range(&address.get_range().get_range())
     }
 
+    /// Calculate every formula in the workbook and store the results as cell values,
+    /// so that the file shows up-to-date values without being recalculated by Excel.
+    /// # Return value
+    /// * `Result` - Err when a worksheet that is not read yet is malformed.
+    /// # Examples
+    /// ```
+    /// let mut book = umya_spreadsheet::new_file();
+    /// let worksheet = book.get_sheet_mut(&0).unwrap();
+    /// worksheet.get_cell_mut("A1").set_value_number(2);
+    /// worksheet.get_cell_mut("A2").set_formula("SUM(A1,3)");
+    /// book.calculate().unwrap();
+    /// assert_eq!(book.get_sheet(&0).unwrap().get_value("A2"), "5");
+    /// ```
+    pub fn calculate(&mut self) -> Result<&mut Self, XlsxError> {
+        self.read_sheet_collection()?;
+        let mut results: Vec<(usize, u32, u32, CellRawValue)> = Vec::new();
+        {
+            let sheets: Vec<&Worksheet> = self.work_sheet_collection.iter().collect();
+            let mut calculator = Calculator::new(sheets, &self.defined_names);
+            for (index, worksheet) in self.work_sheet_collection.iter().enumerate() {
+                for cell in worksheet.get_cell_collection_sorted() {
+                    if !cell.is_formula() {
+                        continue;
+                    }
+                    let col = *cell.get_coordinate().get_col_num();
+                    let row = *cell.get_coordinate().get_row_num();
+                    let value = calculator.calculate_cell(index, col, row).into_raw();
+                    results.push((index, col, row, value));
+                }
+            }
+        }
+        for (index, col, row, value) in results {
+            self.work_sheet_collection[index]
+                .get_cell_mut((col, row))
+                .get_cell_value_mut()
+                .set_formula_result(value);
+        }
+        Ok(self)
+    }
+
+    /// Refresh all pivot tables of the workbook from their source data
+    /// and rewrite their reports in the worksheets.
+    /// # Return value
+    /// * `Result` - Err if the source of a pivot table no longer exists or a pivot table
+    ///   has no cache to refresh from.
+    /// # Examples
+    /// ```
+    /// use umya_spreadsheet::*;
+    /// let mut book = new_file();
+    /// let sheet = book.get_sheet_by_name_mut("Sheet1").unwrap();
+    /// sheet.get_cell_mut("A1").set_value("Sales");
+    /// sheet.get_cell_mut("A2").set_value_number(10);
+    /// let mut pivot_table = PivotTable::new("PivotTable1", sheet, "A1:A2", "C1");
+    /// pivot_table
+    ///     .add_data_field("Sales", DataConsolidateFunctionValues::Sum)
+    ///     .unwrap();
+    /// sheet.add_pivot_table(pivot_table);
+    /// assert_eq!(sheet.get_value("C2"), "10");
+    ///
+    /// book.get_sheet_by_name_mut("Sheet1")
+    ///     .unwrap()
+    ///     .get_cell_mut("A2")
+    ///     .set_value_number(15);
+    /// book.refresh_pivot_tables().unwrap();
+    /// assert_eq!(book.get_sheet(&0).unwrap().get_value("C2"), "15");
+    /// ```
+    pub fn refresh_pivot_tables(&mut self) -> Result<&mut Self, XlsxError> {
+        self.read_sheet_collection()?;
+        for index in 0..self.work_sheet_collection.len() {
+            let mut pivot_tables = self.work_sheet_collection[index]
+                .get_pivot_tables()
+                .to_vec();
+            for pivot_table in pivot_tables.iter_mut() {
+                if pivot_table
+                    .get_pivot_cache_definition()
+                    .get_cache_fields()
+                    .get_list()
+                    .is_empty()
+                {
+                    return Err(XlsxError::UnsupportedPivotSource(format!(
+                        "'{}' has no pivot cache",
+                        pivot_table.get_pivot_table_definition().get_name()
+                    )));
+                }
+                pivot_table.refresh(self)?;
+            }
+
+            let worksheet = &mut self.work_sheet_collection[index];
+            let old_pivot_tables = std::mem::take(worksheet.get_pivot_tables_mut());
+            for old in old_pivot_tables.iter() {
+                old.clear_output(worksheet);
+            }
+            for pivot_table in pivot_tables.iter() {
+                pivot_table.write_output(worksheet);
+            }
+            *worksheet.get_pivot_tables_mut() = pivot_tables.into();
+        }
+        Ok(self)
+    }
+
     /// Get Theme.
     #[inline]
     pub fn get_theme(&self) -> &Theme {
@@ -268,6 +454,37 @@ impl Spreadsheet {
         self.code_name.get_value()
     }
 
+    /// Whether the workbook uses the 1904 date system.
+    /// Date serials are then counted from 1904-01-01 instead of 1900-01-01.
+    #[inline]
+    pub fn get_date1904(&self) -> bool {
+        self.date1904
+    }
+
+    /// Set the date system of the workbook.
+    /// Cell values are kept as they are, only the dates they are read as change.
+    /// # Arguments
+    /// * `value` - true for the 1904 date system, false for the 1900 date system.
+    /// # Examples
+    /// ```
+    /// let mut book = umya_spreadsheet::new_file();
+    /// book.set_date1904(true);
+    /// let worksheet = book.get_sheet_mut(&0).unwrap();
+    /// worksheet.get_cell_mut("A1").set_value_number(0);
+    /// worksheet
+    ///     .get_style_mut("A1")
+    ///     .get_number_format_mut()
+    ///     .set_format_code("yyyy-mm-dd");
+    /// assert_eq!(worksheet.get_formatted_value("A1"), "1904-01-01");
+    /// ```
+    pub fn set_date1904(&mut self, value: bool) -> &mut Self {
+        self.date1904 = value;
+        for worksheet in &mut self.work_sheet_collection {
+            worksheet.set_date1904(value);
+        }
+        self
+    }
+
     /// (This method is crate only.)
     /// Get Stylesheet.
     #[inline]
@@ -282,6 +499,7 @@ impl Spreadsheet {
     #[inline]
     pub(crate) fn set_stylesheet(&mut self, value: Stylesheet) -> &mut Self {
         self.stylesheet = value;
+        self.set_default_font_of_sheets();
         self
     }
 
@@ -290,9 +508,18 @@ impl Spreadsheet {
     #[inline]
     pub(crate) fn set_stylesheet_defalut_value(&mut self) -> &mut Self {
         self.stylesheet.set_defalut_value();
+        self.set_default_font_of_sheets();
         self
     }
 
+    // The worksheets measure their auto widths and heights with the font of the Normal style.
+    fn set_default_font_of_sheets(&mut self) {
+        let default_font = self.stylesheet.get_default_font();
+        for worksheet in &mut self.work_sheet_collection {
+            worksheet.set_default_font(default_font.clone());
+        }
+    }
+
     /// (This method is crate only.)
     /// Get Shared String Table.
     #[inline]
@@ -326,12 +553,20 @@ impl Spreadsheet {
     }
 
     /// Get Work Sheet List in mutable.
+    /// Use [`Self::read_sheet_collection`] first to get the error instead.
     #[inline]
     pub fn get_sheet_collection_mut(&mut self) -> &mut ThinVec<Worksheet> {
-        self.read_sheet_collection();
+        self.read_sheet_collection().unwrap();
         &mut self.work_sheet_collection
     }
 
+    /// Fallible version of [`Self::get_sheet_collection_mut`].
+    #[inline]
+    pub fn try_get_sheet_collection_mut(&mut self) -> Result<&mut ThinVec<Worksheet>, XlsxError> {
+        self.read_sheet_collection()?;
+        Ok(&mut self.work_sheet_collection)
+    }
+
     /// Get Work Sheet Count.
     /// # Return value
     /// * `usize` - Work Sheet Count.
@@ -341,30 +576,51 @@ impl Spreadsheet {
     }
 
     /// deserialize by all worksheet.
+    /// # Return value
+    /// * `Result` - Err when a worksheet or one of its parts is malformed.
     #[inline]
-    pub fn read_sheet_collection(&mut self) -> &mut Self {
+    pub fn read_sheet_collection(&mut self) -> Result<&mut Self, XlsxError> {
         let shared_string_table = self.get_shared_string_table();
         let stylesheet = self.get_stylesheet().clone();
         for worksheet in &mut self.work_sheet_collection {
-            raw_to_deserialize_by_worksheet(worksheet, &shared_string_table, &stylesheet);
+            raw_to_deserialize_by_worksheet(worksheet, &shared_string_table, &stylesheet)?;
         }
-        self
+        Ok(self)
     }
 
     /// deserialize a worksheet.
+    /// # Arguments
+    /// * `index` - sheet index
+    /// # Return value
+    /// * `Result` - Err when the worksheet does not exist or is malformed.
+    /// # Examples
+    /// ```
+    /// let path = std::path::Path::new("./tests/test_files/aaa.xlsx");
+    /// let mut book = umya_spreadsheet::reader::xlsx::lazy_read(path).unwrap();
+    /// book.read_sheet(0).unwrap();
+    /// ```
     #[inline]
-    pub fn read_sheet(&mut self, index: usize) -> &mut Self {
+    pub fn read_sheet(&mut self, index: usize) -> Result<&mut Self, XlsxError> {
         let shared_string_table = self.get_shared_string_table();
         let stylesheet = self.get_stylesheet().clone();
-        let worksheet = self.work_sheet_collection.get_mut(index).unwrap();
-        raw_to_deserialize_by_worksheet(worksheet, &shared_string_table, &stylesheet);
-        self
+        let worksheet = self
+            .work_sheet_collection
+            .get_mut(index)
+            .ok_or_else(|| XlsxError::SheetNotFound(index.to_string()))?;
+        raw_to_deserialize_by_worksheet(worksheet, &shared_string_table, &stylesheet)?;
+        Ok(self)
     }
 
     /// deserialize a worksheet.
+    /// # Arguments
+    /// * `sheet_name` - sheet name
+    /// # Return value
+    /// * `Result` - Err when the worksheet does not exist or is malformed.
     #[inline]
-    pub fn read_sheet_by_name(&mut self, sheet_name: &str) -> &mut Self {
-        let index = self.find_sheet_index_by_name(sheet_name).unwrap();
+    pub fn read_sheet_by_name(&mut self, sheet_name: &str) -> Result<&mut Self, XlsxError> {
+        let index = self
+            .find_sheet_index_by_name(sheet_name)
+            .ok_or_else(|| XlsxError::SheetNotFound(sheet_name.to_string()))?;
         self.read_sheet(index)
     }
 
@@ -423,11 +679,22 @@ impl Spreadsheet {
         let shared_string_table = self.get_shared_string_table();
         let stylesheet = self.get_stylesheet().clone();
         self.work_sheet_collection.get_mut(*index).map(|v| {
-            raw_to_deserialize_by_worksheet(v, &shared_string_table, &stylesheet);
+            raw_to_deserialize_by_worksheet(v, &shared_string_table, &stylesheet).unwrap();
             v
         })
     }
 
+    /// Get Work Sheet in mutable.
+    /// # Arguments
+    /// * `index` - sheet index
+    /// # Return value
+    /// * `Result<&mut Worksheet, XlsxError>` - Err when the worksheet does not exist or is malformed.
+    #[inline]
+    pub fn try_get_sheet_mut(&mut self, index: &usize) -> Result<&mut Worksheet, XlsxError> {
+        self.read_sheet(*index)?;
+        Ok(&mut self.work_sheet_collection[*index])
+    }
+
     /// Get Work Sheet in mutable.
     /// # Arguments
     /// * `sheet_name` - sheet name
@@ -439,6 +706,22 @@ impl Spreadsheet {
             .and_then(move |index| self.get_sheet_mut(&index))
     }
 
+    /// Get Work Sheet in mutable.
+    /// # Arguments
+    /// * `sheet_name` - sheet name
+    /// # Return value
+    /// * `Result<&mut Worksheet, XlsxError>` - Err when the worksheet does not exist or is malformed.
+    #[inline]
+    pub fn try_get_sheet_by_name_mut(
+        &mut self,
+        sheet_name: &str,
+    ) -> Result<&mut Worksheet, XlsxError> {
+        let index = self
+            .find_sheet_index_by_name(sheet_name)
+            .ok_or_else(|| XlsxError::SheetNotFound(sheet_name.to_string()))?;
+        self.try_get_sheet_mut(&index)
+    }
+
     #[inline]
     pub fn set_active_sheet(&mut self, index: u32) -> &mut Self {
         self.get_workbook_view_mut().set_active_tab(index);
@@ -469,9 +752,11 @@ impl Spreadsheet {
     /// # Return value
     /// * `Result<&mut Worksheet, &'static str>` - OK:added work sheet. Err:Error.
     #[inline]
-    pub fn add_sheet(&mut self, value: Worksheet) -> Result<&mut Worksheet, &'static str> {
+    pub fn add_sheet(&mut self, mut value: Worksheet) -> Result<&mut Worksheet, &'static str> {
         let title = value.get_name();
         Spreadsheet::check_sheet_name(self, title)?;
+        value.set_date1904(self.date1904);
+        value.set_default_font(self.stylesheet.get_default_font());
         self.work_sheet_collection.push(value);
         Ok(self.work_sheet_collection.last_mut().unwrap())
     }
@@ -540,6 +825,8 @@ impl Spreadsheet {
         worksheet
             .get_sheet_format_properties_mut()
             .set_defalut_value();
+        worksheet.set_date1904(self.date1904);
+        worksheet.set_default_font(self.stylesheet.get_default_font());
         self.work_sheet_collection.push(worksheet);
         self.work_sheet_collection.last_mut().unwrap()
     }
@@ -733,7 +1020,7 @@ impl AdjustmentCoordinateWithSheet for Spreadsheet {
         root_row_num: &u32,
         offset_row_num: &u32,
     ) {
-        self.read_sheet_collection();
+        self.read_sheet_collection().unwrap();
         for worksheet in &mut self.work_sheet_collection {
             worksheet.adjustment_insert_coordinate(
                 root_col_num,
@@ -759,7 +1046,7 @@ impl AdjustmentCoordinateWithSheet for Spreadsheet {
         root_row_num: &u32,
         offset_row_num: &u32,
     ) {
-        self.read_sheet_collection();
+        self.read_sheet_collection().unwrap();
         for worksheet in &mut self.work_sheet_collection {
             worksheet.adjustment_remove_coordinate(
                 root_col_num,
@@ -1,3 +1,4 @@
+use crate::XlsxError;
 #[derive(Clone, Default, Debug, Eq, Ord, PartialEq, PartialOrd)]
 pub struct StringValue {
     value: Option<Box<str>>,
@@ -25,6 +26,15 @@ impl StringValue {
         self.set_value(value.into())
     }
 
+    #[inline]
+    pub(crate) fn try_set_value_string<S: Into<String>>(
+        &mut self,
+        value: S,
+        _name: &str,
+    ) -> Result<&mut StringValue, XlsxError> {
+        Ok(self.set_value_string(value))
+    }
+
     #[inline]
     pub(crate) fn remove_value(&mut self) -> &mut Self {
         self.value = None;
@@ -7,6 +7,7 @@ use super::CellStyles;
 use super::Colors;
 use super::DifferentialFormats;
 use super::Fills;
+use super::Font;
 use super::Fonts;
 use super::NumberingFormats;
 use super::Protection;
@@ -14,6 +15,7 @@ use super::Style;
 use crate::helper::const_str::*;
 use crate::reader::driver::*;
 use crate::writer::driver::*;
+use crate::XlsxError;
 use quick_xml::events::{BytesStart, Event};
 use quick_xml::Reader;
 use quick_xml::Writer;
@@ -52,7 +54,7 @@ impl Stylesheet {
     }
 
     #[inline]
-    pub(crate) fn _get_fonts(&self) -> &Fonts {
+    pub(crate) fn get_fonts(&self) -> &Fonts {
         &self.fonts
     }
 
@@ -61,6 +63,16 @@ impl Stylesheet {
         &mut self.fonts
     }
 
+    /// The font of the Normal style, the unit of the column widths.
+    #[inline]
+    pub(crate) fn get_default_font(&self) -> Font {
+        self.fonts
+            .get_font()
+            .first()
+            .cloned()
+            .unwrap_or_else(Font::get_default_value)
+    }
+
     #[inline]
     pub(crate) fn _set_fonts(&mut self, value: Fonts) -> &mut Self {
         self.fonts = value;
@@ -180,8 +192,13 @@ impl Stylesheet {
     }
 
     #[inline]
-    pub(crate) fn get_style(&self, id: usize) -> Style {
-        self.maked_style_list.get(id).unwrap().clone()
+    pub(crate) fn get_style(&self, id: usize) -> Option<Style> {
+        self.maked_style_list.get(id).cloned()
+    }
+
+    #[inline]
+    pub(crate) fn get_maked_style_list(&self) -> &[Style] {
+        &self.maked_style_list
     }
 
     pub(crate) fn make_style(&mut self) -> &mut Self {
@@ -373,7 +390,7 @@ impl Stylesheet {
         &mut self,
         reader: &mut Reader<R>,
         _e: &BytesStart,
-    ) {
+    ) -> Result<(), XlsxError> {
         self.numbering_formats.get_build_in_formats();
 
         xml_read_loop!(
@@ -381,41 +398,41 @@ impl Stylesheet {
             Event::Start(ref e) => {
                 match e.name().into_inner() {
                     b"numFmts" => {
-                        self.numbering_formats.set_attributes(reader, e);
+                        self.numbering_formats.set_attributes(reader, e)?;
                     }
                     b"fonts" => {
-                        self.fonts.set_attributes(reader, e);
+                        self.fonts.set_attributes(reader, e)?;
                     }
                     b"fills" => {
-                        self.fills.set_attributes(reader, e);
+                        self.fills.set_attributes(reader, e)?;
                     }
                     b"borders" => {
-                        self.borders.set_attributes(reader, e);
+                        self.borders.set_attributes(reader, e)?;
                     }
                     b"cellStyleXfs" => {
-                        self.cell_style_formats.set_attributes(reader, e);
+                        self.cell_style_formats.set_attributes(reader, e)?;
                     }
                     b"cellXfs" => {
-                        self.cell_formats.set_attributes(reader, e);
+                        self.cell_formats.set_attributes(reader, e)?;
                     }
                     b"cellStyles" => {
-                        self.cell_styles.set_attributes(reader, e);
+                        self.cell_styles.set_attributes(reader, e)?;
                     }
                     b"dxfs" => {
-                        self.differential_formats.set_attributes(reader, e);
+                        self.differential_formats.set_attributes(reader, e)?;
                     }
                     b"colors" => {
-                        self.colors.set_attributes(reader, e);
+                        self.colors.set_attributes(reader, e)?;
                     }
                     _ => (),
                 }
             },
             Event::End(ref e) => {
                 if e.name().into_inner() == b"styleSheet" {
-                    return
+                    return Ok(())
                 }
             },
-            Event::Eof => panic!("Error: Could not find {} end element", "styleSheet")
+            Event::Eof => return Err(end_element_error(reader, "styleSheet"))
         );
     }
 
@@ -1,4 +1,5 @@
 // tabColor
+use crate::XlsxError;
 use super::DoubleValue;
 use super::StringValue;
 use super::Theme;
@@ -187,29 +188,12 @@ impl TabColor {
         &mut self,
         _reader: &mut Reader<R>,
         e: &BytesStart,
-    ) {
-        for a in e.attributes().with_checks(false) {
-            match a {
-                Ok(ref attr) if attr.key.0 == b"indexed" => {
-                    self.indexed
-                        .set_value_string(get_attribute_value(attr).unwrap());
-                }
-                Ok(ref attr) if attr.key.0 == b"theme" => {
-                    self.theme_index
-                        .set_value_string(get_attribute_value(attr).unwrap());
-                }
-                Ok(ref attr) if attr.key.0 == b"rgb" => {
-                    self.argb
-                        .set_value_string(get_attribute_value(attr).unwrap());
-                }
-                Ok(ref attr) if attr.key.0 == b"tint" => {
-                    self.tint
-                        .set_value_string(get_attribute_value(attr).unwrap());
-                }
-                Ok(_) => {}
-                Err(_) => {}
-            }
-        }
+    ) -> Result<(), XlsxError> {
+        set_string_from_xml!(self, e, indexed, "indexed");
+        set_string_from_xml!(self, e, theme_index, "theme");
+        set_string_from_xml!(self, e, argb, "rgb");
+        set_string_from_xml!(self, e, tint, "tint");
+        Ok(())
     }
 
     pub(crate) fn write_to(&self, writer: &mut Writer<Cursor<Vec<u8>>>) {
@@ -9,6 +9,7 @@ use super::{
     coordinate::*, BooleanValue, EnumValue, StringValue, TotalsRowFunctionValues, UInt32Value,
 };
 use crate::helper::coordinate::*;
+use crate::XlsxError;
 use thin_vec::ThinVec;
 //use reader::driver::*;
 
@@ -138,8 +139,10 @@ impl Table {
     }
 
     #[inline]
-    pub(crate) fn set_totals_row_shown_str(&mut self, value: &str) {
-        self.totals_row_shown.set_value_string(value);
+    pub(crate) fn set_totals_row_shown_str(&mut self, value: &str) -> Result<(), XlsxError> {
+        self.totals_row_shown
+            .try_set_value_string(value, "totalsRowShown")?;
+        Ok(())
     }
 
     #[inline]
@@ -163,8 +166,10 @@ impl Table {
     }
 
     #[inline]
-    pub(crate) fn set_totals_row_count_str(&mut self, value: &str) {
-        self.totals_row_count.set_value_string(value);
+    pub(crate) fn set_totals_row_count_str(&mut self, value: &str) -> Result<(), XlsxError> {
+        self.totals_row_count
+            .try_set_value_string(value, "totalsRowCount")?;
+        Ok(())
     }
 
     #[inline]
@@ -254,8 +259,10 @@ impl TableColumn {
     }
 
     #[inline]
-    pub(crate) fn set_totals_row_function_str(&mut self, value: &str) {
-        self.totals_row_function.set_value_string(value);
+    pub(crate) fn set_totals_row_function_str(&mut self, value: &str) -> Result<(), XlsxError> {
+        self.totals_row_function
+            .try_set_value_string(value, "totalsRowFunction")?;
+        Ok(())
     }
 
     #[inline]
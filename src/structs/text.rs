@@ -1,6 +1,7 @@
 // t
 use crate::reader::driver::*;
 use crate::writer::driver::*;
+use crate::XlsxError;
 use md5::Digest;
 use quick_xml::events::{BytesStart, Event};
 use quick_xml::Reader;
@@ -33,18 +34,18 @@ impl Text {
         &mut self,
         reader: &mut Reader<R>,
         _e: &BytesStart,
-    ) {
+    ) -> Result<(), XlsxError> {
         xml_read_loop!(
             reader,
             Event::Text(e) => {
-                self.set_value(e.unescape().unwrap());
+                self.set_value(e.unescape()?);
             },
             Event::End(ref e) => {
                 if e.name().0 == b"t" {
-                    return
+                    return Ok(())
                 }
             },
-            Event::Eof => panic!("Error: Could not find {} end element", "t")
+            Event::Eof => return Err(end_element_error(reader, "t"))
         );
     }
 
@@ -3,6 +3,7 @@ use super::Font;
 use super::Text;
 use crate::reader::driver::*;
 use crate::writer::driver::*;
+use crate::XlsxError;
 use md5::Digest;
 use quick_xml::events::{BytesStart, Event};
 use quick_xml::Reader;
@@ -89,19 +90,19 @@ impl TextElement {
         &mut self,
         reader: &mut Reader<R>,
         _e: &BytesStart,
-    ) {
+    ) -> Result<(), XlsxError> {
         xml_read_loop!(
             reader,
             Event::Start(ref e) => {
                 match e.name().into_inner() {
                     b"t" => {
                         let mut obj = Text::default();
-                        obj.set_attributes(reader, e);
+                        obj.set_attributes(reader, e)?;
                         self.text = obj;
                     }
                     b"rPr" => {
                         let mut obj = Font::default();
-                        obj.set_attributes(reader, e);
+                        obj.set_attributes(reader, e)?;
                         self.set_run_properties(obj);
                     }
                     _ => (),
@@ -109,10 +110,10 @@ impl TextElement {
             },
             Event::End(ref e) => {
                 if e.name().into_inner() == b"r" {
-                    return
+                    return Ok(())
                 }
             },
-            Event::Eof => panic!("Error: Could not find {} end element", "r")
+            Event::Eof => return Err(end_element_error(reader, "r"))
         );
     }
 
@@ -1,5 +1,7 @@
 // to
+use crate::reader::driver::*;
 use crate::writer::driver::*;
+use crate::XlsxError;
 use quick_xml::events::{BytesStart, Event};
 use quick_xml::Reader;
 use quick_xml::Writer;
@@ -81,30 +83,30 @@ impl ToMarker {
         &mut self,
         reader: &mut Reader<R>,
         _e: &BytesStart,
-    ) {
+    ) -> Result<(), XlsxError> {
         let mut string_value: String = String::new();
         let mut buf = Vec::new();
         loop {
             match reader.read_event_into(&mut buf) {
-                Ok(Event::Text(e)) => string_value = e.unescape().unwrap().to_string(),
+                Ok(Event::Text(e)) => string_value = e.unescape()?.to_string(),
                 Ok(Event::End(ref e)) => match e.name().0 {
                     b"xdr:col" => {
-                        self.col = string_value.parse::<usize>().unwrap();
+                        self.col = parse_value("xdr:col", &string_value)?;
                     }
                     b"xdr:colOff" => {
-                        self.col_off = string_value.parse::<usize>().unwrap();
+                        self.col_off = parse_value("xdr:colOff", &string_value)?;
                     }
                     b"xdr:row" => {
-                        self.row = string_value.parse::<usize>().unwrap();
+                        self.row = parse_value("xdr:row", &string_value)?;
                     }
                     b"xdr:rowOff" => {
-                        self.row_off = string_value.parse::<usize>().unwrap();
+                        self.row_off = parse_value("xdr:rowOff", &string_value)?;
                     }
-                    b"to" => return,
+                    b"to" => return Ok(()),
                     _ => (),
                 },
-                Ok(Event::Eof) => panic!("Error: Could not find {} end element", "to"),
-                Err(e) => panic!("Error at position {}: {:?}", reader.buffer_position(), e),
+                Ok(Event::Eof) => return Err(end_element_error(reader, "to")),
+                Err(e) => return Err(xml_error(reader, e)),
                 _ => (),
             }
             buf.clear();
@@ -0,0 +1,105 @@
+// top10
+use super::BooleanValue;
+use super::DoubleValue;
+use crate::reader::driver::*;
+use crate::writer::driver::*;
+use crate::XlsxError;
+use quick_xml::events::BytesStart;
+use quick_xml::Reader;
+use quick_xml::Writer;
+use std::io::Cursor;
+
+#[derive(Clone, Default, Debug)]
+pub struct Top10 {
+    top: BooleanValue,
+    percent: BooleanValue,
+    val: DoubleValue,
+    filter_val: DoubleValue,
+}
+
+impl Top10 {
+    /// Top (true, the default) or bottom items.
+    #[inline]
+    pub fn get_top(&self) -> &bool {
+        if self.top.has_value() {
+            return self.top.get_value();
+        }
+        &true
+    }
+
+    #[inline]
+    pub fn set_top(&mut self, value: bool) -> &mut Self {
+        self.top.set_value(value);
+        self
+    }
+
+    /// `val` is a percentage of the items instead of a count.
+    #[inline]
+    pub fn get_percent(&self) -> &bool {
+        self.percent.get_value()
+    }
+
+    #[inline]
+    pub fn set_percent(&mut self, value: bool) -> &mut Self {
+        self.percent.set_value(value);
+        self
+    }
+
+    #[inline]
+    pub fn get_val(&self) -> &f64 {
+        self.val.get_value()
+    }
+
+    #[inline]
+    pub fn set_val(&mut self, value: f64) -> &mut Self {
+        self.val.set_value(value);
+        self
+    }
+
+    /// The threshold value calculated by the application that last applied the filter.
+    #[inline]
+    pub fn get_filter_val(&self) -> Option<&f64> {
+        if self.filter_val.has_value() {
+            return Some(self.filter_val.get_value());
+        }
+        None
+    }
+
+    #[inline]
+    pub fn set_filter_val(&mut self, value: f64) -> &mut Self {
+        self.filter_val.set_value(value);
+        self
+    }
+
+    #[inline]
+    pub(crate) fn set_attributes<R: std::io::BufRead>(
+        &mut self,
+        _reader: &mut Reader<R>,
+        e: &BytesStart,
+    ) -> Result<(), XlsxError> {
+        set_string_from_xml!(self, e, top, "top");
+        set_string_from_xml!(self, e, percent, "percent");
+        set_string_from_xml!(self, e, val, "val");
+        set_string_from_xml!(self, e, filter_val, "filterVal");
+        Ok(())
+    }
+
+    #[inline]
+    pub(crate) fn write_to(&self, writer: &mut Writer<Cursor<Vec<u8>>>) {
+        // top10
+        let mut attributes: Vec<(&str, &str)> = Vec::new();
+        if !*self.get_top() {
+            attributes.push(("top", self.top.get_value_string()));
+        }
+        if *self.percent.get_value() {
+            attributes.push(("percent", self.percent.get_value_string()));
+        }
+        let val = self.val.get_value_string();
+        attributes.push(("val", &val));
+        let filter_val = self.filter_val.get_value_string();
+        if self.filter_val.has_value() {
+            attributes.push(("filterVal", &filter_val));
+        }
+        write_start_tag(writer, "top10", attributes, true);
+    }
+}
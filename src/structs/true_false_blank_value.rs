@@ -1,3 +1,4 @@
+use crate::XlsxError;
 #[derive(Clone, Default, Debug)]
 pub struct TrueFalseBlankValue {
     value: Option<bool>,
@@ -31,6 +32,15 @@ impl TrueFalseBlankValue {
         self.set_value(!(value.eq_ignore_ascii_case("f") || value.eq_ignore_ascii_case("false")))
     }
 
+    #[inline]
+    pub(crate) fn try_set_value_string<S: Into<String>>(
+        &mut self,
+        value: S,
+        _name: &str,
+    ) -> Result<&mut Self, XlsxError> {
+        Ok(self.set_value_string(value))
+    }
+
     #[inline]
     pub(crate) fn has_value(&self) -> bool {
         self.value.is_some()
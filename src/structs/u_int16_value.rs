@@ -1,3 +1,5 @@
+use crate::reader::driver::invalid_attribute_error;
+use crate::XlsxError;
 #[derive(Clone, Default, Debug)]
 pub struct UInt16Value {
     value: Option<u16>,
@@ -20,8 +22,16 @@ impl UInt16Value {
     }
 
     #[inline]
-    pub(crate) fn set_value_string<S: Into<String>>(&mut self, value: S) -> &mut UInt16Value {
-        self.set_value(value.into().parse::<u16>().unwrap())
+    pub(crate) fn try_set_value_string<S: Into<String>>(
+        &mut self,
+        value: S,
+        name: &str,
+    ) -> Result<&mut UInt16Value, XlsxError> {
+        let value = value.into();
+        match value.parse::<u16>() {
+            Ok(v) => Ok(self.set_value(v)),
+            Err(_) => Err(invalid_attribute_error(name, value)),
+        }
     }
 
     #[inline]
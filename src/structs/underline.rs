@@ -4,6 +4,7 @@ use super::EnumValue;
 use super::UnderlineValues;
 use crate::reader::driver::*;
 use crate::writer::driver::*;
+use crate::XlsxError;
 use quick_xml::events::BytesStart;
 use quick_xml::Reader;
 use quick_xml::Writer;
@@ -34,9 +35,10 @@ impl Underline {
         &mut self,
         _reader: &mut Reader<R>,
         e: &BytesStart,
-    ) {
+    ) -> Result<(), XlsxError> {
         self.set_val(UnderlineValues::default());
-        set_string_from_xml!(self, e, val, "val")
+        set_string_from_xml!(self, e, val, "val");
+        Ok(())
     }
 
     pub(crate) fn write_to(&self, writer: &mut Writer<Cursor<Vec<u8>>>) {
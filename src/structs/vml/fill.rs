@@ -4,6 +4,7 @@ use crate::structs::MediaObject;
 use crate::structs::StringValue;
 use crate::structs::TrueFalseValue;
 use crate::writer::driver::*;
+use crate::XlsxError;
 use quick_xml::events::BytesStart;
 use quick_xml::Reader;
 use quick_xml::Writer;
@@ -85,22 +86,23 @@ impl Fill {
         _reader: &mut Reader<R>,
         e: &BytesStart,
         drawing_relationships: Option<&RawRelationships>,
-    ) {
+    ) -> Result<(), XlsxError> {
         set_string_from_xml!(self, e, color, "color");
         set_string_from_xml!(self, e, color_2, "color2");
         set_string_from_xml!(self, e, on, "on");
         set_string_from_xml!(self, e, focus_size, "focussize");
 
-        if let Some(relid) = get_attribute(e, b"o:relid") {
+        if let Some(relid) = get_attribute(e, b"o:relid")? {
             if let Some(rel) = drawing_relationships {
-                let relationship = rel.get_relationship_by_rid(&relid);
+                let relationship = rel.get_relationship_by_rid(&relid)?;
                 let mut obj = MediaObject::default();
-                obj.set_image_title(get_attribute(e, b"o:title").unwrap());
+                obj.set_image_title(get_required_attribute(e, b"o:title")?);
                 obj.set_image_name(relationship.get_raw_file().get_file_name());
                 obj.set_image_data(relationship.get_raw_file().get_file_data());
                 self.set_image(obj);
             }
         }
+        Ok(())
     }
 
     #[inline]
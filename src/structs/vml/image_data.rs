@@ -3,6 +3,7 @@ use crate::structs::raw::RawRelationships;
 use crate::structs::MediaObject;
 use crate::structs::StringValue;
 use crate::writer::driver::*;
+use crate::XlsxError;
 use quick_xml::events::BytesStart;
 use quick_xml::Reader;
 use quick_xml::Writer;
@@ -45,10 +46,10 @@ impl ImageData {
         _reader: &mut Reader<R>,
         e: &BytesStart,
         drawing_relationships: Option<&RawRelationships>,
-    ) {
-        if let Some(relid) = get_attribute(e, b"o:relid") {
+    ) -> Result<(), XlsxError> {
+        if let Some(relid) = get_attribute(e, b"o:relid")? {
             if let Some(rel) = drawing_relationships {
-                let relationship = rel.get_relationship_by_rid(&relid);
+                let relationship = rel.get_relationship_by_rid(&relid)?;
                 self.get_image_mut()
                     .set_image_name(relationship.get_raw_file().get_file_name());
                 self.get_image_mut()
@@ -57,6 +58,7 @@ impl ImageData {
         }
 
         set_string_from_xml!(self, e, title, "o:title");
+        Ok(())
     }
 
     pub(crate) fn write_to(
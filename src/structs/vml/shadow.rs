@@ -2,6 +2,7 @@ use crate::reader::driver::*;
 use crate::structs::StringValue;
 use crate::structs::TrueFalseValue;
 use crate::writer::driver::*;
+use crate::XlsxError;
 use quick_xml::events::BytesStart;
 use quick_xml::Reader;
 use quick_xml::Writer;
@@ -45,10 +46,11 @@ impl Shadow {
         &mut self,
         _reader: &mut Reader<R>,
         e: &BytesStart,
-    ) {
+    ) -> Result<(), XlsxError> {
         set_string_from_xml!(self, e, on, "on");
         set_string_from_xml!(self, e, color, "color");
         set_string_from_xml!(self, e, obscured, "obscured");
+        Ok(())
     }
 
     pub(crate) fn write_to(&self, writer: &mut Writer<Cursor<Vec<u8>>>) {
@@ -14,6 +14,7 @@ use crate::structs::StringValue;
 use crate::structs::TrueFalseValue;
 use crate::traits::AdjustmentCoordinate;
 use crate::writer::driver::*;
+use crate::XlsxError;
 use quick_xml::events::{BytesStart, Event};
 use quick_xml::Reader;
 use quick_xml::Writer;
@@ -227,7 +228,7 @@ impl Shape {
         reader: &mut Reader<R>,
         e: &BytesStart,
         drawing_relationships: Option<&RawRelationships>,
-    ) {
+    ) -> Result<(), XlsxError> {
         set_string_from_xml!(self, e, r_type, "type");
         set_string_from_xml!(self, e, style, "style");
         set_string_from_xml!(self, e, filled, "filled");
@@ -245,27 +246,27 @@ impl Shape {
                 match e.name().into_inner() {
                 b"v:fill" => {
                     let mut obj = Fill::default();
-                    obj.set_attributes(reader, e, drawing_relationships);
+                    obj.set_attributes(reader, e, drawing_relationships)?;
                     self.set_fill(obj);
                 }
                 b"v:shadow" => {
                     let mut obj = Shadow::default();
-                    obj.set_attributes(reader, e);
+                    obj.set_attributes(reader, e)?;
                     self.set_shadow(obj);
                 }
                 b"v:path" => {
                     let mut obj = Path::default();
-                    obj.set_attributes(reader, e);
+                    obj.set_attributes(reader, e)?;
                     self.set_path(obj);
                 }
                 b"v:stroke" => {
                     let mut obj = Stroke::default();
-                    obj.set_attributes(reader, e);
+                    obj.set_attributes(reader, e)?;
                     self.set_stroke(obj);
                 }
                 b"v:imagedata" => {
                     let mut obj = ImageData::default();
-                    obj.set_attributes(reader, e, drawing_relationships);
+                    obj.set_attributes(reader, e, drawing_relationships)?;
                     self.set_image_data(obj);
                 }
                 _ => (),
@@ -275,12 +276,12 @@ impl Shape {
                 match e.name().into_inner() {
                 b"v:textbox" => {
                     let mut obj = TextBox::default();
-                    obj.set_attributes(reader, e);
+                    obj.set_attributes(reader, e)?;
                     self.set_text_box(obj);
                 }
                 b"x:ClientData" => {
                     let mut obj = ClientData::default();
-                    obj.set_attributes(reader, e);
+                    obj.set_attributes(reader, e)?;
                     self.set_client_data(obj);
                 }
                 _ => (),
@@ -288,10 +289,10 @@ impl Shape {
             },
             Event::End(ref e) => {
                 if  e.name().into_inner() == b"v:shape" {
-                    return
+                    return Ok(())
                 }
             },
-            Event::Eof => panic!("Error: Could not find {} end element", "v:shape")
+            Event::Eof => return Err(end_element_error(reader, "v:shape"))
         );
     }
 
@@ -2,6 +2,7 @@ use crate::helper::coordinate::*;
 use crate::reader::driver::*;
 use crate::traits::AdjustmentCoordinate;
 use crate::writer::driver::*;
+use crate::XlsxError;
 use quick_xml::events::{BytesStart, Event};
 use quick_xml::Reader;
 use quick_xml::Writer;
@@ -153,11 +154,11 @@ impl Anchor {
         &mut self,
         reader: &mut Reader<R>,
         _e: &BytesStart,
-    ) {
+    ) -> Result<(), XlsxError> {
         xml_read_loop!(
             reader,
             Event::Text(e) => {
-                let text = e.unescape().unwrap();
+                let text = e.unescape()?;
                 let split_str: Vec<&str> = text.split(',').collect();
                 self.set_left_column(Self::get_number(split_str.first()));
                 self.set_left_offset(Self::get_number(split_str.get(1)));
@@ -170,10 +171,10 @@ impl Anchor {
             },
             Event::End(ref e) => {
                 if e.name().0 == b"x:Anchor" {
-                    return
+                    return Ok(())
                 }
             },
-            Event::Eof => panic!("Error: Could not find {} end element", "x:Anchor")
+            Event::Eof => return Err(end_element_error(reader, "x:Anchor"))
         );
     }
 
@@ -13,6 +13,7 @@ use crate::structs::EnumValue;
 use crate::traits::AdjustmentCoordinate;
 use crate::traits::AdjustmentValue;
 use crate::writer::driver::*;
+use crate::XlsxError;
 use quick_xml::events::{BytesStart, Event};
 use quick_xml::Reader;
 use quick_xml::Writer;
@@ -193,7 +194,7 @@ impl ClientData {
         &mut self,
         reader: &mut Reader<R>,
         e: &BytesStart,
-    ) {
+    ) -> Result<(), XlsxError> {
         set_string_from_xml!(self, e, object_type, "ObjectType");
 
         xml_read_loop!(
@@ -202,27 +203,27 @@ impl ClientData {
                 match e.name().into_inner() {
                     b"x:MoveWithCells" => {
                         let mut obj = MoveWithCells::default();
-                        obj.set_attributes(reader, e, true);
+                        obj.set_attributes(reader, e, true)?;
                         self.set_move_with_cells(obj);
                     }
                     b"x:SizeWithCells" => {
                         let mut obj = ResizeWithCells::default();
-                        obj.set_attributes(reader, e, true);
+                        obj.set_attributes(reader, e, true)?;
                         self.set_resize_with_cells(obj);
                     }
                     b"x:AutoFill" => {
                         let mut obj = AutoFill::default();
-                        obj.set_attributes(reader, e, true);
+                        obj.set_attributes(reader, e, true)?;
                         self.set_auto_fill(obj);
                     }
                     b"x:Visible" => {
                         let mut obj = Visible::default();
-                        obj.set_attributes(reader, e, true);
+                        obj.set_attributes(reader, e, true)?;
                         self.set_visible(obj);
                     }
                     b"x:AutoPict" => {
                         let mut obj = AutoSizePicture::default();
-                        obj.set_attributes(reader, e, true);
+                        obj.set_attributes(reader, e, true)?;
                         self.set_auto_size_picture(obj);
                     }
                     _ => (),
@@ -232,47 +233,47 @@ impl ClientData {
                 match e.name().into_inner() {
                     b"x:Anchor" => {
                         let mut obj = Anchor::default();
-                        obj.set_attributes(reader, e);
+                        obj.set_attributes(reader, e)?;
                         self.set_anchor(obj);
                     }
                     b"x:MoveWithCells" => {
                         let mut obj = MoveWithCells::default();
-                        obj.set_attributes(reader, e, false);
+                        obj.set_attributes(reader, e, false)?;
                         self.set_move_with_cells(obj);
                     }
                     b"x:SizeWithCells" => {
                         let mut obj = ResizeWithCells::default();
-                        obj.set_attributes(reader, e, false);
+                        obj.set_attributes(reader, e, false)?;
                         self.set_resize_with_cells(obj);
                     }
                     b"x:AutoFill" => {
                         let mut obj = AutoFill::default();
-                        obj.set_attributes(reader, e, false);
+                        obj.set_attributes(reader, e, false)?;
                         self.set_auto_fill(obj);
                     }
                     b"x:Row" => {
                         let mut obj = CommentRowTarget::default();
-                        obj.set_attributes(reader, e);
+                        obj.set_attributes(reader, e)?;
                         self.set_comment_row_target(obj);
                     }
                     b"x:Column" => {
                         let mut obj = CommentColumnTarget::default();
-                        obj.set_attributes(reader, e);
+                        obj.set_attributes(reader, e)?;
                         self.set_comment_column_target(obj);
                     }
                     b"x:CF" => {
                         let mut obj = ClipboardFormat::default();
-                        obj.set_attributes(reader, e);
+                        obj.set_attributes(reader, e)?;
                         self.set_clipboard_format(obj);
                     }
                     b"x:Visible" => {
                         let mut obj = Visible::default();
-                        obj.set_attributes(reader, e, false);
+                        obj.set_attributes(reader, e, false)?;
                         self.set_visible(obj);
                     }
                     b"x:AutoPict" => {
                         let mut obj = AutoSizePicture::default();
-                        obj.set_attributes(reader, e, false);
+                        obj.set_attributes(reader, e, false)?;
                         self.set_auto_size_picture(obj);
                     }
                     _ => (),
@@ -280,10 +281,10 @@ impl ClientData {
             },
             Event::End(ref e) => {
                 if e.name().into_inner() == b"x:ClientData" {
-                    return
+                    return Ok(())
                 }
             },
-            Event::Eof => panic!("Error: Could not find {} end element", "x:ClientData")
+            Event::Eof => return Err(end_element_error(reader, "x:ClientData"))
         );
     }
 
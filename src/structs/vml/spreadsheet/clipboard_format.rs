@@ -2,6 +2,7 @@ use super::ClipboardFormatValues;
 use crate::reader::driver::*;
 use crate::structs::EnumValue;
 use crate::writer::driver::*;
+use crate::XlsxError;
 use quick_xml::events::{BytesStart, Event};
 use quick_xml::Reader;
 use quick_xml::Writer;
@@ -29,18 +30,18 @@ impl ClipboardFormat {
         &mut self,
         reader: &mut Reader<R>,
         _e: &BytesStart,
-    ) {
+    ) -> Result<(), XlsxError> {
         xml_read_loop!(
             reader,
             Event::Text(e) => {
-                self.value.set_value_string(e.unescape().unwrap());
+                self.value.try_set_value_string(e.unescape()?, "x:CF")?;
             },
             Event::End(ref e) => {
                 if e.name().0 == b"x:CF" {
-                    return
+                    return Ok(())
                 }
             },
-            Event::Eof => panic!("Error: Could not find {} end element", "x:CF")
+            Event::Eof => return Err(end_element_error(reader, "x:CF"))
         );
     }
 
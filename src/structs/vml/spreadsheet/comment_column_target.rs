@@ -3,6 +3,7 @@ use crate::reader::driver::*;
 use crate::structs::UInt32Value;
 use crate::traits::AdjustmentValue;
 use crate::writer::driver::*;
+use crate::XlsxError;
 use quick_xml::events::{BytesStart, Event};
 use quick_xml::Reader;
 use quick_xml::Writer;
@@ -30,18 +31,18 @@ impl CommentColumnTarget {
         &mut self,
         reader: &mut Reader<R>,
         _e: &BytesStart,
-    ) {
+    ) -> Result<(), XlsxError> {
         xml_read_loop!(
             reader,
             Event::Text(e) => {
-                self.value.set_value_string(e.unescape().unwrap());
+                self.value.try_set_value_string(e.unescape()?, "x:Column")?;
             },
             Event::End(ref e) => {
                 if e.name().0 == b"x:Column" {
-                    return
+                    return Ok(())
                 }
             },
-            Event::Eof => panic!("Error: Could not find {} end element", "x:Column")
+            Event::Eof => return Err(end_element_error(reader, "x:Column"))
         );
     }
 
@@ -3,6 +3,7 @@ use crate::reader::driver::*;
 use crate::structs::UInt32Value;
 use crate::traits::AdjustmentValue;
 use crate::writer::driver::*;
+use crate::XlsxError;
 use quick_xml::events::{BytesStart, Event};
 use quick_xml::Reader;
 use quick_xml::Writer;
@@ -46,18 +47,18 @@ impl CommentRowTarget {
         &mut self,
         reader: &mut Reader<R>,
         _e: &BytesStart,
-    ) {
+    ) -> Result<(), XlsxError> {
         xml_read_loop!(
             reader,
             Event::Text(e) => {
-                self.value.set_value_string(e.unescape().unwrap());
+                self.value.try_set_value_string(e.unescape()?, "x:Row")?;
             },
             Event::End(ref e) => {
                 if e.name().0 == b"x:Row" {
-                    return
+                    return Ok(())
                 }
             },
-            Event::Eof => panic!("Error: Could not find {} end element", "x:Row")
+            Event::Eof => return Err(end_element_error(reader, "x:Row"))
         );
     }
 
@@ -1,6 +1,7 @@
 use crate::reader::driver::*;
 use crate::structs::TrueFalseBlankValue;
 use crate::writer::driver::*;
+use crate::XlsxError;
 use quick_xml::events::{BytesStart, Event};
 use quick_xml::Reader;
 use quick_xml::Writer;
@@ -29,22 +30,22 @@ impl ResizeWithCells {
         reader: &mut Reader<R>,
         _e: &BytesStart,
         empty_flag: bool,
-    ) {
+    ) -> Result<(), XlsxError> {
         if empty_flag {
-            return;
+            return Ok(());
         }
 
         xml_read_loop!(
             reader,
             Event::Text(e) => {
-                self.value.set_value_string(e.unescape().unwrap());
+                self.value.set_value_string(e.unescape()?);
             },
             Event::End(ref e) => {
                 if e.name().0 == b"x:SizeWithCells" {
-                    return
+                    return Ok(())
                 }
             },
-            Event::Eof => panic!("Error: Could not find {} end element", "x:SizeWithCells")
+            Event::Eof => return Err(end_element_error(reader, "x:SizeWithCells"))
         );
     }
 
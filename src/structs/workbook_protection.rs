@@ -5,6 +5,7 @@ use super::UInt32Value;
 use crate::helper::crypt::*;
 use crate::reader::driver::*;
 use crate::writer::driver::*;
+use crate::XlsxError;
 use md5::Digest;
 use quick_xml::events::{BytesStart, Event};
 use quick_xml::Reader;
@@ -199,7 +200,7 @@ impl WorkbookProtection {
         &mut self,
         _reader: &mut Reader<R>,
         e: &BytesStart,
-    ) {
+    ) -> Result<(), XlsxError> {
         set_string_from_xml!(self, e, workbook_algorithm_name, "workbookAlgorithmName");
         set_string_from_xml!(self, e, workbook_hash_value, "workbookHashValue");
         set_string_from_xml!(self, e, workbook_salt_value, "workbookSaltValue");
@@ -213,6 +214,7 @@ impl WorkbookProtection {
         set_string_from_xml!(self, e, lock_revision, "lockRevision");
         set_string_from_xml!(self, e, lock_structure, "lockStructure");
         set_string_from_xml!(self, e, lock_windows, "lockWindows");
+        Ok(())
     }
 
     pub(crate) fn write_to(&self, writer: &mut Writer<Cursor<Vec<u8>>>) {
@@ -1,6 +1,7 @@
 use super::UInt32Value;
 use crate::reader::driver::*;
 use crate::writer::driver::*;
+use crate::XlsxError;
 use quick_xml::events::BytesStart;
 use quick_xml::Reader;
 use quick_xml::Writer;
@@ -28,8 +29,9 @@ impl WorkbookView {
         &mut self,
         _reader: &mut Reader<R>,
         e: &BytesStart,
-    ) {
-        set_string_from_xml!(self, e, active_tab, "activeTab")
+    ) -> Result<(), XlsxError> {
+        set_string_from_xml!(self, e, active_tab, "activeTab");
+        Ok(())
     }
 
     pub(crate) fn write_to(&self, writer: &mut Writer<Cursor<Vec<u8>>>) {
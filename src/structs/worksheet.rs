@@ -1,30 +1,39 @@
+use crate::helper::calculation::*;
 use crate::helper::const_str::*;
 use crate::helper::coordinate::*;
+use crate::helper::font_metrics::*;
 use crate::helper::range::*;
 use crate::reader::xlsx::worksheet::*;
 use crate::structs::drawing::spreadsheet::WorksheetDrawing;
 use crate::structs::office2010::excel::DataValidations as DataValidations2010;
+use crate::structs::office2010::excel::SparklineGroup;
+use crate::structs::office2010::excel::SparklineGroups;
 use crate::structs::raw::RawWorksheet;
 use crate::structs::AutoFilter;
 use crate::structs::Cell;
+use crate::structs::CellRawValue;
 use crate::structs::CellValue;
 use crate::structs::Cells;
 use crate::structs::Chart;
+use crate::structs::ChartEx;
 use crate::structs::Color;
 use crate::structs::Column;
 use crate::structs::ColumnBreaks;
 use crate::structs::Columns;
 use crate::structs::Comment;
 use crate::structs::ConditionalFormatting;
+use crate::structs::ConditionalFormattingResult;
 use crate::structs::DataValidations;
 use crate::structs::DefinedName;
 use crate::structs::EnumValue;
+use crate::structs::Font;
 use crate::structs::HeaderFooter;
 use crate::structs::Hyperlink;
 use crate::structs::Image;
 use crate::structs::MediaObject;
 use crate::structs::MergeCells;
 use crate::structs::OleObjects;
+use crate::structs::OutlineProperties;
 use crate::structs::PageMargins;
 use crate::structs::PageSetup;
 use crate::structs::PivotTable;
@@ -38,6 +47,7 @@ use crate::structs::SheetFormatProperties;
 use crate::structs::SheetProtection;
 use crate::structs::SheetStateValues;
 use crate::structs::SheetViews;
+use crate::structs::SortState;
 use crate::structs::Style;
 use crate::structs::Stylesheet;
 use crate::structs::Table;
@@ -47,6 +57,7 @@ use crate::traits::AdjustmentCoordinateWith2Sheet;
 use crate::traits::AdjustmentCoordinateWithSheet;
 use crate::traits::AdjustmentValue;
 use crate::StringValue;
+use chrono::{NaiveDate, NaiveDateTime};
 use std::collections::HashMap;
 use thin_vec::ThinVec;
 
@@ -72,9 +83,11 @@ pub struct Worksheet {
     conditional_formatting_collection: ThinVec<ConditionalFormatting>,
     merge_cells: MergeCells,
     auto_filter: Option<AutoFilter>,
+    sort_state: Option<SortState>,
     comments: ThinVec<Comment>,
     active_cell: Box<str>,
     tab_color: Option<Color>,
+    outline_properties: Option<OutlineProperties>,
     code_name: StringValue,
     ole_objects: OleObjects,
     defined_names: ThinVec<DefinedName>,
@@ -85,8 +98,11 @@ pub struct Worksheet {
     pivot_tables: ThinVec<PivotTable>,
     data_validations: Option<DataValidations>,
     data_validations_2010: Option<DataValidations2010>,
+    sparkline_groups: SparklineGroups,
     sheet_format_properties: SheetFormatProperties,
     sheet_protection: Option<SheetProtection>,
+    date1904: bool,
+    default_font: Option<Box<Font>>,
 }
 
 impl Worksheet {
@@ -140,6 +156,65 @@ impl Worksheet {
         self.get_cell((col, row)).and_then(|v| v.get_value_number())
     }
 
+    /// Get the date and time of a cell shown with a date or time format,
+    /// in the date system of the workbook.
+    /// # Arguments
+    /// * `coordinate` - Specify the coordinates. ex) `"A1"` or `(1, 1)`
+    /// # Return value
+    /// * `Option<NaiveDateTime>` - None if the cell is not a number with a date format.
+    /// # Examples
+    /// ```
+    /// use chrono::NaiveDate;
+    /// let mut book = umya_spreadsheet::new_file();
+    /// book.set_date1904(true);
+    /// let worksheet = book.get_sheet_mut(&0).unwrap();
+    /// let value = NaiveDate::from_ymd_opt(2024, 1, 31).unwrap();
+    /// worksheet.set_value_date("A1", value);
+    /// assert_eq!(worksheet.get_value_number("A1"), Some(43860f64));
+    /// assert_eq!(worksheet.get_value_datetime("A1").map(|v| v.date()), Some(value));
+    /// ```
+    #[inline]
+    pub fn get_value_datetime<T>(&self, coordinate: T) -> Option<NaiveDateTime>
+    where
+        T: Into<CellCoordinates>,
+    {
+        let CellCoordinates { col, row } = coordinate.into();
+        self.get_cell((col, row))
+            .and_then(|v| v.get_value_datetime_crate(self.date1904))
+    }
+
+    /// Set a date and time in the date system of the workbook.
+    /// A date format is applied unless the cell already has one.
+    /// # Arguments
+    /// * `coordinate` - Specify the coordinates. ex) `"A1"` or `(1, 1)`
+    /// * `value` - date and time.
+    #[inline]
+    pub fn set_value_datetime<T>(&mut self, coordinate: T, value: NaiveDateTime) -> &mut Self
+    where
+        T: Into<CellCoordinates>,
+    {
+        let date1904 = self.date1904;
+        self.get_cell_mut(coordinate)
+            .set_value_datetime_crate(value, date1904);
+        self
+    }
+
+    /// Set a date in the date system of the workbook.
+    /// A date format is applied unless the cell already has one.
+    /// # Arguments
+    /// * `coordinate` - Specify the coordinates. ex) `"A1"` or `(1, 1)`
+    /// * `value` - date.
+    #[inline]
+    pub fn set_value_date<T>(&mut self, coordinate: T, value: NaiveDate) -> &mut Self
+    where
+        T: Into<CellCoordinates>,
+    {
+        let date1904 = self.date1904;
+        self.get_cell_mut(coordinate)
+            .set_value_date_crate(value, date1904);
+        self
+    }
+
     /// Get formatted value.
     /// # Arguments
     /// * `coordinate` - Specify the coordinates. ex) `"A1"` or `(1, 1)` or `(&1, &1)`
@@ -159,8 +234,43 @@ impl Worksheet {
         T: Into<CellCoordinates>,
     {
         let CellCoordinates { col, row } = coordinate.into();
-        self.cell_collection
-            .get_formatted_value_by_column_and_row(&col, &row)
+        match self.cell_collection.get((col, row)) {
+            Some(v) => v.get_formatted_value_crate(self.date1904),
+            None => "".into(),
+        }
+    }
+
+    /// Calculate the formula of a cell and store the result as its value.
+    /// References to other worksheets cannot be resolved from a single worksheet and
+    /// evaluate to `#REF!`; use `Spreadsheet::calculate()` for workbooks with cross-sheet formulas.
+    /// # Arguments
+    /// * `coordinate` - Specify the coordinates. ex) `"A1"` or `(1, 1)` or `(&1, &1)`
+    /// # Return value
+    /// * `&CellRawValue` - Calculated value of the specified cell.
+    /// # Examples
+    /// ```
+    /// let mut book = umya_spreadsheet::new_file();
+    /// let worksheet = book.get_sheet_mut(&0).unwrap();
+    /// worksheet.get_cell_mut("A1").set_value_number(2);
+    /// worksheet.get_cell_mut("A2").set_formula("A1*3");
+    /// let value = worksheet.calculate_cell("A2");
+    /// ```
+    pub fn calculate_cell<T>(&mut self, coordinate: T) -> &CellRawValue
+    where
+        T: Into<CellCoordinates>,
+    {
+        let CellCoordinates { col, row } = coordinate.into();
+        let result = {
+            let defined_names: ThinVec<DefinedName> = ThinVec::new();
+            let mut calculator = Calculator::new(vec![&*self], &defined_names);
+            calculator.calculate_cell(0, col, row).into_raw()
+        };
+        if self.get_cell((col, row)).map(|v| v.is_formula()) == Some(true) {
+            self.get_cell_mut((col, row))
+                .get_cell_value_mut()
+                .set_formula_result(result);
+        }
+        self.get_cell_value((col, row)).get_raw_value()
     }
 
     // ************************
@@ -531,6 +641,14 @@ impl Worksheet {
         &self.conditional_formatting_collection
     }
 
+    /// Get ConditionalFormatting list in mutable.
+    #[inline]
+    pub fn get_conditional_formatting_collection_mut(
+        &mut self,
+    ) -> &mut ThinVec<ConditionalFormatting> {
+        &mut self.conditional_formatting_collection
+    }
+
     /// Set ConditionalFormatting.
     /// # Arguments
     /// * `value` - ConditionalSet List (Vec)
@@ -550,6 +668,71 @@ impl Worksheet {
         self.conditional_formatting_collection.push(value);
     }
 
+    /// Evaluate the conditional formatting rules for a cell.
+    /// # Arguments
+    /// * `coordinate` - Specify the coordinates. ex) `"A1"` or `(1, 1)` or `(&1, &1)`
+    /// # Return value
+    /// * `ConditionalFormattingResult` - Effective style, color scale color, data bar and icon.
+    /// # Examples
+    /// ```
+    /// let book = umya_spreadsheet::new_file();
+    /// let worksheet = book.get_sheet(&0).unwrap();
+    /// let result = worksheet.get_conditional_formatting_result("A1");
+    /// let length = result.get_data_bar_length();
+    /// ```
+    #[inline]
+    pub fn get_conditional_formatting_result<T>(&self, coordinate: T) -> ConditionalFormattingResult
+    where
+        T: Into<CellCoordinates>,
+    {
+        let CellCoordinates { col, row } = coordinate.into();
+        crate::helper::conditional_formatting::evaluate(self, col, row)
+    }
+
+    /// Evaluate the conditional formatting rules for the cells of a range.
+    /// The values of each conditional formatting range are read once for all the cells,
+    /// so this is faster than calling [`Self::get_conditional_formatting_result`] for each cell.
+    /// # Arguments
+    /// * `range` - range. ex) "A1:C5"
+    /// # Return value
+    /// * `Vec<ConditionalFormattingResult>` - Results of the cells, row by row.
+    /// # Examples
+    /// ```
+    /// let book = umya_spreadsheet::new_file();
+    /// let worksheet = book.get_sheet(&0).unwrap();
+    /// let results = worksheet.get_conditional_formatting_result_by_range("A1:C5");
+    /// ```
+    pub fn get_conditional_formatting_result_by_range(
+        &self,
+        range: &str,
+    ) -> Vec<ConditionalFormattingResult> {
+        let mut evaluator = crate::helper::conditional_formatting::Evaluator::new(self);
+        get_coordinate_list(range)
+            .into_iter()
+            .map(|(col, row)| evaluator.evaluate(col, row))
+            .collect()
+    }
+
+    /// Get the style of a cell with the matching conditional formatting applied.
+    /// # Arguments
+    /// * `coordinate` - Specify the coordinates. ex) `"A1"` or `(1, 1)` or `(&1, &1)`
+    /// # Return value
+    /// * `Style` - Style as displayed by Excel.
+    /// # Examples
+    /// ```
+    /// let book = umya_spreadsheet::new_file();
+    /// let worksheet = book.get_sheet(&0).unwrap();
+    /// let style = worksheet.get_effective_style("A1");
+    /// ```
+    #[inline]
+    pub fn get_effective_style<T>(&self, coordinate: T) -> Style
+    where
+        T: Into<CellCoordinates>,
+    {
+        self.get_conditional_formatting_result(coordinate)
+            .into_style()
+    }
+
     // ************************
     // Hyperlink
     // ************************
@@ -651,12 +834,88 @@ impl Worksheet {
         self.auto_filter = Some(auto_filter);
     }
 
+    // Set Auto Filter object.
+    #[inline]
+    pub fn set_auto_filter_obj(&mut self, value: AutoFilter) {
+        self.auto_filter = Some(value);
+    }
+
     // Remove Auto Filter.
     #[inline]
     pub fn remove_auto_filter(&mut self) {
         self.auto_filter = None;
     }
 
+    /// Get the sort state of the worksheet.
+    /// This is the sort of a range outside of the auto filter,
+    /// the sort of the auto filter range is [`AutoFilter::get_sort_state`].
+    #[inline]
+    pub fn get_sort_state(&self) -> Option<&SortState> {
+        self.sort_state.as_ref()
+    }
+
+    /// Get the sort state of the worksheet in mutable.
+    #[inline]
+    pub fn get_sort_state_mut(&mut self) -> Option<&mut SortState> {
+        self.sort_state.as_mut()
+    }
+
+    /// Set the sort state of the worksheet.
+    /// # Arguments
+    /// * `value` - SortState
+    #[inline]
+    pub fn set_sort_state(&mut self, value: SortState) -> &mut Self {
+        self.sort_state = Some(value);
+        self
+    }
+
+    /// Remove the sort state of the worksheet.
+    #[inline]
+    pub fn remove_sort_state(&mut self) -> &mut Self {
+        self.sort_state = None;
+        self
+    }
+
+    /// Hide the rows of the auto filter range that do not match its filter columns
+    /// and show the rows that do.
+    /// Viewers that display the saved row state without re-filtering then show the filtered result.
+    /// # Examples
+    /// ```
+    /// use umya_spreadsheet::*;
+    /// let mut book = new_file();
+    /// let worksheet = book.get_sheet_mut(&0).unwrap();
+    /// worksheet.get_cell_mut("A1").set_value("price");
+    /// worksheet.get_cell_mut("A2").set_value_number(10);
+    /// worksheet.get_cell_mut("A3").set_value_number(20);
+    /// worksheet.set_auto_filter("A1:A3");
+    /// let mut condition = CustomFilter::default();
+    /// condition
+    ///     .set_operator(FilterOperatorValues::GreaterThan)
+    ///     .set_val("15");
+    /// let mut custom_filters = CustomFilters::default();
+    /// custom_filters.add_custom_filter(condition);
+    /// worksheet
+    ///     .get_auto_filter_mut()
+    ///     .unwrap()
+    ///     .get_filter_column_mut(0)
+    ///     .set_custom_filters(custom_filters);
+    /// worksheet.apply_auto_filter();
+    /// assert!(*worksheet.get_row_dimension(&2).unwrap().get_hidden());
+    /// ```
+    pub fn apply_auto_filter(&mut self) {
+        let row_visibility = match self.get_auto_filter() {
+            Some(v) => v.get_row_visibility(self),
+            None => return,
+        };
+        for (row_num, visible) in row_visibility {
+            if !visible {
+                self.get_row_dimension_mut(&row_num).set_hidden(true);
+            } else if self.get_row_dimension(&row_num).is_some() {
+                self.get_row_dimension_mut(&row_num).set_hidden(false);
+            }
+        }
+    }
+
     // ************************
     // Column Dimensions
     // ************************
@@ -677,8 +936,9 @@ impl Worksheet {
     pub fn calculation_auto_width(&mut self) -> &mut Self {
         let cells = self.get_cell_collection_crate().clone();
         let merge_cells = self.get_merge_cells_crate().clone();
+        let default_font = self.get_default_font();
         self.get_column_dimensions_crate_mut()
-            .calculation_auto_width(&cells, &merge_cells);
+            .calculation_auto_width(&cells, &merge_cells, &default_font);
         self
     }
 
@@ -779,6 +1039,147 @@ impl Worksheet {
         self.row_dimensions.get_row_dimension_mut(row)
     }
 
+    /// Calculation Auto Height.
+    /// Fits the rows set to auto height to their cells, wrapping text at the column widths.
+    /// # Examples
+    /// ```
+    /// let mut book = umya_spreadsheet::new_file();
+    /// let mut worksheet = book.get_sheet_mut(&0).unwrap();
+    /// worksheet
+    ///     .get_cell_mut("A1")
+    ///     .set_value("line 1\nline 2")
+    ///     .get_style_mut()
+    ///     .get_alignment_mut()
+    ///     .set_wrap_text(true);
+    /// worksheet.get_row_dimension_mut(&1).set_auto_height(true);
+    /// worksheet.calculation_auto_height();
+    /// assert_eq!(worksheet.get_row_dimension(&1).unwrap().get_height(), &30f64);
+    /// ```
+    #[inline]
+    pub fn calculation_auto_height(&mut self) -> &mut Self {
+        let default_font = self.get_default_font();
+        self.row_dimensions = self.get_auto_height_rows_crate(&default_font);
+        self
+    }
+
+    /// (This method is crate only.)
+    /// Row dimensions with the auto heights calculated.
+    pub(crate) fn get_auto_height_rows_crate(&self, default_font: &TextFont) -> Rows {
+        let cells = self.get_cell_collection_crate();
+        let merge_cells = self.get_merge_cells_crate();
+        let mut columns = self.get_column_dimensions_crate().clone();
+        columns.calculation_auto_width(cells, merge_cells, default_font);
+        let default_column_width = match *self
+            .get_sheet_format_properties()
+            .get_default_column_width()
+        {
+            v if v > 0f64 => v,
+            _ => get_default_column_width(get_max_digit_width(default_font)),
+        };
+        let mut rows = self.row_dimensions.clone();
+        rows.calculation_auto_height(
+            cells,
+            &columns,
+            merge_cells,
+            default_font,
+            default_column_width,
+        );
+        rows
+    }
+
+    /// Group rows by setting their outline level.
+    /// # Arguments
+    /// * `range` - Row range. ex) "3:7"
+    /// * `level` - Outline level, 1 to 7.
+    /// # Return value
+    /// * `Result` - Err when the range has no rows (ex. "B:D").
+    /// # Examples
+    /// ```
+    /// let mut book = umya_spreadsheet::new_file();
+    /// let mut worksheet = book.get_sheet_mut(&0).unwrap();
+    /// worksheet.group_rows("2:5", 1).unwrap();
+    /// worksheet.group_rows("3:4", 2).unwrap();
+    /// ```
+    pub fn group_rows(&mut self, range: &str, level: u8) -> Result<(), &'static str> {
+        let (row_start, row_end) = Self::get_row_span(range)?;
+        for row_num in row_start..=row_end {
+            self.get_row_dimension_mut(&row_num)
+                .set_outline_level(level);
+        }
+        Ok(())
+    }
+
+    /// Ungroup rows by lowering their outline level by one.
+    /// # Arguments
+    /// * `range` - Row range. ex) "3:7"
+    /// # Return value
+    /// * `Result` - Err when the range has no rows (ex. "B:D").
+    pub fn ungroup_rows(&mut self, range: &str) -> Result<(), &'static str> {
+        let (row_start, row_end) = Self::get_row_span(range)?;
+        for row_num in row_start..=row_end {
+            if let Some(row) = self.row_dimensions.get_row_dimension(&row_num) {
+                let level = row.get_outline_level().saturating_sub(1);
+                self.get_row_dimension_mut(&row_num)
+                    .set_outline_level(level);
+            }
+        }
+        Ok(())
+    }
+
+    /// Group columns by setting their outline level.
+    /// # Arguments
+    /// * `range` - Column range. ex) "B:D"
+    /// * `level` - Outline level, 1 to 7.
+    /// # Return value
+    /// * `Result` - Err when the range has no columns (ex. "3:7").
+    /// # Examples
+    /// ```
+    /// let mut book = umya_spreadsheet::new_file();
+    /// let mut worksheet = book.get_sheet_mut(&0).unwrap();
+    /// worksheet.group_columns("B:D", 1).unwrap();
+    /// ```
+    pub fn group_columns(&mut self, range: &str, level: u8) -> Result<(), &'static str> {
+        let (col_start, col_end) = Self::get_column_span(range)?;
+        for col_num in col_start..=col_end {
+            self.get_column_dimension_by_number_mut(&col_num)
+                .set_outline_level(level);
+        }
+        Ok(())
+    }
+
+    /// Ungroup columns by lowering their outline level by one.
+    /// # Arguments
+    /// * `range` - Column range. ex) "B:D"
+    /// # Return value
+    /// * `Result` - Err when the range has no columns (ex. "3:7").
+    pub fn ungroup_columns(&mut self, range: &str) -> Result<(), &'static str> {
+        let (col_start, col_end) = Self::get_column_span(range)?;
+        for col_num in col_start..=col_end {
+            if let Some(column) = self.get_column_dimension_by_number(&col_num) {
+                let level = column.get_outline_level().saturating_sub(1);
+                self.get_column_dimension_by_number_mut(&col_num)
+                    .set_outline_level(level);
+            }
+        }
+        Ok(())
+    }
+
+    // Rows of a row or cell range. A column range has none.
+    fn get_row_span(range: &str) -> Result<(u32, u32), &'static str> {
+        match get_start_and_end_point(range) {
+            (0, ..) => Err("range has no rows."),
+            (row_start, row_end, ..) => Ok((row_start, row_end)),
+        }
+    }
+
+    // Columns of a column or cell range. A row range has none.
+    fn get_column_span(range: &str) -> Result<(u32, u32), &'static str> {
+        match get_start_and_end_point(range) {
+            (_, _, 0, _) => Err("range has no columns."),
+            (_, _, col_start, col_end) => Ok((col_start, col_end)),
+        }
+    }
+
     /// (This method is crate only.)
     /// Set Row Dimension.
     #[inline]
@@ -1024,6 +1425,34 @@ impl Worksheet {
         self.code_name.set_value(value);
     }
 
+    /// Whether dates are read in the 1904 date system.
+    /// This follows the workbook the worksheet belongs to.
+    #[inline]
+    pub fn get_date1904(&self) -> bool {
+        self.date1904
+    }
+
+    #[inline]
+    pub(crate) fn set_date1904(&mut self, value: bool) -> &mut Self {
+        self.date1904 = value;
+        self
+    }
+
+    /// (This method is crate only.)
+    /// The font of the Normal style of the workbook, the unit of the column widths.
+    pub(crate) fn get_default_font(&self) -> TextFont {
+        match &self.default_font {
+            Some(v) => TextFont::new(v),
+            None => TextFont::new(&Font::get_default_value()),
+        }
+    }
+
+    #[inline]
+    pub(crate) fn set_default_font(&mut self, value: Font) -> &mut Self {
+        self.default_font = Some(Box::new(value));
+        self
+    }
+
     /// Get Header Footer.
     #[inline]
     pub fn get_header_footer(&self) -> &HeaderFooter {
@@ -1123,6 +1552,35 @@ impl Worksheet {
         self
     }
 
+    /// Get Outline Properties.
+    #[inline]
+    pub fn get_outline_properties(&self) -> Option<&OutlineProperties> {
+        self.outline_properties.as_ref()
+    }
+
+    /// Get Outline Properties in mutable.
+    #[inline]
+    pub fn get_outline_properties_mut(&mut self) -> &mut OutlineProperties {
+        self.outline_properties
+            .get_or_insert_with(OutlineProperties::default)
+    }
+
+    /// Set Outline Properties.
+    /// # Arguments
+    /// * `value` - OutlineProperties
+    #[inline]
+    pub fn set_outline_properties(&mut self, value: OutlineProperties) -> &mut Self {
+        self.outline_properties = Some(value);
+        self
+    }
+
+    /// Remove Outline Properties.
+    #[inline]
+    pub fn remove_outline_properties(&mut self) -> &mut Self {
+        self.outline_properties = None;
+        self
+    }
+
     /// Calculate Worksheet Dimension.
     pub fn calculate_worksheet_dimension(&self) -> String {
         let (column, row) = self.cell_collection.get_highest_column_and_row();
@@ -1427,8 +1885,11 @@ impl Worksheet {
         !self.pivot_tables.is_empty()
     }
 
+    /// Add a pivot table.
+    /// A pivot table built with `PivotTable::new` also writes its report into the cells of its location.
     #[inline]
     pub fn add_pivot_table(&mut self, pivot_table: PivotTable) {
+        pivot_table.write_output(self);
         self.pivot_tables.push(pivot_table);
     }
 
@@ -1486,6 +1947,40 @@ impl Worksheet {
         self
     }
 
+    #[inline]
+    pub fn get_sparkline_groups(&self) -> &SparklineGroups {
+        &self.sparkline_groups
+    }
+
+    #[inline]
+    pub fn get_sparkline_groups_mut(&mut self) -> &mut SparklineGroups {
+        &mut self.sparkline_groups
+    }
+
+    #[inline]
+    pub fn set_sparkline_groups(&mut self, value: SparklineGroups) -> &mut Self {
+        self.sparkline_groups = value;
+        self
+    }
+
+    /// Add a sparkline group.
+    /// # Examples
+    /// ```
+    /// use umya_spreadsheet::structs::office2010::excel::SparklineGroup;
+    /// use umya_spreadsheet::structs::office2010::excel::SparklineTypeValues;
+    /// let mut book = umya_spreadsheet::new_file();
+    /// let mut worksheet = book.get_sheet_by_name_mut("Sheet1").unwrap();
+    /// let mut group = SparklineGroup::default();
+    /// group.set_type(SparklineTypeValues::Column);
+    /// group.add_sparkline_range("Sheet1!A1:E1", "F1");
+    /// worksheet.add_sparkline_group(group);
+    /// ```
+    #[inline]
+    pub fn add_sparkline_group(&mut self, value: SparklineGroup) -> &mut Self {
+        self.sparkline_groups.add_sparkline_group(value);
+        self
+    }
+
     #[inline]
     pub fn get_sheet_format_properties(&self) -> &SheetFormatProperties {
         &self.sheet_format_properties
@@ -1623,6 +2118,49 @@ impl Worksheet {
         self.get_worksheet_drawing_mut().get_charts_mut(&col, &row)
     }
 
+    /// Outputs all ChartEx charts (waterfall, histogram, treemap and other Excel 2016 charts) contained in the worksheet.
+    /// # Return value
+    /// * `&Vec<ChartEx>` - ChartEx Object List.
+    #[inline]
+    pub fn get_chart_ex_collection(&self) -> &[ChartEx] {
+        self.get_worksheet_drawing().get_chart_ex_collection()
+    }
+
+    /// Outputs all ChartEx charts contained in the worksheet.
+    /// # Return value
+    /// * `&mut Vec<ChartEx>` - ChartEx Object List.
+    #[inline]
+    pub fn get_chart_ex_collection_mut(&mut self) -> &mut ThinVec<ChartEx> {
+        self.get_worksheet_drawing_mut()
+            .get_chart_ex_collection_mut()
+    }
+
+    #[inline]
+    pub fn add_chart_ex(&mut self, value: ChartEx) -> &mut Self {
+        self.get_worksheet_drawing_mut()
+            .add_chart_ex_collection(value);
+        self
+    }
+
+    #[inline]
+    pub fn get_charts_ex<T>(&self, coordinate: T) -> Vec<&ChartEx>
+    where
+        T: Into<CellCoordinates>,
+    {
+        let CellCoordinates { col, row } = coordinate.into();
+        self.get_worksheet_drawing().get_charts_ex(&col, &row)
+    }
+
+    #[inline]
+    pub fn get_charts_ex_mut<T>(&mut self, coordinate: T) -> Vec<&mut ChartEx>
+    where
+        T: Into<CellCoordinates>,
+    {
+        let CellCoordinates { col, row } = coordinate.into();
+        self.get_worksheet_drawing_mut()
+            .get_charts_ex_mut(&col, &row)
+    }
+
     /// Outputs all media contained in the worksheet.
     /// # Return value
     /// * `Vec<&MediaObject>` - Media Object List.
@@ -1671,6 +2209,15 @@ impl Worksheet {
                 }
             }
         }
+        for image in self.get_header_footer().get_images() {
+            let media_object = image.get_image();
+            let is_new = !list
+                .iter()
+                .any(|v| v.get_image_name() == media_object.get_image_name());
+            if is_new {
+                list.push(media_object);
+            }
+        }
 
         list
     }
@@ -1757,6 +2304,13 @@ impl Worksheet {
         self.has_comments() || self.has_ole_objects()
     }
 
+    /// (This method is crate only.)
+    /// Has Legacy Drawing of the header and footer pictures.
+    #[inline]
+    pub(crate) fn has_legacy_drawing_hf(&self) -> bool {
+        self.header_footer.has_images()
+    }
+
     /// Moving a section of the sheet
     /// # Arguments
     /// 'range' - Specify like "A1:G8"
@@ -2024,6 +2578,24 @@ impl AdjustmentCoordinate for Worksheet {
                 offset_row_num,
             );
         };
+
+        // sort state
+        if let Some(v) = self.get_sort_state_mut() {
+            v.adjustment_insert_coordinate(
+                root_col_num,
+                offset_col_num,
+                root_row_num,
+                offset_row_num,
+            );
+        };
+
+        // sparkline locations
+        self.sparkline_groups.adjustment_insert_coordinate(
+            root_col_num,
+            offset_col_num,
+            root_row_num,
+            offset_row_num,
+        );
     }
 
     fn adjustment_remove_coordinate(
@@ -2146,6 +2718,36 @@ impl AdjustmentCoordinate for Worksheet {
                 offset_row_num,
             );
         };
+
+        // sort state
+        let is_remove = match self.get_sort_state() {
+            Some(v) => v.get_range().is_remove_coordinate(
+                root_col_num,
+                offset_col_num,
+                root_row_num,
+                offset_row_num,
+            ),
+            None => false,
+        };
+        if is_remove {
+            self.remove_sort_state();
+        }
+        if let Some(v) = self.get_sort_state_mut() {
+            v.adjustment_remove_coordinate(
+                root_col_num,
+                offset_col_num,
+                root_row_num,
+                offset_row_num,
+            );
+        };
+
+        // sparkline locations
+        self.sparkline_groups.adjustment_remove_coordinate(
+            root_col_num,
+            offset_col_num,
+            root_row_num,
+            offset_row_num,
+        );
     }
 }
 impl AdjustmentCoordinateWithSheet for Worksheet {
@@ -2182,6 +2784,16 @@ impl AdjustmentCoordinateWithSheet for Worksheet {
                 root_row_num,
                 offset_row_num,
             );
+
+        // sparkline data ranges
+        self.sparkline_groups
+            .adjustment_insert_coordinate_with_sheet(
+                sheet_name,
+                root_col_num,
+                offset_col_num,
+                root_row_num,
+                offset_row_num,
+            );
     }
 
     fn adjustment_remove_coordinate_with_sheet(
@@ -2217,5 +2829,15 @@ impl AdjustmentCoordinateWithSheet for Worksheet {
                 root_row_num,
                 offset_row_num,
             );
+
+        // sparkline data ranges
+        self.sparkline_groups
+            .adjustment_remove_coordinate_with_sheet(
+                sheet_name,
+                root_col_num,
+                offset_col_num,
+                root_row_num,
+                offset_row_num,
+            );
     }
 }
@@ -2,7 +2,9 @@
 use crate::helper::const_str::*;
 use crate::reader::driver::*;
 use crate::structs::Address;
+use crate::structs::StringValue;
 use crate::writer::driver::*;
+use crate::XlsxError;
 use quick_xml::events::{BytesStart, Event};
 use quick_xml::Reader;
 use quick_xml::Writer;
@@ -11,6 +13,7 @@ use std::io::Cursor;
 #[derive(Clone, Default, Debug)]
 pub struct WorksheetSource {
     address: Address,
+    name: StringValue,
 }
 
 impl WorksheetSource {
@@ -27,28 +30,44 @@ impl WorksheetSource {
         self
     }
 
+    /// Name of the table or defined name used as the source, instead of the address.
+    pub fn get_name(&self) -> &str {
+        self.name.get_value_str()
+    }
+
+    pub fn set_name<S: Into<String>>(&mut self, value: S) -> &mut Self {
+        self.name.set_value(value);
+        self
+    }
+
     pub(crate) fn set_attributes<R: std::io::BufRead>(
         &mut self,
         _reader: &mut Reader<R>,
         e: &BytesStart,
-    ) {
+    ) -> Result<(), XlsxError> {
         let mut address = Address::default();
-        if let Some(v) = get_attribute(e, b"ref") {
+        if let Some(v) = get_attribute(e, b"ref")? {
             address.get_range_mut().set_range(v);
         }
-        if let Some(v) = get_attribute(e, b"sheet") {
+        if let Some(v) = get_attribute(e, b"sheet")? {
             address.set_sheet_name(v);
         }
         self.set_address(address);
+        set_string_from_xml!(self, e, name, "name");
+        Ok(())
     }
 
     pub(crate) fn write_to(&self, writer: &mut Writer<Cursor<Vec<u8>>>) {
         // worksheetSource
         let mut attributes: Vec<(&str, &str)> = Vec::new();
         let ref_str = self.address.get_range().get_range();
-        attributes.push(("ref", &ref_str));
-        if self.address.get_sheet_name() != "" {
-            attributes.push(("sheet", self.address.get_sheet_name()));
+        if self.name.has_value() {
+            attributes.push(("name", self.name.get_value_str()));
+        } else {
+            attributes.push(("ref", &ref_str));
+            if self.address.get_sheet_name() != "" {
+                attributes.push(("sheet", self.address.get_sheet_name()));
+            }
         }
         write_start_tag(writer, "worksheetSource", attributes, true);
     }
@@ -10,6 +10,7 @@ pub struct WriterManager<'a, W: io::Seek + io::Write> {
     arv: &'a mut zip::ZipWriter<W>,
     is_light: bool,
     table_no: i32,
+    pivot_caches: Vec<(u32, String, Vec<u8>)>,
 }
 
 impl<'a, W: io::Seek + io::Write> WriterManager<'a, W> {
@@ -20,6 +21,7 @@ impl<'a, W: io::Seek + io::Write> WriterManager<'a, W> {
             arv,
             is_light: false,
             table_no: 0,
+            pivot_caches: Vec::new(),
         }
     }
 
@@ -67,6 +69,13 @@ impl<'a, W: io::Seek + io::Write> WriterManager<'a, W> {
         Ok(())
     }
 
+    /// Record a part that was written to the archive directly (e.g. a streamed worksheet).
+    #[inline]
+    pub(crate) fn add_streamed_file(&mut self, target: &str) -> &mut Self {
+        self.files.push(target.to_string());
+        self
+    }
+
     #[inline]
     pub(crate) fn get_arv_mut(&mut self) -> &mut zip::ZipWriter<W> {
         &mut self.arv
@@ -144,6 +153,40 @@ impl<'a, W: io::Seek + io::Write> WriterManager<'a, W> {
         }
     }
 
+    pub(crate) fn add_file_at_chart_ex(
+        &mut self,
+        writer: Writer<Cursor<Vec<u8>>>,
+    ) -> Result<i32, XlsxError> {
+        let mut index = 0;
+        loop {
+            index += 1;
+            let file_path = format!("{}/chartEx{}.xml", PKG_CHARTS, index);
+            if !self.check_file_exist(&file_path) {
+                self.add_writer(&file_path, writer)?;
+                return Ok(index);
+            }
+        }
+    }
+
+    /// Add a style or colors part of a chart.
+    /// # Arguments
+    /// * `name` - `style` or `colors`.
+    pub(crate) fn add_file_at_chart_style(
+        &mut self,
+        name: &str,
+        data: &[u8],
+    ) -> Result<i32, XlsxError> {
+        let mut index = 0;
+        loop {
+            index += 1;
+            let file_path = format!("{}/{}{}.xml", PKG_CHARTS, name, index);
+            if !self.check_file_exist(&file_path) {
+                self.add_bin(&file_path, data)?;
+                return Ok(index);
+            }
+        }
+    }
+
     pub(crate) fn add_file_at_ole_object(&mut self, writer: &[u8]) -> Result<i32, XlsxError> {
         let mut index = 0;
         loop {
@@ -191,6 +234,80 @@ impl<'a, W: io::Seek + io::Write> WriterManager<'a, W> {
         return Ok(table_no);
     }
 
+    pub(crate) fn add_file_at_pivot_table(
+        &mut self,
+        writer: Writer<Cursor<Vec<u8>>>,
+    ) -> Result<i32, XlsxError> {
+        let mut index = 0;
+        loop {
+            index += 1;
+            let file_path = format!("{}/pivotTable{}.xml", PKG_PIVOT_TABLES, index);
+            if !self.check_file_exist(&file_path) {
+                self.add_writer(&file_path, writer)?;
+                return Ok(index);
+            }
+        }
+    }
+
+    pub(crate) fn add_file_at_pivot_cache_definition(
+        &mut self,
+        writer: Writer<Cursor<Vec<u8>>>,
+    ) -> Result<i32, XlsxError> {
+        let mut index = 0;
+        loop {
+            index += 1;
+            let file_path = format!("{}/pivotCacheDefinition{}.xml", PKG_PIVOT_CACHE, index);
+            if !self.check_file_exist(&file_path) {
+                self.add_writer(&file_path, writer)?;
+                return Ok(index);
+            }
+        }
+    }
+
+    pub(crate) fn add_file_at_pivot_cache_records(
+        &mut self,
+        writer: Writer<Cursor<Vec<u8>>>,
+    ) -> Result<i32, XlsxError> {
+        let mut index = 0;
+        loop {
+            index += 1;
+            let file_path = format!("{}/pivotCacheRecords{}.xml", PKG_PIVOT_CACHE, index);
+            if !self.check_file_exist(&file_path) {
+                self.add_writer(&file_path, writer)?;
+                return Ok(index);
+            }
+        }
+    }
+
+    /// Pivot caches written with the worksheets, as (cacheId, target from `xl/`).
+    #[inline]
+    pub(crate) fn get_pivot_caches(&self) -> Vec<(u32, String)> {
+        self.pivot_caches
+            .iter()
+            .map(|(cache_id, target, _)| (*cache_id, target.clone()))
+            .collect()
+    }
+
+    /// Find a pivot cache already written with the same contents.
+    #[inline]
+    pub(crate) fn find_pivot_cache(&self, key: &[u8]) -> Option<(u32, &str)> {
+        self.pivot_caches
+            .iter()
+            .find(|(_, _, v)| v == key)
+            .map(|(cache_id, target, _)| (*cache_id, target.as_str()))
+    }
+
+    #[inline]
+    pub(crate) fn add_pivot_cache(
+        &mut self,
+        cache_id: u32,
+        target: String,
+        key: Vec<u8>,
+    ) -> &mut Self {
+        self.pivot_caches.push((cache_id, target, key));
+        self
+    }
+
     #[inline]
     pub(crate) fn has_extension(&self, extension: &str) -> bool {
         let extension = format!(".{}", extension);
@@ -226,6 +343,21 @@ impl<'a, W: io::Seek + io::Write> WriterManager<'a, W> {
                 content_type = TABLE_TYPE;
             }
 
+            // Override pivotTable
+            if file.starts_with("/xl/pivotTables/pivotTable") {
+                content_type = PIVOT_TABLE_TYPE;
+            }
+
+            // Override pivotCacheDefinition
+            if file.starts_with("/xl/pivotCache/pivotCacheDefinition") {
+                content_type = PIVOT_CACHE_DEF_TYPE;
+            }
+
+            // Override pivotCacheRecords
+            if file.starts_with("/xl/pivotCache/pivotCacheRecords") {
+                content_type = PIVOT_CACHE_RECORDS_TYPE;
+            }
+
             // Override comments
             if file.starts_with("/xl/comments") {
                 content_type = COMMENTS_TYPE;
@@ -256,6 +388,21 @@ impl<'a, W: io::Seek + io::Write> WriterManager<'a, W> {
                 content_type = CHART_TYPE;
             }
 
+            // Override chartEx
+            if file.starts_with("/xl/charts/chartEx") {
+                content_type = CHART_EX_TYPE;
+            }
+
+            // Override chart style
+            if file.starts_with("/xl/charts/style") {
+                content_type = CHART_STYLE_TYPE;
+            }
+
+            // Override chart colors
+            if file.starts_with("/xl/charts/colors") {
+                content_type = CHART_COLOR_STYLE_TYPE;
+            }
+
             // Override embeddings
             if file.starts_with("/xl/embeddings/oleObject") {
                 content_type = OLE_OBJECT_TYPE;
@@ -3,7 +3,10 @@ use std::io;
 use std::path::Path;
 use std::string::FromUtf8Error;
 
+use crate::helper::range::*;
+use crate::structs::CellRawValue;
 use crate::structs::CsvEncodeValues;
+use crate::structs::CsvQuotingValues;
 use crate::structs::CsvWriterOption;
 use crate::structs::Spreadsheet;
 use crate::structs::XlsxError;
@@ -21,17 +24,49 @@ pub fn write_writer<W: io::Seek + io::Write>(
     option: &CsvWriterOption,
 ) -> Result<(), XlsxError> {
     // get worksheet.
-    let worksheet = spreadsheet.get_active_sheet();
+    let worksheet = if !option.get_sheet_name().is_empty() {
+        spreadsheet
+            .get_sheet_by_name(option.get_sheet_name())
+            .ok_or_else(|| XlsxError::SheetNotFound(option.get_sheet_name().to_string()))?
+    } else if let Some(index) = option.get_sheet_index() {
+        spreadsheet
+            .get_sheet(&index)
+            .ok_or_else(|| XlsxError::SheetNotFound(index.to_string()))?
+    } else {
+        spreadsheet.get_active_sheet()
+    };
 
-    // get max column and row.
+    // get the range to export.
     let (max_column, max_row) = worksheet.get_highest_column_and_row();
+    let (mut row_start, mut row_end, mut col_start, mut col_end) = (1, max_row, 1, max_column);
+    if !option.get_range().is_empty() {
+        if !is_valid_range(option.get_range()) {
+            return Err(XlsxError::InvalidRange(option.get_range().to_string()));
+        }
+        let (r_start, r_end, c_start, c_end) = get_start_and_end_point(option.get_range());
+        if r_start > 0 {
+            (row_start, row_end) = (r_start, r_end);
+        }
+        if c_start > 0 {
+            (col_start, col_end) = (c_start, c_end);
+        }
+    }
 
+    let delimiter = option.get_delimiter().to_string();
+    let quote = match option.get_wrap_with_char() {
+        "" => "\"",
+        v => v,
+    };
     let mut data = String::new();
-    for row in 0u32..max_row {
+    for row in row_start..=row_end {
         let mut row_vec: Vec<String> = Vec::new();
-        for column in 0u32..max_column {
+        for column in col_start..=col_end {
             // get value.
-            let mut value = match worksheet.get_cell((column + 1, row + 1)) {
+            let cell = worksheet.get_cell((column, row));
+            let mut value = match cell {
+                Some(cell) if *option.get_use_formatted_value() => {
+                    cell.get_formatted_value_crate(worksheet.get_date1904())
+                }
                 Some(cell) => cell.get_cell_value().get_value().into(),
                 None => String::new(),
             };
@@ -39,17 +74,36 @@ pub fn write_writer<W: io::Seek + io::Write>(
             if *option.get_do_trim() {
                 value = value.trim().to_string();
             }
-            // wrap_with_char.
-            if option.get_wrap_with_char() != "" {
-                value = format! {"{}{}{}", option.get_wrap_with_char(), value, option.get_wrap_with_char()};
+            // quoting.
+            let is_numeric = matches!(
+                cell.map(|v| v.get_raw_value()),
+                Some(CellRawValue::Numeric(_))
+            );
+            let need_quote = option.get_wrap_with_char() != ""
+                || value.contains(&delimiter)
+                || value.contains(quote)
+                || value.contains(['\r', '\n'])
+                || match option.get_quoting() {
+                    CsvQuotingValues::Minimal => false,
+                    CsvQuotingValues::All => true,
+                    CsvQuotingValues::NonNumeric => !is_numeric,
+                };
+            if need_quote {
+                value = format!(
+                    "{}{}{}",
+                    quote,
+                    value.replace(quote, &format!("{}{}", quote, quote)),
+                    quote
+                );
             }
             row_vec.push(value);
         }
-        write!(data, "{}", row_vec.join(",")).unwrap();
-        write!(data, "\r\n").unwrap();
+        write!(data, "{}", row_vec.join(&delimiter)).unwrap();
+        write!(data, "{}", option.get_line_terminator()).unwrap();
     }
 
     // encoding.
+    let write_bom = *option.get_write_bom();
     let data_bytes = match *option.get_csv_encode_value() {
         CsvEncodeValues::ShiftJis => encoding_rs::SHIFT_JIS.encode(&data).0.into_owned(),
         CsvEncodeValues::Koi8u => encoding_rs::KOI8_U.encode(&data).0.into_owned(),
@@ -58,13 +112,25 @@ pub fn write_writer<W: io::Seek + io::Write>(
         CsvEncodeValues::Gbk => encoding_rs::GBK.encode(&data).0.into_owned(),
         CsvEncodeValues::EucKr => encoding_rs::EUC_KR.encode(&data).0.into_owned(),
         CsvEncodeValues::Big5 => encoding_rs::BIG5.encode(&data).0.into_owned(),
-        CsvEncodeValues::Utf16Le => encoding_rs::UTF_16LE.encode(&data).0.into_owned(),
-        CsvEncodeValues::Utf16Be => encoding_rs::UTF_16BE.encode(&data).0.into_owned(),
-        _ => data.into_bytes(),
+        // encoding_rs only decodes UTF-16, so encode it by hand.
+        CsvEncodeValues::Utf16Le => {
+            let bom: &[u8] = if write_bom { &[0xFF, 0xFE] } else { &[] };
+            let body = data.encode_utf16().flat_map(|v| v.to_le_bytes());
+            bom.iter().copied().chain(body).collect()
+        }
+        CsvEncodeValues::Utf16Be => {
+            let bom: &[u8] = if write_bom { &[0xFE, 0xFF] } else { &[] };
+            let body = data.encode_utf16().flat_map(|v| v.to_be_bytes());
+            bom.iter().copied().chain(body).collect()
+        }
+        CsvEncodeValues::Utf8 => {
+            let bom: &[u8] = if write_bom { &[0xEF, 0xBB, 0xBF] } else { &[] };
+            bom.iter().copied().chain(data.into_bytes()).collect()
+        }
     };
 
     // output.
-    writer.write_all(&data_bytes).unwrap();
+    writer.write_all(&data_bytes)?;
     Ok(())
 }
 
@@ -85,6 +151,14 @@ pub fn write_writer<W: io::Seek + io::Write>(
 /// option.set_do_trim(true);
 /// option.set_wrap_with_char("\"");
 /// let _ = writer::csv::write(&book, path, Some(&option));
+///
+/// let mut option = structs::CsvWriterOption::default();
+/// option.set_sheet_name("Sheet1");
+/// option.set_range("A1:C10");
+/// option.set_delimiter('\t');
+/// option.set_quoting(structs::CsvQuotingValues::NonNumeric);
+/// option.set_use_formatted_value(true);
+/// let _ = writer::csv::write(&book, path, Some(&option));
 /// ```
 pub fn write<P: AsRef<Path>>(
     spreadsheet: &Spreadsheet,
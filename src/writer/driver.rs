@@ -80,14 +80,24 @@ pub(crate) fn make_file_from_bin<W: io::Seek + io::Write>(
     writer: &[u8],
     dir: Option<&str>,
     is_light: &bool,
+) -> Result<(), io::Error> {
+    start_file_in_zip(&to_path(path, dir), arv, is_light)?;
+    arv.write_all(writer)
+}
+
+#[inline]
+pub(crate) fn start_file_in_zip<W: io::Seek + io::Write>(
+    path: &str,
+    arv: &mut zip::ZipWriter<W>,
+    is_light: &bool,
 ) -> Result<(), io::Error> {
     let zip_opt = if *is_light {
         zip::write::SimpleFileOptions::default().compression_method(zip::CompressionMethod::Stored)
     } else {
         zip::write::SimpleFileOptions::default().compression_method(zip::CompressionMethod::DEFLATE)
     };
-    arv.start_file(to_path(path, dir), zip_opt)?;
-    arv.write_all(writer)
+    arv.start_file(path, zip_opt)?;
+    Ok(())
 }
 
 #[inline]
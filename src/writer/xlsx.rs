@@ -1,6 +1,7 @@
 use super::driver;
 use crate::helper::crypt::*;
 use crate::structs::Spreadsheet;
+use crate::structs::Worksheet;
 use crate::structs::WriterManager;
 use crate::XlsxError;
 use std::fmt;
@@ -13,6 +14,8 @@ use std::path::Path;
 use std::string::FromUtf8Error;
 
 mod chart;
+mod chart_ex;
+mod chart_ex_rels;
 mod comment;
 mod content_types;
 mod doc_props_app;
@@ -22,9 +25,11 @@ mod drawing;
 mod drawing_rels;
 mod embeddings;
 mod media;
+mod pivot_table;
 mod printer_settings;
 mod rels;
 mod shared_strings;
+mod streaming_workbook;
 mod styles;
 mod table;
 mod theme;
@@ -36,6 +41,8 @@ mod workbook_rels;
 mod worksheet;
 mod worksheet_rels;
 
+pub use self::streaming_workbook::*;
+
 fn make_buffer(spreadsheet: &Spreadsheet, is_light: bool) -> Result<std::vec::Vec<u8>, XlsxError> {
     let mut arv = zip::ZipWriter::new(std::io::Cursor::new(Vec::new()));
     let mut writer_manager = WriterManager::new(&mut arv);
@@ -92,64 +99,7 @@ fn make_buffer(spreadsheet: &Spreadsheet, is_light: bool) -> Result<std::vec::Ve
         }
 
         // from deserialized.
-        // Add charts
-        let mut chart_no_list: Vec<String> = Vec::new();
-        for chart in worksheet.get_worksheet_drawing().get_chart_collection() {
-            let chart_space = chart.get_chart_space();
-            let chart_no = chart::write(chart_space, spreadsheet, &mut writer_manager)?;
-            chart_no_list.push(chart_no);
-        }
-
-        // Add drawing
-        let (drawing_no, rel_list) = drawing::write(worksheet, &mut writer_manager)?;
-
-        // Add drawing rels
-        drawing_rels::write(
-            worksheet,
-            &drawing_no,
-            &chart_no_list,
-            &rel_list,
-            &mut writer_manager,
-        )?;
-
-        // Add vml drawing
-        let (vml_drawing_no, rel_list) = vml_drawing::write(worksheet, &mut writer_manager)?;
-
-        // Add vml drawing rels
-        vml_drawing_rels::write(worksheet, &vml_drawing_no, &rel_list, &mut writer_manager)?;
-
-        // Add comment
-        let comment_no = comment::write(worksheet, &mut writer_manager)?;
-
-        // Add ole_object and excel
-        let (ole_object_no_list, excel_no_list) =
-            embeddings::write(worksheet, &mut writer_manager)?;
-
-        // Add Media
-        media::write(worksheet, &mut writer_manager)?;
-
-        // Add printer_settings
-        let printer_settings_no = match worksheet.get_page_setup().get_object_data() {
-            Some(_) => printer_settings::write(worksheet, &mut writer_manager)?,
-            None => String::new(),
-        };
-
-        // Add tables
-        let table_no_list = table::write(worksheet, &mut writer_manager)?;
-
-        // Add worksheet rels
-        worksheet_rels::write(
-            worksheet,
-            &worksheet_no.to_string(),
-            &drawing_no,
-            &vml_drawing_no,
-            &comment_no,
-            &ole_object_no_list,
-            &excel_no_list,
-            &printer_settings_no,
-            &table_no_list,
-            &mut writer_manager,
-        )?;
+        write_worksheet_objects(worksheet, worksheet_no, spreadsheet, &mut writer_manager)?;
     }
 
     // file list sort
@@ -174,6 +124,92 @@ fn make_buffer(spreadsheet: &Spreadsheet, is_light: bool) -> Result<std::vec::Ve
     Ok(arv.finish()?.into_inner())
 }
 
+/// Write the parts referenced by a worksheet and its relationships.
+pub(crate) fn write_worksheet_objects<W: io::Seek + io::Write>(
+    worksheet: &Worksheet,
+    worksheet_no: usize,
+    spreadsheet: &Spreadsheet,
+    writer_manager: &mut WriterManager<W>,
+) -> Result<(), XlsxError> {
+    // Add charts
+    let mut chart_no_list: Vec<String> = Vec::new();
+    for chart in worksheet.get_worksheet_drawing().get_chart_collection() {
+        let chart_space = chart.get_chart_space();
+        let chart_no = chart::write(chart_space, spreadsheet, writer_manager)?;
+        chart_no_list.push(chart_no);
+    }
+
+    // Add chartEx
+    let mut chart_ex_no_list: Vec<String> = Vec::new();
+    for chart in worksheet.get_worksheet_drawing().get_chart_ex_collection() {
+        let chart_space = chart.get_chart_space();
+        let chart_no = chart_ex::write(chart_space, spreadsheet, writer_manager)?;
+        chart_ex_no_list.push(chart_no);
+    }
+
+    // Add drawing
+    let (drawing_no, rel_list) = drawing::write(worksheet, writer_manager)?;
+
+    // Add drawing rels
+    drawing_rels::write(
+        worksheet,
+        &drawing_no,
+        &chart_no_list,
+        &chart_ex_no_list,
+        &rel_list,
+        writer_manager,
+    )?;
+
+    // Add vml drawing
+    let (vml_drawing_no, rel_list) = vml_drawing::write(worksheet, writer_manager)?;
+
+    // Add vml drawing rels
+    vml_drawing_rels::write(worksheet, &vml_drawing_no, &rel_list, writer_manager)?;
+
+    // Add vml drawing of the header and footer pictures
+    let (vml_drawing_hf_no, rel_list) =
+        vml_drawing::write_header_footer(worksheet, writer_manager)?;
+    vml_drawing_rels::write(worksheet, &vml_drawing_hf_no, &rel_list, writer_manager)?;
+
+    // Add comment
+    let comment_no = comment::write(worksheet, writer_manager)?;
+
+    // Add ole_object and excel
+    let (ole_object_no_list, excel_no_list) = embeddings::write(worksheet, writer_manager)?;
+
+    // Add Media
+    media::write(worksheet, writer_manager)?;
+
+    // Add printer_settings
+    let printer_settings_no = match worksheet.get_page_setup().get_object_data() {
+        Some(_) => printer_settings::write(worksheet, writer_manager)?,
+        None => String::new(),
+    };
+
+    // Add tables
+    let table_no_list = table::write(worksheet, writer_manager)?;
+
+    // Add pivot tables
+    let pivot_table_no_list = pivot_table::write(worksheet, spreadsheet, writer_manager)?;
+
+    // Add worksheet rels
+    worksheet_rels::write(
+        worksheet,
+        &worksheet_no.to_string(),
+        &drawing_no,
+        &vml_drawing_no,
+        &vml_drawing_hf_no,
+        &comment_no,
+        &ole_object_no_list,
+        &excel_no_list,
+        &printer_settings_no,
+        &table_no_list,
+        &pivot_table_no_list,
+        writer_manager,
+    )?;
+    Ok(())
+}
+
 /// write spreadsheet file to arbitrary writer.
 /// # Arguments
 /// * `spreadsheet` - Spreadsheet structs object.
@@ -0,0 +1,34 @@
+use super::chart_ex_rels;
+use super::driver::*;
+use super::XlsxError;
+use crate::structs::drawing::chart_ex::ChartSpace;
+use crate::structs::Spreadsheet;
+use crate::structs::WriterManager;
+use quick_xml::events::{BytesDecl, Event};
+use quick_xml::Writer;
+use std::io;
+
+pub(crate) fn write<W: io::Seek + io::Write>(
+    chart_space: &ChartSpace,
+    spreadsheet: &Spreadsheet,
+    writer_mng: &mut WriterManager<W>,
+) -> Result<String, XlsxError> {
+    let mut writer = Writer::new(io::Cursor::new(Vec::new()));
+    // XML header
+    writer.write_event(Event::Decl(BytesDecl::new(
+        "1.0",
+        Some("UTF-8"),
+        Some("yes"),
+    )));
+    write_new_line(&mut writer);
+
+    // cx:chartSpace
+    chart_space.write_to(&mut writer, spreadsheet);
+
+    let file_no = writer_mng.add_file_at_chart_ex(writer)?.to_string();
+
+    // style and colors parts
+    chart_ex_rels::write(chart_space, &file_no, writer_mng)?;
+
+    Ok(file_no)
+}
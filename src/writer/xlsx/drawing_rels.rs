@@ -12,6 +12,7 @@ pub(crate) fn write<W: io::Seek + io::Write>(
     worksheet: &Worksheet,
     drawing_no: &str,
     chart_no_list: &[String],
+    chart_ex_no_list: &[String],
     rel_list: &[(String, String)],
     writer_mng: &mut WriterManager<W>,
 ) -> Result<(), XlsxError> {
@@ -29,28 +30,43 @@ pub(crate) fn write<W: io::Seek + io::Write>(
     // relationships
     write_start_tag(&mut writer, "Relationships", vec![("xmlns", REL_NS)], false);
 
-    let mut r_id = 1;
-    for chart_no in chart_no_list {
-        is_write = write_relationship(
-            &mut writer,
-            &r_id,
-            CHART_NS,
-            format!("../charts/chart{}.xml", chart_no).as_str(),
-            "",
-        );
-        r_id += 1;
-    }
-
+    let mut chart_no_iter = chart_no_list.iter();
+    let mut chart_ex_no_iter = chart_ex_no_list.iter();
     let mut r_id = 1;
     for (key, value) in rel_list {
-        if key == "IMAGE" {
-            is_write = write_relationship(
-                &mut writer,
-                &r_id,
-                IMAGE_NS,
-                format!("../media/{}", value).as_str(),
-                "",
-            );
+        match key.as_str() {
+            "CHART" => {
+                if let Some(chart_no) = chart_no_iter.next() {
+                    is_write = write_relationship(
+                        &mut writer,
+                        &r_id,
+                        CHART_NS,
+                        format!("../charts/chart{}.xml", chart_no).as_str(),
+                        "",
+                    );
+                }
+            }
+            "CHART_EX" => {
+                if let Some(chart_no) = chart_ex_no_iter.next() {
+                    is_write = write_relationship(
+                        &mut writer,
+                        &r_id,
+                        CHART_EX_NS,
+                        format!("../charts/chartEx{}.xml", chart_no).as_str(),
+                        "",
+                    );
+                }
+            }
+            "IMAGE" => {
+                is_write = write_relationship(
+                    &mut writer,
+                    &r_id,
+                    IMAGE_NS,
+                    format!("../media/{}", value).as_str(),
+                    "",
+                );
+            }
+            _ => (),
         }
         r_id += 1;
     }
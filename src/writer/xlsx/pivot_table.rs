@@ -0,0 +1,126 @@
+use super::driver::*;
+use super::XlsxError;
+use crate::helper::const_str::*;
+use crate::structs::Spreadsheet;
+use crate::structs::Worksheet;
+use crate::structs::WriterManager;
+use quick_xml::events::{BytesDecl, Event};
+use quick_xml::Writer;
+use std::io;
+
+pub(crate) fn write<W: io::Seek + io::Write>(
+    worksheet: &Worksheet,
+    spreadsheet: &Spreadsheet,
+    writer_mng: &mut WriterManager<W>,
+) -> Result<Vec<String>, XlsxError> {
+    let mut pivot_table_no_list = Vec::<String>::new();
+    for pivot_table in worksheet.get_pivot_tables() {
+        // pivot tables read from a file have no cache of their own.
+        if pivot_table
+            .get_pivot_cache_definition()
+            .get_cache_fields()
+            .get_list()
+            .is_empty()
+        {
+            continue;
+        }
+
+        // pivotCacheRecords
+        let mut records_writer = make_writer();
+        pivot_table
+            .get_pivot_cache_records()
+            .write_to(&mut records_writer);
+
+        // pivotCacheDefinition
+        let mut pivot_cache_definition = pivot_table.get_pivot_cache_definition().clone();
+        pivot_cache_definition.set_id("rId1");
+        let mut definition_writer = make_writer();
+        pivot_cache_definition.write_to(&mut definition_writer);
+
+        // pivot tables sharing a cache in the file keep sharing it.
+        let mut key = pivot_table
+            .get_pivot_table_definition()
+            .get_cache_id()
+            .to_string()
+            .into_bytes();
+        key.extend_from_slice(definition_writer.get_ref().get_ref());
+        key.extend_from_slice(records_writer.get_ref().get_ref());
+
+        let (cache_id, definition_target) = match writer_mng.find_pivot_cache(&key) {
+            Some((cache_id, target)) => (cache_id, target.to_string()),
+            None => {
+                let cache_id = spreadsheet
+                    .get_pivot_caches()
+                    .iter()
+                    .filter_map(|(_, cache_id, _)| cache_id.parse::<u32>().ok())
+                    .chain(writer_mng.get_pivot_caches().into_iter().map(|(v, _)| v))
+                    .max()
+                    .map_or(0, |v| v + 1);
+
+                let records_no = writer_mng.add_file_at_pivot_cache_records(records_writer)?;
+                let definition_no =
+                    writer_mng.add_file_at_pivot_cache_definition(definition_writer)?;
+
+                let writer = make_rels_writer(
+                    PIVOT_CACHE_RECORDS_NS,
+                    &format!("pivotCacheRecords{}.xml", records_no),
+                );
+                writer_mng.add_writer(
+                    &format!(
+                        "{}/_rels/pivotCacheDefinition{}.xml.rels",
+                        PKG_PIVOT_CACHE, definition_no
+                    ),
+                    writer,
+                )?;
+
+                let target = format!("pivotCache/pivotCacheDefinition{}.xml", definition_no);
+                writer_mng.add_pivot_cache(cache_id, target.clone(), key);
+                (cache_id, target)
+            }
+        };
+
+        // pivotTableDefinition
+        let mut pivot_table_definition = pivot_table.get_pivot_table_definition().clone();
+        pivot_table_definition.set_cache_id(cache_id);
+        let mut writer = make_writer();
+        pivot_table_definition.write_to(&mut writer);
+        let pivot_table_no = writer_mng.add_file_at_pivot_table(writer)?;
+
+        let writer = make_rels_writer(PIVOT_CACHE_DEF_NS, &format!("../{}", definition_target));
+        writer_mng.add_writer(
+            &format!(
+                "{}/_rels/pivotTable{}.xml.rels",
+                PKG_PIVOT_TABLES, pivot_table_no
+            ),
+            writer,
+        )?;
+
+        pivot_table_no_list.push(pivot_table_no.to_string());
+    }
+    Ok(pivot_table_no_list)
+}
+
+fn make_writer() -> Writer<io::Cursor<Vec<u8>>> {
+    let mut writer = Writer::new(io::Cursor::new(Vec::new()));
+    // XML header
+    writer.write_event(Event::Decl(BytesDecl::new(
+        "1.0",
+        Some("UTF-8"),
+        Some("yes"),
+    )));
+    write_new_line(&mut writer);
+    writer
+}
+
+fn make_rels_writer(p_type: &str, p_target: &str) -> Writer<io::Cursor<Vec<u8>>> {
+    let mut writer = make_writer();
+    write_start_tag(&mut writer, "Relationships", vec![("xmlns", REL_NS)], false);
+    write_start_tag(
+        &mut writer,
+        "Relationship",
+        vec![("Id", "rId1"), ("Type", p_type), ("Target", p_target)],
+        true,
+    );
+    write_end_tag(&mut writer, "Relationships");
+    writer
+}
@@ -0,0 +1,470 @@
+use super::driver::*;
+use super::worksheet;
+use super::write_worksheet_objects;
+use super::XlsxError;
+use super::{
+    content_types, doc_props_app, doc_props_core, doc_props_custom, rels, shared_strings, styles,
+    theme, workbook, workbook_rels,
+};
+use crate::helper::const_str::*;
+use crate::helper::coordinate::string_from_column_index;
+use crate::structs::CellErrorType;
+use crate::structs::CellRawValue;
+use crate::structs::CellValue;
+use crate::structs::RichText;
+use crate::structs::SharedStringTable;
+use crate::structs::SheetView;
+use crate::structs::Spreadsheet;
+use crate::structs::Style;
+use crate::structs::Stylesheet;
+use crate::structs::Text;
+use crate::structs::Worksheet;
+use crate::structs::WriterManager;
+use quick_xml::Writer;
+use std::io;
+use std::io::Write;
+use std::sync::RwLock;
+
+// Rows are buffered and flushed into the archive once the buffer exceeds this size.
+const FLUSH_SIZE: usize = 64 * 1024;
+
+// The last row and column of a worksheet.
+const MAX_ROW: u32 = 1_048_576;
+const MAX_COLUMN: usize = 16_384;
+
+/// A cell value and its style id, as pushed to `StreamingWorkbook::write_row`.
+#[derive(Clone, Default, Debug, PartialEq)]
+pub struct StreamingCell {
+    value: CellRawValue,
+    style_id: u32,
+}
+impl StreamingCell {
+    #[inline]
+    pub fn get_value(&self) -> &CellRawValue {
+        &self.value
+    }
+
+    #[inline]
+    pub fn set_value_string<S: Into<String>>(&mut self, value: S) -> &mut Self {
+        self.value = CellRawValue::String(value.into().into_boxed_str());
+        self
+    }
+
+    #[inline]
+    pub fn set_value_number<T: Into<f64>>(&mut self, value: T) -> &mut Self {
+        self.value = CellRawValue::Numeric(value.into());
+        self
+    }
+
+    #[inline]
+    pub fn set_value_bool(&mut self, value: bool) -> &mut Self {
+        self.value = CellRawValue::Bool(value);
+        self
+    }
+
+    #[inline]
+    pub fn set_rich_text(&mut self, value: RichText) -> &mut Self {
+        self.value = CellRawValue::RichText(value);
+        self
+    }
+
+    #[inline]
+    pub fn set_error(&mut self, value: CellErrorType) -> &mut Self {
+        self.value = CellRawValue::Error(value);
+        self
+    }
+
+    /// Index returned by `StreamingWorkbook::add_style`.
+    #[inline]
+    pub fn get_style_id(&self) -> &u32 {
+        &self.style_id
+    }
+
+    #[inline]
+    pub fn set_style_id(&mut self, value: u32) -> &mut Self {
+        self.style_id = value;
+        self
+    }
+}
+impl From<&str> for StreamingCell {
+    #[inline]
+    fn from(value: &str) -> Self {
+        let mut cell = Self::default();
+        cell.set_value_string(value);
+        cell
+    }
+}
+impl From<String> for StreamingCell {
+    #[inline]
+    fn from(value: String) -> Self {
+        let mut cell = Self::default();
+        cell.set_value_string(value);
+        cell
+    }
+}
+impl From<f64> for StreamingCell {
+    #[inline]
+    fn from(value: f64) -> Self {
+        let mut cell = Self::default();
+        cell.set_value_number(value);
+        cell
+    }
+}
+impl From<bool> for StreamingCell {
+    #[inline]
+    fn from(value: bool) -> Self {
+        let mut cell = Self::default();
+        cell.set_value_bool(value);
+        cell
+    }
+}
+
+struct StreamingSheet {
+    sheet_no: usize,
+    started: bool,
+    next_row: u32,
+    writer: Writer<io::Cursor<Vec<u8>>>,
+}
+
+/// Writes a workbook row by row without keeping the cells in memory.
+///
+/// Each sheet is flushed into the archive as its rows are pushed, so only one sheet can be
+/// open at a time. Columns, merges, sheet views (freeze panes) and the other sheet settings
+/// are taken from the `Worksheet` returned by `new_sheet`; settings in front of `sheetData`
+/// (columns, views) must be declared before the first row is written. Comments, images, charts,
+/// tables and the other parts referenced by the sheet are written by `finish`.
+/// # Examples
+/// ```
+/// use umya_spreadsheet::*;
+/// let file = std::fs::File::create("./tests/result_files/zzz_streaming.xlsx").unwrap();
+/// let mut book = writer::xlsx::StreamingWorkbook::new(file);
+/// let mut style = Style::default();
+/// style.get_font_mut().set_bold(true);
+/// let bold = book.add_style(&style);
+///
+/// let sheet = book.new_sheet("Data").unwrap();
+/// sheet.get_column_dimension_mut("A").set_width(20.0);
+/// book.write_row(&[
+///     writer::xlsx::StreamingCell::from("name").set_style_id(bold).clone(),
+///     writer::xlsx::StreamingCell::from("value").set_style_id(bold).clone(),
+/// ])
+/// .unwrap();
+/// for i in 0..1000 {
+///     book.write_row(&[format!("item{}", i).into(), (i as f64).into()])
+///         .unwrap();
+/// }
+/// book.finish().unwrap();
+/// ```
+pub struct StreamingWorkbook<W: io::Seek + io::Write> {
+    spreadsheet: Spreadsheet,
+    stylesheet: Stylesheet,
+    shared_string_table: RwLock<SharedStringTable>,
+    arv: zip::ZipWriter<W>,
+    sheet_files: Vec<String>,
+    use_shared_strings: bool,
+    is_light: bool,
+    current: Option<StreamingSheet>,
+}
+
+impl<W: io::Seek + io::Write> StreamingWorkbook<W> {
+    pub fn new(writer: W) -> Self {
+        let spreadsheet = crate::new_file_empty_worksheet();
+        let stylesheet = spreadsheet.get_stylesheet().clone();
+        StreamingWorkbook {
+            spreadsheet,
+            stylesheet,
+            shared_string_table: RwLock::new(SharedStringTable::default()),
+            arv: zip::ZipWriter::new(writer),
+            sheet_files: Vec::new(),
+            use_shared_strings: true,
+            is_light: false,
+            current: None,
+        }
+    }
+
+    /// Store text in the shared string table (default) or inline in each cell.
+    /// Inline strings keep memory constant; shared strings give smaller files.
+    #[inline]
+    pub fn get_use_shared_strings(&self) -> &bool {
+        &self.use_shared_strings
+    }
+
+    #[inline]
+    pub fn set_use_shared_strings(&mut self, value: bool) -> &mut Self {
+        self.use_shared_strings = value;
+        self
+    }
+
+    /// Store the parts without compression.
+    #[inline]
+    pub fn set_is_light(&mut self, value: bool) -> &mut Self {
+        self.is_light = value;
+        self
+    }
+
+    /// Workbook-level settings (properties, defined names, theme).
+    #[inline]
+    pub fn get_spreadsheet_mut(&mut self) -> &mut Spreadsheet {
+        &mut self.spreadsheet
+    }
+
+    /// Register a style and get the id to use with `StreamingCell::set_style_id`.
+    #[inline]
+    pub fn add_style(&mut self, style: &Style) -> u32 {
+        self.stylesheet.set_style(style)
+    }
+
+    /// Close the current sheet and start a new one.
+    /// # Return value
+    /// * `Result<&mut Worksheet, XlsxError>` - the sheet settings to declare before the first row.
+    pub fn new_sheet<S: Into<String>>(
+        &mut self,
+        sheet_name: S,
+    ) -> Result<&mut Worksheet, XlsxError> {
+        let sheet_name = sheet_name.into();
+        self.close_sheet()?;
+        let sheet_no = self.spreadsheet.get_sheet_count() + 1;
+        let worksheet = self
+            .spreadsheet
+            .new_sheet(sheet_name.as_str())
+            .map_err(|_| XlsxError::DuplicateSheetName(sheet_name.clone()))?;
+        let mut sheet_view = SheetView::default();
+        sheet_view.set_workbook_view_id(0);
+        worksheet
+            .get_sheet_views_mut()
+            .add_sheet_view_list_mut(sheet_view);
+        self.current = Some(StreamingSheet {
+            sheet_no,
+            started: false,
+            next_row: 1,
+            writer: Writer::new(io::Cursor::new(Vec::new())),
+        });
+        Ok(worksheet)
+    }
+
+    /// Append a row to the current sheet.
+    pub fn write_row(&mut self, cells: &[StreamingCell]) -> Result<(), XlsxError> {
+        let row_num = match &self.current {
+            Some(v) => v.next_row,
+            None => return Err(XlsxError::NoActiveSheet),
+        };
+        self.write_row_at(row_num, cells)
+    }
+
+    /// Write a row at `row_num` (1-based). Rows must be written in ascending order.
+    pub fn write_row_at(&mut self, row_num: u32, cells: &[StreamingCell]) -> Result<(), XlsxError> {
+        self.start_sheet()?;
+        let current = match &mut self.current {
+            Some(v) => v,
+            None => return Err(XlsxError::NoActiveSheet),
+        };
+        if row_num == 0 || row_num > MAX_ROW {
+            return Err(XlsxError::InvalidRange(format!(
+                "row {} is out of range 1 to {}",
+                row_num, MAX_ROW
+            )));
+        }
+        if row_num < current.next_row {
+            return Err(XlsxError::InvalidRange(format!(
+                "row {} has already been written",
+                row_num
+            )));
+        }
+        if cells.len() > MAX_COLUMN {
+            return Err(XlsxError::InvalidRange(format!(
+                "{} cells do not fit in the {} columns of a row",
+                cells.len(),
+                MAX_COLUMN
+            )));
+        }
+        current.next_row = row_num + 1;
+
+        let writer = &mut current.writer;
+        let row_str = row_num.to_string();
+        write_start_tag(writer, "row", vec![("r", &row_str)], cells.is_empty());
+        if cells.is_empty() {
+            return self.flush(false);
+        }
+        for (index, cell) in cells.iter().enumerate() {
+            let coordinate = format!(
+                "{}{}",
+                string_from_column_index(&(index as u32 + 1)),
+                row_num
+            );
+            let style_str = cell.style_id.to_string();
+            let mut attributes: Vec<(&str, &str)> = vec![("r", &coordinate)];
+            let data_type = match &cell.value {
+                CellRawValue::String(_) | CellRawValue::RichText(_) => {
+                    if self.use_shared_strings {
+                        "s"
+                    } else {
+                        "inlineStr"
+                    }
+                }
+                CellRawValue::Bool(_) => "b",
+                CellRawValue::Error(_) => "e",
+                // NaN and infinity have no representation in a cell.
+                CellRawValue::Numeric(v) if !v.is_finite() => "e",
+                _ => "",
+            };
+            if !data_type.is_empty() {
+                attributes.push(("t", data_type));
+            }
+            if cell.style_id > 0 {
+                attributes.push(("s", &style_str));
+            }
+            if matches!(cell.value, CellRawValue::Empty | CellRawValue::Lazy(_)) {
+                if cell.style_id > 0 {
+                    write_start_tag(writer, "c", attributes, true);
+                }
+                continue;
+            }
+            write_start_tag(writer, "c", attributes, false);
+            match &cell.value {
+                CellRawValue::String(_) | CellRawValue::RichText(_) if self.use_shared_strings => {
+                    let mut cell_value = CellValue::default();
+                    match &cell.value {
+                        CellRawValue::RichText(v) => cell_value.set_rich_text(v.clone()),
+                        v => cell_value.set_value_string(v.to_string()),
+                    };
+                    let index = self
+                        .shared_string_table
+                        .write()
+                        .unwrap()
+                        .set_cell(&cell_value);
+                    write_value(writer, &index.to_string());
+                }
+                CellRawValue::String(v) => {
+                    write_start_tag(writer, "is", vec![], false);
+                    Text::default().set_value(v.to_string()).write_to(writer);
+                    write_end_tag(writer, "is");
+                }
+                CellRawValue::RichText(v) => v.write_to(writer, "is"),
+                CellRawValue::Numeric(v) if !v.is_finite() => write_value(writer, "#NUM!"),
+                CellRawValue::Numeric(v) => write_value(writer, &v.to_string()),
+                CellRawValue::Bool(v) => write_value(writer, if *v { "1" } else { "0" }),
+                CellRawValue::Error(CellErrorType::Data) => write_value(writer, "#VALUE!"),
+                CellRawValue::Error(v) => write_value(writer, &v.to_string()),
+                CellRawValue::Empty | CellRawValue::Lazy(_) => {}
+            }
+            write_end_tag(writer, "c");
+        }
+        write_end_tag(writer, "row");
+        self.flush(false)
+    }
+
+    /// Close the last sheet and write the workbook parts.
+    /// # Return value
+    /// * `Result<W, XlsxError>` - the inner writer.
+    pub fn finish(mut self) -> Result<W, XlsxError> {
+        self.close_sheet()?;
+        if self.spreadsheet.get_sheet_count() == 0 {
+            self.new_sheet("Sheet1")?;
+            self.close_sheet()?;
+        }
+        self.spreadsheet.set_active_sheet(0);
+
+        {
+            let mut writer_manager = WriterManager::new(&mut self.arv);
+            writer_manager.set_is_light(self.is_light);
+            for file in &self.sheet_files {
+                writer_manager.add_streamed_file(file);
+            }
+            for (index, worksheet) in self
+                .spreadsheet
+                .get_sheet_collection_no_check()
+                .iter()
+                .enumerate()
+            {
+                write_worksheet_objects(
+                    worksheet,
+                    index + 1,
+                    &self.spreadsheet,
+                    &mut writer_manager,
+                )?;
+            }
+            doc_props_app::write(&self.spreadsheet, &mut writer_manager)?;
+            doc_props_core::write(&self.spreadsheet, &mut writer_manager)?;
+            doc_props_custom::write(&self.spreadsheet, &mut writer_manager)?;
+            rels::write(&self.spreadsheet, &mut writer_manager)?;
+            theme::write(self.spreadsheet.get_theme(), &mut writer_manager)?;
+            writer_manager.file_list_sort();
+            shared_strings::write(&self.shared_string_table, &mut writer_manager)?;
+            styles::write(&self.stylesheet, &mut writer_manager)?;
+            workbook::write(&self.spreadsheet, &mut writer_manager)?;
+            let has_shared_string_table = self.shared_string_table.read().unwrap().has_value();
+            workbook_rels::write(
+                &self.spreadsheet,
+                has_shared_string_table,
+                &mut writer_manager,
+            )?;
+            content_types::write(&self.spreadsheet, &mut writer_manager)?;
+        }
+        Ok(self.arv.finish()?)
+    }
+
+    // Write everything in front of the first row and open the sheet part in the archive.
+    fn start_sheet(&mut self) -> Result<(), XlsxError> {
+        let current = match &mut self.current {
+            Some(v) if !v.started => v,
+            _ => return Ok(()),
+        };
+        current.started = true;
+        let target = format!("{PKG_SHEET}{}.xml", current.sheet_no);
+        start_file_in_zip(&target, &mut self.arv, &self.is_light)?;
+        self.sheet_files.push(target);
+
+        let worksheet = self
+            .spreadsheet
+            .get_sheet_collection_no_check()
+            .last()
+            .unwrap();
+        worksheet::write_head(
+            &mut current.writer,
+            worksheet,
+            &mut self.stylesheet,
+            self.spreadsheet.get_has_macros(),
+            None,
+        );
+        write_start_tag(&mut current.writer, "sheetData", vec![], false);
+        Ok(())
+    }
+
+    // Write everything after the last row.
+    fn close_sheet(&mut self) -> Result<(), XlsxError> {
+        if self.current.is_none() {
+            return Ok(());
+        }
+        self.start_sheet()?;
+        let current = self.current.as_mut().unwrap();
+        write_end_tag(&mut current.writer, "sheetData");
+        let worksheet = self
+            .spreadsheet
+            .get_sheet_collection_no_check()
+            .last()
+            .unwrap();
+        worksheet::write_tail(&mut current.writer, worksheet, &mut self.stylesheet);
+        self.flush(true)?;
+        self.current = None;
+        Ok(())
+    }
+
+    fn flush(&mut self, force: bool) -> Result<(), XlsxError> {
+        if let Some(current) = &mut self.current {
+            let buffer = current.writer.get_mut().get_mut();
+            if force || buffer.len() > FLUSH_SIZE {
+                self.arv.write_all(buffer)?;
+                buffer.clear();
+                current.writer.get_mut().set_position(0);
+            }
+        }
+        Ok(())
+    }
+}
+
+#[inline]
+fn write_value(writer: &mut Writer<io::Cursor<Vec<u8>>>, value: &str) {
+    write_start_tag(writer, "v", vec![], false);
+    write_text_node(writer, value);
+    write_end_tag(writer, "v");
+}
@@ -44,6 +44,9 @@ pub(crate) fn write<W: io::Seek + io::Write>(
 
     // workbookPr
     let mut attributes: Vec<(&str, &str)> = Vec::new();
+    if spreadsheet.get_date1904() {
+        attributes.push(("date1904", "1"));
+    }
     attributes.push(("filterPrivacy", "1"));
     //attributes.push(("defaultThemeVersion", "124226"));
     if spreadsheet.get_has_macros() {
@@ -122,7 +125,8 @@ pub(crate) fn write<W: io::Seek + io::Write>(
 
     // pivotCaches
     let pivot_cache_definition_collection = spreadsheet.get_pivot_caches();
-    if !pivot_cache_definition_collection.is_empty() {
+    let pivot_cache_list = writer_mng.get_pivot_caches();
+    if !pivot_cache_definition_collection.is_empty() || !pivot_cache_list.is_empty() {
         write_start_tag(&mut writer, "pivotCaches", vec![], false);
         for (_, val2, _) in pivot_cache_definition_collection {
             let r_id = format!("rId{}", index);
@@ -134,6 +138,16 @@ pub(crate) fn write<W: io::Seek + io::Write>(
             );
             index += 1;
         }
+        for (cache_id, _) in pivot_cache_list {
+            let r_id = format!("rId{}", index);
+            write_start_tag(
+                &mut writer,
+                "pivotCache",
+                vec![("cacheId", &cache_id.to_string()), ("r:id", &r_id)],
+                true,
+            );
+            index += 1;
+        }
         write_end_tag(&mut writer, "pivotCaches");
     }
 
@@ -48,6 +48,16 @@ pub(crate) fn write<W: io::Seek + io::Write>(
         );
         index += 1;
     }
+    for (_, pivot_cache_definition) in writer_mng.get_pivot_caches() {
+        write_relationship(
+            &mut writer,
+            &index.to_string(),
+            PIVOT_CACHE_DEF_NS,
+            &pivot_cache_definition,
+            "",
+        );
+        index += 1;
+    }
 
     // relationship styles.xml
     write_relationship(&mut writer, &index.to_string(), STYLES_NS, "styles.xml", "");
@@ -14,11 +14,13 @@ pub(crate) fn write<W: io::Seek + io::Write>(
     worksheet_no: &str,
     drawing_no: &str,
     vml_drawing_no: &str,
+    vml_drawing_hf_no: &str,
     comment_no: &str,
     ole_object_no_list: &[String],
     excel_no_list: &[String],
     printer_settings_no: &str,
     table_no_list: &[String],
+    pivot_table_no_list: &[String],
     writer_mng: &mut WriterManager<W>,
 ) -> Result<(), XlsxError> {
     let mut is_write = false;
@@ -88,6 +90,18 @@ pub(crate) fn write<W: io::Seek + io::Write>(
         r_id += 1;
     }
 
+    // Write vmlDrawing relationship of the header and footer pictures
+    if worksheet.has_legacy_drawing_hf() {
+        is_write = write_relationship(
+            &mut writer,
+            &r_id.to_string(),
+            VML_DRAWING_NS,
+            format!("../drawings/vmlDrawing{}.vml", vml_drawing_hf_no).as_str(),
+            "",
+        );
+        r_id += 1;
+    }
+
     // write table relationships
     for table_no in table_no_list.iter() {
         is_write = write_relationship(
@@ -152,6 +166,20 @@ pub(crate) fn write<W: io::Seek + io::Write>(
             format!("../comments{}.xml", comment_no).as_str(),
             "",
         );
+        r_id += 1;
+    }
+
+    // write pivot table relationships
+    // (not referenced from the worksheet part, so they come last)
+    for pivot_table_no in pivot_table_no_list.iter() {
+        is_write = write_relationship(
+            &mut writer,
+            &r_id.to_string(),
+            PIVOT_TABLE_NS,
+            format!("../pivotTables/pivotTable{}.xml", pivot_table_no).as_str(),
+            "",
+        );
+        r_id += 1;
     }
 
     write_end_tag(&mut writer, "Relationships");
@@ -52,6 +52,52 @@ fn wite_with_password() {
     let _ = umya_spreadsheet::writer::xlsx::set_password(&from_path, &to_path, "password");
 }
 
+#[test]
+fn read_with_password() {
+    let mut book = umya_spreadsheet::new_file();
+    book.get_sheet_by_name_mut("Sheet1")
+        .unwrap()
+        .get_cell_mut("A1")
+        .set_value("secret");
+    let path = std::path::Path::new("./tests/result_files/bbb_read_password.xlsx");
+    umya_spreadsheet::writer::xlsx::write_with_password(&book, path, "password").unwrap();
+
+    let book = umya_spreadsheet::reader::xlsx::read_with_password(path, "password").unwrap();
+    assert_eq!(
+        book.get_sheet_by_name("Sheet1").unwrap().get_value("A1"),
+        "secret"
+    );
+
+    let file = std::fs::File::open(path).unwrap();
+    let mut book =
+        umya_spreadsheet::reader::xlsx::read_reader_with_password(file, "password", false).unwrap();
+    assert_eq!(
+        book.get_sheet_by_name_mut("Sheet1")
+            .unwrap()
+            .get_value("A1"),
+        "secret"
+    );
+
+    let result = umya_spreadsheet::reader::xlsx::read_with_password(path, "wrong");
+    assert!(matches!(result, Err(XlsxError::WrongPassword)));
+
+    // damage the encrypted package
+    let path = std::path::Path::new("./tests/result_files/bbb_read_password_corrupted.xlsx");
+    umya_spreadsheet::writer::xlsx::write_with_password(&book, path, "password").unwrap();
+    {
+        use std::io::{Read, Seek, SeekFrom, Write};
+        let mut comp = cfb::open_rw(path).unwrap();
+        let mut stream = comp.open_stream("EncryptedPackage").unwrap();
+        let mut data = Vec::new();
+        stream.read_to_end(&mut data).unwrap();
+        data[100] ^= 0xff;
+        stream.seek(SeekFrom::Start(0)).unwrap();
+        stream.write_all(&data).unwrap();
+    }
+    let result = umya_spreadsheet::reader::xlsx::read_with_password(path, "password");
+    assert!(matches!(result, Err(XlsxError::CorruptedPackage(_))));
+}
+
 #[test]
 fn lazy_read_and_wite() {
     // reader
@@ -160,7 +206,7 @@ fn read_and_wite_method(book: &mut umya_spreadsheet::Spreadsheet) {
         book.get_sheet(&0).unwrap().get_formatted_value("B23")
     );
     assert_eq!(
-        "2:33 pm",
+        "2:33 PM",
         book.get_sheet(&0).unwrap().get_formatted_value("B24")
     );
     assert_eq!(
@@ -184,7 +230,7 @@ fn read_and_wite_method(book: &mut umya_spreadsheet::Spreadsheet) {
         book.get_sheet(&0).unwrap().get_formatted_value("B29")
     );
     assert_eq!(
-        "2020年10月1日",
+        "令和2年10月1日",
         book.get_sheet(&0).unwrap().get_formatted_value("B30")
     );
     assert_eq!(
@@ -1035,6 +1081,145 @@ fn witer_csv() {
     let _ = umya_spreadsheet::writer::csv::write(&book, path, Some(&option));
 }
 
+#[test]
+fn write_csv_rfc4180() {
+    let mut book = umya_spreadsheet::new_file();
+    let sheet = book.new_sheet("Data").unwrap();
+    sheet.get_cell_mut("A1").set_value("a,b");
+    sheet.get_cell_mut("B1").set_value("say \"hi\"");
+    sheet.get_cell_mut("C1").set_value("two\nlines");
+    sheet.get_cell_mut("A2").set_value_number(1.5);
+    sheet.get_cell_mut("B2").set_value("plain");
+    sheet
+        .get_cell_mut("C2")
+        .set_value_number(0.25)
+        .get_style_mut()
+        .get_number_format_mut()
+        .set_format_code(umya_spreadsheet::NumberingFormat::FORMAT_PERCENTAGE);
+
+    let write = |option: &umya_spreadsheet::structs::CsvWriterOption| {
+        let mut buffer = std::io::Cursor::new(Vec::new());
+        umya_spreadsheet::writer::csv::write_writer(&book, &mut buffer, option).unwrap();
+        buffer.into_inner()
+    };
+
+    let mut option = umya_spreadsheet::structs::CsvWriterOption::default();
+    option.set_sheet_name("Data");
+    let data = write(&option);
+    assert_eq!(
+        String::from_utf8(data.clone()).unwrap(),
+        "\"a,b\",\"say \"\"hi\"\"\",\"two\nlines\"\r\n1.5,plain,0.25\r\n"
+    );
+
+    // lossless round-trip
+    let mut read_option = umya_spreadsheet::structs::CsvReaderOption::default();
+    read_option.set_infer_types(false);
+    let read_book =
+        umya_spreadsheet::reader::csv::read_reader(&data[..], Some(&read_option)).unwrap();
+    let read_sheet = read_book.get_sheet(&0).unwrap();
+    for coordinate in ["A1", "B1", "C1", "A2", "B2", "C2"] {
+        assert_eq!(
+            read_sheet.get_value(coordinate),
+            book.get_sheet_by_name("Data")
+                .unwrap()
+                .get_value(coordinate)
+        );
+    }
+
+    let mut option = umya_spreadsheet::structs::CsvWriterOption::default();
+    option.set_sheet_index(1);
+    option.set_range("A2:C2");
+    option.set_delimiter(';');
+    option.set_quoting(umya_spreadsheet::structs::CsvQuotingValues::NonNumeric);
+    option.set_line_terminator("\n");
+    option.set_use_formatted_value(true);
+    option.set_write_bom(true);
+    assert_eq!(
+        String::from_utf8(write(&option)).unwrap(),
+        "\u{feff}1.5;\"plain\";25%\n"
+    );
+
+    let mut option = umya_spreadsheet::structs::CsvWriterOption::default();
+    option.set_sheet_name("Missing");
+    let mut buffer = std::io::Cursor::new(Vec::new());
+    let result = umya_spreadsheet::writer::csv::write_writer(&book, &mut buffer, &option);
+    assert!(matches!(result, Err(XlsxError::SheetNotFound(_))));
+
+    for range in ["A1:B2:C3", "B:2", "C2:A1", "A0:C2", "#"] {
+        let mut option = umya_spreadsheet::structs::CsvWriterOption::default();
+        option.set_sheet_name("Data");
+        option.set_range(range);
+        let mut buffer = std::io::Cursor::new(Vec::new());
+        let result = umya_spreadsheet::writer::csv::write_writer(&book, &mut buffer, &option);
+        assert!(matches!(result, Err(XlsxError::InvalidRange(ref v)) if v == range));
+    }
+}
+
+#[test]
+fn read_csv() {
+    let data = "name,price,active,date,zip\r\n\"Smith, \"\"J\"\"\",1.5,TRUE,2024-01-31,00123\r\n\"multi\nline\",-2e3,false,2024-01-31 12:00:00,\r\n";
+    let mut option = umya_spreadsheet::structs::CsvReaderOption::default();
+    option.set_has_header(true);
+    let book = umya_spreadsheet::reader::csv::read_reader(data.as_bytes(), Some(&option)).unwrap();
+    let sheet = book.get_sheet(&0).unwrap();
+    assert_eq!(sheet.get_value("B1"), "price");
+    assert_eq!(sheet.get_value("A2"), "Smith, \"J\"");
+    assert_eq!(sheet.get_value("A3"), "multi\nline");
+    assert_eq!(sheet.get_value_number("B2"), Some(1.5));
+    assert_eq!(sheet.get_value_number("B3"), Some(-2000.0));
+    assert_eq!(
+        sheet.get_cell("C2").unwrap().get_raw_value(),
+        &CellRawValue::Bool(true)
+    );
+    assert_eq!(
+        sheet.get_cell("C3").unwrap().get_raw_value(),
+        &CellRawValue::Bool(false)
+    );
+    assert_eq!(sheet.get_value_number("D2"), Some(45322.0));
+    assert_eq!(sheet.get_value_number("D3"), Some(45322.5));
+    assert_eq!(sheet.get_formatted_value("D2"), "2024-01-31");
+    assert_eq!(sheet.get_value("E2"), "00123");
+    assert!(sheet.get_cell("E3").is_none());
+
+    // TSV into an existing workbook
+    let mut book = umya_spreadsheet::new_file();
+    let mut option = umya_spreadsheet::structs::CsvReaderOption::default();
+    option.set_delimiter('\t');
+    option.set_sheet_name("Imported");
+    option.set_start_cell("C3");
+    option.set_infer_types(false);
+    umya_spreadsheet::reader::csv::read_reader_into(
+        &mut book,
+        "a\t1\nb\t2".as_bytes(),
+        Some(&option),
+    )
+    .unwrap();
+    let sheet = book.get_sheet_by_name("Imported").unwrap();
+    assert_eq!(sheet.get_value("C3"), "a");
+    assert_eq!(sheet.get_value("D4"), "2");
+    assert_eq!(
+        sheet.get_cell("D4").unwrap().get_raw_value(),
+        &CellRawValue::String("2".into())
+    );
+
+    // the existing sheet is reused
+    option.set_start_cell("A1");
+    umya_spreadsheet::reader::csv::read_reader_into(&mut book, "c".as_bytes(), Some(&option))
+        .unwrap();
+    assert_eq!(book.get_sheet_count(), 2);
+    let sheet = book.get_sheet_by_name("Imported").unwrap();
+    assert_eq!(sheet.get_value("A1"), "c");
+    assert_eq!(sheet.get_value("C3"), "a");
+
+    // encoding
+    let mut option = umya_spreadsheet::structs::CsvReaderOption::default();
+    option.set_csv_encode_value(umya_spreadsheet::structs::CsvEncodeValues::ShiftJis);
+    let (bytes, _, _) = encoding_rs::SHIFT_JIS.encode("漢字,あいうえお");
+    let book = umya_spreadsheet::reader::csv::read_reader(&bytes[..], Some(&option)).unwrap();
+    assert_eq!(book.get_sheet(&0).unwrap().get_value("A1"), "漢字");
+    assert_eq!(book.get_sheet(&0).unwrap().get_value("B1"), "あいうえお");
+}
+
 #[test]
 fn new_file_empty_worksheet() {
     let book = umya_spreadsheet::new_file_empty_worksheet();
@@ -1452,6 +1637,27 @@ fn issue_190() {
 
     let path = std::path::Path::new("./tests/result_files/issue_190.xlsx");
     let _ = umya_spreadsheet::writer::xlsx::write(&book, path);
+
+    // the grouping of the bar chart is kept
+    let book = umya_spreadsheet::reader::xlsx::read(path).unwrap();
+    let grouping = book
+        .get_sheet_collection_no_check()
+        .iter()
+        .flat_map(|v| v.get_chart_collection())
+        .find_map(|v| {
+            v.get_chart_space()
+                .get_chart()
+                .get_plot_area()
+                .get_bar_chart()
+        })
+        .unwrap()
+        .get_grouping()
+        .get_val()
+        .clone();
+    assert!(matches!(
+        grouping,
+        umya_spreadsheet::drawing::charts::GroupingValues::Clustered
+    ));
 }
 
 #[test]
@@ -1816,7 +2022,7 @@ fn issue_225() {
 fn issue_227() {
     let path = std::path::Path::new("./tests/test_files/aaa.xlsx");
     let mut book = umya_spreadsheet::reader::xlsx::lazy_read(path).unwrap();
-    book.read_sheet_by_name("Sheet2");
+    book.read_sheet_by_name("Sheet2").unwrap();
     let sheet = book.get_sheet_by_name("Sheet2").unwrap();
     assert_eq!("3", sheet.get_cell("B6").unwrap().get_value());
 }
@@ -1830,7 +2036,7 @@ fn issue_230() {
         .get_style_mut("A1")
         .get_number_format_mut()
         .set_format_code("#\\ #");
-    //assert_eq!("1 2", sheet.get_formatted_value("A1"));
+    assert_eq!("1 2", sheet.get_formatted_value("A1"));
 }
 
 #[test]
@@ -1983,3 +2189,2920 @@ fn issue_268() {
 
     println!("All sheets copied successfully!");
 }
+
+#[test]
+fn calculate_formula() {
+    let mut book = new_file();
+    let _ = book.new_sheet("Data Sheet");
+    {
+        let sheet = book.get_sheet_by_name_mut("Sheet1").unwrap();
+        sheet.get_cell_mut("A1").set_value_number(10);
+        sheet.get_cell_mut("A2").set_value_number(20);
+        sheet.get_cell_mut("A3").set_value_number(30);
+        sheet.get_cell_mut("A4").set_value("text");
+        sheet.get_cell_mut("B1").set_formula("SUM(A1:A4)");
+        sheet
+            .get_cell_mut("B2")
+            .set_formula("IF(B1>50,\"big\",\"small\")");
+        sheet.get_cell_mut("B3").set_formula("A1/0");
+        sheet.get_cell_mut("B4").set_formula("'Data Sheet'!A1*2");
+        sheet
+            .get_cell_mut("B5")
+            .set_formula("VLOOKUP(\"b\",'Data Sheet'!A2:B3,2,FALSE)");
+        sheet
+            .get_cell_mut("B6")
+            .set_formula("INDEX(A1:A3,MATCH(20,A1:A3,0))");
+        sheet
+            .get_cell_mut("B7")
+            .set_formula("YEAR(DATE(2024,2,30))&\"-\"&MONTH(DATE(2024,2,30))");
+        sheet.get_cell_mut("B8").set_formula("UNKNOWNFUNC(1)");
+        sheet.get_cell_mut("B9").set_formula("Missing!A1");
+        sheet.get_cell_mut("B10").set_formula("B11+1");
+        sheet.get_cell_mut("B11").set_formula("B1*2");
+        sheet
+            .get_cell_mut("B12")
+            .set_formula("UPPER(LEFT(A4,2))&LEN(A4)");
+        sheet
+            .get_cell_mut("B13")
+            .set_formula("COUNTIF(A1:A4,\">15\")");
+        sheet.get_cell_mut("B14").set_formula("A1>=10");
+    }
+    {
+        let sheet = book.get_sheet_by_name_mut("Data Sheet").unwrap();
+        sheet.get_cell_mut("A1").set_value_number(7);
+        sheet.get_cell_mut("A2").set_value("a");
+        sheet.get_cell_mut("B2").set_value_number(1);
+        sheet.get_cell_mut("A3").set_value("b");
+        sheet.get_cell_mut("B3").set_value_number(2);
+    }
+
+    book.calculate().unwrap();
+
+    let sheet = book.get_sheet_by_name("Sheet1").unwrap();
+    assert_eq!(sheet.get_value("B1"), "60");
+    assert_eq!(sheet.get_value("B2"), "big");
+    assert_eq!(
+        sheet.get_cell_value("B3").get_raw_value(),
+        &CellRawValue::Error(CellErrorType::Div0)
+    );
+    assert_eq!(sheet.get_value("B4"), "14");
+    assert_eq!(sheet.get_value("B5"), "2");
+    assert_eq!(sheet.get_value("B6"), "20");
+    assert_eq!(sheet.get_value("B7"), "2024-3");
+    assert_eq!(sheet.get_value("B8"), "#NAME?");
+    assert_eq!(sheet.get_value("B9"), "#REF!");
+    assert_eq!(sheet.get_value("B10"), "121");
+    assert_eq!(sheet.get_value("B12"), "TE4");
+    assert_eq!(sheet.get_value("B13"), "2");
+    assert_eq!(
+        sheet.get_cell_value("B14").get_raw_value(),
+        &CellRawValue::Bool(true)
+    );
+    assert_eq!(sheet.get_cell("B1").unwrap().get_formula(), "SUM(A1:A4)");
+
+    let path = std::path::Path::new("./tests/result_files/calculate_formula.xlsx");
+    let _ = writer::xlsx::write(&book, path);
+    let book = reader::xlsx::read(path).unwrap();
+    let sheet = book.get_sheet_by_name("Sheet1").unwrap();
+    assert_eq!(sheet.get_value_number("B1"), Some(60f64));
+    assert_eq!(sheet.get_value("B3"), "#DIV/0!");
+
+    let mut book = new_file();
+    let sheet = book.get_sheet_mut(&0).unwrap();
+    sheet.get_cell_mut("A1").set_value_number(2);
+    sheet.get_cell_mut("A2").set_formula("A1^10");
+    assert_eq!(sheet.calculate_cell("A2"), &CellRawValue::Numeric(1024f64));
+}
+
+#[test]
+fn write_streaming_workbook() {
+    use umya_spreadsheet::writer::xlsx::{StreamingCell, StreamingWorkbook};
+
+    for use_shared_strings in [true, false] {
+        let path = if use_shared_strings {
+            "./tests/result_files/bbb_streaming_shared.xlsx"
+        } else {
+            "./tests/result_files/bbb_streaming_inline.xlsx"
+        };
+        let file = std::fs::File::create(path).unwrap();
+        let mut book = StreamingWorkbook::new(std::io::BufWriter::new(file));
+        book.set_use_shared_strings(use_shared_strings);
+        assert!(matches!(
+            book.write_row(&["early".into()]),
+            Err(XlsxError::NoActiveSheet)
+        ));
+        let mut style = Style::default();
+        style.get_font_mut().set_bold(true);
+        let bold = book.add_style(&style);
+
+        let sheet = book.new_sheet("Data").unwrap();
+        sheet.get_column_dimension_mut("A").set_width(25.0);
+        sheet.add_merge_cells("D1:E1");
+        let mut pane = Pane::default();
+        pane.set_vertical_split(1.0)
+            .set_state(PaneStateValues::Frozen)
+            .set_active_pane(PaneValues::BottomLeft);
+        pane.get_top_left_cell_mut().set_coordinate("A2");
+        sheet.get_sheet_views_mut().get_sheet_view_list_mut()[0].set_pane(pane);
+
+        book.write_row(&[
+            StreamingCell::from("name").set_style_id(bold).clone(),
+            StreamingCell::from("value").set_style_id(bold).clone(),
+            StreamingCell::from("flag"),
+            StreamingCell::from(" merged "),
+        ])
+        .unwrap();
+        for i in 0..10000 {
+            book.write_row(&[
+                format!("item{}", i).into(),
+                (i as f64 * 1.5).into(),
+                (i % 2 == 0).into(),
+                StreamingCell::from("0123"),
+            ])
+            .unwrap();
+        }
+        book.write_row(&[f64::NAN.into(), f64::INFINITY.into(), 2.5.into()])
+            .unwrap();
+        book.write_row_at(20000, &["last".into()]).unwrap();
+        assert!(matches!(
+            book.write_row_at(100, &["back".into()]),
+            Err(XlsxError::InvalidRange(_))
+        ));
+        assert!(matches!(
+            book.write_row_at(1_048_577, &["below".into()]),
+            Err(XlsxError::InvalidRange(_))
+        ));
+        assert!(matches!(
+            book.write_row_at(20001, &vec![StreamingCell::from(1.0); 16385]),
+            Err(XlsxError::InvalidRange(_))
+        ));
+        book.write_row_at(20001, &vec![StreamingCell::from(1.0); 16384])
+            .unwrap();
+
+        book.new_sheet("Second").unwrap();
+        book.write_row(&["other".into()]).unwrap();
+        assert!(matches!(
+            book.new_sheet("Data"),
+            Err(XlsxError::DuplicateSheetName(name)) if name == "Data"
+        ));
+        book.finish().unwrap();
+
+        let book = umya_spreadsheet::reader::xlsx::read(path).unwrap();
+        assert_eq!(book.get_sheet_count(), 2);
+        let sheet = book.get_sheet_by_name("Data").unwrap();
+        assert_eq!(sheet.get_value("A1"), "name");
+        assert!(*sheet
+            .get_cell("A1")
+            .unwrap()
+            .get_style()
+            .get_font()
+            .unwrap()
+            .get_bold());
+        assert_eq!(sheet.get_value("D1"), " merged ");
+        assert_eq!(sheet.get_value("A10001"), "item9999");
+        assert_eq!(sheet.get_value_number("B3"), Some(1.5));
+        assert_eq!(sheet.get_value("C2"), "TRUE");
+        assert_eq!(sheet.get_value("D2"), "0123");
+        assert_eq!(sheet.get_value("A10002"), "#NUM!");
+        assert_eq!(sheet.get_value("B10002"), "#NUM!");
+        assert_eq!(sheet.get_value_number("C10002"), Some(2.5));
+        assert_eq!(sheet.get_value("A20000"), "last");
+        assert_eq!(sheet.get_value("XFD20001"), "1");
+        assert!(sheet.get_cell("A10003").is_none());
+        assert_eq!(sheet.get_column_dimension("A").unwrap().get_width(), &25.0);
+        assert_eq!(sheet.get_merge_cells()[0].get_range(), "D1:E1");
+        assert_eq!(
+            sheet.get_sheets_views().get_sheet_view_list()[0]
+                .get_pane()
+                .unwrap()
+                .get_top_left_cell()
+                .to_string(),
+            "A2"
+        );
+        assert_eq!(
+            book.get_sheet_by_name("Second").unwrap().get_value("A1"),
+            "other"
+        );
+    }
+}
+
+#[test]
+fn read_rows() {
+    let mut book = umya_spreadsheet::new_file();
+    let sheet = book.get_sheet_by_name_mut("Sheet1").unwrap();
+    sheet.get_cell_mut("A1").set_value_string("name");
+    sheet.get_cell_mut("B1").set_value_string("price");
+    sheet.get_cell_mut("A2").set_value_string("apple & pear");
+    sheet.get_cell_mut("B2").set_value_number(1.5);
+    sheet.get_cell_mut("D2").set_value_bool(true);
+    sheet.get_cell_mut("B3").set_value_number(45000);
+    sheet
+        .get_style_mut("B3")
+        .get_number_format_mut()
+        .set_format_code(umya_spreadsheet::NumberingFormat::FORMAT_DATE_YYYYMMDD);
+    sheet.get_cell_mut("C3").set_formula("B2*2");
+    sheet
+        .get_cell_mut("C3")
+        .get_cell_value_mut()
+        .set_formula_result_default("3");
+    sheet.get_cell_mut("A5").set_value_string(" padded ");
+    let path = std::path::Path::new("./tests/result_files/zzz_read_rows.xlsx");
+    umya_spreadsheet::writer::xlsx::write(&book, path).unwrap();
+
+    let mut sheet_rows = umya_spreadsheet::reader::xlsx::rows(path, "Sheet1").unwrap();
+    let rows: Vec<_> = sheet_rows.iter().unwrap().map(|v| v.unwrap()).collect();
+    assert_eq!(rows.len(), 4);
+    assert_eq!(rows[0].get_row_num(), &1);
+    assert_eq!(rows[0].get_cell(1).unwrap().get_value().get_value(), "name");
+    assert_eq!(
+        rows[1].get_cell(1).unwrap().get_value().get_value(),
+        "apple & pear"
+    );
+    assert_eq!(
+        rows[1].get_cell(2).unwrap().get_value().get_value_number(),
+        Some(1.5)
+    );
+    let values = rows[1].to_values();
+    assert_eq!(values.len(), 4);
+    assert!(values[2].is_empty());
+    assert_eq!(values[3].get_value(), "TRUE");
+    let date = rows[2].get_cell(2).unwrap();
+    assert_eq!(date.get_format_code(), "yyyy-mm-dd");
+    assert_eq!(date.get_formatted_value(), "2023-03-15");
+    let formula = rows[2].get_cell(3).unwrap().get_value();
+    assert_eq!(formula.get_formula(), "B2*2");
+    assert_eq!(formula.get_value_number(), Some(3.0));
+    assert_eq!(rows[3].get_row_num(), &5);
+    assert_eq!(rows[3].get_cells()[0].get_value().get_value(), " padded ");
+
+    // same values as a full read
+    let path = std::path::Path::new("./tests/test_files/aaa.xlsx");
+    let book = umya_spreadsheet::reader::xlsx::read(path).unwrap();
+    let sheet = book.get_sheet_by_name("Sheet1").unwrap();
+    let mut count = 0;
+    let mut row_count = 0;
+    let mut sheet_rows = umya_spreadsheet::reader::xlsx::rows(path, "Sheet1").unwrap();
+    for row in sheet_rows.iter().unwrap() {
+        let row = row.unwrap();
+        row_count += 1;
+        for cell in row.get_cells() {
+            assert_eq!(
+                cell.get_value().get_value(),
+                sheet.get_value((*cell.get_col_num(), *row.get_row_num())),
+            );
+            count += 1;
+        }
+    }
+    assert!(count > 0);
+    // the rows can be read again
+    assert_eq!(sheet_rows.iter().unwrap().count(), row_count);
+
+    match umya_spreadsheet::reader::xlsx::rows(path, "Missing") {
+        Err(umya_spreadsheet::XlsxError::SheetNotFound(_)) => {}
+        _ => panic!("expected SheetNotFound"),
+    }
+
+    // cells and rows without a reference follow the previous ones
+    let path = std::path::Path::new("./tests/result_files/zzz_read_rows.xlsx");
+    let data = rewrite_xlsx_part(
+        path,
+        "xl/worksheets/sheet1.xml",
+        Some(br#"<?xml version="1.0" encoding="UTF-8"?><worksheet><sheetData><row r="2"><c s="0"/><c><v>1</v></c><c r="E2"><v>2</v></c><c/><c t="inlineStr"><is><t>x</t></is></c></row><row><c><v>3</v></c></row></sheetData></worksheet>"#),
+    );
+    let mut sheet_rows =
+        umya_spreadsheet::reader::xlsx::rows_reader(std::io::Cursor::new(data), "Sheet1").unwrap();
+    let rows: Vec<_> = sheet_rows.iter().unwrap().map(|v| v.unwrap()).collect();
+    assert_eq!(rows.len(), 2);
+    let cols: Vec<u32> = rows[0]
+        .get_cells()
+        .iter()
+        .map(|v| *v.get_col_num())
+        .collect();
+    assert_eq!(cols, vec![2, 5, 7]);
+    assert_eq!(rows[0].get_cell(7).unwrap().get_value().get_value(), "x");
+    assert_eq!(rows[1].get_row_num(), &3);
+    assert_eq!(rows[1].get_cells()[0].get_col_num(), &1);
+}
+
+#[test]
+fn auto_filter_criteria() {
+    use umya_spreadsheet::*;
+
+    let mut book = new_file();
+    let sheet = book.get_sheet_mut(&0).unwrap();
+    let data = [
+        ("East", 10.0, "apple"),
+        ("West", 25.0, "banana"),
+        ("North", 40.0, "cherry"),
+        ("East", 55.0, "avocado"),
+        ("South", 70.0, "apricot"),
+    ];
+    sheet.get_cell_mut("A1").set_value("region");
+    sheet.get_cell_mut("B1").set_value("amount");
+    sheet.get_cell_mut("C1").set_value("item");
+    for (i, (region, amount, item)) in data.iter().enumerate() {
+        let row = i as u32 + 2;
+        sheet.get_cell_mut((1, row)).set_value(*region);
+        sheet.get_cell_mut((2, row)).set_value_number(*amount);
+        sheet.get_cell_mut((3, row)).set_value(*item);
+    }
+    sheet.set_auto_filter("A1:C6");
+
+    let auto_filter = sheet.get_auto_filter_mut().unwrap();
+    let mut filters = Filters::default();
+    filters.add_value("east").add_value("South");
+    auto_filter.get_filter_column_mut(0).set_filters(filters);
+
+    let mut custom_filters = CustomFilters::default();
+    let mut condition = CustomFilter::default();
+    condition
+        .set_operator(FilterOperatorValues::GreaterThanOrEqual)
+        .set_val("20");
+    custom_filters.add_custom_filter(condition);
+    let mut condition = CustomFilter::default();
+    condition.set_val("a*");
+    custom_filters.set_and(false).add_custom_filter(condition);
+    auto_filter
+        .get_filter_column_mut(1)
+        .set_custom_filters(custom_filters);
+
+    let mut custom_filters = CustomFilters::default();
+    let mut condition = CustomFilter::default();
+    condition.set_val("a*");
+    custom_filters.add_custom_filter(condition);
+    auto_filter
+        .get_filter_column_mut(2)
+        .set_custom_filters(custom_filters);
+
+    let mut sort_state = SortState::default();
+    sort_state.set_range("A2:C6");
+    let mut sort_condition = SortCondition::default();
+    sort_condition.set_range("B2:B6").set_descending(true);
+    sort_state.add_sort_condition(sort_condition);
+    auto_filter.set_sort_state(sort_state);
+
+    sheet.apply_auto_filter();
+    let hidden = |sheet: &Worksheet, row: u32| {
+        sheet
+            .get_row_dimension(&row)
+            .map(|v| *v.get_hidden())
+            .unwrap_or(false)
+    };
+    // row 2: East, 10 (< 20) -> hidden; row 5: East, 55, avocado -> shown; row 6: South, 70, apricot -> shown
+    assert!(hidden(sheet, 2));
+    assert!(hidden(sheet, 3));
+    assert!(hidden(sheet, 4));
+    assert!(!hidden(sheet, 5));
+    assert!(!hidden(sheet, 6));
+
+    // top 2 / above average / blanks
+    let auto_filter = sheet.get_auto_filter_mut().unwrap();
+    auto_filter.get_filter_column_list_mut().clear();
+    let mut top10 = Top10::default();
+    top10.set_val(2.0);
+    auto_filter.get_filter_column_mut(1).set_top10(top10);
+    sheet.apply_auto_filter();
+    assert!(hidden(sheet, 4));
+    assert!(!hidden(sheet, 5));
+    assert!(!hidden(sheet, 6));
+    // values that are not finite are not ranked
+    sheet.get_cell_mut("B3").set_value_number(f64::NAN);
+    sheet.apply_auto_filter();
+    assert!(hidden(sheet, 3));
+    assert!(!hidden(sheet, 5));
+    assert!(!hidden(sheet, 6));
+    sheet.get_cell_mut("B3").set_value_number(25.0);
+
+    let mut dynamic_filter = DynamicFilter::default();
+    dynamic_filter.set_type(DynamicFilterValues::BelowAverage);
+    sheet
+        .get_auto_filter_mut()
+        .unwrap()
+        .get_filter_column_mut(1)
+        .set_dynamic_filter(dynamic_filter);
+    sheet.apply_auto_filter();
+    assert!(!hidden(sheet, 2));
+    assert!(!hidden(sheet, 3));
+    assert!(hidden(sheet, 4));
+
+    // round trip
+    let mut filters = Filters::default();
+    filters.set_blank(true).add_value("West");
+    let mut item = DateGroupItem::default();
+    item.set_year(2024)
+        .set_month(3)
+        .set_date_time_grouping(DateTimeGroupingValues::Month);
+    filters.add_date_group_item(item);
+    let mut color_filter = ColorFilter::default();
+    let mut style = Style::default();
+    style.set_background_color("FFFF0000");
+    color_filter.set_style(style);
+    let mut icon_filter = IconFilter::default();
+    icon_filter
+        .set_icon_set(IconSetValues::ThreeArrows)
+        .set_icon_id(2);
+    let auto_filter = sheet.get_auto_filter_mut().unwrap();
+    auto_filter.get_filter_column_mut(0).set_filters(filters);
+    auto_filter
+        .get_filter_column_mut(2)
+        .set_color_filter(color_filter);
+    let mut filter_column = FilterColumn::default();
+    filter_column.set_column_id(3).set_icon_filter(icon_filter);
+    auto_filter.add_filter_column(filter_column);
+
+    // a sort of a range outside of the auto filter
+    let mut sort_state = SortState::default();
+    sort_state.set_range("E2:F6");
+    let mut sort_condition = SortCondition::default();
+    sort_condition.set_range("F2:F6");
+    sort_state.add_sort_condition(sort_condition);
+    sheet.set_sort_state(sort_state);
+
+    let path = std::path::Path::new("./tests/result_files/zzz_auto_filter.xlsx");
+    writer::xlsx::write(&book, path).unwrap();
+    let book = reader::xlsx::read(path).unwrap();
+    let sheet = book.get_sheet(&0).unwrap();
+    let auto_filter = sheet.get_auto_filter().unwrap();
+    assert_eq!(auto_filter.get_range().get_range(), "A1:C6");
+    assert_eq!(auto_filter.get_filter_column_list().len(), 4);
+    let filters = auto_filter
+        .get_filter_column(0)
+        .unwrap()
+        .get_filters()
+        .unwrap();
+    assert!(*filters.get_blank());
+    assert_eq!(filters.get_filter_list()[0].get_val(), "West");
+    assert_eq!(filters.get_date_group_item_list()[0].get_year(), &2024);
+    assert_eq!(filters.get_date_group_item_list()[0].get_month(), &3);
+    assert_eq!(
+        filters.get_date_group_item_list()[0].get_date_time_grouping(),
+        &DateTimeGroupingValues::Month
+    );
+    let dynamic_filter = auto_filter
+        .get_filter_column(1)
+        .unwrap()
+        .get_dynamic_filter()
+        .unwrap();
+    assert_eq!(
+        dynamic_filter.get_type(),
+        &DynamicFilterValues::BelowAverage
+    );
+    let color_filter = auto_filter
+        .get_filter_column(2)
+        .unwrap()
+        .get_color_filter()
+        .unwrap();
+    assert_eq!(
+        color_filter
+            .get_style()
+            .unwrap()
+            .get_background_color()
+            .unwrap()
+            .get_argb(),
+        "FFFF0000"
+    );
+    let icon_filter = auto_filter
+        .get_filter_column(3)
+        .unwrap()
+        .get_icon_filter()
+        .unwrap();
+    assert_eq!(icon_filter.get_icon_set(), &IconSetValues::ThreeArrows);
+    assert_eq!(icon_filter.get_icon_id(), Some(&2));
+    let sort_state = auto_filter.get_sort_state().unwrap();
+    assert_eq!(sort_state.get_range().get_range(), "A2:C6");
+    assert_eq!(
+        sort_state.get_sort_condition_list()[0]
+            .get_range()
+            .get_range(),
+        "B2:B6"
+    );
+    assert!(*sort_state.get_sort_condition_list()[0].get_descending());
+    assert!(*sheet.get_row_dimension(&4).unwrap().get_hidden());
+    let sort_state = sheet.get_sort_state().unwrap();
+    assert_eq!(sort_state.get_range().get_range(), "E2:F6");
+    assert_eq!(
+        sort_state.get_sort_condition_list()[0]
+            .get_range()
+            .get_range(),
+        "F2:F6"
+    );
+}
+
+#[test]
+fn outline_grouping() {
+    let mut book = umya_spreadsheet::new_file();
+    let sheet = book.get_sheet_mut(&0).unwrap();
+    for row in 1..=10 {
+        sheet.get_cell_mut((1, row)).set_value_number(row);
+    }
+    sheet.group_rows("2:8", 1).unwrap();
+    sheet.group_rows("3:5", 2).unwrap();
+    sheet.ungroup_rows("8:8").unwrap();
+    for row in 3..=5 {
+        sheet.get_row_dimension_mut(&row).set_hidden(true);
+    }
+    sheet.get_row_dimension_mut(&6).set_collapsed(true);
+    sheet.group_columns("B:D", 1).unwrap();
+    sheet.get_column_dimension_mut("E").set_collapsed(true);
+    // a column range has no rows and a row range has no columns
+    assert!(sheet.group_rows("B:D", 1).is_err());
+    assert!(sheet.ungroup_rows("B:D").is_err());
+    assert!(sheet.group_columns("2:8", 1).is_err());
+    assert!(sheet.ungroup_columns("2:8").is_err());
+    assert!(sheet.get_row_dimension(&0).is_none());
+    assert!(sheet.get_column_dimension_by_number(&0).is_none());
+    sheet
+        .get_outline_properties_mut()
+        .set_summary_below(false)
+        .set_summary_right(false);
+
+    assert_eq!(sheet.get_row_dimension(&2).unwrap().get_outline_level(), &1);
+    assert_eq!(sheet.get_row_dimension(&4).unwrap().get_outline_level(), &2);
+    assert_eq!(sheet.get_row_dimension(&8).unwrap().get_outline_level(), &0);
+
+    // shifting keeps the outline
+    sheet.insert_new_row(&1, &2);
+    sheet.remove_row(&1, &1);
+    assert_eq!(sheet.get_row_dimension(&5).unwrap().get_outline_level(), &2);
+    assert!(*sheet.get_row_dimension(&7).unwrap().get_collapsed());
+    sheet.insert_new_column("A", &1);
+    assert_eq!(
+        sheet.get_column_dimension("C").unwrap().get_outline_level(),
+        &1
+    );
+
+    let path = std::path::Path::new("./tests/result_files/zzz_outline.xlsx");
+    umya_spreadsheet::writer::xlsx::write(&book, path).unwrap();
+    let book = umya_spreadsheet::reader::xlsx::read(path).unwrap();
+    let sheet = book.get_sheet(&0).unwrap();
+    assert_eq!(sheet.get_row_dimension(&3).unwrap().get_outline_level(), &1);
+    assert_eq!(sheet.get_row_dimension(&5).unwrap().get_outline_level(), &2);
+    assert!(*sheet.get_row_dimension(&5).unwrap().get_hidden());
+    assert!(*sheet.get_row_dimension(&7).unwrap().get_collapsed());
+    assert_eq!(sheet.get_row_dimension(&9).unwrap().get_outline_level(), &0);
+    assert_eq!(
+        sheet.get_column_dimension("D").unwrap().get_outline_level(),
+        &1
+    );
+    assert!(*sheet.get_column_dimension("F").unwrap().get_collapsed());
+    assert_eq!(
+        sheet.get_sheet_format_properties().get_outline_level_row(),
+        &2
+    );
+    assert_eq!(
+        sheet
+            .get_sheet_format_properties()
+            .get_outline_level_column(),
+        &1
+    );
+    let outline_properties = sheet.get_outline_properties().unwrap();
+    assert!(!*outline_properties.get_summary_below());
+    assert!(!*outline_properties.get_summary_right());
+    assert!(*outline_properties.get_show_outline_symbols());
+}
+
+#[test]
+fn sparkline_groups() {
+    use umya_spreadsheet::drawing::charts::DisplayBlanksAsValues;
+    use umya_spreadsheet::structs::office2010::excel::*;
+
+    let mut book = umya_spreadsheet::new_file();
+    let sheet = book.get_sheet_by_name_mut("Sheet1").unwrap();
+    for row in 1..=2 {
+        for col in 1..=5 {
+            sheet
+                .get_cell_mut((col, row))
+                .set_value_number(col as i32 - 3);
+        }
+    }
+
+    let mut group = SparklineGroup::default();
+    group
+        .set_type(SparklineTypeValues::Column)
+        .set_negative(true)
+        .set_high(true)
+        .set_display_empty_cells_as(DisplayBlanksAsValues::Zero)
+        .set_min_axis_type(SparklineAxisMinMaxValues::Custom)
+        .set_manual_min(-5.0)
+        .add_sparkline_range("Sheet1!A1:E1", "F1")
+        .add_sparkline_range("Sheet1!A2:E2", "F2");
+    group.get_color_series_mut().set_argb("FF376092");
+    group.get_color_negative_mut().set_argb("FFD00000");
+    sheet.add_sparkline_group(group);
+
+    let mut group = SparklineGroup::default();
+    group
+        .set_type(SparklineTypeValues::Stacked)
+        .add_sparkline_range("Sheet1!A1:E1", "G1");
+    sheet.add_sparkline_group(group);
+
+    let path = std::path::Path::new("./tests/result_files/zzz_sparkline.xlsx");
+    umya_spreadsheet::writer::xlsx::write(&book, path).unwrap();
+    let book = umya_spreadsheet::reader::xlsx::read(path).unwrap();
+    let sheet = book.get_sheet(&0).unwrap();
+    let groups = sheet.get_sparkline_groups().get_sparkline_group_list();
+    assert_eq!(groups.len(), 2);
+
+    let group = &groups[0];
+    assert_eq!(group.get_type(), &SparklineTypeValues::Column);
+    assert!(*group.get_negative());
+    assert!(*group.get_high());
+    assert!(!*group.get_markers());
+    assert_eq!(group.get_line_weight(), &0.75);
+    assert_eq!(group.get_manual_min(), &-5.0);
+    assert_eq!(
+        group.get_min_axis_type(),
+        &SparklineAxisMinMaxValues::Custom
+    );
+    assert_eq!(
+        group.get_max_axis_type(),
+        &SparklineAxisMinMaxValues::Individual
+    );
+    assert_eq!(group.get_color_series().get_argb(), "FF376092");
+    assert_eq!(group.get_color_negative().get_argb(), "FFD00000");
+    let sparklines = group.get_sparkline_list();
+    assert_eq!(sparklines.len(), 2);
+    assert_eq!(sparklines[1].get_data_range(), "Sheet1!A2:E2");
+    assert_eq!(sparklines[1].get_location(), "F2");
+
+    assert_eq!(groups[1].get_type(), &SparklineTypeValues::Stacked);
+    assert_eq!(groups[1].get_sparkline_list()[0].get_location(), "G1");
+}
+
+#[test]
+fn sparkline_groups_insert_and_remove() {
+    use umya_spreadsheet::structs::office2010::excel::*;
+
+    let mut book = umya_spreadsheet::new_file();
+    book.new_sheet("Sheet2").unwrap();
+    let sheet = book.get_sheet_by_name_mut("Sheet1").unwrap();
+    let mut group = SparklineGroup::default();
+    group
+        .add_sparkline_range("Sheet1!A1:E1", "F1")
+        .add_sparkline_range("Sheet2!A2:E2", "F2");
+    sheet.add_sparkline_group(group);
+    let mut group = SparklineGroup::default();
+    group.add_sparkline_range("Sheet1!A3:E3", "G3");
+    sheet.add_sparkline_group(group);
+
+    let get_sparklines = |book: &umya_spreadsheet::Spreadsheet| {
+        book.get_sheet_by_name("Sheet1")
+            .unwrap()
+            .get_sparkline_groups()
+            .get_sparkline_group_list()
+            .iter()
+            .flat_map(|v| v.get_sparkline_list())
+            .map(|v| (v.get_data_range(), v.get_location()))
+            .collect::<Vec<(String, String)>>()
+    };
+
+    book.insert_new_row("Sheet1", &1, &2);
+    book.insert_new_column("Sheet1", "B", &1);
+    assert_eq!(
+        get_sparklines(&book),
+        vec![
+            ("Sheet1!A3:F3".to_string(), "G3".to_string()),
+            ("Sheet2!A2:E2".to_string(), "G4".to_string()),
+            ("Sheet1!A5:F5".to_string(), "H5".to_string()),
+        ]
+    );
+
+    // the sparklines drawn in removed cells are removed with their groups
+    book.get_sheet_by_name_mut("Sheet1")
+        .unwrap()
+        .remove_row(&5, &1);
+    assert_eq!(
+        get_sparklines(&book),
+        vec![
+            ("Sheet1!A3:F3".to_string(), "G3".to_string()),
+            ("Sheet2!A2:E2".to_string(), "G4".to_string()),
+        ]
+    );
+
+    // data ranges on the other sheet follow that sheet
+    book.insert_new_row("Sheet2", &1, &1);
+    assert_eq!(get_sparklines(&book)[1].0, "Sheet2!A3:E3");
+}
+
+fn rewrite_xlsx_part(src: &std::path::Path, part: &str, data: Option<&[u8]>) -> Vec<u8> {
+    use std::io::{Read, Write};
+    let mut arv = zip::ZipArchive::new(std::fs::File::open(src).unwrap()).unwrap();
+    let mut writer = zip::ZipWriter::new(std::io::Cursor::new(Vec::new()));
+    for i in 0..arv.len() {
+        let mut file = arv.by_index(i).unwrap();
+        let name = file.name().to_string();
+        let mut buf = Vec::new();
+        file.read_to_end(&mut buf).unwrap();
+        if name == part {
+            match data {
+                Some(v) => buf = v.to_vec(),
+                None => continue,
+            }
+        }
+        writer
+            .start_file(name, zip::write::SimpleFileOptions::default())
+            .unwrap();
+        writer.write_all(&buf).unwrap();
+    }
+    writer.finish().unwrap().into_inner()
+}
+
+#[test]
+fn read_malformed_parts() {
+    let path = std::path::Path::new("./tests/test_files/aaa.xlsx");
+
+    // unclosed element in a worksheet
+    let data = rewrite_xlsx_part(
+        path,
+        "xl/worksheets/sheet1.xml",
+        Some(br#"<?xml version="1.0" encoding="UTF-8"?><worksheet><sheetData><row r="1"><c r="A1"><v>1</v></c>"#),
+    );
+    let result = umya_spreadsheet::reader::xlsx::read_reader(std::io::Cursor::new(data), true);
+    match result {
+        Err(XlsxError::Part { path, source }) => {
+            assert_eq!(path, "xl/worksheets/sheet1.xml");
+            assert!(matches!(*source, XlsxError::MissingEndElement { .. }));
+        }
+        _ => panic!("expected a part error"),
+    }
+
+    // invalid attribute value
+    let data = rewrite_xlsx_part(
+        path,
+        "xl/worksheets/sheet1.xml",
+        Some(br#"<?xml version="1.0" encoding="UTF-8"?><worksheet><sheetData><row r="1"><c r="A1" s="abc"><v>1</v></c></row></sheetData></worksheet>"#),
+    );
+    let result = umya_spreadsheet::reader::xlsx::read_reader(std::io::Cursor::new(data), true);
+    match result {
+        Err(XlsxError::Part { source, .. }) => match *source {
+            XlsxError::InvalidAttribute { name, value } => {
+                assert_eq!(name, "s");
+                assert_eq!(value, "abc");
+            }
+            _ => panic!("expected an invalid attribute"),
+        },
+        _ => panic!("expected a part error"),
+    }
+
+    // enum and boolean attribute values out of range
+    for (sheet, name, value) in [
+        (
+            r#"<sheetViews><sheetView workbookViewId="0"><pane xSplit="1" activePane="middle"/></sheetView></sheetViews><sheetData/>"#,
+            "activePane",
+            "middle",
+        ),
+        (
+            r#"<sheetData><row r="1" hidden="yes"/></sheetData>"#,
+            "hidden",
+            "yes",
+        ),
+    ] {
+        let xml = format!(
+            r#"<?xml version="1.0" encoding="UTF-8"?><worksheet>{}</worksheet>"#,
+            sheet
+        );
+        let data = rewrite_xlsx_part(path, "xl/worksheets/sheet1.xml", Some(xml.as_bytes()));
+        let result = umya_spreadsheet::reader::xlsx::read_reader(std::io::Cursor::new(data), true);
+        match result {
+            Err(XlsxError::Part { source, .. }) => {
+                assert!(matches!(
+                    *source,
+                    XlsxError::InvalidAttribute { name: ref n, value: ref v } if n == name && v == value
+                ));
+            }
+            _ => panic!("expected a part error"),
+        }
+    }
+
+    // attribute value that is not utf-8
+    let data = rewrite_xlsx_part(
+        path,
+        "xl/worksheets/sheet1.xml",
+        Some(b"<?xml version=\"1.0\" encoding=\"UTF-8\"?><worksheet><sheetData><row r=\"1\"><c r=\"A\xff\"><v>1</v></c></row></sheetData></worksheet>"),
+    );
+    let result = umya_spreadsheet::reader::xlsx::read_reader(std::io::Cursor::new(data), true);
+    match result {
+        Err(XlsxError::Part { source, .. }) => {
+            assert!(matches!(*source, XlsxError::InvalidAttribute { ref name, .. } if name == "r"));
+        }
+        _ => panic!("expected a part error"),
+    }
+
+    // ole objects of a worksheet without relationships
+    let data = rewrite_xlsx_part(
+        path,
+        "xl/worksheets/sheet7.xml",
+        Some(br#"<?xml version="1.0" encoding="UTF-8"?><worksheet><sheetData/><oleObjects><oleObject progId="Package" shapeId="1025" r:id="rId1"/></oleObjects></worksheet>"#),
+    );
+    let result = umya_spreadsheet::reader::xlsx::read_reader(std::io::Cursor::new(data), true);
+    match result {
+        Err(XlsxError::MissingPart(v)) => assert!(v.contains("xl/worksheets/sheet7.xml")),
+        _ => panic!("expected a missing part"),
+    }
+
+    // lazily read worksheets report the error from read_sheet
+    let data = rewrite_xlsx_part(
+        path,
+        "xl/worksheets/sheet1.xml",
+        Some(br#"<?xml version="1.0" encoding="UTF-8"?><worksheet><sheetData><row r="1"></sheetData></worksheet>"#),
+    );
+    let mut book =
+        umya_spreadsheet::reader::xlsx::read_reader(std::io::Cursor::new(data), false).unwrap();
+    assert!(matches!(book.read_sheet(0), Err(XlsxError::Part { .. })));
+    assert!(matches!(
+        book.try_get_sheet_mut(&0),
+        Err(XlsxError::Part { .. })
+    ));
+    assert!(matches!(
+        book.try_get_sheet_by_name_mut("NotExists"),
+        Err(XlsxError::SheetNotFound(_))
+    ));
+    assert!(matches!(
+        book.try_get_sheet_collection_mut(),
+        Err(XlsxError::Part { .. })
+    ));
+    assert!(matches!(
+        book.try_insert_new_row("Sheet1", &1, &1),
+        Err(XlsxError::Part { .. })
+    ));
+    assert!(matches!(
+        book.try_remove_column("Sheet1", "A", &1),
+        Err(XlsxError::Part { .. })
+    ));
+    assert!(book.try_get_sheet_mut(&1).is_ok());
+    assert!(matches!(
+        book.read_sheet_by_name("NotExists"),
+        Err(XlsxError::SheetNotFound(_))
+    ));
+
+    // defined name of a sheet that does not exist
+    let workbook = {
+        use std::io::Read;
+        let mut arv = zip::ZipArchive::new(std::fs::File::open(path).unwrap()).unwrap();
+        let mut buf = String::new();
+        arv.by_name("xl/workbook.xml")
+            .unwrap()
+            .read_to_string(&mut buf)
+            .unwrap();
+        buf.replace(r#"localSheetId="0""#, r#"localSheetId="9""#)
+    };
+    let data = rewrite_xlsx_part(path, "xl/workbook.xml", Some(workbook.as_bytes()));
+    let result = umya_spreadsheet::reader::xlsx::read_reader(std::io::Cursor::new(data), true);
+    match result {
+        Err(XlsxError::Part { path, source }) => {
+            assert_eq!(path, "xl/workbook.xml");
+            assert!(matches!(
+                *source,
+                XlsxError::InvalidAttribute { ref name, ref value }
+                    if name == "localSheetId" && value == "9"
+            ));
+        }
+        _ => panic!("expected a part error"),
+    }
+
+    // missing part
+    let data = rewrite_xlsx_part(path, "xl/styles.xml", None);
+    let result = umya_spreadsheet::reader::xlsx::read_reader(std::io::Cursor::new(data), true);
+    match result {
+        Err(XlsxError::MissingPart(v)) => assert_eq!(v, "xl/styles.xml"),
+        _ => panic!("expected a missing part"),
+    }
+}
+
+#[test]
+fn conditional_formatting_icon_set_and_data_bar_options() {
+    use umya_spreadsheet::structs::office2010::excel::*;
+
+    let mut book = umya_spreadsheet::new_file();
+    let sheet = book.get_sheet_by_name_mut("Sheet1").unwrap();
+    for row in 1..=10 {
+        sheet
+            .get_cell_mut((1, row))
+            .set_value_number(row as i32 - 5);
+        sheet.get_cell_mut((2, row)).set_value_number(row as i32);
+        sheet.get_cell_mut((3, row)).set_value_number(row as i32);
+    }
+
+    // data bar with x14 options
+    let mut data_bar = DataBar::default();
+    let mut cfvo = ConditionalFormatValueObject::default();
+    cfvo.set_type(ConditionalFormatValueObjectValues::AutoMin);
+    data_bar.add_cfvo_collection(cfvo);
+    let mut cfvo = ConditionalFormatValueObject::default();
+    cfvo.set_type(ConditionalFormatValueObjectValues::AutoMax);
+    data_bar.add_cfvo_collection(cfvo);
+    let mut color = Color::default();
+    color.set_argb("FF638EC6");
+    data_bar
+        .add_color_collection(color)
+        .set_min_length(0)
+        .set_max_length(100)
+        .set_show_value(false)
+        .set_gradient(false)
+        .set_border(true)
+        .set_axis_position(DataBarAxisPositionValues::Middle)
+        .set_direction(DataBarDirectionValues::LeftToRight);
+    data_bar.get_border_color_mut().set_argb("FF638EC6");
+    data_bar.get_negative_fill_color_mut().set_argb("FFFF0000");
+    data_bar.get_axis_color_mut().set_argb("FF000000");
+    let mut rule = ConditionalFormattingRule::default();
+    rule.set_type(ConditionalFormatValues::DataBar)
+        .set_priority(1)
+        .set_data_bar(data_bar);
+    let mut formatting = ConditionalFormatting::default();
+    formatting
+        .get_sequence_of_references_mut()
+        .set_sqref("A1:A10");
+    formatting.add_conditional_collection(rule);
+    sheet.add_conditional_formatting_collection(formatting);
+
+    // icon set of the main part
+    let mut icon_set = IconSet::default();
+    icon_set
+        .set_icon_set(IconSetValues::FiveRating)
+        .set_reverse(true)
+        .set_show_value(false);
+    for val in ["0", "20", "40", "60", "80"] {
+        let mut cfvo = ConditionalFormatValueObject::default();
+        cfvo.set_type(ConditionalFormatValueObjectValues::Percent)
+            .set_val(val);
+        icon_set.add_cfvo_collection(cfvo);
+    }
+    let mut rule = ConditionalFormattingRule::default();
+    rule.set_type(ConditionalFormatValues::IconSet)
+        .set_priority(2)
+        .set_icon_set(icon_set);
+    let mut formatting = ConditionalFormatting::default();
+    formatting
+        .get_sequence_of_references_mut()
+        .set_sqref("B1:B10");
+    formatting.add_conditional_collection(rule);
+    sheet.add_conditional_formatting_collection(formatting);
+
+    // icon set with custom icons, only stored in the x14 extension
+    let mut icon_set = IconSet::default();
+    icon_set.set_icon_set(IconSetValues::ThreeStars);
+    for (val, icon_set_value, icon_id) in [
+        ("0", IconSetValues::ThreeFlags, 0),
+        ("33", IconSetValues::ThreeStars, 1),
+        ("67", IconSetValues::ThreeTrafficLights1, 2),
+    ] {
+        let mut cfvo = ConditionalFormatValueObject::default();
+        cfvo.set_type(ConditionalFormatValueObjectValues::Percent)
+            .set_val(val)
+            .set_greater_than_or_equal(false);
+        icon_set.add_cfvo_collection(cfvo);
+        let mut icon = ConditionalFormattingIcon::default();
+        icon.set_icon_set(icon_set_value).set_icon_id(icon_id);
+        icon_set.add_icon_collection(icon);
+    }
+    let mut rule = ConditionalFormattingRule::default();
+    rule.set_type(ConditionalFormatValues::IconSet)
+        .set_priority(3)
+        .set_icon_set(icon_set);
+    let mut formatting = ConditionalFormatting::default();
+    formatting
+        .get_sequence_of_references_mut()
+        .set_sqref("C1:C10");
+    formatting.add_conditional_collection(rule);
+    sheet.add_conditional_formatting_collection(formatting);
+
+    let path = std::path::Path::new("./tests/result_files/zzz_conditional_formatting_x14.xlsx");
+    umya_spreadsheet::writer::xlsx::write(&book, path).unwrap();
+
+    // the custom icon set must not be written to the main part
+    let mut arv = zip::ZipArchive::new(std::fs::File::open(path).unwrap()).unwrap();
+    let mut xml = String::new();
+    std::io::Read::read_to_string(
+        &mut arv.by_name("xl/worksheets/sheet1.xml").unwrap(),
+        &mut xml,
+    )
+    .unwrap();
+    assert_eq!(xml.matches("<conditionalFormatting ").count(), 2);
+    assert_eq!(xml.matches("<x14:conditionalFormatting ").count(), 2);
+    assert!(xml.contains("<cfvo type=\"min\"/>"));
+    assert!(xml.contains("<x14:cfvo type=\"autoMin\"/>"));
+
+    let book = umya_spreadsheet::reader::xlsx::read(path).unwrap();
+    let sheet = book.get_sheet(&0).unwrap();
+    let collection = sheet.get_conditional_formatting_collection();
+    assert_eq!(collection.len(), 3);
+
+    let rule = &collection[0].get_conditional_collection()[0];
+    assert_eq!(rule.get_priority(), &1);
+    let data_bar = rule.get_data_bar().unwrap();
+    assert_eq!(
+        data_bar.get_cfvo_collection()[0].get_type(),
+        &ConditionalFormatValueObjectValues::AutoMin
+    );
+    assert_eq!(data_bar.get_min_length(), &0);
+    assert_eq!(data_bar.get_max_length(), &100);
+    assert!(!*data_bar.get_show_value());
+    assert!(!*data_bar.get_gradient());
+    assert!(*data_bar.get_border());
+    assert_eq!(
+        data_bar.get_axis_position(),
+        &DataBarAxisPositionValues::Middle
+    );
+    assert_eq!(
+        data_bar.get_direction(),
+        &DataBarDirectionValues::LeftToRight
+    );
+    assert_eq!(data_bar.get_color_collection()[0].get_argb(), "FF638EC6");
+    assert_eq!(data_bar.get_border_color().get_argb(), "FF638EC6");
+    assert_eq!(data_bar.get_negative_fill_color().get_argb(), "FFFF0000");
+    assert_eq!(data_bar.get_axis_color().get_argb(), "FF000000");
+
+    let icon_set = collection[1].get_conditional_collection()[0]
+        .get_icon_set()
+        .unwrap();
+    assert_eq!(icon_set.get_icon_set(), &IconSetValues::FiveRating);
+    assert!(*icon_set.get_reverse());
+    assert!(!*icon_set.get_show_value());
+    assert_eq!(icon_set.get_cfvo_collection().len(), 5);
+    assert_eq!(icon_set.get_cfvo_collection()[4].get_val(), "80");
+
+    assert_eq!(
+        collection[2].get_sequence_of_references().get_sqref(),
+        "C1:C10"
+    );
+    let rule = &collection[2].get_conditional_collection()[0];
+    assert_eq!(rule.get_priority(), &3);
+    let icon_set = rule.get_icon_set().unwrap();
+    assert_eq!(icon_set.get_icon_set(), &IconSetValues::ThreeStars);
+    assert_eq!(icon_set.get_cfvo_collection()[1].get_val(), "33");
+    assert!(!*icon_set.get_cfvo_collection()[1].get_greater_than_or_equal());
+    let icons = icon_set.get_icon_collection();
+    assert_eq!(icons.len(), 3);
+    assert_eq!(icons[0].get_icon_set(), &IconSetValues::ThreeFlags);
+    assert_eq!(icons[2].get_icon_id(), &2);
+
+    // writing the read workbook again keeps the x14 part
+    let path = std::path::Path::new("./tests/result_files/zzz_conditional_formatting_x14_2.xlsx");
+    umya_spreadsheet::writer::xlsx::write(&book, path).unwrap();
+    let book = umya_spreadsheet::reader::xlsx::read(path).unwrap();
+    let collection = book
+        .get_sheet(&0)
+        .unwrap()
+        .get_conditional_formatting_collection();
+    assert_eq!(collection.len(), 3);
+    assert!(*collection[0].get_conditional_collection()[0]
+        .get_data_bar()
+        .unwrap()
+        .get_border());
+    assert_eq!(
+        collection[2].get_conditional_collection()[0]
+            .get_icon_set()
+            .unwrap()
+            .get_icon_collection()
+            .len(),
+        3
+    );
+}
+
+#[test]
+fn conditional_formatting_effective_style() {
+    let mut book = umya_spreadsheet::new_file();
+    let sheet = book.get_sheet_by_name_mut("Sheet1").unwrap();
+    for row in 1..=10 {
+        sheet.get_cell_mut((1, row)).set_value_number(row as i32);
+    }
+    for (row, value) in ["apple", "banana", "apple", "cherry"].iter().enumerate() {
+        sheet.get_cell_mut((2, row as u32 + 1)).set_value(*value);
+    }
+    for (row, value) in [0, 50, 100].iter().enumerate() {
+        sheet
+            .get_cell_mut((3, row as u32 + 1))
+            .set_value_number(*value);
+    }
+
+    let formula = |value: &str| {
+        let mut formula = Formula::default();
+        formula.set_string_value(value);
+        formula
+    };
+    let mut bold = Style::default();
+    bold.get_font_mut().set_bold(true);
+    let mut italic = Style::default();
+    italic.get_font_mut().set_italic(true);
+    let mut fill = Style::default();
+    fill.get_fill_mut()
+        .get_pattern_fill_mut()
+        .get_background_color_mut()
+        .set_argb("FFFFC7CE");
+
+    // A1:A10
+    let mut formatting = ConditionalFormatting::default();
+    formatting
+        .get_sequence_of_references_mut()
+        .set_sqref("A1:A10");
+    let mut rule = ConditionalFormattingRule::default();
+    rule.set_type(ConditionalFormatValues::CellIs)
+        .set_operator(ConditionalFormattingOperatorValues::Between)
+        .set_priority(2)
+        .set_formula(formula("3"))
+        .set_formula2(formula("5"))
+        .set_style(bold.clone());
+    formatting.add_conditional_collection(rule);
+    let mut rule = ConditionalFormattingRule::default();
+    rule.set_type(ConditionalFormatValues::Top10)
+        .set_priority(3)
+        .set_rank(2)
+        .set_style(fill.clone());
+    formatting.add_conditional_collection(rule);
+    let mut rule = ConditionalFormattingRule::default();
+    rule.set_type(ConditionalFormatValues::Expression)
+        .set_priority(1)
+        .set_formula(formula("A1=4"))
+        .set_stop_if_true(true)
+        .set_style(italic.clone());
+    formatting.add_conditional_collection(rule);
+    let mut rule = ConditionalFormattingRule::default();
+    rule.set_type(ConditionalFormatValues::AboveAverage)
+        .set_priority(4)
+        .set_style(bold.clone());
+    formatting.add_conditional_collection(rule);
+    sheet.add_conditional_formatting_collection(formatting);
+
+    // B1:B4
+    let mut formatting = ConditionalFormatting::default();
+    formatting
+        .get_sequence_of_references_mut()
+        .set_sqref("B1:B4");
+    let mut rule = ConditionalFormattingRule::default();
+    rule.set_type(ConditionalFormatValues::DuplicateValues)
+        .set_priority(5)
+        .set_style(fill.clone());
+    formatting.add_conditional_collection(rule);
+    let mut rule = ConditionalFormattingRule::default();
+    rule.set_type(ConditionalFormatValues::ContainsText)
+        .set_operator(ConditionalFormattingOperatorValues::ContainsText)
+        .set_text("AN")
+        .set_priority(6)
+        .set_style(italic.clone());
+    formatting.add_conditional_collection(rule);
+    sheet.add_conditional_formatting_collection(formatting);
+
+    // C1:C3
+    let min_max = || {
+        let mut min = ConditionalFormatValueObject::default();
+        min.set_type(ConditionalFormatValueObjectValues::Min);
+        let mut max = ConditionalFormatValueObject::default();
+        max.set_type(ConditionalFormatValueObjectValues::Max);
+        [min, max]
+    };
+    let mut formatting = ConditionalFormatting::default();
+    formatting
+        .get_sequence_of_references_mut()
+        .set_sqref("C1:C3");
+    let mut color_scale = ColorScale::default();
+    for (cfvo, argb) in min_max().into_iter().zip(["FFF8696B", "FF63BE7B"]) {
+        let mut color = Color::default();
+        color.set_argb(argb);
+        color_scale
+            .add_cfvo_collection(cfvo)
+            .add_color_collection(color);
+    }
+    let mut rule = ConditionalFormattingRule::default();
+    rule.set_type(ConditionalFormatValues::ColorScale)
+        .set_priority(7)
+        .set_color_scale(color_scale);
+    formatting.add_conditional_collection(rule);
+    let mut data_bar = DataBar::default();
+    for cfvo in min_max() {
+        data_bar.add_cfvo_collection(cfvo);
+    }
+    let mut color = Color::default();
+    color.set_argb("FF638EC6");
+    data_bar.add_color_collection(color);
+    let mut rule = ConditionalFormattingRule::default();
+    rule.set_type(ConditionalFormatValues::DataBar)
+        .set_priority(8)
+        .set_data_bar(data_bar);
+    formatting.add_conditional_collection(rule);
+    let mut icon_set = IconSet::default();
+    icon_set.set_icon_set(IconSetValues::ThreeArrows);
+    for val in ["0", "33", "67"] {
+        let mut cfvo = ConditionalFormatValueObject::default();
+        cfvo.set_type(ConditionalFormatValueObjectValues::Percent)
+            .set_val(val);
+        icon_set.add_cfvo_collection(cfvo);
+    }
+    let mut rule = ConditionalFormattingRule::default();
+    rule.set_type(ConditionalFormatValues::IconSet)
+        .set_priority(9)
+        .set_icon_set(icon_set);
+    formatting.add_conditional_collection(rule);
+    sheet.add_conditional_formatting_collection(formatting);
+
+    // no rule matches
+    let style = sheet.get_effective_style("A1");
+    assert!(!style.get_font().map(|v| *v.get_bold()).unwrap_or(false));
+    assert!(style.get_background_color().is_none());
+    // between
+    let style = sheet.get_effective_style("A3");
+    assert!(*style.get_font().unwrap().get_bold());
+    // the expression matches and stops the evaluation
+    let style = sheet.get_effective_style("A4");
+    assert!(*style.get_font().unwrap().get_italic());
+    assert!(!*style.get_font().unwrap().get_bold());
+    // top 2 and above average are merged
+    let style = sheet.get_effective_style("A10");
+    assert!(*style.get_font().unwrap().get_bold());
+    assert_eq!(style.get_background_color().unwrap().get_argb(), "FFFFC7CE");
+    let style = sheet.get_effective_style("A8");
+    assert!(*style.get_font().unwrap().get_bold());
+    assert!(style.get_background_color().is_none());
+
+    // duplicate values and contains text
+    let style = sheet.get_effective_style("B1");
+    assert_eq!(style.get_background_color().unwrap().get_argb(), "FFFFC7CE");
+    assert!(!style.get_font().map(|v| *v.get_italic()).unwrap_or(false));
+    let style = sheet.get_effective_style("B2");
+    assert!(style.get_background_color().is_none());
+    assert!(*style.get_font().unwrap().get_italic());
+
+    // color scale, data bar and icon set
+    let result = sheet.get_conditional_formatting_result("C2");
+    assert_eq!(
+        result.get_color_scale_color().unwrap().get_argb(),
+        "FFAE9473"
+    );
+    assert_eq!(
+        result
+            .get_style()
+            .get_background_color()
+            .unwrap()
+            .get_argb(),
+        "FFAE9473"
+    );
+    assert_eq!(result.get_data_bar_length(), Some(50f64));
+    assert_eq!(result.get_data_bar_color().unwrap().get_argb(), "FF638EC6");
+    let icon = result.get_icon().unwrap();
+    assert_eq!(icon.get_icon_set(), &IconSetValues::ThreeArrows);
+    assert_eq!(icon.get_icon_id(), &1);
+    let result = sheet.get_conditional_formatting_result("C3");
+    assert_eq!(
+        result.get_color_scale_color().unwrap().get_argb(),
+        "FF63BE7B"
+    );
+    assert_eq!(result.get_data_bar_length(), Some(90f64));
+    assert_eq!(result.get_icon().unwrap().get_icon_id(), &2);
+
+    // the results of a range are the ones of each cell
+    let results = sheet.get_conditional_formatting_result_by_range("A1:C10");
+    assert_eq!(results.len(), 30);
+    for (index, result) in results.iter().enumerate() {
+        let coordinate = ((index % 3) as u32 + 1, (index / 3) as u32 + 1);
+        let expected = sheet.get_conditional_formatting_result(coordinate);
+        assert_eq!(result.get_style(), expected.get_style());
+        assert_eq!(
+            result.get_color_scale_color().map(|v| v.get_argb()),
+            expected.get_color_scale_color().map(|v| v.get_argb())
+        );
+        assert_eq!(result.get_data_bar_length(), expected.get_data_bar_length());
+        assert_eq!(
+            result.get_icon().map(|v| *v.get_icon_id()),
+            expected.get_icon().map(|v| *v.get_icon_id())
+        );
+    }
+    assert!(*results[3 * 3].get_style().get_font().unwrap().get_italic());
+    assert_eq!(results[2 * 3 + 2].get_data_bar_length(), Some(90f64));
+}
+
+#[test]
+fn chart_axis_scaling_and_date_axis() {
+    use umya_spreadsheet::drawing::charts::*;
+
+    let mut book = umya_spreadsheet::new_file();
+    let sheet = book.get_sheet_by_name_mut("Sheet1").unwrap();
+    for row in 1..=10 {
+        sheet
+            .get_cell_mut((1, row))
+            .set_value_number(45292 + row as i32 * 7);
+        sheet
+            .get_cell_mut((2, row))
+            .set_value_number(row as i32 * 1000);
+    }
+
+    // line chart with a date axis
+    let mut from_marker = umya_spreadsheet::structs::drawing::spreadsheet::MarkerType::default();
+    let mut to_marker = umya_spreadsheet::structs::drawing::spreadsheet::MarkerType::default();
+    from_marker.set_coordinate("D1");
+    to_marker.set_coordinate("K15");
+    let mut chart = umya_spreadsheet::structs::Chart::default();
+    chart
+        .new_chart(
+            umya_spreadsheet::structs::ChartType::LineChart,
+            from_marker,
+            to_marker,
+            vec!["Sheet1!$B$1:$B$10"],
+        )
+        .set_horizontal_axis_date(TimeUnitValues::Days)
+        .set_horizontal_axis_date_major_unit(1f64, TimeUnitValues::Months)
+        .set_horizontal_axis_number_format("yyyy/mm")
+        .set_horizontal_axis_min(45292f64)
+        .set_horizontal_title("Date")
+        .set_vertical_axis_min(0f64)
+        .set_vertical_axis_max(12000f64)
+        .set_vertical_axis_major_unit(2000f64)
+        .set_vertical_axis_minor_unit(500f64)
+        .set_vertical_axis_display_units(BuiltInUnitValues::Thousands)
+        .set_vertical_axis_number_format("#,##0");
+    sheet.add_chart(chart);
+
+    // scatter chart with a logarithmic scale
+    let mut from_marker = umya_spreadsheet::structs::drawing::spreadsheet::MarkerType::default();
+    let mut to_marker = umya_spreadsheet::structs::drawing::spreadsheet::MarkerType::default();
+    from_marker.set_coordinate("D16");
+    to_marker.set_coordinate("K30");
+    let mut chart = umya_spreadsheet::structs::Chart::default();
+    chart
+        .new_chart(
+            umya_spreadsheet::structs::ChartType::ScatterChart,
+            from_marker,
+            to_marker,
+            vec!["Sheet1!$A$1:$A$10", "Sheet1!$B$1:$B$10"],
+        )
+        .set_horizontal_axis_max(46000f64)
+        .set_vertical_axis_log_base(10f64);
+    sheet.add_chart(chart);
+
+    let path = std::path::Path::new("./tests/result_files/zzz_chart_axis_scaling.xlsx");
+    umya_spreadsheet::writer::xlsx::write(&book, path).unwrap();
+    let book = umya_spreadsheet::reader::xlsx::read(path).unwrap();
+    let sheet = book.get_sheet_by_name("Sheet1").unwrap();
+
+    let plot_area = sheet.get_chart_collection()[0]
+        .get_chart_space()
+        .get_chart()
+        .get_plot_area();
+    assert!(plot_area.get_category_axis().is_empty());
+    let date_axis = &plot_area.get_date_axis()[0];
+    assert_eq!(date_axis.get_axis_id().get_val(), &213468160);
+    assert_eq!(date_axis.get_tick_crossing_axis().get_val(), &169590080);
+    assert!(date_axis.get_title().is_some());
+    assert!(matches!(
+        date_axis.get_base_time_unit().unwrap().get_val(),
+        TimeUnitValues::Days
+    ));
+    assert_eq!(date_axis.get_major_unit().unwrap().get_val(), &1f64);
+    assert!(matches!(
+        date_axis.get_major_time_unit().unwrap().get_val(),
+        TimeUnitValues::Months
+    ));
+    assert_eq!(
+        date_axis.get_numbering_format().unwrap().get_format_code(),
+        "yyyy/mm"
+    );
+    assert_eq!(
+        date_axis
+            .get_scaling()
+            .get_min_axis_value()
+            .unwrap()
+            .get_val(),
+        &45292f64
+    );
+
+    let value_axis = &plot_area.get_value_axis()[0];
+    let scaling = value_axis.get_scaling();
+    assert_eq!(scaling.get_min_axis_value().unwrap().get_val(), &0f64);
+    assert_eq!(scaling.get_max_axis_value().unwrap().get_val(), &12000f64);
+    assert!(scaling.get_log_base().is_none());
+    assert_eq!(value_axis.get_major_unit().unwrap().get_val(), &2000f64);
+    assert_eq!(value_axis.get_minor_unit().unwrap().get_val(), &500f64);
+    let display_units = value_axis.get_display_units().unwrap();
+    assert!(matches!(
+        display_units.get_built_in_unit().unwrap().get_val(),
+        BuiltInUnitValues::Thousands
+    ));
+    assert!(display_units.get_display_units_label().is_some());
+    assert_eq!(value_axis.get_numbering_format().get_format_code(), "#,##0");
+
+    let plot_area = sheet.get_chart_collection()[1]
+        .get_chart_space()
+        .get_chart()
+        .get_plot_area();
+    let value_axis = plot_area.get_value_axis();
+    assert_eq!(
+        value_axis[0]
+            .get_scaling()
+            .get_max_axis_value()
+            .unwrap()
+            .get_val(),
+        &46000f64
+    );
+    assert_eq!(
+        value_axis[1]
+            .get_scaling()
+            .get_log_base()
+            .unwrap()
+            .get_val(),
+        &10f64
+    );
+}
+
+#[test]
+fn chart_trendline_error_bars_and_data_table() {
+    use umya_spreadsheet::drawing::charts::*;
+
+    let mut book = umya_spreadsheet::new_file();
+    let sheet = book.get_sheet_by_name_mut("Sheet1").unwrap();
+    for row in 1..=10 {
+        sheet.get_cell_mut((1, row)).set_value_number(row as i32);
+        sheet
+            .get_cell_mut((2, row))
+            .set_value_number(row as i32 * row as i32);
+        sheet.get_cell_mut((3, row)).set_value_number(2);
+    }
+
+    let mut from_marker = umya_spreadsheet::structs::drawing::spreadsheet::MarkerType::default();
+    let mut to_marker = umya_spreadsheet::structs::drawing::spreadsheet::MarkerType::default();
+    from_marker.set_coordinate("E1");
+    to_marker.set_coordinate("L15");
+    let mut chart = umya_spreadsheet::structs::Chart::default();
+    chart.new_chart(
+        umya_spreadsheet::structs::ChartType::LineChart,
+        from_marker,
+        to_marker,
+        vec!["Sheet1!$A$1:$A$10", "Sheet1!$B$1:$B$10"],
+    );
+
+    let mut trendline_type = TrendlineType::default();
+    trendline_type.set_val(TrendlineValues::Polynomial);
+    let mut order = PolynomialOrder::default();
+    order.set_val(2);
+    let mut forward = Forward::default();
+    forward.set_val(1.5);
+    let mut display_r_squared_value = DisplayRSquaredValue::default();
+    display_r_squared_value.set_val(true);
+    let mut display_equation = DisplayEquation::default();
+    display_equation.set_val(true);
+    let mut name = TrendlineName::default();
+    name.set_text("Poly (Series2)");
+    let mut trendline = Trendline::default();
+    trendline
+        .set_trendline_name(name)
+        .set_trendline_type(trendline_type)
+        .set_polynomial_order(order)
+        .set_forward(forward)
+        .set_display_r_squared_value(display_r_squared_value)
+        .set_display_equation(display_equation)
+        .set_trendline_label(TrendlineLabel::default());
+
+    let mut error_bar_type = ErrorBarType::default();
+    error_bar_type.set_val(ErrorBarValues::Plus);
+    let mut error_bar_value_type = ErrorBarValueType::default();
+    error_bar_value_type.set_val(ErrorValues::Custom);
+    let mut no_end_cap = NoEndCap::default();
+    no_end_cap.set_val(false);
+    let mut plus = Plus::default();
+    plus.get_number_reference_mut()
+        .get_formula_mut()
+        .set_address_str("Sheet1!$C$1:$C$10");
+    let mut error_bars = ErrorBars::default();
+    error_bars
+        .set_error_bar_type(error_bar_type)
+        .set_error_bar_value_type(error_bar_value_type)
+        .set_no_end_cap(no_end_cap)
+        .set_plus(plus);
+
+    let mut moving_average_type = TrendlineType::default();
+    moving_average_type.set_val(TrendlineValues::MovingAverage);
+    let mut period = Period::default();
+    period.set_val(3);
+    let mut moving_average = Trendline::default();
+    moving_average
+        .set_trendline_type(moving_average_type)
+        .set_period(period);
+
+    let mut gap_width = GapWidth::default();
+    gap_width.set_val(150);
+    let mut up_down_bars = UpDownBars::default();
+    up_down_bars
+        .set_gap_width(gap_width)
+        .set_up_bars(UpBars::default())
+        .set_down_bars(DownBars::default());
+
+    let mut show_keys = ShowKeys::default();
+    show_keys.set_val(true);
+    let mut data_table = DataTable::default();
+    data_table.set_show_keys(show_keys);
+
+    let plot_area = chart.get_plot_area_mut();
+    let line_chart = plot_area.get_line_chart_mut().unwrap();
+    line_chart
+        .set_drop_lines(DropLines::default())
+        .set_high_low_lines(HighLowLines::default())
+        .set_up_down_bars(up_down_bars);
+    let series_list = line_chart
+        .get_area_chart_series_list_mut()
+        .get_area_chart_series_mut();
+    series_list[1]
+        .add_trendline(trendline)
+        .add_trendline(moving_average)
+        .add_error_bars(error_bars);
+    plot_area.set_data_table(data_table);
+    sheet.add_chart(chart);
+
+    let path = std::path::Path::new("./tests/result_files/zzz_chart_trendline.xlsx");
+    umya_spreadsheet::writer::xlsx::write(&book, path).unwrap();
+    let mut book = umya_spreadsheet::reader::xlsx::read(path).unwrap();
+    let sheet = book.get_sheet_by_name("Sheet1").unwrap();
+
+    let plot_area = sheet.get_chart_collection()[0]
+        .get_chart_space()
+        .get_chart()
+        .get_plot_area();
+    let line_chart = plot_area.get_line_chart().unwrap();
+    assert!(line_chart.get_drop_lines().is_some());
+    assert!(line_chart.get_high_low_lines().is_some());
+    let up_down_bars = line_chart.get_up_down_bars().unwrap();
+    assert_eq!(up_down_bars.get_gap_width().unwrap().get_val(), &150);
+    assert!(up_down_bars.get_up_bars().is_some());
+    assert!(up_down_bars.get_down_bars().is_some());
+    assert_eq!(
+        plot_area
+            .get_data_table()
+            .unwrap()
+            .get_show_keys()
+            .unwrap()
+            .get_val(),
+        &true
+    );
+
+    let series = &line_chart
+        .get_area_chart_series_list()
+        .get_area_chart_series()[1];
+    let trendline = &series.get_trendline()[0];
+    assert!(matches!(
+        trendline.get_trendline_type().get_val(),
+        TrendlineValues::Polynomial
+    ));
+    assert_eq!(
+        trendline.get_trendline_name().unwrap().get_text(),
+        "Poly (Series2)"
+    );
+    assert_eq!(trendline.get_polynomial_order().unwrap().get_val(), &2);
+    assert_eq!(trendline.get_forward().unwrap().get_val(), &1.5);
+    assert!(trendline.get_backward().is_none());
+    assert_eq!(
+        trendline.get_display_r_squared_value().unwrap().get_val(),
+        &true
+    );
+    assert_eq!(trendline.get_display_equation().unwrap().get_val(), &true);
+    assert!(trendline.get_trendline_label().is_some());
+    let trendline = &series.get_trendline()[1];
+    assert!(matches!(
+        trendline.get_trendline_type().get_val(),
+        TrendlineValues::MovingAverage
+    ));
+    assert_eq!(trendline.get_period().unwrap().get_val(), &3);
+
+    let error_bars = &series.get_error_bars()[0];
+    assert!(error_bars.get_error_direction().is_none());
+    assert!(matches!(
+        error_bars.get_error_bar_type().get_val(),
+        ErrorBarValues::Plus
+    ));
+    assert!(matches!(
+        error_bars.get_error_bar_value_type().get_val(),
+        ErrorValues::Custom
+    ));
+    assert_eq!(error_bars.get_no_end_cap().unwrap().get_val(), &false);
+    assert_eq!(
+        error_bars
+            .get_plus()
+            .unwrap()
+            .get_number_reference()
+            .get_formula()
+            .get_address_str(),
+        "Sheet1!$C$1:$C$10"
+    );
+    assert!(error_bars.get_minus().is_none());
+
+    // the references of the error bars follow inserted rows
+    let sheet = book.get_sheet_by_name_mut("Sheet1").unwrap();
+    sheet.insert_new_row(&1, &2);
+    let series = &sheet.get_chart_collection()[0]
+        .get_chart_space()
+        .get_chart()
+        .get_plot_area()
+        .get_line_chart()
+        .unwrap()
+        .get_area_chart_series_list()
+        .get_area_chart_series()[1];
+    assert_eq!(
+        series.get_error_bars()[0]
+            .get_plus()
+            .unwrap()
+            .get_number_reference()
+            .get_formula()
+            .get_address_str(),
+        "Sheet1!$C$3:$C$12"
+    );
+}
+
+#[test]
+fn chart_combo_secondary_axis() {
+    use umya_spreadsheet::drawing::charts::*;
+
+    let mut book = umya_spreadsheet::new_file();
+    let sheet = book.get_sheet_by_name_mut("Sheet1").unwrap();
+    for row in 1..=10 {
+        sheet.get_cell_mut((1, row)).set_value_number(row as i32);
+        sheet
+            .get_cell_mut((2, row))
+            .set_value_number(row as i32 * 1000);
+        sheet
+            .get_cell_mut((3, row))
+            .set_value_number(row as i32 * 2);
+    }
+
+    let mut from_marker = umya_spreadsheet::structs::drawing::spreadsheet::MarkerType::default();
+    let mut to_marker = umya_spreadsheet::structs::drawing::spreadsheet::MarkerType::default();
+    from_marker.set_coordinate("E1");
+    to_marker.set_coordinate("L15");
+    let mut chart = umya_spreadsheet::structs::Chart::default();
+    chart
+        .new_chart(
+            umya_spreadsheet::structs::ChartType::BarChart,
+            from_marker,
+            to_marker,
+            vec!["Sheet1!$A$1:$A$10"],
+        )
+        .add_series_group(
+            umya_spreadsheet::structs::ChartType::LineChart,
+            vec!["Sheet1!$B$1:$B$10"],
+            umya_spreadsheet::structs::AxisSide::Secondary,
+        )
+        .add_series_group(
+            umya_spreadsheet::structs::ChartType::LineChart,
+            vec!["Sheet1!$C$1:$C$10"],
+            umya_spreadsheet::structs::AxisSide::Primary,
+        )
+        .set_vertical_title("Count")
+        .set_secondary_vertical_title("Amount")
+        .set_series_title(vec!["Count", "Amount", "Double"]);
+    sheet.add_chart(chart);
+
+    let path = std::path::Path::new("./tests/result_files/zzz_chart_combo.xlsx");
+    umya_spreadsheet::writer::xlsx::write(&book, path).unwrap();
+    let book = umya_spreadsheet::reader::xlsx::read(path).unwrap();
+    let sheet = book.get_sheet_by_name("Sheet1").unwrap();
+
+    let plot_area = sheet.get_chart_collection()[0]
+        .get_chart_space()
+        .get_chart()
+        .get_plot_area();
+    assert_eq!(plot_area.get_bar_chart_collection().len(), 1);
+    assert_eq!(plot_area.get_line_chart_collection().len(), 2);
+    let axis_ids = |v: &[AxisId]| v.iter().map(|v| *v.get_val()).collect::<Vec<u32>>();
+    let bar_ids = axis_ids(plot_area.get_bar_chart().unwrap().get_axis_id());
+    let secondary_ids = axis_ids(plot_area.get_line_chart_collection()[0].get_axis_id());
+    let primary_ids = axis_ids(plot_area.get_line_chart_collection()[1].get_axis_id());
+    assert_eq!(bar_ids.len(), 2);
+    assert_eq!(primary_ids, bar_ids);
+    assert!(secondary_ids.iter().all(|v| !bar_ids.contains(v)));
+
+    let category_axis = plot_area.get_category_axis();
+    assert_eq!(category_axis.len(), 2);
+    assert_eq!(category_axis[0].get_delete().get_val(), &false);
+    assert_eq!(category_axis[1].get_delete().get_val(), &true);
+    assert_eq!(category_axis[1].get_axis_id().get_val(), &secondary_ids[0]);
+    assert_eq!(
+        category_axis[1].get_tick_crossing_axis().get_val(),
+        &secondary_ids[1]
+    );
+
+    let value_axis = plot_area.get_value_axis();
+    assert_eq!(value_axis.len(), 2);
+    assert!(matches!(
+        value_axis[0].get_axis_position().get_val(),
+        AxisPositionValues::Left
+    ));
+    assert!(value_axis[0].get_major_gridlines().is_some());
+    assert!(value_axis[0].get_title().is_some());
+    assert_eq!(value_axis[1].get_axis_id().get_val(), &secondary_ids[1]);
+    assert_eq!(
+        value_axis[1].get_tick_crossing_axis().get_val(),
+        &secondary_ids[0]
+    );
+    assert!(matches!(
+        value_axis[1].get_axis_position().get_val(),
+        AxisPositionValues::Right
+    ));
+    assert!(matches!(
+        value_axis[1].get_crosses().get_val(),
+        CrossesValues::Maximum
+    ));
+    assert!(value_axis[1].get_major_gridlines().is_none());
+    assert!(value_axis[1].get_title().is_some());
+
+    let series_index = |v: &[AreaChartSeries]| {
+        v.iter()
+            .map(|v| *v.get_index().get_val())
+            .collect::<Vec<u32>>()
+    };
+    assert_eq!(
+        series_index(
+            plot_area
+                .get_bar_chart()
+                .unwrap()
+                .get_area_chart_series_list()
+                .get_area_chart_series()
+        ),
+        vec![0]
+    );
+    assert_eq!(
+        series_index(
+            plot_area.get_line_chart_collection()[0]
+                .get_area_chart_series_list()
+                .get_area_chart_series()
+        ),
+        vec![1]
+    );
+    assert_eq!(
+        series_index(
+            plot_area.get_line_chart_collection()[1]
+                .get_area_chart_series_list()
+                .get_area_chart_series()
+        ),
+        vec![2]
+    );
+}
+
+#[test]
+fn chart_combo_keeps_group_order() {
+    let mut book = umya_spreadsheet::new_file();
+    let sheet = book.get_sheet_by_name_mut("Sheet1").unwrap();
+    for row in 1..=10 {
+        sheet.get_cell_mut((1, row)).set_value_number(row as i32);
+        sheet
+            .get_cell_mut((2, row))
+            .set_value_number(row as i32 * 2);
+    }
+
+    let mut from_marker = umya_spreadsheet::structs::drawing::spreadsheet::MarkerType::default();
+    let mut to_marker = umya_spreadsheet::structs::drawing::spreadsheet::MarkerType::default();
+    from_marker.set_coordinate("E1");
+    to_marker.set_coordinate("L15");
+    let mut chart = umya_spreadsheet::structs::Chart::default();
+    chart
+        .new_chart(
+            umya_spreadsheet::structs::ChartType::LineChart,
+            from_marker,
+            to_marker,
+            vec!["Sheet1!$A$1:$A$10"],
+        )
+        .add_series_group(
+            umya_spreadsheet::structs::ChartType::BarChart,
+            vec!["Sheet1!$B$1:$B$10"],
+            umya_spreadsheet::structs::AxisSide::Secondary,
+        );
+    sheet.add_chart(chart);
+
+    // the bar chart added last is drawn over the line chart
+    let get_chart_xml = |path: &std::path::Path| {
+        let mut arv = zip::ZipArchive::new(std::fs::File::open(path).unwrap()).unwrap();
+        let mut xml = String::new();
+        std::io::Read::read_to_string(&mut arv.by_name("xl/charts/chart1.xml").unwrap(), &mut xml)
+            .unwrap();
+        xml
+    };
+    let path = std::path::Path::new("./tests/result_files/zzz_chart_combo_order.xlsx");
+    umya_spreadsheet::writer::xlsx::write(&book, path).unwrap();
+    let xml = get_chart_xml(path);
+    assert!(xml.find("<c:lineChart>").unwrap() < xml.find("<c:barChart>").unwrap());
+
+    let book = umya_spreadsheet::reader::xlsx::read(path).unwrap();
+    let path = std::path::Path::new("./tests/result_files/zzz_chart_combo_order_2.xlsx");
+    umya_spreadsheet::writer::xlsx::write(&book, path).unwrap();
+    let xml = get_chart_xml(path);
+    assert!(xml.find("<c:lineChart>").unwrap() < xml.find("<c:barChart>").unwrap());
+}
+
+#[test]
+fn chart_stock_and_surface() {
+    use umya_spreadsheet::drawing::charts::*;
+
+    let mut book = umya_spreadsheet::new_file();
+    let sheet = book.get_sheet_by_name_mut("Sheet1").unwrap();
+    for row in 1..=10 {
+        let base = 100 + row as i32;
+        sheet.get_cell_mut((1, row)).set_value_number(base);
+        sheet.get_cell_mut((2, row)).set_value_number(base + 5);
+        sheet.get_cell_mut((3, row)).set_value_number(base - 5);
+        sheet.get_cell_mut((4, row)).set_value_number(base + 2);
+    }
+
+    let series_list = vec![
+        "Sheet1!$A$1:$A$10",
+        "Sheet1!$B$1:$B$10",
+        "Sheet1!$C$1:$C$10",
+        "Sheet1!$D$1:$D$10",
+    ];
+    let chart_type_list = vec![
+        (
+            umya_spreadsheet::structs::ChartType::StockChart,
+            "F1",
+            "M15",
+        ),
+        (
+            umya_spreadsheet::structs::ChartType::SurfaceChart,
+            "F16",
+            "M30",
+        ),
+        (
+            umya_spreadsheet::structs::ChartType::Surface3DChart,
+            "F31",
+            "M45",
+        ),
+    ];
+    for (chart_type, from, to) in chart_type_list {
+        let mut from_marker =
+            umya_spreadsheet::structs::drawing::spreadsheet::MarkerType::default();
+        let mut to_marker = umya_spreadsheet::structs::drawing::spreadsheet::MarkerType::default();
+        from_marker.set_coordinate(from);
+        to_marker.set_coordinate(to);
+        let mut chart = umya_spreadsheet::structs::Chart::default();
+        chart.new_chart(chart_type, from_marker, to_marker, series_list.clone());
+        sheet.add_chart(chart);
+    }
+
+    let path = std::path::Path::new("./tests/result_files/zzz_chart_stock_surface.xlsx");
+    umya_spreadsheet::writer::xlsx::write(&book, path).unwrap();
+    let book = umya_spreadsheet::reader::xlsx::read(path).unwrap();
+    let sheet = book.get_sheet_by_name("Sheet1").unwrap();
+    let charts = sheet.get_chart_collection();
+    assert_eq!(charts.len(), 3);
+
+    let plot_area = charts[0].get_chart_space().get_chart().get_plot_area();
+    let stock_chart = plot_area.get_stock_chart().unwrap();
+    assert_eq!(
+        stock_chart
+            .get_area_chart_series_list()
+            .get_area_chart_series()
+            .len(),
+        4
+    );
+    assert!(stock_chart.get_high_low_lines().is_some());
+    assert!(stock_chart
+        .get_up_down_bars()
+        .unwrap()
+        .get_up_bars()
+        .is_some());
+    assert_eq!(stock_chart.get_axis_id().len(), 2);
+
+    let plot_area = charts[1].get_chart_space().get_chart().get_plot_area();
+    let surface_chart = plot_area.get_surface_chart().unwrap();
+    assert_eq!(surface_chart.get_wireframe().unwrap().get_val(), &false);
+    assert!(surface_chart.get_band_formats().is_some());
+    assert_eq!(surface_chart.get_axis_id().len(), 3);
+    assert_eq!(plot_area.get_series_axis().len(), 1);
+
+    let plot_area = charts[2].get_chart_space().get_chart().get_plot_area();
+    let surface_3d_chart = plot_area.get_surface_3d_chart().unwrap();
+    assert_eq!(
+        surface_3d_chart
+            .get_area_chart_series_list()
+            .get_area_chart_series()
+            .len(),
+        4
+    );
+    assert!(surface_3d_chart
+        .get_area_chart_series_list()
+        .get_area_chart_series()[0]
+        .get_smooth()
+        .is_none());
+    assert_eq!(surface_3d_chart.get_axis_id().len(), 3);
+}
+
+#[test]
+fn chart_ex_waterfall_and_histogram() {
+    use umya_spreadsheet::drawing::chart_ex::*;
+
+    let mut book = umya_spreadsheet::new_file();
+    let sheet = book.get_sheet_by_name_mut("Sheet1").unwrap();
+    let rows = [
+        ("Start", 100),
+        ("Sales", 40),
+        ("Costs", -25),
+        ("Tax", -5),
+        ("End", 110),
+    ];
+    for (idx, (label, value)) in rows.iter().enumerate() {
+        let row = idx as u32 + 1;
+        sheet.get_cell_mut((1, row)).set_value(*label);
+        sheet.get_cell_mut((2, row)).set_value_number(*value);
+    }
+    for row in 1..=20 {
+        sheet
+            .get_cell_mut((3, row))
+            .set_value_number((row * 7 % 30) as i32);
+    }
+
+    let mut from_marker = umya_spreadsheet::structs::drawing::spreadsheet::MarkerType::default();
+    let mut to_marker = umya_spreadsheet::structs::drawing::spreadsheet::MarkerType::default();
+    from_marker.set_coordinate("E1");
+    to_marker.set_coordinate("L15");
+    let mut chart = umya_spreadsheet::structs::ChartEx::default();
+    chart
+        .new_chart(
+            umya_spreadsheet::structs::ChartExType::Waterfall,
+            from_marker,
+            to_marker,
+            Some("Sheet1!$A$1:$A$5"),
+            vec!["Sheet1!$B$1:$B$5"],
+        )
+        .set_title("Cash flow")
+        .set_subtotals(vec![4]);
+    sheet.add_chart_ex(chart);
+
+    let mut from_marker = umya_spreadsheet::structs::drawing::spreadsheet::MarkerType::default();
+    let mut to_marker = umya_spreadsheet::structs::drawing::spreadsheet::MarkerType::default();
+    from_marker.set_coordinate("E16");
+    to_marker.set_coordinate("L30");
+    let mut chart = umya_spreadsheet::structs::ChartEx::default();
+    chart
+        .new_chart(
+            umya_spreadsheet::structs::ChartExType::Histogram,
+            from_marker,
+            to_marker,
+            None,
+            vec!["Sheet1!$C$1:$C$20"],
+        )
+        .set_bin_count(5);
+    sheet.add_chart_ex(chart);
+
+    let path = std::path::Path::new("./tests/result_files/zzz_chart_ex.xlsx");
+    umya_spreadsheet::writer::xlsx::write(&book, path).unwrap();
+    let book = umya_spreadsheet::reader::xlsx::read(path).unwrap();
+    let sheet = book.get_sheet_by_name("Sheet1").unwrap();
+    assert!(sheet.get_chart_collection().is_empty());
+    let charts = sheet.get_chart_ex_collection();
+    assert_eq!(charts.len(), 2);
+
+    let chart_space = charts[0].get_chart_space();
+    let data = &chart_space.get_chart_data().get_data()[0];
+    assert_eq!(
+        data.get_string_dimension()[0]
+            .get_formula()
+            .unwrap()
+            .get_address_str(),
+        "Sheet1!$A$1:$A$5"
+    );
+    let level = &data.get_string_dimension()[0].get_level()[0];
+    assert_eq!(level.get_point().len(), 5);
+    assert_eq!(level.get_point()[2].get_value(), "Costs");
+    let level = &data.get_numeric_dimension()[0].get_level()[0];
+    assert_eq!(level.get_point()[2].get_value(), "-25");
+    let chart = chart_space.get_chart();
+    assert_eq!(
+        chart
+            .get_title()
+            .unwrap()
+            .get_text()
+            .unwrap()
+            .get_text_data()
+            .get_value(),
+        "Cash flow"
+    );
+    let series = &chart.get_plot_area().get_plot_area_region().get_series()[0];
+    assert!(matches!(
+        series.get_layout_id(),
+        SeriesLayoutValues::Waterfall
+    ));
+    assert_eq!(
+        series.get_layout_properties().unwrap().get_subtotals(),
+        &[4]
+    );
+    assert_eq!(chart.get_plot_area().get_axis().len(), 2);
+
+    let chart = charts[1].get_chart_space().get_chart();
+    let series = &chart.get_plot_area().get_plot_area_region().get_series()[0];
+    assert!(matches!(
+        series.get_layout_id(),
+        SeriesLayoutValues::ClusteredColumn
+    ));
+    let binning = series
+        .get_layout_properties()
+        .unwrap()
+        .get_binning()
+        .unwrap();
+    assert_eq!(binning.get_bin_count(), Some(&5));
+    assert_eq!(binning.get_bin_size(), None);
+
+    // written again from the read model
+    let path = std::path::Path::new("./tests/result_files/zzz_chart_ex2.xlsx");
+    umya_spreadsheet::writer::xlsx::write(&book, path).unwrap();
+    let book = umya_spreadsheet::reader::xlsx::read(path).unwrap();
+    let sheet = book.get_sheet_by_name("Sheet1").unwrap();
+    assert_eq!(sheet.get_chart_ex_collection().len(), 2);
+}
+
+#[test]
+fn read_and_write_chart_ex_file() {
+    use std::io::Read;
+
+    // chartEx part with the formatting and extensions Excel writes, plus its style and colors parts
+    let path = std::path::Path::new("./tests/test_files/chart_ex.xlsx");
+    let book = umya_spreadsheet::reader::xlsx::read(path).unwrap();
+    let sheet = book.get_sheet_by_name("Sheet1").unwrap();
+    let charts = sheet.get_chart_ex_collection();
+    assert_eq!(charts.len(), 1);
+    let chart = charts[0].get_chart_space().get_chart();
+    let series = &chart.get_plot_area().get_plot_area_region().get_series()[0];
+    assert_eq!(
+        series.get_layout_properties().unwrap().get_subtotals(),
+        &[0, 4]
+    );
+    assert!(chart.get_legend().is_some());
+
+    let path = std::path::Path::new("./tests/result_files/zzz_chart_ex_file.xlsx");
+    umya_spreadsheet::writer::xlsx::write(&book, path).unwrap();
+
+    let read_part = |path: &str, name: &str| -> String {
+        let file = std::fs::File::open(path).unwrap();
+        let mut archive = zip::ZipArchive::new(file).unwrap();
+        let mut result = String::new();
+        archive
+            .by_name(name)
+            .unwrap()
+            .read_to_string(&mut result)
+            .unwrap();
+        result
+    };
+    let original = read_part("./tests/test_files/chart_ex.xlsx", "xl/charts/chartEx1.xml");
+    let written = read_part(
+        "./tests/result_files/zzz_chart_ex_file.xlsx",
+        "xl/charts/chartEx1.xml",
+    );
+    // spPr, txPr, numFmt, dataPt, fmtOvrs, extLst, ... are all written back as read
+    let body = |v: &str| v.split_once('\n').unwrap().1.to_string();
+    assert_eq!(body(&written), body(&original));
+
+    let rels = read_part(
+        "./tests/result_files/zzz_chart_ex_file.xlsx",
+        "xl/charts/_rels/chartEx1.xml.rels",
+    );
+    assert!(rels.contains("Target=\"style1.xml\""));
+    assert!(rels.contains("Target=\"colors1.xml\""));
+    assert_eq!(
+        read_part(
+            "./tests/result_files/zzz_chart_ex_file.xlsx",
+            "xl/charts/style1.xml"
+        ),
+        read_part("./tests/test_files/chart_ex.xlsx", "xl/charts/style1.xml")
+    );
+    let content_types = read_part(
+        "./tests/result_files/zzz_chart_ex_file.xlsx",
+        "[Content_Types].xml",
+    );
+    assert!(content_types.contains("application/vnd.ms-office.chartcolorstyle+xml"));
+
+    let book = umya_spreadsheet::reader::xlsx::read(path).unwrap();
+    let sheet = book.get_sheet_by_name("Sheet1").unwrap();
+    assert_eq!(sheet.get_chart_ex_collection().len(), 1);
+}
+
+#[test]
+fn pivot_table() {
+    let mut book = umya_spreadsheet::new_file();
+    let sheet = book.get_sheet_by_name_mut("Sheet1").unwrap();
+    let data = [
+        ("Region", "Product", "Year", "Sales"),
+        ("East", "Apple", "2023", "10"),
+        ("West", "Apple", "2023", "20"),
+        ("East", "Pear", "2024", "30"),
+        ("East", "Apple", "2024", "5"),
+        ("West", "Pear", "2024", "15"),
+    ];
+    for (row, (region, product, year, sales)) in data.iter().enumerate() {
+        let row = row as u32 + 1;
+        sheet.get_cell_mut((1, row)).set_value(*region);
+        sheet.get_cell_mut((2, row)).set_value(*product);
+        sheet.get_cell_mut((3, row)).set_value(*year);
+        sheet.get_cell_mut((4, row)).set_value(*sales);
+    }
+
+    // rows and one data field
+    let mut pivot_table = umya_spreadsheet::PivotTable::new("PivotTable1", sheet, "A1:D6", "F3");
+    pivot_table.add_row_field("Region").unwrap();
+    pivot_table
+        .add_data_field(
+            "Sales",
+            umya_spreadsheet::DataConsolidateFunctionValues::Sum,
+        )
+        .unwrap();
+    assert!(pivot_table.add_row_field("Month").is_err());
+    assert_eq!(
+        pivot_table
+            .get_pivot_table_definition()
+            .get_location()
+            .get_reference(),
+        "F3:G6"
+    );
+    sheet.add_pivot_table(pivot_table);
+    assert_eq!(sheet.get_value("F3"), "Row Labels");
+    assert_eq!(sheet.get_value("G3"), "Sum of Sales");
+    assert_eq!(sheet.get_value("F4"), "East");
+    assert_eq!(sheet.get_value("G4"), "45");
+    assert_eq!(sheet.get_value("F5"), "West");
+    assert_eq!(sheet.get_value("G5"), "35");
+    assert_eq!(sheet.get_value("F6"), "Grand Total");
+    assert_eq!(sheet.get_value("G6"), "80");
+
+    // rows, columns, filters and two data fields
+    let mut pivot_table = umya_spreadsheet::PivotTable::new("PivotTable2", sheet, "A1:D6", "J3");
+    pivot_table.add_row_field("Region").unwrap();
+    pivot_table.add_column_field("Year").unwrap();
+    pivot_table.add_page_field("Product").unwrap();
+    pivot_table
+        .add_data_field(
+            "Sales",
+            umya_spreadsheet::DataConsolidateFunctionValues::Sum,
+        )
+        .unwrap();
+    pivot_table
+        .add_data_field(
+            "Sales",
+            umya_spreadsheet::DataConsolidateFunctionValues::Count,
+        )
+        .unwrap();
+    sheet.add_pivot_table(pivot_table);
+    assert_eq!(sheet.get_value("J1"), "Product");
+    assert_eq!(sheet.get_value("K1"), "(All)");
+    assert_eq!(sheet.get_value("K3"), "Column Labels");
+    assert_eq!(sheet.get_value("K4"), "2023");
+    assert_eq!(sheet.get_value("K5"), "Sum of Sales");
+    assert_eq!(sheet.get_value("L5"), "Count of Sales");
+    assert_eq!(sheet.get_value("M4"), "2024");
+    assert_eq!(sheet.get_value("O4"), "Total Sum of Sales");
+    assert_eq!(sheet.get_value("J6"), "East");
+    assert_eq!(sheet.get_value("K6"), "10");
+    assert_eq!(sheet.get_value("M6"), "35");
+    assert_eq!(sheet.get_value("N6"), "2");
+    assert_eq!(sheet.get_value("O8"), "80");
+    assert_eq!(sheet.get_value("P8"), "5");
+
+    let path = std::path::Path::new("./tests/result_files/zzz_pivot_table.xlsx");
+    umya_spreadsheet::writer::xlsx::write(&book, path).unwrap();
+    let book = umya_spreadsheet::reader::xlsx::read(path).unwrap();
+    let sheet = book.get_sheet_by_name("Sheet1").unwrap();
+    assert_eq!(sheet.get_value("G6"), "80");
+    assert_eq!(sheet.get_value("P8"), "5");
+}
+
+#[test]
+fn pivot_table_refresh() {
+    let mut book = umya_spreadsheet::new_file();
+    let sheet = book.get_sheet_by_name_mut("Sheet1").unwrap();
+    let data = [
+        ("Region", "Sales"),
+        ("East", "10"),
+        ("West", "20"),
+        ("North", "30"),
+    ];
+    for (row, (region, sales)) in data.iter().enumerate() {
+        let row = row as u32 + 1;
+        sheet.get_cell_mut((1, row)).set_value(*region);
+        sheet.get_cell_mut((2, row)).set_value(*sales);
+    }
+    let table = umya_spreadsheet::Table::new("Sales", ("A1", "B4"));
+    sheet.add_table(table.clone());
+
+    let mut pivot_table = umya_spreadsheet::PivotTable::new("PivotTable1", sheet, "A1:B4", "D1");
+    pivot_table.add_row_field("Region").unwrap();
+    pivot_table
+        .add_data_field(
+            "Sales",
+            umya_spreadsheet::DataConsolidateFunctionValues::Sum,
+        )
+        .unwrap();
+    sheet.add_pivot_table(pivot_table);
+
+    let mut pivot_table =
+        umya_spreadsheet::PivotTable::new_with_table("PivotTable2", sheet, &table, "H1");
+    pivot_table.add_row_field("Region").unwrap();
+    pivot_table
+        .add_data_field(
+            "Sales",
+            umya_spreadsheet::DataConsolidateFunctionValues::Maximum,
+        )
+        .unwrap();
+    sheet.add_pivot_table(pivot_table);
+    assert_eq!(sheet.get_value("D3"), "North");
+    assert_eq!(sheet.get_value("D4"), "West");
+    assert_eq!(sheet.get_value("E5"), "60");
+    assert_eq!(sheet.get_value("H5"), "Grand Total");
+
+    // the range source keeps its range, the table source follows the table.
+    sheet.get_cell_mut("A4").set_value("East");
+    sheet.get_cell_mut("A5").set_value("South");
+    sheet.get_cell_mut("B5").set_value_number(5);
+    sheet.get_tables_mut()[0].set_area(("A1", "B5"));
+    book.refresh_pivot_tables().unwrap();
+
+    let sheet = book.get_sheet_by_name("Sheet1").unwrap();
+    assert_eq!(sheet.get_value("D2"), "East");
+    assert_eq!(sheet.get_value("E2"), "40");
+    assert_eq!(sheet.get_value("D3"), "West");
+    assert_eq!(sheet.get_value("D4"), "Grand Total");
+    assert_eq!(sheet.get_value("E4"), "60");
+    assert_eq!(sheet.get_value("D5"), "");
+    assert_eq!(sheet.get_value("E5"), "");
+    let pivot_table = &sheet.get_pivot_tables()[0];
+    assert_eq!(
+        pivot_table
+            .get_pivot_table_definition()
+            .get_location()
+            .get_reference(),
+        "D1:E4"
+    );
+    assert_eq!(
+        pivot_table
+            .get_pivot_table_definition()
+            .get_data_fields()
+            .get_list()[0]
+            .get_name(),
+        "Sum of Sales"
+    );
+
+    assert_eq!(sheet.get_value("H2"), "East");
+    assert_eq!(sheet.get_value("I2"), "30");
+    assert_eq!(sheet.get_value("H3"), "South");
+    assert_eq!(sheet.get_value("I3"), "5");
+    assert_eq!(sheet.get_value("H5"), "Grand Total");
+    assert_eq!(sheet.get_value("I5"), "30");
+    assert_eq!(
+        sheet.get_pivot_tables()[1]
+            .get_pivot_cache_definition()
+            .get_record_count(),
+        &4
+    );
+
+    // a field that is gone from the source
+    let mut book = book.clone();
+    let sheet = book.get_sheet_by_name_mut("Sheet1").unwrap();
+    sheet.get_cell_mut("A1").set_value("Area");
+    assert!(book.refresh_pivot_tables().is_err());
+
+    let path = std::path::Path::new("./tests/result_files/zzz_pivot_table_refresh.xlsx");
+    umya_spreadsheet::writer::xlsx::write(&book, path).unwrap();
+}
+
+#[test]
+fn pivot_table_shared_cache() {
+    use std::io::Read;
+
+    // two pivot tables sharing cacheId 0
+    let path = std::path::Path::new("./tests/test_files/aaa.xlsx");
+    let book = umya_spreadsheet::reader::xlsx::read(path).unwrap();
+    let path = std::path::Path::new("./tests/result_files/zzz_pivot_table_shared_cache.xlsx");
+    umya_spreadsheet::writer::xlsx::write(&book, path).unwrap();
+
+    let mut arv = zip::ZipArchive::new(std::fs::File::open(path).unwrap()).unwrap();
+    let mut read_part = |name: &str| {
+        let mut buf = String::new();
+        arv.by_name(name).unwrap().read_to_string(&mut buf).unwrap();
+        buf
+    };
+    let workbook = read_part("xl/workbook.xml");
+    assert!(workbook.contains(r#"<pivotCaches><pivotCache cacheId="0" r:id="#));
+    assert_eq!(workbook.matches("<pivotCache ").count(), 1);
+    for no in 1..=2 {
+        let rels = read_part(&format!("xl/pivotTables/_rels/pivotTable{}.xml.rels", no));
+        assert!(rels.contains(r#"Target="../pivotCache/pivotCacheDefinition1.xml""#));
+        let definition = read_part(&format!("xl/pivotTables/pivotTable{}.xml", no));
+        assert!(definition.contains(r#"cacheId="0""#));
+        assert!(definition.contains(r#"xr:uid="{"#));
+        assert!(definition.contains("<x14:pivotTableDefinition hideValuesRow=\"1\""));
+    }
+    let cache_definition = read_part("xl/pivotCache/pivotCacheDefinition1.xml");
+    assert!(cache_definition.contains(r#"xr:uid="{77E71A49-E2EF-4ED9-B7AD-D3C42AD32709}""#));
+    assert!(
+        cache_definition.contains("<extLst><ext uri=\"{725AE2AE-9491-48be-B2B4-4EB974FC3084}\"")
+    );
+    assert!(arv
+        .by_name("xl/pivotCache/pivotCacheDefinition2.xml")
+        .is_err());
+
+    let book = umya_spreadsheet::reader::xlsx::read(path).unwrap();
+    let pivot_tables: Vec<_> = book
+        .get_sheet_collection()
+        .iter()
+        .flat_map(|v| v.get_pivot_tables())
+        .collect();
+    assert_eq!(pivot_tables.len(), 2);
+    for pivot_table in pivot_tables {
+        assert_eq!(
+            pivot_table.get_pivot_cache_definition().get_uid(),
+            "{77E71A49-E2EF-4ED9-B7AD-D3C42AD32709}"
+        );
+    }
+}
+
+#[test]
+fn chart_render_svg_and_png() {
+    use umya_spreadsheet::structs::drawing::spreadsheet::MarkerType;
+    use umya_spreadsheet::structs::{Chart, ChartType};
+
+    let mut book = umya_spreadsheet::new_file();
+    let sheet = book.get_sheet_by_name_mut("Sheet1").unwrap();
+    for row in 1..=4 {
+        sheet.get_cell_mut((1, row)).set_value(format!("Q{}", row));
+        sheet.get_cell_mut((2, row)).set_value_number(row * 10);
+    }
+
+    let mut from_marker = MarkerType::default();
+    let mut to_marker = MarkerType::default();
+    from_marker.set_coordinate("D1");
+    to_marker.set_coordinate("K15");
+    let mut chart = Chart::default();
+    chart.new_chart(
+        ChartType::PieChart,
+        from_marker,
+        to_marker,
+        vec!["Sheet1!$A$1:$A$4"],
+    );
+    chart
+        .set_title("Sales")
+        .set_series_point_title(vec!["Q1", "Q2", "Q3", "Q4"]);
+    sheet.add_chart(chart);
+
+    let chart = &book
+        .get_sheet_by_name("Sheet1")
+        .unwrap()
+        .get_chart_collection()[0];
+    let svg = chart.to_svg(&book, 480, 320);
+    assert!(svg.starts_with("<svg"));
+    assert!(svg.contains("width=\"480\" height=\"320\""));
+    assert!(svg.contains(">Sales</text>"));
+    assert!(svg.contains(">Q4</text>"));
+    assert!(svg.trim_end().ends_with("</svg>"));
+
+    // the drawing follows the cells
+    let mut changed = book.clone();
+    changed
+        .get_sheet_by_name_mut("Sheet1")
+        .unwrap()
+        .get_cell_mut("A1")
+        .set_value_number(100);
+    assert_ne!(chart.to_svg(&changed, 480, 320), svg);
+
+    // without the worksheet the cached values are used
+    let path = std::path::Path::new("./tests/result_files/zzz_chart_render.xlsx");
+    umya_spreadsheet::writer::xlsx::write(&book, path).unwrap();
+    let book = umya_spreadsheet::reader::xlsx::read(path).unwrap();
+    let chart = &book
+        .get_sheet_by_name("Sheet1")
+        .unwrap()
+        .get_chart_collection()[0];
+    let empty = umya_spreadsheet::new_file_empty_worksheet();
+    assert_eq!(chart.to_svg(&empty, 480, 320), svg);
+
+    #[cfg(feature = "image")]
+    {
+        let png = chart.to_png(&book, 480, 320).unwrap();
+        let image = image::load_from_memory(&png).unwrap();
+        assert_eq!(image.width(), 480);
+        assert_eq!(image.height(), 320);
+    }
+}
+
+#[test]
+fn date1904() {
+    let mut book = umya_spreadsheet::new_file();
+    assert!(!book.get_date1904());
+    book.set_date1904(true);
+    book.new_sheet("Sheet2").unwrap();
+    assert!(book.get_sheet_by_name("Sheet2").unwrap().get_date1904());
+
+    let sheet = book.get_sheet_by_name_mut("Sheet1").unwrap();
+    assert!(sheet.get_date1904());
+    // 2024-01-31 12:00:00 is 45322.5 in the 1900 date system
+    sheet.get_cell_mut("A1").set_value_number(43860.5);
+    sheet
+        .get_style_mut("A1")
+        .get_number_format_mut()
+        .set_format_code("yyyy-mm-dd hh:mm");
+    sheet.get_cell_mut("A2").set_formula("DATE(2024,1,31)");
+    sheet
+        .get_cell_mut("A3")
+        .set_formula("YEAR(A1)&\"/\"&MONTH(A1)&\"/\"&DAY(A1)");
+    sheet
+        .get_cell_mut("A4")
+        .set_formula("TEXT(A1,\"yyyy-mm-dd\")");
+    sheet.get_cell_mut("A5").set_formula("DATE(1903,12,31)");
+    sheet.get_cell_mut("A6").set_formula("WEEKDAY(A1)");
+    book.calculate().unwrap();
+
+    let sheet = book.get_sheet_by_name("Sheet1").unwrap();
+    assert_eq!(sheet.get_formatted_value("A1"), "2024-01-31 12:00");
+    assert_eq!(sheet.get_value("A2"), "43860");
+    assert_eq!(sheet.get_value("A3"), "2024/1/31");
+    assert_eq!(sheet.get_value("A4"), "2024-01-31");
+    assert_eq!(sheet.get_value("A5"), "#NUM!");
+    assert_eq!(sheet.get_value("A6"), "4");
+    // a cell on its own does not know its workbook
+    assert_eq!(
+        sheet.get_cell("A1").unwrap().get_formatted_value(),
+        "2020-01-30 12:00"
+    );
+
+    let path = std::path::Path::new("./tests/result_files/zzz_date1904.xlsx");
+    umya_spreadsheet::writer::xlsx::write(&book, path).unwrap();
+    let book = umya_spreadsheet::reader::xlsx::read(path).unwrap();
+    assert!(book.get_date1904());
+    let sheet = book.get_sheet_by_name("Sheet1").unwrap();
+    assert_eq!(sheet.get_formatted_value("A1"), "2024-01-31 12:00");
+
+    let mut rows = umya_spreadsheet::reader::xlsx::rows(path, "Sheet1").unwrap();
+    let row = rows.iter().unwrap().next().unwrap().unwrap();
+    assert_eq!(
+        row.get_cell(1).unwrap().get_formatted_value(),
+        "2024-01-31 12:00"
+    );
+
+    let path = std::path::Path::new("./tests/test_files/aaa.xlsx");
+    let book = umya_spreadsheet::reader::xlsx::read(path).unwrap();
+    assert!(!book.get_date1904());
+}
+
+#[test]
+fn cell_date_time_values() {
+    use chrono::{Duration, NaiveDate, NaiveTime};
+
+    let mut book = umya_spreadsheet::new_file();
+    let sheet = book.get_sheet_by_name_mut("Sheet1").unwrap();
+    let date = |y, m, d| NaiveDate::from_ymd_opt(y, m, d).unwrap();
+
+    let value = date(2024, 1, 31)
+        .and_hms_milli_opt(12, 30, 15, 250)
+        .unwrap();
+    sheet.get_cell_mut("A1").set_value_datetime(value);
+    sheet.get_cell_mut("A2").set_value_date(date(1900, 2, 28));
+    sheet.get_cell_mut("A3").set_value_date(date(1900, 3, 1));
+    sheet
+        .get_cell_mut("A4")
+        .set_value_time(NaiveTime::from_hms_opt(18, 0, 0).unwrap());
+    sheet
+        .get_cell_mut("A5")
+        .set_value_duration(Duration::hours(30) + Duration::minutes(15));
+    // an existing date format is kept
+    sheet
+        .get_style_mut("A6")
+        .get_number_format_mut()
+        .set_format_code("yyyy/mm/dd");
+    sheet.get_cell_mut("A6").set_value_datetime(value);
+
+    let sheet = book.get_sheet_by_name("Sheet1").unwrap();
+    assert_eq!(sheet.get_formatted_value("A1"), "1/31/2024 12:30");
+    assert_eq!(sheet.get_value_number("A2"), Some(59f64));
+    assert_eq!(sheet.get_value_number("A3"), Some(61f64));
+    assert_eq!(sheet.get_value_number("A4"), Some(0.75));
+    assert_eq!(sheet.get_formatted_value("A4"), "18:00:00");
+    assert_eq!(sheet.get_value_number("A5"), Some(1.2604166666666667));
+    assert_eq!(sheet.get_formatted_value("A6"), "2024/01/31");
+
+    assert_eq!(sheet.get_value_datetime("A1"), Some(value));
+    assert_eq!(
+        sheet.get_value_datetime("A3"),
+        date(1900, 3, 1).and_hms_opt(0, 0, 0)
+    );
+    assert_eq!(
+        sheet.get_value_datetime("A4").map(|v| v.time()),
+        NaiveTime::from_hms_opt(18, 0, 0)
+    );
+
+    // serials are read only with a date format, and 1900-02-29 does not exist
+    let mut book = umya_spreadsheet::new_file();
+    let sheet = book.get_sheet_by_name_mut("Sheet1").unwrap();
+    sheet.get_cell_mut("B1").set_value_number(45322);
+    assert_eq!(sheet.get_value_datetime("B1"), None);
+    sheet
+        .get_style_mut("B1")
+        .get_number_format_mut()
+        .set_number_format_id(14);
+    assert_eq!(
+        sheet.get_value_datetime("B1"),
+        date(2024, 1, 31).and_hms_opt(0, 0, 0)
+    );
+    sheet.get_cell_mut("B1").set_value_number(60);
+    assert_eq!(sheet.get_value_datetime("B1"), None);
+    sheet.get_cell_mut("B1").set_value("text");
+    assert_eq!(sheet.get_value_datetime("B1"), None);
+
+    // the worksheet follows the date system of the workbook
+    book.set_date1904(true);
+    let sheet = book.get_sheet_by_name_mut("Sheet1").unwrap();
+    sheet.set_value_datetime("C1", value);
+    assert_eq!(
+        sheet.get_value_number("C1").map(|v| v.floor()),
+        Some(43860f64)
+    );
+    assert_eq!(sheet.get_value_datetime("C1"), Some(value));
+    sheet.set_value_date("C2", date(1904, 1, 1));
+    assert_eq!(sheet.get_value_number("C2"), Some(0f64));
+
+    let path = std::path::Path::new("./tests/result_files/zzz_cell_date_time_values.xlsx");
+    umya_spreadsheet::writer::xlsx::write(&book, path).unwrap();
+    let book = umya_spreadsheet::reader::xlsx::read(path).unwrap();
+    let sheet = book.get_sheet_by_name("Sheet1").unwrap();
+    assert_eq!(sheet.get_value_datetime("C1"), Some(value));
+}
+
+#[test]
+fn number_format_sections() {
+    let mut book = umya_spreadsheet::new_file();
+    let sheet = book.get_sheet_mut(&0).unwrap();
+    let format = r#"#,##0.00_);[Red](#,##0.00);"-";"<"@">""#;
+    for (coordinate, value) in [("A1", "1234.5"), ("A2", "-1234.5"), ("A3", "0")] {
+        sheet
+            .get_cell_mut(coordinate)
+            .set_value_number(value.parse::<f64>().unwrap());
+        sheet
+            .get_style_mut(coordinate)
+            .get_number_format_mut()
+            .set_format_code(format);
+    }
+    sheet.get_cell_mut("A4").set_value_string("abc");
+    sheet.get_cell_mut("A5").set_value_string("123456789012");
+    for coordinate in ["A4", "A5"] {
+        sheet
+            .get_style_mut(coordinate)
+            .get_number_format_mut()
+            .set_format_code(format);
+    }
+    sheet.get_cell_mut("A6").set_value_string("123456789012");
+    sheet.get_cell_mut("A7").set_value_number(123456789012f64);
+    sheet.get_cell_mut("A8").set_value_bool(true);
+
+    assert_eq!(sheet.get_formatted_value("A1"), "1,234.50");
+    assert_eq!(sheet.get_formatted_value("A2"), "(1,234.50)");
+    assert_eq!(sheet.get_formatted_value("A3"), "-");
+    assert_eq!(sheet.get_formatted_value("A4"), "<abc>");
+    // text cells are never read as numbers
+    assert_eq!(sheet.get_formatted_value("A5"), "<123456789012>");
+    assert_eq!(sheet.get_formatted_value("A6"), "123456789012");
+    assert_eq!(sheet.get_formatted_value("A7"), "1.23457E+11");
+    assert_eq!(sheet.get_formatted_value("A8"), "TRUE");
+}
+
+#[test]
+fn auto_fit_with_font_metrics() {
+    let mut book = umya_spreadsheet::new_file();
+    let sheet = book.get_sheet_mut(&0).unwrap();
+    sheet.get_cell_mut("J1").set_value_string("0123456789");
+    sheet.get_cell_mut("B1").set_value("iiiiiiiiii");
+    sheet.get_cell_mut("C1").set_value("WWWWWWWWWW");
+    for coordinate in ["D1", "E1"] {
+        sheet
+            .get_cell_mut(coordinate)
+            .set_value("Quarterly Report")
+            .get_style_mut()
+            .get_font_mut()
+            .set_name("Arial")
+            .set_size(10f64);
+    }
+    sheet.get_style_mut("D1").get_font_mut().set_bold(true);
+    // merged across columns, not fitted
+    sheet
+        .get_cell_mut("F1")
+        .set_value("a long title spanning the merged columns");
+    sheet.add_merge_cells("F1:G1");
+    for column in ["A", "B", "C", "D", "E", "F", "J"] {
+        sheet.get_column_dimension_mut(column).set_auto_width(true);
+    }
+
+    sheet
+        .get_cell_mut("A2")
+        .set_value("first line\nsecond line")
+        .get_style_mut()
+        .get_alignment_mut()
+        .set_wrap_text(true);
+    // line feeds of text without wrap are not shown
+    sheet
+        .get_cell_mut("K3")
+        .set_value("first line\nsecond line");
+    sheet
+        .get_cell_mut("A4")
+        .set_value("large")
+        .get_style_mut()
+        .get_font_mut()
+        .set_size(20f64);
+    // merged across rows, not fitted
+    sheet
+        .get_cell_mut("A5")
+        .set_value("1\n2\n3")
+        .get_style_mut()
+        .get_alignment_mut()
+        .set_wrap_text(true);
+    sheet.add_merge_cells("A5:A7");
+    // wraps at the width of the column
+    sheet.get_column_dimension_mut("H").set_width(10f64);
+    sheet
+        .get_cell_mut("H8")
+        .set_value("one two three four five six")
+        .get_style_mut()
+        .get_alignment_mut()
+        .set_wrap_text(true);
+    for row in 2..=8 {
+        sheet.get_row_dimension_mut(&row).set_auto_height(true);
+    }
+
+    let path = std::path::Path::new("./tests/result_files/zzz_auto_fit.xlsx");
+    umya_spreadsheet::writer::xlsx::write(&book, path).unwrap();
+    let book = umya_spreadsheet::reader::xlsx::read(path).unwrap();
+    let sheet = book.get_sheet(&0).unwrap();
+
+    let width = |column: &str| *sheet.get_column_dimension(column).unwrap().get_width();
+    assert_eq!(width("J"), 10.7109375);
+    assert!(width("B") < width("J"));
+    assert!(width("C") > width("J"));
+    assert!(width("D") > width("E"));
+    assert_eq!(width("F"), 8.38);
+
+    let height = |row: u32| *sheet.get_row_dimension(&row).unwrap().get_height();
+    assert_eq!(height(2), 30f64);
+    assert_eq!(height(3), 15f64);
+    assert_eq!(height(4), 26.25);
+    assert_eq!(height(5), 15f64);
+    assert_eq!(height(6), 15f64);
+    assert_eq!(height(8), 45f64);
+    assert!(!*sheet.get_row_dimension(&2).unwrap().get_custom_height());
+}
+
+#[test]
+fn header_footer_sections_and_pictures() {
+    let mut book = umya_spreadsheet::new_file();
+    let sheet = book.get_sheet_mut(&0).unwrap();
+
+    let mut comment = Comment::default();
+    comment.new_comment("B2");
+    comment.set_text_string("TEST");
+    sheet.add_comments(comment);
+
+    let mut builder = HeaderFooterBuilder::default();
+    builder.get_left_mut().add_picture();
+    builder
+        .get_right_mut()
+        .add_text("Page ")
+        .add_page_number()
+        .add_text(" of ")
+        .add_page_count();
+    let odd_header = builder.build();
+
+    let header_footer = sheet.get_header_footer_mut();
+    header_footer
+        .set_different_odd_even(true)
+        .set_different_first(true)
+        .set_scale_with_doc(false);
+    header_footer.get_odd_header_mut().set_value(&odd_header);
+    header_footer.get_odd_footer_mut().set_value("&C&F");
+    header_footer.get_even_header_mut().set_value("&R&A");
+    header_footer.get_even_footer_mut().set_value("&C&D");
+    header_footer.get_first_header_mut().set_value("&C&G");
+    header_footer.get_first_footer_mut().set_value("&CFirst");
+    header_footer.add_image(
+        HeaderFooterImage::new_file("LH", "./images/sample1.png", 72f64, 36f64).unwrap(),
+    );
+    header_footer.add_image(
+        HeaderFooterImage::new_file("CHFIRST", "./images/sample2.png", 48f64, 24f64).unwrap(),
+    );
+
+    let path = std::path::Path::new("./tests/result_files/zzz_header_footer.xlsx");
+    umya_spreadsheet::writer::xlsx::write(&book, path).unwrap();
+    let book = umya_spreadsheet::reader::xlsx::read(path).unwrap();
+    let sheet = book.get_sheet(&0).unwrap();
+
+    assert_eq!(sheet.get_comments().len(), 1);
+    let header_footer = sheet.get_header_footer();
+    assert!(*header_footer.get_different_odd_even());
+    assert!(*header_footer.get_different_first());
+    assert!(!*header_footer.get_scale_with_doc());
+    assert!(*header_footer.get_align_with_margins());
+    assert_eq!(
+        header_footer.get_odd_header().get_value(),
+        "&L&G&RPage &P of &N"
+    );
+    assert_eq!(header_footer.get_odd_footer().get_value(), "&C&F");
+    assert_eq!(header_footer.get_even_header().get_value(), "&R&A");
+    assert_eq!(header_footer.get_even_footer().get_value(), "&C&D");
+    assert_eq!(header_footer.get_first_header().get_value(), "&C&G");
+    assert_eq!(header_footer.get_first_footer().get_value(), "&CFirst");
+
+    let images = header_footer.get_images();
+    assert_eq!(images.len(), 2);
+    assert_eq!(images[0].get_position(), "LH");
+    assert_eq!(images[0].get_width(), &72f64);
+    assert_eq!(images[0].get_height(), &36f64);
+    assert_eq!(images[0].get_image().get_image_title(), "sample1");
+    assert_eq!(
+        images[0].get_image().get_image_data(),
+        std::fs::read("./images/sample1.png").unwrap().as_slice()
+    );
+    assert_eq!(images[1].get_position(), "CHFIRST");
+    assert_eq!(
+        images[1].get_image().get_image_data(),
+        std::fs::read("./images/sample2.png").unwrap().as_slice()
+    );
+}
+
+#[test]
+fn pivot_table_with_ole_objects_relationships() {
+    let path = std::path::Path::new("./tests/test_files/aaa.xlsm");
+    let mut book = umya_spreadsheet::reader::xlsx::read(path).unwrap();
+    let sheet_index = book
+        .get_sheet_collection()
+        .iter()
+        .position(|v| v.get_name() == "Sheet1")
+        .unwrap();
+    let sheet = book.get_sheet_mut(&sheet_index).unwrap();
+    assert!(!sheet.get_ole_objects().get_ole_object().is_empty());
+
+    let data = [
+        ("Region", "Sales"),
+        ("East", "10"),
+        ("West", "20"),
+        ("East", "5"),
+    ];
+    for (row, (region, sales)) in data.iter().enumerate() {
+        let row = row as u32 + 1;
+        sheet.get_cell_mut((40, row)).set_value(*region);
+        sheet.get_cell_mut((41, row)).set_value(*sales);
+    }
+    let mut pivot_table = umya_spreadsheet::PivotTable::new("PivotTable1", sheet, "AN1:AO4", "AQ1");
+    pivot_table.add_row_field("Region").unwrap();
+    pivot_table
+        .add_data_field(
+            "Sales",
+            umya_spreadsheet::DataConsolidateFunctionValues::Sum,
+        )
+        .unwrap();
+    sheet.add_pivot_table(pivot_table);
+
+    let path = std::path::Path::new("./tests/result_files/zzz_pivot_table_ole_objects.xlsm");
+    umya_spreadsheet::writer::xlsx::write(&book, path).unwrap();
+
+    let mut arv = zip::ZipArchive::new(std::fs::File::open(path).unwrap()).unwrap();
+    let mut read = |name: &str| {
+        let mut xml = String::new();
+        std::io::Read::read_to_string(&mut arv.by_name(name).unwrap(), &mut xml).unwrap();
+        xml
+    };
+    let attribute = |tag: &str, name: &str| -> String {
+        let start = tag.find(&format!(" {}=\"", name)).unwrap() + name.len() + 3;
+        tag[start..start + tag[start..].find('"').unwrap()].to_string()
+    };
+
+    let sheet_no = sheet_index + 1;
+    let sheet_xml = read(&format!("xl/worksheets/sheet{}.xml", sheet_no));
+    let rels_xml = read(&format!("xl/worksheets/_rels/sheet{}.xml.rels", sheet_no));
+    let targets: std::collections::HashMap<String, String> = rels_xml
+        .split("<Relationship ")
+        .skip(1)
+        .map(|v| {
+            let v = format!(" {}", v);
+            (attribute(&v, "Id"), attribute(&v, "Target"))
+        })
+        .collect();
+
+    let mut checked = 0;
+    for (position, _) in sheet_xml.match_indices(" r:id=\"") {
+        let tag_start = sheet_xml[..position].rfind('<').unwrap();
+        let tag = &sheet_xml[tag_start..];
+        let element = &tag[1..tag.find(' ').unwrap()];
+        let target = targets
+            .get(&attribute(tag, "r:id"))
+            .unwrap_or_else(|| panic!("{} has no relationship", element));
+        let expected = match element {
+            "oleObject" => "../embeddings/",
+            "objectPr" => "../media/",
+            "legacyDrawing" | "legacyDrawingHF" => "../drawings/vmlDrawing",
+            "drawing" => "../drawings/drawing",
+            "tablePart" => "../tables/",
+            "pageSetup" => "../printerSettings/",
+            _ => "",
+        };
+        assert!(
+            target.starts_with(expected),
+            "{} points to {}",
+            element,
+            target
+        );
+        checked += 1;
+    }
+    assert!(checked >= 3);
+    assert!(targets
+        .values()
+        .any(|v| v.starts_with("../pivotTables/pivotTable")));
+}
+
+#[test]
+fn write_streaming_workbook_relationships() {
+    use umya_spreadsheet::writer::xlsx::StreamingWorkbook;
+
+    let path = "./tests/result_files/bbb_streaming_relationships.xlsx";
+    let file = std::fs::File::create(path).unwrap();
+    let mut book = StreamingWorkbook::new(std::io::BufWriter::new(file));
+
+    let sheet = book.new_sheet("Data").unwrap();
+    let mut comment = Comment::default();
+    comment.new_comment("B2");
+    comment.set_text_string("note");
+    sheet.add_comments(comment);
+    let mut image = Image::default();
+    let mut marker = umya_spreadsheet::structs::drawing::spreadsheet::MarkerType::default();
+    marker.set_coordinate("C3");
+    image.new_image("./images/sample1.png", marker);
+    sheet.add_image(image);
+
+    book.write_row(&["name".into()]).unwrap();
+    book.write_row_at(1_048_576, &["last".into()]).unwrap();
+    assert!(book.write_row_at(1_048_577, &["over".into()]).is_err());
+    assert!(book.write_row_at(0, &["zero".into()]).is_err());
+    book.finish().unwrap();
+
+    let book = umya_spreadsheet::reader::xlsx::read(path).unwrap();
+    let sheet = book.get_sheet_by_name("Data").unwrap();
+    assert_eq!(sheet.get_value("A1"), "name");
+    assert_eq!(sheet.get_value("A1048576"), "last");
+    assert_eq!(sheet.get_comments().len(), 1);
+    assert_eq!(sheet.get_comments()[0].get_text().get_text(), "note");
+    assert_eq!(sheet.get_image_collection().len(), 1);
+}
+
+#[test]
+fn refresh_pivot_tables_of_read_file() {
+    let path = std::path::Path::new("./tests/test_files/aaa.xlsx");
+    let mut book = umya_spreadsheet::reader::xlsx::read(path).unwrap();
+    let sheet = book.get_sheet_by_name_mut("Sheet1").unwrap();
+    assert_eq!(sheet.get_pivot_tables().len(), 2);
+    for pivot_table in sheet.get_pivot_tables() {
+        let cache = pivot_table.get_pivot_cache_definition();
+        assert_eq!(cache.get_cache_fields().get_list()[0].get_name(), "列1");
+        assert_eq!(pivot_table.get_pivot_cache_records().get_list().len(), 2);
+    }
+    assert_eq!(sheet.get_value("D46"), "test2");
+
+    sheet.get_cell_mut("D41").set_value("test3");
+    book.refresh_pivot_tables().unwrap();
+    let sheet = book.get_sheet_by_name("Sheet1").unwrap();
+    assert_eq!(sheet.get_value("D45"), "test1");
+    assert_eq!(sheet.get_value("D46"), "test3");
+    assert_eq!(sheet.get_value("F46"), "test3");
+    let names: Vec<&str> = sheet
+        .get_pivot_tables()
+        .iter()
+        .map(|v| v.get_pivot_table_definition().get_name())
+        .collect();
+    assert!(names.contains(&"ピボットテーブル1"));
+
+    let path = std::path::Path::new("./tests/result_files/zzz_refresh_pivot_tables.xlsx");
+    umya_spreadsheet::writer::xlsx::write(&book, path).unwrap();
+    let book = umya_spreadsheet::reader::xlsx::read(path).unwrap();
+    let sheet = book.get_sheet_by_name("Sheet1").unwrap();
+    assert_eq!(sheet.get_pivot_tables().len(), 2);
+    let shared_items = sheet.get_pivot_tables()[0]
+        .get_pivot_cache_definition()
+        .get_cache_fields()
+        .get_list()[0]
+        .get_shared_items()
+        .get_list()
+        .to_vec();
+    assert!(shared_items.contains(&CacheItemValue::String("test3".into())));
+
+    // a pivot table without a cache can not be refreshed
+    let mut book = umya_spreadsheet::new_file();
+    book.get_sheet_mut(&0)
+        .unwrap()
+        .get_pivot_tables_mut()
+        .push(PivotTable::default());
+    assert!(matches!(
+        book.refresh_pivot_tables(),
+        Err(XlsxError::UnsupportedPivotSource(_))
+    ));
+}
+
+#[test]
+fn calculation_auto_width_with_default_font_of_book() {
+    // the Normal style of google.xlsx is Arial 10.
+    let path = std::path::Path::new("./tests/test_files/google.xlsx");
+    let mut book = umya_spreadsheet::reader::xlsx::read(path).unwrap();
+    let mut default_book = umya_spreadsheet::new_file();
+
+    let mut get_width = |worksheet: &mut umya_spreadsheet::Worksheet| {
+        worksheet.get_cell_mut("Z1").set_value("AAAAAAAAAAAAAAAAAA");
+        worksheet.get_column_dimension_mut("Z").set_auto_width(true);
+        worksheet.calculation_auto_width();
+        *worksheet.get_column_dimension("Z").unwrap().get_width()
+    };
+    let width = get_width(book.get_sheet_mut(&0).unwrap());
+    let new_sheet_width = get_width(book.new_sheet("Sheet New").unwrap());
+    let default_width = get_width(default_book.get_sheet_mut(&0).unwrap());
+
+    assert_eq!(width, new_sheet_width);
+    assert_ne!(width, default_width);
+}